@@ -60,6 +60,8 @@ impl frame_system::Config for Test {
 	type SS58Prefix = ();
 	type OnSetCode = ();
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type AllowDispatchPathRecording = frame_support::traits::ConstBool<false>;
+	type ScanUndecodableAccounts = frame_support::traits::ConstBool<false>;
 	type Nonce = u64;
 	type Block = Block;
 }
@@ -118,6 +118,8 @@ impl frame_system::Config for Test {
 	type SS58Prefix = ConstU16<42>;
 	type OnSetCode = ();
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type AllowDispatchPathRecording = frame_support::traits::ConstBool<false>;
+	type ScanUndecodableAccounts = frame_support::traits::ConstBool<false>;
 	type Nonce = u64;
 	type Block = Block;
 }
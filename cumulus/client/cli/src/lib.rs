@@ -30,7 +30,10 @@ use codec::Encode;
 use sc_chain_spec::ChainSpec;
 use sc_client_api::HeaderBackend;
 use sc_service::{
-	config::{PrometheusConfig, RpcBatchRequestConfig, TelemetryEndpoints},
+	config::{
+		PrometheusConfig, RpcAuthConfig, RpcBatchRequestConfig, RpcMethodAliases,
+		RpcMethodRateLimit, TelemetryEndpoints, TransactionResubmissionPolicy,
+	},
 	BasePath, TransactionPoolOptions,
 };
 use sp_core::hexdisplay::HexDisplay;
@@ -451,6 +454,22 @@ impl sc_cli::CliConfiguration for NormalizedRunCmd {
 		self.base.rpc_batch_config()
 	}
 
+	fn rpc_method_rate_limits(&self) -> sc_cli::Result<Vec<RpcMethodRateLimit>> {
+		self.base.rpc_method_rate_limits()
+	}
+
+	fn rpc_auth(&self) -> sc_cli::Result<Option<RpcAuthConfig>> {
+		self.base.rpc_auth()
+	}
+
+	fn rpc_tx_resubmission(&self) -> sc_cli::Result<TransactionResubmissionPolicy> {
+		self.base.rpc_tx_resubmission()
+	}
+
+	fn rpc_method_aliases(&self) -> sc_cli::Result<RpcMethodAliases> {
+		self.base.rpc_method_aliases()
+	}
+
 	fn transaction_pool(&self, is_dev: bool) -> sc_cli::Result<TransactionPoolOptions> {
 		self.base.transaction_pool(is_dev)
 	}
@@ -17,6 +17,7 @@
 //! Taken from polkadot/runtime/common (at a21cd64) and adapted for parachains.
 
 use frame_support::traits::{
+	fungible,
 	fungibles::{self, Balanced, Credit},
 	Contains, ContainsPair, Currency, Get, Imbalance, OnUnbalanced, OriginTrait,
 };
@@ -132,8 +133,8 @@ pub struct ToParentTreasury<TreasuryAccount, AccountIdConverter, T>(
 	PhantomData<(TreasuryAccount, AccountIdConverter, T)>,
 );
 
-impl<TreasuryAccount, AccountIdConverter, T> OnUnbalanced<NegativeImbalance<T>>
-	for ToParentTreasury<TreasuryAccount, AccountIdConverter, T>
+impl<TreasuryAccount, AccountIdConverter, T>
+	ToParentTreasury<TreasuryAccount, AccountIdConverter, T>
 where
 	T: pallet_balances::Config + pallet_xcm::Config + frame_system::Config,
 	<<T as frame_system::Config>::RuntimeOrigin as OriginTrait>::AccountId: From<AccountIdOf<T>>,
@@ -142,12 +143,9 @@ where
 	AccountIdConverter: ConvertLocation<AccountIdOf<T>>,
 	BalanceOf<T>: Into<Fungibility>,
 {
-	fn on_unbalanced(amount: NegativeImbalance<T>) {
-		let amount = match amount.drop_zero() {
-			Ok(..) => return,
-			Err(amount) => amount,
-		};
-		let imbalance = amount.peek();
+	/// Teleport `imbalance` to the relay chain treasury account after `resolve` deposited it into
+	/// the account of the root location.
+	fn teleport(imbalance: BalanceOf<T>, resolve: impl FnOnce(&AccountIdOf<T>)) {
 		let root_location: Location = Here.into();
 		let root_account: AccountIdOf<T> =
 			match AccountIdConverter::convert_location(&root_location) {
@@ -159,7 +157,7 @@ where
 			};
 		let treasury_account: AccountIdOf<T> = TreasuryAccount::get();
 
-		<pallet_balances::Pallet<T>>::resolve_creating(&root_account, amount);
+		resolve(&root_account);
 
 		let result = <pallet_xcm::Pallet<T>>::limited_teleport_assets(
 			<<T as frame_system::Config>::RuntimeOrigin>::root(),
@@ -180,6 +178,48 @@ where
 	}
 }
 
+impl<TreasuryAccount, AccountIdConverter, T> OnUnbalanced<NegativeImbalance<T>>
+	for ToParentTreasury<TreasuryAccount, AccountIdConverter, T>
+where
+	T: pallet_balances::Config + pallet_xcm::Config + frame_system::Config,
+	<<T as frame_system::Config>::RuntimeOrigin as OriginTrait>::AccountId: From<AccountIdOf<T>>,
+	[u8; 32]: From<<T as frame_system::Config>::AccountId>,
+	TreasuryAccount: Get<AccountIdOf<T>>,
+	AccountIdConverter: ConvertLocation<AccountIdOf<T>>,
+	BalanceOf<T>: Into<Fungibility>,
+{
+	fn on_unbalanced(amount: NegativeImbalance<T>) {
+		let amount = match amount.drop_zero() {
+			Ok(..) => return,
+			Err(amount) => amount,
+		};
+		Self::teleport(amount.peek(), |root_account| {
+			<pallet_balances::Pallet<T>>::resolve_creating(root_account, amount)
+		});
+	}
+}
+
+impl<TreasuryAccount, AccountIdConverter, T>
+	OnUnbalanced<fungible::Credit<AccountIdOf<T>, pallet_balances::Pallet<T>>>
+	for ToParentTreasury<TreasuryAccount, AccountIdConverter, T>
+where
+	T: pallet_balances::Config + pallet_xcm::Config + frame_system::Config,
+	<<T as frame_system::Config>::RuntimeOrigin as OriginTrait>::AccountId: From<AccountIdOf<T>>,
+	[u8; 32]: From<<T as frame_system::Config>::AccountId>,
+	TreasuryAccount: Get<AccountIdOf<T>>,
+	AccountIdConverter: ConvertLocation<AccountIdOf<T>>,
+	BalanceOf<T>: Into<Fungibility>,
+{
+	fn on_nonzero_unbalanced(amount: fungible::Credit<AccountIdOf<T>, pallet_balances::Pallet<T>>) {
+		Self::teleport(amount.peek(), |root_account| {
+			let _ = <pallet_balances::Pallet<T> as fungible::Balanced<_>>::resolve(
+				root_account,
+				amount,
+			);
+		});
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -852,6 +852,10 @@ impl_runtime_apis! {
 		) -> sp_inherents::CheckInherentsResult {
 			data.check_extrinsics(&block)
 		}
+
+		fn extrinsic_proof_sizes() -> Vec<sp_block_builder::ExtrinsicProofSize> {
+			Executive::extrinsic_proof_sizes()
+		}
 	}
 
 	impl sp_transaction_pool::runtime_api::TaggedTransactionQueue<Block> for Runtime {
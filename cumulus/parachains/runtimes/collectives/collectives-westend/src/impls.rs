@@ -14,77 +14,13 @@
 // limitations under the License.
 
 use crate::OriginCaller;
-use frame_support::{
-	dispatch::DispatchResultWithPostInfo,
-	traits::{Currency, PrivilegeCmp},
-	weights::Weight,
-};
-use pallet_alliance::{ProposalIndex, ProposalProvider};
-use sp_runtime::DispatchError;
-use sp_std::{cmp::Ordering, marker::PhantomData, prelude::*};
-
-type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
-
-type ProposalOf<T, I> = <T as pallet_collective::Config<I>>::Proposal;
-
-type HashOf<T> = <T as frame_system::Config>::Hash;
+use frame_support::traits::{Currency, PrivilegeCmp};
+use sp_std::{cmp::Ordering, marker::PhantomData};
 
 /// Type alias to conveniently refer to the `Currency::Balance` associated type.
 pub type BalanceOf<T> =
 	<pallet_balances::Pallet<T> as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
-/// Proposal provider for alliance pallet.
-/// Adapter from collective pallet to alliance proposal provider trait.
-pub struct AllianceProposalProvider<T, I = ()>(PhantomData<(T, I)>);
-
-impl<T, I> ProposalProvider<AccountIdOf<T>, HashOf<T>, ProposalOf<T, I>>
-	for AllianceProposalProvider<T, I>
-where
-	T: pallet_collective::Config<I> + frame_system::Config,
-	I: 'static,
-{
-	fn propose_proposal(
-		who: AccountIdOf<T>,
-		threshold: u32,
-		proposal: Box<ProposalOf<T, I>>,
-		length_bound: u32,
-	) -> Result<(u32, u32), DispatchError> {
-		pallet_collective::Pallet::<T, I>::do_propose_proposed(
-			who,
-			threshold,
-			proposal,
-			length_bound,
-		)
-	}
-
-	fn vote_proposal(
-		who: AccountIdOf<T>,
-		proposal: HashOf<T>,
-		index: ProposalIndex,
-		approve: bool,
-	) -> Result<bool, DispatchError> {
-		pallet_collective::Pallet::<T, I>::do_vote(who, proposal, index, approve)
-	}
-
-	fn close_proposal(
-		proposal_hash: HashOf<T>,
-		proposal_index: ProposalIndex,
-		proposal_weight_bound: Weight,
-		length_bound: u32,
-	) -> DispatchResultWithPostInfo {
-		pallet_collective::Pallet::<T, I>::do_close(
-			proposal_hash,
-			proposal_index,
-			proposal_weight_bound,
-			length_bound,
-		)
-	}
-
-	fn proposal_of(proposal_hash: HashOf<T>) -> Option<ProposalOf<T, I>> {
-		pallet_collective::Pallet::<T, I>::proposal_of(proposal_hash)
-	}
-}
-
 /// Used to compare the privilege of an origin inside the scheduler.
 pub struct EqualOrGreatestRootCmp;
 
@@ -46,7 +46,7 @@ pub use ambassador::pallet_ambassador_origins;
 
 use cumulus_pallet_parachain_system::RelayNumberMonotonicallyIncreases;
 use fellowship::{pallet_fellowship_origins, Fellows};
-use impls::{AllianceProposalProvider, EqualOrGreatestRootCmp};
+use impls::EqualOrGreatestRootCmp;
 use sp_api::impl_runtime_apis;
 use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_runtime::{
@@ -70,7 +70,7 @@ use frame_support::{
 	parameter_types,
 	traits::{
 		fungible::HoldConsideration, ConstBool, ConstU16, ConstU32, ConstU64, ConstU8,
-		EitherOfDiverse, InstanceFilter, LinearStoragePrice, TransformOrigin,
+		EitherOfDiverse, Everything, InstanceFilter, LinearStoragePrice, TransformOrigin,
 	},
 	weights::{ConstantMultiplier, Weight},
 	PalletId,
@@ -548,21 +548,27 @@ parameter_types! {
 	// The number of blocks a member must wait between giving a retirement notice and retiring.
 	// Supposed to be greater than time required to `kick_member` with alliance motion.
 	pub const AllianceRetirementPeriod: BlockNumber = (90 * DAYS) + ALLIANCE_MOTION_DURATION;
+	// The number of allies a single fellow can nominate per `AllianceNominationPeriod`.
+	pub const AllianceNominationQuota: Option<u32> = Some(10);
+	pub const AllianceNominationPeriod: BlockNumber = 30 * DAYS;
 }
 
 impl pallet_alliance::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Proposal = RuntimeCall;
+	type ProposalFilter = Everything;
 	type AdminOrigin = RootOrAllianceTwoThirdsMajority;
 	type MembershipManager = RootOrAllianceTwoThirdsMajority;
 	type AnnouncementOrigin = RootOrAllianceTwoThirdsMajority;
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type Currency = Balances;
 	type Slashed = ToParentTreasury<WestendTreasuryAccount, LocationToAccountId, Runtime>;
 	type InitializeMembers = AllianceMotion;
 	type MembershipChanged = AllianceMotion;
 	type RetirementPeriod = AllianceRetirementPeriod;
 	type IdentityVerifier = (); // Don't block accounts on identity criteria
-	type ProposalProvider = AllianceProposalProvider<Runtime, AllianceCollective>;
+	type RolePolicy = ();
+	type VotingProvider = pallet_alliance::CollectiveVoting<Runtime, AllianceCollective>;
 	type MaxProposals = ConstU32<ALLIANCE_MAX_MEMBERS>;
 	type MaxFellows = ConstU32<MAX_FELLOWS>;
 	type MaxAllies = ConstU32<MAX_ALLIES>;
@@ -572,6 +578,22 @@ impl pallet_alliance::Config for Runtime {
 	type MaxMembersCount = ConstU32<ALLIANCE_MAX_MEMBERS>;
 	type AllyDeposit = AllyDeposit;
 	type WeightInfo = weights::pallet_alliance::WeightInfo<Runtime>;
+	type MaxSubCommittees = ConstU32<10>;
+	type MaxSubCommitteeMembers = ConstU32<10>;
+	type AutoClose = ConstBool<false>;
+	type AutoCloseWeight = ();
+	type MaxRuleHistory = ConstU32<100>;
+	type FlagSigner = ();
+	type NominationQuota = AllianceNominationQuota;
+	type NominationPeriod = AllianceNominationPeriod;
+	type MinimumTenure = ();
+	type EarlyRetirementSlash = ();
+	type EnactmentDelay = ();
+	type UnscrupulousMemberPenalty = ();
+	type UnscrupulousMemberSlash = ();
+	type UnscrupulousPenaltyCooldown = ();
+	type EnactmentOrigin = pallet_alliance::WithCaller<frame_support::traits::EnsureNever<()>>;
+	type PalletsOrigin = OriginCaller;
 }
 
 parameter_types! {
@@ -727,6 +749,8 @@ type Migrations = (
 	pallet_collator_selection::migration::v1::MigrateToV1<Runtime>,
 	// unreleased
 	cumulus_pallet_xcmp_queue::migration::v4::MigrationToV4<Runtime>,
+	// unreleased
+	pallet_alliance::migration::v2_to_v3::MigrateToHolds<Runtime, Balances>,
 	// permanent
 	pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,
 );
@@ -841,6 +865,10 @@ impl_runtime_apis! {
 		) -> sp_inherents::CheckInherentsResult {
 			data.check_extrinsics(&block)
 		}
+
+		fn extrinsic_proof_sizes() -> Vec<sp_block_builder::ExtrinsicProofSize> {
+			Executive::extrinsic_proof_sizes()
+		}
 	}
 
 	impl sp_transaction_pool::runtime_api::TaggedTransactionQueue<Block> for Runtime {
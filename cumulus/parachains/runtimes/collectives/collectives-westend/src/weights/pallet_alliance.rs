@@ -49,175 +49,6 @@ use core::marker::PhantomData;
 /// Weight functions for `pallet_alliance`.
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_alliance::WeightInfo for WeightInfo<T> {
-	/// Storage: `Alliance::Members` (r:1 w:0)
-	/// Proof: `Alliance::Members` (`max_values`: None, `max_size`: Some(3211), added: 5686, mode: `MaxEncodedLen`)
-	/// Storage: `AllianceMotion::ProposalOf` (r:1 w:1)
-	/// Proof: `AllianceMotion::ProposalOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `AllianceMotion::Proposals` (r:1 w:1)
-	/// Proof: `AllianceMotion::Proposals` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `AllianceMotion::ProposalCount` (r:1 w:1)
-	/// Proof: `AllianceMotion::ProposalCount` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `AllianceMotion::Voting` (r:0 w:1)
-	/// Proof: `AllianceMotion::Voting` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// The range of component `b` is `[1, 1024]`.
-	/// The range of component `m` is `[2, 100]`.
-	/// The range of component `p` is `[1, 100]`.
-	fn propose_proposed(b: u32, m: u32, p: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `439 + m * (32 ±0) + p * (36 ±0)`
-		//  Estimated: `6676 + m * (32 ±0) + p * (36 ±0)`
-		// Minimum execution time: 32_783_000 picoseconds.
-		Weight::from_parts(32_174_037, 0)
-			.saturating_add(Weight::from_parts(0, 6676))
-			// Standard Error: 198
-			.saturating_add(Weight::from_parts(1_220, 0).saturating_mul(b.into()))
-			// Standard Error: 2_074
-			.saturating_add(Weight::from_parts(40_945, 0).saturating_mul(m.into()))
-			// Standard Error: 2_048
-			.saturating_add(Weight::from_parts(181_087, 0).saturating_mul(p.into()))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(4))
-			.saturating_add(Weight::from_parts(0, 32).saturating_mul(m.into()))
-			.saturating_add(Weight::from_parts(0, 36).saturating_mul(p.into()))
-	}
-	/// Storage: `Alliance::Members` (r:1 w:0)
-	/// Proof: `Alliance::Members` (`max_values`: None, `max_size`: Some(3211), added: 5686, mode: `MaxEncodedLen`)
-	/// Storage: `AllianceMotion::Voting` (r:1 w:1)
-	/// Proof: `AllianceMotion::Voting` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// The range of component `m` is `[5, 100]`.
-	fn vote(m: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `868 + m * (64 ±0)`
-		//  Estimated: `6676 + m * (64 ±0)`
-		// Minimum execution time: 28_520_000 picoseconds.
-		Weight::from_parts(29_661_024, 0)
-			.saturating_add(Weight::from_parts(0, 6676))
-			// Standard Error: 2_336
-			.saturating_add(Weight::from_parts(89_873, 0).saturating_mul(m.into()))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(1))
-			.saturating_add(Weight::from_parts(0, 64).saturating_mul(m.into()))
-	}
-	/// Storage: `Alliance::Members` (r:1 w:0)
-	/// Proof: `Alliance::Members` (`max_values`: None, `max_size`: Some(3211), added: 5686, mode: `MaxEncodedLen`)
-	/// Storage: `AllianceMotion::Voting` (r:1 w:1)
-	/// Proof: `AllianceMotion::Voting` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `AllianceMotion::Members` (r:1 w:0)
-	/// Proof: `AllianceMotion::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `AllianceMotion::Proposals` (r:1 w:1)
-	/// Proof: `AllianceMotion::Proposals` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `AllianceMotion::ProposalOf` (r:0 w:1)
-	/// Proof: `AllianceMotion::ProposalOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// The range of component `m` is `[4, 100]`.
-	/// The range of component `p` is `[1, 100]`.
-	fn close_early_disapproved(m: u32, p: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `312 + m * (96 ±0) + p * (36 ±0)`
-		//  Estimated: `6676 + m * (97 ±0) + p * (36 ±0)`
-		// Minimum execution time: 39_353_000 picoseconds.
-		Weight::from_parts(33_028_008, 0)
-			.saturating_add(Weight::from_parts(0, 6676))
-			// Standard Error: 2_137
-			.saturating_add(Weight::from_parts(90_946, 0).saturating_mul(m.into()))
-			// Standard Error: 2_084
-			.saturating_add(Weight::from_parts(175_827, 0).saturating_mul(p.into()))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
-			.saturating_add(Weight::from_parts(0, 97).saturating_mul(m.into()))
-			.saturating_add(Weight::from_parts(0, 36).saturating_mul(p.into()))
-	}
-	/// Storage: `Alliance::Members` (r:1 w:0)
-	/// Proof: `Alliance::Members` (`max_values`: None, `max_size`: Some(3211), added: 5686, mode: `MaxEncodedLen`)
-	/// Storage: `AllianceMotion::Voting` (r:1 w:1)
-	/// Proof: `AllianceMotion::Voting` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `AllianceMotion::Members` (r:1 w:0)
-	/// Proof: `AllianceMotion::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `AllianceMotion::ProposalOf` (r:1 w:1)
-	/// Proof: `AllianceMotion::ProposalOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `AllianceMotion::Proposals` (r:1 w:1)
-	/// Proof: `AllianceMotion::Proposals` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// The range of component `b` is `[1, 1024]`.
-	/// The range of component `m` is `[4, 100]`.
-	/// The range of component `p` is `[1, 100]`.
-	fn close_early_approved(_b: u32, m: u32, p: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `762 + m * (96 ±0) + p * (41 ±0)`
-		//  Estimated: `6676 + m * (97 ±0) + p * (40 ±0)`
-		// Minimum execution time: 52_835_000 picoseconds.
-		Weight::from_parts(45_963_292, 0)
-			.saturating_add(Weight::from_parts(0, 6676))
-			// Standard Error: 3_189
-			.saturating_add(Weight::from_parts(111_627, 0).saturating_mul(m.into()))
-			// Standard Error: 3_109
-			.saturating_add(Weight::from_parts(207_923, 0).saturating_mul(p.into()))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(3))
-			.saturating_add(Weight::from_parts(0, 97).saturating_mul(m.into()))
-			.saturating_add(Weight::from_parts(0, 40).saturating_mul(p.into()))
-	}
-	/// Storage: `Alliance::Members` (r:1 w:0)
-	/// Proof: `Alliance::Members` (`max_values`: None, `max_size`: Some(3211), added: 5686, mode: `MaxEncodedLen`)
-	/// Storage: `AllianceMotion::Voting` (r:1 w:1)
-	/// Proof: `AllianceMotion::Voting` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `AllianceMotion::Members` (r:1 w:0)
-	/// Proof: `AllianceMotion::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `AllianceMotion::Prime` (r:1 w:0)
-	/// Proof: `AllianceMotion::Prime` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `AllianceMotion::ProposalOf` (r:1 w:1)
-	/// Proof: `AllianceMotion::ProposalOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `AllianceMotion::Proposals` (r:1 w:1)
-	/// Proof: `AllianceMotion::Proposals` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `Alliance::Rule` (r:0 w:1)
-	/// Proof: `Alliance::Rule` (`max_values`: Some(1), `max_size`: Some(87), added: 582, mode: `MaxEncodedLen`)
-	/// The range of component `m` is `[2, 100]`.
-	/// The range of component `p` is `[1, 100]`.
-	fn close_disapproved(m: u32, p: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `518 + m * (96 ±0) + p * (41 ±0)`
-		//  Estimated: `6676 + m * (109 ±0) + p * (43 ±0)`
-		// Minimum execution time: 49_980_000 picoseconds.
-		Weight::from_parts(48_110_301, 0)
-			.saturating_add(Weight::from_parts(0, 6676))
-			// Standard Error: 5_057
-			.saturating_add(Weight::from_parts(169_065, 0).saturating_mul(m.into()))
-			// Standard Error: 4_995
-			.saturating_add(Weight::from_parts(201_349, 0).saturating_mul(p.into()))
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(4))
-			.saturating_add(Weight::from_parts(0, 109).saturating_mul(m.into()))
-			.saturating_add(Weight::from_parts(0, 43).saturating_mul(p.into()))
-	}
-	/// Storage: `Alliance::Members` (r:1 w:0)
-	/// Proof: `Alliance::Members` (`max_values`: None, `max_size`: Some(3211), added: 5686, mode: `MaxEncodedLen`)
-	/// Storage: `AllianceMotion::Voting` (r:1 w:1)
-	/// Proof: `AllianceMotion::Voting` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `AllianceMotion::Members` (r:1 w:0)
-	/// Proof: `AllianceMotion::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `AllianceMotion::Prime` (r:1 w:0)
-	/// Proof: `AllianceMotion::Prime` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `AllianceMotion::Proposals` (r:1 w:1)
-	/// Proof: `AllianceMotion::Proposals` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `AllianceMotion::ProposalOf` (r:0 w:1)
-	/// Proof: `AllianceMotion::ProposalOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// The range of component `b` is `[1, 1024]`.
-	/// The range of component `m` is `[5, 100]`.
-	/// The range of component `p` is `[1, 100]`.
-	fn close_approved(_b: u32, m: u32, p: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `417 + m * (96 ±0) + p * (36 ±0)`
-		//  Estimated: `6676 + m * (96 ±0) + p * (36 ±0)`
-		// Minimum execution time: 40_646_000 picoseconds.
-		Weight::from_parts(36_865_909, 0)
-			.saturating_add(Weight::from_parts(0, 6676))
-			// Standard Error: 2_136
-			.saturating_add(Weight::from_parts(74_341, 0).saturating_mul(m.into()))
-			// Standard Error: 2_059
-			.saturating_add(Weight::from_parts(170_035, 0).saturating_mul(p.into()))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(3))
-			.saturating_add(Weight::from_parts(0, 96).saturating_mul(m.into()))
-			.saturating_add(Weight::from_parts(0, 36).saturating_mul(p.into()))
-	}
 	/// Storage: `Alliance::Members` (r:2 w:2)
 	/// Proof: `Alliance::Members` (`max_values`: None, `max_size`: Some(3211), added: 5686, mode: `MaxEncodedLen`)
 	/// Storage: `AllianceMotion::Members` (r:1 w:1)
@@ -250,6 +81,8 @@ impl<T: frame_system::Config> pallet_alliance::WeightInfo for WeightInfo<T> {
 	/// Proof: `AllianceMotion::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `AllianceMotion::Prime` (r:0 w:1)
 	/// Proof: `AllianceMotion::Prime` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Alliance::NominationsUsed` (r:0 w:100)
+	/// Proof: `Alliance::NominationsUsed` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[1, 100]`.
 	/// The range of component `y` is `[0, 100]`.
 	/// The range of component `z` is `[0, 50]`.
@@ -271,6 +104,7 @@ impl<T: frame_system::Config> pallet_alliance::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(y.into())))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(z.into())))
 			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
 			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(z.into())))
 			.saturating_add(Weight::from_parts(0, 2539).saturating_mul(x.into()))
 			.saturating_add(Weight::from_parts(0, 2539).saturating_mul(y.into()))
@@ -333,6 +167,8 @@ impl<T: frame_system::Config> pallet_alliance::WeightInfo for WeightInfo<T> {
 	/// Proof: `Alliance::Members` (`max_values`: None, `max_size`: Some(3211), added: 5686, mode: `MaxEncodedLen`)
 	/// Storage: `Alliance::UnscrupulousAccounts` (r:1 w:0)
 	/// Proof: `Alliance::UnscrupulousAccounts` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
+	/// Storage: `Alliance::NominationsUsed` (r:1 w:1)
+	/// Proof: `Alliance::NominationsUsed` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
 	fn nominate_ally() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `193`
@@ -340,8 +176,8 @@ impl<T: frame_system::Config> pallet_alliance::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 25_950_000 picoseconds.
 		Weight::from_parts(26_631_000, 0)
 			.saturating_add(Weight::from_parts(0, 18048))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Alliance::Members` (r:2 w:2)
 	/// Proof: `Alliance::Members` (`max_values`: None, `max_size`: Some(3211), added: 5686, mode: `MaxEncodedLen`)
@@ -387,8 +223,14 @@ impl<T: frame_system::Config> pallet_alliance::WeightInfo for WeightInfo<T> {
 	/// Proof: `Alliance::Members` (`max_values`: None, `max_size`: Some(3211), added: 5686, mode: `MaxEncodedLen`)
 	/// Storage: `Alliance::DepositOf` (r:1 w:1)
 	/// Proof: `Alliance::DepositOf` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
-	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Alliance::NominationsUsed` (r:0 w:1)
+	/// Proof: `Alliance::NominationsUsed` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `Alliance::MemberSince` (r:1 w:1)
+	/// Proof: `Alliance::MemberSince` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `Alliance::SuspendedMembers` (r:0 w:1)
+	/// Proof: `Alliance::SuspendedMembers` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
 	fn retire() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `517`
@@ -396,8 +238,8 @@ impl<T: frame_system::Config> pallet_alliance::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 38_799_000 picoseconds.
 		Weight::from_parts(39_634_000, 0)
 			.saturating_add(Weight::from_parts(0, 6676))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(8))
 	}
 	/// Storage: `Alliance::Members` (r:3 w:1)
 	/// Proof: `Alliance::Members` (`max_values`: None, `max_size`: Some(3211), added: 5686, mode: `MaxEncodedLen`)
@@ -423,6 +265,8 @@ impl<T: frame_system::Config> pallet_alliance::WeightInfo for WeightInfo<T> {
 	/// Proof: `AllianceMotion::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `AllianceMotion::Prime` (r:0 w:1)
 	/// Proof: `AllianceMotion::Prime` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Alliance::NominationsUsed` (r:0 w:1)
+	/// Proof: `Alliance::NominationsUsed` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
 	fn kick_member() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `643`
@@ -431,12 +275,14 @@ impl<T: frame_system::Config> pallet_alliance::WeightInfo for WeightInfo<T> {
 		Weight::from_parts(142_142_000, 0)
 			.saturating_add(Weight::from_parts(0, 18048))
 			.saturating_add(T::DbWeight::get().reads(13))
-			.saturating_add(T::DbWeight::get().writes(8))
+			.saturating_add(T::DbWeight::get().writes(9))
 	}
 	/// Storage: `Alliance::UnscrupulousAccounts` (r:1 w:1)
 	/// Proof: `Alliance::UnscrupulousAccounts` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
 	/// Storage: `Alliance::UnscrupulousWebsites` (r:1 w:1)
 	/// Proof: `Alliance::UnscrupulousWebsites` (`max_values`: Some(1), `max_size`: Some(25702), added: 26197, mode: `MaxEncodedLen`)
+	/// Storage: `Alliance::UnscrupulousEvidence` (r:0 w:100)
+	/// Proof: `Alliance::UnscrupulousEvidence` (`max_values`: None, `max_size`: Some(360), added: 2835, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[0, 100]`.
 	/// The range of component `l` is `[0, 255]`.
 	fn add_unscrupulous_items(n: u32, l: u32, ) -> Weight {
@@ -452,11 +298,16 @@ impl<T: frame_system::Config> pallet_alliance::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(67_739, 0).saturating_mul(l.into()))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
 	}
 	/// Storage: `Alliance::UnscrupulousAccounts` (r:1 w:1)
 	/// Proof: `Alliance::UnscrupulousAccounts` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
 	/// Storage: `Alliance::UnscrupulousWebsites` (r:1 w:1)
 	/// Proof: `Alliance::UnscrupulousWebsites` (`max_values`: Some(1), `max_size`: Some(25702), added: 26197, mode: `MaxEncodedLen`)
+	/// Storage: `Alliance::UnscrupulousEvidence` (r:0 w:100)
+	/// Proof: `Alliance::UnscrupulousEvidence` (`max_values`: None, `max_size`: Some(360), added: 2835, mode: `MaxEncodedLen`)
+	/// Storage: `Alliance::UnscrupulousPenalizedAt` (r:0 w:100)
+	/// Proof: `Alliance::UnscrupulousPenalizedAt` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[0, 100]`.
 	/// The range of component `l` is `[0, 255]`.
 	fn remove_unscrupulous_items(n: u32, l: u32, ) -> Weight {
@@ -472,6 +323,7 @@ impl<T: frame_system::Config> pallet_alliance::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(352_937, 0).saturating_mul(l.into()))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
 	}
 	/// Storage: `Alliance::Members` (r:3 w:2)
 	/// Proof: `Alliance::Members` (`max_values`: None, `max_size`: Some(3211), added: 5686, mode: `MaxEncodedLen`)
@@ -491,4 +343,269 @@ impl<T: frame_system::Config> pallet_alliance::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
+	/// Storage: `Alliance::Members` (r:3 w:0)
+	/// Proof: `Alliance::Members` (`max_values`: None, `max_size`: Some(3211), added: 5686, mode: `MaxEncodedLen`)
+	/// Storage: `Alliance::SuspendedMembers` (r:0 w:1)
+	/// Proof: `Alliance::SuspendedMembers` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn suspend_member() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `236`
+		//  Estimated: `18048`
+		// Minimum execution time: 17_802_000 picoseconds.
+		Weight::from_parts(18_419_000, 0)
+			.saturating_add(Weight::from_parts(0, 18048))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Alliance::SuspendedMembers` (r:1 w:1)
+	/// Proof: `Alliance::SuspendedMembers` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn unsuspend_member() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `139`
+		//  Estimated: `3525`
+		// Minimum execution time: 12_611_000 picoseconds.
+		Weight::from_parts(13_086_000, 0)
+			.saturating_add(Weight::from_parts(0, 3525))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Alliance::CounterForSubCommittees` (r:1 w:1)
+	/// Proof: `Alliance::CounterForSubCommittees` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Alliance::Members` (r:1 w:0)
+	/// Proof: `Alliance::Members` (`max_values`: None, `max_size`: Some(3211), added: 5686, mode: `MaxEncodedLen`)
+	/// Storage: `Alliance::NextSubCommitteeIndex` (r:1 w:1)
+	/// Proof: `Alliance::NextSubCommitteeIndex` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Alliance::SubCommittees` (r:0 w:1)
+	/// Proof: `Alliance::SubCommittees` (`max_values`: None, `max_size`: Some(338), added: 2813, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[1, 10]`.
+	fn create_sub_committee(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `443`
+		//  Estimated: `6676`
+		// Minimum execution time: 20_917_000 picoseconds.
+		Weight::from_parts(21_548_126, 0)
+			.saturating_add(Weight::from_parts(0, 6676))
+			// Standard Error: 2_211
+			.saturating_add(Weight::from_parts(118_406, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Alliance::SubCommittees` (r:1 w:1)
+	/// Proof: `Alliance::SubCommittees` (`max_values`: None, `max_size`: Some(338), added: 2813, mode: `MaxEncodedLen`)
+	/// Storage: `Alliance::CounterForSubCommittees` (r:1 w:1)
+	/// Proof: `Alliance::CounterForSubCommittees` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn revoke_sub_committee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `529`
+		//  Estimated: `3803`
+		// Minimum execution time: 15_302_000 picoseconds.
+		Weight::from_parts(15_874_000, 0)
+			.saturating_add(Weight::from_parts(0, 3803))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Alliance::Members` (r:3 w:0)
+	/// Proof: `Alliance::Members` (`max_values`: None, `max_size`: Some(3211), added: 5686, mode: `MaxEncodedLen`)
+	/// Storage: `Alliance::SuspendedMembers` (r:1 w:0)
+	/// Proof: `Alliance::SuspendedMembers` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `Alliance::Rule` (r:1 w:0)
+	/// Proof: `Alliance::Rule` (`max_values`: Some(1), `max_size`: Some(87), added: 582, mode: `MaxEncodedLen`)
+	/// Storage: `Alliance::Announcements` (r:1 w:0)
+	/// Proof: `Alliance::Announcements` (`max_values`: Some(1), `max_size`: Some(8702), added: 9197, mode: `MaxEncodedLen`)
+	/// Storage: `Alliance::FlaggedCids` (r:1 w:1)
+	/// Proof: `Alliance::FlaggedCids` (`max_values`: None, `max_size`: Some(107), added: 2582, mode: `MaxEncodedLen`)
+	fn flag_unreachable_cid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `403`
+		//  Estimated: `22355`
+		// Minimum execution time: 14_462_000 picoseconds.
+		Weight::from_parts(14_931_000, 0)
+			.saturating_add(Weight::from_parts(0, 22355))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Alliance::FlaggedCids` (r:1 w:1)
+	/// Proof: `Alliance::FlaggedCids` (`max_values`: None, `max_size`: Some(107), added: 2582, mode: `MaxEncodedLen`)
+	fn unflag_cid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `151`
+		//  Estimated: `3572`
+		// Minimum execution time: 10_583_000 picoseconds.
+		Weight::from_parts(10_960_000, 0)
+			.saturating_add(Weight::from_parts(0, 3572))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Alliance::ImportedSnapshot` (r:1 w:1)
+	/// Proof: `Alliance::ImportedSnapshot` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Alliance::Members` (r:3 w:3)
+	/// Proof: `Alliance::Members` (`max_values`: None, `max_size`: Some(3211), added: 5686, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:100 w:100)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Alliance::RetiringMembers` (r:0 w:100)
+	/// Proof: `Alliance::RetiringMembers` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `Alliance::UnscrupulousAccounts` (r:0 w:1)
+	/// Proof: `Alliance::UnscrupulousAccounts` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
+	/// Storage: `Alliance::UnscrupulousWebsites` (r:0 w:1)
+	/// Proof: `Alliance::UnscrupulousWebsites` (`max_values`: Some(1), `max_size`: Some(25702), added: 26197, mode: `MaxEncodedLen`)
+	/// Storage: `AllianceMotion::Members` (r:0 w:1)
+	/// Proof: `AllianceMotion::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `m` is `[1, 100]`.
+	/// The range of component `u` is `[0, 100]`.
+	fn force_import_snapshot(m: u32, u: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `217`
+		//  Estimated: `18048 + m * (2560 ±0)`
+		// Minimum execution time: 51_878_000 picoseconds.
+		Weight::from_parts(38_106_455, 0)
+			.saturating_add(Weight::from_parts(0, 18048))
+			// Standard Error: 1_877
+			.saturating_add(Weight::from_parts(21_702_168, 0).saturating_mul(m.into()))
+			// Standard Error: 1_850
+			.saturating_add(Weight::from_parts(139_884, 0).saturating_mul(u.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 2560).saturating_mul(m.into()))
+	}
+	/// Storage: `Alliance::NextEnactmentIndex` (r:1 w:1)
+	/// Proof: `Alliance::NextEnactmentIndex` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Alliance::EnactmentBlocks` (r:1 w:1)
+	/// Proof: `Alliance::EnactmentBlocks` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
+	/// Storage: `Alliance::PendingEnactments` (r:0 w:1)
+	/// Proof: `Alliance::PendingEnactments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn schedule_enactment() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `476`
+		//  Estimated: `1961`
+		// Minimum execution time: 14_018_000 picoseconds.
+		Weight::from_parts(14_592_000, 0)
+			.saturating_add(Weight::from_parts(0, 1961))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Alliance::PendingEnactments` (r:1 w:1)
+	/// Proof: `Alliance::PendingEnactments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Alliance::EnactmentBlocks` (r:1 w:1)
+	/// Proof: `Alliance::EnactmentBlocks` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[1, 100]`.
+	fn cancel_enactment(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `251 + p * (29 ±0)`
+		//  Estimated: `1886 + p * (29 ±0)`
+		// Minimum execution time: 12_804_000 picoseconds.
+		Weight::from_parts(13_219_637, 0)
+			.saturating_add(Weight::from_parts(0, 1886))
+			// Standard Error: 1_106
+			.saturating_add(Weight::from_parts(44_517, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(Weight::from_parts(0, 29).saturating_mul(p.into()))
+	}
+	/// Storage: `Alliance::Members` (r:3 w:1)
+	/// Proof: `Alliance::Members` (`max_values`: None, `max_size`: Some(3211), added: 5686, mode: `MaxEncodedLen`)
+	/// Storage: `Alliance::UnscrupulousAccounts` (r:1 w:0)
+	/// Proof: `Alliance::UnscrupulousAccounts` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
+	/// Storage: `AllianceMotion::Proposals` (r:1 w:0)
+	/// Proof: `AllianceMotion::Proposals` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Alliance::DepositOf` (r:1 w:1)
+	/// Proof: `Alliance::DepositOf` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Alliance::RetiringMembers` (r:0 w:1)
+	/// Proof: `Alliance::RetiringMembers` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Alliance::SuspendedMembers` (r:0 w:1)
+	/// Proof: `Alliance::SuspendedMembers` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `Alliance::MemberSince` (r:0 w:1)
+	/// Proof: `Alliance::MemberSince` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `AllianceMotion::Members` (r:0 w:1)
+	/// Proof: `AllianceMotion::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `AllianceMotion::Prime` (r:0 w:1)
+	/// Proof: `AllianceMotion::Prime` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Alliance::NominationsUsed` (r:0 w:1)
+	/// Proof: `Alliance::NominationsUsed` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `Alliance::UnscrupulousPenalizedAt` (r:1 w:1)
+	/// Proof: `Alliance::UnscrupulousPenalizedAt` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn enforce_unscrupulous() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `742`
+		//  Estimated: `18048`
+		// Minimum execution time: 145_318_000 picoseconds.
+		Weight::from_parts(150_204_000, 0)
+			.saturating_add(Weight::from_parts(0, 18048))
+			.saturating_add(T::DbWeight::get().reads(15))
+			.saturating_add(T::DbWeight::get().writes(13))
+	}
+	/// Storage: `Alliance::DisbandProgress` (r:1 w:1)
+	/// Proof: `Alliance::DisbandProgress` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	/// Storage: `Alliance::Members` (r:3 w:2)
+	/// Proof: `Alliance::Members` (`max_values`: None, `max_size`: Some(3211), added: 5686, mode: `MaxEncodedLen`)
+	/// Storage: `Alliance::DepositOf` (r:200 w:50)
+	/// Proof: `Alliance::DepositOf` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:50 w:50)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Alliance::MemberSince` (r:0 w:200)
+	/// Proof: `Alliance::MemberSince` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `Alliance::NominationsUsed` (r:0 w:100)
+	/// Proof: `Alliance::NominationsUsed` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 100]`.
+	/// The range of component `y` is `[0, 100]`.
+	/// The range of component `z` is `[0, 50]`.
+	fn continue_disband(x: u32, y: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + x * (50 ±0) + y * (51 ±0) + z * (251 ±0)`
+		//  Estimated: `6816 + x * (2539 ±0) + y * (2539 ±0) + z * (2603 ±1)`
+		// Minimum execution time: 23_916_000 picoseconds.
+		Weight::from_parts(24_563_000, 0)
+			.saturating_add(Weight::from_parts(0, 6816))
+			// Standard Error: 25_871
+			.saturating_add(Weight::from_parts(1_843_215, 0).saturating_mul(x.into()))
+			// Standard Error: 25_746
+			.saturating_add(Weight::from_parts(1_902_407, 0).saturating_mul(y.into()))
+			// Standard Error: 51_443
+			.saturating_add(Weight::from_parts(16_107_532, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(y.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(z.into())))
+			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(y.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(z.into())))
+			.saturating_add(Weight::from_parts(0, 2539).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(0, 2539).saturating_mul(y.into()))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(z.into()))
+	}
+	/// Storage: `AllianceMotion::Voting` (r:1 w:0)
+	/// Proof: `AllianceMotion::Voting` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AllianceMotion::Members` (r:1 w:0)
+	/// Proof: `AllianceMotion::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Alliance::DecidedProposals` (r:1 w:1)
+	/// Proof: `Alliance::DecidedProposals` (`max_values`: Some(1), `max_size`: Some(3602), added: 4097, mode: `MaxEncodedLen`)
+	fn queue_decided_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3899`
+		//  Estimated: `7364`
+		// Minimum execution time: 21_436_000 picoseconds.
+		Weight::from_parts(22_318_000, 0)
+			.saturating_add(Weight::from_parts(0, 7364))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Alliance::SubCommittees` (r:1 w:0)
+	/// Proof: `Alliance::SubCommittees` (`max_values`: None, `max_size`: Some(338), added: 2813, mode: `MaxEncodedLen`)
+	/// Storage: `Alliance::Members` (r:3 w:0)
+	/// Proof: `Alliance::Members` (`max_values`: None, `max_size`: Some(3211), added: 5686, mode: `MaxEncodedLen`)
+	/// Storage: `Alliance::SuspendedMembers` (r:1 w:0)
+	/// Proof: `Alliance::SuspendedMembers` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn ensure_sub_committee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1021`
+		//  Estimated: `18048`
+		// Minimum execution time: 13_284_000 picoseconds.
+		Weight::from_parts(13_902_000, 0)
+			.saturating_add(Weight::from_parts(0, 18048))
+			.saturating_add(T::DbWeight::get().reads(5))
+	}
 }
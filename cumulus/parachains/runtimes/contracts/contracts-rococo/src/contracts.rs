@@ -14,13 +14,15 @@
 // limitations under the License.
 
 use crate::{
-	Balance, Balances, RandomnessCollectiveFlip, Runtime, RuntimeCall, RuntimeEvent,
-	RuntimeHoldReason, Timestamp,
+	xcm_config::XcmConfig, AccountId, Balance, Balances, RandomnessCollectiveFlip, Runtime,
+	RuntimeBlockWeights, RuntimeCall, RuntimeEvent, RuntimeHoldReason, Timestamp,
 };
 use frame_support::{
 	parameter_types,
-	traits::{ConstBool, ConstU32, Nothing},
+	traits::{ConstBool, ConstU32, Everything, Nothing},
+	weights::Weight,
 };
+use frame_system::{EnsureRoot, EnsureSigned};
 use pallet_contracts::{
 	weights::SubstrateWeight, Config, DebugInfo, DefaultAddressGenerator, Frame, Schedule,
 };
@@ -38,11 +40,14 @@ parameter_types! {
 	pub const DefaultDepositLimit: Balance = deposit(1024, 1024 * 1024);
 	pub MySchedule: Schedule<Runtime> = Default::default();
 	pub CodeHashLockupDepositPercent: Perbill = Perbill::from_percent(30);
+	pub MaxScheduledCallsWeight: Weight = Perbill::from_percent(10) * RuntimeBlockWeights::get().max_block;
+	pub const BlockExecutionBudget: Weight = Weight::MAX;
 }
 
 impl Config for Runtime {
 	type Time = Timestamp;
 	type Randomness = RandomnessCollectiveFlip;
+	type RandomnessSource = RandomnessCollectiveFlip;
 	type Currency = Balances;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
@@ -64,13 +69,30 @@ impl Config for Runtime {
 	type AddressGenerator = DefaultAddressGenerator;
 	type MaxCodeLen = ConstU32<{ 123 * 1024 }>;
 	type MaxStorageKeyLen = ConstU32<128>;
+	type MaxStorageItemSize = ConstU32<{ 16 * 1024 }>;
+	type MaxImmutableDataLen = ConstU32<{ 4 * 1024 }>;
 	type UnsafeUnstableInterface = ConstBool<true>;
+	type FeeBreakdownEvents = ConstBool<true>;
+	type CallCacheSize = ConstU32<64>;
 	type MaxDebugBufferLen = ConstU32<{ 2 * 1024 * 1024 }>;
 	type MaxDelegateDependencies = ConstU32<32>;
+	type MaxPinnedCodeHashes = ConstU32<32>;
+	type MaxEventTopicLen = ConstU32<128>;
+	type MaxRegisteredTopics = ConstU32<1024>;
+	type MaxScheduledCallsPerBlock = ConstU32<16>;
+	type MaxScheduledCallDataLen = ConstU32<1024>;
+	type MaxScheduledCallsWeight = MaxScheduledCallsWeight;
+	type BlockExecutionBudget = BlockExecutionBudget;
+	type CodePruningDelay = ();
+	type InstantiateOrigin = EnsureSigned<AccountId>;
+	type InstantiationPermissionOrigin = EnsureRoot<AccountId>;
 	type CodeHashLockupDepositPercent = CodeHashLockupDepositPercent;
+	type CodeDepositCurve = ();
 	type Migrations = ();
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type Debug = ();
 	type Environment = ();
 	type Xcm = pallet_xcm::Pallet<Self>;
+	type XcmWeigher = <XcmConfig as xcm_executor::Config>::Weigher;
+	type XcmDestinations = Everything;
 }
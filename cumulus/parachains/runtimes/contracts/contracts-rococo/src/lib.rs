@@ -500,6 +500,10 @@ impl_runtime_apis! {
 		) -> sp_inherents::CheckInherentsResult {
 			data.check_extrinsics(&block)
 		}
+
+		fn extrinsic_proof_sizes() -> Vec<sp_block_builder::ExtrinsicProofSize> {
+			Executive::extrinsic_proof_sizes()
+		}
 	}
 
 	impl sp_transaction_pool::runtime_api::TaggedTransactionQueue<Block> for Runtime {
@@ -594,6 +598,7 @@ impl_runtime_apis! {
 			gas_limit: Option<Weight>,
 			storage_deposit_limit: Option<Balance>,
 			input_data: Vec<u8>,
+			read_only: bool,
 		) -> pallet_contracts::ContractExecResult<Balance, EventRecord> {
 			let gas_limit = gas_limit.unwrap_or(RuntimeBlockWeights::get().max_block);
 			Contracts::bare_call(
@@ -606,6 +611,7 @@ impl_runtime_apis! {
 				contracts::CONTRACTS_DEBUG_OUTPUT,
 				pallet_contracts::CollectEvents::UnsafeCollect,
 				pallet_contracts::Determinism::Enforced,
+				read_only,
 			)
 		}
 
@@ -652,6 +658,14 @@ impl_runtime_apis! {
 		) -> pallet_contracts::GetStorageResult {
 			Contracts::get_storage(address, key)
 		}
+
+		fn contract_dump(
+			address: AccountId,
+			include_code: bool,
+			cursor: Option<Vec<u8>>,
+		) -> pallet_contracts::ContractDumpResult<Hash, Balance> {
+			Contracts::contract_dump(address, include_code, cursor)
+		}
 	}
 
 	#[cfg(feature = "try-runtime")]
@@ -157,4 +157,28 @@ impl<T: frame_system::Config> frame_system::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	/// Not benchmarked for this runtime yet, the weight of the Substrate node is used.
+	fn set_feature_flag() -> Weight {
+		<frame_system::weights::SubstrateWeight<T> as frame_system::WeightInfo>::set_feature_flag()
+	}
+	/// Not benchmarked for this runtime yet, the weight of the Substrate node is used.
+	fn force_set_nonce() -> Weight {
+		<frame_system::weights::SubstrateWeight<T> as frame_system::WeightInfo>::force_set_nonce()
+	}
+	/// Not benchmarked for this runtime yet, the weight of the Substrate node is used.
+	fn set_block_hash_retention() -> Weight {
+		<frame_system::weights::SubstrateWeight<T> as frame_system::WeightInfo>::set_block_hash_retention()
+	}
+	/// Not benchmarked for this runtime yet, the weight of the Substrate node is used.
+	fn set_dispatch_path_recording() -> Weight {
+		<frame_system::weights::SubstrateWeight<T> as frame_system::WeightInfo>::set_dispatch_path_recording()
+	}
+	/// Not benchmarked for this runtime yet, the weight of the Substrate node is used.
+	fn scan_undecodable_accounts(n: u32, ) -> Weight {
+		<frame_system::weights::SubstrateWeight<T> as frame_system::WeightInfo>::scan_undecodable_accounts(n)
+	}
+	/// Not benchmarked for this runtime yet, the weight of the Substrate node is used.
+	fn mark_migration_failed(n: u32, ) -> Weight {
+		<frame_system::weights::SubstrateWeight<T> as frame_system::WeightInfo>::mark_migration_failed(n)
+	}
 }
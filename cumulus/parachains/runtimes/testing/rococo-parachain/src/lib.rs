@@ -679,6 +679,19 @@ impl frame_support::traits::OnRuntimeUpgrade for RemoveCollectiveFlip {
 	}
 }
 
+/// Reports the storage proof size recorded by the collator to [`Executive`].
+#[cfg(feature = "proof-size-breakdown")]
+pub struct StorageProofSize;
+#[cfg(feature = "proof-size-breakdown")]
+impl frame_executive::proof_size::GetProofSize for StorageProofSize {
+	fn get_proof_size() -> Option<u64> {
+		let size =
+			cumulus_primitives_proof_size_hostfunction::storage_proof_size::storage_proof_size();
+		(size != cumulus_primitives_proof_size_hostfunction::PROOF_RECORDING_DISABLED)
+			.then_some(size)
+	}
+}
+
 impl_runtime_apis! {
 	impl sp_api::Core<Block> for Runtime {
 		fn version() -> RuntimeVersion {
@@ -712,6 +725,9 @@ impl_runtime_apis! {
 		fn apply_extrinsic(
 			extrinsic: <Block as BlockT>::Extrinsic,
 		) -> ApplyExtrinsicResult {
+			#[cfg(feature = "proof-size-breakdown")]
+			return Executive::apply_extrinsic_recording_proof_size::<StorageProofSize>(extrinsic);
+			#[cfg(not(feature = "proof-size-breakdown"))]
 			Executive::apply_extrinsic(extrinsic)
 		}
 
@@ -726,6 +742,10 @@ impl_runtime_apis! {
 		fn check_inherents(block: Block, data: sp_inherents::InherentData) -> sp_inherents::CheckInherentsResult {
 			data.check_extrinsics(&block)
 		}
+
+		fn extrinsic_proof_sizes() -> Vec<sp_block_builder::ExtrinsicProofSize> {
+			Executive::extrinsic_proof_sizes()
+		}
 	}
 
 	impl sp_transaction_pool::runtime_api::TaggedTransactionQueue<Block> for Runtime {
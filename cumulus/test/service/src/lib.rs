@@ -803,6 +803,10 @@ pub fn node_config(
 		rpc_message_buffer_capacity: Default::default(),
 		rpc_batch_config: RpcBatchRequestConfig::Unlimited,
 		rpc_rate_limit: None,
+		rpc_method_rate_limits: Default::default(),
+		rpc_auth: None,
+		rpc_tx_resubmission: Default::default(),
+		rpc_method_aliases: Default::default(),
 		prometheus_config: None,
 		telemetry_endpoints: None,
 		default_heap_pages: None,
@@ -818,6 +822,7 @@ pub fn node_config(
 		base_path,
 		informant_output_format: Default::default(),
 		wasm_runtime_overrides: None,
+		shadow_runtime: None,
 		runtime_cache_size: 2,
 	})
 }
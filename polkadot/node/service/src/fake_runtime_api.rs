@@ -98,6 +98,10 @@ sp_api::impl_runtime_apis! {
 		) -> sp_inherents::CheckInherentsResult {
 			unimplemented!()
 		}
+
+		fn extrinsic_proof_sizes() -> Vec<sp_block_builder::ExtrinsicProofSize> {
+			unimplemented!()
+		}
 	}
 
 	impl sp_transaction_pool::runtime_api::TaggedTransactionQueue<Block> for Runtime {
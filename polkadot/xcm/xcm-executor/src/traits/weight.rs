@@ -29,6 +29,17 @@ pub trait WeightBounds<RuntimeCall> {
 	fn instr_weight(instruction: &Instruction<RuntimeCall>) -> Result<Weight, ()>;
 }
 
+/// No message can be weighed.
+impl<RuntimeCall> WeightBounds<RuntimeCall> for () {
+	fn weight(_message: &mut Xcm<RuntimeCall>) -> Result<Weight, ()> {
+		Err(())
+	}
+
+	fn instr_weight(_instruction: &Instruction<RuntimeCall>) -> Result<Weight, ()> {
+		Err(())
+	}
+}
+
 /// A means of getting approximate weight consumption for a given destination message executor and a
 /// message.
 pub trait UniversalWeigher {
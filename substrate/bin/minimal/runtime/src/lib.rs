@@ -158,6 +158,10 @@ impl_runtime_apis! {
 		) -> CheckInherentsResult {
 			data.check_extrinsics(&block)
 		}
+
+		fn extrinsic_proof_sizes() -> Vec<apis::ExtrinsicProofSize> {
+			RuntimeExecutive::extrinsic_proof_sizes()
+		}
 	}
 
 	impl apis::TaggedTransactionQueue<Block> for Runtime {
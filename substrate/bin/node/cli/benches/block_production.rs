@@ -86,6 +86,10 @@ fn new_node(tokio_handle: Handle) -> node_cli::service::NewFullBase {
 		rpc_message_buffer_capacity: Default::default(),
 		rpc_batch_config: RpcBatchRequestConfig::Unlimited,
 		rpc_rate_limit: None,
+		rpc_method_rate_limits: Default::default(),
+		rpc_auth: None,
+		rpc_tx_resubmission: Default::default(),
+		rpc_method_aliases: Default::default(),
 		prometheus_config: None,
 		telemetry_endpoints: None,
 		default_heap_pages: None,
@@ -102,6 +106,7 @@ fn new_node(tokio_handle: Handle) -> node_cli::service::NewFullBase {
 		base_path,
 		informant_output_format: Default::default(),
 		wasm_runtime_overrides: None,
+		shadow_runtime: None,
 	};
 
 	node_cli::service::new_full_base(config, None, false, |_, _| ())
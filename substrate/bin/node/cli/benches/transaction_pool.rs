@@ -61,6 +61,7 @@ fn new_node(tokio_handle: Handle) -> node_cli::service::NewFullBase {
 			future: PoolLimit { count: 100_000, total_bytes: 100 * 1024 * 1024 },
 			reject_future_transactions: false,
 			ban_time: Duration::from_secs(30 * 60),
+			..Default::default()
 		},
 		network: network_config,
 		keystore: KeystoreConfig::InMemory,
@@ -82,6 +83,10 @@ fn new_node(tokio_handle: Handle) -> node_cli::service::NewFullBase {
 		rpc_message_buffer_capacity: Default::default(),
 		rpc_batch_config: RpcBatchRequestConfig::Unlimited,
 		rpc_rate_limit: None,
+		rpc_method_rate_limits: Default::default(),
+		rpc_auth: None,
+		rpc_tx_resubmission: Default::default(),
+		rpc_method_aliases: Default::default(),
 		prometheus_config: None,
 		telemetry_endpoints: None,
 		default_heap_pages: None,
@@ -98,6 +103,7 @@ fn new_node(tokio_handle: Handle) -> node_cli::service::NewFullBase {
 		base_path,
 		informant_output_format: Default::default(),
 		wasm_runtime_overrides: None,
+		shadow_runtime: None,
 	};
 
 	tokio_handle.block_on(async move {
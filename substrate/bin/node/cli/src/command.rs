@@ -24,7 +24,7 @@ use crate::{
 };
 use frame_benchmarking_cli::*;
 use kitchensink_runtime::{ExistentialDeposit, RuntimeApi};
-use node_primitives::Block;
+use node_primitives::{Balance, Block};
 use sc_cli::{Result, SubstrateCli};
 use sc_service::PartialComponents;
 use sp_keyring::Sr25519Keyring;
@@ -114,6 +114,11 @@ pub fn run() -> Result<()> {
 						let partial = new_partial(&config, None)?;
 						cmd.run(partial.client)
 					},
+					BenchmarkCmd::Calibrate(cmd) => {
+						// ensure that we keep the task manager alive
+						let partial = new_partial(&config, None)?;
+						cmd.run::<Block, _, Balance>(partial.client)
+					},
 					#[cfg(not(feature = "runtime-benchmarks"))]
 					BenchmarkCmd::Storage(_) => Err(
 						"Storage benchmarking can be enabled with `--features runtime-benchmarks`."
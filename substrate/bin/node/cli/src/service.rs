@@ -27,6 +27,7 @@ use frame_system_rpc_runtime_api::AccountNonceApi;
 use futures::prelude::*;
 use kitchensink_runtime::RuntimeApi;
 use node_primitives::Block;
+use pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi;
 use sc_client_api::{Backend, BlockBackend};
 use sc_consensus_babe::{self, SlotProportion};
 use sc_network::{event::Event, NetworkEventStream, NetworkService};
@@ -124,6 +125,7 @@ pub fn create_extrinsic(
 					kitchensink_runtime::Runtime,
 				>::from(tip, None),
 			),
+			pallet_contracts::CheckExecutionBudget::<kitchensink_runtime::Runtime>::new(),
 		);
 
 	let raw_payload = kitchensink_runtime::SignedPayload::from_raw(
@@ -138,6 +140,7 @@ pub fn create_extrinsic(
 			(),
 			(),
 			(),
+			(),
 		),
 	);
 	let signature = raw_payload.using_encoded(|e| sender.sign(e));
@@ -212,12 +215,16 @@ pub fn new_partial(
 
 	let select_chain = sc_consensus::LongestChain::new(backend.clone());
 
-	let transaction_pool = sc_transaction_pool::BasicPool::new_full(
+	let transaction_pool = sc_transaction_pool::BasicPool::new_full_with_fee_estimator(
 		config.transaction_pool.clone(),
 		config.role.is_authority().into(),
 		config.prometheus_registry(),
 		task_manager.spawn_essential_handle(),
 		client.clone(),
+		Arc::new(|client: &FullClient, at, uxt, len| {
+			let info = client.runtime_api().query_info(at, uxt.clone(), len as u32).ok()?;
+			Some(info.partial_fee)
+		}),
 	);
 
 	let (grandpa_block_import, grandpa_link) = grandpa::block_import(
@@ -993,6 +1000,7 @@ mod tests {
 				let tx_payment = pallet_skip_feeless_payment::SkipCheckIfFeeless::from(
 					pallet_asset_conversion_tx_payment::ChargeAssetTxPayment::from(0, None),
 				);
+				let check_execution_budget = pallet_contracts::CheckExecutionBudget::new();
 				let extra = (
 					check_non_zero_sender,
 					check_spec_version,
@@ -1002,11 +1010,22 @@ mod tests {
 					check_nonce,
 					check_weight,
 					tx_payment,
+					check_execution_budget,
 				);
 				let raw_payload = SignedPayload::from_raw(
 					function,
 					extra,
-					((), spec_version, transaction_version, genesis_hash, genesis_hash, (), (), ()),
+					(
+						(),
+						spec_version,
+						transaction_version,
+						genesis_hash,
+						genesis_hash,
+						(),
+						(),
+						(),
+						(),
+					),
 				);
 				let signature = raw_payload.using_encoded(|payload| signer.sign(payload));
 				let (function, extra, _) = raw_payload.deconstruct();
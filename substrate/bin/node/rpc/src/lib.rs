@@ -156,9 +156,12 @@ where
 		mixnet::MixnetApiServer,
 		statement::StatementApiServer,
 	};
-	use sc_rpc_spec_v2::chain_spec::{ChainSpec, ChainSpecApiServer};
+	use sc_rpc_spec_v2::{
+		chain_spec::{ChainSpec, ChainSpecApiServer},
+		transaction::{TransactionNonce, TransactionNonceApiServer},
+	};
 	use sc_sync_state_rpc::{SyncState, SyncStateApiServer};
-	use substrate_frame_rpc_system::{System, SystemApiServer};
+	use substrate_frame_rpc_system::{RuntimeAccountNonce, System, SystemApiServer};
 	use substrate_state_trie_migration_rpc::{StateMigration, StateMigrationApiServer};
 
 	let mut io = RpcModule::new(());
@@ -177,6 +180,14 @@ where
 	let properties = chain_spec.properties();
 	io.merge(ChainSpec::new(chain_name, genesis_hash, properties).into_rpc())?;
 
+	io.merge(
+		TransactionNonce::new(
+			client.clone(),
+			pool.clone(),
+			RuntimeAccountNonce::new(client.clone()),
+		)
+		.into_rpc(),
+	)?;
 	io.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
 	// Making synchronous calls in light client freezes the browser currently,
 	// more context: https://github.com/paritytech/substrate/pull/3480
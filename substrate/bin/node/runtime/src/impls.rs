@@ -24,15 +24,12 @@ use frame_support::{
 		Currency, OnUnbalanced,
 	},
 };
-use pallet_alliance::{IdentityVerifier, ProposalIndex, ProposalProvider};
+use pallet_alliance::IdentityVerifier;
 use pallet_asset_tx_payment::HandleCredit;
 use pallet_identity::legacy::IdentityField;
 use sp_std::prelude::*;
 
-use crate::{
-	AccountId, AllianceMotion, Assets, Authorship, Balances, Hash, NegativeImbalance, Runtime,
-	RuntimeCall,
-};
+use crate::{AccountId, Assets, Authorship, Balances, NegativeImbalance, Runtime};
 
 pub struct Author;
 impl OnUnbalanced<NegativeImbalance> for Author {
@@ -77,40 +74,6 @@ impl IdentityVerifier<AccountId> for AllianceIdentityVerifier {
 	}
 }
 
-pub struct AllianceProposalProvider;
-impl ProposalProvider<AccountId, Hash, RuntimeCall> for AllianceProposalProvider {
-	fn propose_proposal(
-		who: AccountId,
-		threshold: u32,
-		proposal: Box<RuntimeCall>,
-		length_bound: u32,
-	) -> Result<(u32, u32), DispatchError> {
-		AllianceMotion::do_propose_proposed(who, threshold, proposal, length_bound)
-	}
-
-	fn vote_proposal(
-		who: AccountId,
-		proposal: Hash,
-		index: ProposalIndex,
-		approve: bool,
-	) -> Result<bool, DispatchError> {
-		AllianceMotion::do_vote(who, proposal, index, approve)
-	}
-
-	fn close_proposal(
-		proposal_hash: Hash,
-		proposal_index: ProposalIndex,
-		proposal_weight_bound: Weight,
-		length_bound: u32,
-	) -> DispatchResultWithPostInfo {
-		AllianceMotion::do_close(proposal_hash, proposal_index, proposal_weight_bound, length_bound)
-	}
-
-	fn proposal_of(proposal_hash: Hash) -> Option<RuntimeCall> {
-		AllianceMotion::proposal_of(proposal_hash)
-	}
-}
-
 #[cfg(test)]
 mod multiplier_tests {
 	use frame_support::{
@@ -41,13 +41,15 @@ use frame_support::{
 			Balanced, Credit, HoldConsideration, ItemOf, NativeFromLeft, NativeOrWithId, UnionOf,
 		},
 		tokens::{
-			imbalance::ResolveAssetTo, nonfungibles_v2::Inspect, pay::PayAssetFromAccount,
+			imbalance::{ResolveAssetTo, ResolveTo},
+			nonfungibles_v2::Inspect,
+			pay::PayAssetFromAccount,
 			GetSalary, PayFromAccount,
 		},
 		AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU16, ConstU32, Contains, Currency,
-		EitherOfDiverse, EnsureOriginWithArg, EqualPrivilegeOnly, Imbalance, InsideBoth,
-		InstanceFilter, KeyOwnerProofSystem, LinearStoragePrice, LockIdentifier, Nothing,
-		OnUnbalanced, WithdrawReasons,
+		EitherOfDiverse, EnsureOriginWithArg, EqualPrivilegeOnly, Everything, Imbalance,
+		InsideBoth, InstanceFilter, KeyOwnerProofSystem, LinearStoragePrice, LockIdentifier,
+		Nothing, OnUnbalanced, WithdrawReasons,
 	},
 	weights::{
 		constants::{
@@ -116,7 +118,7 @@ pub use sp_runtime::BuildStorage;
 pub mod impls;
 #[cfg(not(feature = "runtime-benchmarks"))]
 use impls::AllianceIdentityVerifier;
-use impls::{AllianceProposalProvider, Author, CreditToBlockAuthor};
+use impls::{Author, CreditToBlockAuthor};
 
 /// Constant values used within the runtime.
 pub mod constants;
@@ -1330,11 +1332,16 @@ impl pallet_tips::Config for Runtime {
 parameter_types! {
 	pub Schedule: pallet_contracts::Schedule<Runtime> = Default::default();
 	pub CodeHashLockupDepositPercent: Perbill = Perbill::from_percent(30);
+	// Every KiB of code beyond 64 KiB is charged twice.
+	pub CodeDepositTiers: Vec<(u32, Balance)> = vec![(64, deposit(0, 1024))];
+	pub MaxScheduledCallsWeight: Weight = Perbill::from_percent(10) * RuntimeBlockWeights::get().max_block;
+	pub BlockExecutionBudget: Weight = Perbill::from_percent(50) * RuntimeBlockWeights::get().max_block;
 }
 
 impl pallet_contracts::Config for Runtime {
 	type Time = Timestamp;
 	type Randomness = RandomnessCollectiveFlip;
+	type RandomnessSource = pallet_babe::RandomnessFromOneEpochAgo<Runtime>;
 	type Currency = Balances;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
@@ -1356,7 +1363,11 @@ impl pallet_contracts::Config for Runtime {
 	type AddressGenerator = pallet_contracts::DefaultAddressGenerator;
 	type MaxCodeLen = ConstU32<{ 123 * 1024 }>;
 	type MaxStorageKeyLen = ConstU32<128>;
+	type MaxStorageItemSize = ConstU32<{ 16 * 1024 }>;
+	type MaxImmutableDataLen = ConstU32<{ 4 * 1024 }>;
 	type UnsafeUnstableInterface = ConstBool<false>;
+	type FeeBreakdownEvents = ConstBool<true>;
+	type CallCacheSize = ConstU32<64>;
 	type MaxDebugBufferLen = ConstU32<{ 2 * 1024 * 1024 }>;
 	type RuntimeHoldReason = RuntimeHoldReason;
 	#[cfg(not(feature = "runtime-benchmarks"))]
@@ -1364,10 +1375,23 @@ impl pallet_contracts::Config for Runtime {
 	#[cfg(feature = "runtime-benchmarks")]
 	type Migrations = pallet_contracts::migration::codegen::BenchMigrations;
 	type MaxDelegateDependencies = ConstU32<32>;
+	type MaxPinnedCodeHashes = ConstU32<32>;
+	type MaxEventTopicLen = ConstU32<128>;
+	type MaxRegisteredTopics = ConstU32<1024>;
+	type MaxScheduledCallsPerBlock = ConstU32<16>;
+	type MaxScheduledCallDataLen = ConstU32<1024>;
+	type MaxScheduledCallsWeight = MaxScheduledCallsWeight;
+	type BlockExecutionBudget = BlockExecutionBudget;
+	type CodePruningDelay = ();
+	type InstantiateOrigin = EnsureSigned<AccountId>;
+	type InstantiationPermissionOrigin = EnsureRoot<AccountId>;
 	type CodeHashLockupDepositPercent = CodeHashLockupDepositPercent;
+	type CodeDepositCurve = pallet_contracts::TieredCodeDeposit<CodeDepositTiers, Balance>;
 	type Debug = ();
 	type Environment = ();
 	type Xcm = ();
+	type XcmWeigher = ();
+	type XcmDestinations = Nothing;
 }
 
 impl pallet_sudo::Config for Runtime {
@@ -1418,6 +1442,7 @@ where
 					tip, None,
 				),
 			),
+			pallet_contracts::CheckExecutionBudget::<Runtime>::new(),
 		);
 		let raw_payload = SignedPayload::new(call, extra)
 			.map_err(|e| {
@@ -1944,11 +1969,20 @@ parameter_types! {
 	pub const MaxAllies: u32 = 100;
 	pub const AllyDeposit: Balance = 10 * DOLLARS;
 	pub const RetirementPeriod: BlockNumber = ALLIANCE_MOTION_DURATION_IN_BLOCKS + (1 * DAYS);
+	pub const AllianceNominationQuota: Option<u32> = Some(10);
+	pub const AllianceMinimumTenure: Option<BlockNumber> = Some(28 * DAYS);
+	pub const AllianceEarlyRetirementSlash: Perbill = Perbill::from_percent(20);
+	pub const AllianceEnactmentDelay: BlockNumber = 2 * DAYS;
+	pub const AllianceUnscrupulousMemberPenalty: pallet_alliance::UnscrupulousPenalty<BlockNumber> =
+		pallet_alliance::UnscrupulousPenalty::Kick;
+	pub const AllianceUnscrupulousMemberSlash: Perbill = Perbill::from_percent(100);
+	pub const AllianceUnscrupulousPenaltyCooldown: BlockNumber = 28 * DAYS;
 }
 
 impl pallet_alliance::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Proposal = RuntimeCall;
+	type ProposalFilter = Everything;
 	type AdminOrigin = EitherOfDiverse<
 		EnsureRoot<AccountId>,
 		pallet_collective::EnsureProportionMoreThan<AccountId, AllianceCollective, 2, 3>,
@@ -1961,15 +1995,17 @@ impl pallet_alliance::Config for Runtime {
 		EnsureRoot<AccountId>,
 		pallet_collective::EnsureProportionMoreThan<AccountId, AllianceCollective, 2, 3>,
 	>;
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type Currency = Balances;
-	type Slashed = Treasury;
+	type Slashed = ResolveTo<TreasuryAccount, Balances>;
 	type InitializeMembers = AllianceMotion;
 	type MembershipChanged = AllianceMotion;
 	#[cfg(not(feature = "runtime-benchmarks"))]
 	type IdentityVerifier = AllianceIdentityVerifier;
 	#[cfg(feature = "runtime-benchmarks")]
 	type IdentityVerifier = ();
-	type ProposalProvider = AllianceProposalProvider;
+	type RolePolicy = ();
+	type VotingProvider = pallet_alliance::CollectiveVoting<Runtime, AllianceCollective>;
 	type MaxProposals = AllianceMaxProposals;
 	type MaxFellows = MaxFellows;
 	type MaxAllies = MaxAllies;
@@ -1980,6 +2016,25 @@ impl pallet_alliance::Config for Runtime {
 	type AllyDeposit = AllyDeposit;
 	type WeightInfo = pallet_alliance::weights::SubstrateWeight<Runtime>;
 	type RetirementPeriod = RetirementPeriod;
+	type MaxSubCommittees = ConstU32<10>;
+	type MaxSubCommitteeMembers = ConstU32<10>;
+	type AutoClose = ConstBool<true>;
+	type AutoCloseWeight = MaxCollectivesProposalWeight;
+	type MaxRuleHistory = ConstU32<100>;
+	type FlagSigner =
+		pallet_alliance::offchain::SignedBy<pallet_alliance::offchain::crypto::AuthorityId>;
+	type NominationQuota = AllianceNominationQuota;
+	type NominationPeriod = ConstU32<{ 7 * DAYS }>;
+	type MinimumTenure = AllianceMinimumTenure;
+	type EarlyRetirementSlash = AllianceEarlyRetirementSlash;
+	type EnactmentDelay = AllianceEnactmentDelay;
+	type UnscrupulousMemberPenalty = AllianceUnscrupulousMemberPenalty;
+	type UnscrupulousMemberSlash = AllianceUnscrupulousMemberSlash;
+	type UnscrupulousPenaltyCooldown = AllianceUnscrupulousPenaltyCooldown;
+	type EnactmentOrigin = pallet_alliance::WithCaller<
+		pallet_collective::EnsureMembers<AccountId, AllianceCollective, 1>,
+	>;
+	type PalletsOrigin = OriginCaller;
 }
 
 impl frame_benchmarking_pallet_pov::Config for Runtime {
@@ -2275,6 +2330,7 @@ pub type SignedExtra = (
 		Runtime,
 		pallet_asset_conversion_tx_payment::ChargeAssetTxPayment<Runtime>,
 	>,
+	pallet_contracts::CheckExecutionBudget<Runtime>,
 );
 
 /// Unchecked extrinsic type as expected by this runtime.
@@ -2297,14 +2353,26 @@ pub type Executive = frame_executive::Executive<
 // We don't have a limit in the Relay Chain.
 const IDENTITY_MIGRATION_KEY_LIMIT: u64 = u64::MAX;
 
+parameter_types! {
+	pub const IdentityV0ToV1: &'static str = "pallet_identity::migration::versioned::V0ToV1";
+}
+
 // All migrations executed on runtime upgrade as a nested tuple of types implementing
 // `OnRuntimeUpgrade`. Note: These are examples and do not need to be run directly
 // after the genesis block.
 type Migrations = (
 	pallet_nomination_pools::migration::versioned::V6ToV7<Runtime>,
 	pallet_alliance::migration::Migration<Runtime>,
+	pallet_alliance::migration::v2_to_v3::MigrateToHolds<Runtime, Balances>,
 	pallet_contracts::Migration<Runtime>,
-	pallet_identity::migration::versioned::V0ToV1<Runtime, IDENTITY_MIGRATION_KEY_LIMIT>,
+	// Skipped if Root marks it as failed with `System::mark_migration_failed`.
+	frame_executive::fail_safe::FailSafe<
+		Runtime,
+		frame_executive::fail_safe::NeverFails<
+			pallet_identity::migration::versioned::V0ToV1<Runtime, IDENTITY_MIGRATION_KEY_LIMIT>,
+		>,
+		IdentityV0ToV1,
+	>,
 );
 
 type EventRecord = frame_system::EventRecord<
@@ -2437,7 +2505,12 @@ impl_runtime_apis! {
 
 	impl sp_block_builder::BlockBuilder<Block> for Runtime {
 		fn apply_extrinsic(extrinsic: <Block as BlockT>::Extrinsic) -> ApplyExtrinsicResult {
-			Executive::apply_extrinsic(extrinsic)
+			// Leave calls into paused pallets out of the blocks we build, without failing them.
+			Executive::apply_extrinsic_for_authoring::<
+				frame_executive::authoring::AllowedCalls<
+					<Runtime as frame_system::Config>::BaseCallFilter,
+				>,
+			>(extrinsic)
 		}
 
 		fn finalize_block() -> <Block as BlockT>::Header {
@@ -2451,6 +2524,10 @@ impl_runtime_apis! {
 		fn check_inherents(block: Block, data: InherentData) -> CheckInherentsResult {
 			data.check_extrinsics(&block)
 		}
+
+		fn extrinsic_proof_sizes() -> Vec<sp_block_builder::ExtrinsicProofSize> {
+			Executive::extrinsic_proof_sizes()
+		}
 	}
 
 	impl sp_transaction_pool::runtime_api::TaggedTransactionQueue<Block> for Runtime {
@@ -2599,6 +2676,22 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl frame_system_rpc_runtime_api::BlockHashApi<Block, BlockNumber> for Runtime {
+		fn block_hash_retention() -> BlockNumber {
+			System::block_hash_retention()
+		}
+
+		fn oldest_block_hash() -> BlockNumber {
+			System::oldest_block_hash()
+		}
+	}
+
+	impl frame_executive::replay::ReplayApi<Block> for Runtime {
+		fn replay_blocks(blocks: Vec<Block>) -> frame_executive::replay::ReplayReport<Hash> {
+			Executive::replay_blocks(blocks)
+		}
+	}
+
 	impl assets_api::AssetsApi<
 		Block,
 		AccountId,
@@ -2620,6 +2713,7 @@ impl_runtime_apis! {
 			gas_limit: Option<Weight>,
 			storage_deposit_limit: Option<Balance>,
 			input_data: Vec<u8>,
+			read_only: bool,
 		) -> pallet_contracts::ContractExecResult<Balance, EventRecord> {
 			let gas_limit = gas_limit.unwrap_or(RuntimeBlockWeights::get().max_block);
 			Contracts::bare_call(
@@ -2632,6 +2726,7 @@ impl_runtime_apis! {
 				pallet_contracts::DebugInfo::UnsafeDebug,
 				pallet_contracts::CollectEvents::UnsafeCollect,
 				pallet_contracts::Determinism::Enforced,
+				read_only,
 			)
 		}
 
@@ -2683,6 +2778,14 @@ impl_runtime_apis! {
 				key
 			)
 		}
+
+		fn contract_dump(
+			address: AccountId,
+			include_code: bool,
+			cursor: Option<Vec<u8>>,
+		) -> pallet_contracts::ContractDumpResult<Hash, Balance> {
+			Contracts::contract_dump(address, include_code, cursor)
+		}
 	}
 
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<
@@ -2722,6 +2825,32 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_alliance::AllianceApi<Block, AccountId, Balance, BlockNumber, Hash> for Runtime {
+		fn rule_history() -> Vec<(BlockNumber, pallet_alliance::Cid)> {
+			Alliance::rule_history().into_inner()
+		}
+
+		fn members(role: pallet_alliance::MemberRole) -> Vec<AccountId> {
+			Alliance::members(role).into_inner()
+		}
+
+		fn active_proposals() -> Vec<Hash> {
+			Alliance::active_proposals()
+		}
+
+		fn deposit_of(who: AccountId) -> Option<Balance> {
+			Alliance::deposit_of(&who)
+		}
+
+		fn unscrupulous_items() -> Vec<pallet_alliance::UnscrupulousItem<AccountId, Vec<u8>>> {
+			Alliance::unscrupulous_items()
+		}
+
+		fn export_snapshot() -> pallet_alliance::AllianceSnapshot<AccountId, Balance> {
+			Alliance::export_snapshot()
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentCallApi<Block, Balance, RuntimeCall>
 		for Runtime
 	{
@@ -340,6 +340,9 @@ where
 
 		let end_reason =
 			self.apply_extrinsics(&mut block_builder, deadline, block_size_limit).await?;
+		if PR::ENABLED && log::log_enabled!(target: LOG_TARGET, log::Level::Debug) {
+			self.print_proof_sizes(&block_builder);
+		}
 		let (block, storage_changes, proof) = block_builder.build()?.into_inner();
 		let block_took = block_timer.elapsed();
 
@@ -515,6 +518,15 @@ where
 						break EndProposingReason::HitBlockWeightLimit
 					}
 				},
+				Err(ApplyExtrinsicFailed(Validity(e))) if e.skipped_by_author() => {
+					// Leave it in the pool for other authors, but skip the transactions depending
+					// on it in this block.
+					pending_iterator.report_invalid(&pending_tx);
+					debug!(
+						target: LOG_TARGET,
+						"[{:?}] Transaction skipped by the runtime while authoring.", pending_tx_hash
+					);
+				},
 				Err(e) => {
 					pending_iterator.report_invalid(&pending_tx);
 					debug!(
@@ -537,6 +549,23 @@ where
 		Ok(end_reason)
 	}
 
+	/// Prints how much each extrinsic grew the storage proof, if the runtime records it.
+	fn print_proof_sizes(&self, block_builder: &sc_block_builder::BlockBuilder<'_, Block, C>) {
+		match block_builder.extrinsic_proof_sizes() {
+			Ok(proof_sizes) =>
+				for proof_size in proof_sizes {
+					debug!(
+						target: LOG_TARGET,
+						"Extrinsic #{} ({} bytes) added {} bytes to the storage proof.",
+						proof_size.index,
+						proof_size.encoded_len,
+						proof_size.proof_size,
+					);
+				},
+			Err(e) => warn!(target: LOG_TARGET, "Failed to fetch the extrinsic proof sizes: {}", e),
+		}
+	}
+
 	/// Prints a summary and does telemetry + metrics.
 	///
 	/// - `block`: The block that was build.
@@ -1063,6 +1092,88 @@ mod tests {
 		assert_eq!(block.extrinsics().len(), MAX_SKIPPED_TRANSACTIONS + 1);
 	}
 
+	#[test]
+	fn should_keep_transactions_skipped_by_the_runtime() {
+		// given
+		let client = Arc::new(substrate_test_runtime_client::new());
+		let spawner = sp_core::testing::TaskExecutor::new();
+		let txpool = BasicPool::new_full(
+			Default::default(),
+			true.into(),
+			None,
+			spawner.clone(),
+			client.clone(),
+		);
+		let genesis_hash = client.info().genesis_hash;
+
+		let skipped = |who| {
+			ExtrinsicBuilder::new_skipped_by_author()
+				.signer(AccountKeyring::numeric(who))
+				.build()
+		};
+		// depends on the skipped transaction of the same signer.
+		let dependent = |who| {
+			ExtrinsicBuilder::new_fill_block(Perbill::from_parts(TINY))
+				.signer(AccountKeyring::numeric(who))
+				.nonce(1)
+				.build()
+		};
+		let tiny = |who| {
+			ExtrinsicBuilder::new_fill_block(Perbill::from_parts(TINY))
+				.signer(AccountKeyring::numeric(who))
+				.build()
+		};
+
+		let count = MAX_SKIPPED_TRANSACTIONS + 2;
+		let included: Vec<_> = (count..count * 2).map(tiny).collect();
+		block_on(
+			txpool.submit_at(
+				genesis_hash,
+				SOURCE,
+				(0..count)
+					.map(skipped)
+					.chain((0..count).map(dependent))
+					.chain(included.iter().cloned())
+					.collect(),
+			),
+		)
+		.unwrap();
+
+		block_on(txpool.maintain(chain_event(
+			client.expect_header(genesis_hash).expect("there should be header"),
+		)));
+		assert_eq!(txpool.ready().count(), count * 3);
+
+		let mut proposer_factory =
+			ProposerFactory::new(spawner.clone(), client.clone(), txpool.clone(), None, None);
+
+		let deadline = time::Duration::from_secs(600);
+		let cell = Mutex::new((0, time::Instant::now()));
+		let proposer = proposer_factory.init_with_now(
+			&client.expect_header(genesis_hash).unwrap(),
+			Box::new(move || {
+				let mut value = cell.lock();
+				let (called, old) = *value;
+				// start after the soft deadline, but never reach the actual deadline.
+				let increase = if called == 1 { deadline / 2 } else { time::Duration::ZERO };
+				*value = (called + 1, old + increase);
+				old
+			}),
+		);
+
+		// when
+		let block =
+			block_on(proposer.propose(Default::default(), Default::default(), deadline, None))
+				.map(|r| r.block)
+				.unwrap();
+
+		// then the skipped transactions neither end the block early, nor are they removed from
+		// the pool.
+		assert_eq!(block.extrinsics().len(), count);
+		assert!(block.extrinsics().iter().all(|xt| included.contains(xt)));
+		assert_eq!(txpool.ready().count(), count * 3);
+	}
+
 	#[test]
 	fn should_only_skip_up_to_some_limit_after_soft_deadline() {
 		// given
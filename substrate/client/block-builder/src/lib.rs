@@ -41,7 +41,7 @@ use sp_runtime::{
 };
 use std::marker::PhantomData;
 
-pub use sp_block_builder::BlockBuilder as BlockBuilderApi;
+pub use sp_block_builder::{BlockBuilder as BlockBuilderApi, ExtrinsicProofSize};
 use sp_trie::proof_size_extension::ProofSizeExt;
 
 /// A builder for creating an instance of [`BlockBuilder`].
@@ -290,6 +290,23 @@ where
 		})
 	}
 
+	/// The storage proof size recorded by the runtime for each extrinsic pushed so far.
+	///
+	/// Empty if the runtime doesn't support version 7 of [`BlockBuilderApi`] or doesn't record
+	/// the breakdown. Must be called before [`Self::build`], which discards the breakdown.
+	pub fn extrinsic_proof_sizes(&self) -> Result<Vec<ExtrinsicProofSize>, Error> {
+		if self.version < 7 {
+			return Ok(Vec::new())
+		}
+
+		let parent_hash = self.parent_hash;
+		self.api
+			.execute_in_transaction(move |api| {
+				TransactionOutcome::Rollback(api.extrinsic_proof_sizes(parent_hash))
+			})
+			.map_err(Error::from)
+	}
+
 	/// Consume the builder to build a valid `Block` containing all pushed extrinsics.
 	///
 	/// Returns the build `Block`, the changes to the storage and an optional `StorageProof`
@@ -393,6 +410,26 @@ mod tests {
 			.contains("Database missing expected key"),);
 	}
 
+	#[test]
+	fn extrinsic_proof_sizes_are_empty_without_breakdown() {
+		let builder = substrate_test_runtime_client::TestClientBuilder::new();
+		let client = builder.build();
+		let genesis_hash = client.info().best_hash;
+
+		let mut block_builder = BlockBuilderBuilder::new(&client)
+			.on_parent_block(genesis_hash)
+			.with_parent_block_number(0)
+			.enable_proof_recording()
+			.build()
+			.unwrap();
+
+		block_builder.push(ExtrinsicBuilder::new_read(8).build()).unwrap();
+
+		assert_eq!(block_builder.version, 7);
+		assert!(block_builder.extrinsic_proof_sizes().unwrap().is_empty());
+		block_builder.build().unwrap();
+	}
+
 	#[test]
 	fn failing_extrinsic_rolls_back_changes_in_storage_proof() {
 		let builder = substrate_test_runtime_client::TestClientBuilder::new();
@@ -110,6 +110,16 @@ impl Into<sc_tracing::TracingReceiver> for TracingReceiver {
 	}
 }
 
+/// The order in which ready transactions are provided for block production.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
+#[value(rename_all = "kebab-case")]
+pub enum TransactionPoolOrdering {
+	/// Order by priority, then by the longevity left.
+	Priority,
+	/// Order by the time transactions were imported, ignoring their priority.
+	Fifo,
+}
+
 /// The type of the node key.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
 #[value(rename_all = "kebab-case")]
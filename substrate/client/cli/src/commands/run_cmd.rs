@@ -26,17 +26,24 @@ use crate::{
 	CliConfiguration, PrometheusParams, RuntimeParams, TelemetryParams,
 	RPC_DEFAULT_MAX_CONNECTIONS, RPC_DEFAULT_MAX_REQUEST_SIZE_MB, RPC_DEFAULT_MAX_RESPONSE_SIZE_MB,
 	RPC_DEFAULT_MAX_SUBS_PER_CONN, RPC_DEFAULT_MESSAGE_CAPACITY_PER_CONN,
+	RPC_DEFAULT_TX_RESUBMIT_MAX_INTERVAL_MS,
 };
 use clap::Parser;
 use regex::Regex;
 use sc_service::{
-	config::{BasePath, PrometheusConfig, RpcBatchRequestConfig, TransactionPoolOptions},
+	config::{
+		BasePath, PrometheusConfig, RpcAuthConfig, RpcBatchRequestConfig, RpcMethodGroup,
+		RpcMethodRateLimit, TokenAuth, TransactionPoolOptions, TransactionResubmissionPolicy,
+	},
 	ChainSpec, Role,
 };
 use sc_telemetry::TelemetryEndpoints;
 use std::{
 	net::{IpAddr, Ipv4Addr, SocketAddr},
 	num::NonZeroU32,
+	path::PathBuf,
+	sync::Arc,
+	time::Duration,
 };
 
 /// The `run` command used to run a node.
@@ -94,6 +101,32 @@ pub struct RunCmd {
 	#[arg(long)]
 	pub rpc_rate_limit: Option<NonZeroU32>,
 
+	/// RPC rate limiting of a group of methods, shared by all connections.
+	///
+	/// The limit is a token bucket refilled with `RATE` calls per second that holds up to `BURST`
+	/// calls, which defaults to `RATE`. The groups are `transaction`, `chainHead` and `archive`.
+	/// Calls over the limit are rejected with the error code `-32005`.
+	///
+	/// For example `--rpc-method-rate-limit archive=10/50` allows bursts of 50 `archive_*` calls
+	/// and 10 calls per second after that. Can be given once per group.
+	#[arg(long, value_name = "GROUP=RATE[/BURST]")]
+	pub rpc_method_rate_limit: Vec<RpcMethodRateLimit>,
+
+	/// Require a bearer token for the calls to the protected groups of RPC methods.
+	///
+	/// The file holds the accepted tokens, one per line. Clients pass one of them in the
+	/// `Authorization: Bearer <TOKEN>` header of their HTTP requests, or of the request opening
+	/// their websocket connection. Unauthorized calls are rejected with the error code `-32006`.
+	#[arg(long, value_name = "PATH")]
+	pub rpc_auth_token_file: Option<PathBuf>,
+
+	/// A group of RPC methods protected by `--rpc-auth-token-file`.
+	///
+	/// The groups are `transaction`, `chainHead` and `archive`. Can be given multiple times,
+	/// only the `archive` methods are protected by default.
+	#[arg(long, value_name = "GROUP", requires = "rpc_auth_token_file")]
+	pub rpc_auth_method_group: Vec<RpcMethodGroup>,
+
 	/// Set the maximum RPC request payload size for both HTTP and WS in megabytes.
 	#[arg(long, default_value_t = RPC_DEFAULT_MAX_REQUEST_SIZE_MB)]
 	pub rpc_max_request_size: u32,
@@ -133,6 +166,30 @@ pub struct RunCmd {
 	#[arg(long, conflicts_with_all = &["rpc_disable_batch_requests"], value_name = "LEN")]
 	pub rpc_max_batch_request_len: Option<u32>,
 
+	/// The minimum time in milliseconds between two submissions of a transaction broadcast with
+	/// `transaction_unstable_broadcast`.
+	///
+	/// A broadcast transaction is resubmitted if the pool rejected it with a retriable error or
+	/// dropped it. By default this happens at the next new best block.
+	#[arg(long, value_name = "MS", default_value_t = 0)]
+	pub rpc_tx_resubmit_interval: u64,
+
+	/// Double the interval between two submissions of a broadcast transaction after every
+	/// resubmission, up to `--rpc-tx-resubmit-max-interval`.
+	#[arg(long)]
+	pub rpc_tx_resubmit_backoff: bool,
+
+	/// The maximum time in milliseconds between two submissions of a broadcast transaction when
+	/// backing off.
+	#[arg(long, value_name = "MS", default_value_t = RPC_DEFAULT_TX_RESUBMIT_MAX_INTERVAL_MS)]
+	pub rpc_tx_resubmit_max_interval: u64,
+
+	/// The maximum number of submissions of a broadcast transaction.
+	///
+	/// Unlimited by default.
+	#[arg(long, value_name = "COUNT")]
+	pub rpc_tx_resubmit_max_attempts: Option<NonZeroU32>,
+
 	/// Specify browser *origins* allowed to access the HTTP & WS RPC servers.
 	///
 	/// A comma-separated list of origins (protocol://domain or special `null`
@@ -439,6 +496,39 @@ impl CliConfiguration for RunCmd {
 		Ok(self.rpc_rate_limit)
 	}
 
+	fn rpc_method_rate_limits(&self) -> Result<Vec<RpcMethodRateLimit>> {
+		Ok(self.rpc_method_rate_limit.clone())
+	}
+
+	fn rpc_auth(&self) -> Result<Option<RpcAuthConfig>> {
+		let Some(path) = &self.rpc_auth_token_file else { return Ok(None) };
+		let tokens = std::fs::read_to_string(path)?
+			.lines()
+			.map(str::trim)
+			.filter(|token| !token.is_empty())
+			.map(String::from)
+			.collect::<Vec<_>>();
+		if tokens.is_empty() {
+			return Err(Error::Input(format!("No RPC auth token found in {}", path.display())))
+		}
+
+		let groups = if self.rpc_auth_method_group.is_empty() {
+			vec![RpcMethodGroup::Archive]
+		} else {
+			self.rpc_auth_method_group.clone()
+		};
+		Ok(Some(RpcAuthConfig { groups, provider: Arc::new(TokenAuth::new(tokens)) }))
+	}
+
+	fn rpc_tx_resubmission(&self) -> Result<TransactionResubmissionPolicy> {
+		Ok(TransactionResubmissionPolicy {
+			interval: Duration::from_millis(self.rpc_tx_resubmit_interval),
+			exponential_backoff: self.rpc_tx_resubmit_backoff,
+			max_interval: Duration::from_millis(self.rpc_tx_resubmit_max_interval),
+			max_attempts: self.rpc_tx_resubmit_max_attempts,
+		})
+	}
+
 	fn transaction_pool(&self, is_dev: bool) -> Result<TransactionPoolOptions> {
 		Ok(self.pool_config.transaction_pool(is_dev))
 	}
@@ -28,7 +28,8 @@ use sc_service::{
 	config::{
 		BasePath, Configuration, DatabaseSource, KeystoreConfig, NetworkConfiguration,
 		NodeKeyConfig, OffchainWorkerConfig, OutputFormat, PrometheusConfig, PruningMode, Role,
-		RpcBatchRequestConfig, RpcMethods, TelemetryEndpoints, TransactionPoolOptions,
+		RpcAuthConfig, RpcBatchRequestConfig, RpcMethodAliases, RpcMethodRateLimit, RpcMethods,
+		TelemetryEndpoints, TransactionPoolOptions, TransactionResubmissionPolicy,
 		WasmExecutionMethod,
 	},
 	BlocksPruning, ChainSpec, TracingReceiver,
@@ -58,6 +59,9 @@ pub const RPC_DEFAULT_MAX_CONNECTIONS: u32 = 100;
 /// The default number of messages the RPC server
 /// is allowed to keep in memory per connection.
 pub const RPC_DEFAULT_MESSAGE_CAPACITY_PER_CONN: u32 = 64;
+/// The default maximum interval in milliseconds between two submissions of a broadcast
+/// transaction.
+pub const RPC_DEFAULT_TX_RESUBMIT_MAX_INTERVAL_MS: u64 = 60_000;
 
 /// Default configuration values used by Substrate
 ///
@@ -294,6 +298,13 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 		self.import_params().map(|x| x.wasm_runtime_overrides()).unwrap_or_default()
 	}
 
+	/// Get the path of the candidate runtime to shadow execute the imported blocks with.
+	///
+	/// By default this is `None`.
+	fn shadow_runtime(&self) -> Option<PathBuf> {
+		self.import_params().map(|x| x.shadow_runtime()).unwrap_or_default()
+	}
+
 	/// Get the RPC address.
 	fn rpc_addr(&self, _default_listen_port: u16) -> Result<Option<SocketAddr>> {
 		Ok(None)
@@ -349,6 +360,34 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 		Ok(None)
 	}
 
+	/// Rate limits of groups of RPC methods.
+	///
+	/// By default no group is limited.
+	fn rpc_method_rate_limits(&self) -> Result<Vec<RpcMethodRateLimit>> {
+		Ok(Vec::new())
+	}
+
+	/// Authorization of the calls to protected groups of RPC methods.
+	///
+	/// By default no method requires authorization.
+	fn rpc_auth(&self) -> Result<Option<RpcAuthConfig>> {
+		Ok(None)
+	}
+
+	/// When transactions broadcast through the RPC are resubmitted to the pool.
+	///
+	/// By default they are resubmitted at every new best block.
+	fn rpc_tx_resubmission(&self) -> Result<TransactionResubmissionPolicy> {
+		Ok(Default::default())
+	}
+
+	/// Additional names of RPC methods and deprecated RPC methods.
+	///
+	/// By default no aliases are registered.
+	fn rpc_method_aliases(&self) -> Result<RpcMethodAliases> {
+		Ok(Default::default())
+	}
+
 	/// Get the prometheus configuration (`None` if disabled)
 	///
 	/// By default this is `None`.
@@ -511,6 +550,7 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 			blocks_pruning: self.blocks_pruning()?,
 			wasm_method: self.wasm_method()?,
 			wasm_runtime_overrides: self.wasm_runtime_overrides(),
+			shadow_runtime: self.shadow_runtime(),
 			rpc_addr: self.rpc_addr(DCV::rpc_listen_port())?,
 			rpc_methods: self.rpc_methods()?,
 			rpc_max_connections: self.rpc_max_connections()?,
@@ -523,6 +563,10 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 			rpc_message_buffer_capacity: self.rpc_buffer_capacity_per_connection()?,
 			rpc_batch_config: self.rpc_batch_config()?,
 			rpc_rate_limit: self.rpc_rate_limit()?,
+			rpc_method_rate_limits: self.rpc_method_rate_limits()?,
+			rpc_auth: self.rpc_auth()?,
+			rpc_tx_resubmission: self.rpc_tx_resubmission()?,
+			rpc_method_aliases: self.rpc_method_aliases()?,
 			prometheus_config: self
 				.prometheus_config(DCV::prometheus_listen_port(), &chain_spec)?,
 			telemetry_endpoints,
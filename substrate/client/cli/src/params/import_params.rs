@@ -71,6 +71,14 @@ pub struct ImportParams {
 	#[arg(long, value_name = "PATH")]
 	pub wasm_runtime_overrides: Option<PathBuf>,
 
+	/// Specify the path of a candidate WASM runtime to shadow execute the imported blocks with.
+	///
+	/// Every imported block is executed again against this runtime, after it was imported, and
+	/// divergences are reported in the logs and the telemetry. The runtime must be built with the
+	/// `shadow-execution` feature of `frame-executive` and must never be enacted.
+	#[arg(long, value_name = "PATH")]
+	pub shadow_runtime: Option<PathBuf>,
+
 	#[allow(missing_docs)]
 	#[clap(flatten)]
 	pub execution_strategies: ExecutionStrategiesParams,
@@ -112,6 +120,12 @@ impl ImportParams {
 	pub fn wasm_runtime_overrides(&self) -> Option<PathBuf> {
 		self.wasm_runtime_overrides.clone()
 	}
+
+	/// Shadow execute the imported blocks against the candidate runtime stored at the returned
+	/// path.
+	pub fn shadow_runtime(&self) -> Option<PathBuf> {
+		self.shadow_runtime.clone()
+	}
 }
 
 /// Execution strategies parameters.
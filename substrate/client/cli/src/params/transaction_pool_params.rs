@@ -16,8 +16,15 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::arg_enums::TransactionPoolOrdering;
 use clap::Args;
-use sc_service::config::TransactionPoolOptions;
+use sc_service::config::{
+	FifoPriorityStrategy, TagPriorityStrategy, TransactionPoolOptions, TransactionPriorityBoost,
+	TransactionTtl, DEFAULT_PRIORITY_BOOST,
+};
+use sp_core::crypto::AccountId32;
+use sp_runtime::Percent;
+use std::{sync::Arc, time::Duration};
 
 /// Parameters used to create the pool configuration.
 #[derive(Debug, Clone, Args)]
@@ -35,6 +42,55 @@ pub struct TransactionPoolParams {
 	/// If it is considered invalid. Defaults to 1800s.
 	#[arg(long, value_name = "SECONDS")]
 	pub tx_ban_seconds: Option<u64>,
+
+	/// How long a transaction submitted locally may stay in the pool.
+	///
+	/// Transactions staying longer are dropped even if they are still valid. By default they
+	/// stay until their longevity runs out.
+	#[arg(long, value_name = "SECONDS")]
+	pub tx_ttl_local: Option<u64>,
+
+	/// How long a transaction received from the network may stay in the pool.
+	///
+	/// Transactions staying longer are dropped even if they are still valid. By default they
+	/// stay until their longevity runs out.
+	#[arg(long, value_name = "SECONDS")]
+	pub tx_ttl_external: Option<u64>,
+
+	/// Account whose transactions are prioritized and protected from eviction from the pool.
+	///
+	/// Meant for the service accounts of infrastructure operators, like bridge relayers or
+	/// oracles. The protection only holds while these transactions take up at most half of the
+	/// pool limits. Can be given multiple times. Accepts an SS58 address or a hex-encoded account
+	/// ID. The accounts can be changed at runtime with the unsafe `txpool_setBoostedAccounts`
+	/// RPC.
+	#[arg(long, value_name = "ACCOUNT")]
+	pub tx_priority_boost_account: Vec<AccountId32>,
+
+	/// The priority added to the transactions of accounts given with
+	/// `--tx-priority-boost-account`.
+	#[arg(long, value_name = "PRIORITY", default_value_t = DEFAULT_PRIORITY_BOOST)]
+	pub tx_priority_boost: u64,
+
+	/// The order in which ready transactions are included in blocks.
+	///
+	/// Transactions of the same sender are always included in order of their dependencies.
+	#[arg(long, value_name = "ORDERING", value_enum, default_value_t = TransactionPoolOrdering::Priority)]
+	pub pool_ordering: TransactionPoolOrdering,
+
+	/// How many percent higher the priority of a transaction must be to replace the transactions
+	/// requiring and providing the same tags, e.g. one of the same sender and nonce.
+	///
+	/// By default, a strictly higher priority suffices.
+	#[arg(long, value_name = "PERCENT", default_value_t = 0, value_parser = clap::value_parser!(u8).range(0..=100))]
+	pub tx_replacement_bump: u8,
+
+	/// For how many blocks the transactions included in a block are remembered.
+	///
+	/// Resubmissions of remembered transactions are rejected without validating them again.
+	/// Zero disables it.
+	#[arg(long, value_name = "BLOCKS", default_value_t = 64)]
+	pub tx_recently_included_depth: u32,
 }
 
 impl TransactionPoolParams {
@@ -59,6 +115,26 @@ impl TransactionPoolParams {
 			std::time::Duration::from_secs(30 * 60)
 		};
 
+		opts.ttl = TransactionTtl {
+			local: self.tx_ttl_local.map(Duration::from_secs),
+			external: self.tx_ttl_external.map(Duration::from_secs),
+		};
+
+		opts.priority_boost = Arc::new(TransactionPriorityBoost::new(
+			self.tx_priority_boost,
+			self.tx_priority_boost_account
+				.iter()
+				.map(|account| AsRef::<[u8]>::as_ref(account).to_vec()),
+		));
+
+		opts.priority_strategy = match self.pool_ordering {
+			TransactionPoolOrdering::Priority => Arc::new(TagPriorityStrategy),
+			TransactionPoolOrdering::Fifo => Arc::new(FifoPriorityStrategy),
+		};
+
+		opts.replacement_bump = Percent::from_percent(self.tx_replacement_bump);
+		opts.recently_included_depth = self.tx_recently_included_depth;
+
 		opts
 	}
 }
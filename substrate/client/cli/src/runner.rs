@@ -261,6 +261,7 @@ mod tests {
 				),
 				wasm_method: Default::default(),
 				wasm_runtime_overrides: None,
+				shadow_runtime: None,
 				rpc_addr: None,
 				rpc_max_connections: Default::default(),
 				rpc_cors: None,
@@ -273,6 +274,10 @@ mod tests {
 				rpc_port: 9944,
 				rpc_batch_config: sc_service::config::RpcBatchRequestConfig::Unlimited,
 				rpc_rate_limit: None,
+				rpc_method_rate_limits: Default::default(),
+				rpc_auth: None,
+				rpc_tx_resubmission: Default::default(),
+				rpc_method_aliases: Default::default(),
 				prometheus_config: None,
 				telemetry_endpoints: None,
 				default_heap_pages: None,
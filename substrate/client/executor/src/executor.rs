@@ -386,7 +386,7 @@ where
 	}
 
 	/// Same as `uncached_call`, except it also returns allocation statistics.
-	#[doc(hidden)] // We use this function in tests.
+	#[doc(hidden)] // We use this function in tests and to track the allocations of benchmarks.
 	pub fn uncached_call_with_allocation_stats(
 		&self,
 		runtime_blob: RuntimeBlob,
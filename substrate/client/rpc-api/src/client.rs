@@ -0,0 +1,47 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! The client of an RPC call.
+//!
+//! The RPC server runs every call of a connection as a call of the client that opened it, which
+//! lets methods keep state per client.
+
+use std::{future::Future, net::IpAddr};
+
+tokio::task_local! {
+	static CLIENT: RpcClient;
+}
+
+/// The client of an RPC call, identified by the address it connects from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RpcClient(pub IpAddr);
+
+impl RpcClient {
+	/// Run `call` as a call of this client.
+	pub async fn scope<F: Future>(self, call: F) -> F::Output {
+		CLIENT.scope(self, call).await
+	}
+
+	/// The client of the running call.
+	///
+	/// Returns `None` for calls that don't go through the RPC server, e.g. calls made by the node
+	/// itself.
+	pub fn current() -> Option<Self> {
+		CLIENT.try_with(|client| *client).ok()
+	}
+}
@@ -22,9 +22,13 @@
 
 #![warn(missing_docs)]
 
+mod client;
 mod error;
+mod nonce;
 mod policy;
 
+pub use client::RpcClient;
+pub use nonce::AccountNonceProvider;
 pub use policy::{DenyUnsafe, UnsafeRpcError};
 
 pub mod author;
@@ -36,3 +40,4 @@ pub mod offchain;
 pub mod state;
 pub mod statement;
 pub mod system;
+pub mod txpool;
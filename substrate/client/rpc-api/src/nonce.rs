@@ -0,0 +1,30 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Access to the on-chain nonces of accounts.
+
+use sp_runtime::traits::Block as BlockT;
+
+/// Provides the on-chain nonce of an account to RPC methods.
+///
+/// This keeps the RPC implementations independent of the runtime API the chain exposes nonces
+/// with. Nodes of FRAME based chains can use `substrate_frame_rpc_system::RuntimeAccountNonce`.
+pub trait AccountNonceProvider<Block: BlockT, AccountId, Nonce>: Send + Sync {
+	/// Returns the nonce of `account` at the block `at`.
+	fn account_nonce(&self, at: Block::Hash, account: AccountId) -> Result<Nonce, String>;
+}
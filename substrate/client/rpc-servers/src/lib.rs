@@ -23,7 +23,8 @@
 pub mod middleware;
 
 use std::{
-	convert::Infallible, error::Error as StdError, net::SocketAddr, num::NonZeroU32, time::Duration,
+	collections::HashMap, convert::Infallible, error::Error as StdError, net::SocketAddr,
+	num::NonZeroU32, sync::Arc, time::Duration,
 };
 
 use http::header::HeaderValue;
@@ -49,7 +50,11 @@ pub use jsonrpsee::{
 	},
 	server::{middleware::rpc::RpcServiceBuilder, BatchRequestConfig},
 };
-pub use middleware::{MetricsLayer, RateLimitLayer, RpcMetrics};
+pub use middleware::{
+	AuthLayer, ClientLayer, DeprecationLayer, MethodRateLimitLayer, MetricsLayer, RateLimitLayer,
+	RpcAuthConfig, RpcAuthProvider, RpcClient, RpcMethodGroup, RpcMethodRateLimit, RpcMetrics,
+	TokenAuth, TraceContext, TracingLayer,
+};
 
 const MEGABYTE: u32 = 1024 * 1024;
 
@@ -85,6 +90,16 @@ pub struct Config<'a, M: Send + Sync + 'static> {
 	pub batch_config: BatchRequestConfig,
 	/// Rate limit calls per minute.
 	pub rate_limit: Option<NonZeroU32>,
+	/// Rate limits of groups of methods, shared by all connections.
+	pub method_rate_limits: Vec<RpcMethodRateLimit>,
+	/// Deprecated methods and their deprecation notes, reported when they are called.
+	pub deprecated_methods: HashMap<String, String>,
+	/// The aliases registered on `rpc_api` and the methods they stand for.
+	///
+	/// Calls to an alias are authorized and rate limited like calls to the method itself.
+	pub method_aliases: HashMap<String, String>,
+	/// Authorization of the calls to protected groups of methods.
+	pub auth: Option<RpcAuthConfig>,
 }
 
 #[derive(Debug, Clone)]
@@ -92,6 +107,10 @@ struct PerConnection<RpcMiddleware, HttpMiddleware> {
 	methods: Methods,
 	stop_handle: StopHandle,
 	metrics: Option<RpcMetrics>,
+	method_rate_limit: Option<MethodRateLimitLayer>,
+	deprecation: Option<DeprecationLayer>,
+	auth: Option<RpcAuthConfig>,
+	method_aliases: Arc<HashMap<String, String>>,
 	tokio_handle: tokio::runtime::Handle,
 	service_builder: TowerServiceBuilder<RpcMiddleware, HttpMiddleware>,
 }
@@ -117,7 +136,12 @@ where
 		tokio_handle,
 		rpc_api,
 		rate_limit,
+		method_rate_limits,
+		deprecated_methods,
+		method_aliases,
+		auth,
 	} = config;
+	let method_aliases = Arc::new(method_aliases);
 
 	let std_listener = TcpListener::bind(addrs.as_slice()).await?.into_std()?;
 	let local_addr = std_listener.local_addr().ok();
@@ -153,22 +177,38 @@ where
 
 	let (stop_handle, server_handle) = stop_channel();
 	let cfg = PerConnection {
-		methods: build_rpc_api(rpc_api).into(),
+		methods: build_rpc_api(rpc_api, &method_aliases).into(),
 		service_builder: builder.to_service_builder(),
 		metrics,
+		method_rate_limit: (!method_rate_limits.is_empty())
+			.then(|| MethodRateLimitLayer::new(&method_rate_limits, method_aliases.clone())),
+		deprecation: (!deprecated_methods.is_empty())
+			.then(|| DeprecationLayer::new(&deprecated_methods)),
+		auth,
+		method_aliases,
 		tokio_handle,
 		stop_handle: stop_handle.clone(),
 	};
 
-	let make_service = make_service_fn(move |_conn: &AddrStream| {
+	let make_service = make_service_fn(move |conn: &AddrStream| {
 		let cfg = cfg.clone();
+		let client = ClientLayer::new(RpcClient(conn.remote_addr().ip()));
 
 		async move {
 			let cfg = cfg.clone();
 
 			Ok::<_, Infallible>(service_fn(move |req| {
-				let PerConnection { service_builder, metrics, tokio_handle, stop_handle, methods } =
-					cfg.clone();
+				let PerConnection {
+					service_builder,
+					metrics,
+					method_rate_limit,
+					deprecation,
+					auth,
+					method_aliases,
+					tokio_handle,
+					stop_handle,
+					methods,
+				} = cfg.clone();
 
 				let is_websocket = ws::is_upgrade_request(&req);
 				let transport_label = if is_websocket { "ws" } else { "http" };
@@ -176,10 +216,28 @@ where
 				let metrics = metrics.map(|m| MetricsLayer::new(m, transport_label));
 				let rate_limit = rate_limit.map(|r| RateLimitLayer::per_minute(r));
 
+				// The trace context is only taken from plain HTTP requests, a websocket
+				// connection outlives the client span that opened it.
+				let trace_context = (!is_websocket)
+					.then(|| req.headers().get(middleware::TRACEPARENT_HEADER))
+					.flatten()
+					.and_then(|v| v.to_str().ok())
+					.and_then(TraceContext::from_traceparent);
+				let tracing = TracingLayer::new(transport_label, trace_context);
+				// The credentials of a websocket connection are those of its upgrade request.
+				let auth =
+					auth.map(|config| AuthLayer::new(&config, method_aliases, req.headers()));
+
 				// NOTE: The metrics needs to run first to include rate-limited calls in the
 				// metrics.
-				let rpc_middleware =
-					RpcServiceBuilder::new().option_layer(metrics.clone()).option_layer(rate_limit);
+				let rpc_middleware = RpcServiceBuilder::new()
+					.layer(client)
+					.layer(tracing)
+					.option_layer(metrics.clone())
+					.option_layer(auth)
+					.option_layer(deprecation)
+					.option_layer(method_rate_limit)
+					.option_layer(rate_limit);
 
 				let mut svc =
 					service_builder.set_rpc_middleware(rpc_middleware).build(methods, stop_handle);
@@ -233,11 +291,17 @@ fn hosts_filtering(enabled: bool, addr: Option<SocketAddr>) -> Option<HostFilter
 	}
 }
 
-fn build_rpc_api<M: Send + Sync + 'static>(mut rpc_api: RpcModule<M>) -> RpcModule<M> {
-	let mut available_methods = rpc_api.method_names().collect::<Vec<_>>();
+fn build_rpc_api<M: Send + Sync + 'static>(
+	mut rpc_api: RpcModule<M>,
+	method_aliases: &HashMap<String, String>,
+) -> RpcModule<M> {
+	let mut available_methods = rpc_api.method_names().map(String::from).collect::<Vec<_>>();
+	// The aliases are called like any other method, so they are reported as well.
+	available_methods.extend(method_aliases.keys().cloned());
 	// The "rpc_methods" is defined below and we want it to be part of the reported methods.
-	available_methods.push("rpc_methods");
+	available_methods.push("rpc_methods".into());
 	available_methods.sort();
+	available_methods.dedup();
 
 	rpc_api
 		.register_method("rpc_methods", move |_, _| {
@@ -272,3 +336,130 @@ fn format_cors(maybe_cors: Option<&Vec<String>>) -> String {
 		format!("{:?}", ["*"])
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use jsonrpsee::{
+		core::{client::ClientT, ClientError},
+		http_client::HttpClientBuilder,
+		rpc_params,
+	};
+
+	const METHOD: &str = "archive_unstable_genesisHash";
+	const ALIAS: &str = "legacy_genesisHash";
+
+	/// A free local address and the config of a server listening on it, without any limits.
+	fn test_config(rpc_api: RpcModule<()>) -> (Config<'static, ()>, String) {
+		// `start_server` doesn't report the port it listens on, so a free one is picked first.
+		let addr = std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap();
+
+		let config = Config {
+			addrs: [addr, addr],
+			cors: None,
+			max_connections: 10,
+			max_subs_per_conn: 10,
+			max_payload_in_mb: 1,
+			max_payload_out_mb: 1,
+			metrics: None,
+			message_buffer_capacity: 16,
+			rpc_api,
+			id_provider: None,
+			tokio_handle: tokio::runtime::Handle::current(),
+			batch_config: BatchRequestConfig::Unlimited,
+			rate_limit: None,
+			method_rate_limits: Vec::new(),
+			deprecated_methods: HashMap::new(),
+			method_aliases: HashMap::new(),
+			auth: None,
+		};
+		(config, format!("http://{addr}"))
+	}
+
+	async fn start_protected_server() -> (Server, String) {
+		let mut rpc_api = RpcModule::new(());
+		rpc_api.register_method(METHOD, |_, _| "0x00").unwrap();
+		rpc_api.register_alias(ALIAS, METHOD).unwrap();
+
+		let (mut config, url) = test_config(rpc_api);
+		config.method_aliases = HashMap::from([(ALIAS.into(), METHOD.into())]);
+		config.auth = Some(RpcAuthConfig {
+			groups: vec![RpcMethodGroup::Archive],
+			provider: Arc::new(TokenAuth::new(["secret".to_string()])),
+		});
+
+		(start_server(config).await.unwrap(), url)
+	}
+
+	#[tokio::test]
+	async fn aliases_of_protected_methods_require_authorization() {
+		let (_server, url) = start_protected_server().await;
+
+		let anonymous = HttpClientBuilder::default().build(&url).unwrap();
+		for method in [METHOD, ALIAS] {
+			let err = anonymous.request::<String, _>(method, rpc_params![]).await.unwrap_err();
+			assert!(
+				matches!(&err, ClientError::Call(e) if e.code() == middleware::UNAUTHORIZED_CODE),
+				"{method}: {err:?}",
+			);
+		}
+
+		let mut headers = http::HeaderMap::new();
+		headers.insert(http::header::AUTHORIZATION, HeaderValue::from_static("Bearer secret"));
+		let authorized = HttpClientBuilder::default().set_headers(headers).build(&url).unwrap();
+		for method in [METHOD, ALIAS] {
+			assert_eq!(
+				authorized.request::<String, _>(method, rpc_params![]).await.unwrap(),
+				"0x00"
+			);
+		}
+	}
+
+	#[tokio::test]
+	async fn rpc_methods_lists_aliases() {
+		let (_server, url) = start_protected_server().await;
+		let client = HttpClientBuilder::default().build(&url).unwrap();
+
+		let response: serde_json::Value =
+			client.request("rpc_methods", rpc_params![]).await.unwrap();
+		assert_eq!(response["methods"], serde_json::json!([METHOD, ALIAS, "rpc_methods"]));
+	}
+
+	#[tokio::test]
+	async fn method_rate_limit_rejects_bursts_and_recovers() {
+		const LIMITED: &str = "chainHead_unstable_header";
+		const RELEASE: &str = "chainHead_unstable_unpin";
+
+		let mut rpc_api = RpcModule::new(());
+		rpc_api.register_method(LIMITED, |_, _| "0x00").unwrap();
+		rpc_api.register_method(RELEASE, |_, _| ()).unwrap();
+
+		let (mut config, url) = test_config(rpc_api);
+		config.method_rate_limits = vec![RpcMethodRateLimit {
+			group: RpcMethodGroup::ChainHead,
+			per_second: NonZeroU32::new(5).unwrap(),
+			burst: NonZeroU32::new(2).unwrap(),
+		}];
+		let _server = start_server(config).await.unwrap();
+		let client = HttpClientBuilder::default().build(&url).unwrap();
+
+		// The burst is allowed, the call exceeding it is rejected.
+		for _ in 0..2 {
+			assert_eq!(client.request::<String, _>(LIMITED, rpc_params![]).await.unwrap(), "0x00");
+		}
+		let err = client.request::<String, _>(LIMITED, rpc_params![]).await.unwrap_err();
+		assert!(
+			matches!(&err, ClientError::Call(e) if e.code() == middleware::METHOD_RATE_LIMIT_EXCEEDED_CODE),
+			"{err:?}",
+		);
+
+		// Releasing resources is never limited.
+		for _ in 0..4 {
+			client.request::<(), _>(RELEASE, rpc_params![]).await.unwrap();
+		}
+
+		// The bucket is refilled with a call every 200ms.
+		tokio::time::sleep(Duration::from_millis(500)).await;
+		assert_eq!(client.request::<String, _>(LIMITED, rpc_params![]).await.unwrap(), "0x00");
+	}
+}
@@ -0,0 +1,222 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Authorization of calls to protected groups of RPC methods.
+//!
+//! The credentials are taken from the `Authorization` header of the HTTP request, or of the
+//! upgrade request of a websocket connection, and are checked by an [`RpcAuthProvider`] before
+//! every call to a method of a protected [`RpcMethodGroup`].
+
+use std::{
+	collections::{HashMap, HashSet},
+	fmt,
+	sync::Arc,
+};
+
+use futures::future::{BoxFuture, FutureExt};
+use http::HeaderMap;
+use jsonrpsee::{
+	server::middleware::rpc::RpcServiceT,
+	types::{ErrorObject, Id, Request},
+	MethodResponse,
+};
+
+use super::RpcMethodGroup;
+
+/// Error code of calls rejected because they are not authorized.
+pub const UNAUTHORIZED_CODE: i32 = -32006;
+
+/// Decides whether calls to protected RPC methods are allowed.
+pub trait RpcAuthProvider: Send + Sync + 'static {
+	/// Whether the call to `method` of `group` is allowed, given the `credentials` of the
+	/// connection, i.e. the value of its `Authorization` header, if any.
+	fn is_authorized(&self, credentials: Option<&str>, group: RpcMethodGroup, method: &str)
+		-> bool;
+}
+
+/// Authorizes the requests carrying one of the configured bearer tokens.
+pub struct TokenAuth {
+	tokens: Vec<Vec<u8>>,
+}
+
+impl TokenAuth {
+	/// Create a provider accepting any of `tokens`.
+	pub fn new(tokens: impl IntoIterator<Item = String>) -> Self {
+		Self { tokens: tokens.into_iter().map(String::into_bytes).collect() }
+	}
+}
+
+impl RpcAuthProvider for TokenAuth {
+	fn is_authorized(&self, credentials: Option<&str>, _: RpcMethodGroup, _: &str) -> bool {
+		let Some(token) = credentials.and_then(|c| c.strip_prefix("Bearer ")) else { return false };
+		// Every token is compared in full, to not leak how much of one was guessed right.
+		self.tokens.iter().fold(false, |found, expected| {
+			constant_time_eq(token.trim().as_bytes(), expected) | found
+		})
+	}
+}
+
+/// Compare `a` and `b` without returning early, also if their lengths differ.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+	let byte = |s: &[u8], i| usize::from(s.get(i).copied().unwrap_or_default());
+	(0..a.len().max(b.len())).fold(a.len() ^ b.len(), |acc, i| acc | (byte(a, i) ^ byte(b, i))) == 0
+}
+
+/// The authorization of calls to protected groups of RPC methods.
+#[derive(Clone)]
+pub struct RpcAuthConfig {
+	/// The groups of methods which require authorization.
+	pub groups: Vec<RpcMethodGroup>,
+	/// Authorizes the calls to the methods of `groups`.
+	pub provider: Arc<dyn RpcAuthProvider>,
+}
+
+impl fmt::Debug for RpcAuthConfig {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("RpcAuthConfig")
+			.field("groups", &self.groups)
+			.finish_non_exhaustive()
+	}
+}
+
+/// JSON-RPC authorization middleware layer.
+#[derive(Clone)]
+pub struct AuthLayer {
+	groups: Arc<HashSet<RpcMethodGroup>>,
+	provider: Arc<dyn RpcAuthProvider>,
+	aliases: Arc<HashMap<String, String>>,
+	credentials: Option<Arc<str>>,
+}
+
+impl AuthLayer {
+	/// Create a new layer authorizing the calls of a connection, as configured by `config`.
+	///
+	/// Calls to `aliases` are authorized like calls to the methods they stand for. The
+	/// credentials are read from the `headers` of the request opening the connection.
+	pub fn new(
+		config: &RpcAuthConfig,
+		aliases: Arc<HashMap<String, String>>,
+		headers: &HeaderMap,
+	) -> Self {
+		let credentials = headers
+			.get(http::header::AUTHORIZATION)
+			.and_then(|v| v.to_str().ok())
+			.map(Into::into);
+		Self {
+			groups: Arc::new(config.groups.iter().copied().collect()),
+			provider: config.provider.clone(),
+			aliases,
+			credentials,
+		}
+	}
+}
+
+/// JSON-RPC authorization middleware.
+pub struct Auth<S> {
+	service: S,
+	layer: AuthLayer,
+}
+
+impl<S> tower::Layer<S> for AuthLayer {
+	type Service = Auth<S>;
+
+	fn layer(&self, service: S) -> Self::Service {
+		Auth { service, layer: self.clone() }
+	}
+}
+
+impl<'a, S> RpcServiceT<'a> for Auth<S>
+where
+	S: Send + Sync + RpcServiceT<'a> + Clone + 'static,
+{
+	type Future = BoxFuture<'a, MethodResponse>;
+
+	fn call(&self, req: Request<'a>) -> Self::Future {
+		let AuthLayer { groups, provider, aliases, credentials } = &self.layer;
+		let unauthorized = RpcMethodGroup::of_aliased(&req.method, aliases)
+			.filter(|group| groups.contains(group))
+			.filter(|group| !provider.is_authorized(credentials.as_deref(), *group, &req.method));
+
+		match unauthorized {
+			Some(group) => {
+				log::debug!(
+					target: "rpc",
+					"Unauthorized call to `{}` of the protected {group} methods",
+					req.method
+				);
+				let response = reject_unauthorized(req.id, group);
+				async move { response }.boxed()
+			},
+			None => self.service.call(req).boxed(),
+		}
+	}
+}
+
+fn reject_unauthorized(id: Id, group: RpcMethodGroup) -> MethodResponse {
+	MethodResponse::error(
+		id,
+		ErrorObject::owned(
+			UNAUTHORIZED_CODE,
+			format!("The {group} methods require authorization"),
+			None::<()>,
+		),
+	)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn token_auth_works() {
+		let auth = TokenAuth::new(["secret".to_string(), "other".to_string()]);
+		let authorized =
+			|credentials| auth.is_authorized(credentials, RpcMethodGroup::Archive, "archive_x");
+
+		assert!(authorized(Some("Bearer secret")));
+		assert!(authorized(Some("Bearer other")));
+		assert!(!authorized(Some("Bearer secre")));
+		assert!(!authorized(Some("Bearer secrets")));
+		assert!(!authorized(Some("Basic secret")));
+		assert!(!authorized(Some("secret")));
+		assert!(!authorized(None));
+	}
+
+	#[test]
+	fn constant_time_eq_works() {
+		assert!(constant_time_eq(b"secret", b"secret"));
+		assert!(constant_time_eq(b"", b""));
+		assert!(!constant_time_eq(b"secret", b"secreT"));
+		assert!(!constant_time_eq(b"secret", b"secret\0"));
+		assert!(!constant_time_eq(b"", b"\0"));
+	}
+
+	#[test]
+	fn credentials_are_read_from_authorization_header() {
+		let config = RpcAuthConfig {
+			groups: vec![RpcMethodGroup::Archive],
+			provider: Arc::new(TokenAuth::new(["secret".to_string()])),
+		};
+		let layer = |headers: &HeaderMap| AuthLayer::new(&config, Default::default(), headers);
+		let mut headers = HeaderMap::new();
+		assert_eq!(layer(&headers).credentials, None);
+
+		headers.insert(http::header::AUTHORIZATION, "Bearer secret".parse().unwrap());
+		assert_eq!(layer(&headers).credentials.as_deref(), Some("Bearer secret"));
+	}
+}
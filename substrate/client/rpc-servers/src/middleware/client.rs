@@ -0,0 +1,59 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runs the calls of a connection as calls of its client.
+
+use futures::future::{BoxFuture, FutureExt};
+use jsonrpsee::{server::middleware::rpc::RpcServiceT, types::Request, MethodResponse};
+pub use sc_rpc_api::RpcClient;
+
+/// JSON-RPC client middleware layer.
+#[derive(Clone, Copy, Debug)]
+pub struct ClientLayer(RpcClient);
+
+impl ClientLayer {
+	/// Create a new layer running the calls of a connection as calls of `client`.
+	pub fn new(client: RpcClient) -> Self {
+		Self(client)
+	}
+}
+
+/// JSON-RPC client middleware.
+pub struct Client<S> {
+	service: S,
+	client: RpcClient,
+}
+
+impl<S> tower::Layer<S> for ClientLayer {
+	type Service = Client<S>;
+
+	fn layer(&self, service: S) -> Self::Service {
+		Client { service, client: self.0 }
+	}
+}
+
+impl<'a, S> RpcServiceT<'a> for Client<S>
+where
+	S: Send + Sync + RpcServiceT<'a> + Clone + 'static,
+{
+	type Future = BoxFuture<'a, MethodResponse>;
+
+	fn call(&self, req: Request<'a>) -> Self::Future {
+		self.client.scope(self.service.call(req)).boxed()
+	}
+}
@@ -0,0 +1,107 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Warnings about calls to deprecated RPC methods.
+
+use std::{
+	collections::HashMap,
+	sync::{
+		atomic::{AtomicBool, Ordering},
+		Arc,
+	},
+};
+
+use jsonrpsee::{server::middleware::rpc::RpcServiceT, types::Request};
+
+/// The log target of the deprecation warnings.
+const LOG_TARGET: &str = "rpc::deprecation";
+
+/// The note of a deprecated method and whether a call to it was reported already.
+#[derive(Debug)]
+struct Deprecation {
+	note: String,
+	reported: AtomicBool,
+}
+
+/// JSON-RPC deprecated method middleware layer.
+#[derive(Debug, Clone)]
+pub struct DeprecationLayer {
+	deprecations: Arc<HashMap<String, Deprecation>>,
+}
+
+impl DeprecationLayer {
+	/// Create a new layer reporting calls to the methods of `deprecations`, given with their
+	/// deprecation notes.
+	///
+	/// The first call to each method is logged as a warning, later ones at the debug level.
+	pub fn new(deprecations: &HashMap<String, String>) -> Self {
+		let deprecations = deprecations
+			.iter()
+			.map(|(method, note)| {
+				(
+					method.clone(),
+					Deprecation { note: note.clone(), reported: AtomicBool::new(false) },
+				)
+			})
+			.collect();
+		Self { deprecations: Arc::new(deprecations) }
+	}
+}
+
+/// JSON-RPC deprecated method middleware.
+pub struct Deprecated<S> {
+	service: S,
+	deprecations: Arc<HashMap<String, Deprecation>>,
+}
+
+impl<S> tower::Layer<S> for DeprecationLayer {
+	type Service = Deprecated<S>;
+
+	fn layer(&self, service: S) -> Self::Service {
+		Deprecated { service, deprecations: self.deprecations.clone() }
+	}
+}
+
+impl<'a, S> RpcServiceT<'a> for Deprecated<S>
+where
+	S: Send + Sync + RpcServiceT<'a>,
+{
+	type Future = S::Future;
+
+	fn call(&self, req: Request<'a>) -> Self::Future {
+		if let Some(deprecation) = self.deprecations.get(req.method_name()) {
+			if deprecation.reported.swap(true, Ordering::Relaxed) {
+				log::debug!(
+					target: LOG_TARGET,
+					"Deprecated RPC method `{}` called: {}",
+					req.method_name(),
+					deprecation.note,
+				);
+			} else {
+				log::warn!(
+					target: LOG_TARGET,
+					"Deprecated RPC method `{}` called, clients should migrate: {}",
+					req.method_name(),
+					deprecation.note,
+				);
+			}
+		}
+
+		self.service.call(req)
+	}
+}
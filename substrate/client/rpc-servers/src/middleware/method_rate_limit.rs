@@ -0,0 +1,284 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Rate limiting of groups of expensive RPC methods.
+//!
+//! Unlike [`RateLimitLayer`](super::RateLimitLayer), which limits the calls of each connection,
+//! the limits here are shared by all connections: they protect the node, not fairness between
+//! clients. Each group of methods has its own token bucket and calls exceeding it are rejected
+//! right away. Calls releasing resources, like `chainHead_unstable_unpin`, are never rejected.
+
+use std::{collections::HashMap, fmt, num::NonZeroU32, str::FromStr, sync::Arc};
+
+use futures::future::{BoxFuture, FutureExt};
+use governor::{
+	clock::DefaultClock,
+	middleware::NoOpMiddleware,
+	state::{InMemoryState, NotKeyed},
+};
+use jsonrpsee::{
+	server::middleware::rpc::RpcServiceT,
+	types::{ErrorObject, Id, Request},
+	MethodResponse,
+};
+
+type RateLimitInner = governor::RateLimiter<NotKeyed, InMemoryState, DefaultClock, NoOpMiddleware>;
+
+/// Error code of calls rejected because their group exceeded its rate limit.
+pub const METHOD_RATE_LIMIT_EXCEEDED_CODE: i32 = -32005;
+
+/// A group of RPC methods sharing a rate limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RpcMethodGroup {
+	/// The `transaction_*` and `transactionWatch_*` methods.
+	Transaction,
+	/// The `chainHead_*` methods.
+	ChainHead,
+	/// The `archive_*` methods.
+	Archive,
+}
+
+impl RpcMethodGroup {
+	/// The group of the method `name`, if any.
+	pub fn of(name: &str) -> Option<Self> {
+		if name.starts_with("transaction_") || name.starts_with("transactionWatch_") {
+			Some(Self::Transaction)
+		} else if name.starts_with("chainHead_") {
+			Some(Self::ChainHead)
+		} else if name.starts_with("archive_") {
+			Some(Self::Archive)
+		} else {
+			None
+		}
+	}
+
+	/// The group of the method `name`, which is resolved first if it is one of the `aliases` of
+	/// another method.
+	///
+	/// Calls to an alias are treated as calls to the method it stands for, whatever its name.
+	pub fn of_aliased(name: &str, aliases: &HashMap<String, String>) -> Option<Self> {
+		Self::of(aliases.get(name).map_or(name, String::as_str))
+	}
+}
+
+impl fmt::Display for RpcMethodGroup {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::Transaction => write!(f, "transaction"),
+			Self::ChainHead => write!(f, "chainHead"),
+			Self::Archive => write!(f, "archive"),
+		}
+	}
+}
+
+impl FromStr for RpcMethodGroup {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s.to_lowercase().as_str() {
+			"transaction" => Ok(Self::Transaction),
+			"chainhead" => Ok(Self::ChainHead),
+			"archive" => Ok(Self::Archive),
+			_ => Err(format!(
+				"Unknown method group `{s}`, expected `transaction`, `chainHead` or `archive`"
+			)),
+		}
+	}
+}
+
+/// The last part of the names of the methods releasing resources held for the client, e.g.
+/// `chainHead_unstable_unpin`.
+const RELEASE_METHODS: [&str; 3] = ["_unfollow", "_unpin", "_unwatch"];
+
+/// Whether the method `name` releases a subscription or pinned blocks held for the client.
+///
+/// Such calls are never rate limited: rejecting them would only make the client hold on to the
+/// resources for longer.
+pub fn is_release_method(name: &str) -> bool {
+	RELEASE_METHODS.iter().any(|suffix| name.ends_with(suffix))
+}
+
+/// The token bucket of a group of RPC methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RpcMethodRateLimit {
+	/// The limited group of methods.
+	pub group: RpcMethodGroup,
+	/// The number of calls per second the bucket is refilled with.
+	pub per_second: NonZeroU32,
+	/// The size of the bucket, i.e. the number of calls allowed in a burst.
+	pub burst: NonZeroU32,
+}
+
+/// Parses `GROUP=RATE[/BURST]`, the burst defaults to the rate.
+impl FromStr for RpcMethodRateLimit {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let (group, limit) = s
+			.split_once('=')
+			.ok_or_else(|| format!("Expected `GROUP=RATE[/BURST]`, got `{s}`"))?;
+		let parse = |n: &str| {
+			n.trim()
+				.parse::<NonZeroU32>()
+				.map_err(|e| format!("Invalid rate limit `{n}`: {e}"))
+		};
+		let (per_second, burst) = match limit.split_once('/') {
+			Some((rate, burst)) => (parse(rate)?, parse(burst)?),
+			None => (parse(limit)?, parse(limit)?),
+		};
+		Ok(Self { group: group.trim().parse()?, per_second, burst })
+	}
+}
+
+/// JSON-RPC method group rate limit middleware layer.
+#[derive(Debug, Clone)]
+pub struct MethodRateLimitLayer {
+	limiters: Arc<HashMap<RpcMethodGroup, RateLimitInner>>,
+	aliases: Arc<HashMap<String, String>>,
+}
+
+impl MethodRateLimitLayer {
+	/// Create a new layer enforcing `limits`, counting the calls to `aliases` towards the groups
+	/// of the methods they stand for.
+	///
+	/// If a group is limited more than once, the last limit is used.
+	pub fn new(limits: &[RpcMethodRateLimit], aliases: Arc<HashMap<String, String>>) -> Self {
+		let limiters = limits
+			.iter()
+			.map(|limit| {
+				let quota = governor::Quota::per_second(limit.per_second).allow_burst(limit.burst);
+				(limit.group, RateLimitInner::direct(quota))
+			})
+			.collect();
+		Self { limiters: Arc::new(limiters), aliases }
+	}
+}
+
+/// JSON-RPC method group rate limit middleware.
+pub struct MethodRateLimit<S> {
+	service: S,
+	layer: MethodRateLimitLayer,
+}
+
+impl<S> tower::Layer<S> for MethodRateLimitLayer {
+	type Service = MethodRateLimit<S>;
+
+	fn layer(&self, service: S) -> Self::Service {
+		MethodRateLimit { service, layer: self.clone() }
+	}
+}
+
+impl<'a, S> RpcServiceT<'a> for MethodRateLimit<S>
+where
+	S: Send + Sync + RpcServiceT<'a> + Clone + 'static,
+{
+	type Future = BoxFuture<'a, MethodResponse>;
+
+	fn call(&self, req: Request<'a>) -> Self::Future {
+		let MethodRateLimitLayer { limiters, aliases } = &self.layer;
+		let method = aliases.get(req.method_name()).map_or(req.method_name(), String::as_str);
+		let limited = RpcMethodGroup::of(method)
+			.filter(|_| !is_release_method(method))
+			.and_then(|group| limiters.get(&group).map(|limiter| (group, limiter)))
+			.filter(|(_, limiter)| limiter.check().is_err());
+
+		match limited {
+			Some((group, _)) => {
+				let response = reject_rate_limited(req.id, group);
+				async move { response }.boxed()
+			},
+			None => self.service.call(req).boxed(),
+		}
+	}
+}
+
+fn reject_rate_limited(id: Id, group: RpcMethodGroup) -> MethodResponse {
+	MethodResponse::error(
+		id,
+		ErrorObject::owned(
+			METHOD_RATE_LIMIT_EXCEEDED_CODE,
+			format!("Rate limit of the {group} methods exceeded"),
+			None::<()>,
+		),
+	)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn methods_are_grouped_by_prefix() {
+		assert_eq!(
+			RpcMethodGroup::of("transactionWatch_unstable_submitAndWatch"),
+			Some(RpcMethodGroup::Transaction)
+		);
+		assert_eq!(
+			RpcMethodGroup::of("transaction_unstable_broadcast"),
+			Some(RpcMethodGroup::Transaction)
+		);
+		assert_eq!(
+			RpcMethodGroup::of("chainHead_unstable_storage"),
+			Some(RpcMethodGroup::ChainHead)
+		);
+		assert_eq!(RpcMethodGroup::of("archive_unstable_storage"), Some(RpcMethodGroup::Archive));
+		assert_eq!(RpcMethodGroup::of("state_getStorage"), None);
+	}
+
+	#[test]
+	fn release_methods_are_recognized() {
+		for method in [
+			"chainHead_unstable_unfollow",
+			"chainHead_unstable_unpin",
+			"chainHead_v1_unpin",
+			"transactionWatch_unstable_unwatch",
+		] {
+			assert!(is_release_method(method), "{method}");
+		}
+		assert!(!is_release_method("chainHead_unstable_follow"));
+		assert!(!is_release_method("transactionWatch_unstable_submitAndWatch"));
+	}
+
+	#[test]
+	fn aliases_are_grouped_like_their_methods() {
+		let aliases = HashMap::from([("legacy_body".to_string(), "archive_v1_body".to_string())]);
+		assert_eq!(
+			RpcMethodGroup::of_aliased("legacy_body", &aliases),
+			Some(RpcMethodGroup::Archive)
+		);
+		assert_eq!(
+			RpcMethodGroup::of_aliased("chainHead_v1_body", &aliases),
+			Some(RpcMethodGroup::ChainHead)
+		);
+		assert_eq!(RpcMethodGroup::of_aliased("state_getStorage", &aliases), None);
+	}
+
+	#[test]
+	fn parse_rate_limit_works() {
+		let limit = |group, per_second, burst| RpcMethodRateLimit {
+			group,
+			per_second: NonZeroU32::new(per_second).unwrap(),
+			burst: NonZeroU32::new(burst).unwrap(),
+		};
+		assert_eq!("chainHead=10".parse(), Ok(limit(RpcMethodGroup::ChainHead, 10, 10)));
+		assert_eq!("archive=5/20".parse(), Ok(limit(RpcMethodGroup::Archive, 5, 20)));
+		assert!("archive".parse::<RpcMethodRateLimit>().is_err());
+		assert!("archive=0".parse::<RpcMethodRateLimit>().is_err());
+		assert!("state=10".parse::<RpcMethodRateLimit>().is_err());
+	}
+}
@@ -18,10 +18,25 @@
 
 //! JSON-RPC specific middleware.
 
+/// Authorization middleware.
+pub mod auth;
+/// Client middleware.
+pub mod client;
+/// Deprecated method middleware.
+pub mod deprecation;
+/// Method group rate limit middleware.
+pub mod method_rate_limit;
 /// Grafana metrics middleware.
 pub mod metrics;
 /// Rate limit middleware.
 pub mod rate_limit;
+/// Tracing middleware.
+pub mod tracing;
 
+pub use self::tracing::*;
+pub use auth::*;
+pub use client::*;
+pub use deprecation::*;
+pub use method_rate_limit::*;
 pub use metrics::*;
 pub use rate_limit::*;
@@ -0,0 +1,215 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC tracing middleware.
+//!
+//! Every call is executed inside a `rpc_call` span. When the client sent a W3C
+//! [`traceparent`](https://www.w3.org/TR/trace-context/#traceparent-header) header
+//! the client span is set as the OpenTelemetry parent of the `rpc_call` span, so that
+//! an OpenTelemetry subscriber exports the RPC-layer spans as part of the client trace.
+//! The trace and parent span IDs are recorded on the span as well, for the other
+//! subscribers.
+
+use jsonrpsee::{server::middleware::rpc::RpcServiceT, types::Request};
+use opentelemetry::trace::{SpanContext, SpanId, TraceContextExt, TraceFlags, TraceId, TraceState};
+use tracing::{field, instrument::Instrumented, Instrument};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+/// Name of the HTTP header carrying the W3C trace context.
+pub const TRACEPARENT_HEADER: &str = "traceparent";
+
+const LOG_TARGET: &str = "rpc";
+
+/// Trace context propagated by the client, see the W3C trace context specification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceContext {
+	/// 16-byte trace ID, hex encoded.
+	pub trace_id: String,
+	/// 8-byte ID of the client span that issued the request, hex encoded.
+	pub parent_id: String,
+	/// Whether the client sampled the trace.
+	pub sampled: bool,
+}
+
+impl TraceContext {
+	/// Parse the value of a `traceparent` header.
+	///
+	/// Returns `None` if the header is malformed or uses an invalid version or ID.
+	pub fn from_traceparent(value: &str) -> Option<Self> {
+		let mut parts = value.trim().split('-');
+		let version = parts.next()?;
+		let trace_id = parts.next()?;
+		let parent_id = parts.next()?;
+		let flags = parts.next()?;
+
+		let is_hex = |s: &str, len: usize| {
+			s.len() == len && s.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'))
+		};
+		let is_zero = |s: &str| s.bytes().all(|b| b == b'0');
+
+		if !is_hex(version, 2) || version == "ff" {
+			return None
+		}
+		// Version `00` must not have any trailing fields, future versions may.
+		if version == "00" && parts.next().is_some() {
+			return None
+		}
+		if !is_hex(trace_id, 32) || is_zero(trace_id) {
+			return None
+		}
+		if !is_hex(parent_id, 16) || is_zero(parent_id) {
+			return None
+		}
+		if !is_hex(flags, 2) {
+			return None
+		}
+		let flags = u8::from_str_radix(flags, 16).ok()?;
+
+		Some(Self {
+			trace_id: trace_id.to_string(),
+			parent_id: parent_id.to_string(),
+			sampled: flags & 0x01 == 0x01,
+		})
+	}
+
+	/// The OpenTelemetry context whose active span is the remote client span.
+	pub fn otel_context(&self) -> Option<opentelemetry::Context> {
+		let trace_id = TraceId::from_hex(&self.trace_id).ok()?;
+		let span_id = SpanId::from_hex(&self.parent_id).ok()?;
+		let flags = if self.sampled { TraceFlags::SAMPLED } else { TraceFlags::default() };
+		let span_context = SpanContext::new(trace_id, span_id, flags, true, TraceState::default());
+
+		Some(opentelemetry::Context::new().with_remote_span_context(span_context))
+	}
+}
+
+/// JSON-RPC tracing middleware layer.
+#[derive(Debug, Clone)]
+pub struct TracingLayer {
+	transport_label: &'static str,
+	trace_context: Option<TraceContext>,
+}
+
+impl TracingLayer {
+	/// Create a new tracing layer, optionally linked to the trace context of the client.
+	pub fn new(transport_label: &'static str, trace_context: Option<TraceContext>) -> Self {
+		Self { transport_label, trace_context }
+	}
+}
+
+impl<S> tower::Layer<S> for TracingLayer {
+	type Service = Tracing<S>;
+
+	fn layer(&self, service: S) -> Self::Service {
+		Tracing {
+			service,
+			transport_label: self.transport_label,
+			trace_context: self.trace_context.clone(),
+		}
+	}
+}
+
+/// JSON-RPC tracing middleware.
+#[derive(Debug, Clone)]
+pub struct Tracing<S> {
+	service: S,
+	transport_label: &'static str,
+	trace_context: Option<TraceContext>,
+}
+
+impl<'a, S> RpcServiceT<'a> for Tracing<S>
+where
+	S: Send + Sync + RpcServiceT<'a>,
+{
+	type Future = Instrumented<S::Future>;
+
+	fn call(&self, req: Request<'a>) -> Self::Future {
+		let span = tracing::info_span!(
+			target: LOG_TARGET,
+			"rpc_call",
+			method = %req.method_name(),
+			id = ?req.id(),
+			transport = self.transport_label,
+			trace_id = field::Empty,
+			parent_id = field::Empty,
+			sampled = field::Empty,
+		);
+
+		if let Some(ctx) = &self.trace_context {
+			span.record("trace_id", ctx.trace_id.as_str());
+			span.record("parent_id", ctx.parent_id.as_str());
+			span.record("sampled", ctx.sampled);
+			if let Some(parent) = ctx.otel_context() {
+				span.set_parent(parent);
+			}
+		}
+
+		let _enter = span.enter();
+		self.service.call(req).instrument(span.clone())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parses_valid_traceparent() {
+		let ctx = TraceContext::from_traceparent(
+			"00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+		)
+		.unwrap();
+
+		assert_eq!(ctx.trace_id, "4bf92f3577b34da6a3ce929d0e0e4736");
+		assert_eq!(ctx.parent_id, "00f067aa0ba902b7");
+		assert!(ctx.sampled);
+	}
+
+	#[test]
+	fn converts_to_remote_parent_context() {
+		let ctx = TraceContext::from_traceparent(
+			"00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+		)
+		.unwrap();
+
+		let otel_context = ctx.otel_context().unwrap();
+		let parent = otel_context.span().span_context().clone();
+		assert_eq!(
+			parent.trace_id(),
+			TraceId::from_hex("4bf92f3577b34da6a3ce929d0e0e4736").unwrap()
+		);
+		assert_eq!(parent.span_id(), SpanId::from_hex("00f067aa0ba902b7").unwrap());
+		assert!(parent.is_remote());
+		assert!(parent.is_sampled());
+	}
+
+	#[test]
+	fn rejects_invalid_traceparent() {
+		for value in [
+			"",
+			"00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7",
+			"ff-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+			"00-00000000000000000000000000000000-00f067aa0ba902b7-01",
+			"00-4bf92f3577b34da6a3ce929d0e0e4736-0000000000000000-01",
+			"00-4BF92F3577B34DA6A3CE929D0E0E4736-00f067aa0ba902b7-01",
+			"00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01-extra",
+		] {
+			assert_eq!(TraceContext::from_traceparent(value), None, "{value}");
+		}
+	}
+}
@@ -0,0 +1,135 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Aliases of RPC methods, to keep clients working while methods are renamed.
+//!
+//! Methods of the specification are introduced as `unstable` and renamed once they are
+//! stabilized, e.g. from `chainSpec_unstable_chainName` to `chainSpec_v1_chainName`. With
+//! [`MethodAliases`] the server keeps answering the old name too, and warns the clients still
+//! calling it that it is deprecated.
+
+use jsonrpsee::{core::server::RegisterMethodError, RpcModule};
+use std::collections::HashMap;
+
+/// The aliases of RPC methods and the deprecation notes of methods.
+///
+/// Aliases are registered on the RPC module with [`Self::register`]. Deprecation notes are
+/// reported by the RPC server whenever a deprecated method is called, see
+/// [`Self::deprecations`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MethodAliases {
+	/// The aliases and the methods they stand for.
+	aliases: Vec<(&'static str, &'static str)>,
+	/// The deprecated methods and their notes.
+	deprecations: HashMap<String, String>,
+}
+
+impl MethodAliases {
+	/// No aliases and no deprecated methods.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Serve the method `target` under the additional name `alias`.
+	///
+	/// Subscriptions keep the notification name of `target`. Aliasing a subscription usually
+	/// goes together with aliasing its unsubscribe method.
+	pub fn alias(mut self, alias: &'static str, target: &'static str) -> Self {
+		self.aliases.push((alias, target));
+		self
+	}
+
+	/// Serve the method `target` under the additional name `alias` and deprecate `alias` in
+	/// favour of `target`.
+	pub fn deprecated_alias(self, alias: &'static str, target: &'static str) -> Self {
+		self.alias(alias, target).deprecate(alias, format!("use `{target}` instead"))
+	}
+
+	/// Report `method` as deprecated with the given `note` whenever it is called.
+	pub fn deprecate(mut self, method: impl Into<String>, note: impl Into<String>) -> Self {
+		self.deprecations.insert(method.into(), note.into());
+		self
+	}
+
+	/// Keep serving the `chainSpec_unstable_*` methods, which were stabilized as
+	/// `chainSpec_v1_*`, as deprecated aliases.
+	pub fn with_chain_spec_unstable(self) -> Self {
+		self.deprecated_alias("chainSpec_unstable_chainName", "chainSpec_v1_chainName")
+			.deprecated_alias("chainSpec_unstable_genesisHash", "chainSpec_v1_genesisHash")
+			.deprecated_alias("chainSpec_unstable_properties", "chainSpec_v1_properties")
+	}
+
+	/// Register the aliases on `module`.
+	///
+	/// Fails if the method an alias stands for is not part of `module`, or if the alias is
+	/// already taken.
+	pub fn register<Context>(
+		&self,
+		module: &mut RpcModule<Context>,
+	) -> Result<(), RegisterMethodError> {
+		for (alias, target) in &self.aliases {
+			module.register_alias(*alias, *target)?;
+		}
+		Ok(())
+	}
+
+	/// The aliases and the methods they stand for.
+	pub fn aliases(&self) -> impl Iterator<Item = (&'static str, &'static str)> + '_ {
+		self.aliases.iter().copied()
+	}
+
+	/// The deprecated methods and their notes.
+	pub fn deprecations(&self) -> &HashMap<String, String> {
+		&self.deprecations
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::chain_spec::{ChainSpec, ChainSpecApiServer};
+	use jsonrpsee::core::EmptyServerParams as EmptyParams;
+
+	fn api() -> RpcModule<ChainSpec> {
+		ChainSpec::new("TEST_CHAIN_NAME".to_string(), [0; 32], Default::default()).into_rpc()
+	}
+
+	#[tokio::test]
+	async fn deprecated_alias_works() {
+		let aliases = MethodAliases::new().with_chain_spec_unstable();
+		let mut api = api();
+		aliases.register(&mut api).unwrap();
+
+		for method in ["chainSpec_v1_chainName", "chainSpec_unstable_chainName"] {
+			let name = api.call::<_, String>(method, EmptyParams::new()).await.unwrap();
+			assert_eq!(name, "TEST_CHAIN_NAME");
+		}
+
+		assert_eq!(
+			aliases.deprecations().get("chainSpec_unstable_chainName").map(String::as_str),
+			Some("use `chainSpec_v1_chainName` instead"),
+		);
+		assert!(!aliases.deprecations().contains_key("chainSpec_v1_chainName"));
+	}
+
+	#[test]
+	fn alias_of_unknown_method_fails() {
+		let aliases = MethodAliases::new().alias("chainSpec_v2_chainName", "chainSpec_v2_name");
+		assert!(aliases.register(&mut api()).is_err());
+	}
+}
@@ -18,8 +18,10 @@
 
 //! API trait of the archive methods.
 
+#[cfg(doc)]
+use crate::error::ErrorCode;
 use crate::{
-	common::events::{ArchiveStorageResult, PaginatedStorageQuery},
+	common::events::{ArchiveEventsResult, ArchiveStorageResult, PaginatedStorageQuery},
 	MethodResult,
 };
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
@@ -73,6 +75,11 @@ pub trait ArchiveApi<Hash> {
 	/// Returns an array (possibly empty) of strings containing an hexadecimal-encoded hash of a
 	/// block header.
 	///
+	/// # Errors
+	///
+	/// - [`ErrorCode::ArchiveInvalidParam`] if the height does not fit the block numbers.
+	/// - [`ErrorCode::ArchiveFetchLeaves`] if the leaves of the chain can not be fetched.
+	///
 	/// # Unstable
 	///
 	/// This method is unstable and subject to change in the future.
@@ -81,6 +88,10 @@ pub trait ArchiveApi<Hash> {
 
 	/// Call into the Runtime API at a specified block's state.
 	///
+	/// # Errors
+	///
+	/// - [`ErrorCode::ArchiveInvalidParam`] if the call parameters are not hexadecimal.
+	///
 	/// # Unstable
 	///
 	/// This method is unstable and subject to change in the future.
@@ -92,8 +103,33 @@ pub trait ArchiveApi<Hash> {
 		call_parameters: String,
 	) -> RpcResult<MethodResult>;
 
+	/// Call into the Runtime API at the state of several blocks.
+	///
+	/// Takes a list of `(hash, function, call_parameters)` calls, with the same meaning as the
+	/// parameters of `archive_unstable_call`. The calls are executed concurrently and a result is
+	/// returned for every call, in the order of the calls.
+	///
+	/// # Errors
+	///
+	/// - [`ErrorCode::ArchiveInvalidParam`] if there are too many calls or the parameters of a call
+	///   are not hexadecimal.
+	///
+	/// # Unstable
+	///
+	/// This method is unstable and subject to change in the future.
+	#[method(name = "archive_unstable_callBatch")]
+	async fn archive_unstable_call_batch(
+		&self,
+		calls: Vec<(Hash, String, String)>,
+	) -> RpcResult<Vec<MethodResult>>;
+
 	/// Returns storage entries at a specific block's state.
 	///
+	/// # Errors
+	///
+	/// - [`ErrorCode::ArchiveInvalidParam`] if a key or the child trie is not hexadecimal, or a
+	///   pagination start key is given for a query that is not a descendants query.
+	///
 	/// # Unstable
 	///
 	/// This method is unstable and subject to change in the future.
@@ -104,4 +140,22 @@ pub trait ArchiveApi<Hash> {
 		items: Vec<PaginatedStorageQuery<String>>,
 		child_trie: Option<String>,
 	) -> RpcResult<ArchiveStorageResult>;
+
+	/// Get the events of a block.
+	///
+	/// Returns the hexadecimal-encoded SCALE-encoded events together with the spec version of the
+	/// runtime that produced them, so that clients can pick the matching metadata. If the server
+	/// is able to decode the events, their decoded form is included as well. If no block with that
+	/// hash is found, null.
+	///
+	/// # Errors
+	///
+	/// - [`ErrorCode::ArchiveRuntimeCall`] if the runtime version of the block can not be queried.
+	/// - [`ErrorCode::ArchiveFetchEvents`] if the events of the block can not be fetched.
+	///
+	/// # Unstable
+	///
+	/// This method is unstable and subject to change in the future.
+	#[method(name = "archive_unstable_events", blocking)]
+	fn archive_unstable_events(&self, hash: Hash) -> RpcResult<Option<ArchiveEventsResult>>;
 }
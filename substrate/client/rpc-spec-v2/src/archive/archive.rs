@@ -20,11 +20,12 @@
 
 use crate::{
 	archive::{error::Error as ArchiveError, ArchiveApiServer},
-	common::events::{ArchiveStorageResult, PaginatedStorageQuery},
-	hex_string, MethodResult,
+	common::events::{ArchiveEventsResult, ArchiveStorageResult, PaginatedStorageQuery},
+	hex_string, MethodResult, SubscriptionTaskExecutor,
 };
 
-use codec::Encode;
+use codec::{Decode, Encode};
+use futures::{channel::oneshot, FutureExt, StreamExt};
 use jsonrpsee::core::{async_trait, RpcResult};
 use sc_client_api::{
 	Backend, BlockBackend, BlockchainEvents, CallExecutor, ChildInfo, ExecutorProvider, StorageKey,
@@ -40,8 +41,15 @@ use sp_runtime::{
 	SaturatedConversion,
 };
 use std::{collections::HashSet, marker::PhantomData, sync::Arc};
+use tokio::sync::Semaphore;
 
-use super::archive_storage::ArchiveStorage;
+use super::{
+	archive_events::{events_key, EventsDecoder, MetadataCache},
+	archive_storage::ArchiveStorage,
+};
+
+/// The log target of the archive methods.
+const LOG_TARGET: &str = "rpc-spec-v2::archive";
 
 /// The configuration of [`Archive`].
 pub struct ArchiveConfig {
@@ -50,6 +58,13 @@ pub struct ArchiveConfig {
 	pub max_descendant_responses: usize,
 	/// The maximum number of queried items allowed for the `archive_storage` at a time.
 	pub max_queried_items: usize,
+	/// The maximum number of runtime versions the metadata is cached of, for decoding events.
+	pub max_cached_metadata: usize,
+	/// The maximum number of calls accepted by `archive_callBatch` at a time.
+	pub max_batched_calls: usize,
+	/// The maximum number of calls of `archive_callBatch` executed concurrently, across all the
+	/// batches of the server.
+	pub max_concurrent_calls: usize,
 }
 
 /// The maximum number of items the `archive_storage` can return for a descendant query before
@@ -64,11 +79,24 @@ const MAX_DESCENDANT_RESPONSES: usize = 5;
 /// `MAX_DESCENDANT_RESPONSES`.
 const MAX_QUERIED_ITEMS: usize = 8;
 
+/// The maximum number of runtime versions the metadata is cached of, for decoding events.
+const MAX_CACHED_METADATA: usize = 4;
+
+/// The maximum number of calls accepted by `archive_callBatch` at a time.
+const MAX_BATCHED_CALLS: usize = 64;
+
+/// The maximum number of calls of `archive_callBatch` executed concurrently, across all the
+/// batches of the server.
+const MAX_CONCURRENT_CALLS: usize = 4;
+
 impl Default for ArchiveConfig {
 	fn default() -> Self {
 		Self {
 			max_descendant_responses: MAX_DESCENDANT_RESPONSES,
 			max_queried_items: MAX_QUERIED_ITEMS,
+			max_cached_metadata: MAX_CACHED_METADATA,
+			max_batched_calls: MAX_BATCHED_CALLS,
+			max_concurrent_calls: MAX_CONCURRENT_CALLS,
 		}
 	}
 }
@@ -79,6 +107,8 @@ pub struct Archive<BE: Backend<Block>, Block: BlockT, Client> {
 	client: Arc<Client>,
 	/// Backend of the chain.
 	backend: Arc<BE>,
+	/// Executor to spawn the calls of `archive_callBatch`.
+	executor: SubscriptionTaskExecutor,
 	/// The hexadecimal encoded hash of the genesis block.
 	genesis_hash: String,
 	/// The maximum number of items the `archive_storage` can return for a descendant query before
@@ -86,6 +116,16 @@ pub struct Archive<BE: Backend<Block>, Block: BlockT, Client> {
 	storage_max_descendant_responses: usize,
 	/// The maximum number of queried items allowed for the `archive_storage` at a time.
 	storage_max_queried_items: usize,
+	/// The maximum number of calls accepted by `archive_callBatch` at a time.
+	call_batch_max_calls: usize,
+	/// The maximum number of calls of `archive_callBatch` executed concurrently.
+	call_batch_max_concurrency: usize,
+	/// Limits the calls of `archive_callBatch` executed concurrently across all the batches.
+	call_batch_permits: Arc<Semaphore>,
+	/// Decodes the events of `archive_unstable_events`, if configured.
+	events_decoder: Option<Arc<dyn EventsDecoder>>,
+	/// The metadata of recently used runtime versions, for decoding events.
+	metadata_cache: MetadataCache,
 	/// Phantom member to pin the block type.
 	_phantom: PhantomData<Block>,
 }
@@ -95,19 +135,34 @@ impl<BE: Backend<Block>, Block: BlockT, Client> Archive<BE, Block, Client> {
 	pub fn new<GenesisHash: AsRef<[u8]>>(
 		client: Arc<Client>,
 		backend: Arc<BE>,
+		executor: SubscriptionTaskExecutor,
 		genesis_hash: GenesisHash,
 		config: ArchiveConfig,
 	) -> Self {
 		let genesis_hash = hex_string(&genesis_hash.as_ref());
+		// At least one call must be executed at a time to make progress.
+		let call_batch_max_concurrency = config.max_concurrent_calls.max(1);
 		Self {
 			client,
 			backend,
+			executor,
 			genesis_hash,
 			storage_max_descendant_responses: config.max_descendant_responses,
 			storage_max_queried_items: config.max_queried_items,
+			call_batch_max_calls: config.max_batched_calls,
+			call_batch_max_concurrency,
+			call_batch_permits: Arc::new(Semaphore::new(call_batch_max_concurrency)),
+			events_decoder: None,
+			metadata_cache: MetadataCache::new(config.max_cached_metadata),
 			_phantom: PhantomData,
 		}
 	}
+
+	/// Decode the events returned by `archive_unstable_events` with `decoder`.
+	pub fn with_events_decoder(mut self, decoder: Arc<dyn EventsDecoder>) -> Self {
+		self.events_decoder = Some(decoder);
+		self
+	}
 }
 
 /// Parse hex-encoded string parameter as raw bytes.
@@ -122,6 +177,23 @@ fn parse_hex_param(param: String) -> Result<Vec<u8>, ArchiveError> {
 	array_bytes::hex2bytes(&param).map_err(|_| ArchiveError::InvalidParam(param))
 }
 
+/// Call the runtime API `function` at the state of the block `hash`.
+fn call_runtime_api<Block, Client>(
+	client: &Client,
+	hash: Block::Hash,
+	function: &str,
+	call_parameters: &[u8],
+) -> MethodResult
+where
+	Block: BlockT,
+	Client: ExecutorProvider<Block>,
+{
+	match client.executor().call(hash, function, call_parameters, CallContext::Offchain) {
+		Ok(result) => MethodResult::ok(hex_string(&result)),
+		Err(error) => MethodResult::err(error.to_string()),
+	}
+}
+
 #[async_trait]
 impl<BE, Block, Client> ArchiveApiServer<Block::Hash> for Archive<BE, Block, Client>
 where
@@ -223,15 +295,61 @@ where
 	) -> RpcResult<MethodResult> {
 		let call_parameters = Bytes::from(parse_hex_param(call_parameters)?);
 
-		let result =
-			self.client
-				.executor()
-				.call(hash, &function, &call_parameters, CallContext::Offchain);
+		Ok(call_runtime_api::<Block, _>(&*self.client, hash, &function, &call_parameters))
+	}
+
+	async fn archive_unstable_call_batch(
+		&self,
+		calls: Vec<(Block::Hash, String, String)>,
+	) -> RpcResult<Vec<MethodResult>> {
+		if calls.len() > self.call_batch_max_calls {
+			return Err(ArchiveError::InvalidParam(format!(
+				"At most {} calls can be batched",
+				self.call_batch_max_calls
+			))
+			.into())
+		}
+
+		let results = futures::stream::iter(calls)
+			.map(|(hash, function, call_parameters)| {
+				let client = self.client.clone();
+				let executor = self.executor.clone();
+				let permits = self.call_batch_permits.clone();
+				async move {
+					let call_parameters = match parse_hex_param(call_parameters) {
+						Ok(call_parameters) => call_parameters,
+						Err(error) => return MethodResult::err(error.to_string()),
+					};
+
+					// The permit is shared by all the batches and held until the call is done,
+					// which bounds the calls executed concurrently by the server.
+					let Ok(permit) = permits.acquire_owned().await else {
+						return MethodResult::err("Call was cancelled")
+					};
+
+					let (tx, rx) = oneshot::channel();
+					let fut = async move {
+						let result = call_runtime_api::<Block, _>(
+							&*client,
+							hash,
+							&function,
+							&call_parameters,
+						);
+						let _ = tx.send(result);
+						drop(permit);
+					};
+					executor.spawn_blocking("archive-call-batch", Some("rpc"), fut.boxed());
+
+					rx.await.unwrap_or_else(|_| MethodResult::err("Call was cancelled"))
+				}
+			})
+			// Results are returned in order, so a batch never waits on more calls than could
+			// be executed at a time.
+			.buffered(self.call_batch_max_concurrency)
+			.collect()
+			.await;
 
-		Ok(match result {
-			Ok(result) => MethodResult::ok(hex_string(&result)),
-			Err(error) => MethodResult::err(error.to_string()),
-		})
+		Ok(results)
 	}
 
 	fn archive_unstable_storage(
@@ -277,4 +395,57 @@ where
 		);
 		Ok(storage_client.handle_query(hash, items, child_trie))
 	}
+
+	fn archive_unstable_events(&self, hash: Block::Hash) -> RpcResult<Option<ArchiveEventsResult>> {
+		let Ok(Some(_)) = self.client.header(hash) else { return Ok(None) };
+
+		let spec_version = self
+			.client
+			.runtime_version_at(hash)
+			.map_err(|error| ArchiveError::RuntimeCall(error.to_string()))?
+			.spec_version;
+		let events = self
+			.client
+			.storage(hash, &StorageKey(events_key()))
+			.map_err(|error| ArchiveError::FetchEvents(error.to_string()))?
+			.map(|events| events.0)
+			.unwrap_or_default();
+
+		let decoded = self.events_decoder.as_ref().and_then(|decoder| {
+			let metadata = self
+				.metadata_cache
+				.get_or_fetch(spec_version, || self.fetch_metadata(hash))
+				.map_err(|error| {
+					log::debug!(
+						target: LOG_TARGET,
+						"Failed to fetch metadata of spec version {}: {}",
+						spec_version,
+						error
+					);
+				})
+				.ok()?;
+			decoder.decode(&metadata, &events)
+		});
+
+		Ok(Some(ArchiveEventsResult { events: hex_string(&events), spec_version, decoded }))
+	}
+}
+
+impl<BE, Block, Client> Archive<BE, Block, Client>
+where
+	Block: BlockT + 'static,
+	BE: Backend<Block> + 'static,
+	Client: ExecutorProvider<Block> + 'static,
+{
+	/// Fetch the SCALE-encoded metadata of the runtime at `hash`.
+	fn fetch_metadata(&self, hash: Block::Hash) -> Result<Vec<u8>, ArchiveError> {
+		let result = self
+			.client
+			.executor()
+			.call(hash, "Metadata_metadata", &[], CallContext::Offchain)
+			.map_err(|error| ArchiveError::RuntimeCall(error.to_string()))?;
+		// The runtime returns the metadata as SCALE-encoded `OpaqueMetadata`.
+		Vec::<u8>::decode(&mut &result[..])
+			.map_err(|error| ArchiveError::RuntimeCall(error.to_string()))
+	}
 }
@@ -0,0 +1,119 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Implementation of the `archive_unstable_events` method.
+
+use std::{collections::VecDeque, sync::Arc};
+
+use parking_lot::Mutex;
+
+/// Decodes the events of a block with the metadata of the runtime that produced them.
+pub trait EventsDecoder: Send + Sync {
+	/// Decode the SCALE-encoded `events` with the SCALE-encoded `metadata`.
+	///
+	/// Returns `None` if the events can't be decoded with the given metadata.
+	fn decode(&self, metadata: &[u8], events: &[u8]) -> Option<serde_json::Value>;
+}
+
+/// The storage key of the events of a block, `System::Events`.
+pub fn events_key() -> Vec<u8> {
+	[sp_crypto_hashing::twox_128(b"System"), sp_crypto_hashing::twox_128(b"Events")].concat()
+}
+
+/// The metadata of the most recently used runtime versions, by spec version.
+pub struct MetadataCache {
+	/// The maximum number of runtime versions to keep the metadata of.
+	max_entries: usize,
+	/// The cached metadata, most recently used last.
+	entries: Mutex<VecDeque<(u32, Arc<Vec<u8>>)>>,
+}
+
+impl MetadataCache {
+	/// Create a new [`MetadataCache`] holding the metadata of at most `max_entries` runtimes.
+	pub fn new(max_entries: usize) -> Self {
+		Self { max_entries, entries: Default::default() }
+	}
+
+	/// Get the metadata of `spec_version`, calling `fetch` to obtain it if it is not cached.
+	pub fn get_or_fetch<E>(
+		&self,
+		spec_version: u32,
+		fetch: impl FnOnce() -> Result<Vec<u8>, E>,
+	) -> Result<Arc<Vec<u8>>, E> {
+		if let Some(metadata) = self.get(spec_version) {
+			return Ok(metadata)
+		}
+
+		// Fetching the metadata executes the runtime, don't hold the lock meanwhile.
+		let metadata = Arc::new(fetch()?);
+		if self.max_entries > 0 {
+			let mut entries = self.entries.lock();
+			entries.retain(|(version, _)| *version != spec_version);
+			if entries.len() >= self.max_entries {
+				entries.pop_front();
+			}
+			entries.push_back((spec_version, metadata.clone()));
+		}
+		Ok(metadata)
+	}
+
+	/// Get the cached metadata of `spec_version`.
+	fn get(&self, spec_version: u32) -> Option<Arc<Vec<u8>>> {
+		let mut entries = self.entries.lock();
+		let position = entries.iter().position(|(version, _)| *version == spec_version)?;
+		let entry = entries.remove(position)?;
+		let metadata = entry.1.clone();
+		entries.push_back(entry);
+		Some(metadata)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn fetch(metadata: &[u8]) -> impl FnOnce() -> Result<Vec<u8>, ()> + '_ {
+		move || Ok(metadata.to_vec())
+	}
+
+	#[test]
+	fn metadata_is_cached_per_spec_version() {
+		let cache = MetadataCache::new(2);
+		assert_eq!(*cache.get_or_fetch(1, fetch(b"v1")).unwrap(), b"v1");
+		assert_eq!(*cache.get_or_fetch(2, fetch(b"v2")).unwrap(), b"v2");
+
+		// Cached entries are not fetched again.
+		assert_eq!(
+			*cache.get_or_fetch(1, || -> Result<Vec<u8>, ()> { panic!("cached") }).unwrap(),
+			b"v1"
+		);
+
+		// The least recently used entry is evicted.
+		assert_eq!(*cache.get_or_fetch(3, fetch(b"v3")).unwrap(), b"v3");
+		assert!(cache.get(2).is_none());
+		assert!(cache.get(1).is_some());
+		assert!(cache.get(3).is_some());
+	}
+
+	#[test]
+	fn failed_fetches_are_not_cached() {
+		let cache = MetadataCache::new(2);
+		assert!(cache.get_or_fetch(1, || Err(())).is_err());
+		assert!(cache.get(1).is_none());
+	}
+}
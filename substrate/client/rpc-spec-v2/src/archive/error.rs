@@ -18,6 +18,7 @@
 
 //! Error helpers for `archive` RPC module.
 
+use crate::error::ErrorCode;
 use jsonrpsee::types::error::ErrorObject;
 
 /// ChainHead RPC errors.
@@ -32,26 +33,21 @@ pub enum Error {
 	/// Failed to fetch leaves.
 	#[error("Failed to fetch leaves of the chain: {0}")]
 	FetchLeaves(String),
+	/// Failed to fetch events.
+	#[error("Failed to fetch events of the block: {0}")]
+	FetchEvents(String),
 }
 
-// Base code for all `archive` errors.
-const BASE_ERROR: i32 = 3000;
-/// Invalid parameter error.
-const INVALID_PARAM_ERROR: i32 = BASE_ERROR + 1;
-/// Runtime call error.
-const RUNTIME_CALL_ERROR: i32 = BASE_ERROR + 2;
-/// Failed to fetch leaves.
-const FETCH_LEAVES_ERROR: i32 = BASE_ERROR + 3;
-
 impl From<Error> for ErrorObject<'static> {
 	fn from(e: Error) -> Self {
 		let msg = e.to_string();
 
 		match e {
-			Error::InvalidParam(_) => ErrorObject::owned(INVALID_PARAM_ERROR, msg, None::<()>),
-			Error::RuntimeCall(_) => ErrorObject::owned(RUNTIME_CALL_ERROR, msg, None::<()>),
-			Error::FetchLeaves(_) => ErrorObject::owned(FETCH_LEAVES_ERROR, msg, None::<()>),
+			Error::InvalidParam(param) =>
+				ErrorCode::ArchiveInvalidParam.error_object(msg, Some(param)),
+			Error::RuntimeCall(err) => ErrorCode::ArchiveRuntimeCall.error_object(msg, Some(err)),
+			Error::FetchLeaves(err) => ErrorCode::ArchiveFetchLeaves.error_object(msg, Some(err)),
+			Error::FetchEvents(err) => ErrorCode::ArchiveFetchEvents.error_object(msg, Some(err)),
 		}
-		.into()
 	}
 }
@@ -25,6 +25,7 @@
 #[cfg(test)]
 mod tests;
 
+mod archive_events;
 mod archive_storage;
 
 pub mod api;
@@ -33,3 +34,4 @@ pub mod error;
 
 pub use api::ArchiveApiServer;
 pub use archive::{Archive, ArchiveConfig};
+pub use archive_events::EventsDecoder;
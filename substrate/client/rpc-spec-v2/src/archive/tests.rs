@@ -18,9 +18,10 @@
 
 use crate::{
 	common::events::{
-		ArchiveStorageMethodOk, ArchiveStorageResult, PaginatedStorageQuery, StorageQueryType,
-		StorageResultType,
+		ArchiveEventsResult, ArchiveStorageMethodOk, ArchiveStorageResult, PaginatedStorageQuery,
+		StorageQueryType, StorageResultType,
 	},
+	error::{ErrorCode, ErrorData},
 	hex_string, MethodResult,
 };
 
@@ -35,10 +36,10 @@ use jsonrpsee::{
 	core::EmptyServerParams as EmptyParams, rpc_params, MethodsError as Error, RpcModule,
 };
 use sc_block_builder::BlockBuilderBuilder;
-use sc_client_api::ChildInfo;
+use sc_client_api::{ChildInfo, StorageProvider};
 use sp_blockchain::HeaderBackend;
 use sp_consensus::BlockOrigin;
-use sp_core::{Blake2Hasher, Hasher};
+use sp_core::{testing::TaskExecutor, Blake2Hasher, Hasher};
 use sp_runtime::{
 	traits::{Block as BlockT, Header as HeaderT},
 	SaturatedConversion,
@@ -77,8 +78,9 @@ fn setup_api(
 	let api = Archive::new(
 		client.clone(),
 		backend,
+		Arc::new(TaskExecutor::default()),
 		CHAIN_GENESIS,
-		ArchiveConfig { max_descendant_responses, max_queried_items },
+		ArchiveConfig { max_descendant_responses, max_queried_items, ..Default::default() },
 	)
 	.into_rpc();
 
@@ -293,7 +295,7 @@ async fn archive_call() {
 		)
 		.await
 		.unwrap_err();
-	assert_matches!(err, Error::JsonRpc(err) if err.code() == 3001 && err.message().contains("Invalid parameter"));
+	assert_matches!(err, Error::JsonRpc(err) if err.code() == 3001 && ErrorData::of(&err).unwrap().kind == ErrorCode::ArchiveInvalidParam);
 
 	// Pass an invalid parameters that cannot be decode.
 	let err = api
@@ -304,7 +306,7 @@ async fn archive_call() {
 		)
 		.await
 		.unwrap_err();
-	assert_matches!(err, Error::JsonRpc(err) if err.code() == 3001 && err.message().contains("Invalid parameter"));
+	assert_matches!(err, Error::JsonRpc(err) if err.code() == 3001 && ErrorData::of(&err).unwrap().kind == ErrorCode::ArchiveInvalidParam);
 
 	// Invalid hash.
 	let result: MethodResult = api
@@ -339,6 +341,56 @@ async fn archive_call() {
 	assert_eq!(result, expected);
 }
 
+#[tokio::test]
+async fn archive_call_batch() {
+	let (mut client, api) = setup_api(MAX_PAGINATION_LIMIT, MAX_QUERIED_LIMIT);
+	let invalid_hash = hex_string(&INVALID_HASH);
+
+	let block_1 = BlockBuilderBuilder::new(&*client)
+		.on_parent_block(client.chain_info().genesis_hash)
+		.with_parent_block_number(0)
+		.build()
+		.unwrap()
+		.build()
+		.unwrap()
+		.block;
+	let block_1_hash = format!("{:?}", block_1.header.hash());
+	client.import(BlockOrigin::Own, block_1.clone()).await.unwrap();
+
+	let call_parameters = hex_string(&AccountKeyring::Alice.to_account_id().encode());
+	let call = |hash: &str, call_parameters: &str| {
+		(hash.to_string(), "AccountNonceApi_account_nonce".to_string(), call_parameters.to_string())
+	};
+
+	// Every call gets its own result, in order.
+	let results: Vec<MethodResult> = api
+		.call(
+			"archive_unstable_callBatch",
+			rpc_params![vec![
+				call(&block_1_hash, &call_parameters),
+				call(&invalid_hash, &call_parameters),
+				call(&block_1_hash, "0x0"),
+				call(&block_1_hash, &call_parameters),
+			]],
+		)
+		.await
+		.unwrap();
+	assert_eq!(results.len(), 4);
+	assert_eq!(results[0], MethodResult::ok("0x0000000000000000"));
+	assert_matches!(&results[1], MethodResult::Err(_));
+	assert_matches!(&results[2], MethodResult::Err(err) if err.error.contains("Invalid parameter"));
+	assert_eq!(results[3], MethodResult::ok("0x0000000000000000"));
+
+	// Too many calls.
+	let calls =
+		vec![call(&block_1_hash, &call_parameters); ArchiveConfig::default().max_batched_calls + 1];
+	let err = api
+		.call::<_, Vec<MethodResult>>("archive_unstable_callBatch", rpc_params![calls])
+		.await
+		.unwrap_err();
+	assert_matches!(err, Error::JsonRpc(err) if err.code() == 3001 && ErrorData::of(&err).unwrap().kind == ErrorCode::ArchiveInvalidParam);
+}
+
 #[tokio::test]
 async fn archive_storage_hashes_values() {
 	let (mut client, api) = setup_api(MAX_PAGINATION_LIMIT, MAX_QUERIED_LIMIT);
@@ -838,3 +890,82 @@ async fn archive_storage_discarded_items() {
 		_ => panic!("Unexpected result"),
 	};
 }
+
+/// Reports the size of the metadata and the events it was called with.
+#[derive(Default)]
+struct TestEventsDecoder {
+	calls: std::sync::atomic::AtomicUsize,
+}
+
+impl EventsDecoder for TestEventsDecoder {
+	fn decode(&self, metadata: &[u8], events: &[u8]) -> Option<serde_json::Value> {
+		self.calls.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+		Some(serde_json::json!({ "metadataLen": metadata.len(), "eventsLen": events.len() }))
+	}
+}
+
+#[tokio::test]
+async fn archive_events() {
+	let builder = TestClientBuilder::new();
+	let backend = builder.backend();
+	let mut client = Arc::new(builder.build());
+	let decoder = Arc::new(TestEventsDecoder::default());
+	let api = Archive::new(
+		client.clone(),
+		backend,
+		Arc::new(TaskExecutor::default()),
+		CHAIN_GENESIS,
+		ArchiveConfig::default(),
+	)
+	.with_events_decoder(decoder.clone())
+	.into_rpc();
+
+	// Invalid block hash.
+	let invalid_hash = hex_string(&INVALID_HASH);
+	let res: Option<ArchiveEventsResult> =
+		api.call("archive_unstable_events", [invalid_hash]).await.unwrap();
+	assert!(res.is_none());
+
+	// Import a new block with an extrinsic.
+	let mut builder = BlockBuilderBuilder::new(&*client)
+		.on_parent_block(client.chain_info().genesis_hash)
+		.with_parent_block_number(0)
+		.build()
+		.unwrap();
+	builder
+		.push_transfer(runtime::Transfer {
+			from: AccountKeyring::Alice.into(),
+			to: AccountKeyring::Ferdie.into(),
+			amount: 42,
+			nonce: 0,
+		})
+		.unwrap();
+	let block = builder.build().unwrap().block;
+	let block_hash = format!("{:?}", block.header.hash());
+	client.import(BlockOrigin::Own, block.clone()).await.unwrap();
+
+	let expected_events = client
+		.storage(block.header.hash(), &sc_client_api::StorageKey(archive_events::events_key()))
+		.unwrap()
+		.map(|events| events.0)
+		.unwrap_or_default();
+
+	let result: ArchiveEventsResult =
+		api.call("archive_unstable_events", [&block_hash]).await.unwrap();
+	assert_eq!(result.events, hex_string(&expected_events));
+	assert_eq!(result.spec_version, runtime::VERSION.spec_version);
+	let decoded = result.decoded.unwrap();
+	assert!(decoded["metadataLen"].as_u64().unwrap() > 0);
+	assert_eq!(decoded["eventsLen"].as_u64().unwrap(), expected_events.len() as u64);
+
+	// The metadata of the runtime is cached, the decoder is called for every request.
+	let _: ArchiveEventsResult = api.call("archive_unstable_events", [&block_hash]).await.unwrap();
+	assert_eq!(decoder.calls.load(std::sync::atomic::Ordering::Relaxed), 2);
+
+	// Without a decoder only the encoded events are returned.
+	let (_client, api) = setup_api(MAX_PAGINATION_LIMIT, MAX_QUERIED_LIMIT);
+	let genesis_hash = format!("{:?}", client.chain_info().genesis_hash);
+	let result: ArchiveEventsResult =
+		api.call("archive_unstable_events", [&genesis_hash]).await.unwrap();
+	assert!(result.decoded.is_none());
+}
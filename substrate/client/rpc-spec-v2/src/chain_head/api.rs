@@ -19,6 +19,8 @@
 #![allow(non_snake_case)]
 
 //! API trait of the chain head.
+#[cfg(doc)]
+use crate::error::ErrorCode;
 use crate::{
 	chain_head::{
 		error::Error,
@@ -51,6 +53,10 @@ pub trait ChainHeadApi<Hash> {
 	///
 	/// Use `archive_unstable_body` if instead you want to retrieve the body of an arbitrary block.
 	///
+	/// # Errors
+	///
+	/// - [`ErrorCode::InvalidBlock`] if the block is not pinned by the subscription.
+	///
 	/// # Unstable
 	///
 	/// This method is unstable and subject to change in the future.
@@ -70,6 +76,10 @@ pub trait ChainHeadApi<Hash> {
 	/// Use `archive_unstable_header` if instead you want to retrieve the header of an arbitrary
 	/// block.
 	///
+	/// # Errors
+	///
+	/// - [`ErrorCode::InvalidBlock`] if the block is not pinned by the subscription.
+	///
 	/// # Unstable
 	///
 	/// This method is unstable and subject to change in the future.
@@ -82,6 +92,11 @@ pub trait ChainHeadApi<Hash> {
 
 	/// Returns storage entries at a specific block's state.
 	///
+	/// # Errors
+	///
+	/// - [`ErrorCode::InvalidBlock`] if the block is not pinned by the subscription.
+	/// - [`ErrorCode::InvalidParam`] if a key or the child trie is not hexadecimal.
+	///
 	/// # Unstable
 	///
 	/// This method is unstable and subject to change in the future.
@@ -94,8 +109,37 @@ pub trait ChainHeadApi<Hash> {
 		child_trie: Option<String>,
 	) -> ResponsePayload<'static, MethodResponse>;
 
+	/// Watch storage entries of the head of the chain.
+	///
+	/// The values of the `keys` are reported by `operationStorageChanged` events at the next
+	/// best and finalized blocks, and afterwards whenever any of them changes in a new best or
+	/// finalized block. The watch lasts until it is stopped with
+	/// `chainHead_unstable_stopOperation` or the `follow` subscription ends.
+	///
+	/// # Errors
+	///
+	/// - [`ErrorCode::InvalidParam`] if a key or the child trie is not hexadecimal.
+	///
+	/// # Unstable
+	///
+	/// This method is unstable and subject to change in the future.
+	#[method(name = "chainHead_unstable_storageWatch", blocking)]
+	fn chain_head_unstable_storage_watch(
+		&self,
+		follow_subscription: String,
+		keys: Vec<String>,
+		child_trie: Option<String>,
+	) -> ResponsePayload<'static, MethodResponse>;
+
 	/// Call into the Runtime API at a specified block's state.
 	///
+	/// # Errors
+	///
+	/// - [`ErrorCode::InvalidBlock`] if the block is not pinned by the subscription.
+	/// - [`ErrorCode::InvalidRuntimeCall`] if the subscription was started with `withRuntime` set
+	///   to `false`.
+	/// - [`ErrorCode::InvalidParam`] if the call parameters are not hexadecimal.
+	///
 	/// # Unstable
 	///
 	/// This method is unstable and subject to change in the future.
@@ -115,6 +159,11 @@ pub trait ChainHeadApi<Hash> {
 	///
 	/// When this method returns an error, it is guaranteed that no blocks have been unpinned.
 	///
+	/// # Errors
+	///
+	/// - [`ErrorCode::InvalidBlock`] if a block is not pinned by the subscription.
+	/// - [`ErrorCode::InvalidDuplicateHashes`] if a block is provided more than once.
+	///
 	/// # Unstable
 	///
 	/// This method is unstable and subject to change in the future.
@@ -128,6 +177,10 @@ pub trait ChainHeadApi<Hash> {
 	/// Resumes a storage fetch started with `chainHead_storage` after it has generated an
 	/// `operationWaitingForContinue` event.
 	///
+	/// # Errors
+	///
+	/// - [`ErrorCode::InvalidContinue`] if the operation is not waiting for a continue.
+	///
 	/// # Unstable
 	///
 	/// This method is unstable and subject to change in the future.
@@ -138,9 +191,10 @@ pub trait ChainHeadApi<Hash> {
 		operation_id: String,
 	) -> Result<(), Error>;
 
-	/// Stops an operation started with chainHead_unstable_body, chainHead_unstable_call, or
-	/// chainHead_unstable_storage. If the operation was still in progress, this interrupts it. If
-	/// the operation was already finished, this call has no effect.
+	/// Stops an operation started with chainHead_unstable_body, chainHead_unstable_call,
+	/// chainHead_unstable_storage or chainHead_unstable_storageWatch. If the operation was still in
+	/// progress, this interrupts it. If the operation was already finished, this call has no
+	/// effect.
 	///
 	/// # Unstable
 	///
@@ -20,7 +20,7 @@
 
 use super::{
 	chain_head_storage::ChainHeadStorage,
-	event::{MethodResponseStarted, OperationBodyDone, OperationCallDone},
+	event::{MethodResponseStarted, OperationBodyDone, OperationCallDone, OperationId},
 };
 use crate::{
 	chain_head::{
@@ -28,7 +28,9 @@ use crate::{
 		chain_head_follow::ChainHeadFollower,
 		error::Error as ChainHeadRpcError,
 		event::{FollowEvent, MethodResponse, OperationError},
-		subscription::{SubscriptionManagement, SubscriptionManagementError},
+		subscription::{
+			BlockGuard, StorageWatch, SubscriptionManagement, SubscriptionManagementError,
+		},
 	},
 	common::events::StorageQuery,
 	hex_string, SubscriptionTaskExecutor,
@@ -49,8 +51,9 @@ use sp_api::CallApiAt;
 use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
 use sp_core::{traits::CallContext, Bytes};
 use sp_rpc::list::ListOrValue;
-use sp_runtime::traits::Block as BlockT;
-use std::{marker::PhantomData, sync::Arc, time::Duration};
+use sp_runtime::traits::{Block as BlockT, NumberFor, Saturating};
+use std::{fmt::Debug, marker::PhantomData, sync::Arc, time::Duration};
+use tracing::Instrument;
 
 pub(crate) const LOG_TARGET: &str = "rpc-spec-v2";
 
@@ -59,12 +62,30 @@ pub struct ChainHeadConfig {
 	/// The maximum number of pinned blocks across all subscriptions.
 	pub global_max_pinned_blocks: usize,
 	/// The maximum duration that a block is allowed to be pinned per subscription.
+	///
+	/// When the global limit of pinned blocks is reached, blocks pinned for longer
+	/// are evicted and reported via the `pinnedBlocksEvicted` event.
 	pub subscription_max_pinned_duration: Duration,
 	/// The maximum number of ongoing operations per subscription.
 	pub subscription_max_ongoing_operations: usize,
 	/// The maximum number of items reported by the `chainHead_storage` before
 	/// pagination is required.
 	pub operation_max_storage_items: usize,
+	/// The maximum number of bytes of a storage value reported by a single
+	/// `operationStorageItems` event.
+	///
+	/// Larger values are split into chunks that are reported by consecutive events, each
+	/// carrying the offset of the chunk within the value. Values are never split if `None`.
+	pub operation_storage_value_chunk_size: Option<usize>,
+	/// The maximum number of storage keys watched by `chainHead_storageWatch` per subscription.
+	pub subscription_max_watched_keys: usize,
+	/// The number of finalized blocks behind the last finalized block that `chainHead_body`,
+	/// `chainHead_header`, `chainHead_storage` and `chainHead_call` accept without being pinned
+	/// by the subscription.
+	///
+	/// This is best-effort: operations on such blocks generate the `operationInaccessible`
+	/// event if their body or state was already pruned. Only pinned blocks are accepted if zero.
+	pub finalized_lookback_depth: u32,
 }
 
 /// Maximum pinned blocks across all connections.
@@ -86,6 +107,12 @@ const MAX_ONGOING_OPERATIONS: usize = 16;
 /// before paginations is required.
 const MAX_STORAGE_ITER_ITEMS: usize = 5;
 
+/// The maximum number of storage keys watched per subscription.
+const MAX_WATCHED_KEYS: usize = 64;
+
+/// Only blocks pinned by the subscription are accepted by default, as required by the spec.
+const FINALIZED_LOOKBACK_DEPTH: u32 = 0;
+
 impl Default for ChainHeadConfig {
 	fn default() -> Self {
 		ChainHeadConfig {
@@ -93,6 +120,9 @@ impl Default for ChainHeadConfig {
 			subscription_max_pinned_duration: MAX_PINNED_DURATION,
 			subscription_max_ongoing_operations: MAX_ONGOING_OPERATIONS,
 			operation_max_storage_items: MAX_STORAGE_ITER_ITEMS,
+			operation_storage_value_chunk_size: None,
+			subscription_max_watched_keys: MAX_WATCHED_KEYS,
+			finalized_lookback_depth: FINALIZED_LOOKBACK_DEPTH,
 		}
 	}
 }
@@ -110,6 +140,13 @@ pub struct ChainHead<BE: Backend<Block>, Block: BlockT, Client> {
 	/// The maximum number of items reported by the `chainHead_storage` before
 	/// pagination is required.
 	operation_max_storage_items: usize,
+	/// The maximum number of bytes of a storage value reported by a single
+	/// `operationStorageItems` event.
+	operation_storage_value_chunk_size: Option<usize>,
+	/// The maximum number of storage keys watched by `chainHead_storageWatch` per subscription.
+	subscription_max_watched_keys: usize,
+	/// The number of finalized blocks operations can look back at without pinning them.
+	finalized_lookback_depth: u32,
 	/// Phantom member to pin the block type.
 	_phantom: PhantomData<Block>,
 }
@@ -133,11 +170,56 @@ impl<BE: Backend<Block>, Block: BlockT, Client> ChainHead<BE, Block, Client> {
 				backend,
 			)),
 			operation_max_storage_items: config.operation_max_storage_items,
+			operation_storage_value_chunk_size: config.operation_storage_value_chunk_size,
+			subscription_max_watched_keys: config.subscription_max_watched_keys,
+			finalized_lookback_depth: config.finalized_lookback_depth,
 			_phantom: PhantomData,
 		}
 	}
 }
 
+impl<BE, Block, Client> ChainHead<BE, Block, Client>
+where
+	Block: BlockT + 'static,
+	BE: Backend<Block> + 'static,
+	Client: HeaderBackend<Block>,
+{
+	/// Lock `hash` for an operation of the subscription.
+	///
+	/// Besides the blocks pinned by the subscription, this accepts the finalized blocks within
+	/// the `finalized_lookback_depth` of the last finalized block.
+	fn lock_block(
+		&self,
+		follow_subscription: &str,
+		hash: Block::Hash,
+		to_reserve: usize,
+	) -> Result<BlockGuard<Block, BE>, SubscriptionManagementError> {
+		match self.subscriptions.lock_block(follow_subscription, hash, to_reserve) {
+			Err(SubscriptionManagementError::BlockHashAbsent)
+				if self.is_recently_finalized(hash) =>
+				self.subscriptions.lock_unpinned_ancestor(follow_subscription, hash, to_reserve),
+			result => result,
+		}
+	}
+
+	/// Whether `hash` is a finalized block within the `finalized_lookback_depth` of the last
+	/// finalized block.
+	fn is_recently_finalized(&self, hash: Block::Hash) -> bool {
+		if self.finalized_lookback_depth == 0 {
+			return false
+		}
+
+		let Ok(Some(number)) = self.client.number(hash) else { return false };
+		let info = self.client.info();
+		let depth: NumberFor<Block> = self.finalized_lookback_depth.into();
+
+		number <= info.finalized_number &&
+			info.finalized_number.saturating_sub(number) <= depth &&
+			// Rule out blocks of discarded forks.
+			self.client.hash(number).ok().flatten() == Some(hash)
+	}
+}
+
 /// Helper to convert the `subscription ID` to a string.
 pub fn read_subscription_id_as_string(sink: &SubscriptionSink) -> String {
 	match sink.subscription_id() {
@@ -220,7 +302,7 @@ where
 		follow_subscription: String,
 		hash: Block::Hash,
 	) -> ResponsePayload<'static, MethodResponse> {
-		let mut block_guard = match self.subscriptions.lock_block(&follow_subscription, hash, 1) {
+		let mut block_guard = match self.lock_block(&follow_subscription, hash, 1) {
 			Ok(block) => block,
 			Err(SubscriptionManagementError::SubscriptionAbsent) |
 			Err(SubscriptionManagementError::ExceededLimits) =>
@@ -233,8 +315,15 @@ where
 		};
 
 		let operation_id = block_guard.operation().operation_id();
+		let span =
+			operation_span("chainHead_unstable_body", &follow_subscription, &operation_id, &hash);
+		let _enter = span.enter();
 
 		let event = match self.client.block(hash) {
+			_ if !block_guard.is_accessible() =>
+				FollowEvent::<Block::Hash>::OperationInaccessible(OperationId {
+					operation_id: operation_id.clone(),
+				}),
 			Ok(Some(signed_block)) => {
 				let extrinsics = signed_block
 					.block
@@ -247,6 +336,10 @@ where
 					value: extrinsics,
 				})
 			},
+			Ok(None) if block_guard.is_unpinned_ancestor() =>
+				FollowEvent::<Block::Hash>::OperationInaccessible(OperationId {
+					operation_id: operation_id.clone(),
+				}),
 			Ok(None) => {
 				// The block's body was pruned. This subscription ID has become invalid.
 				debug!(
@@ -273,7 +366,8 @@ where
 				return;
 			}
 			let _ = block_guard.response_sender().unbounded_send(event);
-		};
+		}
+		.instrument(span.clone());
 
 		self.executor.spawn("substrate-rpc-subscription", Some("rpc"), fut.boxed());
 
@@ -285,7 +379,7 @@ where
 		follow_subscription: String,
 		hash: Block::Hash,
 	) -> Result<Option<String>, ChainHeadRpcError> {
-		let _block_guard = match self.subscriptions.lock_block(&follow_subscription, hash, 1) {
+		let _block_guard = match self.lock_block(&follow_subscription, hash, 1) {
 			Ok(block) => block,
 			Err(SubscriptionManagementError::SubscriptionAbsent) |
 			Err(SubscriptionManagementError::ExceededLimits) => return Ok(None),
@@ -330,26 +424,33 @@ where
 			Err(e) => return ResponsePayload::error(e),
 		};
 
-		let mut block_guard =
-			match self.subscriptions.lock_block(&follow_subscription, hash, items.len()) {
-				Ok(block) => block,
-				Err(SubscriptionManagementError::SubscriptionAbsent) |
-				Err(SubscriptionManagementError::ExceededLimits) => {
-					return ResponsePayload::success(MethodResponse::LimitReached);
-				},
-				Err(SubscriptionManagementError::BlockHashAbsent) => {
-					// Block is not part of the subscription.
-					return ResponsePayload::error(ChainHeadRpcError::InvalidBlock)
-				},
-				Err(_) => return ResponsePayload::error(ChainHeadRpcError::InvalidBlock),
-			};
+		let mut block_guard = match self.lock_block(&follow_subscription, hash, items.len()) {
+			Ok(block) => block,
+			Err(SubscriptionManagementError::SubscriptionAbsent) |
+			Err(SubscriptionManagementError::ExceededLimits) => {
+				return ResponsePayload::success(MethodResponse::LimitReached);
+			},
+			Err(SubscriptionManagementError::BlockHashAbsent) => {
+				// Block is not part of the subscription.
+				return ResponsePayload::error(ChainHeadRpcError::InvalidBlock)
+			},
+			Err(_) => return ResponsePayload::error(ChainHeadRpcError::InvalidBlock),
+		};
 
 		let mut storage_client = ChainHeadStorage::<Client, Block, BE>::new(
 			self.client.clone(),
 			self.operation_max_storage_items,
+			self.operation_storage_value_chunk_size,
 		);
 		let operation = block_guard.operation();
 		let operation_id = operation.operation_id();
+		let span = operation_span(
+			"chainHead_unstable_storage",
+			&follow_subscription,
+			&operation_id,
+			&hash,
+		);
+		let _enter = span.enter();
 
 		// The number of operations we are allowed to execute.
 		let num_operations = operation.num_reserved();
@@ -365,8 +466,16 @@ where
 			if rp_is_success.await.is_err() {
 				return;
 			}
+			if !block_guard.is_accessible() {
+				let event = FollowEvent::<Block::Hash>::OperationInaccessible(OperationId {
+					operation_id: block_guard.operation().operation_id(),
+				});
+				let _ = block_guard.response_sender().unbounded_send(event);
+				return;
+			}
 			storage_client.generate_events(block_guard, hash, items, child_trie).await;
-		};
+		}
+		.instrument(span.clone());
 
 		self.executor
 			.spawn_blocking("substrate-rpc-subscription", Some("rpc"), fut.boxed());
@@ -374,6 +483,42 @@ where
 		rp
 	}
 
+	fn chain_head_unstable_storage_watch(
+		&self,
+		follow_subscription: String,
+		keys: Vec<String>,
+		child_trie: Option<String>,
+	) -> ResponsePayload<'static, MethodResponse> {
+		let keys = match keys
+			.into_iter()
+			.map(|key| parse_hex_param(key).map(StorageKey))
+			.collect::<Result<Vec<_>, ChainHeadRpcError>>()
+		{
+			Ok(keys) => keys,
+			Err(err) => return ResponsePayload::error(err),
+		};
+
+		let child_trie = match child_trie.map(|child_trie| parse_hex_param(child_trie)).transpose()
+		{
+			Ok(c) => c.map(ChildInfo::new_default_from_vec),
+			Err(e) => return ResponsePayload::error(e),
+		};
+
+		let watch = StorageWatch { keys, child_trie };
+		match self.subscriptions.add_storage_watch(
+			&follow_subscription,
+			watch,
+			self.subscription_max_watched_keys,
+		) {
+			Ok(operation_id) =>
+				ResponsePayload::success(MethodResponse::Started(MethodResponseStarted {
+					operation_id,
+					discarded_items: None,
+				})),
+			Err(_) => ResponsePayload::success(MethodResponse::LimitReached),
+		}
+	}
+
 	fn chain_head_unstable_call(
 		&self,
 		follow_subscription: String,
@@ -386,7 +531,7 @@ where
 			Err(err) => return ResponsePayload::error(err),
 		};
 
-		let mut block_guard = match self.subscriptions.lock_block(&follow_subscription, hash, 1) {
+		let mut block_guard = match self.lock_block(&follow_subscription, hash, 1) {
 			Ok(block) => block,
 			Err(SubscriptionManagementError::SubscriptionAbsent) |
 			Err(SubscriptionManagementError::ExceededLimits) => {
@@ -408,22 +553,31 @@ where
 		}
 
 		let operation_id = block_guard.operation().operation_id();
-		let event = self
-			.client
-			.executor()
-			.call(hash, &function, &call_parameters, CallContext::Offchain)
-			.map(|result| {
-				FollowEvent::<Block::Hash>::OperationCallDone(OperationCallDone {
-					operation_id: operation_id.clone(),
-					output: hex_string(&result),
-				})
+		let span =
+			operation_span("chainHead_unstable_call", &follow_subscription, &operation_id, &hash);
+		let _enter = span.enter();
+
+		let event = if !block_guard.is_accessible() {
+			FollowEvent::<Block::Hash>::OperationInaccessible(OperationId {
+				operation_id: operation_id.clone(),
 			})
-			.unwrap_or_else(|error| {
-				FollowEvent::<Block::Hash>::OperationError(OperationError {
-					operation_id: operation_id.clone(),
-					error: error.to_string(),
+		} else {
+			self.client
+				.executor()
+				.call(hash, &function, &call_parameters, CallContext::Offchain)
+				.map(|result| {
+					FollowEvent::<Block::Hash>::OperationCallDone(OperationCallDone {
+						operation_id: operation_id.clone(),
+						output: hex_string(&result),
+					})
+				})
+				.unwrap_or_else(|error| {
+					FollowEvent::<Block::Hash>::OperationError(OperationError {
+						operation_id: operation_id.clone(),
+						error: error.to_string(),
+					})
 				})
-			});
+		};
 
 		let (rp, rp_fut) = method_started_response(operation_id, None);
 
@@ -434,7 +588,8 @@ where
 				return;
 			}
 			let _ = block_guard.response_sender().unbounded_send(event);
-		};
+		}
+		.instrument(span.clone());
 
 		self.executor.spawn("substrate-rpc-subscription", Some("rpc"), fut.boxed());
 
@@ -492,6 +647,10 @@ where
 		follow_subscription: String,
 		operation_id: String,
 	) -> Result<(), ChainHeadRpcError> {
+		if self.subscriptions.remove_storage_watch(&follow_subscription, &operation_id) {
+			return Ok(())
+		}
+
 		let Some(operation) = self.subscriptions.get_operation(&follow_subscription, &operation_id)
 		else {
 			return Ok(())
@@ -503,6 +662,26 @@ where
 	}
 }
 
+/// Create the span an operation is executed in.
+///
+/// The span is a child of the current RPC call span, linking the operation
+/// to the trace of the client.
+fn operation_span(
+	method: &'static str,
+	follow_subscription: &str,
+	operation_id: &str,
+	hash: &impl Debug,
+) -> tracing::Span {
+	tracing::debug_span!(
+		target: LOG_TARGET,
+		"chain_head_operation",
+		method,
+		follow_subscription,
+		operation_id,
+		block = ?hash,
+	)
+}
+
 fn method_started_response(
 	operation_id: String,
 	discarded_items: Option<usize>,
@@ -18,13 +18,16 @@
 
 //! Implementation of the `chainHead_follow` method.
 
-use crate::chain_head::{
-	chain_head::LOG_TARGET,
-	event::{
-		BestBlockChanged, Finalized, FollowEvent, Initialized, NewBlock, RuntimeEvent,
-		RuntimeVersionEvent,
+use crate::{
+	chain_head::{
+		chain_head::LOG_TARGET,
+		event::{
+			BestBlockChanged, Finalized, FollowEvent, Initialized, NewBlock,
+			OperationStorageChanged, RuntimeEvent, RuntimeVersionEvent, StorageWatchItem,
+		},
+		subscription::{SubscriptionManagement, SubscriptionManagementError},
 	},
-	subscription::{SubscriptionManagement, SubscriptionManagementError},
+	hex_string,
 };
 use futures::{
 	channel::oneshot,
@@ -35,6 +38,7 @@ use jsonrpsee::SubscriptionSink;
 use log::{debug, error};
 use sc_client_api::{
 	Backend, BlockBackend, BlockImportNotification, BlockchainEvents, FinalityNotification,
+	StorageData, StorageKey, StorageProvider,
 };
 use sc_rpc::utils::to_sub_message;
 use sp_api::CallApiAt;
@@ -42,7 +46,10 @@ use sp_blockchain::{
 	Backend as BlockChainBackend, Error as BlockChainError, HeaderBackend, HeaderMetadata, Info,
 };
 use sp_runtime::traits::{Block as BlockT, Header as HeaderT, NumberFor};
-use std::{collections::HashSet, sync::Arc};
+use std::{
+	collections::{HashMap, HashSet},
+	sync::Arc,
+};
 
 use super::subscription::InsertedSubscriptionData;
 
@@ -60,6 +67,19 @@ pub struct ChainHeadFollower<BE: Backend<Block>, Block: BlockT, Client> {
 	sub_id: String,
 	/// The best reported block by this subscription.
 	best_block_cache: Option<Block::Hash>,
+	/// The values last reported by the storage watches, indexed by their operation ID.
+	watched_values: HashMap<String, WatchedValues>,
+}
+
+/// The values last reported by a storage watch.
+///
+/// A value that was never reported is absent, while a reported removal is `None`.
+#[derive(Default)]
+struct WatchedValues {
+	/// The values at the best block.
+	best: HashMap<StorageKey, Option<StorageData>>,
+	/// The values at the finalized block.
+	finalized: HashMap<StorageKey, Option<StorageData>>,
 }
 
 impl<BE: Backend<Block>, Block: BlockT, Client> ChainHeadFollower<BE, Block, Client> {
@@ -71,7 +91,15 @@ impl<BE: Backend<Block>, Block: BlockT, Client> ChainHeadFollower<BE, Block, Cli
 		with_runtime: bool,
 		sub_id: String,
 	) -> Self {
-		Self { client, backend, sub_handle, with_runtime, sub_id, best_block_cache: None }
+		Self {
+			client,
+			backend,
+			sub_handle,
+			with_runtime,
+			sub_id,
+			best_block_cache: None,
+			watched_values: Default::default(),
+		}
 	}
 }
 
@@ -140,6 +168,7 @@ where
 		+ HeaderMetadata<Block, Error = BlockChainError>
 		+ BlockchainEvents<Block>
 		+ CallApiAt<Block>
+		+ StorageProvider<Block, BE>
 		+ 'static,
 {
 	/// Conditionally generate the runtime event of the given block.
@@ -496,6 +525,81 @@ where
 		}
 	}
 
+	/// Generate the `OperationStorageChanged` events of the storage watches for the best and
+	/// finalized blocks reported by `events`.
+	///
+	/// The first event of a watch reports the value of all its keys.
+	fn generate_storage_watch_events(
+		&mut self,
+		events: &[FollowEvent<Block::Hash>],
+	) -> Vec<FollowEvent<Block::Hash>> {
+		let watches = self.sub_handle.storage_watches(&self.sub_id);
+		// Forget the values of stopped watches.
+		self.watched_values
+			.retain(|id, _| watches.iter().any(|(watch_id, _)| watch_id == id));
+		if watches.is_empty() {
+			return Vec::new()
+		}
+
+		let mut changes = Vec::new();
+		for event in events {
+			let (hash, is_finalized) = match event {
+				FollowEvent::BestBlockChanged(BestBlockChanged { best_block_hash }) =>
+					(*best_block_hash, false),
+				FollowEvent::Finalized(Finalized { finalized_block_hashes, .. }) =>
+					match finalized_block_hashes.last() {
+						Some(hash) => (*hash, true),
+						None => continue,
+					},
+				_ => continue,
+			};
+
+			for (operation_id, watch) in &watches {
+				let values = self.watched_values.entry(operation_id.clone()).or_default();
+				let last = if is_finalized { &mut values.finalized } else { &mut values.best };
+
+				let mut items = Vec::new();
+				for key in &watch.keys {
+					let value = match &watch.child_trie {
+						Some(child_trie) => self.client.child_storage(hash, child_trie, key),
+						None => self.client.storage(hash, key),
+					};
+					let value = match value {
+						Ok(value) => value,
+						Err(err) => {
+							debug!(
+								target: LOG_TARGET,
+								"[follow][id={:?}] Failed to read watched key {:?}: {:?}",
+								self.sub_id,
+								key,
+								err
+							);
+							continue
+						},
+					};
+
+					if last.get(key) != Some(&value) {
+						items.push(StorageWatchItem {
+							key: hex_string(&key.0),
+							value: value.as_ref().map(|value| hex_string(&value.0)),
+						});
+						last.insert(key.clone(), value);
+					}
+				}
+
+				if !items.is_empty() {
+					changes.push(FollowEvent::OperationStorageChanged(OperationStorageChanged {
+						operation_id: operation_id.clone(),
+						block_hash: hash,
+						items,
+					}));
+				}
+			}
+		}
+
+		changes
+	}
+
 	/// Submit the events from the provided stream to the RPC client
 	/// for as long as the `rx_stop` event was not called.
 	async fn submit_events<EventStream>(
@@ -523,7 +627,7 @@ where
 				NotificationType::MethodResponse(notification) => Ok(vec![notification]),
 			};
 
-			let events = match events {
+			let mut events = match events {
 				Ok(events) => events,
 				Err(err) => {
 					debug!(
@@ -537,6 +641,8 @@ where
 					return
 				},
 			};
+			let storage_changes = self.generate_storage_watch_events(&events);
+			events.extend(storage_changes);
 
 			for event in events {
 				let msg = to_sub_message(&sink, &event);
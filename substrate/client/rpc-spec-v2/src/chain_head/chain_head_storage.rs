@@ -31,7 +31,9 @@ use crate::{
 		FollowEvent,
 	},
 	common::{
-		events::{StorageQuery, StorageQueryType},
+		events::{
+			StorageQuery, StorageQueryType, StorageResult, StorageResultType, StorageValueChunk,
+		},
 		storage::{IterQueryType, QueryIter, QueryIterResult, Storage},
 	},
 };
@@ -45,16 +47,24 @@ pub struct ChainHeadStorage<Client, Block, BE> {
 	/// The maximum number of items reported by the `chainHead_storage` before
 	/// pagination is required.
 	operation_max_storage_items: usize,
+	/// The maximum number of bytes of a storage value reported by a single
+	/// `operationStorageItems` event.
+	operation_storage_value_chunk_size: Option<usize>,
 	_phandom: PhantomData<(BE, Block)>,
 }
 
 impl<Client, Block, BE> ChainHeadStorage<Client, Block, BE> {
 	/// Constructs a new [`ChainHeadStorage`].
-	pub fn new(client: Arc<Client>, operation_max_storage_items: usize) -> Self {
+	pub fn new(
+		client: Arc<Client>,
+		operation_max_storage_items: usize,
+		operation_storage_value_chunk_size: Option<usize>,
+	) -> Self {
 		Self {
 			client: Storage::new(client),
 			iter_operations: VecDeque::new(),
 			operation_max_storage_items,
+			operation_storage_value_chunk_size,
 			_phandom: PhantomData,
 		}
 	}
@@ -96,12 +106,8 @@ where
 				},
 			};
 
-			if !events.is_empty() {
-				// Send back the results of the iteration produced so far.
-				let _ = sender.unbounded_send(FollowEvent::<Block::Hash>::OperationStorageItems(
-					OperationStorageItems { operation_id: operation.operation_id(), items: events },
-				));
-			}
+			// Send back the results of the iteration produced so far.
+			self.send_items(&sender, operation.operation_id(), events);
 
 			if let Some(next_query) = maybe_next_query {
 				let _ =
@@ -183,19 +189,76 @@ where
 			};
 		}
 
-		if !storage_results.is_empty() {
+		self.send_items(&sender, operation.operation_id(), storage_results);
+
+		self.generate_storage_iter_events(block_guard, hash, child_key).await
+	}
+
+	/// Send the `items` via `operationStorageItems` events.
+	///
+	/// Values exceeding the configured chunk size are split, and every chunk is reported by its
+	/// own event.
+	fn send_items(
+		&self,
+		sender: &TracingUnboundedSender<FollowEvent<Block::Hash>>,
+		operation_id: String,
+		items: Vec<StorageResult>,
+	) {
+		let send = |items: Vec<StorageResult>| {
 			let _ = sender.unbounded_send(FollowEvent::<Block::Hash>::OperationStorageItems(
-				OperationStorageItems {
-					operation_id: operation.operation_id(),
-					items: storage_results,
-				},
+				OperationStorageItems { operation_id: operation_id.clone(), items },
 			));
+		};
+
+		let mut batch = Vec::with_capacity(items.len());
+		for item in items {
+			let chunks = match self.operation_storage_value_chunk_size {
+				Some(chunk_size) => split_value(&item, chunk_size),
+				None => None,
+			};
+			let Some(chunks) = chunks else {
+				batch.push(item);
+				continue
+			};
+
+			// Preserve the order in which the items were queried.
+			if !batch.is_empty() {
+				send(std::mem::take(&mut batch));
+			}
+			chunks.into_iter().for_each(|chunk| send(vec![chunk]));
 		}
 
-		self.generate_storage_iter_events(block_guard, hash, child_key).await
+		if !batch.is_empty() {
+			send(batch);
+		}
 	}
 }
 
+/// Split the hex-encoded value of `item` into chunks of at most `chunk_size` bytes.
+///
+/// Returns `None` if the item is not a value or fits into a single chunk.
+fn split_value(item: &StorageResult, chunk_size: usize) -> Option<Vec<StorageResult>> {
+	let StorageResultType::Value(value) = &item.result else { return None };
+	let hex = value.strip_prefix("0x")?;
+	let total_length = hex.len() / 2;
+	if chunk_size == 0 || total_length <= chunk_size {
+		return None
+	}
+
+	let chunks = (0..hex.len())
+		.step_by(chunk_size * 2)
+		.map(|start| {
+			let end = hex.len().min(start + chunk_size * 2);
+			StorageResult {
+				key: item.key.clone(),
+				result: StorageResultType::Value(format!("0x{}", &hex[start..end])),
+				chunk: Some(StorageValueChunk { offset: start / 2, total_length }),
+			}
+		})
+		.collect();
+	Some(chunks)
+}
+
 /// Build and send the opaque error back to the `chainHead_follow` method.
 fn send_error<Block: BlockT>(
 	sender: &TracingUnboundedSender<FollowEvent<Block::Hash>>,
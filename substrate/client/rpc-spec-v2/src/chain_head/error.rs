@@ -18,6 +18,7 @@
 
 //! Error helpers for `chainHead` RPC module.
 
+use crate::error::ErrorCode;
 use jsonrpsee::types::error::ErrorObject;
 
 /// ChainHead RPC errors.
@@ -46,23 +47,27 @@ pub enum Error {
 /// Errors for `chainHead` RPC module, as defined in
 /// <https://github.com/paritytech/json-rpc-interface-spec>.
 pub mod rpc_spec_v2 {
+	use super::ErrorCode;
+
 	/// The provided block hash is invalid.
-	pub const INVALID_BLOCK_ERROR: i32 = -32801;
+	pub const INVALID_BLOCK_ERROR: i32 = ErrorCode::InvalidBlock.code();
 	/// The follow subscription was started with `withRuntime` set to `false`.
-	pub const INVALID_RUNTIME_CALL: i32 = -32802;
+	pub const INVALID_RUNTIME_CALL: i32 = ErrorCode::InvalidRuntimeCall.code();
 	/// Wait-for-continue event not generated.
-	pub const INVALID_CONTINUE: i32 = -32803;
+	pub const INVALID_CONTINUE: i32 = ErrorCode::InvalidContinue.code();
 	/// Received duplicate hashes for the `chainHead_unpin` method.
-	pub const INVALID_DUPLICATE_HASHES: i32 = -32804;
+	pub const INVALID_DUPLICATE_HASHES: i32 = ErrorCode::InvalidDuplicateHashes.code();
 }
 
 /// General purpose errors, as defined in
 /// <https://www.jsonrpc.org/specification#error_object>.
 pub mod json_rpc_spec {
+	use super::ErrorCode;
+
 	/// Invalid parameter error.
-	pub const INVALID_PARAM_ERROR: i32 = -32602;
+	pub const INVALID_PARAM_ERROR: i32 = ErrorCode::InvalidParam.code();
 	/// Internal error.
-	pub const INTERNAL_ERROR: i32 = -32603;
+	pub const INTERNAL_ERROR: i32 = ErrorCode::Internal.code();
 }
 
 impl From<Error> for ErrorObject<'static> {
@@ -70,18 +75,14 @@ impl From<Error> for ErrorObject<'static> {
 		let msg = e.to_string();
 
 		match e {
-			Error::InvalidBlock =>
-				ErrorObject::owned(rpc_spec_v2::INVALID_BLOCK_ERROR, msg, None::<()>),
-			Error::InvalidRuntimeCall(_) =>
-				ErrorObject::owned(rpc_spec_v2::INVALID_RUNTIME_CALL, msg, None::<()>),
-			Error::InvalidContinue =>
-				ErrorObject::owned(rpc_spec_v2::INVALID_CONTINUE, msg, None::<()>),
+			Error::InvalidBlock => ErrorCode::InvalidBlock.error_object(msg, None),
+			Error::InvalidRuntimeCall(err) =>
+				ErrorCode::InvalidRuntimeCall.error_object(msg, Some(err)),
+			Error::InvalidContinue => ErrorCode::InvalidContinue.error_object(msg, None),
 			Error::InvalidDuplicateHashes =>
-				ErrorObject::owned(rpc_spec_v2::INVALID_DUPLICATE_HASHES, msg, None::<()>),
-			Error::InvalidParam(_) =>
-				ErrorObject::owned(json_rpc_spec::INVALID_PARAM_ERROR, msg, None::<()>),
-			Error::InternalError(_) =>
-				ErrorObject::owned(json_rpc_spec::INTERNAL_ERROR, msg, None::<()>),
+				ErrorCode::InvalidDuplicateHashes.error_object(msg, None),
+			Error::InvalidParam(param) => ErrorCode::InvalidParam.error_object(msg, Some(param)),
+			Error::InternalError(err) => ErrorCode::Internal.error_object(msg, Some(err)),
 		}
 	}
 }
@@ -207,6 +207,14 @@ pub struct Finalized<Hash> {
 	pub pruned_block_hashes: Vec<Hash>,
 }
 
+/// Indicate the block hashes that were unpinned by the server.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PinnedBlocksEvicted<Hash> {
+	/// Block hashes that are no longer pinned, ordered from the oldest pinned block.
+	pub block_hashes: Vec<Hash>,
+}
+
 /// Indicate the operation id of the event.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -245,6 +253,28 @@ pub struct OperationStorageItems {
 	pub items: Vec<StorageResult>,
 }
 
+/// A watched storage item whose value changed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageWatchItem {
+	/// The hex-encoded key of the item.
+	pub key: String,
+	/// The hex-encoded new value of the item, `None` if the item was removed.
+	pub value: Option<String>,
+}
+
+/// The notification of the `chainHead_storageWatch` method.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OperationStorageChanged<Hash> {
+	/// The operation id of the storage watch.
+	pub operation_id: String,
+	/// The best or finalized block at which the items changed.
+	pub block_hash: Hash,
+	/// The changed items.
+	pub items: Vec<StorageWatchItem>,
+}
+
 /// Indicate a problem during the operation.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -264,6 +294,10 @@ pub struct OperationError {
 ///    announced priorly with the `NewBlock` event.
 /// 4. Finalized - State the finalized and pruned blocks.
 ///
+/// When the server runs out of space for pinned blocks, it unpins the blocks that have been pinned
+/// for too long and reports them with the `PinnedBlocksEvicted` event. Evicted blocks must not be
+/// unpinned by the user and operations on them fail with an invalid block hash error.
+///
 /// The following events are related to operations:
 /// - OperationBodyDone: The response of the `chianHead_body`
 /// - OperationCallDone: The response of the `chianHead_call`
@@ -271,6 +305,8 @@ pub struct OperationError {
 /// - OperationWaitingForContinue: Generated after OperationStorageItems and requires the user to
 ///   call `chainHead_continue`
 /// - OperationStorageDone: The `chianHead_storage` method has produced all the results
+/// - OperationStorageChanged: Some keys registered by `chainHead_storageWatch` changed in a new
+///   best or finalized block
 /// - OperationInaccessible: The server was unable to provide the result, retries might succeed in
 ///   the future
 /// - OperationError: The server encountered an error, retries will not succeed
@@ -291,6 +327,8 @@ pub enum FollowEvent<Hash> {
 	BestBlockChanged(BestBlockChanged<Hash>),
 	/// A list of finalized and pruned blocks.
 	Finalized(Finalized<Hash>),
+	/// A list of blocks that were unpinned by the server.
+	PinnedBlocksEvicted(PinnedBlocksEvicted<Hash>),
 	/// The response of the `chainHead_body` method.
 	OperationBodyDone(OperationBodyDone),
 	/// The response of the `chainHead_call` method.
@@ -302,6 +340,8 @@ pub enum FollowEvent<Hash> {
 	OperationWaitingForContinue(OperationId),
 	/// The responses of the `chainHead_storage` method have been produced.
 	OperationStorageDone(OperationId),
+	/// Some keys registered by the `chainHead_storageWatch` method changed.
+	OperationStorageChanged(OperationStorageChanged<Hash>),
 	/// The RPC server was unable to provide the response of the following operation id.
 	///
 	/// Repeating the same operation in the future might succeed.
@@ -498,6 +538,20 @@ mod tests {
 		assert_eq!(event_dec, event);
 	}
 
+	#[test]
+	fn follow_pinned_blocks_evicted_event() {
+		let event: FollowEvent<String> = FollowEvent::PinnedBlocksEvicted(PinnedBlocksEvicted {
+			block_hashes: vec!["0x1".into(), "0x2".into()],
+		});
+
+		let ser = serde_json::to_string(&event).unwrap();
+		let exp = r#"{"event":"pinnedBlocksEvicted","blockHashes":["0x1","0x2"]}"#;
+		assert_eq!(ser, exp);
+
+		let event_dec: FollowEvent<String> = serde_json::from_str(exp).unwrap();
+		assert_eq!(event_dec, event);
+	}
+
 	#[test]
 	fn follow_op_body_event() {
 		let event: FollowEvent<String> = FollowEvent::OperationBodyDone(OperationBodyDone {
@@ -536,6 +590,7 @@ mod tests {
 				items: vec![StorageResult {
 					key: "0x1".into(),
 					result: StorageResultType::Value("0x123".to_string()),
+					chunk: None,
 				}],
 			});
 
@@ -39,6 +39,6 @@ mod subscription;
 pub use api::ChainHeadApiServer;
 pub use chain_head::{ChainHead, ChainHeadConfig};
 pub use event::{
-	BestBlockChanged, ErrorEvent, Finalized, FollowEvent, Initialized, NewBlock, RuntimeEvent,
-	RuntimeVersionEvent,
+	BestBlockChanged, ErrorEvent, Finalized, FollowEvent, Initialized, NewBlock,
+	PinnedBlocksEvicted, RuntimeEvent, RuntimeVersionEvent,
 };
@@ -18,7 +18,7 @@
 
 use futures::channel::oneshot;
 use parking_lot::Mutex;
-use sc_client_api::Backend;
+use sc_client_api::{Backend, ChildInfo, StorageKey};
 use sc_utils::mpsc::{tracing_unbounded, TracingUnboundedReceiver, TracingUnboundedSender};
 use sp_runtime::traits::Block as BlockT;
 use std::{
@@ -27,7 +27,9 @@ use std::{
 	time::{Duration, Instant},
 };
 
-use crate::chain_head::{subscription::SubscriptionManagementError, FollowEvent};
+use crate::chain_head::{
+	subscription::SubscriptionManagementError, FollowEvent, PinnedBlocksEvicted,
+};
 
 /// The queue size after which the `sc_utils::mpsc::tracing_unbounded` would produce warnings.
 const QUEUE_SIZE_WARNING: usize = 512;
@@ -336,6 +338,15 @@ impl Operations {
 	}
 }
 
+/// The keys registered by the `chainHead_storageWatch` method.
+#[derive(Clone)]
+pub struct StorageWatch {
+	/// The watched keys.
+	pub keys: Vec<StorageKey>,
+	/// The child trie containing the keys, if any.
+	pub child_trie: Option<ChildInfo>,
+}
+
 struct BlockState {
 	/// The state machine of this block.
 	state_machine: BlockStateMachine,
@@ -355,6 +366,8 @@ struct SubscriptionState<Block: BlockT> {
 	response_sender: TracingUnboundedSender<FollowEvent<Block::Hash>>,
 	/// The ongoing operations of a subscription.
 	operations: Operations,
+	/// The storage watches of the subscription, indexed by their operation ID.
+	storage_watches: HashMap<String, StorageWatch>,
 	/// Track the block hashes available for this subscription.
 	///
 	/// This implementation assumes:
@@ -454,17 +467,37 @@ impl<Block: BlockT> SubscriptionState<Block> {
 		!state.state_machine.was_unpinned()
 	}
 
-	/// Get the timestamp of the oldest inserted block.
+	/// Evict the blocks that have been pinned for longer than `max_duration`.
 	///
-	/// # Note
+	/// The blocks are unregistered as if the user called `unpin` and are reported to the user
+	/// via the `PinnedBlocksEvicted` event.
 	///
-	/// This iterates over all the blocks of the subscription.
-	fn find_oldest_block_timestamp(&self) -> Instant {
-		let mut timestamp = Instant::now();
-		for (_, state) in self.blocks.iter() {
-			timestamp = std::cmp::min(timestamp, state.timestamp);
+	/// Returns the hashes of the evicted blocks, which must be unregistered globally.
+	fn evict_blocks(&mut self, now: Instant, max_duration: Duration) -> Vec<Block::Hash> {
+		let mut to_evict: Vec<_> = self
+			.blocks
+			.iter()
+			.filter(|(_, state)| {
+				!state.state_machine.was_unpinned() &&
+					now.checked_duration_since(state.timestamp)
+						.map_or(false, |duration| duration > max_duration)
+			})
+			.map(|(hash, state)| (*hash, state.timestamp))
+			.collect();
+		if to_evict.is_empty() {
+			return Vec::new()
+		}
+
+		to_evict.sort_by_key(|(_, timestamp)| *timestamp);
+		let evicted: Vec<_> = to_evict.into_iter().map(|(hash, _)| hash).collect();
+		for hash in &evicted {
+			self.unregister_block(*hash);
 		}
-		timestamp
+
+		let _ = self.response_sender.unbounded_send(FollowEvent::PinnedBlocksEvicted(
+			PinnedBlocksEvicted { block_hashes: evicted.clone() },
+		));
+		evicted
 	}
 
 	/// Register a new operation.
@@ -489,6 +522,10 @@ pub struct BlockGuard<Block: BlockT, BE: Backend<Block>> {
 	response_sender: TracingUnboundedSender<FollowEvent<Block::Hash>>,
 	operation: RegisteredOperation,
 	backend: Arc<BE>,
+	/// Whether the block is a finalized ancestor that was not pinned by the subscription.
+	is_unpinned_ancestor: bool,
+	/// Whether the block is pinned in the backend while the guard is alive.
+	is_pinned: bool,
 }
 
 // Custom implementation of Debug to avoid bounds on `backend: Debug` for `unwrap_err()` needed for
@@ -512,7 +549,38 @@ impl<Block: BlockT, BE: Backend<Block>> BlockGuard<Block, BE> {
 			.pin_block(hash)
 			.map_err(|err| SubscriptionManagementError::Custom(err.to_string()))?;
 
-		Ok(Self { hash, with_runtime, response_sender, operation, backend })
+		Ok(Self {
+			hash,
+			with_runtime,
+			response_sender,
+			operation,
+			backend,
+			is_unpinned_ancestor: false,
+			is_pinned: true,
+		})
+	}
+
+	/// Construct a new [`BlockGuard`] for a finalized block not pinned by the subscription.
+	///
+	/// The block is pinned on a best-effort basis, as its state may already be discarded.
+	fn new_unpinned_ancestor(
+		hash: Block::Hash,
+		with_runtime: bool,
+		response_sender: TracingUnboundedSender<FollowEvent<Block::Hash>>,
+		operation: RegisteredOperation,
+		backend: Arc<BE>,
+	) -> Self {
+		let is_pinned = backend.pin_block(hash).is_ok();
+
+		Self {
+			hash,
+			with_runtime,
+			response_sender,
+			operation,
+			backend,
+			is_unpinned_ancestor: true,
+			is_pinned,
+		}
 	}
 
 	/// The `with_runtime` flag of the subscription.
@@ -529,11 +597,28 @@ impl<Block: BlockT, BE: Backend<Block>> BlockGuard<Block, BE> {
 	pub fn operation(&mut self) -> &mut RegisteredOperation {
 		&mut self.operation
 	}
+
+	/// Whether the block is a finalized ancestor that was not pinned by the subscription.
+	///
+	/// The body or state of such blocks may be pruned, which is reported with the
+	/// `operationInaccessible` event instead of stopping the subscription.
+	pub fn is_unpinned_ancestor(&self) -> bool {
+		self.is_unpinned_ancestor
+	}
+
+	/// Whether the block is accessible, i.e. it could be pinned in the backend.
+	///
+	/// Always true for blocks pinned by the subscription.
+	pub fn is_accessible(&self) -> bool {
+		self.is_pinned
+	}
 }
 
 impl<Block: BlockT, BE: Backend<Block>> Drop for BlockGuard<Block, BE> {
 	fn drop(&mut self) {
-		self.backend.unpin_block(self.hash);
+		if self.is_pinned {
+			self.backend.unpin_block(self.hash);
+		}
 	}
 }
 
@@ -550,7 +635,9 @@ pub struct SubscriptionsInner<Block: BlockT, BE: Backend<Block>> {
 	/// Reference count the block hashes across all subscriptions.
 	///
 	/// The pinned blocks cannot exceed the [`Self::global_limit`] limit.
-	/// When the limit is exceeded subscriptions are stopped via the `Stop` event.
+	/// When the limit is exceeded, blocks pinned for longer than the local pin duration are
+	/// evicted via the `PinnedBlocksEvicted` event. If that is not enough, subscriptions are
+	/// stopped via the `Stop` event.
 	global_blocks: HashMap<Block::Hash, usize>,
 	/// The maximum number of pinned blocks across all subscriptions.
 	global_max_pinned_blocks: usize,
@@ -600,6 +687,7 @@ impl<Block: BlockT, BE: Backend<Block>> SubscriptionsInner<Block, BE> {
 				response_sender,
 				blocks: Default::default(),
 				operations: Operations::new(self.max_ongoing_operations),
+				storage_watches: Default::default(),
 			};
 			entry.insert(state);
 
@@ -626,10 +714,11 @@ impl<Block: BlockT, BE: Backend<Block>> SubscriptionsInner<Block, BE> {
 	/// Ensure that a new block could be pinned.
 	///
 	/// If the global number of blocks has been reached this method
-	/// will remove all subscriptions that have blocks older than the
-	/// specified pin duration.
+	/// will evict from all subscriptions the blocks that are older than the
+	/// specified pin duration. The subscriptions are notified about the evicted
+	/// blocks, which are no longer pinned for them.
 	///
-	/// If after removing all subscriptions that exceed the pin duration
+	/// If after evicting all blocks that exceed the pin duration
 	/// there is no space for pinning a new block, then all subscriptions
 	/// are terminated.
 	///
@@ -639,39 +728,27 @@ impl<Block: BlockT, BE: Backend<Block>> SubscriptionsInner<Block, BE> {
 			return false
 		}
 
-		// Terminate all subscriptions that have blocks older than
-		// the specified pin duration.
+		// Evict the blocks that are older than the specified pin duration.
 		let now = Instant::now();
+		let max_duration = self.local_max_pin_duration;
 
-		let to_remove: Vec<_> = self
+		let evicted: Vec<_> = self
 			.subs
-			.iter_mut()
-			.filter_map(|(sub_id, sub)| {
-				let sub_time = sub.find_oldest_block_timestamp();
-				// Subscriptions older than the specified pin duration should be removed.
-				let should_remove = match now.checked_duration_since(sub_time) {
-					Some(duration) => duration > self.local_max_pin_duration,
-					None => true,
-				};
-				should_remove.then(|| sub_id.clone())
-			})
+			.values_mut()
+			.flat_map(|sub| sub.evict_blocks(now, max_duration))
 			.collect();
-
-		let mut is_terminated = false;
-		for sub_id in to_remove {
-			if sub_id == request_sub_id {
-				is_terminated = true;
-			}
-			self.remove_subscription(&sub_id);
+		for hash in evicted {
+			self.global_unregister_block(hash);
 		}
 
-		// Make sure we have enough space after first pass of terminating subscriptions.
+		// Make sure we have enough space after evicting the old blocks.
 		if self.global_blocks.len() < self.global_max_pinned_blocks {
-			return is_terminated
+			return false
 		}
 
 		// Sanity check: cannot uphold `chainHead` guarantees anymore. We have not
-		// found any subscriptions that have older pinned blocks to terminate.
+		// found any older pinned blocks to evict.
+		let mut is_terminated = false;
 		let to_remove: Vec<_> = self.subs.keys().map(|sub_id| sub_id.clone()).collect();
 		for sub_id in to_remove {
 			if sub_id == request_sub_id {
@@ -827,15 +904,82 @@ impl<Block: BlockT, BE: Backend<Block>> SubscriptionsInner<Block, BE> {
 		)
 	}
 
+	pub fn lock_unpinned_ancestor(
+		&mut self,
+		sub_id: &str,
+		hash: Block::Hash,
+		to_reserve: usize,
+	) -> Result<BlockGuard<Block, BE>, SubscriptionManagementError> {
+		let Some(sub) = self.subs.get_mut(sub_id) else {
+			return Err(SubscriptionManagementError::SubscriptionAbsent)
+		};
+
+		let Some(operation) = sub.register_operation(to_reserve) else {
+			return Err(SubscriptionManagementError::ExceededLimits)
+		};
+
+		Ok(BlockGuard::new_unpinned_ancestor(
+			hash,
+			sub.with_runtime,
+			sub.response_sender.clone(),
+			operation,
+			self.backend.clone(),
+		))
+	}
+
 	pub fn get_operation(&mut self, sub_id: &str, id: &str) -> Option<OperationState> {
 		let state = self.subs.get(sub_id)?;
 		state.get_operation(id)
 	}
+
+	/// Register a storage watch, returning its operation ID.
+	///
+	/// Fails if the subscription would watch more than `max_watched_keys` keys.
+	pub fn add_storage_watch(
+		&mut self,
+		sub_id: &str,
+		watch: StorageWatch,
+		max_watched_keys: usize,
+	) -> Result<String, SubscriptionManagementError> {
+		let Some(sub) = self.subs.get_mut(sub_id) else {
+			return Err(SubscriptionManagementError::SubscriptionAbsent)
+		};
+
+		let watched_keys: usize = sub.storage_watches.values().map(|watch| watch.keys.len()).sum();
+		if watched_keys.saturating_add(watch.keys.len()) > max_watched_keys {
+			return Err(SubscriptionManagementError::ExceededLimits)
+		}
+
+		let operation_id = sub.operations.next_operation_id();
+		sub.storage_watches.insert(operation_id.clone(), watch);
+		Ok(operation_id)
+	}
+
+	/// Remove a storage watch, returning `true` if it existed.
+	pub fn remove_storage_watch(&mut self, sub_id: &str, id: &str) -> bool {
+		self.subs
+			.get_mut(sub_id)
+			.map_or(false, |sub| sub.storage_watches.remove(id).is_some())
+	}
+
+	/// The storage watches of the subscription.
+	pub fn storage_watches(&self, sub_id: &str) -> Vec<(String, StorageWatch)> {
+		self.subs
+			.get(sub_id)
+			.map(|sub| {
+				sub.storage_watches
+					.iter()
+					.map(|(id, watch)| (id.clone(), watch.clone()))
+					.collect()
+			})
+			.unwrap_or_default()
+	}
 }
 
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use futures::StreamExt;
 	use sc_block_builder::BlockBuilderBuilder;
 	use sc_service::client::new_in_mem;
 	use sp_consensus::BlockOrigin;
@@ -1366,12 +1510,12 @@ mod tests {
 		let id_1 = "abc".to_string();
 		let id_2 = "abcd".to_string();
 
-		let _stop = subs.insert_subscription(id_1.clone(), true).unwrap();
+		let mut sub_data_1 = subs.insert_subscription(id_1.clone(), true).unwrap();
 		assert_eq!(subs.pin_block(&id_1, hash_1).unwrap(), true);
 		assert_eq!(subs.pin_block(&id_1, hash_2).unwrap(), true);
 
-		// Maximum pin duration is 5 second, sleep 5 seconds to ensure we clean up
-		// the first subscription.
+		// Maximum pin duration is 5 second, sleep 5 seconds to ensure we evict
+		// the blocks of the first subscription.
 		std::thread::sleep(std::time::Duration::from_secs(5));
 
 		let _stop = subs.insert_subscription(id_2.clone(), true).unwrap();
@@ -1381,29 +1525,48 @@ mod tests {
 		assert_eq!(*subs.global_blocks.get(&hash_1).unwrap(), 2);
 		assert_eq!(*subs.global_blocks.get(&hash_2).unwrap(), 1);
 
-		// Second subscription has only 1 block pinned. Only the first subscription is terminated.
-		let err = subs.pin_block(&id_1, hash_3).unwrap_err();
-		assert_eq!(err, SubscriptionManagementError::ExceededLimits);
+		// The blocks of the first subscription are evicted to make space for block 3.
+		// The second subscription pinned block 1 recently, so it keeps it.
+		assert_eq!(subs.pin_block(&id_1, hash_3).unwrap(), true);
 
-		// Ensure both subscriptions are removed.
+		let event = futures::executor::block_on(sub_data_1.response_receiver.next()).unwrap();
+		let FollowEvent::PinnedBlocksEvicted(PinnedBlocksEvicted { mut block_hashes }) = event
+		else {
+			panic!("Expected the `PinnedBlocksEvicted` event")
+		};
+		block_hashes.sort();
+		let mut expected = vec![hash_1, hash_2];
+		expected.sort();
+		assert_eq!(block_hashes, expected);
+		assert!(sub_data_1.rx_stop.try_recv().unwrap().is_none());
+
+		// The evicted blocks are no longer available to the first subscription.
 		let err = subs.lock_block(&id_1, hash_1, 1).unwrap_err();
-		assert_eq!(err, SubscriptionManagementError::SubscriptionAbsent);
+		assert_eq!(err, SubscriptionManagementError::BlockHashAbsent);
+		let err = subs.unpin_blocks(&id_1, vec![hash_2]).unwrap_err();
+		assert_eq!(err, SubscriptionManagementError::BlockHashAbsent);
 
+		let _block_guard = subs.lock_block(&id_1, hash_3, 1).unwrap();
 		let _block_guard = subs.lock_block(&id_2, hash_1, 1).unwrap();
 
 		assert_eq!(*subs.global_blocks.get(&hash_1).unwrap(), 1);
 		assert!(subs.global_blocks.get(&hash_2).is_none());
-		assert!(subs.global_blocks.get(&hash_3).is_none());
-		assert_eq!(subs.global_blocks.len(), 1);
+		assert_eq!(*subs.global_blocks.get(&hash_3).unwrap(), 1);
+		assert_eq!(subs.global_blocks.len(), 2);
 
-		// Force second subscription to get terminated.
-		assert_eq!(subs.pin_block(&id_2, hash_2).unwrap(), true);
-		let err = subs.pin_block(&id_2, hash_3).unwrap_err();
+		// Registering an evicted block again, as for the `Finalized` event, does not pin it.
+		assert_eq!(subs.pin_block(&id_1, hash_1).unwrap(), false);
+		assert_eq!(*subs.global_blocks.get(&hash_1).unwrap(), 1);
+
+		// No blocks are old enough to be evicted, all subscriptions are terminated.
+		let err = subs.pin_block(&id_2, hash_2).unwrap_err();
 		assert_eq!(err, SubscriptionManagementError::ExceededLimits);
 
-		assert!(subs.global_blocks.get(&hash_1).is_none());
-		assert!(subs.global_blocks.get(&hash_2).is_none());
-		assert!(subs.global_blocks.get(&hash_3).is_none());
+		let err = subs.lock_block(&id_1, hash_3, 1).unwrap_err();
+		assert_eq!(err, SubscriptionManagementError::SubscriptionAbsent);
+		let err = subs.lock_block(&id_2, hash_1, 1).unwrap_err();
+		assert_eq!(err, SubscriptionManagementError::SubscriptionAbsent);
+
 		assert_eq!(subs.global_blocks.len(), 0);
 	}
 
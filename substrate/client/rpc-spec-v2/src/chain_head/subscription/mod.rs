@@ -28,7 +28,7 @@ use self::inner::SubscriptionsInner;
 
 pub use self::inner::OperationState;
 pub use error::SubscriptionManagementError;
-pub use inner::{BlockGuard, InsertedSubscriptionData};
+pub use inner::{BlockGuard, InsertedSubscriptionData, StorageWatch};
 
 /// Manage block pinning / unpinning for subscription IDs.
 pub struct SubscriptionManagement<Block: BlockT, BE: Backend<Block>> {
@@ -130,9 +130,54 @@ impl<Block: BlockT, BE: Backend<Block>> SubscriptionManagement<Block, BE> {
 		inner.lock_block(sub_id, hash, to_reserve)
 	}
 
+	/// Reserve capacity for ongoing operations on a finalized block that is not pinned by
+	/// the subscription.
+	///
+	/// Unlike [`Self::lock_block`] this does not fail if the block can't be pinned in the
+	/// backend, which is reported by [`BlockGuard::is_accessible`] instead. The caller is
+	/// responsible for checking that the block is a finalized one.
+	pub fn lock_unpinned_ancestor(
+		&self,
+		sub_id: &str,
+		hash: Block::Hash,
+		to_reserve: usize,
+	) -> Result<BlockGuard<Block, BE>, SubscriptionManagementError> {
+		let mut inner = self.inner.write();
+		inner.lock_unpinned_ancestor(sub_id, hash, to_reserve)
+	}
+
 	/// Get the operation state.
 	pub fn get_operation(&self, sub_id: &str, operation_id: &str) -> Option<OperationState> {
 		let mut inner = self.inner.write();
 		inner.get_operation(sub_id, operation_id)
 	}
+
+	/// Register the keys of a `chainHead_storageWatch` call, returning the operation ID
+	/// identifying the watch.
+	///
+	/// Returns an error if the subscription ID is invalid or the subscription would watch
+	/// more than `max_watched_keys` keys.
+	pub fn add_storage_watch(
+		&self,
+		sub_id: &str,
+		watch: StorageWatch,
+		max_watched_keys: usize,
+	) -> Result<String, SubscriptionManagementError> {
+		let mut inner = self.inner.write();
+		inner.add_storage_watch(sub_id, watch, max_watched_keys)
+	}
+
+	/// Stop the storage watch with the given operation ID.
+	///
+	/// Returns `true` if the watch existed.
+	pub fn remove_storage_watch(&self, sub_id: &str, operation_id: &str) -> bool {
+		let mut inner = self.inner.write();
+		inner.remove_storage_watch(sub_id, operation_id)
+	}
+
+	/// Get the storage watches of the subscription.
+	pub fn storage_watches(&self, sub_id: &str) -> Vec<(String, StorageWatch)> {
+		let inner = self.inner.read();
+		inner.storage_watches(sub_id)
+	}
 }
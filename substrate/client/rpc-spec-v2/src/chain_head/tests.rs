@@ -17,8 +17,12 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-	chain_head::{event::MethodResponse, test_utils::ChainHeadMockClient},
-	common::events::{StorageQuery, StorageQueryType, StorageResultType},
+	chain_head::{
+		event::{MethodResponse, StorageWatchItem},
+		test_utils::ChainHeadMockClient,
+	},
+	common::events::{StorageQuery, StorageQueryType, StorageResultType, StorageValueChunk},
+	error::{ErrorCode, ErrorData},
 	hex_string,
 };
 
@@ -59,6 +63,8 @@ const MAX_PINNED_BLOCKS: usize = 32;
 const MAX_PINNED_SECS: u64 = 60;
 const MAX_OPERATIONS: usize = 16;
 const MAX_PAGINATION_LIMIT: usize = 5;
+const MAX_WATCHED_KEYS: usize = 2;
+const FINALIZED_LOOKBACK_DEPTH: u32 = 0;
 const INVALID_HASH: [u8; 32] = [1; 32];
 const KEY: &[u8] = b":mock";
 const VALUE: &[u8] = b"hello world";
@@ -113,6 +119,9 @@ async fn setup_api() -> (
 			subscription_max_pinned_duration: Duration::from_secs(MAX_PINNED_SECS),
 			subscription_max_ongoing_operations: MAX_OPERATIONS,
 			operation_max_storage_items: MAX_PAGINATION_LIMIT,
+			operation_storage_value_chunk_size: None,
+			subscription_max_watched_keys: MAX_WATCHED_KEYS,
+			finalized_lookback_depth: FINALIZED_LOOKBACK_DEPTH,
 		},
 	)
 	.into_rpc();
@@ -163,6 +172,9 @@ async fn follow_subscription_produces_blocks() {
 			subscription_max_pinned_duration: Duration::from_secs(MAX_PINNED_SECS),
 			subscription_max_ongoing_operations: MAX_OPERATIONS,
 			operation_max_storage_items: MAX_PAGINATION_LIMIT,
+			operation_storage_value_chunk_size: None,
+			subscription_max_watched_keys: MAX_WATCHED_KEYS,
+			finalized_lookback_depth: FINALIZED_LOOKBACK_DEPTH,
 		},
 	)
 	.into_rpc();
@@ -231,6 +243,9 @@ async fn follow_with_runtime() {
 			subscription_max_pinned_duration: Duration::from_secs(MAX_PINNED_SECS),
 			subscription_max_ongoing_operations: MAX_OPERATIONS,
 			operation_max_storage_items: MAX_PAGINATION_LIMIT,
+			operation_storage_value_chunk_size: None,
+			subscription_max_watched_keys: MAX_WATCHED_KEYS,
+			finalized_lookback_depth: FINALIZED_LOOKBACK_DEPTH,
 		},
 	)
 	.into_rpc();
@@ -358,7 +373,7 @@ async fn get_header() {
 		.await
 		.unwrap_err();
 	assert_matches!(err,
-		Error::JsonRpc(ref err) if err.code() == super::error::rpc_spec_v2::INVALID_BLOCK_ERROR && err.message() == "Invalid block hash"
+		Error::JsonRpc(ref err) if err.code() == super::error::rpc_spec_v2::INVALID_BLOCK_ERROR && ErrorData::of(err).unwrap().kind == ErrorCode::InvalidBlock
 	);
 
 	// Obtain the valid header.
@@ -447,6 +462,94 @@ async fn get_body() {
 	);
 }
 
+#[tokio::test]
+async fn get_body_of_unpinned_finalized_ancestor() {
+	let builder = TestClientBuilder::new();
+	let backend = builder.backend();
+	let mut client = Arc::new(builder.build());
+
+	let api = ChainHead::new(
+		client.clone(),
+		backend,
+		Arc::new(TaskExecutor::default()),
+		ChainHeadConfig {
+			global_max_pinned_blocks: MAX_PINNED_BLOCKS,
+			subscription_max_pinned_duration: Duration::from_secs(MAX_PINNED_SECS),
+			subscription_max_ongoing_operations: MAX_OPERATIONS,
+			operation_max_storage_items: MAX_PAGINATION_LIMIT,
+			operation_storage_value_chunk_size: None,
+			subscription_max_watched_keys: MAX_WATCHED_KEYS,
+			finalized_lookback_depth: 1,
+		},
+	)
+	.into_rpc();
+
+	// Block tree:
+	//   genesis -> block_1 -> block_2
+	//                         ^ finalized before the subscription starts
+	let block_1 = BlockBuilderBuilder::new(&*client)
+		.on_parent_block(client.chain_info().genesis_hash)
+		.with_parent_block_number(0)
+		.build()
+		.unwrap()
+		.build()
+		.unwrap()
+		.block;
+	let block_1_hash = block_1.header.hash();
+	client.import(BlockOrigin::Own, block_1.clone()).await.unwrap();
+
+	let block_2 = BlockBuilderBuilder::new(&*client)
+		.on_parent_block(block_1_hash)
+		.with_parent_block_number(1)
+		.build()
+		.unwrap()
+		.build()
+		.unwrap()
+		.block;
+	let block_2_hash = block_2.header.hash();
+	client.import(BlockOrigin::Own, block_2.clone()).await.unwrap();
+	client.finalize_block(block_2_hash, None).unwrap();
+
+	let mut sub = api.subscribe_unbounded("chainHead_unstable_follow", [false]).await.unwrap();
+	let sub_id = sub.subscription_id();
+	let sub_id = serde_json::to_string(&sub_id).unwrap();
+
+	// Only the finalized block is pinned by the subscription.
+	assert_matches!(
+		get_next_event::<FollowEvent<String>>(&mut sub).await,
+		FollowEvent::Initialized(init) if init.finalized_block_hash == format!("{:?}", block_2_hash)
+	);
+
+	// Block 1 is within the lookback depth.
+	let block_hash = format!("{:?}", block_1_hash);
+	let response: MethodResponse =
+		api.call("chainHead_unstable_body", [&sub_id, &block_hash]).await.unwrap();
+	let operation_id = match response {
+		MethodResponse::Started(started) => started.operation_id,
+		MethodResponse::LimitReached => panic!("Expected started response"),
+	};
+	assert_matches!(
+		get_next_event::<FollowEvent<String>>(&mut sub).await,
+		FollowEvent::OperationBodyDone(done) if done.operation_id == operation_id && done.value.is_empty()
+	);
+
+	// The header of block 1 is also available.
+	let res: String = api.call("chainHead_unstable_header", [&sub_id, &block_hash]).await.unwrap();
+	let bytes = array_bytes::hex2bytes(&res).unwrap();
+	let header: Header = Decode::decode(&mut &bytes[..]).unwrap();
+	assert_eq!(header, block_1.header);
+
+	// The genesis block is beyond the lookback depth.
+	let genesis_hash = format!("{:?}", client.chain_info().genesis_hash);
+	let err = api
+		.call::<_, serde_json::Value>("chainHead_unstable_body", [&sub_id, &genesis_hash])
+		.await
+		.unwrap_err();
+	assert_matches!(err,
+		Error::JsonRpc(ref err) if err.code() == super::error::rpc_spec_v2::INVALID_BLOCK_ERROR
+	);
+}
+
 #[tokio::test]
 async fn call_runtime() {
 	let (_client, api, mut block_sub, sub_id, block) = setup_api().await;
@@ -485,7 +588,7 @@ async fn call_runtime() {
 		.await
 		.unwrap_err();
 	assert_matches!(err,
-		Error::JsonRpc(err) if err.code() == super::error::json_rpc_spec::INVALID_PARAM_ERROR && err.message().contains("Invalid parameter")
+		Error::JsonRpc(err) if err.code() == super::error::json_rpc_spec::INVALID_PARAM_ERROR && ErrorData::of(&err).unwrap().kind == ErrorCode::InvalidParam
 	);
 
 	// Valid call.
@@ -543,6 +646,9 @@ async fn call_runtime_without_flag() {
 			subscription_max_pinned_duration: Duration::from_secs(MAX_PINNED_SECS),
 			subscription_max_ongoing_operations: MAX_OPERATIONS,
 			operation_max_storage_items: MAX_PAGINATION_LIMIT,
+			operation_storage_value_chunk_size: None,
+			subscription_max_watched_keys: MAX_WATCHED_KEYS,
+			finalized_lookback_depth: FINALIZED_LOOKBACK_DEPTH,
 		},
 	)
 	.into_rpc();
@@ -1004,6 +1110,113 @@ async fn get_storage_value() {
 	);
 }
 
+#[tokio::test]
+async fn storage_watch_reports_changes() {
+	let (mut client, api, mut block_sub, sub_id, block) = setup_api().await;
+	let key = hex_string(&KEY);
+
+	// Subscription ID is invalid.
+	let response: MethodResponse = api
+		.call("chainHead_unstable_storageWatch", rpc_params!["invalid_sub_id", vec![key.clone()]])
+		.await
+		.unwrap();
+	assert_matches!(response, MethodResponse::LimitReached);
+
+	let response: MethodResponse = api
+		.call("chainHead_unstable_storageWatch", rpc_params![&sub_id, vec![key.clone()]])
+		.await
+		.unwrap();
+	let operation_id = match response {
+		MethodResponse::Started(started) => started.operation_id,
+		MethodResponse::LimitReached => panic!("Expected started response"),
+	};
+
+	// The subscription can not watch more than `MAX_WATCHED_KEYS` keys.
+	let response: MethodResponse = api
+		.call(
+			"chainHead_unstable_storageWatch",
+			rpc_params![&sub_id, vec![hex_string(b"a"), hex_string(b"b")]],
+		)
+		.await
+		.unwrap();
+	assert_matches!(response, MethodResponse::LimitReached);
+
+	// The first notification reports the current value of the key.
+	let block = BlockBuilderBuilder::new(&*client)
+		.on_parent_block(block.hash())
+		.with_parent_block_number(1)
+		.build()
+		.unwrap()
+		.build()
+		.unwrap()
+		.block;
+	client.import(BlockOrigin::Own, block.clone()).await.unwrap();
+	assert_matches!(
+		get_next_event::<FollowEvent<String>>(&mut block_sub).await,
+		FollowEvent::NewBlock(_)
+	);
+	assert_matches!(
+		get_next_event::<FollowEvent<String>>(&mut block_sub).await,
+		FollowEvent::BestBlockChanged(_)
+	);
+	assert_matches!(
+		get_next_event::<FollowEvent<String>>(&mut block_sub).await,
+		FollowEvent::OperationStorageChanged(changed) if changed.operation_id == operation_id &&
+			changed.block_hash == format!("{:?}", block.hash()) &&
+			changed.items == vec![StorageWatchItem { key: key.clone(), value: None }]
+	);
+
+	// Import a new block with storage changes.
+	let mut builder = BlockBuilderBuilder::new(&*client)
+		.on_parent_block(block.hash())
+		.with_parent_block_number(2)
+		.build()
+		.unwrap();
+	builder.push_storage_change(KEY.to_vec(), Some(VALUE.to_vec())).unwrap();
+	let block = builder.build().unwrap().block;
+	client.import(BlockOrigin::Own, block.clone()).await.unwrap();
+	assert_matches!(
+		get_next_event::<FollowEvent<String>>(&mut block_sub).await,
+		FollowEvent::NewBlock(_)
+	);
+	assert_matches!(
+		get_next_event::<FollowEvent<String>>(&mut block_sub).await,
+		FollowEvent::BestBlockChanged(_)
+	);
+	assert_matches!(
+		get_next_event::<FollowEvent<String>>(&mut block_sub).await,
+		FollowEvent::OperationStorageChanged(changed) if changed.operation_id == operation_id &&
+			changed.items == vec![StorageWatchItem { key: key.clone(), value: Some(hex_string(&VALUE)) }]
+	);
+
+	// No more notifications are generated once the watch is stopped.
+	let _res: () = api
+		.call("chainHead_unstable_stopOperation", rpc_params![&sub_id, &operation_id])
+		.await
+		.unwrap();
+	let mut builder = BlockBuilderBuilder::new(&*client)
+		.on_parent_block(block.hash())
+		.with_parent_block_number(3)
+		.build()
+		.unwrap();
+	builder.push_storage_change(KEY.to_vec(), None).unwrap();
+	let block = builder.build().unwrap().block;
+	client.import(BlockOrigin::Own, block.clone()).await.unwrap();
+	assert_matches!(
+		get_next_event::<FollowEvent<String>>(&mut block_sub).await,
+		FollowEvent::NewBlock(_)
+	);
+	assert_matches!(
+		get_next_event::<FollowEvent<String>>(&mut block_sub).await,
+		FollowEvent::BestBlockChanged(_)
+	);
+	does_not_produce_event::<FollowEvent<String>>(
+		&mut block_sub,
+		std::time::Duration::from_secs(DOES_NOT_PRODUCE_EVENTS_SECONDS),
+	)
+	.await;
+}
+
 #[tokio::test]
 async fn get_storage_non_queryable_key() {
 	let (mut _client, api, mut block_sub, sub_id, block) = setup_api().await;
@@ -1201,6 +1414,9 @@ async fn separate_operation_ids_for_subscriptions() {
 			subscription_max_pinned_duration: Duration::from_secs(MAX_PINNED_SECS),
 			subscription_max_ongoing_operations: MAX_OPERATIONS,
 			operation_max_storage_items: MAX_PAGINATION_LIMIT,
+			operation_storage_value_chunk_size: None,
+			subscription_max_watched_keys: MAX_WATCHED_KEYS,
+			finalized_lookback_depth: FINALIZED_LOOKBACK_DEPTH,
 		},
 	)
 	.into_rpc();
@@ -1289,6 +1505,9 @@ async fn follow_generates_initial_blocks() {
 			subscription_max_pinned_duration: Duration::from_secs(MAX_PINNED_SECS),
 			subscription_max_ongoing_operations: MAX_OPERATIONS,
 			operation_max_storage_items: MAX_PAGINATION_LIMIT,
+			operation_storage_value_chunk_size: None,
+			subscription_max_watched_keys: MAX_WATCHED_KEYS,
+			finalized_lookback_depth: FINALIZED_LOOKBACK_DEPTH,
 		},
 	)
 	.into_rpc();
@@ -1444,6 +1663,9 @@ async fn follow_exceeding_pinned_blocks() {
 			subscription_max_pinned_duration: Duration::from_secs(MAX_PINNED_SECS),
 			subscription_max_ongoing_operations: MAX_OPERATIONS,
 			operation_max_storage_items: MAX_PAGINATION_LIMIT,
+			operation_storage_value_chunk_size: None,
+			subscription_max_watched_keys: MAX_WATCHED_KEYS,
+			finalized_lookback_depth: FINALIZED_LOOKBACK_DEPTH,
 		},
 	)
 	.into_rpc();
@@ -1505,6 +1727,212 @@ async fn follow_exceeding_pinned_blocks() {
 	assert!(sub.next::<FollowEvent<String>>().await.is_none());
 }
 
+#[tokio::test]
+async fn follow_evicts_old_pinned_blocks() {
+	let builder = TestClientBuilder::new();
+	let backend = builder.backend();
+	let mut client = Arc::new(builder.build());
+
+	let api = ChainHead::new(
+		client.clone(),
+		backend,
+		Arc::new(TaskExecutor::default()),
+		ChainHeadConfig {
+			global_max_pinned_blocks: 4,
+			subscription_max_pinned_duration: Duration::from_secs(1),
+			subscription_max_ongoing_operations: MAX_OPERATIONS,
+			operation_max_storage_items: MAX_PAGINATION_LIMIT,
+			operation_storage_value_chunk_size: None,
+			subscription_max_watched_keys: MAX_WATCHED_KEYS,
+			finalized_lookback_depth: FINALIZED_LOOKBACK_DEPTH,
+		},
+	)
+	.into_rpc();
+
+	let finalized_hash = client.info().finalized_hash;
+	let mut sub = api.subscribe_unbounded("chainHead_unstable_follow", [false]).await.unwrap();
+	let sub_id = sub.subscription_id();
+	let sub_id = serde_json::to_string(&sub_id).unwrap();
+
+	assert_matches!(
+		get_next_event::<FollowEvent<String>>(&mut sub).await,
+		FollowEvent::Initialized(_)
+	);
+
+	// Block tree:
+	//
+	// finalized -> block 1 -> block 2
+	//                       -> block 2_f
+	//
+	// All 4 blocks are pinned, which is the limit.
+	let block_1 = BlockBuilderBuilder::new(&*client)
+		.on_parent_block(finalized_hash)
+		.with_parent_block_number(0)
+		.build()
+		.unwrap()
+		.build()
+		.unwrap()
+		.block;
+	let block_1_hash = block_1.hash();
+	client.import(BlockOrigin::Own, block_1.clone()).await.unwrap();
+
+	let block_2 = BlockBuilderBuilder::new(&*client)
+		.on_parent_block(block_1_hash)
+		.with_parent_block_number(1)
+		.build()
+		.unwrap()
+		.build()
+		.unwrap()
+		.block;
+	let block_2_hash = block_2.hash();
+	client.import(BlockOrigin::Own, block_2.clone()).await.unwrap();
+
+	let mut block_builder = BlockBuilderBuilder::new(&*client)
+		.on_parent_block(block_1_hash)
+		.with_parent_block_number(1)
+		.build()
+		.unwrap();
+	// This push is required as otherwise block 2_f has the same hash as block 2 and won't get
+	// imported
+	block_builder
+		.push_transfer(Transfer {
+			from: AccountKeyring::Alice.into(),
+			to: AccountKeyring::Ferdie.into(),
+			amount: 41,
+			nonce: 0,
+		})
+		.unwrap();
+	let block_2_f = block_builder.build().unwrap().block;
+	let block_2_f_hash = block_2_f.hash();
+	client.import(BlockOrigin::Own, block_2_f.clone()).await.unwrap();
+
+	// Events for block 1 and block 2. Block 2_f is not the best block.
+	for _ in 0..2 {
+		assert_matches!(
+			get_next_event::<FollowEvent<String>>(&mut sub).await,
+			FollowEvent::NewBlock(_)
+		);
+		assert_matches!(
+			get_next_event::<FollowEvent<String>>(&mut sub).await,
+			FollowEvent::BestBlockChanged(_)
+		);
+	}
+	assert_matches!(
+		get_next_event::<FollowEvent<String>>(&mut sub).await,
+		FollowEvent::NewBlock(_)
+	);
+
+	// All blocks become older than the maximum pin duration.
+	tokio::time::sleep(Duration::from_secs(2)).await;
+
+	// Block tree:
+	//
+	// finalized -> block 1 -> block 2 -> block 3
+	//                       -> block 2_f
+	//
+	// Pinning block 3 exceeds the limit and evicts all the old blocks instead of stopping the
+	// subscription.
+	let block_3 = BlockBuilderBuilder::new(&*client)
+		.on_parent_block(block_2_hash)
+		.with_parent_block_number(2)
+		.build()
+		.unwrap()
+		.build()
+		.unwrap()
+		.block;
+	let block_3_hash = block_3.hash();
+	client.import(BlockOrigin::Own, block_3.clone()).await.unwrap();
+
+	let event: FollowEvent<String> = get_next_event(&mut sub).await;
+	let expected = FollowEvent::NewBlock(NewBlock {
+		block_hash: format!("{:?}", block_3_hash),
+		parent_block_hash: format!("{:?}", block_2_hash),
+		new_runtime: None,
+		with_runtime: false,
+	});
+	assert_eq!(event, expected);
+	assert_matches!(
+		get_next_event::<FollowEvent<String>>(&mut sub).await,
+		FollowEvent::BestBlockChanged(_)
+	);
+
+	let event: FollowEvent<String> = get_next_event(&mut sub).await;
+	let FollowEvent::PinnedBlocksEvicted(PinnedBlocksEvicted { block_hashes }) = event else {
+		panic!("Expected the `PinnedBlocksEvicted` event");
+	};
+	let evicted: HashSet<_> = block_hashes.into_iter().collect();
+	let expected: HashSet<_> = [finalized_hash, block_1_hash, block_2_hash, block_2_f_hash]
+		.iter()
+		.map(|hash| format!("{:?}", hash))
+		.collect();
+	assert_eq!(evicted, expected);
+
+	// Evicted blocks are no longer available for this subscription.
+	let block_1_hash_str = format!("{:?}", block_1_hash);
+	let err = api
+		.call::<_, serde_json::Value>(
+			"chainHead_unstable_header",
+			rpc_params![&sub_id, &block_1_hash_str],
+		)
+		.await
+		.unwrap_err();
+	assert_matches!(err,
+		Error::JsonRpc(ref err) if err.code() == super::error::rpc_spec_v2::INVALID_BLOCK_ERROR && err.message() == "Invalid block hash"
+	);
+	let err = api
+		.call::<_, serde_json::Value>(
+			"chainHead_unstable_unpin",
+			rpc_params![&sub_id, &block_1_hash_str],
+		)
+		.await
+		.unwrap_err();
+	assert_matches!(err,
+		Error::JsonRpc(ref err) if err.code() == super::error::rpc_spec_v2::INVALID_BLOCK_ERROR && err.message() == "Invalid block hash"
+	);
+
+	// The newest block is still pinned.
+	let block_3_hash_str = format!("{:?}", block_3_hash);
+	let _res: String = api
+		.call("chainHead_unstable_header", [&sub_id, &block_3_hash_str])
+		.await
+		.unwrap();
+
+	// Finalizing block 3 prunes block 2_f, which was already evicted. The finalized blocks are
+	// reported without generating new events for the evicted blocks.
+	client.finalize_block(block_3_hash, None).unwrap();
+
+	let event: FollowEvent<String> = get_next_event(&mut sub).await;
+	let expected = FollowEvent::Finalized(Finalized {
+		finalized_block_hashes: vec![
+			format!("{:?}", block_1_hash),
+			format!("{:?}", block_2_hash),
+			format!("{:?}", block_3_hash),
+		],
+		pruned_block_hashes: vec![format!("{:?}", block_2_f_hash)],
+	});
+	assert_eq!(event, expected);
+
+	// The subscription keeps producing events.
+	let block_4 = BlockBuilderBuilder::new(&*client)
+		.on_parent_block(block_3_hash)
+		.with_parent_block_number(3)
+		.build()
+		.unwrap()
+		.build()
+		.unwrap()
+		.block;
+	client.import(BlockOrigin::Own, block_4.clone()).await.unwrap();
+
+	assert_matches!(
+		get_next_event::<FollowEvent<String>>(&mut sub).await,
+		FollowEvent::NewBlock(_)
+	);
+	assert_matches!(
+		get_next_event::<FollowEvent<String>>(&mut sub).await,
+		FollowEvent::BestBlockChanged(_)
+	);
+}
+
 #[tokio::test]
 async fn follow_with_unpin() {
 	let builder = TestClientBuilder::new();
@@ -1520,6 +1948,9 @@ async fn follow_with_unpin() {
 			subscription_max_pinned_duration: Duration::from_secs(MAX_PINNED_SECS),
 			subscription_max_ongoing_operations: MAX_OPERATIONS,
 			operation_max_storage_items: MAX_PAGINATION_LIMIT,
+			operation_storage_value_chunk_size: None,
+			subscription_max_watched_keys: MAX_WATCHED_KEYS,
+			finalized_lookback_depth: FINALIZED_LOOKBACK_DEPTH,
 		},
 	)
 	.into_rpc();
@@ -1631,6 +2062,9 @@ async fn unpin_duplicate_hashes() {
 			subscription_max_pinned_duration: Duration::from_secs(MAX_PINNED_SECS),
 			subscription_max_ongoing_operations: MAX_OPERATIONS,
 			operation_max_storage_items: MAX_PAGINATION_LIMIT,
+			operation_storage_value_chunk_size: None,
+			subscription_max_watched_keys: MAX_WATCHED_KEYS,
+			finalized_lookback_depth: FINALIZED_LOOKBACK_DEPTH,
 		},
 	)
 	.into_rpc();
@@ -1673,7 +2107,7 @@ async fn unpin_duplicate_hashes() {
 		.await
 		.unwrap_err();
 	assert_matches!(err,
-		Error::JsonRpc(err) if err.code() == super::error::rpc_spec_v2::INVALID_DUPLICATE_HASHES && err.message() == "Received duplicate hashes for the `chainHead_unpin` method"
+		Error::JsonRpc(err) if err.code() == super::error::rpc_spec_v2::INVALID_DUPLICATE_HASHES && ErrorData::of(&err).unwrap().kind == ErrorCode::InvalidDuplicateHashes
 	);
 
 	// Block tree:
@@ -1733,6 +2167,9 @@ async fn follow_with_multiple_unpin_hashes() {
 			subscription_max_pinned_duration: Duration::from_secs(MAX_PINNED_SECS),
 			subscription_max_ongoing_operations: MAX_OPERATIONS,
 			operation_max_storage_items: MAX_PAGINATION_LIMIT,
+			operation_storage_value_chunk_size: None,
+			subscription_max_watched_keys: MAX_WATCHED_KEYS,
+			finalized_lookback_depth: FINALIZED_LOOKBACK_DEPTH,
 		},
 	)
 	.into_rpc();
@@ -1886,6 +2323,9 @@ async fn follow_prune_best_block() {
 			subscription_max_pinned_duration: Duration::from_secs(MAX_PINNED_SECS),
 			subscription_max_ongoing_operations: MAX_OPERATIONS,
 			operation_max_storage_items: MAX_PAGINATION_LIMIT,
+			operation_storage_value_chunk_size: None,
+			subscription_max_watched_keys: MAX_WATCHED_KEYS,
+			finalized_lookback_depth: FINALIZED_LOOKBACK_DEPTH,
 		},
 	)
 	.into_rpc();
@@ -2071,6 +2511,9 @@ async fn follow_forks_pruned_block() {
 			subscription_max_pinned_duration: Duration::from_secs(MAX_PINNED_SECS),
 			subscription_max_ongoing_operations: MAX_OPERATIONS,
 			operation_max_storage_items: MAX_PAGINATION_LIMIT,
+			operation_storage_value_chunk_size: None,
+			subscription_max_watched_keys: MAX_WATCHED_KEYS,
+			finalized_lookback_depth: FINALIZED_LOOKBACK_DEPTH,
 		},
 	)
 	.into_rpc();
@@ -2222,6 +2665,9 @@ async fn follow_report_multiple_pruned_block() {
 			subscription_max_pinned_duration: Duration::from_secs(MAX_PINNED_SECS),
 			subscription_max_ongoing_operations: MAX_OPERATIONS,
 			operation_max_storage_items: MAX_PAGINATION_LIMIT,
+			operation_storage_value_chunk_size: None,
+			subscription_max_watched_keys: MAX_WATCHED_KEYS,
+			finalized_lookback_depth: FINALIZED_LOOKBACK_DEPTH,
 		},
 	)
 	.into_rpc();
@@ -2467,6 +2913,9 @@ async fn pin_block_references() {
 			subscription_max_pinned_duration: Duration::from_secs(MAX_PINNED_SECS),
 			subscription_max_ongoing_operations: MAX_OPERATIONS,
 			operation_max_storage_items: MAX_PAGINATION_LIMIT,
+			operation_storage_value_chunk_size: None,
+			subscription_max_watched_keys: MAX_WATCHED_KEYS,
+			finalized_lookback_depth: FINALIZED_LOOKBACK_DEPTH,
 		},
 	)
 	.into_rpc();
@@ -2604,6 +3053,9 @@ async fn follow_finalized_before_new_block() {
 			subscription_max_pinned_duration: Duration::from_secs(MAX_PINNED_SECS),
 			subscription_max_ongoing_operations: MAX_OPERATIONS,
 			operation_max_storage_items: MAX_PAGINATION_LIMIT,
+			operation_storage_value_chunk_size: None,
+			subscription_max_watched_keys: MAX_WATCHED_KEYS,
+			finalized_lookback_depth: FINALIZED_LOOKBACK_DEPTH,
 		},
 	)
 	.into_rpc();
@@ -2718,6 +3170,9 @@ async fn ensure_operation_limits_works() {
 			subscription_max_pinned_duration: Duration::from_secs(MAX_PINNED_SECS),
 			subscription_max_ongoing_operations: 1,
 			operation_max_storage_items: MAX_PAGINATION_LIMIT,
+			operation_storage_value_chunk_size: None,
+			subscription_max_watched_keys: MAX_WATCHED_KEYS,
+			finalized_lookback_depth: FINALIZED_LOOKBACK_DEPTH,
 		},
 	)
 	.into_rpc();
@@ -2822,6 +3277,9 @@ async fn check_continue_operation() {
 			subscription_max_pinned_duration: Duration::from_secs(MAX_PINNED_SECS),
 			subscription_max_ongoing_operations: MAX_OPERATIONS,
 			operation_max_storage_items: 1,
+			operation_storage_value_chunk_size: None,
+			subscription_max_watched_keys: MAX_WATCHED_KEYS,
+			finalized_lookback_depth: FINALIZED_LOOKBACK_DEPTH,
 		},
 	)
 	.into_rpc();
@@ -2983,6 +3441,108 @@ async fn check_continue_operation() {
 	);
 }
 
+#[tokio::test]
+async fn storage_value_chunks() {
+	let builder = TestClientBuilder::new();
+	let backend = builder.backend();
+	let mut client = Arc::new(builder.build());
+
+	// Configure the chainHead to report at most 4 bytes of a value per event.
+	let api = ChainHead::new(
+		client.clone(),
+		backend,
+		Arc::new(TaskExecutor::default()),
+		ChainHeadConfig {
+			global_max_pinned_blocks: MAX_PINNED_BLOCKS,
+			subscription_max_pinned_duration: Duration::from_secs(MAX_PINNED_SECS),
+			subscription_max_ongoing_operations: MAX_OPERATIONS,
+			operation_max_storage_items: MAX_PAGINATION_LIMIT,
+			operation_storage_value_chunk_size: Some(4),
+			subscription_max_watched_keys: MAX_WATCHED_KEYS,
+			finalized_lookback_depth: FINALIZED_LOOKBACK_DEPTH,
+		},
+	)
+	.into_rpc();
+
+	let mut sub = api.subscribe_unbounded("chainHead_unstable_follow", [true]).await.unwrap();
+	let sub_id = sub.subscription_id();
+	let sub_id = serde_json::to_string(&sub_id).unwrap();
+
+	// Import a new block with storage changes.
+	let mut builder = BlockBuilderBuilder::new(&*client)
+		.on_parent_block(client.chain_info().genesis_hash)
+		.with_parent_block_number(0)
+		.build()
+		.unwrap();
+	builder.push_storage_change(b":moc".to_vec(), Some(b"abc".to_vec())).unwrap();
+	builder.push_storage_change(KEY.to_vec(), Some(VALUE.to_vec())).unwrap();
+	let block = builder.build().unwrap().block;
+	let block_hash = format!("{:?}", block.header.hash());
+	client.import(BlockOrigin::Own, block.clone()).await.unwrap();
+
+	// Ensure the imported block is propagated and pinned for this subscription.
+	assert_matches!(
+		get_next_event::<FollowEvent<String>>(&mut sub).await,
+		FollowEvent::Initialized(_)
+	);
+	assert_matches!(
+		get_next_event::<FollowEvent<String>>(&mut sub).await,
+		FollowEvent::NewBlock(_)
+	);
+	assert_matches!(
+		get_next_event::<FollowEvent<String>>(&mut sub).await,
+		FollowEvent::BestBlockChanged(_)
+	);
+
+	let response: MethodResponse = api
+		.call(
+			"chainHead_unstable_storage",
+			rpc_params![
+				&sub_id,
+				&block_hash,
+				vec![
+					StorageQuery { key: hex_string(b":moc"), query_type: StorageQueryType::Value },
+					StorageQuery { key: hex_string(&KEY), query_type: StorageQueryType::Value },
+				]
+			],
+		)
+		.await
+		.unwrap();
+	let operation_id = match response {
+		MethodResponse::Started(started) => started.operation_id,
+		MethodResponse::LimitReached => panic!("Expected started response"),
+	};
+
+	// Values fitting into a chunk are reported as usual.
+	assert_matches!(
+		get_next_event::<FollowEvent<String>>(&mut sub).await,
+		FollowEvent::OperationStorageItems(res) if res.operation_id == operation_id &&
+			res.items.len() == 1 &&
+			res.items[0].key == hex_string(b":moc") &&
+			res.items[0].result == StorageResultType::Value(hex_string(b"abc")) &&
+			res.items[0].chunk.is_none()
+	);
+
+	// Larger values are reported by one event per chunk.
+	for (offset, chunk) in
+		[(0, b"hell".as_slice()), (4, b"o wo".as_slice()), (8, b"rld".as_slice())]
+	{
+		assert_matches!(
+			get_next_event::<FollowEvent<String>>(&mut sub).await,
+			FollowEvent::OperationStorageItems(res) if res.operation_id == operation_id &&
+				res.items.len() == 1 &&
+				res.items[0].key == hex_string(&KEY) &&
+				res.items[0].result == StorageResultType::Value(hex_string(&chunk)) &&
+				res.items[0].chunk == Some(StorageValueChunk { offset, total_length: VALUE.len() })
+		);
+	}
+
+	assert_matches!(
+		get_next_event::<FollowEvent<String>>(&mut sub).await,
+		FollowEvent::OperationStorageDone(done) if done.operation_id == operation_id
+	);
+}
+
 #[tokio::test]
 async fn stop_storage_operation() {
 	let child_info = ChildInfo::new_default(CHILD_STORAGE_KEY);
@@ -3004,6 +3564,9 @@ async fn stop_storage_operation() {
 			subscription_max_pinned_duration: Duration::from_secs(MAX_PINNED_SECS),
 			subscription_max_ongoing_operations: MAX_OPERATIONS,
 			operation_max_storage_items: 1,
+			operation_storage_value_chunk_size: None,
+			subscription_max_watched_keys: MAX_WATCHED_KEYS,
+			finalized_lookback_depth: FINALIZED_LOOKBACK_DEPTH,
 		},
 	)
 	.into_rpc();
@@ -78,6 +78,22 @@ pub struct StorageResult {
 	/// The result of the query.
 	#[serde(flatten)]
 	pub result: StorageResultType,
+	/// Position of the reported value within the full storage value.
+	///
+	/// Only present if the value was split into chunks reported by multiple results.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	#[serde(default)]
+	pub chunk: Option<StorageValueChunk>,
+}
+
+/// Identifies a chunk of a storage value that was too large to be reported at once.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageValueChunk {
+	/// Offset in bytes of the chunk within the storage value.
+	pub offset: usize,
+	/// Length in bytes of the full storage value.
+	pub total_length: usize,
 }
 
 /// The type of the storage query.
@@ -103,6 +119,22 @@ pub struct StorageResultErr {
 	pub error: StorageResultType,
 }
 
+/// The result of the `archive_unstable_events` method.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveEventsResult {
+	/// The hexadecimal-encoded SCALE-encoded events of the block.
+	pub events: String,
+	/// The spec version of the runtime that produced the events.
+	pub spec_version: u32,
+	/// The events decoded with the metadata of the runtime.
+	///
+	/// Only present if the server is able to decode the events.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	#[serde(default)]
+	pub decoded: Option<serde_json::Value>,
+}
+
 /// The result of a storage call.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -150,8 +182,11 @@ mod tests {
 	#[test]
 	fn storage_result() {
 		// Item with Value.
-		let item =
-			StorageResult { key: "0x1".into(), result: StorageResultType::Value("res".into()) };
+		let item = StorageResult {
+			key: "0x1".into(),
+			result: StorageResultType::Value("res".into()),
+			chunk: None,
+		};
 		// Encode
 		let ser = serde_json::to_string(&item).unwrap();
 		let exp = r#"{"key":"0x1","value":"res"}"#;
@@ -161,8 +196,11 @@ mod tests {
 		assert_eq!(dec, item);
 
 		// Item with Hash.
-		let item =
-			StorageResult { key: "0x1".into(), result: StorageResultType::Hash("res".into()) };
+		let item = StorageResult {
+			key: "0x1".into(),
+			result: StorageResultType::Hash("res".into()),
+			chunk: None,
+		};
 		// Encode
 		let ser = serde_json::to_string(&item).unwrap();
 		let exp = r#"{"key":"0x1","hash":"res"}"#;
@@ -175,6 +213,7 @@ mod tests {
 		let item = StorageResult {
 			key: "0x1".into(),
 			result: StorageResultType::ClosestDescendantMerkleValue("res".into()),
+			chunk: None,
 		};
 		// Encode
 		let ser = serde_json::to_string(&item).unwrap();
@@ -183,6 +222,20 @@ mod tests {
 		// Decode
 		let dec: StorageResult = serde_json::from_str(exp).unwrap();
 		assert_eq!(dec, item);
+
+		// Item with a chunk of a Value.
+		let item = StorageResult {
+			key: "0x1".into(),
+			result: StorageResultType::Value("0x02".into()),
+			chunk: Some(StorageValueChunk { offset: 1, total_length: 3 }),
+		};
+		// Encode
+		let ser = serde_json::to_string(&item).unwrap();
+		let exp = r#"{"key":"0x1","value":"0x02","chunk":{"offset":1,"totalLength":3}}"#;
+		assert_eq!(ser, exp);
+		// Decode
+		let dec: StorageResult = serde_json::from_str(exp).unwrap();
+		assert_eq!(dec, item);
 	}
 
 	#[test]
@@ -88,6 +88,7 @@ where
 				QueryResult::Ok(opt.map(|storage_data| StorageResult {
 					key: hex_string(&key.0),
 					result: StorageResultType::Value(hex_string(&storage_data.0)),
+					chunk: None,
 				}))
 			})
 			.unwrap_or_else(|error| QueryResult::Err(error.to_string()))
@@ -111,6 +112,7 @@ where
 				QueryResult::Ok(opt.map(|storage_data| StorageResult {
 					key: hex_string(&key.0),
 					result: StorageResultType::Hash(hex_string(&storage_data.as_ref())),
+					chunk: None,
 				}))
 			})
 			.unwrap_or_else(|error| QueryResult::Err(error.to_string()))
@@ -140,6 +142,7 @@ where
 					StorageResult {
 						key: hex_string(&key.0),
 						result: StorageResultType::ClosestDescendantMerkleValue(result),
+						chunk: None,
 					}
 				}))
 			})
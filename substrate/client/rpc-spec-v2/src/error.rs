@@ -0,0 +1,154 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Error codes of the RPC methods of this crate.
+//!
+//! Every error object returned by the methods of the `transaction`, `chainHead` and `archive`
+//! modules carries an [`ErrorData`] in its `data` field. Its `kind` identifies the error without
+//! parsing the message, which is meant for humans and may change. The numeric code of the error
+//! object is the one of its [`ErrorCode`], which several kinds may share, e.g. the generic codes
+//! of the JSON-RPC specification.
+
+use jsonrpsee::types::error::ErrorObject;
+use serde::{Deserialize, Serialize};
+
+/// The kinds of errors returned by the RPC methods of this crate.
+///
+/// Both the numeric codes and the names of the kinds are stable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ErrorCode {
+	/// Invalid parameter provided to the method.
+	InvalidParam,
+	/// Internal error of the server.
+	Internal,
+	/// The provided block hash is invalid.
+	InvalidBlock,
+	/// The `chainHead_follow` subscription was started with `withRuntime` set to `false`.
+	InvalidRuntimeCall,
+	/// Wait-for-continue event not generated.
+	InvalidContinue,
+	/// Received duplicate hashes for the `chainHead_unpin` method.
+	InvalidDuplicateHashes,
+	/// The transaction could not be decoded. The code is the one of the old `author` API.
+	BadTransactionFormat,
+	/// The transaction failed the validation requested for its broadcast.
+	InvalidTransaction,
+	/// The provided operation ID is invalid.
+	InvalidOperationId,
+	/// The nonce of the account could not be queried from the runtime.
+	NonceUnavailable,
+	/// Invalid parameter provided to an `archive` method.
+	ArchiveInvalidParam,
+	/// A runtime call of an `archive` method failed.
+	ArchiveRuntimeCall,
+	/// Failed to fetch the leaves of the chain.
+	ArchiveFetchLeaves,
+	/// Failed to fetch the events of a block.
+	ArchiveFetchEvents,
+}
+
+/// General purpose error codes, as defined in
+/// <https://www.jsonrpc.org/specification#error_object>.
+mod json_rpc_spec {
+	pub const INVALID_PARAM_ERROR: i32 = -32602;
+	pub const INTERNAL_ERROR: i32 = -32603;
+}
+
+/// Base code of the `transaction` errors that are not defined by the specification.
+const TRANSACTION_BASE_ERROR: i32 = 1000;
+/// Base code of the `archive` errors.
+const ARCHIVE_BASE_ERROR: i32 = 3000;
+
+impl ErrorCode {
+	/// The numeric code of the error objects of this kind.
+	pub const fn code(self) -> i32 {
+		match self {
+			Self::InvalidParam | Self::InvalidTransaction | Self::InvalidOperationId =>
+				json_rpc_spec::INVALID_PARAM_ERROR,
+			Self::Internal | Self::NonceUnavailable => json_rpc_spec::INTERNAL_ERROR,
+			// Defined by <https://github.com/paritytech/json-rpc-interface-spec>.
+			Self::InvalidBlock => -32801,
+			Self::InvalidRuntimeCall => -32802,
+			Self::InvalidContinue => -32803,
+			Self::InvalidDuplicateHashes => -32804,
+			Self::BadTransactionFormat => TRANSACTION_BASE_ERROR + 1,
+			Self::ArchiveInvalidParam => ARCHIVE_BASE_ERROR + 1,
+			Self::ArchiveRuntimeCall => ARCHIVE_BASE_ERROR + 2,
+			Self::ArchiveFetchLeaves => ARCHIVE_BASE_ERROR + 3,
+			Self::ArchiveFetchEvents => ARCHIVE_BASE_ERROR + 4,
+		}
+	}
+
+	/// An error object of this kind with the given `message` and `details`.
+	pub fn error_object(self, message: String, details: Option<String>) -> ErrorObject<'static> {
+		ErrorObject::owned(self.code(), message, Some(ErrorData { kind: self, details }))
+	}
+}
+
+/// The `data` of the error objects returned by the RPC methods of this crate.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorData {
+	/// The kind of the error.
+	pub kind: ErrorCode,
+	/// Details about the error, e.g. the invalid parameter or the message of the underlying
+	/// error.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub details: Option<String>,
+}
+
+impl ErrorData {
+	/// The data of an error object returned by an RPC method of this crate, if any.
+	pub fn of(error: &ErrorObject) -> Option<Self> {
+		error.data().and_then(|data| serde_json::from_str(data.get()).ok())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn error_data_roundtrips() {
+		let error = ErrorCode::InvalidOperationId.error_object("Invalid operation id".into(), None);
+		assert_eq!(error.code(), -32602);
+		assert_eq!(error.data().unwrap().get(), r#"{"kind":"invalidOperationId"}"#);
+		assert_eq!(
+			ErrorData::of(&error),
+			Some(ErrorData { kind: ErrorCode::InvalidOperationId, details: None })
+		);
+
+		let error =
+			ErrorCode::ArchiveFetchLeaves.error_object("Failed".into(), Some("reason".into()));
+		assert_eq!(error.code(), 3003);
+		assert_eq!(
+			error.data().unwrap().get(),
+			r#"{"kind":"archiveFetchLeaves","details":"reason"}"#
+		);
+	}
+
+	#[test]
+	fn error_data_of_foreign_errors_is_none() {
+		let error = ErrorObject::owned(-32602, "Invalid params", None::<()>);
+		assert_eq!(ErrorData::of(&error), None);
+
+		let error = ErrorObject::owned(-32602, "Invalid params", Some("data"));
+		assert_eq!(ErrorData::of(&error), None);
+	}
+}
@@ -19,6 +19,9 @@
 //! Substrate JSON-RPC interface v2.
 //!
 //! Specification [document](https://paritytech.github.io/json-rpc-interface-spec/).
+//!
+//! The errors of the methods carry a machine-readable [`error::ErrorData`], whose kinds are
+//! listed in the `# Errors` section of the documentation of each method.
 
 #![warn(missing_docs)]
 #![deny(unused_crate_dependencies)]
@@ -28,9 +31,11 @@ use sp_core::hexdisplay::{AsBytesRef, HexDisplay};
 
 mod common;
 
+pub mod aliases;
 pub mod archive;
 pub mod chain_head;
 pub mod chain_spec;
+pub mod error;
 pub mod transaction;
 
 /// Task executor that is being used by RPC subscriptions.
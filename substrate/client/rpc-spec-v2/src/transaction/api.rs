@@ -18,7 +18,12 @@
 
 //! API trait for transactions.
 
-use crate::transaction::{error::ErrorBroadcast, event::TransactionEvent};
+#[cfg(doc)]
+use crate::error::ErrorCode;
+use crate::transaction::{
+	error::{ErrorBroadcast, ErrorNonce},
+	event::TransactionEvent,
+};
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
 use sp_core::Bytes;
 
@@ -29,6 +34,10 @@ pub trait TransactionApi<Hash: Clone> {
 	/// See [`TransactionEvent`](crate::transaction::event::TransactionEvent) for details on
 	/// transaction life cycle.
 	///
+	/// # Errors
+	///
+	/// - [`ErrorCode::BadTransactionFormat`] if the extrinsic can not be decoded.
+	///
 	/// # Unstable
 	///
 	/// This method is unstable and subject to change in the future.
@@ -41,20 +50,91 @@ pub trait TransactionApi<Hash: Clone> {
 }
 
 #[rpc(client, server)]
-pub trait TransactionBroadcastApi {
+pub trait TransactionBroadcastApi<Hash: Clone> {
 	/// Broadcast an extrinsic to the chain.
 	///
+	/// If an `idempotency_key` is provided, the operation ID is derived from the key and the
+	/// extrinsic. Calling this method again from the same client with the same key and extrinsic
+	/// while the broadcast is running returns the same operation ID instead of starting another
+	/// broadcast, which makes it safe to retry calls that failed because of network errors.
+	/// Clients are identified by their address, and the number of running operations started
+	/// with a key is limited per client.
+	///
+	/// If `validate` is `true`, the extrinsic is decoded and submitted to the pool at the current
+	/// best block before the call returns. An extrinsic that can't be decoded or is invalid is
+	/// reported as an error instead of starting a broadcast.
+	///
+	/// This method is unsafe and can only be called if unsafe RPC calls are allowed.
+	///
+	/// # Errors
+	///
+	/// - [`ErrorCode::BadTransactionFormat`] if `validate` is `true` and the extrinsic can't be
+	///   decoded.
+	/// - [`ErrorCode::InvalidTransaction`] if `validate` is `true` and the extrinsic is invalid.
+	///
 	/// # Unstable
 	///
 	/// This method is unstable and subject to change in the future.
 	#[method(name = "transaction_unstable_broadcast")]
-	fn broadcast(&self, bytes: Bytes) -> RpcResult<Option<String>>;
+	async fn broadcast(
+		&self,
+		bytes: Bytes,
+		idempotency_key: Option<String>,
+		validate: Option<bool>,
+	) -> RpcResult<Option<String>>;
 
 	/// Broadcast an extrinsic to the chain.
 	///
+	/// This method is unsafe and can only be called if unsafe RPC calls are allowed.
+	///
+	/// # Errors
+	///
+	/// - [`ErrorCode::InvalidOperationId`] if the operation is unknown or already finished.
+	///
 	/// # Unstable
 	///
 	/// This method is unstable and subject to change in the future.
 	#[method(name = "transaction_unstable_stop")]
-	fn stop_broadcast(&self, operation_id: String) -> Result<(), ErrorBroadcast>;
+	fn stop_broadcast(&self, operation_id: String) -> RpcResult<()>;
+
+	/// Returns the latest statuses of the extrinsic of a broadcast operation, oldest first.
+	///
+	/// The statuses are the events of `transactionWatch_unstable_submitAndWatch`, for every
+	/// submission of the extrinsic by the broadcast. This lets clients that can't keep a
+	/// subscription open poll the progress of a broadcast. Only the most recent statuses of an
+	/// operation are retained, as are the statuses of the most recently finished operations.
+	///
+	/// # Errors
+	///
+	/// - [`ErrorCode::InvalidOperationId`] if the operation is unknown, was stopped or finished too
+	///   long ago.
+	///
+	/// # Unstable
+	///
+	/// This method is unstable and subject to change in the future.
+	#[method(name = "transaction_unstable_status")]
+	fn broadcast_status(
+		&self,
+		operation_id: String,
+	) -> Result<Vec<TransactionEvent<Hash>>, ErrorBroadcast>;
+}
+
+#[rpc(client, server)]
+pub trait TransactionNonceApi<AccountId, Nonce> {
+	/// Returns the nonce to use for the next transaction of `account`.
+	///
+	/// Starts from the nonce of the account at the best block and skips the nonces of the
+	/// transactions of the account in the pool, ready and future ones alike. If the future
+	/// transactions leave a gap, the first missing nonce is returned so that the new transaction
+	/// fills it. Otherwise this is the nonce following the last transaction in the pool.
+	///
+	/// # Errors
+	///
+	/// - [`ErrorCode::NonceUnavailable`] if the nonce of the account can not be queried.
+	///
+	/// # Unstable
+	///
+	/// This method is unstable and subject to change in the future.
+	#[method(name = "transaction_unstable_nextNonce")]
+	async fn next_nonce(&self, account: AccountId) -> Result<Nonce, ErrorNonce>;
 }
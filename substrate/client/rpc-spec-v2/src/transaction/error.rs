@@ -20,7 +20,10 @@
 //!
 //! Errors are interpreted as transaction events for subscriptions.
 
-use crate::transaction::event::{TransactionError, TransactionEvent};
+use crate::{
+	error::ErrorCode,
+	transaction::event::{TransactionError, TransactionEvent},
+};
 use jsonrpsee::types::error::ErrorObject;
 use sc_transaction_pool_api::error::Error as PoolError;
 use sp_runtime::transaction_validity::InvalidTransaction;
@@ -96,6 +99,10 @@ impl<Hash> From<Error> for TransactionEvent<Hash> {
 				TransactionEvent::Invalid(TransactionError {
 					error: "The pool is not accepting future transactions".into(),
 				}),
+			Error::Pool(PoolError::RecentlyIncluded) =>
+				TransactionEvent::Invalid(TransactionError {
+					error: "The transaction was already included in a recent block".into(),
+				}),
 		}
 	}
 }
@@ -106,13 +113,31 @@ pub enum ErrorBroadcast {
 	/// The provided operation ID is invalid.
 	#[error("Invalid operation id")]
 	InvalidOperationID,
+	/// The extrinsic of a broadcast with validation can't be decoded.
+	#[error("Extrinsic has invalid format: {0}")]
+	BadTransactionFormat(codec::Error),
+	/// The transaction pool rejected the extrinsic of a broadcast with validation.
+	#[error("Invalid transaction: {0}")]
+	InvalidTransaction(Error),
+}
+
+/// TransactionNonce error.
+#[derive(Debug, thiserror::Error)]
+pub enum ErrorNonce {
+	/// The nonce of the account couldn't be queried from the runtime.
+	#[error("Unable to query the account nonce: {0}")]
+	RuntimeCall(String),
 }
 
 /// General purpose errors, as defined in
 /// <https://www.jsonrpc.org/specification#error_object>.
 pub mod json_rpc_spec {
+	use super::ErrorCode;
+
 	/// Invalid parameter error.
-	pub const INVALID_PARAM_ERROR: i32 = -32602;
+	pub const INVALID_PARAM_ERROR: i32 = ErrorCode::InvalidParam.code();
+	/// Internal error.
+	pub const INTERNAL_ERROR: i32 = ErrorCode::Internal.code();
 }
 
 impl From<ErrorBroadcast> for ErrorObject<'static> {
@@ -121,7 +146,22 @@ impl From<ErrorBroadcast> for ErrorObject<'static> {
 
 		match e {
 			ErrorBroadcast::InvalidOperationID =>
-				ErrorObject::owned(json_rpc_spec::INVALID_PARAM_ERROR, msg, None::<()>),
+				ErrorCode::InvalidOperationId.error_object(msg, None),
+			ErrorBroadcast::BadTransactionFormat(err) =>
+				ErrorCode::BadTransactionFormat.error_object(msg, Some(err.to_string())),
+			ErrorBroadcast::InvalidTransaction(err) =>
+				ErrorCode::InvalidTransaction.error_object(msg, Some(err.to_string())),
+		}
+	}
+}
+
+impl From<ErrorNonce> for ErrorObject<'static> {
+	fn from(e: ErrorNonce) -> Self {
+		let msg = e.to_string();
+
+		match e {
+			ErrorNonce::RuntimeCall(err) =>
+				ErrorCode::NonceUnavailable.error_object(msg, Some(err)),
 		}
 	}
 }
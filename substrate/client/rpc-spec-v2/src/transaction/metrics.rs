@@ -0,0 +1,152 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Prometheus metrics of the transaction methods.
+
+use prometheus_endpoint::{
+	register, CounterVec, Gauge, Histogram, HistogramOpts, Opts, PrometheusError, Registry, U64,
+};
+use std::time::Instant;
+
+/// Histogram time buckets in seconds.
+const HISTOGRAM_BUCKETS: [f64; 10] =
+	[6.0, 12.0, 18.0, 30.0, 60.0, 120.0, 300.0, 600.0, 1_800.0, 3_600.0];
+
+/// How a broadcast operation terminated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BroadcastOutcome {
+	/// The transaction was finalized.
+	Success,
+	/// The transaction is invalid.
+	Invalid,
+	/// The transaction was replaced by another one providing the same tags.
+	Usurped,
+	/// The transaction was dropped and could not be resubmitted anymore.
+	Dropped,
+	/// The operation was stopped by `transaction_v1_stop`.
+	Stopped,
+}
+
+impl BroadcastOutcome {
+	fn as_str(&self) -> &'static str {
+		match self {
+			Self::Success => "success",
+			Self::Invalid => "invalid",
+			Self::Usurped => "usurped",
+			Self::Dropped => "dropped",
+			Self::Stopped => "stopped",
+		}
+	}
+}
+
+/// Metrics of the transaction methods, counting the outcomes of broadcast operations and timing
+/// the finalization of watched transactions.
+#[derive(Debug, Clone)]
+pub struct TransactionMetrics {
+	/// Number of terminated broadcast operations, by outcome.
+	broadcast_outcomes: CounterVec<U64>,
+	/// Number of currently running broadcast operations.
+	active_broadcasts: Gauge<U64>,
+	/// Histogram over the time from the submission to the finalization of transactions.
+	finalization_time: Histogram,
+}
+
+impl TransactionMetrics {
+	/// Create an instance of metrics
+	pub fn new(metrics_registry: Option<&Registry>) -> Result<Option<Self>, PrometheusError> {
+		let Some(metrics_registry) = metrics_registry else { return Ok(None) };
+
+		Ok(Some(Self {
+			broadcast_outcomes: register(
+				CounterVec::new(
+					Opts::new(
+						"substrate_rpc_transaction_broadcast_outcomes",
+						"Number of terminated transaction broadcast operations by outcome",
+					),
+					&["outcome"],
+				)?,
+				metrics_registry,
+			)?,
+			active_broadcasts: register(
+				Gauge::new(
+					"substrate_rpc_transaction_active_broadcasts",
+					"Number of running transaction broadcast operations",
+				)?,
+				metrics_registry,
+			)?,
+			finalization_time: register(
+				Histogram::with_opts(
+					HistogramOpts::new(
+						"substrate_rpc_transaction_finalization_time",
+						"Time [s] from the submission to the finalization of watched transactions",
+					)
+					.buckets(HISTOGRAM_BUCKETS.to_vec()),
+				)?,
+				metrics_registry,
+			)?,
+		}))
+	}
+
+	/// A broadcast operation started.
+	pub(crate) fn broadcast_started(&self) {
+		self.active_broadcasts.inc();
+	}
+
+	/// A broadcast operation terminated with `outcome`.
+	pub(crate) fn broadcast_finished(&self, outcome: BroadcastOutcome) {
+		self.active_broadcasts.dec();
+		self.broadcast_outcomes.with_label_values(&[outcome.as_str()]).inc();
+	}
+
+	/// A transaction submitted at `submitted` was finalized.
+	pub(crate) fn transaction_finalized(&self, submitted: Instant) {
+		self.finalization_time.observe(submitted.elapsed().as_secs_f64());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn metrics_are_disabled_without_registry() {
+		assert!(TransactionMetrics::new(None).unwrap().is_none());
+	}
+
+	#[test]
+	fn broadcast_outcomes_are_counted_separately() {
+		let registry = Registry::new();
+		let metrics = TransactionMetrics::new(Some(&registry)).unwrap().unwrap();
+		let count = |outcome: BroadcastOutcome| {
+			metrics.broadcast_outcomes.with_label_values(&[outcome.as_str()]).get()
+		};
+
+		(0..4).for_each(|_| metrics.broadcast_started());
+		assert_eq!(metrics.active_broadcasts.get(), 4);
+
+		metrics.broadcast_finished(BroadcastOutcome::Usurped);
+		metrics.broadcast_finished(BroadcastOutcome::Invalid);
+		metrics.broadcast_finished(BroadcastOutcome::Invalid);
+		assert_eq!(metrics.active_broadcasts.get(), 1);
+		assert_eq!(count(BroadcastOutcome::Usurped), 1);
+		assert_eq!(count(BroadcastOutcome::Invalid), 2);
+		assert_eq!(count(BroadcastOutcome::Success), 0);
+		assert_eq!(count(BroadcastOutcome::Dropped), 0);
+		assert_eq!(count(BroadcastOutcome::Stopped), 0);
+	}
+}
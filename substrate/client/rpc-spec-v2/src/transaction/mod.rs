@@ -31,10 +31,14 @@ mod tests;
 pub mod api;
 pub mod error;
 pub mod event;
+pub mod metrics;
 pub mod transaction;
 pub mod transaction_broadcast;
+pub mod transaction_nonce;
 
-pub use api::{TransactionApiServer, TransactionBroadcastApiServer};
+pub use api::{TransactionApiServer, TransactionBroadcastApiServer, TransactionNonceApiServer};
 pub use event::{TransactionBlock, TransactionDropped, TransactionError, TransactionEvent};
+pub use metrics::TransactionMetrics;
 pub use transaction::Transaction;
-pub use transaction_broadcast::TransactionBroadcast;
+pub use transaction_broadcast::{ResubmissionPolicy, TransactionBroadcast};
+pub use transaction_nonce::TransactionNonce;
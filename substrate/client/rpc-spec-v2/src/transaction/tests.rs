@@ -18,16 +18,26 @@
 
 use super::*;
 use crate::{
-	chain_head::test_utils::ChainHeadMockClient, hex_string,
-	transaction::TransactionBroadcast as RpcTransactionBroadcast,
+	chain_head::test_utils::ChainHeadMockClient,
+	error::{ErrorCode, ErrorData},
+	hex_string,
+	transaction::{
+		transaction_broadcast::{MAX_IDEMPOTENT_OPERATIONS, MAX_IDEMPOTENT_OPERATIONS_PER_CLIENT},
+		TransactionBroadcast as RpcTransactionBroadcast, TransactionMetrics, TransactionNonce,
+	},
 };
 use assert_matches::assert_matches;
 use codec::Encode;
 use futures::Future;
 use jsonrpsee::{rpc_params, MethodsError as Error, RpcModule};
+use prometheus_endpoint::Registry;
+use sc_rpc::{AccountNonceProvider, DenyUnsafe, RpcClient};
 use sc_transaction_pool::*;
-use sc_transaction_pool_api::{ChainEvent, MaintainedTransactionPool, TransactionPool};
-use sp_core::{testing::TaskExecutor, traits::SpawnNamed};
+use sc_transaction_pool_api::{
+	ChainEvent, MaintainedTransactionPool, TransactionPool, TransactionSource,
+};
+use sp_blockchain::HeaderBackend;
+use sp_core::{testing::TaskExecutor, traits::SpawnNamed, H256};
 use std::{pin::Pin, sync::Arc, time::Duration};
 use substrate_test_runtime_client::{prelude::*, AccountKeyring::*, Client};
 use substrate_test_runtime_transaction_pool::{uxt, TestApi};
@@ -122,6 +132,35 @@ fn setup_api() -> (
 		TransactionBroadcast<BasicPool<TestApi, Block>, ChainHeadMockClient<Client<Backend>>>,
 	>,
 	TaskExecutorRecv,
+) {
+	setup_api_with_deny_unsafe(DenyUnsafe::No)
+}
+
+fn setup_api_with_deny_unsafe(
+	deny_unsafe: DenyUnsafe,
+) -> (
+	Arc<TestApi>,
+	Arc<BasicPool<TestApi, Block>>,
+	Arc<ChainHeadMockClient<Client<Backend>>>,
+	RpcModule<
+		TransactionBroadcast<BasicPool<TestApi, Block>, ChainHeadMockClient<Client<Backend>>>,
+	>,
+	TaskExecutorRecv,
+) {
+	setup_api_with(deny_unsafe, None)
+}
+
+fn setup_api_with(
+	deny_unsafe: DenyUnsafe,
+	metrics: Option<TransactionMetrics>,
+) -> (
+	Arc<TestApi>,
+	Arc<BasicPool<TestApi, Block>>,
+	Arc<ChainHeadMockClient<Client<Backend>>>,
+	RpcModule<
+		TransactionBroadcast<BasicPool<TestApi, Block>, ChainHeadMockClient<Client<Backend>>>,
+	>,
+	TaskExecutorRecv,
 ) {
 	let (pool, api, _) = maintained_pool();
 	let pool = Arc::new(pool);
@@ -132,13 +171,39 @@ fn setup_api() -> (
 
 	let (task_executor, executor_recv) = TaskExecutorBroadcast::new();
 
-	let tx_api =
-		RpcTransactionBroadcast::new(client_mock.clone(), pool.clone(), Arc::new(task_executor))
-			.into_rpc();
+	let tx_api = RpcTransactionBroadcast::new(
+		client_mock.clone(),
+		pool.clone(),
+		Arc::new(task_executor),
+		Default::default(),
+		metrics,
+		deny_unsafe,
+	)
+	.into_rpc();
 
 	(api, pool, client_mock, tx_api, executor_recv)
 }
 
+#[tokio::test]
+async fn tx_broadcast_is_unsafe() {
+	let (api, pool, _, tx_api, _) = setup_api_with_deny_unsafe(DenyUnsafe::Yes);
+	api.push_block(1, vec![], true);
+
+	let xt = hex_string(&uxt(Alice, ALICE_NONCE).encode());
+	let err = tx_api
+		.call::<_, serde_json::Value>("transaction_unstable_broadcast", rpc_params![&xt])
+		.await
+		.unwrap_err();
+	assert_matches!(err, Error::JsonRpc(err) if err.message() == "RPC call is unsafe to be called externally");
+	assert_eq!(pool.status().ready, 0);
+
+	let err = tx_api
+		.call::<_, serde_json::Value>("transaction_unstable_stop", rpc_params!["operation"])
+		.await
+		.unwrap_err();
+	assert_matches!(err, Error::JsonRpc(err) if err.message() == "RPC call is unsafe to be called externally");
+}
+
 #[tokio::test]
 async fn tx_broadcast_enters_pool() {
 	let (api, pool, client_mock, tx_api, _) = setup_api();
@@ -184,6 +249,164 @@ async fn tx_broadcast_enters_pool() {
 		.unwrap();
 }
 
+/// The value of the metric `name`, restricted to the label value `outcome` if given.
+fn metric_value(registry: &Registry, name: &str, outcome: Option<&str>) -> f64 {
+	registry
+		.gather()
+		.into_iter()
+		.find(|family| family.get_name() == name)
+		.and_then(|family| {
+			family
+				.get_metric()
+				.iter()
+				.find(|metric| {
+					outcome.map_or(true, |outcome| {
+						metric.get_label().iter().any(|label| label.get_value() == outcome)
+					})
+				})
+				.map(|metric| metric.get_counter().get_value() + metric.get_gauge().get_value())
+		})
+		.unwrap_or_default()
+}
+
+#[tokio::test]
+async fn tx_broadcast_metrics_count_outcomes() {
+	let registry = Registry::new();
+	let metrics = TransactionMetrics::new(Some(&registry)).unwrap();
+	let (_, _, _, tx_api, mut exec_recv) = setup_api_with(DenyUnsafe::No, metrics);
+	let outcomes = "substrate_rpc_transaction_broadcast_outcomes";
+	let active = "substrate_rpc_transaction_active_broadcasts";
+
+	// A transaction that cannot be decoded terminates the broadcast as invalid.
+	let _: String = tx_api
+		.call("transaction_unstable_broadcast", rpc_params!["0xdeadbeef"])
+		.await
+		.unwrap();
+	exec_recv.recv().await.unwrap();
+	assert_eq!(metric_value(&registry, outcomes, Some("invalid")), 1.0);
+	assert_eq!(metric_value(&registry, active, None), 0.0);
+
+	// A stopped broadcast is counted as such.
+	let xt = hex_string(&uxt(Alice, ALICE_NONCE).encode());
+	let operation_id: String =
+		tx_api.call("transaction_unstable_broadcast", rpc_params![&xt]).await.unwrap();
+	assert_eq!(metric_value(&registry, active, None), 1.0);
+	let _: () = tx_api
+		.call("transaction_unstable_stop", rpc_params![&operation_id])
+		.await
+		.unwrap();
+	exec_recv.recv().await.unwrap();
+	assert_eq!(metric_value(&registry, outcomes, Some("stopped")), 1.0);
+	assert_eq!(metric_value(&registry, outcomes, Some("invalid")), 1.0);
+	assert_eq!(metric_value(&registry, active, None), 0.0);
+}
+
+/// Poll the statuses of a broadcast operation until there are `count` of them.
+async fn wait_for_statuses(
+	tx_api: &RpcModule<
+		TransactionBroadcast<BasicPool<TestApi, Block>, ChainHeadMockClient<Client<Backend>>>,
+	>,
+	operation_id: &str,
+	count: usize,
+) -> Vec<TransactionEvent<H256>> {
+	let mut num_retries = 60;
+	loop {
+		let statuses: Vec<TransactionEvent<H256>> = tx_api
+			.call("transaction_unstable_status", rpc_params![operation_id])
+			.await
+			.unwrap();
+		if statuses.len() >= count || num_retries == 0 {
+			return statuses
+		}
+		num_retries -= 1;
+		tokio::time::sleep(Duration::from_secs(1)).await;
+	}
+}
+
+#[tokio::test]
+async fn tx_broadcast_status_is_retained() {
+	let (api, pool, client_mock, tx_api, mut exec_recv) = setup_api();
+
+	let block_1_header = api.push_block(1, vec![], true);
+	let uxt = uxt(Alice, ALICE_NONCE);
+	let xt = hex_string(&uxt.encode());
+
+	let operation_id: String =
+		tx_api.call("transaction_unstable_broadcast", rpc_params![&xt]).await.unwrap();
+	let statuses: Vec<TransactionEvent<H256>> = tx_api
+		.call("transaction_unstable_status", rpc_params![&operation_id])
+		.await
+		.unwrap();
+	assert!(statuses.is_empty());
+
+	client_mock.trigger_import_stream(block_1_header).await;
+	assert_eq!(
+		wait_for_statuses(&tx_api, &operation_id, 1).await,
+		vec![TransactionEvent::Validated]
+	);
+
+	// Include and finalize the transaction.
+	let block_2 = api.push_block(2, vec![uxt.clone()], true).hash();
+	pool.maintain(ChainEvent::NewBestBlock { hash: block_2, tree_route: None })
+		.await;
+	pool.maintain(ChainEvent::Finalized { hash: block_2, tree_route: Arc::from(vec![]) })
+		.await;
+
+	let expected = vec![
+		TransactionEvent::Validated,
+		TransactionEvent::BestChainBlockIncluded(Some(TransactionBlock {
+			hash: block_2,
+			index: 0,
+		})),
+		TransactionEvent::Finalized(TransactionBlock { hash: block_2, index: 0 }),
+	];
+	assert_eq!(wait_for_statuses(&tx_api, &operation_id, 3).await, expected);
+
+	// The statuses of the finished operation are still available, but it can't be stopped.
+	exec_recv.recv().await.unwrap();
+	let statuses: Vec<TransactionEvent<H256>> = tx_api
+		.call("transaction_unstable_status", rpc_params![&operation_id])
+		.await
+		.unwrap();
+	assert_eq!(statuses, expected);
+	let err = tx_api
+		.call::<_, serde_json::Value>("transaction_unstable_stop", rpc_params![&operation_id])
+		.await
+		.unwrap_err();
+	assert_matches!(err,
+		Error::JsonRpc(err) if ErrorData::of(&err).unwrap().kind == ErrorCode::InvalidOperationId
+	);
+}
+
+#[tokio::test]
+async fn tx_broadcast_status_of_unknown_operation() {
+	let (_, _, _, tx_api, _) = setup_api();
+
+	let err = tx_api
+		.call::<_, serde_json::Value>("transaction_unstable_status", ["invalid_operation_id"])
+		.await
+		.unwrap_err();
+	assert_matches!(err,
+		Error::JsonRpc(err) if err.code() == super::error::json_rpc_spec::INVALID_PARAM_ERROR && ErrorData::of(&err).unwrap().kind == ErrorCode::InvalidOperationId
+	);
+
+	// Stopped operations are forgotten.
+	let xt = hex_string(&uxt(Alice, ALICE_NONCE).encode());
+	let operation_id: String =
+		tx_api.call("transaction_unstable_broadcast", rpc_params![&xt]).await.unwrap();
+	let _: () = tx_api
+		.call("transaction_unstable_stop", rpc_params![&operation_id])
+		.await
+		.unwrap();
+	let err = tx_api
+		.call::<_, serde_json::Value>("transaction_unstable_status", rpc_params![&operation_id])
+		.await
+		.unwrap_err();
+	assert_matches!(err,
+		Error::JsonRpc(err) if ErrorData::of(&err).unwrap().kind == ErrorCode::InvalidOperationId
+	);
+}
+
 #[tokio::test]
 async fn tx_broadcast_invalid_tx() {
 	let (_, pool, _, tx_api, mut exec_recv) = setup_api();
@@ -219,10 +442,112 @@ async fn tx_broadcast_invalid_tx() {
 		.await
 		.unwrap_err();
 	assert_matches!(err,
-		Error::JsonRpc(err) if err.code() == super::error::json_rpc_spec::INVALID_PARAM_ERROR && err.message() == "Invalid operation id"
+		Error::JsonRpc(err) if err.code() == super::error::json_rpc_spec::INVALID_PARAM_ERROR && ErrorData::of(&err).unwrap().kind == ErrorCode::InvalidOperationId
 	);
 }
 
+#[tokio::test]
+async fn tx_broadcast_validate_reports_invalid_tx() {
+	let (_, pool, _, tx_api, _) = setup_api();
+
+	// With validation the undecodable transaction is reported instead of starting a broadcast.
+	let xt = "0xdeadbeef";
+	let err = tx_api
+		.call::<_, serde_json::Value>(
+			"transaction_unstable_broadcast",
+			rpc_params![&xt, Option::<String>::None, true],
+		)
+		.await
+		.unwrap_err();
+	assert_matches!(err,
+		Error::JsonRpc(err) if err.code() == super::error::json_rpc_spec::INVALID_PARAM_ERROR && ErrorData::of(&err).unwrap().kind == ErrorCode::BadTransactionFormat
+	);
+
+	assert_eq!(0, pool.status().ready);
+}
+
+/// Make the best block of the client known to the pool, such that broadcasts with validation can
+/// submit extrinsics at it.
+fn import_client_best_block(api: &TestApi, client: &ChainHeadMockClient<Client<Backend>>) {
+	let header = client.header(client.info().best_hash).unwrap().unwrap();
+	api.add_block(Block { header, extrinsics: vec![] }, true);
+}
+
+#[tokio::test]
+async fn tx_broadcast_validate_reports_rejected_tx() {
+	let (api, pool, client_mock, tx_api, _) = setup_api();
+	import_client_best_block(&api, &client_mock);
+
+	let uxt = uxt(Alice, ALICE_NONCE);
+	api.add_invalid(&uxt);
+	let xt = hex_string(&uxt.encode());
+	let err = tx_api
+		.call::<_, serde_json::Value>(
+			"transaction_unstable_broadcast",
+			rpc_params![&xt, "retry", true],
+		)
+		.await
+		.unwrap_err();
+	assert_matches!(err,
+		Error::JsonRpc(err) if err.code() == super::error::json_rpc_spec::INVALID_PARAM_ERROR && ErrorData::of(&err).unwrap().kind == ErrorCode::InvalidTransaction
+	);
+
+	assert_eq!(0, pool.status().ready);
+}
+
+#[tokio::test]
+async fn tx_broadcast_validate_failures_do_not_keep_operations() {
+	let (_, _, _, tx_api, _) = setup_api();
+
+	// Every failed validation gives up the operation registered for it.
+	for key in 0..MAX_IDEMPOTENT_OPERATIONS_PER_CLIENT {
+		let result = RpcClient([127, 0, 0, 1].into())
+			.scope(tx_api.call::<_, serde_json::Value>(
+				"transaction_unstable_broadcast",
+				rpc_params!["0xdeadbeef", key.to_string(), true],
+			))
+			.await;
+		assert!(result.is_err());
+	}
+
+	// So the client can still start operations with an idempotency key.
+	let xt = hex_string(&uxt(Alice, ALICE_NONCE).encode());
+	let operation_id = broadcast_of_client(&tx_api, 1, &xt, Some("valid".into())).await;
+	assert!(operation_id.is_some());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn tx_broadcast_validate_concurrent_retries_submit_once() {
+	let (api, pool, client_mock, tx_api, _) = setup_api();
+	import_client_best_block(&api, &client_mock);
+	let tx_api = Arc::new(tx_api);
+	let xt = hex_string(&uxt(Alice, ALICE_NONCE).encode());
+
+	// Submitting the extrinsic again would fail as it is already imported.
+	let handles = (0..16)
+		.map(|_| {
+			let tx_api = tx_api.clone();
+			let xt = xt.clone();
+			tokio::spawn(async move {
+				tx_api
+					.call::<_, String>(
+						"transaction_unstable_broadcast",
+						rpc_params![&xt, "retry", true],
+					)
+					.await
+					.unwrap()
+			})
+		})
+		.collect::<Vec<_>>();
+	let ids = futures::future::join_all(handles)
+		.await
+		.into_iter()
+		.map(Result::unwrap)
+		.collect::<std::collections::HashSet<_>>();
+	assert_eq!(ids.len(), 1);
+	assert_eq!(1, pool.status().ready);
+}
+
 #[tokio::test]
 async fn tx_invalid_stop() {
 	let (_, _, _, tx_api, _) = setup_api();
@@ -233,6 +558,238 @@ async fn tx_invalid_stop() {
 		.await
 		.unwrap_err();
 	assert_matches!(err,
-		Error::JsonRpc(err) if err.code() == super::error::json_rpc_spec::INVALID_PARAM_ERROR && err.message() == "Invalid operation id"
+		Error::JsonRpc(err) if err.code() == super::error::json_rpc_spec::INVALID_PARAM_ERROR && ErrorData::of(&err).unwrap().kind == ErrorCode::InvalidOperationId
+	);
+}
+
+#[tokio::test]
+async fn tx_broadcast_idempotency_key() {
+	let (_, _, _, tx_api, _) = setup_api();
+
+	let xt = hex_string(&uxt(Alice, ALICE_NONCE).encode());
+	let operation_id: String = tx_api
+		.call("transaction_unstable_broadcast", rpc_params![&xt, "retry"])
+		.await
+		.unwrap();
+
+	// Retries map to the running operation.
+	let retried_id: String = tx_api
+		.call("transaction_unstable_broadcast", rpc_params![&xt, "retry"])
+		.await
+		.unwrap();
+	assert_eq!(operation_id, retried_id);
+
+	// Other keys and calls without a key start new operations.
+	let other_id: String = tx_api
+		.call("transaction_unstable_broadcast", rpc_params![&xt, "other"])
+		.await
+		.unwrap();
+	assert_ne!(operation_id, other_id);
+	let unkeyed_id: String =
+		tx_api.call("transaction_unstable_broadcast", rpc_params![&xt]).await.unwrap();
+	assert_ne!(operation_id, unkeyed_id);
+
+	// A stopped operation can be started again with the same key.
+	let _: () = tx_api
+		.call("transaction_unstable_stop", rpc_params![&operation_id])
+		.await
+		.unwrap();
+	let restarted_id: String = tx_api
+		.call("transaction_unstable_broadcast", rpc_params![&xt, "retry"])
+		.await
+		.unwrap();
+	assert_eq!(operation_id, restarted_id);
+
+	// The restarted operation is not removed when the stopped one exits.
+	tokio::time::sleep(Duration::from_millis(100)).await;
+	let _: () = tx_api
+		.call("transaction_unstable_stop", rpc_params![&operation_id])
+		.await
+		.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn tx_broadcast_concurrent_retries() {
+	let (_, _, _, tx_api, _) = setup_api();
+	let tx_api = Arc::new(tx_api);
+	let xt = hex_string(&uxt(Alice, ALICE_NONCE).encode());
+
+	let handles = (0..16)
+		.map(|_| {
+			let tx_api = tx_api.clone();
+			let xt = xt.clone();
+			tokio::spawn(async move {
+				tx_api
+					.call::<_, String>("transaction_unstable_broadcast", rpc_params![&xt, "retry"])
+					.await
+					.unwrap()
+			})
+		})
+		.collect::<Vec<_>>();
+	let ids = futures::future::join_all(handles)
+		.await
+		.into_iter()
+		.map(Result::unwrap)
+		.collect::<std::collections::HashSet<_>>();
+	assert_eq!(ids.len(), 1);
+
+	// Only one operation was started, stopping it a second time fails.
+	let operation_id = ids.into_iter().next().unwrap();
+	let _: () = tx_api
+		.call("transaction_unstable_stop", rpc_params![&operation_id])
+		.await
+		.unwrap();
+	let err = tx_api
+		.call::<_, serde_json::Value>("transaction_unstable_stop", rpc_params![&operation_id])
+		.await
+		.unwrap_err();
+	assert_matches!(err,
+		Error::JsonRpc(err) if err.code() == super::error::json_rpc_spec::INVALID_PARAM_ERROR && ErrorData::of(&err).unwrap().kind == ErrorCode::InvalidOperationId
 	);
 }
+
+/// Broadcast `xt` as a call of `client`, with the optional idempotency `key`.
+async fn broadcast_of_client(
+	tx_api: &RpcModule<
+		TransactionBroadcast<BasicPool<TestApi, Block>, ChainHeadMockClient<Client<Backend>>>,
+	>,
+	client: u8,
+	xt: &str,
+	key: Option<String>,
+) -> Option<String> {
+	let client = RpcClient([127, 0, 0, client].into());
+	client
+		.scope(tx_api.call("transaction_unstable_broadcast", rpc_params![xt, key]))
+		.await
+		.unwrap()
+}
+
+#[tokio::test]
+async fn tx_broadcast_idempotency_keys_are_scoped_per_client() {
+	let (_, _, _, tx_api, _) = setup_api();
+	let xt = hex_string(&uxt(Alice, ALICE_NONCE).encode());
+
+	let first = broadcast_of_client(&tx_api, 1, &xt, Some("retry".into())).await.unwrap();
+	let retry = broadcast_of_client(&tx_api, 1, &xt, Some("retry".into())).await.unwrap();
+	assert_eq!(first, retry);
+
+	// The same key and extrinsic of another client start another operation.
+	let other = broadcast_of_client(&tx_api, 2, &xt, Some("retry".into())).await.unwrap();
+	assert_ne!(first, other);
+
+	// Stopping the operation of one client leaves the other one running.
+	let _: () = tx_api.call("transaction_unstable_stop", rpc_params![&first]).await.unwrap();
+	let _: () = tx_api.call("transaction_unstable_stop", rpc_params![&other]).await.unwrap();
+}
+
+#[tokio::test]
+async fn tx_broadcast_idempotent_operations_are_bounded_per_client() {
+	let (_, _, _, tx_api, _) = setup_api();
+	let xt = hex_string(&uxt(Alice, ALICE_NONCE).encode());
+
+	for key in 0..MAX_IDEMPOTENT_OPERATIONS_PER_CLIENT {
+		let operation_id = broadcast_of_client(&tx_api, 1, &xt, Some(key.to_string())).await;
+		assert!(operation_id.is_some());
+	}
+
+	// No more operations with an idempotency key can be started by the client.
+	let operation_id = broadcast_of_client(&tx_api, 1, &xt, Some("overflow".into())).await;
+	assert_eq!(operation_id, None);
+
+	// Retries of running operations and calls without a key are still served.
+	let operation_id = broadcast_of_client(&tx_api, 1, &xt, Some("0".into())).await;
+	assert!(operation_id.is_some());
+	let operation_id = broadcast_of_client(&tx_api, 1, &xt, None).await;
+	assert!(operation_id.is_some());
+
+	// Other clients can still start operations with an idempotency key.
+	let operation_id = broadcast_of_client(&tx_api, 2, &xt, Some("overflow".into())).await;
+	assert!(operation_id.is_some());
+}
+
+#[tokio::test]
+async fn tx_broadcast_idempotent_operations_are_bounded() {
+	let (_, _, _, tx_api, _) = setup_api();
+	let xt = hex_string(&uxt(Alice, ALICE_NONCE).encode());
+
+	// Every client starts as many operations with an idempotency key as it may.
+	let clients = MAX_IDEMPOTENT_OPERATIONS / MAX_IDEMPOTENT_OPERATIONS_PER_CLIENT;
+	for client in 0..clients {
+		for key in 0..MAX_IDEMPOTENT_OPERATIONS_PER_CLIENT {
+			let key = Some(key.to_string());
+			let operation_id = broadcast_of_client(&tx_api, client as u8, &xt, key).await;
+			assert!(operation_id.is_some());
+		}
+	}
+
+	// No more operations with an idempotency key can be started, by any client.
+	let operation_id = broadcast_of_client(&tx_api, clients as u8, &xt, Some("0".into())).await;
+	assert_eq!(operation_id, None);
+}
+
+/// The nonces of the test runtime at genesis, where every account starts at 0.
+struct GenesisNonces;
+
+impl AccountNonceProvider<Block, substrate_test_runtime_client::runtime::AccountId, u64>
+	for GenesisNonces
+{
+	fn account_nonce(
+		&self,
+		_at: H256,
+		_account: substrate_test_runtime_client::runtime::AccountId,
+	) -> Result<u64, String> {
+		Ok(0)
+	}
+}
+
+#[tokio::test]
+async fn tx_next_nonce_skips_pool_transactions() {
+	let client = Arc::new(substrate_test_runtime_client::new());
+	let pool = BasicPool::new_full(
+		Default::default(),
+		true.into(),
+		None,
+		TaskExecutor::new(),
+		client.clone(),
+	);
+	let tx_api =
+		TransactionNonce::<_, _, _, Block>::new(client.clone(), pool.clone(), GenesisNonces)
+			.into_rpc();
+	let next_nonce = |account: substrate_test_runtime_client::runtime::AccountId| {
+		let tx_api = &tx_api;
+		async move {
+			let nonce: u64 = tx_api
+				.call("transaction_unstable_nextNonce", rpc_params![account])
+				.await
+				.unwrap();
+			nonce
+		}
+	};
+
+	// Without transactions in the pool the on-chain nonce is used.
+	assert_eq!(next_nonce(Alice.into()).await, 0);
+
+	let transfer = |nonce| {
+		substrate_test_runtime_client::runtime::Transfer {
+			from: Alice.into(),
+			to: Bob.into(),
+			amount: 5,
+			nonce,
+		}
+		.into_unchecked_extrinsic()
+	};
+	let genesis_hash = client.info().genesis_hash;
+	for nonce in [0, 1, 3] {
+		pool.submit_one(genesis_hash, TransactionSource::External, transfer(nonce))
+			.await
+			.unwrap();
+	}
+
+	// The future transaction leaves a gap that is filled first.
+	assert_eq!(next_nonce(Alice.into()).await, 2);
+	pool.submit_one(genesis_hash, TransactionSource::External, transfer(2))
+		.await
+		.unwrap();
+	assert_eq!(next_nonce(Alice.into()).await, 4);
+	assert_eq!(next_nonce(Bob.into()).await, 0);
+}
@@ -19,16 +19,18 @@
 //! API implementation for submitting transactions.
 
 use crate::{
+	error::ErrorCode,
 	transaction::{
 		api::TransactionApiServer,
 		error::Error,
 		event::{TransactionBlock, TransactionDropped, TransactionError, TransactionEvent},
+		metrics::TransactionMetrics,
 	},
 	SubscriptionTaskExecutor,
 };
 use codec::Decode;
 use futures::{StreamExt, TryFutureExt};
-use jsonrpsee::{core::async_trait, types::error::ErrorObject, PendingSubscriptionSink};
+use jsonrpsee::{core::async_trait, PendingSubscriptionSink};
 use sc_rpc::utils::pipe_from_stream;
 use sc_transaction_pool_api::{
 	error::IntoPoolError, BlockHash, TransactionFor, TransactionPool, TransactionSource,
@@ -37,7 +39,7 @@ use sc_transaction_pool_api::{
 use sp_blockchain::HeaderBackend;
 use sp_core::Bytes;
 use sp_runtime::traits::Block as BlockT;
-use std::sync::Arc;
+use std::{sync::Arc, time::Instant};
 
 /// An API for transaction RPC calls.
 pub struct Transaction<Pool, Client> {
@@ -47,12 +49,19 @@ pub struct Transaction<Pool, Client> {
 	pool: Arc<Pool>,
 	/// Executor to spawn subscriptions.
 	executor: SubscriptionTaskExecutor,
+	/// Prometheus metrics, if enabled.
+	metrics: Option<TransactionMetrics>,
 }
 
 impl<Pool, Client> Transaction<Pool, Client> {
 	/// Creates a new [`Transaction`].
-	pub fn new(client: Arc<Client>, pool: Arc<Pool>, executor: SubscriptionTaskExecutor) -> Self {
-		Transaction { client, pool, executor }
+	pub fn new(
+		client: Arc<Client>,
+		pool: Arc<Pool>,
+		executor: SubscriptionTaskExecutor,
+		metrics: Option<TransactionMetrics>,
+	) -> Self {
+		Transaction { client, pool, executor, metrics }
 	}
 }
 
@@ -63,13 +72,6 @@ impl<Pool, Client> Transaction<Pool, Client> {
 /// some unique transactions via RPC and have them included in the pool.
 const TX_SOURCE: TransactionSource = TransactionSource::External;
 
-/// Extrinsic has an invalid format.
-///
-/// # Note
-///
-/// This is similar to the old `author` API error code.
-const BAD_FORMAT: i32 = 1001;
-
 #[async_trait]
 impl<Pool, Client> TransactionApiServer<BlockHash<Pool>> for Transaction<Pool, Client>
 where
@@ -81,6 +83,8 @@ where
 	fn submit_and_watch(&self, pending: PendingSubscriptionSink, xt: Bytes) {
 		let client = self.client.clone();
 		let pool = self.pool.clone();
+		let metrics = self.metrics.clone();
+		let submitted = Instant::now();
 
 		let fut = async move {
 			// This is the only place where the RPC server can return an error for this
@@ -88,10 +92,9 @@ where
 			let decoded_extrinsic = match TransactionFor::<Pool>::decode(&mut &xt[..]) {
 				Ok(decoded_extrinsic) => decoded_extrinsic,
 				Err(e) => {
-					let err = ErrorObject::owned(
-						BAD_FORMAT,
+					let err = ErrorCode::BadTransactionFormat.error_object(
 						format!("Extrinsic has invalid format: {}", e),
-						None::<()>,
+						Some(e.to_string()),
 					);
 					let _ = pending.reject(err).await;
 					return
@@ -110,7 +113,13 @@ where
 
 			match submit.await {
 				Ok(stream) => {
-					let stream = stream.filter_map(move |event| async move { handle_event(event) });
+					let stream = stream.filter_map(move |event| {
+						if let (Some(metrics), TransactionStatus::Finalized(_)) = (&metrics, &event)
+						{
+							metrics.transaction_finalized(submitted);
+						}
+						async move { handle_event(event) }
+					});
 					pipe_from_stream(pending, stream.boxed()).await;
 				},
 				Err(err) => {
@@ -150,6 +159,9 @@ pub fn handle_event<Hash: Clone, BlockHash: Clone>(
 		TransactionStatus::Dropped => Some(TransactionEvent::Invalid(TransactionError {
 			error: "Extrinsic dropped from the pool due to exceeding limits".into(),
 		})),
+		TransactionStatus::Expired => Some(TransactionEvent::Dropped(TransactionDropped {
+			error: "Extrinsic stayed in the pool longer than its time-to-live".into(),
+		})),
 		TransactionStatus::Invalid => Some(TransactionEvent::Invalid(TransactionError {
 			error: "Extrinsic marked as invalid".into(),
 		})),
@@ -19,45 +19,224 @@
 //! API implementation for broadcasting transactions.
 
 use crate::{transaction::api::TransactionBroadcastApiServer, SubscriptionTaskExecutor};
-use codec::Decode;
+use codec::{Decode, Encode};
 use futures::{FutureExt, Stream, StreamExt};
-use futures_util::stream::AbortHandle;
+use futures_util::stream::{AbortHandle, Abortable};
 use jsonrpsee::core::{async_trait, RpcResult};
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 use rand::{distributions::Alphanumeric, Rng};
 use sc_client_api::BlockchainEvents;
+use sc_rpc::{DenyUnsafe, RpcClient};
 use sc_transaction_pool_api::{
-	error::IntoPoolError, TransactionFor, TransactionPool, TransactionSource,
+	error::IntoPoolError, BlockHash, TransactionFor, TransactionPool, TransactionSource,
+	TransactionStatus, TransactionStatusStreamFor,
 };
 use sp_blockchain::HeaderBackend;
 use sp_core::Bytes;
 use sp_runtime::traits::Block as BlockT;
-use std::{collections::HashMap, sync::Arc};
+use std::{
+	collections::{HashMap, VecDeque},
+	num::NonZeroU32,
+	pin::Pin,
+	sync::{
+		atomic::{AtomicU64, Ordering},
+		Arc,
+	},
+	time::{Duration, Instant},
+};
+
+use super::{
+	error::{Error, ErrorBroadcast},
+	event::{TransactionDropped, TransactionError, TransactionEvent},
+	metrics::{BroadcastOutcome, TransactionMetrics},
+	transaction::handle_event,
+};
+
+/// The maximum number of running broadcast operations that were started with an idempotency key.
+///
+/// Further calls with an idempotency key return `null` until some of these operations finish.
+pub(crate) const MAX_IDEMPOTENT_OPERATIONS: usize = 1024;
+
+/// The maximum number of running broadcast operations that a client started with an idempotency
+/// key.
+///
+/// Further calls of the client with an idempotency key return `null` until some of these
+/// operations finish.
+pub(crate) const MAX_IDEMPOTENT_OPERATIONS_PER_CLIENT: usize = 64;
+
+/// The maximum number of statuses retained per broadcast operation.
+///
+/// The oldest statuses are forgotten first.
+pub(crate) const MAX_STATUSES_PER_OPERATION: usize = 16;
+
+/// The maximum number of finished broadcast operations whose statuses are retained.
+///
+/// The statuses of the operation that finished first are forgotten first.
+pub(crate) const MAX_FINISHED_OPERATIONS: usize = 1024;
+
+/// When the transactions of broadcast operations are resubmitted to the pool.
+///
+/// A transaction is resubmitted if the pool rejected it with a retriable error or dropped it. The
+/// resubmission happens on top of the best block, once the interval elapsed and a new best block
+/// was imported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResubmissionPolicy {
+	/// The minimum time between two submissions.
+	///
+	/// With a zero interval the transaction is resubmitted at the next new best block.
+	pub interval: Duration,
+	/// Whether to double the interval after every resubmission.
+	pub exponential_backoff: bool,
+	/// The maximum interval between two submissions when backing off.
+	pub max_interval: Duration,
+	/// The maximum number of submissions of a transaction, `None` for no limit.
+	pub max_attempts: Option<NonZeroU32>,
+}
 
-use super::error::ErrorBroadcast;
+impl Default for ResubmissionPolicy {
+	fn default() -> Self {
+		Self {
+			interval: Duration::ZERO,
+			exponential_backoff: false,
+			max_interval: Duration::from_secs(60),
+			max_attempts: None,
+		}
+	}
+}
+
+impl ResubmissionPolicy {
+	/// The interval following `interval`.
+	fn next_interval(&self, interval: Duration) -> Duration {
+		if self.exponential_backoff {
+			interval.saturating_mul(2).min(self.max_interval)
+		} else {
+			interval
+		}
+	}
+
+	/// Whether another submission is allowed after `attempts` submissions.
+	fn may_resubmit(&self, attempts: u32) -> bool {
+		self.max_attempts.map_or(true, |max| attempts < max.get())
+	}
+}
 
 /// An API for transaction RPC calls.
-pub struct TransactionBroadcast<Pool, Client> {
+pub struct TransactionBroadcast<Pool: TransactionPool, Client> {
 	/// Substrate client.
 	client: Arc<Client>,
 	/// Transactions pool.
 	pool: Arc<Pool>,
 	/// Executor to spawn subscriptions.
 	executor: SubscriptionTaskExecutor,
+	/// Whether to deny unsafe calls.
+	deny_unsafe: DenyUnsafe,
 	/// The brodcast operation IDs.
-	broadcast_ids: Arc<RwLock<HashMap<String, BroadcastState>>>,
+	broadcast_ids: Arc<RwLock<HashMap<String, BroadcastState<BlockHash<Pool>>>>>,
+	/// The statuses of the most recently finished broadcast operations.
+	finished: Arc<RwLock<FinishedOperations<BlockHash<Pool>>>>,
+	/// The number of broadcast operations started so far, used to tell them apart.
+	operations: AtomicU64,
+	/// The secret mixed into the operation IDs derived from idempotency keys.
+	secret: [u8; 32],
+	/// When transactions are resubmitted.
+	resubmission: ResubmissionPolicy,
+	/// Prometheus metrics, if enabled.
+	metrics: Option<TransactionMetrics>,
 }
 
 /// The state of a broadcast operation.
-struct BroadcastState {
+struct BroadcastState<Hash> {
 	/// Handle to abort the running future that broadcasts the transaction.
 	handle: AbortHandle,
+	/// The number of the operation, distinguishes operations that reuse a derived ID.
+	operation: u64,
+	/// Whether the operation was started with an idempotency key.
+	idempotent: bool,
+	/// The client that started the operation, if known.
+	client: Option<RpcClient>,
+	/// The statuses of the transaction, updated by the running future.
+	statuses: Arc<Mutex<StatusHistory<Hash>>>,
+}
+
+/// The latest statuses of the transaction of a broadcast operation, oldest first.
+struct StatusHistory<Hash>(VecDeque<TransactionEvent<Hash>>);
+
+impl<Hash> Default for StatusHistory<Hash> {
+	fn default() -> Self {
+		Self(VecDeque::new())
+	}
+}
+
+impl<Hash: Clone> StatusHistory<Hash> {
+	/// Record a new status, forgetting the oldest one if needed.
+	fn push(&mut self, status: TransactionEvent<Hash>) {
+		if self.0.len() >= MAX_STATUSES_PER_OPERATION {
+			self.0.pop_front();
+		}
+		self.0.push_back(status);
+	}
+
+	fn to_vec(&self) -> Vec<TransactionEvent<Hash>> {
+		self.0.iter().cloned().collect()
+	}
+}
+
+/// The statuses of the most recently finished broadcast operations, by operation ID.
+struct FinishedOperations<Hash> {
+	statuses: HashMap<String, StatusHistory<Hash>>,
+	/// The operation IDs, in the order the operations finished.
+	order: VecDeque<String>,
+}
+
+impl<Hash> Default for FinishedOperations<Hash> {
+	fn default() -> Self {
+		Self { statuses: HashMap::new(), order: VecDeque::new() }
+	}
+}
+
+impl<Hash> FinishedOperations<Hash> {
+	/// Retain the statuses of a finished operation, forgetting the oldest operation if needed.
+	fn insert(&mut self, id: String, statuses: StatusHistory<Hash>) {
+		self.remove(&id);
+		if self.order.len() >= MAX_FINISHED_OPERATIONS {
+			if let Some(oldest) = self.order.pop_front() {
+				self.statuses.remove(&oldest);
+			}
+		}
+		self.order.push_back(id.clone());
+		self.statuses.insert(id, statuses);
+	}
+
+	/// Forget the statuses of an operation, e.g. because its ID is reused.
+	fn remove(&mut self, id: &str) {
+		if self.statuses.remove(id).is_some() {
+			self.order.retain(|finished| finished != id);
+		}
+	}
 }
 
-impl<Pool, Client> TransactionBroadcast<Pool, Client> {
+impl<Pool: TransactionPool, Client> TransactionBroadcast<Pool, Client> {
 	/// Creates a new [`TransactionBroadcast`].
-	pub fn new(client: Arc<Client>, pool: Arc<Pool>, executor: SubscriptionTaskExecutor) -> Self {
-		TransactionBroadcast { client, pool, executor, broadcast_ids: Default::default() }
+	pub fn new(
+		client: Arc<Client>,
+		pool: Arc<Pool>,
+		executor: SubscriptionTaskExecutor,
+		resubmission: ResubmissionPolicy,
+		metrics: Option<TransactionMetrics>,
+		deny_unsafe: DenyUnsafe,
+	) -> Self {
+		TransactionBroadcast {
+			client,
+			pool,
+			executor,
+			deny_unsafe,
+			broadcast_ids: Default::default(),
+			finished: Default::default(),
+			operations: Default::default(),
+			secret: rand::thread_rng().gen(),
+			resubmission,
+			metrics,
+		}
 	}
 
 	/// Generate an unique operation ID for the `transaction_broadcast` RPC method.
@@ -85,6 +264,52 @@ impl<Pool, Client> TransactionBroadcast<Pool, Client> {
 	}
 }
 
+impl<Pool, Client> TransactionBroadcast<Pool, Client>
+where
+	Pool: TransactionPool,
+	Pool::Error: IntoPoolError,
+	Client: HeaderBackend<Pool::Block>,
+{
+	/// Decode the extrinsic and submit it to the pool at the current best block.
+	///
+	/// Returns the status stream of the submitted extrinsic, or `None` if the pool rejected it for
+	/// now and the submission should be retried by the broadcast.
+	async fn submit_validated(
+		&self,
+		bytes: &[u8],
+	) -> Result<Option<Pin<Box<TransactionStatusStreamFor<Pool>>>>, ErrorBroadcast> {
+		let decoded_extrinsic = TransactionFor::<Pool>::decode(&mut &bytes[..])
+			.map_err(ErrorBroadcast::BadTransactionFormat)?;
+		let best_block_hash = self.client.info().best_hash;
+
+		match self.pool.submit_and_watch(best_block_hash, TX_SOURCE, decoded_extrinsic).await {
+			Ok(stream) => Ok(Some(stream)),
+			Err(e) => match e.into_pool_error() {
+				Ok(pool_err) if pool_err.is_retriable() => Ok(None),
+				Ok(pool_err) => Err(ErrorBroadcast::InvalidTransaction(Error::Pool(pool_err))),
+				Err(e) => Err(ErrorBroadcast::InvalidTransaction(Error::Verification(Box::new(e)))),
+			},
+		}
+	}
+}
+
+/// Derive the operation ID of a broadcast started with `idempotency_key`.
+///
+/// The ID only depends on the client, the key and the transaction, so retries of the same call by
+/// the same client are mapped to the same operation, while the keys of different clients can't
+/// clash. The `secret` keeps other clients from deriving the ID of an operation. The ID is longer
+/// than the generated IDs, the two can't collide.
+fn derive_operation_id(
+	secret: &[u8; 32],
+	client: Option<RpcClient>,
+	idempotency_key: &str,
+	bytes: &[u8],
+) -> String {
+	let client = client.map(|client| client.0.to_string());
+	let hash = sp_crypto_hashing::blake2_128(&(secret, client, idempotency_key, bytes).encode());
+	hex::encode(hash)
+}
+
 /// Currently we treat all RPC transactions as externals.
 ///
 /// Possibly in the future we could allow opt-in for special treatment
@@ -93,72 +318,199 @@ impl<Pool, Client> TransactionBroadcast<Pool, Client> {
 const TX_SOURCE: TransactionSource = TransactionSource::External;
 
 #[async_trait]
-impl<Pool, Client> TransactionBroadcastApiServer for TransactionBroadcast<Pool, Client>
+impl<Pool, Client> TransactionBroadcastApiServer<BlockHash<Pool>>
+	for TransactionBroadcast<Pool, Client>
 where
 	Pool: TransactionPool + Sync + Send + 'static,
 	Pool::Error: IntoPoolError,
 	<Pool::Block as BlockT>::Hash: Unpin,
 	Client: HeaderBackend<Pool::Block> + BlockchainEvents<Pool::Block> + Send + Sync + 'static,
 {
-	fn broadcast(&self, bytes: Bytes) -> RpcResult<Option<String>> {
+	async fn broadcast(
+		&self,
+		bytes: Bytes,
+		idempotency_key: Option<String>,
+		validate: Option<bool>,
+	) -> RpcResult<Option<String>> {
+		self.deny_unsafe.check_if_safe()?;
+
 		let pool = self.pool.clone();
 
-		// The unique ID of this operation.
-		let id = self.generate_unique_id();
+		// The ID of this operation, unique unless derived from an idempotency key.
+		let idempotent = idempotency_key.is_some();
+		let client = RpcClient::current();
+		let id = match idempotency_key {
+			Some(key) => derive_operation_id(&self.secret, client, &key, &bytes),
+			None => self.generate_unique_id(),
+		};
+		let operation = self.operations.fetch_add(1, Ordering::Relaxed);
+		let resubmission = self.resubmission;
+		let metrics = self.metrics.clone();
+		let submitted = Instant::now();
+
+		// Keep track of this operation before the extrinsic is validated, which submits it.
+		// Checking for an existing operation and inserting the new one happen under the same
+		// lock, such that concurrent retries can't start or submit the same operation twice.
+		let statuses = Arc::new(Mutex::new(StatusHistory::default()));
+		let (handle, registration) = AbortHandle::new_pair();
+		{
+			let mut broadcast_ids = self.broadcast_ids.write();
+			if idempotent {
+				if broadcast_ids.contains_key(&id) {
+					return Ok(Some(id))
+				}
+				let (running, running_of_client) = broadcast_ids
+					.values()
+					.filter(|state| state.idempotent)
+					.fold((0, 0), |(all, of_client), state| {
+						(all + 1, of_client + usize::from(state.client == client))
+					});
+				if running >= MAX_IDEMPOTENT_OPERATIONS ||
+					running_of_client >= MAX_IDEMPOTENT_OPERATIONS_PER_CLIENT
+				{
+					return Ok(None)
+				}
+			}
+			broadcast_ids.insert(
+				id.clone(),
+				BroadcastState {
+					handle,
+					operation,
+					idempotent,
+					client,
+					statuses: statuses.clone(),
+				},
+			);
+			self.finished.write().remove(&id);
+		}
+
+		let mut validated_stream = match validate {
+			Some(true) => match self.submit_validated(&bytes).await {
+				Ok(stream) => stream,
+				Err(e) => {
+					// Give up the operation, unless it was stopped and started again meanwhile.
+					let mut broadcast_ids = self.broadcast_ids.write();
+					if broadcast_ids.get(&id).map_or(false, |state| state.operation == operation) {
+						broadcast_ids.remove(&id);
+					}
+					return Err(e.into())
+				},
+			},
+			_ => None,
+		};
 
 		let mut best_block_import_stream =
 			Box::pin(self.client.import_notification_stream().filter_map(
 				|notification| async move { notification.is_new_best.then_some(notification.hash) },
 			));
 
+		let record = {
+			let statuses = statuses.clone();
+			move |status: TransactionEvent<BlockHash<Pool>>| statuses.lock().push(status)
+		};
+
 		let broadcast_transaction_fut = async move {
 			// There is nothing we could do with an extrinsic of invalid format.
-			let Ok(decoded_extrinsic) = TransactionFor::<Pool>::decode(&mut &bytes[..]) else {
-				return;
+			let decoded_extrinsic = match TransactionFor::<Pool>::decode(&mut &bytes[..]) {
+				Ok(decoded_extrinsic) => decoded_extrinsic,
+				Err(e) => {
+					record(TransactionEvent::Error(TransactionError {
+						error: format!("Extrinsic has invalid format: {e}"),
+					}));
+					return BroadcastOutcome::Invalid
+				},
 			};
 
-			// Flag to determine if the we should broadcast the transaction again.
-			let mut is_done = false;
+			let mut attempts = 0;
+			let mut interval = resubmission.interval;
+			// The outcome if the transaction can not be resubmitted anymore.
+			let mut last_failure = BroadcastOutcome::Dropped;
 
-			while !is_done {
-				// Wait for the last block to become available.
-				let Some(best_block_hash) =
-					last_stream_element(&mut best_block_import_stream).await
-				else {
-					return;
-				};
-
-				let mut stream = match pool
-					.submit_and_watch(best_block_hash, TX_SOURCE, decoded_extrinsic.clone())
-					.await
-				{
-					Ok(stream) => stream,
-					// The transaction was not included to the pool.
-					Err(e) => {
-						let Ok(pool_err) = e.into_pool_error() else { return };
-
-						if pool_err.is_retriable() {
-							// Try to resubmit the transaction at a later block for
-							// recoverable errors.
-							continue
-						} else {
-							return;
-						}
-					},
+			loop {
+				if attempts > 0 {
+					if !resubmission.may_resubmit(attempts) {
+						return last_failure;
+					}
+					if !interval.is_zero() {
+						futures_timer::Delay::new(interval).await;
+					}
+					interval = resubmission.next_interval(interval);
+				}
+				attempts += 1;
+
+				// The first submission may already have happened during the validation.
+				let mut stream = if let Some(stream) = validated_stream.take() {
+					stream
+				} else {
+					// Wait for the last block to become available.
+					let Some(best_block_hash) =
+						last_stream_element(&mut best_block_import_stream).await
+					else {
+						return BroadcastOutcome::Dropped;
+					};
+
+					match pool
+						.submit_and_watch(best_block_hash, TX_SOURCE, decoded_extrinsic.clone())
+						.await
+					{
+						Ok(stream) => stream,
+						// The transaction was not included to the pool.
+						Err(e) => {
+							let pool_err = match e.into_pool_error() {
+								Ok(pool_err) => pool_err,
+								Err(e) => {
+									record(TransactionEvent::Error(TransactionError {
+										error: e.to_string(),
+									}));
+									return BroadcastOutcome::Invalid
+								},
+							};
+
+							if pool_err.is_retriable() {
+								// Try to resubmit the transaction at a later block for
+								// recoverable errors.
+								record(TransactionEvent::Dropped(TransactionDropped {
+									error: pool_err.to_string(),
+								}));
+								last_failure = BroadcastOutcome::Dropped;
+								continue
+							} else {
+								record(TransactionEvent::Invalid(TransactionError {
+									error: pool_err.to_string(),
+								}));
+								return BroadcastOutcome::Invalid;
+							}
+						},
+					}
 				};
 
 				while let Some(event) = stream.next().await {
+					if let Some(status) = handle_event(event.clone()) {
+						record(status);
+					}
+
 					// Check if the transaction could be submitted again
 					// at a later time.
 					if event.is_retriable() {
+						last_failure = match event {
+							TransactionStatus::Invalid => BroadcastOutcome::Invalid,
+							_ => BroadcastOutcome::Dropped,
+						};
 						break;
 					}
 
 					// Stop if this is the final event of the transaction stream
 					// and the event is not retriable.
-					if event.is_final() {
-						is_done = true;
-						break;
+					match event {
+						TransactionStatus::Finalized(_) => {
+							if let Some(metrics) = &metrics {
+								metrics.transaction_finalized(submitted);
+							}
+							return BroadcastOutcome::Success
+						},
+						TransactionStatus::Usurped(_) => return BroadcastOutcome::Usurped,
+						event if event.is_final() => return BroadcastOutcome::Invalid,
+						_ => {},
 					}
 				}
 			}
@@ -166,20 +518,32 @@ where
 
 		// Convert the future into an abortable future, for easily terminating it from the
 		// `transaction_stop` method.
-		let (fut, handle) = futures::future::abortable(broadcast_transaction_fut);
+		let fut = Abortable::new(broadcast_transaction_fut, registration);
 		let broadcast_ids = self.broadcast_ids.clone();
+		let finished = self.finished.clone();
 		let drop_id = id.clone();
+		let metrics = self.metrics.clone();
 		// The future expected by the executor must be `Future<Output = ()>` instead of
-		// `Future<Output = Result<(), Aborted>>`.
-		let fut = fut.map(move |_| {
-			// Remove the entry from the broadcast IDs map.
-			broadcast_ids.write().remove(&drop_id);
+		// `Future<Output = Result<BroadcastOutcome, Aborted>>`.
+		let fut = fut.map(move |result| {
+			if let Some(metrics) = &metrics {
+				metrics.broadcast_finished(result.unwrap_or(BroadcastOutcome::Stopped));
+			}
+
+			// Move the entry from the broadcast IDs map to the finished operations, unless the
+			// operation was stopped and another one was started with the same derived ID
+			// meanwhile.
+			let mut broadcast_ids = broadcast_ids.write();
+			if broadcast_ids.get(&drop_id).map_or(false, |state| state.operation == operation) {
+				if let Some(state) = broadcast_ids.remove(&drop_id) {
+					let statuses = std::mem::take(&mut *state.statuses.lock());
+					finished.write().insert(drop_id, statuses);
+				}
+			}
 		});
 
-		// Keep track of this entry and the abortable handle.
-		{
-			let mut broadcast_ids = self.broadcast_ids.write();
-			broadcast_ids.insert(id.clone(), BroadcastState { handle });
+		if let Some(metrics) = &self.metrics {
+			metrics.broadcast_started();
 		}
 
 		sc_rpc::utils::spawn_subscription_task(&self.executor, fut);
@@ -187,17 +551,38 @@ where
 		Ok(Some(id))
 	}
 
-	fn stop_broadcast(&self, operation_id: String) -> Result<(), ErrorBroadcast> {
+	fn stop_broadcast(&self, operation_id: String) -> RpcResult<()> {
+		self.deny_unsafe.check_if_safe()?;
+
 		let mut broadcast_ids = self.broadcast_ids.write();
 
 		let Some(broadcast_state) = broadcast_ids.remove(&operation_id) else {
-			return Err(ErrorBroadcast::InvalidOperationID)
+			return Err(ErrorBroadcast::InvalidOperationID.into())
 		};
 
 		broadcast_state.handle.abort();
 
 		Ok(())
 	}
+
+	fn broadcast_status(
+		&self,
+		operation_id: String,
+	) -> Result<Vec<TransactionEvent<BlockHash<Pool>>>, ErrorBroadcast> {
+		// Both maps are locked in the same order as by a finishing operation, which moves its
+		// statuses from one to the other.
+		let broadcast_ids = self.broadcast_ids.read();
+		if let Some(state) = broadcast_ids.get(&operation_id) {
+			return Ok(state.statuses.lock().to_vec())
+		}
+
+		self.finished
+			.read()
+			.statuses
+			.get(&operation_id)
+			.map(StatusHistory::to_vec)
+			.ok_or(ErrorBroadcast::InvalidOperationID)
+	}
 }
 
 /// Returns the last element of the providided stream, or `None` if the stream is closed.
@@ -225,6 +610,7 @@ where
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::transaction::event::TransactionBlock;
 	use tokio_stream::wrappers::ReceiverStream;
 
 	#[tokio::test]
@@ -248,4 +634,61 @@ mod tests {
 		drop(tx);
 		assert_eq!(last_stream_element(&mut stream).await, None);
 	}
+
+	#[test]
+	fn resubmission_policy_backs_off() {
+		let policy = ResubmissionPolicy::default();
+		assert_eq!(policy.next_interval(Duration::ZERO), Duration::ZERO);
+		assert!(policy.may_resubmit(u32::MAX - 1));
+
+		let policy = ResubmissionPolicy {
+			interval: Duration::from_secs(1),
+			exponential_backoff: true,
+			max_interval: Duration::from_secs(3),
+			max_attempts: NonZeroU32::new(2),
+		};
+		assert_eq!(policy.next_interval(Duration::from_secs(1)), Duration::from_secs(2));
+		assert_eq!(policy.next_interval(Duration::from_secs(2)), Duration::from_secs(3));
+		assert!(policy.may_resubmit(1));
+		assert!(!policy.may_resubmit(2));
+	}
+
+	#[test]
+	fn status_history_is_bounded() {
+		let mut history = StatusHistory::<u64>::default();
+		for index in 0..=MAX_STATUSES_PER_OPERATION {
+			history.push(TransactionEvent::BestChainBlockIncluded(Some(TransactionBlock {
+				hash: 1,
+				index,
+			})));
+		}
+
+		let statuses = history.to_vec();
+		assert_eq!(statuses.len(), MAX_STATUSES_PER_OPERATION);
+		assert_eq!(
+			statuses[0],
+			TransactionEvent::BestChainBlockIncluded(Some(TransactionBlock { hash: 1, index: 1 }))
+		);
+	}
+
+	#[test]
+	fn finished_operations_are_bounded() {
+		let mut finished = FinishedOperations::<u64>::default();
+		for id in 0..=MAX_FINISHED_OPERATIONS {
+			finished.insert(id.to_string(), StatusHistory::default());
+		}
+		assert_eq!(finished.statuses.len(), MAX_FINISHED_OPERATIONS);
+		assert!(!finished.statuses.contains_key("0"));
+
+		// A reused ID is moved to the end of the queue.
+		finished.insert("1".into(), StatusHistory::default());
+		finished.insert("new".into(), StatusHistory::default());
+		assert!(finished.statuses.contains_key("1"));
+		assert!(!finished.statuses.contains_key("2"));
+		assert_eq!(finished.order.len(), MAX_FINISHED_OPERATIONS);
+
+		finished.remove("1");
+		assert!(!finished.statuses.contains_key("1"));
+		assert_eq!(finished.order.len(), MAX_FINISHED_OPERATIONS - 1);
+	}
 }
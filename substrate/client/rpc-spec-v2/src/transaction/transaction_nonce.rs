@@ -0,0 +1,71 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! API implementation for picking the nonce of the next transaction of an account.
+
+use crate::transaction::{api::TransactionNonceApiServer, error::ErrorNonce};
+use codec::Codec;
+use jsonrpsee::core::async_trait;
+use sc_rpc::AccountNonceProvider;
+use sc_transaction_pool_api::{AccountStatus, TransactionPool};
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::{AtLeast32Bit, Block as BlockT};
+use std::{marker::PhantomData, sync::Arc};
+
+/// An API for picking transaction nonces.
+pub struct TransactionNonce<Pool, Client, Nonces, Block> {
+	/// Substrate client.
+	client: Arc<Client>,
+	/// Transactions pool.
+	pool: Arc<Pool>,
+	/// Provides the on-chain nonces of accounts.
+	nonces: Nonces,
+	_phantom: PhantomData<Block>,
+}
+
+impl<Pool, Client, Nonces, Block> TransactionNonce<Pool, Client, Nonces, Block> {
+	/// Creates a new [`TransactionNonce`].
+	///
+	/// The on-chain nonces are taken from `nonces`, so that the chain can expose them with any
+	/// runtime API.
+	pub fn new(client: Arc<Client>, pool: Arc<Pool>, nonces: Nonces) -> Self {
+		TransactionNonce { client, pool, nonces, _phantom: PhantomData }
+	}
+}
+
+#[async_trait]
+impl<Pool, Client, Nonces, Block, AccountId, Nonce> TransactionNonceApiServer<AccountId, Nonce>
+	for TransactionNonce<Pool, Client, Nonces, Block>
+where
+	Block: BlockT,
+	Pool: TransactionPool + Send + Sync + 'static,
+	Client: HeaderBackend<Block> + Send + Sync + 'static,
+	Nonces: AccountNonceProvider<Block, AccountId, Nonce> + 'static,
+	AccountId: Codec + Clone + Send + 'static,
+	Nonce: Codec + AtLeast32Bit + Clone + Send + 'static,
+{
+	async fn next_nonce(&self, account: AccountId) -> Result<Nonce, ErrorNonce> {
+		let best_hash = self.client.info().best_hash;
+		let on_chain_nonce = self
+			.nonces
+			.account_nonce(best_hash, account.clone())
+			.map_err(ErrorNonce::RuntimeCall)?;
+
+		Ok(AccountStatus::from_pool(&*self.pool, &account, on_chain_nonce).next_nonce())
+	}
+}
@@ -29,7 +29,7 @@ pub use jsonrpsee::core::{
 	},
 	traits::IdProvider as RpcSubscriptionIdProvider,
 };
-pub use sc_rpc_api::DenyUnsafe;
+pub use sc_rpc_api::{AccountNonceProvider, DenyUnsafe, RpcClient};
 
 pub mod author;
 pub mod chain;
@@ -39,6 +39,7 @@ pub mod offchain;
 pub mod state;
 pub mod statement;
 pub mod system;
+pub mod txpool;
 pub mod utils;
 
 #[cfg(any(test, feature = "test-helpers"))]
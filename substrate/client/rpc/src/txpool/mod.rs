@@ -0,0 +1,61 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! Substrate transaction pool API.
+
+#[cfg(test)]
+mod tests;
+
+use self::error::Error;
+use jsonrpsee::core::async_trait;
+/// Re-export the API for backward compatibility.
+pub use sc_rpc_api::txpool::*;
+use sc_rpc_api::DenyUnsafe;
+use sc_transaction_pool_api::PriorityBoost;
+use sp_core::Bytes;
+use std::sync::Arc;
+
+/// Transaction pool API
+#[derive(Debug)]
+pub struct TxPool {
+	/// The accounts whose transactions are prioritized by the pool.
+	priority_boost: Arc<PriorityBoost>,
+	deny_unsafe: DenyUnsafe,
+}
+
+impl TxPool {
+	/// Create new instance of the transaction pool API.
+	pub fn new(priority_boost: Arc<PriorityBoost>, deny_unsafe: DenyUnsafe) -> Self {
+		TxPool { priority_boost, deny_unsafe }
+	}
+}
+
+#[async_trait]
+impl TxPoolApiServer for TxPool {
+	fn boosted_accounts(&self) -> Result<Vec<Bytes>, Error> {
+		self.deny_unsafe.check_if_safe()?;
+
+		Ok(self.priority_boost.accounts().into_iter().map(Into::into).collect())
+	}
+
+	fn set_boosted_accounts(&self, accounts: Vec<Bytes>) -> Result<(), Error> {
+		self.deny_unsafe.check_if_safe()?;
+
+		self.priority_boost.set_accounts(accounts.into_iter().map(|account| account.0));
+		Ok(())
+	}
+}
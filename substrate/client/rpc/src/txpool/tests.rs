@@ -0,0 +1,48 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use super::*;
+use assert_matches::assert_matches;
+
+#[test]
+fn boosted_accounts_should_work() {
+	let priority_boost = Arc::new(PriorityBoost::default());
+	let txpool = TxPool::new(priority_boost.clone(), DenyUnsafe::No);
+	let account = Bytes(vec![1; 32]);
+
+	assert_matches!(txpool.set_boosted_accounts(vec![account.clone()]), Ok(()));
+	assert_eq!(priority_boost.accounts(), vec![account.0.clone()]);
+	assert_matches!(txpool.boosted_accounts(), Ok(ref accounts) if *accounts == vec![account]);
+}
+
+#[test]
+fn txpool_calls_considered_unsafe() {
+	let txpool = TxPool::new(Default::default(), DenyUnsafe::Yes);
+
+	assert_matches!(
+		txpool.set_boosted_accounts(vec![Bytes(vec![1; 32])]),
+		Err(Error::UnsafeRpcCalled(e)) => {
+			assert_eq!(e.to_string(), "RPC call is unsafe to be called externally")
+		}
+	);
+	assert_matches!(
+		txpool.boosted_accounts(),
+		Err(Error::UnsafeRpcCalled(e)) => {
+			assert_eq!(e.to_string(), "RPC call is unsafe to be called externally")
+		}
+	);
+}
@@ -64,10 +64,12 @@ use sc_rpc::{
 	DenyUnsafe, SubscriptionTaskExecutor,
 };
 use sc_rpc_spec_v2::{
-	archive::ArchiveApiServer, chain_head::ChainHeadApiServer, transaction::TransactionApiServer,
+	archive::ArchiveApiServer,
+	chain_head::ChainHeadApiServer,
+	transaction::{TransactionApiServer, TransactionBroadcastApiServer},
 };
 use sc_telemetry::{telemetry, ConnectionMessage, Telemetry, TelemetryHandle, SUBSTRATE_INFO};
-use sc_transaction_pool_api::{MaintainedTransactionPool, TransactionPool};
+use sc_transaction_pool_api::{error::IntoPoolError, MaintainedTransactionPool, TransactionPool};
 use sc_utils::mpsc::{tracing_unbounded, TracingUnboundedSender};
 use sp_api::{CallApiAt, ProvideRuntimeApi};
 use sp_blockchain::{HeaderBackend, HeaderMetadata};
@@ -206,6 +208,19 @@ where
 		.cloned()
 		.unwrap_or_default();
 
+	let shadow_execution = config
+		.shadow_runtime
+		.as_ref()
+		.map(|path| {
+			crate::client::ShadowExecution::new(
+				path,
+				backend.clone(),
+				executor.clone(),
+				telemetry.clone(),
+			)
+		})
+		.transpose()?;
+
 	let client = {
 		let extensions = sc_client_api::execution_extensions::ExecutionExtensions::new(
 			None,
@@ -249,12 +264,17 @@ where
 				),
 				wasm_runtime_substitutes,
 				enable_import_proof_recording,
+				runtime_api_cache: Default::default(),
 			},
 		)?;
 
 		client
 	};
 
+	if let Some(shadow_execution) = shadow_execution {
+		shadow_execution.spawn(&task_manager.spawn_handle(), client.import_notification_stream());
+	}
+
 	Ok((client, backend, keystore_container, task_manager))
 }
 
@@ -411,6 +431,7 @@ where
 	TBl::Header: Unpin,
 	TBackend: 'static + sc_client_api::backend::Backend<TBl> + Send,
 	TExPool: MaintainedTransactionPool<Block = TBl, Hash = <TBl as BlockT>::Hash> + 'static,
+	TExPool::Error: IntoPoolError,
 {
 	let SpawnTasksParams {
 		mut config,
@@ -497,6 +518,10 @@ where
 
 	let rpc_id_provider = config.rpc_id_provider.take();
 
+	// The RPC module is generated more than once, the metrics must only be registered once.
+	let rpc_transaction_metrics =
+		sc_rpc_spec_v2::transaction::TransactionMetrics::new(config.prometheus_registry())?;
+
 	// jsonrpsee RPC
 	let gen_rpc_module = |deny_unsafe: DenyUnsafe| {
 		gen_rpc_module(
@@ -508,6 +533,7 @@ where
 			system_rpc_tx.clone(),
 			&config,
 			backend.clone(),
+			rpc_transaction_metrics.clone(),
 			&*rpc_builder,
 		)
 	};
@@ -610,6 +636,7 @@ pub fn gen_rpc_module<TBl, TBackend, TCl, TRpc, TExPool>(
 	system_rpc_tx: TracingUnboundedSender<sc_rpc::system::Request<TBl>>,
 	config: &Configuration,
 	backend: Arc<TBackend>,
+	transaction_metrics: Option<sc_rpc_spec_v2::transaction::TransactionMetrics>,
 	rpc_builder: &(dyn Fn(DenyUnsafe, SubscriptionTaskExecutor) -> Result<RpcModule<TRpc>, Error>),
 ) -> Result<RpcModule<()>, Error>
 where
@@ -629,6 +656,7 @@ where
 	TBackend: sc_client_api::backend::Backend<TBl> + 'static,
 	<TCl as ProvideRuntimeApi<TBl>>::Api: sp_session::SessionKeys<TBl> + sp_api::Metadata<TBl>,
 	TExPool: MaintainedTransactionPool<Block = TBl, Hash = <TBl as BlockT>::Hash> + 'static,
+	TExPool::Error: IntoPoolError,
 	TBl::Hash: Unpin,
 	TBl::Header: Unpin,
 {
@@ -657,6 +685,17 @@ where
 		client.clone(),
 		transaction_pool.clone(),
 		task_executor.clone(),
+		transaction_metrics.clone(),
+	)
+	.into_rpc();
+
+	let transaction_broadcast_v2 = sc_rpc_spec_v2::transaction::TransactionBroadcast::new(
+		client.clone(),
+		transaction_pool.clone(),
+		task_executor.clone(),
+		config.rpc_tx_resubmission,
+		transaction_metrics,
+		deny_unsafe,
 	)
 	.into_rpc();
 
@@ -681,6 +720,7 @@ where
 		let archive_v2 = sc_rpc_spec_v2::archive::Archive::new(
 			client.clone(),
 			backend.clone(),
+			task_executor.clone(),
 			genesis_hash,
 			// Defaults to sensible limits for the `Archive`.
 			sc_rpc_spec_v2::archive::ArchiveConfig::default(),
@@ -700,6 +740,10 @@ where
 
 	let system = sc_rpc::system::System::new(system_info, system_rpc_tx, deny_unsafe).into_rpc();
 
+	let txpool =
+		sc_rpc::txpool::TxPool::new(config.transaction_pool.priority_boost.clone(), deny_unsafe)
+			.into_rpc();
+
 	if let Some(storage) = backend.offchain_storage() {
 		let offchain = sc_rpc::offchain::Offchain::new(storage, deny_unsafe).into_rpc();
 
@@ -708,12 +752,16 @@ where
 
 	// Part of the RPC v2 spec.
 	rpc_api.merge(transaction_v2).map_err(|e| Error::Application(e.into()))?;
+	rpc_api
+		.merge(transaction_broadcast_v2)
+		.map_err(|e| Error::Application(e.into()))?;
 	rpc_api.merge(chain_head_v2).map_err(|e| Error::Application(e.into()))?;
 
 	// Part of the old RPC spec.
 	rpc_api.merge(chain).map_err(|e| Error::Application(e.into()))?;
 	rpc_api.merge(author).map_err(|e| Error::Application(e.into()))?;
 	rpc_api.merge(system).map_err(|e| Error::Application(e.into()))?;
+	rpc_api.merge(txpool).map_err(|e| Error::Application(e.into()))?;
 	rpc_api.merge(state).map_err(|e| Error::Application(e.into()))?;
 	rpc_api.merge(child_state).map_err(|e| Error::Application(e.into()))?;
 	// Additional [`RpcModule`]s defined in the node to fit the specific blockchain
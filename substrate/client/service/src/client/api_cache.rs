@@ -0,0 +1,222 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Cache of runtime API results at finalized blocks.
+//!
+//! The state of a finalized block never changes, so calling a runtime API function that doesn't
+//! write to the state with the same arguments at such a block always returns the same result.
+//! RPC-heavy nodes serve many identical queries, like the metadata, and the cache saves them from
+//! instantiating the runtime for each of them.
+//!
+//! Only the functions configured in [`RuntimeApiCacheConfig::methods`] are cached. These must not
+//! write to the state, as the changes a call makes are not replayed when its result is served from
+//! the cache.
+
+use parking_lot::Mutex;
+use std::{
+	collections::{HashMap, VecDeque},
+	hash::Hash,
+};
+
+/// Default size of the cache, 32 MiB.
+const DEFAULT_MAX_SIZE: usize = 32 * 1024 * 1024;
+
+/// Configuration of the runtime API result cache.
+#[derive(Debug, Clone)]
+pub struct RuntimeApiCacheConfig {
+	/// The maximum total size in bytes of the cached results. `0` disables the cache.
+	pub max_size: usize,
+	/// The runtime API functions whose results are cached, e.g. `Metadata_metadata`.
+	pub methods: Vec<String>,
+}
+
+impl Default for RuntimeApiCacheConfig {
+	fn default() -> Self {
+		Self {
+			max_size: DEFAULT_MAX_SIZE,
+			methods: vec![
+				"Core_version".into(),
+				"Metadata_metadata".into(),
+				"Metadata_metadata_at_version".into(),
+				"Metadata_metadata_versions".into(),
+			],
+		}
+	}
+}
+
+/// Key of a cached result.
+#[derive(PartialEq, Eq, Hash, Clone)]
+struct CacheKey<H> {
+	at: H,
+	function: &'static str,
+	arguments: Vec<u8>,
+}
+
+impl<H> CacheKey<H> {
+	fn size(&self) -> usize {
+		self.arguments.len() + self.function.len()
+	}
+}
+
+struct Entries<H> {
+	results: HashMap<CacheKey<H>, Vec<u8>>,
+	/// Keys of the results, least recently used first.
+	order: VecDeque<CacheKey<H>>,
+	/// Total size of the cached keys and results.
+	size: usize,
+}
+
+/// Least recently used cache of runtime API results, keyed by block hash, function and arguments.
+pub struct RuntimeApiCache<H> {
+	config: RuntimeApiCacheConfig,
+	entries: Mutex<Entries<H>>,
+}
+
+impl<H: Hash + Eq + Clone> RuntimeApiCache<H> {
+	/// Create a new, empty cache.
+	pub fn new(config: RuntimeApiCacheConfig) -> Self {
+		Self {
+			config,
+			entries: Mutex::new(Entries {
+				results: HashMap::new(),
+				order: VecDeque::new(),
+				size: 0,
+			}),
+		}
+	}
+
+	/// Whether results of `function` are cached.
+	pub fn caches(&self, function: &str) -> bool {
+		self.config.max_size > 0 && self.config.methods.iter().any(|method| method == function)
+	}
+
+	/// Get the cached result of calling `function` with `arguments` at block `at`.
+	pub fn get(&self, at: &H, function: &'static str, arguments: &[u8]) -> Option<Vec<u8>> {
+		let key = CacheKey { at: at.clone(), function, arguments: arguments.to_vec() };
+		let mut entries = self.entries.lock();
+		let result = entries.results.get(&key)?.clone();
+		if let Some(position) = entries.order.iter().position(|k| *k == key) {
+			entries.order.remove(position);
+		}
+		entries.order.push_back(key);
+		Some(result)
+	}
+
+	/// Cache the `result` of calling `function` with `arguments` at block `at`.
+	///
+	/// Evicts the least recently used results to stay within the configured size. Results larger
+	/// than the whole cache are not cached.
+	pub fn insert(&self, at: H, function: &'static str, arguments: Vec<u8>, result: Vec<u8>) {
+		let key = CacheKey { at, function, arguments };
+		let size = key.size() + result.len();
+		if size > self.config.max_size {
+			return
+		}
+
+		let mut entries = self.entries.lock();
+		if entries.results.contains_key(&key) {
+			return
+		}
+		while entries.size + size > self.config.max_size {
+			let Some(evicted) = entries.order.pop_front() else { break };
+			if let Some(result) = entries.results.remove(&evicted) {
+				entries.size -= evicted.size() + result.len();
+			}
+		}
+		entries.size += size;
+		entries.order.push_back(key.clone());
+		entries.results.insert(key, result);
+	}
+
+	/// Remove all results cached at block `at`, e.g. because its state was pruned.
+	pub fn remove_block(&self, at: &H) {
+		let mut entries = self.entries.lock();
+		let Entries { results, order, size } = &mut *entries;
+		results.retain(|key, result| {
+			let keep = key.at != *at;
+			if !keep {
+				*size -= key.size() + result.len();
+			}
+			keep
+		});
+		order.retain(|key| key.at != *at);
+	}
+
+	/// The total size of the cached keys and results.
+	#[cfg(test)]
+	fn size(&self) -> usize {
+		self.entries.lock().size
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn cache(max_size: usize) -> RuntimeApiCache<u64> {
+		RuntimeApiCache::new(RuntimeApiCacheConfig {
+			max_size,
+			methods: vec!["Metadata_metadata".into()],
+		})
+	}
+
+	#[test]
+	fn only_configured_methods_are_cached() {
+		assert!(cache(100).caches("Metadata_metadata"));
+		assert!(!cache(100).caches("Core_execute_block"));
+		assert!(!cache(0).caches("Metadata_metadata"));
+	}
+
+	#[test]
+	fn results_are_keyed_by_block_and_arguments() {
+		let cache = cache(1000);
+		cache.insert(1, "Metadata_metadata", vec![], vec![1]);
+		cache.insert(1, "Metadata_metadata", vec![0], vec![2]);
+		cache.insert(2, "Metadata_metadata", vec![], vec![3]);
+
+		assert_eq!(cache.get(&1, "Metadata_metadata", &[]), Some(vec![1]));
+		assert_eq!(cache.get(&1, "Metadata_metadata", &[0]), Some(vec![2]));
+		assert_eq!(cache.get(&2, "Metadata_metadata", &[]), Some(vec![3]));
+		assert_eq!(cache.get(&3, "Metadata_metadata", &[]), None);
+
+		cache.remove_block(&1);
+		assert_eq!(cache.get(&1, "Metadata_metadata", &[]), None);
+		assert_eq!(cache.get(&2, "Metadata_metadata", &[]), Some(vec![3]));
+		assert_eq!(cache.size(), "Metadata_metadata".len() + 1);
+	}
+
+	#[test]
+	fn least_recently_used_results_are_evicted() {
+		let entry_size = "Metadata_metadata".len() + 10;
+		let cache = cache(2 * entry_size);
+		cache.insert(1, "Metadata_metadata", vec![], vec![1; 10]);
+		cache.insert(2, "Metadata_metadata", vec![], vec![2; 10]);
+		assert!(cache.get(&1, "Metadata_metadata", &[]).is_some());
+
+		cache.insert(3, "Metadata_metadata", vec![], vec![3; 10]);
+		assert!(cache.get(&2, "Metadata_metadata", &[]).is_none());
+		assert!(cache.get(&1, "Metadata_metadata", &[]).is_some());
+		assert!(cache.get(&3, "Metadata_metadata", &[]).is_some());
+		assert_eq!(cache.size(), 2 * entry_size);
+
+		// Results larger than the cache are not cached.
+		cache.insert(4, "Metadata_metadata", vec![], vec![4; 2 * entry_size]);
+		assert!(cache.get(&4, "Metadata_metadata", &[]).is_none());
+		assert!(cache.get(&1, "Metadata_metadata", &[]).is_some());
+	}
+}
@@ -18,7 +18,10 @@
 
 //! Substrate Client
 
-use super::block_rules::{BlockRules, LookupResult as BlockLookupResult};
+use super::{
+	api_cache::{RuntimeApiCache, RuntimeApiCacheConfig},
+	block_rules::{BlockRules, LookupResult as BlockLookupResult},
+};
 use futures::{FutureExt, StreamExt};
 use log::{error, info, trace, warn};
 use parking_lot::{Mutex, RwLock};
@@ -112,6 +115,8 @@ where
 	// Holds the block hash currently being imported. TODO: replace this with block queue.
 	importing_block: RwLock<Option<Block::Hash>>,
 	block_rules: BlockRules<Block>,
+	// Results of runtime API calls at finalized blocks.
+	api_cache: RuntimeApiCache<Block::Hash>,
 	config: ClientConfig<Block>,
 	telemetry: Option<TelemetryHandle>,
 	unpin_worker_sender: TracingUnboundedSender<Block::Hash>,
@@ -200,6 +205,8 @@ pub struct ClientConfig<Block: BlockT> {
 	pub wasm_runtime_substitutes: HashMap<NumberFor<Block>, Vec<u8>>,
 	/// Enable recording of storage proofs during block import
 	pub enable_import_proof_recording: bool,
+	/// Cache of the results of runtime API calls at finalized blocks.
+	pub runtime_api_cache: RuntimeApiCacheConfig,
 }
 
 impl<Block: BlockT> Default for ClientConfig<Block> {
@@ -211,6 +218,7 @@ impl<Block: BlockT> Default for ClientConfig<Block> {
 			no_genesis: false,
 			wasm_runtime_substitutes: HashMap::new(),
 			enable_import_proof_recording: false,
+			runtime_api_cache: Default::default(),
 		}
 	}
 }
@@ -447,6 +455,7 @@ where
 			finality_actions: Default::default(),
 			importing_block: Default::default(),
 			block_rules: BlockRules::new(fork_blocks, bad_blocks),
+			api_cache: RuntimeApiCache::new(config.runtime_api_cache.clone()),
 			config,
 			telemetry,
 			unpin_worker_sender,
@@ -486,6 +495,13 @@ where
 		CallExecutor::runtime_version(&self.executor, hash)
 	}
 
+	/// Whether the block with the given hash is finalized.
+	fn is_finalized(&self, hash: Block::Hash) -> sp_blockchain::Result<bool> {
+		let blockchain = self.backend.blockchain();
+		let Some(number) = blockchain.number(hash)? else { return Ok(false) };
+		Ok(number <= blockchain.info().finalized_number && blockchain.hash(number)? == Some(hash))
+	}
+
 	/// Apply a checked and validated block to an operation.
 	fn apply_block(
 		&self,
@@ -1686,17 +1702,41 @@ where
 	type StateBackend = B::State;
 
 	fn call_api_at(&self, params: CallApiAtParams<Block>) -> Result<Vec<u8>, sp_api::ApiError> {
-		self.executor
-			.contextual_call(
-				params.at,
-				params.function,
-				&params.arguments,
-				params.overlayed_changes,
-				params.recorder,
-				params.call_context,
-				params.extensions,
-			)
-			.map_err(Into::into)
+		// Only calls that start from the plain state of a finalized block are deterministic.
+		let cacheable = self.api_cache.caches(params.function) &&
+			params.recorder.is_none() &&
+			params.call_context == CallContext::Offchain &&
+			params.overlayed_changes.borrow().is_empty() &&
+			self.is_finalized(params.at)?;
+
+		if cacheable {
+			if let Some(result) = self.api_cache.get(&params.at, params.function, &params.arguments)
+			{
+				let number = self.expect_block_number_from_id(&BlockId::Hash(params.at))?;
+				if self.backend.have_state_at(params.at, number) {
+					return Ok(result)
+				}
+				self.api_cache.remove_block(&params.at);
+			}
+		}
+
+		let result = self.executor.contextual_call(
+			params.at,
+			params.function,
+			&params.arguments,
+			params.overlayed_changes,
+			params.recorder,
+			params.call_context,
+			params.extensions,
+		)?;
+
+		// Calls that write to the state are not served from the cache.
+		if cacheable && params.overlayed_changes.borrow().is_empty() {
+			self.api_cache
+				.insert(params.at, params.function, params.arguments, result.clone());
+		}
+
+		Ok(result)
 	}
 
 	fn runtime_version_at(&self, hash: Block::Hash) -> Result<RuntimeVersion, sp_api::ApiError> {
@@ -44,13 +44,17 @@
 //! the ways in which the runtime can interface with the outside. Any code that builds a `Client`
 //! is responsible for putting the right marker.
 
+mod api_cache;
 mod block_rules;
 mod call_executor;
 mod client;
+mod shadow_execution;
 mod wasm_override;
 mod wasm_substitutes;
 
+pub(crate) use self::shadow_execution::ShadowExecution;
 pub use self::{
+	api_cache::RuntimeApiCacheConfig,
 	call_executor::LocalCallExecutor,
 	client::{Client, ClientConfig},
 };
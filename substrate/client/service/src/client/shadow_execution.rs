@@ -0,0 +1,355 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Shadow Execution
+//!
+//! Shadow execution re-executes every imported block against a candidate runtime, to validate it
+//! with real traffic before it is enacted with `set_code`.
+//!
+//! ## Usage
+//!
+//! Shadow execution is enabled with the `--shadow-runtime` argument, which expects the path to the
+//! WASM blob of the candidate runtime. The blob must be built with the `shadow-execution` feature
+//! of `frame-executive`, so that it doesn't run its migrations: the state it executes the blocks
+//! on was not migrated. Such a blob must never be enacted.
+//!
+//! The blocks are executed after they were imported, outside of the import pipeline, on top of
+//! the state of their parent. At most [`MAX_PENDING_BLOCKS`] imported blocks wait to be executed,
+//! the blocks imported while that many are pending are skipped. A block whose execution fails, e.g.
+//! because the computed state root doesn't match the one in its header, is reported as a divergence
+//! through the logs and the telemetry. Nothing computed by the shadow execution is kept.
+
+use crate::SpawnTaskHandle;
+use codec::Encode;
+use futures::{channel::mpsc, future, Future, Stream, StreamExt};
+use sc_client_api::{backend, BlockImportNotification, ImportNotifications};
+use sc_executor::RuntimeVersionOf;
+use sc_telemetry::{telemetry, TelemetryHandle, SUBSTRATE_INFO};
+use sp_blockchain::{Backend as _, Result};
+use sp_core::traits::{
+	CallContext, CodeExecutor, FetchRuntimeCode, ReadRuntimeVersionExt, RuntimeCode,
+	WrappedRuntimeCode,
+};
+use sp_externalities::Extensions;
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT};
+use sp_state_machine::{BasicExternalities, OverlayedChanges, StateMachine};
+use sp_version::RuntimeVersion;
+use std::{
+	fs,
+	marker::PhantomData,
+	path::{Path, PathBuf},
+	sync::Arc,
+};
+
+const LOG_TARGET: &str = "shadow-execution";
+
+/// The maximum number of imported blocks waiting to be executed.
+const MAX_PENDING_BLOCKS: usize = 16;
+
+#[derive(Debug, thiserror::Error)]
+#[allow(missing_docs)]
+pub enum ShadowExecutionError {
+	#[error("Failed to get runtime version: {0}")]
+	VersionInvalid(String),
+
+	#[error("Shadow runtime IO error")]
+	Io(PathBuf, #[source] std::io::Error),
+}
+
+impl From<ShadowExecutionError> for sp_blockchain::Error {
+	fn from(err: ShadowExecutionError) -> Self {
+		Self::Application(Box::new(err))
+	}
+}
+
+/// The WASM blob of the candidate runtime.
+#[derive(Debug)]
+struct ShadowRuntime {
+	/// The actual wasm blob, aka the code.
+	code: Vec<u8>,
+	/// The hash of [`Self::code`].
+	hash: Vec<u8>,
+	/// The runtime version of this blob.
+	version: RuntimeVersion,
+}
+
+impl ShadowRuntime {
+	/// Read the blob at `path`.
+	fn read<E: RuntimeVersionOf>(path: &Path, executor: &E) -> Result<Self> {
+		let code = fs::read(path).map_err(|e| ShadowExecutionError::Io(path.to_owned(), e))?;
+		let hash = sp_core::blake2_256(&code).to_vec();
+
+		let mut ext = BasicExternalities::default();
+		let version = executor
+			.runtime_version(
+				&mut ext,
+				&RuntimeCode {
+					code_fetcher: &WrappedRuntimeCode(code.as_slice().into()),
+					heap_pages: None,
+					hash: hash.clone(),
+				},
+			)
+			.map_err(|e| ShadowExecutionError::VersionInvalid(e.to_string()))?;
+
+		Ok(Self { code, hash, version })
+	}
+
+	fn runtime_code(&self, heap_pages: Option<u64>) -> RuntimeCode {
+		RuntimeCode { code_fetcher: self, hash: self.hash.clone(), heap_pages }
+	}
+}
+
+impl FetchRuntimeCode for ShadowRuntime {
+	fn fetch_runtime_code(&self) -> Option<std::borrow::Cow<[u8]>> {
+		Some(self.code.as_slice().into())
+	}
+}
+
+/// Executes the imported blocks against a candidate runtime and reports the divergences.
+pub struct ShadowExecution<Block, B, E> {
+	backend: Arc<B>,
+	executor: E,
+	runtime: ShadowRuntime,
+	telemetry: Option<TelemetryHandle>,
+	_phantom: PhantomData<Block>,
+}
+
+impl<Block, B, E> ShadowExecution<Block, B, E>
+where
+	Block: BlockT,
+	B: backend::Backend<Block>,
+	E: CodeExecutor + RuntimeVersionOf,
+{
+	/// Create a new instance executing the blocks against the runtime at `path`.
+	pub fn new(
+		path: &Path,
+		backend: Arc<B>,
+		executor: E,
+		telemetry: Option<TelemetryHandle>,
+	) -> Result<Self> {
+		let runtime = ShadowRuntime::read(path, &executor)?;
+		log::info!(
+			target: LOG_TARGET,
+			"Shadow executing the imported blocks against runtime {} from {}",
+			runtime.version,
+			path.display(),
+		);
+
+		Ok(Self { backend, executor, runtime, telemetry, _phantom: PhantomData })
+	}
+
+	/// Execute every block announced by `imports` in a blocking task, until the client is shut
+	/// down.
+	///
+	/// The blocks imported while [`MAX_PENDING_BLOCKS`] blocks are waiting to be executed are
+	/// skipped.
+	pub fn spawn(self, spawn_handle: &SpawnTaskHandle, imports: ImportNotifications<Block>)
+	where
+		Self: Send + 'static,
+	{
+		let (queue, pending) = bounded_queue(imports, MAX_PENDING_BLOCKS);
+		spawn_handle.spawn("shadow-execution-queue", None, queue);
+		spawn_handle.spawn_blocking("shadow-execution", None, self.run(pending));
+	}
+
+	async fn run(self, mut pending: mpsc::Receiver<BlockImportNotification<Block>>) {
+		while let Some(notification) = pending.next().await {
+			let number = *notification.header.number();
+			match self.execute_block(notification.header) {
+				Ok(None) => log::trace!(
+					target: LOG_TARGET,
+					"Block #{number} ({}) executed identically",
+					notification.hash,
+				),
+				Ok(Some(divergence)) => {
+					log::warn!(
+						target: LOG_TARGET,
+						"Block #{number} ({}) diverged: {divergence}",
+						notification.hash,
+					);
+					telemetry!(
+						self.telemetry;
+						SUBSTRATE_INFO;
+						"block.shadow_execution_divergence";
+						"number" => ?number,
+						"hash" => ?notification.hash,
+						"spec_version" => self.runtime.version.spec_version,
+						"divergence" => divergence,
+					);
+				},
+				Err(e) => log::debug!(
+					target: LOG_TARGET,
+					"Failed to execute block #{number} ({}): {e}",
+					notification.hash,
+				),
+			}
+		}
+	}
+
+	/// Execute the block of `header` on top of the state of its parent.
+	///
+	/// Returns why the execution failed if it did, i.e. why the candidate runtime diverged.
+	fn execute_block(&self, header: Block::Header) -> Result<Option<String>> {
+		let hash = header.hash();
+		let extrinsics = self
+			.backend
+			.blockchain()
+			.body(hash)?
+			.ok_or_else(|| sp_blockchain::Error::UnknownBlock(format!("{hash}")))?;
+		self.execute(Block::new(header, extrinsics))
+	}
+
+	/// Execute `block` on top of the state of its parent.
+	///
+	/// Returns why the execution failed if it did.
+	fn execute(&self, block: Block) -> Result<Option<String>> {
+		let parent_hash = *block.header().parent_hash();
+		let state = self.backend.state_at(parent_hash)?;
+
+		let heap_pages = sp_state_machine::backend::BackendRuntimeCode::new(&state)
+			.runtime_code()
+			.map_err(sp_blockchain::Error::RuntimeCode)?
+			.heap_pages;
+		let runtime_code = self.runtime.runtime_code(heap_pages);
+
+		let mut changes = OverlayedChanges::default();
+		let mut extensions = Extensions::default();
+		extensions.register(ReadRuntimeVersionExt::new(self.executor.clone()));
+
+		let result = StateMachine::new(
+			&state,
+			&mut changes,
+			&self.executor,
+			"Core_execute_block",
+			&block.encode(),
+			&mut extensions,
+			&runtime_code,
+			CallContext::Onchain,
+		)
+		.set_parent_hash(parent_hash)
+		.execute();
+
+		Ok(result.err().map(|e| e.to_string()))
+	}
+}
+
+/// Forward the items of `stream` to a queue of at most `capacity` items, dropping the items
+/// received while it is full.
+fn bounded_queue<T>(
+	stream: impl Stream<Item = T>,
+	capacity: usize,
+) -> (impl Future<Output = ()>, mpsc::Receiver<T>) {
+	// The channel holds one item per sender on top of its buffer.
+	let (mut sender, receiver) = mpsc::channel(capacity.saturating_sub(1));
+	let forward = stream.for_each(move |item| {
+		match sender.try_send(item) {
+			Err(e) if e.is_full() => log::debug!(
+				target: LOG_TARGET,
+				"Skipping an imported block, {capacity} blocks are waiting to be executed",
+			),
+			// The shadow execution stopped, or the item was queued.
+			_ => {},
+		}
+		future::ready(())
+	});
+
+	(forward, receiver)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use futures::executor::block_on;
+	use sc_block_builder::BlockBuilderBuilder;
+	use sc_executor::{NativeElseWasmExecutor, WasmExecutor};
+	use sp_consensus::BlockOrigin;
+	use substrate_test_runtime_client::{
+		ClientBlockImportExt, DefaultTestClientBuilderExt, LocalExecutorDispatch,
+		TestClientBuilder, TestClientBuilderExt,
+	};
+
+	fn executor() -> NativeElseWasmExecutor<LocalExecutorDispatch> {
+		NativeElseWasmExecutor::new_with_wasm_executor(
+			WasmExecutor::builder()
+				.with_max_runtime_instances(1)
+				.with_runtime_cache_size(2)
+				.build(),
+		)
+	}
+
+	#[test]
+	fn should_read_shadow_runtime() {
+		let dir = tempfile::tempdir().expect("Create a temporary directory");
+		let path = dir.path().join("candidate.wasm");
+		fs::write(&path, substrate_test_runtime::wasm_binary_unwrap()).expect("Create test file");
+
+		let runtime = ShadowRuntime::read(&path, &executor()).expect("Reads the shadow runtime");
+		assert_eq!(runtime.version.spec_version, 2);
+		assert_eq!(runtime.code, substrate_test_runtime::wasm_binary_unwrap().to_vec());
+	}
+
+	#[test]
+	fn should_fail_on_missing_shadow_runtime() {
+		let dir = tempfile::tempdir().expect("Create a temporary directory");
+		let read = ShadowRuntime::read(&dir.path().join("missing.wasm"), &executor());
+
+		match read {
+			Err(sp_blockchain::Error::Application(e)) => assert!(matches!(
+				e.downcast_ref::<ShadowExecutionError>(),
+				Some(ShadowExecutionError::Io(..))
+			)),
+			_ => panic!("Reading a missing runtime should fail"),
+		}
+	}
+
+	#[test]
+	fn should_detect_divergences() {
+		let (client, backend) = TestClientBuilder::new().build_with_backend();
+		let mut client = Arc::new(client);
+		let block = BlockBuilderBuilder::new(&*client)
+			.on_parent_block(client.chain_info().genesis_hash)
+			.with_parent_block_number(0)
+			.build()
+			.unwrap()
+			.build()
+			.unwrap()
+			.block;
+		block_on(client.import(BlockOrigin::Own, block.clone())).unwrap();
+
+		let dir = tempfile::tempdir().expect("Create a temporary directory");
+		let path = dir.path().join("candidate.wasm");
+		fs::write(&path, substrate_test_runtime::wasm_binary_unwrap()).expect("Create test file");
+		let shadow_execution = ShadowExecution::new(&path, backend, executor(), None).unwrap();
+
+		assert_eq!(shadow_execution.execute_block(block.header().clone()).unwrap(), None);
+
+		// The candidate computes another state root than the one of the block.
+		let (mut header, extrinsics) = block.deconstruct();
+		header.state_root = Default::default();
+		let divergence = shadow_execution.execute(BlockT::new(header, extrinsics)).unwrap();
+		assert!(divergence.is_some());
+	}
+
+	#[test]
+	fn should_skip_blocks_while_the_queue_is_full() {
+		let (queue, pending) = bounded_queue(futures::stream::iter(0..10), 4);
+		block_on(queue);
+
+		assert_eq!(block_on(pending.collect::<Vec<_>>()), vec![0, 1, 2, 3]);
+	}
+}
@@ -34,8 +34,23 @@ pub use sc_network::{
 	},
 	Multiaddr,
 };
+pub use sc_rpc_server::{
+	RpcAuthConfig, RpcAuthProvider, RpcMethodGroup, RpcMethodRateLimit, TokenAuth,
+};
+pub use sc_rpc_spec_v2::{
+	aliases::MethodAliases as RpcMethodAliases,
+	transaction::ResubmissionPolicy as TransactionResubmissionPolicy,
+};
 pub use sc_telemetry::TelemetryEndpoints;
-pub use sc_transaction_pool::Options as TransactionPoolOptions;
+pub use sc_transaction_pool::{
+	FifoPriorityStrategy, Options as TransactionPoolOptions,
+	PriorityStrategy as TransactionPriorityStrategy, PropagationInfo as TransactionPropagationInfo,
+	PropagationPolicy as TransactionPropagationPolicy, TagPriorityStrategy, TransactionTtl,
+	ValidityPropagationPolicy,
+};
+pub use sc_transaction_pool_api::priority_boost::{
+	PriorityBoost as TransactionPriorityBoost, DEFAULT_PRIORITY_BOOST,
+};
 use sp_core::crypto::SecretString;
 use std::{
 	io, iter,
@@ -82,6 +97,9 @@ pub struct Configuration {
 	/// over on-chain runtimes when the spec version matches. Set to `None` to
 	/// disable overrides (default).
 	pub wasm_runtime_overrides: Option<PathBuf>,
+	/// Path of the WASM blob of a candidate runtime, against which every imported block is also
+	/// executed to report where it diverges. Set to `None` to disable shadow execution (default).
+	pub shadow_runtime: Option<PathBuf>,
 	/// JSON-RPC server binding address.
 	pub rpc_addr: Option<SocketAddr>,
 	/// Maximum number of connections for JSON-RPC server.
@@ -108,6 +126,14 @@ pub struct Configuration {
 	pub rpc_batch_config: RpcBatchRequestConfig,
 	/// RPC rate limit per minute.
 	pub rpc_rate_limit: Option<NonZeroU32>,
+	/// Rate limits of groups of RPC methods, shared by all connections.
+	pub rpc_method_rate_limits: Vec<RpcMethodRateLimit>,
+	/// Authorization of the calls to protected groups of RPC methods. `None` if disabled.
+	pub rpc_auth: Option<RpcAuthConfig>,
+	/// When transactions broadcast through the RPC are resubmitted to the pool.
+	pub rpc_tx_resubmission: TransactionResubmissionPolicy,
+	/// Additional names of RPC methods and deprecated RPC methods.
+	pub rpc_method_aliases: RpcMethodAliases,
 	/// Prometheus endpoint configuration. `None` if disabled.
 	pub prometheus_config: Option<PrometheusConfig>,
 	/// Telemetry service URL. `None` if disabled.
@@ -58,7 +58,7 @@ pub use self::{
 		KeystoreContainer, NetworkStarter, SpawnTasksParams, TFullBackend, TFullCallExecutor,
 		TFullClient,
 	},
-	client::{ClientConfig, LocalCallExecutor},
+	client::{ClientConfig, LocalCallExecutor, RuntimeApiCacheConfig},
 	error::Error,
 };
 
@@ -391,6 +391,12 @@ where
 	let backup_addr = backup_port(addr);
 	let metrics = sc_rpc_server::RpcMetrics::new(config.prometheus_registry())?;
 
+	let mut rpc_api = gen_rpc_module(deny_unsafe(addr, &config.rpc_methods))?;
+	config
+		.rpc_method_aliases
+		.register(&mut rpc_api)
+		.map_err(|e| Error::Application(e.into()))?;
+
 	let server_config = sc_rpc_server::Config {
 		addrs: [addr, backup_addr],
 		batch_config: config.rpc_batch_config,
@@ -399,12 +405,20 @@ where
 		max_payload_out_mb: config.rpc_max_response_size,
 		max_subs_per_conn: config.rpc_max_subs_per_conn,
 		message_buffer_capacity: config.rpc_message_buffer_capacity,
-		rpc_api: gen_rpc_module(deny_unsafe(addr, &config.rpc_methods))?,
+		rpc_api,
 		metrics,
 		id_provider: rpc_id_provider,
 		cors: config.rpc_cors.as_ref(),
 		tokio_handle: config.tokio_handle.clone(),
 		rate_limit: config.rpc_rate_limit,
+		method_rate_limits: config.rpc_method_rate_limits.clone(),
+		deprecated_methods: config.rpc_method_aliases.deprecations().clone(),
+		method_aliases: config
+			.rpc_method_aliases
+			.aliases()
+			.map(|(alias, target)| (alias.into(), target.into()))
+			.collect(),
+		auth: config.rpc_auth.clone(),
 	};
 
 	// TODO: https://github.com/paritytech/substrate/issues/13773
@@ -443,7 +457,7 @@ where
 	E: IntoPoolError + From<sc_transaction_pool_api::error::Error>,
 {
 	pool.ready()
-		.filter(|t| t.is_propagable())
+		.filter(|t| pool.should_propagate(t))
 		.map(|t| {
 			let hash = t.hash().clone();
 			let ex: B::Extrinsic = t.data().clone();
@@ -518,7 +532,7 @@ where
 	fn transaction(&self, hash: &H) -> Option<B::Extrinsic> {
 		self.pool.ready_transaction(hash).and_then(
 			// Only propagable transactions should be resolved for network service.
-			|tx| if tx.is_propagable() { Some(tx.data().clone()) } else { None },
+			|tx| if self.pool.should_propagate(&tx) { Some(tx.data().clone()) } else { None },
 		)
 	}
 }
@@ -527,8 +541,11 @@ where
 mod tests {
 	use super::*;
 	use futures::executor::block_on;
-	use sc_transaction_pool::BasicPool;
+	use sc_transaction_pool::{
+		BasicPool, Options as TransactionPoolOptions, PropagationInfo, PropagationPolicy,
+	};
 	use sp_consensus::SelectChain;
+	use sp_runtime::transaction_validity::TransactionSource;
 	use substrate_test_runtime_client::{
 		prelude::*,
 		runtime::{ExtrinsicBuilder, Transfer, TransferData},
@@ -567,4 +584,45 @@ mod tests {
 		assert_eq!(transactions.len(), 1);
 		assert!(TransferData::try_from(&transactions[0].1).is_ok());
 	}
+	#[test]
+	fn should_not_propagate_transactions_kept_local_by_the_policy() {
+		#[derive(Debug)]
+		struct KeepExternalLocal;
+
+		impl PropagationPolicy for KeepExternalLocal {
+			fn should_propagate(&self, tx: &PropagationInfo) -> bool {
+				tx.propagate && tx.source != TransactionSource::External
+			}
+		}
+
+		// given
+		let (client, longest_chain) = TestClientBuilder::new().build_with_longest_chain();
+		let client = Arc::new(client);
+		let spawner = sp_core::testing::TaskExecutor::new();
+		let options = TransactionPoolOptions {
+			propagation_policy: Arc::new(KeepExternalLocal),
+			..Default::default()
+		};
+		let pool = BasicPool::new_full(options, true.into(), None, spawner, client.clone());
+		let best = block_on(longest_chain.best_chain()).unwrap();
+		let transfer = |nonce| {
+			Transfer {
+				amount: 5,
+				nonce,
+				from: AccountKeyring::Alice.into(),
+				to: AccountKeyring::Bob.into(),
+			}
+			.into_unchecked_extrinsic()
+		};
+		block_on(pool.submit_one(best.hash(), TransactionSource::External, transfer(0))).unwrap();
+		block_on(pool.submit_one(best.hash(), TransactionSource::Local, transfer(1))).unwrap();
+		assert_eq!(pool.status().ready, 2);
+
+		// when
+		let transactions = transactions_to_propagate(&*pool);
+
+		// then
+		assert_eq!(transactions.len(), 1);
+		assert_eq!(transactions[0].1, transfer(1));
+	}
 }
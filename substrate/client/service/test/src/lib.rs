@@ -244,6 +244,7 @@ fn node_config<
 		chain_spec: Box::new((*spec).clone()),
 		wasm_method: Default::default(),
 		wasm_runtime_overrides: Default::default(),
+		shadow_runtime: Default::default(),
 		rpc_addr: Default::default(),
 		rpc_max_connections: Default::default(),
 		rpc_cors: None,
@@ -256,6 +257,10 @@ fn node_config<
 		rpc_message_buffer_capacity: Default::default(),
 		rpc_batch_config: RpcBatchRequestConfig::Unlimited,
 		rpc_rate_limit: None,
+		rpc_method_rate_limits: Default::default(),
+		rpc_auth: None,
+		rpc_tx_resubmission: Default::default(),
+		rpc_method_aliases: Default::default(),
 		prometheus_config: None,
 		telemetry_endpoints: None,
 		default_heap_pages: None,
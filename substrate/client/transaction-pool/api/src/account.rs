@@ -0,0 +1,231 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Per-account view of the transactions in the pool.
+//!
+//! Transactions are opaque to the pool. To attribute them to an account we rely on the tag
+//! convention used by FRAME's `CheckNonce` signed extension: a transaction with nonce `n` sent by
+//! `who` provides the tag `(who, n).encode()` and requires `(who, n - 1).encode()` unless `n` is
+//! the current on-chain nonce.
+
+use crate::{InPoolTransaction, TransactionLongevity, TransactionPool, TransactionPriority};
+use codec::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+use sp_runtime::traits::AtLeast32Bit;
+use std::collections::BTreeSet;
+
+/// A transaction of a single account in the pool.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountTransaction<Hash, Nonce> {
+	/// Hash of the transaction.
+	pub hash: Hash,
+	/// Nonce of the transaction.
+	pub nonce: Nonce,
+	/// Priority of the transaction.
+	pub priority: TransactionPriority,
+	/// Number of blocks the transaction stays valid for, as reported by the runtime.
+	pub longevity: TransactionLongevity,
+}
+
+/// An inclusive range of nonces without any transaction in the pool.
+///
+/// Transactions with a nonce above a gap stay in the future queue until the gap is filled.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NonceGap<Nonce> {
+	/// First missing nonce.
+	pub start: Nonce,
+	/// Last missing nonce.
+	pub end: Nonce,
+}
+
+/// The transactions of an account in the pool.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountStatus<Hash, Nonce> {
+	/// The nonce of the account according to the state of the best block.
+	pub on_chain_nonce: Nonce,
+	/// Transactions that are ready to be included, ordered by nonce.
+	pub ready: Vec<AccountTransaction<Hash, Nonce>>,
+	/// Transactions waiting for their requirements to be satisfied, ordered by nonce.
+	pub future: Vec<AccountTransaction<Hash, Nonce>>,
+	/// Missing nonces that keep the `future` transactions from becoming ready.
+	pub gaps: Vec<NonceGap<Nonce>>,
+}
+
+impl<Hash, Nonce> AccountStatus<Hash, Nonce>
+where
+	Hash: Clone,
+	Nonce: AtLeast32Bit + Decode + Clone,
+{
+	/// Collect the transactions of `account` from the `pool`.
+	///
+	/// `on_chain_nonce` is the account's nonce at the block the pool is currently at.
+	pub fn from_pool<P, AccountId>(pool: &P, account: &AccountId, on_chain_nonce: Nonce) -> Self
+	where
+		P: TransactionPool<Hash = Hash> + ?Sized,
+		AccountId: Encode,
+	{
+		let account = account.encode();
+		let ready = pool
+			.ready()
+			.filter_map(|tx| account_transaction(&*tx, &account))
+			.collect::<Vec<_>>();
+		let future = pool
+			.futures()
+			.iter()
+			.filter_map(|tx| account_transaction(tx, &account))
+			.collect::<Vec<_>>();
+		Self::new(on_chain_nonce, ready, future)
+	}
+
+	/// Build the status from the transactions of an account, computing the gaps.
+	pub fn new(
+		on_chain_nonce: Nonce,
+		mut ready: Vec<AccountTransaction<Hash, Nonce>>,
+		mut future: Vec<AccountTransaction<Hash, Nonce>>,
+	) -> Self {
+		ready.sort_by(|a, b| a.nonce.cmp(&b.nonce));
+		future.sort_by(|a, b| a.nonce.cmp(&b.nonce));
+
+		let known = ready
+			.iter()
+			.chain(future.iter())
+			.map(|tx| tx.nonce.clone())
+			.filter(|nonce| *nonce >= on_chain_nonce)
+			.collect::<BTreeSet<_>>();
+		let mut gaps = Vec::new();
+		let mut expected = on_chain_nonce.clone();
+		for nonce in known {
+			if nonce > expected {
+				gaps.push(NonceGap { start: expected, end: nonce.clone() - Nonce::one() });
+			}
+			expected = nonce + Nonce::one();
+		}
+
+		Self { on_chain_nonce, ready, future, gaps }
+	}
+
+	/// Convert the transaction hashes using `f`.
+	pub fn map_hash<H>(self, mut f: impl FnMut(Hash) -> H) -> AccountStatus<H, Nonce> {
+		let mut map = |txs: Vec<AccountTransaction<Hash, Nonce>>| {
+			txs.into_iter()
+				.map(|tx| AccountTransaction {
+					hash: f(tx.hash),
+					nonce: tx.nonce,
+					priority: tx.priority,
+					longevity: tx.longevity,
+				})
+				.collect()
+		};
+		AccountStatus {
+			ready: map(self.ready),
+			future: map(self.future),
+			on_chain_nonce: self.on_chain_nonce,
+			gaps: self.gaps,
+		}
+	}
+
+	/// The nonce that should be used for the next transaction of this account.
+	///
+	/// This is the first missing nonce, so that a new transaction fills the first gap if there
+	/// is one, and otherwise the nonce following the last transaction in the pool.
+	pub fn next_nonce(&self) -> Nonce {
+		if let Some(gap) = self.gaps.first() {
+			return gap.start.clone()
+		}
+		self.ready
+			.iter()
+			.chain(self.future.iter())
+			.map(|tx| tx.nonce.clone() + Nonce::one())
+			.max()
+			.map_or(self.on_chain_nonce.clone(), |nonce| nonce.max(self.on_chain_nonce.clone()))
+	}
+}
+
+/// Returns the nonce encoded in `tag` if it was provided by `account`.
+fn nonce_from_tag<Nonce: Decode>(account: &[u8], tag: &[u8]) -> Option<Nonce> {
+	let mut nonce = tag.strip_prefix(account)?;
+	let decoded = Nonce::decode(&mut nonce).ok()?;
+	nonce.is_empty().then_some(decoded)
+}
+
+fn account_transaction<Tx, Nonce>(
+	tx: &Tx,
+	account: &[u8],
+) -> Option<AccountTransaction<Tx::Hash, Nonce>>
+where
+	Tx: InPoolTransaction + ?Sized,
+	Tx::Hash: Clone,
+	Nonce: Decode,
+{
+	let nonce = tx.provides().iter().find_map(|tag| nonce_from_tag(account, tag))?;
+	Some(AccountTransaction {
+		hash: tx.hash().clone(),
+		nonce,
+		priority: *tx.priority(),
+		longevity: *tx.longevity(),
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn tx(nonce: u64) -> AccountTransaction<u64, u64> {
+		AccountTransaction { hash: nonce, nonce, priority: 0, longevity: 64 }
+	}
+
+	#[test]
+	fn nonce_from_tag_works() {
+		let account = 42u64.encode();
+		assert_eq!(nonce_from_tag::<u32>(&account, &(42u64, 7u32).encode()), Some(7));
+		assert_eq!(nonce_from_tag::<u32>(&account, &(43u64, 7u32).encode()), None);
+		// Trailing bytes mean the tag does not follow the expected format.
+		assert_eq!(nonce_from_tag::<u32>(&account, &(42u64, 7u64).encode()), None);
+	}
+
+	#[test]
+	fn gaps_are_computed() {
+		let status = AccountStatus::new(3, vec![tx(4), tx(3)], vec![tx(9), tx(6), tx(10)]);
+		assert_eq!(status.ready, vec![tx(3), tx(4)]);
+		assert_eq!(status.future, vec![tx(6), tx(9), tx(10)]);
+		assert_eq!(status.gaps, vec![NonceGap { start: 5, end: 5 }, NonceGap { start: 7, end: 8 }]);
+		assert_eq!(status.next_nonce(), 5);
+	}
+
+	#[test]
+	fn no_gaps_without_future_transactions() {
+		let status = AccountStatus::new(3, vec![tx(3), tx(4)], vec![]);
+		assert!(status.gaps.is_empty());
+		assert_eq!(status.next_nonce(), 5);
+
+		// Stale transactions below the on-chain nonce don't count.
+		let status = AccountStatus::<u64, u64>::new(3, vec![], vec![tx(1)]);
+		assert!(status.gaps.is_empty());
+		assert_eq!(status.next_nonce(), 3);
+	}
+
+	#[test]
+	fn gap_at_on_chain_nonce() {
+		let status = AccountStatus::<u64, u64>::new(3, vec![], vec![tx(5)]);
+		assert_eq!(status.gaps, vec![NonceGap { start: 3, end: 4 }]);
+		assert_eq!(status.next_nonce(), 3);
+	}
+}
@@ -69,6 +69,10 @@ pub enum Error {
 
 	#[error("The pool is not accepting future transactions")]
 	RejectedFutureTransaction,
+
+	/// The transaction was included in a recent block and is not accepted again.
+	#[error("Transaction was already included in a recent block")]
+	RecentlyIncluded,
 }
 
 impl Error {
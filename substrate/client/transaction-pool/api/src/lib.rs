@@ -19,18 +19,25 @@
 //! Transaction pool client facing API.
 #![warn(missing_docs)]
 
+pub mod account;
 pub mod error;
+pub mod priority_boost;
 
 use async_trait::async_trait;
 use codec::Codec;
-use futures::{Future, Stream};
+use futures::{Future, Stream, StreamExt};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use sp_core::offchain::TransactionPoolExt;
 use sp_runtime::traits::{Block as BlockT, Member, NumberFor};
-use std::{collections::HashMap, hash::Hash, marker::PhantomData, pin::Pin, sync::Arc};
+use std::{
+	collections::HashMap, hash::Hash, marker::PhantomData, pin::Pin, sync::Arc, time::Duration,
+};
 
 const LOG_TARGET: &str = "txpool::api";
 
+pub use account::{AccountStatus, AccountTransaction, NonceGap};
+pub use priority_boost::PriorityBoost;
+
 pub use sp_runtime::transaction_validity::{
 	TransactionLongevity, TransactionPriority, TransactionSource, TransactionTag,
 };
@@ -71,6 +78,7 @@ impl PoolStatus {
 /// 		- [Invalid](TransactionStatus::Invalid)
 /// 		- [Usurped](TransactionStatus::Usurped)
 /// 		- [Dropped](TransactionStatus::Dropped)
+/// 		- [Expired](TransactionStatus::Expired)
 /// 	4. Re-entering the pool:
 /// 		- [Retracted](TransactionStatus::Retracted)
 /// 	5. Block finalized:
@@ -96,7 +104,7 @@ impl PoolStatus {
 /// in one block, may later re-enter the pool or be marked as invalid.
 /// 2. Transaction `Dropped` at one point, may later re-enter the pool if some other
 /// transactions are removed. A `Dropped` transaction may re-enter the pool only if it is
-/// resubmitted.
+/// resubmitted. The same goes for `Expired` transactions.
 /// 3. `Invalid` transaction may become valid at some point in the future.
 /// (Note that runtimes are encouraged to use `UnknownValidity` to inform the pool about
 /// such case). An `Invalid` transaction may re-enter the pool only if it is resubmitted.
@@ -115,6 +123,7 @@ impl PoolStatus {
 /// - [Usurped](TransactionStatus::Usurped)
 /// - [Invalid](TransactionStatus::Invalid)
 /// - [Dropped](TransactionStatus::Dropped)
+/// - [Expired](TransactionStatus::Expired)
 ///
 /// See [`TransactionStatus::is_final`] for more details.
 ///
@@ -124,6 +133,7 @@ impl PoolStatus {
 /// - [FinalityTimeout](TransactionStatus::FinalityTimeout)
 /// - [Invalid](TransactionStatus::Invalid)
 /// - [Dropped](TransactionStatus::Dropped)
+/// - [Expired](TransactionStatus::Expired)
 ///
 /// See [`TransactionStatus::is_retriable`] for more details.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -152,6 +162,9 @@ pub enum TransactionStatus<Hash, BlockHash> {
 	Usurped(Hash),
 	/// Transaction has been dropped from the pool because of the limit.
 	Dropped,
+	/// Transaction has been dropped from the pool because it stayed there longer than the
+	/// time-to-live of the pool, regardless of its longevity.
+	Expired,
 	/// Transaction is no longer valid in the current state.
 	Invalid,
 }
@@ -165,7 +178,8 @@ impl<Hash, BlockHash> TransactionStatus<Hash, BlockHash> {
 			Self::Finalized(_) |
 			Self::FinalityTimeout(_) |
 			Self::Invalid |
-			Self::Dropped => true,
+			Self::Dropped |
+			Self::Expired => true,
 			_ => false,
 		}
 	}
@@ -182,16 +196,44 @@ impl<Hash, BlockHash> TransactionStatus<Hash, BlockHash> {
 			Self::Invalid |
 			// The transaction was dropped because of the limits of the pool.
 			// It can reenter the pool when other transactions are removed / finalized.
-			Self::Dropped => true,
+			Self::Dropped |
+			// The transaction stayed in the pool for too long, it can be resubmitted.
+			Self::Expired => true,
 			_ => false,
 		}
 	}
 }
 
+/// Timings recorded by the pool for a watched transaction, relative to its submission.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionTimings {
+	/// Time it took to validate the transaction before it was imported.
+	pub validation: Option<Duration>,
+	/// Time until the transaction first entered the ready queue.
+	pub ready: Option<Duration>,
+	/// Time until the transaction was last included in a block.
+	pub in_block: Option<Duration>,
+}
+
+/// A [`TransactionStatus`] together with the [`TransactionTimings`] recorded up to that event.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DetailedTransactionStatus<Hash, BlockHash> {
+	/// The status of the transaction.
+	pub status: TransactionStatus<Hash, BlockHash>,
+	/// The timings of the transaction so far.
+	pub timings: TransactionTimings,
+}
+
 /// The stream of transaction events.
 pub type TransactionStatusStream<Hash, BlockHash> =
 	dyn Stream<Item = TransactionStatus<Hash, BlockHash>> + Send;
 
+/// The stream of transaction events with their timings.
+pub type DetailedTransactionStatusStream<Hash, BlockHash> =
+	dyn Stream<Item = DetailedTransactionStatus<Hash, BlockHash>> + Send;
+
 /// The import notification event stream.
 pub type ImportNotificationStream<H> = futures::channel::mpsc::Receiver<H>;
 
@@ -203,6 +245,9 @@ pub type BlockHash<P> = <<P as TransactionPool>::Block as BlockT>::Hash;
 pub type TransactionFor<P> = <<P as TransactionPool>::Block as BlockT>::Extrinsic;
 /// Type of transactions event stream for a pool.
 pub type TransactionStatusStreamFor<P> = TransactionStatusStream<TxHash<P>, BlockHash<P>>;
+/// Type of detailed transactions event stream for a pool.
+pub type DetailedTransactionStatusStreamFor<P> =
+	DetailedTransactionStatusStream<TxHash<P>, BlockHash<P>>;
 /// Transaction type for a local pool.
 pub type LocalTransactionFor<P> = <<P as LocalTransactionPool>::Block as BlockT>::Extrinsic;
 /// Transaction's index within the block in which it was included.
@@ -278,6 +323,26 @@ pub trait TransactionPool: Send + Sync {
 		xt: TransactionFor<Self>,
 	) -> PoolFuture<Pin<Box<TransactionStatusStreamFor<Self>>>, Self::Error>;
 
+	/// Like [`Self::submit_and_watch`], but every event carries the [`TransactionTimings`] the
+	/// pool recorded for the transaction so far.
+	///
+	/// Pools that do not record timings report them as empty.
+	fn submit_and_watch_detailed(
+		&self,
+		at: <Self::Block as BlockT>::Hash,
+		source: TransactionSource,
+		xt: TransactionFor<Self>,
+	) -> PoolFuture<Pin<Box<DetailedTransactionStatusStreamFor<Self>>>, Self::Error> {
+		let watcher = self.submit_and_watch(at, source, xt);
+
+		Box::pin(async move {
+			let stream = watcher
+				.await?
+				.map(|status| DetailedTransactionStatus { status, timings: Default::default() });
+			Ok(Box::pin(stream) as Pin<Box<DetailedTransactionStatusStreamFor<Self>>>)
+		})
+	}
+
 	// *** Block production / Networking
 	/// Get an iterator for ready transactions ordered by priority.
 	///
@@ -316,6 +381,14 @@ pub trait TransactionPool: Send + Sync {
 	/// Notify the pool about transactions broadcast.
 	fn on_broadcasted(&self, propagations: HashMap<TxHash<Self>, Vec<String>>);
 
+	/// Whether a ready transaction should be handed to the network layer for propagation.
+	///
+	/// By default the transactions are propagated if their validity allows it, see
+	/// [`InPoolTransaction::is_propagable`].
+	fn should_propagate(&self, tx: &Self::InPoolTransaction) -> bool {
+		tx.is_propagable()
+	}
+
 	/// Returns transaction hash
 	fn hash_of(&self, xt: &TransactionFor<Self>) -> TxHash<Self>;
 
@@ -0,0 +1,121 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Priority boost for transactions of operator-designated accounts.
+//!
+//! Infrastructure operators, like bridge relayers or oracles, may want the transactions of their
+//! service accounts to be included before others. The transactions of the configured accounts
+//! get their priority increased and are not evicted from the pool to enforce its limits, as long
+//! as they take up at most half of the limits.
+//!
+//! Like the [`account`](crate::account) view, this relies on the tag convention of FRAME's
+//! `CheckNonce` signed extension to attribute transactions to accounts: a transaction of `who`
+//! provides a tag starting with `who.encode()`.
+
+use crate::{TransactionPriority, TransactionTag};
+use parking_lot::RwLock;
+use std::collections::BTreeSet;
+
+/// The default priority added to transactions of boosted accounts.
+///
+/// Large enough to put them ahead of any regular transaction.
+pub const DEFAULT_PRIORITY_BOOST: TransactionPriority = TransactionPriority::MAX / 2;
+
+/// The accounts whose transactions are boosted, shared between the pool and the RPC updating them.
+#[derive(Debug)]
+pub struct PriorityBoost {
+	/// The priority added to the transactions of the accounts.
+	priority: TransactionPriority,
+	/// The SCALE-encoded account IDs.
+	accounts: RwLock<BTreeSet<Vec<u8>>>,
+}
+
+impl Default for PriorityBoost {
+	fn default() -> Self {
+		Self::new(DEFAULT_PRIORITY_BOOST, [])
+	}
+}
+
+impl PriorityBoost {
+	/// Boost the transactions of the SCALE-encoded `accounts` by `priority`.
+	pub fn new(priority: TransactionPriority, accounts: impl IntoIterator<Item = Vec<u8>>) -> Self {
+		Self { priority, accounts: RwLock::new(accounts.into_iter().collect()) }
+	}
+
+	/// The priority added to the transactions of boosted accounts.
+	pub fn priority(&self) -> TransactionPriority {
+		self.priority
+	}
+
+	/// The SCALE-encoded IDs of the boosted accounts.
+	pub fn accounts(&self) -> Vec<Vec<u8>> {
+		self.accounts.read().iter().cloned().collect()
+	}
+
+	/// Replace the boosted accounts.
+	///
+	/// Transactions already in the pool keep their priority until they are revalidated.
+	pub fn set_accounts(&self, accounts: impl IntoIterator<Item = Vec<u8>>) {
+		*self.accounts.write() = accounts.into_iter().collect();
+	}
+
+	/// Whether a transaction providing `provides` belongs to a boosted account.
+	pub fn is_boosted(&self, provides: &[TransactionTag]) -> bool {
+		let accounts = self.accounts.read();
+		!accounts.is_empty() &&
+			provides.iter().any(|tag| {
+				accounts.iter().any(|account| !account.is_empty() && tag.starts_with(account))
+			})
+	}
+
+	/// The priority of a transaction with the given `priority` and `provides` tags after applying
+	/// the boost.
+	pub fn boosted_priority(
+		&self,
+		priority: TransactionPriority,
+		provides: &[TransactionTag],
+	) -> TransactionPriority {
+		if self.is_boosted(provides) {
+			priority.saturating_add(self.priority)
+		} else {
+			priority
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use codec::Encode;
+
+	#[test]
+	fn transactions_of_boosted_accounts_are_boosted() {
+		let boost = PriorityBoost::new(100, [42u64.encode()]);
+		assert!(boost.is_boosted(&[(42u64, 7u32).encode()]));
+		assert!(!boost.is_boosted(&[(43u64, 7u32).encode()]));
+		assert!(!boost.is_boosted(&[]));
+		assert_eq!(boost.boosted_priority(1, &[(42u64, 7u32).encode()]), 101);
+		assert_eq!(boost.boosted_priority(u64::MAX, &[(42u64, 7u32).encode()]), u64::MAX);
+		assert_eq!(boost.boosted_priority(1, &[(43u64, 7u32).encode()]), 1);
+
+		boost.set_accounts([43u64.encode()]);
+		assert_eq!(boost.accounts(), vec![43u64.encode()]);
+		assert!(!boost.is_boosted(&[(42u64, 7u32).encode()]));
+		assert!(boost.is_boosted(&[(43u64, 7u32).encode()]));
+	}
+}
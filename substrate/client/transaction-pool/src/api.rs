@@ -26,7 +26,7 @@ use futures::{
 	lock::Mutex,
 	SinkExt, StreamExt,
 };
-use std::{marker::PhantomData, pin::Pin, sync::Arc};
+use std::{marker::PhantomData, pin::Pin, sync::Arc, time::Instant};
 
 use prometheus_endpoint::Registry as PrometheusRegistry;
 use sc_client_api::{blockchain::HeaderBackend, BlockBackend};
@@ -47,11 +47,20 @@ use crate::{
 };
 
 /// The transaction pool logic for full client.
-pub struct FullChainApi<Client, Block> {
+/// Estimates the fee paid by an extrinsic of the given length when included on top of the given
+/// block, see [`FullChainApi::with_fee_estimator`].
+pub type FeeEstimator<Client, Block> = Arc<
+	dyn Fn(&Client, <Block as BlockT>::Hash, &<Block as BlockT>::Extrinsic, usize) -> Option<u128>
+		+ Send
+		+ Sync,
+>;
+
+pub struct FullChainApi<Client, Block: BlockT> {
 	client: Arc<Client>,
 	_marker: PhantomData<Block>,
 	metrics: Option<Arc<ApiMetrics>>,
 	validation_pool: Arc<Mutex<mpsc::Sender<Pin<Box<dyn Future<Output = ()> + Send>>>>>,
+	fee_estimator: Option<FeeEstimator<Client, Block>>,
 }
 
 /// Spawn a validation task that will be used by the transaction pool to validate transactions.
@@ -76,7 +85,7 @@ fn spawn_validation_pool_task(
 	);
 }
 
-impl<Client, Block> FullChainApi<Client, Block> {
+impl<Client, Block: BlockT> FullChainApi<Client, Block> {
 	/// Create new transaction pool logic.
 	pub fn new(
 		client: Arc<Client>,
@@ -106,8 +115,17 @@ impl<Client, Block> FullChainApi<Client, Block> {
 			validation_pool: Arc::new(Mutex::new(sender)),
 			_marker: Default::default(),
 			metrics,
+			fee_estimator: None,
 		}
 	}
+
+	/// Expose the fees estimated by `estimator` to the [`PriorityStrategy`] of the pool.
+	///
+	/// [`PriorityStrategy`]: graph::PriorityStrategy
+	pub fn with_fee_estimator(mut self, estimator: FeeEstimator<Client, Block>) -> Self {
+		self.fee_estimator = Some(estimator);
+		self
+	}
 }
 
 impl<Client, Block> graph::ChainApi for FullChainApi<Client, Block>
@@ -143,8 +161,10 @@ where
 		let metrics = self.metrics.clone();
 
 		async move {
+			let scheduled_at = Instant::now();
 			metrics.report(|m| m.validations_scheduled.inc());
 
+			let validation_metrics = metrics.clone();
 			validation_pool
 				.lock()
 				.await
@@ -152,14 +172,17 @@ where
 					async move {
 						let res = validate_transaction_blocking(&*client, at, source, uxt);
 						let _ = tx.send(res);
-						metrics.report(|m| m.validations_finished.inc());
+						validation_metrics.report(|m| m.validations_finished.inc());
 					}
 					.boxed(),
 				)
 				.await
 				.map_err(|e| Error::RuntimeApi(format!("Validation pool down: {:?}", e)))?;
 
-			match rx.await {
+			let res = rx.await;
+			metrics.report(|m| m.validation_time.observe(scheduled_at.elapsed().as_secs_f64()));
+
+			match res {
 				Ok(r) => r,
 				Err(_) => Err(Error::RuntimeApi("Validation was canceled".into())),
 			}
@@ -188,6 +211,17 @@ where
 		ex.using_encoded(|x| (<traits::HashingFor<Block> as traits::Hash>::hash(x), x.len()))
 	}
 
+	fn transaction_fee(
+		&self,
+		at: <Self::Block as BlockT>::Hash,
+		uxt: &graph::ExtrinsicFor<Self>,
+		len: usize,
+	) -> Option<u128> {
+		self.fee_estimator
+			.as_ref()
+			.and_then(|estimate| estimate(&*self.client, at, uxt, len))
+	}
+
 	fn block_header(
 		&self,
 		hash: <Self::Block as BlockT>::Hash,
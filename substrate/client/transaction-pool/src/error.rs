@@ -38,6 +38,12 @@ pub enum Error {
 
 	#[error("Runtime error: {0}")]
 	RuntimeApi(String),
+
+	#[error("There already is a transaction pool for chain {0}")]
+	DuplicateChain(String),
+
+	#[error("The limits of the transaction pool for chain {0} exceed the node-wide limits")]
+	ChainLimitsExceeded(String),
 }
 
 impl sc_transaction_pool_api::error::IntoPoolError for Error {
@@ -33,10 +33,12 @@ use sp_runtime::{
 		TransactionLongevity as Longevity, TransactionPriority as Priority,
 		TransactionSource as Source, TransactionTag as Tag,
 	},
+	Percent,
 };
 
 use super::{
 	future::{FutureTransactions, WaitingTransaction},
+	priority_strategy::{PriorityStrategy, TagPriorityStrategy},
 	ready::{BestIterator, ReadyTransactions, TransactionRef},
 };
 
@@ -51,13 +53,16 @@ pub enum Imported<Hash, Ex> {
 		promoted: Vec<Hash>,
 		/// Transactions that failed to be promoted from the Future queue and are now discarded.
 		failed: Vec<Hash>,
-		/// Transactions removed from the Ready pool (replaced).
-		removed: Vec<Arc<Transaction<Hash, Ex>>>,
+		/// Transactions removed from the Ready pool (replaced), together with the hash of the
+		/// transaction replacing them.
+		removed: Vec<(Arc<Transaction<Hash, Ex>>, Hash)>,
 	},
 	/// Transaction was successfully imported to Future queue.
 	Future {
 		/// Hash of transaction that was successfully imported.
 		hash: Hash,
+		/// Transactions removed from the Future queue, replaced by the imported one.
+		removed: Vec<Arc<Transaction<Hash, Ex>>>,
 	},
 }
 
@@ -83,6 +88,13 @@ pub struct PruneStatus<Hash, Ex> {
 	pub pruned: Vec<Arc<Transaction<Hash, Ex>>>,
 }
 
+/// Whether a transaction of priority `new` may replace transactions of collective priority `old`.
+///
+/// `new` has to exceed `old` by more than `bump` of `old`.
+pub(crate) fn is_priority_bumped(old: Priority, new: Priority, bump: Percent) -> bool {
+	new > old.saturating_add(bump.mul_ceil(old))
+}
+
 /// Immutable transaction
 #[derive(PartialEq, Eq, Clone)]
 pub struct Transaction<Hash, Extrinsic> {
@@ -104,6 +116,8 @@ pub struct Transaction<Hash, Extrinsic> {
 	pub propagate: bool,
 	/// Source of that transaction.
 	pub source: Source,
+	/// Fee paid by the transaction, if known.
+	pub fee: Option<u128>,
 }
 
 impl<Hash, Extrinsic> AsRef<Extrinsic> for Transaction<Hash, Extrinsic> {
@@ -162,6 +176,7 @@ impl<Hash: Clone, Extrinsic: Clone> Transaction<Hash, Extrinsic> {
 			requires: self.requires.clone(),
 			provides: self.provides.clone(),
 			propagate: self.propagate,
+			fee: self.fee,
 		}
 	}
 }
@@ -210,6 +225,9 @@ const RECENTLY_PRUNED_TAGS: usize = 2;
 #[derive(Debug)]
 pub struct BasePool<Hash: hash::Hash + Eq, Ex> {
 	reject_future_transactions: bool,
+	/// How much higher the priority of a transaction must be to replace the ones it conflicts
+	/// with.
+	replacement_bump: Percent,
 	future: FutureTransactions<Hash, Ex>,
 	ready: ReadyTransactions<Hash, Ex>,
 	/// Store recently pruned tags (for last two invocations).
@@ -229,10 +247,19 @@ impl<Hash: hash::Hash + Member + Serialize, Ex: std::fmt::Debug> Default for Bas
 impl<Hash: hash::Hash + Member + Serialize, Ex: std::fmt::Debug> BasePool<Hash, Ex> {
 	/// Create new pool given reject_future_transactions flag.
 	pub fn new(reject_future_transactions: bool) -> Self {
+		Self::with_priority_strategy(reject_future_transactions, Arc::new(TagPriorityStrategy))
+	}
+
+	/// Create new pool ordering its ready transactions with `strategy`.
+	pub fn with_priority_strategy(
+		reject_future_transactions: bool,
+		strategy: Arc<dyn PriorityStrategy>,
+	) -> Self {
 		Self {
 			reject_future_transactions,
+			replacement_bump: Percent::zero(),
 			future: Default::default(),
-			ready: Default::default(),
+			ready: ReadyTransactions::new(strategy),
 			recently_pruned: Default::default(),
 			recently_pruned_index: 0,
 		}
@@ -254,6 +281,12 @@ impl<Hash: hash::Hash + Member + Serialize, Ex: std::fmt::Debug> BasePool<Hash,
 		return_value
 	}
 
+	/// Require replacing transactions to exceed the priority of the transactions they replace by
+	/// more than `bump` of it.
+	pub fn set_replacement_bump(&mut self, bump: Percent) {
+		self.replacement_bump = bump;
+	}
+
 	/// Returns if the transaction for the given hash is already imported.
 	pub fn is_imported(&self, tx_hash: &Hash) -> bool {
 		self.future.contains(tx_hash) || self.ready.contains(tx_hash)
@@ -287,8 +320,9 @@ impl<Hash: hash::Hash + Member + Serialize, Ex: std::fmt::Debug> BasePool<Hash,
 			}
 
 			let hash = tx.transaction.hash.clone();
+			let removed = self.future.replace_previous(&tx, self.replacement_bump)?;
 			self.future.import(tx);
-			return Ok(Imported::Future { hash })
+			return Ok(Imported::Future { hash, removed })
 		}
 
 		self.import_to_ready(tx)
@@ -316,14 +350,14 @@ impl<Hash: hash::Hash + Member + Serialize, Ex: std::fmt::Debug> BasePool<Hash,
 
 			// import this transaction
 			let current_hash = tx.transaction.hash.clone();
-			match self.ready.import(tx) {
-				Ok(mut replaced) => {
+			match self.ready.import(tx, self.replacement_bump) {
+				Ok(replaced) => {
 					if !first {
-						promoted.push(current_hash);
+						promoted.push(current_hash.clone());
 					}
 					// The transactions were removed from the ready pool. We might attempt to
 					// re-import them.
-					removed.append(&mut replaced);
+					removed.extend(replaced.into_iter().map(|tx| (tx, current_hash.clone())));
 				},
 				// transaction failed to be imported.
 				Err(e) =>
@@ -342,7 +376,7 @@ impl<Hash: hash::Hash + Member + Serialize, Ex: std::fmt::Debug> BasePool<Hash,
 		// future transactions pushed out current transaction.
 		// This means that there is a cycle and the transactions should
 		// be moved back to future, since we can't resolve it.
-		if removed.iter().any(|tx| tx.hash == hash) {
+		if removed.iter().any(|(tx, _)| tx.hash == hash) {
 			// We still need to remove all transactions that we promoted
 			// since they depend on each other and will never get to the best iterator.
 			self.ready.remove_subtree(&promoted);
@@ -386,17 +420,37 @@ impl<Hash: hash::Hash + Member + Serialize, Ex: std::fmt::Debug> BasePool<Hash,
 	/// them. Technically the worst transaction should be evaluated by computing the entire pending
 	/// set. We use a simplified approach to remove transactions with the lowest priority first or
 	/// those that occupy the pool for the longest time in case priority is the same.
+	///
+	/// Transactions for which `is_protected` returns `true` are not picked as the worst one while
+	/// they stay within the [protected part](Limit::protected) of the limit of their queue, they
+	/// are only removed if they depend on a removed transaction. Beyond it, they are removed like
+	/// any other transaction.
 	pub fn enforce_limits(
 		&mut self,
 		ready: &Limit,
 		future: &Limit,
+		is_protected: impl Fn(&Transaction<Hash, Ex>) -> bool,
 	) -> Vec<Arc<Transaction<Hash, Ex>>> {
 		let mut removed = vec![];
+		// the number and size of the protected transactions of a queue
+		let protected_usage = |usage: Option<(usize, usize)>, tx: &Transaction<Hash, Ex>| {
+			let (count, bytes) = usage.unwrap_or_default();
+			Some(if is_protected(tx) { (count + 1, bytes + tx.bytes) } else { (count, bytes) })
+		};
 
 		while ready.is_exceeded(self.ready.len(), self.ready.bytes()) {
+			let (count, bytes) = self
+				.ready
+				.fold(|usage, current| protected_usage(usage, &current.transaction.transaction))
+				.unwrap_or_default();
+			let protect = !ready.protected().is_exceeded(count, bytes);
+
 			// find the worst transaction
 			let worst = self.ready.fold::<TransactionRef<Hash, Ex>, _>(|worst, current| {
 				let transaction = &current.transaction;
+				if protect && is_protected(&transaction.transaction) {
+					return worst
+				}
 				worst
 					.map(|worst| {
 						// Here we don't use `TransactionRef`'s ordering implementation because
@@ -425,8 +479,15 @@ impl<Hash: hash::Hash + Member + Serialize, Ex: std::fmt::Debug> BasePool<Hash,
 		}
 
 		while future.is_exceeded(self.future.len(), self.future.bytes()) {
+			let (count, bytes) = self
+				.future
+				.fold(|usage, current| protected_usage(usage, &current.transaction))
+				.unwrap_or_default();
+			let protect = !future.protected().is_exceeded(count, bytes);
+
 			// find the worst transaction
 			let worst = self.future.fold(|worst, current| match worst {
+				_ if protect && is_protected(&current.transaction) => worst,
 				None => Some(current.clone()),
 				Some(ref tx) if tx.imported_at > current.imported_at => Some(current.clone()),
 				other => other,
@@ -514,7 +575,7 @@ impl<Hash: hash::Hash + Member + Serialize, Ex: std::fmt::Debug> BasePool<Hash,
 }
 
 /// Queue limits
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Limit {
 	/// Maximal number of transactions in the queue.
 	pub count: usize,
@@ -527,6 +588,11 @@ impl Limit {
 	pub fn is_exceeded(&self, count: usize, bytes: usize) -> bool {
 		self.count < count || self.total_bytes < bytes
 	}
+
+	/// The part of the limit that protected transactions may take up, half of it.
+	pub fn protected(&self) -> Limit {
+		Limit { count: self.count / 2, total_bytes: self.total_bytes / 2 }
+	}
 }
 
 #[cfg(test)]
@@ -549,6 +615,7 @@ mod tests {
 		provides: vec![],
 		propagate: true,
 		source: Source::External,
+		fee: None,
 	};
 
 	#[test]
@@ -581,6 +648,136 @@ mod tests {
 		assert_eq!(pool.ready.len(), 1);
 	}
 
+	#[test]
+	fn should_replace_ready_transaction_only_with_bumped_priority() {
+		// given
+		let mut pool = pool();
+		pool.set_replacement_bump(Percent::from_percent(10));
+		pool.import(Transaction {
+			data: vec![1u8],
+			hash: 1,
+			priority: 10,
+			provides: vec![vec![1]],
+			..DEFAULT_TX.clone()
+		})
+		.unwrap();
+
+		// when
+		let err = pool
+			.import(Transaction {
+				data: vec![2u8],
+				hash: 2,
+				priority: 11,
+				provides: vec![vec![1]],
+				..DEFAULT_TX.clone()
+			})
+			.unwrap_err();
+		let imported = pool
+			.import(Transaction {
+				data: vec![3u8],
+				hash: 3,
+				priority: 12,
+				provides: vec![vec![1]],
+				..DEFAULT_TX.clone()
+			})
+			.unwrap();
+
+		// then
+		assert!(matches!(err, error::Error::TooLowPriority { old: 10, new: 11 }));
+		let Imported::Ready { removed, .. } = imported else { panic!("imported to ready") };
+		assert_eq!(removed.iter().map(|(tx, by)| (tx.hash, *by)).collect::<Vec<_>>(), vec![(1, 3)]);
+		assert_eq!(pool.ready().map(|tx| tx.hash).collect::<Vec<_>>(), vec![3]);
+	}
+
+	#[test]
+	fn should_replace_future_transaction_with_same_tags() {
+		// given
+		let mut pool = pool();
+		let future_tx = |hash, priority, provides| Transaction {
+			data: vec![hash as u8],
+			hash,
+			priority,
+			requires: vec![vec![0]],
+			provides: vec![vec![provides]],
+			..DEFAULT_TX.clone()
+		};
+		pool.import(future_tx(1, 5, 1)).unwrap();
+		// provides another tag, so doesn't replace the first transaction
+		pool.import(future_tx(2, 10, 2)).unwrap();
+
+		// when
+		let err = pool.import(future_tx(3, 5, 1)).unwrap_err();
+		let imported = pool.import(future_tx(4, 6, 1)).unwrap();
+
+		// then
+		assert!(matches!(err, error::Error::TooLowPriority { old: 5, new: 5 }));
+		let Imported::Future { hash, removed } = imported else { panic!("imported to future") };
+		assert_eq!(hash, 4);
+		assert_eq!(removed.iter().map(|tx| tx.hash).collect::<Vec<_>>(), vec![1]);
+		let mut futures = pool.futures().map(|tx| tx.hash).collect::<Vec<_>>();
+		futures.sort();
+		assert_eq!(futures, vec![2, 4]);
+	}
+
+	/// A pool with ready transactions 1, 2 and 3 of increasing priority and future transaction 4,
+	/// of which 1 and 4 are protected.
+	fn pool_with_protected_transactions() -> BasePool<Hash, Vec<u8>> {
+		let mut pool = pool();
+		for (hash, priority) in [(1, 1), (2, 5), (3, 9)] {
+			pool.import(Transaction {
+				data: vec![hash as u8],
+				hash,
+				priority,
+				provides: vec![vec![hash as u8]],
+				..DEFAULT_TX.clone()
+			})
+			.unwrap();
+		}
+		pool.import(Transaction {
+			data: vec![4u8],
+			hash: 4,
+			requires: vec![vec![0]],
+			provides: vec![vec![4]],
+			..DEFAULT_TX.clone()
+		})
+		.unwrap();
+		pool
+	}
+
+	fn is_protected(tx: &Transaction<Hash, Vec<u8>>) -> bool {
+		tx.hash == 1 || tx.hash == 4
+	}
+
+	#[test]
+	fn should_not_evict_protected_transactions() {
+		// given
+		let mut pool = pool_with_protected_transactions();
+
+		// when
+		let limit = |count| Limit { count, total_bytes: 1024 };
+		let removed = pool.enforce_limits(&limit(2), &limit(2), is_protected);
+
+		// then
+		assert_eq!(removed.iter().map(|tx| tx.hash).collect::<Vec<_>>(), vec![2]);
+		assert_eq!(pool.ready().map(|tx| tx.hash).collect::<Vec<_>>(), vec![3, 1]);
+		assert_eq!(pool.future.len(), 1);
+	}
+
+	#[test]
+	fn should_evict_protected_transactions_beyond_their_part_of_the_limits() {
+		// given
+		let mut pool = pool_with_protected_transactions();
+
+		// when
+		let limit = |count| Limit { count, total_bytes: 1024 };
+		let removed = pool.enforce_limits(&limit(1), &limit(0), is_protected);
+
+		// then
+		assert_eq!(removed.iter().map(|tx| tx.hash).collect::<Vec<_>>(), vec![1, 2, 4]);
+		assert_eq!(pool.ready().map(|tx| tx.hash).collect::<Vec<_>>(), vec![3]);
+		assert_eq!(pool.future.len(), 0);
+	}
+
 	#[test]
 	fn should_import_transaction_to_future_and_promote_it_later() {
 		// given
@@ -22,11 +22,12 @@ use std::{
 	sync::Arc,
 };
 
+use sc_transaction_pool_api::error;
 use sp_core::hexdisplay::HexDisplay;
-use sp_runtime::transaction_validity::TransactionTag as Tag;
+use sp_runtime::{transaction_validity::TransactionTag as Tag, Percent};
 use std::time::Instant;
 
-use super::base_pool::Transaction;
+use super::base_pool::{is_priority_bumped, Transaction};
 
 /// Transaction with partially satisfied dependencies.
 pub struct WaitingTransaction<Hash, Ex> {
@@ -152,6 +153,52 @@ impl<Hash: hash::Hash + Eq + Clone, Ex> FutureTransactions<Hash, Ex> {
 		self.waiting.insert(tx.transaction.hash.clone(), tx);
 	}
 
+	/// Removes the transactions requiring and providing the same tags as `tx`, as `tx` replaces
+	/// them.
+	///
+	/// Fails if the priority of `tx` isn't higher than the collective priority of these
+	/// transactions by more than `replacement_bump`.
+	pub fn replace_previous(
+		&mut self,
+		tx: &WaitingTransaction<Hash, Ex>,
+		replacement_bump: Percent,
+	) -> error::Result<Vec<Arc<Transaction<Hash, Ex>>>> {
+		let transaction = &tx.transaction;
+		let Some(replace_hashes) =
+			tx.missing_tags.iter().next().and_then(|tag| self.wanted_tags.get(tag))
+		else {
+			return Ok(vec![])
+		};
+		let replace_hashes = replace_hashes
+			.iter()
+			.filter(|hash| {
+				self.waiting.get(hash).map_or(false, |waiting| {
+					waiting.transaction.requires == transaction.requires &&
+						waiting.transaction.provides == transaction.provides
+				})
+			})
+			.cloned()
+			.collect::<Vec<_>>();
+
+		// early exit if we are not replacing anything.
+		if replace_hashes.is_empty() {
+			return Ok(vec![])
+		}
+
+		let old_priority = replace_hashes
+			.iter()
+			.filter_map(|hash| self.waiting.get(hash))
+			.fold(0u64, |total, waiting| total.saturating_add(waiting.transaction.priority));
+		if !is_priority_bumped(old_priority, transaction.priority, replacement_bump) {
+			return Err(error::Error::TooLowPriority {
+				old: old_priority,
+				new: transaction.priority,
+			})
+		}
+
+		Ok(self.remove(&replace_hashes))
+	}
+
 	/// Returns true if given hash is part of the queue.
 	pub fn contains(&self, hash: &Hash) -> bool {
 		self.waiting.contains_key(hash)
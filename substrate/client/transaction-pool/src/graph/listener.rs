@@ -16,7 +16,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use std::{collections::HashMap, fmt::Debug, hash};
+use std::{collections::HashMap, fmt::Debug, hash, time::Instant};
 
 use crate::LOG_TARGET;
 use linked_hash_map::LinkedHashMap;
@@ -58,11 +58,19 @@ impl<H: hash::Hash + traits::Member + Serialize, C: ChainApi> Listener<H, C> {
 		}
 	}
 
-	/// Creates a new watcher for given verified extrinsic.
+	/// Creates a new watcher for given verified extrinsic, submitted to the pool at
+	/// `submitted_at`.
 	///
 	/// The watcher can be used to subscribe to life-cycle events of that extrinsic.
-	pub fn create_watcher(&mut self, hash: H) -> watcher::Watcher<H, ExtrinsicHash<C>> {
-		let sender = self.watchers.entry(hash.clone()).or_insert_with(watcher::Sender::default);
+	pub fn create_watcher(
+		&mut self,
+		hash: H,
+		submitted_at: Instant,
+	) -> watcher::Watcher<H, ExtrinsicHash<C>> {
+		let sender = self
+			.watchers
+			.entry(hash.clone())
+			.or_insert_with(|| watcher::Sender::new(submitted_at));
 		sender.new_watcher(hash)
 	}
 
@@ -96,6 +104,12 @@ impl<H: hash::Hash + traits::Member + Serialize, C: ChainApi> Listener<H, C> {
 		})
 	}
 
+	/// Transaction was dropped from the pool because it stayed there for too long.
+	pub fn expired(&mut self, tx: &H) {
+		debug!(target: LOG_TARGET, "[{:?}] Expired", tx);
+		self.fire(tx, |watcher| watcher.expired());
+	}
+
 	/// Transaction was removed as invalid.
 	pub fn invalid(&mut self, tx: &H) {
 		debug!(target: LOG_TARGET, "[{:?}] Extrinsic invalid", tx);
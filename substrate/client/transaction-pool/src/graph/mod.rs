@@ -29,7 +29,10 @@
 mod future;
 mod listener;
 mod pool;
+mod priority_strategy;
+mod propagation_policy;
 mod ready;
+mod recently_included;
 mod rotator;
 mod tracked_map;
 mod validated_pool;
@@ -39,6 +42,10 @@ pub mod watcher;
 
 pub use self::{
 	base_pool::Transaction,
-	pool::{BlockHash, ChainApi, ExtrinsicFor, ExtrinsicHash, NumberFor, Options, Pool},
+	pool::{
+		BlockHash, ChainApi, ExtrinsicFor, ExtrinsicHash, NumberFor, Options, Pool, TransactionTtl,
+	},
+	priority_strategy::{FifoPriorityStrategy, PriorityStrategy, ScoringInfo, TagPriorityStrategy},
+	propagation_policy::{PropagationInfo, PropagationPolicy, ValidityPropagationPolicy},
 };
 pub use validated_pool::{IsValidator, ValidatedTransaction};
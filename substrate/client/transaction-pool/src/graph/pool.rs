@@ -18,9 +18,11 @@
 
 use std::{collections::HashMap, sync::Arc, time::Duration};
 
-use crate::LOG_TARGET;
+use crate::{
+	metrics::MetricsLink as PrometheusMetrics, revalidation::RevalidationOptions, LOG_TARGET,
+};
 use futures::{channel::mpsc::Receiver, Future};
-use sc_transaction_pool_api::error;
+use sc_transaction_pool_api::{error, PriorityBoost};
 use sp_blockchain::TreeRoute;
 use sp_runtime::{
 	generic::BlockId,
@@ -28,11 +30,14 @@ use sp_runtime::{
 	transaction_validity::{
 		TransactionSource, TransactionTag as Tag, TransactionValidity, TransactionValidityError,
 	},
+	Percent,
 };
 use std::time::Instant;
 
 use super::{
 	base_pool as base,
+	priority_strategy::{PriorityStrategy, TagPriorityStrategy},
+	propagation_policy::{PropagationPolicy, ValidityPropagationPolicy},
 	validated_pool::{IsValidator, ValidatedPool, ValidatedTransaction},
 	watcher::Watcher,
 };
@@ -91,6 +96,20 @@ pub trait ChainApi: Send + Sync {
 	/// Returns hash and encoding length of the extrinsic.
 	fn hash_and_length(&self, uxt: &ExtrinsicFor<Self>) -> (ExtrinsicHash<Self>, usize);
 
+	/// Returns the fee paid by the extrinsic of `len` bytes when included on top of the block
+	/// `at`, which is exposed to the [`PriorityStrategy`] of the pool.
+	///
+	/// Only called for valid extrinsics. Returns `None` if the fee is unknown, which is the
+	/// default.
+	fn transaction_fee(
+		&self,
+		_at: <Self::Block as BlockT>::Hash,
+		_uxt: &ExtrinsicFor<Self>,
+		_len: usize,
+	) -> Option<u128> {
+		None
+	}
+
 	/// Returns a block body given the block.
 	fn block_body(&self, at: <Self::Block as BlockT>::Hash) -> Self::BodyFuture;
 
@@ -119,6 +138,31 @@ pub struct Options {
 	pub reject_future_transactions: bool,
 	/// How long the extrinsic is banned for.
 	pub ban_time: Duration,
+	/// The accounts whose transactions are prioritized and protected from eviction.
+	pub priority_boost: Arc<PriorityBoost>,
+	/// Decides the order in which ready transactions are provided for block production.
+	pub priority_strategy: Arc<dyn PriorityStrategy>,
+	/// Decides which ready transactions are handed to the network layer for propagation.
+	pub propagation_policy: Arc<dyn PropagationPolicy>,
+	/// How long transactions may stay in the pool, regardless of their longevity.
+	pub ttl: TransactionTtl,
+	/// How much higher the priority of a transaction must be, relative to the priority of the
+	/// transactions requiring and providing the same tags, to replace them. With zero, a strictly
+	/// higher priority suffices.
+	///
+	/// Replaced transactions are reported as [`TransactionStatus::Usurped`] by the replacing one.
+	///
+	/// [`TransactionStatus::Usurped`]: sc_transaction_pool_api::TransactionStatus::Usurped
+	pub replacement_bump: Percent,
+	/// Tuning of the background revalidation of ready transactions.
+	pub revalidation: RevalidationOptions,
+	/// For how many blocks the extrinsics included in a block are remembered.
+	///
+	/// Resubmissions of remembered extrinsics are rejected with
+	/// [`Error::RecentlyIncluded`] before they are validated. Zero disables it.
+	///
+	/// [`Error::RecentlyIncluded`]: sc_transaction_pool_api::error::Error::RecentlyIncluded
+	pub recently_included_depth: u32,
 }
 
 impl Default for Options {
@@ -128,6 +172,38 @@ impl Default for Options {
 			future: base::Limit { count: 512, total_bytes: 1 * 1024 * 1024 },
 			reject_future_transactions: false,
 			ban_time: Duration::from_secs(60 * 30),
+			priority_boost: Default::default(),
+			priority_strategy: Arc::new(TagPriorityStrategy),
+			propagation_policy: Arc::new(ValidityPropagationPolicy),
+			ttl: Default::default(),
+			replacement_bump: Percent::zero(),
+			revalidation: Default::default(),
+			recently_included_depth: 64,
+		}
+	}
+}
+
+/// How long transactions may stay in the pool, depending on their source.
+///
+/// Transactions staying longer are dropped with [`TransactionStatus::Expired`], even if their
+/// longevity didn't run out yet, and the external ones are banned. `None` keeps transactions until
+/// their longevity runs out.
+///
+/// [`TransactionStatus::Expired`]: sc_transaction_pool_api::TransactionStatus::Expired
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TransactionTtl {
+	/// The time-to-live of transactions submitted locally.
+	pub local: Option<Duration>,
+	/// The time-to-live of transactions received from the network or from retracted blocks.
+	pub external: Option<Duration>,
+}
+
+impl TransactionTtl {
+	/// The time-to-live of transactions from `source`.
+	pub fn for_source(&self, source: TransactionSource) -> Option<Duration> {
+		match source {
+			TransactionSource::Local => self.local,
+			TransactionSource::External | TransactionSource::InBlock => self.external,
 		}
 	}
 }
@@ -151,6 +227,23 @@ impl<B: ChainApi> Pool<B> {
 		Self { validated_pool: Arc::new(ValidatedPool::new(options, is_validator, api)) }
 	}
 
+	/// Create a new transaction pool reporting to the given metrics.
+	pub(crate) fn with_metrics(
+		options: Options,
+		is_validator: IsValidator,
+		api: Arc<B>,
+		metrics: PrometheusMetrics,
+	) -> Self {
+		Self {
+			validated_pool: Arc::new(ValidatedPool::with_metrics(
+				options,
+				is_validator,
+				api,
+				metrics,
+			)),
+		}
+	}
+
 	/// Imports a bunch of unverified extrinsics to the pool
 	pub async fn submit_at(
 		&self,
@@ -195,11 +288,12 @@ impl<B: ChainApi> Pool<B> {
 		source: TransactionSource,
 		xt: ExtrinsicFor<B>,
 	) -> Result<Watcher<ExtrinsicHash<B>, ExtrinsicHash<B>>, B::Error> {
+		let submitted_at = Instant::now();
 		let block_number = self.resolve_block_number(&BlockId::Hash(at))?;
 		let (_, tx) = self
 			.verify_one(at, block_number, source, xt, CheckBannedBeforeVerify::Yes)
 			.await;
-		self.validated_pool.submit_and_watch(tx)
+		self.validated_pool.submit_and_watch(tx, submitted_at)
 	}
 
 	/// Resubmit some transaction that were validated elsewhere.
@@ -337,6 +431,7 @@ impl<B: ChainApi> Pool<B> {
 		// inputs are pruned so such transaction would go to future again.
 		self.validated_pool
 			.ban(&Instant::now(), known_imported_hashes.clone().into_iter());
+		self.validated_pool.note_included(known_imported_hashes.clone());
 
 		// Try to re-validate pruned transactions since some of them might be still valid.
 		// note that `known_imported_hashes` will be rejected here due to temporary ban.
@@ -423,12 +518,14 @@ impl<B: ChainApi> Pool<B> {
 				if validity.provides.is_empty() {
 					ValidatedTransaction::Invalid(hash, error::Error::NoTagsProvided.into())
 				} else {
+					let fee = self.validated_pool.api().transaction_fee(block_hash, &xt, bytes);
 					ValidatedTransaction::valid_at(
 						block_number.saturated_into::<u64>(),
 						hash,
 						source,
 						xt,
 						bytes,
+						fee,
 						validity,
 					)
 				},
@@ -456,7 +553,10 @@ impl<B: ChainApi> Clone for Pool<B> {
 #[cfg(test)]
 mod tests {
 	use super::{super::base_pool::Limit, *};
-	use crate::tests::{pool, uxt, TestApi, INVALID_NONCE};
+	use crate::{
+		graph::ScoringInfo,
+		tests::{pool, uxt, TestApi, INVALID_NONCE},
+	};
 	use assert_matches::assert_matches;
 	use codec::Encode;
 	use futures::executor::block_on;
@@ -491,6 +591,72 @@ mod tests {
 		assert_eq!(pool.validated_pool().ready().map(|v| v.hash).collect::<Vec<_>>(), vec![hash]);
 	}
 
+	#[test]
+	fn should_boost_priority_of_designated_accounts() {
+		// given
+		// The test API provides the nonce as the only tag, which stands in for the account here.
+		let priority_boost = Arc::new(PriorityBoost::new(100, [vec![0]]));
+		let options = Options { priority_boost: priority_boost.clone(), ..Default::default() };
+		let api = Arc::new(TestApi::default());
+		let pool = Pool::new(options, true.into(), api.clone());
+		let transfer = |nonce| {
+			uxt(Transfer {
+				from: Alice.into(),
+				to: AccountId::from_h256(H256::from_low_u64_be(2)),
+				amount: 5,
+				nonce,
+			})
+		};
+
+		// when
+		block_on(pool.submit_one(api.expect_hash_from_number(0), SOURCE, transfer(0))).unwrap();
+		priority_boost.set_accounts([]);
+		block_on(pool.submit_one(api.expect_hash_from_number(0), SOURCE, transfer(1))).unwrap();
+
+		// then
+		let priorities = pool.validated_pool().ready().map(|tx| tx.priority).collect::<Vec<_>>();
+		assert_eq!(priorities, vec![104, 4]);
+	}
+
+	#[test]
+	fn should_expose_fees_to_the_priority_strategy() {
+		// given
+		#[derive(Debug)]
+		struct FeeStrategy;
+		impl PriorityStrategy for FeeStrategy {
+			fn score(&self, tx: &ScoringInfo) -> u128 {
+				tx.fee.unwrap_or_default()
+			}
+		}
+
+		let options = Options { priority_strategy: Arc::new(FeeStrategy), ..Default::default() };
+		let api = Arc::new(TestApi::default());
+		let pool = Pool::new(options, true.into(), api.clone());
+		let transfer = |nonce, amount| {
+			uxt(Transfer {
+				from: Alice.into(),
+				to: AccountId::from_h256(H256::from_low_u64_be(2)),
+				amount,
+				nonce,
+			})
+		};
+		let cheap = transfer(0, 5);
+		let expensive = transfer(1, 10);
+		// Both transactions are ready at once.
+		api.clear_requirements.lock().insert(pool.hash_of(&expensive));
+
+		// when
+		let cheap =
+			block_on(pool.submit_one(api.expect_hash_from_number(0), SOURCE, cheap)).unwrap();
+		let expensive =
+			block_on(pool.submit_one(api.expect_hash_from_number(0), SOURCE, expensive)).unwrap();
+
+		// then
+		let ready = pool.validated_pool().ready().collect::<Vec<_>>();
+		assert_eq!(ready.iter().map(|tx| tx.hash).collect::<Vec<_>>(), vec![expensive, cheap]);
+		assert_eq!(ready.iter().map(|tx| tx.fee).collect::<Vec<_>>(), vec![Some(10), Some(5)]);
+	}
+
 	#[test]
 	fn should_reject_if_temporarily_banned() {
 		// given
@@ -642,6 +808,85 @@ mod tests {
 		assert!(pool.validated_pool.is_banned(&hash3));
 	}
 
+	#[test]
+	fn should_expire_transactions_after_ttl() {
+		// given
+		let options = Options {
+			ttl: TransactionTtl { local: None, external: Some(Duration::ZERO) },
+			..Default::default()
+		};
+		let api = Arc::new(TestApi::default());
+		let pool = Pool::new(options, true.into(), api.clone());
+		let hash_of_block0 = api.expect_hash_from_number(0);
+		let local = block_on(pool.submit_and_watch(
+			hash_of_block0,
+			TransactionSource::Local,
+			uxt(Transfer {
+				from: Alice.into(),
+				to: AccountId::from_h256(H256::from_low_u64_be(2)),
+				amount: 5,
+				nonce: 0,
+			}),
+		))
+		.unwrap();
+		let external = block_on(pool.submit_and_watch(
+			hash_of_block0,
+			TransactionSource::External,
+			uxt(Transfer {
+				from: Alice.into(),
+				to: AccountId::from_h256(H256::from_low_u64_be(2)),
+				amount: 5,
+				nonce: 1,
+			}),
+		))
+		.unwrap();
+		let external_hash = *external.hash();
+
+		// when
+		pool.validated_pool.clear_stale(&BlockId::Number(0)).unwrap();
+
+		// then
+		assert_eq!(pool.validated_pool().status().ready, 1);
+		assert!(pool.validated_pool.is_banned(&external_hash));
+		assert!(!pool.validated_pool.is_banned(local.hash()));
+		let mut stream = futures::executor::block_on_stream(external.into_stream());
+		assert_eq!(stream.next(), Some(TransactionStatus::Ready));
+		assert_eq!(stream.next(), Some(TransactionStatus::Expired));
+		assert_eq!(stream.next(), None);
+	}
+
+	#[test]
+	fn should_not_ban_expired_local_transactions() {
+		// given
+		let options = Options {
+			ttl: TransactionTtl { local: Some(Duration::ZERO), external: None },
+			..Default::default()
+		};
+		let api = Arc::new(TestApi::default());
+		let pool = Pool::new(options, true.into(), api.clone());
+		let hash_of_block0 = api.expect_hash_from_number(0);
+		let xt = uxt(Transfer {
+			from: Alice.into(),
+			to: AccountId::from_h256(H256::from_low_u64_be(2)),
+			amount: 5,
+			nonce: 0,
+		});
+		let hash = block_on(pool.submit_one(hash_of_block0, TransactionSource::Local, xt.clone()))
+			.unwrap();
+
+		// when
+		pool.validated_pool.clear_stale(&BlockId::Number(0)).unwrap();
+
+		// then
+		assert_eq!(pool.validated_pool().status().ready, 0);
+		assert!(!pool.validated_pool.is_banned(&hash));
+		assert_eq!(
+			block_on(pool.submit_one(hash_of_block0, TransactionSource::Local, xt)).unwrap(),
+			hash
+		);
+		assert_eq!(pool.validated_pool().status().ready, 1);
+	}
+
 	#[test]
 	fn should_ban_mined_transactions() {
 		// given
@@ -666,6 +911,38 @@ mod tests {
 		assert!(pool.validated_pool.is_banned(&hash1));
 	}
 
+	#[test]
+	fn should_reject_recently_included_transactions() {
+		let xt = uxt(Transfer {
+			from: Alice.into(),
+			to: AccountId::from_h256(H256::from_low_u64_be(2)),
+			amount: 5,
+			nonce: 0,
+		});
+		let submit_included = |options: Options| {
+			let api = Arc::new(TestApi::default());
+			let pool = Pool::new(options, true.into(), api.clone());
+			let hash =
+				block_on(pool.submit_one(api.expect_hash_from_number(0), SOURCE, xt.clone()))
+					.unwrap();
+			block_on(pool.prune_tags(api.expect_hash_from_number(1), vec![vec![0]], vec![hash]))
+				.unwrap();
+			block_on(pool.submit_one(api.expect_hash_from_number(1), SOURCE, xt.clone()))
+		};
+
+		// when
+		let res = submit_included(Default::default());
+
+		// then
+		assert_matches!(res.unwrap_err(), error::Error::RecentlyIncluded);
+
+		// when
+		let res = submit_included(Options { recently_included_depth: 0, ..Default::default() });
+
+		// then
+		assert_matches!(res.unwrap_err(), error::Error::TemporarilyBanned);
+	}
+
 	#[test]
 	fn should_limit_futures() {
 		sp_tracing::try_init_simple();
@@ -825,6 +1102,42 @@ mod tests {
 			assert_eq!(stream.next(), Some(TransactionStatus::InBlock((hash_of_block2.into(), 0))),);
 		}
 
+		#[test]
+		fn should_report_timings_in_detailed_stream() {
+			// given
+			let (pool, api) = pool();
+			let watcher = block_on(pool.submit_and_watch(
+				api.expect_hash_from_number(0),
+				SOURCE,
+				uxt(Transfer {
+					from: Alice.into(),
+					to: AccountId::from_h256(H256::from_low_u64_be(2)),
+					amount: 5,
+					nonce: 0,
+				}),
+			))
+			.unwrap();
+			let hash_of_block2 = api.expect_hash_from_number(2);
+
+			// when
+			block_on(pool.prune_tags(hash_of_block2, vec![vec![0u8]], vec![])).unwrap();
+
+			// then
+			let mut stream = futures::executor::block_on_stream(watcher.into_detailed_stream());
+			let ready = stream.next().unwrap();
+			assert_eq!(ready.status, TransactionStatus::Ready);
+			let validation = ready.timings.validation.unwrap();
+			let time_to_ready = ready.timings.ready.unwrap();
+			assert!(validation <= time_to_ready);
+			assert_eq!(ready.timings.in_block, None);
+
+			let in_block = stream.next().unwrap();
+			assert_eq!(in_block.status, TransactionStatus::InBlock((hash_of_block2.into(), 0)));
+			assert_eq!(in_block.timings.validation, Some(validation));
+			assert_eq!(in_block.timings.ready, Some(time_to_ready));
+			assert!(in_block.timings.in_block.unwrap() >= time_to_ready);
+		}
+
 		#[test]
 		fn should_trigger_future_and_ready_after_promoted() {
 			// given
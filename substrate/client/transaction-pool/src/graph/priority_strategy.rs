@@ -0,0 +1,76 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Strategies deciding the order of ready transactions.
+
+use std::fmt;
+
+use sp_runtime::transaction_validity::{
+	TransactionLongevity, TransactionPriority, TransactionSource,
+};
+
+/// What a [`PriorityStrategy`] knows about a ready transaction.
+#[derive(Debug, Clone, Copy)]
+pub struct ScoringInfo {
+	/// Priority returned by the runtime validation.
+	pub priority: TransactionPriority,
+	/// The block at which the transaction becomes invalid.
+	pub valid_till: TransactionLongevity,
+	/// Number of bytes of the encoded transaction.
+	pub bytes: usize,
+	/// Where the transaction came from.
+	pub source: TransactionSource,
+	/// The fee paid by the transaction, if the [`ChainApi`](super::ChainApi) of the pool knows
+	/// it, see [`ChainApi::transaction_fee`](super::ChainApi::transaction_fee).
+	pub fee: Option<u128>,
+}
+
+/// Decides the order in which ready transactions are provided for block production.
+///
+/// Only transactions with all their requirements satisfied are ordered, so a strategy can't
+/// reorder the transactions of a single sender. Replacing a transaction providing the same tags
+/// still depends on the priority only.
+pub trait PriorityStrategy: Send + Sync + fmt::Debug {
+	/// The score of a ready transaction.
+	///
+	/// Transactions with higher scores go first, ties are broken in favour of the transaction
+	/// that is in the pool for longer.
+	fn score(&self, tx: &ScoringInfo) -> u128;
+}
+
+/// Orders transactions by priority and then by the longevity left, shorter-lived first.
+///
+/// This is the default strategy.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TagPriorityStrategy;
+
+impl PriorityStrategy for TagPriorityStrategy {
+	fn score(&self, tx: &ScoringInfo) -> u128 {
+		(u128::from(tx.priority) << 64) | u128::from(u64::MAX - tx.valid_till)
+	}
+}
+
+/// Orders transactions by the time they were imported, ignoring their priority.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct FifoPriorityStrategy;
+
+impl PriorityStrategy for FifoPriorityStrategy {
+	fn score(&self, _tx: &ScoringInfo) -> u128 {
+		0
+	}
+}
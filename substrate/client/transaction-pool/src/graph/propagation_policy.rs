@@ -0,0 +1,59 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Policies deciding which ready transactions are propagated to other peers.
+
+use std::fmt;
+
+use sp_runtime::transaction_validity::{TransactionPriority, TransactionSource, TransactionTag};
+
+/// What a [`PropagationPolicy`] knows about a ready transaction.
+#[derive(Debug, Clone, Copy)]
+pub struct PropagationInfo<'a> {
+	/// Whether the runtime validation allows propagating the transaction.
+	pub propagate: bool,
+	/// Priority returned by the runtime validation.
+	pub priority: TransactionPriority,
+	/// Number of bytes of the encoded transaction.
+	pub bytes: usize,
+	/// Where the transaction came from.
+	pub source: TransactionSource,
+	/// The tags provided by the transaction.
+	pub provides: &'a [TransactionTag],
+}
+
+/// Decides which ready transactions are handed to the network layer for propagation.
+///
+/// The policy is consulted every time the ready transactions are handed to the network layer, so
+/// a transaction kept local may be propagated later on, e.g. once some threshold is reached.
+pub trait PropagationPolicy: Send + Sync + fmt::Debug {
+	/// Whether the ready transaction should be propagated to other peers.
+	fn should_propagate(&self, tx: &PropagationInfo) -> bool;
+}
+
+/// Propagates the transactions whose validity allows it.
+///
+/// This is the default policy.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ValidityPropagationPolicy;
+
+impl PropagationPolicy for ValidityPropagationPolicy {
+	fn should_propagate(&self, tx: &PropagationInfo) -> bool {
+		tx.propagate
+	}
+}
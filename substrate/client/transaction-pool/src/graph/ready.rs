@@ -27,11 +27,12 @@ use crate::LOG_TARGET;
 use log::{debug, trace};
 use sc_transaction_pool_api::error;
 use serde::Serialize;
-use sp_runtime::{traits::Member, transaction_validity::TransactionTag as Tag};
+use sp_runtime::{traits::Member, transaction_validity::TransactionTag as Tag, Percent};
 
 use super::{
-	base_pool::Transaction,
+	base_pool::{is_priority_bumped, Transaction},
 	future::WaitingTransaction,
+	priority_strategy::{PriorityStrategy, ScoringInfo, TagPriorityStrategy},
 	tracked_map::{self, TrackedMap},
 };
 
@@ -44,20 +45,42 @@ pub struct TransactionRef<Hash, Ex> {
 	pub transaction: Arc<Transaction<Hash, Ex>>,
 	/// Unique id when transaction was inserted into the pool.
 	pub insertion_id: u64,
+	/// The score given by the [`PriorityStrategy`] of the pool.
+	pub score: u128,
+}
+
+impl<Hash, Ex> TransactionRef<Hash, Ex> {
+	/// Create a reference scored by `strategy`.
+	pub fn new(
+		transaction: Arc<Transaction<Hash, Ex>>,
+		insertion_id: u64,
+		strategy: &dyn PriorityStrategy,
+	) -> Self {
+		let score = strategy.score(&ScoringInfo {
+			priority: transaction.priority,
+			valid_till: transaction.valid_till,
+			bytes: transaction.bytes,
+			source: transaction.source,
+			fee: transaction.fee,
+		});
+		Self { transaction, insertion_id, score }
+	}
 }
 
 impl<Hash, Ex> Clone for TransactionRef<Hash, Ex> {
 	fn clone(&self) -> Self {
-		Self { transaction: self.transaction.clone(), insertion_id: self.insertion_id }
+		Self {
+			transaction: self.transaction.clone(),
+			insertion_id: self.insertion_id,
+			score: self.score,
+		}
 	}
 }
 
 impl<Hash, Ex> Ord for TransactionRef<Hash, Ex> {
 	fn cmp(&self, other: &Self) -> cmp::Ordering {
-		self.transaction
-			.priority
-			.cmp(&other.transaction.priority)
-			.then_with(|| other.transaction.valid_till.cmp(&self.transaction.valid_till))
+		self.score
+			.cmp(&other.score)
 			.then_with(|| other.insertion_id.cmp(&self.insertion_id))
 	}
 }
@@ -118,6 +141,8 @@ pub struct ReadyTransactions<Hash: hash::Hash + Eq, Ex> {
 	/// Best transactions that are ready to be included to the block without any other previous
 	/// transaction.
 	best: BTreeSet<TransactionRef<Hash, Ex>>,
+	/// Decides the order of the `best` transactions.
+	strategy: Arc<dyn PriorityStrategy>,
 }
 
 impl<Hash, Ex> tracked_map::Size for ReadyTx<Hash, Ex> {
@@ -128,11 +153,19 @@ impl<Hash, Ex> tracked_map::Size for ReadyTx<Hash, Ex> {
 
 impl<Hash: hash::Hash + Eq, Ex> Default for ReadyTransactions<Hash, Ex> {
 	fn default() -> Self {
+		Self::new(Arc::new(TagPriorityStrategy))
+	}
+}
+
+impl<Hash: hash::Hash + Eq, Ex> ReadyTransactions<Hash, Ex> {
+	/// Create an empty queue ordering transactions with `strategy`.
+	pub fn new(strategy: Arc<dyn PriorityStrategy>) -> Self {
 		Self {
 			insertion_id: Default::default(),
 			provided_tags: Default::default(),
 			ready: Default::default(),
 			best: Default::default(),
+			strategy,
 		}
 	}
 }
@@ -150,12 +183,11 @@ impl<Hash: hash::Hash + Member + Serialize, Ex> ReadyTransactions<Hash, Ex> {
 	/// 	- never return transaction that requires a tag, which was not provided by one of the
 	///    previously
 	/// returned transactions
-	/// 2. Then by priority:
-	/// - If there are two transactions with all requirements satisfied the one with higher priority
-	///   goes first.
-	/// 3. Then by the ttl that's left
-	/// - transactions that are valid for a shorter time go first
-	/// 4. Lastly we sort by the time in the queue
+	/// 2. Then by the score of the [`PriorityStrategy`]:
+	/// - If there are two transactions with all requirements satisfied the one with higher score
+	///   goes first. By default ([`TagPriorityStrategy`]) transactions are scored by priority and
+	///   then by the ttl that's left, transactions that are valid for a shorter time go first.
+	/// 3. Lastly we sort by the time in the queue
 	/// - transactions that are longer in the queue go first
 	///
 	/// The iterator is providing a way to report transactions that the receiver considers invalid.
@@ -178,6 +210,7 @@ impl<Hash: hash::Hash + Member + Serialize, Ex> ReadyTransactions<Hash, Ex> {
 	pub fn import(
 		&mut self,
 		tx: WaitingTransaction<Hash, Ex>,
+		replacement_bump: Percent,
 	) -> error::Result<Vec<Arc<Transaction<Hash, Ex>>>> {
 		assert!(
 			tx.is_ready(),
@@ -194,7 +227,7 @@ impl<Hash: hash::Hash + Member + Serialize, Ex> ReadyTransactions<Hash, Ex> {
 		let hash = tx.transaction.hash.clone();
 		let transaction = tx.transaction;
 
-		let (replaced, unlocks) = self.replace_previous(&transaction)?;
+		let (replaced, unlocks) = self.replace_previous(&transaction, replacement_bump)?;
 
 		let mut goes_to_best = true;
 		let mut ready = self.ready.write();
@@ -219,7 +252,7 @@ impl<Hash: hash::Hash + Member + Serialize, Ex> ReadyTransactions<Hash, Ex> {
 			self.provided_tags.insert(tag.clone(), hash.clone());
 		}
 
-		let transaction = TransactionRef { insertion_id, transaction };
+		let transaction = TransactionRef::new(transaction, insertion_id, &*self.strategy);
 
 		// insert to best if it doesn't require any other transaction to be included before it
 		if goes_to_best {
@@ -421,6 +454,7 @@ impl<Hash: hash::Hash + Member + Serialize, Ex> ReadyTransactions<Hash, Ex> {
 	fn replace_previous(
 		&mut self,
 		tx: &Transaction<Hash, Ex>,
+		replacement_bump: Percent,
 	) -> error::Result<(Vec<Arc<Transaction<Hash, Ex>>>, Vec<Hash>)> {
 		let (to_remove, unlocks) = {
 			// check if we are replacing a transaction
@@ -447,7 +481,7 @@ impl<Hash: hash::Hash + Member + Serialize, Ex> ReadyTransactions<Hash, Ex> {
 			};
 
 			// bail - the transaction has too low priority to replace the old ones
-			if old_priority >= tx.priority {
+			if !is_priority_bumped(old_priority, tx.priority, replacement_bump) {
 				return Err(error::Error::TooLowPriority { old: old_priority, new: tx.priority })
 			}
 
@@ -589,6 +623,7 @@ fn remove_item<T: PartialEq>(vec: &mut Vec<T>, item: &T) {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::graph::priority_strategy::FifoPriorityStrategy;
 	use sp_runtime::transaction_validity::TransactionSource as Source;
 
 	fn tx(id: u8) -> Transaction<u64, Vec<u8>> {
@@ -602,6 +637,7 @@ mod tests {
 			provides: vec![vec![3], vec![4]],
 			propagate: true,
 			source: Source::External,
+			fee: None,
 		}
 	}
 
@@ -610,7 +646,7 @@ mod tests {
 		tx: Transaction<H, Ex>,
 	) -> error::Result<Vec<Arc<Transaction<H, Ex>>>> {
 		let x = WaitingTransaction::new(tx, ready.provided_tags(), &[]);
-		ready.import(x)
+		ready.import(x, Percent::zero())
 	}
 
 	#[test]
@@ -712,6 +748,7 @@ mod tests {
 			provides: vec![],
 			propagate: true,
 			source: Source::External,
+			fee: None,
 		};
 
 		// when
@@ -752,21 +789,38 @@ mod tests {
 			tx.valid_till = longevity;
 			tx
 		};
+		let tx_ref = |tx, insertion_id| {
+			TransactionRef::new(Arc::new(tx), insertion_id, &TagPriorityStrategy)
+		};
 		// higher priority = better
-		assert!(
-			TransactionRef { transaction: Arc::new(with_priority(3, 3)), insertion_id: 1 } >
-				TransactionRef { transaction: Arc::new(with_priority(2, 3)), insertion_id: 2 }
-		);
+		assert!(tx_ref(with_priority(3, 3), 1) > tx_ref(with_priority(2, 3), 2));
 		// lower validity = better
-		assert!(
-			TransactionRef { transaction: Arc::new(with_priority(3, 2)), insertion_id: 1 } >
-				TransactionRef { transaction: Arc::new(with_priority(3, 3)), insertion_id: 2 }
-		);
+		assert!(tx_ref(with_priority(3, 2), 1) > tx_ref(with_priority(3, 3), 2));
 		// lower insertion_id = better
-		assert!(
-			TransactionRef { transaction: Arc::new(with_priority(3, 3)), insertion_id: 1 } >
-				TransactionRef { transaction: Arc::new(with_priority(3, 3)), insertion_id: 2 }
-		);
+		assert!(tx_ref(with_priority(3, 3), 1) > tx_ref(with_priority(3, 3), 2));
+	}
+
+	#[test]
+	fn should_order_by_insertion_with_fifo_strategy() {
+		// given
+		let mut ready = ReadyTransactions::new(Arc::new(FifoPriorityStrategy));
+		let mut tx1 = tx(1);
+		tx1.requires.clear();
+		tx1.priority = 1;
+		let mut tx2 = tx(2);
+		tx2.requires.clear();
+		tx2.provides = vec![vec![2]];
+		tx2.priority = 10;
+
+		// when
+		import(&mut ready, tx1).unwrap();
+		import(&mut ready, tx2).unwrap();
+
+		// then
+		let mut it = ready.get().map(|tx| tx.data[0]);
+		assert_eq!(it.next(), Some(1));
+		assert_eq!(it.next(), Some(2));
+		assert_eq!(it.next(), None);
 	}
 
 	#[test]
@@ -0,0 +1,122 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Remember the extrinsics included in recent blocks.
+//!
+//! Re-broadcasts of already included extrinsics are rejected before they are validated again.
+
+use parking_lot::RwLock;
+use std::{
+	collections::{HashMap, VecDeque},
+	hash,
+};
+
+/// The hashes of the extrinsics included in the last `depth` blocks.
+pub struct RecentlyIncluded<Hash> {
+	/// How many blocks are remembered.
+	depth: usize,
+	inner: RwLock<Inner<Hash>>,
+}
+
+struct Inner<Hash> {
+	/// The hashes included by each remembered block, oldest first.
+	blocks: VecDeque<Vec<Hash>>,
+	/// The number of remembered blocks including each hash.
+	included: HashMap<Hash, usize>,
+}
+
+impl<Hash: hash::Hash + Eq + Clone> RecentlyIncluded<Hash> {
+	/// Remember the extrinsics of the last `depth` blocks. Zero remembers none.
+	pub fn new(depth: u32) -> Self {
+		Self {
+			depth: depth as usize,
+			inner: RwLock::new(Inner { blocks: Default::default(), included: Default::default() }),
+		}
+	}
+
+	/// Returns `true` if the extrinsic was included in one of the remembered blocks.
+	pub fn contains(&self, hash: &Hash) -> bool {
+		self.inner.read().included.contains_key(hash)
+	}
+
+	/// Remember the extrinsics included by a new block, forgetting the oldest block if needed.
+	pub fn note_block(&self, hashes: impl IntoIterator<Item = Hash>) {
+		if self.depth == 0 {
+			return
+		}
+		let mut inner = self.inner.write();
+		let hashes: Vec<_> = hashes.into_iter().collect();
+		for hash in &hashes {
+			*inner.included.entry(hash.clone()).or_default() += 1;
+		}
+		inner.blocks.push_back(hashes);
+
+		while inner.blocks.len() > self.depth {
+			let Some(oldest) = inner.blocks.pop_front() else { break };
+			for hash in oldest {
+				if let Some(count) = inner.included.get_mut(&hash) {
+					*count -= 1;
+					if *count == 0 {
+						inner.included.remove(&hash);
+					}
+				}
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn remembers_the_last_blocks() {
+		let included = RecentlyIncluded::<u64>::new(2);
+		included.note_block([1, 2]);
+		included.note_block([3]);
+		assert!(included.contains(&1));
+		assert!(included.contains(&3));
+
+		included.note_block([4]);
+		assert!(!included.contains(&1));
+		assert!(!included.contains(&2));
+		assert!(included.contains(&3));
+		assert!(included.contains(&4));
+		assert_eq!(included.inner.read().included.len(), 2);
+	}
+
+	#[test]
+	fn hash_included_twice_is_kept_until_both_blocks_are_forgotten() {
+		let included = RecentlyIncluded::<u64>::new(2);
+		included.note_block([1]);
+		included.note_block([1]);
+		included.note_block([]);
+		assert!(included.contains(&1));
+
+		included.note_block([]);
+		assert!(!included.contains(&1));
+	}
+
+	#[test]
+	fn zero_depth_remembers_nothing() {
+		let included = RecentlyIncluded::<u64>::new(0);
+		included.note_block([1]);
+		assert!(!included.contains(&1));
+		assert!(included.inner.read().blocks.is_empty());
+	}
+}
@@ -17,12 +17,12 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use std::{
-	collections::{HashMap, HashSet},
+	collections::{BTreeSet, HashMap, HashSet},
 	hash,
 	sync::Arc,
 };
 
-use crate::LOG_TARGET;
+use crate::{metrics::MetricsLink as PrometheusMetrics, LOG_TARGET};
 use futures::channel::mpsc::{channel, Sender};
 use parking_lot::{Mutex, RwLock};
 use sc_transaction_pool_api::{error, PoolStatus, ReadyTransactions};
@@ -40,6 +40,8 @@ use super::{
 	pool::{
 		BlockHash, ChainApi, EventStream, ExtrinsicFor, ExtrinsicHash, Options, TransactionFor,
 	},
+	propagation_policy::PropagationInfo,
+	recently_included::RecentlyIncluded,
 	rotator::PoolRotator,
 	watcher::Watcher,
 };
@@ -65,6 +67,7 @@ impl<Hash, Ex, Error> ValidatedTransaction<Hash, Ex, Error> {
 		source: TransactionSource,
 		data: Ex,
 		bytes: usize,
+		fee: Option<u128>,
 		validity: ValidTransaction,
 	) -> Self {
 		Self::Valid(base::Transaction {
@@ -72,6 +75,7 @@ impl<Hash, Ex, Error> ValidatedTransaction<Hash, Ex, Error> {
 			bytes,
 			hash,
 			source,
+			fee,
 			priority: validity.priority,
 			requires: validity.requires,
 			provides: validity.provides,
@@ -109,13 +113,35 @@ pub struct ValidatedPool<B: ChainApi> {
 	pub(crate) pool: RwLock<base::BasePool<ExtrinsicHash<B>, ExtrinsicFor<B>>>,
 	import_notification_sinks: Mutex<Vec<Sender<ExtrinsicHash<B>>>>,
 	rotator: PoolRotator<ExtrinsicHash<B>>,
+	/// The extrinsics included in recent blocks, rejected on resubmission.
+	recently_included: RecentlyIncluded<ExtrinsicHash<B>>,
+	/// When the transactions in the pool were first imported, to enforce `Options::ttl`.
+	imported_at: RwLock<HashMap<ExtrinsicHash<B>, Instant>>,
+	/// The transactions with a time-to-live, ordered by when they expire.
+	expirations: Mutex<BTreeSet<(Instant, ExtrinsicHash<B>)>>,
+	metrics: PrometheusMetrics,
 }
 
 impl<B: ChainApi> ValidatedPool<B> {
 	/// Create a new transaction pool.
 	pub fn new(options: Options, is_validator: IsValidator, api: Arc<B>) -> Self {
-		let base_pool = base::BasePool::new(options.reject_future_transactions);
+		Self::with_metrics(options, is_validator, api, Default::default())
+	}
+
+	/// Create a new transaction pool reporting to the given metrics.
+	pub(crate) fn with_metrics(
+		options: Options,
+		is_validator: IsValidator,
+		api: Arc<B>,
+		metrics: PrometheusMetrics,
+	) -> Self {
+		let mut base_pool = base::BasePool::with_priority_strategy(
+			options.reject_future_transactions,
+			options.priority_strategy.clone(),
+		);
+		base_pool.set_replacement_bump(options.replacement_bump);
 		let ban_time = options.ban_time;
+		let recently_included = RecentlyIncluded::new(options.recently_included_depth);
 		Self {
 			is_validator,
 			options,
@@ -124,6 +150,10 @@ impl<B: ChainApi> ValidatedPool<B> {
 			pool: RwLock::new(base_pool),
 			import_notification_sinks: Default::default(),
 			rotator: PoolRotator::new(ban_time),
+			recently_included,
+			imported_at: Default::default(),
+			expirations: Default::default(),
+			metrics,
 		}
 	}
 
@@ -137,17 +167,27 @@ impl<B: ChainApi> ValidatedPool<B> {
 		self.rotator.is_banned(hash)
 	}
 
+	/// Remember the extrinsics included by a newly imported block.
+	pub fn note_included(&self, hashes: impl IntoIterator<Item = ExtrinsicHash<B>>) {
+		self.recently_included.note_block(hashes)
+	}
+
 	/// A fast check before doing any further processing of a transaction, like validation.
 	///
-	/// If `ignore_banned` is `true`, it will not check if the transaction is banned.
+	/// If `ignore_banned` is `true`, it will not check if the transaction is banned or was
+	/// included in a recent block.
 	///
-	/// It checks if the transaction is already imported or banned. If so, it returns an error.
+	/// It checks if the transaction is already imported, recently included or banned. If so, it
+	/// returns an error.
 	pub fn check_is_known(
 		&self,
 		tx_hash: &ExtrinsicHash<B>,
 		ignore_banned: bool,
 	) -> Result<(), B::Error> {
-		if !ignore_banned && self.is_banned(tx_hash) {
+		if !ignore_banned && self.recently_included.contains(tx_hash) {
+			self.metrics.report(|metrics| metrics.recently_included_rejected.inc());
+			Err(error::Error::RecentlyIncluded.into())
+		} else if !ignore_banned && self.is_banned(tx_hash) {
 			Err(error::Error::TemporarilyBanned.into())
 		} else if self.pool.read().is_imported(tx_hash) {
 			Err(error::Error::AlreadyImported(Box::new(*tx_hash)).into())
@@ -183,15 +223,33 @@ impl<B: ChainApi> ValidatedPool<B> {
 			.collect()
 	}
 
+	/// Apply the priority boost of operator-designated accounts to a validated transaction.
+	fn boost_priority(&self, tx: ValidatedTransactionFor<B>) -> ValidatedTransactionFor<B> {
+		match tx {
+			ValidatedTransaction::Valid(mut tx) => {
+				tx.priority =
+					self.options.priority_boost.boosted_priority(tx.priority, &tx.provides);
+				ValidatedTransaction::Valid(tx)
+			},
+			other => other,
+		}
+	}
+
 	/// Submit single pre-validated transaction to the pool.
 	fn submit_one(&self, tx: ValidatedTransactionFor<B>) -> Result<ExtrinsicHash<B>, B::Error> {
-		match tx {
+		match self.boost_priority(tx) {
 			ValidatedTransaction::Valid(tx) => {
 				if !tx.propagate && !(self.is_validator.0)() {
 					return Err(error::Error::Unactionable.into())
 				}
 
+				let ttl = self.options.ttl.for_source(tx.source);
 				let imported = self.pool.write().import(tx)?;
+				let imported_at =
+					*self.imported_at.write().entry(*imported.hash()).or_insert_with(Instant::now);
+				if let Some(expires_at) = ttl.and_then(|ttl| imported_at.checked_add(ttl)) {
+					self.expirations.lock().insert((expires_at, *imported.hash()));
+				}
 
 				if let base::Imported::Ready { ref hash, .. } = imported {
 					let sinks = &mut self.import_notification_sinks.lock();
@@ -247,8 +305,12 @@ impl<B: ChainApi> ValidatedPool<B> {
 			// clean up the pool
 			let removed = {
 				let mut pool = self.pool.write();
+				// transactions of boosted accounts are protected within half of the limits
+				let priority_boost = &self.options.priority_boost;
 				let removed = pool
-					.enforce_limits(ready_limit, future_limit)
+					.enforce_limits(ready_limit, future_limit, |tx| {
+						priority_boost.is_boosted(&tx.provides)
+					})
 					.into_iter()
 					.map(|x| x.hash)
 					.collect::<HashSet<_>>();
@@ -273,14 +335,18 @@ impl<B: ChainApi> ValidatedPool<B> {
 	}
 
 	/// Import a single extrinsic and starts to watch their progress in the pool.
+	///
+	/// `submitted_at` is when the extrinsic was submitted for validation, the timings reported
+	/// by the watcher are relative to it.
 	pub fn submit_and_watch(
 		&self,
 		tx: ValidatedTransactionFor<B>,
+		submitted_at: Instant,
 	) -> Result<Watcher<ExtrinsicHash<B>, ExtrinsicHash<B>>, B::Error> {
 		match tx {
 			ValidatedTransaction::Valid(tx) => {
 				let hash = self.api.hash_and_length(&tx.data).0;
-				let watcher = self.listener.write().create_watcher(hash);
+				let watcher = self.listener.write().create_watcher(hash, submitted_at);
 				self.submit(std::iter::once(ValidatedTransaction::Valid(tx)))
 					.pop()
 					.expect("One extrinsic passed; one result returned; qed")
@@ -303,11 +369,12 @@ impl<B: ChainApi> ValidatedPool<B> {
 		mut updated_transactions: HashMap<ExtrinsicHash<B>, ValidatedTransactionFor<B>>,
 	) {
 		#[derive(Debug, Clone, Copy, PartialEq)]
-		enum Status {
+		enum Status<Hash> {
 			Future,
 			Ready,
 			Failed,
 			Dropped,
+			Usurped(Hash),
 		}
 
 		let (mut initial_statuses, final_statuses) = {
@@ -337,7 +404,7 @@ impl<B: ChainApi> ValidatedPool<B> {
 					let removed_hash = removed_tx.hash;
 					let updated_transaction = updated_transactions.remove(&removed_hash);
 					let tx_to_resubmit = if let Some(updated_tx) = updated_transaction {
-						updated_tx
+						self.boost_priority(updated_tx)
 					} else {
 						// in most cases we'll end up in successful `try_unwrap`, but if not
 						// we still need to reinsert transaction back to the pool => duplicate call
@@ -374,12 +441,15 @@ impl<B: ChainApi> ValidatedPool<B> {
 									for hash in failed {
 										final_statuses.insert(hash, Status::Failed);
 									}
-									for tx in removed {
-										final_statuses.insert(tx.hash, Status::Dropped);
+									for (tx, by) in removed {
+										final_statuses.insert(tx.hash, Status::Usurped(by));
 									}
 								},
-								base::Imported::Future { .. } => {
+								base::Imported::Future { removed, .. } => {
 									final_statuses.insert(hash, Status::Future);
+									for tx in removed {
+										final_statuses.insert(tx.hash, Status::Usurped(hash));
+									}
 								},
 							},
 							Err(err) => {
@@ -424,6 +494,7 @@ impl<B: ChainApi> ValidatedPool<B> {
 					Status::Future => listener.future(&hash),
 					Status::Ready => listener.ready(&hash, None),
 					Status::Dropped => listener.dropped(&hash, None),
+					Status::Usurped(by) => listener.dropped(&hash, Some(&by)),
 					Status::Failed => listener.invalid(&hash),
 				}
 			}
@@ -512,12 +583,18 @@ impl<B: ChainApi> ValidatedPool<B> {
 			.block_id_to_hash(at)?
 			.ok_or_else(|| error::Error::InvalidBlockId(format!("{:?}", at)))?;
 		let mut listener = self.listener.write();
+		let imported_at = self.imported_at.read();
 		let mut set = HashSet::with_capacity(hashes.size_hint().0);
 		for h in hashes {
 			// `hashes` has possibly duplicate hashes.
 			// we'd like to send out the `InBlock` notification only once.
 			if !set.contains(&h) {
 				listener.pruned(header_hash, &h);
+				if let Some(imported_at) = imported_at.get(&h) {
+					self.metrics.report(|metrics| {
+						metrics.time_in_pool.observe(imported_at.elapsed().as_secs_f64())
+					});
+				}
 				set.insert(h);
 			}
 		}
@@ -555,12 +632,70 @@ impl<B: ChainApi> ValidatedPool<B> {
 		// removing old transactions
 		self.remove_invalid(&to_remove);
 		self.remove_invalid(&futures_to_remove);
+		// removing transactions that stayed in the pool for too long
+		self.remove_expired(&now);
 		// clear banned transactions timeouts
 		self.rotator.clear_timeouts(&now);
 
 		Ok(())
 	}
 
+	/// Removes the transactions that stayed in the pool longer than the time-to-live of their
+	/// source, together with the transactions depending on them.
+	fn remove_expired(&self, now: &Instant) {
+		let expired = {
+			let pool = self.pool.read();
+			let mut imported_at = self.imported_at.write();
+			imported_at.retain(|hash, _| pool.is_imported(hash));
+
+			let ttl = self.options.ttl;
+			let mut expirations = self.expirations.lock();
+			let mut expired = Vec::new();
+			while let Some(&(expires_at, hash)) = expirations.first() {
+				if expires_at > *now {
+					break
+				}
+				expirations.pop_first();
+				// Skip the transactions which left the pool, or were imported again since.
+				expired.extend(pool.by_hashes(&[hash]).pop().flatten().filter(|tx| {
+					ttl.for_source(tx.source)
+						.zip(imported_at.get(&hash))
+						.and_then(|(ttl, imported_at)| imported_at.checked_add(ttl)) ==
+						Some(expires_at)
+				}));
+			}
+			expired
+		};
+		if expired.is_empty() {
+			return
+		}
+
+		let expired_hashes = expired.iter().map(|tx| tx.hash).collect::<Vec<_>>();
+		log::debug!(target: LOG_TARGET, "Removing expired transactions: {:?}", expired_hashes);
+
+		// temporarily ban expired external transactions, so that they aren't received again right
+		// away, the local ones may be resubmitted by the node's own user at any time
+		self.rotator.ban(
+			now,
+			expired
+				.iter()
+				.filter(|tx| tx.source != TransactionSource::Local)
+				.map(|tx| tx.hash),
+		);
+
+		let removed = self.pool.write().remove_subtree(&expired_hashes);
+		let expired = expired_hashes.into_iter().collect::<HashSet<_>>();
+
+		let mut listener = self.listener.write();
+		for tx in &removed {
+			if expired.contains(&tx.hash) {
+				listener.expired(&tx.hash);
+			} else {
+				listener.dropped(&tx.hash, None);
+			}
+		}
+	}
+
 	/// Get api reference.
 	pub fn api(&self) -> &B {
 		&self.api
@@ -578,6 +713,21 @@ impl<B: ChainApi> ValidatedPool<B> {
 		stream
 	}
 
+	/// Whether a ready transaction should be propagated to other peers, as decided by the
+	/// propagation policy of the pool.
+	pub fn should_propagate(
+		&self,
+		tx: &base::Transaction<ExtrinsicHash<B>, ExtrinsicFor<B>>,
+	) -> bool {
+		self.options.propagation_policy.should_propagate(&PropagationInfo {
+			propagate: tx.propagate,
+			priority: tx.priority,
+			bytes: tx.bytes,
+			source: tx.source,
+			provides: &tx.provides,
+		})
+	}
+
 	/// Invoked when extrinsics are broadcasted.
 	pub fn on_broadcasted(&self, propagated: HashMap<ExtrinsicHash<B>, Vec<String>>) {
 		let mut listener = self.listener.write();
@@ -656,9 +806,12 @@ where
 		base::Imported::Ready { ref promoted, ref failed, ref removed, ref hash } => {
 			listener.ready(hash, None);
 			failed.iter().for_each(|f| listener.invalid(f));
-			removed.iter().for_each(|r| listener.dropped(&r.hash, Some(hash)));
+			removed.iter().for_each(|(r, by)| listener.dropped(&r.hash, Some(by)));
 			promoted.iter().for_each(|p| listener.ready(p, None));
 		},
-		base::Imported::Future { ref hash } => listener.future(hash),
+		base::Imported::Future { ref hash, ref removed } => {
+			listener.future(hash);
+			removed.iter().for_each(|r| listener.dropped(&r.hash, Some(hash)));
+		},
 	}
 }
@@ -18,16 +18,17 @@
 
 //! Extrinsics status updates.
 
-use futures::Stream;
-use sc_transaction_pool_api::TransactionStatus;
+use futures::{Stream, StreamExt};
+use sc_transaction_pool_api::{DetailedTransactionStatus, TransactionStatus, TransactionTimings};
 use sc_utils::mpsc::{tracing_unbounded, TracingUnboundedReceiver, TracingUnboundedSender};
+use std::time::Instant;
 
 /// Extrinsic watcher.
 ///
 /// Represents a stream of status updates for a particular extrinsic.
 #[derive(Debug)]
 pub struct Watcher<H, BH> {
-	receiver: TracingUnboundedReceiver<TransactionStatus<H, BH>>,
+	receiver: TracingUnboundedReceiver<DetailedTransactionStatus<H, BH>>,
 	/// transaction hash of watched extrinsic
 	hash: H,
 }
@@ -42,6 +43,13 @@ impl<H, BH> Watcher<H, BH> {
 	///
 	/// Make sure to drive the future to completion.
 	pub fn into_stream(self) -> impl Stream<Item = TransactionStatus<H, BH>> {
+		self.receiver.map(|detailed| detailed.status)
+	}
+
+	/// Pipe the notifications together with the timings of the extrinsic to given sink.
+	///
+	/// Make sure to drive the future to completion.
+	pub fn into_detailed_stream(self) -> impl Stream<Item = DetailedTransactionStatus<H, BH>> {
 		self.receiver
 	}
 }
@@ -49,13 +57,31 @@ impl<H, BH> Watcher<H, BH> {
 /// Sender part of the watcher. Exposed only for testing purposes.
 #[derive(Debug)]
 pub struct Sender<H, BH> {
-	receivers: Vec<TracingUnboundedSender<TransactionStatus<H, BH>>>,
+	receivers: Vec<TracingUnboundedSender<DetailedTransactionStatus<H, BH>>>,
 	is_finalized: bool,
+	/// When the watched extrinsic was submitted to the pool.
+	submitted_at: Instant,
+	timings: TransactionTimings,
 }
 
 impl<H, BH> Default for Sender<H, BH> {
 	fn default() -> Self {
-		Sender { receivers: Default::default(), is_finalized: false }
+		Self::new(Instant::now())
+	}
+}
+
+impl<H, BH> Sender<H, BH> {
+	/// Create a sender for an extrinsic submitted at `submitted_at` and validated just now.
+	pub fn new(submitted_at: Instant) -> Self {
+		Sender {
+			receivers: Default::default(),
+			is_finalized: false,
+			submitted_at,
+			timings: TransactionTimings {
+				validation: Some(submitted_at.elapsed()),
+				..Default::default()
+			},
+		}
 	}
 }
 
@@ -69,6 +95,8 @@ impl<H: Clone, BH: Clone> Sender<H, BH> {
 
 	/// Transaction became ready.
 	pub fn ready(&mut self) {
+		let since_submission = self.submitted_at.elapsed();
+		self.timings.ready.get_or_insert(since_submission);
 		self.send(TransactionStatus::Ready)
 	}
 
@@ -85,6 +113,7 @@ impl<H: Clone, BH: Clone> Sender<H, BH> {
 
 	/// Extrinsic has been included in block with given hash.
 	pub fn in_block(&mut self, hash: BH, index: usize) {
+		self.timings.in_block = Some(self.submitted_at.elapsed());
 		self.send(TransactionStatus::InBlock((hash, index)));
 	}
 
@@ -118,6 +147,12 @@ impl<H: Clone, BH: Clone> Sender<H, BH> {
 		self.is_finalized = true;
 	}
 
+	/// Transaction has been dropped from the pool because it stayed there for too long.
+	pub fn expired(&mut self) {
+		self.send(TransactionStatus::Expired);
+		self.is_finalized = true;
+	}
+
 	/// The extrinsic has been broadcast to the given peers.
 	pub fn broadcast(&mut self, peers: Vec<String>) {
 		self.send(TransactionStatus::Broadcast(peers))
@@ -129,6 +164,7 @@ impl<H: Clone, BH: Clone> Sender<H, BH> {
 	}
 
 	fn send(&mut self, status: TransactionStatus<H, BH>) {
-		self.receivers.retain(|sender| sender.unbounded_send(status.clone()).is_ok())
+		let detailed = DetailedTransactionStatus { status, timings: self.timings.clone() };
+		self.receivers.retain(|sender| sender.unbounded_send(detailed.clone()).is_ok())
 	}
 }
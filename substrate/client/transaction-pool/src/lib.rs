@@ -27,12 +27,18 @@ mod enactment_state;
 pub mod error;
 mod graph;
 mod metrics;
+mod multi_chain;
 mod revalidation;
 #[cfg(test)]
 mod tests;
 
-pub use crate::api::FullChainApi;
+pub use crate::{
+	api::{FeeEstimator, FullChainApi},
+	multi_chain::MultiChainPools,
+	revalidation::RevalidationOptions,
+};
 use async_trait::async_trait;
+use codec::Encode;
 use enactment_state::{EnactmentAction, EnactmentState};
 use futures::{
 	channel::oneshot,
@@ -40,7 +46,9 @@ use futures::{
 	prelude::*,
 };
 pub use graph::{
-	base_pool::Limit as PoolLimit, ChainApi, Options, Pool, Transaction, ValidatedTransaction,
+	base_pool::Limit as PoolLimit, ChainApi, FifoPriorityStrategy, Options, Pool, PriorityStrategy,
+	PropagationInfo, PropagationPolicy, ScoringInfo, TagPriorityStrategy, Transaction,
+	TransactionTtl, ValidatedTransaction, ValidityPropagationPolicy,
 };
 use parking_lot::Mutex;
 use std::{
@@ -51,9 +59,9 @@ use std::{
 
 use graph::{ExtrinsicHash, IsValidator};
 use sc_transaction_pool_api::{
-	error::Error as TxPoolError, ChainEvent, ImportNotificationStream, MaintainedTransactionPool,
-	PoolFuture, PoolStatus, ReadyTransactions, TransactionFor, TransactionPool, TransactionSource,
-	TransactionStatusStreamFor, TxHash,
+	error::Error as TxPoolError, ChainEvent, DetailedTransactionStatusStreamFor,
+	ImportNotificationStream, MaintainedTransactionPool, PoolFuture, PoolStatus, ReadyTransactions,
+	TransactionFor, TransactionPool, TransactionSource, TransactionStatusStreamFor, TxHash,
 };
 use sp_core::traits::SpawnEssentialNamed;
 use sp_runtime::{
@@ -170,6 +178,8 @@ where
 			pool_api.clone(),
 			pool.clone(),
 			finalized_hash,
+			Default::default(),
+			Default::default(),
 		);
 		(
 			Self {
@@ -201,7 +211,14 @@ where
 		best_block_hash: Block::Hash,
 		finalized_hash: Block::Hash,
 	) -> Self {
-		let pool = Arc::new(graph::Pool::new(options, is_validator, pool_api.clone()));
+		let metrics = PrometheusMetrics::new(prometheus);
+		let revalidation_options = options.revalidation.clone();
+		let pool = Arc::new(graph::Pool::with_metrics(
+			options,
+			is_validator,
+			pool_api.clone(),
+			metrics.clone(),
+		));
 		let (revalidation_queue, background_task) = match revalidation_type {
 			RevalidationType::Light =>
 				(revalidation::RevalidationQueue::new(pool_api.clone(), pool.clone()), None),
@@ -210,6 +227,8 @@ where
 					pool_api.clone(),
 					pool.clone(),
 					finalized_hash,
+					revalidation_options,
+					metrics.clone(),
 				);
 				(queue, Some(background))
 			},
@@ -229,7 +248,7 @@ where
 				RevalidationType::Full => RevalidationStrategy::Always,
 			})),
 			ready_poll: Arc::new(Mutex::new(ReadyPoll::new(best_block_number))),
-			metrics: PrometheusMetrics::new(prometheus),
+			metrics,
 			enactment_state: Arc::new(Mutex::new(EnactmentState::new(
 				best_block_hash,
 				finalized_hash,
@@ -303,6 +322,24 @@ where
 		.boxed()
 	}
 
+	fn submit_and_watch_detailed(
+		&self,
+		at: <Self::Block as BlockT>::Hash,
+		source: TransactionSource,
+		xt: TransactionFor<Self>,
+	) -> PoolFuture<Pin<Box<DetailedTransactionStatusStreamFor<Self>>>, Self::Error> {
+		let pool = self.pool.clone();
+
+		self.metrics.report(|metrics| metrics.submitted_transactions.inc());
+
+		async move {
+			let watcher = pool.submit_and_watch(at, source, xt).await?;
+
+			Ok(watcher.into_detailed_stream().boxed())
+		}
+		.boxed()
+	}
+
 	fn remove_invalid(&self, hashes: &[TxHash<Self>]) -> Vec<Arc<Self::InPoolTransaction>> {
 		let removed = self.pool.validated_pool().remove_invalid(hashes);
 		self.metrics
@@ -326,6 +363,10 @@ where
 		self.pool.validated_pool().on_broadcasted(propagations)
 	}
 
+	fn should_propagate(&self, tx: &Self::InPoolTransaction) -> bool {
+		self.pool.validated_pool().should_propagate(tx)
+	}
+
 	fn ready_transaction(&self, hash: &TxHash<Self>) -> Option<Arc<Self::InPoolTransaction>> {
 		self.pool.validated_pool().ready_by_hash(hash)
 	}
@@ -392,7 +433,34 @@ where
 		spawner: impl SpawnEssentialNamed,
 		client: Arc<Client>,
 	) -> Arc<Self> {
-		let pool_api = Arc::new(FullChainApi::new(client.clone(), prometheus, &spawner));
+		let pool_api = FullChainApi::new(client.clone(), prometheus, &spawner);
+		Self::with_full_chain_api(options, is_validator, prometheus, spawner, client, pool_api)
+	}
+
+	/// Create new basic transaction pool for a full node, exposing the fees estimated by
+	/// `fee_estimator` to the [`PriorityStrategy`] of the pool.
+	pub fn new_full_with_fee_estimator(
+		options: graph::Options,
+		is_validator: IsValidator,
+		prometheus: Option<&PrometheusRegistry>,
+		spawner: impl SpawnEssentialNamed,
+		client: Arc<Client>,
+		fee_estimator: FeeEstimator<Client, Block>,
+	) -> Arc<Self> {
+		let pool_api = FullChainApi::new(client.clone(), prometheus, &spawner)
+			.with_fee_estimator(fee_estimator);
+		Self::with_full_chain_api(options, is_validator, prometheus, spawner, client, pool_api)
+	}
+
+	fn with_full_chain_api(
+		options: graph::Options,
+		is_validator: IsValidator,
+		prometheus: Option<&PrometheusRegistry>,
+		spawner: impl SpawnEssentialNamed,
+		client: Arc<Client>,
+		pool_api: FullChainApi<Client, Block>,
+	) -> Arc<Self> {
+		let pool_api = Arc::new(pool_api);
 		let pool = Arc::new(Self::with_revalidation_type(
 			options,
 			is_validator,
@@ -450,12 +518,14 @@ where
 			.block_id_to_number(&BlockId::hash(at))?
 			.ok_or_else(|| error::Error::BlockIdConversion(format!("{:?}", at)))?;
 
+		let fee = self.api.transaction_fee(at, &xt, bytes);
 		let validated = ValidatedTransaction::valid_at(
 			block_number.saturated_into::<u64>(),
 			hash,
 			TransactionSource::Local,
 			xt,
 			bytes,
+			fee,
 			validity,
 		);
 
@@ -546,11 +616,13 @@ impl<N: Clone + Copy + AtLeast32Bit> RevalidationStatus<N> {
 }
 
 /// Prune the known txs for the given block.
+///
+/// Returns the hashes of the extrinsics of the block and their total encoded length.
 async fn prune_known_txs_for_block<Block: BlockT, Api: graph::ChainApi<Block = Block>>(
 	block_hash: Block::Hash,
 	api: &Api,
 	pool: &graph::Pool<Api>,
-) -> Vec<ExtrinsicHash<Api>> {
+) -> (Vec<ExtrinsicHash<Api>>, usize) {
 	let extrinsics = api
 		.block_body(block_hash)
 		.await
@@ -561,6 +633,7 @@ async fn prune_known_txs_for_block<Block: BlockT, Api: graph::ChainApi<Block = B
 		.unwrap_or_default();
 
 	let hashes = extrinsics.iter().map(|tx| pool.hash_of(tx)).collect::<Vec<_>>();
+	let body_length: usize = extrinsics.iter().map(|tx| tx.encoded_size()).sum();
 
 	log::trace!(target: LOG_TARGET, "Pruning transactions: {:?}", hashes);
 
@@ -568,11 +641,11 @@ async fn prune_known_txs_for_block<Block: BlockT, Api: graph::ChainApi<Block = B
 		Ok(Some(h)) => h,
 		Ok(None) => {
 			log::debug!(target: LOG_TARGET, "Could not find header for {:?}.", block_hash);
-			return hashes
+			return (hashes, body_length)
 		},
 		Err(e) => {
 			log::debug!(target: LOG_TARGET, "Error retrieving header for {:?}: {}", block_hash, e);
-			return hashes
+			return (hashes, body_length)
 		},
 	};
 
@@ -580,7 +653,7 @@ async fn prune_known_txs_for_block<Block: BlockT, Api: graph::ChainApi<Block = B
 		log::error!("Cannot prune known in the pool: {}", e);
 	}
 
-	hashes
+	(hashes, body_length)
 }
 
 impl<PoolApi, Block> BasicPool<PoolApi, Block>
@@ -636,8 +709,9 @@ where
 		)
 		.await
 		.into_iter()
-		.for_each(|enacted_log| {
+		.for_each(|(enacted_log, body_length)| {
 			pruned_log.extend(enacted_log);
+			self.revalidation_queue.note_block_enacted(body_length);
 		});
 
 		self.metrics
@@ -764,6 +838,8 @@ where
 				{prev_finalized_block:?}",
 			);
 
+			self.revalidation_queue.note_finalized();
+
 			for hash in tree_route.iter().chain(std::iter::once(&hash)) {
 				if let Err(e) = self.pool.validated_pool().on_block_finalized(*hash).await {
 					log::warn!(
@@ -20,7 +20,10 @@
 
 use std::sync::Arc;
 
-use prometheus_endpoint::{register, Counter, PrometheusError, Registry, U64};
+use prometheus_endpoint::{
+	exponential_buckets, register, Counter, Gauge, Histogram, HistogramOpts, PrometheusError,
+	Registry, U64,
+};
 
 #[derive(Clone, Default)]
 pub struct MetricsLink(Arc<Option<Metrics>>);
@@ -49,6 +52,11 @@ pub struct Metrics {
 	pub validations_invalid: Counter<U64>,
 	pub block_transactions_pruned: Counter<U64>,
 	pub block_transactions_resubmitted: Counter<U64>,
+	pub recently_included_rejected: Counter<U64>,
+	pub revalidation_queue: Gauge<U64>,
+	pub revalidation_batch_size: Gauge<U64>,
+	pub revalidation_lag: Histogram,
+	pub time_in_pool: Histogram,
 }
 
 impl Metrics {
@@ -82,6 +90,47 @@ impl Metrics {
 				)?,
 				registry,
 			)?,
+			recently_included_rejected: register(
+				Counter::new(
+					"substrate_sub_txpool_recently_included_rejected",
+					"Total number of resubmitted transactions rejected as included in a recent block",
+				)?,
+				registry,
+			)?,
+			revalidation_queue: register(
+				Gauge::new(
+					"substrate_sub_txpool_revalidation_queue",
+					"Number of transactions waiting in the background revalidation queue",
+				)?,
+				registry,
+			)?,
+			revalidation_batch_size: register(
+				Gauge::new(
+					"substrate_sub_txpool_revalidation_batch_size",
+					"Number of transactions revalidated in the last background revalidation batch",
+				)?,
+				registry,
+			)?,
+			revalidation_lag: register(
+				Histogram::with_opts(
+					HistogramOpts::new(
+						"substrate_sub_txpool_revalidation_lag",
+						"Longest time in seconds a transaction of a revalidation batch was queued",
+					)
+					.buckets(exponential_buckets(0.01, 2.0, 14)?),
+				)?,
+				registry,
+			)?,
+			time_in_pool: register(
+				Histogram::with_opts(
+					HistogramOpts::new(
+						"substrate_sub_txpool_time_in_pool",
+						"Time in seconds transactions spent in the pool until they were included in a block",
+					)
+					.buckets(exponential_buckets(0.1, 2.0, 14)?),
+				)?,
+				registry,
+			)?,
 		})
 	}
 }
@@ -90,6 +139,7 @@ impl Metrics {
 pub struct ApiMetrics {
 	pub validations_scheduled: Counter<U64>,
 	pub validations_finished: Counter<U64>,
+	pub validation_time: Histogram,
 }
 
 impl ApiMetrics {
@@ -110,6 +160,16 @@ impl ApiMetrics {
 				)?,
 				registry,
 			)?,
+			validation_time: register(
+				Histogram::with_opts(
+					HistogramOpts::new(
+						"substrate_sub_txpool_validation_time",
+						"Time in seconds it took to validate a transaction, including the time it waited for a validation thread",
+					)
+					.buckets(exponential_buckets(0.001, 2.0, 14)?),
+				)?,
+				registry,
+			)?,
 		})
 	}
 }
@@ -0,0 +1,273 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Transaction pools of nodes that embed more than one chain.
+//!
+//! A collator runs the pool of its parachain next to the pool of the relay chain node it embeds.
+//! [`MultiChainPools`] keeps them apart:
+//!
+//! - The limits of every pool are carved out of a node-wide budget, so that the transaction load of
+//!   one chain can not grow the memory used by all pools beyond what the node was configured for.
+//! - The metrics of every pool are registered with a `chain` label.
+//! - RPC handlers look up the pool of the chain they serve by its name.
+
+use crate::{
+	error::{Error, Result},
+	graph::{base_pool::Limit, Options},
+	LOG_TARGET,
+};
+use prometheus_endpoint::{
+	prometheus::{
+		core::{Collector, Desc},
+		proto::MetricFamily,
+	},
+	Registry as PrometheusRegistry,
+};
+use sc_transaction_pool_api::{PoolStatus, TransactionPool};
+use std::{
+	any::Any,
+	collections::{BTreeMap, HashMap},
+	sync::Arc,
+};
+
+/// A transaction pool of one of the chains.
+trait ChainPool: Send + Sync {
+	fn status(&self) -> PoolStatus;
+
+	fn into_any(self: Arc<Self>) -> Arc<dyn Any + Send + Sync>;
+}
+
+impl<P: TransactionPool + 'static> ChainPool for P {
+	fn status(&self) -> PoolStatus {
+		TransactionPool::status(self)
+	}
+
+	fn into_any(self: Arc<Self>) -> Arc<dyn Any + Send + Sync> {
+		self
+	}
+}
+
+/// The transaction pools of all chains embedded in a node.
+pub struct MultiChainPools {
+	ready: Limit,
+	future: Limit,
+	prometheus: Option<PrometheusRegistry>,
+	pools: BTreeMap<String, Arc<dyn ChainPool>>,
+}
+
+impl MultiChainPools {
+	/// Create an empty set of pools.
+	///
+	/// `ready` and `future` are the node-wide limits that the limits of all pools must fit into.
+	/// The metrics of the pools are registered with `prometheus`.
+	pub fn new(ready: Limit, future: Limit, prometheus: Option<&PrometheusRegistry>) -> Self {
+		Self { ready, future, prometheus: prometheus.cloned(), pools: Default::default() }
+	}
+
+	/// Add the pool of `chain`.
+	///
+	/// `build` is called with the `options` of the pool and a registry that labels all metrics
+	/// registered with it with `chain`.
+	///
+	/// Fails if there already is a pool for `chain` or if the limits in `options` don't fit into
+	/// what is left of the node-wide limits.
+	pub fn add<P, F>(&mut self, chain: &str, options: Options, build: F) -> Result<Arc<P>>
+	where
+		P: TransactionPool + 'static,
+		F: FnOnce(Options, Option<&PrometheusRegistry>) -> Arc<P>,
+	{
+		if self.pools.contains_key(chain) {
+			return Err(Error::DuplicateChain(chain.into()))
+		}
+		let (ready, future) = self.remaining();
+		if exceeds(&options.ready, &ready) || exceeds(&options.future, &future) {
+			return Err(Error::ChainLimitsExceeded(chain.into()))
+		}
+
+		let prometheus = self.prometheus.as_ref().and_then(|registry| {
+			chain_registry(registry, chain)
+				.map_err(|err| {
+					log::warn!(
+						target: LOG_TARGET,
+						"Failed to register prometheus metrics of chain {}: {}",
+						chain,
+						err,
+					);
+				})
+				.ok()
+		});
+		let pool = build(options.clone(), prometheus.as_ref());
+		self.reserve(&options);
+		self.pools.insert(chain.into(), pool.clone());
+		Ok(pool)
+	}
+
+	/// The pool of `chain`, or `None` if there is no such pool or it is not a `P`.
+	pub fn pool<P: TransactionPool + 'static>(&self, chain: &str) -> Option<Arc<P>> {
+		self.pools.get(chain)?.clone().into_any().downcast().ok()
+	}
+
+	/// The names of all chains with a pool.
+	pub fn chains(&self) -> impl Iterator<Item = &str> {
+		self.pools.keys().map(String::as_str)
+	}
+
+	/// The status of the pools of all chains.
+	pub fn status(&self) -> BTreeMap<String, PoolStatus> {
+		self.pools.iter().map(|(chain, pool)| (chain.clone(), pool.status())).collect()
+	}
+
+	/// The part of the node-wide ready and future limits not yet given to a pool.
+	pub fn remaining(&self) -> (Limit, Limit) {
+		(self.ready.clone(), self.future.clone())
+	}
+
+	fn reserve(&mut self, options: &Options) {
+		let take = |limit: &mut Limit, taken: &Limit| {
+			limit.count -= taken.count;
+			limit.total_bytes -= taken.total_bytes;
+		};
+		take(&mut self.ready, &options.ready);
+		take(&mut self.future, &options.future);
+	}
+}
+
+fn exceeds(limit: &Limit, remaining: &Limit) -> bool {
+	remaining.is_exceeded(limit.count, limit.total_bytes)
+}
+
+/// Create a registry for the metrics of `chain` and expose them through `parent`.
+fn chain_registry(
+	parent: &PrometheusRegistry,
+	chain: &str,
+) -> std::result::Result<PrometheusRegistry, prometheus_endpoint::PrometheusError> {
+	let labels = HashMap::from([(String::from("chain"), chain.to_string())]);
+	let registry = PrometheusRegistry::new_custom(None, Some(labels))?;
+	let desc = Desc::new(
+		format!(
+			"substrate_sub_txpool_chain_{}",
+			chain.replace(|c: char| !c.is_alphanumeric(), "_")
+		),
+		format!("Transaction pool metrics of chain {}", chain),
+		Vec::new(),
+		HashMap::new(),
+	)?;
+	parent.register(Box::new(ChainMetrics { desc, registry: registry.clone() }))?;
+	Ok(registry)
+}
+
+/// Exposes the metrics of a chain registry through the registry of the node.
+struct ChainMetrics {
+	desc: Desc,
+	registry: PrometheusRegistry,
+}
+
+impl Collector for ChainMetrics {
+	fn desc(&self) -> Vec<&Desc> {
+		vec![&self.desc]
+	}
+
+	fn collect(&self) -> Vec<MetricFamily> {
+		self.registry.gather()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::BasicPool;
+	use prometheus_endpoint::{register, Counter, U64};
+	use substrate_test_runtime_client::runtime::Block;
+	use substrate_test_runtime_transaction_pool::TestApi;
+
+	fn limit(count: usize, total_bytes: usize) -> Limit {
+		Limit { count, total_bytes }
+	}
+
+	fn options(count: usize) -> Options {
+		Options {
+			ready: limit(count, count * 100),
+			future: limit(count / 2, count * 50),
+			..Default::default()
+		}
+	}
+
+	fn add(pools: &mut MultiChainPools, chain: &str, options: Options) -> Result<()> {
+		let api = Arc::new(TestApi::empty());
+		let genesis = api.expect_hash_from_number(0);
+		pools.add(chain, options, |_, _| Arc::new(BasicPool::new_test(api, genesis, genesis).0))?;
+		Ok(())
+	}
+
+	#[test]
+	fn limits_are_taken_from_the_budget() {
+		let mut pools = MultiChainPools::new(limit(100, 10_000), limit(50, 5_000), None);
+
+		assert!(add(&mut pools, "parachain", options(60)).is_ok());
+		assert_eq!(pools.remaining(), (limit(40, 4_000), limit(20, 2_000)));
+		assert!(matches!(
+			add(&mut pools, "relaychain", options(60)),
+			Err(Error::ChainLimitsExceeded(chain)) if chain == "relaychain"
+		));
+		assert!(matches!(
+			add(&mut pools, "parachain", options(10)),
+			Err(Error::DuplicateChain(chain)) if chain == "parachain"
+		));
+		assert!(add(&mut pools, "relaychain", options(40)).is_ok());
+		assert_eq!(pools.remaining(), (limit(0, 0), limit(0, 0)));
+		assert_eq!(pools.chains().collect::<Vec<_>>(), vec!["parachain", "relaychain"]);
+	}
+
+	#[test]
+	fn pools_are_routed_by_chain() {
+		let mut pools = MultiChainPools::new(limit(100, 10_000), limit(50, 5_000), None);
+		add(&mut pools, "parachain", options(10)).unwrap();
+
+		assert!(pools.pool::<BasicPool<TestApi, Block>>("parachain").is_some());
+		assert!(pools.pool::<BasicPool<TestApi, Block>>("relaychain").is_none());
+		assert!(pools.status()["parachain"].is_empty());
+	}
+
+	#[test]
+	fn metrics_are_labelled_with_chain() {
+		let registry = PrometheusRegistry::new();
+		let mut pools = MultiChainPools::new(limit(100, 10_000), limit(50, 5_000), Some(&registry));
+		for chain in ["parachain", "relaychain"] {
+			let api = Arc::new(TestApi::empty());
+			let genesis = api.expect_hash_from_number(0);
+			pools
+				.add(chain, options(10), |_, prometheus| {
+					let counter = Counter::<U64>::new("substrate_sub_txpool_test", "test").unwrap();
+					register(counter, prometheus.unwrap()).unwrap().inc();
+					Arc::new(BasicPool::new_test(api, genesis, genesis).0)
+				})
+				.unwrap();
+		}
+
+		let families = registry.gather();
+		let mut labels = families
+			.iter()
+			.filter(|family| family.get_name() == "substrate_sub_txpool_test")
+			.flat_map(|family| family.get_metric())
+			.flat_map(|metric| metric.get_label())
+			.map(|label| (label.get_name(), label.get_value()))
+			.collect::<Vec<_>>();
+		labels.sort();
+		assert_eq!(labels, vec![("chain", "parachain"), ("chain", "relaychain")]);
+	}
+}
@@ -26,19 +26,51 @@ use std::{
 
 use crate::{
 	graph::{BlockHash, ChainApi, ExtrinsicHash, Pool, ValidatedTransaction},
+	metrics::MetricsLink as PrometheusMetrics,
 	LOG_TARGET,
 };
 use sc_utils::mpsc::{tracing_unbounded, TracingUnboundedReceiver, TracingUnboundedSender};
 use sp_runtime::{
 	generic::BlockId, traits::SaturatedConversion, transaction_validity::TransactionValidityError,
+	Perbill, Percent,
 };
 
 use futures::prelude::*;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
-const BACKGROUND_REVALIDATION_INTERVAL: Duration = Duration::from_millis(200);
+/// Tuning of the background revalidation.
+///
+/// Every `interval` a batch of the queued transactions is revalidated. The size of the batch is
+/// `queue_share` of the queue, reduced linearly by the fullness of the recently enacted blocks and
+/// bounded by `min_batch_size` and `max_batch_size`. After every finalized block the batch size
+/// restarts at `min_batch_size` and doubles with every batch, so that the transactions queued by
+/// a finality notification are not revalidated all at once.
+#[derive(Debug, Clone)]
+pub struct RevalidationOptions {
+	/// How often a batch of transactions is revalidated.
+	pub interval: Duration,
+	/// The minimal number of transactions revalidated in a batch.
+	pub min_batch_size: usize,
+	/// The maximal number of transactions revalidated in a batch.
+	pub max_batch_size: usize,
+	/// The share of the queued transactions revalidated in a batch while blocks are empty.
+	pub queue_share: Percent,
+	/// The encoded length of the extrinsics of a full block.
+	pub full_block_length: usize,
+}
 
-const MIN_BACKGROUND_REVALIDATION_BATCH_SIZE: usize = 20;
+impl Default for RevalidationOptions {
+	fn default() -> Self {
+		Self {
+			interval: Duration::from_millis(200),
+			min_batch_size: 20,
+			max_batch_size: 2048,
+			queue_share: Percent::from_percent(25),
+			// 75% of 5 MiB, the share of normal extrinsics in the default block length.
+			full_block_length: 3 * 5 * 1024 * 1024 / 4,
+		}
+	}
+}
 
 /// Payload from queue to worker.
 struct WorkerPayload<Api: ChainApi> {
@@ -46,6 +78,16 @@ struct WorkerPayload<Api: ChainApi> {
 	transactions: Vec<ExtrinsicHash<Api>>,
 }
 
+/// Message from queue to worker.
+enum WorkerMessage<Api: ChainApi> {
+	/// Transactions to queue for revalidation.
+	Revalidate(WorkerPayload<Api>),
+	/// A block whose extrinsics have the given encoded length was enacted.
+	BlockEnacted { body_length: usize },
+	/// A block was finalized.
+	Finalized,
+}
+
 /// Async revalidation worker.
 ///
 /// Implements future and can be spawned in place or in background.
@@ -54,7 +96,14 @@ struct RevalidationWorker<Api: ChainApi> {
 	pool: Arc<Pool<Api>>,
 	best_block: BlockHash<Api>,
 	block_ordered: BTreeMap<BlockHash<Api>, HashSet<ExtrinsicHash<Api>>>,
-	members: HashMap<ExtrinsicHash<Api>, BlockHash<Api>>,
+	/// The queued transactions and when they were queued.
+	members: HashMap<ExtrinsicHash<Api>, Instant>,
+	options: RevalidationOptions,
+	/// Moving average of the fullness of the recently enacted blocks.
+	block_fullness: Perbill,
+	/// Upper bound of the batch size while warming up after a finalized block.
+	warm_up_limit: usize,
+	metrics: PrometheusMetrics,
 }
 
 impl<Api: ChainApi> Unpin for RevalidationWorker<Api> {}
@@ -117,6 +166,7 @@ async fn batch_revalidate<Api: ChainApi>(
 				);
 			},
 			Ok(Ok(validity)) => {
+				let bytes = api.hash_and_length(&ext.data).1;
 				revalidated.insert(
 					ext_hash,
 					ValidatedTransaction::valid_at(
@@ -124,7 +174,8 @@ async fn batch_revalidate<Api: ChainApi>(
 						ext_hash,
 						ext.source,
 						ext.data.clone(),
-						api.hash_and_length(&ext.data).1,
+						bytes,
+						api.transaction_fee(at, &ext.data, bytes),
 						validity,
 					),
 				);
@@ -148,20 +199,59 @@ async fn batch_revalidate<Api: ChainApi>(
 }
 
 impl<Api: ChainApi> RevalidationWorker<Api> {
-	fn new(api: Arc<Api>, pool: Arc<Pool<Api>>, best_block: BlockHash<Api>) -> Self {
+	fn new(
+		api: Arc<Api>,
+		pool: Arc<Pool<Api>>,
+		best_block: BlockHash<Api>,
+		options: RevalidationOptions,
+		metrics: PrometheusMetrics,
+	) -> Self {
 		Self {
 			api,
 			pool,
 			best_block,
 			block_ordered: Default::default(),
 			members: Default::default(),
+			warm_up_limit: options.max_batch_size,
+			options,
+			block_fullness: Perbill::zero(),
+			metrics,
 		}
 	}
 
+	/// The number of transactions to revalidate in the next batch.
+	fn batch_size(&self) -> usize {
+		let share = self.options.queue_share.mul_floor(self.members.len() as u64);
+		let budget = self
+			.block_fullness
+			.left_from_one()
+			.mul_floor(share.max(self.options.min_batch_size as u64)) as usize;
+		budget
+			.min(self.options.max_batch_size)
+			.min(self.warm_up_limit)
+			.max(self.options.min_batch_size)
+	}
+
+	/// Account for the fullness of an enacted block in the batch size.
+	fn note_block_enacted(&mut self, body_length: usize) {
+		let full_block_length = self.options.full_block_length.max(1);
+		let fullness = Perbill::from_rational(
+			body_length.min(full_block_length) as u64,
+			full_block_length as u64,
+		);
+		self.block_fullness =
+			Perbill::from_parts((self.block_fullness.deconstruct() + fullness.deconstruct()) / 2);
+	}
+
+	/// Restart the warm-up of the batch size.
+	fn note_finalized(&mut self) {
+		self.warm_up_limit = self.options.min_batch_size;
+	}
+
 	fn prepare_batch(&mut self) -> Vec<ExtrinsicHash<Api>> {
 		let mut queued_exts = Vec::new();
-		let mut left =
-			std::cmp::max(MIN_BACKGROUND_REVALIDATION_BATCH_SIZE, self.members.len() / 4);
+		let mut left = self.batch_size();
+		self.warm_up_limit = self.warm_up_limit.saturating_mul(2);
 
 		// Take maximum of count transaction by order
 		// which they got into the pool
@@ -189,9 +279,18 @@ impl<Api: ChainApi> RevalidationWorker<Api> {
 			}
 		}
 
-		for hash in queued_exts.iter() {
-			self.members.remove(hash);
-		}
+		let longest_wait = queued_exts
+			.iter()
+			.filter_map(|hash| self.members.remove(hash))
+			.map(|queued_at| queued_at.elapsed())
+			.max();
+
+		self.metrics.report(|metrics| {
+			metrics.revalidation_batch_size.set(queued_exts.len() as u64);
+			if let Some(wait) = longest_wait {
+				metrics.revalidation_lag.observe(wait.as_secs_f64());
+			}
+		});
 
 		queued_exts
 	}
@@ -227,7 +326,7 @@ impl<Api: ChainApi> RevalidationWorker<Api> {
 					bt.insert(ext_hash);
 					bt
 				});
-			self.members.insert(ext_hash, block_number);
+			self.members.insert(ext_hash, Instant::now());
 		}
 	}
 
@@ -236,11 +335,8 @@ impl<Api: ChainApi> RevalidationWorker<Api> {
 	/// It does two things: periodically tries to process some transactions
 	/// from the queue and also accepts messages to enqueue some more
 	/// transactions from the pool.
-	pub async fn run(
-		mut self,
-		from_queue: TracingUnboundedReceiver<WorkerPayload<Api>>,
-		interval: Duration,
-	) {
+	pub async fn run(mut self, from_queue: TracingUnboundedReceiver<WorkerMessage<Api>>) {
+		let interval = self.options.interval;
 		let interval_fut = futures_timer::Delay::new(interval);
 		let from_queue = from_queue.fuse();
 		futures::pin_mut!(interval_fut, from_queue);
@@ -255,6 +351,8 @@ impl<Api: ChainApi> RevalidationWorker<Api> {
 
 					batch_revalidate(this.pool.clone(), this.api.clone(), this.best_block, next_batch).await;
 
+					this.metrics.report(|metrics| metrics.revalidation_queue.set(this.len() as u64));
+
 					if batch_len > 0 || this.len() > 0 {
 						log::debug!(
 							target: LOG_TARGET,
@@ -268,7 +366,7 @@ impl<Api: ChainApi> RevalidationWorker<Api> {
 				},
 				workload = from_queue.next() => {
 					match workload {
-						Some(worker_payload) => {
+						Some(WorkerMessage::Revalidate(worker_payload)) => {
 							this.best_block = worker_payload.at;
 							this.push(worker_payload);
 
@@ -277,12 +375,20 @@ impl<Api: ChainApi> RevalidationWorker<Api> {
 									target: LOG_TARGET,
 									"Updated revalidation queue at {:?}. Transactions: {:?}",
 									this.best_block,
-									this.members,
+									this.members.keys(),
 								);
 							}
 
 							continue;
 						},
+						Some(WorkerMessage::BlockEnacted { body_length }) => {
+							this.note_block_enacted(body_length);
+							continue;
+						},
+						Some(WorkerMessage::Finalized) => {
+							this.note_finalized();
+							continue;
+						},
 						// R.I.P. worker!
 						None => break,
 					}
@@ -299,7 +405,7 @@ impl<Api: ChainApi> RevalidationWorker<Api> {
 pub struct RevalidationQueue<Api: ChainApi> {
 	pool: Arc<Pool<Api>>,
 	api: Arc<Api>,
-	background: Option<TracingUnboundedSender<WorkerPayload<Api>>>,
+	background: Option<TracingUnboundedSender<WorkerMessage<Api>>>,
 }
 
 impl<Api: ChainApi> RevalidationQueue<Api>
@@ -312,28 +418,43 @@ where
 	}
 
 	/// New revalidation queue with background worker.
-	pub fn new_with_interval(
+	pub fn new_background(
 		api: Arc<Api>,
 		pool: Arc<Pool<Api>>,
-		interval: Duration,
 		best_block: BlockHash<Api>,
+		options: RevalidationOptions,
+		metrics: PrometheusMetrics,
 	) -> (Self, Pin<Box<dyn Future<Output = ()> + Send>>) {
 		let (to_worker, from_queue) = tracing_unbounded("mpsc_revalidation_queue", 100_000);
 
-		let worker = RevalidationWorker::new(api.clone(), pool.clone(), best_block);
+		let worker =
+			RevalidationWorker::new(api.clone(), pool.clone(), best_block, options, metrics);
 
 		let queue = Self { api, pool, background: Some(to_worker) };
 
-		(queue, worker.run(from_queue, interval).boxed())
+		(queue, worker.run(from_queue).boxed())
 	}
 
-	/// New revalidation queue with background worker.
-	pub fn new_background(
-		api: Arc<Api>,
-		pool: Arc<Pool<Api>>,
-		best_block: BlockHash<Api>,
-	) -> (Self, Pin<Box<dyn Future<Output = ()> + Send>>) {
-		Self::new_with_interval(api, pool, BACKGROUND_REVALIDATION_INTERVAL, best_block)
+	fn send_to_worker(&self, message: WorkerMessage<Api>) {
+		if let Some(ref to_worker) = self.background {
+			if let Err(e) = to_worker.unbounded_send(message) {
+				log::warn!(target: LOG_TARGET, "Failed to update background worker: {:?}", e);
+			}
+		}
+	}
+
+	/// Inform the background worker about an enacted block, whose extrinsics have the given
+	/// encoded length.
+	///
+	/// The fuller the recent blocks, the smaller the revalidation batches.
+	pub fn note_block_enacted(&self, body_length: usize) {
+		self.send_to_worker(WorkerMessage::BlockEnacted { body_length });
+	}
+
+	/// Inform the background worker about a finalized block, restarting the warm-up of the
+	/// revalidation batches.
+	pub fn note_finalized(&self) {
+		self.send_to_worker(WorkerMessage::Finalized);
 	}
 
 	/// Queue some transaction for later revalidation.
@@ -354,10 +475,8 @@ where
 			);
 		}
 
-		if let Some(ref to_worker) = self.background {
-			if let Err(e) = to_worker.unbounded_send(WorkerPayload { at, transactions }) {
-				log::warn!(target: LOG_TARGET, "Failed to update background worker: {:?}", e);
-			}
+		if self.background.is_some() {
+			self.send_to_worker(WorkerMessage::Revalidate(WorkerPayload { at, transactions }));
 		} else {
 			let pool = self.pool.clone();
 			let api = self.api.clone();
@@ -405,6 +524,45 @@ mod tests {
 		assert_eq!(pool.validated_pool().status().ready, 1);
 	}
 
+	#[test]
+	fn batch_size_follows_block_fullness_and_warm_up() {
+		let api = Arc::new(TestApi::default());
+		let pool = Arc::new(Pool::new(Default::default(), true.into(), api.clone()));
+		let hash_of_block0 = api.expect_hash_from_number(0);
+		let options = RevalidationOptions {
+			min_batch_size: 10,
+			max_batch_size: 100,
+			queue_share: Percent::from_percent(50),
+			full_block_length: 1000,
+			..Default::default()
+		};
+		let mut worker =
+			RevalidationWorker::new(api, pool, hash_of_block0, options, Default::default());
+
+		worker.push(WorkerPayload {
+			at: hash_of_block0,
+			transactions: (0..400).map(H256::from_low_u64_be).collect(),
+		});
+
+		// half of the queue, bounded by the maximum
+		assert_eq!(worker.batch_size(), 100);
+
+		// full blocks shrink the batches
+		worker.note_block_enacted(1000);
+		assert_eq!(worker.batch_size(), 100);
+		worker.note_block_enacted(2000);
+		assert_eq!(worker.batch_size(), 50);
+
+		// a finalized block restarts the warm-up
+		worker.note_finalized();
+		assert_eq!(worker.prepare_batch().len(), 10);
+		assert_eq!(worker.prepare_batch().len(), 20);
+		assert_eq!(worker.prepare_batch().len(), 40);
+		// the warm-up limit of 80 now exceeds a quarter of half of the 330 queued transactions
+		assert_eq!(worker.prepare_batch().len(), 41);
+		assert_eq!(worker.len(), 400 - 10 - 20 - 40 - 41);
+	}
+
 	#[test]
 	fn revalidation_queue_skips_revalidation_for_unknown_block_hash() {
 		let api = Arc::new(TestApi::default());
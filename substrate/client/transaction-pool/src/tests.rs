@@ -182,6 +182,16 @@ impl ChainApi for TestApi {
 		(Hashing::hash(&encoded), len)
 	}
 
+	/// The fee of a transfer is the amount transferred.
+	fn transaction_fee(
+		&self,
+		_at: <Self::Block as BlockT>::Hash,
+		uxt: &ExtrinsicFor<Self>,
+		_len: usize,
+	) -> Option<u128> {
+		TransferData::try_from(uxt).ok().map(|transfer| transfer.amount.into())
+	}
+
 	fn block_body(&self, _id: <Self::Block as BlockT>::Hash) -> Self::BodyFuture {
 		futures::future::ready(Ok(None))
 	}
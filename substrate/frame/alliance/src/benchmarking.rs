@@ -22,20 +22,17 @@
 use core::{
 	cmp,
 	convert::{TryFrom, TryInto},
-	mem::size_of,
 };
 use sp_runtime::traits::{Bounded, Hash, StaticLookup};
 
 use frame_benchmarking::{account, impl_benchmark_test_suite, v2::*, BenchmarkError};
-use frame_support::traits::{EnsureOrigin, Get, UnfilteredDispatchable};
+use frame_support::traits::{fungible::Mutate, EnsureOrigin, Get, UnfilteredDispatchable};
 use frame_system::{pallet_prelude::BlockNumberFor, Pallet as System, RawOrigin as SystemOrigin};
 
 use super::{Call as AllianceCall, Pallet as Alliance, *};
 
 const SEED: u32 = 0;
 
-const MAX_BYTES: u32 = 1_024;
-
 fn assert_last_event<T: Config<I>, I: 'static>(generic_event: <T as Config<I>>::RuntimeEvent) {
 	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
@@ -53,9 +50,26 @@ fn announcement(input: impl AsRef<[u8]>) -> Cid {
 	cid(input)
 }
 
+fn remark<T: Config<I>, I: 'static>(input: impl AsRef<[u8]>) -> T::Proposal {
+	frame_system::Call::<T>::remark { remark: input.as_ref().to_vec() }.into()
+}
+
+fn fill_pending_enactments<T: Config<I>, I: 'static>(
+	origin: &T::RuntimeOrigin,
+	count: u32,
+) -> Result<(), BenchmarkError> {
+	for i in 0..count {
+		let call = AllianceCall::<T, I>::schedule_enactment {
+			proposal: Box::new(remark::<T, I>(i.to_le_bytes())),
+		};
+		call.dispatch_bypass_filter(origin.clone())?;
+	}
+	Ok(())
+}
+
 fn funded_account<T: Config<I>, I: 'static>(name: &'static str, index: u32) -> T::AccountId {
 	let account: T::AccountId = account(name, index, SEED);
-	T::Currency::make_free_balance_be(&account, BalanceOf::<T, I>::max_value() / 100u8.into());
+	T::Currency::set_balance(&account, BalanceOf::<T, I>::max_value() / 100u8.into());
 	account
 }
 
@@ -79,16 +93,14 @@ fn set_members<T: Config<I>, I: 'static>() {
 	let fellows: BoundedVec<_, T::MaxMembersCount> =
 		BoundedVec::try_from(vec![fellow::<T, I>(1), fellow::<T, I>(2)]).unwrap();
 	fellows.iter().for_each(|who| {
-		T::Currency::reserve(&who, T::AllyDeposit::get()).unwrap();
-		<DepositOf<T, I>>::insert(&who, T::AllyDeposit::get());
+		DepositsOf::<T, I>::place(&who, T::AllyDeposit::get()).unwrap();
 	});
 	Members::<T, I>::insert(MemberRole::Fellow, fellows.clone());
 
 	let allies: BoundedVec<_, T::MaxMembersCount> =
 		BoundedVec::try_from(vec![ally::<T, I>(1)]).unwrap();
 	allies.iter().for_each(|who| {
-		T::Currency::reserve(&who, T::AllyDeposit::get()).unwrap();
-		<DepositOf<T, I>>::insert(&who, T::AllyDeposit::get());
+		DepositsOf::<T, I>::place(&who, T::AllyDeposit::get()).unwrap();
 	});
 	Members::<T, I>::insert(MemberRole::Ally, allies);
 
@@ -99,396 +111,6 @@ fn set_members<T: Config<I>, I: 'static>() {
 mod benchmarks {
 	use super::*;
 
-	// This tests when proposal is created and queued as "proposed"
-	#[benchmark]
-	fn propose_proposed(
-		b: Linear<1, MAX_BYTES>,
-		m: Linear<2, { T::MaxFellows::get() }>,
-		p: Linear<1, { T::MaxProposals::get() }>,
-	) -> Result<(), BenchmarkError> {
-		let bytes_in_storage = b + size_of::<Cid>() as u32 + 32;
-
-		// Construct `members`.
-		let fellows = (0..m).map(fellow::<T, I>).collect::<Vec<_>>();
-		let proposer = fellows[0].clone();
-
-		Alliance::<T, I>::init_members(SystemOrigin::Root.into(), fellows, vec![])?;
-
-		let threshold = m;
-		// Add previous proposals.
-		for i in 0..p - 1 {
-			// Proposals should be different so that different proposal hashes are generated
-			let proposal: T::Proposal =
-				AllianceCall::<T, I>::set_rule { rule: rule(vec![i as u8; b as usize]) }.into();
-			Alliance::<T, I>::propose(
-				SystemOrigin::Signed(proposer.clone()).into(),
-				threshold,
-				Box::new(proposal),
-				bytes_in_storage,
-			)?;
-		}
-
-		let proposal: T::Proposal =
-			AllianceCall::<T, I>::set_rule { rule: rule(vec![p as u8; b as usize]) }.into();
-
-		#[extrinsic_call]
-		propose(
-			SystemOrigin::Signed(proposer.clone()),
-			threshold,
-			Box::new(proposal.clone()),
-			bytes_in_storage,
-		);
-
-		let proposal_hash = T::Hashing::hash_of(&proposal);
-		assert_eq!(T::ProposalProvider::proposal_of(proposal_hash), Some(proposal));
-		Ok(())
-	}
-
-	#[benchmark]
-	fn vote(m: Linear<5, { T::MaxFellows::get() }>) -> Result<(), BenchmarkError> {
-		let p = T::MaxProposals::get();
-		let b = MAX_BYTES;
-		let _bytes_in_storage = b + size_of::<Cid>() as u32 + 32;
-
-		// Construct `members`.
-		let fellows = (0..m).map(fellow::<T, I>).collect::<Vec<_>>();
-		let proposer = fellows[0].clone();
-
-		let members = fellows.clone();
-
-		Alliance::<T, I>::init_members(SystemOrigin::Root.into(), fellows, vec![])?;
-
-		// Threshold is 1 less than the number of members so that one person can vote nay
-		let threshold = m - 1;
-
-		// Add previous proposals
-		let mut last_hash = T::Hash::default();
-		for i in 0..p {
-			// Proposals should be different so that different proposal hashes are generated
-			let proposal: T::Proposal =
-				AllianceCall::<T, I>::set_rule { rule: rule(vec![i as u8; b as usize]) }.into();
-			Alliance::<T, I>::propose(
-				SystemOrigin::Signed(proposer.clone()).into(),
-				threshold,
-				Box::new(proposal.clone()),
-				b,
-			)?;
-			last_hash = T::Hashing::hash_of(&proposal);
-		}
-
-		let index = p - 1;
-		// Have almost everyone vote aye on last proposal, while keeping it from passing.
-		for j in 0..m - 3 {
-			let voter = &members[j as usize];
-			Alliance::<T, I>::vote(
-				SystemOrigin::Signed(voter.clone()).into(),
-				last_hash,
-				index,
-				true,
-			)?;
-		}
-
-		let voter = members[m as usize - 3].clone();
-		// Voter votes aye without resolving the vote.
-		Alliance::<T, I>::vote(SystemOrigin::Signed(voter.clone()).into(), last_hash, index, true)?;
-
-		// Voter switches vote to nay, but does not kill the vote, just updates + inserts
-		let approve = false;
-
-		// Whitelist voter account from further DB operations.
-		let voter_key = frame_system::Account::<T>::hashed_key_for(&voter);
-		frame_benchmarking::benchmarking::add_to_whitelist(voter_key.into());
-
-		#[extrinsic_call]
-		_(SystemOrigin::Signed(voter), last_hash, index, approve);
-
-		//nothing to verify
-		Ok(())
-	}
-
-	#[benchmark]
-	fn close_early_disapproved(
-		m: Linear<4, { T::MaxFellows::get() }>,
-		p: Linear<1, { T::MaxProposals::get() }>,
-	) -> Result<(), BenchmarkError> {
-		let bytes = 100;
-		let bytes_in_storage = bytes + size_of::<Cid>() as u32 + 32;
-
-		// Construct `members`.
-		let fellows = (0..m).map(fellow::<T, I>).collect::<Vec<_>>();
-
-		let members = fellows.clone();
-
-		Alliance::<T, I>::init_members(SystemOrigin::Root.into(), fellows, vec![])?;
-
-		let proposer = members[0].clone();
-		let voter = members[1].clone();
-
-		// Threshold is total members so that one nay will disapprove the vote
-		let threshold = m;
-
-		// Add previous proposals
-		let mut last_hash = T::Hash::default();
-		for i in 0..p {
-			// Proposals should be different so that different proposal hashes are generated
-			let proposal: T::Proposal =
-				AllianceCall::<T, I>::set_rule { rule: rule(vec![i as u8; bytes as usize]) }.into();
-			Alliance::<T, I>::propose(
-				SystemOrigin::Signed(proposer.clone()).into(),
-				threshold,
-				Box::new(proposal.clone()),
-				bytes_in_storage,
-			)?;
-			last_hash = T::Hashing::hash_of(&proposal);
-			assert_eq!(T::ProposalProvider::proposal_of(last_hash), Some(proposal));
-		}
-
-		let index = p - 1;
-		// Have most everyone vote aye on last proposal, while keeping it from passing.
-		for j in 2..m - 1 {
-			let voter = &members[j as usize];
-			Alliance::<T, I>::vote(
-				SystemOrigin::Signed(voter.clone()).into(),
-				last_hash,
-				index,
-				true,
-			)?;
-		}
-
-		// Voter votes aye without resolving the vote.
-		Alliance::<T, I>::vote(SystemOrigin::Signed(voter.clone()).into(), last_hash, index, true)?;
-
-		// Voter switches vote to nay, which kills the vote
-		Alliance::<T, I>::vote(
-			SystemOrigin::Signed(voter.clone()).into(),
-			last_hash,
-			index,
-			false,
-		)?;
-
-		// Whitelist voter account from further DB operations.
-		let voter_key = frame_system::Account::<T>::hashed_key_for(&voter);
-		frame_benchmarking::benchmarking::add_to_whitelist(voter_key.into());
-
-		#[extrinsic_call]
-		close(SystemOrigin::Signed(voter), last_hash, index, Weight::MAX, bytes_in_storage);
-
-		assert_eq!(T::ProposalProvider::proposal_of(last_hash), None);
-		Ok(())
-	}
-
-	// We choose 4 as a minimum for param m, so we always trigger a vote in the voting loop (`for j
-	// in ...`)
-	#[benchmark]
-	fn close_early_approved(
-		b: Linear<1, MAX_BYTES>,
-		m: Linear<4, { T::MaxFellows::get() }>,
-		p: Linear<1, { T::MaxProposals::get() }>,
-	) -> Result<(), BenchmarkError> {
-		let bytes_in_storage = b + size_of::<Cid>() as u32 + 32;
-
-		// Construct `members`.
-		let fellows = (0..m).map(fellow::<T, I>).collect::<Vec<_>>();
-
-		let members = fellows.clone();
-
-		Alliance::<T, I>::init_members(SystemOrigin::Root.into(), fellows, vec![])?;
-
-		let proposer = members[0].clone();
-		// Threshold is 2 so any two ayes will approve the vote
-		let threshold = 2;
-
-		// Add previous proposals
-		let mut last_hash = T::Hash::default();
-		for i in 0..p {
-			// Proposals should be different so that different proposal hashes are generated
-			let proposal: T::Proposal =
-				AllianceCall::<T, I>::set_rule { rule: rule(vec![i as u8; b as usize]) }.into();
-			Alliance::<T, I>::propose(
-				SystemOrigin::Signed(proposer.clone()).into(),
-				threshold,
-				Box::new(proposal.clone()),
-				bytes_in_storage,
-			)?;
-			last_hash = T::Hashing::hash_of(&proposal);
-			assert_eq!(T::ProposalProvider::proposal_of(last_hash), Some(proposal));
-		}
-
-		let index = p - 1;
-		// Caller switches vote to nay on their own proposal, allowing them to be the deciding
-		// approval vote
-		Alliance::<T, I>::vote(
-			SystemOrigin::Signed(proposer.clone()).into(),
-			last_hash,
-			index,
-			false,
-		)?;
-
-		// Have almost everyone vote nay on last proposal, while keeping it from failing.
-		for j in 2..m - 1 {
-			let voter = &members[j as usize];
-			Alliance::<T, I>::vote(
-				SystemOrigin::Signed(voter.clone()).into(),
-				last_hash,
-				index,
-				false,
-			)?;
-		}
-
-		// Member zero is the first aye
-		Alliance::<T, I>::vote(
-			SystemOrigin::Signed(members[0].clone()).into(),
-			last_hash,
-			index,
-			true,
-		)?;
-
-		let voter = members[1].clone();
-		// Caller switches vote to aye, which passes the vote
-		Alliance::<T, I>::vote(SystemOrigin::Signed(voter.clone()).into(), last_hash, index, true)?;
-
-		#[extrinsic_call]
-		close(SystemOrigin::Signed(voter), last_hash, index, Weight::MAX, bytes_in_storage);
-
-		assert_eq!(T::ProposalProvider::proposal_of(last_hash), None);
-		Ok(())
-	}
-
-	#[benchmark]
-	fn close_disapproved(
-		m: Linear<2, { T::MaxFellows::get() }>,
-		p: Linear<1, { T::MaxProposals::get() }>,
-	) -> Result<(), BenchmarkError> {
-		let bytes = 100;
-		let bytes_in_storage = bytes + size_of::<Cid>() as u32 + 32;
-
-		// Construct `members`.
-		let fellows = (0..m).map(fellow::<T, I>).collect::<Vec<_>>();
-
-		let members = fellows.clone();
-
-		Alliance::<T, I>::init_members(SystemOrigin::Root.into(), fellows, vec![])?;
-
-		let proposer = members[0].clone();
-		let voter = members[1].clone();
-
-		// Threshold is one less than total members so that two nays will disapprove the vote
-		let threshold = m - 1;
-
-		// Add proposals
-		let mut last_hash = T::Hash::default();
-		for i in 0..p {
-			// Proposals should be different so that different proposal hashes are generated
-			let proposal: T::Proposal =
-				AllianceCall::<T, I>::set_rule { rule: rule(vec![i as u8; bytes as usize]) }.into();
-			Alliance::<T, I>::propose(
-				SystemOrigin::Signed(proposer.clone()).into(),
-				threshold,
-				Box::new(proposal.clone()),
-				bytes_in_storage,
-			)?;
-			last_hash = T::Hashing::hash_of(&proposal);
-			assert_eq!(T::ProposalProvider::proposal_of(last_hash), Some(proposal));
-		}
-
-		let index = p - 1;
-		// Have almost everyone vote aye on last proposal, while keeping it from passing.
-		// A few abstainers will be the nay votes needed to fail the vote.
-		for j in 2..m - 1 {
-			let voter = &members[j as usize];
-			Alliance::<T, I>::vote(
-				SystemOrigin::Signed(voter.clone()).into(),
-				last_hash,
-				index,
-				true,
-			)?;
-		}
-
-		Alliance::<T, I>::vote(
-			SystemOrigin::Signed(voter.clone()).into(),
-			last_hash,
-			index,
-			false,
-		)?;
-
-		System::<T>::set_block_number(BlockNumberFor::<T>::max_value());
-
-		#[extrinsic_call]
-		close(SystemOrigin::Signed(voter), last_hash, index, Weight::MAX, bytes_in_storage);
-
-		// The last proposal is removed.
-		assert_eq!(T::ProposalProvider::proposal_of(last_hash), None);
-		Ok(())
-	}
-
-	// We choose 5 fellows as a minimum so we always trigger a vote in the voting loop (`for j in
-	// ...`)
-	#[benchmark]
-	fn close_approved(
-		b: Linear<1, MAX_BYTES>,
-		m: Linear<5, { T::MaxFellows::get() }>,
-		p: Linear<1, { T::MaxProposals::get() }>,
-	) -> Result<(), BenchmarkError> {
-		let bytes_in_storage = b + size_of::<Cid>() as u32 + 32;
-
-		// Construct `members`.
-		let fellows = (0..m).map(fellow::<T, I>).collect::<Vec<_>>();
-
-		let members = fellows.clone();
-
-		Alliance::<T, I>::init_members(SystemOrigin::Root.into(), fellows, vec![])?;
-
-		let proposer = members[0].clone();
-		// Threshold is two, so any two ayes will pass the vote
-		let threshold = 2;
-
-		// Add proposals
-		let mut last_hash = T::Hash::default();
-		for i in 0..p {
-			// Proposals should be different so that different proposal hashes are generated
-			let proposal: T::Proposal =
-				AllianceCall::<T, I>::set_rule { rule: rule(vec![i as u8; b as usize]) }.into();
-			Alliance::<T, I>::propose(
-				SystemOrigin::Signed(proposer.clone()).into(),
-				threshold,
-				Box::new(proposal.clone()),
-				bytes_in_storage,
-			)?;
-			last_hash = T::Hashing::hash_of(&proposal);
-			assert_eq!(T::ProposalProvider::proposal_of(last_hash), Some(proposal));
-		}
-
-		// The prime member votes aye, so abstentions default to aye.
-		Alliance::<T, I>::vote(
-			SystemOrigin::Signed(proposer.clone()).into(),
-			last_hash,
-			p - 1,
-			true, // Vote aye.
-		)?;
-
-		let index = p - 1;
-		// Have almost everyone vote nay on last proposal, while keeping it from failing.
-		// A few abstainers will be the aye votes needed to pass the vote.
-		for j in 2..m - 1 {
-			let voter = &members[j as usize];
-			Alliance::<T, I>::vote(
-				SystemOrigin::Signed(voter.clone()).into(),
-				last_hash,
-				index,
-				false,
-			)?;
-		}
-
-		// caller is prime, prime already votes aye by creating the proposal
-		System::<T>::set_block_number(BlockNumberFor::<T>::max_value());
-
-		#[extrinsic_call]
-		close(SystemOrigin::Signed(proposer), last_hash, index, Weight::MAX, bytes_in_storage);
-
-		assert_eq!(T::ProposalProvider::proposal_of(last_hash), None);
-		Ok(())
-	}
-
 	#[benchmark]
 	fn init_members(
 		m: Linear<1, { T::MaxFellows::get() }>,
@@ -523,11 +145,10 @@ mod benchmarks {
 		// setting the Alliance to disband on the benchmark call
 		Alliance::<T, I>::init_members(SystemOrigin::Root.into(), fellows.clone(), allies.clone())?;
 
-		// reserve deposits
+		// hold deposits
 		let deposit = T::AllyDeposit::get();
 		for member in fellows.iter().chain(allies.iter()).take(z as usize) {
-			T::Currency::reserve(&member, deposit)?;
-			<DepositOf<T, I>>::insert(&member, deposit);
+			DepositsOf::<T, I>::place(&member, deposit)?;
 		}
 
 		assert_eq!(Alliance::<T, I>::voting_members_count(), x);
@@ -549,6 +170,38 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn continue_disband(
+		x: Linear<0, { T::MaxFellows::get() - 1 }>,
+		y: Linear<1, { T::MaxAllies::get() }>,
+		z: Linear<0, { T::MaxMembersCount::get() / 2 }>,
+	) -> Result<(), BenchmarkError> {
+		// one fellow is left, so that the Alliance is not disbanded yet
+		let fellows = (0..=x).map(fellow::<T, I>).collect::<Vec<_>>();
+		let allies = (0..y).map(ally::<T, I>).collect::<Vec<_>>();
+		let witness = DisbandWitness { fellow_members: x, ally_members: y };
+
+		Alliance::<T, I>::init_members(SystemOrigin::Root.into(), fellows.clone(), allies.clone())?;
+		DisbandProgress::<T, I>::put(DisbandStatus::default());
+
+		// hold deposits
+		let deposit = T::AllyDeposit::get();
+		for member in allies.iter().chain(fellows.iter()).take(z as usize) {
+			DepositsOf::<T, I>::place(&member, deposit)?;
+		}
+
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(SystemOrigin::Signed(caller), witness);
+
+		let progress = DisbandProgress::<T, I>::get().unwrap();
+		assert_eq!((progress.fellow_members, progress.ally_members), (x, y));
+		assert_eq!(Alliance::<T, I>::voting_members_count(), 1);
+		assert_eq!(Alliance::<T, I>::ally_members_count(), 0);
+		Ok(())
+	}
+
 	#[benchmark]
 	fn set_rule() -> Result<(), BenchmarkError> {
 		set_members::<T, I>();
@@ -564,7 +217,7 @@ mod benchmarks {
 			call.dispatch_bypass_filter(origin)?;
 		}
 		assert_eq!(Alliance::<T, I>::rule(), Some(rule.clone()));
-		assert_last_event::<T, I>(Event::NewRuleSet { rule }.into());
+		assert_last_event::<T, I>(Event::NewRuleSet { rule, previous: None }.into());
 		Ok(())
 	}
 
@@ -617,13 +270,13 @@ mod benchmarks {
 
 		let outsider = outsider::<T, I>(1);
 		assert!(!Alliance::<T, I>::is_member(&outsider));
-		assert_eq!(DepositOf::<T, I>::get(&outsider), None);
+		assert_eq!(Alliance::<T, I>::deposit_of(&outsider), None);
 
 		#[extrinsic_call]
 		_(SystemOrigin::Signed(outsider.clone()));
 
 		assert!(Alliance::<T, I>::is_member_of(&outsider, MemberRole::Ally)); // outsider is now an ally
-		assert_eq!(DepositOf::<T, I>::get(&outsider), Some(T::AllyDeposit::get())); // with a deposit
+		assert_eq!(Alliance::<T, I>::deposit_of(&outsider), Some(T::AllyDeposit::get())); // with a deposit
 		assert!(!Alliance::<T, I>::has_voting_rights(&outsider)); // allies don't have voting rights
 		assert_last_event::<T, I>(
 			Event::NewAllyJoined {
@@ -645,15 +298,25 @@ mod benchmarks {
 
 		let outsider = outsider::<T, I>(1);
 		assert!(!Alliance::<T, I>::is_member(&outsider));
-		assert_eq!(DepositOf::<T, I>::get(&outsider), None);
+		assert_eq!(Alliance::<T, I>::deposit_of(&outsider), None);
 
 		let outsider_lookup = T::Lookup::unlookup(outsider.clone());
 
+		// The worst case uses up the nomination quota of the fellow.
+		let quota = T::NominationQuota::get();
+		if let Some(quota) = quota {
+			let period = Alliance::<T, I>::nomination_period();
+			NominationsUsed::<T, I>::insert(&fellow1, (period, quota.saturating_sub(1)));
+		}
+
 		#[extrinsic_call]
 		_(SystemOrigin::Signed(fellow1.clone()), outsider_lookup);
 
+		if let Some(quota) = quota {
+			assert_eq!(NominationsUsed::<T, I>::get(&fellow1).map(|(_, used)| used), Some(quota));
+		}
 		assert!(Alliance::<T, I>::is_member_of(&outsider, MemberRole::Ally)); // outsider is now an ally
-		assert_eq!(DepositOf::<T, I>::get(&outsider), None); // without a deposit
+		assert_eq!(Alliance::<T, I>::deposit_of(&outsider), None); // without a deposit
 		assert!(!Alliance::<T, I>::has_voting_rights(&outsider)); // allies don't have voting rights
 		assert_last_event::<T, I>(
 			Event::NewAllyJoined { ally: outsider, nominator: Some(fellow1), reserved: None }
@@ -712,6 +375,9 @@ mod benchmarks {
 
 		let fellow2 = fellow::<T, I>(2);
 		assert!(Alliance::<T, I>::has_voting_rights(&fellow2));
+		// Worst case: the fellow gives its notice before the minimum tenure and is slashed.
+		let notice = System::<T>::block_number();
+		MemberSince::<T, I>::insert(&fellow2, notice);
 
 		assert_eq!(
 			Alliance::<T, I>::give_retirement_notice(SystemOrigin::Signed(fellow2.clone()).into()),
@@ -719,16 +385,22 @@ mod benchmarks {
 		);
 		System::<T>::set_block_number(System::<T>::block_number() + T::RetirementPeriod::get());
 
-		assert_eq!(DepositOf::<T, I>::get(&fellow2), Some(T::AllyDeposit::get()));
+		let deposit = T::AllyDeposit::get();
+		assert_eq!(Alliance::<T, I>::deposit_of(&fellow2), Some(deposit));
+		let unreserved =
+			deposit - Alliance::<T, I>::early_retirement_slash(&fellow2, deposit, notice);
 
 		#[extrinsic_call]
 		_(SystemOrigin::Signed(fellow2.clone()));
 
 		assert!(!Alliance::<T, I>::is_member(&fellow2));
-		assert_eq!(DepositOf::<T, I>::get(&fellow2), None);
+		assert_eq!(Alliance::<T, I>::deposit_of(&fellow2), None);
 		assert_last_event::<T, I>(
-			Event::MemberRetired { member: fellow2, unreserved: Some(T::AllyDeposit::get()) }
-				.into(),
+			Event::MemberRetired {
+				member: fellow2,
+				unreserved: Some(unreserved).filter(|unreserved| !unreserved.is_zero()),
+			}
+			.into(),
 		);
 		Ok(())
 	}
@@ -739,7 +411,7 @@ mod benchmarks {
 
 		let fellow2 = fellow::<T, I>(2);
 		assert!(Alliance::<T, I>::is_member_of(&fellow2, MemberRole::Fellow));
-		assert_eq!(DepositOf::<T, I>::get(&fellow2), Some(T::AllyDeposit::get()));
+		assert_eq!(Alliance::<T, I>::deposit_of(&fellow2), Some(T::AllyDeposit::get()));
 
 		let fellow2_lookup = T::Lookup::unlookup(fellow2.clone());
 		let call = Call::<T, I>::kick_member { who: fellow2_lookup };
@@ -752,7 +424,7 @@ mod benchmarks {
 		}
 
 		assert!(!Alliance::<T, I>::is_member(&fellow2));
-		assert_eq!(DepositOf::<T, I>::get(&fellow2), None);
+		assert_eq!(Alliance::<T, I>::deposit_of(&fellow2), None);
 		assert_last_event::<T, I>(
 			Event::MemberKicked { member: fellow2, slashed: Some(T::AllyDeposit::get()) }.into(),
 		);
@@ -773,11 +445,18 @@ mod benchmarks {
 			})
 			.collect::<Vec<_>>();
 
+		// Every item carries evidence as the worst case.
 		let mut unscrupulous_list = Vec::with_capacity(accounts.len() + websites.len());
 		unscrupulous_list.extend(accounts.into_iter().map(UnscrupulousItem::AccountId));
 		unscrupulous_list.extend(websites.into_iter().map(UnscrupulousItem::Website));
-
-		let call = Call::<T, I>::add_unscrupulous_items { items: unscrupulous_list.clone() };
+		let unscrupulous_list: Vec<_> = unscrupulous_list
+			.into_iter()
+			.enumerate()
+			.map(|(i, item)| (item, Some(cid(i.to_le_bytes()))))
+			.collect();
+
+		let call =
+			Call::<T, I>::add_unscrupulous_items_with_evidence { items: unscrupulous_list.clone() };
 		let origin = T::AnnouncementOrigin::try_successful_origin()
 			.map_err(|_| BenchmarkError::Weightless)?;
 
@@ -815,6 +494,9 @@ mod benchmarks {
 		let mut unscrupulous_list = Vec::with_capacity(accounts.len() + websites.len());
 		unscrupulous_list.extend(accounts.into_iter().map(UnscrupulousItem::AccountId));
 		unscrupulous_list.extend(websites.into_iter().map(UnscrupulousItem::Website));
+		for (i, item) in unscrupulous_list.iter().enumerate() {
+			UnscrupulousEvidence::<T, I>::insert(item, cid(i.to_le_bytes()));
+		}
 
 		let call = Call::<T, I>::remove_unscrupulous_items { items: unscrupulous_list.clone() };
 		let origin = T::AnnouncementOrigin::try_successful_origin()
@@ -844,5 +526,314 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn suspend_member() -> Result<(), BenchmarkError> {
+		set_members::<T, I>();
+
+		let fellow2 = fellow::<T, I>(2);
+		let until = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		let fellow2_lookup = T::Lookup::unlookup(fellow2.clone());
+		let call = Call::<T, I>::suspend_member { who: fellow2_lookup, until };
+		let origin = T::MembershipManager::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+
+		#[block]
+		{
+			call.dispatch_bypass_filter(origin)?;
+		}
+
+		assert!(Alliance::<T, I>::is_suspended(&fellow2));
+		assert_last_event::<T, I>(Event::MemberSuspended { member: fellow2, until }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn unsuspend_member() -> Result<(), BenchmarkError> {
+		set_members::<T, I>();
+
+		let fellow2 = fellow::<T, I>(2);
+		let until = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		SuspendedMembers::<T, I>::insert(&fellow2, until);
+		let fellow2_lookup = T::Lookup::unlookup(fellow2.clone());
+		let call = Call::<T, I>::unsuspend_member { who: fellow2_lookup };
+		let origin = T::MembershipManager::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+
+		#[block]
+		{
+			call.dispatch_bypass_filter(origin)?;
+		}
+
+		assert!(!Alliance::<T, I>::is_suspended(&fellow2));
+		assert_last_event::<T, I>(Event::MemberUnsuspended { member: fellow2 }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn create_sub_committee(
+		m: Linear<1, { T::MaxSubCommitteeMembers::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let fellows = (0..m).map(fellow::<T, I>).collect::<Vec<_>>();
+		Alliance::<T, I>::init_members(SystemOrigin::Root.into(), fellows.clone(), vec![])?;
+
+		let expiry = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		let members = fellows.iter().cloned().map(T::Lookup::unlookup).collect();
+		let call = Call::<T, I>::create_sub_committee {
+			members,
+			power: SubCommitteePower::UnscrupulousItems,
+			expiry,
+		};
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[block]
+		{
+			call.dispatch_bypass_filter(origin)?;
+		}
+
+		let mut members = fellows;
+		members.sort();
+		assert_last_event::<T, I>(
+			Event::SubCommitteeCreated {
+				index: 0,
+				members,
+				power: SubCommitteePower::UnscrupulousItems,
+				expiry,
+			}
+			.into(),
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn revoke_sub_committee() -> Result<(), BenchmarkError> {
+		set_members::<T, I>();
+
+		let members = vec![fellow::<T, I>(1)].try_into().unwrap();
+		let expiry = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		SubCommittees::<T, I>::insert(
+			0,
+			SubCommittee { members, power: SubCommitteePower::UnscrupulousItems, expiry },
+		);
+		let call = Call::<T, I>::revoke_sub_committee { index: 0 };
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[block]
+		{
+			call.dispatch_bypass_filter(origin)?;
+		}
+
+		assert!(!SubCommittees::<T, I>::contains_key(0));
+		assert_last_event::<T, I>(Event::SubCommitteeRevoked { index: 0 }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn flag_unreachable_cid() -> Result<(), BenchmarkError> {
+		// Worst case: the CID is not the rule and is searched in the full announcements.
+		Rule::<T, I>::put(rule(b"rule"));
+		let mut announcements = (0..T::MaxAnnouncementsCount::get())
+			.map(|i| announcement(i.encode()))
+			.collect::<Vec<_>>();
+		announcements.sort();
+		let cid = announcements[0].clone();
+		Announcements::<T, I>::put(BoundedVec::try_from(announcements).unwrap());
+
+		set_members::<T, I>();
+		let flagger = fellow::<T, I>(1);
+		let call = Call::<T, I>::flag_unreachable_cid { cid: cid.clone() };
+		let origin = SystemOrigin::Signed(flagger).into();
+
+		#[block]
+		{
+			call.dispatch_bypass_filter(origin)?;
+		}
+
+		assert!(FlaggedCids::<T, I>::contains_key(&cid));
+		assert_last_event::<T, I>(Event::CidFlagged { cid }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn unflag_cid() -> Result<(), BenchmarkError> {
+		let cid = rule(b"hello world");
+		FlaggedCids::<T, I>::insert(&cid, System::<T>::block_number());
+
+		let call = Call::<T, I>::unflag_cid { cid: cid.clone() };
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[block]
+		{
+			call.dispatch_bypass_filter(origin)?;
+		}
+
+		assert!(!FlaggedCids::<T, I>::contains_key(&cid));
+		assert_last_event::<T, I>(Event::CidUnflagged { cid }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn force_import_snapshot(
+		m: Linear<1, { T::MaxFellows::get() }>,
+		u: Linear<0, { T::MaxUnscrupulousItems::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let fellows = (0..m).map(fellow::<T, I>).collect::<Vec<_>>();
+		let deposits = fellows.iter().map(|who| (who.clone(), T::AllyDeposit::get())).collect();
+		let snapshot = AllianceSnapshot::V1(SnapshotV1 {
+			members: vec![(MemberRole::Fellow, fellows)],
+			deposits,
+			rule: Some(rule(b"hello world")),
+			unscrupulous_accounts: (0..u).map(generate_unscrupulous_account::<T, I>).collect(),
+			unscrupulous_websites: vec![],
+		});
+		let hash = T::Hashing::hash_of(&snapshot);
+
+		#[extrinsic_call]
+		_(SystemOrigin::Root, snapshot);
+
+		assert_eq!(ImportedSnapshot::<T, I>::get(), Some(hash));
+		assert_last_event::<T, I>(Event::SnapshotImported { hash, members: m }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn schedule_enactment() -> Result<(), BenchmarkError> {
+		let origin =
+			T::EnactmentOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let proposal = remark::<T, I>(b"hello world");
+		if proposal.get_dispatch_info().weight.any_gt(T::AutoCloseWeight::get()) {
+			return Err(BenchmarkError::Weightless)
+		}
+		fill_pending_enactments::<T, I>(&origin, T::MaxProposals::get() - 1)?;
+
+		let index = NextEnactmentIndex::<T, I>::get();
+		let proposal_hash = T::Hashing::hash_of(&proposal);
+		let when = System::<T>::block_number().saturating_add(T::EnactmentDelay::get());
+		let call = Call::<T, I>::schedule_enactment { proposal: Box::new(proposal) };
+
+		#[block]
+		{
+			call.dispatch_bypass_filter(origin)?;
+		}
+
+		assert_eq!(
+			PendingEnactments::<T, I>::decode_len(when),
+			Some(T::MaxProposals::get() as usize)
+		);
+		assert_last_event::<T, I>(Event::EnactmentScheduled { index, proposal_hash, when }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_enactment(p: Linear<1, { T::MaxProposals::get() }>) -> Result<(), BenchmarkError> {
+		let enactment_origin =
+			T::EnactmentOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		fill_pending_enactments::<T, I>(&enactment_origin, p)?;
+
+		// Cancel the most recently scheduled enactment, which is the last one in the queue.
+		let when = System::<T>::block_number().saturating_add(T::EnactmentDelay::get());
+		let index = NextEnactmentIndex::<T, I>::get() - 1;
+		let call = Call::<T, I>::cancel_enactment { when, index };
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[block]
+		{
+			call.dispatch_bypass_filter(origin)?;
+		}
+
+		assert!(PendingEnactments::<T, I>::get(when)
+			.iter()
+			.all(|enactment| enactment.index != index));
+		assert_last_event::<T, I>(Event::EnactmentCancelled { index }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn enforce_unscrupulous() -> Result<(), BenchmarkError> {
+		set_members::<T, I>();
+
+		// The member is listed among a full list of unscrupulous accounts as the worst case.
+		let fellow2 = fellow::<T, I>(2);
+		let mut accounts = (1..T::MaxUnscrupulousItems::get())
+			.map(|i| generate_unscrupulous_account::<T, I>(i))
+			.collect::<Vec<_>>();
+		accounts.push(fellow2.clone());
+		accounts.sort();
+		let accounts: BoundedVec<_, T::MaxUnscrupulousItems> = accounts.try_into().unwrap();
+		UnscrupulousAccounts::<T, I>::put(accounts);
+
+		let caller: T::AccountId = whitelisted_caller();
+		let fellow2_lookup = T::Lookup::unlookup(fellow2.clone());
+
+		#[extrinsic_call]
+		_(SystemOrigin::Signed(caller), fellow2_lookup);
+
+		match T::UnscrupulousMemberPenalty::get() {
+			UnscrupulousPenalty::Kick => assert!(!Alliance::<T, I>::is_member(&fellow2)),
+			UnscrupulousPenalty::Suspend(_) => assert!(Alliance::<T, I>::is_suspended(&fellow2)),
+		}
+		Ok(())
+	}
+
+	#[benchmark]
+	fn queue_decided_proposal() -> Result<(), BenchmarkError> {
+		set_members::<T, I>();
+
+		// The proposal is approved by both fellows. Without a voting backend, nothing is queued.
+		let proposal = remark::<T, I>(b"hello world");
+		let length = proposal.encoded_size() as u32;
+		let proposal_hash = T::Hashing::hash_of(&proposal);
+		T::VotingProvider::propose(fellow::<T, I>(1), 2, Box::new(proposal), length)
+			.map_err(|_| BenchmarkError::Weightless)?;
+		for i in 1..=2 {
+			T::VotingProvider::vote(fellow::<T, I>(i), proposal_hash, 0, true.into())?;
+		}
+
+		// The queue is searched for the proposal before it is appended.
+		let queued = (1..T::MaxProposals::get())
+			.map(|i| (T::Hashing::hash_of(&i), i))
+			.collect::<Vec<_>>();
+		DecidedProposals::<T, I>::put(BoundedVec::truncate_from(queued));
+
+		#[block]
+		{
+			Alliance::<T, I>::queue_if_decided(proposal_hash, 0);
+		}
+
+		assert_eq!(DecidedProposals::<T, I>::get().last(), Some(&(proposal_hash, 0)));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn ensure_sub_committee() -> Result<(), BenchmarkError> {
+		// The caller is the last member of a full sub-committee.
+		let fellows = (0..T::MaxSubCommitteeMembers::get()).map(fellow::<T, I>).collect::<Vec<_>>();
+		Alliance::<T, I>::init_members(SystemOrigin::Root.into(), fellows.clone(), vec![])?;
+		let caller = fellows.last().cloned().unwrap();
+		let expiry = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		SubCommittees::<T, I>::insert(
+			0,
+			SubCommittee {
+				members: fellows.try_into().unwrap(),
+				power: SubCommitteePower::Announcements,
+				expiry,
+			},
+		);
+
+		#[block]
+		{
+			Alliance::<T, I>::ensure_sub_committee(
+				SystemOrigin::Signed(caller).into(),
+				0,
+				SubCommitteePower::Announcements,
+			)?;
+		}
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Alliance, crate::mock::new_bench_ext(), crate::mock::Test);
 }
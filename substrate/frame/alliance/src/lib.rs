@@ -28,11 +28,17 @@
 //!
 //! The network initializes the Alliance via a Root call. After that, anyone with an approved
 //! identity and website can join as an Ally. The `MembershipManager` origin can elevate Allies to
-//! Fellows, giving them voting rights within the Alliance.
+//! Fellows, giving them voting rights within the Alliance. Allies may rely on the identity of their
+//! super account, but Fellows need a judged identity of their own, which is checked again on
+//! elevation.
 //!
 //! Voting members of the Alliance maintain a list of accounts and websites. Members can also vote
 //! to update the Alliance's rule and make announcements.
 //!
+//! If an `EnactmentDelay` is configured, approved proposals are not dispatched right away but
+//! queued for enactment once the delay has passed, leaving the `AdminOrigin` time to cancel
+//! malicious ones.
+//!
 //! ### Terminology
 //!
 //! - Rule: The IPFS CID (hash) of the Alliance rules for the community to read and the Alliance
@@ -46,6 +52,8 @@
 //!   by placing a deposit or by nomination from a voting member.
 //! - Unscrupulous List: A list of bad websites and addresses; items can be added or removed by
 //!   voting members.
+//! - Sub-Committee: A group of Fellows that the Alliance delegated a restricted power to, e.g.
+//!   maintaining the unscrupulous list, so that routine work doesn't require a motion every time.
 //!
 //! ## Interface
 //!
@@ -54,12 +62,17 @@
 //! #### For General Users
 //!
 //! - `join_alliance` - Join the Alliance as an Ally. This requires a slashable deposit.
+//! - `enforce_unscrupulous` - Kick or suspend a member listed as unscrupulous, as configured by
+//!   `UnscrupulousMemberPenalty`.
+//! - `continue_disband` - Remove more members of a disbanding Alliance and release their deposits.
 //!
 //! #### For Members (All)
 //!
 //! - `give_retirement_notice` - Give a retirement notice and start a retirement period required to
 //!   pass in order to retire.
 //! - `retire` - Retire from the Alliance and release the caller's deposit.
+//! - `flag_unreachable_cid` - Flag the CID of the rule or an announcement as unreachable on IPFS.
+//!   Usually submitted by the off-chain worker, see the [`offchain`] module.
 //!
 //! #### For Voting Members
 //!
@@ -71,15 +84,37 @@
 //! - `nominate_ally` - Nominate a non-member to become an Ally, without deposit.
 //! - `elevate_ally` - Approve an ally to become a Fellow.
 //! - `kick_member` - Kick a member and slash its deposit.
+//! - `suspend_member` - Suspend a member's privileges until a given block.
+//! - `unsuspend_member` - Lift a member's suspension before it lapses.
 //! - `add_unscrupulous_items` - Add some items, either accounts or websites, to the list of
 //!   unscrupulous items.
+//! - `add_unscrupulous_items_with_evidence` - Add some items to the list of unscrupulous items,
+//!   each with an optional IPFS CID of the evidence against it.
 //! - `remove_unscrupulous_items` - Remove some items from the list of unscrupulous items.
 //! - `abdicate_fellow_status` - Abdicate one's voting rights, demoting themself to Ally.
+//! - `schedule_enactment` - Queue an approved proposal for enactment. Proposals are wrapped into it
+//!   by `propose` if there is an `EnactmentDelay`.
+//!
+//! #### For Sub-Committee Members
+//!
+//! - `delegated_add_unscrupulous_items` - Add some items to the list of unscrupulous items.
+//! - `delegated_add_unscrupulous_items_with_evidence` - Add some items to the list of unscrupulous
+//!   items, each with an optional IPFS CID of the evidence against it.
+//! - `delegated_remove_unscrupulous_items` - Remove some items from the list of unscrupulous items.
+//! - `delegated_announce` - Make announcement by IPFS CID.
+//!
+//! #### For the Admin Origin
+//!
+//! - `create_sub_committee` - Delegate a power to a group of fellows until a given block.
+//! - `revoke_sub_committee` - Revoke a sub-committee. Anyone can remove an expired one.
+//! - `unflag_cid` - Remove a CID from the list of CIDs flagged as unreachable.
+//! - `cancel_enactment` - Cancel the enactment of an approved proposal.
 //!
 //! #### Root Calls
 //!
 //! - `init_members` - Initialize the Alliance, onboard fellows and allies.
-//! - `disband` - Disband the Alliance, remove all active members and unreserve deposits.
+//! - `disband` - Disband the Alliance, remove all active members and release deposits. Large
+//!   Alliances are disbanded over several calls, see `continue_disband`.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -91,31 +126,44 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 pub mod migration;
+pub mod offchain;
+pub mod snapshot;
 mod types;
+pub mod unscrupulous;
+pub mod voting;
 pub mod weights;
 
-use codec::{Decode, Encode, MaxEncodedLen};
+use codec::{Codec, Decode, Encode, MaxEncodedLen};
 use frame_support::pallet_prelude::*;
 use frame_system::pallet_prelude::*;
 use sp_runtime::{
-	traits::{Dispatchable, Saturating, StaticLookup, Zero},
-	DispatchError, RuntimeDebug,
+	traits::{CheckedDiv, Dispatchable, Hash as _, Saturating, StaticLookup, Zero},
+	DispatchError, Perbill, RuntimeDebug,
 };
 use sp_std::{convert::TryInto, prelude::*};
 
 use frame_support::{
-	dispatch::{DispatchResult, DispatchResultWithPostInfo, GetDispatchInfo, PostDispatchInfo},
+	dispatch::{
+		extract_actual_weight, DispatchResult, DispatchResultWithPostInfo, GetDispatchInfo,
+		PostDispatchInfo,
+	},
 	ensure,
 	traits::{
-		ChangeMembers, Currency, Get, InitializeMembers, IsSubType, OnUnbalanced,
-		ReservableCurrency,
+		fungible::{self, BalancedHold, DepositLedger, MutateHold},
+		ChangeMembers, Contains, DefensiveTruncateFrom, EnsureOrigin, Get, InitializeMembers,
+		IsSubType, OnUnbalanced, OriginTrait,
 	},
 	weights::Weight,
 };
 use scale_info::TypeInfo;
 
 pub use pallet::*;
+pub use snapshot::{AllianceSnapshot, SnapshotV1};
 pub use types::*;
+pub use unscrupulous::{UnscrupulousAggregate, UnscrupulousList};
+#[cfg(feature = "collective")]
+pub use voting::CollectiveVoting;
+pub use voting::{ProposalOutcome, ProposalTally, VotingProvider};
 pub use weights::*;
 
 /// The log target of this pallet.
@@ -124,13 +172,34 @@ pub const LOG_TARGET: &str = "runtime::alliance";
 /// Simple index type for proposal counting.
 pub type ProposalIndex = u32;
 
+type VoteOf<T, I> = <<T as Config<I>>::VotingProvider as VotingProvider<
+	<T as frame_system::Config>::AccountId,
+	<T as frame_system::Config>::Hash,
+	<T as Config<I>>::Proposal,
+>>::Vote;
+
 type UrlOf<T, I> = BoundedVec<u8, <T as pallet::Config<I>>::MaxWebsiteUrlLength>;
 
-type BalanceOf<T, I> =
-	<<T as Config<I>>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
-type NegativeImbalanceOf<T, I> = <<T as Config<I>>::Currency as Currency<
+type BalanceOf<T, I> = <<T as Config<I>>::Currency as fungible::Inspect<
 	<T as frame_system::Config>::AccountId,
->>::NegativeImbalance;
+>>::Balance;
+type CreditOf<T, I> =
+	fungible::Credit<<T as frame_system::Config>::AccountId, <T as Config<I>>::Currency>;
+
+/// The deposits of members, held for [`HoldReason::AllyDeposit`].
+type DepositsOf<T, I> = DepositLedger<
+	<T as frame_system::Config>::AccountId,
+	<T as Config<I>>::Currency,
+	AllyDepositReason<T, I>,
+>;
+
+/// The runtime hold reason of [`HoldReason::AllyDeposit`].
+pub struct AllyDepositReason<T, I = ()>(PhantomData<(T, I)>);
+impl<T: Config<I>, I: 'static> Get<T::RuntimeHoldReason> for AllyDepositReason<T, I> {
+	fn get() -> T::RuntimeHoldReason {
+		HoldReason::<I>::AllyDeposit.into()
+	}
+}
 
 /// Interface required for identity verification.
 pub trait IdentityVerifier<AccountId> {
@@ -161,44 +230,126 @@ impl<AccountId> IdentityVerifier<AccountId> for () {
 	}
 }
 
-/// The provider of a collective action interface, for example an instance of `pallet-collective`.
-pub trait ProposalProvider<AccountId, Hash, Proposal> {
-	/// Add a new proposal.
-	/// Returns a proposal length and active proposals count if successful.
-	fn propose_proposal(
-		who: AccountId,
-		threshold: u32,
-		proposal: Box<Proposal>,
-		length_bound: u32,
-	) -> Result<(u32, u32), DispatchError>;
-
-	/// Add an aye or nay vote for the sender to the given proposal.
-	/// Returns true if the sender votes first time if successful.
-	fn vote_proposal(
-		who: AccountId,
-		proposal: Hash,
-		index: ProposalIndex,
-		approve: bool,
-	) -> Result<bool, DispatchError>;
-
-	/// Close a proposal that is either approved, disapproved, or whose voting period has ended.
-	fn close_proposal(
-		proposal_hash: Hash,
-		index: ProposalIndex,
-		proposal_weight_bound: Weight,
-		length_bound: u32,
-	) -> DispatchResultWithPostInfo;
-
-	/// Return a proposal of the given hash.
-	fn proposal_of(proposal_hash: Hash) -> Option<Proposal>;
-}
-
 /// The various roles that a member can hold.
 #[derive(Copy, Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
 pub enum MemberRole {
+	#[codec(index = 0)]
 	Fellow,
+	#[codec(index = 1)]
 	Ally,
+	#[codec(index = 2)]
 	Retiring,
+	/// An additional tier defined by the [`RolePolicy`] of the Alliance, e.g. Observer.
+	#[codec(index = 3)]
+	Custom(u8),
+}
+
+/// The roles of an Alliance and the permissions attached to them.
+///
+/// The roles form an ordered set: members join with the lowest role, can be elevated to the
+/// next higher one and voting members can step down to the highest role without voting rights.
+/// [`MemberRole::Retiring`] is not part of the set, every member can give a retirement notice.
+pub trait RolePolicy {
+	/// The roles members can hold, from the lowest to the highest.
+	fn roles() -> Vec<MemberRole>;
+
+	/// Whether members holding `role` can propose, vote and nominate new members.
+	fn has_voting_rights(role: MemberRole) -> bool;
+}
+
+/// The two-tier Alliance of Allies and Fellows, where only Fellows have voting rights.
+impl RolePolicy for () {
+	fn roles() -> Vec<MemberRole> {
+		vec![MemberRole::Ally, MemberRole::Fellow]
+	}
+
+	fn has_voting_rights(role: MemberRole) -> bool {
+		role == MemberRole::Fellow
+	}
+}
+
+/// A power the Alliance can delegate to a sub-committee.
+#[derive(Copy, Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub enum SubCommitteePower {
+	/// Add and remove unscrupulous items.
+	UnscrupulousItems,
+	/// Make announcements.
+	Announcements,
+}
+
+/// What happens to a member whose account is listed as unscrupulous.
+#[derive(
+	Copy, Clone, PartialEq, Eq, Default, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen,
+)]
+pub enum UnscrupulousPenalty<BlockNumber> {
+	/// The member is kicked out of the Alliance.
+	#[default]
+	Kick,
+	/// The member is suspended for the given number of blocks.
+	Suspend(BlockNumber),
+}
+
+/// A group of fellows acting on behalf of the Alliance with a delegated power.
+#[derive(
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	RuntimeDebugNoBound,
+	Encode,
+	Decode,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T, I))]
+#[codec(mel_bound())]
+pub struct SubCommittee<T: Config<I>, I: 'static = ()> {
+	/// The fellows that can exercise the power, each on their own.
+	pub members: BoundedVec<T::AccountId, T::MaxSubCommitteeMembers>,
+	/// The delegated power.
+	pub power: SubCommitteePower,
+	/// The block from which on the sub-committee can no longer exercise its power.
+	pub expiry: BlockNumberFor<T>,
+}
+
+/// Index of a sub-committee.
+pub type SubCommitteeIndex = u32;
+
+/// Index of an enactment of an approved proposal.
+pub type EnactmentIndex = u32;
+
+/// An approved proposal waiting for its enactment.
+#[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo)]
+pub struct Enactment<Origin, Proposal, BlockNumber> {
+	/// The index to cancel the enactment with.
+	pub index: EnactmentIndex,
+	/// The block from which on the proposal is enacted.
+	pub when: BlockNumber,
+	/// The origin the proposal was approved with, which it is dispatched with.
+	pub origin: Origin,
+	/// The approved proposal.
+	pub proposal: Proposal,
+}
+
+type EnactmentOf<T, I> =
+	Enactment<<T as Config<I>>::PalletsOrigin, <T as Config<I>>::Proposal, BlockNumberFor<T>>;
+
+/// Ensures the origin with `Inner` and returns its caller, for [`Config::EnactmentOrigin`].
+pub struct WithCaller<Inner>(PhantomData<Inner>);
+impl<O: OriginTrait, Inner: EnsureOrigin<O>> EnsureOrigin<O> for WithCaller<Inner>
+where
+	O::PalletsOrigin: Clone,
+{
+	type Success = O::PalletsOrigin;
+
+	fn try_origin(o: O) -> Result<Self::Success, O> {
+		let caller = o.caller().clone();
+		Inner::try_origin(o).map(|_| caller)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<O, ()> {
+		Inner::try_successful_origin()
+	}
 }
 
 /// The type of item that may be deemed unscrupulous.
@@ -208,9 +359,39 @@ pub enum UnscrupulousItem<AccountId, Url> {
 	Website(Url),
 }
 
+/// The deposit needed for an account to join the Alliance as an Ally.
+#[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo)]
+pub struct DepositInfo<Balance> {
+	/// The amount that will be reserved from the joining account.
+	pub deposit: Balance,
+	/// The number of Allies that can still join before the limit is reached.
+	pub remaining_capacity: u32,
+}
+
+/// The reason why an account cannot join the Alliance as an Ally.
+#[derive(Copy, Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo)]
+pub enum JoinBlocker {
+	/// The Alliance has not been initialized yet.
+	NotInitialized,
+	/// The account is listed as unscrupulous.
+	AccountNonGrata,
+	/// The account is already a member.
+	AlreadyMember,
+	/// Neither the account nor its super account has the required identity fields with a good
+	/// judgement.
+	MissingIdentity,
+	/// The account cannot reserve the deposit.
+	InsufficientFunds,
+	/// The maximum number of Allies has been reached.
+	TooManyMembers,
+}
+
 type UnscrupulousItemOf<T, I> =
 	UnscrupulousItem<<T as frame_system::Config>::AccountId, UrlOf<T, I>>;
 
+/// An unscrupulous item to be listed, with the CID of the evidence against it, if any.
+type UnscrupulousEntryOf<T, I> = (UnscrupulousItemOf<T, I>, Option<Cid>);
+
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
 
 #[frame_support::pallet]
@@ -236,6 +417,11 @@ pub mod pallet {
 			+ IsSubType<Call<Self, I>>
 			+ IsType<<Self as frame_system::Config>::RuntimeCall>;
 
+		/// The calls that may be proposed to the Alliance.
+		///
+		/// Use [`Everything`](frame_support::traits::Everything) to allow any call.
+		type ProposalFilter: Contains<Self::Proposal>;
+
 		/// Origin for admin-level operations, like setting the Alliance's rules.
 		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
@@ -245,11 +431,17 @@ pub mod pallet {
 		/// Origin for making announcements and adding/removing unscrupulous items.
 		type AnnouncementOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// The overarching hold reason.
+		type RuntimeHoldReason: From<HoldReason<I>>;
+
 		/// The currency used for deposits.
-		type Currency: ReservableCurrency<Self::AccountId>;
+		type Currency: fungible::Mutate<Self::AccountId>
+			+ MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>
+			+ BalancedHold<Self::AccountId>;
 
-		/// What to do with slashed funds.
-		type Slashed: OnUnbalanced<NegativeImbalanceOf<Self, I>>;
+		/// What to do with slashed funds, of kicked members, of members listed as unscrupulous and
+		/// of members retiring before the `MinimumTenure`.
+		type Slashed: OnUnbalanced<CreditOf<Self, I>>;
 
 		/// What to do with initial voting members of the Alliance.
 		type InitializeMembers: InitializeMembers<Self::AccountId>;
@@ -258,10 +450,20 @@ pub mod pallet {
 		type MembershipChanged: ChangeMembers<Self::AccountId>;
 
 		/// The identity verifier of an Alliance member.
+		///
+		/// Use `()` to not require any identity.
 		type IdentityVerifier: IdentityVerifier<Self::AccountId>;
 
-		/// The provider of the proposal operation.
-		type ProposalProvider: ProposalProvider<Self::AccountId, Self::Hash, Self::Proposal>;
+		/// The roles members can hold and their permissions.
+		///
+		/// Use `()` for the two-tier Alliance of Allies and Fellows.
+		type RolePolicy: RolePolicy;
+
+		/// The backend the Fellows vote on proposals with.
+		///
+		/// Use `CollectiveVoting` to vote in an instance of `pallet-collective`, with the
+		/// `collective` feature.
+		type VotingProvider: VotingProvider<Self::AccountId, Self::Hash, Self::Proposal>;
 
 		/// Maximum number of proposals allowed to be active in parallel.
 		type MaxProposals: Get<ProposalIndex>;
@@ -306,6 +508,95 @@ pub mod pallet {
 		/// The number of blocks a member must wait between giving a retirement notice and retiring.
 		/// Supposed to be greater than time required to `kick_member`.
 		type RetirementPeriod: Get<BlockNumberFor<Self>>;
+
+		/// The maximum number of sub-committees that can exist at the same time.
+		#[pallet::constant]
+		type MaxSubCommittees: Get<u32>;
+
+		/// The maximum number of members of a sub-committee.
+		#[pallet::constant]
+		type MaxSubCommitteeMembers: Get<u32>;
+
+		/// Whether proposals are closed automatically in `on_initialize` once their outcome is
+		/// final, without waiting for someone to call `close`.
+		#[pallet::constant]
+		type AutoClose: Get<bool>;
+
+		/// The maximum weight `on_initialize` may spend on closing decided proposals and on
+		/// enacting approved ones, including the weight of the proposals themselves.
+		#[pallet::constant]
+		type AutoCloseWeight: Get<Weight>;
+
+		/// The maximum number of past rules kept in [`RuleHistory`]. The oldest entry is dropped
+		/// once the history is full.
+		#[pallet::constant]
+		type MaxRuleHistory: Get<u32>;
+
+		/// Signs the transactions of the off-chain worker flagging unreachable CIDs, see
+		/// [`offchain`].
+		///
+		/// `()` disables the off-chain worker, [`offchain::SignedBy`] signs with keys from the
+		/// node's keystore.
+		type FlagSigner: offchain::FlagSigner<Self, I>;
+
+		/// The number of allies a single fellow can nominate per nomination period, unlimited if
+		/// `None`.
+		#[pallet::constant]
+		type NominationQuota: Get<Option<u32>>;
+
+		/// The length of a nomination period in blocks. Periods start at the multiples of it.
+		///
+		/// Must not be zero while a `NominationQuota` is set.
+		#[pallet::constant]
+		type NominationPeriod: Get<BlockNumberFor<Self>>;
+
+		/// The number of blocks a member has to spend in the Alliance before retiring to get its
+		/// whole deposit back, counted from when it joined or was last elevated until it gave its
+		/// retirement notice. Members giving their notice earlier lose the `EarlyRetirementSlash`
+		/// share of their deposit when they retire. Never slashed if `None`.
+		#[pallet::constant]
+		type MinimumTenure: Get<Option<BlockNumberFor<Self>>>;
+
+		/// The share of the deposit slashed from members retiring before the `MinimumTenure`.
+		#[pallet::constant]
+		type EarlyRetirementSlash: Get<Perbill>;
+
+		/// What happens to members whose account is listed as unscrupulous, when it is listed or
+		/// later with [`Call::enforce_unscrupulous`].
+		#[pallet::constant]
+		type UnscrupulousMemberPenalty: Get<UnscrupulousPenalty<BlockNumberFor<Self>>>;
+
+		/// The share of the deposit slashed from members whose account is listed as unscrupulous.
+		/// The rest of the deposit of kicked members is released.
+		#[pallet::constant]
+		type UnscrupulousMemberSlash: Get<Perbill>;
+
+		/// The number of blocks after a suspended member was penalized for being listed as
+		/// unscrupulous before it can be penalized again with [`Call::enforce_unscrupulous`].
+		#[pallet::constant]
+		type UnscrupulousPenaltyCooldown: Get<BlockNumberFor<Self>>;
+
+		/// The number of blocks approved proposals wait for their enactment, during which the
+		/// `AdminOrigin` can cancel them. Approved proposals are dispatched right away if zero.
+		#[pallet::constant]
+		type EnactmentDelay: Get<BlockNumberFor<Self>>;
+
+		/// The origin of approved proposals, which may queue them for enactment. Its success is
+		/// the caller the proposals are dispatched with.
+		///
+		/// Use [`WithCaller`] around the origin of approvals of the voting backend, e.g.
+		/// `pallet_collective::EnsureMembers`.
+		type EnactmentOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::PalletsOrigin>;
+
+		/// The caller origin, overarching type of all pallets origins.
+		type PalletsOrigin: Parameter + Into<<Self as frame_system::Config>::RuntimeOrigin>;
+	}
+
+	/// A reason for the pallet placing a hold on funds.
+	#[pallet::composite_enum]
+	pub enum HoldReason<I: 'static = ()> {
+		/// The deposit of a member of the Alliance.
+		AllyDeposit,
 	}
 
 	#[pallet::error]
@@ -359,13 +650,73 @@ pub mod pallet {
 		RetirementPeriodNotPassed,
 		/// Fellows must be provided to initialize the Alliance.
 		FellowsMissing,
+		/// The member is suspended and can not make use of its privileges.
+		MemberSuspended,
+		/// The member is not suspended.
+		NotSuspended,
+		/// A suspension must end in a future block.
+		InvalidSuspensionEnd,
+		/// The sub-committee does not exist.
+		UnknownSubCommittee,
+		/// Number of sub-committees exceeds `MaxSubCommittees`.
+		TooManySubCommittees,
+		/// A sub-committee must have between one and `MaxSubCommitteeMembers` members.
+		InvalidSubCommitteeMembers,
+		/// A sub-committee must expire in a future block.
+		InvalidSubCommitteeExpiry,
+		/// The sub-committee has expired.
+		SubCommitteeExpired,
+		/// The sub-committee has not expired yet.
+		SubCommitteeNotExpired,
+		/// Account is not a member of the sub-committee.
+		NotSubCommitteeMember,
+		/// The sub-committee has not been delegated the power needed for this call.
+		MissingSubCommitteePower,
+		/// Fellows need a judged identity of their own, the identity of their super account is
+		/// not sufficient.
+		WithoutOwnIdentity,
+		/// The CID is neither the rule nor an announcement.
+		UnknownCid,
+		/// The CID is already flagged as unreachable.
+		CidAlreadyFlagged,
+		/// The CID is not flagged as unreachable.
+		CidNotFlagged,
+		/// The fellow used up its nomination quota of the current nomination period.
+		NominationQuotaExceeded,
+		/// The proposed call is not allowed by `ProposalFilter`.
+		ProposalNotAllowed,
+		/// The snapshot has unknown or duplicated roles, duplicated members, deposits of
+		/// non-members or members that are deemed unscrupulous.
+		InvalidSnapshot,
+		/// A snapshot has already been imported.
+		SnapshotAlreadyImported,
+		/// Enacting the proposal weighs more than `AutoCloseWeight`, so it can not be enacted.
+		EnactmentTooHeavy,
+		/// There are `MaxProposals` pending enactments at the block, or `MaxProposals` blocks
+		/// with pending enactments.
+		TooManyPendingEnactments,
+		/// The enactment does not exist.
+		UnknownEnactment,
+		/// The member is already suspended.
+		AlreadySuspended,
+		/// The member was penalized less than `UnscrupulousPenaltyCooldown` blocks ago.
+		PenaltyCooldown,
+		/// The Alliance is being disbanded.
+		DisbandInProgress,
+		/// The Alliance is not being disbanded.
+		NoDisbandInProgress,
+		/// The CID is malformed, e.g. its digest doesn't match its hash function.
+		InvalidCid,
+		/// The `RolePolicy` has no role with voting rights for the founding fellows, or no other
+		/// role for the founding allies.
+		NoFoundingRole,
 	}
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
-		/// A new rule has been set.
-		NewRuleSet { rule: Cid },
+		/// A new rule has been set, replacing `previous`.
+		NewRuleSet { rule: Cid, previous: Option<Cid> },
 		/// A new announcement has been proposed.
 		Announced { announcement: Cid },
 		/// An on-chain announcement has been removed.
@@ -386,14 +737,76 @@ pub mod pallet {
 		MemberRetired { member: T::AccountId, unreserved: Option<BalanceOf<T, I>> },
 		/// A member has been kicked out with its deposit slashed.
 		MemberKicked { member: T::AccountId, slashed: Option<BalanceOf<T, I>> },
-		/// Accounts or websites have been added into the list of unscrupulous items.
-		UnscrupulousItemAdded { items: Vec<UnscrupulousItemOf<T, I>> },
+		/// Accounts or websites have been added into the list of unscrupulous items, with the
+		/// evidence against them.
+		UnscrupulousItemAdded { items: Vec<UnscrupulousEntryOf<T, I>> },
 		/// Accounts or websites have been removed from the list of unscrupulous items.
 		UnscrupulousItemRemoved { items: Vec<UnscrupulousItemOf<T, I>> },
 		/// Alliance disbanded. Includes number deleted members and unreserved deposits.
 		AllianceDisbanded { fellow_members: u32, ally_members: u32, unreserved: u32 },
 		/// A Fellow abdicated their voting rights. They are now an Ally.
 		FellowAbdicated { fellow: T::AccountId },
+		/// A member has been suspended until the given block.
+		MemberSuspended { member: T::AccountId, until: BlockNumberFor<T> },
+		/// The suspension of a member has been lifted.
+		MemberUnsuspended { member: T::AccountId },
+		/// A sub-committee has been created.
+		SubCommitteeCreated {
+			index: SubCommitteeIndex,
+			members: Vec<T::AccountId>,
+			power: SubCommitteePower,
+			expiry: BlockNumberFor<T>,
+		},
+		/// A sub-committee has been revoked or removed after it expired.
+		SubCommitteeRevoked { index: SubCommitteeIndex },
+		/// A proposal has been closed with the given votes.
+		ProposalClosed {
+			hash: T::Hash,
+			ayes: u32,
+			nays: u32,
+			threshold: u32,
+			outcome: ProposalOutcome,
+		},
+		/// The CID could not be resolved on IPFS by an off-chain worker.
+		CidFlagged { cid: Cid },
+		/// The CID was removed from the flagged CIDs.
+		CidUnflagged { cid: Cid },
+		/// A fellow used up its nomination quota of the current nomination period.
+		NominationQuotaExhausted { fellow: T::AccountId },
+		/// A snapshot of an Alliance has been imported with the given number of members.
+		SnapshotImported { hash: T::Hash, members: u32 },
+		/// A member retired before the `MinimumTenure` and part of its deposit was slashed. The
+		/// `tenure` is measured until the retirement notice.
+		EarlyRetirementSlashed {
+			member: T::AccountId,
+			slashed: BalanceOf<T, I>,
+			tenure: BlockNumberFor<T>,
+		},
+		/// An approved proposal has been queued to be enacted at the given block.
+		EnactmentScheduled {
+			index: EnactmentIndex,
+			proposal_hash: T::Hash,
+			when: BlockNumberFor<T>,
+		},
+		/// An approved proposal has been enacted.
+		ProposalEnacted { index: EnactmentIndex, result: DispatchResult },
+		/// The enactment of an approved proposal has been cancelled.
+		EnactmentCancelled { index: EnactmentIndex },
+		/// A member listed as unscrupulous has been kicked out with part of its deposit slashed.
+		UnscrupulousMemberKicked { member: T::AccountId, slashed: Option<BalanceOf<T, I>> },
+		/// A member listed as unscrupulous has been suspended until the given block with part of
+		/// its deposit slashed.
+		UnscrupulousMemberSuspended {
+			member: T::AccountId,
+			until: BlockNumberFor<T>,
+			slashed: Option<BalanceOf<T, I>>,
+		},
+		/// Disbanding the Alliance started. Includes the number of members to remove.
+		DisbandStarted { fellow_members: u32, ally_members: u32 },
+		/// Some members of the disbanding Alliance have been removed, more are left to be removed
+		/// with [`Call::continue_disband`]. Includes the number of members removed and deposits
+		/// unreserved by the call.
+		DisbandProgressed { fellow_members: u32, ally_members: u32, unreserved: u32 },
 	}
 
 	#[pallet::genesis_config]
@@ -408,31 +821,38 @@ pub mod pallet {
 	#[pallet::genesis_build]
 	impl<T: Config<I>, I: 'static> BuildGenesisConfig for GenesisConfig<T, I> {
 		fn build(&self) {
-			for m in self.fellows.iter().chain(self.allies.iter()) {
+			for m in self.fellows.iter() {
+				assert!(
+					Pallet::<T, I>::has_fellow_identity(m).is_ok(),
+					"Fellow does not set identity!"
+				);
+			}
+			for m in self.allies.iter() {
 				assert!(Pallet::<T, I>::has_identity(m).is_ok(), "Member does not set identity!");
 			}
 
 			if !self.fellows.is_empty() {
+				let fellow_role = Pallet::<T, I>::founding_fellow_role()
+					.expect("The role policy has no role with voting rights");
 				assert!(
-					!Pallet::<T, I>::has_member(MemberRole::Fellow),
+					!Pallet::<T, I>::has_member(fellow_role),
 					"Fellows are already initialized!"
 				);
 				let members: BoundedVec<T::AccountId, T::MaxMembersCount> =
 					self.fellows.clone().try_into().expect("Too many genesis fellows");
-				Members::<T, I>::insert(MemberRole::Fellow, members);
+				Members::<T, I>::insert(fellow_role, members);
 			}
 			if !self.allies.is_empty() {
-				assert!(
-					!Pallet::<T, I>::has_member(MemberRole::Ally),
-					"Allies are already initialized!"
-				);
+				let ally_role = Pallet::<T, I>::founding_ally_role()
+					.expect("The role policy has no role for allies");
+				assert!(!Pallet::<T, I>::has_member(ally_role), "Allies are already initialized!");
 				assert!(
 					!self.fellows.is_empty(),
 					"Fellows must be provided to initialize the Alliance"
 				);
 				let members: BoundedVec<T::AccountId, T::MaxMembersCount> =
 					self.allies.clone().try_into().expect("Too many genesis allies");
-				Members::<T, I>::insert(MemberRole::Ally, members);
+				Members::<T, I>::insert(ally_role, members);
 			}
 
 			T::InitializeMembers::initialize_members(self.fellows.as_slice())
@@ -445,18 +865,18 @@ pub mod pallet {
 	#[pallet::getter(fn rule)]
 	pub type Rule<T: Config<I>, I: 'static = ()> = StorageValue<_, Cid, OptionQuery>;
 
+	/// The rules set so far together with the block they were set at, oldest first.
+	#[pallet::storage]
+	#[pallet::getter(fn rule_history)]
+	pub type RuleHistory<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BoundedVec<(BlockNumberFor<T>, Cid), T::MaxRuleHistory>, ValueQuery>;
+
 	/// The current IPFS CIDs of any announcements.
 	#[pallet::storage]
-	#[pallet::getter(fn announcements)]
+	#[pallet::getter(fn announcements, len)]
 	pub type Announcements<T: Config<I>, I: 'static = ()> =
 		StorageValue<_, BoundedVec<Cid, T::MaxAnnouncementsCount>, ValueQuery>;
 
-	/// Maps members to their candidacy deposit.
-	#[pallet::storage]
-	#[pallet::getter(fn deposit_of)]
-	pub type DepositOf<T: Config<I>, I: 'static = ()> =
-		StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T, I>, OptionQuery>;
-
 	/// Maps member type to members of each type.
 	#[pallet::storage]
 	#[pallet::getter(fn members)]
@@ -475,6 +895,18 @@ pub mod pallet {
 	pub type RetiringMembers<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
 
+	/// Members who are suspended until the stored block number. While suspended they can not
+	/// propose, vote or nominate allies.
+	#[pallet::storage]
+	pub type SuspendedMembers<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
+	/// The block at which members listed as unscrupulous were last suspended for it. Removed
+	/// when their account is no longer listed.
+	#[pallet::storage]
+	pub type UnscrupulousPenalizedAt<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
 	/// The current list of accounts deemed unscrupulous. These accounts non grata cannot submit
 	/// candidacy.
 	#[pallet::storage]
@@ -488,17 +920,134 @@ pub mod pallet {
 	pub type UnscrupulousWebsites<T: Config<I>, I: 'static = ()> =
 		StorageValue<_, BoundedVec<UrlOf<T, I>, T::MaxUnscrupulousItems>, ValueQuery>;
 
+	/// The IPFS CID of the evidence an unscrupulous item was listed with.
+	///
+	/// Removed together with the item from the unscrupulous list.
+	#[pallet::storage]
+	#[pallet::getter(fn unscrupulous_evidence)]
+	pub type UnscrupulousEvidence<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, UnscrupulousItemOf<T, I>, Cid, OptionQuery>;
+
+	/// The sub-committees with delegated powers.
+	#[pallet::storage]
+	pub type SubCommittees<T: Config<I>, I: 'static = ()> =
+		CountedStorageMap<_, Twox64Concat, SubCommitteeIndex, SubCommittee<T, I>, OptionQuery>;
+
+	/// The index of the next sub-committee.
+	#[pallet::storage]
+	pub type NextSubCommitteeIndex<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, SubCommitteeIndex, ValueQuery>;
+
+	/// Proposals whose outcome is final and which are waiting to be closed automatically, oldest
+	/// first. Only used if `AutoClose` is enabled.
+	#[pallet::storage]
+	pub type DecidedProposals<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BoundedVec<(T::Hash, ProposalIndex), T::MaxProposals>, ValueQuery>;
+
+	/// The CIDs of the rule or announcements an off-chain worker failed to resolve on IPFS,
+	/// with the block they were flagged at. They are reviewed by the `AdminOrigin`.
+	#[pallet::storage]
+	pub type FlaggedCids<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, Cid, BlockNumberFor<T>, OptionQuery>;
+
+	/// The nomination period a fellow nominated allies in last, and the number of allies it
+	/// nominated in that period. Only tracked if a `NominationQuota` is set.
+	///
+	/// The entry of a member is removed when it leaves the Alliance.
+	#[pallet::storage]
+	pub type NominationsUsed<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (BlockNumberFor<T>, u32), OptionQuery>;
+
+	/// The hash of the snapshot imported with [`Call::force_import_snapshot`], if any. A snapshot
+	/// can only be imported once.
+	#[pallet::storage]
+	pub type ImportedSnapshot<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, T::Hash, OptionQuery>;
+
+	/// The block members joined the Alliance or were last elevated at, which their tenure is
+	/// counted from. Not tracked for the members the Alliance was initialized with or imported
+	/// from a snapshot.
+	#[pallet::storage]
+	pub type MemberSince<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
+	/// Approved proposals waiting for their enactment, by the block they are enacted at, in the
+	/// order they were approved in.
+	#[pallet::storage]
+	#[pallet::unbounded]
+	pub type PendingEnactments<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<EnactmentOf<T, I>, T::MaxProposals>,
+		ValueQuery,
+	>;
+
+	/// The blocks with [`PendingEnactments`], in ascending order.
+	#[pallet::storage]
+	pub type EnactmentBlocks<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BoundedVec<BlockNumberFor<T>, T::MaxProposals>, ValueQuery>;
+
+	/// The index of the next enactment.
+	#[pallet::storage]
+	pub type NextEnactmentIndex<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, EnactmentIndex, ValueQuery>;
+
+	/// The members removed so far while the Alliance is being disbanded, if it is.
+	#[pallet::storage]
+	pub type DisbandProgress<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, DisbandStatus, OptionQuery>;
+
+	#[pallet::extra_constants]
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// The version of the layout of the events of this pallet.
+		///
+		/// It is increased whenever an event is added, removed or changes its fields, see
+		/// [`frame_support::event_schema`].
+		#[pallet::constant_name(EventSchemaVersion)]
+		pub fn event_schema_version() -> u32 {
+			8
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			let limit = T::AutoCloseWeight::get();
+			let weight = Self::enact_due_proposals(n, limit);
+			if !T::AutoClose::get() {
+				return weight
+			}
+			weight.saturating_add(Self::close_decided_proposals(limit.saturating_sub(weight)))
+		}
+
+		fn offchain_worker(_n: BlockNumberFor<T>) {
+			Self::check_cids();
+		}
+
+		fn integrity_test() {
+			let roles = T::RolePolicy::roles();
+			assert!(!roles.is_empty(), "The Alliance needs at least one role");
+			assert!(!roles.contains(&MemberRole::Retiring), "Retiring is not an ordinary role");
+			assert!(
+				roles.iter().any(|role| T::RolePolicy::has_voting_rights(*role)),
+				"At least one role needs voting rights"
+			);
+			assert!(
+				T::NominationQuota::get().is_none() || !T::NominationPeriod::get().is_zero(),
+				"A NominationQuota needs a non-zero NominationPeriod"
+			);
+		}
+	}
+
 	#[pallet::call(weight(<T as Config<I>>::WeightInfo))]
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		/// Add a new proposal to be voted on.
 		///
 		/// Must be called by a Fellow.
 		#[pallet::call_index(0)]
-		#[pallet::weight(T::WeightInfo::propose_proposed(
-			*length_bound, // B
-			T::MaxFellows::get(), // M
-			T::MaxProposals::get(), // P2
-		))]
+		#[pallet::weight(T::VotingProvider::propose_weight(*length_bound)
+			.saturating_add(Pallet::<T, I>::voting_checks_weight()))]
 		pub fn propose(
 			origin: OriginFor<T>,
 			#[pallet::compact] threshold: u32,
@@ -507,8 +1056,14 @@ pub mod pallet {
 		) -> DispatchResult {
 			let proposor = ensure_signed(origin)?;
 			ensure!(Self::has_voting_rights(&proposor), Error::<T, I>::NoVotingRights);
+			ensure!(!Self::is_suspended(&proposor), Error::<T, I>::MemberSuspended);
+			ensure!(T::ProposalFilter::contains(&proposal), Error::<T, I>::ProposalNotAllowed);
+			if let Some(Call::schedule_enactment { proposal: delayed }) = proposal.is_sub_type() {
+				ensure!(T::ProposalFilter::contains(delayed), Error::<T, I>::ProposalNotAllowed);
+			}
 
-			T::ProposalProvider::propose_proposal(proposor, threshold, proposal, length_bound)?;
+			let (proposal, length_bound) = Self::with_enactment_delay(proposal, length_bound);
+			T::VotingProvider::propose(proposor, threshold, proposal, length_bound)?;
 			Ok(())
 		}
 
@@ -516,17 +1071,21 @@ pub mod pallet {
 		///
 		/// Must be called by a Fellow.
 		#[pallet::call_index(1)]
-		#[pallet::weight(T::WeightInfo::vote(T::MaxFellows::get()))]
+		#[pallet::weight(Pallet::<T, I>::vote_weight())]
 		pub fn vote(
 			origin: OriginFor<T>,
 			proposal: T::Hash,
 			#[pallet::compact] index: ProposalIndex,
-			approve: bool,
+			vote: VoteOf<T, I>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 			ensure!(Self::has_voting_rights(&who), Error::<T, I>::NoVotingRights);
+			ensure!(!Self::is_suspended(&who), Error::<T, I>::MemberSuspended);
 
-			T::ProposalProvider::vote_proposal(who, proposal, index, approve)?;
+			T::VotingProvider::vote(who, proposal, index, vote)?;
+			if T::AutoClose::get() {
+				Self::queue_if_decided(proposal, index);
+			}
 			Ok(())
 		}
 
@@ -534,7 +1093,9 @@ pub mod pallet {
 
 		/// Initialize the Alliance, onboard fellows and allies.
 		///
-		/// The Alliance must be empty, and the call must provide some founding members.
+		/// The Alliance must be empty, and the call must provide some founding members. Fellows
+		/// join with the highest role of the `RolePolicy` with voting rights, allies with the
+		/// lowest role.
 		///
 		/// Must be called by the Root origin.
 		#[pallet::call_index(3)]
@@ -557,14 +1118,21 @@ pub mod pallet {
 			let mut allies: BoundedVec<T::AccountId, T::MaxMembersCount> =
 				allies.try_into().map_err(|_| Error::<T, I>::TooManyMembers)?;
 
-			for member in fellows.iter().chain(allies.iter()) {
-				Self::has_identity(member)?;
+			for fellow in fellows.iter() {
+				Self::has_fellow_identity(fellow)?;
+			}
+			for ally in allies.iter() {
+				Self::has_identity(ally)?;
 			}
 
+			let fellow_role = Self::founding_fellow_role().ok_or(Error::<T, I>::NoFoundingRole)?;
 			fellows.sort();
-			Members::<T, I>::insert(&MemberRole::Fellow, fellows.clone());
-			allies.sort();
-			Members::<T, I>::insert(&MemberRole::Ally, allies.clone());
+			Members::<T, I>::insert(&fellow_role, fellows.clone());
+			if !allies.is_empty() {
+				let ally_role = Self::founding_ally_role().ok_or(Error::<T, I>::NoFoundingRole)?;
+				allies.sort();
+				Members::<T, I>::insert(&ally_role, allies.clone());
+			}
 
 			let mut voteable_members = fellows.clone();
 			voteable_members.sort();
@@ -585,9 +1153,11 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Disband the Alliance, remove all active members and unreserve deposits.
+		/// Disband the Alliance, remove all active members and release deposits.
 		///
-		/// Witness data must be set.
+		/// The voting members lose their voting rights at once, so `witness.fellow_members` must
+		/// cover all of them. At most `witness.ally_members` other members are removed by this
+		/// call, the rest is left to [`Call::continue_disband`].
 		#[pallet::call_index(4)]
 		#[pallet::weight(T::WeightInfo::disband(
 			witness.fellow_members,
@@ -605,35 +1175,23 @@ pub mod pallet {
 				Self::voting_members_count() <= witness.fellow_members,
 				Error::<T, I>::BadWitness
 			);
-			ensure!(Self::ally_members_count() <= witness.ally_members, Error::<T, I>::BadWitness);
 			ensure!(Self::is_initialized(), Error::<T, I>::AllianceNotYetInitialized);
+			ensure!(!DisbandProgress::<T, I>::exists(), Error::<T, I>::DisbandInProgress);
 
 			let voting_members = Self::voting_members();
 			T::MembershipChanged::change_members_sorted(&[], &voting_members, &[]);
 
-			let ally_members = Self::members_of(MemberRole::Ally);
-			let mut unreserve_count: u32 = 0;
-			for member in voting_members.iter().chain(ally_members.iter()) {
-				if let Some(deposit) = DepositOf::<T, I>::take(&member) {
-					let err_amount = T::Currency::unreserve(&member, deposit);
-					debug_assert!(err_amount.is_zero());
-					unreserve_count += 1;
-				}
-			}
-
-			Members::<T, I>::remove(&MemberRole::Fellow);
-			Members::<T, I>::remove(&MemberRole::Ally);
-
-			Self::deposit_event(Event::AllianceDisbanded {
+			Self::deposit_event(Event::DisbandStarted {
 				fellow_members: voting_members.len() as u32,
-				ally_members: ally_members.len() as u32,
-				unreserved: unreserve_count,
+				ally_members: Self::ally_members_count(),
 			});
+			DisbandProgress::<T, I>::put(DisbandStatus::default());
 
+			let removed = Self::disband_members(witness);
 			Ok(Some(T::WeightInfo::disband(
-				voting_members.len() as u32,
-				ally_members.len() as u32,
-				unreserve_count,
+				removed.fellow_members,
+				removed.ally_members,
+				removed.unreserved,
 			))
 			.into())
 		}
@@ -642,10 +1200,18 @@ pub mod pallet {
 		#[pallet::call_index(5)]
 		pub fn set_rule(origin: OriginFor<T>, rule: Cid) -> DispatchResult {
 			T::AdminOrigin::ensure_origin(origin)?;
+			rule.validate().map_err(|_| Error::<T, I>::InvalidCid)?;
 
+			let previous = Rule::<T, I>::get();
 			Rule::<T, I>::put(&rule);
+			RuleHistory::<T, I>::mutate(|history| {
+				if !history.is_empty() && history.len() as u32 >= T::MaxRuleHistory::get() {
+					history.remove(0);
+				}
+				let _ = history.try_push((frame_system::Pallet::<T>::block_number(), rule.clone()));
+			});
 
-			Self::deposit_event(Event::NewRuleSet { rule });
+			Self::deposit_event(Event::NewRuleSet { rule, previous });
 			Ok(())
 		}
 
@@ -653,15 +1219,7 @@ pub mod pallet {
 		#[pallet::call_index(6)]
 		pub fn announce(origin: OriginFor<T>, announcement: Cid) -> DispatchResult {
 			T::AnnouncementOrigin::ensure_origin(origin)?;
-
-			let mut announcements = <Announcements<T, I>>::get();
-			announcements
-				.try_push(announcement.clone())
-				.map_err(|_| Error::<T, I>::TooManyAnnouncements)?;
-			<Announcements<T, I>>::put(announcements);
-
-			Self::deposit_event(Event::Announced { announcement });
-			Ok(())
+			Self::do_announce(announcement)
 		}
 
 		/// Remove an announcement.
@@ -681,7 +1239,7 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Submit oneself for candidacy. A fixed deposit is reserved.
+		/// Submit oneself for candidacy. A fixed deposit is held.
 		#[pallet::call_index(8)]
 		pub fn join_alliance(origin: OriginFor<T>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
@@ -703,10 +1261,11 @@ pub mod pallet {
 			Self::has_identity(&who)?;
 
 			let deposit = T::AllyDeposit::get();
-			T::Currency::reserve(&who, deposit).map_err(|_| Error::<T, I>::InsufficientFunds)?;
-			<DepositOf<T, I>>::insert(&who, deposit);
+			DepositsOf::<T, I>::place(&who, deposit)
+				.map_err(|_| Error::<T, I>::InsufficientFunds)?;
 
-			Self::add_member(&who, MemberRole::Ally)?;
+			Self::add_member(&who, Self::entry_role())?;
+			<MemberSince<T, I>>::insert(&who, frame_system::Pallet::<T>::block_number());
 
 			Self::deposit_event(Event::NewAllyJoined {
 				ally: who,
@@ -718,10 +1277,13 @@ pub mod pallet {
 
 		/// A Fellow can nominate someone to join the alliance as an Ally. There is no deposit
 		/// required from the nominator or nominee.
+		///
+		/// Counts against the `NominationQuota` of the Fellow, if any.
 		#[pallet::call_index(9)]
 		pub fn nominate_ally(origin: OriginFor<T>, who: AccountIdLookupOf<T>) -> DispatchResult {
 			let nominator = ensure_signed(origin)?;
 			ensure!(Self::has_voting_rights(&nominator), Error::<T, I>::NoVotingRights);
+			ensure!(!Self::is_suspended(&nominator), Error::<T, I>::MemberSuspended);
 			let who = T::Lookup::lookup(who)?;
 
 			// Individual voting members cannot nominate accounts non grata.
@@ -730,8 +1292,10 @@ pub mod pallet {
 			// check user self or parent should has verified identity to reuse display name and
 			// website.
 			Self::has_identity(&who)?;
+			Self::use_nomination_quota(&nominator)?;
 
-			Self::add_member(&who, MemberRole::Ally)?;
+			Self::add_member(&who, Self::entry_role())?;
+			<MemberSince<T, I>>::insert(&who, frame_system::Pallet::<T>::block_number());
 
 			Self::deposit_event(Event::NewAllyJoined {
 				ally: who,
@@ -741,16 +1305,23 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Elevate an Ally to Fellow.
+		/// Elevate a member to the next higher role, e.g. an Ally to Fellow.
 		#[pallet::call_index(10)]
 		pub fn elevate_ally(origin: OriginFor<T>, ally: AccountIdLookupOf<T>) -> DispatchResult {
 			T::MembershipManager::ensure_origin(origin)?;
 			let ally = T::Lookup::lookup(ally)?;
-			ensure!(Self::is_ally(&ally), Error::<T, I>::NotAlly);
-			ensure!(!Self::has_voting_rights(&ally), Error::<T, I>::AlreadyElevated);
+			let role = Self::member_role_of(&ally)
+				.filter(|role| *role != MemberRole::Retiring)
+				.ok_or(Error::<T, I>::NotAlly)?;
+			let next = Self::next_role(role).ok_or(Error::<T, I>::AlreadyElevated)?;
+			if T::RolePolicy::has_voting_rights(next) {
+				// The identity may have changed since the ally joined.
+				Self::has_fellow_identity(&ally)?;
+			}
 
-			Self::remove_member(&ally, MemberRole::Ally)?;
-			Self::add_member(&ally, MemberRole::Fellow)?;
+			Self::remove_member(&ally, role)?;
+			Self::add_member(&ally, next)?;
+			<MemberSince<T, I>>::insert(&ally, frame_system::Pallet::<T>::block_number());
 
 			Self::deposit_event(Event::AllyElevated { ally });
 			Ok(())
@@ -776,10 +1347,11 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// As a member, retire from the Alliance and unreserve the deposit.
+		/// As a member, retire from the Alliance and release the deposit.
 		///
 		/// This can only be done once you have called `give_retirement_notice` and the
-		/// `RetirementPeriod` has passed.
+		/// `RetirementPeriod` has passed. Part of the deposit is slashed if you gave the notice
+		/// before the `MinimumTenure`.
 		#[pallet::call_index(12)]
 		pub fn retire(origin: OriginFor<T>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
@@ -792,10 +1364,14 @@ pub mod pallet {
 
 			Self::remove_member(&who, MemberRole::Retiring)?;
 			<RetiringMembers<T, I>>::remove(&who);
-			let deposit = DepositOf::<T, I>::take(&who);
-			if let Some(deposit) = deposit {
-				let err_amount = T::Currency::unreserve(&who, deposit);
-				debug_assert!(err_amount.is_zero());
+			<SuspendedMembers<T, I>>::remove(&who);
+			let notice = retirement_period_end.saturating_sub(T::RetirementPeriod::get());
+			Self::slash_early_retirement(&who, notice);
+			<MemberSince<T, I>>::remove(&who);
+			<NominationsUsed<T, I>>::remove(&who);
+			let deposit = Self::deposit_of(&who);
+			if deposit.is_some() {
+				DepositsOf::<T, I>::release_all(&who)?;
 			}
 			Self::deposit_event(Event::MemberRetired { member: who, unreserved: deposit });
 			Ok(())
@@ -809,43 +1385,35 @@ pub mod pallet {
 
 			let role = Self::member_role_of(&member).ok_or(Error::<T, I>::NotMember)?;
 			Self::remove_member(&member, role)?;
-			let deposit = DepositOf::<T, I>::take(member.clone());
-			if let Some(deposit) = deposit {
-				T::Slashed::on_unbalanced(T::Currency::slash_reserved(&member, deposit).0);
+			<SuspendedMembers<T, I>>::remove(&member);
+			<MemberSince<T, I>>::remove(&member);
+			<NominationsUsed<T, I>>::remove(&member);
+			let deposit = Self::deposit_of(&member);
+			if deposit.is_some() {
+				T::Slashed::on_unbalanced(DepositsOf::<T, I>::slash_all(&member));
 			}
 
 			Self::deposit_event(Event::MemberKicked { member, slashed: deposit });
 			Ok(())
 		}
 
-		/// Add accounts or websites to the list of unscrupulous items.
+		/// Add accounts or websites to the list of unscrupulous items, without evidence.
+		///
+		/// Members among the listed accounts are penalized as configured by
+		/// `UnscrupulousMemberPenalty`. See [`Pallet::add_unscrupulous_items_with_evidence`] to
+		/// list items with evidence.
 		#[pallet::call_index(14)]
-		#[pallet::weight(T::WeightInfo::add_unscrupulous_items(items.len() as u32, T::MaxWebsiteUrlLength::get()))]
+		#[pallet::weight(Pallet::<T, I>::add_unscrupulous_items_weight(
+			items.len() as u32, items.len() as u32
+		))]
 		pub fn add_unscrupulous_items(
 			origin: OriginFor<T>,
 			items: Vec<UnscrupulousItemOf<T, I>>,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			T::AnnouncementOrigin::ensure_origin(origin)?;
-
-			let mut accounts = vec![];
-			let mut webs = vec![];
-			for info in items.iter() {
-				ensure!(!Self::is_unscrupulous(info), Error::<T, I>::AlreadyUnscrupulous);
-				match info {
-					UnscrupulousItem::AccountId(who) => accounts.push(who.clone()),
-					UnscrupulousItem::Website(url) => {
-						ensure!(
-							url.len() as u32 <= T::MaxWebsiteUrlLength::get(),
-							Error::<T, I>::TooLongWebsiteUrl
-						);
-						webs.push(url.clone());
-					},
-				}
-			}
-
-			Self::do_add_unscrupulous_items(&mut accounts, &mut webs)?;
-			Self::deposit_event(Event::UnscrupulousItemAdded { items });
-			Ok(())
+			let len = items.len() as u32;
+			let penalized = Self::add_unscrupulous_items_checked(Self::without_evidence(items))?;
+			Ok(Some(Self::add_unscrupulous_items_weight(len, penalized)).into())
 		}
 
 		/// Deem some items no longer unscrupulous.
@@ -858,35 +1426,15 @@ pub mod pallet {
 			items: Vec<UnscrupulousItemOf<T, I>>,
 		) -> DispatchResult {
 			T::AnnouncementOrigin::ensure_origin(origin)?;
-			let mut accounts = vec![];
-			let mut webs = vec![];
-			for info in items.iter() {
-				ensure!(Self::is_unscrupulous(info), Error::<T, I>::NotListedAsUnscrupulous);
-				match info {
-					UnscrupulousItem::AccountId(who) => accounts.push(who.clone()),
-					UnscrupulousItem::Website(url) => webs.push(url.clone()),
-				}
-			}
-			Self::do_remove_unscrupulous_items(&mut accounts, &mut webs)?;
-			Self::deposit_event(Event::UnscrupulousItemRemoved { items });
-			Ok(())
+			Self::remove_unscrupulous_items_checked(items)
 		}
 
 		/// Close a vote that is either approved, disapproved, or whose voting period has ended.
 		///
 		/// Must be called by a Fellow.
 		#[pallet::call_index(16)]
-		#[pallet::weight({
-			let b = *length_bound;
-			let m = T::MaxFellows::get();
-			let p1 = *proposal_weight_bound;
-			let p2 = T::MaxProposals::get();
-			T::WeightInfo::close_early_approved(b, m, p2)
-				.max(T::WeightInfo::close_early_disapproved(m, p2))
-				.max(T::WeightInfo::close_approved(b, m, p2))
-				.max(T::WeightInfo::close_disapproved(m, p2))
-				.saturating_add(p1)
-		})]
+		#[pallet::weight(Pallet::<T, I>::close_weight(*length_bound, *proposal_weight_bound)
+			.saturating_add(Pallet::<T, I>::voting_checks_weight()))]
 		pub fn close(
 			origin: OriginFor<T>,
 			proposal_hash: T::Hash,
@@ -900,9 +1448,9 @@ pub mod pallet {
 			Self::do_close(proposal_hash, index, proposal_weight_bound, length_bound)
 		}
 
-		/// Abdicate one's position as a voting member and just be an Ally. May be used by Fellows
-		/// who do not want to leave the Alliance but do not have the capacity to participate
-		/// operationally for some time.
+		/// Abdicate one's position as a voting member and just be an Ally, or whichever is the
+		/// highest role without voting rights. May be used by Fellows who do not want to leave
+		/// the Alliance but do not have the capacity to participate operationally for some time.
 		#[pallet::call_index(17)]
 		pub fn abdicate_fellow_status(origin: OriginFor<T>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
@@ -910,19 +1458,499 @@ pub mod pallet {
 			// Not applicable to members who are retiring or who are already Allies.
 			ensure!(Self::has_voting_rights(&who), Error::<T, I>::NoVotingRights);
 
+			let lower = T::RolePolicy::roles()
+				.into_iter()
+				.rev()
+				.find(|role| !T::RolePolicy::has_voting_rights(*role))
+				.ok_or(Error::<T, I>::NoVotingRights)?;
+
 			Self::remove_member(&who, role)?;
-			Self::add_member(&who, MemberRole::Ally)?;
+			Self::add_member(&who, lower)?;
 
 			Self::deposit_event(Event::FellowAbdicated { fellow: who });
 			Ok(())
 		}
+
+		/// Suspend a member until block `until`.
+		///
+		/// Unlike [`Call::kick_member`] the member keeps its role and deposit, but can not
+		/// propose, vote or nominate allies until the suspension lapses or is lifted with
+		/// [`Call::unsuspend_member`]. Suspending a suspended member changes the end of the
+		/// suspension.
+		#[pallet::call_index(18)]
+		pub fn suspend_member(
+			origin: OriginFor<T>,
+			who: AccountIdLookupOf<T>,
+			until: BlockNumberFor<T>,
+		) -> DispatchResult {
+			T::MembershipManager::ensure_origin(origin)?;
+			let member = T::Lookup::lookup(who)?;
+			ensure!(Self::is_member(&member), Error::<T, I>::NotMember);
+			ensure!(
+				until > frame_system::Pallet::<T>::block_number(),
+				Error::<T, I>::InvalidSuspensionEnd
+			);
+
+			<SuspendedMembers<T, I>>::insert(&member, until);
+
+			Self::deposit_event(Event::MemberSuspended { member, until });
+			Ok(())
+		}
+
+		/// Lift the suspension of a member before it lapses.
+		#[pallet::call_index(19)]
+		pub fn unsuspend_member(origin: OriginFor<T>, who: AccountIdLookupOf<T>) -> DispatchResult {
+			T::MembershipManager::ensure_origin(origin)?;
+			let member = T::Lookup::lookup(who)?;
+			ensure!(Self::is_suspended(&member), Error::<T, I>::NotSuspended);
+
+			<SuspendedMembers<T, I>>::remove(&member);
+
+			Self::deposit_event(Event::MemberUnsuspended { member });
+			Ok(())
+		}
+
+		/// Create a sub-committee of fellows that can exercise `power` on behalf of the Alliance
+		/// until block `expiry`.
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::create_sub_committee(members.len() as u32))]
+		pub fn create_sub_committee(
+			origin: OriginFor<T>,
+			members: Vec<AccountIdLookupOf<T>>,
+			power: SubCommitteePower,
+			expiry: BlockNumberFor<T>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(
+				expiry > frame_system::Pallet::<T>::block_number(),
+				Error::<T, I>::InvalidSubCommitteeExpiry
+			);
+			ensure!(
+				<SubCommittees<T, I>>::count() < T::MaxSubCommittees::get(),
+				Error::<T, I>::TooManySubCommittees
+			);
+
+			let mut members =
+				members.into_iter().map(T::Lookup::lookup).collect::<Result<Vec<_>, _>>()?;
+			members.sort();
+			members.dedup();
+			ensure!(!members.is_empty(), Error::<T, I>::InvalidSubCommitteeMembers);
+			for member in members.iter() {
+				ensure!(Self::has_voting_rights(member), Error::<T, I>::NoVotingRights);
+			}
+			let members: BoundedVec<_, T::MaxSubCommitteeMembers> =
+				members.try_into().map_err(|_| Error::<T, I>::InvalidSubCommitteeMembers)?;
+
+			let index = <NextSubCommitteeIndex<T, I>>::get();
+			<NextSubCommitteeIndex<T, I>>::put(index.saturating_add(1));
+			<SubCommittees<T, I>>::insert(
+				index,
+				SubCommittee { members: members.clone(), power, expiry },
+			);
+
+			Self::deposit_event(Event::SubCommitteeCreated {
+				index,
+				members: members.into_inner(),
+				power,
+				expiry,
+			});
+			Ok(())
+		}
+
+		/// Revoke a sub-committee.
+		///
+		/// Must be called by the `AdminOrigin`, or by any signed origin once the sub-committee has
+		/// expired.
+		#[pallet::call_index(21)]
+		pub fn revoke_sub_committee(
+			origin: OriginFor<T>,
+			index: SubCommitteeIndex,
+		) -> DispatchResult {
+			let committee =
+				<SubCommittees<T, I>>::get(index).ok_or(Error::<T, I>::UnknownSubCommittee)?;
+			if T::AdminOrigin::try_origin(origin.clone()).is_err() {
+				ensure_signed(origin)?;
+				ensure!(
+					committee.expiry <= frame_system::Pallet::<T>::block_number(),
+					Error::<T, I>::SubCommitteeNotExpired
+				);
+			}
+
+			<SubCommittees<T, I>>::remove(index);
+
+			Self::deposit_event(Event::SubCommitteeRevoked { index });
+			Ok(())
+		}
+
+		/// Add accounts or websites to the list of unscrupulous items, without evidence, on behalf
+		/// of a sub-committee with the [`SubCommitteePower::UnscrupulousItems`] power.
+		#[pallet::call_index(22)]
+		#[pallet::weight(Pallet::<T, I>::add_unscrupulous_items_weight(
+			items.len() as u32, items.len() as u32
+		).saturating_add(T::WeightInfo::ensure_sub_committee()))]
+		pub fn delegated_add_unscrupulous_items(
+			origin: OriginFor<T>,
+			committee: SubCommitteeIndex,
+			items: Vec<UnscrupulousItemOf<T, I>>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_sub_committee(origin, committee, SubCommitteePower::UnscrupulousItems)?;
+			let len = items.len() as u32;
+			let penalized = Self::add_unscrupulous_items_checked(Self::without_evidence(items))?;
+			Ok(Some(
+				Self::add_unscrupulous_items_weight(len, penalized)
+					.saturating_add(T::WeightInfo::ensure_sub_committee()),
+			)
+			.into())
+		}
+
+		/// Deem some items no longer unscrupulous on behalf of a sub-committee with the
+		/// [`SubCommitteePower::UnscrupulousItems`] power.
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::remove_unscrupulous_items(
+			items.len() as u32, T::MaxWebsiteUrlLength::get()
+		).saturating_add(T::WeightInfo::ensure_sub_committee()))]
+		pub fn delegated_remove_unscrupulous_items(
+			origin: OriginFor<T>,
+			committee: SubCommitteeIndex,
+			items: Vec<UnscrupulousItemOf<T, I>>,
+		) -> DispatchResult {
+			Self::ensure_sub_committee(origin, committee, SubCommitteePower::UnscrupulousItems)?;
+			Self::remove_unscrupulous_items_checked(items)
+		}
+
+		/// Make an announcement on behalf of a sub-committee with the
+		/// [`SubCommitteePower::Announcements`] power.
+		#[pallet::call_index(24)]
+		#[pallet::weight(
+			T::WeightInfo::announce().saturating_add(T::WeightInfo::ensure_sub_committee())
+		)]
+		pub fn delegated_announce(
+			origin: OriginFor<T>,
+			committee: SubCommitteeIndex,
+			announcement: Cid,
+		) -> DispatchResult {
+			Self::ensure_sub_committee(origin, committee, SubCommitteePower::Announcements)?;
+			Self::do_announce(announcement)
+		}
+
+		/// Flag the CID of the rule or an announcement as unreachable on IPFS.
+		///
+		/// Must be called by a voting member who is not suspended, usually through the off-chain
+		/// worker of a node holding the member's key.
+		#[pallet::call_index(25)]
+		pub fn flag_unreachable_cid(origin: OriginFor<T>, cid: Cid) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(Self::has_voting_rights(&who), Error::<T, I>::NoVotingRights);
+			ensure!(!Self::is_suspended(&who), Error::<T, I>::MemberSuspended);
+			ensure!(Self::is_published(&cid), Error::<T, I>::UnknownCid);
+			ensure!(!FlaggedCids::<T, I>::contains_key(&cid), Error::<T, I>::CidAlreadyFlagged);
+
+			FlaggedCids::<T, I>::insert(&cid, frame_system::Pallet::<T>::block_number());
+			Self::deposit_event(Event::CidFlagged { cid });
+			Ok(())
+		}
+
+		/// Remove a CID from the CIDs flagged as unreachable, after it has been reviewed.
+		///
+		/// Must be called by the `AdminOrigin`.
+		#[pallet::call_index(26)]
+		pub fn unflag_cid(origin: OriginFor<T>, cid: Cid) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			FlaggedCids::<T, I>::take(&cid).ok_or(Error::<T, I>::CidNotFlagged)?;
+
+			Self::deposit_event(Event::CidUnflagged { cid });
+			Ok(())
+		}
+
+		/// Import the membership of an Alliance exported with [`Pallet::export_snapshot`], e.g.
+		/// on another chain.
+		///
+		/// The Alliance must be empty and no snapshot may have been imported before. Members are
+		/// imported without identity checks, their deposits are held from their balance on this
+		/// chain and retiring members start a new retirement period.
+		///
+		/// Must be called by the Root origin.
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::force_import_snapshot(
+			snapshot.members_count(),
+			snapshot.unscrupulous_count(),
+		))]
+		pub fn force_import_snapshot(
+			origin: OriginFor<T>,
+			snapshot: AllianceSnapshot<T::AccountId, BalanceOf<T, I>>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			ensure!(
+				ImportedSnapshot::<T, I>::get().is_none(),
+				Error::<T, I>::SnapshotAlreadyImported
+			);
+			ensure!(
+				!Self::is_initialized() && !Self::has_member(MemberRole::Retiring),
+				Error::<T, I>::AllianceAlreadyInitialized
+			);
+
+			let hash = T::Hashing::hash_of(&snapshot);
+			let members = match snapshot {
+				AllianceSnapshot::V1(snapshot) => Self::import_snapshot(snapshot)?,
+			};
+			ImportedSnapshot::<T, I>::put(hash);
+
+			Self::deposit_event(Event::SnapshotImported { hash, members });
+			Ok(())
+		}
+
+		/// Queue an approved proposal to be enacted after the `EnactmentDelay`.
+		///
+		/// `propose` wraps proposals into this call if there is an `EnactmentDelay`, so that
+		/// approving them queues them instead of dispatching them. The proposal must be allowed
+		/// by the `ProposalFilter`. Must be called by the `EnactmentOrigin`.
+		#[pallet::call_index(28)]
+		pub fn schedule_enactment(
+			origin: OriginFor<T>,
+			proposal: Box<<T as Config<I>>::Proposal>,
+		) -> DispatchResult {
+			let caller = T::EnactmentOrigin::ensure_origin(origin)?;
+			ensure!(T::ProposalFilter::contains(&proposal), Error::<T, I>::ProposalNotAllowed);
+			ensure!(
+				Self::enactment_cost(proposal.get_dispatch_info().weight)
+					.all_lte(T::AutoCloseWeight::get()),
+				Error::<T, I>::EnactmentTooHeavy
+			);
+
+			let index = NextEnactmentIndex::<T, I>::mutate(|next| {
+				let index = *next;
+				next.saturating_inc();
+				index
+			});
+			let when =
+				frame_system::Pallet::<T>::block_number().saturating_add(T::EnactmentDelay::get());
+			let proposal_hash = T::Hashing::hash_of(&proposal);
+			EnactmentBlocks::<T, I>::try_mutate(|blocks| match blocks.binary_search(&when) {
+				Ok(_) => Ok(()),
+				Err(position) => blocks
+					.try_insert(position, when)
+					.map_err(|_| Error::<T, I>::TooManyPendingEnactments),
+			})?;
+			PendingEnactments::<T, I>::try_append(
+				when,
+				Enactment { index, when, origin: caller, proposal: *proposal },
+			)
+			.map_err(|_| Error::<T, I>::TooManyPendingEnactments)?;
+
+			Self::deposit_event(Event::EnactmentScheduled { index, proposal_hash, when });
+			Ok(())
+		}
+
+		/// Cancel the enactment of an approved proposal, scheduled at block `when`.
+		///
+		/// Must be called by the `AdminOrigin`.
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::cancel_enactment(T::MaxProposals::get()))]
+		pub fn cancel_enactment(
+			origin: OriginFor<T>,
+			when: BlockNumberFor<T>,
+			index: EnactmentIndex,
+		) -> DispatchResultWithPostInfo {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let mut pending = PendingEnactments::<T, I>::get(when);
+			let count = pending.len() as u32;
+			let position = pending
+				.iter()
+				.position(|enactment| enactment.index == index)
+				.ok_or(Error::<T, I>::UnknownEnactment)?;
+			pending.remove(position);
+			if pending.is_empty() {
+				PendingEnactments::<T, I>::remove(when);
+				EnactmentBlocks::<T, I>::mutate(|blocks| blocks.retain(|block| *block != when));
+			} else {
+				PendingEnactments::<T, I>::insert(when, pending);
+			}
+
+			Self::deposit_event(Event::EnactmentCancelled { index });
+			Ok(Some(T::WeightInfo::cancel_enactment(count)).into())
+		}
+
+		/// Penalize a member whose account is listed as unscrupulous, as configured by
+		/// `UnscrupulousMemberPenalty`.
+		///
+		/// Members are penalized when their account is listed, this is for the members that are
+		/// listed but were not penalized, e.g. because they were listed before members were
+		/// penalized or because their suspension lapsed. Suspended members can only be penalized
+		/// again `UnscrupulousPenaltyCooldown` blocks after they were last penalized. May be called
+		/// by any signed origin.
+		#[pallet::call_index(30)]
+		pub fn enforce_unscrupulous(
+			origin: OriginFor<T>,
+			who: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let member = T::Lookup::lookup(who)?;
+			ensure!(Self::is_member(&member), Error::<T, I>::NotMember);
+			ensure!(Self::is_unscrupulous_account(&member), Error::<T, I>::NotListedAsUnscrupulous);
+			if let UnscrupulousPenalty::Suspend(_) = T::UnscrupulousMemberPenalty::get() {
+				ensure!(!Self::is_suspended(&member), Error::<T, I>::AlreadySuspended);
+				if let Some(penalized_at) = <UnscrupulousPenalizedAt<T, I>>::get(&member) {
+					let now = frame_system::Pallet::<T>::block_number();
+					ensure!(
+						now >= penalized_at.saturating_add(T::UnscrupulousPenaltyCooldown::get()),
+						Error::<T, I>::PenaltyCooldown
+					);
+				}
+			}
+
+			Self::penalize_unscrupulous_member(&member)
+		}
+
+		/// Remove more members of the disbanding Alliance and release their deposits.
+		///
+		/// At most `witness.fellow_members` voting members and `witness.ally_members` other
+		/// members are removed. May be called by any signed origin.
+		#[pallet::call_index(31)]
+		#[pallet::weight(T::WeightInfo::continue_disband(
+			witness.fellow_members,
+			witness.ally_members,
+			witness.fellow_members.saturating_add(witness.ally_members),
+		))]
+		pub fn continue_disband(
+			origin: OriginFor<T>,
+			witness: DisbandWitness,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			ensure!(!witness.is_zero(), Error::<T, I>::BadWitness);
+			ensure!(DisbandProgress::<T, I>::exists(), Error::<T, I>::NoDisbandInProgress);
+
+			let removed = Self::disband_members(witness);
+			Ok(Some(T::WeightInfo::continue_disband(
+				removed.fellow_members,
+				removed.ally_members,
+				removed.unreserved,
+			))
+			.into())
+		}
+
+		/// Add accounts or websites to the list of unscrupulous items.
+		///
+		/// Each item may carry the IPFS CID of the evidence against it. Members among the listed
+		/// accounts are penalized as configured by `UnscrupulousMemberPenalty`.
+		#[pallet::call_index(32)]
+		#[pallet::weight(Pallet::<T, I>::add_unscrupulous_items_weight(
+			items.len() as u32, items.len() as u32
+		))]
+		pub fn add_unscrupulous_items_with_evidence(
+			origin: OriginFor<T>,
+			items: Vec<UnscrupulousEntryOf<T, I>>,
+		) -> DispatchResultWithPostInfo {
+			T::AnnouncementOrigin::ensure_origin(origin)?;
+			let len = items.len() as u32;
+			let penalized = Self::add_unscrupulous_items_checked(items)?;
+			Ok(Some(Self::add_unscrupulous_items_weight(len, penalized)).into())
+		}
+
+		/// Add accounts or websites to the list of unscrupulous items on behalf of a
+		/// sub-committee with the [`SubCommitteePower::UnscrupulousItems`] power.
+		///
+		/// Each item may carry the IPFS CID of the evidence against it.
+		#[pallet::call_index(33)]
+		#[pallet::weight(Pallet::<T, I>::add_unscrupulous_items_weight(
+			items.len() as u32, items.len() as u32
+		).saturating_add(T::WeightInfo::ensure_sub_committee()))]
+		pub fn delegated_add_unscrupulous_items_with_evidence(
+			origin: OriginFor<T>,
+			committee: SubCommitteeIndex,
+			items: Vec<UnscrupulousEntryOf<T, I>>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_sub_committee(origin, committee, SubCommitteePower::UnscrupulousItems)?;
+			let len = items.len() as u32;
+			let penalized = Self::add_unscrupulous_items_checked(items)?;
+			Ok(Some(
+				Self::add_unscrupulous_items_weight(len, penalized)
+					.saturating_add(T::WeightInfo::ensure_sub_committee()),
+			)
+			.into())
+		}
 	}
 }
 
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// The deposit held from `who` for being a member, if any.
+	pub fn deposit_of(who: &T::AccountId) -> Option<BalanceOf<T, I>> {
+		Some(DepositsOf::<T, I>::deposit_of(who)).filter(|deposit| !deposit.is_zero())
+	}
+
+	/// Check whether `who` can join the Alliance as an Ally with [`Call::join_alliance`].
+	///
+	/// Returns the deposit to be reserved on success, or the first reason that would make the
+	/// call fail otherwise. This is meant to be queried by UIs before submitting the call.
+	pub fn can_join(who: &T::AccountId) -> Result<DepositInfo<BalanceOf<T, I>>, JoinBlocker> {
+		ensure!(Self::is_initialized(), JoinBlocker::NotInitialized);
+		ensure!(!Self::is_unscrupulous_account(who), JoinBlocker::AccountNonGrata);
+		ensure!(!Self::is_member(who), JoinBlocker::AlreadyMember);
+		Self::has_identity(who).map_err(|_| JoinBlocker::MissingIdentity)?;
+
+		let deposit = T::AllyDeposit::get();
+		ensure!(DepositsOf::<T, I>::can_place(who, deposit), JoinBlocker::InsufficientFunds);
+
+		let remaining_capacity = T::MaxMembersCount::get()
+			.saturating_sub(Self::members_len(Self::entry_role()).unwrap_or(0) as u32);
+		ensure!(remaining_capacity > 0, JoinBlocker::TooManyMembers);
+
+		Ok(DepositInfo { deposit, remaining_capacity })
+	}
+
+	/// The hashes of the proposals currently being voted on.
+	pub fn active_proposals() -> Vec<T::Hash> {
+		T::VotingProvider::proposals()
+	}
+
+	/// The accounts and websites deemed unscrupulous, accounts first.
+	pub fn unscrupulous_items() -> Vec<UnscrupulousItem<T::AccountId, Vec<u8>>> {
+		UnscrupulousAccounts::<T, I>::get()
+			.into_iter()
+			.map(UnscrupulousItem::AccountId)
+			.chain(
+				UnscrupulousWebsites::<T, I>::get()
+					.into_iter()
+					.map(|url| UnscrupulousItem::Website(url.into_inner())),
+			)
+			.collect()
+	}
+
 	/// Check if the Alliance has been initialized.
 	fn is_initialized() -> bool {
-		Self::has_member(MemberRole::Fellow) || Self::has_member(MemberRole::Ally)
+		T::RolePolicy::roles().into_iter().any(Self::has_member)
+	}
+
+	/// The role new members join the Alliance with.
+	fn entry_role() -> MemberRole {
+		T::RolePolicy::roles().first().copied().unwrap_or(MemberRole::Ally)
+	}
+
+	/// The role founding fellows join the Alliance with, the highest role with voting rights.
+	fn founding_fellow_role() -> Option<MemberRole> {
+		Self::voting_roles().last()
+	}
+
+	/// The role founding allies join the Alliance with, the lowest role if it differs from the
+	/// role of the founding fellows.
+	fn founding_ally_role() -> Option<MemberRole> {
+		let role = Self::entry_role();
+		(Some(role) != Self::founding_fellow_role()).then_some(role)
+	}
+
+	/// The role above `role`, if any.
+	fn next_role(role: MemberRole) -> Option<MemberRole> {
+		let roles = T::RolePolicy::roles();
+		let pos = roles.iter().position(|r| *r == role)?;
+		roles.get(pos + 1).copied()
+	}
+
+	/// The roles whose members have voting rights.
+	fn voting_roles() -> impl Iterator<Item = MemberRole> {
+		T::RolePolicy::roles()
+			.into_iter()
+			.filter(|role| T::RolePolicy::has_voting_rights(*role))
 	}
 
 	/// Check if a given role has any members.
@@ -943,7 +1971,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 	/// Check if an account has a given role.
 	pub fn is_member_of(who: &T::AccountId, role: MemberRole) -> bool {
-		Members::<T, I>::get(role).contains(&who)
+		Members::<T, I>::decode_contains(role, who)
 	}
 
 	/// Check if an account is an Ally.
@@ -953,17 +1981,52 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 	/// Check if a member has voting rights.
 	fn has_voting_rights(who: &T::AccountId) -> bool {
-		Self::is_member_of(who, MemberRole::Fellow)
+		Self::voting_roles().any(|role| Self::is_member_of(who, role))
+	}
+
+	/// Check if a member is suspended. A suspension lapses at the block it was suspended until.
+	pub fn is_suspended(who: &T::AccountId) -> bool {
+		<SuspendedMembers<T, I>>::get(who)
+			.map_or(false, |until| frame_system::Pallet::<T>::block_number() < until)
 	}
 
-	/// Count of ally members.
+	/// Ensure that `origin` is a member of the sub-committee `index`, which must not have expired
+	/// and must hold `power`.
+	///
+	/// Members that lost their voting rights or are suspended can not act for a sub-committee.
+	fn ensure_sub_committee(
+		origin: OriginFor<T>,
+		index: SubCommitteeIndex,
+		power: SubCommitteePower,
+	) -> Result<T::AccountId, DispatchError> {
+		let who = ensure_signed(origin)?;
+		let committee =
+			<SubCommittees<T, I>>::get(index).ok_or(Error::<T, I>::UnknownSubCommittee)?;
+		ensure!(
+			frame_system::Pallet::<T>::block_number() < committee.expiry,
+			Error::<T, I>::SubCommitteeExpired
+		);
+		ensure!(committee.power == power, Error::<T, I>::MissingSubCommitteePower);
+		ensure!(committee.members.contains(&who), Error::<T, I>::NotSubCommitteeMember);
+		ensure!(Self::has_voting_rights(&who), Error::<T, I>::NoVotingRights);
+		ensure!(!Self::is_suspended(&who), Error::<T, I>::MemberSuspended);
+		Ok(who)
+	}
+
+	/// Count of all members without voting rights, not counting retiring members.
 	fn ally_members_count() -> u32 {
-		Members::<T, I>::decode_len(MemberRole::Ally).unwrap_or(0) as u32
+		T::RolePolicy::roles()
+			.into_iter()
+			.filter(|role| !T::RolePolicy::has_voting_rights(*role))
+			.map(|role| Members::<T, I>::decode_len(role).unwrap_or(0) as u32)
+			.sum()
 	}
 
 	/// Count of all members who have voting rights.
 	fn voting_members_count() -> u32 {
-		Members::<T, I>::decode_len(MemberRole::Fellow).unwrap_or(0) as u32
+		Self::voting_roles()
+			.map(|role| Members::<T, I>::decode_len(role).unwrap_or(0) as u32)
+			.sum()
 	}
 
 	/// Get all members of a given role.
@@ -971,13 +2034,16 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Members::<T, I>::get(role).into_inner()
 	}
 
-	/// Collect all members who have voting rights into one list.
+	/// Collect all members who have voting rights into one sorted list.
 	fn voting_members() -> Vec<T::AccountId> {
-		Self::members_of(MemberRole::Fellow)
+		let mut members: Vec<_> = Self::voting_roles().flat_map(Self::members_of).collect();
+		members.sort();
+		members
 	}
 
 	/// Add a user to the sorted alliance member set.
 	fn add_member(who: &T::AccountId, role: MemberRole) -> DispatchResult {
+		ensure!(!DisbandProgress::<T, I>::exists(), Error::<T, I>::DisbandInProgress);
 		<Members<T, I>>::try_mutate(role, |members| -> DispatchResult {
 			let pos = members.binary_search(who).err().ok_or(Error::<T, I>::AlreadyMember)?;
 			members
@@ -986,7 +2052,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Ok(())
 		})?;
 
-		if role == MemberRole::Fellow {
+		if T::RolePolicy::has_voting_rights(role) {
 			let members = Self::voting_members();
 			T::MembershipChanged::change_members_sorted(&[who.clone()], &[], &members[..]);
 		}
@@ -1001,24 +2067,294 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Ok(())
 		})?;
 
-		if role == MemberRole::Fellow {
+		if T::RolePolicy::has_voting_rights(role) {
 			let members = Self::voting_members();
 			T::MembershipChanged::change_members_sorted(&[], &[who.clone()], &members[..]);
 		}
 		Ok(())
 	}
 
+	/// Remove at most `witness.fellow_members` voting members and `witness.ally_members` other
+	/// members of the disbanding Alliance and release their deposits.
+	///
+	/// Completes the disbanding once no member is left. Returns what was removed by this call.
+	fn disband_members(witness: DisbandWitness) -> DisbandStatus {
+		let mut removed = DisbandStatus::default();
+		for role in T::RolePolicy::roles() {
+			let voting = T::RolePolicy::has_voting_rights(role);
+			let (limit, count) = if voting {
+				(witness.fellow_members, &mut removed.fellow_members)
+			} else {
+				(witness.ally_members, &mut removed.ally_members)
+			};
+			let mut members = Self::members_of(role);
+			let take = members.len().min(limit.saturating_sub(*count) as usize);
+			if take == 0 {
+				continue
+			}
+			*count += take as u32;
+
+			for member in members.split_off(members.len() - take) {
+				MemberSince::<T, I>::remove(&member);
+				if voting {
+					NominationsUsed::<T, I>::remove(&member);
+				}
+				if Self::deposit_of(&member).is_some() {
+					let released = DepositsOf::<T, I>::release_all(&member);
+					debug_assert!(released.is_ok());
+					removed.unreserved += 1;
+				}
+			}
+			if members.is_empty() {
+				Members::<T, I>::remove(role);
+			} else {
+				Members::<T, I>::insert(role, BoundedVec::truncate_from(members));
+			}
+		}
+
+		let progress = DisbandProgress::<T, I>::get().unwrap_or_default();
+		let total = DisbandStatus {
+			fellow_members: progress.fellow_members.saturating_add(removed.fellow_members),
+			ally_members: progress.ally_members.saturating_add(removed.ally_members),
+			unreserved: progress.unreserved.saturating_add(removed.unreserved),
+		};
+		if Self::is_initialized() {
+			DisbandProgress::<T, I>::put(total);
+			Self::deposit_event(Event::DisbandProgressed {
+				fellow_members: removed.fellow_members,
+				ally_members: removed.ally_members,
+				unreserved: removed.unreserved,
+			});
+		} else {
+			DisbandProgress::<T, I>::kill();
+			Self::deposit_event(Event::AllianceDisbanded {
+				fellow_members: total.fellow_members,
+				ally_members: total.ally_members,
+				unreserved: total.unreserved,
+			});
+		}
+		removed
+	}
+
+	/// The number of blocks from when `who` joined the Alliance or was last elevated until
+	/// block `at`, if tracked.
+	fn tenure_of(who: &T::AccountId, at: BlockNumberFor<T>) -> Option<BlockNumberFor<T>> {
+		MemberSince::<T, I>::get(who).map(|since| at.saturating_sub(since))
+	}
+
+	/// The share of its `deposit` slashed if `who` gave its retirement notice at block `notice`,
+	/// zero unless it gave it before the `MinimumTenure`.
+	pub(crate) fn early_retirement_slash(
+		who: &T::AccountId,
+		deposit: BalanceOf<T, I>,
+		notice: BlockNumberFor<T>,
+	) -> BalanceOf<T, I> {
+		match (T::MinimumTenure::get(), Self::tenure_of(who, notice)) {
+			(Some(minimum_tenure), Some(tenure)) if tenure < minimum_tenure =>
+				T::EarlyRetirementSlash::get() * deposit,
+			_ => Zero::zero(),
+		}
+	}
+
+	/// Slash the share of the deposit of `who` lost by giving its retirement notice at block
+	/// `notice`, before the `MinimumTenure`.
+	///
+	/// The block of the notice is derived from the end of the retirement period, so a change of
+	/// the `RetirementPeriod` during the retirement shifts it.
+	fn slash_early_retirement(who: &T::AccountId, notice: BlockNumberFor<T>) {
+		let Some(deposit) = Self::deposit_of(who) else { return };
+		let slashed = Self::early_retirement_slash(who, deposit, notice);
+		if slashed.is_zero() {
+			return
+		}
+		T::Slashed::on_unbalanced(DepositsOf::<T, I>::slash(who, slashed));
+		Self::deposit_event(Event::EarlyRetirementSlashed {
+			member: who.clone(),
+			slashed,
+			tenure: Self::tenure_of(who, notice).unwrap_or_default(),
+		});
+	}
+
+	/// The index of the current nomination period.
+	///
+	/// A zero `NominationPeriod`, which the integrity test rejects, makes the whole lifetime of
+	/// the chain a single period.
+	fn nomination_period() -> BlockNumberFor<T> {
+		frame_system::Pallet::<T>::block_number()
+			.checked_div(&T::NominationPeriod::get())
+			.unwrap_or_default()
+	}
+
+	/// Count a nomination of `fellow` against its quota of the current nomination period.
+	fn use_nomination_quota(fellow: &T::AccountId) -> DispatchResult {
+		let Some(quota) = T::NominationQuota::get() else { return Ok(()) };
+		let period = Self::nomination_period();
+		let used = match NominationsUsed::<T, I>::get(fellow) {
+			Some((last_period, used)) if last_period == period => used,
+			_ => 0,
+		};
+		ensure!(used < quota, Error::<T, I>::NominationQuotaExceeded);
+
+		let used = used + 1;
+		NominationsUsed::<T, I>::insert(fellow, (period, used));
+		if used == quota {
+			Self::deposit_event(Event::NominationQuotaExhausted { fellow: fellow.clone() });
+		}
+		Ok(())
+	}
+
 	/// Check if an item is listed as unscrupulous.
 	fn is_unscrupulous(info: &UnscrupulousItemOf<T, I>) -> bool {
 		match info {
-			UnscrupulousItem::Website(url) => <UnscrupulousWebsites<T, I>>::get().contains(url),
-			UnscrupulousItem::AccountId(who) => <UnscrupulousAccounts<T, I>>::get().contains(who),
+			UnscrupulousItem::Website(url) => <UnscrupulousWebsites<T, I>>::decode_contains(url),
+			UnscrupulousItem::AccountId(who) => <UnscrupulousAccounts<T, I>>::decode_contains(who),
 		}
 	}
 
 	/// Check if an account is listed as unscrupulous.
 	fn is_unscrupulous_account(who: &T::AccountId) -> bool {
-		<UnscrupulousAccounts<T, I>>::get().contains(who)
+		<UnscrupulousAccounts<T, I>>::decode_contains(who)
+	}
+
+	/// Whether `cid` is the current rule or an announcement.
+	fn is_published(cid: &Cid) -> bool {
+		Self::rule().as_ref() == Some(cid) || <Announcements<T, I>>::decode_contains(cid)
+	}
+
+	/// Add an announcement, failing if it is not a valid CID or there are too many already.
+	fn do_announce(announcement: Cid) -> DispatchResult {
+		announcement.validate().map_err(|_| Error::<T, I>::InvalidCid)?;
+		let mut announcements = <Announcements<T, I>>::get();
+		announcements
+			.try_push(announcement.clone())
+			.map_err(|_| Error::<T, I>::TooManyAnnouncements)?;
+		<Announcements<T, I>>::put(announcements);
+
+		Self::deposit_event(Event::Announced { announcement });
+		Ok(())
+	}
+
+	/// The weight of adding `n` unscrupulous items, of which `penalized` are members.
+	fn add_unscrupulous_items_weight(n: u32, penalized: u32) -> Weight {
+		T::WeightInfo::add_unscrupulous_items(n, T::MaxWebsiteUrlLength::get())
+			.saturating_add(T::WeightInfo::enforce_unscrupulous().saturating_mul(penalized.into()))
+	}
+
+	/// `items` to be listed as unscrupulous without evidence.
+	fn without_evidence(items: Vec<UnscrupulousItemOf<T, I>>) -> Vec<UnscrupulousEntryOf<T, I>> {
+		items.into_iter().map(|item| (item, None)).collect()
+	}
+
+	/// Check that none of `items` is listed yet and add them to the unscrupulous list, together
+	/// with their evidence, then penalize the listed members.
+	///
+	/// Returns the number of penalized members.
+	fn add_unscrupulous_items_checked(
+		items: Vec<UnscrupulousEntryOf<T, I>>,
+	) -> Result<u32, DispatchError> {
+		let mut accounts = vec![];
+		let mut webs = vec![];
+		for (info, evidence) in items.iter() {
+			ensure!(!Self::is_unscrupulous(info), Error::<T, I>::AlreadyUnscrupulous);
+			if let Some(evidence) = evidence {
+				evidence.validate().map_err(|_| Error::<T, I>::InvalidCid)?;
+			}
+			match info {
+				UnscrupulousItem::AccountId(who) => accounts.push(who.clone()),
+				UnscrupulousItem::Website(url) => {
+					ensure!(
+						url.len() as u32 <= T::MaxWebsiteUrlLength::get(),
+						Error::<T, I>::TooLongWebsiteUrl
+					);
+					webs.push(url.clone());
+				},
+			}
+		}
+
+		let members: Vec<_> = accounts.iter().filter(|who| Self::is_member(who)).cloned().collect();
+		Self::do_add_unscrupulous_items(&mut accounts, &mut webs)?;
+		for (info, evidence) in items.iter() {
+			if let Some(evidence) = evidence {
+				<UnscrupulousEvidence<T, I>>::insert(info, evidence);
+			}
+		}
+		Self::deposit_event(Event::UnscrupulousItemAdded { items });
+
+		for member in members.iter() {
+			Self::penalize_unscrupulous_member(member)?;
+		}
+		Ok(members.len() as u32)
+	}
+
+	/// Kick or suspend `member`, whose account is listed as unscrupulous, as configured by
+	/// `UnscrupulousMemberPenalty` and slash the `UnscrupulousMemberSlash` share of its deposit.
+	///
+	/// Suspending a suspended member changes the end of the suspension only if it is later.
+	fn penalize_unscrupulous_member(member: &T::AccountId) -> DispatchResult {
+		let role = Self::member_role_of(member).ok_or(Error::<T, I>::NotMember)?;
+		let slashed = Self::deposit_of(member)
+			.map(|deposit| T::UnscrupulousMemberSlash::get() * deposit)
+			.filter(|slashed| !slashed.is_zero());
+
+		match T::UnscrupulousMemberPenalty::get() {
+			UnscrupulousPenalty::Kick => {
+				Self::remove_member(member, role)?;
+				<RetiringMembers<T, I>>::remove(member);
+				<SuspendedMembers<T, I>>::remove(member);
+				<MemberSince<T, I>>::remove(member);
+				<NominationsUsed<T, I>>::remove(member);
+				if let Some(slashed) = slashed {
+					T::Slashed::on_unbalanced(DepositsOf::<T, I>::slash(member, slashed));
+				}
+				if Self::deposit_of(member).is_some() {
+					DepositsOf::<T, I>::release_all(member)?;
+				}
+
+				Self::deposit_event(Event::UnscrupulousMemberKicked {
+					member: member.clone(),
+					slashed,
+				});
+			},
+			UnscrupulousPenalty::Suspend(duration) => {
+				let now = frame_system::Pallet::<T>::block_number();
+				let until = now.saturating_add(duration);
+				let until = <SuspendedMembers<T, I>>::get(member).map_or(until, |u| u.max(until));
+				<SuspendedMembers<T, I>>::insert(member, until);
+				<UnscrupulousPenalizedAt<T, I>>::insert(member, now);
+				if let Some(slashed) = slashed {
+					T::Slashed::on_unbalanced(DepositsOf::<T, I>::slash(member, slashed));
+				}
+
+				Self::deposit_event(Event::UnscrupulousMemberSuspended {
+					member: member.clone(),
+					until,
+					slashed,
+				});
+			},
+		}
+		Ok(())
+	}
+
+	/// Check that all `items` are listed and remove them from the unscrupulous list.
+	fn remove_unscrupulous_items_checked(items: Vec<UnscrupulousItemOf<T, I>>) -> DispatchResult {
+		let mut accounts = vec![];
+		let mut webs = vec![];
+		for info in items.iter() {
+			ensure!(Self::is_unscrupulous(info), Error::<T, I>::NotListedAsUnscrupulous);
+			match info {
+				UnscrupulousItem::AccountId(who) => accounts.push(who.clone()),
+				UnscrupulousItem::Website(url) => webs.push(url.clone()),
+			}
+		}
+		Self::do_remove_unscrupulous_items(&mut accounts, &mut webs)?;
+		for info in items.iter() {
+			<UnscrupulousEvidence<T, I>>::remove(info);
+		}
+		for who in accounts.iter() {
+			<UnscrupulousPenalizedAt<T, I>>::remove(who);
+		}
+		Self::deposit_event(Event::UnscrupulousItemRemoved { items });
+		Ok(())
 	}
 
 	/// Add item to the unscrupulous list.
@@ -1102,18 +2438,237 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		res
 	}
 
+	/// Check that `who` has a judged identity of its own, as required for Fellows.
+	fn has_fellow_identity(who: &T::AccountId) -> DispatchResult {
+		Self::has_identity(who)?;
+		ensure!(
+			T::IdentityVerifier::has_required_identities(who) &&
+				T::IdentityVerifier::has_good_judgement(who),
+			Error::<T, I>::WithoutOwnIdentity
+		);
+		Ok(())
+	}
+
 	fn do_close(
 		proposal_hash: T::Hash,
 		index: ProposalIndex,
 		proposal_weight_bound: Weight,
 		length_bound: u32,
 	) -> DispatchResultWithPostInfo {
-		let info = T::ProposalProvider::close_proposal(
-			proposal_hash,
-			index,
-			proposal_weight_bound,
-			length_bound,
-		)?;
-		Ok(info.into())
+		let (info, tally) =
+			T::VotingProvider::close(proposal_hash, index, proposal_weight_bound, length_bound)?;
+		Self::deposit_event(Event::ProposalClosed {
+			hash: proposal_hash,
+			ayes: tally.ayes,
+			nays: tally.nays,
+			threshold: tally.threshold,
+			outcome: tally.outcome,
+		});
+		Ok(info)
+	}
+
+	/// The worst case weight of closing a proposal of `length` bytes that weighs
+	/// `proposal_weight`.
+	fn close_weight(length: u32, proposal_weight: Weight) -> Weight {
+		T::VotingProvider::close_weight(length).saturating_add(proposal_weight)
+	}
+
+	/// The weight of `vote`, including queueing the proposal to be closed automatically if
+	/// `AutoClose` is enabled.
+	fn vote_weight() -> Weight {
+		let weight = T::VotingProvider::vote_weight().saturating_add(Self::voting_checks_weight());
+		if T::AutoClose::get() {
+			weight.saturating_add(T::WeightInfo::queue_decided_proposal())
+		} else {
+			weight
+		}
+	}
+
+	/// Queue the proposal to be closed automatically if its outcome is final.
+	fn queue_if_decided(proposal: T::Hash, index: ProposalIndex) {
+		if !T::VotingProvider::is_decided(proposal) {
+			return
+		}
+		DecidedProposals::<T, I>::mutate(|decided| {
+			if !decided.contains(&(proposal, index)) {
+				// The queue holds up to `MaxProposals`, so there is always room.
+				let _ = decided.try_push((proposal, index));
+			}
+		});
+	}
+
+	/// The weight of checking that the caller has voting rights and is not suspended, done by the
+	/// pallet before handing over to the `VotingProvider`.
+	fn voting_checks_weight() -> Weight {
+		// The members of every voting role and the suspension of the caller are read.
+		T::DbWeight::get().reads(Self::voting_roles().count() as u64 + 1)
+	}
+
+	/// Close the queued decided proposals, oldest first, until closing the next one could exceed
+	/// `limit`. Returns the weight used.
+	///
+	/// Proposals that were closed in the meantime are dropped from the queue, as are proposals
+	/// too heavy to ever be closed within `limit`; those must be closed with `close`.
+	fn close_decided_proposals(limit: Weight) -> Weight {
+		let decided = DecidedProposals::<T, I>::get();
+		if decided.is_empty() {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut weight = T::DbWeight::get().reads_writes(1, 1);
+		let mut remaining = decided.into_inner().into_iter();
+		while let Some((proposal_hash, index)) = remaining.next() {
+			weight.saturating_accrue(T::DbWeight::get().reads(1));
+			let Some(proposal) = T::VotingProvider::proposal_of(proposal_hash) else { continue };
+			let length = proposal.encoded_size() as u32;
+			let proposal_weight = proposal.get_dispatch_info().weight;
+			let close_weight = Self::close_weight(length, proposal_weight);
+			if close_weight.any_gt(limit) {
+				continue
+			}
+			if weight.saturating_add(close_weight).any_gt(limit) {
+				DecidedProposals::<T, I>::put(BoundedVec::<_, T::MaxProposals>::truncate_from(
+					sp_std::iter::once((proposal_hash, index)).chain(remaining).collect(),
+				));
+				return weight
+			}
+
+			// A failed close can't be retried, the proposal is dropped from the queue either way.
+			if let Err(e) = Self::do_close(proposal_hash, index, proposal_weight, length) {
+				log::debug!(
+					target: LOG_TARGET,
+					"failed to close decided proposal {:?}: {:?}",
+					proposal_hash,
+					e,
+				);
+			}
+			weight.saturating_accrue(close_weight);
+		}
+
+		DecidedProposals::<T, I>::kill();
+		weight
+	}
+
+	/// Wrap `proposal` into [`Call::schedule_enactment`] if there is an `EnactmentDelay`, and
+	/// extend `length_bound` by the length of the wrapper.
+	///
+	/// Calls managing the enactments themselves are not delayed, so that malicious proposals can
+	/// be cancelled in time.
+	fn with_enactment_delay(
+		proposal: Box<<T as Config<I>>::Proposal>,
+		length_bound: u32,
+	) -> (Box<<T as Config<I>>::Proposal>, u32) {
+		if T::EnactmentDelay::get().is_zero() ||
+			matches!(
+				proposal.is_sub_type(),
+				Some(Call::schedule_enactment { .. } | Call::cancel_enactment { .. })
+			) {
+			return (proposal, length_bound)
+		}
+
+		let length = proposal.encoded_size() as u32;
+		let wrapped: <T as Config<I>>::Proposal =
+			Call::<T, I>::schedule_enactment { proposal }.into();
+		let overhead = (wrapped.encoded_size() as u32).saturating_sub(length);
+		(Box::new(wrapped), length_bound.saturating_add(overhead))
+	}
+
+	/// The weight of enacting a proposal of the given `weight` on its own in
+	/// [`Self::enact_due_proposals`].
+	fn enactment_cost(weight: Weight) -> Weight {
+		// `EnactmentBlocks` and the `PendingEnactments` of the block are read and written.
+		T::DbWeight::get().reads_writes(2, 2).saturating_add(weight)
+	}
+
+	/// Enact the approved proposals whose enactment block is reached, oldest first, as long as
+	/// they fit in `limit`. Proposals that don't fit are left for a later block, without holding
+	/// back lighter ones. Returns the weight used.
+	fn enact_due_proposals(now: BlockNumberFor<T>, limit: Weight) -> Weight {
+		let mut blocks = EnactmentBlocks::<T, I>::get();
+		let mut weight = T::DbWeight::get().reads(1);
+		let due = blocks.iter().take_while(|when| **when <= now).count();
+		if due == 0 {
+			return weight
+		}
+
+		weight.saturating_accrue(T::DbWeight::get().writes(1));
+		let mut emptied = Vec::new();
+		for when in blocks.iter().take(due) {
+			let queue_weight = T::DbWeight::get().reads_writes(1, 1);
+			if weight.saturating_add(queue_weight).any_gt(limit) {
+				break
+			}
+			weight.saturating_accrue(queue_weight);
+
+			let pending = PendingEnactments::<T, I>::take(when);
+			let mut remaining = Vec::new();
+			for enactment in pending.into_inner() {
+				let info = enactment.proposal.get_dispatch_info();
+				if weight.saturating_add(info.weight).any_gt(limit) {
+					remaining.push(enactment);
+					continue
+				}
+
+				let result = enactment.proposal.dispatch(enactment.origin.into());
+				weight.saturating_accrue(extract_actual_weight(&result, &info));
+				Self::deposit_event(Event::ProposalEnacted {
+					index: enactment.index,
+					result: result.map(|_| ()).map_err(|e| e.error),
+				});
+			}
+
+			if remaining.is_empty() {
+				emptied.push(*when);
+			} else {
+				// Fewer than were taken, so they always fit.
+				PendingEnactments::<T, I>::insert(
+					when,
+					BoundedVec::<_, T::MaxProposals>::defensive_truncate_from(remaining),
+				);
+			}
+		}
+
+		blocks.retain(|when| !emptied.contains(when));
+		EnactmentBlocks::<T, I>::put(blocks);
+		weight
+	}
+}
+
+sp_api::decl_runtime_apis! {
+	/// This runtime api allows off-chain archives to follow how the Alliance's rule evolved, and
+	/// wallets to render the state of the Alliance without querying its storage.
+	#[api_version(3)]
+	pub trait AllianceApi<AccountId, Balance, BlockNumber, Hash>
+	where
+		AccountId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+		Hash: Codec,
+	{
+		/// The rules set so far together with the block they were set at, oldest first.
+		///
+		/// Only the last [`Config::MaxRuleHistory`] rules are kept.
+		fn rule_history() -> Vec<(BlockNumber, Cid)>;
+
+		/// The members holding `role`.
+		#[api_version(2)]
+		fn members(role: MemberRole) -> Vec<AccountId>;
+
+		/// The hashes of the proposals currently being voted on.
+		#[api_version(2)]
+		fn active_proposals() -> Vec<Hash>;
+
+		/// The deposit held from `who` for being a member, if any.
+		#[api_version(2)]
+		fn deposit_of(who: AccountId) -> Option<Balance>;
+
+		/// The accounts and websites deemed unscrupulous.
+		#[api_version(2)]
+		fn unscrupulous_items() -> Vec<UnscrupulousItem<AccountId, Vec<u8>>>;
+
+		/// A snapshot of the Alliance to be imported elsewhere with
+		/// [`Call::force_import_snapshot`].
+		#[api_version(3)]
+		fn export_snapshot() -> AllianceSnapshot<AccountId, Balance>;
 	}
 }
@@ -20,9 +20,12 @@ use frame_support::{pallet_prelude::*, storage::migration, traits::OnRuntimeUpgr
 use log;
 
 /// The current storage version.
-pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
-/// Wrapper for all migrations of this pallet.
+/// Wrapper for the migrations of this pallet up to version 2.
+///
+/// The migration to version 3 needs the currency deposits used to be reserved in, see
+/// [`v2_to_v3::MigrateToHolds`].
 pub fn migrate<T: Config<I>, I: 'static>() -> Weight {
 	let onchain_version = Pallet::<T, I>::on_chain_storage_version();
 	let mut weight: Weight = Weight::zero();
@@ -35,7 +38,9 @@ pub fn migrate<T: Config<I>, I: 'static>() -> Weight {
 		weight = weight.saturating_add(v1_to_v2::migrate::<T, I>());
 	}
 
-	STORAGE_VERSION.put::<Pallet<T, I>>();
+	if onchain_version < 2 {
+		StorageVersion::new(2).put::<Pallet<T, I>>();
+	}
 	weight = weight.saturating_add(T::DbWeight::get().writes(1));
 
 	weight
@@ -159,10 +164,86 @@ pub(crate) mod v1_to_v2 {
 	}
 }
 
+/// v2_to_v3: deposits are held for [`crate::HoldReason::AllyDeposit`] instead of being reserved
+/// and recorded in `DepositOf`.
+pub mod v2_to_v3 {
+	use super::*;
+	use crate::{BalanceOf, DepositsOf};
+	use frame_support::{storage_alias, traits::ReservableCurrency};
+	use sp_std::vec::Vec;
+
+	/// Maps members to their candidacy deposit.
+	#[storage_alias]
+	pub(crate) type DepositOf<T: Config<I>, I: 'static> = StorageMap<
+		Pallet<T, I>,
+		Blake2_128Concat,
+		<T as frame_system::Config>::AccountId,
+		BalanceOf<T, I>,
+	>;
+
+	/// Moves the reserved deposits of the members to holds.
+	///
+	/// `OldCurrency` is the currency the deposits were reserved in. It must be backed by the same
+	/// balances as `T::Currency`.
+	pub struct MigrateToHolds<T, OldCurrency, I = ()>(PhantomData<(T, OldCurrency, I)>);
+
+	impl<T, OldCurrency, I> OnRuntimeUpgrade for MigrateToHolds<T, OldCurrency, I>
+	where
+		T: Config<I>,
+		OldCurrency: ReservableCurrency<T::AccountId, Balance = BalanceOf<T, I>>,
+		I: 'static,
+	{
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T, I>::on_chain_storage_version() != 2 {
+				log::info!(target: LOG_TARGET, "Skipping migration v2_to_v3.");
+				return T::DbWeight::get().reads(1)
+			}
+			log::info!(target: LOG_TARGET, "Running migration v2_to_v3: deposits are held.");
+
+			let mut count: u64 = 0;
+			// The entries are only removed once their deposit is held, so that the deposits which
+			// couldn't be moved are still recorded.
+			let deposits = DepositOf::<T, I>::iter().collect::<Vec<_>>();
+			for (who, deposit) in deposits {
+				count += 1;
+				match DepositsOf::<T, I>::migrate_from_reserve::<OldCurrency>(&who, deposit) {
+					Ok(moved) => {
+						if moved != deposit {
+							log::warn!(
+								target: LOG_TARGET,
+								"Only {:?} of the deposit {:?} of {:?} was reserved.",
+								moved,
+								deposit,
+								who,
+							);
+						}
+						DepositOf::<T, I>::remove(&who);
+					},
+					Err(e) => log::error!(
+						target: LOG_TARGET,
+						"Failed to hold the deposit {:?} of {:?}, it stays reserved: {:?}",
+						deposit,
+						who,
+						e,
+					),
+				}
+			}
+			log::info!(target: LOG_TARGET, "Moved {} deposits to holds.", count);
+
+			StorageVersion::new(3).put::<Pallet<T, I>>();
+			// One write to remove the entry, two to unreserve and hold per deposit.
+			T::DbWeight::get().reads_writes(
+				count.saturating_mul(2).saturating_add(1),
+				count.saturating_mul(3).saturating_add(1),
+			)
+		}
+	}
+}
+
 #[cfg(test)]
 mod test {
 	use super::*;
-	use crate::{mock::*, MemberRole};
+	use crate::{mock::*, HoldReason, MemberRole};
 
 	#[test]
 	fn migration_v1_to_v2_works() {
@@ -176,4 +257,23 @@ mod test {
 			assert_eq!(Alliance::members(MemberRole::Retiring), vec![]);
 		});
 	}
+
+	#[test]
+	fn migration_v2_to_v3_works() {
+		use frame_support::traits::{fungible::InspectHold, ReservableCurrency};
+
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(2).put::<Pallet<Test, ()>>();
+			assert_ok!(Balances::reserve(&4, 25));
+			v2_to_v3::DepositOf::<Test, ()>::insert(4, 25);
+
+			v2_to_v3::MigrateToHolds::<Test, Balances>::on_runtime_upgrade();
+
+			assert_eq!(Pallet::<Test, ()>::on_chain_storage_version(), 3);
+			assert_eq!(Balances::reserved_balance(&4), 25);
+			assert_eq!(Balances::balance_on_hold(&HoldReason::AllyDeposit.into(), &4), 25);
+			assert_eq!(Alliance::deposit_of(&4), Some(25));
+			assert!(!v2_to_v3::DepositOf::<Test, ()>::contains_key(4));
+		});
+	}
 }
@@ -19,17 +19,21 @@
 
 use core::convert::{TryFrom, TryInto};
 pub use sp_core::H256;
-use sp_runtime::traits::Hash;
+use sp_runtime::{
+	testing::{TestSignature, TestXt, UintAuthorityId},
+	traits::Hash,
+};
 pub use sp_runtime::{
 	traits::{BlakeTwo256, IdentifyAccount, Lazy, Verify},
 	BuildStorage,
 };
 
+use frame_support::dispatch::DispatchErrorWithPostInfo;
 pub use frame_support::{
 	assert_noop, assert_ok, derive_impl, ord_parameter_types, parameter_types,
 	traits::EitherOfDiverse, BoundedVec,
 };
-use frame_system::{EnsureRoot, EnsureSignedBy};
+use frame_system::{offchain::AppCrypto, EnsureRoot, EnsureSignedBy};
 use pallet_identity::{
 	legacy::{IdentityField, IdentityInfo},
 	Data, Judgement,
@@ -70,7 +74,7 @@ impl pallet_balances::Config for Test {
 	type ReserveIdentifier = [u8; 8];
 	type FreezeIdentifier = ();
 	type MaxFreezes = ();
-	type RuntimeHoldReason = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type RuntimeFreezeReason = ();
 }
 
@@ -178,37 +182,123 @@ impl IdentityVerifier<AccountId> for AllianceIdentityVerifier {
 	}
 }
 
-pub struct AllianceProposalProvider;
-impl ProposalProvider<AccountId, H256, RuntimeCall> for AllianceProposalProvider {
-	fn propose_proposal(
+/// The Observer tier of [`TestRolePolicy`].
+pub const OBSERVER: MemberRole = MemberRole::Custom(0);
+
+/// The default two-tier policy, with an Observer tier below the Allies if `ObserverTier` is set.
+pub struct TestRolePolicy;
+impl RolePolicy for TestRolePolicy {
+	fn roles() -> Vec<MemberRole> {
+		if ObserverTier::get() {
+			vec![OBSERVER, MemberRole::Ally, MemberRole::Fellow]
+		} else {
+			<() as RolePolicy>::roles()
+		}
+	}
+
+	fn has_voting_rights(role: MemberRole) -> bool {
+		<() as RolePolicy>::has_voting_rights(role)
+	}
+}
+
+pub type Collective = CollectiveVoting<Test, AllianceCollective>;
+
+/// Votes in the `AllianceMotion` collective, or without a voting backend if `NoVotingBackend` is
+/// set.
+pub struct TestVoting;
+impl VotingProvider<AccountId, H256, RuntimeCall> for TestVoting {
+	type Vote = bool;
+
+	fn propose(
 		who: AccountId,
 		threshold: u32,
 		proposal: Box<RuntimeCall>,
 		length_bound: u32,
 	) -> Result<(u32, u32), DispatchError> {
-		AllianceMotion::do_propose_proposed(who, threshold, proposal, length_bound)
+		if NoVotingBackend::get() {
+			<() as VotingProvider<_, H256, _>>::propose(who, threshold, proposal, length_bound)
+		} else {
+			Collective::propose(who, threshold, proposal, length_bound)
+		}
 	}
 
-	fn vote_proposal(
+	fn vote(
 		who: AccountId,
 		proposal: H256,
 		index: ProposalIndex,
-		approve: bool,
+		vote: bool,
 	) -> Result<bool, DispatchError> {
-		AllianceMotion::do_vote(who, proposal, index, approve)
+		if NoVotingBackend::get() {
+			<() as VotingProvider<_, _, RuntimeCall>>::vote(who, proposal, index, vote)
+		} else {
+			Collective::vote(who, proposal, index, vote)
+		}
 	}
 
-	fn close_proposal(
+	fn close(
 		proposal_hash: H256,
-		proposal_index: ProposalIndex,
+		index: ProposalIndex,
 		proposal_weight_bound: Weight,
 		length_bound: u32,
-	) -> DispatchResultWithPostInfo {
-		AllianceMotion::do_close(proposal_hash, proposal_index, proposal_weight_bound, length_bound)
+	) -> Result<(PostDispatchInfo, ProposalTally), DispatchErrorWithPostInfo> {
+		if NoVotingBackend::get() {
+			<() as VotingProvider<AccountId, _, RuntimeCall>>::close(
+				proposal_hash,
+				index,
+				proposal_weight_bound,
+				length_bound,
+			)
+		} else {
+			Collective::close(proposal_hash, index, proposal_weight_bound, length_bound)
+		}
 	}
 
 	fn proposal_of(proposal_hash: H256) -> Option<RuntimeCall> {
-		AllianceMotion::proposal_of(proposal_hash)
+		if NoVotingBackend::get() {
+			<() as VotingProvider<AccountId, _, _>>::proposal_of(proposal_hash)
+		} else {
+			Collective::proposal_of(proposal_hash)
+		}
+	}
+
+	fn is_decided(proposal_hash: H256) -> bool {
+		if NoVotingBackend::get() {
+			<() as VotingProvider<AccountId, _, RuntimeCall>>::is_decided(proposal_hash)
+		} else {
+			Collective::is_decided(proposal_hash)
+		}
+	}
+
+	fn proposals() -> Vec<H256> {
+		if NoVotingBackend::get() {
+			<() as VotingProvider<AccountId, _, RuntimeCall>>::proposals()
+		} else {
+			Collective::proposals()
+		}
+	}
+
+	fn propose_weight(length: u32) -> Weight {
+		if NoVotingBackend::get() {
+			<() as VotingProvider<AccountId, H256, RuntimeCall>>::propose_weight(length)
+		} else {
+			Collective::propose_weight(length)
+		}
+	}
+
+	fn vote_weight() -> Weight {
+		if NoVotingBackend::get() {
+			<() as VotingProvider<AccountId, H256, RuntimeCall>>::vote_weight()
+		} else {
+			Collective::vote_weight()
+		}
+	}
+
+	fn close_weight(length: u32) -> Weight {
+		if NoVotingBackend::get() {
+			<() as VotingProvider<AccountId, H256, RuntimeCall>>::close_weight(length)
+		} else {
+			Collective::close_weight(length)
+		}
 	}
 }
 
@@ -217,13 +307,37 @@ parameter_types! {
 	pub const MaxAllies: u32 = 100;
 	pub const AllyDeposit: u64 = 25;
 	pub const RetirementPeriod: BlockNumber = MOTION_DURATION_IN_BLOCKS + 1;
+	pub static AutoClose: bool = false;
+	pub static AutoCloseWeight: Weight = Weight::MAX;
+	pub static NominationQuota: Option<u32> = None;
+	pub static ObserverTier: bool = false;
+	pub static NoVotingBackend: bool = false;
+	pub static OnlyAllianceProposals: bool = false;
+	pub static MinimumTenure: Option<BlockNumber> = None;
+	pub static EarlyRetirementSlash: Perbill = Perbill::zero();
+	pub static EnactmentDelay: BlockNumber = 0;
+	pub static UnscrupulousMemberPenalty: UnscrupulousPenalty<BlockNumber> =
+		UnscrupulousPenalty::Kick;
+	pub static UnscrupulousMemberSlash: Perbill = Perbill::zero();
+	pub static UnscrupulousPenaltyCooldown: BlockNumber = 0;
 }
+
+/// Allows any proposal, or only calls into the Alliance if `OnlyAllianceProposals` is set.
+pub struct TestProposalFilter;
+impl Contains<RuntimeCall> for TestProposalFilter {
+	fn contains(call: &RuntimeCall) -> bool {
+		!OnlyAllianceProposals::get() || matches!(call, RuntimeCall::Alliance(..))
+	}
+}
+
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Proposal = RuntimeCall;
+	type ProposalFilter = TestProposalFilter;
 	type AdminOrigin = EnsureSignedBy<One, AccountId>;
 	type MembershipManager = EnsureSignedBy<Two, AccountId>;
 	type AnnouncementOrigin = EnsureSignedBy<Three, AccountId>;
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type Currency = Balances;
 	type Slashed = ();
 	type InitializeMembers = AllianceMotion;
@@ -232,7 +346,8 @@ impl Config for Test {
 	type IdentityVerifier = AllianceIdentityVerifier;
 	#[cfg(feature = "runtime-benchmarks")]
 	type IdentityVerifier = ();
-	type ProposalProvider = AllianceProposalProvider;
+	type RolePolicy = TestRolePolicy;
+	type VotingProvider = TestVoting;
 	type MaxProposals = MaxProposals;
 	type MaxFellows = MaxFellows;
 	type MaxAllies = MaxAllies;
@@ -243,6 +358,59 @@ impl Config for Test {
 	type AllyDeposit = AllyDeposit;
 	type WeightInfo = ();
 	type RetirementPeriod = RetirementPeriod;
+	type MaxSubCommittees = ConstU32<2>;
+	type MaxSubCommitteeMembers = ConstU32<3>;
+	type AutoClose = AutoClose;
+	type AutoCloseWeight = AutoCloseWeight;
+	type MaxRuleHistory = ConstU32<2>;
+	type FlagSigner = offchain::SignedBy<TestAuthorityId>;
+	type NominationQuota = NominationQuota;
+	type NominationPeriod = ConstU64<10>;
+	type MinimumTenure = MinimumTenure;
+	type EarlyRetirementSlash = EarlyRetirementSlash;
+	type EnactmentDelay = EnactmentDelay;
+	type UnscrupulousMemberPenalty = UnscrupulousMemberPenalty;
+	type UnscrupulousMemberSlash = UnscrupulousMemberSlash;
+	type UnscrupulousPenaltyCooldown = UnscrupulousPenaltyCooldown;
+	type EnactmentOrigin =
+		WithCaller<pallet_collective::EnsureMembers<AccountId, AllianceCollective, 1>>;
+	type PalletsOrigin = OriginCaller;
+}
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Test
+where
+	RuntimeCall: From<C>,
+{
+	type OverarchingCall = RuntimeCall;
+	type Extrinsic = TestXt<RuntimeCall, ()>;
+}
+
+impl frame_system::offchain::SigningTypes for Test {
+	type Public = UintAuthorityId;
+	type Signature = TestSignature;
+}
+
+impl<C> frame_system::offchain::CreateSignedTransaction<C> for Test
+where
+	RuntimeCall: From<C>,
+{
+	fn create_transaction<A: AppCrypto<UintAuthorityId, TestSignature>>(
+		call: RuntimeCall,
+		_public: UintAuthorityId,
+		account: AccountId,
+		_nonce: u64,
+	) -> Option<(RuntimeCall, (AccountId, ()))> {
+		Some((call, (account, ())))
+	}
+}
+
+/// Signs with the keys set through [`UintAuthorityId::set_all_keys`].
+pub struct TestAuthorityId;
+
+impl AppCrypto<UintAuthorityId, TestSignature> for TestAuthorityId {
+	type RuntimeAppPublic = UintAuthorityId;
+	type GenericPublic = UintAuthorityId;
+	type GenericSignature = TestSignature;
 }
 
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -0,0 +1,180 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Off-chain worker checking that the rule and announcements resolve on IPFS.
+//!
+//! The worker is optional: runtimes enable it by setting [`Config::FlagSigner`] to
+//! [`SignedBy`], which needs the runtime to implement [`CreateSignedTransaction`]. It still does
+//! nothing unless the node operator sets the persistent local storage key [`IPFS_GATEWAY_KEY`]
+//! to the URL of an IPFS gateway, e.g. `https://ipfs.io`, for example through
+//! the `offchain_localStorageSet` RPC. It then requests `<gateway>/ipfs/<cid>` for every CID that
+//! it didn't check before. CIDs the gateway fails to serve are reported with a
+//! `flag_unreachable_cid` transaction and end up in [`FlaggedCids`] for the `AdminOrigin` to
+//! review. If the gateway itself can't be reached, nothing is flagged.
+//!
+//! The transaction is signed with a [`crypto`] key of type [`KEY_TYPE`] from the node's keystore,
+//! inserted e.g. through the `author_insertKey` RPC. Its account must be a voting member of the
+//! Alliance who is not suspended, otherwise the transaction fails.
+
+use super::*;
+use frame_support::traits::PalletInfoAccess;
+use frame_system::offchain::{AppCrypto, CreateSignedTransaction, SendSignedTransaction, Signer};
+use sp_core::crypto::KeyTypeId;
+use sp_runtime::offchain::{http, storage::StorageValueRef, Duration, StorageKind};
+
+/// The key type of the keys signing the transactions of the off-chain worker.
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"alli");
+
+/// The sr25519 keys of type [`KEY_TYPE`] signing the transactions of the off-chain worker.
+pub mod crypto {
+	use super::KEY_TYPE;
+	use sp_runtime::{
+		app_crypto::{app_crypto, sr25519},
+		MultiSignature, MultiSigner,
+	};
+	app_crypto!(sr25519, KEY_TYPE);
+
+	/// The keys of runtimes using [`MultiSignature`], see [`SignedBy`](super::SignedBy).
+	pub struct AuthorityId;
+
+	impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for AuthorityId {
+		type RuntimeAppPublic = Public;
+		type GenericSignature = sp_core::sr25519::Signature;
+		type GenericPublic = sp_core::sr25519::Public;
+	}
+}
+
+/// Signs and submits the transactions of the off-chain worker flagging unreachable CIDs.
+///
+/// `()` disables the off-chain worker.
+pub trait FlagSigner<T: Config<I>, I: 'static> {
+	/// Whether the off-chain worker checks CIDs at all.
+	const ENABLED: bool = true;
+
+	/// Submit a `flag_unreachable_cid` transaction for `cid`.
+	///
+	/// Returns `None` if there is no key to sign it with.
+	fn flag_unreachable_cid(cid: &Cid) -> Option<Result<(), ()>>;
+}
+
+impl<T: Config<I>, I: 'static> FlagSigner<T, I> for () {
+	const ENABLED: bool = false;
+
+	fn flag_unreachable_cid(_cid: &Cid) -> Option<Result<(), ()>> {
+		None
+	}
+}
+
+/// Signs the transactions of the off-chain worker with any key of `AuthorityId`, e.g.
+/// [`crypto::AuthorityId`], from the node's keystore.
+pub struct SignedBy<AuthorityId>(PhantomData<AuthorityId>);
+
+impl<T, I, AuthorityId> FlagSigner<T, I> for SignedBy<AuthorityId>
+where
+	T: Config<I> + CreateSignedTransaction<Call<T, I>>,
+	I: 'static,
+	AuthorityId: AppCrypto<T::Public, T::Signature>,
+{
+	fn flag_unreachable_cid(cid: &Cid) -> Option<Result<(), ()>> {
+		Signer::<T, AuthorityId>::any_account()
+			.send_signed_transaction(|_| Call::<T, I>::flag_unreachable_cid { cid: cid.clone() })
+			.map(|(_, result)| result)
+	}
+}
+
+/// The persistent local storage key holding the URL of the IPFS gateway to use.
+pub const IPFS_GATEWAY_KEY: &[u8] = b"alliance::ipfs-gateway";
+
+/// The maximum number of CIDs requested in a single run of the off-chain worker.
+const MAX_CHECKS_PER_RUN: usize = 4;
+
+/// How long the gateway is given to answer a request.
+const REQUEST_TIMEOUT: Duration = Duration::from_millis(5_000);
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Check the published CIDs not checked before, flagging the ones failing to resolve.
+	pub(crate) fn check_cids() {
+		if !T::FlagSigner::ENABLED {
+			return
+		}
+		let Some(gateway) =
+			sp_io::offchain::local_storage_get(StorageKind::PERSISTENT, IPFS_GATEWAY_KEY)
+		else {
+			return
+		};
+
+		let unchecked = Self::rule()
+			.into_iter()
+			.chain(Self::announcements())
+			.filter(|cid| !FlaggedCids::<T, I>::contains_key(cid))
+			.map(|cid| {
+				let key = (b"alliance::checked::", Self::name(), &cid).encode();
+				(cid, key)
+			})
+			.filter(|(_, key)| {
+				!matches!(StorageValueRef::persistent(key).get::<()>(), Ok(Some(())))
+			})
+			.take(MAX_CHECKS_PER_RUN);
+
+		for (cid, key) in unchecked {
+			match Self::resolves(&gateway, &cid) {
+				Ok(true) => {},
+				Ok(false) => match T::FlagSigner::flag_unreachable_cid(&cid) {
+					Some(Ok(())) => {},
+					Some(Err(())) => {
+						log::warn!(
+							target: LOG_TARGET,
+							"failed to flag unreachable CID {:?}",
+							cid
+						);
+						continue
+					},
+					None => {
+						log::warn!(target: LOG_TARGET, "no key to flag unreachable CIDs with");
+						return
+					},
+				},
+				Err(error) => {
+					log::debug!(target: LOG_TARGET, "IPFS gateway unavailable: {:?}", error);
+					return
+				},
+			}
+			StorageValueRef::persistent(&key).set(&());
+		}
+	}
+
+	/// Whether `gateway` serves `cid`.
+	///
+	/// Returns an error if the gateway didn't answer.
+	fn resolves(gateway: &[u8], cid: &Cid) -> Result<bool, http::Error> {
+		let mut url = gateway.strip_suffix(b"/").unwrap_or(gateway).to_vec();
+		url.extend_from_slice(b"/ipfs/");
+		url.extend(cid.to_text());
+		let url = sp_std::str::from_utf8(&url).map_err(|_| http::Error::Unknown)?;
+
+		let deadline = sp_io::offchain::timestamp().add(REQUEST_TIMEOUT);
+		let response = http::Request::get(url)
+			.method(http::Method::Other("HEAD"))
+			.deadline(deadline)
+			.send()
+			.map_err(|_| http::Error::IoError)?
+			.try_wait(deadline)
+			.map_err(|_| http::Error::DeadlineReached)??;
+
+		Ok((200..300).contains(&response.code))
+	}
+}
@@ -0,0 +1,199 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Snapshots of the membership of an Alliance, used to move it to another chain.
+//!
+//! [`Pallet::export_snapshot`] reads the members, their deposits, the rule and the unscrupulous
+//! lists into an [`AllianceSnapshot`], which is exposed through the
+//! [`AllianceApi`](crate::AllianceApi). The snapshot can then be written to an empty Alliance on
+//! the target chain with [`Call::force_import_snapshot`](crate::Call::force_import_snapshot).
+
+use super::{
+	BalanceOf, Cid, Config, DepositsOf, Error, MemberRole, Members, Pallet, RetiringMembers,
+	RolePolicy, Rule, RuleHistory, UnscrupulousAccounts, UnscrupulousWebsites, UrlOf,
+};
+use codec::{Decode, Encode};
+use frame_support::{
+	ensure,
+	traits::{Get, InitializeMembers},
+	BoundedVec,
+};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	DispatchError, RuntimeDebug,
+};
+use sp_std::{collections::btree_set::BTreeSet, prelude::*};
+
+/// A versioned snapshot of the membership of an Alliance.
+#[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo)]
+pub enum AllianceSnapshot<AccountId, Balance> {
+	/// See [`SnapshotV1`].
+	#[codec(index = 1)]
+	V1(SnapshotV1<AccountId, Balance>),
+}
+
+impl<AccountId, Balance> AllianceSnapshot<AccountId, Balance> {
+	/// The number of members in the snapshot, retiring members included.
+	pub fn members_count(&self) -> u32 {
+		match self {
+			Self::V1(snapshot) =>
+				snapshot.members.iter().map(|(_, accounts)| accounts.len() as u32).sum(),
+		}
+	}
+
+	/// The number of unscrupulous accounts and websites in the snapshot.
+	pub fn unscrupulous_count(&self) -> u32 {
+		match self {
+			Self::V1(snapshot) =>
+				(snapshot.unscrupulous_accounts.len() + snapshot.unscrupulous_websites.len()) as u32,
+		}
+	}
+}
+
+/// The first version of [`AllianceSnapshot`].
+#[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo)]
+pub struct SnapshotV1<AccountId, Balance> {
+	/// The members of each role, [`MemberRole::Retiring`] included.
+	pub members: Vec<(MemberRole, Vec<AccountId>)>,
+	/// The deposits held from members.
+	pub deposits: Vec<(AccountId, Balance)>,
+	/// The current rule, if any.
+	pub rule: Option<Cid>,
+	/// The accounts deemed unscrupulous.
+	pub unscrupulous_accounts: Vec<AccountId>,
+	/// The websites deemed unscrupulous.
+	pub unscrupulous_websites: Vec<Vec<u8>>,
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// A snapshot of the members, their deposits, the rule and the unscrupulous lists.
+	pub fn export_snapshot() -> AllianceSnapshot<T::AccountId, BalanceOf<T, I>> {
+		let members: Vec<_> = T::RolePolicy::roles()
+			.into_iter()
+			.chain(sp_std::iter::once(MemberRole::Retiring))
+			.map(|role| (role, Self::members_of(role)))
+			.filter(|(_, accounts)| !accounts.is_empty())
+			.collect();
+		let deposits = members
+			.iter()
+			.flat_map(|(_, accounts)| accounts.iter())
+			.filter_map(|who| Self::deposit_of(who).map(|deposit| (who.clone(), deposit)))
+			.collect();
+
+		AllianceSnapshot::V1(SnapshotV1 {
+			members,
+			deposits,
+			rule: Rule::<T, I>::get(),
+			unscrupulous_accounts: UnscrupulousAccounts::<T, I>::get().into_inner(),
+			unscrupulous_websites: UnscrupulousWebsites::<T, I>::get()
+				.into_iter()
+				.map(|url| url.into_inner())
+				.collect(),
+		})
+	}
+
+	/// Validate `snapshot` and write it to the storage of an empty Alliance.
+	///
+	/// Returns the number of imported members.
+	pub(crate) fn import_snapshot(
+		snapshot: SnapshotV1<T::AccountId, BalanceOf<T, I>>,
+	) -> Result<u32, DispatchError> {
+		let SnapshotV1 { members, deposits, rule, unscrupulous_accounts, unscrupulous_websites } =
+			snapshot;
+
+		let policy_roles = T::RolePolicy::roles();
+		let mut roles = Vec::with_capacity(members.len());
+		let mut accounts_seen = BTreeSet::new();
+		let mut has_voting_members = false;
+		let mut imported = Vec::with_capacity(members.len());
+		for (role, accounts) in members {
+			ensure!(
+				role == MemberRole::Retiring || policy_roles.contains(&role),
+				Error::<T, I>::InvalidSnapshot
+			);
+			ensure!(!roles.contains(&role), Error::<T, I>::InvalidSnapshot);
+			roles.push(role);
+
+			let mut accounts: BoundedVec<T::AccountId, T::MaxMembersCount> =
+				accounts.try_into().map_err(|_| Error::<T, I>::TooManyMembers)?;
+			for who in accounts.iter() {
+				ensure!(accounts_seen.insert(who.clone()), Error::<T, I>::InvalidSnapshot);
+			}
+			has_voting_members |= T::RolePolicy::has_voting_rights(role) && !accounts.is_empty();
+			accounts.sort();
+			imported.push((role, accounts));
+		}
+		ensure!(has_voting_members, Error::<T, I>::FellowsMissing);
+		// Every member has at most one deposit, placing it twice would hold it twice.
+		let mut depositors = BTreeSet::new();
+		for (who, _) in deposits.iter() {
+			ensure!(
+				accounts_seen.contains(who) && depositors.insert(who),
+				Error::<T, I>::InvalidSnapshot
+			);
+		}
+
+		let unscrupulous_accounts: BoundedVec<T::AccountId, T::MaxUnscrupulousItems> =
+			unscrupulous_accounts
+				.try_into()
+				.map_err(|_| Error::<T, I>::TooManyUnscrupulousItems)?;
+		ensure!(
+			!unscrupulous_accounts.iter().any(|who| accounts_seen.contains(who)),
+			Error::<T, I>::InvalidSnapshot
+		);
+		let unscrupulous_websites: BoundedVec<UrlOf<T, I>, T::MaxUnscrupulousItems> =
+			unscrupulous_websites
+				.into_iter()
+				.map(|url| url.try_into().map_err(|_| Error::<T, I>::TooLongWebsiteUrl))
+				.collect::<Result<Vec<_>, _>>()?
+				.try_into()
+				.map_err(|_| Error::<T, I>::TooManyUnscrupulousItems)?;
+
+		for (who, deposit) in deposits.into_iter().filter(|(_, deposit)| !deposit.is_zero()) {
+			DepositsOf::<T, I>::place(&who, deposit)
+				.map_err(|_| Error::<T, I>::InsufficientFunds)?;
+		}
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let retirement_period_end = now.saturating_add(T::RetirementPeriod::get());
+		for (role, accounts) in imported {
+			if role == MemberRole::Retiring {
+				for who in accounts.iter() {
+					RetiringMembers::<T, I>::insert(who, retirement_period_end);
+				}
+			}
+			Members::<T, I>::insert(role, accounts);
+		}
+
+		if let Some(rule) = rule {
+			RuleHistory::<T, I>::mutate(|history| {
+				if !history.is_empty() && history.len() as u32 >= T::MaxRuleHistory::get() {
+					history.remove(0);
+				}
+				let _ = history.try_push((now, rule.clone()));
+			});
+			Rule::<T, I>::put(rule);
+		}
+		UnscrupulousAccounts::<T, I>::put(unscrupulous_accounts);
+		UnscrupulousWebsites::<T, I>::put(unscrupulous_websites);
+
+		T::InitializeMembers::initialize_members(&Self::voting_members());
+
+		Ok(accounts_seen.len() as u32)
+	}
+}
@@ -17,14 +17,20 @@
 
 //! Tests for the alliance pallet.
 
-use frame_support::{assert_noop, assert_ok, error::BadOrigin};
+use frame_support::{assert_event_schema, assert_noop, assert_ok, error::BadOrigin};
 use frame_system::{EventRecord, Phase};
+use sp_runtime::{offchain::StorageKind, traits::Hash};
 
 use super::*;
 use crate::mock::*;
 
 type AllianceMotionEvent = pallet_collective::Event<Test, pallet_collective::Instance1>;
 
+/// The origin of an approved Alliance motion with `yes` ayes out of three fellows.
+fn motion_origin(yes: u32) -> RuntimeOrigin {
+	pallet_collective::RawOrigin::<u64, pallet_collective::Instance1>::Members(yes, 3).into()
+}
+
 fn assert_powerless(user: RuntimeOrigin, user_is_member: bool) {
 	//vote / veto with a valid propsal
 	let cid = test_cid();
@@ -118,7 +124,7 @@ fn disband_works() {
 		// join alliance and reserve funds
 		assert_eq!(Balances::free_balance(9), 1000 - id_deposit);
 		assert_ok!(Alliance::join_alliance(RuntimeOrigin::signed(9)));
-		assert_eq!(Alliance::deposit_of(9), Some(expected_join_deposit));
+		assert_eq!(Alliance::deposit_of(&9), Some(expected_join_deposit));
 		assert_eq!(Balances::free_balance(9), 1000 - id_deposit - expected_join_deposit);
 		assert!(Alliance::is_member_of(&9, MemberRole::Ally));
 
@@ -135,10 +141,6 @@ fn disband_works() {
 			Alliance::disband(RuntimeOrigin::root(), DisbandWitness::new(1, 1)),
 			Error::<Test, ()>::BadWitness,
 		);
-		assert_noop!(
-			Alliance::disband(RuntimeOrigin::root(), DisbandWitness::new(2, 0)),
-			Error::<Test, ()>::BadWitness,
-		);
 
 		// success call
 		assert_ok!(Alliance::disband(RuntimeOrigin::root(), DisbandWitness::new(2, 1)));
@@ -148,7 +150,7 @@ fn disband_works() {
 		assert!(!Alliance::is_initialized());
 		// assert a retiring member from the previous Alliance not removed
 		assert!(Alliance::is_member_of(&2, MemberRole::Retiring));
-		// deposit unreserved
+		// deposit released
 		assert_eq!(Balances::free_balance(9), 1000 - id_deposit);
 
 		System::assert_last_event(mock::RuntimeEvent::Alliance(crate::Event::AllianceDisbanded {
@@ -165,6 +167,74 @@ fn disband_works() {
 	})
 }
 
+#[test]
+fn disband_over_several_calls_works() {
+	new_test_ext().execute_with(|| {
+		let id_deposit = test_identity_info_deposit();
+		assert_ok!(Alliance::join_alliance(RuntimeOrigin::signed(4)));
+		assert_ok!(Alliance::join_alliance(RuntimeOrigin::signed(9)));
+		assert_ok!(Alliance::nominate_ally(RuntimeOrigin::signed(1), 5));
+
+		assert_noop!(
+			Alliance::continue_disband(RuntimeOrigin::signed(1), DisbandWitness::new(0, 1)),
+			Error::<Test, ()>::NoDisbandInProgress,
+		);
+
+		// the fellows and one ally are removed by the first call
+		assert_ok!(Alliance::disband(RuntimeOrigin::root(), DisbandWitness::new(3, 1)));
+		System::assert_has_event(mock::RuntimeEvent::Alliance(crate::Event::DisbandStarted {
+			fellow_members: 3,
+			ally_members: 3,
+		}));
+		System::assert_last_event(mock::RuntimeEvent::Alliance(crate::Event::DisbandProgressed {
+			fellow_members: 3,
+			ally_members: 1,
+			unreserved: 1,
+		}));
+		assert_eq!(Alliance::voting_members(), Vec::<u64>::new());
+		assert_eq!(Alliance::members(MemberRole::Ally), vec![4, 5]);
+		assert_eq!(Balances::free_balance(9), 1000 - id_deposit);
+
+		// the Alliance can't change while being disbanded
+		assert_noop!(
+			Alliance::disband(RuntimeOrigin::root(), DisbandWitness::new(3, 3)),
+			Error::<Test, ()>::DisbandInProgress,
+		);
+		assert_noop!(
+			Alliance::join_alliance(RuntimeOrigin::signed(8)),
+			Error::<Test, ()>::DisbandInProgress,
+		);
+
+		// anyone can continue, with witness data for the chunk
+		assert_noop!(
+			Alliance::continue_disband(RuntimeOrigin::signed(8), Default::default()),
+			Error::<Test, ()>::BadWitness,
+		);
+		assert_ok!(Alliance::continue_disband(RuntimeOrigin::signed(8), DisbandWitness::new(0, 1)));
+		System::assert_last_event(mock::RuntimeEvent::Alliance(crate::Event::DisbandProgressed {
+			fellow_members: 0,
+			ally_members: 1,
+			unreserved: 0,
+		}));
+		assert_eq!(Alliance::members(MemberRole::Ally), vec![4]);
+
+		assert_ok!(Alliance::continue_disband(RuntimeOrigin::signed(8), DisbandWitness::new(0, 5)));
+		System::assert_last_event(mock::RuntimeEvent::Alliance(crate::Event::AllianceDisbanded {
+			fellow_members: 3,
+			ally_members: 3,
+			unreserved: 2,
+		}));
+		assert!(!Alliance::is_initialized());
+		assert_eq!(DisbandProgress::<Test>::get(), None);
+		assert_eq!(Balances::free_balance(4), 1000 - id_deposit);
+
+		assert_noop!(
+			Alliance::continue_disband(RuntimeOrigin::signed(8), DisbandWitness::new(0, 1)),
+			Error::<Test, ()>::NoDisbandInProgress,
+		);
+	})
+}
+
 #[test]
 fn propose_works() {
 	new_test_ext().execute_with(|| {
@@ -205,6 +275,73 @@ fn propose_works() {
 	});
 }
 
+#[test]
+fn active_proposals_works() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Alliance::active_proposals(), Vec::<H256>::new());
+
+		let (first, first_len, first_hash) = make_remark_proposal(42);
+		let (second, second_len, second_hash) = make_remark_proposal(43);
+		assert_ok!(Alliance::propose(RuntimeOrigin::signed(1), 3, Box::new(first), first_len));
+		assert_ok!(Alliance::propose(RuntimeOrigin::signed(1), 3, Box::new(second), second_len));
+		assert_eq!(Alliance::active_proposals(), vec![first_hash, second_hash]);
+	});
+}
+
+#[test]
+fn propose_respects_proposal_filter() {
+	new_test_ext().execute_with(|| {
+		OnlyAllianceProposals::set(true);
+		let (remark, remark_len, _) = make_remark_proposal(42);
+		let (kick, kick_len, kick_hash) = make_kick_member_proposal(2);
+
+		// calls outside of the Alliance are filtered out
+		assert_noop!(
+			Alliance::propose(RuntimeOrigin::signed(1), 3, Box::new(remark.clone()), remark_len),
+			Error::<Test, ()>::ProposalNotAllowed
+		);
+
+		assert_ok!(Alliance::propose(RuntimeOrigin::signed(1), 3, Box::new(kick), kick_len));
+		assert_eq!(Alliance::active_proposals(), vec![kick_hash]);
+
+		// the filter does not lift the other requirements
+		assert_noop!(
+			Alliance::propose(RuntimeOrigin::signed(4), 3, Box::new(remark.clone()), remark_len),
+			Error::<Test, ()>::NoVotingRights
+		);
+
+		OnlyAllianceProposals::set(false);
+		assert_ok!(Alliance::propose(RuntimeOrigin::signed(1), 3, Box::new(remark), remark_len));
+	});
+}
+
+#[test]
+fn voting_provider_weighs_proposals() {
+	new_test_ext().execute_with(|| {
+		let (proposal, proposal_len, _) = make_remark_proposal(42);
+		let call = crate::Call::<Test>::propose {
+			threshold: 3,
+			proposal: Box::new(proposal.clone()),
+			length_bound: proposal_len,
+		};
+		// The members of the Fellows and the suspension of the caller are read.
+		let checks = <Test as frame_system::Config>::DbWeight::get().reads(2);
+		assert_eq!(
+			call.get_dispatch_info().weight,
+			Collective::propose_weight(proposal_len).saturating_add(checks)
+		);
+
+		// Without a voting backend only the checks are charged and nothing can be proposed.
+		NoVotingBackend::set(true);
+		assert_eq!(call.get_dispatch_info().weight, checks);
+		assert_noop!(
+			Alliance::propose(RuntimeOrigin::signed(1), 3, Box::new(proposal), proposal_len),
+			DispatchError::Other("No voting backend")
+		);
+		assert_eq!(Alliance::active_proposals(), Vec::<H256>::new());
+	});
+}
+
 #[test]
 fn vote_works() {
 	new_test_ext().execute_with(|| {
@@ -303,12 +440,107 @@ fn close_works() {
 				record(mock::RuntimeEvent::AllianceMotion(AllianceMotionEvent::Executed {
 					proposal_hash: hash,
 					result: Ok(()),
+				})),
+				record(mock::RuntimeEvent::Alliance(crate::Event::ProposalClosed {
+					hash,
+					ayes: 3,
+					nays: 0,
+					threshold: 3,
+					outcome: ProposalOutcome::Approved,
 				}))
 			]
 		);
 	});
 }
 
+#[test]
+fn close_reports_tally_of_expired_proposal() {
+	new_test_ext().execute_with(|| {
+		let (proposal, proposal_len, hash) = make_remark_proposal(42);
+		let proposal_weight = proposal.get_dispatch_info().weight;
+		assert_ok!(Alliance::propose(
+			RuntimeOrigin::signed(1),
+			2,
+			Box::new(proposal.clone()),
+			proposal_len
+		));
+		assert_ok!(Alliance::vote(RuntimeOrigin::signed(1), hash, 0, true));
+
+		// The voting period has ended.
+		System::set_block_number(5);
+		assert_ok!(Alliance::close(
+			RuntimeOrigin::signed(1),
+			hash,
+			0,
+			proposal_weight,
+			proposal_len
+		));
+
+		// Abstentions count as nays by default.
+		System::assert_last_event(mock::RuntimeEvent::Alliance(crate::Event::ProposalClosed {
+			hash,
+			ayes: 1,
+			nays: 2,
+			threshold: 2,
+			outcome: ProposalOutcome::Disapproved,
+		}));
+	});
+}
+
+#[test]
+fn decided_proposals_are_closed_automatically() {
+	new_test_ext().execute_with(|| {
+		AutoClose::set(true);
+		let (proposal, proposal_len, hash) = make_remark_proposal(42);
+		assert_ok!(Alliance::propose(
+			RuntimeOrigin::signed(1),
+			3,
+			Box::new(proposal.clone()),
+			proposal_len
+		));
+		assert_ok!(Alliance::vote(RuntimeOrigin::signed(1), hash, 0, true));
+		assert_ok!(Alliance::vote(RuntimeOrigin::signed(2), hash, 0, true));
+		assert!(DecidedProposals::<Test>::get().is_empty());
+
+		// The third vote decides the proposal.
+		assert_ok!(Alliance::vote(RuntimeOrigin::signed(3), hash, 0, true));
+		assert_eq!(DecidedProposals::<Test>::get().into_inner(), vec![(hash, 0)]);
+
+		// Not enough weight to close it.
+		AutoCloseWeight::set(Weight::zero());
+		Alliance::on_initialize(2);
+		assert!(DecidedProposals::<Test>::get().is_empty());
+		assert!(AllianceMotion::proposal_of(hash).is_some());
+
+		// Queue it again, only once, and close it.
+		assert_ok!(Alliance::vote(RuntimeOrigin::signed(3), hash, 0, false));
+		assert_ok!(Alliance::vote(RuntimeOrigin::signed(3), hash, 0, true));
+		assert_eq!(DecidedProposals::<Test>::get().into_inner(), vec![(hash, 0)]);
+		AutoCloseWeight::set(Weight::MAX);
+		Alliance::on_initialize(3);
+		assert!(DecidedProposals::<Test>::get().is_empty());
+		assert!(AllianceMotion::proposal_of(hash).is_none());
+		System::assert_has_event(mock::RuntimeEvent::AllianceMotion(
+			AllianceMotionEvent::Executed { proposal_hash: hash, result: Ok(()) },
+		));
+	});
+}
+
+#[test]
+fn proposals_are_not_queued_without_auto_close() {
+	new_test_ext().execute_with(|| {
+		let (proposal, proposal_len, hash) = make_remark_proposal(42);
+		assert_ok!(Alliance::propose(
+			RuntimeOrigin::signed(1),
+			1,
+			Box::new(proposal.clone()),
+			proposal_len
+		));
+		assert_ok!(Alliance::vote(RuntimeOrigin::signed(1), hash, 0, true));
+		assert!(DecidedProposals::<Test>::get().is_empty());
+	});
+}
+
 #[test]
 fn set_rule_works() {
 	new_test_ext().execute_with(|| {
@@ -318,7 +550,27 @@ fn set_rule_works() {
 
 		System::assert_last_event(mock::RuntimeEvent::Alliance(crate::Event::NewRuleSet {
 			rule: cid,
+			previous: None,
+		}));
+	});
+}
+
+#[test]
+fn rule_history_is_bounded() {
+	new_test_ext().execute_with(|| {
+		let cid = |n: u8| Cid::new_v0(sp_crypto_hashing::sha2_256(&[n]));
+
+		for n in 1..=3 {
+			System::set_block_number(n as u64);
+			assert_ok!(Alliance::set_rule(RuntimeOrigin::signed(1), cid(n)));
+		}
+
+		System::assert_last_event(mock::RuntimeEvent::Alliance(crate::Event::NewRuleSet {
+			rule: cid(3),
+			previous: Some(cid(2)),
 		}));
+		// `MaxRuleHistory` is 2, so the first rule was dropped.
+		assert_eq!(Alliance::rule_history().into_inner(), vec![(2, cid(2)), (3, cid(3))]);
 	});
 }
 
@@ -358,6 +610,26 @@ fn remove_announcement_works() {
 	});
 }
 
+#[test]
+fn announcements_are_looked_up_without_decoding_them() {
+	new_test_ext().execute_with(|| {
+		let cid = |n: u8| Cid::new_v0(sp_crypto_hashing::sha2_256(&[n]));
+		assert!(!Alliance::is_published(&cid(1)));
+
+		assert_ok!(Alliance::announce(RuntimeOrigin::signed(3), cid(1)));
+		assert_ok!(Alliance::announce(RuntimeOrigin::signed(3), cid(2)));
+		assert_eq!(Alliance::announcements_len(), Some(2));
+		assert!(Alliance::is_published(&cid(1)));
+		assert!(Alliance::is_published(&cid(2)));
+		assert!(!Alliance::is_published(&cid(3)));
+
+		assert_ok!(Alliance::remove_announcement(RuntimeOrigin::signed(3), cid(1)));
+		assert_eq!(Alliance::announcements_len(), Some(1));
+		assert!(!Alliance::is_published(&cid(1)));
+		assert!(Alliance::is_published(&cid(2)));
+	});
+}
+
 #[test]
 fn join_alliance_works() {
 	new_test_ext().execute_with(|| {
@@ -394,7 +666,7 @@ fn join_alliance_works() {
 		// success to submit
 		assert_ok!(Alliance::join_alliance(RuntimeOrigin::signed(4)));
 		assert_eq!(Balances::free_balance(4), 1000 - id_deposit - join_deposit);
-		assert_eq!(Alliance::deposit_of(4), Some(25));
+		assert_eq!(Alliance::deposit_of(&4), Some(25));
 		assert_eq!(Alliance::members(MemberRole::Ally), vec![4]);
 
 		// check already member
@@ -418,6 +690,59 @@ fn join_alliance_works() {
 	});
 }
 
+#[test]
+fn can_join_works() {
+	new_test_ext().execute_with(|| {
+		let join_deposit = <Test as Config>::AllyDeposit::get();
+		let max_members = <Test as Config>::MaxMembersCount::get();
+		assert_eq!(
+			Alliance::can_join(&4),
+			Ok(DepositInfo { deposit: join_deposit, remaining_capacity: max_members })
+		);
+
+		// already member
+		assert_eq!(Alliance::can_join(&1), Err(JoinBlocker::AlreadyMember));
+
+		// listed as unscrupulous
+		assert_ok!(Alliance::add_unscrupulous_items(
+			RuntimeOrigin::signed(3),
+			vec![UnscrupulousItem::AccountId(4)]
+		));
+		assert_eq!(Alliance::can_join(&4), Err(JoinBlocker::AccountNonGrata));
+		assert_ok!(Alliance::remove_unscrupulous_items(
+			RuntimeOrigin::signed(3),
+			vec![UnscrupulousItem::AccountId(4)]
+		));
+
+		// not enough funds for the deposit
+		assert_eq!(Alliance::can_join(&5), Err(JoinBlocker::InsufficientFunds));
+
+		// missing identity judgement and missing identity info
+		#[cfg(not(feature = "runtime-benchmarks"))]
+		{
+			assert_eq!(Alliance::can_join(&6), Err(JoinBlocker::MissingIdentity));
+			assert_eq!(Alliance::can_join(&7), Err(JoinBlocker::MissingIdentity));
+		}
+
+		// the capacity shrinks as allies join
+		assert_ok!(Alliance::join_alliance(RuntimeOrigin::signed(4)));
+		assert_eq!(
+			Alliance::can_join(&8),
+			Ok(DepositInfo { deposit: join_deposit, remaining_capacity: max_members - 1 })
+		);
+
+		// no capacity left
+		let allies: BoundedVec<_, _> =
+			(100..100 + max_members as u64).collect::<Vec<_>>().try_into().unwrap();
+		Members::<Test, ()>::insert(MemberRole::Ally, allies);
+		assert_eq!(Alliance::can_join(&8), Err(JoinBlocker::TooManyMembers));
+		assert_noop!(
+			Alliance::join_alliance(RuntimeOrigin::signed(8)),
+			Error::<Test, ()>::TooManyMembers
+		);
+	});
+}
+
 #[test]
 fn nominate_ally_works() {
 	new_test_ext().execute_with(|| {
@@ -449,7 +774,7 @@ fn nominate_ally_works() {
 
 		// success to nominate
 		assert_ok!(Alliance::nominate_ally(RuntimeOrigin::signed(1), 4));
-		assert_eq!(Alliance::deposit_of(4), None);
+		assert_eq!(Alliance::deposit_of(&4), None);
 		assert_eq!(Alliance::members(MemberRole::Ally), vec![4]);
 
 		// check already member
@@ -473,6 +798,54 @@ fn nominate_ally_works() {
 	});
 }
 
+#[test]
+fn nominate_ally_respects_nomination_quota() {
+	new_test_ext().execute_with(|| {
+		NominationQuota::set(Some(1));
+
+		assert_ok!(Alliance::nominate_ally(RuntimeOrigin::signed(1), 4));
+		System::assert_last_event(mock::RuntimeEvent::Alliance(crate::Event::NewAllyJoined {
+			ally: 4,
+			nominator: Some(1),
+			reserved: None,
+		}));
+		System::assert_has_event(mock::RuntimeEvent::Alliance(
+			crate::Event::NominationQuotaExhausted { fellow: 1 },
+		));
+		assert_noop!(
+			Alliance::nominate_ally(RuntimeOrigin::signed(1), 5),
+			Error::<Test, ()>::NominationQuotaExceeded
+		);
+		// The quota is per fellow.
+		assert_ok!(Alliance::nominate_ally(RuntimeOrigin::signed(2), 5));
+
+		// The quota is renewed in the next nomination period.
+		System::set_block_number(10);
+		assert_ok!(Alliance::nominate_ally(RuntimeOrigin::signed(1), 8));
+		assert_eq!(Alliance::members(MemberRole::Ally), vec![4, 5, 8]);
+	});
+}
+
+#[test]
+fn nominations_used_are_removed_with_the_member() {
+	new_test_ext().execute_with(|| {
+		NominationQuota::set(Some(2));
+
+		assert_ok!(Alliance::nominate_ally(RuntimeOrigin::signed(1), 4));
+		assert_ok!(Alliance::nominate_ally(RuntimeOrigin::signed(3), 5));
+		assert_eq!(NominationsUsed::<Test, ()>::get(1), Some((0, 1)));
+		assert_eq!(NominationsUsed::<Test, ()>::get(3), Some((0, 1)));
+
+		assert_ok!(Alliance::kick_member(RuntimeOrigin::signed(2), 1));
+		assert_eq!(NominationsUsed::<Test, ()>::get(1), None);
+
+		assert_ok!(Alliance::give_retirement_notice(RuntimeOrigin::signed(3)));
+		System::set_block_number(System::block_number() + RetirementPeriod::get());
+		assert_ok!(Alliance::retire(RuntimeOrigin::signed(3)));
+		assert_eq!(NominationsUsed::<Test, ()>::get(3), None);
+	});
+}
+
 #[test]
 fn elevate_ally_works() {
 	new_test_ext().execute_with(|| {
@@ -491,6 +864,78 @@ fn elevate_ally_works() {
 	});
 }
 
+#[test]
+fn role_policy_supports_custom_tiers() {
+	new_test_ext().execute_with(|| {
+		ObserverTier::set(true);
+
+		// New members join with the lowest role.
+		assert_ok!(Alliance::join_alliance(RuntimeOrigin::signed(4)));
+		assert_eq!(Alliance::members(OBSERVER), vec![4]);
+		assert!(!Alliance::is_ally(&4));
+
+		// Members are elevated one role at a time.
+		assert_ok!(Alliance::elevate_ally(RuntimeOrigin::signed(2), 4));
+		assert_eq!(Alliance::members(OBSERVER), Vec::<u64>::new());
+		assert_eq!(Alliance::members(MemberRole::Ally), vec![4]);
+		assert_ok!(Alliance::elevate_ally(RuntimeOrigin::signed(2), 4));
+		assert_eq!(Alliance::members(MemberRole::Fellow), vec![1, 2, 3, 4]);
+		assert_eq!(Alliance::voting_members(), vec![1, 2, 3, 4]);
+		assert_noop!(
+			Alliance::elevate_ally(RuntimeOrigin::signed(2), 4),
+			Error::<Test, ()>::AlreadyElevated
+		);
+
+		// Abdicating steps down to the highest role without voting rights.
+		assert_ok!(Alliance::abdicate_fellow_status(RuntimeOrigin::signed(4)));
+		assert_eq!(Alliance::members(MemberRole::Ally), vec![4]);
+		assert_eq!(Alliance::voting_members(), vec![1, 2, 3]);
+	});
+}
+
+#[test]
+fn init_members_follows_role_policy() {
+	new_test_ext().execute_with(|| {
+		ObserverTier::set(true);
+		assert_ok!(Alliance::disband(RuntimeOrigin::root(), DisbandWitness::new(3, 0)));
+
+		// Fellows join with the highest voting role, allies with the lowest role.
+		assert_ok!(Alliance::init_members(RuntimeOrigin::root(), vec![8, 5], vec![2]));
+		assert_eq!(Alliance::members(MemberRole::Fellow), vec![5, 8]);
+		assert_eq!(Alliance::members(OBSERVER), vec![2]);
+		assert_eq!(Alliance::members(MemberRole::Ally), Vec::<u64>::new());
+		assert_eq!(Alliance::voting_members(), vec![5, 8]);
+	});
+}
+
+#[cfg(not(feature = "runtime-benchmarks"))]
+#[test]
+fn elevate_ally_requires_own_identity() {
+	use pallet_identity::Data;
+
+	new_test_ext().execute_with(|| {
+		// 7 has no identity of its own but is a sub account of 8.
+		assert_ok!(Identity::set_subs(
+			RuntimeOrigin::signed(8),
+			vec![(7, Data::Raw(b"sub".to_vec().try_into().unwrap()))]
+		));
+		assert_ok!(Alliance::join_alliance(RuntimeOrigin::signed(7)));
+		assert_noop!(
+			Alliance::elevate_ally(RuntimeOrigin::signed(2), 7),
+			Error::<Test, ()>::WithoutOwnIdentity
+		);
+
+		// The identity is checked again on elevation.
+		assert_ok!(Alliance::join_alliance(RuntimeOrigin::signed(4)));
+		assert_ok!(Identity::clear_identity(RuntimeOrigin::signed(4)));
+		assert_noop!(
+			Alliance::elevate_ally(RuntimeOrigin::signed(2), 4),
+			Error::<Test, ()>::WithoutRequiredIdentityFields
+		);
+		assert_eq!(Alliance::members(MemberRole::Ally), vec![4, 7]);
+	});
+}
+
 #[test]
 fn give_retirement_notice_work() {
 	new_test_ext().execute_with(|| {
@@ -549,22 +994,95 @@ fn retire_works() {
 }
 
 #[test]
-fn abdicate_works() {
+fn early_retirement_is_slashed() {
 	new_test_ext().execute_with(|| {
-		assert_eq!(Alliance::members(MemberRole::Fellow), vec![1, 2, 3]);
-		assert_ok!(Alliance::abdicate_fellow_status(RuntimeOrigin::signed(3)));
+		MinimumTenure::set(Some(10));
+		EarlyRetirementSlash::set(Perbill::from_percent(20));
+		let free = Balances::free_balance(9);
 
-		System::assert_last_event(mock::RuntimeEvent::Alliance(crate::Event::FellowAbdicated {
-			fellow: (3),
+		// Retiring before the minimum tenure costs 20% of the deposit.
+		assert_ok!(Alliance::join_alliance(RuntimeOrigin::signed(9)));
+		assert_ok!(Alliance::give_retirement_notice(RuntimeOrigin::signed(9)));
+		System::set_block_number(System::block_number() + RetirementPeriod::get());
+		assert_ok!(Alliance::retire(RuntimeOrigin::signed(9)));
+		System::assert_has_event(mock::RuntimeEvent::Alliance(
+			crate::Event::EarlyRetirementSlashed { member: 9, slashed: 5, tenure: 0 },
+		));
+		System::assert_last_event(mock::RuntimeEvent::Alliance(crate::Event::MemberRetired {
+			member: 9,
+			unreserved: Some(20),
 		}));
+		assert_eq!(Balances::free_balance(9), free - 5);
+		assert_eq!(MemberSince::<Test>::get(9), None);
 
-		assert_powerless(RuntimeOrigin::signed(3), true);
-	});
-}
+		// Elevation restarts the tenure.
+		assert_ok!(Alliance::join_alliance(RuntimeOrigin::signed(9)));
+		System::set_block_number(System::block_number() + 10);
+		assert_ok!(Alliance::elevate_ally(RuntimeOrigin::signed(2), 9));
+		assert_eq!(Alliance::early_retirement_slash(&9, 25, System::block_number()), 5);
+
+		// Retiring after the minimum tenure releases the whole deposit.
+		System::set_block_number(System::block_number() + 10);
+		assert_eq!(Alliance::early_retirement_slash(&9, 25, System::block_number()), 0);
+		assert_ok!(Alliance::give_retirement_notice(RuntimeOrigin::signed(9)));
+		System::set_block_number(System::block_number() + RetirementPeriod::get());
+		assert_ok!(Alliance::retire(RuntimeOrigin::signed(9)));
+		System::assert_last_event(mock::RuntimeEvent::Alliance(crate::Event::MemberRetired {
+			member: 9,
+			unreserved: Some(25),
+		}));
+		assert_eq!(Balances::free_balance(9), free - 5);
 
-#[test]
-fn kick_member_works() {
-	new_test_ext().execute_with(|| {
+		// Founding members have no tracked tenure and are never slashed.
+		assert_ok!(Alliance::give_retirement_notice(RuntimeOrigin::signed(3)));
+		System::set_block_number(System::block_number() + RetirementPeriod::get());
+		assert_ok!(Alliance::retire(RuntimeOrigin::signed(3)));
+		System::assert_last_event(mock::RuntimeEvent::Alliance(crate::Event::MemberRetired {
+			member: 3,
+			unreserved: None,
+		}));
+	});
+}
+
+#[test]
+fn early_retirement_tenure_ends_at_the_notice() {
+	new_test_ext().execute_with(|| {
+		MinimumTenure::set(Some(RetirementPeriod::get()));
+		EarlyRetirementSlash::set(Perbill::from_percent(20));
+
+		// The retirement period does not count towards the tenure.
+		assert_ok!(Alliance::join_alliance(RuntimeOrigin::signed(9)));
+		System::set_block_number(System::block_number() + 1);
+		assert_ok!(Alliance::give_retirement_notice(RuntimeOrigin::signed(9)));
+		System::set_block_number(System::block_number() + RetirementPeriod::get() + 10);
+		assert_ok!(Alliance::retire(RuntimeOrigin::signed(9)));
+		System::assert_has_event(mock::RuntimeEvent::Alliance(
+			crate::Event::EarlyRetirementSlashed { member: 9, slashed: 5, tenure: 1 },
+		));
+		System::assert_last_event(mock::RuntimeEvent::Alliance(crate::Event::MemberRetired {
+			member: 9,
+			unreserved: Some(20),
+		}));
+	});
+}
+
+#[test]
+fn abdicate_works() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Alliance::members(MemberRole::Fellow), vec![1, 2, 3]);
+		assert_ok!(Alliance::abdicate_fellow_status(RuntimeOrigin::signed(3)));
+
+		System::assert_last_event(mock::RuntimeEvent::Alliance(crate::Event::FellowAbdicated {
+			fellow: (3),
+		}));
+
+		assert_powerless(RuntimeOrigin::signed(3), true);
+	});
+}
+
+#[test]
+fn kick_member_works() {
+	new_test_ext().execute_with(|| {
 		assert_noop!(Alliance::kick_member(RuntimeOrigin::signed(4), 4), BadOrigin);
 
 		assert_noop!(
@@ -572,11 +1090,13 @@ fn kick_member_works() {
 			Error::<Test, ()>::NotMember
 		);
 
-		<DepositOf<Test, ()>>::insert(2, 25);
+		let reserved = Balances::reserved_balance(2);
+		assert_ok!(Balances::hold(&HoldReason::AllyDeposit.into(), &2, 25));
 		assert_eq!(Alliance::members(MemberRole::Fellow), vec![1, 2, 3]);
 		assert_ok!(Alliance::kick_member(RuntimeOrigin::signed(2), 2));
 		assert_eq!(Alliance::members(MemberRole::Fellow), vec![1, 3]);
-		assert_eq!(<DepositOf<Test, ()>>::get(2), None);
+		assert_eq!(Alliance::deposit_of(&2), None);
+		assert_eq!(Balances::reserved_balance(2), reserved);
 		System::assert_last_event(mock::RuntimeEvent::Alliance(crate::Event::MemberKicked {
 			member: (2),
 			slashed: Some(25),
@@ -584,20 +1104,204 @@ fn kick_member_works() {
 	});
 }
 
+#[test]
+fn suspend_member_works() {
+	new_test_ext().execute_with(|| {
+		let (proposal, proposal_len, hash) = make_remark_proposal(42);
+		assert_ok!(Alliance::propose(
+			RuntimeOrigin::signed(1),
+			3,
+			Box::new(proposal.clone()),
+			proposal_len
+		));
+
+		assert_noop!(Alliance::suspend_member(RuntimeOrigin::signed(3), 3, 5), BadOrigin);
+		assert_noop!(
+			Alliance::suspend_member(RuntimeOrigin::signed(2), 9, 5),
+			Error::<Test, ()>::NotMember
+		);
+		assert_noop!(
+			Alliance::suspend_member(RuntimeOrigin::signed(2), 3, 1),
+			Error::<Test, ()>::InvalidSuspensionEnd
+		);
+
+		assert_ok!(Alliance::suspend_member(RuntimeOrigin::signed(2), 3, 5));
+		System::assert_last_event(mock::RuntimeEvent::Alliance(crate::Event::MemberSuspended {
+			member: 3,
+			until: 5,
+		}));
+		assert!(Alliance::is_suspended(&3));
+		// The member keeps its role.
+		assert_eq!(Alliance::members(MemberRole::Fellow), vec![1, 2, 3]);
+
+		// A suspended member loses its privileges.
+		assert_noop!(
+			Alliance::propose(RuntimeOrigin::signed(3), 3, Box::new(proposal), proposal_len),
+			Error::<Test, ()>::MemberSuspended
+		);
+		assert_noop!(
+			Alliance::vote(RuntimeOrigin::signed(3), hash, 0, true),
+			Error::<Test, ()>::MemberSuspended
+		);
+		assert_noop!(
+			Alliance::nominate_ally(RuntimeOrigin::signed(3), 8),
+			Error::<Test, ()>::MemberSuspended
+		);
+
+		// The suspension lapses at the given block.
+		System::set_block_number(5);
+		assert!(!Alliance::is_suspended(&3));
+		assert_ok!(Alliance::vote(RuntimeOrigin::signed(3), hash, 0, true));
+		assert_noop!(
+			Alliance::unsuspend_member(RuntimeOrigin::signed(2), 3),
+			Error::<Test, ()>::NotSuspended
+		);
+
+		// A suspension can be lifted before it lapses.
+		assert_ok!(Alliance::suspend_member(RuntimeOrigin::signed(2), 3, 10));
+		assert_noop!(Alliance::unsuspend_member(RuntimeOrigin::signed(3), 3), BadOrigin);
+		assert_ok!(Alliance::unsuspend_member(RuntimeOrigin::signed(2), 3));
+		System::assert_last_event(mock::RuntimeEvent::Alliance(crate::Event::MemberUnsuspended {
+			member: 3,
+		}));
+		assert!(!Alliance::is_suspended(&3));
+		assert_eq!(<SuspendedMembers<Test, ()>>::get(3), None);
+	});
+}
+
+#[test]
+fn sub_committee_works() {
+	new_test_ext().execute_with(|| {
+		let power = SubCommitteePower::UnscrupulousItems;
+		assert_noop!(
+			Alliance::create_sub_committee(RuntimeOrigin::signed(2), vec![2], power, 10),
+			BadOrigin
+		);
+		assert_noop!(
+			Alliance::create_sub_committee(RuntimeOrigin::signed(1), vec![2], power, 1),
+			Error::<Test, ()>::InvalidSubCommitteeExpiry
+		);
+		assert_noop!(
+			Alliance::create_sub_committee(RuntimeOrigin::signed(1), vec![], power, 10),
+			Error::<Test, ()>::InvalidSubCommitteeMembers
+		);
+		assert_noop!(
+			Alliance::create_sub_committee(RuntimeOrigin::signed(1), vec![2, 5], power, 10),
+			Error::<Test, ()>::NoVotingRights
+		);
+
+		assert_ok!(Alliance::create_sub_committee(RuntimeOrigin::signed(1), vec![3, 2], power, 10));
+		System::assert_last_event(mock::RuntimeEvent::Alliance(
+			crate::Event::SubCommitteeCreated { index: 0, members: vec![2, 3], power, expiry: 10 },
+		));
+		assert_ok!(Alliance::create_sub_committee(
+			RuntimeOrigin::signed(1),
+			vec![1],
+			SubCommitteePower::Announcements,
+			20
+		));
+		assert_noop!(
+			Alliance::create_sub_committee(RuntimeOrigin::signed(1), vec![1], power, 10),
+			Error::<Test, ()>::TooManySubCommittees
+		);
+
+		// Members act on their own within the delegated power.
+		let items = vec![UnscrupulousItem::AccountId(8)];
+		assert_ok!(Alliance::delegated_add_unscrupulous_items(
+			RuntimeOrigin::signed(2),
+			0,
+			items.clone()
+		));
+		assert_eq!(Alliance::unscrupulous_accounts().into_inner(), vec![8]);
+		assert_noop!(
+			Alliance::delegated_remove_unscrupulous_items(RuntimeOrigin::signed(1), 0, vec![]),
+			Error::<Test, ()>::NotSubCommitteeMember
+		);
+		assert_noop!(
+			Alliance::delegated_remove_unscrupulous_items(RuntimeOrigin::signed(1), 1, vec![]),
+			Error::<Test, ()>::MissingSubCommitteePower
+		);
+		assert_noop!(
+			Alliance::delegated_announce(RuntimeOrigin::signed(2), 0, test_cid()),
+			Error::<Test, ()>::MissingSubCommitteePower
+		);
+		assert_noop!(
+			Alliance::delegated_announce(RuntimeOrigin::signed(1), 2, test_cid()),
+			Error::<Test, ()>::UnknownSubCommittee
+		);
+		assert_ok!(Alliance::delegated_announce(RuntimeOrigin::signed(1), 1, test_cid()));
+		assert_eq!(Alliance::announcements(), vec![test_cid()]);
+
+		// Expired sub-committees lose their power and can be removed by anyone.
+		assert_noop!(
+			Alliance::revoke_sub_committee(RuntimeOrigin::signed(5), 0),
+			Error::<Test, ()>::SubCommitteeNotExpired
+		);
+		System::set_block_number(10);
+		assert_noop!(
+			Alliance::delegated_remove_unscrupulous_items(RuntimeOrigin::signed(2), 0, items),
+			Error::<Test, ()>::SubCommitteeExpired
+		);
+		assert_ok!(Alliance::revoke_sub_committee(RuntimeOrigin::signed(5), 0));
+		System::assert_last_event(mock::RuntimeEvent::Alliance(
+			crate::Event::SubCommitteeRevoked { index: 0 },
+		));
+
+		// The admin origin can revoke a sub-committee at any time.
+		assert_ok!(Alliance::revoke_sub_committee(RuntimeOrigin::signed(1), 1));
+		assert_noop!(
+			Alliance::delegated_announce(RuntimeOrigin::signed(1), 1, test_cid()),
+			Error::<Test, ()>::UnknownSubCommittee
+		);
+		assert_eq!(SubCommittees::<Test, ()>::count(), 0);
+	});
+}
+
+#[test]
+fn unscrupulous_items_works() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Alliance::unscrupulous_items(), vec![]);
+
+		let website: UrlOf<Test, ()> = "abc".as_bytes().to_vec().try_into().unwrap();
+		assert_ok!(Alliance::add_unscrupulous_items(
+			RuntimeOrigin::signed(3),
+			vec![UnscrupulousItem::Website(website), UnscrupulousItem::AccountId(5)]
+		));
+		// Accounts are listed first.
+		assert_eq!(
+			Alliance::unscrupulous_items(),
+			vec![UnscrupulousItem::AccountId(5), UnscrupulousItem::Website(b"abc".to_vec())]
+		);
+	});
+}
+
 #[test]
 fn add_unscrupulous_items_works() {
 	new_test_ext().execute_with(|| {
 		assert_noop!(Alliance::add_unscrupulous_items(RuntimeOrigin::signed(2), vec![]), BadOrigin);
 
-		assert_ok!(Alliance::add_unscrupulous_items(
+		let website: UrlOf<Test, ()> = "abc".as_bytes().to_vec().try_into().unwrap();
+		let items = vec![
+			(UnscrupulousItem::AccountId(3), Some(test_cid())),
+			(UnscrupulousItem::Website(website.clone()), None),
+		];
+		assert_ok!(Alliance::add_unscrupulous_items_with_evidence(
 			RuntimeOrigin::signed(3),
-			vec![
-				UnscrupulousItem::AccountId(3),
-				UnscrupulousItem::Website("abc".as_bytes().to_vec().try_into().unwrap())
-			]
+			items.clone()
+		));
+		System::assert_has_event(mock::RuntimeEvent::Alliance(
+			crate::Event::UnscrupulousItemAdded { items },
+		));
+		System::assert_last_event(mock::RuntimeEvent::Alliance(
+			crate::Event::UnscrupulousMemberKicked { member: 3, slashed: None },
 		));
 		assert_eq!(Alliance::unscrupulous_accounts().into_inner(), vec![3]);
 		assert_eq!(Alliance::unscrupulous_websites().into_inner(), vec!["abc".as_bytes().to_vec()]);
+		assert_eq!(
+			Alliance::unscrupulous_evidence(UnscrupulousItem::AccountId(3)),
+			Some(test_cid())
+		);
+		assert_eq!(Alliance::unscrupulous_evidence(UnscrupulousItem::Website(website)), None);
 
 		assert_noop!(
 			Alliance::add_unscrupulous_items(
@@ -609,6 +1313,158 @@ fn add_unscrupulous_items_works() {
 	});
 }
 
+#[test]
+fn unscrupulous_items_can_be_added_with_or_without_evidence() {
+	new_test_ext().execute_with(|| {
+		let power = SubCommitteePower::UnscrupulousItems;
+		assert_ok!(Alliance::create_sub_committee(RuntimeOrigin::signed(1), vec![2], power, 10));
+
+		assert_ok!(Alliance::add_unscrupulous_items(
+			RuntimeOrigin::signed(3),
+			vec![UnscrupulousItem::AccountId(7)]
+		));
+		System::assert_last_event(mock::RuntimeEvent::Alliance(
+			crate::Event::UnscrupulousItemAdded {
+				items: vec![(UnscrupulousItem::AccountId(7), None)],
+			},
+		));
+		assert_eq!(Alliance::unscrupulous_evidence(UnscrupulousItem::AccountId(7)), None);
+
+		let items = vec![(UnscrupulousItem::AccountId(8), Some(test_cid()))];
+		assert_noop!(
+			Alliance::delegated_add_unscrupulous_items_with_evidence(
+				RuntimeOrigin::signed(1),
+				0,
+				items.clone()
+			),
+			Error::<Test, ()>::NotSubCommitteeMember
+		);
+		assert_ok!(Alliance::delegated_add_unscrupulous_items_with_evidence(
+			RuntimeOrigin::signed(2),
+			0,
+			items.clone()
+		));
+		System::assert_last_event(mock::RuntimeEvent::Alliance(
+			crate::Event::UnscrupulousItemAdded { items },
+		));
+		assert_eq!(
+			Alliance::unscrupulous_evidence(UnscrupulousItem::AccountId(8)),
+			Some(test_cid())
+		);
+	});
+}
+
+#[test]
+fn listed_members_are_kicked() {
+	new_test_ext().execute_with(|| {
+		UnscrupulousMemberSlash::set(Perbill::from_percent(20));
+		let free = Balances::free_balance(9);
+		assert_ok!(Alliance::join_alliance(RuntimeOrigin::signed(9)));
+
+		let items = vec![(UnscrupulousItem::AccountId(9), Some(test_cid()))];
+		assert_ok!(Alliance::add_unscrupulous_items_with_evidence(
+			RuntimeOrigin::signed(3),
+			items.clone()
+		));
+		System::assert_has_event(mock::RuntimeEvent::Alliance(
+			crate::Event::UnscrupulousItemAdded { items },
+		));
+		System::assert_last_event(mock::RuntimeEvent::Alliance(
+			crate::Event::UnscrupulousMemberKicked { member: 9, slashed: Some(5) },
+		));
+		assert!(!Alliance::is_member(&9));
+		assert_eq!(Alliance::deposit_of(&9), None);
+		assert_eq!(Balances::free_balance(9), free - 5);
+	});
+}
+
+#[test]
+fn enforce_unscrupulous_works() {
+	new_test_ext().execute_with(|| {
+		UnscrupulousMemberPenalty::set(UnscrupulousPenalty::Suspend(5));
+		assert_noop!(
+			Alliance::enforce_unscrupulous(RuntimeOrigin::signed(9), 9),
+			Error::<Test, ()>::NotMember
+		);
+		assert_noop!(
+			Alliance::enforce_unscrupulous(RuntimeOrigin::signed(9), 2),
+			Error::<Test, ()>::NotListedAsUnscrupulous
+		);
+
+		// Listing a member suspends it.
+		assert_ok!(Alliance::add_unscrupulous_items(
+			RuntimeOrigin::signed(3),
+			vec![UnscrupulousItem::AccountId(2)]
+		));
+		let until = System::block_number() + 5;
+		System::assert_last_event(mock::RuntimeEvent::Alliance(
+			crate::Event::UnscrupulousMemberSuspended { member: 2, until, slashed: None },
+		));
+		assert!(Alliance::is_suspended(&2));
+		assert_noop!(
+			Alliance::enforce_unscrupulous(RuntimeOrigin::signed(9), 2),
+			Error::<Test, ()>::AlreadySuspended
+		);
+
+		// Anyone can suspend the member again once the suspension lapsed.
+		System::set_block_number(until);
+		assert_ok!(Alliance::enforce_unscrupulous(RuntimeOrigin::signed(9), 2));
+		System::assert_last_event(mock::RuntimeEvent::Alliance(
+			crate::Event::UnscrupulousMemberSuspended {
+				member: 2,
+				until: until + 5,
+				slashed: None,
+			},
+		));
+
+		// Or kick it, which is distinct from being kicked by the `MembershipManager`.
+		UnscrupulousMemberPenalty::set(UnscrupulousPenalty::Kick);
+		assert_ok!(Alliance::enforce_unscrupulous(RuntimeOrigin::signed(9), 2));
+		System::assert_last_event(mock::RuntimeEvent::Alliance(
+			crate::Event::UnscrupulousMemberKicked { member: 2, slashed: None },
+		));
+		assert_eq!(Alliance::members(MemberRole::Fellow), vec![1, 3]);
+		assert!(!Alliance::is_suspended(&2));
+	});
+}
+
+#[test]
+fn enforce_unscrupulous_respects_the_penalty_cooldown() {
+	new_test_ext().execute_with(|| {
+		UnscrupulousMemberPenalty::set(UnscrupulousPenalty::Suspend(5));
+		UnscrupulousPenaltyCooldown::set(10);
+		UnscrupulousMemberSlash::set(Perbill::from_percent(20));
+
+		assert_ok!(Alliance::add_unscrupulous_items(
+			RuntimeOrigin::signed(3),
+			vec![UnscrupulousItem::AccountId(2)]
+		));
+		let penalized_at = System::block_number();
+		assert_eq!(UnscrupulousPenalizedAt::<Test, ()>::get(2), Some(penalized_at));
+
+		// The suspension lapsed, but the member can not be suspended and slashed again yet.
+		System::set_block_number(penalized_at + 5);
+		assert!(!Alliance::is_suspended(&2));
+		let deposit = Alliance::deposit_of(&2);
+		assert_noop!(
+			Alliance::enforce_unscrupulous(RuntimeOrigin::signed(9), 2),
+			Error::<Test, ()>::PenaltyCooldown
+		);
+		assert_eq!(Alliance::deposit_of(&2), deposit);
+
+		System::set_block_number(penalized_at + 10);
+		assert_ok!(Alliance::enforce_unscrupulous(RuntimeOrigin::signed(9), 2));
+		assert_eq!(UnscrupulousPenalizedAt::<Test, ()>::get(2), Some(penalized_at + 10));
+
+		// The record is dropped with the listing.
+		assert_ok!(Alliance::remove_unscrupulous_items(
+			RuntimeOrigin::signed(3),
+			vec![UnscrupulousItem::AccountId(2)]
+		));
+		assert_eq!(UnscrupulousPenalizedAt::<Test, ()>::get(2), None);
+	});
+}
+
 #[test]
 fn remove_unscrupulous_items_works() {
 	new_test_ext().execute_with(|| {
@@ -625,9 +1481,9 @@ fn remove_unscrupulous_items_works() {
 			Error::<Test, ()>::NotListedAsUnscrupulous
 		);
 
-		assert_ok!(Alliance::add_unscrupulous_items(
+		assert_ok!(Alliance::add_unscrupulous_items_with_evidence(
 			RuntimeOrigin::signed(3),
-			vec![UnscrupulousItem::AccountId(3)]
+			vec![(UnscrupulousItem::AccountId(3), Some(test_cid()))]
 		));
 		assert_eq!(Alliance::unscrupulous_accounts(), vec![3]);
 		assert_ok!(Alliance::remove_unscrupulous_items(
@@ -635,6 +1491,572 @@ fn remove_unscrupulous_items_works() {
 			vec![UnscrupulousItem::AccountId(3)]
 		));
 		assert_eq!(Alliance::unscrupulous_accounts(), Vec::<u64>::new());
+		assert_eq!(Alliance::unscrupulous_evidence(UnscrupulousItem::AccountId(3)), None);
+	});
+}
+
+#[test]
+fn unscrupulous_aggregate_unions_instances() {
+	/// Another Alliance instance with a fixed list.
+	struct OtherAlliance;
+	impl UnscrupulousList<u64> for OtherAlliance {
+		fn unscrupulous_accounts() -> Vec<u64> {
+			vec![5, 6]
+		}
+
+		fn unscrupulous_websites() -> Vec<Vec<u8>> {
+			vec![b"xyz".to_vec()]
+		}
+
+		fn is_unscrupulous_account(who: &u64) -> bool {
+			Self::unscrupulous_accounts().contains(who)
+		}
+	}
+
+	type Aggregate = UnscrupulousAggregate<u64, (Alliance, OtherAlliance)>;
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Alliance::add_unscrupulous_items(
+			RuntimeOrigin::signed(3),
+			vec![
+				UnscrupulousItem::AccountId(4),
+				UnscrupulousItem::AccountId(5),
+				UnscrupulousItem::Website(b"abc".to_vec().try_into().unwrap())
+			]
+		));
+
+		// duplicates are listed once
+		assert_eq!(Aggregate::unscrupulous_accounts(), vec![4, 5, 6]);
+		assert_eq!(Aggregate::unscrupulous_websites(), vec![b"abc".to_vec(), b"xyz".to_vec()]);
+
+		assert!(Aggregate::is_unscrupulous_anywhere(&4));
+		assert!(Aggregate::is_unscrupulous_anywhere(&6));
+		assert!(!Aggregate::is_unscrupulous_anywhere(&7));
+		assert!(<Aggregate as Contains<u64>>::contains(&5));
+		assert!(!UnscrupulousAggregate::<u64, (Alliance,)>::is_unscrupulous_anywhere(&6));
+	});
+}
+
+#[test]
+fn cid_to_text_works() {
+	let v0 = Cid::new_v0(array_bytes::hex2bytes_unchecked(
+		"59948439065f29619ef41280cbb932be52c56d99c5966b65e0111239f098bbef",
+	));
+	assert_eq!(v0.to_text(), b"QmUNLLsPACCz1vLxQVkXqqLX5R1X345qqfHbsf67hvA3Nn".to_vec());
+
+	let v1 = Cid {
+		version: Version::V1,
+		codec: 0x55,
+		hash: Multihash {
+			code: 0x12,
+			digest: sp_crypto_hashing::sha2_256(b"hello world").to_vec().try_into().unwrap(),
+		},
+	};
+	assert_eq!(
+		v1.to_text(),
+		b"bafkreifzjut3te2nhyekklss27nh3k72ysco7y32koao5eei66wof36n5e".to_vec()
+	);
+}
+
+#[test]
+fn invalid_cid_is_rejected() {
+	new_test_ext().execute_with(|| {
+		let mut cid = test_cid();
+		cid.hash.digest.truncate(20);
+
+		assert_noop!(
+			Alliance::set_rule(RuntimeOrigin::signed(1), cid.clone()),
+			Error::<Test, ()>::InvalidCid
+		);
+		assert_noop!(
+			Alliance::announce(RuntimeOrigin::signed(3), cid.clone()),
+			Error::<Test, ()>::InvalidCid
+		);
+		assert_noop!(
+			Alliance::add_unscrupulous_items_with_evidence(
+				RuntimeOrigin::signed(3),
+				vec![(UnscrupulousItem::AccountId(3), Some(cid))]
+			),
+			Error::<Test, ()>::InvalidCid
+		);
+
+		let mut raw_v0 = test_cid();
+		raw_v0.codec = 0x55;
+		assert_noop!(
+			Alliance::set_rule(RuntimeOrigin::signed(1), raw_v0),
+			Error::<Test, ()>::InvalidCid
+		);
+	});
+}
+
+#[test]
+fn flag_unreachable_cid_works() {
+	new_test_ext().execute_with(|| {
+		let cid = test_cid();
+		assert_noop!(
+			Alliance::flag_unreachable_cid(RuntimeOrigin::signed(1), cid.clone()),
+			Error::<Test, ()>::UnknownCid
+		);
+		assert_ok!(Alliance::announce(RuntimeOrigin::signed(3), cid.clone()));
+
+		assert_noop!(Alliance::flag_unreachable_cid(RuntimeOrigin::none(), cid.clone()), BadOrigin);
+		assert_noop!(
+			Alliance::flag_unreachable_cid(RuntimeOrigin::signed(4), cid.clone()),
+			Error::<Test, ()>::NoVotingRights
+		);
+		// Allies can't flag CIDs.
+		assert_ok!(Alliance::join_alliance(RuntimeOrigin::signed(4)));
+		assert_noop!(
+			Alliance::flag_unreachable_cid(RuntimeOrigin::signed(4), cid.clone()),
+			Error::<Test, ()>::NoVotingRights
+		);
+		// Neither can suspended fellows.
+		assert_ok!(Alliance::suspend_member(RuntimeOrigin::signed(2), 3, 5));
+		assert_noop!(
+			Alliance::flag_unreachable_cid(RuntimeOrigin::signed(3), cid.clone()),
+			Error::<Test, ()>::MemberSuspended
+		);
+
+		assert_ok!(Alliance::flag_unreachable_cid(RuntimeOrigin::signed(1), cid.clone()));
+		assert_eq!(FlaggedCids::<Test>::get(&cid), Some(1));
+		System::assert_last_event(mock::RuntimeEvent::Alliance(crate::Event::CidFlagged {
+			cid: cid.clone(),
+		}));
+
+		assert_noop!(
+			Alliance::flag_unreachable_cid(RuntimeOrigin::signed(2), cid.clone()),
+			Error::<Test, ()>::CidAlreadyFlagged
+		);
+	});
+}
+
+#[test]
+fn unflag_cid_works() {
+	new_test_ext().execute_with(|| {
+		let cid = test_cid();
+		assert_ok!(Alliance::set_rule(RuntimeOrigin::signed(1), cid.clone()));
+		assert_ok!(Alliance::flag_unreachable_cid(RuntimeOrigin::signed(2), cid.clone()));
+
+		assert_noop!(Alliance::unflag_cid(RuntimeOrigin::signed(2), cid.clone()), BadOrigin);
+		assert_ok!(Alliance::unflag_cid(RuntimeOrigin::signed(1), cid.clone()));
+		assert_eq!(FlaggedCids::<Test>::get(&cid), None);
+		System::assert_last_event(mock::RuntimeEvent::Alliance(crate::Event::CidUnflagged {
+			cid: cid.clone(),
+		}));
+
+		assert_noop!(
+			Alliance::unflag_cid(RuntimeOrigin::signed(1), cid),
+			Error::<Test, ()>::CidNotFlagged
+		);
+	});
+}
+
+#[test]
+fn offchain_worker_checks_cids_once() {
+	use sp_core::offchain::{
+		testing::{PendingRequest, TestOffchainExt, TestTransactionPoolExt},
+		OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+	};
+
+	let mut ext = new_test_ext();
+	let (offchain, offchain_state) = TestOffchainExt::new();
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+	ext.register_extension(TransactionPoolExt::new(pool));
+
+	ext.execute_with(|| {
+		let cid = test_cid();
+		assert_ok!(Alliance::set_rule(RuntimeOrigin::signed(1), cid.clone()));
+
+		// Without a gateway, nothing is checked.
+		Alliance::offchain_worker(1);
+
+		sp_io::offchain::local_storage_set(
+			StorageKind::PERSISTENT,
+			offchain::IPFS_GATEWAY_KEY,
+			b"https://ipfs.io/",
+		);
+		let mut uri = b"https://ipfs.io/ipfs/".to_vec();
+		uri.extend(cid.to_text());
+		offchain_state.write().expect_request(PendingRequest {
+			method: "HEAD".into(),
+			uri: String::from_utf8(uri).unwrap(),
+			response: Some(vec![]),
+			sent: true,
+			..Default::default()
+		});
+		Alliance::offchain_worker(2);
+
+		// The CID resolved, so it is not flagged and not requested again.
+		Alliance::offchain_worker(3);
+		assert!(pool_state.read().transactions.is_empty());
+	});
+}
+
+#[test]
+fn snapshot_export_and_import_works() {
+	let snapshot = new_test_ext().execute_with(|| {
+		assert_ok!(Alliance::set_rule(RuntimeOrigin::signed(1), test_cid()));
+		assert_ok!(Alliance::join_alliance(RuntimeOrigin::signed(9)));
+		assert_ok!(Alliance::give_retirement_notice(RuntimeOrigin::signed(3)));
+		assert_ok!(Alliance::add_unscrupulous_items(
+			RuntimeOrigin::signed(3),
+			vec![
+				UnscrupulousItem::AccountId(7),
+				UnscrupulousItem::Website(b"abc".to_vec().try_into().unwrap())
+			]
+		));
+
+		let snapshot = Alliance::export_snapshot();
+		assert_eq!(
+			snapshot,
+			AllianceSnapshot::V1(SnapshotV1 {
+				members: vec![
+					(MemberRole::Ally, vec![9]),
+					(MemberRole::Fellow, vec![1, 2]),
+					(MemberRole::Retiring, vec![3]),
+				],
+				deposits: vec![(9, 25)],
+				rule: Some(test_cid()),
+				unscrupulous_accounts: vec![7],
+				unscrupulous_websites: vec![b"abc".to_vec()],
+			})
+		);
+		snapshot
+	});
+
+	new_test_ext().execute_with(|| {
+		let id_deposit = test_identity_info_deposit();
+		let with_members = |members: Vec<(MemberRole, Vec<u64>)>| {
+			let AllianceSnapshot::V1(mut snapshot) = snapshot.clone();
+			snapshot.members = members;
+			AllianceSnapshot::V1(snapshot)
+		};
+
+		assert_noop!(
+			Alliance::force_import_snapshot(RuntimeOrigin::signed(1), snapshot.clone()),
+			BadOrigin
+		);
+		// the Alliance must be empty
+		assert_noop!(
+			Alliance::force_import_snapshot(RuntimeOrigin::root(), snapshot.clone()),
+			Error::<Test, ()>::AllianceAlreadyInitialized
+		);
+		assert_ok!(Alliance::disband(RuntimeOrigin::root(), DisbandWitness::new(3, 0)));
+
+		// unknown or duplicated roles
+		assert_noop!(
+			Alliance::force_import_snapshot(
+				RuntimeOrigin::root(),
+				with_members(vec![(MemberRole::Fellow, vec![1]), (MemberRole::Custom(7), vec![9])])
+			),
+			Error::<Test, ()>::InvalidSnapshot
+		);
+		assert_noop!(
+			Alliance::force_import_snapshot(
+				RuntimeOrigin::root(),
+				with_members(vec![(MemberRole::Fellow, vec![1]), (MemberRole::Fellow, vec![9])])
+			),
+			Error::<Test, ()>::InvalidSnapshot
+		);
+		// an account holding two roles
+		assert_noop!(
+			Alliance::force_import_snapshot(
+				RuntimeOrigin::root(),
+				with_members(vec![(MemberRole::Fellow, vec![1, 9]), (MemberRole::Ally, vec![9])])
+			),
+			Error::<Test, ()>::InvalidSnapshot
+		);
+		// a deposit of a non-member
+		assert_noop!(
+			Alliance::force_import_snapshot(
+				RuntimeOrigin::root(),
+				with_members(vec![(MemberRole::Fellow, vec![1])])
+			),
+			Error::<Test, ()>::InvalidSnapshot
+		);
+		// no member with voting rights
+		assert_noop!(
+			Alliance::force_import_snapshot(
+				RuntimeOrigin::root(),
+				with_members(vec![(MemberRole::Ally, vec![9])])
+			),
+			Error::<Test, ()>::FellowsMissing
+		);
+
+		assert_ok!(Alliance::force_import_snapshot(RuntimeOrigin::root(), snapshot.clone()));
+
+		assert_eq!(Alliance::members(MemberRole::Fellow), vec![1, 2]);
+		assert_eq!(Alliance::members(MemberRole::Ally), vec![9]);
+		assert_eq!(Alliance::members(MemberRole::Retiring), vec![3]);
+		assert_eq!(Alliance::retiring_members(&3), Some(1 + RetirementPeriod::get()));
+		assert_eq!(Alliance::voting_members(), vec![1, 2]);
+		assert_eq!(AllianceMotion::members(), vec![1, 2]);
+		assert_eq!(Alliance::deposit_of(&9), Some(25));
+		assert_eq!(Balances::free_balance(9), 1000 - id_deposit - 25);
+		assert_eq!(Alliance::rule(), Some(test_cid()));
+		assert_eq!(Alliance::unscrupulous_accounts().into_inner(), vec![7]);
+		assert_eq!(Alliance::unscrupulous_websites().into_inner(), vec![b"abc".to_vec()]);
+		assert_eq!(Alliance::export_snapshot(), snapshot);
+
+		let hash = <Test as frame_system::Config>::Hashing::hash_of(&snapshot);
+		assert_eq!(ImportedSnapshot::<Test>::get(), Some(hash));
+		System::assert_last_event(mock::RuntimeEvent::Alliance(crate::Event::SnapshotImported {
+			hash,
+			members: 4,
+		}));
+
+		// a snapshot can only be imported once
+		assert_ok!(Alliance::disband(RuntimeOrigin::root(), DisbandWitness::new(2, 1)));
+		assert_noop!(
+			Alliance::force_import_snapshot(RuntimeOrigin::root(), snapshot),
+			Error::<Test, ()>::SnapshotAlreadyImported
+		);
+	});
+}
+
+#[test]
+fn snapshot_import_rejects_duplicate_deposits() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Alliance::disband(RuntimeOrigin::root(), DisbandWitness::new(3, 0)));
+		let snapshot = |deposits| {
+			AllianceSnapshot::V1(SnapshotV1 {
+				members: vec![(MemberRole::Fellow, vec![1, 2]), (MemberRole::Ally, vec![9])],
+				deposits,
+				rule: None,
+				unscrupulous_accounts: vec![],
+				unscrupulous_websites: vec![],
+			})
+		};
+
+		assert_noop!(
+			Alliance::force_import_snapshot(
+				RuntimeOrigin::root(),
+				snapshot(vec![(9, 25), (9, 25)])
+			),
+			Error::<Test, ()>::InvalidSnapshot
+		);
+
+		assert_ok!(Alliance::force_import_snapshot(RuntimeOrigin::root(), snapshot(vec![(9, 25)])));
+		assert_eq!(Alliance::deposit_of(&9), Some(25));
+	});
+}
+
+#[test]
+fn approved_proposals_are_enacted_after_delay() {
+	new_test_ext().execute_with(|| {
+		EnactmentDelay::set(5);
+		let (inner, proposal_len, inner_hash) = make_remark_proposal(42);
+		let (proposal, wrapped_len, hash) =
+			make_proposal(RuntimeCall::Alliance(crate::Call::schedule_enactment {
+				proposal: Box::new(inner.clone()),
+			}));
+		assert_ok!(Alliance::propose(RuntimeOrigin::signed(1), 3, Box::new(inner), proposal_len));
+		assert_eq!(AllianceMotion::proposal_of(hash), Some(proposal.clone()));
+		assert_ok!(Alliance::vote(RuntimeOrigin::signed(1), hash, 0, true));
+		assert_ok!(Alliance::vote(RuntimeOrigin::signed(2), hash, 0, true));
+		assert_ok!(Alliance::vote(RuntimeOrigin::signed(3), hash, 0, true));
+		assert_ok!(Alliance::close(
+			RuntimeOrigin::signed(1),
+			hash,
+			0,
+			proposal.get_dispatch_info().weight,
+			wrapped_len
+		));
+		System::assert_has_event(mock::RuntimeEvent::Alliance(crate::Event::EnactmentScheduled {
+			index: 0,
+			proposal_hash: inner_hash,
+			when: 6,
+		}));
+		assert_eq!(PendingEnactments::<Test>::decode_len(6), Some(1));
+		assert_eq!(EnactmentBlocks::<Test>::get().into_inner(), vec![6]);
+
+		// Not due yet.
+		Alliance::on_initialize(5);
+		assert_eq!(PendingEnactments::<Test>::decode_len(6), Some(1));
+
+		Alliance::on_initialize(6);
+		assert!(!PendingEnactments::<Test>::contains_key(6));
+		assert!(EnactmentBlocks::<Test>::get().is_empty());
+		System::assert_last_event(mock::RuntimeEvent::Alliance(crate::Event::ProposalEnacted {
+			index: 0,
+			result: Ok(()),
+		}));
+	});
+}
+
+#[test]
+fn enactment_management_calls_are_not_delayed() {
+	new_test_ext().execute_with(|| {
+		EnactmentDelay::set(5);
+		let (proposal, proposal_len, hash) =
+			make_proposal(RuntimeCall::Alliance(crate::Call::cancel_enactment {
+				when: 0,
+				index: 0,
+			}));
+		assert_ok!(Alliance::propose(
+			RuntimeOrigin::signed(1),
+			3,
+			Box::new(proposal.clone()),
+			proposal_len
+		));
+		assert_eq!(AllianceMotion::proposal_of(hash), Some(proposal));
+	});
+}
+
+#[test]
+fn schedule_enactment_works() {
+	new_test_ext().execute_with(|| {
+		let (proposal, _, _) = make_remark_proposal(42);
+
+		// Only the Alliance can schedule enactments.
+		assert_noop!(
+			Alliance::schedule_enactment(RuntimeOrigin::signed(1), Box::new(proposal.clone())),
+			BadOrigin
+		);
+		assert_noop!(
+			Alliance::schedule_enactment(motion_origin(0), Box::new(proposal.clone())),
+			BadOrigin
+		);
+
+		// Proposals which cannot be enacted in a block are rejected.
+		AutoCloseWeight::set(Weight::zero());
+		assert_noop!(
+			Alliance::schedule_enactment(motion_origin(2), Box::new(proposal.clone())),
+			Error::<Test, ()>::EnactmentTooHeavy
+		);
+		AutoCloseWeight::set(Weight::MAX);
+
+		for index in 0..MaxProposals::get() {
+			assert_ok!(Alliance::schedule_enactment(motion_origin(2), Box::new(proposal.clone())));
+			assert_eq!(NextEnactmentIndex::<Test>::get(), index + 1);
+		}
+		assert_noop!(
+			Alliance::schedule_enactment(motion_origin(2), Box::new(proposal)),
+			Error::<Test, ()>::TooManyPendingEnactments
+		);
+	});
+}
+
+#[test]
+fn delayed_proposals_respect_proposal_filter() {
+	new_test_ext().execute_with(|| {
+		OnlyAllianceProposals::set(true);
+		let (remark, _, _) = make_remark_proposal(42);
+		let (proposal, proposal_len, _) =
+			make_proposal(RuntimeCall::Alliance(crate::Call::schedule_enactment {
+				proposal: Box::new(remark.clone()),
+			}));
+
+		// The call wrapping the proposal is allowed, but not the proposal itself.
+		assert!(TestProposalFilter::contains(&proposal));
+		assert_noop!(
+			Alliance::propose(RuntimeOrigin::signed(1), 3, Box::new(proposal), proposal_len),
+			Error::<Test, ()>::ProposalNotAllowed
+		);
+		assert_noop!(
+			Alliance::schedule_enactment(motion_origin(2), Box::new(remark)),
+			Error::<Test, ()>::ProposalNotAllowed
+		);
+
+		let (kick, _, _) = make_kick_member_proposal(2);
+		assert_ok!(Alliance::schedule_enactment(motion_origin(2), Box::new(kick)));
+	});
+}
+
+#[test]
+fn schedule_enactment_fails_once_too_many_blocks_are_pending() {
+	new_test_ext().execute_with(|| {
+		let (proposal, _, _) = make_remark_proposal(42);
+		for block in 1..=MaxProposals::get() as u64 {
+			System::set_block_number(block);
+			assert_ok!(Alliance::schedule_enactment(motion_origin(2), Box::new(proposal.clone())));
+		}
+		assert_eq!(EnactmentBlocks::<Test>::decode_len(), Some(MaxProposals::get() as usize));
+
+		System::set_block_number(MaxProposals::get() as u64 + 1);
+		assert_noop!(
+			Alliance::schedule_enactment(motion_origin(2), Box::new(proposal)),
+			Error::<Test, ()>::TooManyPendingEnactments
+		);
+	});
+}
+
+#[test]
+fn heavy_enactments_do_not_hold_back_lighter_ones() {
+	new_test_ext().execute_with(|| {
+		EnactmentDelay::set(5);
+		let (heavy, _, _) = make_proposal(RuntimeCall::System(frame_system::Call::remark {
+			remark: vec![0; 1024],
+		}));
+		let (light, _, _) = make_remark_proposal(42);
+		assert_ok!(Alliance::schedule_enactment(motion_origin(2), Box::new(heavy)));
+		assert_ok!(Alliance::schedule_enactment(motion_origin(2), Box::new(light.clone())));
+
+		// Only the lighter proposal, approved later, fits in the block.
+		AutoCloseWeight::set(light.get_dispatch_info().weight);
+		Alliance::on_initialize(6);
+		System::assert_last_event(mock::RuntimeEvent::Alliance(crate::Event::ProposalEnacted {
+			index: 1,
+			result: Ok(()),
+		}));
+		assert_eq!(
+			PendingEnactments::<Test>::get(6).iter().map(|e| e.index).collect::<Vec<_>>(),
+			vec![0]
+		);
+		assert_eq!(EnactmentBlocks::<Test>::get().into_inner(), vec![6]);
+
+		// The heavier one is enacted once it fits.
+		AutoCloseWeight::set(Weight::MAX);
+		Alliance::on_initialize(7);
+		System::assert_last_event(mock::RuntimeEvent::Alliance(crate::Event::ProposalEnacted {
+			index: 0,
+			result: Ok(()),
+		}));
+		assert!(!PendingEnactments::<Test>::contains_key(6));
+		assert!(EnactmentBlocks::<Test>::get().is_empty());
+	});
+}
+
+#[test]
+fn cancel_enactment_works() {
+	new_test_ext().execute_with(|| {
+		EnactmentDelay::set(5);
+		let (proposal, _, _) = make_remark_proposal(42);
+		assert_ok!(Alliance::schedule_enactment(motion_origin(2), Box::new(proposal.clone())));
+		assert_ok!(Alliance::schedule_enactment(motion_origin(2), Box::new(proposal)));
+
+		assert_noop!(Alliance::cancel_enactment(RuntimeOrigin::signed(2), 6, 0), BadOrigin);
+		assert_noop!(
+			Alliance::cancel_enactment(RuntimeOrigin::signed(1), 6, 2),
+			Error::<Test, ()>::UnknownEnactment
+		);
+		assert_noop!(
+			Alliance::cancel_enactment(RuntimeOrigin::signed(1), 7, 0),
+			Error::<Test, ()>::UnknownEnactment
+		);
+
+		assert_ok!(Alliance::cancel_enactment(RuntimeOrigin::signed(1), 6, 0));
+		System::assert_last_event(mock::RuntimeEvent::Alliance(crate::Event::EnactmentCancelled {
+			index: 0,
+		}));
+		assert_eq!(
+			PendingEnactments::<Test>::get(6).iter().map(|e| e.index).collect::<Vec<_>>(),
+			vec![1]
+		);
+
+		// The cancelled proposal is not enacted.
+		Alliance::on_initialize(6);
+		System::assert_has_event(mock::RuntimeEvent::Alliance(crate::Event::ProposalEnacted {
+			index: 1,
+			result: Ok(()),
+		}));
+		assert!(!System::events().iter().any(|record| record.event ==
+			mock::RuntimeEvent::Alliance(crate::Event::ProposalEnacted {
+				index: 0,
+				result: Ok(()),
+			})));
 	});
 }
 
@@ -646,3 +2068,258 @@ fn weights_sane() {
 	let info = crate::Call::<Test>::nominate_ally { who: 10 }.get_dispatch_info();
 	assert_eq!(<() as crate::WeightInfo>::nominate_ally(), info.weight);
 }
+
+#[test]
+fn event_schema_is_recorded() {
+	assert_event_schema!(Event<Test>, Alliance::event_schema_version(), {
+		1 => [
+			"0: NewRuleSet { rule: Cid, previous: Option<Cid> }",
+			"1: Announced { announcement: Cid }",
+			"2: AnnouncementRemoved { announcement: Cid }",
+			"3: MembersInitialized { fellows: Vec<T::AccountId>, allies: Vec<T::AccountId> }",
+			"4: NewAllyJoined { ally: T::AccountId, nominator: Option<T::AccountId>, \
+			 reserved: Option<BalanceOf<T,I>> }",
+			"5: AllyElevated { ally: T::AccountId }",
+			"6: MemberRetirementPeriodStarted { member: T::AccountId }",
+			"7: MemberRetired { member: T::AccountId, unreserved: Option<BalanceOf<T,I>> }",
+			"8: MemberKicked { member: T::AccountId, slashed: Option<BalanceOf<T,I>> }",
+			"9: UnscrupulousItemAdded { items: Vec<UnscrupulousItemOf<T,I>> }",
+			"10: UnscrupulousItemRemoved { items: Vec<UnscrupulousItemOf<T,I>> }",
+			"11: AllianceDisbanded { fellow_members: u32, ally_members: u32, unreserved: u32 }",
+			"12: FellowAbdicated { fellow: T::AccountId }",
+			"13: MemberSuspended { member: T::AccountId, until: BlockNumberFor<T> }",
+			"14: MemberUnsuspended { member: T::AccountId }",
+			"15: SubCommitteeCreated { index: SubCommitteeIndex, members: Vec<T::AccountId>, \
+			 power: SubCommitteePower, expiry: BlockNumberFor<T> }",
+			"16: SubCommitteeRevoked { index: SubCommitteeIndex }",
+			"17: ProposalClosed { hash: T::Hash, ayes: u32, nays: u32, threshold: u32, \
+			 outcome: ProposalOutcome }",
+			"18: CidFlagged { cid: Cid }",
+			"19: CidUnflagged { cid: Cid }",
+		],
+		2 => [
+			"0: NewRuleSet { rule: Cid, previous: Option<Cid> }",
+			"1: Announced { announcement: Cid }",
+			"2: AnnouncementRemoved { announcement: Cid }",
+			"3: MembersInitialized { fellows: Vec<T::AccountId>, allies: Vec<T::AccountId> }",
+			"4: NewAllyJoined { ally: T::AccountId, nominator: Option<T::AccountId>, \
+			 reserved: Option<BalanceOf<T,I>> }",
+			"5: AllyElevated { ally: T::AccountId }",
+			"6: MemberRetirementPeriodStarted { member: T::AccountId }",
+			"7: MemberRetired { member: T::AccountId, unreserved: Option<BalanceOf<T,I>> }",
+			"8: MemberKicked { member: T::AccountId, slashed: Option<BalanceOf<T,I>> }",
+			"9: UnscrupulousItemAdded { items: Vec<UnscrupulousItemOf<T,I>> }",
+			"10: UnscrupulousItemRemoved { items: Vec<UnscrupulousItemOf<T,I>> }",
+			"11: AllianceDisbanded { fellow_members: u32, ally_members: u32, unreserved: u32 }",
+			"12: FellowAbdicated { fellow: T::AccountId }",
+			"13: MemberSuspended { member: T::AccountId, until: BlockNumberFor<T> }",
+			"14: MemberUnsuspended { member: T::AccountId }",
+			"15: SubCommitteeCreated { index: SubCommitteeIndex, members: Vec<T::AccountId>, \
+			 power: SubCommitteePower, expiry: BlockNumberFor<T> }",
+			"16: SubCommitteeRevoked { index: SubCommitteeIndex }",
+			"17: ProposalClosed { hash: T::Hash, ayes: u32, nays: u32, threshold: u32, \
+			 outcome: ProposalOutcome }",
+			"18: CidFlagged { cid: Cid }",
+			"19: CidUnflagged { cid: Cid }",
+			"20: NominationQuotaExhausted { fellow: T::AccountId }",
+		],
+		3 => [
+			"0: NewRuleSet { rule: Cid, previous: Option<Cid> }",
+			"1: Announced { announcement: Cid }",
+			"2: AnnouncementRemoved { announcement: Cid }",
+			"3: MembersInitialized { fellows: Vec<T::AccountId>, allies: Vec<T::AccountId> }",
+			"4: NewAllyJoined { ally: T::AccountId, nominator: Option<T::AccountId>, \
+			 reserved: Option<BalanceOf<T,I>> }",
+			"5: AllyElevated { ally: T::AccountId }",
+			"6: MemberRetirementPeriodStarted { member: T::AccountId }",
+			"7: MemberRetired { member: T::AccountId, unreserved: Option<BalanceOf<T,I>> }",
+			"8: MemberKicked { member: T::AccountId, slashed: Option<BalanceOf<T,I>> }",
+			"9: UnscrupulousItemAdded { items: Vec<UnscrupulousItemOf<T,I>> }",
+			"10: UnscrupulousItemRemoved { items: Vec<UnscrupulousItemOf<T,I>> }",
+			"11: AllianceDisbanded { fellow_members: u32, ally_members: u32, unreserved: u32 }",
+			"12: FellowAbdicated { fellow: T::AccountId }",
+			"13: MemberSuspended { member: T::AccountId, until: BlockNumberFor<T> }",
+			"14: MemberUnsuspended { member: T::AccountId }",
+			"15: SubCommitteeCreated { index: SubCommitteeIndex, members: Vec<T::AccountId>, \
+			 power: SubCommitteePower, expiry: BlockNumberFor<T> }",
+			"16: SubCommitteeRevoked { index: SubCommitteeIndex }",
+			"17: ProposalClosed { hash: T::Hash, ayes: u32, nays: u32, threshold: u32, \
+			 outcome: ProposalOutcome }",
+			"18: CidFlagged { cid: Cid }",
+			"19: CidUnflagged { cid: Cid }",
+			"20: NominationQuotaExhausted { fellow: T::AccountId }",
+			"21: SnapshotImported { hash: T::Hash, members: u32 }",
+		],
+		4 => [
+			"0: NewRuleSet { rule: Cid, previous: Option<Cid> }",
+			"1: Announced { announcement: Cid }",
+			"2: AnnouncementRemoved { announcement: Cid }",
+			"3: MembersInitialized { fellows: Vec<T::AccountId>, allies: Vec<T::AccountId> }",
+			"4: NewAllyJoined { ally: T::AccountId, nominator: Option<T::AccountId>, \
+			 reserved: Option<BalanceOf<T,I>> }",
+			"5: AllyElevated { ally: T::AccountId }",
+			"6: MemberRetirementPeriodStarted { member: T::AccountId }",
+			"7: MemberRetired { member: T::AccountId, unreserved: Option<BalanceOf<T,I>> }",
+			"8: MemberKicked { member: T::AccountId, slashed: Option<BalanceOf<T,I>> }",
+			"9: UnscrupulousItemAdded { items: Vec<UnscrupulousItemOf<T,I>> }",
+			"10: UnscrupulousItemRemoved { items: Vec<UnscrupulousItemOf<T,I>> }",
+			"11: AllianceDisbanded { fellow_members: u32, ally_members: u32, unreserved: u32 }",
+			"12: FellowAbdicated { fellow: T::AccountId }",
+			"13: MemberSuspended { member: T::AccountId, until: BlockNumberFor<T> }",
+			"14: MemberUnsuspended { member: T::AccountId }",
+			"15: SubCommitteeCreated { index: SubCommitteeIndex, members: Vec<T::AccountId>, \
+			 power: SubCommitteePower, expiry: BlockNumberFor<T> }",
+			"16: SubCommitteeRevoked { index: SubCommitteeIndex }",
+			"17: ProposalClosed { hash: T::Hash, ayes: u32, nays: u32, threshold: u32, \
+			 outcome: ProposalOutcome }",
+			"18: CidFlagged { cid: Cid }",
+			"19: CidUnflagged { cid: Cid }",
+			"20: NominationQuotaExhausted { fellow: T::AccountId }",
+			"21: SnapshotImported { hash: T::Hash, members: u32 }",
+			"22: EarlyRetirementSlashed { member: T::AccountId, slashed: BalanceOf<T,I>, \
+			 tenure: BlockNumberFor<T> }",
+		],
+		5 => [
+			"0: NewRuleSet { rule: Cid, previous: Option<Cid> }",
+			"1: Announced { announcement: Cid }",
+			"2: AnnouncementRemoved { announcement: Cid }",
+			"3: MembersInitialized { fellows: Vec<T::AccountId>, allies: Vec<T::AccountId> }",
+			"4: NewAllyJoined { ally: T::AccountId, nominator: Option<T::AccountId>, \
+			 reserved: Option<BalanceOf<T,I>> }",
+			"5: AllyElevated { ally: T::AccountId }",
+			"6: MemberRetirementPeriodStarted { member: T::AccountId }",
+			"7: MemberRetired { member: T::AccountId, unreserved: Option<BalanceOf<T,I>> }",
+			"8: MemberKicked { member: T::AccountId, slashed: Option<BalanceOf<T,I>> }",
+			"9: UnscrupulousItemAdded { items: Vec<UnscrupulousItemOf<T,I>> }",
+			"10: UnscrupulousItemRemoved { items: Vec<UnscrupulousItemOf<T,I>> }",
+			"11: AllianceDisbanded { fellow_members: u32, ally_members: u32, unreserved: u32 }",
+			"12: FellowAbdicated { fellow: T::AccountId }",
+			"13: MemberSuspended { member: T::AccountId, until: BlockNumberFor<T> }",
+			"14: MemberUnsuspended { member: T::AccountId }",
+			"15: SubCommitteeCreated { index: SubCommitteeIndex, members: Vec<T::AccountId>, \
+			 power: SubCommitteePower, expiry: BlockNumberFor<T> }",
+			"16: SubCommitteeRevoked { index: SubCommitteeIndex }",
+			"17: ProposalClosed { hash: T::Hash, ayes: u32, nays: u32, threshold: u32, \
+			 outcome: ProposalOutcome }",
+			"18: CidFlagged { cid: Cid }",
+			"19: CidUnflagged { cid: Cid }",
+			"20: NominationQuotaExhausted { fellow: T::AccountId }",
+			"21: SnapshotImported { hash: T::Hash, members: u32 }",
+			"22: EarlyRetirementSlashed { member: T::AccountId, slashed: BalanceOf<T,I>, \
+			 tenure: BlockNumberFor<T> }",
+			"23: EnactmentScheduled { index: EnactmentIndex, proposal_hash: T::Hash, \
+			 when: BlockNumberFor<T> }",
+			"24: ProposalEnacted { index: EnactmentIndex, result: DispatchResult }",
+			"25: EnactmentCancelled { index: EnactmentIndex }",
+		],
+		6 => [
+			"0: NewRuleSet { rule: Cid, previous: Option<Cid> }",
+			"1: Announced { announcement: Cid }",
+			"2: AnnouncementRemoved { announcement: Cid }",
+			"3: MembersInitialized { fellows: Vec<T::AccountId>, allies: Vec<T::AccountId> }",
+			"4: NewAllyJoined { ally: T::AccountId, nominator: Option<T::AccountId>, \
+			 reserved: Option<BalanceOf<T,I>> }",
+			"5: AllyElevated { ally: T::AccountId }",
+			"6: MemberRetirementPeriodStarted { member: T::AccountId }",
+			"7: MemberRetired { member: T::AccountId, unreserved: Option<BalanceOf<T,I>> }",
+			"8: MemberKicked { member: T::AccountId, slashed: Option<BalanceOf<T,I>> }",
+			"9: UnscrupulousItemAdded { items: Vec<UnscrupulousEntryOf<T,I>> }",
+			"10: UnscrupulousItemRemoved { items: Vec<UnscrupulousItemOf<T,I>> }",
+			"11: AllianceDisbanded { fellow_members: u32, ally_members: u32, unreserved: u32 }",
+			"12: FellowAbdicated { fellow: T::AccountId }",
+			"13: MemberSuspended { member: T::AccountId, until: BlockNumberFor<T> }",
+			"14: MemberUnsuspended { member: T::AccountId }",
+			"15: SubCommitteeCreated { index: SubCommitteeIndex, members: Vec<T::AccountId>, \
+			 power: SubCommitteePower, expiry: BlockNumberFor<T> }",
+			"16: SubCommitteeRevoked { index: SubCommitteeIndex }",
+			"17: ProposalClosed { hash: T::Hash, ayes: u32, nays: u32, threshold: u32, \
+			 outcome: ProposalOutcome }",
+			"18: CidFlagged { cid: Cid }",
+			"19: CidUnflagged { cid: Cid }",
+			"20: NominationQuotaExhausted { fellow: T::AccountId }",
+			"21: SnapshotImported { hash: T::Hash, members: u32 }",
+			"22: EarlyRetirementSlashed { member: T::AccountId, slashed: BalanceOf<T,I>, \
+			 tenure: BlockNumberFor<T> }",
+			"23: EnactmentScheduled { index: EnactmentIndex, proposal_hash: T::Hash, \
+			 when: BlockNumberFor<T> }",
+			"24: ProposalEnacted { index: EnactmentIndex, result: DispatchResult }",
+			"25: EnactmentCancelled { index: EnactmentIndex }",
+		],
+		7 => [
+			"0: NewRuleSet { rule: Cid, previous: Option<Cid> }",
+			"1: Announced { announcement: Cid }",
+			"2: AnnouncementRemoved { announcement: Cid }",
+			"3: MembersInitialized { fellows: Vec<T::AccountId>, allies: Vec<T::AccountId> }",
+			"4: NewAllyJoined { ally: T::AccountId, nominator: Option<T::AccountId>, \
+			 reserved: Option<BalanceOf<T,I>> }",
+			"5: AllyElevated { ally: T::AccountId }",
+			"6: MemberRetirementPeriodStarted { member: T::AccountId }",
+			"7: MemberRetired { member: T::AccountId, unreserved: Option<BalanceOf<T,I>> }",
+			"8: MemberKicked { member: T::AccountId, slashed: Option<BalanceOf<T,I>> }",
+			"9: UnscrupulousItemAdded { items: Vec<UnscrupulousEntryOf<T,I>> }",
+			"10: UnscrupulousItemRemoved { items: Vec<UnscrupulousItemOf<T,I>> }",
+			"11: AllianceDisbanded { fellow_members: u32, ally_members: u32, unreserved: u32 }",
+			"12: FellowAbdicated { fellow: T::AccountId }",
+			"13: MemberSuspended { member: T::AccountId, until: BlockNumberFor<T> }",
+			"14: MemberUnsuspended { member: T::AccountId }",
+			"15: SubCommitteeCreated { index: SubCommitteeIndex, members: Vec<T::AccountId>, \
+			 power: SubCommitteePower, expiry: BlockNumberFor<T> }",
+			"16: SubCommitteeRevoked { index: SubCommitteeIndex }",
+			"17: ProposalClosed { hash: T::Hash, ayes: u32, nays: u32, threshold: u32, \
+			 outcome: ProposalOutcome }",
+			"18: CidFlagged { cid: Cid }",
+			"19: CidUnflagged { cid: Cid }",
+			"20: NominationQuotaExhausted { fellow: T::AccountId }",
+			"21: SnapshotImported { hash: T::Hash, members: u32 }",
+			"22: EarlyRetirementSlashed { member: T::AccountId, slashed: BalanceOf<T,I>, \
+			 tenure: BlockNumberFor<T> }",
+			"23: EnactmentScheduled { index: EnactmentIndex, proposal_hash: T::Hash, \
+			 when: BlockNumberFor<T> }",
+			"24: ProposalEnacted { index: EnactmentIndex, result: DispatchResult }",
+			"25: EnactmentCancelled { index: EnactmentIndex }",
+			"26: UnscrupulousMemberKicked { member: T::AccountId, \
+			 slashed: Option<BalanceOf<T,I>> }",
+			"27: UnscrupulousMemberSuspended { member: T::AccountId, until: BlockNumberFor<T>, \
+			 slashed: Option<BalanceOf<T,I>> }",
+		],
+		8 => [
+			"0: NewRuleSet { rule: Cid, previous: Option<Cid> }",
+			"1: Announced { announcement: Cid }",
+			"2: AnnouncementRemoved { announcement: Cid }",
+			"3: MembersInitialized { fellows: Vec<T::AccountId>, allies: Vec<T::AccountId> }",
+			"4: NewAllyJoined { ally: T::AccountId, nominator: Option<T::AccountId>, \
+			 reserved: Option<BalanceOf<T,I>> }",
+			"5: AllyElevated { ally: T::AccountId }",
+			"6: MemberRetirementPeriodStarted { member: T::AccountId }",
+			"7: MemberRetired { member: T::AccountId, unreserved: Option<BalanceOf<T,I>> }",
+			"8: MemberKicked { member: T::AccountId, slashed: Option<BalanceOf<T,I>> }",
+			"9: UnscrupulousItemAdded { items: Vec<UnscrupulousEntryOf<T,I>> }",
+			"10: UnscrupulousItemRemoved { items: Vec<UnscrupulousItemOf<T,I>> }",
+			"11: AllianceDisbanded { fellow_members: u32, ally_members: u32, unreserved: u32 }",
+			"12: FellowAbdicated { fellow: T::AccountId }",
+			"13: MemberSuspended { member: T::AccountId, until: BlockNumberFor<T> }",
+			"14: MemberUnsuspended { member: T::AccountId }",
+			"15: SubCommitteeCreated { index: SubCommitteeIndex, members: Vec<T::AccountId>, \
+			 power: SubCommitteePower, expiry: BlockNumberFor<T> }",
+			"16: SubCommitteeRevoked { index: SubCommitteeIndex }",
+			"17: ProposalClosed { hash: T::Hash, ayes: u32, nays: u32, threshold: u32, \
+			 outcome: ProposalOutcome }",
+			"18: CidFlagged { cid: Cid }",
+			"19: CidUnflagged { cid: Cid }",
+			"20: NominationQuotaExhausted { fellow: T::AccountId }",
+			"21: SnapshotImported { hash: T::Hash, members: u32 }",
+			"22: EarlyRetirementSlashed { member: T::AccountId, slashed: BalanceOf<T,I>, \
+			 tenure: BlockNumberFor<T> }",
+			"23: EnactmentScheduled { index: EnactmentIndex, proposal_hash: T::Hash, \
+			 when: BlockNumberFor<T> }",
+			"24: ProposalEnacted { index: EnactmentIndex, result: DispatchResult }",
+			"25: EnactmentCancelled { index: EnactmentIndex }",
+			"26: UnscrupulousMemberKicked { member: T::AccountId, \
+			 slashed: Option<BalanceOf<T,I>> }",
+			"27: UnscrupulousMemberSuspended { member: T::AccountId, until: BlockNumberFor<T>, \
+			 slashed: Option<BalanceOf<T,I>> }",
+			"28: DisbandStarted { fellow_members: u32, ally_members: u32 }",
+			"29: DisbandProgressed { fellow_members: u32, ally_members: u32, unreserved: u32 }",
+		],
+	});
+}
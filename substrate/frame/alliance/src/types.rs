@@ -16,93 +16,21 @@
 // limitations under the License.
 
 use codec::{Decode, Encode, MaxEncodedLen};
-use frame_support::{traits::ConstU32, BoundedVec};
 use scale_info::TypeInfo;
 use sp_runtime::RuntimeDebug;
-use sp_std::{convert::TryInto, prelude::*};
 
-/// A Multihash instance that only supports the basic functionality and no hashing.
-#[derive(
-	Clone, PartialEq, Eq, PartialOrd, Ord, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen,
-)]
-pub struct Multihash {
-	/// The code of the Multihash.
-	pub code: u64,
-	/// The digest.
-	pub digest: BoundedVec<u8, ConstU32<68>>, // 4 byte dig size + 64 bytes hash digest
-}
-
-impl Multihash {
-	/// Returns the size of the digest.
-	pub fn size(&self) -> usize {
-		self.digest.len()
-	}
-}
-
-/// The version of the CID.
-#[derive(
-	Clone,
-	Copy,
-	PartialEq,
-	Eq,
-	PartialOrd,
-	Ord,
-	RuntimeDebug,
-	Encode,
-	Decode,
-	TypeInfo,
-	MaxEncodedLen,
-)]
-pub enum Version {
-	/// CID version 0.
-	V0,
-	/// CID version 1.
-	V1,
-}
-
-/// Representation of a CID.
-///
-/// The generic is about the allocated size of the multihash.
-#[derive(
-	Clone, PartialEq, Eq, PartialOrd, Ord, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen,
-)]
-pub struct Cid {
-	/// The version of CID.
-	pub version: Version,
-	/// The codec of CID.
-	pub codec: u64,
-	/// The multihash of CID.
-	pub hash: Multihash,
-}
-
-impl Cid {
-	/// Creates a new CIDv0.
-	pub fn new_v0(sha2_256_digest: impl Into<Vec<u8>>) -> Self {
-		/// DAG-PB multicodec code
-		const DAG_PB: u64 = 0x70;
-		/// The SHA_256 multicodec code
-		const SHA2_256: u64 = 0x12;
-
-		let digest = sha2_256_digest.into();
-		assert_eq!(digest.len(), 32);
-
-		Self {
-			version: Version::V0,
-			codec: DAG_PB,
-			hash: Multihash { code: SHA2_256, digest: digest.try_into().expect("msg") },
-		}
-	}
-}
+pub use frame_support::cid::{Cid, Multihash, Version};
 
-/// Witness data for the `disband` call.
+/// Witness data for the `disband` and `continue_disband` calls.
 #[derive(
 	Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, Default,
 )]
 pub struct DisbandWitness {
-	/// Total number of fellow members in the current Alliance.
+	/// Maximum number of fellow members removed by the call. For `disband` it must cover all
+	/// fellow members of the current Alliance.
 	#[codec(compact)]
 	pub(super) fellow_members: u32,
-	/// Total number of ally members in the current Alliance.
+	/// Maximum number of ally members removed by the call.
 	#[codec(compact)]
 	pub(super) ally_members: u32,
 }
@@ -120,3 +48,16 @@ impl DisbandWitness {
 		self == Self::default()
 	}
 }
+
+/// The members removed so far while disbanding the Alliance over several calls.
+#[derive(
+	Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, Default,
+)]
+pub struct DisbandStatus {
+	/// Number of fellow members removed.
+	pub fellow_members: u32,
+	/// Number of ally members removed.
+	pub ally_members: u32,
+	/// Number of deposits released.
+	pub unreserved: u32,
+}
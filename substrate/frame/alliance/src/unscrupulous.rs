@@ -0,0 +1,126 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Unscrupulous lists shared between several instances of the Alliance.
+//!
+//! Every instance keeps its own lists of unscrupulous accounts and websites. Runtimes with more
+//! than one instance can use [`UnscrupulousAggregate`] to treat them as one list, e.g.
+//! `UnscrupulousAggregate<AccountId, (Alliance, TechnicalAlliance)>`, or hand it to other pallets
+//! as a [`Contains`] filter.
+
+use super::{Config, Pallet, UnscrupulousAccounts, UnscrupulousWebsites};
+use frame_support::traits::Contains;
+use sp_std::{marker::PhantomData, prelude::*};
+
+/// Read access to the unscrupulous lists of one or more Alliance instances.
+///
+/// Implemented by every instance of the [`Pallet`] and by tuples of them, which union the lists
+/// of their members.
+pub trait UnscrupulousList<AccountId> {
+	/// The accounts deemed unscrupulous, without duplicates.
+	fn unscrupulous_accounts() -> Vec<AccountId>;
+
+	/// The websites deemed unscrupulous, without duplicates.
+	fn unscrupulous_websites() -> Vec<Vec<u8>>;
+
+	/// Whether `who` is listed as unscrupulous.
+	fn is_unscrupulous_account(who: &AccountId) -> bool;
+}
+
+impl<T: Config<I>, I: 'static> UnscrupulousList<T::AccountId> for Pallet<T, I> {
+	fn unscrupulous_accounts() -> Vec<T::AccountId> {
+		UnscrupulousAccounts::<T, I>::get().into_inner()
+	}
+
+	fn unscrupulous_websites() -> Vec<Vec<u8>> {
+		UnscrupulousWebsites::<T, I>::get()
+			.into_iter()
+			.map(|url| url.into_inner())
+			.collect()
+	}
+
+	fn is_unscrupulous_account(who: &T::AccountId) -> bool {
+		UnscrupulousAccounts::<T, I>::get().contains(who)
+	}
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(1, 8)]
+impl<AccountId: PartialEq> UnscrupulousList<AccountId> for Tuple {
+	fn unscrupulous_accounts() -> Vec<AccountId> {
+		let mut accounts = Vec::new();
+		for_tuples!( #(
+			union(&mut accounts, <Tuple as UnscrupulousList<AccountId>>::unscrupulous_accounts());
+		)* );
+		accounts
+	}
+
+	fn unscrupulous_websites() -> Vec<Vec<u8>> {
+		let mut websites = Vec::new();
+		for_tuples!( #(
+			union(&mut websites, <Tuple as UnscrupulousList<AccountId>>::unscrupulous_websites());
+		)* );
+		websites
+	}
+
+	fn is_unscrupulous_account(who: &AccountId) -> bool {
+		for_tuples!( #(
+			if <Tuple as UnscrupulousList<AccountId>>::is_unscrupulous_account(who) {
+				return true
+			}
+		)* );
+		false
+	}
+}
+
+/// Appends the items of `other` that are not in `items` yet.
+fn union<Item: PartialEq>(items: &mut Vec<Item>, other: Vec<Item>) {
+	for item in other {
+		if !items.contains(&item) {
+			items.push(item);
+		}
+	}
+}
+
+/// The union of the unscrupulous lists of the `Instances`, a tuple of Alliance [`Pallet`]s.
+pub struct UnscrupulousAggregate<AccountId, Instances>(PhantomData<(AccountId, Instances)>);
+
+impl<AccountId, Instances: UnscrupulousList<AccountId>>
+	UnscrupulousAggregate<AccountId, Instances>
+{
+	/// The accounts deemed unscrupulous by any of the instances.
+	pub fn unscrupulous_accounts() -> Vec<AccountId> {
+		Instances::unscrupulous_accounts()
+	}
+
+	/// The websites deemed unscrupulous by any of the instances.
+	pub fn unscrupulous_websites() -> Vec<Vec<u8>> {
+		Instances::unscrupulous_websites()
+	}
+
+	/// Whether `who` is listed as unscrupulous by any of the instances.
+	pub fn is_unscrupulous_anywhere(who: &AccountId) -> bool {
+		Instances::is_unscrupulous_account(who)
+	}
+}
+
+impl<AccountId, Instances: UnscrupulousList<AccountId>> Contains<AccountId>
+	for UnscrupulousAggregate<AccountId, Instances>
+{
+	fn contains(who: &AccountId) -> bool {
+		Self::is_unscrupulous_anywhere(who)
+	}
+}
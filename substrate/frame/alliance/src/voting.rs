@@ -0,0 +1,266 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The voting backend of the Alliance.
+//!
+//! The pallet keeps the proposals of the Fellows with a [`VotingProvider`], its `propose`, `vote`
+//! and `close` extrinsics only check the caller's rights before handing over to it.
+//! [`CollectiveVoting`] adapts an instance of `pallet-collective` and is what most alliances
+//! use, it is available with the `collective` feature. Others can be backed by e.g.
+//! `pallet-referenda` with token or rank voting, by implementing the trait on top of it. The unit
+//! type leaves the Alliance without proposals.
+//!
+//! The extrinsics are weighed with the weights the backend reports, on top of the checks of the
+//! pallet.
+
+use super::{ProposalIndex, Weight};
+use frame_support::{
+	dispatch::{DispatchErrorWithPostInfo, PostDispatchInfo},
+	pallet_prelude::*,
+};
+#[cfg(feature = "collective")]
+use pallet_collective::WeightInfo as _;
+use sp_runtime::DispatchError;
+#[cfg(feature = "collective")]
+use sp_std::marker::PhantomData;
+use sp_std::prelude::*;
+
+/// The backend the Fellows vote on proposals with.
+pub trait VotingProvider<AccountId, Hash, Proposal> {
+	/// A vote on a proposal, e.g. `bool` for an aye or a nay in a collective.
+	///
+	/// Every backend must accept a plain aye (`true`) or nay (`false`).
+	type Vote: Parameter + MaxEncodedLen + From<bool>;
+
+	/// Add a new proposal, to be approved by `threshold` votes if the backend counts votes.
+	/// Returns a proposal length and active proposals count if successful.
+	fn propose(
+		who: AccountId,
+		threshold: u32,
+		proposal: Box<Proposal>,
+		length_bound: u32,
+	) -> Result<(u32, u32), DispatchError>;
+
+	/// Record the vote of `who` on the given proposal.
+	/// Returns true if `who` votes for the first time if successful.
+	fn vote(
+		who: AccountId,
+		proposal: Hash,
+		index: ProposalIndex,
+		vote: Self::Vote,
+	) -> Result<bool, DispatchError>;
+
+	/// Close a proposal that is either approved, disapproved, or whose voting period has ended.
+	/// Returns the votes the proposal was closed with if successful.
+	///
+	/// Backends that close proposals on their own, like `pallet-referenda`, return an error.
+	fn close(
+		proposal_hash: Hash,
+		index: ProposalIndex,
+		proposal_weight_bound: Weight,
+		length_bound: u32,
+	) -> Result<(PostDispatchInfo, ProposalTally), DispatchErrorWithPostInfo>;
+
+	/// Return a proposal of the given hash.
+	fn proposal_of(proposal_hash: Hash) -> Option<Proposal>;
+
+	/// Whether the proposal has enough votes to be closed before its voting period ends.
+	fn is_decided(proposal_hash: Hash) -> bool;
+
+	/// Return the hashes of the proposals currently being voted on.
+	fn proposals() -> Vec<Hash>;
+
+	/// The weight of [`Self::propose`] for a proposal of at most `length` bytes.
+	fn propose_weight(length: u32) -> Weight;
+
+	/// The weight of [`Self::vote`].
+	fn vote_weight() -> Weight;
+
+	/// The worst case weight of [`Self::close`] for a proposal of at most `length` bytes, without
+	/// the weight of the proposal itself.
+	fn close_weight(length: u32) -> Weight;
+}
+
+/// No voting backend, proposals can not be made.
+impl<AccountId, Hash, Proposal> VotingProvider<AccountId, Hash, Proposal> for () {
+	type Vote = bool;
+
+	fn propose(
+		_who: AccountId,
+		_threshold: u32,
+		_proposal: Box<Proposal>,
+		_length_bound: u32,
+	) -> Result<(u32, u32), DispatchError> {
+		Err(DispatchError::Other("No voting backend"))
+	}
+
+	fn vote(
+		_who: AccountId,
+		_proposal: Hash,
+		_index: ProposalIndex,
+		_vote: bool,
+	) -> Result<bool, DispatchError> {
+		Err(DispatchError::Other("No voting backend"))
+	}
+
+	fn close(
+		_proposal_hash: Hash,
+		_index: ProposalIndex,
+		_proposal_weight_bound: Weight,
+		_length_bound: u32,
+	) -> Result<(PostDispatchInfo, ProposalTally), DispatchErrorWithPostInfo> {
+		Err(DispatchError::Other("No voting backend").into())
+	}
+
+	fn proposal_of(_proposal_hash: Hash) -> Option<Proposal> {
+		None
+	}
+
+	fn is_decided(_proposal_hash: Hash) -> bool {
+		false
+	}
+
+	fn proposals() -> Vec<Hash> {
+		Vec::new()
+	}
+
+	fn propose_weight(_length: u32) -> Weight {
+		Weight::zero()
+	}
+
+	fn vote_weight() -> Weight {
+		Weight::zero()
+	}
+
+	fn close_weight(_length: u32) -> Weight {
+		Weight::zero()
+	}
+}
+
+/// The outcome of a closed proposal.
+#[derive(Copy, Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub enum ProposalOutcome {
+	/// The proposal reached its threshold and was executed.
+	Approved,
+	/// The proposal was rejected, or its voting period ended without reaching the threshold.
+	Disapproved,
+}
+
+/// The votes a proposal was closed with.
+///
+/// Abstentions are counted as ayes or nays according to the default vote of the collective.
+#[derive(Copy, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct ProposalTally {
+	/// The number of votes in favour of the proposal.
+	pub ayes: u32,
+	/// The number of votes against the proposal.
+	pub nays: u32,
+	/// The number of ayes the proposal needed to be approved.
+	pub threshold: u32,
+	/// Whether the proposal was approved.
+	pub outcome: ProposalOutcome,
+}
+
+/// Voting with the instance `I` of `pallet-collective`, weighed with its `WeightInfo`.
+#[cfg(feature = "collective")]
+pub struct CollectiveVoting<T, I = ()>(PhantomData<(T, I)>);
+
+#[cfg(feature = "collective")]
+impl<T: pallet_collective::Config<I>, I: 'static>
+	VotingProvider<T::AccountId, T::Hash, <T as pallet_collective::Config<I>>::Proposal>
+	for CollectiveVoting<T, I>
+{
+	type Vote = bool;
+
+	fn propose(
+		who: T::AccountId,
+		threshold: u32,
+		proposal: Box<<T as pallet_collective::Config<I>>::Proposal>,
+		length_bound: u32,
+	) -> Result<(u32, u32), DispatchError> {
+		pallet_collective::Pallet::<T, I>::do_propose_proposed(
+			who,
+			threshold,
+			proposal,
+			length_bound,
+		)
+	}
+
+	fn vote(
+		who: T::AccountId,
+		proposal: T::Hash,
+		index: ProposalIndex,
+		approve: bool,
+	) -> Result<bool, DispatchError> {
+		pallet_collective::Pallet::<T, I>::do_vote(who, proposal, index, approve)
+	}
+
+	fn close(
+		proposal_hash: T::Hash,
+		index: ProposalIndex,
+		proposal_weight_bound: Weight,
+		length_bound: u32,
+	) -> Result<(PostDispatchInfo, ProposalTally), DispatchErrorWithPostInfo> {
+		let (info, tally) = pallet_collective::Pallet::<T, I>::do_close_with_tally(
+			proposal_hash,
+			index,
+			proposal_weight_bound,
+			length_bound,
+		)?;
+		let outcome =
+			if tally.approved { ProposalOutcome::Approved } else { ProposalOutcome::Disapproved };
+		Ok((
+			info,
+			ProposalTally { ayes: tally.yes, nays: tally.no, threshold: tally.threshold, outcome },
+		))
+	}
+
+	fn proposal_of(
+		proposal_hash: T::Hash,
+	) -> Option<<T as pallet_collective::Config<I>>::Proposal> {
+		pallet_collective::Pallet::<T, I>::proposal_of(proposal_hash)
+	}
+
+	fn is_decided(proposal_hash: T::Hash) -> bool {
+		pallet_collective::Pallet::<T, I>::is_decided(&proposal_hash)
+	}
+
+	fn proposals() -> Vec<T::Hash> {
+		pallet_collective::Pallet::<T, I>::proposals().into_inner()
+	}
+
+	fn propose_weight(length: u32) -> Weight {
+		<T as pallet_collective::Config<I>>::WeightInfo::propose_proposed(
+			length,
+			T::MaxMembers::get(),
+			T::MaxProposals::get(),
+		)
+	}
+
+	fn vote_weight() -> Weight {
+		<T as pallet_collective::Config<I>>::WeightInfo::vote(T::MaxMembers::get())
+	}
+
+	fn close_weight(length: u32) -> Weight {
+		let m = T::MaxMembers::get();
+		let p = T::MaxProposals::get();
+		<T as pallet_collective::Config<I>>::WeightInfo::close_early_approved(length, m, p)
+			.max(<T as pallet_collective::Config<I>>::WeightInfo::close_early_disapproved(m, p))
+			.max(<T as pallet_collective::Config<I>>::WeightInfo::close_approved(length, m, p))
+			.max(<T as pallet_collective::Config<I>>::WeightInfo::close_disapproved(m, p))
+	}
+}
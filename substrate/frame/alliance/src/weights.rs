@@ -52,12 +52,6 @@ use core::marker::PhantomData;
 
 /// Weight functions needed for pallet_alliance.
 pub trait WeightInfo {
-	fn propose_proposed(b: u32, m: u32, p: u32, ) -> Weight;
-	fn vote(m: u32, ) -> Weight;
-	fn close_early_disapproved(m: u32, p: u32, ) -> Weight;
-	fn close_early_approved(b: u32, m: u32, p: u32, ) -> Weight;
-	fn close_disapproved(m: u32, p: u32, ) -> Weight;
-	fn close_approved(b: u32, m: u32, p: u32, ) -> Weight;
 	fn init_members(m: u32, z: u32, ) -> Weight;
 	fn disband(x: u32, y: u32, z: u32, ) -> Weight;
 	fn set_rule() -> Weight;
@@ -72,176 +66,24 @@ pub trait WeightInfo {
 	fn add_unscrupulous_items(n: u32, l: u32, ) -> Weight;
 	fn remove_unscrupulous_items(n: u32, l: u32, ) -> Weight;
 	fn abdicate_fellow_status() -> Weight;
+	fn suspend_member() -> Weight;
+	fn unsuspend_member() -> Weight;
+	fn create_sub_committee(m: u32, ) -> Weight;
+	fn revoke_sub_committee() -> Weight;
+	fn flag_unreachable_cid() -> Weight;
+	fn unflag_cid() -> Weight;
+	fn force_import_snapshot(m: u32, u: u32, ) -> Weight;
+	fn schedule_enactment() -> Weight;
+	fn cancel_enactment(p: u32, ) -> Weight;
+	fn enforce_unscrupulous() -> Weight;
+	fn continue_disband(x: u32, y: u32, z: u32, ) -> Weight;
+	fn queue_decided_proposal() -> Weight;
+	fn ensure_sub_committee() -> Weight;
 }
 
 /// Weights for pallet_alliance using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-	/// Storage: Alliance Members (r:1 w:0)
-	/// Proof: Alliance Members (max_values: None, max_size: Some(3211), added: 5686, mode: MaxEncodedLen)
-	/// Storage: AllianceMotion ProposalOf (r:1 w:1)
-	/// Proof Skipped: AllianceMotion ProposalOf (max_values: None, max_size: None, mode: Measured)
-	/// Storage: AllianceMotion Proposals (r:1 w:1)
-	/// Proof Skipped: AllianceMotion Proposals (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: AllianceMotion ProposalCount (r:1 w:1)
-	/// Proof Skipped: AllianceMotion ProposalCount (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: AllianceMotion Voting (r:0 w:1)
-	/// Proof Skipped: AllianceMotion Voting (max_values: None, max_size: None, mode: Measured)
-	/// The range of component `b` is `[1, 1024]`.
-	/// The range of component `m` is `[2, 100]`.
-	/// The range of component `p` is `[1, 100]`.
-	fn propose_proposed(b: u32, m: u32, p: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `653 + m * (32 ±0) + p * (35 ±0)`
-		//  Estimated: `6676 + m * (32 ±0) + p * (36 ±0)`
-		// Minimum execution time: 36_908_000 picoseconds.
-		Weight::from_parts(39_040_304, 6676)
-			// Standard Error: 131
-			.saturating_add(Weight::from_parts(781, 0).saturating_mul(b.into()))
-			// Standard Error: 1_375
-			.saturating_add(Weight::from_parts(48_745, 0).saturating_mul(m.into()))
-			// Standard Error: 1_358
-			.saturating_add(Weight::from_parts(148_047, 0).saturating_mul(p.into()))
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
-			.saturating_add(Weight::from_parts(0, 32).saturating_mul(m.into()))
-			.saturating_add(Weight::from_parts(0, 36).saturating_mul(p.into()))
-	}
-	/// Storage: Alliance Members (r:1 w:0)
-	/// Proof: Alliance Members (max_values: None, max_size: Some(3211), added: 5686, mode: MaxEncodedLen)
-	/// Storage: AllianceMotion Voting (r:1 w:1)
-	/// Proof Skipped: AllianceMotion Voting (max_values: None, max_size: None, mode: Measured)
-	/// The range of component `m` is `[5, 100]`.
-	fn vote(m: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1042 + m * (64 ±0)`
-		//  Estimated: `6676 + m * (64 ±0)`
-		// Minimum execution time: 30_166_000 picoseconds.
-		Weight::from_parts(32_798_454, 6676)
-			// Standard Error: 1_432
-			.saturating_add(Weight::from_parts(83_001, 0).saturating_mul(m.into()))
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
-			.saturating_add(Weight::from_parts(0, 64).saturating_mul(m.into()))
-	}
-	/// Storage: Alliance Members (r:1 w:0)
-	/// Proof: Alliance Members (max_values: None, max_size: Some(3211), added: 5686, mode: MaxEncodedLen)
-	/// Storage: AllianceMotion Voting (r:1 w:1)
-	/// Proof Skipped: AllianceMotion Voting (max_values: None, max_size: None, mode: Measured)
-	/// Storage: AllianceMotion Members (r:1 w:0)
-	/// Proof Skipped: AllianceMotion Members (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: AllianceMotion Proposals (r:1 w:1)
-	/// Proof Skipped: AllianceMotion Proposals (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: AllianceMotion ProposalOf (r:0 w:1)
-	/// Proof Skipped: AllianceMotion ProposalOf (max_values: None, max_size: None, mode: Measured)
-	/// The range of component `m` is `[4, 100]`.
-	/// The range of component `p` is `[1, 100]`.
-	fn close_early_disapproved(m: u32, p: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `576 + m * (96 ±0) + p * (36 ±0)`
-		//  Estimated: `6676 + m * (97 ±0) + p * (36 ±0)`
-		// Minimum execution time: 45_173_000 picoseconds.
-		Weight::from_parts(42_192_020, 6676)
-			// Standard Error: 1_456
-			.saturating_add(Weight::from_parts(66_751, 0).saturating_mul(m.into()))
-			// Standard Error: 1_420
-			.saturating_add(Weight::from_parts(158_161, 0).saturating_mul(p.into()))
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
-			.saturating_add(Weight::from_parts(0, 97).saturating_mul(m.into()))
-			.saturating_add(Weight::from_parts(0, 36).saturating_mul(p.into()))
-	}
-	/// Storage: Alliance Members (r:1 w:0)
-	/// Proof: Alliance Members (max_values: None, max_size: Some(3211), added: 5686, mode: MaxEncodedLen)
-	/// Storage: AllianceMotion Voting (r:1 w:1)
-	/// Proof Skipped: AllianceMotion Voting (max_values: None, max_size: None, mode: Measured)
-	/// Storage: AllianceMotion Members (r:1 w:0)
-	/// Proof Skipped: AllianceMotion Members (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: AllianceMotion ProposalOf (r:1 w:1)
-	/// Proof Skipped: AllianceMotion ProposalOf (max_values: None, max_size: None, mode: Measured)
-	/// Storage: AllianceMotion Proposals (r:1 w:1)
-	/// Proof Skipped: AllianceMotion Proposals (max_values: Some(1), max_size: None, mode: Measured)
-	/// The range of component `b` is `[1, 1024]`.
-	/// The range of component `m` is `[4, 100]`.
-	/// The range of component `p` is `[1, 100]`.
-	fn close_early_approved(b: u32, m: u32, p: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1087 + m * (96 ±0) + p * (39 ±0)`
-		//  Estimated: `6676 + m * (97 ±0) + p * (40 ±0)`
-		// Minimum execution time: 58_290_000 picoseconds.
-		Weight::from_parts(54_924_919, 6676)
-			// Standard Error: 157
-			.saturating_add(Weight::from_parts(464, 0).saturating_mul(b.into()))
-			// Standard Error: 1_665
-			.saturating_add(Weight::from_parts(73_183, 0).saturating_mul(m.into()))
-			// Standard Error: 1_623
-			.saturating_add(Weight::from_parts(168_318, 0).saturating_mul(p.into()))
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
-			.saturating_add(Weight::from_parts(0, 97).saturating_mul(m.into()))
-			.saturating_add(Weight::from_parts(0, 40).saturating_mul(p.into()))
-	}
-	/// Storage: Alliance Members (r:1 w:0)
-	/// Proof: Alliance Members (max_values: None, max_size: Some(3211), added: 5686, mode: MaxEncodedLen)
-	/// Storage: AllianceMotion Voting (r:1 w:1)
-	/// Proof Skipped: AllianceMotion Voting (max_values: None, max_size: None, mode: Measured)
-	/// Storage: AllianceMotion Members (r:1 w:0)
-	/// Proof Skipped: AllianceMotion Members (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: AllianceMotion Prime (r:1 w:0)
-	/// Proof Skipped: AllianceMotion Prime (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: AllianceMotion Proposals (r:1 w:1)
-	/// Proof Skipped: AllianceMotion Proposals (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: AllianceMotion ProposalOf (r:0 w:1)
-	/// Proof Skipped: AllianceMotion ProposalOf (max_values: None, max_size: None, mode: Measured)
-	/// The range of component `m` is `[2, 100]`.
-	/// The range of component `p` is `[1, 100]`.
-	fn close_disapproved(m: u32, p: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `577 + m * (96 ±0) + p * (36 ±0)`
-		//  Estimated: `6676 + m * (97 ±0) + p * (36 ±0)`
-		// Minimum execution time: 46_794_000 picoseconds.
-		Weight::from_parts(43_092_958, 6676)
-			// Standard Error: 1_273
-			.saturating_add(Weight::from_parts(71_054, 0).saturating_mul(m.into()))
-			// Standard Error: 1_257
-			.saturating_add(Weight::from_parts(152_820, 0).saturating_mul(p.into()))
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
-			.saturating_add(Weight::from_parts(0, 97).saturating_mul(m.into()))
-			.saturating_add(Weight::from_parts(0, 36).saturating_mul(p.into()))
-	}
-	/// Storage: Alliance Members (r:1 w:0)
-	/// Proof: Alliance Members (max_values: None, max_size: Some(3211), added: 5686, mode: MaxEncodedLen)
-	/// Storage: AllianceMotion Voting (r:1 w:1)
-	/// Proof Skipped: AllianceMotion Voting (max_values: None, max_size: None, mode: Measured)
-	/// Storage: AllianceMotion Members (r:1 w:0)
-	/// Proof Skipped: AllianceMotion Members (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: AllianceMotion Prime (r:1 w:0)
-	/// Proof Skipped: AllianceMotion Prime (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: AllianceMotion Proposals (r:1 w:1)
-	/// Proof Skipped: AllianceMotion Proposals (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: AllianceMotion ProposalOf (r:0 w:1)
-	/// Proof Skipped: AllianceMotion ProposalOf (max_values: None, max_size: None, mode: Measured)
-	/// The range of component `b` is `[1, 1024]`.
-	/// The range of component `m` is `[5, 100]`.
-	/// The range of component `p` is `[1, 100]`.
-	fn close_approved(b: u32, m: u32, p: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `684 + m * (96 ±0) + p * (35 ±0)`
-		//  Estimated: `6676 + m * (97 ±0) + p * (36 ±0)`
-		// Minimum execution time: 47_338_000 picoseconds.
-		Weight::from_parts(41_257_479, 6676)
-			// Standard Error: 119
-			.saturating_add(Weight::from_parts(1_019, 0).saturating_mul(b.into()))
-			// Standard Error: 1_277
-			.saturating_add(Weight::from_parts(78_453, 0).saturating_mul(m.into()))
-			// Standard Error: 1_231
-			.saturating_add(Weight::from_parts(150_991, 0).saturating_mul(p.into()))
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
-			.saturating_add(Weight::from_parts(0, 97).saturating_mul(m.into()))
-			.saturating_add(Weight::from_parts(0, 36).saturating_mul(p.into()))
-	}
 	/// Storage: Alliance Members (r:2 w:2)
 	/// Proof: Alliance Members (max_values: None, max_size: Some(3211), added: 5686, mode: MaxEncodedLen)
 	/// Storage: AllianceMotion Members (r:1 w:1)
@@ -273,6 +115,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof Skipped: AllianceMotion Members (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: AllianceMotion Prime (r:0 w:1)
 	/// Proof Skipped: AllianceMotion Prime (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Alliance NominationsUsed (r:0 w:100)
+	/// Proof: Alliance NominationsUsed (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
 	/// The range of component `x` is `[1, 100]`.
 	/// The range of component `y` is `[0, 100]`.
 	/// The range of component `z` is `[0, 50]`.
@@ -293,6 +137,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(y.into())))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(z.into())))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
 			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(z.into())))
 			.saturating_add(Weight::from_parts(0, 2539).saturating_mul(x.into()))
 			.saturating_add(Weight::from_parts(0, 2539).saturating_mul(y.into()))
@@ -351,14 +196,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Alliance Members (max_values: None, max_size: Some(3211), added: 5686, mode: MaxEncodedLen)
 	/// Storage: Alliance UnscrupulousAccounts (r:1 w:0)
 	/// Proof: Alliance UnscrupulousAccounts (max_values: Some(1), max_size: Some(3202), added: 3697, mode: MaxEncodedLen)
+	/// Storage: Alliance NominationsUsed (r:1 w:1)
+	/// Proof: Alliance NominationsUsed (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
 	fn nominate_ally() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `367`
 		//  Estimated: `18048`
 		// Minimum execution time: 26_114_000 picoseconds.
 		Weight::from_parts(27_069_000, 18048)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: Alliance Members (r:2 w:2)
 	/// Proof: Alliance Members (max_values: None, max_size: Some(3211), added: 5686, mode: MaxEncodedLen)
@@ -402,16 +249,22 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Alliance Members (max_values: None, max_size: Some(3211), added: 5686, mode: MaxEncodedLen)
 	/// Storage: Alliance DepositOf (r:1 w:1)
 	/// Proof: Alliance DepositOf (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Alliance NominationsUsed (r:0 w:1)
+	/// Proof: Alliance NominationsUsed (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Alliance MemberSince (r:1 w:1)
+	/// Proof: Alliance MemberSince (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: Alliance SuspendedMembers (r:0 w:1)
+	/// Proof: Alliance SuspendedMembers (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
 	fn retire() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `687`
 		//  Estimated: `6676`
 		// Minimum execution time: 41_239_000 picoseconds.
 		Weight::from_parts(42_764_000, 6676)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
 	/// Storage: Alliance Members (r:3 w:1)
 	/// Proof: Alliance Members (max_values: None, max_size: Some(3211), added: 5686, mode: MaxEncodedLen)
@@ -425,6 +278,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof Skipped: AllianceMotion Members (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: AllianceMotion Prime (r:0 w:1)
 	/// Proof Skipped: AllianceMotion Prime (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Alliance NominationsUsed (r:0 w:1)
+	/// Proof: Alliance NominationsUsed (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
 	fn kick_member() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `707`
@@ -432,12 +287,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 68_071_000 picoseconds.
 		Weight::from_parts(71_808_000, 18048)
 			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: Alliance UnscrupulousAccounts (r:1 w:1)
 	/// Proof: Alliance UnscrupulousAccounts (max_values: Some(1), max_size: Some(3202), added: 3697, mode: MaxEncodedLen)
 	/// Storage: Alliance UnscrupulousWebsites (r:1 w:1)
 	/// Proof: Alliance UnscrupulousWebsites (max_values: Some(1), max_size: Some(25702), added: 26197, mode: MaxEncodedLen)
+	/// Storage: Alliance UnscrupulousEvidence (r:0 w:100)
+	/// Proof: Alliance UnscrupulousEvidence (max_values: None, max_size: Some(360), added: 2835, mode: MaxEncodedLen)
 	/// The range of component `n` is `[0, 100]`.
 	/// The range of component `l` is `[0, 255]`.
 	fn add_unscrupulous_items(n: u32, l: u32, ) -> Weight {
@@ -452,11 +309,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(72_943, 0).saturating_mul(l.into()))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
 	}
 	/// Storage: Alliance UnscrupulousAccounts (r:1 w:1)
 	/// Proof: Alliance UnscrupulousAccounts (max_values: Some(1), max_size: Some(3202), added: 3697, mode: MaxEncodedLen)
 	/// Storage: Alliance UnscrupulousWebsites (r:1 w:1)
 	/// Proof: Alliance UnscrupulousWebsites (max_values: Some(1), max_size: Some(25702), added: 26197, mode: MaxEncodedLen)
+	/// Storage: Alliance UnscrupulousEvidence (r:0 w:100)
+	/// Proof: Alliance UnscrupulousEvidence (max_values: None, max_size: Some(360), added: 2835, mode: MaxEncodedLen)
+	/// Storage: Alliance UnscrupulousPenalizedAt (r:0 w:100)
+	/// Proof: Alliance UnscrupulousPenalizedAt (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
 	/// The range of component `n` is `[0, 100]`.
 	/// The range of component `l` is `[0, 255]`.
 	fn remove_unscrupulous_items(n: u32, l: u32, ) -> Weight {
@@ -471,6 +333,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(310_978, 0).saturating_mul(l.into()))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
 	}
 	/// Storage: Alliance Members (r:3 w:2)
 	/// Proof: Alliance Members (max_values: None, max_size: Some(3211), added: 5686, mode: MaxEncodedLen)
@@ -489,175 +352,262 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
-}
-
-// For backwards compatibility and tests
-impl WeightInfo for () {
-	/// Storage: Alliance Members (r:1 w:0)
+	/// Storage: Alliance Members (r:3 w:0)
 	/// Proof: Alliance Members (max_values: None, max_size: Some(3211), added: 5686, mode: MaxEncodedLen)
-	/// Storage: AllianceMotion ProposalOf (r:1 w:1)
-	/// Proof Skipped: AllianceMotion ProposalOf (max_values: None, max_size: None, mode: Measured)
-	/// Storage: AllianceMotion Proposals (r:1 w:1)
-	/// Proof Skipped: AllianceMotion Proposals (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: AllianceMotion ProposalCount (r:1 w:1)
-	/// Proof Skipped: AllianceMotion ProposalCount (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: AllianceMotion Voting (r:0 w:1)
-	/// Proof Skipped: AllianceMotion Voting (max_values: None, max_size: None, mode: Measured)
-	/// The range of component `b` is `[1, 1024]`.
-	/// The range of component `m` is `[2, 100]`.
-	/// The range of component `p` is `[1, 100]`.
-	fn propose_proposed(b: u32, m: u32, p: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `653 + m * (32 ±0) + p * (35 ±0)`
-		//  Estimated: `6676 + m * (32 ±0) + p * (36 ±0)`
-		// Minimum execution time: 36_908_000 picoseconds.
-		Weight::from_parts(39_040_304, 6676)
-			// Standard Error: 131
-			.saturating_add(Weight::from_parts(781, 0).saturating_mul(b.into()))
-			// Standard Error: 1_375
-			.saturating_add(Weight::from_parts(48_745, 0).saturating_mul(m.into()))
-			// Standard Error: 1_358
-			.saturating_add(Weight::from_parts(148_047, 0).saturating_mul(p.into()))
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
-			.saturating_add(Weight::from_parts(0, 32).saturating_mul(m.into()))
-			.saturating_add(Weight::from_parts(0, 36).saturating_mul(p.into()))
+	/// Storage: Alliance SuspendedMembers (r:0 w:1)
+	/// Proof: Alliance SuspendedMembers (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	fn suspend_member() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `443`
+		//  Estimated: `18048`
+		// Minimum execution time: 19_204_000 picoseconds.
+		Weight::from_parts(20_113_000, 18048)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Alliance SuspendedMembers (r:1 w:1)
+	/// Proof: Alliance SuspendedMembers (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	fn unsuspend_member() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `246`
+		//  Estimated: `3525`
+		// Minimum execution time: 13_387_000 picoseconds.
+		Weight::from_parts(14_020_000, 3525)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: Alliance CounterForSubCommittees (r:1 w:1)
+	/// Proof: Alliance CounterForSubCommittees (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
 	/// Storage: Alliance Members (r:1 w:0)
 	/// Proof: Alliance Members (max_values: None, max_size: Some(3211), added: 5686, mode: MaxEncodedLen)
-	/// Storage: AllianceMotion Voting (r:1 w:1)
-	/// Proof Skipped: AllianceMotion Voting (max_values: None, max_size: None, mode: Measured)
-	/// The range of component `m` is `[5, 100]`.
-	fn vote(m: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1042 + m * (64 ±0)`
-		//  Estimated: `6676 + m * (64 ±0)`
-		// Minimum execution time: 30_166_000 picoseconds.
-		Weight::from_parts(32_798_454, 6676)
-			// Standard Error: 1_432
-			.saturating_add(Weight::from_parts(83_001, 0).saturating_mul(m.into()))
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
-			.saturating_add(Weight::from_parts(0, 64).saturating_mul(m.into()))
+	/// Storage: Alliance NextSubCommitteeIndex (r:1 w:1)
+	/// Proof: Alliance NextSubCommitteeIndex (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Alliance SubCommittees (r:0 w:1)
+	/// Proof: Alliance SubCommittees (max_values: None, max_size: Some(338), added: 2813, mode: MaxEncodedLen)
+	/// The range of component `m` is `[1, 10]`.
+	fn create_sub_committee(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `443`
+		//  Estimated: `6676`
+		// Minimum execution time: 20_917_000 picoseconds.
+		Weight::from_parts(21_548_126, 6676)
+			// Standard Error: 2_211
+			.saturating_add(Weight::from_parts(118_406, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
-	/// Storage: Alliance Members (r:1 w:0)
+	/// Storage: Alliance SubCommittees (r:1 w:1)
+	/// Proof: Alliance SubCommittees (max_values: None, max_size: Some(338), added: 2813, mode: MaxEncodedLen)
+	/// Storage: Alliance CounterForSubCommittees (r:1 w:1)
+	/// Proof: Alliance CounterForSubCommittees (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn revoke_sub_committee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `529`
+		//  Estimated: `3803`
+		// Minimum execution time: 15_302_000 picoseconds.
+		Weight::from_parts(15_874_000, 3803)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Alliance Members (r:3 w:0)
 	/// Proof: Alliance Members (max_values: None, max_size: Some(3211), added: 5686, mode: MaxEncodedLen)
-	/// Storage: AllianceMotion Voting (r:1 w:1)
-	/// Proof Skipped: AllianceMotion Voting (max_values: None, max_size: None, mode: Measured)
-	/// Storage: AllianceMotion Members (r:1 w:0)
-	/// Proof Skipped: AllianceMotion Members (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: AllianceMotion Proposals (r:1 w:1)
-	/// Proof Skipped: AllianceMotion Proposals (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: AllianceMotion ProposalOf (r:0 w:1)
-	/// Proof Skipped: AllianceMotion ProposalOf (max_values: None, max_size: None, mode: Measured)
-	/// The range of component `m` is `[4, 100]`.
-	/// The range of component `p` is `[1, 100]`.
-	fn close_early_disapproved(m: u32, p: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `576 + m * (96 ±0) + p * (36 ±0)`
-		//  Estimated: `6676 + m * (97 ±0) + p * (36 ±0)`
-		// Minimum execution time: 45_173_000 picoseconds.
-		Weight::from_parts(42_192_020, 6676)
-			// Standard Error: 1_456
-			.saturating_add(Weight::from_parts(66_751, 0).saturating_mul(m.into()))
-			// Standard Error: 1_420
-			.saturating_add(Weight::from_parts(158_161, 0).saturating_mul(p.into()))
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
-			.saturating_add(Weight::from_parts(0, 97).saturating_mul(m.into()))
-			.saturating_add(Weight::from_parts(0, 36).saturating_mul(p.into()))
+	/// Storage: Alliance SuspendedMembers (r:1 w:0)
+	/// Proof: Alliance SuspendedMembers (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Alliance Rule (r:1 w:0)
+	/// Proof: Alliance Rule (max_values: Some(1), max_size: Some(87), added: 582, mode: MaxEncodedLen)
+	/// Storage: Alliance Announcements (r:1 w:0)
+	/// Proof: Alliance Announcements (max_values: Some(1), max_size: Some(8702), added: 9197, mode: MaxEncodedLen)
+	/// Storage: Alliance FlaggedCids (r:1 w:1)
+	/// Proof: Alliance FlaggedCids (max_values: None, max_size: Some(107), added: 2582, mode: MaxEncodedLen)
+	fn flag_unreachable_cid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `573`
+		//  Estimated: `22355`
+		// Minimum execution time: 15_118_000 picoseconds.
+		Weight::from_parts(15_672_000, 22355)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-	/// Storage: Alliance Members (r:1 w:0)
+	/// Storage: Alliance FlaggedCids (r:1 w:1)
+	/// Proof: Alliance FlaggedCids (max_values: None, max_size: Some(107), added: 2582, mode: MaxEncodedLen)
+	fn unflag_cid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `151`
+		//  Estimated: `3572`
+		// Minimum execution time: 10_904_000 picoseconds.
+		Weight::from_parts(11_287_000, 3572)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Alliance ImportedSnapshot (r:1 w:1)
+	/// Proof: Alliance ImportedSnapshot (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: Alliance Members (r:3 w:3)
 	/// Proof: Alliance Members (max_values: None, max_size: Some(3211), added: 5686, mode: MaxEncodedLen)
-	/// Storage: AllianceMotion Voting (r:1 w:1)
-	/// Proof Skipped: AllianceMotion Voting (max_values: None, max_size: None, mode: Measured)
-	/// Storage: AllianceMotion Members (r:1 w:0)
+	/// Storage: Balances Holds (r:100 w:100)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Alliance RetiringMembers (r:0 w:100)
+	/// Proof: Alliance RetiringMembers (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Alliance UnscrupulousAccounts (r:0 w:1)
+	/// Proof: Alliance UnscrupulousAccounts (max_values: Some(1), max_size: Some(3202), added: 3697, mode: MaxEncodedLen)
+	/// Storage: Alliance UnscrupulousWebsites (r:0 w:1)
+	/// Proof: Alliance UnscrupulousWebsites (max_values: Some(1), max_size: Some(25702), added: 26197, mode: MaxEncodedLen)
+	/// Storage: AllianceMotion Members (r:0 w:1)
 	/// Proof Skipped: AllianceMotion Members (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: AllianceMotion ProposalOf (r:1 w:1)
-	/// Proof Skipped: AllianceMotion ProposalOf (max_values: None, max_size: None, mode: Measured)
-	/// Storage: AllianceMotion Proposals (r:1 w:1)
-	/// Proof Skipped: AllianceMotion Proposals (max_values: Some(1), max_size: None, mode: Measured)
-	/// The range of component `b` is `[1, 1024]`.
-	/// The range of component `m` is `[4, 100]`.
+	/// The range of component `m` is `[1, 100]`.
+	/// The range of component `u` is `[0, 100]`.
+	fn force_import_snapshot(m: u32, u: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `217`
+		//  Estimated: `18048 + m * (2560 ±0)`
+		// Minimum execution time: 52_309_000 picoseconds.
+		Weight::from_parts(38_734_120, 18048)
+			// Standard Error: 1_912
+			.saturating_add(Weight::from_parts(21_846_307, 0).saturating_mul(m.into()))
+			// Standard Error: 1_884
+			.saturating_add(Weight::from_parts(141_523, 0).saturating_mul(u.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 2560).saturating_mul(m.into()))
+	}
+	/// Storage: Alliance NextEnactmentIndex (r:1 w:1)
+	/// Proof: Alliance NextEnactmentIndex (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Alliance EnactmentBlocks (r:1 w:1)
+	/// Proof: Alliance EnactmentBlocks (max_values: Some(1), max_size: Some(801), added: 1296, mode: MaxEncodedLen)
+	/// Storage: Alliance PendingEnactments (r:0 w:1)
+	/// Proof Skipped: Alliance PendingEnactments (max_values: None, max_size: None, mode: Measured)
+	fn schedule_enactment() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `876`
+		//  Estimated: `2361`
+		// Minimum execution time: 14_174_000 picoseconds.
+		Weight::from_parts(14_730_000, 2361)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Alliance PendingEnactments (r:1 w:1)
+	/// Proof Skipped: Alliance PendingEnactments (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Alliance EnactmentBlocks (r:1 w:1)
+	/// Proof: Alliance EnactmentBlocks (max_values: Some(1), max_size: Some(801), added: 1296, mode: MaxEncodedLen)
 	/// The range of component `p` is `[1, 100]`.
-	fn close_early_approved(b: u32, m: u32, p: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1087 + m * (96 ±0) + p * (39 ±0)`
-		//  Estimated: `6676 + m * (97 ±0) + p * (40 ±0)`
-		// Minimum execution time: 58_290_000 picoseconds.
-		Weight::from_parts(54_924_919, 6676)
-			// Standard Error: 157
-			.saturating_add(Weight::from_parts(464, 0).saturating_mul(b.into()))
-			// Standard Error: 1_665
-			.saturating_add(Weight::from_parts(73_183, 0).saturating_mul(m.into()))
-			// Standard Error: 1_623
-			.saturating_add(Weight::from_parts(168_318, 0).saturating_mul(p.into()))
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
-			.saturating_add(Weight::from_parts(0, 97).saturating_mul(m.into()))
-			.saturating_add(Weight::from_parts(0, 40).saturating_mul(p.into()))
+	fn cancel_enactment(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `651 + p * (29 ±0)`
+		//  Estimated: `2286 + p * (29 ±0)`
+		// Minimum execution time: 12_951_000 picoseconds.
+		Weight::from_parts(13_377_102, 2286)
+			// Standard Error: 1_093
+			.saturating_add(Weight::from_parts(45_268, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 29).saturating_mul(p.into()))
 	}
-	/// Storage: Alliance Members (r:1 w:0)
+	/// Storage: Alliance Members (r:3 w:1)
 	/// Proof: Alliance Members (max_values: None, max_size: Some(3211), added: 5686, mode: MaxEncodedLen)
-	/// Storage: AllianceMotion Voting (r:1 w:1)
-	/// Proof Skipped: AllianceMotion Voting (max_values: None, max_size: None, mode: Measured)
-	/// Storage: AllianceMotion Members (r:1 w:0)
+	/// Storage: Alliance UnscrupulousAccounts (r:1 w:0)
+	/// Proof: Alliance UnscrupulousAccounts (max_values: Some(1), max_size: Some(3202), added: 3697, mode: MaxEncodedLen)
+	/// Storage: AllianceMotion Proposals (r:1 w:0)
+	/// Proof Skipped: AllianceMotion Proposals (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Alliance DepositOf (r:1 w:1)
+	/// Proof: Alliance DepositOf (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Alliance RetiringMembers (r:0 w:1)
+	/// Proof: Alliance RetiringMembers (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Alliance SuspendedMembers (r:0 w:1)
+	/// Proof: Alliance SuspendedMembers (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Alliance MemberSince (r:0 w:1)
+	/// Proof: Alliance MemberSince (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: AllianceMotion Members (r:0 w:1)
 	/// Proof Skipped: AllianceMotion Members (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: AllianceMotion Prime (r:1 w:0)
+	/// Storage: AllianceMotion Prime (r:0 w:1)
 	/// Proof Skipped: AllianceMotion Prime (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: AllianceMotion Proposals (r:1 w:1)
-	/// Proof Skipped: AllianceMotion Proposals (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: AllianceMotion ProposalOf (r:0 w:1)
-	/// Proof Skipped: AllianceMotion ProposalOf (max_values: None, max_size: None, mode: Measured)
-	/// The range of component `m` is `[2, 100]`.
-	/// The range of component `p` is `[1, 100]`.
-	fn close_disapproved(m: u32, p: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `577 + m * (96 ±0) + p * (36 ±0)`
-		//  Estimated: `6676 + m * (97 ±0) + p * (36 ±0)`
-		// Minimum execution time: 46_794_000 picoseconds.
-		Weight::from_parts(43_092_958, 6676)
-			// Standard Error: 1_273
-			.saturating_add(Weight::from_parts(71_054, 0).saturating_mul(m.into()))
-			// Standard Error: 1_257
-			.saturating_add(Weight::from_parts(152_820, 0).saturating_mul(p.into()))
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
-			.saturating_add(Weight::from_parts(0, 97).saturating_mul(m.into()))
-			.saturating_add(Weight::from_parts(0, 36).saturating_mul(p.into()))
+	/// Storage: Alliance NominationsUsed (r:0 w:1)
+	/// Proof: Alliance NominationsUsed (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Alliance UnscrupulousPenalizedAt (r:1 w:1)
+	/// Proof: Alliance UnscrupulousPenalizedAt (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn enforce_unscrupulous() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `806`
+		//  Estimated: `18048`
+		// Minimum execution time: 79_431_000 picoseconds.
+		Weight::from_parts(82_067_000, 18048)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
 	}
-	/// Storage: Alliance Members (r:1 w:0)
+	/// Storage: Alliance DisbandProgress (r:1 w:1)
+	/// Proof: Alliance DisbandProgress (max_values: Some(1), max_size: Some(12), added: 507, mode: MaxEncodedLen)
+	/// Storage: Alliance Members (r:3 w:2)
 	/// Proof: Alliance Members (max_values: None, max_size: Some(3211), added: 5686, mode: MaxEncodedLen)
-	/// Storage: AllianceMotion Voting (r:1 w:1)
+	/// Storage: Alliance DepositOf (r:200 w:50)
+	/// Proof: Alliance DepositOf (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: System Account (r:50 w:50)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Alliance MemberSince (r:0 w:200)
+	/// Proof: Alliance MemberSince (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: Alliance NominationsUsed (r:0 w:100)
+	/// Proof: Alliance NominationsUsed (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 100]`.
+	/// The range of component `y` is `[0, 100]`.
+	/// The range of component `z` is `[0, 50]`.
+	fn continue_disband(x: u32, y: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + x * (50 ±0) + y * (51 ±0) + z * (251 ±0)`
+		//  Estimated: `6816 + x * (2539 ±0) + y * (2539 ±0) + z * (2603 ±1)`
+		// Minimum execution time: 23_916_000 picoseconds.
+		Weight::from_parts(24_563_000, 6816)
+			// Standard Error: 25_871
+			.saturating_add(Weight::from_parts(1_843_215, 0).saturating_mul(x.into()))
+			// Standard Error: 25_746
+			.saturating_add(Weight::from_parts(1_902_407, 0).saturating_mul(y.into()))
+			// Standard Error: 51_443
+			.saturating_add(Weight::from_parts(16_107_532, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(y.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(z.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(y.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(z.into())))
+			.saturating_add(Weight::from_parts(0, 2539).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(0, 2539).saturating_mul(y.into()))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(z.into()))
+	}
+	/// Storage: AllianceMotion Voting (r:1 w:0)
 	/// Proof Skipped: AllianceMotion Voting (max_values: None, max_size: None, mode: Measured)
 	/// Storage: AllianceMotion Members (r:1 w:0)
 	/// Proof Skipped: AllianceMotion Members (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: AllianceMotion Prime (r:1 w:0)
-	/// Proof Skipped: AllianceMotion Prime (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: AllianceMotion Proposals (r:1 w:1)
-	/// Proof Skipped: AllianceMotion Proposals (max_values: Some(1), max_size: None, mode: Measured)
-	/// Storage: AllianceMotion ProposalOf (r:0 w:1)
-	/// Proof Skipped: AllianceMotion ProposalOf (max_values: None, max_size: None, mode: Measured)
-	/// The range of component `b` is `[1, 1024]`.
-	/// The range of component `m` is `[5, 100]`.
-	/// The range of component `p` is `[1, 100]`.
-	fn close_approved(b: u32, m: u32, p: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `684 + m * (96 ±0) + p * (35 ±0)`
-		//  Estimated: `6676 + m * (97 ±0) + p * (36 ±0)`
-		// Minimum execution time: 47_338_000 picoseconds.
-		Weight::from_parts(41_257_479, 6676)
-			// Standard Error: 119
-			.saturating_add(Weight::from_parts(1_019, 0).saturating_mul(b.into()))
-			// Standard Error: 1_277
-			.saturating_add(Weight::from_parts(78_453, 0).saturating_mul(m.into()))
-			// Standard Error: 1_231
-			.saturating_add(Weight::from_parts(150_991, 0).saturating_mul(p.into()))
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
-			.saturating_add(Weight::from_parts(0, 97).saturating_mul(m.into()))
-			.saturating_add(Weight::from_parts(0, 36).saturating_mul(p.into()))
+	/// Storage: Alliance DecidedProposals (r:1 w:1)
+	/// Proof: Alliance DecidedProposals (max_values: Some(1), max_size: Some(3602), added: 4097, mode: MaxEncodedLen)
+	fn queue_decided_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3899`
+		//  Estimated: `7364`
+		// Minimum execution time: 21_436_000 picoseconds.
+		Weight::from_parts(22_318_000, 7364)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: Alliance SubCommittees (r:1 w:0)
+	/// Proof: Alliance SubCommittees (max_values: None, max_size: Some(338), added: 2813, mode: MaxEncodedLen)
+	/// Storage: Alliance Members (r:3 w:0)
+	/// Proof: Alliance Members (max_values: None, max_size: Some(3211), added: 5686, mode: MaxEncodedLen)
+	/// Storage: Alliance SuspendedMembers (r:1 w:0)
+	/// Proof: Alliance SuspendedMembers (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	fn ensure_sub_committee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1021`
+		//  Estimated: `18048`
+		// Minimum execution time: 13_284_000 picoseconds.
+		Weight::from_parts(13_902_000, 18048)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
 	/// Storage: Alliance Members (r:2 w:2)
 	/// Proof: Alliance Members (max_values: None, max_size: Some(3211), added: 5686, mode: MaxEncodedLen)
 	/// Storage: AllianceMotion Members (r:1 w:1)
@@ -689,6 +639,8 @@ impl WeightInfo for () {
 	/// Proof Skipped: AllianceMotion Members (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: AllianceMotion Prime (r:0 w:1)
 	/// Proof Skipped: AllianceMotion Prime (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Alliance NominationsUsed (r:0 w:100)
+	/// Proof: Alliance NominationsUsed (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
 	/// The range of component `x` is `[1, 100]`.
 	/// The range of component `y` is `[0, 100]`.
 	/// The range of component `z` is `[0, 50]`.
@@ -709,6 +661,7 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(y.into())))
 			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(z.into())))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(x.into())))
 			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(z.into())))
 			.saturating_add(Weight::from_parts(0, 2539).saturating_mul(x.into()))
 			.saturating_add(Weight::from_parts(0, 2539).saturating_mul(y.into()))
@@ -767,14 +720,16 @@ impl WeightInfo for () {
 	/// Proof: Alliance Members (max_values: None, max_size: Some(3211), added: 5686, mode: MaxEncodedLen)
 	/// Storage: Alliance UnscrupulousAccounts (r:1 w:0)
 	/// Proof: Alliance UnscrupulousAccounts (max_values: Some(1), max_size: Some(3202), added: 3697, mode: MaxEncodedLen)
+	/// Storage: Alliance NominationsUsed (r:1 w:1)
+	/// Proof: Alliance NominationsUsed (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
 	fn nominate_ally() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `367`
 		//  Estimated: `18048`
 		// Minimum execution time: 26_114_000 picoseconds.
 		Weight::from_parts(27_069_000, 18048)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: Alliance Members (r:2 w:2)
 	/// Proof: Alliance Members (max_values: None, max_size: Some(3211), added: 5686, mode: MaxEncodedLen)
@@ -818,16 +773,22 @@ impl WeightInfo for () {
 	/// Proof: Alliance Members (max_values: None, max_size: Some(3211), added: 5686, mode: MaxEncodedLen)
 	/// Storage: Alliance DepositOf (r:1 w:1)
 	/// Proof: Alliance DepositOf (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Alliance NominationsUsed (r:0 w:1)
+	/// Proof: Alliance NominationsUsed (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Alliance MemberSince (r:1 w:1)
+	/// Proof: Alliance MemberSince (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: Alliance SuspendedMembers (r:0 w:1)
+	/// Proof: Alliance SuspendedMembers (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
 	fn retire() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `687`
 		//  Estimated: `6676`
 		// Minimum execution time: 41_239_000 picoseconds.
 		Weight::from_parts(42_764_000, 6676)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
 	/// Storage: Alliance Members (r:3 w:1)
 	/// Proof: Alliance Members (max_values: None, max_size: Some(3211), added: 5686, mode: MaxEncodedLen)
@@ -841,6 +802,8 @@ impl WeightInfo for () {
 	/// Proof Skipped: AllianceMotion Members (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: AllianceMotion Prime (r:0 w:1)
 	/// Proof Skipped: AllianceMotion Prime (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Alliance NominationsUsed (r:0 w:1)
+	/// Proof: Alliance NominationsUsed (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
 	fn kick_member() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `707`
@@ -848,12 +811,14 @@ impl WeightInfo for () {
 		// Minimum execution time: 68_071_000 picoseconds.
 		Weight::from_parts(71_808_000, 18048)
 			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: Alliance UnscrupulousAccounts (r:1 w:1)
 	/// Proof: Alliance UnscrupulousAccounts (max_values: Some(1), max_size: Some(3202), added: 3697, mode: MaxEncodedLen)
 	/// Storage: Alliance UnscrupulousWebsites (r:1 w:1)
 	/// Proof: Alliance UnscrupulousWebsites (max_values: Some(1), max_size: Some(25702), added: 26197, mode: MaxEncodedLen)
+	/// Storage: Alliance UnscrupulousEvidence (r:0 w:100)
+	/// Proof: Alliance UnscrupulousEvidence (max_values: None, max_size: Some(360), added: 2835, mode: MaxEncodedLen)
 	/// The range of component `n` is `[0, 100]`.
 	/// The range of component `l` is `[0, 255]`.
 	fn add_unscrupulous_items(n: u32, l: u32, ) -> Weight {
@@ -868,11 +833,16 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(72_943, 0).saturating_mul(l.into()))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
 	}
 	/// Storage: Alliance UnscrupulousAccounts (r:1 w:1)
 	/// Proof: Alliance UnscrupulousAccounts (max_values: Some(1), max_size: Some(3202), added: 3697, mode: MaxEncodedLen)
 	/// Storage: Alliance UnscrupulousWebsites (r:1 w:1)
 	/// Proof: Alliance UnscrupulousWebsites (max_values: Some(1), max_size: Some(25702), added: 26197, mode: MaxEncodedLen)
+	/// Storage: Alliance UnscrupulousEvidence (r:0 w:100)
+	/// Proof: Alliance UnscrupulousEvidence (max_values: None, max_size: Some(360), added: 2835, mode: MaxEncodedLen)
+	/// Storage: Alliance UnscrupulousPenalizedAt (r:0 w:100)
+	/// Proof: Alliance UnscrupulousPenalizedAt (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
 	/// The range of component `n` is `[0, 100]`.
 	/// The range of component `l` is `[0, 255]`.
 	fn remove_unscrupulous_items(n: u32, l: u32, ) -> Weight {
@@ -887,6 +857,7 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(310_978, 0).saturating_mul(l.into()))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
 	}
 	/// Storage: Alliance Members (r:3 w:2)
 	/// Proof: Alliance Members (max_values: None, max_size: Some(3211), added: 5686, mode: MaxEncodedLen)
@@ -905,4 +876,256 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
+	/// Storage: Alliance Members (r:3 w:0)
+	/// Proof: Alliance Members (max_values: None, max_size: Some(3211), added: 5686, mode: MaxEncodedLen)
+	/// Storage: Alliance SuspendedMembers (r:0 w:1)
+	/// Proof: Alliance SuspendedMembers (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	fn suspend_member() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `443`
+		//  Estimated: `18048`
+		// Minimum execution time: 19_204_000 picoseconds.
+		Weight::from_parts(20_113_000, 18048)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Alliance SuspendedMembers (r:1 w:1)
+	/// Proof: Alliance SuspendedMembers (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	fn unsuspend_member() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `246`
+		//  Estimated: `3525`
+		// Minimum execution time: 13_387_000 picoseconds.
+		Weight::from_parts(14_020_000, 3525)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Alliance CounterForSubCommittees (r:1 w:1)
+	/// Proof: Alliance CounterForSubCommittees (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Alliance Members (r:1 w:0)
+	/// Proof: Alliance Members (max_values: None, max_size: Some(3211), added: 5686, mode: MaxEncodedLen)
+	/// Storage: Alliance NextSubCommitteeIndex (r:1 w:1)
+	/// Proof: Alliance NextSubCommitteeIndex (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Alliance SubCommittees (r:0 w:1)
+	/// Proof: Alliance SubCommittees (max_values: None, max_size: Some(338), added: 2813, mode: MaxEncodedLen)
+	/// The range of component `m` is `[1, 10]`.
+	fn create_sub_committee(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `443`
+		//  Estimated: `6676`
+		// Minimum execution time: 20_917_000 picoseconds.
+		Weight::from_parts(21_548_126, 6676)
+			// Standard Error: 2_211
+			.saturating_add(Weight::from_parts(118_406, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Alliance SubCommittees (r:1 w:1)
+	/// Proof: Alliance SubCommittees (max_values: None, max_size: Some(338), added: 2813, mode: MaxEncodedLen)
+	/// Storage: Alliance CounterForSubCommittees (r:1 w:1)
+	/// Proof: Alliance CounterForSubCommittees (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn revoke_sub_committee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `529`
+		//  Estimated: `3803`
+		// Minimum execution time: 15_302_000 picoseconds.
+		Weight::from_parts(15_874_000, 3803)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Alliance Members (r:3 w:0)
+	/// Proof: Alliance Members (max_values: None, max_size: Some(3211), added: 5686, mode: MaxEncodedLen)
+	/// Storage: Alliance SuspendedMembers (r:1 w:0)
+	/// Proof: Alliance SuspendedMembers (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Alliance Rule (r:1 w:0)
+	/// Proof: Alliance Rule (max_values: Some(1), max_size: Some(87), added: 582, mode: MaxEncodedLen)
+	/// Storage: Alliance Announcements (r:1 w:0)
+	/// Proof: Alliance Announcements (max_values: Some(1), max_size: Some(8702), added: 9197, mode: MaxEncodedLen)
+	/// Storage: Alliance FlaggedCids (r:1 w:1)
+	/// Proof: Alliance FlaggedCids (max_values: None, max_size: Some(107), added: 2582, mode: MaxEncodedLen)
+	fn flag_unreachable_cid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `573`
+		//  Estimated: `22355`
+		// Minimum execution time: 15_118_000 picoseconds.
+		Weight::from_parts(15_672_000, 22355)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Alliance FlaggedCids (r:1 w:1)
+	/// Proof: Alliance FlaggedCids (max_values: None, max_size: Some(107), added: 2582, mode: MaxEncodedLen)
+	fn unflag_cid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `151`
+		//  Estimated: `3572`
+		// Minimum execution time: 10_904_000 picoseconds.
+		Weight::from_parts(11_287_000, 3572)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Alliance ImportedSnapshot (r:1 w:1)
+	/// Proof: Alliance ImportedSnapshot (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: Alliance Members (r:3 w:3)
+	/// Proof: Alliance Members (max_values: None, max_size: Some(3211), added: 5686, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:100 w:100)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Alliance RetiringMembers (r:0 w:100)
+	/// Proof: Alliance RetiringMembers (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Alliance UnscrupulousAccounts (r:0 w:1)
+	/// Proof: Alliance UnscrupulousAccounts (max_values: Some(1), max_size: Some(3202), added: 3697, mode: MaxEncodedLen)
+	/// Storage: Alliance UnscrupulousWebsites (r:0 w:1)
+	/// Proof: Alliance UnscrupulousWebsites (max_values: Some(1), max_size: Some(25702), added: 26197, mode: MaxEncodedLen)
+	/// Storage: AllianceMotion Members (r:0 w:1)
+	/// Proof Skipped: AllianceMotion Members (max_values: Some(1), max_size: None, mode: Measured)
+	/// The range of component `m` is `[1, 100]`.
+	/// The range of component `u` is `[0, 100]`.
+	fn force_import_snapshot(m: u32, u: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `217`
+		//  Estimated: `18048 + m * (2560 ±0)`
+		// Minimum execution time: 52_309_000 picoseconds.
+		Weight::from_parts(38_734_120, 18048)
+			// Standard Error: 1_912
+			.saturating_add(Weight::from_parts(21_846_307, 0).saturating_mul(m.into()))
+			// Standard Error: 1_884
+			.saturating_add(Weight::from_parts(141_523, 0).saturating_mul(u.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(m.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 2560).saturating_mul(m.into()))
+	}
+	/// Storage: Alliance NextEnactmentIndex (r:1 w:1)
+	/// Proof: Alliance NextEnactmentIndex (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Alliance EnactmentBlocks (r:1 w:1)
+	/// Proof: Alliance EnactmentBlocks (max_values: Some(1), max_size: Some(801), added: 1296, mode: MaxEncodedLen)
+	/// Storage: Alliance PendingEnactments (r:0 w:1)
+	/// Proof Skipped: Alliance PendingEnactments (max_values: None, max_size: None, mode: Measured)
+	fn schedule_enactment() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `876`
+		//  Estimated: `2361`
+		// Minimum execution time: 14_174_000 picoseconds.
+		Weight::from_parts(14_730_000, 2361)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Alliance PendingEnactments (r:1 w:1)
+	/// Proof Skipped: Alliance PendingEnactments (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Alliance EnactmentBlocks (r:1 w:1)
+	/// Proof: Alliance EnactmentBlocks (max_values: Some(1), max_size: Some(801), added: 1296, mode: MaxEncodedLen)
+	/// The range of component `p` is `[1, 100]`.
+	fn cancel_enactment(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `651 + p * (29 ±0)`
+		//  Estimated: `2286 + p * (29 ±0)`
+		// Minimum execution time: 12_951_000 picoseconds.
+		Weight::from_parts(13_377_102, 2286)
+			// Standard Error: 1_093
+			.saturating_add(Weight::from_parts(45_268, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 29).saturating_mul(p.into()))
+	}
+	/// Storage: Alliance Members (r:3 w:1)
+	/// Proof: Alliance Members (max_values: None, max_size: Some(3211), added: 5686, mode: MaxEncodedLen)
+	/// Storage: Alliance UnscrupulousAccounts (r:1 w:0)
+	/// Proof: Alliance UnscrupulousAccounts (max_values: Some(1), max_size: Some(3202), added: 3697, mode: MaxEncodedLen)
+	/// Storage: AllianceMotion Proposals (r:1 w:0)
+	/// Proof Skipped: AllianceMotion Proposals (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Alliance DepositOf (r:1 w:1)
+	/// Proof: Alliance DepositOf (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Alliance RetiringMembers (r:0 w:1)
+	/// Proof: Alliance RetiringMembers (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Alliance SuspendedMembers (r:0 w:1)
+	/// Proof: Alliance SuspendedMembers (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Alliance MemberSince (r:0 w:1)
+	/// Proof: Alliance MemberSince (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: AllianceMotion Members (r:0 w:1)
+	/// Proof Skipped: AllianceMotion Members (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: AllianceMotion Prime (r:0 w:1)
+	/// Proof Skipped: AllianceMotion Prime (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Alliance NominationsUsed (r:0 w:1)
+	/// Proof: Alliance NominationsUsed (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Alliance UnscrupulousPenalizedAt (r:1 w:1)
+	/// Proof: Alliance UnscrupulousPenalizedAt (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn enforce_unscrupulous() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `806`
+		//  Estimated: `18048`
+		// Minimum execution time: 79_431_000 picoseconds.
+		Weight::from_parts(82_067_000, 18048)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: Alliance DisbandProgress (r:1 w:1)
+	/// Proof: Alliance DisbandProgress (max_values: Some(1), max_size: Some(12), added: 507, mode: MaxEncodedLen)
+	/// Storage: Alliance Members (r:3 w:2)
+	/// Proof: Alliance Members (max_values: None, max_size: Some(3211), added: 5686, mode: MaxEncodedLen)
+	/// Storage: Alliance DepositOf (r:200 w:50)
+	/// Proof: Alliance DepositOf (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: System Account (r:50 w:50)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Alliance MemberSince (r:0 w:200)
+	/// Proof: Alliance MemberSince (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: Alliance NominationsUsed (r:0 w:100)
+	/// Proof: Alliance NominationsUsed (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 100]`.
+	/// The range of component `y` is `[0, 100]`.
+	/// The range of component `z` is `[0, 50]`.
+	fn continue_disband(x: u32, y: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + x * (50 ±0) + y * (51 ±0) + z * (251 ±0)`
+		//  Estimated: `6816 + x * (2539 ±0) + y * (2539 ±0) + z * (2603 ±1)`
+		// Minimum execution time: 23_916_000 picoseconds.
+		Weight::from_parts(24_563_000, 6816)
+			// Standard Error: 25_871
+			.saturating_add(Weight::from_parts(1_843_215, 0).saturating_mul(x.into()))
+			// Standard Error: 25_746
+			.saturating_add(Weight::from_parts(1_902_407, 0).saturating_mul(y.into()))
+			// Standard Error: 51_443
+			.saturating_add(Weight::from_parts(16_107_532, 0).saturating_mul(z.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(y.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(z.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(y.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(z.into())))
+			.saturating_add(Weight::from_parts(0, 2539).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(0, 2539).saturating_mul(y.into()))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(z.into()))
+	}
+	/// Storage: AllianceMotion Voting (r:1 w:0)
+	/// Proof Skipped: AllianceMotion Voting (max_values: None, max_size: None, mode: Measured)
+	/// Storage: AllianceMotion Members (r:1 w:0)
+	/// Proof Skipped: AllianceMotion Members (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Alliance DecidedProposals (r:1 w:1)
+	/// Proof: Alliance DecidedProposals (max_values: Some(1), max_size: Some(3602), added: 4097, mode: MaxEncodedLen)
+	fn queue_decided_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3899`
+		//  Estimated: `7364`
+		// Minimum execution time: 21_436_000 picoseconds.
+		Weight::from_parts(22_318_000, 7364)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Alliance SubCommittees (r:1 w:0)
+	/// Proof: Alliance SubCommittees (max_values: None, max_size: Some(338), added: 2813, mode: MaxEncodedLen)
+	/// Storage: Alliance Members (r:3 w:0)
+	/// Proof: Alliance Members (max_values: None, max_size: Some(3211), added: 5686, mode: MaxEncodedLen)
+	/// Storage: Alliance SuspendedMembers (r:1 w:0)
+	/// Proof: Alliance SuspendedMembers (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	fn ensure_sub_committee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1021`
+		//  Estimated: `18048`
+		// Minimum execution time: 13_284_000 picoseconds.
+		Weight::from_parts(13_902_000, 18048)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+	}
 }
@@ -18,11 +18,14 @@
 //! Tests regarding the functionality of the `fungible` trait set implementations.
 
 use super::*;
-use frame_support::traits::tokens::{
-	Fortitude::{Force, Polite},
-	Precision::{BestEffort, Exact},
-	Preservation::{Expendable, Preserve, Protect},
-	Restriction::Free,
+use frame_support::traits::{
+	tokens::{
+		Fortitude::{Force, Polite},
+		Precision::{BestEffort, Exact},
+		Preservation::{Expendable, Preserve, Protect},
+		Restriction::Free,
+	},
+	ReservableCurrency,
 };
 use fungible::{Inspect, InspectFreeze, InspectHold, Mutate, MutateFreeze, MutateHold, Unbalanced};
 
@@ -493,3 +496,37 @@ fn withdraw_precision_exact_works() {
 			);
 		});
 }
+
+#[test]
+fn deposit_ledger_works() {
+	ExtBuilder::default().existential_deposit(1).build_and_execute_with(|| {
+		parameter_types! {
+			pub const FooReason: TestId = TestId::Foo;
+		}
+		type Ledger = fungible::DepositLedger<u64, Balances, FooReason>;
+
+		Balances::set_balance(&1, 100);
+		assert!(Ledger::can_place(&1, 30));
+		assert_ok!(Ledger::place(&1, 30));
+		assert_ok!(Balances::hold(&TestId::Bar, &1, 10));
+		assert_eq!(Ledger::deposit_of(&1), 30);
+		assert_eq!(Ledger::total_of(&1), 40);
+
+		assert_eq!(Ledger::release(&1, 50), Ok(30));
+		assert_eq!(Ledger::deposit_of(&1), 0);
+
+		// Reserves are moved to the hold.
+		assert_ok!(<Balances as ReservableCurrency<_>>::reserve(&1, 20));
+		assert_eq!(Ledger::migrate_from_reserve::<Balances>(&1, 20), Ok(20));
+		assert_eq!(Ledger::deposit_of(&1), 20);
+		assert_eq!(Ledger::total_of(&1), 30);
+		assert_eq!(Balances::free_balance(&1), 70);
+
+		// Slashing leaves the deposits of other reasons alone.
+		let credit = Ledger::slash_all(&1);
+		assert_eq!(credit.peek(), 20);
+		assert_eq!(Ledger::deposit_of(&1), 0);
+		assert_eq!(Ledger::total_of(&1), 10);
+		assert_eq!(Balances::free_balance(&1), 70);
+	});
+}
@@ -200,6 +200,33 @@ pub use v1::*;
 /// 0x… in case a storage key that does not have metadata. Note that this skips the analysis of
 /// all accesses, not just ones without metadata.
 ///
+/// #### `tags`
+///
+/// Tags the benchmark, i.e. `#[benchmark(tags = ["storage-heavy", "xcm"])]`. The tags are part
+/// of the benchmark metadata returned by the runtime, and `frame-benchmarking-cli` only runs the
+/// benchmarks with any of the tags passed to `--tags`. This allows to run only the benchmarks
+/// affected by a change. Benchmarks in the old syntax have no tags.
+///
+/// ### Verifying DB Reads and Writes
+///
+/// A benchmark can declare how many storage reads and writes the benchmarked code performs with
+/// the `#[verify_db]` attribute. The expressions can use the components of the benchmark:
+///
+/// ```ignore
+/// #[benchmark]
+/// #[verify_db(reads = 1 + n, writes = n)]
+/// fn bench(n: Linear<0, 10>) {
+/// 	// ...
+/// }
+/// ```
+///
+/// The counts follow the same rules as the ones reported in the benchmark results, so accesses
+/// to whitelisted keys and repeated accesses to the same key are not counted. When the recorded
+/// counts differ from the declared ones, the benchmark fails. The check runs both while the
+/// benchmark is measured by the `frame-benchmarking-cli` and in the tests generated by
+/// `impl_benchmark_test_suite`, where only the keys whitelisted by `frame_system` are excluded.
+/// Running the benchmark without one of its components is an error instead of skipping the check.
+///
 /// ## Where Clause
 ///
 /// Some pallets require a where clause specifying constraints on their generics to make
@@ -442,3 +442,92 @@ mod benchmarks {
 		});
 	}
 }
+
+#[cfg(feature = "runtime-benchmarks")]
+mod fill_storage {
+	use super::{
+		new_test_ext,
+		pallet_test::{Config, Pallet},
+		Test,
+	};
+	use crate::{
+		fill_child_trie, fill_storage, fill_storage_to_max, BenchmarkError, MAX_FILL_ENTRIES,
+	};
+	use frame_support::{
+		storage::StoragePrefixedMap,
+		storage_alias,
+		traits::{ConstU32, GetDefault},
+		Twox64Concat,
+	};
+	use sp_storage::ChildInfo;
+
+	#[storage_alias]
+	type Bounded<T: Config> =
+		StorageMap<Pallet<T>, Twox64Concat, u32, [u8; 16], OptionQuery, GetDefault, ConstU32<8>>;
+
+	#[storage_alias]
+	type Unbounded<T: Config> = StorageMap<Pallet<T>, Twox64Concat, u32, [u8; 16]>;
+
+	#[storage_alias]
+	type Foreign = StorageMap<Other, Twox64Concat, u32, [u8; 16]>;
+
+	/// The number of keys under `prefix`.
+	fn prefix_len(prefix: &[u8]) -> usize {
+		let mut len = 0;
+		let mut key = prefix.to_vec();
+		while let Some(next) = sp_io::storage::next_key(&key).filter(|k| k.starts_with(prefix)) {
+			len += 1;
+			key = next;
+		}
+		len
+	}
+
+	#[test]
+	fn fill_storage_to_max_works() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(fill_storage_to_max::<Pallet<Test>, Bounded<Test>>(), Ok(8));
+			assert_eq!(prefix_len(&Bounded::<Test>::final_prefix()), 8);
+
+			// Filling is deterministic.
+			let root = sp_io::storage::root(sp_runtime::StateVersion::V1);
+			assert_eq!(fill_storage_to_max::<Pallet<Test>, Bounded<Test>>(), Ok(8));
+			assert_eq!(sp_io::storage::root(sp_runtime::StateVersion::V1), root);
+		});
+	}
+
+	#[test]
+	fn fill_storage_checks_bounds() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(
+				fill_storage_to_max::<Pallet<Test>, Unbounded<Test>>(),
+				Err(BenchmarkError::Stop("the number of values of the storage item is unbounded"))
+			);
+			assert_eq!(fill_storage::<Pallet<Test>, Unbounded<Test>>(4), Ok(()));
+			assert_eq!(prefix_len(&Unbounded::<Test>::final_prefix()), 4);
+
+			assert_eq!(
+				fill_storage::<Pallet<Test>, Unbounded<Test>>(MAX_FILL_ENTRIES + 1),
+				Err(BenchmarkError::Stop("too many entries to fill"))
+			);
+			assert_eq!(
+				fill_storage::<Pallet<Test>, Foreign>(1),
+				Err(BenchmarkError::Stop("the storage item is not part of the pallet"))
+			);
+		});
+	}
+
+	#[test]
+	fn fill_child_trie_works() {
+		new_test_ext().execute_with(|| {
+			let child_info = ChildInfo::new_default(b"fill");
+			assert_eq!(fill_child_trie(&child_info, 3, 32), Ok(()));
+
+			let first = sp_io::default_child_storage::next_key(child_info.storage_key(), &[])
+				.expect("the child trie is filled");
+			assert_eq!(
+				sp_io::default_child_storage::get(child_info.storage_key(), &first),
+				Some(vec![0u8; 32])
+			);
+		});
+	}
+}
@@ -19,7 +19,7 @@
 
 #![cfg(test)]
 
-use frame_support::{derive_impl, traits::ConstU32};
+use frame_support::{derive_impl, instances::Instance2, traits::ConstU32};
 use sp_runtime::{
 	testing::H256,
 	traits::{BlakeTwo256, IdentityLookup},
@@ -82,6 +82,7 @@ frame_support::construct_runtime!(
 	{
 		System: frame_system,
 		TestPallet: pallet_test,
+		TestPallet2: pallet_test::<Instance2>,
 	}
 );
 
@@ -118,6 +119,12 @@ impl pallet_test::Config for Test {
 	type UpperBound = ConstU32<100>;
 }
 
+impl pallet_test::Config<Instance2> for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type LowerBound = ConstU32<1>;
+	type UpperBound = ConstU32<10>;
+}
+
 impl pallet_test::OtherConfig for Test {
 	type OtherEvent = RuntimeEvent;
 }
@@ -127,7 +134,10 @@ fn new_test_ext() -> sp_io::TestExternalities {
 }
 
 mod benchmarks {
-	use super::pallet_test::{self, Value};
+	use super::{
+		pallet_test::{self, Value},
+		Instance2,
+	};
 	use crate::account;
 	use frame_support::ensure;
 	use frame_system::RawOrigin;
@@ -152,6 +162,14 @@ mod benchmarks {
 			assert_eq!(Value::<T, I>::get(), Some(b));
 		}
 
+		bounded_value {
+			let b in ( T::LowerBound::get() ) .. T::UpperBound::get();
+			let caller = account::<T::AccountId>("caller", 0, 0);
+		}: set_value (RawOrigin::Signed(caller), b.into())
+		verify {
+			ensure!(Value::<T, I>::get() <= Some(T::UpperBound::get()), "Value exceeds the bound!");
+		}
+
 		other_name {
 			let b in 1 .. 1000;
 		}: dummy (RawOrigin::None, b.into())
@@ -174,4 +192,11 @@ mod benchmarks {
 			crate::tests_instance::Test
 		)
 	}
+
+	crate::impl_benchmark_test_suite!(
+		Pallet,
+		crate::tests_instance::new_test_ext(),
+		crate::tests_instance::Test,
+		instance = Instance2
+	);
 }
@@ -17,6 +17,8 @@
 
 //! Interfaces, types and utils for benchmarking a FRAME runtime.
 use codec::{Decode, Encode};
+#[cfg(feature = "runtime-benchmarks")]
+use frame_support::traits::{PalletInfoAccess, StorageInfoTrait};
 use frame_support::{dispatch::DispatchErrorWithPostInfo, pallet_prelude::*, traits::StorageInfo};
 use scale_info::TypeInfo;
 #[cfg(feature = "std")]
@@ -24,6 +26,8 @@ use serde::{Deserialize, Serialize};
 use sp_io::hashing::blake2_256;
 use sp_runtime::{traits::TrailingZeroInput, DispatchError};
 use sp_std::{prelude::Box, vec::Vec};
+#[cfg(feature = "runtime-benchmarks")]
+use sp_storage::ChildInfo;
 use sp_storage::TrackedStorageKey;
 
 /// An alphabet of possible parameters to use for benchmarking.
@@ -102,6 +106,20 @@ pub struct BenchmarkBatchSplitResults {
 	pub time_results: Vec<BenchmarkResult>,
 	/// The db tracking results from this benchmark.
 	pub db_results: Vec<BenchmarkResult>,
+	/// The memory allocated by this benchmark, if it was tracked.
+	#[cfg_attr(feature = "std", serde(default, skip_serializing_if = "Vec::is_empty"))]
+	pub allocation_results: Vec<BenchmarkAllocations>,
+}
+
+/// The memory allocated by the runtime while running a benchmark once, including its setup.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Debug)]
+pub struct BenchmarkAllocations {
+	pub components: Vec<(BenchmarkParameter, u32)>,
+	/// The peak number of bytes allocated at the same time.
+	pub peak: u32,
+	/// The sum of the sizes of all allocations, in bytes.
+	pub total: u128,
 }
 
 /// Result from running benchmarks on a FRAME pallet.
@@ -225,10 +243,46 @@ pub struct BenchmarkMetadata {
 	pub name: Vec<u8>,
 	pub components: Vec<(BenchmarkParameter, u32, u32)>,
 	pub pov_modes: Vec<(Vec<u8>, Vec<u8>)>,
+	pub tags: Vec<Vec<u8>>,
+}
+
+/// A list of benchmarks available for a particular pallet and instance. Version 1.
+///
+/// All `Vec<u8>` must be valid utf8 strings.
+#[derive(Encode, Decode, Default, Clone, PartialEq, Debug, TypeInfo)]
+pub struct BenchmarkListV1 {
+	pub pallet: Vec<u8>,
+	pub instance: Vec<u8>,
+	pub benchmarks: Vec<BenchmarkMetadataV1>,
+}
+
+impl From<BenchmarkListV1> for BenchmarkList {
+	fn from(v1: BenchmarkListV1) -> Self {
+		Self {
+			pallet: v1.pallet,
+			instance: v1.instance,
+			benchmarks: v1.benchmarks.into_iter().map(Into::into).collect(),
+		}
+	}
+}
+
+/// The metadata of a benchmark without tags. Version 1.
+#[derive(Encode, Decode, Default, Clone, PartialEq, Debug, TypeInfo)]
+pub struct BenchmarkMetadataV1 {
+	pub name: Vec<u8>,
+	pub components: Vec<(BenchmarkParameter, u32, u32)>,
+	pub pov_modes: Vec<(Vec<u8>, Vec<u8>)>,
+}
+
+impl From<BenchmarkMetadataV1> for BenchmarkMetadata {
+	fn from(v1: BenchmarkMetadataV1) -> Self {
+		Self { name: v1.name, components: v1.components, pov_modes: v1.pov_modes, tags: Vec::new() }
+	}
 }
 
 sp_api::decl_runtime_apis! {
 	/// Runtime api for benchmarking a FRAME runtime.
+	#[api_version(2)]
 	pub trait Benchmark {
 		/// Get the benchmark metadata available for this runtime.
 		///
@@ -237,6 +291,10 @@ sp_api::decl_runtime_apis! {
 		///            needed for weight calculation.
 		fn benchmark_metadata(extra: bool) -> (Vec<BenchmarkList>, Vec<StorageInfo>);
 
+		/// Get the benchmark metadata available for this runtime. Version 1.
+		#[changed_in(2)]
+		fn benchmark_metadata(extra: bool) -> (Vec<BenchmarkListV1>, Vec<StorageInfo>);
+
 		/// Dispatch the given benchmark.
 		fn dispatch_benchmark(config: BenchmarkConfig) -> Result<Vec<BenchmarkBatch>, sp_runtime::RuntimeString>;
 	}
@@ -356,6 +414,17 @@ pub trait BenchmarkingSetup<T, I = ()> {
 		components: &[(BenchmarkParameter, u32)],
 		verify: bool,
 	) -> Result<Box<dyn FnOnce() -> Result<(), BenchmarkError>>, BenchmarkError>;
+
+	/// The number of DB reads and writes the benchmarked code is expected to perform with the
+	/// given components, if the benchmark declares them.
+	///
+	/// Fails if one of the components of the benchmark is missing.
+	fn expected_read_write_count(
+		&self,
+		_components: &[(BenchmarkParameter, u32)],
+	) -> Result<Option<(u32, u32)>, BenchmarkError> {
+		Ok(None)
+	}
 }
 
 /// Grab an account, seeded by a name and index.
@@ -370,6 +439,103 @@ pub fn whitelisted_caller<AccountId: Decode>() -> AccountId {
 	account::<AccountId>("whitelisted_caller", 0, 0)
 }
 
+/// The maximum number of dummy entries written by a single call to one of the storage filling
+/// helpers.
+#[cfg(feature = "runtime-benchmarks")]
+pub const MAX_FILL_ENTRIES: u32 = 100_000;
+
+/// Fill the storage item `Item` of `Pallet` up to its `max_values` with dummy entries.
+///
+/// This sets up the worst case proof size for accessing `Item`, without constructing valid
+/// entries by hand. Returns the number of entries written.
+///
+/// See [`fill_storage`] for the guarantees and restrictions.
+#[cfg(feature = "runtime-benchmarks")]
+pub fn fill_storage_to_max<Pallet, Item>() -> Result<u32, BenchmarkError>
+where
+	Pallet: PalletInfoAccess,
+	Item: StorageInfoTrait,
+{
+	let max_values = bounded_storage_info::<Pallet, Item>()?
+		.max_values
+		.ok_or(BenchmarkError::Stop("the number of values of the storage item is unbounded"))?;
+	fill_storage::<Pallet, Item>(max_values)?;
+	Ok(max_values)
+}
+
+/// Write `count` dummy entries of the maximum size of `Item` under the prefix of `Item`.
+///
+/// Keys and values are derived from the entry index only, so that every benchmark run observes
+/// the same trie. Each value alone is as large as the maximum size of a whole entry, which
+/// over-approximates the proof size. The entries are not valid entries of `Item` and must not be
+/// read by the benchmarked code.
+///
+/// Errors if `Item` is not part of `Pallet`, is a single value, has no maximum encoded size or
+/// `count` exceeds [`MAX_FILL_ENTRIES`].
+#[cfg(feature = "runtime-benchmarks")]
+pub fn fill_storage<Pallet, Item>(count: u32) -> Result<(), BenchmarkError>
+where
+	Pallet: PalletInfoAccess,
+	Item: StorageInfoTrait,
+{
+	let info = bounded_storage_info::<Pallet, Item>()?;
+	let max_size = info.max_size.ok_or(BenchmarkError::Stop("the storage item is unbounded"))?;
+	if info.max_values == Some(1) {
+		return Err(BenchmarkError::Stop("single value storage items can not be filled"))
+	}
+	if count > MAX_FILL_ENTRIES {
+		return Err(BenchmarkError::Stop("too many entries to fill"))
+	}
+
+	let value = sp_std::vec![0u8; max_size as usize];
+	for index in 0..count {
+		let suffix = (b"benchmark:fill", &info.prefix, index).using_encoded(blake2_256);
+		let key = [&info.prefix[..], &suffix[..]].concat();
+		sp_io::storage::set(&key, &value);
+	}
+
+	Ok(())
+}
+
+/// Write `count` dummy entries of `value_len` bytes into the child trie `child_info`.
+///
+/// Like [`fill_storage`], the entries are deterministic and limited to [`MAX_FILL_ENTRIES`].
+#[cfg(feature = "runtime-benchmarks")]
+pub fn fill_child_trie(
+	child_info: &ChildInfo,
+	count: u32,
+	value_len: u32,
+) -> Result<(), BenchmarkError> {
+	if count > MAX_FILL_ENTRIES {
+		return Err(BenchmarkError::Stop("too many entries to fill"))
+	}
+
+	let value = sp_std::vec![0u8; value_len as usize];
+	for index in 0..count {
+		let key = (b"benchmark:fill", index).using_encoded(blake2_256);
+		sp_io::default_child_storage::set(child_info.storage_key(), &key, &value);
+	}
+
+	Ok(())
+}
+
+/// The storage info of `Item`, ensuring that it belongs to `Pallet`.
+#[cfg(feature = "runtime-benchmarks")]
+fn bounded_storage_info<Pallet, Item>() -> Result<StorageInfo, BenchmarkError>
+where
+	Pallet: PalletInfoAccess,
+	Item: StorageInfoTrait,
+{
+	let info = Item::storage_info()
+		.into_iter()
+		.next()
+		.ok_or(BenchmarkError::Stop("the storage item has no storage info"))?;
+	if info.pallet_name != Pallet::name().as_bytes() {
+		return Err(BenchmarkError::Stop("the storage item is not part of the pallet"))
+	}
+	Ok(info)
+}
+
 #[macro_export]
 macro_rules! whitelist_account {
 	($acc:ident) => {
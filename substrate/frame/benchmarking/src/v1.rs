@@ -819,13 +819,16 @@ macro_rules! benchmark_backend {
 #[doc(hidden)]
 macro_rules! impl_bench_case_tests {
 	(
-		{ $module:ident, $new_test_exec:expr, $exec_name:ident, $test:path, $extra:expr }
+		{
+			$module:ident, $new_test_exec:expr, $exec_name:ident, $test:path,
+			{ $( $instance:ident )? }, $extra:expr
+		}
 		{ $( $names_extra:tt )* }
 		$( { $( $bench_inst:ident )? } $bench:ident )*
 	)
 	=> {
 		$crate::impl_bench_name_tests!(
-			$module, $new_test_exec, $exec_name, $test, $extra,
+			$module, $new_test_exec, $exec_name, $test, { $( $instance )? }, $extra,
 			{ $( $names_extra )* },
 			$( { $bench } )+
 		);
@@ -838,7 +841,8 @@ macro_rules! impl_bench_case_tests {
 macro_rules! impl_bench_name_tests {
 	// recursion anchor
 	(
-		$module:ident, $new_test_exec:expr, $exec_name:ident, $test:path, $extra:expr,
+		$module:ident, $new_test_exec:expr, $exec_name:ident, $test:path,
+		{ $( $instance:ident )? }, $extra:expr,
 		{ $( $names_extra:tt )* },
 		{ $name:ident }
 	) => {
@@ -861,7 +865,7 @@ macro_rules! impl_bench_name_tests {
 					// Same per-case logic as when all cases are run in the
 					// same function.
 					match std::panic::catch_unwind(|| {
-						$module::<$test>::[< test_benchmark_ $name >] ()
+						$module::<$test $(, $instance)?>::[< test_benchmark_ $name >] ()
 					}) {
 						Err(err) => {
 							panic!("{}: {:?}", stringify!($name), err);
@@ -902,16 +906,17 @@ macro_rules! impl_bench_name_tests {
 	};
 	// recursion tail
     (
-		$module:ident, $new_test_exec:expr, $exec_name:ident, $test:path, $extra:expr,
+		$module:ident, $new_test_exec:expr, $exec_name:ident, $test:path,
+		{ $( $instance:ident )? }, $extra:expr,
 		{ $( $names_extra:tt )* },
 		{ $name:ident } $( { $rest:ident } )+
 	) => {
 		// car
-		$crate::impl_bench_name_tests!($module, $new_test_exec, $exec_name, $test, $extra,
-			{ $( $names_extra )* }, { $name });
+		$crate::impl_bench_name_tests!($module, $new_test_exec, $exec_name, $test,
+			{ $( $instance )? }, $extra, { $( $names_extra )* }, { $name });
 		// cdr
-		$crate::impl_bench_name_tests!($module, $new_test_exec, $exec_name, $test, $extra,
-			{ $( $names_extra )* }, $( { $rest } )+);
+		$crate::impl_bench_name_tests!($module, $new_test_exec, $exec_name, $test,
+			{ $( $instance )? }, $extra, { $( $names_extra )* }, $( { $rest } )+);
 	};
 }
 
@@ -1024,6 +1029,8 @@ macro_rules! impl_benchmark {
 						name: name.clone(),
 						components,
 						pov_modes: pov_modes.iter().find(|p| p.0 == name).map(|p| p.1.clone()).unwrap_or_default(),
+						// Tags are only supported by the V2 syntax.
+						tags: $crate::__private::vec![],
 					}
 				}).collect::<$crate::__private::Vec<_>>()
 			}
@@ -1426,6 +1433,31 @@ macro_rules! impl_benchmark_test {
 /// - It must be the name of a method applied to the output of the `new_test_ext` argument.
 /// - That method must have a signature capable of receiving a single argument of the form `impl
 ///   FnOnce()`.
+///
+/// There is an optional seventh argument, with keyword syntax: `instance = InstanceName`. By
+/// default, the benchmarks of an instantiable pallet are tested against its default instance `()`.
+/// If set, they are tested against the given instance instead, so that benchmarks depending on the
+/// configuration of an instance are run with the right one. As for `benchmarks_path`, the instance
+/// has to be an ident in the scope of `impl_benchmark_test_suite`. The macro can be invoked once
+/// per instance, the test module generated for an instance being suffixed with its name:
+///
+/// ```rust,ignore
+/// #[instance_benchmarks]
+/// mod benchmarks {
+/// 	...
+///
+/// 	impl_benchmark_test_suite!(Pallet, new_test_ext(), Test);
+/// }
+///
+/// mod instance_tests {
+/// 	use crate::Pallet;
+/// 	use frame_support::instances::{Instance1, Instance2};
+///
+/// 	// Generates the `benchmark_tests_instance1` and `benchmark_tests_instance2` modules.
+/// 	impl_benchmark_test_suite!(Pallet, new_test_ext(), Test, instance = Instance1);
+/// 	impl_benchmark_test_suite!(Pallet, new_test_ext(), Test, instance = Instance2);
+/// }
+/// ```
 // ## Notes (not for rustdoc)
 //
 // The biggest challenge for this macro is communicating the actual test functions to be run. We
@@ -1524,6 +1556,7 @@ macro_rules! impl_test_function {
 				benchmarks_path = super,
 				extra = true,
 				exec_name = execute_with,
+				instance = (),
 			@user:
 				$( $( $rest )* )?
 		);
@@ -1541,6 +1574,7 @@ macro_rules! impl_test_function {
 			benchmarks_path = $old:ident,
 			extra = $extra:expr,
 			exec_name = $exec_name:ident,
+			instance = ( $( $instance:ident )? ),
 		@user:
 			benchmarks_path = $benchmarks_path:ident
 			$(, $( $rest:tt )* )?
@@ -1557,6 +1591,7 @@ macro_rules! impl_test_function {
 				benchmarks_path = $benchmarks_path,
 				extra = $extra,
 				exec_name = $exec_name,
+				instance = ( $( $instance )? ),
 			@user:
 				$( $( $rest )* )?
 		);
@@ -1574,6 +1609,7 @@ macro_rules! impl_test_function {
 			benchmarks_path = $benchmarks_path:ident,
 			extra = $old:expr,
 			exec_name = $exec_name:ident,
+			instance = ( $( $instance:ident )? ),
 		@user:
 			extra = $extra:expr
 			$(, $( $rest:tt )* )?
@@ -1590,6 +1626,7 @@ macro_rules! impl_test_function {
 				benchmarks_path = $benchmarks_path,
 				extra = $extra,
 				exec_name = $exec_name,
+				instance = ( $( $instance )? ),
 			@user:
 				$( $( $rest )* )?
 		);
@@ -1607,6 +1644,7 @@ macro_rules! impl_test_function {
 			benchmarks_path = $benchmarks_path:ident,
 			extra = $extra:expr,
 			exec_name = $old:ident,
+			instance = ( $( $instance:ident )? ),
 		@user:
 			exec_name = $exec_name:ident
 			$(, $( $rest:tt )* )?
@@ -1623,6 +1661,42 @@ macro_rules! impl_test_function {
 				benchmarks_path = $benchmarks_path,
 				extra = $extra,
 				exec_name = $exec_name,
+				instance = ( $( $instance )? ),
+			@user:
+				$( $( $rest )* )?
+		);
+	};
+	// pick off the instance keyword argument
+	(
+		@cases:
+			( $( $names:tt )* )
+			( $( $names_extra:tt )* )
+			( $( $names_skip_meta:tt )* )
+		@selected:
+			$bench_module:ident,
+			$new_test_ext:expr,
+			$test:path,
+			benchmarks_path = $benchmarks_path:ident,
+			extra = $extra:expr,
+			exec_name = $exec_name:ident,
+			instance = ( $( $old:ident )? ),
+		@user:
+			instance = $instance:ident
+			$(, $( $rest:tt )* )?
+	) => {
+		$crate::impl_test_function!(
+			@cases:
+				( $( $names )* )
+				( $( $names_extra )* )
+				( $( $names_skip_meta )* )
+			@selected:
+				$bench_module,
+				$new_test_ext,
+				$test,
+				benchmarks_path = $benchmarks_path,
+				extra = $extra,
+				exec_name = $exec_name,
+				instance = ( $instance ),
 			@user:
 				$( $( $rest )* )?
 		);
@@ -1640,11 +1714,12 @@ macro_rules! impl_test_function {
 			benchmarks_path = $path_to_benchmarks_invocation:ident,
 			extra = $extra:expr,
 			exec_name = $exec_name:ident,
+			instance = ( $( $instance:ident )? ),
 		@user:
 			$(,)?
 	) => {
 		$crate::impl_bench_case_tests!(
-			{ $bench_module, $new_test_ext, $exec_name, $test, $extra }
+			{ $bench_module, $new_test_ext, $exec_name, $test, { $( $instance )? }, $extra }
 			{ $( $names_extra:tt )* }
 			$($names)+
 		);
@@ -1662,76 +1737,80 @@ macro_rules! impl_test_function {
 			benchmarks_path = $path_to_benchmarks_invocation:ident,
 			extra = $extra:expr,
 			exec_name = $exec_name:ident,
+			instance = ( $( $instance:ident )? ),
 		@user:
 			$(,)?
 	) => {
-		#[cfg(test)]
-		mod benchmark_tests {
-			use super::$bench_module;
-
-			#[test]
-			fn test_benchmarks() {
-				$new_test_ext.$exec_name(|| {
-					use $crate::Benchmarking;
-
-					let mut anything_failed = false;
-					println!("failing benchmark tests:");
-					for benchmark_metadata in $bench_module::<$test>::benchmarks($extra) {
-						let benchmark_name = &benchmark_metadata.name;
-						match std::panic::catch_unwind(|| {
-							$bench_module::<$test>::test_bench_by_name(benchmark_name)
-						}) {
-							Err(err) => {
-								println!(
-									"{}: {:?}",
-									$crate::__private::str::from_utf8(benchmark_name)
-										.expect("benchmark name is always a valid string!"),
-									err,
-								);
-								anything_failed = true;
-							},
-							Ok(Err(err)) => {
-								match err {
-									$crate::BenchmarkError::Stop(err) => {
-										println!(
-											"{}: {:?}",
-											$crate::__private::str::from_utf8(benchmark_name)
-												.expect("benchmark name is always a valid string!"),
-											err,
-										);
-										anything_failed = true;
-									},
-									$crate::BenchmarkError::Override(_) => {
-										// This is still considered a success condition.
-										$crate::__private::log::error!(
-											"WARNING: benchmark error overrided - {}",
+		$crate::__private::paste::paste! {
+			#[cfg(test)]
+			mod [< benchmark_tests $( _ $instance:snake )? >] {
+				use super::$bench_module;
+				$( use super::$instance; )?
+
+				#[test]
+				fn test_benchmarks() {
+					$new_test_ext.$exec_name(|| {
+						use $crate::Benchmarking;
+
+						let mut anything_failed = false;
+						println!("failing benchmark tests:");
+						for benchmark_metadata in $bench_module::<$test $(, $instance)?>::benchmarks($extra) {
+							let benchmark_name = &benchmark_metadata.name;
+							match std::panic::catch_unwind(|| {
+								$bench_module::<$test $(, $instance)?>::test_bench_by_name(benchmark_name)
+							}) {
+								Err(err) => {
+									println!(
+										"{}: {:?}",
+										$crate::__private::str::from_utf8(benchmark_name)
+											.expect("benchmark name is always a valid string!"),
+										err,
+									);
+									anything_failed = true;
+								},
+								Ok(Err(err)) => {
+									match err {
+										$crate::BenchmarkError::Stop(err) => {
+											println!(
+												"{}: {:?}",
 												$crate::__private::str::from_utf8(benchmark_name)
 													.expect("benchmark name is always a valid string!"),
+												err,
 											);
-									},
-									$crate::BenchmarkError::Skip => {
-										// This is considered a success condition.
-										$crate::__private::log::error!(
-											"WARNING: benchmark error skipped - {}",
-											$crate::__private::str::from_utf8(benchmark_name)
-												.expect("benchmark name is always a valid string!"),
-										);
-									}
-									$crate::BenchmarkError::Weightless => {
-										// This is considered a success condition.
-										$crate::__private::log::error!(
-											"WARNING: benchmark weightless skipped - {}",
-											$crate::__private::str::from_utf8(benchmark_name)
-												.expect("benchmark name is always a valid string!"),
-										);
+											anything_failed = true;
+										},
+										$crate::BenchmarkError::Override(_) => {
+											// This is still considered a success condition.
+											$crate::__private::log::error!(
+												"WARNING: benchmark error overrided - {}",
+													$crate::__private::str::from_utf8(benchmark_name)
+														.expect("benchmark name is always a valid string!"),
+												);
+										},
+										$crate::BenchmarkError::Skip => {
+											// This is considered a success condition.
+											$crate::__private::log::error!(
+												"WARNING: benchmark error skipped - {}",
+												$crate::__private::str::from_utf8(benchmark_name)
+													.expect("benchmark name is always a valid string!"),
+											);
+										}
+										$crate::BenchmarkError::Weightless => {
+											// This is considered a success condition.
+											$crate::__private::log::error!(
+												"WARNING: benchmark weightless skipped - {}",
+												$crate::__private::str::from_utf8(benchmark_name)
+													.expect("benchmark name is always a valid string!"),
+											);
+										}
 									}
-								}
-							},
-							Ok(Ok(())) => (),
+								},
+								Ok(Ok(())) => (),
+							}
 						}
-					}
-					assert!(!anything_failed);
-				});
+						assert!(!anything_failed);
+					});
+				}
 			}
 		}
 	};
@@ -52,7 +52,8 @@ use sp_std::{marker::PhantomData, prelude::*, result};
 
 use frame_support::{
 	dispatch::{
-		DispatchResult, DispatchResultWithPostInfo, GetDispatchInfo, Pays, PostDispatchInfo,
+		DispatchErrorWithPostInfo, DispatchResult, DispatchResultWithPostInfo, GetDispatchInfo,
+		Pays, PostDispatchInfo,
 	},
 	ensure, impl_ensure_origin_with_arg_ignoring_arg,
 	traits::{
@@ -61,6 +62,7 @@ use frame_support::{
 	},
 	weights::Weight,
 };
+use frame_system::{with_dispatch_frame, DispatchFrame};
 
 #[cfg(any(feature = "try-runtime", test))]
 use sp_runtime::TryRuntimeError;
@@ -170,6 +172,19 @@ pub struct Votes<AccountId, BlockNumber> {
 	end: BlockNumber,
 }
 
+/// The votes a motion was closed with.
+#[derive(PartialEq, Eq, Clone, Copy, RuntimeDebug)]
+pub struct Tally {
+	/// The number of approval votes, including abstentions counted by the default vote.
+	pub yes: MemberCount,
+	/// The number of rejection votes, including abstentions counted by the default vote.
+	pub no: MemberCount,
+	/// The number of approval votes that were needed to pass the motion.
+	pub threshold: MemberCount,
+	/// Whether the motion was approved.
+	pub approved: bool,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -465,7 +480,9 @@ pub mod pallet {
 			ensure!(proposal_len <= length_bound as usize, Error::<T, I>::WrongProposalLength);
 
 			let proposal_hash = T::Hashing::hash_of(&proposal);
-			let result = proposal.dispatch(RawOrigin::Member(who).into());
+			let result = with_dispatch_frame(DispatchFrame::new::<Self>(0), || {
+				proposal.dispatch(RawOrigin::Member(who).into())
+			});
 			Self::deposit_event(Event::MemberExecuted {
 				proposal_hash,
 				result: result.map(|_| ()).map_err(|e| e.error),
@@ -689,7 +706,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		ensure!(!<ProposalOf<T, I>>::contains_key(proposal_hash), Error::<T, I>::DuplicateProposal);
 
 		let seats = Self::members().len() as MemberCount;
-		let result = proposal.dispatch(RawOrigin::Members(1, seats).into());
+		let result = with_dispatch_frame(DispatchFrame::new::<Self>(0), || {
+			proposal.dispatch(RawOrigin::Members(1, seats).into())
+		});
 		Self::deposit_event(Event::Executed {
 			proposal_hash,
 			result: result.map(|_| ()).map_err(|e| e.error),
@@ -791,6 +810,15 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok(is_account_voting_first_time)
 	}
 
+	/// Whether the outcome of a proposal is final, i.e. it has enough votes to be approved or
+	/// disapproved before the end of its voting period.
+	pub fn is_decided(proposal_hash: &T::Hash) -> bool {
+		let Some(voting) = Self::voting(proposal_hash) else { return false };
+		let seats = Self::members().len() as MemberCount;
+		voting.ayes.len() as MemberCount >= voting.threshold ||
+			seats.saturating_sub(voting.nays.len() as MemberCount) < voting.threshold
+	}
+
 	/// Close a vote that is either approved, disapproved or whose voting period has ended.
 	pub fn do_close(
 		proposal_hash: T::Hash,
@@ -798,6 +826,17 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		proposal_weight_bound: Weight,
 		length_bound: u32,
 	) -> DispatchResultWithPostInfo {
+		Self::do_close_with_tally(proposal_hash, index, proposal_weight_bound, length_bound)
+			.map(|(info, _)| info)
+	}
+
+	/// Like [`Self::do_close`], but also returns the votes the motion was closed with.
+	pub fn do_close_with_tally(
+		proposal_hash: T::Hash,
+		index: ProposalIndex,
+		proposal_weight_bound: Weight,
+		length_bound: u32,
+	) -> result::Result<(PostDispatchInfo, Tally), DispatchErrorWithPostInfo> {
 		let voting = Self::voting(&proposal_hash).ok_or(Error::<T, I>::ProposalMissing)?;
 		ensure!(voting.index == index, Error::<T, I>::WrongIndex);
 
@@ -816,22 +855,33 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Self::deposit_event(Event::Closed { proposal_hash, yes: yes_votes, no: no_votes });
 			let (proposal_weight, proposal_count) =
 				Self::do_approve_proposal(seats, yes_votes, proposal_hash, proposal);
+			let tally =
+				Tally { yes: yes_votes, no: no_votes, threshold: voting.threshold, approved: true };
 			return Ok((
-				Some(
-					T::WeightInfo::close_early_approved(len as u32, seats, proposal_count)
-						.saturating_add(proposal_weight),
-				),
-				Pays::Yes,
-			)
-				.into())
+				(
+					Some(
+						T::WeightInfo::close_early_approved(len as u32, seats, proposal_count)
+							.saturating_add(proposal_weight),
+					),
+					Pays::Yes,
+				)
+					.into(),
+				tally,
+			))
 		} else if disapproved {
 			Self::deposit_event(Event::Closed { proposal_hash, yes: yes_votes, no: no_votes });
 			let proposal_count = Self::do_disapprove_proposal(proposal_hash);
+			let tally = Tally {
+				yes: yes_votes,
+				no: no_votes,
+				threshold: voting.threshold,
+				approved: false,
+			};
 			return Ok((
-				Some(T::WeightInfo::close_early_disapproved(seats, proposal_count)),
-				Pays::No,
-			)
-				.into())
+				(Some(T::WeightInfo::close_early_disapproved(seats, proposal_count)), Pays::No)
+					.into(),
+				tally,
+			))
 		}
 
 		// Only allow actual closing of the proposal after the voting period has ended.
@@ -848,6 +898,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			false => no_votes += abstentions,
 		}
 		let approved = yes_votes >= voting.threshold;
+		let tally = Tally { yes: yes_votes, no: no_votes, threshold: voting.threshold, approved };
 
 		if approved {
 			let (proposal, len) = Self::validate_and_get_proposal(
@@ -859,17 +910,23 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			let (proposal_weight, proposal_count) =
 				Self::do_approve_proposal(seats, yes_votes, proposal_hash, proposal);
 			Ok((
-				Some(
-					T::WeightInfo::close_approved(len as u32, seats, proposal_count)
-						.saturating_add(proposal_weight),
-				),
-				Pays::Yes,
-			)
-				.into())
+				(
+					Some(
+						T::WeightInfo::close_approved(len as u32, seats, proposal_count)
+							.saturating_add(proposal_weight),
+					),
+					Pays::Yes,
+				)
+					.into(),
+				tally,
+			))
 		} else {
 			Self::deposit_event(Event::Closed { proposal_hash, yes: yes_votes, no: no_votes });
 			let proposal_count = Self::do_disapprove_proposal(proposal_hash);
-			Ok((Some(T::WeightInfo::close_disapproved(seats, proposal_count)), Pays::No).into())
+			Ok((
+				(Some(T::WeightInfo::close_disapproved(seats, proposal_count)), Pays::No).into(),
+				tally,
+			))
 		}
 	}
 
@@ -917,7 +974,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		let dispatch_weight = proposal.get_dispatch_info().weight;
 		let origin = RawOrigin::Members(yes_votes, seats).into();
-		let result = proposal.dispatch(origin);
+		let result =
+			with_dispatch_frame(DispatchFrame::new::<Self>(0), || proposal.dispatch(origin));
 		Self::deposit_event(Event::Executed {
 			proposal_hash,
 			result: result.map(|_| ()).map_err(|e| e.error),
@@ -0,0 +1,69 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! This calls the supplied dest with the supplied ref_time limit using `call_v3` and
+//! returns the outcome: `[0]` on success, `[1, data..]` if the callee reverted and
+//! `[2, failure..]` with the encoded `CalleeFailure` if the callee failed.
+//! It also forwards its input to the callee.
+#![no_std]
+#![no_main]
+
+use common::input;
+use uapi::{CallError, HostFn, HostFnImpl as api};
+
+#[no_mangle]
+#[polkavm_derive::polkavm_export]
+pub extern "C" fn deploy() {}
+
+#[no_mangle]
+#[polkavm_derive::polkavm_export]
+pub extern "C" fn call() {
+	input!(
+		100,
+		callee_addr: [u8; 32],
+		ref_time_limit: u64,
+		input: [u8],
+	);
+
+	let mut output = [0u8; 32];
+	let output = &mut &mut output[..];
+	let result = api::call_v3(
+		uapi::CallFlags::empty(),
+		callee_addr,
+		ref_time_limit,
+		0u64, // How much proof_size to devote for the execution. 0 = all.
+		None, // No deposit limit.
+		&0u64.to_le_bytes(),
+		input,
+		output,
+	);
+
+	match result {
+		Ok(()) => api::return_value(uapi::ReturnFlags::empty(), &[0u8]),
+		Err(CallError::Reverted) => {
+			let mut buf = [1u8; 33];
+			buf[1..1 + output.len()].copy_from_slice(output);
+			api::return_value(uapi::ReturnFlags::empty(), &buf[..1 + output.len()]);
+		},
+		Err(CallError::Failed(_)) => {
+			let mut buf = [2u8; 33];
+			buf[1..1 + output.len()].copy_from_slice(output);
+			api::return_value(uapi::ReturnFlags::empty(), &buf[..1 + output.len()]);
+		},
+		Err(CallError::Other(_)) => panic!(),
+	}
+}
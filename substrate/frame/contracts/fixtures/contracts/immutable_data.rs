@@ -0,0 +1,56 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! This contract sets its input as immutable data on deploy and returns it when called.
+//! A call with a non-empty input tries to set the immutable data again.
+#![no_std]
+#![no_main]
+
+use common::input;
+use uapi::{HostFn, HostFnImpl as api};
+
+#[no_mangle]
+#[polkavm_derive::polkavm_export]
+pub extern "C" fn deploy() {
+	input!(
+		256,
+		data: [u8],
+	);
+
+	#[allow(deprecated)]
+	api::set_immutable_data(data);
+}
+
+#[no_mangle]
+#[polkavm_derive::polkavm_export]
+pub extern "C" fn call() {
+	input!(
+		256,
+		data: [u8],
+	);
+
+	if !data.is_empty() {
+		#[allow(deprecated)]
+		api::set_immutable_data(data);
+	}
+
+	let mut buffer = [0u8; 256];
+	let output = &mut &mut buffer[..];
+	#[allow(deprecated)]
+	api::get_immutable_data(output);
+	api::return_value(uapi::ReturnFlags::empty(), output);
+}
@@ -0,0 +1,62 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! This contract tests the behavior of pinning / unpinning code hashes.
+#![no_std]
+#![no_main]
+
+use common::input;
+use uapi::{HostFn, HostFnImpl as api};
+
+const ALICE: [u8; 32] = [1u8; 32];
+
+/// Load input data and perform the action specified by the input.
+fn load_input() {
+	input!(
+		action: u32,
+		code_hash: [u8; 32],
+	);
+
+	match action {
+		// 1 = Pin code hash
+		1 => {
+			api::pin_code_hash(code_hash);
+		},
+		// 2 = Unpin code hash
+		2 => {
+			api::unpin_code_hash(code_hash);
+		},
+		// 3 = Terminate
+		3 => {
+			api::terminate_v1(&ALICE);
+		},
+		// Everything else is a noop
+		_ => {},
+	}
+}
+
+#[no_mangle]
+#[polkavm_derive::polkavm_export]
+pub extern "C" fn deploy() {
+	load_input();
+}
+
+#[no_mangle]
+#[polkavm_derive::polkavm_export]
+pub extern "C" fn call() {
+	load_input();
+}
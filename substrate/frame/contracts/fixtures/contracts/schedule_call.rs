@@ -0,0 +1,54 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! This contract schedules calls to itself and cancels them again.
+#![no_std]
+#![no_main]
+
+use common::input;
+use uapi::{HostFn, HostFnImpl as api};
+
+#[no_mangle]
+#[polkavm_derive::polkavm_export]
+pub extern "C" fn deploy() {}
+
+#[no_mangle]
+#[polkavm_derive::polkavm_export]
+pub extern "C" fn call() {
+	input!(
+		action: u32,
+		when: [u8; 8],
+		index: u32,
+	);
+
+	match action {
+		// 0 = Executed as the scheduled call: leave a marker in storage.
+		0 => {
+			api::set_storage(&[1u8; 32], &[42u8]);
+		},
+		// 1 = Schedule a call with `action` 0 at block `when` and return its index.
+		1 => {
+			let index = api::schedule_call(when, &[0u8; 16], 10_000_000_000, 1024 * 1024, None);
+			api::return_value(uapi::ReturnFlags::empty(), &index.to_le_bytes());
+		},
+		// 2 = Cancel the call scheduled at block `when` with `index`.
+		2 => {
+			api::cancel_scheduled_call(when, index);
+		},
+		_ => {},
+	}
+}
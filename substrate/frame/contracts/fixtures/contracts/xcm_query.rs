@@ -0,0 +1,51 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![no_std]
+#![no_main]
+
+use common::input;
+use uapi::{HostFn, HostFnImpl as api};
+
+#[no_mangle]
+#[polkavm_derive::polkavm_export]
+pub extern "C" fn deploy() {}
+
+#[no_mangle]
+#[polkavm_derive::polkavm_export]
+pub extern "C" fn call() {
+	input!(
+		512,
+		timeout: [u8; 8],
+		match_querier: [u8],
+	);
+
+	let mut query_id = [0u8; 8];
+
+	#[allow(deprecated)]
+	api::xcm_query(timeout, match_querier, &mut query_id).unwrap();
+
+	// Return the query id followed by the status of the query, which only the contract that
+	// registered it can take.
+	let mut output = [0u8; 72];
+	let status = &mut &mut output[8..];
+	#[allow(deprecated)]
+	api::xcm_take_response(&query_id, status);
+	let len = 8 + status.len();
+	output[..8].copy_from_slice(&query_id);
+	api::return_value(uapi::ReturnFlags::empty(), &output[..len]);
+}
@@ -18,14 +18,14 @@ use super::{Balances, Runtime, RuntimeCall, RuntimeEvent};
 use crate::{
 	parachain,
 	parachain::RuntimeHoldReason,
-	primitives::{Balance, CENTS},
+	primitives::{AccountId, Balance, CENTS},
 };
 use frame_support::{
 	parameter_types,
-	traits::{ConstBool, ConstU32, Contains, Randomness},
+	traits::{ConstBool, ConstU32, Contains, Equals, Randomness},
 	weights::Weight,
 };
-use frame_system::pallet_prelude::BlockNumberFor;
+use frame_system::{pallet_prelude::BlockNumberFor, EnsureRoot, EnsureSigned};
 use pallet_xcm::BalanceOf;
 use sp_runtime::{traits::Convert, Perbill};
 
@@ -40,6 +40,9 @@ parameter_types! {
 	pub Schedule: pallet_contracts::Schedule<Runtime> = Default::default();
 	pub const CodeHashLockupDepositPercent: Perbill = Perbill::from_percent(0);
 	pub const MaxDelegateDependencies: u32 = 32;
+	pub const MaxScheduledCallsWeight: Weight = Weight::from_parts(100_000_000_000, 1024 * 1024);
+	pub const BlockExecutionBudget: Weight = Weight::MAX;
+	pub ContractsXcmDestination: xcm::latest::Location = xcm::latest::Location::parent();
 }
 
 pub struct DummyRandomness<T: pallet_contracts::Config>(sp_std::marker::PhantomData<T>);
@@ -74,25 +77,43 @@ impl pallet_contracts::Config for Runtime {
 	type CallStack = [pallet_contracts::Frame<Self>; 5];
 	type ChainExtension = ();
 	type CodeHashLockupDepositPercent = CodeHashLockupDepositPercent;
+	type CodeDepositCurve = ();
 	type Currency = Balances;
 	type DefaultDepositLimit = DefaultDepositLimit;
 	type DepositPerByte = DepositPerByte;
 	type DepositPerItem = DepositPerItem;
+	type InstantiateOrigin = EnsureSigned<AccountId>;
+	type InstantiationPermissionOrigin = EnsureRoot<AccountId>;
 	type MaxCodeLen = ConstU32<{ 123 * 1024 }>;
 	type MaxDebugBufferLen = ConstU32<{ 2 * 1024 * 1024 }>;
 	type MaxDelegateDependencies = MaxDelegateDependencies;
+	type MaxPinnedCodeHashes = ConstU32<32>;
+	type MaxEventTopicLen = ConstU32<128>;
+	type MaxRegisteredTopics = ConstU32<1024>;
+	type MaxScheduledCallDataLen = ConstU32<1024>;
+	type MaxScheduledCallsPerBlock = ConstU32<16>;
+	type MaxScheduledCallsWeight = MaxScheduledCallsWeight;
+	type BlockExecutionBudget = BlockExecutionBudget;
+	type CodePruningDelay = ();
 	type MaxStorageKeyLen = ConstU32<128>;
+	type MaxStorageItemSize = ConstU32<{ 16 * 1024 }>;
+	type MaxImmutableDataLen = ConstU32<{ 4 * 1024 }>;
 	type Migrations = ();
 	type Randomness = DummyRandomness<Self>;
+	type RandomnessSource = DummyRandomness<Self>;
 	type RuntimeCall = RuntimeCall;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type Schedule = Schedule;
 	type Time = super::Timestamp;
 	type UnsafeUnstableInterface = ConstBool<true>;
+	type FeeBreakdownEvents = ConstBool<false>;
+	type CallCacheSize = ConstU32<0>;
 	type WeightInfo = ();
 	type WeightPrice = Self;
 	type Debug = ();
 	type Environment = ();
 	type Xcm = pallet_xcm::Pallet<Self>;
+	type XcmWeigher = <parachain::XcmConfig as xcm_executor::Config>::Weigher;
+	type XcmDestinations = Equals<ContractsXcmDestination>;
 }
@@ -106,6 +106,7 @@ fn test_xcm_execute() {
 			DebugInfo::UnsafeDebug,
 			CollectEvents::UnsafeCollect,
 			Determinism::Enforced,
+			false,
 		);
 
 		assert_eq!(result.gas_consumed, result.gas_required);
@@ -150,6 +151,7 @@ fn test_xcm_execute_incomplete() {
 			DebugInfo::UnsafeDebug,
 			CollectEvents::UnsafeCollect,
 			Determinism::Enforced,
+			false,
 		);
 
 		assert_eq!(result.gas_consumed, result.gas_required);
@@ -185,12 +187,51 @@ fn test_xcm_execute_filtered_call() {
 			DebugInfo::UnsafeDebug,
 			CollectEvents::UnsafeCollect,
 			Determinism::Enforced,
+			false,
 		);
 
 		assert_err!(result.result, frame_system::Error::<parachain::Runtime>::CallFiltered);
 	});
 }
 
+#[test]
+fn test_xcm_execute_disallowed_destination() {
+	MockNet::reset();
+
+	let contract_addr = instantiate_test_contract("xcm_execute");
+
+	ParaA::execute_with(|| {
+		// Only the relay chain is allowed by `XcmDestinations`, not the sibling parachain.
+		let message: Xcm<parachain::RuntimeCall> = Xcm(vec![
+			WithdrawAsset(vec![(Here, 10 * CENTS).into()].into()),
+			InitiateTeleport {
+				assets: All.into(),
+				dest: (Parent, Parachain(2)).into(),
+				xcm: Xcm(vec![]),
+			},
+		]);
+
+		let result = ParachainContracts::bare_call(
+			ALICE,
+			contract_addr.clone(),
+			0,
+			Weight::MAX,
+			None,
+			VersionedXcm::V4(message).encode(),
+			DebugInfo::UnsafeDebug,
+			CollectEvents::UnsafeCollect,
+			Determinism::Enforced,
+			false,
+		);
+
+		assert_err!(
+			result.result,
+			pallet_contracts::Error::<parachain::Runtime>::XcmDestinationNotAllowed
+		);
+		assert_eq!(ParachainBalances::free_balance(&contract_addr), INITIAL_BALANCE);
+	});
+}
+
 #[test]
 fn test_xcm_execute_reentrant_call() {
 	MockNet::reset();
@@ -226,6 +267,7 @@ fn test_xcm_execute_reentrant_call() {
 			DebugInfo::UnsafeDebug,
 			CollectEvents::UnsafeCollect,
 			Determinism::Enforced,
+			false,
 		);
 
 		assert_return_code!(&result.result.unwrap(), ReturnErrorCode::XcmExecutionFailed);
@@ -262,6 +304,7 @@ fn test_xcm_send() {
 			DebugInfo::UnsafeDebug,
 			CollectEvents::UnsafeCollect,
 			Determinism::Enforced,
+			false,
 		);
 
 		let mut data = &exec.result.unwrap().data[..];
@@ -276,3 +319,92 @@ fn test_xcm_send() {
 		);
 	});
 }
+
+#[test]
+fn test_xcm_weigh_message() {
+	use xcm_executor::traits::WeightBounds;
+
+	MockNet::reset();
+	let contract_addr = instantiate_test_contract("xcm_weigh_message");
+
+	ParaA::execute_with(|| {
+		let mut message: Xcm<parachain::RuntimeCall> = Xcm(vec![
+			WithdrawAsset(vec![(Here, 10 * CENTS).into()].into()),
+			DepositAsset {
+				assets: All.into(),
+				beneficiary: AccountId32 { network: None, id: BOB.clone().into() }.into(),
+			},
+		]);
+
+		let result = ParachainContracts::bare_call(
+			ALICE,
+			contract_addr.clone(),
+			0,
+			Weight::MAX,
+			None,
+			VersionedXcm::V4(message.clone()).encode(),
+			DebugInfo::UnsafeDebug,
+			CollectEvents::UnsafeCollect,
+			Determinism::Enforced,
+			false,
+		);
+
+		let weight = Weight::decode(&mut &result.result.unwrap().data[..]).unwrap();
+		assert_eq!(
+			Ok(weight),
+			<parachain::XcmConfig as xcm_executor::Config>::Weigher::weight(&mut message)
+		);
+	});
+}
+
+#[test]
+fn test_xcm_query_and_take_response() {
+	use xcm_executor::traits::QueryResponseStatus;
+
+	MockNet::reset();
+	let query_addr = instantiate_test_contract("xcm_query");
+	let take_response_addr = instantiate_test_contract("xcm_take_response");
+
+	ParaA::execute_with(|| {
+		let timeout: u64 = 10;
+		let match_querier = VersionedLocation::V4(Location::from(Parent));
+
+		let result = ParachainContracts::bare_call(
+			ALICE,
+			query_addr.clone(),
+			0,
+			Weight::MAX,
+			None,
+			(timeout, match_querier).encode(),
+			DebugInfo::UnsafeDebug,
+			CollectEvents::UnsafeCollect,
+			Determinism::Enforced,
+			false,
+		);
+		let (query_id, status) =
+			<(QueryId, QueryResponseStatus<u64>)>::decode(&mut &result.result.unwrap().data[..])
+				.unwrap();
+		// The query is registered, but no response arrived yet.
+		assert_eq!(status, QueryResponseStatus::Pending { timeout });
+
+		let take_response = |query_id: QueryId| {
+			let result = ParachainContracts::bare_call(
+				ALICE,
+				take_response_addr.clone(),
+				0,
+				Weight::MAX,
+				None,
+				query_id.encode(),
+				DebugInfo::UnsafeDebug,
+				CollectEvents::UnsafeCollect,
+				Determinism::Enforced,
+				false,
+			);
+			QueryResponseStatus::<u64>::decode(&mut &result.result.unwrap().data[..]).unwrap()
+		};
+
+		// Only the contract that registered the query can see it.
+		assert_eq!(take_response(query_id), QueryResponseStatus::NotFound);
+		assert_eq!(take_response(query_id + 1), QueryResponseStatus::NotFound);
+	});
+}
@@ -636,6 +636,7 @@ fn expand_functions(def: &EnvDef, expand_blocks: bool, host_state: TokenStream2)
 		} else {
 			quote! { #[allow(unused_variables)] }
 		};
+		let metering_name = format!("{}::{}", module, name);
 		let sync_gas_before = if expand_blocks {
 			quote! {
 				// Write gas from wasmi into pallet-contracts before entering the host function.
@@ -650,6 +651,7 @@ fn expand_functions(def: &EnvDef, expand_blocks: bool, host_state: TokenStream2)
 						.map_err(TrapReason::from)
 						.map_err(#into_host)?
 				};
+				let __weight_left_before__ = __caller__.data_mut().ext().gas_meter().gas_left();
 			}
 		} else {
 			quote! { }
@@ -657,6 +659,9 @@ fn expand_functions(def: &EnvDef, expand_blocks: bool, host_state: TokenStream2)
 		// Write gas from pallet-contracts into wasmi after leaving the host function.
 		let sync_gas_after = if expand_blocks {
 			quote! {
+				let gas_meter = __caller__.data_mut().ext().gas_meter_mut();
+				let weight_consumed = __weight_left_before__.saturating_sub(gas_meter.gas_left());
+				gas_meter.note_host_fn(#metering_name, weight_consumed);
 				let fuel_consumed = __caller__
 					.data_mut()
 					.ext()
@@ -18,8 +18,28 @@
 //! Functions that deal with address derivation.
 
 use crate::{CodeHash, Config};
-use codec::{Decode, Encode};
-use sp_runtime::traits::{Hash, TrailingZeroInput};
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{Hash, TrailingZeroInput},
+	RuntimeDebug,
+};
+
+/// Selects how the address of a new contract is derived.
+#[derive(
+	Copy, Clone, Default, PartialEq, Eq, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo,
+)]
+pub enum AddressKind {
+	/// The address depends on the deployer, see [`AddressGenerator::contract_address`].
+	#[default]
+	Deployer,
+	/// The address only depends on the namespace, the code hash, the input data and the salt,
+	/// see [`AddressGenerator::namespaced_contract_address`].
+	///
+	/// Anyone deploying the same code with the same input data and salt to the same namespace
+	/// arrives at the same address, on any chain using the same formula.
+	Namespaced([u8; 32]),
+}
 
 /// Provides the contract address generation method.
 ///
@@ -40,6 +60,27 @@ pub trait AddressGenerator<T: Config> {
 		input_data: &[u8],
 		salt: &[u8],
 	) -> T::AccountId;
+
+	/// The address of a contract instantiated with [`AddressKind::Namespaced`].
+	///
+	/// The result must not depend on the deployer and must never collide with an address
+	/// returned by [`Self::contract_address`].
+	///
+	/// The input data is part of the preimage so that nobody can front-run a deployment at the
+	/// same address with a different constructor input.
+	///
+	/// Formula: `hash("contract_addr_v2" ++ namespace ++ code_hash ++ input_data ++ salt)`
+	fn namespaced_contract_address(
+		namespace: &[u8; 32],
+		code_hash: &CodeHash<T>,
+		input_data: &[u8],
+		salt: &[u8],
+	) -> T::AccountId {
+		let entropy = (b"contract_addr_v2", namespace, code_hash, input_data, salt)
+			.using_encoded(T::Hashing::hash);
+		Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+			.expect("infinite length input; no invalid inputs for type; qed")
+	}
 }
 
 /// Default address generator.
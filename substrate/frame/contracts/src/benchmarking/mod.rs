@@ -21,18 +21,23 @@
 
 mod code;
 mod sandbox;
+pub mod workload;
 use self::{
 	code::{
 		body::{self, DynInstr::*},
 		DataSegment, ImportedFunction, ImportedMemory, Location, ModuleDefinition, WasmModule,
 	},
 	sandbox::Sandbox,
+	workload::Workload,
 };
 use crate::{
+	code_pruning::process_code_pruning_batch,
 	exec::Key,
 	migration::{
-		codegen::LATEST_MIGRATION_VERSION, v09, v10, v11, v12, v13, v14, v15, MigrationStep,
+		codegen::LATEST_MIGRATION_VERSION, v09, v10, v11, v12, v13, v14, v15, v16, v17, v18,
+		MigrationStep,
 	},
+	scheduled_call::ScheduledCall,
 	Pallet as Contracts, *,
 };
 use codec::{Encode, MaxEncodedLen};
@@ -46,7 +51,7 @@ use frame_support::{
 use frame_system::RawOrigin;
 use pallet_balances;
 use pallet_contracts_uapi::CallFlags;
-use sp_runtime::traits::{Bounded, Hash};
+use sp_runtime::traits::{Bounded, Convert, Hash};
 use sp_std::prelude::*;
 use wasm_instrument::parity_wasm::elements::{BlockType, Instruction, Local, ValueType};
 
@@ -208,6 +213,48 @@ benchmarks! {
 		ContractInfo::<T>::process_deletion_queue_batch(Weight::MAX)
 	}
 
+	// This benchmarks the overhead of executing `n` scheduled calls in `on_initialize`. The
+	// weight of the calls themselves is accounted for by the gas they consume.
+	#[pov_mode = Measured]
+	on_initialize_per_scheduled_call {
+		let n in 0 .. T::MaxScheduledCallsPerBlock::get();
+		let instance = Contract::<T>::new(WasmModule::dummy(), vec![])?;
+		instance.set_balance(caller_funding::<T>());
+		let when = frame_system::Pallet::<T>::block_number() + 1u32.into();
+		for _ in 0 .. n {
+			ScheduledCall::<T>::schedule(instance.account_id.clone(), when, vec![], Weight::zero(), None)?;
+		}
+	}: {
+		ScheduledCall::<T>::service(when)
+	}
+
+	// The base weight consumed on sweeping the codes subject to pruning.
+	#[pov_mode = Measured]
+	on_process_code_pruning_batch {
+		let now = frame_system::Pallet::<T>::block_number();
+	}: {
+		process_code_pruning_batch::<T>(now, 0u32.into(), Weight::MAX)
+	}
+
+	// The weight of inspecting a single code during the sweep and pruning it, which is the worst
+	// case.
+	#[pov_mode = Measured]
+	on_idle_per_pruned_code {
+		let caller = whitelisted_caller();
+		T::Currency::set_balance(&caller, caller_funding::<T>());
+		let WasmModule { code, hash, .. } = WasmModule::<T>::dummy();
+		<Contracts<T>>::bare_upload_code(caller.clone(), code, None, Determinism::Enforced)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		CodeUploadedAt::<T>::insert(hash, now);
+		let now = now + 1u32.into();
+	}: {
+		process_code_pruning_batch::<T>(now, 0u32.into(), Weight::MAX)
+	}
+	verify {
+		assert_eq!(T::Currency::total_balance_on_hold(&caller), 0u32.into());
+		assert!(<Contract<T>>::code_removed(&hash));
+	}
+
 	// This benchmarks the v9 migration step (update codeStorage).
 	#[pov_mode = Measured]
 	v9_migration_step {
@@ -293,6 +340,45 @@ benchmarks! {
 		m.step();
 	}
 
+	// This benchmarks the v16 migration step (Add reentrancy_guard to ContractInfo).
+	#[pov_mode = Measured]
+	v16_migration_step {
+		let contract = <Contract<T>>::with_caller(
+			whitelisted_caller(), WasmModule::dummy(), vec![],
+		)?;
+
+		v16::store_old_contract_info::<T>(contract.account_id.clone(), contract.info()?);
+		let mut m = v16::Migration::<T>::default();
+	}: {
+		m.step();
+	}
+
+	// This benchmarks the v17 migration step (Add storage_largest_item to ContractInfo).
+	#[pov_mode = Measured]
+	v17_migration_step {
+		let contract = <Contract<T>>::with_caller(
+			whitelisted_caller(), WasmModule::dummy(), vec![],
+		)?;
+
+		v17::store_old_contract_info::<T>(contract.account_id.clone(), contract.info()?);
+		let mut m = v17::Migration::<T>::default();
+	}: {
+		m.step();
+	}
+
+	// This benchmarks the v18 migration step (Add immutable_data to ContractInfo).
+	#[pov_mode = Measured]
+	v18_migration_step {
+		let contract = <Contract<T>>::with_caller(
+			whitelisted_caller(), WasmModule::dummy(), vec![],
+		)?;
+
+		v18::store_old_contract_info::<T>(contract.account_id.clone(), contract.info()?);
+		let mut m = v18::Migration::<T>::default();
+	}: {
+		m.step();
+	}
+
 	// This benchmarks the weight of executing Migration::migrate to execute a noop migration.
 	#[pov_mode = Measured]
 	migration_noop {
@@ -473,11 +559,16 @@ benchmarks! {
 		let caller = whitelisted_caller();
 		T::Currency::set_balance(&caller, caller_funding::<T>());
 		let WasmModule { code, hash, .. } = WasmModule::<T>::sized(c, Location::Call);
+		let code_len = code.len() as u32;
 		let origin = RawOrigin::Signed(caller.clone());
 	}: _(origin, code, None, Determinism::Enforced)
 	verify {
 		// uploading the code reserves some balance in the callers account
 		assert!(T::Currency::total_balance_on_hold(&caller) > 0u32.into());
+		// which includes the size dependent part of the deposit
+		assert!(
+			T::Currency::total_balance_on_hold(&caller) >= T::CodeDepositCurve::convert(code_len)
+		);
 		assert!(<Contract<T>>::code_exists(&hash));
 	}
 
@@ -516,6 +607,49 @@ benchmarks! {
 		assert_eq!(instance.info()?.code_hash, hash);
 	}
 
+	#[pov_mode = Measured]
+	set_instantiation_permission {
+		let origin = T::InstantiationPermissionOrigin::try_successful_origin()
+			.map_err(|_| "InstantiationPermissionOrigin has no successful origin")?;
+		let who: T::AccountId = account("who", 0, 0);
+		let lookup = T::Lookup::unlookup(who.clone());
+	}: _<T::RuntimeOrigin>(origin, lookup, Some(InstantiationPermission::Denied))
+	verify {
+		assert!(!Contracts::<T>::can_instantiate(&who));
+	}
+
+	approve_deposit_sponsorship {
+		let sponsor = whitelisted_caller();
+		let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+		let lookup = T::Lookup::unlookup(beneficiary.clone());
+		let allowance = DepositAllowance {
+			remaining: Pallet::<T>::min_balance(),
+			per_call: Pallet::<T>::min_balance(),
+		};
+	}: _(RawOrigin::Signed(sponsor.clone()), lookup, Some(allowance))
+	verify {
+		assert_eq!(DepositAllowances::<T>::get(&sponsor, &beneficiary), Some(allowance));
+	}
+
+	// The overhead of a sponsored call on top of the call itself: capping the storage deposit
+	// limit by the allowance, then deducting the charged deposit from it.
+	deposit_sponsorship {
+		let sponsor: T::AccountId = account("sponsor", 0, 0);
+		let caller: T::AccountId = whitelisted_caller();
+		let allowance = DepositAllowance {
+			remaining: Pallet::<T>::min_balance(),
+			per_call: Pallet::<T>::min_balance(),
+		};
+		DepositAllowances::<T>::insert(&sponsor, &caller, allowance);
+	}: {
+		let limit = Pallet::<T>::sponsored_deposit_limit(&sponsor, &caller, None)?;
+		Pallet::<T>::consume_deposit_allowance(&sponsor, &caller, &StorageDeposit::Charge(limit));
+	}
+	verify {
+		let remaining = DepositAllowances::<T>::get(&sponsor, &caller).map(|a| a.remaining);
+		assert_eq!(remaining, Some(0u32.into()));
+	}
+
 	#[pov_mode = Measured]
 	seal_caller {
 		let r in 0 .. API_BENCHMARK_RUNS;
@@ -922,6 +1056,21 @@ benchmarks! {
 			.. Default::default()
 		});
 		let instance = Contract::<T>::new(code, vec![])?;
+		// Maximize the topic registry to account for the worst-case scenario.
+		let max_topics = T::MaxRegisteredTopics::get();
+		for i in 0 .. max_topics {
+			EventTopicRegistry::<T>::insert(&instance.account_id, T::Hashing::hash_of(&i), frame_support::BoundedVec::<u8, T::MaxEventTopicLen>::default());
+		}
+		RegisteredTopics::<T>::insert(&instance.account_id, (max_topics, BalanceOf::<T>::from(0u32)));
+		// Maximize the pinned code hashes to account for the worst-case scenario.
+		let pinned = (0..T::MaxPinnedCodeHashes::get())
+			.map(|i| {
+				let new_code = WasmModule::<T>::dummy_with_bytes(65 + T::MaxDelegateDependencies::get() + i);
+				Contracts::<T>::store_code_raw(new_code.code, caller.clone())?;
+				Ok((new_code.hash, BalanceOf::<T>::from(0u32)))
+			})
+			.collect::<Result<sp_std::collections::btree_map::BTreeMap<_, _>, &'static str>>()?;
+		PinnedCodeHashes::<T>::insert(&instance.account_id, frame_support::BoundedBTreeMap::try_from(pinned).expect("bounded by MaxPinnedCodeHashes; qed"));
 		let origin = RawOrigin::Signed(instance.caller.clone());
 		assert_eq!(T::Currency::total_balance(&beneficiary), 0u32.into());
 		assert_eq!(T::Currency::balance(&instance.account_id), Pallet::<T>::min_balance() * 2u32.into());
@@ -971,6 +1120,40 @@ benchmarks! {
 		let origin = RawOrigin::Signed(instance.caller.clone());
 	}: call(origin, instance.addr, 0u32.into(), Weight::MAX, None, vec![])
 
+	// Like `seal_random`, benchmarked for the maximum domain length.
+	#[pov_mode = Measured]
+	seal_randomness {
+		let r in 0 .. API_BENCHMARK_RUNS;
+		let pages = code::max_pages::<T>();
+		let domain_len = T::Schedule::get().limits.subject_len;
+		assert!(domain_len < 1024);
+		let code = WasmModule::<T>::from(ModuleDefinition {
+			memory: Some(ImportedMemory::max::<T>()),
+			imported_functions: vec![ImportedFunction {
+				module: "seal0",
+				name: "randomness",
+				params: vec![ValueType::I32, ValueType::I32, ValueType::I32, ValueType::I32],
+				return_type: None,
+			}],
+			data_segments: vec![
+				DataSegment {
+					offset: 0,
+					value: (pages * 64 * 1024 - domain_len - 4).to_le_bytes().to_vec(),
+				},
+			],
+			call_body: Some(body::repeated(r, &[
+				Instruction::I32Const(4), // domain_ptr
+				Instruction::I32Const(domain_len as i32), // domain_len
+				Instruction::I32Const((domain_len + 4) as i32), // out_ptr
+				Instruction::I32Const(0),	// out_len_ptr
+				Instruction::Call(0),
+			])),
+			.. Default::default()
+		});
+		let instance = Contract::<T>::new(code, vec![])?;
+		let origin = RawOrigin::Signed(instance.caller.clone());
+	}: call(origin, instance.addr, 0u32.into(), Weight::MAX, None, vec![])
+
 	// Overhead of calling the function without any topic.
 	// We benchmark for the worst case (largest event).
 	#[pov_mode = Measured]
@@ -1034,6 +1217,53 @@ benchmarks! {
 		let origin = RawOrigin::Signed(instance.caller.clone());
 	}: call(origin, instance.addr, 0u32.into(), Weight::MAX, None, vec![])
 
+	// Benchmark the overhead of hashing and registering the topics of `deposit_event_v2`.
+	// We benchmark for the worst case: every topic has the maximum length and is new.
+	// `t`: Number of topics
+	#[pov_mode = Measured]
+	seal_register_event_topic {
+		let t in 0 .. T::Schedule::get().limits.event_topics;
+		let topic_len = T::MaxEventTopicLen::get() as usize;
+		let topics = (0..t)
+			.map(|i| {
+				let mut topic = vec![0u8; topic_len];
+				topic[..4].copy_from_slice(&i.to_le_bytes());
+				topic
+			})
+			.collect::<Vec<_>>()
+			.encode();
+		let topics_len = topics.len();
+		let code = WasmModule::<T>::from(ModuleDefinition {
+			memory: Some(ImportedMemory::max::<T>()),
+			imported_functions: vec![ImportedFunction {
+				module: "seal0",
+				name: "deposit_event_v2",
+				params: vec![ValueType::I32, ValueType::I32, ValueType::I32, ValueType::I32],
+				return_type: None,
+			}],
+			data_segments: vec![
+				DataSegment {
+					offset: 0,
+					value: topics,
+				},
+			],
+			call_body: Some(body::plain(vec![
+				Instruction::I32Const(0), // topics_ptr
+				Instruction::I32Const(topics_len as i32), // topics_len
+				Instruction::I32Const(0), // data_ptr
+				Instruction::I32Const(0), // data_len
+				Instruction::Call(0),
+				Instruction::End,
+			])),
+			.. Default::default()
+		});
+		let instance = Contract::<T>::new(code, vec![])?;
+		let origin = RawOrigin::Signed(instance.caller.clone());
+	}: call(origin, instance.addr.clone(), 0u32.into(), Weight::MAX, None, vec![])
+	verify {
+		assert_eq!(RegisteredTopics::<T>::get(&instance.account_id).0, t);
+	}
+
 	// Benchmark debug_message call with zero input data.
 	// Whereas this function is used in RPC mode only, it still should be secured
 	// against an excessive use.
@@ -1068,6 +1298,7 @@ benchmarks! {
 			DebugInfo::UnsafeDebug,
 			CollectEvents::Skip,
 			Determinism::Enforced,
+			false,
 		)
 		.result?;
 	}
@@ -1118,6 +1349,7 @@ benchmarks! {
 			DebugInfo::UnsafeDebug,
 			CollectEvents::Skip,
 			Determinism::Enforced,
+			false,
 		)
 		.result?;
 	}
@@ -1181,10 +1413,12 @@ benchmarks! {
 		let origin = RawOrigin::Signed(instance.caller.clone());
 	}: call(origin, instance.addr, 0u32.into(), Weight::MAX, None, vec![])
 
+	// Larger values are rejected with `StorageItemTooLarge` before they are written.
+	// `n`: Size of the new value in bytes, up to `MaxStorageItemSize`
 	#[skip_meta]
 	#[pov_mode = Measured]
 	seal_set_storage_per_new_byte {
-		let n in 0 .. T::Schedule::get().limits.payload_len;
+		let n in 0 .. T::Schedule::get().limits.payload_len.min(T::MaxStorageItemSize::get());
 		let max_key_len = T::MaxStorageKeyLen::get();
 		let key = vec![0u8; max_key_len as usize];
 		let code = WasmModule::<T>::from(ModuleDefinition {
@@ -2488,6 +2722,212 @@ benchmarks! {
 		let origin = RawOrigin::Signed(instance.caller.clone());
 	}: call(origin, instance.addr, 0u32.into(), Weight::MAX, None, vec![])
 
+	#[pov_mode = Measured]
+	pin_code_hash {
+		let r in 0 .. T::MaxPinnedCodeHashes::get();
+		let code_hashes = (0..r)
+			.map(|i| {
+				let new_code = WasmModule::<T>::dummy_with_bytes(65 + i);
+				let caller = whitelisted_caller();
+				T::Currency::set_balance(&caller, caller_funding::<T>());
+				Contracts::<T>::store_code_raw(new_code.code, caller)?;
+				Ok(new_code.hash)
+			})
+			.collect::<Result<Vec<_>, &'static str>>()?;
+		let code_hash_len = code_hashes.get(0).map(|x| x.encode().len()).unwrap_or(0);
+		let code_hashes_bytes = code_hashes.iter().flat_map(|x| x.encode()).collect::<Vec<_>>();
+
+		let code = WasmModule::<T>::from(ModuleDefinition {
+			memory: Some(ImportedMemory::max::<T>()),
+			imported_functions: vec![ImportedFunction {
+				module: "seal0",
+				name: "pin_code_hash",
+				params: vec![ValueType::I32],
+				return_type: None,
+			}],
+			data_segments: vec![
+				DataSegment {
+					offset: 0,
+					value: code_hashes_bytes,
+				},
+			],
+			call_body: Some(body::repeated_dyn(r, vec![
+				Counter(0, code_hash_len as u32), // code_hash_ptr
+				Regular(Instruction::Call(0)),
+			])),
+			.. Default::default()
+		});
+		let instance = Contract::<T>::new(code, vec![])?;
+		let origin = RawOrigin::Signed(instance.caller.clone());
+	}: call(origin, instance.addr, 0u32.into(), Weight::MAX, None, vec![])
+
+	unpin_code_hash {
+		let r in 0 .. T::MaxPinnedCodeHashes::get();
+		let code_hashes = (0..r)
+			.map(|i| {
+				let new_code = WasmModule::<T>::dummy_with_bytes(65 + i);
+				let caller = whitelisted_caller();
+				T::Currency::set_balance(&caller, caller_funding::<T>());
+				Contracts::<T>::store_code_raw(new_code.code, caller)?;
+				Ok(new_code.hash)
+			})
+			.collect::<Result<Vec<_>, &'static str>>()?;
+
+		let code_hash_len = code_hashes.get(0).map(|x| x.encode().len()).unwrap_or(0);
+		let code_hashes_bytes = code_hashes.iter().flat_map(|x| x.encode()).collect::<Vec<_>>();
+
+		let code = WasmModule::<T>::from(ModuleDefinition {
+			memory: Some(ImportedMemory::max::<T>()),
+			imported_functions: vec![ImportedFunction {
+				module: "seal0",
+				name: "unpin_code_hash",
+				params: vec![ValueType::I32],
+				return_type: None,
+			}, ImportedFunction {
+				module: "seal0",
+				name: "pin_code_hash",
+				params: vec![ValueType::I32],
+				return_type: None
+			}],
+			data_segments: vec![
+				DataSegment {
+					offset: 0,
+					value: code_hashes_bytes,
+				},
+			],
+			deploy_body: Some(body::repeated_dyn(r, vec![
+				Counter(0, code_hash_len as u32), // code_hash_ptr
+				Regular(Instruction::Call(1)),
+			])),
+			call_body: Some(body::repeated_dyn(r, vec![
+				Counter(0, code_hash_len as u32), // code_hash_ptr
+				Regular(Instruction::Call(0)),
+			])),
+			.. Default::default()
+		});
+		let instance = Contract::<T>::new(code, vec![])?;
+		let origin = RawOrigin::Signed(instance.caller.clone());
+	}: call(origin, instance.addr, 0u32.into(), Weight::MAX, None, vec![])
+
+	#[pov_mode = Measured]
+	seal_schedule_call {
+		let r in 0 .. 1;
+		let when = frame_system::Pallet::<T>::block_number() + 1u32.into();
+		let code = WasmModule::<T>::from(ModuleDefinition {
+			memory: Some(ImportedMemory::max::<T>()),
+			imported_functions: vec![ImportedFunction {
+				module: "seal0",
+				name: "schedule_call",
+				params: vec![
+					ValueType::I32,
+					ValueType::I32,
+					ValueType::I32,
+					ValueType::I64,
+					ValueType::I64,
+					ValueType::I32,
+				],
+				return_type: Some(ValueType::I32),
+			}],
+			data_segments: vec![
+				DataSegment {
+					offset: 0,
+					value: when.encode(),
+				},
+			],
+			call_body: Some(body::repeated(r, &[
+				Instruction::I32Const(0), // when_ptr
+				Instruction::I32Const(0), // input_data_ptr
+				Instruction::I32Const(0), // input_data_len
+				Instruction::I64Const(0), // ref_time_limit
+				Instruction::I64Const(0), // proof_size_limit
+				Instruction::I32Const(SENTINEL as i32), // deposit_ptr
+				Instruction::Call(0),
+				Instruction::Drop,
+			])),
+			.. Default::default()
+		});
+		let instance = Contract::<T>::new(code, vec![])?;
+		instance.set_balance(caller_funding::<T>());
+		let origin = RawOrigin::Signed(instance.caller.clone());
+	}: call(origin, instance.addr, 0u32.into(), Weight::MAX, None, vec![])
+
+	#[pov_mode = Measured]
+	seal_schedule_call_per_byte {
+		let n in 0 .. T::MaxScheduledCallDataLen::get();
+		let when = frame_system::Pallet::<T>::block_number() + 1u32.into();
+		let when_len = when.encoded_size() as i32;
+		let code = WasmModule::<T>::from(ModuleDefinition {
+			memory: Some(ImportedMemory::max::<T>()),
+			imported_functions: vec![ImportedFunction {
+				module: "seal0",
+				name: "schedule_call",
+				params: vec![
+					ValueType::I32,
+					ValueType::I32,
+					ValueType::I32,
+					ValueType::I64,
+					ValueType::I64,
+					ValueType::I32,
+				],
+				return_type: Some(ValueType::I32),
+			}],
+			data_segments: vec![
+				DataSegment {
+					offset: 0,
+					value: when.encode(),
+				},
+			],
+			call_body: Some(body::plain(vec![
+				Instruction::I32Const(0), // when_ptr
+				Instruction::I32Const(when_len), // input_data_ptr
+				Instruction::I32Const(n as i32), // input_data_len
+				Instruction::I64Const(0), // ref_time_limit
+				Instruction::I64Const(0), // proof_size_limit
+				Instruction::I32Const(SENTINEL as i32), // deposit_ptr
+				Instruction::Call(0),
+				Instruction::Drop,
+				Instruction::End,
+			])),
+			.. Default::default()
+		});
+		let instance = Contract::<T>::new(code, vec![])?;
+		instance.set_balance(caller_funding::<T>());
+		let origin = RawOrigin::Signed(instance.caller.clone());
+	}: call(origin, instance.addr, 0u32.into(), Weight::MAX, None, vec![])
+
+	#[pov_mode = Measured]
+	seal_cancel_scheduled_call {
+		let r in 0 .. 1;
+		let when = frame_system::Pallet::<T>::block_number() + 1u32.into();
+		let code = WasmModule::<T>::from(ModuleDefinition {
+			memory: Some(ImportedMemory::max::<T>()),
+			imported_functions: vec![ImportedFunction {
+				module: "seal0",
+				name: "cancel_scheduled_call",
+				params: vec![ValueType::I32, ValueType::I32],
+				return_type: None,
+			}],
+			data_segments: vec![
+				DataSegment {
+					offset: 0,
+					value: when.encode(),
+				},
+			],
+			call_body: Some(body::repeated(r, &[
+				Instruction::I32Const(0), // when_ptr
+				Instruction::I32Const(0), // index
+				Instruction::Call(0),
+			])),
+			.. Default::default()
+		});
+		let instance = Contract::<T>::new(code, vec![])?;
+		instance.set_balance(caller_funding::<T>());
+		if r > 0 {
+			ScheduledCall::<T>::schedule(instance.account_id.clone(), when, vec![], Weight::zero(), None)?;
+		}
+		let origin = RawOrigin::Signed(instance.caller.clone());
+	}: call(origin, instance.addr, 0u32.into(), Weight::MAX, None, vec![])
+
 	#[pov_mode = Measured]
 	seal_reentrance_count {
 		let r in 0 .. API_BENCHMARK_RUNS;
@@ -2509,6 +2949,87 @@ benchmarks! {
 		let origin = RawOrigin::Signed(instance.caller.clone());
 	}: call(origin, instance.addr, 0u32.into(), Weight::MAX, None, vec![])
 
+	#[pov_mode = Measured]
+	seal_set_reentrancy_guard {
+		let r in 0 .. API_BENCHMARK_RUNS;
+		let code = WasmModule::<T>::from(ModuleDefinition {
+			memory: Some(ImportedMemory::max::<T>()),
+			imported_functions: vec![ImportedFunction {
+				module: "seal0",
+				name: "set_reentrancy_guard",
+				params: vec![ValueType::I32],
+				return_type: None,
+			}],
+			call_body: Some(body::repeated(r, &[
+				Instruction::I32Const(1),
+				Instruction::Call(0),
+			])),
+			.. Default::default()
+		});
+		let instance = Contract::<T>::new(code, vec![])?;
+		let origin = RawOrigin::Signed(instance.caller.clone());
+	}: call(origin, instance.addr, 0u32.into(), Weight::MAX, None, vec![])
+
+	// The immutable data can only be set once, by the constructor. It is always charged for the
+	// maximum size.
+	#[pov_mode = Measured]
+	seal_set_immutable_data {
+		let r in 0 .. 1;
+		let code = WasmModule::<T>::from(ModuleDefinition {
+			memory: Some(ImportedMemory::max::<T>()),
+			imported_functions: vec![ImportedFunction {
+				module: "seal0",
+				name: "set_immutable_data",
+				params: vec![ValueType::I32, ValueType::I32],
+				return_type: None,
+			}],
+			deploy_body: Some(body::repeated(r, &[
+				Instruction::I32Const(0), // data_ptr
+				Instruction::I32Const(T::MaxImmutableDataLen::get() as i32), // data_len
+				Instruction::Call(0),
+			])),
+			.. Default::default()
+		});
+		let caller = whitelisted_caller();
+		T::Currency::set_balance(&caller, caller_funding::<T>());
+		let origin = RawOrigin::Signed(caller.clone());
+		let hash = code.hash;
+		Contracts::<T>::store_code_raw(code.code, caller)?;
+		let value = Pallet::<T>::min_balance();
+	}: instantiate(origin, value, Weight::MAX, None, hash, vec![], vec![])
+
+	// The immutable data is always charged for the maximum size.
+	#[pov_mode = Measured]
+	seal_get_immutable_data {
+		let r in 0 .. API_BENCHMARK_RUNS;
+		let instance = Contract::<T>::new(WasmModule::getter(
+			"seal0", "get_immutable_data", r
+		), vec![])?;
+		let mut info = instance.info()?;
+		let data = vec![42u8; T::MaxImmutableDataLen::get() as usize];
+		info.set_immutable_data(data.try_into().map_err(|_| "Data has the maximum size; qed")?);
+		<ContractInfoOf<T>>::insert(&instance.account_id, info);
+		let origin = RawOrigin::Signed(instance.caller.clone());
+	}: call(origin, instance.addr, 0u32.into(), Weight::MAX, None, vec![])
+
+	#[pov_mode = Measured]
+	seal_storage_deposit_consumed {
+		let r in 0 .. API_BENCHMARK_RUNS;
+		let instance = Contract::<T>::new(WasmModule::getter(
+			"seal0", "storage_deposit_consumed", r
+		), vec![])?;
+		let origin = RawOrigin::Signed(instance.caller.clone());
+	}: call(origin, instance.addr, 0u32.into(), Weight::MAX, None, vec![])
+
+	#[pov_mode = Measured]
+	seal_storage_deposit_limit {
+		let r in 0 .. API_BENCHMARK_RUNS;
+		let instance = Contract::<T>::new(WasmModule::getter(
+			"seal0", "storage_deposit_limit", r
+		), vec![])?;
+		let origin = RawOrigin::Signed(instance.caller.clone());
+	}: call(origin, instance.addr, 0u32.into(), Weight::MAX, None, vec![])
+
 	#[pov_mode = Measured]
 	seal_account_reentrance_count {
 		let r in 0 .. API_BENCHMARK_RUNS;
@@ -2564,6 +3085,173 @@ benchmarks! {
 		let origin = RawOrigin::Signed(instance.caller.clone());
 	}: call(origin, instance.addr, 0u32.into(), Weight::MAX, None, vec![])
 
+	#[pov_mode = Measured]
+	seal_xcm_weigh_message {
+		use xcm::{latest::prelude::{ClearOrigin, Xcm}, VersionedXcm};
+
+		let r in 0 .. API_BENCHMARK_RUNS;
+		let message = VersionedXcm::<CallOf<T>>::from(Xcm(vec![ClearOrigin])).encode();
+		let message_len = message.len() as i32;
+		let code = WasmModule::<T>::from(ModuleDefinition {
+			memory: Some(ImportedMemory::max::<T>()),
+			imported_functions: vec![ImportedFunction {
+				module: "seal0",
+				name: "xcm_weigh_message",
+				params: vec![ValueType::I32, ValueType::I32, ValueType::I32, ValueType::I32],
+				return_type: Some(ValueType::I32),
+			}],
+			data_segments: vec![
+				DataSegment {
+					offset: 0,
+					value: 32u32.to_le_bytes().to_vec(),
+				},
+				DataSegment {
+					offset: 36,
+					value: message,
+				},
+			],
+			call_body: Some(body::repeated(r, &[
+				Instruction::I32Const(36), // msg_ptr
+				Instruction::I32Const(message_len), // msg_len
+				Instruction::I32Const(4), // out_ptr
+				Instruction::I32Const(0), // out_len_ptr
+				Instruction::Call(0),
+				Instruction::Drop,
+			])),
+			.. Default::default()
+		});
+		let instance = Contract::<T>::new(code, vec![])?;
+		let origin = RawOrigin::Signed(instance.caller.clone());
+	}: call(origin, instance.addr, 0u32.into(), Weight::MAX, None, vec![])
+
+	// The weight of weighing one instruction on top of `seal_xcm_weigh_message`.
+	#[pov_mode = Measured]
+	seal_xcm_weigh_message_per_instruction {
+		use xcm::{
+			latest::{prelude::{ClearOrigin, Xcm}, MAX_INSTRUCTIONS_TO_DECODE},
+			VersionedXcm,
+		};
+
+		let n in 0 .. MAX_INSTRUCTIONS_TO_DECODE as u32;
+		let message =
+			VersionedXcm::<CallOf<T>>::from(Xcm(vec![ClearOrigin; n as usize])).encode();
+		let message_len = message.len() as i32;
+		let code = WasmModule::<T>::from(ModuleDefinition {
+			memory: Some(ImportedMemory::max::<T>()),
+			imported_functions: vec![ImportedFunction {
+				module: "seal0",
+				name: "xcm_weigh_message",
+				params: vec![ValueType::I32, ValueType::I32, ValueType::I32, ValueType::I32],
+				return_type: Some(ValueType::I32),
+			}],
+			data_segments: vec![
+				DataSegment {
+					offset: 0,
+					value: 32u32.to_le_bytes().to_vec(),
+				},
+				DataSegment {
+					offset: 36,
+					value: message,
+				},
+			],
+			call_body: Some(body::plain(vec![
+				Instruction::I32Const(36), // msg_ptr
+				Instruction::I32Const(message_len), // msg_len
+				Instruction::I32Const(4), // out_ptr
+				Instruction::I32Const(0), // out_len_ptr
+				Instruction::Call(0),
+				Instruction::Drop,
+				Instruction::End,
+			])),
+			.. Default::default()
+		});
+		let instance = Contract::<T>::new(code, vec![])?;
+		let origin = RawOrigin::Signed(instance.caller.clone());
+	}: call(origin, instance.addr, 0u32.into(), Weight::MAX, None, vec![])
+
+	// Only the owner bookkeeping is measured, the weight of registering the query is charged
+	// separately.
+	#[pov_mode = Measured]
+	seal_xcm_query {
+		use xcm::VersionedLocation;
+
+		let r in 0 .. API_BENCHMARK_RUNS;
+		let timeout = (frame_system::Pallet::<T>::block_number() + 10u32.into()).encode();
+		let timeout_len = timeout.len() as u32;
+		let match_querier = VersionedLocation::from(xcm::latest::Location::here()).encode();
+		let match_querier_len = match_querier.len() as u32;
+		let code = WasmModule::<T>::from(ModuleDefinition {
+			memory: Some(ImportedMemory::max::<T>()),
+			imported_functions: vec![ImportedFunction {
+				module: "seal0",
+				name: "xcm_query",
+				params: vec![ValueType::I32, ValueType::I32, ValueType::I32],
+				return_type: Some(ValueType::I32),
+			}],
+			data_segments: vec![
+				DataSegment {
+					offset: 0,
+					value: timeout,
+				},
+				DataSegment {
+					offset: timeout_len,
+					value: match_querier,
+				},
+			],
+			call_body: Some(body::repeated(r, &[
+				Instruction::I32Const(0), // timeout_ptr
+				Instruction::I32Const(timeout_len as i32), // match_querier_ptr
+				Instruction::I32Const((timeout_len + match_querier_len) as i32), // output_ptr
+				Instruction::Call(0),
+				Instruction::Drop,
+			])),
+			.. Default::default()
+		});
+		let instance = Contract::<T>::new(code, vec![])?;
+		let origin = RawOrigin::Signed(instance.caller.clone());
+	}: call(origin, instance.addr, 0u32.into(), Weight::MAX, None, vec![])
+
+	// Every call takes the response of a different query owned by the contract.
+	#[pov_mode = Measured]
+	seal_xcm_take_response {
+		let r in 0 .. API_BENCHMARK_RUNS;
+		let query_ids = (0 .. r as u64).map(XcmQueryIdOf::<T>::from).collect::<Vec<_>>();
+		let query_id_len = XcmQueryIdOf::<T>::from(0u64).encoded_size() as u32;
+		let query_id_bytes = query_ids.iter().flat_map(Encode::encode).collect::<Vec<_>>();
+		let query_id_bytes_len = query_id_bytes.len() as u32;
+		let code = WasmModule::<T>::from(ModuleDefinition {
+			memory: Some(ImportedMemory::max::<T>()),
+			imported_functions: vec![ImportedFunction {
+				module: "seal0",
+				name: "xcm_take_response",
+				params: vec![ValueType::I32, ValueType::I32, ValueType::I32],
+				return_type: None,
+			}],
+			data_segments: vec![
+				DataSegment {
+					offset: 0,
+					value: query_id_bytes,
+				},
+				DataSegment {
+					offset: query_id_bytes_len,
+					value: T::Schedule::get().limits.payload_len.to_le_bytes().into(),
+				},
+			],
+			call_body: Some(body::repeated_dyn(r, vec![
+				Counter(0, query_id_len), // query_id_ptr
+				Regular(Instruction::I32Const((query_id_bytes_len + 4) as i32)), // out_ptr
+				Regular(Instruction::I32Const(query_id_bytes_len as i32)), // out_len_ptr
+				Regular(Instruction::Call(0)),
+			])),
+			.. Default::default()
+		});
+		let instance = Contract::<T>::new(code, vec![])?;
+		for query_id in query_ids {
+			XcmQueryOwner::<T>::insert(query_id, &instance.account_id);
+		}
+		let origin = RawOrigin::Signed(instance.caller.clone());
+	}: call(origin, instance.addr, 0u32.into(), Weight::MAX, None, vec![])
+
 	// We load `i64` values from random linear memory locations and store the loaded
 	// values back into yet another random linear memory location.
 	// The random addresses are uniformely distributed across the entire span of the linear memory.
@@ -2609,6 +3297,57 @@ benchmarks! {
 		sbox.invoke();
 	}
 
+	// The following benchmarks execute contracts resembling typical workloads. They are not used
+	// to generate weights. Instead, their results are meant to be compared against the weight
+	// charged for the same contracts according to the `Schedule` to spot host functions whose
+	// isolated benchmarks are not representative. See the `workload` module.
+	//
+	// `r`: Number of storage items written and read back.
+	#[extra]
+	#[pov_mode = Measured]
+	workload_storage_heavy {
+		let r in 0 .. API_BENCHMARK_RUNS / 8;
+		let workload = Workload::StorageHeavy { iterations: r, value_len: 128 };
+		let instance = Contract::<T>::new(workload.module(), vec![])?;
+		let origin = RawOrigin::Signed(instance.caller.clone());
+	}: call(origin, instance.addr, 0u32.into(), Weight::MAX, None, vec![])
+
+	// `r`: Number of contracts called by the root contract. Each of them calls a storage heavy
+	// leaf contract twice, forming a call tree of depth three.
+	#[extra]
+	#[pov_mode = Measured]
+	workload_call_tree_heavy {
+		let r in 0 .. API_BENCHMARK_RUNS / 32;
+		let leaf = Contract::<T>::with_index(
+			1,
+			Workload::StorageHeavy { iterations: 4, value_len: 128 }.module(),
+			vec![],
+		)?;
+		let branch = Workload::CallTreeHeavy {
+			callees: vec![leaf.account_id.encode()],
+			iterations: 2,
+		}.module();
+		let branches = (0..r)
+			.map(|i| Contract::<T>::with_index(i + 2, branch.clone(), vec![]))
+			.collect::<Result<Vec<_>, _>>()?;
+		let workload = Workload::CallTreeHeavy {
+			callees: branches.iter().map(|c| c.account_id.encode()).collect(),
+			iterations: 1,
+		};
+		let instance = Contract::<T>::new(workload.module(), vec![])?;
+		let origin = RawOrigin::Signed(instance.caller.clone());
+	}: call(origin, instance.addr, 0u32.into(), Weight::MAX, None, vec![])
+
+	// `r`: Number of rounds hashing a 256 byte input with every hash function.
+	#[extra]
+	#[pov_mode = Measured]
+	workload_crypto_heavy {
+		let r in 0 .. API_BENCHMARK_RUNS / 8;
+		let workload = Workload::CryptoHeavy { iterations: r, input_len: 256 };
+		let instance = Contract::<T>::new(workload.module(), vec![])?;
+		let origin = RawOrigin::Signed(instance.caller.clone());
+	}: call(origin, instance.addr, 0u32.into(), Weight::MAX, None, vec![])
+
 	// This is no benchmark. It merely exist to have an easy way to pretty print the currently
 	// configured `Schedule` during benchmark development. Check the README on how to print this.
 	#[extra]
@@ -0,0 +1,257 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Generators for contracts that resemble representative real world workloads.
+//!
+//! The API benchmarks measure every host function in isolation by calling it in a tight loop.
+//! Real contracts interleave host function calls with plain arithmetic and memory accesses. The
+//! contracts generated here mix both in a way that is typical for the given [`Workload`]. They
+//! are used by the `workload_*` benchmarks which can be run to compare the measured execution
+//! time against the weight charged according to the `Schedule`:
+//!
+//! ```sh
+//! cargo run --features runtime-benchmarks --bin substrate-node -- benchmark pallet --extra \
+//! 	-p pallet_contracts -e 'workload_*'
+//! ```
+//!
+//! The generator is public so that chains can build the same contracts for their own tooling.
+
+use super::code::{body, DataSegment, ImportedFunction, ImportedMemory, ModuleDefinition};
+use crate::{BalanceOf, CodeHash, Config, SENTINEL};
+use codec::{Encode, MaxEncodedLen};
+use sp_std::prelude::*;
+use wasm_instrument::parity_wasm::elements::{Instruction, ValueType};
+
+pub use super::code::WasmModule;
+
+/// Length of the storage keys used by [`Workload::StorageHeavy`].
+const KEY_LEN: u32 = 32;
+
+/// Length of the output of all hash functions used by [`Workload::CryptoHeavy`].
+const HASH_LEN: u32 = 32;
+
+/// A contract workload that can be turned into Wasm code.
+#[derive(Clone)]
+pub enum Workload {
+	/// Writes `iterations` distinct storage items of `value_len` bytes and reads each one back.
+	StorageHeavy { iterations: u32, value_len: u32 },
+	/// Calls every contract in `callees` `iterations` times without transferring any value.
+	///
+	/// `callees` are the SCALE encoded account ids of contracts that already exist.
+	CallTreeHeavy { callees: Vec<Vec<u8>>, iterations: u32 },
+	/// Hashes `input_len` bytes with sha2, keccak and blake2 `iterations` times.
+	CryptoHeavy { iterations: u32, input_len: u32 },
+}
+
+impl Workload {
+	/// The Wasm code and code hash of a contract executing this workload in its `call` export.
+	pub fn code<T: Config>(&self) -> (Vec<u8>, CodeHash<T>) {
+		let module = self.module::<T>();
+		(module.code, module.hash)
+	}
+
+	/// Same as [`Self::code`] but returns the module used by the benchmarks.
+	pub fn module<T: Config>(&self) -> WasmModule<T> {
+		match self {
+			Self::StorageHeavy { iterations, value_len } =>
+				storage_heavy::<T>(*iterations, *value_len),
+			Self::CallTreeHeavy { callees, iterations } =>
+				call_tree_heavy::<T>(callees, *iterations),
+			Self::CryptoHeavy { iterations, input_len } =>
+				crypto_heavy::<T>(*iterations, *input_len),
+		}
+		.into()
+	}
+}
+
+/// Some arithmetic that resembles the bookkeeping a contract does around each host function call.
+fn bookkeeping(seed: u32) -> [Instruction; 6] {
+	[
+		Instruction::I64Const(seed as i64),
+		Instruction::I64Const(0x5bd1_e995),
+		Instruction::I64Mul,
+		Instruction::I64Const(47),
+		Instruction::I64ShrU,
+		Instruction::Drop,
+	]
+}
+
+fn storage_heavy<T: Config>(iterations: u32, value_len: u32) -> ModuleDefinition {
+	// Memory layout: | out_len | keys | value | out |
+	let out_len_ptr = 0;
+	let keys_ptr = 4;
+	let value_ptr = keys_ptr + iterations * KEY_LEN;
+	let out_ptr = value_ptr + value_len;
+
+	let keys = (0..iterations)
+		.flat_map(|i| sp_io::hashing::blake2_256(&i.encode()))
+		.collect::<Vec<_>>();
+
+	let mut instructions = Vec::new();
+	for i in 0..iterations {
+		let key_ptr = (keys_ptr + i * KEY_LEN) as i32;
+		instructions.extend(bookkeeping(i));
+		instructions.extend([
+			Instruction::I32Const(key_ptr),
+			Instruction::I32Const(KEY_LEN as i32),
+			Instruction::I32Const(value_ptr as i32),
+			Instruction::I32Const(value_len as i32),
+			Instruction::Call(0),
+			Instruction::Drop,
+			Instruction::I32Const(key_ptr),
+			Instruction::I32Const(KEY_LEN as i32),
+			Instruction::I32Const(out_ptr as i32),
+			Instruction::I32Const(out_len_ptr as i32),
+			Instruction::Call(1),
+			Instruction::Drop,
+		]);
+	}
+	instructions.push(Instruction::End);
+
+	ModuleDefinition {
+		memory: Some(ImportedMemory::max::<T>()),
+		imported_functions: vec![
+			ImportedFunction {
+				module: "seal2",
+				name: "set_storage",
+				params: vec![ValueType::I32, ValueType::I32, ValueType::I32, ValueType::I32],
+				return_type: Some(ValueType::I32),
+			},
+			ImportedFunction {
+				module: "seal1",
+				name: "get_storage",
+				params: vec![ValueType::I32, ValueType::I32, ValueType::I32, ValueType::I32],
+				return_type: Some(ValueType::I32),
+			},
+		],
+		data_segments: vec![
+			// `get_storage` overwrites the length with the actual value length. Since all values
+			// have the same length it suffices to initialize it once.
+			DataSegment { offset: out_len_ptr, value: value_len.to_le_bytes().to_vec() },
+			DataSegment { offset: keys_ptr, value: keys },
+			DataSegment { offset: value_ptr, value: vec![42u8; value_len as usize] },
+		],
+		call_body: Some(body::plain(instructions)),
+		..Default::default()
+	}
+}
+
+fn call_tree_heavy<T: Config>(callees: &[Vec<u8>], iterations: u32) -> ModuleDefinition {
+	// Memory layout: | value | callees |
+	let value = BalanceOf::<T>::from(0u32).encode();
+	let value_ptr = 0;
+	let callees_ptr = BalanceOf::<T>::max_encoded_len() as u32;
+
+	let mut instructions = Vec::new();
+	let mut callee_ptr = callees_ptr;
+	for callee in callees {
+		for i in 0..iterations {
+			instructions.extend(bookkeeping(i));
+			instructions.extend([
+				Instruction::I32Const(0),                 // flags
+				Instruction::I32Const(callee_ptr as i32), // callee_ptr
+				Instruction::I64Const(0),                 // ref_time weight
+				Instruction::I64Const(0),                 // proof_size weight
+				Instruction::I32Const(SENTINEL as i32),   // deposit_limit_ptr
+				Instruction::I32Const(value_ptr as i32),  // value_ptr
+				Instruction::I32Const(0),                 // input_data_ptr
+				Instruction::I32Const(0),                 // input_data_len
+				Instruction::I32Const(SENTINEL as i32),   // output_ptr
+				Instruction::I32Const(0),                 // output_len_ptr
+				Instruction::Call(0),
+				Instruction::Drop,
+			]);
+		}
+		callee_ptr += callee.len() as u32;
+	}
+	instructions.push(Instruction::End);
+
+	ModuleDefinition {
+		memory: Some(ImportedMemory::max::<T>()),
+		imported_functions: vec![ImportedFunction {
+			module: "seal2",
+			name: "call",
+			params: vec![
+				ValueType::I32,
+				ValueType::I32,
+				ValueType::I64,
+				ValueType::I64,
+				ValueType::I32,
+				ValueType::I32,
+				ValueType::I32,
+				ValueType::I32,
+				ValueType::I32,
+				ValueType::I32,
+			],
+			return_type: Some(ValueType::I32),
+		}],
+		data_segments: vec![
+			DataSegment { offset: value_ptr, value },
+			DataSegment { offset: callees_ptr, value: callees.concat() },
+		],
+		call_body: Some(body::plain(instructions)),
+		..Default::default()
+	}
+}
+
+fn crypto_heavy<T: Config>(iterations: u32, input_len: u32) -> ModuleDefinition {
+	// Memory layout: | input | out |
+	// The input region is at least 8 bytes so that the hash chain below stays within it.
+	let input_ptr = 0;
+	let out_ptr = input_len.max(8);
+	let hashers = ["seal_hash_sha2_256", "seal_hash_keccak_256", "seal_hash_blake2_256"];
+
+	let mut instructions = Vec::new();
+	for i in 0..iterations {
+		instructions.extend(bookkeeping(i));
+		for func in 0..hashers.len() as u32 {
+			instructions.extend([
+				Instruction::I32Const(input_ptr as i32),
+				Instruction::I32Const(input_len as i32),
+				Instruction::I32Const(out_ptr as i32),
+				Instruction::Call(func),
+			]);
+		}
+		// Feed the output into the next round like a hash chain would.
+		instructions.extend([
+			Instruction::I32Const(input_ptr as i32),
+			Instruction::I32Const(out_ptr as i32),
+			Instruction::I64Load(3, 0),
+			Instruction::I64Store(3, 0),
+		]);
+	}
+	instructions.push(Instruction::End);
+
+	ModuleDefinition {
+		memory: Some(ImportedMemory::max::<T>()),
+		imported_functions: hashers
+			.into_iter()
+			.map(|name| ImportedFunction {
+				module: "seal0",
+				name,
+				params: vec![ValueType::I32, ValueType::I32, ValueType::I32],
+				return_type: None,
+			})
+			.collect(),
+		data_segments: vec![
+			DataSegment { offset: input_ptr, value: vec![42u8; out_ptr as usize] },
+			DataSegment { offset: out_ptr, value: vec![0u8; HASH_LEN as usize] },
+		],
+		call_body: Some(body::plain(instructions)),
+		..Default::default()
+	}
+}
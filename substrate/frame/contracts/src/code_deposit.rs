@@ -0,0 +1,74 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Size dependent pricing of the deposit for storing code.
+
+use frame_support::traits::Get;
+use sp_runtime::traits::{AtLeast32BitUnsigned, Convert};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+/// Prices code by its size in KiB, every KiB costing the price of the tier it falls into.
+///
+/// `Tiers` are `(from_kib, price_per_kib)` pairs sorted by `from_kib`. Every KiB from `from_kib`
+/// up to the `from_kib` of the next tier costs `price_per_kib`, KiB below the first tier are free.
+/// A partial KiB counts as a full one.
+///
+/// Meant to be used as [`Config::CodeDepositCurve`](crate::Config::CodeDepositCurve), making
+/// giant blobs increasingly expensive, e.g. `vec![(64, price), (96, 4 * price)]`.
+pub struct TieredCodeDeposit<Tiers, Balance>(PhantomData<(Tiers, Balance)>);
+
+impl<Tiers, Balance> Convert<u32, Balance> for TieredCodeDeposit<Tiers, Balance>
+where
+	Tiers: Get<Vec<(u32, Balance)>>,
+	Balance: AtLeast32BitUnsigned + Copy,
+{
+	fn convert(code_len: u32) -> Balance {
+		let kib = code_len.saturating_add(1023) / 1024;
+		let tiers = Tiers::get();
+		let mut deposit = Balance::zero();
+		for (i, (from, price)) in tiers.iter().enumerate() {
+			let to = tiers.get(i + 1).map_or(kib, |(next, _)| kib.min(*next));
+			if to <= *from {
+				break
+			}
+			deposit = deposit.saturating_add(price.saturating_mul((to - from).into()));
+		}
+		deposit
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use frame_support::parameter_types;
+
+	parameter_types! {
+		pub Tiers: Vec<(u32, u64)> = vec![(0, 1), (4, 10), (16, 100)];
+	}
+
+	type Deposit = TieredCodeDeposit<Tiers, u64>;
+
+	#[test]
+	fn kib_are_priced_by_tier() {
+		assert_eq!(Deposit::convert(0), 0);
+		assert_eq!(Deposit::convert(1), 1);
+		assert_eq!(Deposit::convert(4 * 1024), 4);
+		assert_eq!(Deposit::convert(4 * 1024 + 1), 4 + 10);
+		assert_eq!(Deposit::convert(20 * 1024), 4 + 12 * 10 + 4 * 100);
+		assert_eq!(Deposit::convert(u32::MAX), 4 + 12 * 10 + (4 * 1024 * 1024 - 16) * 100);
+	}
+}
@@ -0,0 +1,72 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Automatic removal of code that no contract uses.
+//!
+//! When [`Config::CodePruningDelay`] is set, the block in which a code is uploaded is recorded in
+//! [`CodeUploadedAt`]. The `on_idle` hook sweeps over these codes with the weight left in the
+//! block, resuming where the previous sweep stopped, and removes the codes that were uploaded more
+//! than `CodePruningDelay` blocks ago and are not referenced by any contract, including through
+//! delegate dependencies and pinned code hashes. The upload deposit is refunded to the uploader
+//! and a [`Event::CodePruned`](crate::Event::CodePruned) is emitted.
+//!
+//! Codes uploaded while the pruning is disabled are never pruned.
+
+use crate::{wasm::WasmBlob, weights::WeightInfo, CodePruningCursor, CodeUploadedAt, Config};
+use frame_support::weights::Weight;
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::traits::Saturating;
+
+/// Prune the unreferenced codes uploaded more than `delay` blocks before `now`, within the
+/// supplied weight limit.
+///
+/// It returns the amount of weight used for that task.
+pub fn process_code_pruning_batch<T: Config>(
+	now: BlockNumberFor<T>,
+	delay: BlockNumberFor<T>,
+	weight_limit: Weight,
+) -> Weight {
+	let base_weight = T::WeightInfo::on_process_code_pruning_batch();
+	let weight_per_code = T::WeightInfo::on_idle_per_pruned_code();
+	if weight_limit.any_lt(base_weight.saturating_add(weight_per_code)) {
+		return Weight::zero()
+	}
+
+	let mut codes = match CodePruningCursor::<T>::get() {
+		Some(cursor) => CodeUploadedAt::<T>::iter_from(CodeUploadedAt::<T>::hashed_key_for(cursor)),
+		None => CodeUploadedAt::<T>::iter(),
+	};
+	let mut weight_used = base_weight;
+	let mut cursor = None;
+	while !weight_limit.any_lt(weight_used.saturating_add(weight_per_code)) {
+		let Some((code_hash, uploaded_at)) = codes.next() else {
+			// The sweep is done, the next one starts over.
+			CodePruningCursor::<T>::kill();
+			return weight_used
+		};
+		weight_used.saturating_accrue(weight_per_code);
+		if now.saturating_sub(uploaded_at) > delay {
+			WasmBlob::<T>::prune(code_hash);
+		}
+		cursor = Some(code_hash);
+	}
+
+	if let Some(cursor) = cursor {
+		CodePruningCursor::<T>::put(cursor);
+	}
+	weight_used
+}
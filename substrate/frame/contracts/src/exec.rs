@@ -16,14 +16,18 @@
 // limitations under the License.
 
 use crate::{
+	address::AddressKind,
 	debug::{CallInterceptor, CallSpan, Tracing},
 	gas::GasMeter,
-	primitives::{ExecReturnValue, StorageDeposit},
+	primitives::{ExecReturnValue, RandomnessOutput, StorageDeposit},
+	scheduled_call::ScheduledCall,
 	storage::{self, meter::Diff, WriteOutcome},
 	BalanceOf, CodeHash, CodeInfo, CodeInfoOf, Config, ContractInfo, ContractInfoOf,
-	DebugBufferVec, Determinism, Error, Event, Nonce, Origin, Pallet as Contracts, Schedule,
+	DebugBufferVec, Determinism, Error, Event, EventTopicRegistry, ImmutableData, Nonce, Origin,
+	Pallet as Contracts, PinnedCodeHashes, RegisteredTopics, Schedule, XcmQueryIdOf, XcmQueryOwner,
 	LOG_TARGET,
 };
+use codec::Encode;
 use frame_support::{
 	crypto::ecdsa::ECDSAExt,
 	dispatch::{DispatchResult, DispatchResultWithPostInfo},
@@ -37,7 +41,7 @@ use frame_support::{
 	weights::Weight,
 	Blake2_128Concat, BoundedVec, StorageHasher,
 };
-use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use frame_system::{pallet_prelude::BlockNumberFor, with_dispatch_frame, DispatchFrame, RawOrigin};
 use smallvec::{Array, SmallVec};
 use sp_core::{
 	ecdsa::Public as ECDSAPublic,
@@ -49,7 +53,9 @@ use sp_runtime::{
 	traits::{Convert, Dispatchable, Hash, Zero},
 	DispatchError,
 };
-use sp_std::{fmt::Debug, marker::PhantomData, mem, prelude::*, vec::Vec};
+use sp_std::{
+	collections::btree_map::BTreeMap, fmt::Debug, marker::PhantomData, mem, prelude::*, vec::Vec,
+};
 
 pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 pub type MomentOf<T> = <<T as Config>::Time as Time>::Moment;
@@ -140,6 +146,9 @@ pub trait Ext: sealing::Sealed {
 
 	/// Call (possibly transferring some amount of funds) into the specified account.
 	///
+	/// A `read_only` call denies any state change of the callee. Its result is served from
+	/// and added to the call cache of the transaction if `use_cache` is set.
+	///
 	/// Returns the code size of the called contract.
 	fn call(
 		&mut self,
@@ -149,6 +158,8 @@ pub trait Ext: sealing::Sealed {
 		value: BalanceOf<Self::T>,
 		input_data: Vec<u8>,
 		allows_reentry: bool,
+		read_only: bool,
+		use_cache: bool,
 	) -> Result<ExecReturnValue, ExecError>;
 
 	/// Execute code in the current frame.
@@ -187,6 +198,16 @@ pub trait Ext: sealing::Sealed {
 	/// Transfer some amount of funds into the specified account.
 	fn transfer(&mut self, to: &AccountIdOf<Self::T>, value: BalanceOf<Self::T>) -> DispatchResult;
 
+	/// Returns `true` if the current frame was called in read-only mode, directly or by one of
+	/// its callers.
+	fn is_read_only(&self) -> bool;
+
+	/// Prepare a state change that is not performed through this interface.
+	///
+	/// Fails with [`Error::StateChangeDenied`] if the current frame is read-only. Otherwise the
+	/// cached results of read-only calls are dropped as the change could make them stale.
+	fn ensure_writable(&mut self) -> DispatchResult;
+
 	/// Returns the storage entry of the executing account by the given `key`.
 	///
 	/// Returns `None` if the `key` wasn't previously set by `set_storage` or
@@ -251,11 +272,30 @@ pub trait Ext: sealing::Sealed {
 	/// Returns a random number for the current block with the given subject.
 	fn random(&self, subject: &[u8]) -> (SeedOf<Self::T>, BlockNumberFor<Self::T>);
 
+	/// Returns randomness from [`Config::RandomnessSource`] for the current contract and the
+	/// given domain.
+	///
+	/// Different contracts never get the same seed, whatever domain they ask for.
+	fn randomness(
+		&self,
+		domain: &[u8],
+	) -> RandomnessOutput<SeedOf<Self::T>, BlockNumberFor<Self::T>>;
+
 	/// Deposit an event with the given topics.
 	///
 	/// There should not be any duplicates in `topics`.
 	fn deposit_event(&mut self, topics: Vec<TopicOf<Self::T>>, data: Vec<u8>);
 
+	/// Register `topic` in the topic registry of the current contract and return its hash.
+	///
+	/// A deposit is charged for every newly registered topic. Once the registry holds
+	/// [`Config::MaxRegisteredTopics`] topics, the hash is returned without registering it.
+	///
+	/// # Errors
+	///
+	/// - [`Error::<T>::EventTopicTooLong`]
+	fn register_event_topic(&mut self, topic: Vec<u8>) -> Result<TopicOf<Self::T>, DispatchError>;
+
 	/// Returns the current block number.
 	fn block_number(&self) -> BlockNumberFor<Self::T>;
 
@@ -277,6 +317,16 @@ pub trait Ext: sealing::Sealed {
 	/// Charges `diff` from the meter.
 	fn charge_storage(&mut self, diff: &Diff);
 
+	/// Returns the net storage deposit charged or refunded so far by the current frame, including
+	/// the calls it made that already returned.
+	fn storage_deposit_consumed(&mut self) -> StorageDeposit<BalanceOf<Self::T>>;
+
+	/// Returns the storage deposit limit of the current frame.
+	///
+	/// The frame fails with [`Error::<T>::StorageDepositLimitExhausted`] once
+	/// [`Self::storage_deposit_consumed`] charges more than this.
+	fn storage_deposit_limit(&self) -> BalanceOf<Self::T>;
+
 	/// Append a string to the debug buffer.
 	///
 	/// It is added as-is without any additional new line.
@@ -318,6 +368,21 @@ pub trait Ext: sealing::Sealed {
 	/// A value of 0 means it does not exist on the call stack.
 	fn account_reentrance_count(&self, account_id: &AccountIdOf<Self::T>) -> u32;
 
+	/// Enables or disables the reentrancy guard of the current contract.
+	///
+	/// While enabled, any call that would reenter the contract fails with
+	/// [`Error::<T>::ReentranceDenied`], independent of the flags passed by the caller.
+	fn set_reentrancy_guard(&mut self, enabled: bool);
+
+	/// Returns the immutable data of the current contract.
+	fn immutable_data(&mut self) -> Vec<u8>;
+
+	/// Sets the immutable data of the contract being instantiated and charges its deposit.
+	///
+	/// Fails with [`Error::<T>::InvalidImmutableAccess`] unless called by the constructor of the
+	/// contract for the first time.
+	fn set_immutable_data(&mut self, data: Vec<u8>) -> DispatchResult;
+
 	/// Returns a nonce that is incremented for every instantiated contract.
 	fn nonce(&mut self) -> u64;
 
@@ -365,6 +430,70 @@ pub trait Ext: sealing::Sealed {
 		&mut self,
 		code_hash: &CodeHash<Self::T>,
 	) -> Result<(), DispatchError>;
+
+	/// Pins a code hash the contract depends on, e.g. the code of a contract it calls.
+	///
+	/// A pinned code can not be removed until it is unpinned or the contract terminates. Like
+	/// [`Self::lock_delegate_dependency`] this increases the reference count of the code hash
+	/// and charges a fraction (see [`Config::CodeHashLockupDepositPercent`]) of the code deposit.
+	///
+	/// # Errors
+	///
+	/// - [`Error::<T>::CodeNotFound`]
+	/// - [`Error::<T>::MaxPinnedCodeHashesReached`]
+	/// - [`Error::<T>::CodeHashAlreadyPinned`]
+	fn pin_code_hash(&mut self, code_hash: CodeHash<Self::T>) -> Result<(), DispatchError>;
+
+	/// Unpins a code hash previously pinned by the contract.
+	///
+	/// This is the counterpart of [`Self::pin_code_hash`]. It decreases the reference count and
+	/// refunds the deposit that was charged by [`Self::pin_code_hash`].
+	///
+	/// # Errors
+	///
+	/// - [`Error::<T>::CodeHashNotPinned`]
+	fn unpin_code_hash(&mut self, code_hash: &CodeHash<Self::T>) -> Result<(), DispatchError>;
+
+	/// Schedules a call of the current contract to itself at the beginning of block `when`.
+	///
+	/// The storage deposit for the call is charged like any other storage of the contract. The
+	/// fee for `gas_limit` is held from the contract's balance until the call is executed or
+	/// cancelled. Returns the index needed by [`Self::cancel_scheduled_call`].
+	///
+	/// # Errors
+	///
+	/// - [`Error::<T>::InvalidScheduledCallBlock`]
+	/// - [`Error::<T>::TooManyScheduledCalls`]
+	/// - [`Error::<T>::ValueTooLarge`]
+	/// - [`Error::<T>::ScheduledCallFeeNotEnoughFunds`]
+	fn schedule_call(
+		&mut self,
+		when: BlockNumberFor<Self::T>,
+		input_data: Vec<u8>,
+		gas_limit: Weight,
+		storage_deposit_limit: Option<BalanceOf<Self::T>>,
+	) -> Result<u32, DispatchError>;
+
+	/// Cancels a call that was scheduled by the current contract with [`Self::schedule_call`],
+	/// refunds its storage deposit and releases its fee.
+	///
+	/// # Errors
+	///
+	/// - [`Error::<T>::ScheduledCallNotFound`]
+	fn cancel_scheduled_call(
+		&mut self,
+		when: BlockNumberFor<Self::T>,
+		index: u32,
+	) -> Result<(), DispatchError>;
+
+	/// Records the current contract as the owner of the XCM query `query_id`.
+	fn register_xcm_query(&mut self, query_id: XcmQueryIdOf<Self::T>) -> DispatchResult;
+
+	/// Returns whether the XCM query `query_id` was registered by the current contract.
+	fn is_xcm_query_owner(&self, query_id: XcmQueryIdOf<Self::T>) -> bool;
+
+	/// Forgets the owner of the XCM query `query_id` after its response was taken.
+	fn forget_xcm_query(&mut self, query_id: XcmQueryIdOf<Self::T>) -> DispatchResult;
 }
 
 /// Describes the different functions that can be exported by an [`Executable`].
@@ -470,6 +599,10 @@ pub struct Stack<'a, T: Config, E> {
 	debug_message: Option<&'a mut DebugBufferVec<T>>,
 	/// The determinism requirement of this call stack.
 	determinism: Determinism,
+	/// The results of read-only calls, keyed by the hash of caller, callee and input.
+	///
+	/// Holds at most [`Config::CallCacheSize`] entries and is cleared on every state change.
+	call_cache: BTreeMap<[u8; 32], ExecReturnValue>,
 	/// No executable is held by the struct but influences its behaviour.
 	_phantom: PhantomData<E>,
 }
@@ -498,6 +631,8 @@ pub struct Frame<T: Config> {
 	nested_storage: storage::meter::NestedMeter<T>,
 	/// If `false` the contract enabled its defense against reentrance attacks.
 	allows_reentry: bool,
+	/// If `true` the frame or one of its callers was called in read-only mode.
+	read_only: bool,
 	/// The caller of the currently executing frame which was spawned by `delegate_call`.
 	delegate_caller: Option<Origin<T>>,
 }
@@ -535,6 +670,8 @@ enum FrameArgs<'a, T: Config, E> {
 		salt: &'a [u8],
 		/// The input data is used in the contract address deriviation of the new contract.
 		input_data: &'a [u8],
+		/// Selects how the address of the new contract is derived.
+		address_kind: AddressKind,
 	},
 }
 
@@ -665,6 +802,9 @@ where
 	/// `debug_message` should only ever be set to `Some` when executing as an RPC because
 	/// it adds allocations and could be abused to drive the runtime into an OOM panic.
 	///
+	/// A `read_only` call denies any state change, including the transfer of `value`, with
+	/// [`Error::StateChangeDenied`].
+	///
 	/// # Return Value
 	///
 	/// Result<(ExecReturnValue, CodeSize), (ExecError, CodeSize)>
@@ -678,7 +818,11 @@ where
 		input_data: Vec<u8>,
 		debug_message: Option<&'a mut DebugBufferVec<T>>,
 		determinism: Determinism,
+		read_only: bool,
 	) -> Result<ExecReturnValue, ExecError> {
+		if read_only && !value.is_zero() {
+			return Err(Error::<T>::StateChangeDenied.into())
+		}
 		let (mut stack, executable) = Self::new(
 			FrameArgs::Call { dest, cached_info: None, delegated_call: None },
 			origin,
@@ -688,6 +832,7 @@ where
 			value,
 			debug_message,
 			determinism,
+			read_only,
 		)?;
 		stack.run(executable, input_data)
 	}
@@ -711,6 +856,7 @@ where
 		value: BalanceOf<T>,
 		input_data: Vec<u8>,
 		salt: &[u8],
+		address_kind: AddressKind,
 		debug_message: Option<&'a mut DebugBufferVec<T>>,
 	) -> Result<(T::AccountId, ExecReturnValue), ExecError> {
 		let (mut stack, executable) = Self::new(
@@ -720,20 +866,35 @@ where
 				executable,
 				salt,
 				input_data: input_data.as_ref(),
+				address_kind,
 			},
-			Origin::from_account_id(origin),
+			Origin::from_account_id(origin.clone()),
 			gas_meter,
 			storage_meter,
 			schedule,
 			value,
 			debug_message,
 			Determinism::Enforced,
+			false,
 		)?;
 		let account_id = stack.top_frame().account_id.clone();
-		stack.run(executable, input_data).map(|ret| (account_id, ret))
+		let output = stack.run(executable, input_data)?;
+		if let (AddressKind::Namespaced(namespace), false) = (address_kind, output.did_revert()) {
+			Contracts::<T>::deposit_event(
+				vec![T::Hashing::hash_of(&origin), T::Hashing::hash_of(&account_id)],
+				Event::InstantiatedNamespaced {
+					deployer: origin,
+					contract: account_id.clone(),
+					namespace,
+				},
+			);
+		}
+		Ok((account_id, output))
 	}
 
 	/// Create a new call stack.
+	///
+	/// The first frame is read-only if `read_only` is set.
 	fn new(
 		args: FrameArgs<T, E>,
 		origin: Origin<T>,
@@ -743,6 +904,7 @@ where
 		value: BalanceOf<T>,
 		debug_message: Option<&'a mut DebugBufferVec<T>>,
 		determinism: Determinism,
+		read_only: bool,
 	) -> Result<(Self, E), ExecError> {
 		let (first_frame, executable, nonce) = Self::new_frame(
 			args,
@@ -752,6 +914,7 @@ where
 			storage_meter,
 			BalanceOf::<T>::zero(),
 			determinism,
+			read_only,
 		)?;
 
 		let stack = Self {
@@ -766,6 +929,7 @@ where
 			frames: Default::default(),
 			debug_message,
 			determinism,
+			call_cache: Default::default(),
 			_phantom: Default::default(),
 		};
 
@@ -784,6 +948,7 @@ where
 		storage_meter: &mut storage::meter::GenericMeter<T, S>,
 		deposit_limit: BalanceOf<T>,
 		determinism: Determinism,
+		read_only: bool,
 	) -> Result<(Frame<T>, E, Option<u64>), ExecError> {
 		let (account_id, contract_info, executable, delegate_caller, entry_point, nonce) =
 			match frame_args {
@@ -803,13 +968,37 @@ where
 
 					(dest, contract, executable, delegate_caller, ExportedFunction::Call, None)
 				},
-				FrameArgs::Instantiate { sender, nonce, executable, salt, input_data } => {
-					let account_id = Contracts::<T>::contract_address(
-						&sender,
-						&executable.code_hash(),
-						input_data,
-						salt,
-					);
+				FrameArgs::Instantiate {
+					sender,
+					nonce,
+					executable,
+					salt,
+					input_data,
+					address_kind,
+				} => {
+					let account_id = match address_kind {
+						AddressKind::Deployer => Contracts::<T>::contract_address(
+							&sender,
+							&executable.code_hash(),
+							input_data,
+							salt,
+						),
+						AddressKind::Namespaced(namespace) => {
+							let account_id = Contracts::<T>::namespaced_contract_address(
+								&namespace,
+								&executable.code_hash(),
+								input_data,
+								salt,
+							);
+							// Anyone can derive the address, so a collision is reported
+							// explicitly rather than as a duplicate instantiation.
+							ensure!(
+								!<ContractInfoOf<T>>::contains_key(&account_id),
+								Error::<T>::NamespacedAddressTaken
+							);
+							account_id
+						},
+					};
 					let contract = ContractInfo::new(&account_id, nonce, *executable.code_hash())?;
 					(
 						account_id,
@@ -840,18 +1029,22 @@ where
 			nested_gas: gas_meter.nested(gas_limit),
 			nested_storage: storage_meter.nested(deposit_limit),
 			allows_reentry: true,
+			read_only,
 		};
 
 		Ok((frame, executable, nonce))
 	}
 
 	/// Create a subsequent nested frame.
+	///
+	/// The new frame is read-only if `read_only` is set or the current frame is read-only.
 	fn push_frame(
 		&mut self,
 		frame_args: FrameArgs<T, E>,
 		value_transferred: BalanceOf<T>,
 		gas_limit: Weight,
 		deposit_limit: BalanceOf<T>,
+		read_only: bool,
 	) -> Result<E, ExecError> {
 		if self.frames.len() == T::CallStack::size() {
 			return Err(Error::<T>::MaxCallDepthReached.into())
@@ -869,6 +1062,7 @@ where
 		}
 
 		let frame = top_frame_mut!(self);
+		let read_only = read_only || frame.read_only;
 		let nested_gas = &mut frame.nested_gas;
 		let nested_storage = &mut frame.nested_storage;
 		let (frame, executable, _) = Self::new_frame(
@@ -879,6 +1073,7 @@ where
 			nested_storage,
 			deposit_limit,
 			self.determinism,
+			read_only,
 		)?;
 		self.frames.push(frame);
 		Ok(executable)
@@ -1039,8 +1234,10 @@ where
 
 			prev.nested_gas.absorb_nested(frame.nested_gas);
 
-			// Only gas counter changes are persisted in case of a failure.
+			// Only gas counter changes are persisted in case of a failure. Results cached
+			// after a state change of the reverted frame could reflect that change.
 			if !persist {
+				self.call_cache.clear();
 				return
 			}
 
@@ -1166,8 +1363,16 @@ where
 	}
 
 	/// Returns whether the specified contract allows to be reentered right now.
+	///
+	/// This is not the case if any of its frames on the stack disabled reentry for its current
+	/// call or if the contract enabled its reentrancy guard.
 	fn allows_reentry(&self, id: &AccountIdOf<T>) -> bool {
-		!self.frames().any(|f| &f.account_id == id && !f.allows_reentry)
+		let Some(frame) = self.frames().find(|f| &f.account_id == id) else { return true };
+		let guarded = match &frame.contract_info {
+			CachedContract::Cached(info) => info.reentrancy_guard(),
+			_ => <ContractInfoOf<T>>::get(id).map_or(false, |info| info.reentrancy_guard()),
+		};
+		!guarded && !self.frames().any(|f| &f.account_id == id && !f.allows_reentry)
 	}
 
 	/// Increments and returns the next nonce. Pulls it from storage if it isn't in cache.
@@ -1193,6 +1398,8 @@ where
 		value: BalanceOf<T>,
 		input_data: Vec<u8>,
 		allows_reentry: bool,
+		read_only: bool,
+		use_cache: bool,
 	) -> Result<ExecReturnValue, ExecError> {
 		// Before pushing the new frame: Protect the caller contract against reentrancy attacks.
 		// It is important to do this before calling `allows_reentry` so that a direct recursion
@@ -1203,6 +1410,20 @@ where
 			if !self.allows_reentry(&to) {
 				return Err(<Error<T>>::ReentranceDenied.into())
 			}
+			if !value.is_zero() && (read_only || self.is_read_only()) {
+				return Err(<Error<T>>::StateChangeDenied.into())
+			}
+			// The callee of a writable call, as well as the transfer of `value`, may change the
+			// state that the cached results depend on.
+			if !read_only && !self.is_read_only() {
+				self.call_cache.clear();
+			}
+			let cache_key = (read_only && use_cache && T::CallCacheSize::get() > 0).then(|| {
+				(&self.top_frame().account_id, &to, &input_data).using_encoded(blake2_256)
+			});
+			if let Some(cached) = cache_key.as_ref().and_then(|key| self.call_cache.get(key)) {
+				return Ok(cached.clone())
+			}
 			// We ignore instantiate frames in our search for a cached contract.
 			// Otherwise it would be possible to recursively call a contract from its own
 			// constructor: We disallow calling not fully constructed contracts.
@@ -1218,8 +1439,16 @@ where
 				value,
 				gas_limit,
 				deposit_limit,
+				read_only,
 			)?;
-			self.run(executable, input_data)
+			let output = self.run(executable, input_data)?;
+			if let Some(key) = cache_key {
+				if !output.did_revert() && self.call_cache.len() < T::CallCacheSize::get() as usize
+				{
+					self.call_cache.insert(key, output.clone());
+				}
+			}
+			Ok(output)
 		};
 
 		// We need to make sure to reset `allows_reentry` even on failure.
@@ -1237,6 +1466,9 @@ where
 		input_data: Vec<u8>,
 	) -> Result<ExecReturnValue, ExecError> {
 		let executable = E::from_storage(code_hash, self.gas_meter_mut())?;
+		if !self.is_read_only() {
+			self.call_cache.clear();
+		}
 		let top_frame = self.top_frame_mut();
 		let contract_info = top_frame.contract_info().clone();
 		let account_id = top_frame.account_id.clone();
@@ -1250,6 +1482,7 @@ where
 			value,
 			Weight::zero(),
 			BalanceOf::<T>::zero(),
+			false,
 		)?;
 		self.run(executable, input_data)
 	}
@@ -1263,6 +1496,7 @@ where
 		input_data: Vec<u8>,
 		salt: &[u8],
 	) -> Result<(AccountIdOf<T>, ExecReturnValue), ExecError> {
+		self.ensure_writable()?;
 		let executable = E::from_storage(code_hash, self.gas_meter_mut())?;
 		let nonce = self.next_nonce();
 		let executable = self.push_frame(
@@ -1272,16 +1506,19 @@ where
 				executable,
 				salt,
 				input_data: input_data.as_ref(),
+				address_kind: AddressKind::Deployer,
 			},
 			value,
 			gas_limit,
 			deposit_limit,
+			false,
 		)?;
 		let account_id = self.top_frame().account_id.clone();
 		self.run(executable, input_data).map(|ret| (account_id, ret))
 	}
 
 	fn terminate(&mut self, beneficiary: &AccountIdOf<Self::T>) -> Result<(), DispatchError> {
+		self.ensure_writable()?;
 		if self.is_recursive() {
 			return Err(Error::<T>::TerminatedWhileReentrant.into())
 		}
@@ -1300,6 +1537,25 @@ where
 				.charge_deposit(frame.account_id.clone(), StorageDeposit::Refund(*deposit));
 		}
 
+		for (code_hash, deposit) in PinnedCodeHashes::<T>::take(&frame.account_id) {
+			Self::decrement_refcount(code_hash);
+			frame
+				.nested_storage
+				.charge_deposit(frame.account_id.clone(), StorageDeposit::Refund(deposit));
+		}
+
+		let (_, topics_deposit) = RegisteredTopics::<T>::take(&frame.account_id);
+		let _ = EventTopicRegistry::<T>::clear_prefix(
+			&frame.account_id,
+			T::MaxRegisteredTopics::get(),
+			None,
+		);
+		if !topics_deposit.is_zero() {
+			frame
+				.nested_storage
+				.charge_deposit(frame.account_id.clone(), StorageDeposit::Refund(topics_deposit));
+		}
+
 		Contracts::<T>::deposit_event(
 			vec![T::Hashing::hash_of(&frame.account_id), T::Hashing::hash_of(&beneficiary)],
 			Event::Terminated {
@@ -1311,9 +1567,20 @@ where
 	}
 
 	fn transfer(&mut self, to: &T::AccountId, value: BalanceOf<T>) -> DispatchResult {
+		self.ensure_writable()?;
 		Self::transfer(Preservation::Preserve, &self.top_frame().account_id, to, value)
 	}
 
+	fn is_read_only(&self) -> bool {
+		self.top_frame().read_only
+	}
+
+	fn ensure_writable(&mut self) -> DispatchResult {
+		ensure!(!self.is_read_only(), Error::<T>::StateChangeDenied);
+		self.call_cache.clear();
+		Ok(())
+	}
+
 	fn get_storage(&mut self, key: &Key<T>) -> Option<Vec<u8>> {
 		self.top_frame_mut().contract_info().read(key)
 	}
@@ -1328,6 +1595,7 @@ where
 		value: Option<Vec<u8>>,
 		take_old: bool,
 	) -> Result<WriteOutcome, DispatchError> {
+		self.ensure_writable()?;
 		let frame = self.top_frame_mut();
 		frame.contract_info.get(&frame.account_id).write(
 			key.into(),
@@ -1389,6 +1657,13 @@ where
 		T::Randomness::random(subject)
 	}
 
+	fn randomness(&self, domain: &[u8]) -> RandomnessOutput<SeedOf<T>, BlockNumberFor<T>> {
+		// Mixing in the address keeps contracts from predicting the seeds of each other.
+		let subject = (b"contracts:randomness", self.address(), domain).encode();
+		let (seed, known_since) = T::RandomnessSource::random(&subject);
+		RandomnessOutput { seed, known_since }
+	}
+
 	fn now(&self) -> &MomentOf<T> {
 		&self.timestamp
 	}
@@ -1404,6 +1679,32 @@ where
 		);
 	}
 
+	fn register_event_topic(&mut self, topic: Vec<u8>) -> Result<T::Hash, DispatchError> {
+		self.ensure_writable()?;
+		let topic: BoundedVec<u8, T::MaxEventTopicLen> =
+			topic.try_into().map_err(|_| Error::<T>::EventTopicTooLong)?;
+		let hash = T::Hashing::hash(&topic);
+
+		let frame = self.top_frame_mut();
+		if EventTopicRegistry::<T>::contains_key(&frame.account_id, hash) {
+			return Ok(hash)
+		}
+		let (count, held) = RegisteredTopics::<T>::get(&frame.account_id);
+		if count >= T::MaxRegisteredTopics::get() {
+			return Ok(hash)
+		}
+
+		let bytes = (topic.len() + hash.as_ref().len()) as u32;
+		let deposit = T::DepositPerItem::get()
+			.saturating_add(T::DepositPerByte::get().saturating_mul(bytes.into()));
+		EventTopicRegistry::<T>::insert(&frame.account_id, hash, topic);
+		RegisteredTopics::<T>::insert(&frame.account_id, (count + 1, held.saturating_add(deposit)));
+		frame
+			.nested_storage
+			.charge_deposit(frame.account_id.clone(), StorageDeposit::Charge(deposit));
+		Ok(hash)
+	}
+
 	fn block_number(&self) -> BlockNumberFor<T> {
 		self.block_number
 	}
@@ -1432,6 +1733,16 @@ where
 		self.top_frame_mut().nested_storage.charge(diff)
 	}
 
+	fn storage_deposit_consumed(&mut self) -> StorageDeposit<BalanceOf<T>> {
+		let frame = self.top_frame_mut();
+		let info = frame.contract_info.get(&frame.account_id);
+		frame.nested_storage.consumed(Some(&*info))
+	}
+
+	fn storage_deposit_limit(&self) -> BalanceOf<T> {
+		self.top_frame().nested_storage.limit()
+	}
+
 	fn debug_buffer_enabled(&self) -> bool {
 		self.debug_message.is_some()
 	}
@@ -1457,7 +1768,7 @@ where
 	fn call_runtime(&self, call: <Self::T as Config>::RuntimeCall) -> DispatchResultWithPostInfo {
 		let mut origin: T::RuntimeOrigin = RawOrigin::Signed(self.address().clone()).into();
 		origin.add_filter(T::CallFilter::contains);
-		call.dispatch(origin)
+		with_dispatch_frame(DispatchFrame::new::<Contracts<T>>(0), || call.dispatch(origin))
 	}
 
 	fn ecdsa_recover(&self, signature: &[u8; 65], message_hash: &[u8; 32]) -> Result<[u8; 33], ()> {
@@ -1482,6 +1793,7 @@ where
 	}
 
 	fn set_code_hash(&mut self, hash: CodeHash<Self::T>) -> Result<(), DispatchError> {
+		self.ensure_writable()?;
 		let frame = top_frame_mut!(self);
 		if !E::from_storage(hash, &mut frame.nested_gas)?.is_deterministic() {
 			return Err(<Error<T>>::Indeterministic.into())
@@ -1525,6 +1837,34 @@ where
 			.count() as u32
 	}
 
+	fn set_reentrancy_guard(&mut self, enabled: bool) {
+		self.top_frame_mut().contract_info().set_reentrancy_guard(enabled);
+	}
+
+	fn immutable_data(&mut self) -> Vec<u8> {
+		self.top_frame_mut().contract_info().immutable_data().to_vec()
+	}
+
+	fn set_immutable_data(&mut self, data: Vec<u8>) -> DispatchResult {
+		self.ensure_writable()?;
+		let data =
+			ImmutableData::<T>::try_from(data).map_err(|_| Error::<T>::ImmutableDataTooLarge)?;
+		let frame = self.top_frame_mut();
+		// Delegate calls are never constructors, so this is the contract being instantiated.
+		ensure!(
+			frame.entry_point == ExportedFunction::Constructor,
+			Error::<T>::InvalidImmutableAccess
+		);
+		let info = frame.contract_info();
+		ensure!(info.immutable_data().is_empty(), Error::<T>::InvalidImmutableAccess);
+
+		let deposit = info.set_immutable_data(data);
+		frame
+			.nested_storage
+			.charge_deposit(frame.account_id.clone(), StorageDeposit::Charge(deposit));
+		Ok(())
+	}
+
 	fn nonce(&mut self) -> u64 {
 		if let Some(current) = self.nonce {
 			current
@@ -1558,6 +1898,7 @@ where
 		&mut self,
 		code_hash: CodeHash<Self::T>,
 	) -> Result<(), DispatchError> {
+		self.ensure_writable()?;
 		let frame = self.top_frame_mut();
 		let info = frame.contract_info.get(&frame.account_id);
 		ensure!(code_hash != info.code_hash, Error::<T>::CannotAddSelfAsDelegateDependency);
@@ -1577,6 +1918,7 @@ where
 		&mut self,
 		code_hash: &CodeHash<Self::T>,
 	) -> Result<(), DispatchError> {
+		self.ensure_writable()?;
 		let frame = self.top_frame_mut();
 		let info = frame.contract_info.get(&frame.account_id);
 
@@ -1587,6 +1929,90 @@ where
 			.charge_deposit(frame.account_id.clone(), StorageDeposit::Refund(deposit));
 		Ok(())
 	}
+
+	fn pin_code_hash(&mut self, code_hash: CodeHash<Self::T>) -> Result<(), DispatchError> {
+		self.ensure_writable()?;
+		let frame = self.top_frame_mut();
+		let code_info = CodeInfoOf::<T>::get(code_hash).ok_or(Error::<T>::CodeNotFound)?;
+		let deposit = T::CodeHashLockupDepositPercent::get().mul_ceil(code_info.deposit());
+
+		PinnedCodeHashes::<T>::try_mutate(&frame.account_id, |pinned| {
+			pinned
+				.try_insert(code_hash, deposit)
+				.map_err(|_| Error::<T>::MaxPinnedCodeHashesReached)?
+				.map_or(Ok(()), |_| Err(Error::<T>::CodeHashAlreadyPinned))
+		})?;
+		Self::increment_refcount(code_hash)?;
+		frame
+			.nested_storage
+			.charge_deposit(frame.account_id.clone(), StorageDeposit::Charge(deposit));
+		Ok(())
+	}
+
+	fn unpin_code_hash(&mut self, code_hash: &CodeHash<Self::T>) -> Result<(), DispatchError> {
+		self.ensure_writable()?;
+		let frame = self.top_frame_mut();
+		let deposit = PinnedCodeHashes::<T>::try_mutate(&frame.account_id, |pinned| {
+			pinned.remove(code_hash).ok_or(Error::<T>::CodeHashNotPinned)
+		})?;
+		Self::decrement_refcount(*code_hash);
+		frame
+			.nested_storage
+			.charge_deposit(frame.account_id.clone(), StorageDeposit::Refund(deposit));
+		Ok(())
+	}
+
+	fn schedule_call(
+		&mut self,
+		when: BlockNumberFor<Self::T>,
+		input_data: Vec<u8>,
+		gas_limit: Weight,
+		storage_deposit_limit: Option<BalanceOf<Self::T>>,
+	) -> Result<u32, DispatchError> {
+		self.ensure_writable()?;
+		let frame = self.top_frame_mut();
+		let (index, deposit) = ScheduledCall::<T>::schedule(
+			frame.account_id.clone(),
+			when,
+			input_data,
+			gas_limit,
+			storage_deposit_limit,
+		)?;
+		frame
+			.nested_storage
+			.charge_deposit(frame.account_id.clone(), StorageDeposit::Charge(deposit));
+		Ok(index)
+	}
+
+	fn cancel_scheduled_call(
+		&mut self,
+		when: BlockNumberFor<Self::T>,
+		index: u32,
+	) -> Result<(), DispatchError> {
+		self.ensure_writable()?;
+		let frame = self.top_frame_mut();
+		let deposit = ScheduledCall::<T>::cancel(&frame.account_id, when, index)?;
+		frame
+			.nested_storage
+			.charge_deposit(frame.account_id.clone(), StorageDeposit::Refund(deposit));
+		Ok(())
+	}
+
+	fn register_xcm_query(&mut self, query_id: XcmQueryIdOf<Self::T>) -> DispatchResult {
+		self.ensure_writable()?;
+		XcmQueryOwner::<T>::insert(query_id, self.top_frame().account_id.clone());
+		Ok(())
+	}
+
+	fn is_xcm_query_owner(&self, query_id: XcmQueryIdOf<Self::T>) -> bool {
+		XcmQueryOwner::<T>::get(query_id).as_ref() == Some(&self.top_frame().account_id)
+	}
+
+	fn forget_xcm_query(&mut self, query_id: XcmQueryIdOf<Self::T>) -> DispatchResult {
+		self.ensure_writable()?;
+		XcmQueryOwner::<T>::remove(query_id);
+		Ok(())
+	}
 }
 
 mod sealing {
@@ -1616,8 +2042,8 @@ mod tests {
 		gas::GasMeter,
 		tests::{
 			test_utils::{get_balance, hash, place_contract, set_balance},
-			ExtBuilder, RuntimeCall, RuntimeEvent as MetaEvent, Test, TestFilter, ALICE, BOB,
-			CHARLIE, GAS_LIMIT,
+			CallCacheSize, ExtBuilder, MaxImmutableDataLen, RuntimeCall, RuntimeEvent as MetaEvent,
+			Test, TestFilter, ALICE, BOB, CHARLIE, DJANGO, GAS_LIMIT,
 		},
 		Error,
 	};
@@ -1786,6 +2212,7 @@ mod tests {
 					vec![],
 					None,
 					Determinism::Enforced,
+					false,
 				),
 				Ok(_)
 			);
@@ -1842,6 +2269,7 @@ mod tests {
 				vec![],
 				None,
 				Determinism::Enforced,
+				false,
 			)
 			.unwrap();
 
@@ -1886,6 +2314,7 @@ mod tests {
 				vec![],
 				None,
 				Determinism::Enforced,
+				false,
 			)
 			.unwrap();
 
@@ -1924,6 +2353,7 @@ mod tests {
 				vec![],
 				None,
 				Determinism::Enforced,
+				false,
 			)
 			.unwrap();
 
@@ -1978,6 +2408,7 @@ mod tests {
 				vec![],
 				None,
 				Determinism::Enforced,
+				false,
 			);
 
 			let output = result.unwrap();
@@ -2013,6 +2444,7 @@ mod tests {
 				vec![],
 				None,
 				Determinism::Enforced,
+				false,
 			);
 
 			let output = result.unwrap();
@@ -2046,6 +2478,7 @@ mod tests {
 				vec![1, 2, 3, 4],
 				None,
 				Determinism::Enforced,
+				false,
 			);
 			assert_matches!(result, Ok(_));
 		});
@@ -2082,6 +2515,7 @@ mod tests {
 					min_balance,
 					vec![1, 2, 3, 4],
 					&[],
+					AddressKind::Deployer,
 					None,
 				);
 				assert_matches!(result, Ok(_));
@@ -2098,7 +2532,16 @@ mod tests {
 		let value = Default::default();
 		let recurse_ch = MockLoader::insert(Call, |ctx, _| {
 			// Try to call into yourself.
-			let r = ctx.ext.call(Weight::zero(), BalanceOf::<Test>::zero(), BOB, 0, vec![], true);
+			let r = ctx.ext.call(
+				Weight::zero(),
+				BalanceOf::<Test>::zero(),
+				BOB,
+				0,
+				vec![],
+				true,
+				false,
+				false,
+			);
 
 			ReachedBottom::mutate(|reached_bottom| {
 				if !*reached_bottom {
@@ -2133,6 +2576,7 @@ mod tests {
 				vec![],
 				None,
 				Determinism::Enforced,
+				false,
 			);
 
 			assert_matches!(result, Ok(_));
@@ -2157,8 +2601,16 @@ mod tests {
 
 			// Call into CHARLIE contract.
 			assert_matches!(
-				ctx.ext
-					.call(Weight::zero(), BalanceOf::<Test>::zero(), CHARLIE, 0, vec![], true),
+				ctx.ext.call(
+					Weight::zero(),
+					BalanceOf::<Test>::zero(),
+					CHARLIE,
+					0,
+					vec![],
+					true,
+					false,
+					false
+				),
 				Ok(_)
 			);
 			exec_success()
@@ -2189,6 +2641,7 @@ mod tests {
 				vec![],
 				None,
 				Determinism::Enforced,
+				false,
 			);
 
 			assert_matches!(result, Ok(_));
@@ -2225,6 +2678,7 @@ mod tests {
 				vec![],
 				None,
 				Determinism::Enforced,
+				false,
 			);
 			assert_matches!(result, Ok(_));
 		});
@@ -2257,6 +2711,7 @@ mod tests {
 				vec![0],
 				None,
 				Determinism::Enforced,
+				false,
 			);
 			assert_matches!(result, Ok(_));
 		});
@@ -2287,6 +2742,7 @@ mod tests {
 				vec![0],
 				None,
 				Determinism::Enforced,
+				false,
 			);
 			assert_matches!(result, Ok(_));
 		});
@@ -2304,8 +2760,16 @@ mod tests {
 			// ALICE is the origin of the call stack
 			assert!(ctx.ext.caller_is_origin());
 			// BOB calls CHARLIE
-			ctx.ext
-				.call(Weight::zero(), BalanceOf::<Test>::zero(), CHARLIE, 0, vec![], true)
+			ctx.ext.call(
+				Weight::zero(),
+				BalanceOf::<Test>::zero(),
+				CHARLIE,
+				0,
+				vec![],
+				true,
+				false,
+				false,
+			)
 		});
 
 		ExtBuilder::default().build().execute_with(|| {
@@ -2326,6 +2790,7 @@ mod tests {
 				vec![0],
 				None,
 				Determinism::Enforced,
+				false,
 			);
 			assert_matches!(result, Ok(_));
 		});
@@ -2356,6 +2821,7 @@ mod tests {
 				vec![0],
 				None,
 				Determinism::Enforced,
+				false,
 			);
 			assert_matches!(result, Ok(_));
 		});
@@ -2386,6 +2852,7 @@ mod tests {
 				vec![0],
 				None,
 				Determinism::Enforced,
+				false,
 			);
 			assert_matches!(result, Err(_));
 		});
@@ -2403,8 +2870,16 @@ mod tests {
 			// root is the origin of the call stack.
 			assert!(ctx.ext.caller_is_root());
 			// BOB calls CHARLIE.
-			ctx.ext
-				.call(Weight::zero(), BalanceOf::<Test>::zero(), CHARLIE, 0, vec![], true)
+			ctx.ext.call(
+				Weight::zero(),
+				BalanceOf::<Test>::zero(),
+				CHARLIE,
+				0,
+				vec![],
+				true,
+				false,
+				false,
+			)
 		});
 
 		ExtBuilder::default().build().execute_with(|| {
@@ -2425,6 +2900,7 @@ mod tests {
 				vec![0],
 				None,
 				Determinism::Enforced,
+				false,
 			);
 			assert_matches!(result, Ok(_));
 		});
@@ -2438,8 +2914,16 @@ mod tests {
 
 			// Call into charlie contract.
 			assert_matches!(
-				ctx.ext
-					.call(Weight::zero(), BalanceOf::<Test>::zero(), CHARLIE, 0, vec![], true),
+				ctx.ext.call(
+					Weight::zero(),
+					BalanceOf::<Test>::zero(),
+					CHARLIE,
+					0,
+					vec![],
+					true,
+					false,
+					false
+				),
 				Ok(_)
 			);
 			exec_success()
@@ -2467,6 +2951,7 @@ mod tests {
 				vec![],
 				None,
 				Determinism::Enforced,
+				false,
 			);
 
 			assert_matches!(result, Ok(_));
@@ -2495,6 +2980,7 @@ mod tests {
 					0, // <- zero value
 					vec![],
 					&[],
+					AddressKind::Deployer,
 					None,
 				),
 				Err(_)
@@ -2536,6 +3022,7 @@ mod tests {
 						min_balance,
 						vec![],
 						&[],
+						AddressKind::Deployer,
 						None,
 					),
 					Ok((address, ref output)) if output.data == vec![80, 65, 83, 83] => address
@@ -2591,6 +3078,7 @@ mod tests {
 						min_balance,
 						vec![],
 						&[],
+						AddressKind::Deployer,
 						None,
 					),
 					Ok((address, ref output)) if output.data == vec![70, 65, 73, 76] => address
@@ -2657,6 +3145,7 @@ mod tests {
 						vec![],
 						None,
 						Determinism::Enforced,
+						false,
 					),
 					Ok(_)
 				);
@@ -2732,6 +3221,7 @@ mod tests {
 						vec![],
 						None,
 						Determinism::Enforced,
+						false,
 					),
 					Ok(_)
 				);
@@ -2776,6 +3266,7 @@ mod tests {
 						100,
 						vec![],
 						&[],
+						AddressKind::Deployer,
 						None,
 					),
 					Err(Error::<Test>::TerminatedInConstructor.into())
@@ -2807,7 +3298,9 @@ mod tests {
 						CHARLIE,
 						0,
 						vec![],
-						true
+						true,
+						false,
+						false,
 					),
 					exec_trapped()
 				);
@@ -2818,7 +3311,16 @@ mod tests {
 		let code_charlie = MockLoader::insert(Call, |ctx, _| {
 			assert!(ctx
 				.ext
-				.call(Weight::zero(), BalanceOf::<Test>::zero(), BOB, 0, vec![99], true)
+				.call(
+					Weight::zero(),
+					BalanceOf::<Test>::zero(),
+					BOB,
+					0,
+					vec![99],
+					true,
+					false,
+					false
+				)
 				.is_ok());
 			exec_trapped()
 		});
@@ -2842,6 +3344,7 @@ mod tests {
 				vec![0],
 				None,
 				Determinism::Enforced,
+				false,
 			);
 			assert_matches!(result, Ok(_));
 		});
@@ -2851,7 +3354,7 @@ mod tests {
 	fn recursive_call_during_constructor_fails() {
 		let code = MockLoader::insert(Constructor, |ctx, _| {
 			assert_matches!(
-				ctx.ext.call(Weight::zero(), BalanceOf::<Test>::zero(), ctx.ext.address().clone(), 0, vec![], true),
+				ctx.ext.call(Weight::zero(), BalanceOf::<Test>::zero(), ctx.ext.address().clone(), 0, vec![], true, false, false),
 				Err(ExecError{error, ..}) if error == <Error<Test>>::ContractNotFound.into()
 			);
 			exec_success()
@@ -2880,6 +3383,7 @@ mod tests {
 					min_balance,
 					vec![],
 					&[],
+					AddressKind::Deployer,
 					None,
 				);
 				assert_matches!(result, Ok(_));
@@ -2915,6 +3419,7 @@ mod tests {
 				vec![],
 				Some(&mut debug_buffer),
 				Determinism::Enforced,
+				false,
 			)
 			.unwrap();
 		});
@@ -2951,6 +3456,7 @@ mod tests {
 				vec![],
 				Some(&mut debug_buffer),
 				Determinism::Enforced,
+				false,
 			);
 			assert!(result.is_err());
 		});
@@ -2990,6 +3496,7 @@ mod tests {
 				vec![],
 				Some(&mut debug_buf_after),
 				Determinism::Enforced,
+				false,
 			)
 			.unwrap();
 			assert_eq!(debug_buf_before, debug_buf_after);
@@ -3001,7 +3508,16 @@ mod tests {
 		// call the contract passed as input with disabled reentry
 		let code_bob = MockLoader::insert(Call, |ctx, _| {
 			let dest = Decode::decode(&mut ctx.input_data.as_ref()).unwrap();
-			ctx.ext.call(Weight::zero(), BalanceOf::<Test>::zero(), dest, 0, vec![], false)
+			ctx.ext.call(
+				Weight::zero(),
+				BalanceOf::<Test>::zero(),
+				dest,
+				0,
+				vec![],
+				false,
+				false,
+				false,
+			)
 		});
 
 		let code_charlie = MockLoader::insert(Call, |_, _| exec_success());
@@ -3024,7 +3540,8 @@ mod tests {
 				0,
 				CHARLIE.encode(),
 				None,
-				Determinism::Enforced
+				Determinism::Enforced,
+				false,
 			));
 
 			// Calling into oneself fails
@@ -3038,7 +3555,8 @@ mod tests {
 					0,
 					BOB.encode(),
 					None,
-					Determinism::Enforced
+					Determinism::Enforced,
+					false,
 				)
 				.map_err(|e| e.error),
 				<Error<Test>>::ReentranceDenied,
@@ -3050,8 +3568,16 @@ mod tests {
 	fn call_deny_reentry() {
 		let code_bob = MockLoader::insert(Call, |ctx, _| {
 			if ctx.input_data[0] == 0 {
-				ctx.ext
-					.call(Weight::zero(), BalanceOf::<Test>::zero(), CHARLIE, 0, vec![], false)
+				ctx.ext.call(
+					Weight::zero(),
+					BalanceOf::<Test>::zero(),
+					CHARLIE,
+					0,
+					vec![],
+					false,
+					false,
+					false,
+				)
 			} else {
 				exec_success()
 			}
@@ -3059,7 +3585,16 @@ mod tests {
 
 		// call BOB with input set to '1'
 		let code_charlie = MockLoader::insert(Call, |ctx, _| {
-			ctx.ext.call(Weight::zero(), BalanceOf::<Test>::zero(), BOB, 0, vec![1], true)
+			ctx.ext.call(
+				Weight::zero(),
+				BalanceOf::<Test>::zero(),
+				BOB,
+				0,
+				vec![1],
+				true,
+				false,
+				false,
+			)
 		});
 
 		ExtBuilder::default().build().execute_with(|| {
@@ -3081,7 +3616,8 @@ mod tests {
 					0,
 					vec![0],
 					None,
-					Determinism::Enforced
+					Determinism::Enforced,
+					false,
 				)
 				.map_err(|e| e.error),
 				<Error<Test>>::ReentranceDenied,
@@ -3090,46 +3626,415 @@ mod tests {
 	}
 
 	#[test]
-	fn call_runtime_works() {
-		let code_hash = MockLoader::insert(Call, |ctx, _| {
-			let call = RuntimeCall::System(frame_system::Call::remark_with_event {
-				remark: b"Hello World".to_vec(),
-			});
-			ctx.ext.call_runtime(call).unwrap();
-			exec_success()
+	fn call_reentrancy_guard() {
+		// optionally enable the guard and call CHARLIE which calls back with reentry allowed
+		let code_bob = MockLoader::insert(Call, |ctx, _| match ctx.input_data[0] {
+			0 | 1 => {
+				if ctx.input_data[0] == 0 {
+					ctx.ext.set_reentrancy_guard(true);
+				}
+				ctx.ext.call(
+					Weight::zero(),
+					BalanceOf::<Test>::zero(),
+					CHARLIE,
+					0,
+					vec![],
+					true,
+					false,
+					false,
+				)
+			},
+			_ => exec_success(),
+		});
+
+		// call BOB with input set to '2'
+		let code_charlie = MockLoader::insert(Call, |ctx, _| {
+			ctx.ext.call(
+				Weight::zero(),
+				BalanceOf::<Test>::zero(),
+				BOB,
+				0,
+				vec![2],
+				true,
+				false,
+				false,
+			)
 		});
 
 		ExtBuilder::default().build().execute_with(|| {
-			let min_balance = <Test as Config>::Currency::minimum_balance();
 			let schedule = <Test as Config>::Schedule::get();
-			let mut gas_meter = GasMeter::<Test>::new(GAS_LIMIT);
-			set_balance(&ALICE, min_balance * 10);
-			place_contract(&BOB, code_hash);
+			place_contract(&BOB, code_bob);
+			place_contract(&CHARLIE, code_charlie);
 			let contract_origin = Origin::from_account_id(ALICE);
 			let mut storage_meter =
 				storage::meter::Meter::new(&contract_origin, Some(0), 0).unwrap();
-			System::reset_events();
-			MockStack::run_call(
-				contract_origin,
+
+			// BOB -> CHARLIE -> BOB succeeds without the guard.
+			assert_ok!(MockStack::run_call(
+				contract_origin.clone(),
 				BOB,
-				&mut gas_meter,
+				&mut GasMeter::<Test>::new(GAS_LIMIT),
 				&mut storage_meter,
 				&schedule,
 				0,
-				vec![],
+				vec![1],
 				None,
 				Determinism::Enforced,
-			)
-			.unwrap();
+				false,
+			));
 
-			let remark_hash = <Test as frame_system::Config>::Hashing::hash(b"Hello World");
-			assert_eq!(
-				System::events(),
-				vec![
-					EventRecord {
-						phase: Phase::Initialization,
-						event: MetaEvent::System(frame_system::Event::Remarked {
-							sender: BOB,
+			// BOB -> CHARLIE -> BOB fails as BOB enabled its reentrancy guard.
+			assert_err!(
+				MockStack::run_call(
+					contract_origin.clone(),
+					BOB,
+					&mut GasMeter::<Test>::new(GAS_LIMIT),
+					&mut storage_meter,
+					&schedule,
+					0,
+					vec![0],
+					None,
+					Determinism::Enforced,
+					false,
+				)
+				.map_err(|e| e.error),
+				<Error<Test>>::ReentranceDenied,
+			);
+
+			// The guard is persisted with the contract.
+			ContractInfoOf::<Test>::mutate(&BOB, |info| {
+				info.as_mut().unwrap().set_reentrancy_guard(true)
+			});
+			assert_err!(
+				MockStack::run_call(
+					contract_origin,
+					BOB,
+					&mut GasMeter::<Test>::new(GAS_LIMIT),
+					&mut storage_meter,
+					&schedule,
+					0,
+					vec![1],
+					None,
+					Determinism::Enforced,
+					false,
+				)
+				.map_err(|e| e.error),
+				<Error<Test>>::ReentranceDenied,
+			);
+		});
+	}
+
+	#[test]
+	fn read_only_call_denies_state_change() {
+		let code_bob = MockLoader::insert(Call, |ctx, _| {
+			assert!(!ctx.ext.is_read_only());
+			ctx.ext.call(
+				Weight::zero(),
+				BalanceOf::<Test>::zero(),
+				CHARLIE,
+				0,
+				vec![],
+				true,
+				true,
+				false,
+			)
+		});
+
+		let code_charlie = MockLoader::insert(Call, |ctx, _| {
+			assert!(ctx.ext.is_read_only());
+			assert_err!(
+				ctx.ext.set_storage(&Key::Fix([1; 32]), Some(vec![1]), false),
+				<Error<Test>>::StateChangeDenied,
+			);
+			assert_err!(ctx.ext.transfer(&ALICE, 1), <Error<Test>>::StateChangeDenied);
+			assert_err!(ctx.ext.ensure_writable(), <Error<Test>>::StateChangeDenied);
+			// Nested calls are read-only too and hence can't transfer value.
+			assert_err!(
+				ctx.ext
+					.call(
+						Weight::zero(),
+						BalanceOf::<Test>::zero(),
+						BOB,
+						1,
+						vec![],
+						true,
+						false,
+						false
+					)
+					.map_err(|e| e.error),
+				<Error<Test>>::StateChangeDenied,
+			);
+			assert!(ctx.ext.get_storage(&Key::Fix([1; 32])).is_none());
+			exec_success()
+		});
+
+		ExtBuilder::default().build().execute_with(|| {
+			let schedule = <Test as Config>::Schedule::get();
+			place_contract(&BOB, code_bob);
+			place_contract(&CHARLIE, code_charlie);
+			let contract_origin = Origin::from_account_id(ALICE);
+			let mut storage_meter =
+				storage::meter::Meter::new(&contract_origin, Some(0), 0).unwrap();
+
+			assert_ok!(MockStack::run_call(
+				contract_origin,
+				BOB,
+				&mut GasMeter::<Test>::new(GAS_LIMIT),
+				&mut storage_meter,
+				&schedule,
+				0,
+				vec![],
+				None,
+				Determinism::Enforced,
+				false,
+			));
+		});
+	}
+
+	#[test]
+	fn read_only_call_results_are_cached() {
+		let executions = Rc::new(RefCell::new(0u32));
+		let code_bob = MockLoader::insert(Call, |ctx, _| {
+			// The output of CHARLIE is the number of its executions. A repeated call is served
+			// from the cache unless it opts out. A different input is a different entry.
+			for (input, use_cache, executions) in
+				[(1, true, 1), (1, true, 1), (1, false, 2), (2, true, 3), (2, true, 3)]
+			{
+				let output = ctx
+					.ext
+					.call(
+						Weight::zero(),
+						BalanceOf::<Test>::zero(),
+						CHARLIE,
+						0,
+						vec![input],
+						true,
+						true,
+						use_cache,
+					)
+					.unwrap();
+				assert_eq!(output.data, vec![executions]);
+			}
+
+			// A state change drops the cache.
+			ctx.ext.set_storage(&Key::Fix([1; 32]), Some(vec![1]), false).unwrap();
+			let output = ctx
+				.ext
+				.call(
+					Weight::zero(),
+					BalanceOf::<Test>::zero(),
+					CHARLIE,
+					0,
+					vec![1],
+					true,
+					true,
+					true,
+				)
+				.unwrap();
+			assert_eq!(output.data, vec![4]);
+			exec_success()
+		});
+
+		let code_charlie = MockLoader::insert(Call, {
+			let executions = executions.clone();
+			move |_, _| {
+				*executions.borrow_mut() += 1;
+				Ok(ExecReturnValue {
+					flags: ReturnFlags::empty(),
+					data: vec![*executions.borrow() as u8],
+				})
+			}
+		});
+
+		ExtBuilder::default().build().execute_with(|| {
+			CallCacheSize::set(8);
+			let schedule = <Test as Config>::Schedule::get();
+			place_contract(&BOB, code_bob);
+			place_contract(&CHARLIE, code_charlie);
+			let contract_origin = Origin::from_account_id(ALICE);
+			let mut storage_meter =
+				storage::meter::Meter::new(&contract_origin, Some(0), 0).unwrap();
+
+			assert_ok!(MockStack::run_call(
+				contract_origin,
+				BOB,
+				&mut GasMeter::<Test>::new(GAS_LIMIT),
+				&mut storage_meter,
+				&schedule,
+				0,
+				vec![],
+				None,
+				Determinism::Enforced,
+				false,
+			));
+			assert_eq!(*executions.borrow(), 4);
+		});
+	}
+
+	#[test]
+	fn call_cache_is_bounded() {
+		let executions = Rc::new(RefCell::new(0u32));
+		let code_bob = MockLoader::insert(Call, |ctx, _| {
+			for input in [1, 2, 1, 2] {
+				ctx.ext
+					.call(
+						Weight::zero(),
+						BalanceOf::<Test>::zero(),
+						CHARLIE,
+						0,
+						vec![input],
+						true,
+						true,
+						true,
+					)
+					.unwrap();
+			}
+			exec_success()
+		});
+
+		let code_charlie = MockLoader::insert(Call, {
+			let executions = executions.clone();
+			move |_, _| {
+				*executions.borrow_mut() += 1;
+				exec_success()
+			}
+		});
+
+		ExtBuilder::default().build().execute_with(|| {
+			let schedule = <Test as Config>::Schedule::get();
+			place_contract(&BOB, code_bob);
+			place_contract(&CHARLIE, code_charlie);
+
+			// Only the first result fits into the cache. Without a cache every call executes.
+			for (size, expected) in [(1, 3), (0, 4)] {
+				CallCacheSize::set(size);
+				*executions.borrow_mut() = 0;
+				let contract_origin = Origin::from_account_id(ALICE);
+				let mut storage_meter =
+					storage::meter::Meter::new(&contract_origin, Some(0), 0).unwrap();
+				assert_ok!(MockStack::run_call(
+					contract_origin,
+					BOB,
+					&mut GasMeter::<Test>::new(GAS_LIMIT),
+					&mut storage_meter,
+					&schedule,
+					0,
+					vec![],
+					None,
+					Determinism::Enforced,
+					false,
+				));
+				assert_eq!(*executions.borrow(), expected);
+			}
+		});
+	}
+
+	#[test]
+	fn writable_call_invalidates_call_cache() {
+		let executions = Rc::new(RefCell::new(0u32));
+		let code_bob = MockLoader::insert(Call, |ctx, _| {
+			let mut call = |to, value, read_only| {
+				ctx.ext
+					.call(
+						Weight::zero(),
+						BalanceOf::<Test>::zero(),
+						to,
+						value,
+						vec![],
+						true,
+						read_only,
+						true,
+					)
+					.unwrap()
+			};
+			// A writable call of a contract that doesn't change anything still drops the
+			// cache, and so does a transfer of value.
+			call(CHARLIE, 0, true);
+			call(CHARLIE, 0, true);
+			call(DJANGO, 0, false);
+			call(CHARLIE, 0, true);
+			call(DJANGO, 1, false);
+			call(CHARLIE, 0, true);
+			exec_success()
+		});
+
+		let code_charlie = MockLoader::insert(Call, {
+			let executions = executions.clone();
+			move |_, _| {
+				*executions.borrow_mut() += 1;
+				exec_success()
+			}
+		});
+		let code_django = MockLoader::insert(Call, |_, _| exec_success());
+
+		ExtBuilder::default().build().execute_with(|| {
+			CallCacheSize::set(8);
+			let schedule = <Test as Config>::Schedule::get();
+			place_contract(&BOB, code_bob);
+			place_contract(&CHARLIE, code_charlie);
+			place_contract(&DJANGO, code_django);
+			let contract_origin = Origin::from_account_id(ALICE);
+			let mut storage_meter =
+				storage::meter::Meter::new(&contract_origin, Some(0), 0).unwrap();
+
+			assert_ok!(MockStack::run_call(
+				contract_origin,
+				BOB,
+				&mut GasMeter::<Test>::new(GAS_LIMIT),
+				&mut storage_meter,
+				&schedule,
+				0,
+				vec![],
+				None,
+				Determinism::Enforced,
+				false,
+			));
+			assert_eq!(*executions.borrow(), 3);
+		});
+	}
+
+	#[test]
+	fn call_runtime_works() {
+		let code_hash = MockLoader::insert(Call, |ctx, _| {
+			let call = RuntimeCall::System(frame_system::Call::remark_with_event {
+				remark: b"Hello World".to_vec(),
+			});
+			ctx.ext.call_runtime(call).unwrap();
+			exec_success()
+		});
+
+		ExtBuilder::default().build().execute_with(|| {
+			let min_balance = <Test as Config>::Currency::minimum_balance();
+			let schedule = <Test as Config>::Schedule::get();
+			let mut gas_meter = GasMeter::<Test>::new(GAS_LIMIT);
+			set_balance(&ALICE, min_balance * 10);
+			place_contract(&BOB, code_hash);
+			let contract_origin = Origin::from_account_id(ALICE);
+			let mut storage_meter =
+				storage::meter::Meter::new(&contract_origin, Some(0), 0).unwrap();
+			System::reset_events();
+			MockStack::run_call(
+				contract_origin,
+				BOB,
+				&mut gas_meter,
+				&mut storage_meter,
+				&schedule,
+				0,
+				vec![],
+				None,
+				Determinism::Enforced,
+				false,
+			)
+			.unwrap();
+
+			let remark_hash = <Test as frame_system::Config>::Hashing::hash(b"Hello World");
+			assert_eq!(
+				System::events(),
+				vec![
+					EventRecord {
+						phase: Phase::Initialization,
+						event: MetaEvent::System(frame_system::Event::Remarked {
+							sender: BOB,
 							hash: remark_hash
 						}),
 						topics: vec![],
@@ -3206,6 +4111,7 @@ mod tests {
 				vec![],
 				None,
 				Determinism::Enforced,
+				false,
 			)
 			.unwrap();
 
@@ -3279,7 +4185,16 @@ mod tests {
 
 			// a plain call should not influence the account counter
 			ctx.ext
-				.call(Weight::zero(), BalanceOf::<Test>::zero(), account_id, 0, vec![], false)
+				.call(
+					Weight::zero(),
+					BalanceOf::<Test>::zero(),
+					account_id,
+					0,
+					vec![],
+					false,
+					false,
+					false,
+				)
 				.unwrap();
 
 			exec_success()
@@ -3314,6 +4229,7 @@ mod tests {
 					min_balance * 100,
 					vec![],
 					&[],
+					AddressKind::Deployer,
 					None,
 				)
 				.ok();
@@ -3328,6 +4244,7 @@ mod tests {
 					min_balance * 100,
 					vec![],
 					&[],
+					AddressKind::Deployer,
 					None,
 				));
 				assert_eq!(<Nonce<Test>>::get(), 1);
@@ -3341,6 +4258,7 @@ mod tests {
 					min_balance * 200,
 					vec![],
 					&[],
+					AddressKind::Deployer,
 					None,
 				));
 				assert_eq!(<Nonce<Test>>::get(), 2);
@@ -3354,6 +4272,7 @@ mod tests {
 					min_balance * 200,
 					vec![],
 					&[],
+					AddressKind::Deployer,
 					None,
 				));
 				assert_eq!(<Nonce<Test>>::get(), 4);
@@ -3423,7 +4342,49 @@ mod tests {
 				0,
 				vec![],
 				None,
-				Determinism::Enforced
+				Determinism::Enforced,
+				false,
+			));
+		});
+	}
+
+	#[test]
+	fn storage_deposit_consumed_works() {
+		let code_hash = MockLoader::insert(Call, |ctx, _| {
+			assert_eq!(ctx.ext.storage_deposit_limit(), 100);
+			assert_eq!(ctx.ext.storage_deposit_consumed(), StorageDeposit::Charge(0));
+
+			// 3 bytes and 1 item.
+			assert_ok!(ctx.ext.set_storage(&Key::Fix([1; 32]), Some(vec![1, 2, 3]), false));
+			assert_eq!(ctx.ext.storage_deposit_consumed(), StorageDeposit::Charge(5));
+
+			// Removing the item again leaves nothing to be charged.
+			assert_ok!(ctx.ext.set_storage(&Key::Fix([1; 32]), None, false));
+			assert_eq!(ctx.ext.storage_deposit_consumed(), StorageDeposit::Charge(0));
+
+			exec_success()
+		});
+
+		ExtBuilder::default().build().execute_with(|| {
+			let min_balance = <Test as Config>::Currency::minimum_balance();
+			let schedule = <Test as Config>::Schedule::get();
+			let mut gas_meter = GasMeter::<Test>::new(GAS_LIMIT);
+			set_balance(&ALICE, min_balance * 1000);
+			place_contract(&BOB, code_hash);
+			let contract_origin = Origin::from_account_id(ALICE);
+			let mut storage_meter =
+				storage::meter::Meter::new(&contract_origin, Some(100), 0).unwrap();
+			assert_ok!(MockStack::run_call(
+				contract_origin,
+				BOB,
+				&mut gas_meter,
+				&mut storage_meter,
+				&schedule,
+				0,
+				vec![],
+				None,
+				Determinism::Enforced,
+				false,
 			));
 		});
 	}
@@ -3551,7 +4512,8 @@ mod tests {
 				0,
 				vec![],
 				None,
-				Determinism::Enforced
+				Determinism::Enforced,
+				false,
 			));
 		});
 	}
@@ -3591,7 +4553,8 @@ mod tests {
 				0,
 				vec![],
 				None,
-				Determinism::Enforced
+				Determinism::Enforced,
+				false,
 			));
 		});
 	}
@@ -3631,7 +4594,8 @@ mod tests {
 				0,
 				vec![],
 				None,
-				Determinism::Enforced
+				Determinism::Enforced,
+				false,
 			));
 		});
 	}
@@ -3688,7 +4652,8 @@ mod tests {
 				0,
 				vec![],
 				None,
-				Determinism::Enforced
+				Determinism::Enforced,
+				false,
 			));
 		});
 	}
@@ -3745,7 +4710,8 @@ mod tests {
 				0,
 				vec![],
 				None,
-				Determinism::Enforced
+				Determinism::Enforced,
+				false,
 			));
 		});
 	}
@@ -3782,6 +4748,7 @@ mod tests {
 				vec![],
 				None,
 				Determinism::Enforced,
+				false,
 			);
 			assert_matches!(result, Ok(_));
 		});
@@ -3848,7 +4815,67 @@ mod tests {
 					0,
 					vec![],
 					None,
-					Determinism::Enforced
+					Determinism::Enforced,
+					false,
+				));
+			});
+	}
+
+	#[test]
+	fn immutable_data_can_only_be_set_by_constructor() {
+		let constructor = MockLoader::insert(Constructor, |ctx, _| {
+			assert_eq!(ctx.ext.immutable_data(), Vec::<u8>::new());
+			assert_ok!(ctx.ext.set_immutable_data(vec![1, 2, 3]));
+			assert_eq!(ctx.ext.immutable_data(), vec![1, 2, 3]);
+			// It can only be set once.
+			assert_err!(
+				ctx.ext.set_immutable_data(vec![4, 5, 6]),
+				<Error<Test>>::InvalidImmutableAccess
+			);
+			// It is bounded.
+			assert_err!(
+				ctx.ext.set_immutable_data(vec![0; MaxImmutableDataLen::get() as usize + 1]),
+				<Error<Test>>::ImmutableDataTooLarge
+			);
+			exec_success()
+		});
+		let code_hash = MockLoader::insert(Call, move |ctx, _| {
+			assert_err!(
+				ctx.ext.set_immutable_data(vec![1, 2, 3]),
+				<Error<Test>>::InvalidImmutableAccess
+			);
+			let (address, _) = ctx
+				.ext
+				.instantiate(Weight::zero(), BalanceOf::<Test>::zero(), constructor, 0, vec![], &[])
+				.unwrap();
+			let info = ContractInfoOf::<Test>::get(&address).unwrap();
+			assert_eq!(info.immutable_data(), &[1, 2, 3]);
+			exec_success()
+		});
+
+		ExtBuilder::default()
+			.with_code_hashes(MockLoader::code_hashes())
+			.build()
+			.execute_with(|| {
+				let min_balance = <Test as Config>::Currency::minimum_balance();
+				let schedule = <Test as Config>::Schedule::get();
+				let mut gas_meter = GasMeter::<Test>::new(GAS_LIMIT);
+				set_balance(&ALICE, min_balance * 1000);
+				place_contract(&BOB, code_hash);
+				let contract_origin = Origin::from_account_id(ALICE);
+				let mut storage_meter =
+					storage::meter::Meter::new(&contract_origin, None, 0).unwrap();
+				assert_ok!(MockStack::run_call(
+					contract_origin,
+					BOB,
+					&mut gas_meter,
+					&mut storage_meter,
+					&schedule,
+					0,
+					vec![],
+					None,
+					Determinism::Enforced,
+					false,
 				));
 			});
 	}
@@ -3881,8 +4908,54 @@ mod tests {
 				vec![],
 				None,
 				Determinism::Enforced,
+				false,
 			);
 			assert_matches!(result, Ok(_));
 		});
 	}
+
+	#[test]
+	fn randomness_is_separated_by_contract_and_domain() {
+		let seeds = Rc::new(RefCell::new(Vec::new()));
+		let code_hash = MockLoader::insert(Call, {
+			let seeds = seeds.clone();
+			move |ctx, _| {
+				let output = ctx.ext.randomness(b"lottery");
+				let expected = <Test as Config>::RandomnessSource::random(
+					&(b"contracts:randomness", ctx.ext.address(), b"lottery".as_ref()).encode(),
+				);
+				assert_eq!((output.seed, output.known_since), expected);
+				assert_ne!(output.seed, ctx.ext.randomness(b"raffle").seed);
+				seeds.borrow_mut().push(output.seed);
+				exec_success()
+			}
+		});
+
+		ExtBuilder::default().build().execute_with(|| {
+			let schedule = <Test as Config>::Schedule::get();
+			place_contract(&BOB, code_hash);
+			place_contract(&CHARLIE, code_hash);
+
+			for dest in [BOB, CHARLIE] {
+				let contract_origin = Origin::from_account_id(ALICE);
+				let mut storage_meter =
+					storage::meter::Meter::new(&contract_origin, Some(0), 0).unwrap();
+				assert_ok!(MockStack::run_call(
+					contract_origin,
+					dest,
+					&mut GasMeter::<Test>::new(GAS_LIMIT),
+					&mut storage_meter,
+					&schedule,
+					0,
+					vec![],
+					None,
+					Determinism::Enforced,
+					false,
+				));
+			}
+			let seeds = seeds.borrow();
+			assert_eq!(seeds.len(), 2);
+			assert_ne!(seeds[0], seeds[1]);
+		});
+	}
 }
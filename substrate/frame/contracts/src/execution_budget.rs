@@ -0,0 +1,214 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! A per-block budget bounding the share of the block weight taken by contract execution.
+//!
+//! The weight of the contract calls and instantiations dispatched in a block is tracked in
+//! [`BlockExecutionWeight`], which is cleared at the end of each block. A dispatch whose gas
+//! weight, that is its base weight plus its gas limit, doesn't fit in what is left of
+//! [`Config::BlockExecutionBudget`] fails with [`Error::ExecutionBudgetExhausted`]. The actual
+//! weight of the dispatch is charged afterwards.
+//!
+//! Runtimes should include [`CheckExecutionBudget`] in their signed extensions. It validates the
+//! dispatch weight of a contract extrinsic against the same budget and rejects it as
+//! [`InvalidTransaction::ExhaustsResources`] if it doesn't fit, so that the block author leaves
+//! it in the transaction pool for a later block instead of including a failing extrinsic. It also
+//! accounts for the weight of failed dispatches, whose storage changes are reverted.
+//!
+//! [`Event::ExecutionBudgetSaturated`] is emitted once per block when no further call fits in
+//! the budget.
+
+use crate::{
+	weights::WeightInfo, BalanceOf, BlockExecutionWeight, Call, Config, Error, Event, Pallet,
+	LOG_TARGET,
+};
+use codec::{Decode, Encode, HasCompact};
+use frame_support::{
+	dispatch::{DispatchInfo, DispatchResultWithPostInfo, PostDispatchInfo},
+	ensure,
+	traits::{Get, IsSubType},
+	weights::Weight,
+	DefaultNoBound,
+};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{DispatchInfoOf, Dispatchable, PostDispatchInfoOf, SignedExtension},
+	transaction_validity::{
+		InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
+	},
+	DispatchResult,
+};
+use sp_std::{fmt::Debug, marker::PhantomData, prelude::*};
+
+impl<T: Config> Pallet<T> {
+	/// The weight of contract execution left in the budget of the current block.
+	pub fn execution_budget_left() -> Weight {
+		T::BlockExecutionBudget::get().saturating_sub(BlockExecutionWeight::<T>::get())
+	}
+
+	/// Ensure that a dispatch of the given `weight`, its base weight plus its gas limit, fits in
+	/// the budget of the current block.
+	pub(crate) fn ensure_execution_budget(weight: Weight) -> DispatchResult {
+		ensure!(
+			weight.all_lte(Self::execution_budget_left()),
+			Error::<T>::ExecutionBudgetExhausted
+		);
+		Ok(())
+	}
+
+	/// Charge the actual weight of a successful dispatch to the budget of the current block.
+	///
+	/// The charge of a failed dispatch is reverted together with its other storage changes, it
+	/// is done by [`CheckExecutionBudget`] instead.
+	pub(crate) fn charge_execution_budget(
+		result: DispatchResultWithPostInfo,
+	) -> DispatchResultWithPostInfo {
+		if let Ok(post_info) = &result {
+			if let Some(weight) = post_info.actual_weight {
+				Self::charge_execution_weight(weight);
+			}
+		}
+		result
+	}
+
+	/// Charge `weight` to the budget of the current block.
+	fn charge_execution_weight(weight: Weight) {
+		// Saturated once not even a call with no gas fits anymore.
+		let saturated = |left: Weight| left.any_lt(T::WeightInfo::call());
+		let was_saturated = saturated(Self::execution_budget_left());
+		let consumed = BlockExecutionWeight::<T>::mutate(|consumed| {
+			consumed.saturating_accrue(weight);
+			*consumed
+		});
+		if !was_saturated && saturated(Self::execution_budget_left()) {
+			Self::deposit_event(vec![], Event::ExecutionBudgetSaturated { consumed });
+		}
+	}
+}
+
+/// Keep contract extrinsics that exceed [`Config::BlockExecutionBudget`] out of the block.
+///
+/// See the [module documentation](self).
+#[derive(Encode, Decode, DefaultNoBound, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct CheckExecutionBudget<T>(PhantomData<T>);
+
+impl<T: Config + Send + Sync> CheckExecutionBudget<T> {
+	/// Create new `SignedExtension` to check the contract execution budget.
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<T: Config + Send + Sync> Debug for CheckExecutionBudget<T> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "CheckExecutionBudget")
+	}
+
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T: Config + Send + Sync> CheckExecutionBudget<T>
+where
+	<T as frame_system::Config>::RuntimeCall: IsSubType<Call<T>>,
+	<BalanceOf<T> as HasCompact>::Type: Clone + Eq + PartialEq + Debug + TypeInfo + Encode,
+{
+	/// Whether `call` calls or instantiates a contract.
+	fn executes_contract(call: &<T as frame_system::Config>::RuntimeCall) -> bool {
+		#[allow(deprecated)]
+		let executes = matches!(
+			call.is_sub_type(),
+			Some(
+				Call::call { .. } |
+					Call::call_old_weight { .. } |
+					Call::call_sponsored { .. } |
+					Call::instantiate { .. } |
+					Call::instantiate_old_weight { .. } |
+					Call::instantiate_with_code { .. } |
+					Call::instantiate_with_code_old_weight { .. } |
+					Call::instantiate_sponsored { .. } |
+					Call::instantiate_with_code_sponsored { .. } |
+					Call::instantiate_with_address_kind { .. }
+			)
+		);
+		executes
+	}
+}
+
+impl<T: Config + Send + Sync> SignedExtension for CheckExecutionBudget<T>
+where
+	<T as frame_system::Config>::RuntimeCall:
+		Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo> + IsSubType<Call<T>>,
+	<BalanceOf<T> as HasCompact>::Type: Clone + Eq + PartialEq + Debug + TypeInfo + Encode,
+{
+	type AccountId = T::AccountId;
+	type Call = <T as frame_system::Config>::RuntimeCall;
+	type AdditionalSigned = ();
+	/// Whether the extrinsic calls or instantiates a contract.
+	type Pre = bool;
+	const IDENTIFIER: &'static str = "CheckExecutionBudget";
+
+	fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		_who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		if Self::executes_contract(call) && info.weight.any_gt(Pallet::<T>::execution_budget_left())
+		{
+			log::debug!(
+				target: LOG_TARGET,
+				"Contract execution budget exhausted, deferring extrinsic of weight {:?}",
+				info.weight,
+			);
+			return Err(InvalidTransaction::ExhaustsResources.into())
+		}
+		Ok(ValidTransaction::default())
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		self.validate(who, call, info, len)?;
+		Ok(Self::executes_contract(call))
+	}
+
+	fn post_dispatch(
+		pre: Option<Self::Pre>,
+		info: &DispatchInfoOf<Self::Call>,
+		post_info: &PostDispatchInfoOf<Self::Call>,
+		_len: usize,
+		result: &DispatchResult,
+	) -> Result<(), TransactionValidityError> {
+		if pre == Some(true) && result.is_err() {
+			Pallet::<T>::charge_execution_weight(post_info.calc_actual_weight(info));
+		}
+		Ok(())
+	}
+}
@@ -15,7 +15,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{exec::ExecError, Config, Error};
+use crate::{exec::ExecError, primitives::HostFnMetering, Config, Error};
 use core::marker::PhantomData;
 use frame_support::{
 	dispatch::{DispatchErrorWithPostInfo, DispatchResultWithPostInfo, PostDispatchInfo},
@@ -24,6 +24,7 @@ use frame_support::{
 };
 use sp_core::Get;
 use sp_runtime::{traits::Zero, DispatchError, Saturating};
+use sp_std::{collections::btree_map::BTreeMap, prelude::*};
 
 #[cfg(test)]
 use std::{any::Any, fmt::Debug};
@@ -109,6 +110,8 @@ pub struct GasMeter<T: Config> {
 	/// separately in order to avoid the loss of precision that happens when converting from
 	/// ref_time to the execution engine unit.
 	executor_consumed: u64,
+	/// The invocation count and weight per host function, if metering was enabled.
+	host_fn_metering: Option<BTreeMap<&'static str, (u32, Weight)>>,
 	_phantom: PhantomData<T>,
 	#[cfg(test)]
 	tokens: Vec<ErasedToken>,
@@ -121,6 +124,7 @@ impl<T: Config> GasMeter<T> {
 			gas_left: gas_limit,
 			gas_left_lowest: gas_limit,
 			executor_consumed: 0,
+			host_fn_metering: None,
 			_phantom: PhantomData,
 			#[cfg(test)]
 			tokens: Vec::new(),
@@ -147,7 +151,11 @@ impl<T: Config> GasMeter<T> {
 		)
 		.min(self.gas_left);
 		self.gas_left -= amount;
-		GasMeter::new(amount)
+		let mut nested = GasMeter::new(amount);
+		if self.host_fn_metering.is_some() {
+			nested.enable_host_fn_metering();
+		}
+		nested
 	}
 
 	/// Absorb the remaining gas of a nested meter after we are done using it.
@@ -156,6 +164,48 @@ impl<T: Config> GasMeter<T> {
 			.saturating_sub(nested.gas_required())
 			.min(self.gas_left_lowest);
 		self.gas_left += nested.gas_left;
+		if let (Some(metering), Some(nested)) =
+			(self.host_fn_metering.as_mut(), nested.host_fn_metering)
+		{
+			for (name, (invocations, weight)) in nested {
+				let entry = metering.entry(name).or_default();
+				entry.0.saturating_accrue(invocations);
+				entry.1.saturating_accrue(weight);
+			}
+		}
+	}
+
+	/// Record the invocation count and weight of every host function called with this meter.
+	///
+	/// Meters nested from this one record as well and their records are merged into this one
+	/// when absorbed.
+	pub fn enable_host_fn_metering(&mut self) {
+		self.host_fn_metering = Some(Default::default());
+	}
+
+	/// Record an invocation of the host function `name` which charged `weight`.
+	///
+	/// Does nothing unless metering was enabled.
+	pub fn note_host_fn(&mut self, name: &'static str, weight: Weight) {
+		if let Some(metering) = self.host_fn_metering.as_mut() {
+			let entry = metering.entry(name).or_default();
+			entry.0.saturating_inc();
+			entry.1.saturating_accrue(weight);
+		}
+	}
+
+	/// The recorded invocations of host functions, if metering was enabled.
+	pub fn host_fn_metering(&self) -> Option<Vec<HostFnMetering>> {
+		self.host_fn_metering.as_ref().map(|metering| {
+			metering
+				.iter()
+				.map(|(name, (invocations, weight))| HostFnMetering {
+					name: name.as_bytes().to_vec(),
+					invocations: *invocations,
+					weight: *weight,
+				})
+				.collect()
+		})
 	}
 
 	/// Account for used gas.
@@ -232,6 +282,11 @@ impl<T: Config> GasMeter<T> {
 		Ok(Syncable(chargable_executor_resource))
 	}
 
+	/// Returns the gas limit the meter was created with.
+	pub fn gas_limit(&self) -> Weight {
+		self.gas_limit
+	}
+
 	/// Returns the amount of gas that is required to run the same call.
 	///
 	/// This can be different from `gas_spent` because due to `adjust_gas` the amount of
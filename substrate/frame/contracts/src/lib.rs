@@ -89,12 +89,16 @@
 
 mod address;
 mod benchmarking;
+mod code_deposit;
+mod code_pruning;
 mod exec;
+mod execution_budget;
 mod gas;
 mod primitives;
 pub use primitives::*;
 
 mod schedule;
+mod scheduled_call;
 mod storage;
 mod wasm;
 
@@ -110,6 +114,7 @@ use crate::{
 		AccountIdOf, ErrorOrigin, ExecError, Executable, Ext, Key, MomentOf, Stack as ExecStack,
 	},
 	gas::GasMeter,
+	scheduled_call::ScheduledCall,
 	storage::{meter::Meter as StorageMeter, ContractInfo, DeletionQueueManager},
 	wasm::{CodeInfo, WasmBlob},
 };
@@ -121,10 +126,10 @@ use frame_support::{
 	error::BadOrigin,
 	traits::{
 		fungible::{Inspect, Mutate, MutateHold},
-		ConstU32, Contains, Get, Randomness, Time,
+		ConstU32, Contains, EnsureOrigin, Get, Randomness, Time,
 	},
 	weights::Weight,
-	BoundedVec, DefaultNoBound, RuntimeDebugNoBound,
+	BoundedBTreeMap, BoundedVec, DefaultNoBound, RuntimeDebugNoBound,
 };
 use frame_system::{
 	ensure_signed,
@@ -140,9 +145,11 @@ use sp_runtime::{
 use sp_std::{fmt::Debug, prelude::*};
 
 pub use crate::{
-	address::{AddressGenerator, DefaultAddressGenerator},
+	address::{AddressGenerator, AddressKind, DefaultAddressGenerator},
+	code_deposit::TieredCodeDeposit,
 	debug::Tracing,
 	exec::Frame,
+	execution_budget::CheckExecutionBudget,
 	migration::{MigrateSequence, Migration, NoopMigration},
 	pallet::*,
 	schedule::{HostFnWeights, InstructionWeights, Limits, Schedule},
@@ -153,6 +160,9 @@ pub use weights::WeightInfo;
 #[cfg(doc)]
 pub use crate::wasm::api_doc;
 
+#[cfg(feature = "runtime-benchmarks")]
+pub use crate::benchmarking::workload;
+
 type CodeHash<T> = <T as frame_system::Config>::Hash;
 type TrieId = BoundedVec<u8, ConstU32<128>>;
 type BalanceOf<T> =
@@ -160,8 +170,10 @@ type BalanceOf<T> =
 type CodeVec<T> = BoundedVec<u8, <T as Config>::MaxCodeLen>;
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
 type DebugBufferVec<T> = BoundedVec<u8, <T as Config>::MaxDebugBufferLen>;
+type ImmutableData<T> = BoundedVec<u8, <T as Config>::MaxImmutableDataLen>;
 type EventRecordOf<T> =
 	EventRecord<<T as frame_system::Config>::RuntimeEvent, <T as frame_system::Config>::Hash>;
+type XcmQueryIdOf<T> = <<T as Config>::Xcm as xcm_executor::traits::QueryHandler>::QueryId;
 
 /// The old weight type.
 ///
@@ -184,6 +196,9 @@ const SENTINEL: u32 = u32::MAX;
 /// Example: `RUST_LOG=runtime::contracts=debug my_code --dev`
 const LOG_TARGET: &str = "runtime::contracts";
 
+/// The maximum size in bytes of the storage items returned by a single [`Pallet::contract_dump`].
+pub const CONTRACT_DUMP_STORAGE_LIMIT: u32 = 2 * 1024 * 1024;
+
 /// Wrapper around `PhantomData` to prevent it being filtered by `scale-info`.
 ///
 /// `scale-info` filters out `PhantomData` fields because usually we are only interested
@@ -223,7 +238,7 @@ pub mod pallet {
 	use sp_runtime::Perbill;
 
 	/// The current storage version.
-	pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(15);
+	pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(18);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -244,6 +259,13 @@ pub mod pallet {
 		/// to supply a dummy implementation for this type (because it is never used).
 		type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
 
+		/// The source of the randomness handed out by the `randomness` host function.
+		///
+		/// Contracts are only given seeds derived from their own address and a domain of their
+		/// choosing, together with the block since which the seed was known. Use e.g. BABE's
+		/// `RandomnessFromOneEpochAgo` or a VRF based pallet.
+		type RandomnessSource: Randomness<Self::Hash, BlockNumberFor<Self>>;
+
 		/// The fungible in which fees are paid and contract balances are held.
 		type Currency: Inspect<Self::AccountId>
 			+ Mutate<Self::AccountId>
@@ -326,11 +348,24 @@ pub mod pallet {
 
 		/// The percentage of the storage deposit that should be held for using a code hash.
 		/// Instantiating a contract, or calling [`chain_extension::Ext::lock_delegate_dependency`]
-		/// protects the code from being removed. In order to prevent abuse these actions are
-		/// protected with a percentage of the code deposit.
+		/// or [`chain_extension::Ext::pin_code_hash`] protects the code from being removed. In
+		/// order to prevent abuse these actions are protected with a percentage of the code
+		/// deposit.
 		#[pallet::constant]
 		type CodeHashLockupDepositPercent: Get<Perbill>;
 
+		/// The deposit charged for storing code on top of the one charged per byte and item,
+		/// given the length of the code in bytes.
+		///
+		/// Allows charging giant blobs more than linearly, see [`TieredCodeDeposit`]. As the
+		/// deposit held for instantiating a contract is a percentage of the code deposit, it is
+		/// affected, too. Use `()` to charge nothing on top.
+		///
+		/// # Note
+		///
+		/// Changing this only affects code uploaded afterwards.
+		type CodeDepositCurve: Convert<u32, BalanceOf<Self>>;
+
 		/// The address generator used to generate the addresses of contracts.
 		type AddressGenerator: AddressGenerator<Self>;
 
@@ -346,11 +381,86 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxStorageKeyLen: Get<u32>;
 
+		/// The maximum size in bytes of a single item a contract can write to its storage.
+		///
+		/// This bounds how costly a single item is to migrate or to prove, independently of the
+		/// deposit paid for it. Values above [`Limits::payload_len`](crate::Limits::payload_len)
+		/// have no effect, as no larger value can be passed to `seal_set_storage`.
+		#[pallet::constant]
+		type MaxStorageItemSize: Get<u32>;
+
+		/// The maximum size in bytes of the immutable data a contract can set at instantiation.
+		///
+		/// The immutable data is stored with the contract info, so this bounds its size as well as
+		/// the cost of loading it on every call into the contract.
+		#[pallet::constant]
+		type MaxImmutableDataLen: Get<u32>;
+
 		/// The maximum number of delegate_dependencies that a contract can lock with
 		/// [`chain_extension::Ext::lock_delegate_dependency`].
 		#[pallet::constant]
 		type MaxDelegateDependencies: Get<u32>;
 
+		/// The maximum number of code hashes that a contract can pin with
+		/// [`chain_extension::Ext::pin_code_hash`].
+		#[pallet::constant]
+		type MaxPinnedCodeHashes: Get<u32>;
+
+		/// The maximum length in bytes of a topic passed to `deposit_event_v2`.
+		#[pallet::constant]
+		type MaxEventTopicLen: Get<u32>;
+
+		/// The maximum number of topics registered for a contract by `deposit_event_v2`.
+		///
+		/// Once the registry of a contract is full, new topics are still emitted, but their
+		/// preimage is no longer registered.
+		#[pallet::constant]
+		type MaxRegisteredTopics: Get<u32>;
+
+		/// The maximum number of calls that contracts can schedule for the same block.
+		#[pallet::constant]
+		type MaxScheduledCallsPerBlock: Get<u32>;
+
+		/// The maximum length in bytes of the input data of a scheduled call.
+		#[pallet::constant]
+		type MaxScheduledCallDataLen: Get<u32>;
+
+		/// The maximum sum of the gas limits of all calls scheduled for the same block.
+		///
+		/// The scheduled calls are executed in `on_initialize`. This value should leave enough
+		/// room in the block for other extrinsics.
+		#[pallet::constant]
+		type MaxScheduledCallsWeight: Get<Weight>;
+
+		/// The maximum weight of the contract calls and instantiations dispatched in a block.
+		///
+		/// Bounds the share of the block weight taken by contract execution. Runtimes should
+		/// include [`CheckExecutionBudget`] in their signed extensions, so that extrinsics
+		/// exceeding the budget are left in the transaction pool for a later block. Use
+		/// `Weight::MAX` to not bound contract execution.
+		#[pallet::constant]
+		type BlockExecutionBudget: Get<Weight>;
+
+		/// The number of blocks after which uploaded code that no contract uses is removed.
+		///
+		/// The codes are pruned in `on_idle` and their deposit is refunded to the uploader. Use
+		/// `None` to keep unused code until it is removed with [`Pallet::remove_code`].
+		#[pallet::constant]
+		type CodePruningDelay: Get<Option<BlockNumberFor<Self>>>;
+
+		/// The origins that may instantiate contracts unless they are denied by
+		/// [`Pallet::set_instantiation_permission`].
+		///
+		/// Use [`EnsureSigned`](frame_system::EnsureSigned) to let everyone instantiate contracts,
+		/// or [`EnsureNever`](frame_support::traits::EnsureNever) to only allow the accounts
+		/// given the [`InstantiationPermission::Allowed`] permission, e.g. during a permissioned
+		/// deployment phase. Only the origin of a call or instantiation is checked, contracts
+		/// instantiated by an allowed account may instantiate further contracts.
+		type InstantiateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The origin that may allow or deny accounts to instantiate contracts.
+		type InstantiationPermissionOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
 		/// Make contract callable functions marked as `#[unstable]` available.
 		///
 		/// Contracts that use `#[unstable]` functions won't be able to be uploaded unless
@@ -363,10 +473,27 @@ pub mod pallet {
 		#[pallet::constant]
 		type UnsafeUnstableInterface: Get<bool>;
 
+		/// Emit [`Event::FeeBreakdown`] for every successful `call` and `call_sponsored`.
+		///
+		/// Wallets use it to show what a contract call was charged for. Disable it to save the
+		/// event's weight and space in blocks.
+		#[pallet::constant]
+		type FeeBreakdownEvents: Get<bool>;
+
 		/// The maximum length of the debug buffer in bytes.
 		#[pallet::constant]
 		type MaxDebugBufferLen: Get<u32>;
 
+		/// The maximum number of read-only call results cached within one transaction.
+		///
+		/// A contract calling another one with [`CallFlags::READ_ONLY`] is served a result
+		/// cached earlier in the same transaction when the caller, callee and input match.
+		/// Every state change drops the cache. Set to `0` to disable caching.
+		///
+		/// [`CallFlags::READ_ONLY`]: pallet_contracts_uapi::CallFlags::READ_ONLY
+		#[pallet::constant]
+		type CallCacheSize: Get<u32>;
+
 		/// Overarching hold reason.
 		type RuntimeHoldReason: From<HoldReason>;
 
@@ -409,11 +536,32 @@ pub mod pallet {
 			<Self as frame_system::Config>::RuntimeCall,
 			BlockNumberFor<Self>,
 		>;
+
+		/// Weighs the XCM programs passed to `xcm_weigh_message`.
+		///
+		/// This should be the weigher of the local XCM executor, so that contracts get the weight
+		/// of executing a program through `xcm_execute`. Use `()` if contracts are not supposed
+		/// to weigh XCM programs.
+		type XcmWeigher: xcm_executor::traits::WeightBounds<
+			<Self as frame_system::Config>::RuntimeCall,
+		>;
+
+		/// The destinations contracts are allowed to send XCM programs to, either directly with
+		/// `xcm_send` or through the instructions of a program executed with `xcm_execute`.
+		type XcmDestinations: Contains<xcm::latest::Location>;
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-		fn on_idle(_block: BlockNumberFor<T>, mut remaining_weight: Weight) -> Weight {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			ScheduledCall::<T>::service(now)
+		}
+
+		fn on_finalize(_now: BlockNumberFor<T>) {
+			<BlockExecutionWeight<T>>::kill();
+		}
+
+		fn on_idle(block: BlockNumberFor<T>, mut remaining_weight: Weight) -> Weight {
 			use migration::MigrateResult::*;
 
 			loop {
@@ -432,8 +580,16 @@ pub mod pallet {
 				}
 			}
 
-			ContractInfo::<T>::process_deletion_queue_batch(remaining_weight)
-				.saturating_add(T::WeightInfo::on_process_deletion_queue_batch())
+			let deletion_weight = ContractInfo::<T>::process_deletion_queue_batch(remaining_weight)
+				.saturating_add(T::WeightInfo::on_process_deletion_queue_batch());
+			remaining_weight.saturating_reduce(deletion_weight);
+
+			let pruning_weight = match T::CodePruningDelay::get() {
+				Some(delay) =>
+					code_pruning::process_code_pruning_batch::<T>(block, delay, remaining_weight),
+				None => Weight::zero(),
+			};
+			deletion_weight.saturating_add(pruning_weight)
 		}
 
 		fn integrity_test() {
@@ -689,7 +845,8 @@ pub mod pallet {
 		/// * `value`: The balance to transfer from the `origin` to `dest`.
 		/// * `gas_limit`: The gas limit enforced when executing the constructor.
 		/// * `storage_deposit_limit`: The maximum amount of balance that can be charged from the
-		///   caller to pay for the storage consumed.
+		///   caller to pay for the storage consumed. It applies to all contracts called during the
+		///   call and is put on hold until the call finishes.
 		/// * `data`: The input data to pass to the contract.
 		///
 		/// * If the account is a smart-contract account, the associated code will be
@@ -698,7 +855,11 @@ pub mod pallet {
 		/// * If no account exists and the call value is not less than `existential_deposit`,
 		/// a regular account will be created and any value will be transferred.
 		#[pallet::call_index(6)]
-		#[pallet::weight(T::WeightInfo::call().saturating_add(*gas_limit))]
+		#[pallet::weight(
+			T::WeightInfo::call()
+				.saturating_add(Pallet::<T>::fee_breakdown_weight())
+				.saturating_add(*gas_limit)
+		)]
 		pub fn call(
 			origin: OriginFor<T>,
 			dest: AccountIdLookupOf<T>,
@@ -708,6 +869,11 @@ pub mod pallet {
 			data: Vec<u8>,
 		) -> DispatchResultWithPostInfo {
 			Migration::<T>::ensure_migrated()?;
+			Self::ensure_execution_budget(
+				T::WeightInfo::call()
+					.saturating_add(Self::fee_breakdown_weight())
+					.saturating_add(gas_limit),
+			)?;
 			let common = CommonInput {
 				origin: Origin::from_runtime_origin(origin)?,
 				value,
@@ -715,16 +881,26 @@ pub mod pallet {
 				gas_limit: gas_limit.into(),
 				storage_deposit_limit: storage_deposit_limit.map(Into::into),
 				debug_message: None,
+				deposit_payer: None,
+				host_fn_metering: false,
 			};
 			let dest = T::Lookup::lookup(dest)?;
-			let mut output =
-				CallInput::<T> { dest, determinism: Determinism::Enforced }.run_guarded(common);
+			let mut output = CallInput::<T> {
+				dest: dest.clone(),
+				determinism: Determinism::Enforced,
+				read_only: false,
+			}
+			.run_guarded(common);
 			if let Ok(retval) = &output.result {
 				if retval.did_revert() {
 					output.result = Err(<Error<T>>::ContractReverted.into());
 				}
 			}
-			output.gas_meter.into_dispatch_result(output.result, T::WeightInfo::call())
+			Self::deposit_fee_breakdown(dest, &output);
+			Self::charge_execution_budget(output.gas_meter.into_dispatch_result(
+				output.result,
+				T::WeightInfo::call().saturating_add(Self::fee_breakdown_weight()),
+			))
 		}
 
 		/// Instantiates a new contract from the supplied `code` optionally transferring
@@ -767,6 +943,14 @@ pub mod pallet {
 			salt: Vec<u8>,
 		) -> DispatchResultWithPostInfo {
 			Migration::<T>::ensure_migrated()?;
+			Self::ensure_execution_budget(
+				T::WeightInfo::instantiate_with_code(
+					code.len() as u32,
+					data.len() as u32,
+					salt.len() as u32,
+				)
+				.saturating_add(gas_limit),
+			)?;
 			let origin = ensure_signed(origin)?;
 			let code_len = code.len() as u32;
 
@@ -791,20 +975,26 @@ pub mod pallet {
 				gas_limit,
 				storage_deposit_limit,
 				debug_message: None,
+				deposit_payer: None,
+				host_fn_metering: false,
 			};
 
-			let mut output =
-				InstantiateInput::<T> { code: WasmCode::Wasm(module), salt }.run_guarded(common);
+			let mut output = InstantiateInput::<T> {
+				code: WasmCode::Wasm(module),
+				salt,
+				address_kind: AddressKind::Deployer,
+			}
+			.run_guarded(common);
 			if let Ok(retval) = &output.result {
 				if retval.1.did_revert() {
 					output.result = Err(<Error<T>>::ContractReverted.into());
 				}
 			}
 
-			output.gas_meter.into_dispatch_result(
+			Self::charge_execution_budget(output.gas_meter.into_dispatch_result(
 				output.result.map(|(_address, output)| output),
 				T::WeightInfo::instantiate_with_code(code_len, data_len, salt_len),
-			)
+			))
 		}
 
 		/// Instantiates a contract from a previously deployed wasm binary.
@@ -826,6 +1016,10 @@ pub mod pallet {
 			salt: Vec<u8>,
 		) -> DispatchResultWithPostInfo {
 			Migration::<T>::ensure_migrated()?;
+			Self::ensure_execution_budget(
+				T::WeightInfo::instantiate(data.len() as u32, salt.len() as u32)
+					.saturating_add(gas_limit),
+			)?;
 			let data_len = data.len() as u32;
 			let salt_len = salt.len() as u32;
 			let common = CommonInput {
@@ -835,18 +1029,24 @@ pub mod pallet {
 				gas_limit,
 				storage_deposit_limit: storage_deposit_limit.map(Into::into),
 				debug_message: None,
+				deposit_payer: None,
+				host_fn_metering: false,
 			};
-			let mut output = InstantiateInput::<T> { code: WasmCode::CodeHash(code_hash), salt }
-				.run_guarded(common);
+			let mut output = InstantiateInput::<T> {
+				code: WasmCode::CodeHash(code_hash),
+				salt,
+				address_kind: AddressKind::Deployer,
+			}
+			.run_guarded(common);
 			if let Ok(retval) = &output.result {
 				if retval.1.did_revert() {
 					output.result = Err(<Error<T>>::ContractReverted.into());
 				}
 			}
-			output.gas_meter.into_dispatch_result(
+			Self::charge_execution_budget(output.gas_meter.into_dispatch_result(
 				output.result.map(|(_address, output)| output),
 				T::WeightInfo::instantiate(data_len, salt_len),
-			)
+			))
 		}
 
 		/// When a migration is in progress, this dispatchable can be used to run migration steps.
@@ -875,6 +1075,312 @@ pub mod pallet {
 				},
 			}
 		}
+
+		/// Allow or deny `who` to instantiate contracts regardless of
+		/// [`Config::InstantiateOrigin`].
+		///
+		/// Passing `None` removes the permission so that the `InstantiateOrigin` applies again.
+		/// Must be called by the [`Config::InstantiationPermissionOrigin`].
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::set_instantiation_permission())]
+		pub fn set_instantiation_permission(
+			origin: OriginFor<T>,
+			who: AccountIdLookupOf<T>,
+			permission: Option<InstantiationPermission>,
+		) -> DispatchResult {
+			T::InstantiationPermissionOrigin::ensure_origin(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			<InstantiationPermissions<T>>::set(&who, permission);
+			Self::deposit_event(
+				vec![T::Hashing::hash_of(&who)],
+				Event::InstantiationPermissionSet { account: who, permission },
+			);
+			Ok(())
+		}
+
+		/// Allow `beneficiary` to charge up to `allowance` of storage deposit to the origin.
+		///
+		/// The allowance is used by [`Self::call_sponsored`], [`Self::instantiate_sponsored`] and
+		/// [`Self::instantiate_with_code_sponsored`] and replaces any previous allowance. Passing
+		/// `None` revokes it.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::approve_deposit_sponsorship())]
+		pub fn approve_deposit_sponsorship(
+			origin: OriginFor<T>,
+			beneficiary: AccountIdLookupOf<T>,
+			allowance: Option<DepositAllowance<BalanceOf<T>>>,
+		) -> DispatchResult {
+			let sponsor = ensure_signed(origin)?;
+			let beneficiary = T::Lookup::lookup(beneficiary)?;
+			<DepositAllowances<T>>::set(&sponsor, &beneficiary, allowance);
+			Self::deposit_event(
+				vec![T::Hashing::hash_of(&sponsor), T::Hashing::hash_of(&beneficiary)],
+				Event::DepositSponsorshipApproved { sponsor, beneficiary, allowance },
+			);
+			Ok(())
+		}
+
+		/// Makes a call to an account like [`Self::call`], but charges the storage deposit to
+		/// `deposit_payer` instead of the caller.
+		///
+		/// `deposit_payer` must have approved the caller with
+		/// [`Self::approve_deposit_sponsorship`]. The `storage_deposit_limit` is capped by both
+		/// the remaining allowance and its per-call limit. The remaining allowance is reduced by
+		/// the deposit charged for the call. Refunds are paid to `deposit_payer` but do not
+		/// restore the allowance.
+		#[pallet::call_index(12)]
+		#[pallet::weight(
+			T::WeightInfo::call()
+				.saturating_add(T::WeightInfo::deposit_sponsorship())
+				.saturating_add(Pallet::<T>::fee_breakdown_weight())
+				.saturating_add(*gas_limit)
+		)]
+		pub fn call_sponsored(
+			origin: OriginFor<T>,
+			deposit_payer: AccountIdLookupOf<T>,
+			dest: AccountIdLookupOf<T>,
+			#[pallet::compact] value: BalanceOf<T>,
+			gas_limit: Weight,
+			storage_deposit_limit: Option<<BalanceOf<T> as codec::HasCompact>::Type>,
+			data: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			Migration::<T>::ensure_migrated()?;
+			Self::ensure_execution_budget(
+				T::WeightInfo::call()
+					.saturating_add(T::WeightInfo::deposit_sponsorship())
+					.saturating_add(Self::fee_breakdown_weight())
+					.saturating_add(gas_limit),
+			)?;
+			let common = CommonInput {
+				origin: Origin::from_account_id(ensure_signed(origin)?),
+				value,
+				data,
+				gas_limit: gas_limit.into(),
+				storage_deposit_limit: storage_deposit_limit.map(Into::into),
+				debug_message: None,
+				deposit_payer: Some(T::Lookup::lookup(deposit_payer)?),
+				host_fn_metering: false,
+			};
+			let dest = T::Lookup::lookup(dest)?;
+			let mut output = CallInput::<T> {
+				dest: dest.clone(),
+				determinism: Determinism::Enforced,
+				read_only: false,
+			}
+			.run_guarded(common);
+			if let Ok(retval) = &output.result {
+				if retval.did_revert() {
+					output.result = Err(<Error<T>>::ContractReverted.into());
+				}
+			}
+			Self::deposit_fee_breakdown(dest, &output);
+			Self::charge_execution_budget(
+				output.gas_meter.into_dispatch_result(
+					output.result,
+					T::WeightInfo::call()
+						.saturating_add(T::WeightInfo::deposit_sponsorship())
+						.saturating_add(Self::fee_breakdown_weight()),
+				),
+			)
+		}
+
+		/// Instantiates a contract like [`Self::instantiate`], but charges the storage deposit to
+		/// `deposit_payer` instead of the caller.
+		///
+		/// The allowance works as described in [`Self::call_sponsored`]. The `value` is still
+		/// transferred from the caller.
+		#[pallet::call_index(13)]
+		#[pallet::weight(
+			T::WeightInfo::instantiate(data.len() as u32, salt.len() as u32)
+				.saturating_add(T::WeightInfo::deposit_sponsorship())
+				.saturating_add(*gas_limit)
+		)]
+		pub fn instantiate_sponsored(
+			origin: OriginFor<T>,
+			deposit_payer: AccountIdLookupOf<T>,
+			#[pallet::compact] value: BalanceOf<T>,
+			gas_limit: Weight,
+			storage_deposit_limit: Option<<BalanceOf<T> as codec::HasCompact>::Type>,
+			code_hash: CodeHash<T>,
+			data: Vec<u8>,
+			salt: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			Migration::<T>::ensure_migrated()?;
+			Self::ensure_execution_budget(
+				T::WeightInfo::instantiate(data.len() as u32, salt.len() as u32)
+					.saturating_add(T::WeightInfo::deposit_sponsorship())
+					.saturating_add(gas_limit),
+			)?;
+			let data_len = data.len() as u32;
+			let salt_len = salt.len() as u32;
+			let common = CommonInput {
+				origin: Origin::from_account_id(ensure_signed(origin)?),
+				value,
+				data,
+				gas_limit,
+				storage_deposit_limit: storage_deposit_limit.map(Into::into),
+				debug_message: None,
+				deposit_payer: Some(T::Lookup::lookup(deposit_payer)?),
+				host_fn_metering: false,
+			};
+			let mut output = InstantiateInput::<T> {
+				code: WasmCode::CodeHash(code_hash),
+				salt,
+				address_kind: AddressKind::Deployer,
+			}
+			.run_guarded(common);
+			if let Ok(retval) = &output.result {
+				if retval.1.did_revert() {
+					output.result = Err(<Error<T>>::ContractReverted.into());
+				}
+			}
+			Self::charge_execution_budget(
+				output.gas_meter.into_dispatch_result(
+					output.result.map(|(_address, output)| output),
+					T::WeightInfo::instantiate(data_len, salt_len)
+						.saturating_add(T::WeightInfo::deposit_sponsorship()),
+				),
+			)
+		}
+
+		/// Instantiates a contract like [`Self::instantiate_with_code`], but charges the storage
+		/// deposit of both the code and the contract to `deposit_payer` instead of the caller.
+		///
+		/// The allowance works as described in [`Self::call_sponsored`], its per-call limit
+		/// bounding the sum of both deposits. `deposit_payer` becomes the owner of the uploaded
+		/// code, so that it receives the code deposit back when the code is removed.
+		#[pallet::call_index(15)]
+		#[pallet::weight(
+			T::WeightInfo::instantiate_with_code(code.len() as u32, data.len() as u32, salt.len() as u32)
+				.saturating_add(T::WeightInfo::deposit_sponsorship().saturating_mul(2))
+				.saturating_add(*gas_limit)
+		)]
+		pub fn instantiate_with_code_sponsored(
+			origin: OriginFor<T>,
+			deposit_payer: AccountIdLookupOf<T>,
+			#[pallet::compact] value: BalanceOf<T>,
+			gas_limit: Weight,
+			storage_deposit_limit: Option<<BalanceOf<T> as codec::HasCompact>::Type>,
+			code: Vec<u8>,
+			data: Vec<u8>,
+			salt: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			Migration::<T>::ensure_migrated()?;
+			Self::ensure_execution_budget(
+				T::WeightInfo::instantiate_with_code(
+					code.len() as u32,
+					data.len() as u32,
+					salt.len() as u32,
+				)
+				.saturating_add(T::WeightInfo::deposit_sponsorship().saturating_mul(2))
+				.saturating_add(gas_limit),
+			)?;
+			let origin = ensure_signed(origin)?;
+			let deposit_payer = T::Lookup::lookup(deposit_payer)?;
+			let code_len = code.len() as u32;
+
+			let storage_deposit_limit = Self::sponsored_deposit_limit(
+				&deposit_payer,
+				&origin,
+				storage_deposit_limit.map(Into::into),
+			)?;
+			let (module, upload_deposit) = Self::try_upload_code(
+				deposit_payer.clone(),
+				code,
+				Some(storage_deposit_limit),
+				Determinism::Enforced,
+				None,
+			)?;
+			Self::consume_deposit_allowance(
+				&deposit_payer,
+				&origin,
+				&StorageDeposit::Charge(upload_deposit),
+			);
+
+			let data_len = data.len() as u32;
+			let salt_len = salt.len() as u32;
+			let common = CommonInput {
+				origin: Origin::from_account_id(origin),
+				value,
+				data,
+				gas_limit,
+				storage_deposit_limit: Some(storage_deposit_limit.saturating_sub(upload_deposit)),
+				debug_message: None,
+				deposit_payer: Some(deposit_payer),
+				host_fn_metering: false,
+			};
+			let mut output = InstantiateInput::<T> {
+				code: WasmCode::Wasm(module),
+				salt,
+				address_kind: AddressKind::Deployer,
+			}
+			.run_guarded(common);
+			if let Ok(retval) = &output.result {
+				if retval.1.did_revert() {
+					output.result = Err(<Error<T>>::ContractReverted.into());
+				}
+			}
+			Self::charge_execution_budget(
+				output.gas_meter.into_dispatch_result(
+					output.result.map(|(_address, output)| output),
+					T::WeightInfo::instantiate_with_code(code_len, data_len, salt_len)
+						.saturating_add(T::WeightInfo::deposit_sponsorship().saturating_mul(2)),
+				),
+			)
+		}
+
+		/// Instantiates a contract like [`Self::instantiate`], deriving its address as selected
+		/// by `address_kind`.
+		///
+		/// With [`AddressKind::Namespaced`] the address only depends on the namespace, the
+		/// `code_hash`, the `data` and the `salt`, so that the same contract can be deployed to
+		/// the same address by anyone and on any chain. Instantiation fails with
+		/// [`Error::NamespacedAddressTaken`] if a contract already exists at that address, and
+		/// emits [`Event::InstantiatedNamespaced`] on success.
+		#[pallet::call_index(14)]
+		#[pallet::weight(
+			T::WeightInfo::instantiate(data.len() as u32, salt.len() as u32).saturating_add(*gas_limit)
+		)]
+		pub fn instantiate_with_address_kind(
+			origin: OriginFor<T>,
+			#[pallet::compact] value: BalanceOf<T>,
+			gas_limit: Weight,
+			storage_deposit_limit: Option<<BalanceOf<T> as codec::HasCompact>::Type>,
+			code_hash: CodeHash<T>,
+			data: Vec<u8>,
+			salt: Vec<u8>,
+			address_kind: AddressKind,
+		) -> DispatchResultWithPostInfo {
+			Migration::<T>::ensure_migrated()?;
+			Self::ensure_execution_budget(
+				T::WeightInfo::instantiate(data.len() as u32, salt.len() as u32)
+					.saturating_add(gas_limit),
+			)?;
+			let data_len = data.len() as u32;
+			let salt_len = salt.len() as u32;
+			let common = CommonInput {
+				origin: Origin::from_runtime_origin(origin)?,
+				value,
+				data,
+				gas_limit,
+				storage_deposit_limit: storage_deposit_limit.map(Into::into),
+				debug_message: None,
+				deposit_payer: None,
+				host_fn_metering: false,
+			};
+			let mut output =
+				InstantiateInput::<T> { code: WasmCode::CodeHash(code_hash), salt, address_kind }
+					.run_guarded(common);
+			if let Ok(retval) = &output.result {
+				if retval.1.did_revert() {
+					output.result = Err(<Error<T>>::ContractReverted.into());
+				}
+			}
+			Self::charge_execution_budget(output.gas_meter.into_dispatch_result(
+				output.result.map(|(_address, output)| output),
+				T::WeightInfo::instantiate(data_len, salt_len),
+			))
+		}
 	}
 
 	#[pallet::event]
@@ -934,6 +1440,14 @@ pub mod pallet {
 			contract: T::AccountId,
 		},
 
+		/// The fees of a successful contract call, see [`Config::FeeBreakdownEvents`].
+		FeeBreakdown {
+			/// The contract that was called.
+			contract: T::AccountId,
+			/// What the call was charged for.
+			breakdown: FeeBreakdown<BalanceOf<T>>,
+		},
+
 		/// A contract delegate called a code hash.
 		///
 		/// # Note
@@ -962,6 +1476,92 @@ pub mod pallet {
 			to: T::AccountId,
 			amount: BalanceOf<T>,
 		},
+
+		/// A contract scheduled a call to itself.
+		CallScheduled {
+			/// The contract that scheduled the call.
+			contract: T::AccountId,
+			/// The block in which the call will be executed.
+			when: BlockNumberFor<T>,
+			/// The index of the call among the calls scheduled for `when`.
+			index: u32,
+		},
+
+		/// A contract cancelled a call it had scheduled.
+		ScheduledCallCancelled {
+			/// The contract that cancelled the call.
+			contract: T::AccountId,
+			/// The block in which the call would have been executed.
+			when: BlockNumberFor<T>,
+			/// The index of the call among the calls scheduled for `when`.
+			index: u32,
+		},
+
+		/// A scheduled call was executed.
+		ScheduledCallExecuted {
+			/// The contract that was called.
+			contract: T::AccountId,
+			/// The index of the call among the calls scheduled for the current block.
+			index: u32,
+			/// The result of the call.
+			result: DispatchResult,
+		},
+
+		/// The permission of an account to instantiate contracts was changed.
+		InstantiationPermissionSet {
+			/// The account whose permission was changed.
+			account: T::AccountId,
+			/// The new permission, `None` if [`Config::InstantiateOrigin`] applies.
+			permission: Option<InstantiationPermission>,
+		},
+
+		/// A sponsor changed the storage deposit it pays on behalf of an account.
+		DepositSponsorshipApproved {
+			/// The account paying the storage deposit.
+			sponsor: T::AccountId,
+			/// The account whose calls are sponsored.
+			beneficiary: T::AccountId,
+			/// The new allowance, `None` if it was revoked.
+			allowance: Option<DepositAllowance<BalanceOf<T>>>,
+		},
+
+		/// A sponsor paid the storage deposit of a call.
+		StorageDepositSponsored {
+			/// The account that paid the storage deposit.
+			sponsor: T::AccountId,
+			/// The account that made the call.
+			caller: T::AccountId,
+			/// The storage deposit charged to the sponsor.
+			amount: BalanceOf<T>,
+		},
+
+		/// A contract was instantiated at an address derived from a namespace.
+		///
+		/// Emitted in addition to [`Event::Instantiated`].
+		InstantiatedNamespaced {
+			/// The account that instantiated the contract.
+			deployer: T::AccountId,
+			/// The address of the new contract.
+			contract: T::AccountId,
+			/// The namespace the address was derived from.
+			namespace: [u8; 32],
+		},
+
+		/// No further contract call fits in [`Config::BlockExecutionBudget`] in this block.
+		ExecutionBudgetSaturated {
+			/// The weight of contract execution consumed in this block.
+			consumed: Weight,
+		},
+
+		/// A code that no contract used was removed after [`Config::CodePruningDelay`].
+		CodePruned {
+			/// The hash of the removed code.
+			code_hash: T::Hash,
+			/// The deposit refunded to the uploader.
+			deposit_released: BalanceOf<T>,
+			/// The account that uploaded the code.
+			uploader: T::AccountId,
+		},
 	}
 
 	#[pallet::error]
@@ -1002,7 +1602,8 @@ pub mod pallet {
 		TerminatedWhileReentrant,
 		/// `seal_call` forwarded this contracts input. It therefore is no longer available.
 		InputForwarded,
-		/// The subject passed to `seal_random` exceeds the limit.
+		/// The subject passed to `seal_random` or the domain passed to `randomness` exceeds the
+		/// limit.
 		RandomSubjectTooLong,
 		/// The amount of topics passed to `seal_deposit_events` exceeds the limit.
 		TooManyTopics,
@@ -1023,6 +1624,8 @@ pub mod pallet {
 		/// into `pallet-contracts`. This would make the whole pallet reentrant with regard to
 		/// contract code execution which is not supported.
 		ReentranceDenied,
+		/// A contract called in read-only mode tried to change state.
+		StateChangeDenied,
 		/// Origin doesn't have enough balance to pay the required storage deposits.
 		StorageDepositNotEnoughFunds,
 		/// More storage was created than allowed by the storage deposit limit.
@@ -1057,6 +1660,40 @@ pub mod pallet {
 		DelegateDependencyAlreadyExists,
 		/// Can not add a delegate dependency to the code hash of the contract itself.
 		CannotAddSelfAsDelegateDependency,
+		/// A call can only be scheduled for a future block.
+		InvalidScheduledCallBlock,
+		/// The maximum number or weight of calls scheduled for the block was reached.
+		TooManyScheduledCalls,
+		/// The scheduled call was not found or was not scheduled by the caller.
+		ScheduledCallNotFound,
+		/// The contract does not have enough balance to pay the fee of the scheduled call.
+		ScheduledCallFeeNotEnoughFunds,
+		/// The origin is not allowed to instantiate contracts.
+		InstantiationNotAllowed,
+		/// The deposit payer did not approve sponsoring the storage deposit of the caller.
+		NoDepositAllowance,
+		/// A contract already exists at the address derived from the namespace, code hash, input
+		/// data and salt. A different salt must be used.
+		NamespacedAddressTaken,
+		/// A topic passed to `deposit_event_v2` is longer than [`Config::MaxEventTopicLen`].
+		EventTopicTooLong,
+		/// The contract has reached its maximum number of pinned code hashes.
+		MaxPinnedCodeHashesReached,
+		/// The contract already pinned the given code hash.
+		CodeHashAlreadyPinned,
+		/// The code hash was not pinned by the contract.
+		CodeHashNotPinned,
+		/// The gas limit exceeds what is left of [`Config::BlockExecutionBudget`] in this block.
+		ExecutionBudgetExhausted,
+		/// A storage item written by the contract is larger than [`Config::MaxStorageItemSize`].
+		StorageItemTooLarge,
+		/// The immutable data is larger than [`Config::MaxImmutableDataLen`].
+		ImmutableDataTooLarge,
+		/// The immutable data can only be set once, by the constructor of the contract.
+		InvalidImmutableAccess,
+		/// The XCM program passed to `xcm_execute` sends a message to a destination not allowed
+		/// by [`Config::XcmDestinations`].
+		XcmDestinationNotAllowed,
 	}
 
 	/// A reason for the pallet contracts placing a hold on funds.
@@ -1066,6 +1703,10 @@ pub mod pallet {
 		CodeUploadDepositReserve,
 		/// The Pallet has reserved it for storage deposit.
 		StorageDepositReserve,
+		/// The Pallet has reserved it for the fee of a scheduled call.
+		ScheduledCallFee,
+		/// The Pallet has put the storage deposit limit of a call on hold while it executes.
+		StorageDepositLimit,
 	}
 
 	/// A mapping from a contract's code hash to its code.
@@ -1121,11 +1762,101 @@ pub mod pallet {
 	pub(crate) type DeletionQueueCounter<T: Config> =
 		StorageValue<_, DeletionQueueManager<T>, ValueQuery>;
 
+	/// The calls scheduled by contracts, by the block in which they are executed.
+	///
+	/// Cancelled calls leave a `None` in their slot so that the indices of the other calls
+	/// scheduled for the same block stay valid.
+	#[pallet::storage]
+	pub(crate) type ScheduledCalls<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<Option<ScheduledCall<T>>, T::MaxScheduledCallsPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Accounts that are explicitly allowed or denied to instantiate contracts.
+	///
+	/// Accounts without an entry may instantiate contracts if they pass the
+	/// [`Config::InstantiateOrigin`].
+	#[pallet::storage]
+	pub(crate) type InstantiationPermissions<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, InstantiationPermission>;
+
+	/// The storage deposit a sponsor (first key) still pays on behalf of an account (second key).
+	#[pallet::storage]
+	pub(crate) type DepositAllowances<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		DepositAllowance<BalanceOf<T>>,
+	>;
+
 	/// A migration can span across multiple blocks. This storage defines a cursor to track the
 	/// progress of the migration, enabling us to resume from the last completed position.
 	#[pallet::storage]
 	pub(crate) type MigrationInProgress<T: Config> =
 		StorageValue<_, migration::Cursor, OptionQuery>;
+
+	/// The preimages of the topics a contract (first key) emitted events with through
+	/// `deposit_event_v2`, by their hash (second key).
+	///
+	/// Event indexers use it to resolve the topics of `ContractEmitted` events.
+	#[pallet::storage]
+	pub(crate) type EventTopicRegistry<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Identity,
+		T::Hash,
+		BoundedVec<u8, T::MaxEventTopicLen>,
+	>;
+
+	/// The number of topics in the registry of a contract and the deposit held for them.
+	#[pallet::storage]
+	pub(crate) type RegisteredTopics<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, (u32, BalanceOf<T>), ValueQuery>;
+
+	/// The code hashes pinned by a contract and the deposit held for each of them.
+	///
+	/// A pinned code hash can not be removed from the chain state, which allows contracts to
+	/// safely call or instantiate contracts of the pinned code.
+	#[pallet::storage]
+	pub(crate) type PinnedCodeHashes<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		BoundedBTreeMap<CodeHash<T>, BalanceOf<T>, T::MaxPinnedCodeHashes>,
+		ValueQuery,
+	>;
+
+	/// The weight of the contract calls and instantiations dispatched in the current block.
+	///
+	/// Checked against [`Config::BlockExecutionBudget`] and cleared at the end of the block.
+	#[pallet::storage]
+	#[pallet::whitelist_storage]
+	pub(crate) type BlockExecutionWeight<T: Config> = StorageValue<_, Weight, ValueQuery>;
+
+	/// The block in which a code was uploaded, for the codes subject to automatic pruning.
+	///
+	/// See [`Config::CodePruningDelay`].
+	#[pallet::storage]
+	pub(crate) type CodeUploadedAt<T: Config> =
+		StorageMap<_, Identity, CodeHash<T>, BlockNumberFor<T>>;
+
+	/// The last code hash inspected by the pruning sweep, which the next sweep resumes after.
+	#[pallet::storage]
+	pub(crate) type CodePruningCursor<T: Config> = StorageValue<_, CodeHash<T>>;
+
+	/// The contract that registered an XCM query with `xcm_query`.
+	///
+	/// Only that contract may take the response with `xcm_take_response`. The entry is removed
+	/// once the response was taken.
+	#[pallet::storage]
+	pub(crate) type XcmQueryOwner<T: Config> =
+		StorageMap<_, Twox64Concat, XcmQueryIdOf<T>, T::AccountId>;
 }
 
 /// The type of origins supported by the contracts pallet.
@@ -1157,6 +1888,28 @@ impl<T: Config> Origin<T> {
 	}
 }
 
+/// Whether an account may instantiate contracts, overriding [`Config::InstantiateOrigin`].
+#[derive(
+	Copy, Clone, PartialEq, Eq, RuntimeDebug, Decode, Encode, MaxEncodedLen, scale_info::TypeInfo,
+)]
+pub enum InstantiationPermission {
+	/// The account may instantiate contracts.
+	Allowed,
+	/// The account may not instantiate contracts.
+	Denied,
+}
+
+/// The storage deposit a sponsor pays on behalf of an account.
+#[derive(
+	Copy, Clone, PartialEq, Eq, RuntimeDebug, Decode, Encode, MaxEncodedLen, scale_info::TypeInfo,
+)]
+pub struct DepositAllowance<Balance> {
+	/// The storage deposit the sponsor still pays in total.
+	pub remaining: Balance,
+	/// The maximum storage deposit the sponsor pays for a single call.
+	pub per_call: Balance,
+}
+
 /// Context of a contract invocation.
 struct CommonInput<'a, T: Config> {
 	origin: Origin<T>,
@@ -1165,12 +1918,15 @@ struct CommonInput<'a, T: Config> {
 	gas_limit: Weight,
 	storage_deposit_limit: Option<BalanceOf<T>>,
 	debug_message: Option<&'a mut DebugBufferVec<T>>,
+	deposit_payer: Option<T::AccountId>,
+	host_fn_metering: bool,
 }
 
 /// Input specific to a call into contract.
 struct CallInput<T: Config> {
 	dest: T::AccountId,
 	determinism: Determinism,
+	read_only: bool,
 }
 
 /// Reference to an existing code hash or a new wasm module.
@@ -1183,6 +1939,7 @@ enum WasmCode<T: Config> {
 struct InstantiateInput<T: Config> {
 	code: WasmCode<T>,
 	salt: Vec<u8>,
+	address_kind: AddressKind,
 }
 
 /// Determines whether events should be collected during execution.
@@ -1214,6 +1971,15 @@ pub enum DebugInfo {
 	/// This should only ever be set to `UnsafeDebug` when executing as an RPC because
 	/// it adds allocations and could be abused to drive the runtime into an OOM panic.
 	UnsafeDebug,
+	/// Collect debug messages and meter the invocations of host functions.
+	///
+	/// The invocation count and weight of every called host function is returned in
+	/// [`ContractResult::host_fn_metering`].
+	///
+	/// # Note
+	///
+	/// This is unstable and must, like `UnsafeDebug`, only be used for dry runs.
+	UnsafeMetering,
 	/// Skip collection of debug messages.
 	Skip,
 }
@@ -1228,6 +1994,17 @@ struct InternalOutput<T: Config, O> {
 	result: Result<O, ExecError>,
 }
 
+impl<T: Config, O> InternalOutput<T, O> {
+	/// The fees of the execution.
+	fn fee_breakdown(&self) -> FeeBreakdown<BalanceOf<T>> {
+		FeeBreakdown {
+			gas_consumed: self.gas_meter.gas_consumed(),
+			gas_limit: self.gas_meter.gas_limit(),
+			storage_deposit: self.storage_deposit.clone(),
+		}
+	}
+}
+
 // Set up a global reference to the boolean flag used for the re-entrancy guard.
 environmental!(executing_contract: bool);
 
@@ -1281,7 +2058,13 @@ trait Invokable<T: Config>: Sized {
 					}),
 				},
 				// Enter contract call.
-				|_| self.run(common, GasMeter::new(gas_limit)),
+				|_| {
+					let mut gas_meter = GasMeter::new(gas_limit);
+					if common.host_fn_metering {
+						gas_meter.enable_host_fn_metering();
+					}
+					self.run(common, gas_meter)
+				},
 			)
 		})
 	}
@@ -1307,18 +2090,22 @@ impl<T: Config> Invokable<T> for CallInput<T> {
 		common: CommonInput<T>,
 		mut gas_meter: GasMeter<T>,
 	) -> InternalOutput<T, Self::Output> {
-		let CallInput { dest, determinism } = self;
-		let CommonInput { origin, value, data, debug_message, .. } = common;
-		let mut storage_meter =
-			match StorageMeter::new(&origin, common.storage_deposit_limit, common.value) {
-				Ok(meter) => meter,
-				Err(err) =>
-					return InternalOutput {
-						result: Err(err.into()),
-						gas_meter,
-						storage_deposit: Default::default(),
-					},
-			};
+		let CallInput { dest, determinism, read_only } = self;
+		let CommonInput { origin, value, data, debug_message, deposit_payer, .. } = common;
+		let mut storage_meter = match Pallet::<T>::storage_meter(
+			&origin,
+			common.storage_deposit_limit,
+			common.value,
+			deposit_payer.as_ref(),
+		) {
+			Ok(meter) => meter,
+			Err(err) =>
+				return InternalOutput {
+					result: Err(err.into()),
+					gas_meter,
+					storage_deposit: Default::default(),
+				},
+		};
 		let schedule = T::Schedule::get();
 		let result = ExecStack::<T, WasmBlob<T>>::run_call(
 			origin.clone(),
@@ -1330,10 +2117,16 @@ impl<T: Config> Invokable<T> for CallInput<T> {
 			data.clone(),
 			debug_message,
 			determinism,
+			read_only,
 		);
 
 		match storage_meter.try_into_deposit(&origin) {
-			Ok(storage_deposit) => InternalOutput { gas_meter, storage_deposit, result },
+			Ok(storage_deposit) => {
+				if let (Some(payer), Origin::Signed(caller)) = (&deposit_payer, &origin) {
+					Pallet::<T>::consume_deposit_allowance(payer, caller, &storage_deposit);
+				}
+				InternalOutput { gas_meter, storage_deposit, result }
+			},
 			Err(err) => InternalOutput {
 				gas_meter,
 				storage_deposit: Default::default(),
@@ -1358,7 +2151,7 @@ impl<T: Config> Invokable<T> for InstantiateInput<T> {
 		let mut storage_deposit = Default::default();
 		let try_exec = || {
 			let schedule = T::Schedule::get();
-			let InstantiateInput { salt, .. } = self;
+			let InstantiateInput { salt, address_kind, .. } = self;
 			let CommonInput { origin: contract_origin, .. } = common;
 			let origin = contract_origin.account_id()?;
 
@@ -1368,9 +2161,13 @@ impl<T: Config> Invokable<T> for InstantiateInput<T> {
 			};
 
 			let contract_origin = Origin::from_account_id(origin.clone());
-			let mut storage_meter =
-				StorageMeter::new(&contract_origin, common.storage_deposit_limit, common.value)?;
-			let CommonInput { value, data, debug_message, .. } = common;
+			let CommonInput { value, data, debug_message, deposit_payer, .. } = common;
+			let mut storage_meter = Pallet::<T>::storage_meter(
+				&contract_origin,
+				common.storage_deposit_limit,
+				value,
+				deposit_payer.as_ref(),
+			)?;
 			let result = ExecStack::<T, WasmBlob<T>>::run_instantiate(
 				origin.clone(),
 				executable,
@@ -1380,10 +2177,14 @@ impl<T: Config> Invokable<T> for InstantiateInput<T> {
 				value,
 				data.clone(),
 				&salt,
+				address_kind,
 				debug_message,
 			);
 
 			storage_deposit = storage_meter.try_into_deposit(&contract_origin)?;
+			if let Some(payer) = &deposit_payer {
+				Pallet::<T>::consume_deposit_allowance(payer, &origin, &storage_deposit);
+			}
 			result
 		};
 		InternalOutput { result: try_exec(), gas_meter, storage_deposit }
@@ -1391,7 +2192,10 @@ impl<T: Config> Invokable<T> for InstantiateInput<T> {
 
 	fn ensure_origin(&self, origin: Origin<T>) -> Result<(), DispatchError> {
 		match origin {
-			Origin::Signed(_) => Ok(()),
+			Origin::Signed(who) => {
+				ensure!(Pallet::<T>::can_instantiate(&who), <Error<T>>::InstantiationNotAllowed);
+				Ok(())
+			},
 			Origin::Root => Err(DispatchError::RootNotAllowed),
 		}
 	}
@@ -1407,6 +2211,12 @@ macro_rules! ensure_no_migration_in_progress {
 				debug_message: Vec::new(),
 				result: Err(Error::<T>::MigrationInProgress.into()),
 				events: None,
+				host_fn_metering: None,
+				fee_breakdown: FeeBreakdown {
+					gas_consumed: Zero::zero(),
+					gas_limit,
+					storage_deposit: Default::default(),
+				},
 			}
 		}
 	};
@@ -1425,6 +2235,11 @@ impl<T: Config> Pallet<T> {
 	///
 	/// If `collect_events` is set to `CollectEvents::UnsafeCollect` it collects all the Events
 	/// emitted in the block so far and the ones emitted during the execution of this contract.
+	///
+	/// If `read_only` is set the call is guaranteed not to mutate any state: every attempt of the
+	/// contract or of its callees to do so, as well as a non-zero `value`, fails the call with
+	/// [`Error::StateChangeDenied`]. This makes it safe to simulate calls on behalf of untrusted
+	/// users.
 	pub fn bare_call(
 		origin: T::AccountId,
 		dest: T::AccountId,
@@ -1435,14 +2250,16 @@ impl<T: Config> Pallet<T> {
 		debug: DebugInfo,
 		collect_events: CollectEvents,
 		determinism: Determinism,
+		read_only: bool,
 	) -> ContractExecResult<BalanceOf<T>, EventRecordOf<T>> {
 		ensure_no_migration_in_progress!();
 
-		let mut debug_message = if matches!(debug, DebugInfo::UnsafeDebug) {
-			Some(DebugBufferVec::<T>::default())
-		} else {
-			None
-		};
+		let mut debug_message =
+			if matches!(debug, DebugInfo::UnsafeDebug | DebugInfo::UnsafeMetering) {
+				Some(DebugBufferVec::<T>::default())
+			} else {
+				None
+			};
 		let origin = Origin::from_account_id(origin);
 		let common = CommonInput {
 			origin,
@@ -1451,8 +2268,10 @@ impl<T: Config> Pallet<T> {
 			gas_limit,
 			storage_deposit_limit,
 			debug_message: debug_message.as_mut(),
+			deposit_payer: None,
+			host_fn_metering: debug == DebugInfo::UnsafeMetering,
 		};
-		let output = CallInput::<T> { dest, determinism }.run_guarded(common);
+		let output = CallInput::<T> { dest, determinism, read_only }.run_guarded(common);
 		let events = if matches!(collect_events, CollectEvents::UnsafeCollect) {
 			Some(System::<T>::read_events_no_consensus().map(|e| *e).collect())
 		} else {
@@ -1460,12 +2279,14 @@ impl<T: Config> Pallet<T> {
 		};
 
 		ContractExecResult {
+			fee_breakdown: output.fee_breakdown(),
 			result: output.result.map_err(|r| r.error),
 			gas_consumed: output.gas_meter.gas_consumed(),
 			gas_required: output.gas_meter.gas_required(),
 			storage_deposit: output.storage_deposit,
 			debug_message: debug_message.unwrap_or_default().to_vec(),
 			events,
+			host_fn_metering: output.gas_meter.host_fn_metering(),
 		}
 	}
 
@@ -1484,6 +2305,36 @@ impl<T: Config> Pallet<T> {
 	/// If `collect_events` is set to `CollectEvents::UnsafeCollect` it collects all the Events
 	/// emitted in the block so far.
 	pub fn bare_instantiate(
+		origin: T::AccountId,
+		value: BalanceOf<T>,
+		gas_limit: Weight,
+		storage_deposit_limit: Option<BalanceOf<T>>,
+		code: Code<CodeHash<T>>,
+		data: Vec<u8>,
+		salt: Vec<u8>,
+		debug: DebugInfo,
+		collect_events: CollectEvents,
+	) -> ContractInstantiateResult<T::AccountId, BalanceOf<T>, EventRecordOf<T>> {
+		Self::bare_instantiate_with_address_kind(
+			origin,
+			value,
+			gas_limit,
+			storage_deposit_limit,
+			code,
+			data,
+			salt,
+			AddressKind::Deployer,
+			debug,
+			collect_events,
+		)
+	}
+
+	/// Instantiate a new contract at an address derived as selected by `address_kind`.
+	///
+	/// This function is similar to [`Self::instantiate_with_address_kind`], but doesn't perform
+	/// any address lookups and better suitable for calling directly from Rust. See
+	/// [`Self::bare_instantiate`] for the remaining arguments.
+	pub fn bare_instantiate_with_address_kind(
 		origin: T::AccountId,
 		value: BalanceOf<T>,
 		gas_limit: Weight,
@@ -1491,16 +2342,18 @@ impl<T: Config> Pallet<T> {
 		code: Code<CodeHash<T>>,
 		data: Vec<u8>,
 		salt: Vec<u8>,
+		address_kind: AddressKind,
 		debug: DebugInfo,
 		collect_events: CollectEvents,
 	) -> ContractInstantiateResult<T::AccountId, BalanceOf<T>, EventRecordOf<T>> {
 		ensure_no_migration_in_progress!();
 
-		let mut debug_message = if debug == DebugInfo::UnsafeDebug {
-			Some(DebugBufferVec::<T>::default())
-		} else {
-			None
-		};
+		let mut debug_message =
+			if matches!(debug, DebugInfo::UnsafeDebug | DebugInfo::UnsafeMetering) {
+				Some(DebugBufferVec::<T>::default())
+			} else {
+				None
+			};
 		// collect events if CollectEvents is UnsafeCollect
 		let events = || {
 			if collect_events == CollectEvents::UnsafeCollect {
@@ -1530,6 +2383,12 @@ impl<T: Config> Pallet<T> {
 							debug_message: debug_message.unwrap_or(Default::default()).into(),
 							result: Err(error),
 							events: events(),
+							host_fn_metering: None,
+							fee_breakdown: FeeBreakdown {
+								gas_consumed: Zero::zero(),
+								gas_limit,
+								storage_deposit: Default::default(),
+							},
 						},
 				};
 
@@ -1547,21 +2406,28 @@ impl<T: Config> Pallet<T> {
 			gas_limit,
 			storage_deposit_limit,
 			debug_message: debug_message.as_mut(),
+			deposit_payer: None,
+			host_fn_metering: debug == DebugInfo::UnsafeMetering,
 		};
 
-		let output = InstantiateInput::<T> { code, salt }.run_guarded(common);
+		let output = InstantiateInput::<T> { code, salt, address_kind }.run_guarded(common);
+		let storage_deposit =
+			output.storage_deposit.saturating_add(&StorageDeposit::Charge(upload_deposit));
 		ContractInstantiateResult {
+			fee_breakdown: FeeBreakdown {
+				storage_deposit: storage_deposit.clone(),
+				..output.fee_breakdown()
+			},
 			result: output
 				.result
 				.map(|(account_id, result)| InstantiateReturnValue { result, account_id })
 				.map_err(|e| e.error),
 			gas_consumed: output.gas_meter.gas_consumed(),
 			gas_required: output.gas_meter.gas_required(),
-			storage_deposit: output
-				.storage_deposit
-				.saturating_add(&StorageDeposit::Charge(upload_deposit)),
+			storage_deposit,
 			debug_message: debug_message.unwrap_or_default().to_vec(),
 			events: events(),
+			host_fn_metering: output.gas_meter.host_fn_metering(),
 		}
 	}
 
@@ -1619,6 +2485,48 @@ impl<T: Config> Pallet<T> {
 		Ok(maybe_value)
 	}
 
+	/// The preimage of `topic` if `contract` registered it through `deposit_event_v2`.
+	pub fn event_topic(contract: &T::AccountId, topic: &T::Hash) -> Option<Vec<u8>> {
+		EventTopicRegistry::<T>::get(contract, topic).map(Into::into)
+	}
+
+	/// Dump the code hash, the storage and the delegate dependencies of a contract.
+	///
+	/// The code is included if `include_code` is set. Storage items are returned in the order of
+	/// the contract's child trie, starting after `cursor`, up to [`CONTRACT_DUMP_STORAGE_LIMIT`]
+	/// bytes. Pass the returned cursor to the next call to continue with the remaining items.
+	pub fn contract_dump(
+		address: T::AccountId,
+		include_code: bool,
+		cursor: Option<Vec<u8>>,
+	) -> ContractDumpResult<CodeHash<T>, BalanceOf<T>> {
+		if Migration::<T>::in_progress() {
+			return Err(ContractAccessError::MigrationInProgress)
+		}
+		let contract_info =
+			ContractInfoOf::<T>::get(&address).ok_or(ContractAccessError::DoesntExist)?;
+
+		let code = include_code.then(|| {
+			PristineCode::<T>::get(contract_info.code_hash)
+				.map(|code| code.into_inner())
+				.unwrap_or_default()
+		});
+		let (storage, cursor) = contract_info.dump(cursor, CONTRACT_DUMP_STORAGE_LIMIT);
+		let delegate_dependencies = contract_info
+			.delegate_dependencies()
+			.iter()
+			.map(|(code_hash, deposit)| (*code_hash, *deposit))
+			.collect();
+
+		Ok(ContractDump {
+			code_hash: contract_info.code_hash,
+			code,
+			storage,
+			cursor,
+			delegate_dependencies,
+		})
+	}
+
 	/// Determine the address of a contract.
 	///
 	/// This is the address generation function used by contract instantiation. See
@@ -1632,6 +2540,87 @@ impl<T: Config> Pallet<T> {
 		T::AddressGenerator::contract_address(deploying_address, code_hash, input_data, salt)
 	}
 
+	/// Determine the address of a contract instantiated with [`AddressKind::Namespaced`].
+	///
+	/// Unlike [`Self::contract_address`] the address doesn't depend on the deployer.
+	pub fn namespaced_contract_address(
+		namespace: &[u8; 32],
+		code_hash: &CodeHash<T>,
+		input_data: &[u8],
+		salt: &[u8],
+	) -> T::AccountId {
+		T::AddressGenerator::namespaced_contract_address(namespace, code_hash, input_data, salt)
+	}
+
+	/// Create the root storage meter of a call stack started by `origin`.
+	///
+	/// If there is a `deposit_payer` it is charged instead of `origin`, limited by the allowance
+	/// it approved for `origin`.
+	fn storage_meter(
+		origin: &Origin<T>,
+		limit: Option<BalanceOf<T>>,
+		min_leftover: BalanceOf<T>,
+		deposit_payer: Option<&T::AccountId>,
+	) -> Result<StorageMeter<T>, DispatchError> {
+		let Some(payer) = deposit_payer else {
+			return StorageMeter::new(origin, limit, min_leftover)
+		};
+		let limit = Self::sponsored_deposit_limit(payer, origin.account_id()?, limit)?;
+		StorageMeter::new_sponsored(payer, limit)
+	}
+
+	/// The storage deposit `payer` pays for a single call of `caller`.
+	///
+	/// This is the `limit` requested by the caller, capped by both the remaining allowance and
+	/// its per-call limit.
+	fn sponsored_deposit_limit(
+		payer: &T::AccountId,
+		caller: &T::AccountId,
+		limit: Option<BalanceOf<T>>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		let allowance =
+			<DepositAllowances<T>>::get(payer, caller).ok_or(<Error<T>>::NoDepositAllowance)?;
+		let cap = allowance.remaining.min(allowance.per_call);
+		Ok(limit.map_or(cap, |limit| limit.min(cap)))
+	}
+
+	/// Deduct the `deposit` that `payer` was charged for a call of `caller` from its allowance.
+	fn consume_deposit_allowance(
+		payer: &T::AccountId,
+		caller: &T::AccountId,
+		deposit: &StorageDeposit<BalanceOf<T>>,
+	) {
+		let StorageDeposit::Charge(amount) = deposit else { return };
+		if amount.is_zero() {
+			return
+		}
+		<DepositAllowances<T>>::mutate_exists(payer, caller, |allowance| {
+			if let Some(allowance) = allowance {
+				allowance.remaining.saturating_reduce(*amount);
+			}
+		});
+		Self::deposit_event(
+			vec![T::Hashing::hash_of(payer), T::Hashing::hash_of(caller)],
+			Event::StorageDepositSponsored {
+				sponsor: payer.clone(),
+				caller: caller.clone(),
+				amount: *amount,
+			},
+		);
+	}
+
+	/// Whether `who` may instantiate contracts.
+	///
+	/// This is checked by [`Self::instantiate`], [`Self::instantiate_with_code`] and
+	/// [`Self::bare_instantiate`], so that dry-runs fail the same way as the extrinsics.
+	pub fn can_instantiate(who: &T::AccountId) -> bool {
+		match <InstantiationPermissions<T>>::get(who) {
+			Some(InstantiationPermission::Allowed) => true,
+			Some(InstantiationPermission::Denied) => false,
+			None => T::InstantiateOrigin::try_origin(RawOrigin::Signed(who.clone()).into()).is_ok(),
+		}
+	}
+
 	/// Returns the code hash of the contract specified by `account` ID.
 	pub fn code_hash(account: &AccountIdOf<T>) -> Option<CodeHash<T>> {
 		ContractInfo::<T>::load_code_hash(account)
@@ -1648,6 +2637,34 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Deposit [`Event::FeeBreakdown`] for a call to `contract` if enabled.
+	///
+	/// On failure, the event is rolled back together with the call.
+	fn deposit_fee_breakdown(contract: T::AccountId, output: &InternalOutput<T, ExecReturnValue>) {
+		if !T::FeeBreakdownEvents::get() {
+			return
+		}
+		Self::deposit_event(
+			vec![T::Hashing::hash_of(&contract)],
+			Event::FeeBreakdown { contract, breakdown: output.fee_breakdown() },
+		);
+	}
+
+	/// The weight of [`Self::deposit_fee_breakdown`], or zero if the event is disabled.
+	///
+	/// Priced like a contract depositing an event of the maximum size with one topic.
+	fn fee_breakdown_weight() -> Weight {
+		if !T::FeeBreakdownEvents::get() {
+			return Weight::zero()
+		}
+		let len = T::AccountId::max_encoded_len()
+			.saturating_add(FeeBreakdown::<BalanceOf<T>>::max_encoded_len());
+		let s = T::Schedule::get().host_fn_weights;
+		s.deposit_event
+			.saturating_add(s.deposit_event_per_topic)
+			.saturating_add(s.deposit_event_per_byte.saturating_mul(len as u64))
+	}
+
 	/// Deposit a pallet contracts event. Handles the conversion to the overarching event type.
 	fn deposit_event(topics: Vec<T::Hash>, event: Event<T>) {
 		<frame_system::Pallet<T>>::deposit_event_indexed(
@@ -1672,7 +2689,7 @@ impl<T: Config> Pallet<T> {
 
 sp_api::decl_runtime_apis! {
 	/// The API used to dry-run contract interactions.
-	#[api_version(2)]
+	#[api_version(4)]
 	pub trait ContractsApi<AccountId, Balance, BlockNumber, Hash, EventRecord> where
 		AccountId: Codec,
 		Balance: Codec,
@@ -1680,9 +2697,21 @@ sp_api::decl_runtime_apis! {
 		Hash: Codec,
 		EventRecord: Codec,
 	{
+		/// Perform a call from a specified account to a given contract.
+		#[changed_in(4)]
+		fn call(
+			origin: AccountId,
+			dest: AccountId,
+			value: Balance,
+			gas_limit: Option<Weight>,
+			storage_deposit_limit: Option<Balance>,
+			input_data: Vec<u8>,
+		) -> ContractExecResult<Balance, EventRecord>;
+
 		/// Perform a call from a specified account to a given contract.
 		///
-		/// See [`crate::Pallet::bare_call`].
+		/// A `read_only` call fails instead of mutating any state, see
+		/// [`crate::Pallet::bare_call`].
 		fn call(
 			origin: AccountId,
 			dest: AccountId,
@@ -1690,6 +2719,7 @@ sp_api::decl_runtime_apis! {
 			gas_limit: Option<Weight>,
 			storage_deposit_limit: Option<Balance>,
 			input_data: Vec<u8>,
+			read_only: bool,
 		) -> ContractExecResult<Balance, EventRecord>;
 
 		/// Instantiate a new contract.
@@ -1724,5 +2754,16 @@ sp_api::decl_runtime_apis! {
 			address: AccountId,
 			key: Vec<u8>,
 		) -> GetStorageResult;
+
+		/// Dump the code hash, the storage and the delegate dependencies of a contract, and its
+		/// code if `include_code` is set.
+		///
+		/// See [`crate::Pallet::contract_dump`].
+		#[api_version(3)]
+		fn contract_dump(
+			address: AccountId,
+			include_code: bool,
+			cursor: Option<Vec<u8>>,
+		) -> ContractDumpResult<Hash, Balance>;
 	}
 }
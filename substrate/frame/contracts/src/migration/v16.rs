@@ -0,0 +1,222 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Add `reentrancy_guard` to `ContractInfo`.
+
+use crate::{
+	migration::{IsFinished, MigrationStep},
+	weights::WeightInfo,
+	BalanceOf, CodeHash, Config, Pallet, TrieId, Weight, LOG_TARGET,
+};
+use codec::{Decode, Encode};
+use frame_support::{pallet_prelude::*, storage_alias, DefaultNoBound};
+use sp_runtime::BoundedBTreeMap;
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+mod old {
+	use super::*;
+
+	#[derive(
+		Encode, Decode, CloneNoBound, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	pub struct ContractInfo<T: Config> {
+		pub trie_id: TrieId,
+		pub code_hash: CodeHash<T>,
+		pub storage_bytes: u32,
+		pub storage_items: u32,
+		pub storage_byte_deposit: BalanceOf<T>,
+		pub storage_item_deposit: BalanceOf<T>,
+		pub storage_base_deposit: BalanceOf<T>,
+		pub delegate_dependencies:
+			BoundedBTreeMap<CodeHash<T>, BalanceOf<T>, T::MaxDelegateDependencies>,
+	}
+
+	#[storage_alias]
+	pub type ContractInfoOf<T: Config> = StorageMap<
+		Pallet<T>,
+		Twox64Concat,
+		<T as frame_system::Config>::AccountId,
+		ContractInfo<T>,
+	>;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub fn store_old_contract_info<T: Config>(account: T::AccountId, info: crate::ContractInfo<T>) {
+	let info = old::ContractInfo {
+		trie_id: info.trie_id.clone(),
+		code_hash: info.code_hash,
+		storage_bytes: Default::default(),
+		storage_items: Default::default(),
+		storage_byte_deposit: info.storage_byte_deposit,
+		storage_item_deposit: Default::default(),
+		storage_base_deposit: info.storage_base_deposit(),
+		delegate_dependencies: info.delegate_dependencies().clone(),
+	};
+	old::ContractInfoOf::<T>::insert(account, info);
+}
+
+#[storage_alias]
+pub type ContractInfoOf<T: Config> =
+	StorageMap<Pallet<T>, Twox64Concat, <T as frame_system::Config>::AccountId, ContractInfo<T>>;
+
+#[derive(Encode, Decode, CloneNoBound, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct ContractInfo<T: Config> {
+	trie_id: TrieId,
+	code_hash: CodeHash<T>,
+	storage_bytes: u32,
+	storage_items: u32,
+	storage_byte_deposit: BalanceOf<T>,
+	storage_item_deposit: BalanceOf<T>,
+	storage_base_deposit: BalanceOf<T>,
+	delegate_dependencies: BoundedBTreeMap<CodeHash<T>, BalanceOf<T>, T::MaxDelegateDependencies>,
+	reentrancy_guard: bool,
+}
+
+#[derive(Encode, Decode, MaxEncodedLen, DefaultNoBound)]
+pub struct Migration<T: Config> {
+	last_account: Option<T::AccountId>,
+}
+
+impl<T: Config> MigrationStep for Migration<T> {
+	const VERSION: u16 = 16;
+
+	fn max_step_weight() -> Weight {
+		T::WeightInfo::v16_migration_step()
+	}
+
+	fn step(&mut self) -> (IsFinished, Weight) {
+		let mut iter = if let Some(last_account) = self.last_account.take() {
+			old::ContractInfoOf::<T>::iter_from(old::ContractInfoOf::<T>::hashed_key_for(
+				last_account,
+			))
+		} else {
+			old::ContractInfoOf::<T>::iter()
+		};
+
+		if let Some((key, old)) = iter.next() {
+			log::debug!(target: LOG_TARGET, "Migrating contract {:?}", key);
+			let info = ContractInfo {
+				trie_id: old.trie_id,
+				code_hash: old.code_hash,
+				storage_bytes: old.storage_bytes,
+				storage_items: old.storage_items,
+				storage_byte_deposit: old.storage_byte_deposit,
+				storage_item_deposit: old.storage_item_deposit,
+				storage_base_deposit: old.storage_base_deposit,
+				delegate_dependencies: old.delegate_dependencies,
+				reentrancy_guard: false,
+			};
+			ContractInfoOf::<T>::insert(key.clone(), info);
+			self.last_account = Some(key);
+			(IsFinished::No, T::WeightInfo::v16_migration_step())
+		} else {
+			log::debug!(target: LOG_TARGET, "No more contracts to migrate");
+			(IsFinished::Yes, T::WeightInfo::v16_migration_step())
+		}
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade_step() -> Result<Vec<u8>, TryRuntimeError> {
+		let sample: Vec<_> = old::ContractInfoOf::<T>::iter().take(100).collect();
+
+		log::debug!(target: LOG_TARGET, "Taking sample of {} contracts", sample.len());
+		Ok(sample.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade_step(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		let sample = <Vec<(T::AccountId, old::ContractInfo<T>)> as Decode>::decode(&mut &state[..])
+			.expect("pre_upgrade_step provides a valid state; qed");
+
+		log::debug!(target: LOG_TARGET, "Validating sample of {} contracts", sample.len());
+		for (account, old) in sample {
+			let info = ContractInfoOf::<T>::get(&account)
+				.ok_or(TryRuntimeError::Other("contract info not migrated"))?;
+			ensure!(old.trie_id == info.trie_id, "invalid trie_id");
+			ensure!(old.code_hash == info.code_hash, "invalid code_hash");
+			ensure!(old.storage_bytes == info.storage_bytes, "invalid storage_bytes");
+			ensure!(old.storage_items == info.storage_items, "invalid storage_items");
+			ensure!(
+				old.storage_byte_deposit == info.storage_byte_deposit,
+				"invalid storage_byte_deposit"
+			);
+			ensure!(
+				old.storage_item_deposit == info.storage_item_deposit,
+				"invalid storage_item_deposit"
+			);
+			ensure!(
+				old.storage_base_deposit == info.storage_base_deposit,
+				"invalid storage_base_deposit"
+			);
+			ensure!(
+				old.delegate_dependencies == info.delegate_dependencies,
+				"invalid delegate_dependencies"
+			);
+			ensure!(!info.reentrancy_guard, "reentrancy_guard must be disabled");
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::tests::{ExtBuilder, Test, ALICE, BOB};
+
+	fn old_contract_info(seed: u8) -> old::ContractInfo<Test> {
+		old::ContractInfo {
+			trie_id: vec![seed; 32].try_into().unwrap(),
+			code_hash: Default::default(),
+			storage_bytes: seed.into(),
+			storage_items: 1,
+			storage_byte_deposit: 10,
+			storage_item_deposit: 20,
+			storage_base_deposit: 30,
+			delegate_dependencies: Default::default(),
+		}
+	}
+
+	#[test]
+	fn migration_adds_disabled_reentrancy_guard() {
+		ExtBuilder::default().build().execute_with(|| {
+			old::ContractInfoOf::<Test>::insert(ALICE, old_contract_info(1));
+			old::ContractInfoOf::<Test>::insert(BOB, old_contract_info(2));
+
+			let mut migration = Migration::<Test>::default();
+			let mut steps = 0;
+			while let (IsFinished::No, _) = migration.step() {
+				steps += 1;
+			}
+			assert_eq!(steps, 2);
+
+			for (account, seed) in [(ALICE, 1), (BOB, 2)] {
+				let old = old_contract_info(seed);
+				let info = ContractInfoOf::<Test>::get(account).unwrap();
+				assert_eq!(info.trie_id, old.trie_id);
+				assert_eq!(info.storage_bytes, old.storage_bytes);
+				assert_eq!(info.storage_base_deposit, old.storage_base_deposit);
+				assert!(!info.reentrancy_guard);
+			}
+		});
+	}
+}
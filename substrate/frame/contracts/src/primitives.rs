@@ -34,9 +34,10 @@ use sp_std::prelude::*;
 ///
 /// #Note
 ///
-/// It has been extended to include `events` at the end of the struct while not bumping the
-/// `ContractsApi` version. Therefore when SCALE decoding a `ContractResult` its trailing data
-/// should be ignored to avoid any potential compatibility issues.
+/// It has been extended to include `events`, `host_fn_metering` and `fee_breakdown` at the end of
+/// the struct while not bumping the `ContractsApi` version. Therefore when SCALE decoding a
+/// `ContractResult` its trailing data should be ignored to avoid any potential compatibility
+/// issues.
 #[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
 pub struct ContractResult<R, Balance, EventRecord> {
 	/// How much weight was consumed during execution.
@@ -79,6 +80,52 @@ pub struct ContractResult<R, Balance, EventRecord> {
 	/// The events that were emitted during execution. It is an option as event collection is
 	/// optional.
 	pub events: Option<Vec<EventRecord>>,
+	/// The invocations of every host function called during execution. It is only collected
+	/// when requested with [`crate::DebugInfo::UnsafeMetering`].
+	///
+	/// # Note
+	///
+	/// This is unstable and meant as a tool for contract developers. It may change or be
+	/// removed in any release.
+	pub host_fn_metering: Option<Vec<HostFnMetering>>,
+	/// What the execution was charged for, in the same form as [`crate::Event::FeeBreakdown`].
+	pub fee_breakdown: FeeBreakdown<Balance>,
+}
+
+/// The fees of a contract execution, broken down for display by wallets.
+///
+/// The tip is not part of the breakdown since it is not known to the contracts pallet. It is
+/// reported alongside the actual fee by the transaction payment pallet.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct FeeBreakdown<Balance> {
+	/// The weight consumed by the execution.
+	pub gas_consumed: Weight,
+	/// The weight limit of the execution. Whatever is left of it is refunded.
+	pub gas_limit: Weight,
+	/// The storage deposit charged from or refunded to the origin.
+	pub storage_deposit: StorageDeposit<Balance>,
+}
+
+impl<Balance> FeeBreakdown<Balance> {
+	/// The weight that was refunded because it was not consumed.
+	pub fn gas_refunded(&self) -> Weight {
+		self.gas_limit.saturating_sub(self.gas_consumed)
+	}
+}
+
+/// The invocations of a host function during a call, see [`ContractResult::host_fn_metering`].
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct HostFnMetering {
+	/// The name of the host function, as `module::name`, e.g. `seal0::set_storage`.
+	pub name: Vec<u8>,
+	/// How often the host function was called.
+	pub invocations: u32,
+	/// The weight charged by the calls of the host function.
+	///
+	/// The weight of host functions calling into contracts includes the weight of the callee.
+	/// Weight consumed by executing the contract code itself is not attributed to any host
+	/// function.
+	pub weight: Weight,
 }
 
 /// Result type of a `bare_call` call as well as `ContractsApi::call`.
@@ -96,6 +143,30 @@ pub type CodeUploadResult<CodeHash, Balance> =
 /// Result type of a `get_storage` call.
 pub type GetStorageResult = Result<Option<Vec<u8>>, ContractAccessError>;
 
+/// Result type of a `contract_dump` call.
+pub type ContractDumpResult<CodeHash, Balance> =
+	Result<ContractDump<CodeHash, Balance>, ContractAccessError>;
+
+/// The code and storage of a contract, as returned by `ContractsApi::contract_dump`.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ContractDump<CodeHash, Balance> {
+	/// The hash of the code of the contract.
+	pub code_hash: CodeHash,
+	/// The code of the contract, if it was requested.
+	pub code: Option<Vec<u8>>,
+	/// The storage items of the contract, as pairs of hashed keys and values.
+	///
+	/// Items are in the order of the contract's child trie.
+	pub storage: Vec<(Vec<u8>, Vec<u8>)>,
+	/// The key of the last returned storage item, if not all of them fitted into the dump.
+	///
+	/// Pass it to the next `contract_dump` call to continue with the next item.
+	pub cursor: Option<Vec<u8>>,
+	/// The code hashes the contract locked as delegate dependencies, with the deposits held for
+	/// them.
+	pub delegate_dependencies: Vec<(CodeHash, Balance)>,
+}
+
 /// The possible errors that can happen querying the storage of a contract.
 #[derive(Copy, Clone, Eq, PartialEq, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo)]
 pub enum ContractAccessError {
@@ -141,6 +212,17 @@ pub struct CodeUploadReturnValue<CodeHash, Balance> {
 	pub deposit: Balance,
 }
 
+/// Randomness handed out to a contract by the `randomness` host function.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct RandomnessOutput<Hash, BlockNumber> {
+	/// The random seed, specific to the contract and the domain it asked for.
+	pub seed: Hash,
+	/// The block since which the seed was determinable by chain observers.
+	///
+	/// The seed should only be used to decide on commitments made before this block.
+	pub known_since: BlockNumber,
+}
+
 /// Reference to an existing code hash or a new wasm module.
 #[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
 pub enum Code<Hash> {
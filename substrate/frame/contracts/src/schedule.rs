@@ -175,6 +175,9 @@ pub struct HostFnWeights<T: Config> {
 	/// Weight of calling `seal_random`.
 	pub random: Weight,
 
+	/// Weight of calling `randomness`.
+	pub randomness: Weight,
+
 	/// Weight of calling `seal_reposit_event`.
 	pub deposit_event: Weight,
 
@@ -295,6 +298,21 @@ pub struct HostFnWeights<T: Config> {
 	/// Weight of calling `account_reentrance_count`.
 	pub account_reentrance_count: Weight,
 
+	/// Weight of calling `set_reentrancy_guard`.
+	pub set_reentrancy_guard: Weight,
+
+	/// Weight of calling `set_immutable_data`, for data of the maximum size.
+	pub set_immutable_data: Weight,
+
+	/// Weight of calling `get_immutable_data`, for data of the maximum size.
+	pub get_immutable_data: Weight,
+
+	/// Weight of calling `storage_deposit_consumed`.
+	pub storage_deposit_consumed: Weight,
+
+	/// Weight of calling `storage_deposit_limit`.
+	pub storage_deposit_limit: Weight,
+
 	/// Weight of calling `instantiation_nonce`.
 	pub instantiation_nonce: Weight,
 
@@ -304,6 +322,36 @@ pub struct HostFnWeights<T: Config> {
 	/// Weight of calling `unlock_delegate_dependency`.
 	pub unlock_delegate_dependency: Weight,
 
+	/// Weight of calling `pin_code_hash`.
+	pub pin_code_hash: Weight,
+
+	/// Weight of calling `unpin_code_hash`.
+	pub unpin_code_hash: Weight,
+
+	/// Weight of calling `schedule_call`.
+	pub schedule_call: Weight,
+
+	/// Weight per byte of the input data of `schedule_call`.
+	pub schedule_call_per_byte: Weight,
+
+	/// Weight of calling `cancel_scheduled_call`.
+	pub cancel_scheduled_call: Weight,
+
+	/// Weight of calling `xcm_weigh_message`.
+	pub xcm_weigh_message: Weight,
+
+	/// Weight per instruction of the message passed to `xcm_weigh_message`.
+	pub xcm_weigh_message_per_instruction: Weight,
+
+	/// Weight of calling `xcm_query`, on top of the weight of registering the query.
+	pub xcm_query: Weight,
+
+	/// Weight of calling `xcm_take_response`, on top of the weight of taking the response.
+	pub xcm_take_response: Weight,
+
+	/// Weight of hashing and registering a topic passed to `deposit_event_v2`.
+	pub register_event_topic: Weight,
+
 	/// The type parameter is used in the default implementation.
 	#[codec(skip)]
 	pub _phantom: PhantomData<T>,
@@ -379,6 +427,7 @@ impl<T: Config> Default for HostFnWeights<T> {
 			return_per_byte: cost!(seal_return_per_byte),
 			terminate: cost!(seal_terminate),
 			random: cost!(seal_random),
+			randomness: cost!(seal_randomness),
 			deposit_event: cost!(seal_deposit_event),
 			deposit_event_per_topic: cost_args!(seal_deposit_event_per_topic_and_byte, 1, 0),
 			deposit_event_per_byte: cost_args!(seal_deposit_event_per_topic_and_byte, 0, 1),
@@ -434,9 +483,24 @@ impl<T: Config> Default for HostFnWeights<T> {
 			ecdsa_to_eth_address: cost!(seal_ecdsa_to_eth_address),
 			reentrance_count: cost!(seal_reentrance_count),
 			account_reentrance_count: cost!(seal_account_reentrance_count),
+			set_reentrancy_guard: cost!(seal_set_reentrancy_guard),
+			set_immutable_data: cost!(seal_set_immutable_data),
+			get_immutable_data: cost!(seal_get_immutable_data),
+			storage_deposit_consumed: cost!(seal_storage_deposit_consumed),
+			storage_deposit_limit: cost!(seal_storage_deposit_limit),
 			instantiation_nonce: cost!(seal_instantiation_nonce),
 			lock_delegate_dependency: cost!(lock_delegate_dependency),
 			unlock_delegate_dependency: cost!(unlock_delegate_dependency),
+			pin_code_hash: cost!(pin_code_hash),
+			unpin_code_hash: cost!(unpin_code_hash),
+			schedule_call: cost!(seal_schedule_call),
+			schedule_call_per_byte: cost!(seal_schedule_call_per_byte),
+			cancel_scheduled_call: cost!(seal_cancel_scheduled_call),
+			xcm_weigh_message: cost!(seal_xcm_weigh_message),
+			xcm_weigh_message_per_instruction: cost!(seal_xcm_weigh_message_per_instruction),
+			xcm_query: cost!(seal_xcm_query),
+			xcm_take_response: cost!(seal_xcm_take_response),
+			register_event_topic: cost!(seal_register_event_topic),
 			_phantom: PhantomData,
 		}
 	}
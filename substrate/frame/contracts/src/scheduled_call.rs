@@ -0,0 +1,258 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Calls that contracts schedule to be executed in a future block.
+//!
+//! A contract can schedule a call to itself through the `schedule_call` host function. The call
+//! is stored in [`ScheduledCalls`] under the block in which it should be executed and is
+//! dispatched by the `on_initialize` hook of that block with the contract as origin.
+//!
+//! The queue entry is paid for with a storage deposit that is charged through the storage meter of
+//! the scheduling call, like any other storage the contract uses, and refunded when the call is
+//! cancelled. The scheduled execution is weighed once, in the block that executes it. It is paid
+//! for by a fee for its gas limit that is held from the contract's balance when the call is
+//! scheduled, of which the part for the gas actually consumed is burned when it is executed. To
+//! bound the work done in `on_initialize`, the number of calls and their total weight per block
+//! are limited by [`Config::MaxScheduledCallsPerBlock`] and [`Config::MaxScheduledCallsWeight`].
+//!
+//! Calls scheduled for a block in which a migration is in progress are not executed. Their fee
+//! and deposit are released and [`Event::ScheduledCallExecuted`] reports
+//! [`Error::MigrationInProgress`].
+
+use crate::{
+	migration::Migration, weights::WeightInfo, BalanceOf, CollectEvents, Config, DebugInfo,
+	Determinism, Error, Event, HoldReason, Pallet, ScheduledCalls,
+};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	ensure,
+	traits::{
+		fungible::MutateHold,
+		tokens::{Fortitude, Precision},
+		Get,
+	},
+	weights::Weight,
+	BoundedVec, CloneNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
+use frame_system::{pallet_prelude::BlockNumberFor, Pallet as System};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{Convert, Hash, Saturating, Zero},
+	DispatchError,
+};
+use sp_std::prelude::*;
+
+/// A call of a contract to itself that is executed at the beginning of a future block.
+#[derive(
+	Encode, Decode, CloneNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct ScheduledCall<T: Config> {
+	/// The contract that scheduled the call and is called by it.
+	contract: T::AccountId,
+	/// The input data passed to the contract.
+	data: BoundedVec<u8, T::MaxScheduledCallDataLen>,
+	/// The gas limit of the call.
+	gas_limit: Weight,
+	/// The storage deposit limit of the call.
+	storage_deposit_limit: Option<BalanceOf<T>>,
+	/// The storage deposit charged for storing this call.
+	deposit: BalanceOf<T>,
+	/// The fee for the gas limit of the call held from the contract.
+	fee: BalanceOf<T>,
+}
+
+impl<T: Config> ScheduledCall<T> {
+	/// Schedule a call of `contract` to itself at the beginning of block `when`.
+	///
+	/// Returns the index of the call among the calls scheduled for `when`, which is needed to
+	/// cancel the call, and the storage deposit the caller must charge for it.
+	pub fn schedule(
+		contract: T::AccountId,
+		when: BlockNumberFor<T>,
+		data: Vec<u8>,
+		gas_limit: Weight,
+		storage_deposit_limit: Option<BalanceOf<T>>,
+	) -> Result<(u32, BalanceOf<T>), DispatchError> {
+		ensure!(when > System::<T>::block_number(), Error::<T>::InvalidScheduledCallBlock);
+		let data = data.try_into().map_err(|_| Error::<T>::ValueTooLarge)?;
+		let fee = T::WeightPrice::convert(gas_limit);
+		let mut call =
+			Self { contract, data, gas_limit, storage_deposit_limit, deposit: Zero::zero(), fee };
+		call.deposit = T::DepositPerItem::get().saturating_add(
+			T::DepositPerByte::get().saturating_mul((call.encoded_size() as u32).into()),
+		);
+
+		let index = ScheduledCalls::<T>::try_mutate(when, |calls| {
+			let total_weight = calls
+				.iter()
+				.flatten()
+				.fold(gas_limit, |total, call| total.saturating_add(call.gas_limit));
+			ensure!(
+				total_weight.all_lte(T::MaxScheduledCallsWeight::get()),
+				Error::<T>::TooManyScheduledCalls
+			);
+
+			// Reuse the slot of a cancelled call if there is one.
+			let index = match calls.iter().position(Option::is_none) {
+				Some(hole) => {
+					calls[hole] = Some(call.clone());
+					hole
+				},
+				None => {
+					calls
+						.try_push(Some(call.clone()))
+						.map_err(|_| Error::<T>::TooManyScheduledCalls)?;
+					calls.len() - 1
+				},
+			};
+
+			T::Currency::hold(&HoldReason::ScheduledCallFee.into(), &call.contract, call.fee)
+				.map_err(|_| Error::<T>::ScheduledCallFeeNotEnoughFunds)?;
+			Ok::<_, DispatchError>(index as u32)
+		})?;
+
+		Pallet::<T>::deposit_event(
+			vec![T::Hashing::hash_of(&call.contract)],
+			Event::CallScheduled { contract: call.contract, when, index },
+		);
+		Ok((index, call.deposit))
+	}
+
+	/// Cancel the call scheduled by `contract` for block `when` at `index` and release its fee.
+	///
+	/// Returns the storage deposit the caller must refund for it.
+	pub fn cancel(
+		contract: &T::AccountId,
+		when: BlockNumberFor<T>,
+		index: u32,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		let call = ScheduledCalls::<T>::try_mutate_exists(when, |maybe_calls| {
+			let calls = maybe_calls.as_mut().ok_or(Error::<T>::ScheduledCallNotFound)?;
+			let slot = calls.get_mut(index as usize).ok_or(Error::<T>::ScheduledCallNotFound)?;
+			ensure!(
+				slot.as_ref().map_or(false, |call| &call.contract == contract),
+				Error::<T>::ScheduledCallNotFound
+			);
+			let call = slot.take();
+			if calls.iter().all(Option::is_none) {
+				*maybe_calls = None;
+			}
+			Ok::<_, DispatchError>(call)
+		})?;
+
+		let deposit = call.map_or_else(Zero::zero, |call| {
+			call.release_fee(Zero::zero());
+			call.deposit
+		});
+		Pallet::<T>::deposit_event(
+			vec![T::Hashing::hash_of(contract)],
+			Event::ScheduledCallCancelled { contract: contract.clone(), when, index },
+		);
+		Ok(deposit)
+	}
+
+	/// Execute all calls scheduled for block `now`.
+	///
+	/// Returns the weight that was consumed.
+	pub fn service(now: BlockNumberFor<T>) -> Weight {
+		let calls = ScheduledCalls::<T>::take(now);
+		let mut weight = T::WeightInfo::on_initialize_per_scheduled_call(calls.len() as u32);
+		let migrating = Migration::<T>::in_progress();
+
+		for (index, call) in calls.into_iter().enumerate() {
+			let Some(call) = call else { continue };
+			call.release_deposit();
+
+			if migrating {
+				call.release_fee(Zero::zero());
+				Pallet::<T>::deposit_event(
+					vec![T::Hashing::hash_of(&call.contract)],
+					Event::ScheduledCallExecuted {
+						contract: call.contract,
+						index: index as u32,
+						result: Err(Error::<T>::MigrationInProgress.into()),
+					},
+				);
+				continue
+			}
+
+			let output = Pallet::<T>::bare_call(
+				call.contract.clone(),
+				call.contract.clone(),
+				Zero::zero(),
+				call.gas_limit,
+				call.storage_deposit_limit,
+				call.data.into(),
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+				Determinism::Enforced,
+				false,
+			);
+			weight.saturating_accrue(output.gas_consumed);
+			call.release_fee(T::WeightPrice::convert(output.gas_consumed));
+
+			let result = match output.result {
+				Ok(retval) if retval.did_revert() => Err(Error::<T>::ContractReverted.into()),
+				Ok(_) => Ok(()),
+				Err(error) => Err(error),
+			};
+			Pallet::<T>::deposit_event(
+				vec![T::Hashing::hash_of(&call.contract)],
+				Event::ScheduledCallExecuted {
+					contract: call.contract,
+					index: index as u32,
+					result,
+				},
+			);
+		}
+
+		weight
+	}
+
+	/// Release the storage deposit held for this call to the contract.
+	///
+	/// Executed calls have no storage meter to refund the deposit through, it is released to the
+	/// contract instead.
+	fn release_deposit(&self) {
+		let _ = T::Currency::release(
+			&HoldReason::StorageDepositReserve.into(),
+			&self.contract,
+			self.deposit,
+			Precision::BestEffort,
+		);
+	}
+
+	/// Burn `used` of the fee held for this call and release the rest.
+	fn release_fee(&self, used: BalanceOf<T>) {
+		let reason = HoldReason::ScheduledCallFee.into();
+		let used = used.min(self.fee);
+		let _ = T::Currency::burn_held(
+			&reason,
+			&self.contract,
+			used,
+			Precision::BestEffort,
+			Fortitude::Force,
+		);
+		let _ = T::Currency::release(
+			&reason,
+			&self.contract,
+			self.fee.saturating_sub(used),
+			Precision::BestEffort,
+		);
+	}
+}
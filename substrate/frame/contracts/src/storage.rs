@@ -23,7 +23,7 @@ use crate::{
 	exec::{AccountIdOf, Key},
 	weights::WeightInfo,
 	BalanceOf, CodeHash, CodeInfo, Config, ContractInfoOf, DeletionQueue, DeletionQueueCounter,
-	Error, Pallet, TrieId, SENTINEL,
+	Error, ImmutableData, Pallet, TrieId, SENTINEL,
 };
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
@@ -35,7 +35,7 @@ use scale_info::TypeInfo;
 use sp_core::Get;
 use sp_io::KillStorageResult;
 use sp_runtime::{
-	traits::{Hash, Saturating, Zero},
+	traits::{Hash, SaturatedConversion, Saturating, Zero},
 	BoundedBTreeMap, DispatchError, DispatchResult, RuntimeDebug,
 };
 use sp_std::{marker::PhantomData, prelude::*};
@@ -55,6 +55,10 @@ pub struct ContractInfo<T: Config> {
 	storage_bytes: u32,
 	/// How many items of storage are accumulated in this contract's child trie.
 	storage_items: u32,
+	/// The size in bytes of the largest item written to this contract's child trie.
+	///
+	/// This only ever grows: it is not lowered when the item is shrunk or removed.
+	storage_largest_item: u32,
 	/// This records to how much deposit the accumulated `storage_bytes` amount to.
 	pub storage_byte_deposit: BalanceOf<T>,
 	/// This records to how much deposit the accumulated `storage_items` amount to.
@@ -70,6 +74,15 @@ pub struct ContractInfo<T: Config> {
 	/// to the map can not be removed from the chain state and can be safely used for delegate
 	/// calls.
 	delegate_dependencies: BoundedBTreeMap<CodeHash<T>, BalanceOf<T>, T::MaxDelegateDependencies>,
+	/// Whether the contract rejects every call that would reenter it.
+	///
+	/// This is set by the contract itself and is enforced by the executor regardless of the
+	/// flags used by the caller.
+	reentrancy_guard: bool,
+	/// The data set by the constructor of the contract, which can't be changed afterwards.
+	///
+	/// It is paid for by the base deposit.
+	immutable_data: ImmutableData<T>,
 }
 
 impl<T: Config> ContractInfo<T> {
@@ -99,10 +112,13 @@ impl<T: Config> ContractInfo<T> {
 			code_hash,
 			storage_bytes: 0,
 			storage_items: 0,
+			storage_largest_item: 0,
 			storage_byte_deposit: Zero::zero(),
 			storage_item_deposit: Zero::zero(),
 			storage_base_deposit: Zero::zero(),
 			delegate_dependencies: Default::default(),
+			reentrancy_guard: false,
+			immutable_data: Default::default(),
 		};
 
 		Ok(contract)
@@ -130,6 +146,41 @@ impl<T: Config> ContractInfo<T> {
 		self.storage_base_deposit
 	}
 
+	/// Returns the size in bytes of the largest item ever written to the contract's child trie.
+	pub fn storage_largest_item(&self) -> u32 {
+		self.storage_largest_item
+	}
+
+	/// Returns whether the contract rejects all reentrant calls.
+	pub fn reentrancy_guard(&self) -> bool {
+		self.reentrancy_guard
+	}
+
+	/// Enables or disables rejecting all reentrant calls into the contract.
+	pub fn set_reentrancy_guard(&mut self, enabled: bool) {
+		self.reentrancy_guard = enabled;
+	}
+
+	/// Returns the immutable data of the contract.
+	pub fn immutable_data(&self) -> &[u8] {
+		&self.immutable_data
+	}
+
+	/// Sets the immutable data of the contract.
+	///
+	/// The deposit for the bytes it adds to the contract info is added to the base deposit and
+	/// returned, so that it can be charged.
+	pub fn set_immutable_data(&mut self, data: ImmutableData<T>) -> BalanceOf<T> {
+		let old_size = self.encoded_size();
+		self.immutable_data = data;
+		let bytes_added = self.encoded_size().saturating_sub(old_size) as u32;
+		let deposit = Diff { bytes_added, ..Default::default() }
+			.update_contract::<T>(None)
+			.charge_or_zero();
+		self.storage_base_deposit.saturating_accrue(deposit);
+		deposit
+	}
+
 	/// Reads a storage kv pair of a contract.
 	///
 	/// The read is performed from the `trie_id` only. The `address` is not necessary. If the
@@ -138,6 +189,35 @@ impl<T: Config> ContractInfo<T> {
 		child::get_raw(&self.child_trie_info(), key.hash().as_slice())
 	}
 
+	/// Reads the storage kv pairs of a contract after the hashed key `cursor`.
+	///
+	/// Items are read until their total size exceeds `limit` bytes, but at least one is read.
+	/// Returns the pairs of hashed keys and values, and the key to continue after if not all items
+	/// were read.
+	pub fn dump(
+		&self,
+		cursor: Option<Vec<u8>>,
+		limit: u32,
+	) -> (Vec<(Vec<u8>, Vec<u8>)>, Option<Vec<u8>>) {
+		let child_info = self.child_trie_info();
+		let mut items = Vec::new();
+		let mut size: u32 = 0;
+		let mut previous = cursor.unwrap_or_default();
+		while let Some(key) =
+			sp_io::default_child_storage::next_key(child_info.storage_key(), &previous)
+		{
+			let value = child::get_raw(&child_info, &key).unwrap_or_default();
+			let item_size = key.len().saturating_add(value.len()).saturated_into::<u32>();
+			if !items.is_empty() && size.saturating_add(item_size) > limit {
+				return (items, Some(previous))
+			}
+			size.saturating_accrue(item_size);
+			items.push((key.clone(), value));
+			previous = key;
+		}
+		(items, None)
+	}
+
 	/// Returns `Some(len)` (in bytes) if a storage item exists at `key`.
 	///
 	/// Returns `None` if the `key` wasn't previously set by `set_storage` or
@@ -172,15 +252,18 @@ impl<T: Config> ContractInfo<T> {
 		if let Some(storage_meter) = storage_meter {
 			let mut diff = meter::Diff::default();
 			match (old_len, new_value.as_ref().map(|v| v.len() as u32)) {
-				(Some(old_len), Some(new_len)) =>
+				(Some(old_len), Some(new_len)) => {
 					if new_len > old_len {
 						diff.bytes_added = new_len - old_len;
 					} else {
 						diff.bytes_removed = old_len - new_len;
-					},
+					}
+					diff.largest_item = new_len;
+				},
 				(None, Some(new_len)) => {
 					diff.bytes_added = new_len;
 					diff.items_added = 1;
+					diff.largest_item = new_len;
 				},
 				(Some(old_len), None) => {
 					diff.bytes_removed = old_len;
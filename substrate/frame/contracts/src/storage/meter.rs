@@ -25,7 +25,7 @@ use crate::{
 use frame_support::{
 	ensure,
 	traits::{
-		fungible::{Mutate, MutateHold},
+		fungible::{InspectHold, Mutate, MutateHold},
 		tokens::{
 			Fortitude, Fortitude::Polite, Precision, Preservation, Restriction, WithdrawConsequence,
 		},
@@ -86,6 +86,18 @@ pub trait Ext<T: Config> {
 		amount: &DepositOf<T>,
 		state: &ContractState<T>,
 	) -> Result<(), DispatchError>;
+	/// Put the `limit` returned by `check_limit` on hold for the duration of the call stack.
+	///
+	/// This makes sure that `origin` can't spend the balance needed to pay for the deposits
+	/// while the call stack is executing, e.g. by transferring it away from a nested contract.
+	fn hold_limit(origin: &T::AccountId, limit: BalanceOf<T>) -> Result<(), DispatchError>;
+	/// Release `amount` of the balance put on hold by `hold_limit`, or all of it if `None`.
+	///
+	/// This is called before any balance is transferred from `origin` as part of the call stack.
+	fn release_limit(
+		origin: &T::AccountId,
+		amount: Option<BalanceOf<T>>,
+	) -> Result<(), DispatchError>;
 }
 
 /// This [`Ext`] is used for actual on-chain execution when balance needs to be charged.
@@ -130,6 +142,8 @@ pub struct RawMeter<T: Config, E, S: State + Default + Debug> {
 	charges: Vec<Charge<T>>,
 	/// We store the nested state to determine if it has a special limit for sub-call.
 	nested: S,
+	/// The account paying the deposit instead of the origin of the call stack, if any.
+	deposit_payer: Option<T::AccountId>,
 	/// Type parameter only used in impls.
 	_phantom: PhantomData<E>,
 }
@@ -145,6 +159,8 @@ pub struct Diff {
 	pub items_added: u32,
 	/// How many storage items were removed from storage.
 	pub items_removed: u32,
+	/// The size of the largest storage item that was written.
+	pub largest_item: u32,
 }
 
 impl Diff {
@@ -192,6 +208,7 @@ impl Diff {
 			info.storage_bytes.saturating_add(bytes_added).saturating_sub(bytes_removed);
 		info.storage_items =
 			info.storage_items.saturating_add(items_added).saturating_sub(items_removed);
+		info.storage_largest_item = info.storage_largest_item.max(self.largest_item);
 		match &bytes_deposit {
 			Deposit::Charge(amount) =>
 				info.storage_byte_deposit = info.storage_byte_deposit.saturating_add(*amount),
@@ -216,6 +233,7 @@ impl Diff {
 			bytes_removed: self.bytes_removed.saturating_add(rhs.bytes_removed),
 			items_added: self.items_added.saturating_add(rhs.items_added),
 			items_removed: self.items_removed.saturating_add(rhs.items_removed),
+			largest_item: self.largest_item.max(rhs.largest_item),
 		}
 	}
 }
@@ -295,9 +313,18 @@ where
 		// we want to enforce the lesser limit to the nested meter, to fail in the sub-call.
 		let limit = self.available().min(limit);
 		if limit.is_zero() {
-			RawMeter { limit: self.available(), ..Default::default() }
+			RawMeter {
+				limit: self.available(),
+				deposit_payer: self.deposit_payer.clone(),
+				..Default::default()
+			}
 		} else {
-			RawMeter { limit, nested: Nested::OwnLimit, ..Default::default() }
+			RawMeter {
+				limit,
+				nested: Nested::OwnLimit,
+				deposit_payer: self.deposit_payer.clone(),
+				..Default::default()
+			}
 		}
 	}
 
@@ -337,6 +364,21 @@ where
 		}
 	}
 
+	/// The limit of how much balance this meter is allowed to consume.
+	pub fn limit(&self) -> BalanceOf<T> {
+		self.limit
+	}
+
+	/// The net deposit consumed by this meter and all of its already absorbed children so far.
+	///
+	/// Refunds of the own contribution are calculated pro rata of `info` like in
+	/// [`Self::absorb`], but without modifying it.
+	pub fn consumed(&self, info: Option<&ContractInfo<T>>) -> DepositOf<T> {
+		let mut info = info.cloned();
+		let own_deposit = self.own_contribution.update_contract(info.as_mut());
+		self.total_deposit.saturating_add(&own_deposit)
+	}
+
 	/// The amount of balance that is still available from the original `limit`.
 	fn available(&self) -> BalanceOf<T> {
 		self.total_deposit.available(&self.limit)
@@ -360,7 +402,9 @@ where
 {
 	/// Create new storage meter for the specified `origin` and `limit`.
 	///
-	/// This tries to [`Ext::check_limit`] on `origin` and fails if this is not possible.
+	/// This tries to [`Ext::check_limit`] on `origin` and fails if this is not possible. The limit
+	/// is then put on hold using [`Ext::hold_limit`] until [`Self::try_into_deposit`] is called,
+	/// so that the whole call stack, including all sub-calls, can never charge more than it.
 	pub fn new(
 		origin: &Origin<T>,
 		limit: Option<BalanceOf<T>>,
@@ -374,11 +418,22 @@ where
 			}),
 			Origin::Signed(o) => {
 				let limit = E::check_limit(o, limit, min_leftover)?;
+				E::hold_limit(o, limit)?;
 				Ok(Self { limit, ..Default::default() })
 			},
 		}
 	}
 
+	/// Create new storage meter whose deposit is paid by `payer` instead of the origin.
+	///
+	/// All charges and refunds of the call stack are settled with `payer`, which has `limit`
+	/// put on hold for the duration of the call.
+	pub fn new_sponsored(payer: &T::AccountId, limit: BalanceOf<T>) -> Result<Self, DispatchError> {
+		let limit = E::check_limit(payer, Some(limit), Zero::zero())?;
+		E::hold_limit(payer, limit)?;
+		Ok(Self { limit, deposit_payer: Some(payer.clone()), ..Default::default() })
+	}
+
 	/// The total amount of deposit that should change hands as result of the execution
 	/// that this meter was passed into. This will also perform all the charges accumulated
 	/// in the whole contract stack.
@@ -386,11 +441,13 @@ where
 	/// This drops the root meter in order to make sure it is only called when the whole
 	/// execution did finish.
 	pub fn try_into_deposit(self, origin: &Origin<T>) -> Result<DepositOf<T>, DispatchError> {
-		// Only refund or charge deposit if the origin is not root.
-		let origin = match origin {
-			Origin::Root => return Ok(Deposit::Charge(Zero::zero())),
-			Origin::Signed(o) => o,
+		// Only refund or charge deposit if the origin is not root or it is sponsored.
+		let origin = match (&self.deposit_payer, origin) {
+			(Some(payer), _) => payer,
+			(None, Origin::Root) => return Ok(Deposit::Charge(Zero::zero())),
+			(None, Origin::Signed(o)) => o,
 		};
+		E::release_limit(origin, None)?;
 		for charge in self.charges.iter().filter(|c| matches!(c.amount, Deposit::Refund(_))) {
 			E::charge(origin, &charge.contract, &charge.amount, &charge.state)?;
 		}
@@ -419,8 +476,8 @@ where
 	///
 	/// Use this method instead of [`Self::charge`] when the charge is not the result of a storage
 	/// change. This is the case when a `delegate_dependency` is added or removed, or when the
-	/// `code_hash` is updated. [`Self::charge`] cannot be used here because we keep track of the
-	/// deposit charge separately from the storage charge.
+	/// `code_hash` or the immutable data is updated. [`Self::charge`] cannot be used here because
+	/// we keep track of the deposit charge separately from the storage charge.
 	pub fn charge_deposit(&mut self, contract: T::AccountId, amount: DepositOf<T>) {
 		self.total_deposit = self.total_deposit.saturating_add(&amount);
 		self.charges.push(Charge { contract, amount, state: ContractState::Alive });
@@ -428,6 +485,7 @@ where
 
 	/// Charges from `origin` a storage deposit for contract instantiation.
 	///
+	/// The deposit payer of the call stack is charged instead of `origin` if there is one.
 	/// This immediately transfers the balance in order to create the account.
 	pub fn charge_instantiate(
 		&mut self,
@@ -440,7 +498,7 @@ where
 		let ed = Pallet::<T>::min_balance();
 
 		let deposit = contract_info.update_base_deposit(&code_info);
-		if deposit > self.limit {
+		if deposit > self.available() {
 			return Err(<Error<T>>::StorageDepositLimitExhausted.into())
 		}
 
@@ -448,10 +506,13 @@ where
 
 		// We do not increase `own_contribution` because this will be charged later when the
 		// contract execution does conclude and hence would lead to a double charge.
-		self.total_deposit = Deposit::Charge(ed);
+		self.total_deposit = self.total_deposit.saturating_add(&Deposit::Charge(ed));
 
-		// We need to make sure that the contract's account exists.
-		T::Currency::transfer(origin, contract, ed, Preservation::Preserve)?;
+		// We need to make sure that the contract's account exists. The ed is part of the limit
+		// that was put on hold, so it needs to be released before it can be transferred.
+		let payer = self.deposit_payer.as_ref().unwrap_or(origin);
+		E::release_limit(payer, Some(ed))?;
+		T::Currency::transfer(payer, contract, ed, Preservation::Preserve)?;
 
 		// A consumer is added at account creation and removed it on termination, otherwise the
 		// runtime could remove the account. As long as a contract exists its account must exist.
@@ -538,6 +599,29 @@ impl<T: Config> Ext<T> for ReservingExt {
 		Ok(limit)
 	}
 
+	fn hold_limit(origin: &T::AccountId, limit: BalanceOf<T>) -> Result<(), DispatchError> {
+		if limit.is_zero() {
+			return Ok(())
+		}
+		T::Currency::hold(&HoldReason::StorageDepositLimit.into(), origin, limit)
+			.map_err(|_| <Error<T>>::StorageDepositNotEnoughFunds.into())
+	}
+
+	fn release_limit(
+		origin: &T::AccountId,
+		amount: Option<BalanceOf<T>>,
+	) -> Result<(), DispatchError> {
+		let reason = HoldReason::StorageDepositLimit.into();
+		// The root meter releases whatever is left once the call stack is done. Nested meters
+		// can only release less than what is held because they never exceed the limit.
+		let amount = match amount {
+			Some(amount) => amount,
+			None => T::Currency::balance_on_hold(&reason, origin),
+		};
+		T::Currency::release(&reason, origin, amount, Precision::BestEffort)?;
+		Ok(())
+	}
+
 	fn charge(
 		origin: &T::AccountId,
 		contract: &T::AccountId,
@@ -694,6 +778,20 @@ mod tests {
 			});
 			Ok(())
 		}
+
+		fn hold_limit(
+			_origin: &AccountIdOf<Test>,
+			_limit: BalanceOf<Test>,
+		) -> Result<(), DispatchError> {
+			Ok(())
+		}
+
+		fn release_limit(
+			_origin: &AccountIdOf<Test>,
+			_amount: Option<BalanceOf<Test>>,
+		) -> Result<(), DispatchError> {
+			Ok(())
+		}
 	}
 
 	fn clear_ext() {
@@ -720,10 +818,13 @@ mod tests {
 			code_hash: Default::default(),
 			storage_bytes: info.bytes,
 			storage_items: info.items,
+			storage_largest_item: Default::default(),
 			storage_byte_deposit: info.bytes_deposit,
 			storage_item_deposit: info.items_deposit,
 			storage_base_deposit: Default::default(),
 			delegate_dependencies: Default::default(),
+			reentrancy_guard: false,
+			immutable_data: Default::default(),
 		}
 	}
 
@@ -35,27 +35,32 @@ use crate::{
 	tests::test_utils::{get_contract, get_contract_checked},
 	wasm::{Determinism, ReturnErrorCode as RuntimeReturnCode},
 	weights::WeightInfo,
-	Array, BalanceOf, Code, CodeHash, CodeInfoOf, CollectEvents, Config, ContractInfo,
-	ContractInfoOf, DebugInfo, DefaultAddressGenerator, DeletionQueueCounter, Error, HoldReason,
-	MigrationInProgress, Origin, Pallet, PristineCode, Schedule,
+	AddressKind, Array, BalanceOf, Code, CodeHash, CodeInfoOf, CodePruningCursor, CodeUploadedAt,
+	CollectEvents, Config, ContractAccessError, ContractInfo, ContractInfoOf, DebugInfo,
+	DefaultAddressGenerator, DeletionQueueCounter, DepositAllowance, DepositAllowances, Error,
+	FeeBreakdown, HoldReason, InstantiationPermission, MigrationInProgress, Origin, Pallet,
+	PinnedCodeHashes, PristineCode, RegisteredTopics, Schedule, ScheduledCalls, StorageDeposit,
+	TieredCodeDeposit, CONTRACT_DUMP_STORAGE_LIMIT,
 };
 use assert_matches::assert_matches;
 use codec::{Decode, Encode};
 use frame_support::{
 	assert_err, assert_err_ignore_postinfo, assert_err_with_weight, assert_noop, assert_ok,
 	derive_impl,
-	dispatch::{DispatchErrorWithPostInfo, PostDispatchInfo},
+	dispatch::{DispatchErrorWithPostInfo, GetDispatchInfo, PostDispatchInfo},
 	parameter_types,
 	storage::child,
 	traits::{
 		fungible::{BalancedHold, Inspect, Mutate, MutateHold},
 		tokens::Preservation,
-		ConstU32, ConstU64, Contains, OnIdle, OnInitialize, StorageVersion,
+		ConstU32, ConstU64, Contains, EnsureOrigin, Everything, OnIdle, OnInitialize,
+		StorageVersion,
 	},
 	weights::{constants::WEIGHT_REF_TIME_PER_SECOND, Weight},
 };
 use frame_system::{EventRecord, Phase};
 use pallet_contracts_fixtures::compile_module;
+use pallet_contracts_uapi::CalleeFailure;
 use pretty_assertions::{assert_eq, assert_ne};
 use sp_core::ByteArray;
 use sp_io::hashing::blake2_256;
@@ -392,8 +397,15 @@ parameter_types! {
 	pub static DepositPerByte: BalanceOf<Test> = 1;
 	pub const DepositPerItem: BalanceOf<Test> = 2;
 	pub static MaxDelegateDependencies: u32 = 32;
+	pub static MaxPinnedCodeHashes: u32 = 32;
+	pub static MaxRegisteredTopics: u32 = 32;
+	pub static MaxScheduledCallsPerBlock: u32 = 2;
+	pub const MaxScheduledCallsWeight: Weight = Weight::from_parts(20_000_000_000, 2 * 1024 * 1024);
+	pub static BlockExecutionBudget: Weight = Weight::MAX;
+	pub static CodePruningDelay: Option<u64> = None;
 
 	pub static CodeHashLockupDepositPercent: Perbill = Perbill::from_percent(0);
+	pub static CodeDepositTiers: Vec<(u32, BalanceOf<Test>)> = vec![];
 	// We need this one set high enough for running benchmarks.
 	pub static DefaultDepositLimit: BalanceOf<Test> = 10_000_000;
 }
@@ -436,11 +448,44 @@ impl Contains<RuntimeCall> for TestFilter {
 
 parameter_types! {
 	pub static UnstableInterface: bool = true;
+	pub static PermissionlessInstantiation: bool = true;
+	pub static FeeBreakdownEvents: bool = false;
+	pub static CallCacheSize: u32 = 0;
+	pub static MaxStorageItemSize: u32 = 16 * 1024;
+	pub static MaxImmutableDataLen: u32 = 4 * 1024;
+}
+
+/// Lets every signed origin instantiate contracts unless `PermissionlessInstantiation` is unset.
+pub struct TestInstantiateOrigin;
+
+impl EnsureOrigin<RuntimeOrigin> for TestInstantiateOrigin {
+	type Success = AccountId32;
+
+	fn try_origin(o: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
+		if !PermissionlessInstantiation::get() {
+			return Err(o)
+		}
+		frame_system::EnsureSigned::try_origin(o)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+		Ok(RuntimeOrigin::signed(ALICE))
+	}
+}
+
+/// Hands out a different seed for every subject, known since the current block.
+pub struct TestRandomnessSource;
+impl frame_support::traits::Randomness<H256, u64> for TestRandomnessSource {
+	fn random(subject: &[u8]) -> (H256, u64) {
+		(H256(blake2_256(subject)), System::block_number())
+	}
 }
 
 impl Config for Test {
 	type Time = Timestamp;
 	type Randomness = Randomness;
+	type RandomnessSource = TestRandomnessSource;
 	type Currency = Balances;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
@@ -457,15 +502,32 @@ impl Config for Test {
 	type AddressGenerator = DefaultAddressGenerator;
 	type MaxCodeLen = ConstU32<{ 123 * 1024 }>;
 	type MaxStorageKeyLen = ConstU32<128>;
+	type MaxStorageItemSize = MaxStorageItemSize;
+	type MaxImmutableDataLen = MaxImmutableDataLen;
 	type UnsafeUnstableInterface = UnstableInterface;
+	type FeeBreakdownEvents = FeeBreakdownEvents;
+	type CallCacheSize = CallCacheSize;
 	type MaxDebugBufferLen = ConstU32<{ 2 * 1024 * 1024 }>;
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type Migrations = crate::migration::codegen::BenchMigrations;
 	type CodeHashLockupDepositPercent = CodeHashLockupDepositPercent;
+	type CodeDepositCurve = TieredCodeDeposit<CodeDepositTiers, BalanceOf<Self>>;
 	type MaxDelegateDependencies = MaxDelegateDependencies;
+	type MaxPinnedCodeHashes = MaxPinnedCodeHashes;
+	type MaxEventTopicLen = ConstU32<32>;
+	type MaxRegisteredTopics = MaxRegisteredTopics;
+	type MaxScheduledCallsPerBlock = MaxScheduledCallsPerBlock;
+	type MaxScheduledCallDataLen = ConstU32<128>;
+	type MaxScheduledCallsWeight = MaxScheduledCallsWeight;
+	type BlockExecutionBudget = BlockExecutionBudget;
+	type CodePruningDelay = CodePruningDelay;
+	type InstantiateOrigin = TestInstantiateOrigin;
+	type InstantiationPermissionOrigin = frame_system::EnsureRoot<AccountId32>;
 	type Debug = TestDebug;
 	type Environment = ();
 	type Xcm = ();
+	type XcmWeigher = ();
+	type XcmDestinations = Everything;
 }
 
 pub const ALICE: AccountId32 = AccountId32::new([1u8; 32]);
@@ -821,6 +883,90 @@ fn deposit_event_max_value_limit() {
 	});
 }
 
+#[test]
+fn deposit_event_v2_registers_topics() {
+	MaxRegisteredTopics::set(2);
+	let (wasm, _code_hash) = compile_module::<Test>("deposit_event_v2").unwrap();
+
+	const ED: u64 = 50;
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 1_000_000);
+		let addr = Contracts::bare_instantiate(
+			ALICE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(wasm),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		let call = |input: Vec<u8>| {
+			<Pallet<Test>>::bare_call(
+				ALICE,
+				addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				input,
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+				Determinism::Enforced,
+				false,
+			)
+			.result
+		};
+		let topic_deposit = |topic: &[u8]| DepositPerItem::get() + topic.len() as u64 + 32;
+		let deposit_on_hold =
+			|| test_utils::get_balance_on_hold(&HoldReason::StorageDepositReserve.into(), &addr);
+		let base_deposit = test_utils::get_contract(&addr).storage_base_deposit();
+
+		// The topics are hashed, emitted and registered.
+		assert_ok!(call(vec![b"alice".to_vec(), b"bob".to_vec()].encode()));
+		assert_eq!(
+			System::events().last().unwrap().topics,
+			vec![BlakeTwo256::hash(b"alice"), BlakeTwo256::hash(b"bob")],
+		);
+		assert_eq!(
+			Contracts::event_topic(&addr, &BlakeTwo256::hash(b"alice")),
+			Some(b"alice".to_vec())
+		);
+		assert_eq!(
+			Contracts::event_topic(&addr, &BlakeTwo256::hash(b"bob")),
+			Some(b"bob".to_vec())
+		);
+		let registry_deposit = topic_deposit(b"alice") + topic_deposit(b"bob");
+		assert_eq!(deposit_on_hold(), base_deposit + registry_deposit - ED);
+
+		// Registered topics are not charged again.
+		assert_ok!(call(vec![b"alice".to_vec()].encode()));
+		assert_eq!(deposit_on_hold(), base_deposit + registry_deposit - ED);
+
+		// Topics are still emitted, but no longer registered once the registry is full.
+		assert_ok!(call(vec![b"charlie".to_vec()].encode()));
+		assert_eq!(System::events().last().unwrap().topics, vec![BlakeTwo256::hash(b"charlie")]);
+		assert_eq!(Contracts::event_topic(&addr, &BlakeTwo256::hash(b"charlie")), None);
+		assert_eq!(deposit_on_hold(), base_deposit + registry_deposit - ED);
+
+		// Topics longer than `MaxEventTopicLen` are rejected.
+		assert_err!(call(vec![vec![0u8; 33]].encode()), Error::<Test>::EventTopicTooLong);
+
+		// Terminating the contract clears the registry and refunds its deposit.
+		let balance_before = test_utils::get_balance(&ALICE);
+		assert_ok!(call(vec![]));
+		assert_eq!(
+			test_utils::get_balance(&ALICE),
+			balance_before + base_deposit + registry_deposit
+		);
+		assert_eq!(Contracts::event_topic(&addr, &BlakeTwo256::hash(b"alice")), None);
+		assert_eq!(RegisteredTopics::<Test>::get(&addr), (0, 0));
+	});
+}
+
 // Fail out of fuel (ref_time weight) inside the start function.
 #[test]
 fn run_out_of_fuel_start_fun() {
@@ -917,6 +1063,7 @@ fn run_out_of_fuel_host() {
 			DebugInfo::Skip,
 			CollectEvents::Skip,
 			Determinism::Enforced,
+			false,
 		)
 		.result;
 		assert_err!(result, <Error<Test>>::OutOfGas);
@@ -988,6 +1135,7 @@ fn gas_syncs_work() {
 			DebugInfo::Skip,
 			CollectEvents::Skip,
 			Determinism::Enforced,
+			false,
 		);
 		assert_ok!(result.result);
 		let engine_consumed_noop = result.gas_consumed.ref_time();
@@ -1002,6 +1150,7 @@ fn gas_syncs_work() {
 			DebugInfo::Skip,
 			CollectEvents::Skip,
 			Determinism::Enforced,
+			false,
 		);
 		assert_ok!(result.result);
 		let gas_consumed_once = result.gas_consumed.ref_time();
@@ -1018,6 +1167,7 @@ fn gas_syncs_work() {
 			DebugInfo::Skip,
 			CollectEvents::Skip,
 			Determinism::Enforced,
+			false,
 		);
 		assert_ok!(result.result);
 		let gas_consumed_twice = result.gas_consumed.ref_time();
@@ -1146,6 +1296,67 @@ fn storage_max_value_limit() {
 	});
 }
 
+#[test]
+fn storage_item_size_limit() {
+	MaxStorageItemSize::set(100);
+	let (wasm, _code_hash) = compile_module::<Test>("store_call").unwrap();
+
+	ExtBuilder::default().existential_deposit(50).build().execute_with(|| {
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 1_000_000);
+		let addr = Contracts::bare_instantiate(
+			ALICE,
+			30_000,
+			GAS_LIMIT,
+			None,
+			Code::Upload(wasm),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		assert_eq!(get_contract(&addr).storage_largest_item(), 0);
+
+		// Items up to the limit can be written and are metered.
+		assert_ok!(Contracts::call(
+			RuntimeOrigin::signed(ALICE),
+			addr.clone(),
+			0,
+			GAS_LIMIT,
+			None,
+			100u32.encode(),
+		));
+		assert_eq!(get_contract(&addr).storage_largest_item(), 100);
+
+		// Shrinking the item doesn't lower the largest size seen.
+		assert_ok!(Contracts::call(
+			RuntimeOrigin::signed(ALICE),
+			addr.clone(),
+			0,
+			GAS_LIMIT,
+			None,
+			10u32.encode(),
+		));
+		assert_eq!(get_contract(&addr).storage_largest_item(), 100);
+
+		// A larger item is rejected although it fits into the payload limit.
+		assert!(101 <= <Test as Config>::Schedule::get().limits.payload_len);
+		assert_err_ignore_postinfo!(
+			Contracts::call(
+				RuntimeOrigin::signed(ALICE),
+				addr,
+				0,
+				GAS_LIMIT,
+				None,
+				101u32.encode()
+			),
+			Error::<Test>::StorageItemTooLarge,
+		);
+	});
+}
+
 #[test]
 fn deploy_and_call_other_contract() {
 	let (caller_wasm, _caller_code_hash) = compile_module::<Test>("caller_contract").unwrap();
@@ -1755,6 +1966,7 @@ fn crypto_hashes() {
 				DebugInfo::Skip,
 				CollectEvents::Skip,
 				Determinism::Enforced,
+				false,
 			)
 			.result
 			.unwrap();
@@ -1799,6 +2011,7 @@ fn transfer_return_code() {
 			DebugInfo::Skip,
 			CollectEvents::Skip,
 			Determinism::Enforced,
+			false,
 		)
 		.result
 		.unwrap();
@@ -1806,6 +2019,132 @@ fn transfer_return_code() {
 	});
 }
 
+#[test]
+fn read_only_bare_call_denies_state_change() {
+	let (store_code, _) = compile_module::<Test>("set_empty_storage").unwrap();
+	let (dummy_code, _) = compile_module::<Test>("dummy").unwrap();
+	ExtBuilder::default().existential_deposit(50).build().execute_with(|| {
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 1_000_000);
+		let instantiate = |code| {
+			Contracts::bare_instantiate(
+				ALICE,
+				0,
+				GAS_LIMIT,
+				None,
+				Code::Upload(code),
+				vec![],
+				vec![],
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+			)
+			.result
+			.unwrap()
+			.account_id
+		};
+		let store_addr = instantiate(store_code);
+		let dummy_addr = instantiate(dummy_code);
+		let call = |addr: &AccountId32, value, read_only| {
+			Contracts::bare_call(
+				ALICE,
+				addr.clone(),
+				value,
+				GAS_LIMIT,
+				None,
+				vec![],
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+				Determinism::Enforced,
+				read_only,
+			)
+			.result
+		};
+
+		// A contract writing to its storage can't be called read-only.
+		assert_err!(call(&store_addr, 0, true), <Error<Test>>::StateChangeDenied);
+		assert_eq!(get_contract(&store_addr).read(&Key::Fix([0u8; 32])), None);
+		assert_ok!(call(&store_addr, 0, false));
+		assert_eq!(get_contract(&store_addr).read(&Key::Fix([0u8; 32])), Some(vec![0u8; 4]));
+
+		// No value can be transferred by a read-only call.
+		assert_ok!(call(&dummy_addr, 0, true));
+		assert_err!(call(&dummy_addr, 1, true), <Error<Test>>::StateChangeDenied);
+		assert_ok!(call(&dummy_addr, 1, false));
+	});
+}
+
+#[test]
+fn read_only_call_denies_mutating_host_functions() {
+	let (_, dummy_hash) = compile_module::<Test>("dummy").unwrap();
+	let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![] }).encode();
+	let cases: Vec<(&str, Vec<u8>)> = vec![
+		("multi_store", (1u32, 1u32).encode()),
+		("transfer_return_code", vec![]),
+		("self_destruct", vec![]),
+		("set_code_hash", dummy_hash.encode()),
+		("instantiate_return_code", dummy_hash.encode()),
+		("locking_delegate_dependency", (1u32, dummy_hash).encode()),
+		("pinning_code_hash", (1u32, dummy_hash).encode()),
+		("event_size", 0u32.encode()),
+		("deposit_event_v2", vec![1u8; 32]),
+		("schedule_call", (1u32, 10u64, 0u32).encode()),
+		("set_reentrancy_guard", 1u32.encode()),
+		("chain_extension", 0u32.encode()),
+		("call_runtime", remark),
+		("xcm_execute", vec![]),
+		("xcm_send", vec![0u8; 3]),
+		("xcm_query", vec![0u8; 8]),
+		("xcm_take_response", 0u64.encode()),
+	];
+
+	for (fixture, input) in cases {
+		let (wasm, _) = compile_module::<Test>(fixture).unwrap();
+		ExtBuilder::default().existential_deposit(50).build().execute_with(|| {
+			let min_balance = Contracts::min_balance();
+			let _ = <Test as Config>::Currency::set_balance(&ALICE, 1_000 * min_balance);
+			assert_ok!(Contracts::upload_code(
+				RuntimeOrigin::signed(ALICE),
+				compile_module::<Test>("dummy").unwrap().0,
+				None,
+				Determinism::Enforced,
+			));
+			let addr = Contracts::bare_instantiate(
+				ALICE,
+				min_balance * 100,
+				GAS_LIMIT,
+				None,
+				Code::Upload(wasm),
+				vec![],
+				vec![],
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+			)
+			.result
+			.unwrap()
+			.account_id;
+
+			let result = Contracts::bare_call(
+				ALICE,
+				addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				input,
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+				Determinism::Enforced,
+				true,
+			)
+			.result;
+			assert_eq!(
+				result.map(|_| ()),
+				Err(<Error<Test>>::StateChangeDenied.into()),
+				"{fixture}"
+			);
+			assert!(ContractInfoOf::<Test>::contains_key(&addr), "{fixture}");
+		});
+	}
+}
+
 #[test]
 fn call_return_code() {
 	let (caller_code, _caller_hash) = compile_module::<Test>("call_return_code").unwrap();
@@ -1842,6 +2181,7 @@ fn call_return_code() {
 			DebugInfo::Skip,
 			CollectEvents::Skip,
 			Determinism::Enforced,
+			false,
 		)
 		.result
 		.unwrap();
@@ -1878,6 +2218,7 @@ fn call_return_code() {
 			DebugInfo::Skip,
 			CollectEvents::Skip,
 			Determinism::Enforced,
+			false,
 		)
 		.result
 		.unwrap();
@@ -1899,6 +2240,7 @@ fn call_return_code() {
 			DebugInfo::Skip,
 			CollectEvents::Skip,
 			Determinism::Enforced,
+			false,
 		)
 		.result
 		.unwrap();
@@ -1919,6 +2261,7 @@ fn call_return_code() {
 			DebugInfo::Skip,
 			CollectEvents::Skip,
 			Determinism::Enforced,
+			false,
 		)
 		.result
 		.unwrap();
@@ -1926,6 +2269,67 @@ fn call_return_code() {
 	});
 }
 
+#[test]
+fn call_v3_reports_callee_failure() {
+	let (caller_code, _caller_hash) = compile_module::<Test>("call_failure").unwrap();
+	let (callee_code, _callee_hash) = compile_module::<Test>("ok_trap_revert").unwrap();
+	let (gas_code, _gas_hash) = compile_module::<Test>("run_out_of_gas").unwrap();
+	ExtBuilder::default().existential_deposit(50).build().execute_with(|| {
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 1_000_000);
+
+		let instantiate = |code| {
+			Contracts::bare_instantiate(
+				ALICE,
+				0,
+				GAS_LIMIT,
+				None,
+				Code::Upload(code),
+				vec![],
+				vec![],
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+			)
+			.result
+			.unwrap()
+			.account_id
+		};
+		let addr_caller = instantiate(caller_code);
+		let addr_callee = instantiate(callee_code);
+		let addr_gas = instantiate(gas_code);
+
+		let call = |callee: &AccountId32, ref_time_limit: u64, input: u32| {
+			Contracts::bare_call(
+				ALICE,
+				addr_caller.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				AsRef::<[u8]>::as_ref(callee)
+					.iter()
+					.chain(&ref_time_limit.to_le_bytes())
+					.chain(&input.to_le_bytes())
+					.cloned()
+					.collect(),
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+				Determinism::Enforced,
+				false,
+			)
+			.result
+			.unwrap()
+			.data
+		};
+
+		assert_eq!(call(&addr_callee, 0, 0), vec![0]);
+		assert_eq!(call(&addr_callee, 0, 1), vec![1]);
+		assert_eq!(call(&addr_callee, 0, 2), [vec![2], CalleeFailure::Trapped.encode()].concat());
+		assert_eq!(
+			call(&addr_gas, 1_000_000_000, 0),
+			[vec![2], CalleeFailure::OutOfGas.encode()].concat()
+		);
+	});
+}
+
 #[test]
 fn instantiate_return_code() {
 	let (caller_code, _caller_hash) = compile_module::<Test>("instantiate_return_code").unwrap();
@@ -1973,6 +2377,7 @@ fn instantiate_return_code() {
 			DebugInfo::Skip,
 			CollectEvents::Skip,
 			Determinism::Enforced,
+			false,
 		)
 		.result
 		.unwrap();
@@ -1990,6 +2395,7 @@ fn instantiate_return_code() {
 			DebugInfo::Skip,
 			CollectEvents::Skip,
 			Determinism::Enforced,
+			false,
 		)
 		.result
 		.unwrap();
@@ -2006,6 +2412,7 @@ fn instantiate_return_code() {
 			DebugInfo::Skip,
 			CollectEvents::Skip,
 			Determinism::Enforced,
+			false,
 		)
 		.result
 		.unwrap();
@@ -2022,6 +2429,7 @@ fn instantiate_return_code() {
 			DebugInfo::Skip,
 			CollectEvents::Skip,
 			Determinism::Enforced,
+			false,
 		)
 		.result
 		.unwrap();
@@ -2112,6 +2520,7 @@ fn chain_extension_works() {
 			DebugInfo::Skip,
 			CollectEvents::Skip,
 			Determinism::Enforced,
+			false,
 		);
 		assert_eq!(TestExtension::last_seen_buffer(), input);
 		assert_eq!(result.result.unwrap().data, input);
@@ -2127,6 +2536,7 @@ fn chain_extension_works() {
 			DebugInfo::Skip,
 			CollectEvents::Skip,
 			Determinism::Enforced,
+			false,
 		)
 		.result
 		.unwrap();
@@ -2143,6 +2553,7 @@ fn chain_extension_works() {
 			DebugInfo::Skip,
 			CollectEvents::Skip,
 			Determinism::Enforced,
+			false,
 		);
 		assert_ok!(result.result);
 		let gas_consumed = result.gas_consumed;
@@ -2156,6 +2567,7 @@ fn chain_extension_works() {
 			DebugInfo::Skip,
 			CollectEvents::Skip,
 			Determinism::Enforced,
+			false,
 		);
 		assert_ok!(result.result);
 		assert_eq!(result.gas_consumed.ref_time(), gas_consumed.ref_time() + 42);
@@ -2169,6 +2581,7 @@ fn chain_extension_works() {
 			DebugInfo::Skip,
 			CollectEvents::Skip,
 			Determinism::Enforced,
+			false,
 		);
 		assert_ok!(result.result);
 		assert_eq!(result.gas_consumed.ref_time(), gas_consumed.ref_time() + 95);
@@ -2184,6 +2597,7 @@ fn chain_extension_works() {
 			DebugInfo::Skip,
 			CollectEvents::Skip,
 			Determinism::Enforced,
+			false,
 		)
 		.result
 		.unwrap();
@@ -2203,6 +2617,7 @@ fn chain_extension_works() {
 			DebugInfo::Skip,
 			CollectEvents::Skip,
 			Determinism::Enforced,
+			false,
 		)
 		.result
 		.unwrap();
@@ -2266,6 +2681,7 @@ fn chain_extension_temp_storage_works() {
 				DebugInfo::Skip,
 				CollectEvents::Skip,
 				Determinism::Enforced,
+				false,
 			)
 			.result
 		);
@@ -2820,6 +3236,7 @@ fn debug_message_works() {
 			DebugInfo::UnsafeDebug,
 			CollectEvents::Skip,
 			Determinism::Enforced,
+			false,
 		);
 
 		assert_matches!(result.result, Ok(_));
@@ -2827,6 +3244,56 @@ fn debug_message_works() {
 	});
 }
 
+#[test]
+fn host_fn_metering_works() {
+	let (wasm, _code_hash) = compile_module::<Test>("debug_message_works").unwrap();
+
+	ExtBuilder::default().existential_deposit(50).build().execute_with(|| {
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 1_000_000);
+		let addr = Contracts::bare_instantiate(
+			ALICE,
+			30_000,
+			GAS_LIMIT,
+			None,
+			Code::Upload(wasm),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		let call = |debug| {
+			Contracts::bare_call(
+				ALICE,
+				addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				vec![],
+				debug,
+				CollectEvents::Skip,
+				Determinism::Enforced,
+				false,
+			)
+		};
+
+		// Metering is only collected on request.
+		assert_eq!(call(DebugInfo::UnsafeDebug).host_fn_metering, None);
+
+		let result = call(DebugInfo::UnsafeMetering);
+		assert_matches!(result.result, Ok(_));
+		assert_eq!(std::str::from_utf8(&result.debug_message).unwrap(), "Hello World!");
+		let metering = result.host_fn_metering.unwrap();
+		assert_eq!(metering.len(), 1);
+		assert_eq!(metering[0].name, b"seal0::debug_message".to_vec());
+		assert_eq!(metering[0].invocations, 1);
+		assert!(metering[0].weight.ref_time() > 0);
+		assert!(metering[0].weight.all_lte(result.gas_consumed));
+	});
+}
+
 #[test]
 fn debug_message_logging_disabled() {
 	let (wasm, _code_hash) = compile_module::<Test>("debug_message_logging_disabled").unwrap();
@@ -2858,6 +3325,7 @@ fn debug_message_logging_disabled() {
 			DebugInfo::Skip,
 			CollectEvents::Skip,
 			Determinism::Enforced,
+			false,
 		);
 		assert_matches!(result.result, Ok(_));
 		// the dispatchables always run without debugging
@@ -2896,6 +3364,7 @@ fn debug_message_invalid_utf8() {
 			DebugInfo::UnsafeDebug,
 			CollectEvents::Skip,
 			Determinism::Enforced,
+			false,
 		);
 		assert_ok!(result.result);
 		assert!(result.debug_message.is_empty());
@@ -3000,6 +3469,7 @@ fn gas_estimation_for_subcalls() {
 					DebugInfo::Skip,
 					CollectEvents::Skip,
 					Determinism::Enforced,
+					false,
 				);
 				assert_ok!(&result.result);
 
@@ -3026,6 +3496,7 @@ fn gas_estimation_for_subcalls() {
 						DebugInfo::Skip,
 						CollectEvents::Skip,
 						Determinism::Enforced,
+						false,
 					)
 					.result
 				);
@@ -3042,6 +3513,7 @@ fn gas_estimation_for_subcalls() {
 						DebugInfo::Skip,
 						CollectEvents::Skip,
 						Determinism::Enforced,
+						false,
 					)
 					.result,
 					error,
@@ -3059,6 +3531,7 @@ fn gas_estimation_for_subcalls() {
 						DebugInfo::Skip,
 						CollectEvents::Skip,
 						Determinism::Enforced,
+						false,
 					)
 					.result,
 					error,
@@ -3107,6 +3580,7 @@ fn gas_estimation_call_runtime() {
 			DebugInfo::Skip,
 			CollectEvents::Skip,
 			Determinism::Enforced,
+			false,
 		);
 		// contract encodes the result of the dispatch runtime
 		let outcome = u32::decode(&mut result.result.unwrap().data.as_ref()).unwrap();
@@ -3125,6 +3599,7 @@ fn gas_estimation_call_runtime() {
 				DebugInfo::Skip,
 				CollectEvents::Skip,
 				Determinism::Enforced,
+				false,
 			)
 			.result
 		);
@@ -3191,6 +3666,7 @@ fn call_runtime_reentrancy_guarded() {
 			DebugInfo::Skip,
 			CollectEvents::Skip,
 			Determinism::Enforced,
+			false,
 		)
 		.result
 		.unwrap();
@@ -3255,6 +3731,7 @@ fn ecdsa_recover() {
 			DebugInfo::Skip,
 			CollectEvents::Skip,
 			Determinism::Enforced,
+			false,
 		)
 		.result
 		.unwrap();
@@ -3345,6 +3822,7 @@ fn bare_call_returns_events() {
 			DebugInfo::Skip,
 			CollectEvents::UnsafeCollect,
 			Determinism::Enforced,
+			false,
 		);
 
 		let events = result.events.unwrap();
@@ -3386,6 +3864,7 @@ fn bare_call_does_not_return_events() {
 			DebugInfo::Skip,
 			CollectEvents::Skip,
 			Determinism::Enforced,
+			false,
 		);
 
 		let events = result.events;
@@ -3450,6 +3929,7 @@ fn sr25519_verify() {
 				DebugInfo::Skip,
 				CollectEvents::Skip,
 				Determinism::Enforced,
+				false,
 			)
 			.result
 			.unwrap()
@@ -3582,8 +4062,33 @@ fn upload_code_works() {
 }
 
 #[test]
-fn upload_code_limit_too_low() {
-	let (wasm, _code_hash) = compile_module::<Test>("dummy").unwrap();
+fn upload_code_charges_code_deposit_curve() {
+	let (wasm, code_hash) = compile_module::<Test>("dummy").unwrap();
+	let deposit_expected = expected_deposit(wasm.len()) + 50;
+
+	ExtBuilder::default().existential_deposit(100).build().execute_with(|| {
+		// The dummy contract is smaller than 1 KiB.
+		CodeDepositTiers::set(vec![(0, 50), (1, 1_000)]);
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 1_000_000);
+
+		assert_ok!(Contracts::upload_code(
+			RuntimeOrigin::signed(ALICE),
+			wasm,
+			Some(codec::Compact(deposit_expected)),
+			Determinism::Enforced,
+		));
+
+		assert_eq!(CodeInfoOf::<Test>::get(code_hash).unwrap().deposit(), deposit_expected);
+		assert_eq!(
+			test_utils::get_balance_on_hold(&HoldReason::CodeUploadDepositReserve.into(), &ALICE),
+			deposit_expected,
+		);
+	});
+}
+
+#[test]
+fn upload_code_limit_too_low() {
+	let (wasm, _code_hash) = compile_module::<Test>("dummy").unwrap();
 	let deposit_expected = expected_deposit(wasm.len());
 	let deposit_insufficient = deposit_expected.saturating_sub(1);
 
@@ -3766,6 +4271,143 @@ fn remove_code_not_found() {
 	});
 }
 
+#[test]
+fn code_pruning_works() {
+	let (dummy_wasm, dummy_hash) = compile_module::<Test>("dummy").unwrap();
+	let (wasm, code_hash) = compile_module::<Test>("self_destruct").unwrap();
+
+	ExtBuilder::default().existential_deposit(100).build().execute_with(|| {
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 1_000_000);
+		CodePruningDelay::set(Some(10));
+		initialize_block(2);
+
+		assert_ok!(Contracts::upload_code(
+			RuntimeOrigin::signed(ALICE),
+			dummy_wasm,
+			None,
+			Determinism::Enforced,
+		));
+		let dummy_deposit = expected_deposit(ensure_stored(dummy_hash));
+		let addr = Contracts::bare_instantiate(
+			ALICE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(wasm),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		let deposit = expected_deposit(ensure_stored(code_hash));
+		assert_eq!(CodeUploadedAt::<Test>::get(dummy_hash), Some(2));
+		assert_eq!(CodeUploadedAt::<Test>::get(code_hash), Some(2));
+
+		// Not older than the delay yet.
+		initialize_block(12);
+		Contracts::on_idle(System::block_number(), Weight::MAX);
+		ensure_stored(dummy_hash);
+		assert_eq!(CodePruningCursor::<Test>::get(), None);
+
+		// Only the code that no contract uses is pruned.
+		initialize_block(13);
+		Contracts::on_idle(System::block_number(), Weight::MAX);
+		assert!(!PristineCode::<Test>::contains_key(dummy_hash));
+		assert!(!CodeInfoOf::<Test>::contains_key(dummy_hash));
+		assert!(!CodeUploadedAt::<Test>::contains_key(dummy_hash));
+		ensure_stored(code_hash);
+		assert_eq!(
+			test_utils::get_balance_on_hold(&HoldReason::CodeUploadDepositReserve.into(), &ALICE),
+			deposit
+		);
+		assert_eq!(
+			System::events(),
+			vec![EventRecord {
+				phase: Phase::Initialization,
+				event: RuntimeEvent::Contracts(crate::Event::CodePruned {
+					code_hash: dummy_hash,
+					deposit_released: dummy_deposit,
+					uploader: ALICE,
+				}),
+				topics: vec![dummy_hash],
+			}]
+		);
+
+		// The code is pruned once the contract using it is terminated.
+		assert_ok!(Contracts::call(RuntimeOrigin::signed(ALICE), addr, 0, GAS_LIMIT, None, vec![]));
+		initialize_block(14);
+		Contracts::on_idle(System::block_number(), Weight::MAX);
+		assert!(!PristineCode::<Test>::contains_key(code_hash));
+		assert!(!CodeUploadedAt::<Test>::contains_key(code_hash));
+		assert_eq!(
+			test_utils::get_balance_on_hold(&HoldReason::CodeUploadDepositReserve.into(), &ALICE),
+			0
+		);
+	});
+}
+
+#[test]
+fn code_pruning_resumes_in_next_block() {
+	let (dummy_wasm, dummy_hash) = compile_module::<Test>("dummy").unwrap();
+	let (wasm, code_hash) = compile_module::<Test>("self_destruct").unwrap();
+
+	ExtBuilder::default().existential_deposit(100).build().execute_with(|| {
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 1_000_000);
+
+		// Codes uploaded while the pruning is disabled are not tracked.
+		assert_ok!(Contracts::upload_code(
+			RuntimeOrigin::signed(ALICE),
+			dummy_wasm,
+			None,
+			Determinism::Enforced,
+		));
+		assert!(!CodeUploadedAt::<Test>::contains_key(dummy_hash));
+		CodeUploadedAt::<Test>::insert(dummy_hash, 1);
+
+		CodePruningDelay::set(Some(0));
+		assert_ok!(Contracts::upload_code(
+			RuntimeOrigin::signed(ALICE),
+			wasm,
+			None,
+			Determinism::Enforced,
+		));
+		assert_eq!(CodeUploadedAt::<Test>::get(code_hash), Some(1));
+
+		// Only one code fits in the weight limit of each sweep.
+		let weight_limit = <Test as Config>::WeightInfo::on_process_code_pruning_batch()
+			.saturating_add(<Test as Config>::WeightInfo::on_idle_per_pruned_code());
+		let now = 2;
+		assert_eq!(
+			crate::code_pruning::process_code_pruning_batch::<Test>(now, 0, weight_limit),
+			weight_limit
+		);
+		assert_eq!(CodeUploadedAt::<Test>::iter().count(), 1);
+		assert!(CodePruningCursor::<Test>::get().is_some());
+
+		crate::code_pruning::process_code_pruning_batch::<Test>(now, 0, weight_limit);
+		assert_eq!(CodeUploadedAt::<Test>::iter().count(), 0);
+		assert!(!CodeInfoOf::<Test>::contains_key(dummy_hash));
+		assert!(!CodeInfoOf::<Test>::contains_key(code_hash));
+
+		// The next sweep finds no more code and starts over.
+		crate::code_pruning::process_code_pruning_batch::<Test>(now, 0, weight_limit);
+		assert_eq!(CodePruningCursor::<Test>::get(), None);
+
+		// Too little weight for a single code.
+		assert_eq!(
+			crate::code_pruning::process_code_pruning_batch::<Test>(
+				now,
+				0,
+				<Test as Config>::WeightInfo::on_idle_per_pruned_code()
+			),
+			Weight::zero()
+		);
+	});
+}
+
 #[test]
 fn instantiate_with_zero_balance_works() {
 	let (wasm, code_hash) = compile_module::<Test>("dummy").unwrap();
@@ -3967,6 +4609,113 @@ fn instantiate_with_below_existential_deposit_works() {
 	});
 }
 
+#[test]
+fn fee_breakdown_works() {
+	let (wasm, _code_hash) = compile_module::<Test>("multi_store").unwrap();
+	ExtBuilder::default().existential_deposit(200).build().execute_with(|| {
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 1_000_000);
+		let addr = Contracts::bare_instantiate(
+			ALICE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(wasm),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+
+		// The breakdown is part of the result of `bare_call`.
+		let result = Contracts::bare_call(
+			ALICE,
+			addr.clone(),
+			0,
+			GAS_LIMIT,
+			None,
+			(1_000u32, 5_000u32).encode(),
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+			Determinism::Enforced,
+			false,
+		);
+		assert_ok!(result.result);
+		assert_eq!(
+			result.fee_breakdown,
+			FeeBreakdown {
+				gas_consumed: result.gas_consumed,
+				gas_limit: GAS_LIMIT,
+				storage_deposit: StorageDeposit::Charge(4 + 1_000 + 5_000),
+			}
+		);
+		assert_eq!(result.fee_breakdown.gas_refunded(), GAS_LIMIT - result.gas_consumed);
+
+		let fee_breakdown_events = || {
+			System::events()
+				.into_iter()
+				.filter_map(|record| match record.event {
+					RuntimeEvent::Contracts(crate::Event::FeeBreakdown { contract, breakdown }) =>
+						Some((contract, breakdown)),
+					_ => None,
+				})
+				.collect::<Vec<_>>()
+		};
+
+		// No event unless enabled.
+		initialize_block(2);
+		assert_ok!(Contracts::call(
+			RuntimeOrigin::signed(ALICE),
+			addr.clone(),
+			0,
+			GAS_LIMIT,
+			None,
+			(2_000u32, 4_900u32).encode(),
+		));
+		assert_eq!(fee_breakdown_events(), vec![]);
+
+		FeeBreakdownEvents::set(true);
+		initialize_block(3);
+		assert_ok!(Contracts::call(
+			RuntimeOrigin::signed(ALICE),
+			addr.clone(),
+			0,
+			GAS_LIMIT,
+			None,
+			(3_000u32, 4_900u32).encode(),
+		));
+		let events = fee_breakdown_events();
+		assert_eq!(events.len(), 1);
+		let (contract, breakdown) = &events[0];
+		assert_eq!(contract, &addr);
+		assert_eq!(breakdown.gas_limit, GAS_LIMIT);
+		assert!(breakdown.gas_consumed.any_gt(Weight::zero()));
+		assert!(breakdown.gas_consumed.all_lte(GAS_LIMIT));
+		assert_eq!(breakdown.storage_deposit, StorageDeposit::Charge(1_000));
+
+		// The event is accounted for in the weight of the call.
+		let call_weight = || {
+			RuntimeCall::Contracts(crate::Call::call {
+				dest: addr.clone(),
+				value: 0,
+				gas_limit: GAS_LIMIT,
+				storage_deposit_limit: None,
+				data: vec![],
+			})
+			.get_dispatch_info()
+			.weight
+		};
+		let event_weight = Contracts::fee_breakdown_weight();
+		assert!(event_weight.any_gt(Weight::zero()));
+		let with_event = call_weight();
+		FeeBreakdownEvents::set(false);
+		assert_eq!(Contracts::fee_breakdown_weight(), Weight::zero());
+		assert_eq!(with_event, call_weight() + event_weight);
+	});
+}
+
 #[test]
 fn storage_deposit_works() {
 	let (wasm, _code_hash) = compile_module::<Test>("multi_store").unwrap();
@@ -4414,6 +5163,7 @@ fn contract_reverted() {
 			DebugInfo::Skip,
 			CollectEvents::Skip,
 			Determinism::Enforced,
+			false,
 		)
 		.result
 		.unwrap();
@@ -4556,6 +5306,7 @@ fn set_code_hash() {
 			DebugInfo::UnsafeDebug,
 			CollectEvents::Skip,
 			Determinism::Enforced,
+			false,
 		)
 		.result
 		.unwrap();
@@ -4572,6 +5323,7 @@ fn set_code_hash() {
 			DebugInfo::UnsafeDebug,
 			CollectEvents::Skip,
 			Determinism::Enforced,
+			false,
 		)
 		.result
 		.unwrap();
@@ -4710,6 +5462,83 @@ fn storage_deposit_limit_is_enforced() {
 	});
 }
 
+#[test]
+fn storage_deposit_limit_is_held_during_call() {
+	let (wasm, _code_hash) = compile_module::<Test>("store_call").unwrap();
+	ExtBuilder::default().existential_deposit(200).build().execute_with(|| {
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 1_000_000);
+		let addr = Contracts::bare_instantiate(
+			ALICE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(wasm),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		let limit_on_hold =
+			|| test_utils::get_balance_on_hold(&HoldReason::StorageDepositLimit.into(), &ALICE);
+		assert_eq!(limit_on_hold(), 0);
+
+		// A limit the origin can't afford is rejected before anything is held.
+		let balance = test_utils::get_balance(&ALICE);
+		let result = Contracts::bare_call(
+			ALICE,
+			addr.clone(),
+			0,
+			GAS_LIMIT,
+			Some(balance),
+			1u32.to_le_bytes().to_vec(),
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+			Determinism::Enforced,
+			false,
+		);
+		assert_err!(result.result, <Error<Test>>::StorageDepositNotEnoughFunds);
+		assert_eq!(limit_on_hold(), 0);
+
+		// Exceeding the limit releases the hold without charging anything.
+		let result = Contracts::bare_call(
+			ALICE,
+			addr.clone(),
+			0,
+			GAS_LIMIT,
+			Some(2),
+			1u32.to_le_bytes().to_vec(),
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+			Determinism::Enforced,
+			false,
+		);
+		assert_err!(result.result, <Error<Test>>::StorageDepositLimitExhausted);
+		assert_eq!(limit_on_hold(), 0);
+		assert_eq!(test_utils::get_balance(&ALICE), balance);
+
+		// Only the actual deposit is charged from the held limit, the rest is released.
+		let result = Contracts::bare_call(
+			ALICE,
+			addr.clone(),
+			0,
+			GAS_LIMIT,
+			Some(1_000),
+			1u32.to_le_bytes().to_vec(),
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+			Determinism::Enforced,
+			false,
+		);
+		assert_ok!(result.result);
+		assert_eq!(result.storage_deposit.charge_or_zero(), 3);
+		assert_eq!(limit_on_hold(), 0);
+		assert_eq!(test_utils::get_balance(&ALICE), balance - 3);
+	});
+}
+
 #[test]
 fn deposit_limit_in_nested_calls() {
 	let (wasm_caller, _code_hash_caller) =
@@ -4985,6 +5814,7 @@ fn deposit_limit_in_nested_instantiate() {
 			DebugInfo::Skip,
 			CollectEvents::Skip,
 			Determinism::Enforced,
+			false,
 		);
 
 		let returned = result.result.unwrap();
@@ -5265,6 +6095,7 @@ fn cannot_instantiate_indeterministic_code() {
 				DebugInfo::Skip,
 				CollectEvents::Skip,
 				Determinism::Enforced,
+				false,
 			)
 			.result,
 			<Error<Test>>::Indeterministic,
@@ -5282,6 +6113,7 @@ fn cannot_instantiate_indeterministic_code() {
 				DebugInfo::Skip,
 				CollectEvents::Skip,
 				Determinism::Relaxed,
+				false,
 			)
 			.result,
 			<Error<Test>>::Indeterministic,
@@ -5332,6 +6164,7 @@ fn cannot_set_code_indeterministic_code() {
 				DebugInfo::Skip,
 				CollectEvents::Skip,
 				Determinism::Relaxed,
+				false,
 			)
 			.result,
 			<Error<Test>>::Indeterministic,
@@ -5382,6 +6215,7 @@ fn delegate_call_indeterministic_code() {
 				DebugInfo::Skip,
 				CollectEvents::Skip,
 				Determinism::Enforced,
+				false,
 			)
 			.result,
 			<Error<Test>>::Indeterministic,
@@ -5399,6 +6233,7 @@ fn delegate_call_indeterministic_code() {
 				DebugInfo::Skip,
 				CollectEvents::Skip,
 				Determinism::Relaxed,
+				false,
 			)
 			.result
 		);
@@ -5450,6 +6285,7 @@ fn locking_delegate_dependency_works() {
 			DebugInfo::UnsafeDebug,
 			CollectEvents::Skip,
 			Determinism::Enforced,
+			false,
 		)
 	};
 
@@ -5561,42 +6397,153 @@ fn locking_delegate_dependency_works() {
 }
 
 #[test]
-fn native_dependency_deposit_works() {
-	let (wasm, code_hash) = compile_module::<Test>("set_code_hash").unwrap();
-	let (dummy_wasm, dummy_code_hash) = compile_module::<Test>("dummy").unwrap();
-
-	// Set hash lock up deposit to 30%, to test deposit calculation.
+fn pinning_code_hash_works() {
 	CODE_HASH_LOCKUP_DEPOSIT_PERCENT.with(|c| *c.borrow_mut() = Perbill::from_percent(30));
+	MaxPinnedCodeHashes::set(1);
 
-	// Set a low existential deposit so that the base storage deposit is based on the contract
-	// storage deposit rather than the existential deposit.
-	const ED: u64 = 10;
+	let (wasm_caller, _) = compile_module::<Test>("pinning_code_hash").unwrap();
+	let (wasm_callee, code_hash) = compile_module::<Test>("dummy").unwrap();
+	let (wasm_other, other_code_hash) = compile_module::<Test>("call").unwrap();
 
-	// Test with both existing and uploaded code
-	for code in [Code::Upload(wasm.clone()), Code::Existing(code_hash)] {
-		ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
-			let _ = Balances::set_balance(&ALICE, 1_000_000);
-			let lockup_deposit_percent = CodeHashLockupDepositPercent::get();
+	let noop_input = (0u32, code_hash);
+	let pin_input = (1u32, code_hash);
+	let unpin_input = (2u32, code_hash);
+	let terminate_input = (3u32, code_hash);
 
-			// Upload the dummy contract,
-			Contracts::upload_code(
-				RuntimeOrigin::signed(ALICE),
-				dummy_wasm.clone(),
-				None,
-				Determinism::Enforced,
-			)
-			.unwrap();
+	let call = |addr_caller: &AccountId32, input: &(u32, H256)| {
+		<Pallet<Test>>::bare_call(
+			ALICE,
+			addr_caller.clone(),
+			0,
+			GAS_LIMIT,
+			None,
+			input.encode(),
+			DebugInfo::UnsafeDebug,
+			CollectEvents::Skip,
+			Determinism::Enforced,
+			false,
+		)
+	};
 
-			// Upload `set_code_hash` contracts if using Code::Existing.
-			let add_upload_deposit = match code {
-				Code::Existing(_) => {
-					Contracts::upload_code(
-						RuntimeOrigin::signed(ALICE),
-						wasm.clone(),
-						None,
-						Determinism::Enforced,
-					)
-					.unwrap();
+	const ED: u64 = 2000;
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+
+		let addr_caller = Contracts::bare_instantiate(
+			ALICE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(wasm_caller),
+			noop_input.encode(),
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		let contract = test_utils::get_contract(&addr_caller);
+
+		// Pinning fails as long as the code is not on chain.
+		assert_err!(call(&addr_caller, &pin_input).result, Error::<Test>::CodeNotFound);
+
+		let CodeUploadReturnValue { deposit, .. } =
+			Contracts::bare_upload_code(ALICE, wasm_callee.clone(), None, Determinism::Enforced)
+				.unwrap();
+		assert_ok!(call(&addr_caller, &pin_input).result);
+
+		// The pin holds a fraction of the code deposit.
+		let pin_deposit = CodeHashLockupDepositPercent::get().mul_ceil(deposit);
+		assert_eq!(PinnedCodeHashes::<Test>::get(&addr_caller).get(&code_hash), Some(&pin_deposit));
+		assert_eq!(
+			test_utils::get_balance_on_hold(
+				&HoldReason::StorageDepositReserve.into(),
+				&addr_caller
+			),
+			pin_deposit + contract.storage_base_deposit() - ED
+		);
+
+		// The pinned code can not be removed.
+		assert_err!(
+			Contracts::remove_code(RuntimeOrigin::signed(ALICE), code_hash),
+			<Error<Test>>::CodeInUse
+		);
+
+		// A code hash can only be pinned once.
+		assert_err!(call(&addr_caller, &pin_input).result, Error::<Test>::CodeHashAlreadyPinned);
+
+		// Pinning more than the maximum allowed code hashes fails.
+		Contracts::bare_upload_code(ALICE, wasm_other, None, Determinism::Enforced).unwrap();
+		assert_err!(
+			call(&addr_caller, &(1u32, other_code_hash)).result,
+			Error::<Test>::MaxPinnedCodeHashesReached
+		);
+
+		// Unpinning releases the deposit and allows removing the code.
+		assert_ok!(call(&addr_caller, &unpin_input).result);
+		assert!(PinnedCodeHashes::<Test>::get(&addr_caller).is_empty());
+		assert_eq!(
+			test_utils::get_balance_on_hold(
+				&HoldReason::StorageDepositReserve.into(),
+				&addr_caller
+			),
+			contract.storage_base_deposit() - ED
+		);
+		assert_err!(call(&addr_caller, &unpin_input).result, Error::<Test>::CodeHashNotPinned);
+		assert_ok!(Contracts::remove_code(RuntimeOrigin::signed(ALICE), code_hash));
+
+		// Terminating the contract unpins its code hashes and refunds their deposit.
+		Contracts::bare_upload_code(ALICE, wasm_callee, None, Determinism::Enforced).unwrap();
+		assert_ok!(call(&addr_caller, &pin_input).result);
+		let balance_before = test_utils::get_balance(&ALICE);
+		assert_ok!(call(&addr_caller, &terminate_input).result);
+		assert_eq!(
+			test_utils::get_balance(&ALICE),
+			balance_before + contract.storage_base_deposit() + pin_deposit
+		);
+		assert!(!PinnedCodeHashes::<Test>::contains_key(&addr_caller));
+		assert_ok!(Contracts::remove_code(RuntimeOrigin::signed(ALICE), code_hash));
+	});
+}
+
+#[test]
+fn native_dependency_deposit_works() {
+	let (wasm, code_hash) = compile_module::<Test>("set_code_hash").unwrap();
+	let (dummy_wasm, dummy_code_hash) = compile_module::<Test>("dummy").unwrap();
+
+	// Set hash lock up deposit to 30%, to test deposit calculation.
+	CODE_HASH_LOCKUP_DEPOSIT_PERCENT.with(|c| *c.borrow_mut() = Perbill::from_percent(30));
+
+	// Set a low existential deposit so that the base storage deposit is based on the contract
+	// storage deposit rather than the existential deposit.
+	const ED: u64 = 10;
+
+	// Test with both existing and uploaded code
+	for code in [Code::Upload(wasm.clone()), Code::Existing(code_hash)] {
+		ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+			let _ = Balances::set_balance(&ALICE, 1_000_000);
+			let lockup_deposit_percent = CodeHashLockupDepositPercent::get();
+
+			// Upload the dummy contract,
+			Contracts::upload_code(
+				RuntimeOrigin::signed(ALICE),
+				dummy_wasm.clone(),
+				None,
+				Determinism::Enforced,
+			)
+			.unwrap();
+
+			// Upload `set_code_hash` contracts if using Code::Existing.
+			let add_upload_deposit = match code {
+				Code::Existing(_) => {
+					Contracts::upload_code(
+						RuntimeOrigin::signed(ALICE),
+						wasm.clone(),
+						None,
+						Determinism::Enforced,
+					)
+					.unwrap();
 					false
 				},
 				Code::Upload(_) => true,
@@ -5638,6 +6585,7 @@ fn native_dependency_deposit_works() {
 				DebugInfo::Skip,
 				CollectEvents::Skip,
 				Determinism::Enforced,
+				false,
 			)
 			.result
 			.unwrap();
@@ -5689,6 +6637,7 @@ fn reentrance_count_works_with_call() {
 			DebugInfo::UnsafeDebug,
 			CollectEvents::Skip,
 			Determinism::Enforced,
+			false,
 		)
 		.result
 		.unwrap();
@@ -5730,6 +6679,7 @@ fn reentrance_count_works_with_delegated_call() {
 			DebugInfo::UnsafeDebug,
 			CollectEvents::Skip,
 			Determinism::Enforced,
+			false,
 		)
 		.result
 		.unwrap();
@@ -5785,6 +6735,7 @@ fn account_reentrance_count_works() {
 			DebugInfo::UnsafeDebug,
 			CollectEvents::Skip,
 			Determinism::Enforced,
+			false,
 		)
 		.result
 		.unwrap();
@@ -5799,6 +6750,7 @@ fn account_reentrance_count_works() {
 			DebugInfo::UnsafeDebug,
 			CollectEvents::Skip,
 			Determinism::Enforced,
+			false,
 		)
 		.result
 		.unwrap();
@@ -6014,6 +6966,7 @@ fn gas_consumed_is_linear_for_nested_calls() {
 						DebugInfo::Skip,
 						CollectEvents::Skip,
 						Determinism::Enforced,
+						false,
 					);
 					assert_ok!(result.result);
 					result.gas_consumed
@@ -6027,3 +6980,685 @@ fn gas_consumed_is_linear_for_nested_calls() {
 		assert_eq!(gas_max, gas_0 + gas_per_recursion * max_call_depth as u64);
 	});
 }
+
+#[test]
+fn schedule_call_works() {
+	let (wasm, _code_hash) = compile_module::<Test>("schedule_call").unwrap();
+
+	ExtBuilder::default().existential_deposit(200).build().execute_with(|| {
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 1_000_000);
+		let addr = Contracts::bare_instantiate(
+			ALICE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(wasm),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		let _ = <Test as Config>::Currency::set_balance(&addr, 100_000_000_000);
+
+		// Call the contract with the given action. See the contract for more details.
+		let call = |action: u32, when: u64, index: u32| {
+			<Pallet<Test>>::bare_call(
+				ALICE,
+				addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				(action, when, index).encode(),
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+				Determinism::Enforced,
+				false,
+			)
+			.result
+		};
+		let schedule =
+			|when: u64| call(1, when, 0).map(|retval| u32::decode(&mut &retval.data[..]).unwrap());
+		let held_fee =
+			|| test_utils::get_balance_on_hold(&HoldReason::ScheduledCallFee.into(), &addr);
+		let held_deposit =
+			|| test_utils::get_balance_on_hold(&HoldReason::StorageDepositReserve.into(), &addr);
+		// The gas limit the contract passes to `schedule_call`, priced by `WeightPrice`.
+		let fee = 10_000_000_000;
+
+		// Calls can only be scheduled for future blocks.
+		assert_err!(schedule(1), Error::<Test>::InvalidScheduledCallBlock);
+
+		// The fee for the gas limit is held from the contract and the storage deposit is charged
+		// from the origin for every scheduled call.
+		let base_deposit = held_deposit();
+		let alice_balance = <Test as Config>::Currency::free_balance(&ALICE);
+		assert_eq!(schedule(2), Ok(0));
+		let deposit = held_deposit() - base_deposit;
+		assert!(deposit > 0);
+		assert_eq!(<Test as Config>::Currency::free_balance(&ALICE), alice_balance - deposit);
+		assert_eq!(held_fee(), fee);
+		assert_eq!(schedule(2), Ok(1));
+		assert_eq!(held_deposit(), base_deposit + 2 * deposit);
+		assert_eq!(held_fee(), 2 * fee);
+
+		// Only `MaxScheduledCallsPerBlock` calls can be scheduled for the same block.
+		assert_err!(schedule(2), Error::<Test>::TooManyScheduledCalls);
+		assert_eq!(schedule(3), Ok(0));
+		assert_ok!(call(2, 3, 0));
+
+		// Cancelling refunds the deposit, releases the fee and frees the slot.
+		assert_ok!(call(2, 2, 0));
+		assert_eq!(held_deposit(), base_deposit + deposit);
+		assert_eq!(held_fee(), fee);
+		assert_err!(call(2, 2, 0), Error::<Test>::ScheduledCallNotFound);
+		assert_eq!(schedule(2), Ok(0));
+		assert_ok!(call(2, 2, 0));
+		assert_eq!(Contracts::get_storage(addr.clone(), vec![1u8; 32]), Ok(None));
+
+		// The remaining call is executed at the beginning of its block. Its deposit is released
+		// and only the fee for the gas it consumed is burned.
+		let balance = <Test as Config>::Currency::total_balance(&addr);
+		initialize_block(2);
+		let weight = Contracts::on_initialize(2);
+		assert_eq!(Contracts::get_storage(addr.clone(), vec![1u8; 32]), Ok(Some(vec![42])));
+		assert_eq!(held_fee(), 0);
+		assert!(ScheduledCalls::<Test>::get(2).is_empty());
+		assert!(System::events().iter().any(|record| record.event ==
+			RuntimeEvent::Contracts(crate::Event::ScheduledCallExecuted {
+				contract: addr.clone(),
+				index: 1,
+				result: Ok(()),
+			})));
+
+		// The weight of the call is only accounted for in the block that executes it.
+		let gas_consumed =
+			weight - <Test as Config>::WeightInfo::on_initialize_per_scheduled_call(2);
+		let burned = balance - <Test as Config>::Currency::total_balance(&addr);
+		assert!(burned > 0 && burned < fee);
+		assert_eq!(burned, gas_consumed.ref_time());
+	});
+}
+
+#[test]
+fn scheduled_calls_are_not_executed_during_migration() {
+	let (wasm, _code_hash) = compile_module::<Test>("schedule_call").unwrap();
+
+	ExtBuilder::default().existential_deposit(200).build().execute_with(|| {
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 1_000_000);
+		let addr = Contracts::bare_instantiate(
+			ALICE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(wasm),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		let _ = <Test as Config>::Currency::set_balance(&addr, 100_000_000_000);
+		assert_ok!(
+			<Pallet<Test>>::bare_call(
+				ALICE,
+				addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				(1u32, 2u64, 0u32).encode(),
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+				Determinism::Enforced,
+				false,
+			)
+			.result
+		);
+		let balance = <Test as Config>::Currency::total_balance(&addr);
+
+		MigrationInProgress::<Test>::set(Some(Default::default()));
+		initialize_block(2);
+		assert_eq!(
+			Contracts::on_initialize(2),
+			<Test as Config>::WeightInfo::on_initialize_per_scheduled_call(1)
+		);
+
+		// The call is dropped and its fee is released in full.
+		MigrationInProgress::<Test>::set(None);
+		assert_eq!(Contracts::get_storage(addr.clone(), vec![1u8; 32]), Ok(None));
+		assert!(ScheduledCalls::<Test>::get(2).is_empty());
+		assert_eq!(test_utils::get_balance_on_hold(&HoldReason::ScheduledCallFee.into(), &addr), 0);
+		assert_eq!(<Test as Config>::Currency::total_balance(&addr), balance);
+		assert!(System::events().iter().any(|record| record.event ==
+			RuntimeEvent::Contracts(crate::Event::ScheduledCallExecuted {
+				contract: addr.clone(),
+				index: 0,
+				result: Err(Error::<Test>::MigrationInProgress.into()),
+			})));
+	});
+}
+
+#[test]
+fn contract_dump_works() {
+	let (wasm, code_hash) = compile_module::<Test>("dummy").unwrap();
+	ExtBuilder::default().existential_deposit(50).build().execute_with(|| {
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 1_000_000);
+		let addr = Contracts::bare_instantiate(
+			ALICE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(wasm.clone()),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+
+		assert_eq!(
+			Contracts::contract_dump(BOB, false, None),
+			Err(ContractAccessError::DoesntExist)
+		);
+
+		// Three items of which only two fit into a single dump.
+		let info = get_contract(&addr);
+		let value_len = CONTRACT_DUMP_STORAGE_LIMIT as usize * 2 / 5;
+		let mut items = (1u8..=3)
+			.map(|i| {
+				let key = Key::<Test>::Fix([i; 32]);
+				info.write(&key, Some(vec![i; value_len]), None, false).unwrap();
+				(key.hash(), vec![i; value_len])
+			})
+			.collect::<Vec<_>>();
+		items.sort();
+
+		let dump = Contracts::contract_dump(addr.clone(), true, None).unwrap();
+		assert_eq!(dump.code_hash, code_hash);
+		assert_eq!(dump.code, Some(wasm));
+		assert!(dump.delegate_dependencies.is_empty());
+		assert_eq!(dump.storage, items[..2].to_vec());
+		assert_eq!(dump.cursor, Some(items[1].0.clone()));
+
+		let dump = Contracts::contract_dump(addr, false, dump.cursor).unwrap();
+		assert_eq!(dump.code, None);
+		assert_eq!(dump.storage, items[2..].to_vec());
+		assert_eq!(dump.cursor, None);
+	});
+}
+
+#[test]
+fn instantiation_permissions_work() {
+	let (wasm, code_hash) = compile_module::<Test>("dummy").unwrap();
+
+	ExtBuilder::default().existential_deposit(100).build().execute_with(|| {
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 1_000_000);
+		let _ = <Test as Config>::Currency::set_balance(&BOB, 1_000_000);
+		assert_ok!(Contracts::upload_code(
+			RuntimeOrigin::signed(ALICE),
+			wasm,
+			None,
+			Determinism::Enforced
+		));
+		let instantiate = |origin: AccountId32, salt: Vec<u8>| {
+			Contracts::instantiate(
+				RuntimeOrigin::signed(origin),
+				0,
+				GAS_LIMIT,
+				None,
+				code_hash,
+				vec![],
+				salt,
+			)
+		};
+		let dry_run = |origin: AccountId32| {
+			Contracts::bare_instantiate(
+				origin,
+				0,
+				GAS_LIMIT,
+				None,
+				Code::Existing(code_hash),
+				vec![],
+				vec![3],
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+			)
+			.result
+		};
+
+		// Only the permission origin can change permissions.
+		assert_noop!(
+			Contracts::set_instantiation_permission(
+				RuntimeOrigin::signed(ALICE),
+				BOB,
+				Some(InstantiationPermission::Denied)
+			),
+			DispatchError::BadOrigin,
+		);
+
+		// Denied accounts can't instantiate, not even in a dry-run.
+		assert_ok!(Contracts::set_instantiation_permission(
+			RuntimeOrigin::root(),
+			BOB,
+			Some(InstantiationPermission::Denied)
+		));
+		assert_err_ignore_postinfo!(
+			instantiate(BOB, vec![1]),
+			Error::<Test>::InstantiationNotAllowed
+		);
+		assert_err!(dry_run(BOB), Error::<Test>::InstantiationNotAllowed);
+		assert_ok!(instantiate(ALICE, vec![1]));
+
+		// During a permissioned phase only allowed accounts can instantiate.
+		PermissionlessInstantiation::set(false);
+		assert_ok!(Contracts::set_instantiation_permission(
+			RuntimeOrigin::root(),
+			BOB,
+			Some(InstantiationPermission::Allowed)
+		));
+		assert_err_ignore_postinfo!(
+			instantiate(ALICE, vec![2]),
+			Error::<Test>::InstantiationNotAllowed
+		);
+		assert_ok!(dry_run(BOB));
+		assert_ok!(instantiate(BOB, vec![2]));
+
+		// Removing the permission falls back to the instantiate origin.
+		assert_ok!(Contracts::set_instantiation_permission(RuntimeOrigin::root(), BOB, None));
+		assert!(!Contracts::can_instantiate(&BOB));
+		PermissionlessInstantiation::set(true);
+		assert!(Contracts::can_instantiate(&BOB));
+	});
+}
+
+#[test]
+fn sponsored_storage_deposit_works() {
+	let (wasm, code_hash) = compile_module::<Test>("multi_store").unwrap();
+
+	ExtBuilder::default().existential_deposit(200).build().execute_with(|| {
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 1_000_000);
+		let _ = <Test as Config>::Currency::set_balance(&BOB, 1_000_000);
+		assert_ok!(Contracts::upload_code(
+			RuntimeOrigin::signed(ALICE),
+			wasm,
+			None,
+			Determinism::Enforced
+		));
+		let addr = Contracts::contract_address(&BOB, &code_hash, &[], &[]);
+		let approve = |remaining, per_call| {
+			Contracts::approve_deposit_sponsorship(
+				RuntimeOrigin::signed(ALICE),
+				BOB,
+				Some(DepositAllowance { remaining, per_call }),
+			)
+		};
+		let remaining = || DepositAllowances::<Test>::get(ALICE, BOB).map(|a| a.remaining);
+		let call = |data: Vec<u8>| {
+			Contracts::call_sponsored(
+				RuntimeOrigin::signed(BOB),
+				ALICE,
+				addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				data,
+			)
+		};
+
+		// Without an allowance the sponsor can't be charged.
+		assert_err_ignore_postinfo!(
+			Contracts::instantiate_sponsored(
+				RuntimeOrigin::signed(BOB),
+				ALICE,
+				0,
+				GAS_LIMIT,
+				None,
+				code_hash,
+				vec![],
+				vec![],
+			),
+			Error::<Test>::NoDepositAllowance,
+		);
+
+		// The sponsor pays the deposit of the instantiation.
+		assert_ok!(approve(100_000, 100_000));
+		let alice_balance = test_utils::get_balance(&ALICE);
+		let bob_balance = test_utils::get_balance(&BOB);
+		assert_ok!(Contracts::instantiate_sponsored(
+			RuntimeOrigin::signed(BOB),
+			ALICE,
+			0,
+			GAS_LIMIT,
+			None,
+			code_hash,
+			vec![],
+			vec![],
+		));
+		let charged = alice_balance - test_utils::get_balance(&ALICE);
+		assert!(charged > 0);
+		assert_eq!(test_utils::get_balance(&BOB), bob_balance);
+		assert_eq!(remaining(), Some(100_000 - charged));
+
+		// The remaining allowance limits the deposit of a call.
+		assert_ok!(approve(100, 100_000));
+		assert_err_ignore_postinfo!(
+			call((1_000u32, 5_000u32).encode()),
+			Error::<Test>::StorageDepositLimitExhausted,
+		);
+
+		// So does the per-call limit, even if enough allowance remains.
+		assert_ok!(approve(100_000, 6_003));
+		assert_err_ignore_postinfo!(
+			call((1_000u32, 5_000u32).encode()),
+			Error::<Test>::StorageDepositLimitExhausted,
+		);
+		assert_eq!(remaining(), Some(100_000));
+
+		// 4 is for creating 2 storage items.
+		assert_ok!(approve(10_000, 6_004));
+		initialize_block(2);
+		assert_ok!(call((1_000u32, 5_000u32).encode()));
+		assert_eq!(remaining(), Some(10_000 - 6_004));
+		assert_eq!(test_utils::get_balance(&BOB), bob_balance);
+		assert!(System::events().iter().any(|record| record.event ==
+			RuntimeEvent::Contracts(crate::Event::StorageDepositSponsored {
+				sponsor: ALICE,
+				caller: BOB,
+				amount: 6_004,
+			})));
+
+		// A revoked allowance can't be used anymore.
+		assert_ok!(Contracts::approve_deposit_sponsorship(RuntimeOrigin::signed(ALICE), BOB, None));
+		assert_err_ignore_postinfo!(call(vec![]), Error::<Test>::NoDepositAllowance);
+	});
+}
+
+#[test]
+fn sponsored_instantiate_with_code_works() {
+	let (wasm, code_hash) = compile_module::<Test>("dummy").unwrap();
+
+	ExtBuilder::default().existential_deposit(200).build().execute_with(|| {
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 1_000_000);
+		let _ = <Test as Config>::Currency::set_balance(&BOB, 1_000_000);
+		let instantiate = || {
+			Contracts::instantiate_with_code_sponsored(
+				RuntimeOrigin::signed(BOB),
+				ALICE,
+				0,
+				GAS_LIMIT,
+				None,
+				wasm.clone(),
+				vec![],
+				vec![],
+			)
+		};
+		let approve = |remaining, per_call| {
+			Contracts::approve_deposit_sponsorship(
+				RuntimeOrigin::signed(ALICE),
+				BOB,
+				Some(DepositAllowance { remaining, per_call }),
+			)
+		};
+
+		// Without an allowance the sponsor can't be charged.
+		assert_err_ignore_postinfo!(instantiate(), Error::<Test>::NoDepositAllowance);
+
+		// The per-call limit bounds the deposit of the code and the contract together.
+		assert_ok!(approve(1_000_000, 1));
+		assert_err_ignore_postinfo!(instantiate(), Error::<Test>::StorageDepositLimitExhausted);
+		assert!(!PristineCode::<Test>::contains_key(&code_hash));
+
+		// The sponsor pays both deposits and owns the code.
+		assert_ok!(approve(1_000_000, 1_000_000));
+		let alice_balance = test_utils::get_balance(&ALICE);
+		let bob_balance = test_utils::get_balance(&BOB);
+		assert_ok!(instantiate());
+		let charged = alice_balance - test_utils::get_balance(&ALICE);
+		let code_deposit = CodeInfoOf::<Test>::get(&code_hash).unwrap().deposit();
+		assert!(charged > code_deposit);
+		assert!(System::events().iter().any(|record| record.event ==
+			RuntimeEvent::Contracts(crate::Event::CodeStored {
+				code_hash,
+				deposit_held: code_deposit,
+				uploader: ALICE,
+			})));
+		assert_eq!(test_utils::get_balance(&BOB), bob_balance);
+		assert_eq!(
+			DepositAllowances::<Test>::get(ALICE, BOB).map(|a| a.remaining),
+			Some(1_000_000 - charged)
+		);
+	});
+}
+
+#[test]
+fn instantiate_with_address_kind_works() {
+	let (wasm, code_hash) = compile_module::<Test>("dummy").unwrap();
+	ExtBuilder::default().existential_deposit(200).build().execute_with(|| {
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 1_000_000);
+		let _ = <Test as Config>::Currency::set_balance(&BOB, 1_000_000);
+		assert_ok!(Contracts::upload_code(
+			RuntimeOrigin::signed(ALICE),
+			wasm,
+			None,
+			Determinism::Enforced
+		));
+		let instantiate = |origin: AccountId32, salt: Vec<u8>, address_kind: AddressKind| {
+			Contracts::instantiate_with_address_kind(
+				RuntimeOrigin::signed(origin),
+				0,
+				GAS_LIMIT,
+				None,
+				code_hash,
+				vec![],
+				salt,
+				address_kind,
+			)
+		};
+
+		// The namespaced address doesn't depend on the deployer.
+		let namespace = [1u8; 32];
+		let addr = Contracts::namespaced_contract_address(&namespace, &code_hash, &[], &[1]);
+		assert_ne!(addr, Contracts::contract_address(&ALICE, &code_hash, &[], &[1]));
+		assert_ne!(addr, Contracts::namespaced_contract_address(&[2u8; 32], &code_hash, &[], &[1]));
+		assert_ne!(
+			addr,
+			Contracts::namespaced_contract_address(&namespace, &code_hash, &[0], &[1])
+		);
+		assert_ok!(instantiate(ALICE, vec![1], AddressKind::Namespaced(namespace)));
+		assert!(ContractInfoOf::<Test>::contains_key(&addr));
+		assert!(System::events().iter().any(|record| record.event ==
+			RuntimeEvent::Contracts(crate::Event::InstantiatedNamespaced {
+				deployer: ALICE,
+				contract: addr.clone(),
+				namespace,
+			})));
+
+		// Anyone deploying with the same salt to the same namespace collides.
+		assert_err_ignore_postinfo!(
+			instantiate(BOB, vec![1], AddressKind::Namespaced(namespace)),
+			Error::<Test>::NamespacedAddressTaken,
+		);
+		assert_ok!(instantiate(BOB, vec![2], AddressKind::Namespaced(namespace)));
+		assert!(ContractInfoOf::<Test>::contains_key(&Contracts::namespaced_contract_address(
+			&namespace,
+			&code_hash,
+			&[],
+			&[2]
+		)));
+
+		// The default derivation still depends on the deployer.
+		assert_ok!(instantiate(BOB, vec![1], AddressKind::Deployer));
+		assert!(ContractInfoOf::<Test>::contains_key(&Contracts::contract_address(
+			&BOB,
+			&code_hash,
+			&[],
+			&[1]
+		)));
+	});
+}
+
+#[test]
+fn execution_budget_limits_contract_calls() {
+	use crate::CheckExecutionBudget;
+	use frame_support::traits::OnFinalize;
+	use sp_runtime::{traits::SignedExtension, transaction_validity::InvalidTransaction};
+
+	let (wasm, _code_hash) = compile_module::<Test>("dummy").unwrap();
+	ExtBuilder::default().existential_deposit(50).build().execute_with(|| {
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 1_000_000);
+		let addr = Contracts::bare_instantiate(
+			ALICE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(wasm),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+
+		let call = |gas_limit| {
+			RuntimeCall::Contracts(crate::Call::call {
+				dest: addr.clone(),
+				value: 0,
+				gas_limit,
+				storage_deposit_limit: None,
+				data: vec![],
+			})
+		};
+		let info = call(GAS_LIMIT).get_dispatch_info();
+		let check = || CheckExecutionBudget::<Test>::new();
+
+		// The budget fits a single call.
+		BLOCK_EXECUTION_BUDGET.with(|v| *v.borrow_mut() = info.weight);
+		assert_eq!(Contracts::execution_budget_left(), info.weight);
+		let too_big = call(GAS_LIMIT.saturating_add(Weight::from_parts(1, 1)));
+		assert_eq!(
+			check().validate(&ALICE, &too_big, &too_big.get_dispatch_info(), 0),
+			Err(InvalidTransaction::ExhaustsResources.into()),
+		);
+
+		assert_eq!(check().pre_dispatch(&ALICE, &call(GAS_LIMIT), &info, 0), Ok(true));
+		assert_ok!(Contracts::call(
+			RuntimeOrigin::signed(ALICE),
+			addr.clone(),
+			0,
+			GAS_LIMIT,
+			None,
+			vec![]
+		));
+		let left = Contracts::execution_budget_left();
+		assert!(left.any_lt(info.weight));
+
+		// Further calls are left for a later block, or fail when nested in other calls.
+		assert_eq!(
+			check().validate(&ALICE, &call(GAS_LIMIT), &info, 0),
+			Err(InvalidTransaction::ExhaustsResources.into()),
+		);
+		assert_eq!(
+			check().pre_dispatch(&ALICE, &call(GAS_LIMIT), &info, 0),
+			Err(InvalidTransaction::ExhaustsResources.into()),
+		);
+		assert_err_ignore_postinfo!(
+			Contracts::call(RuntimeOrigin::signed(ALICE), addr.clone(), 0, GAS_LIMIT, None, vec![]),
+			Error::<Test>::ExecutionBudgetExhausted,
+		);
+
+		// Other extrinsics are not affected.
+		let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+		assert_eq!(
+			check().pre_dispatch(&ALICE, &remark, &remark.get_dispatch_info(), 0),
+			Ok(false)
+		);
+
+		// A failed call is charged by the extension and can saturate the budget.
+		let post_info =
+			PostDispatchInfo { actual_weight: Some(left), pays_fee: Default::default() };
+		assert_ok!(CheckExecutionBudget::<Test>::post_dispatch(
+			Some(true),
+			&info,
+			&post_info,
+			0,
+			&Err(Error::<Test>::ContractTrapped.into()),
+		));
+		assert_eq!(Contracts::execution_budget_left(), Weight::zero());
+		assert_eq!(
+			System::events().last().unwrap().event,
+			RuntimeEvent::Contracts(crate::Event::ExecutionBudgetSaturated {
+				consumed: info.weight
+			}),
+		);
+
+		// The budget is available again in the next block.
+		Contracts::on_finalize(1);
+		assert_eq!(Contracts::execution_budget_left(), info.weight);
+	});
+}
+
+#[test]
+fn immutable_data_works() {
+	let (wasm, _code_hash) = compile_module::<Test>("immutable_data").unwrap();
+	ExtBuilder::default().existential_deposit(50).build().execute_with(|| {
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 1_000_000);
+		let instantiate = |data: Vec<u8>, salt: Vec<u8>| {
+			Contracts::bare_instantiate(
+				ALICE,
+				0,
+				GAS_LIMIT,
+				None,
+				Code::Upload(wasm.clone()),
+				data,
+				salt,
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+			)
+			.result
+			.unwrap()
+			.account_id
+		};
+		let call = |addr: &AccountId32, data: Vec<u8>| {
+			Contracts::bare_call(
+				ALICE,
+				addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				data,
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+				Determinism::Enforced,
+				false,
+			)
+			.result
+		};
+
+		let empty = instantiate(vec![], vec![0]);
+		let addr = instantiate(vec![1, 2, 3], vec![1]);
+
+		// The data is stored with the contract info and charged with its base deposit.
+		assert_eq!(get_contract(&addr).immutable_data(), &[1, 2, 3]);
+		assert!(
+			get_contract(&addr).storage_base_deposit() >
+				get_contract(&empty).storage_base_deposit()
+		);
+
+		// It is readable from calls.
+		assert_eq!(call(&addr, vec![]).unwrap().data, vec![1, 2, 3]);
+		assert_eq!(call(&empty, vec![]).unwrap().data, Vec::<u8>::new());
+
+		// But can't be changed by them.
+		assert_err!(call(&addr, vec![4, 5, 6]), <Error<Test>>::InvalidImmutableAccess);
+		assert_err!(call(&empty, vec![4, 5, 6]), <Error<Test>>::InvalidImmutableAccess);
+		assert_eq!(get_contract(&addr).immutable_data(), &[1, 2, 3]);
+	});
+}
@@ -39,8 +39,8 @@ use crate::{
 	gas::{GasMeter, Token},
 	wasm::prepare::LoadedModule,
 	weights::WeightInfo,
-	AccountIdOf, BadOrigin, BalanceOf, CodeHash, CodeInfoOf, CodeVec, Config, Error, Event,
-	HoldReason, Pallet, PristineCode, Schedule, Weight, LOG_TARGET,
+	AccountIdOf, BadOrigin, BalanceOf, CodeHash, CodeInfoOf, CodeUploadedAt, CodeVec, Config,
+	Error, Event, HoldReason, Pallet, PristineCode, Schedule, Weight, LOG_TARGET,
 };
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
@@ -181,6 +181,7 @@ impl<T: Config> WasmBlob<T> {
 
 				*existing = None;
 				<PristineCode<T>>::remove(&code_hash);
+				<CodeUploadedAt<T>>::remove(&code_hash);
 				<Pallet<T>>::deposit_event(
 					vec![code_hash],
 					Event::CodeRemoved { code_hash, deposit_released, remover },
@@ -192,6 +193,35 @@ impl<T: Config> WasmBlob<T> {
 		})
 	}
 
+	/// Remove the code from storage if no contract uses it and refund the deposit to its owner.
+	///
+	/// Returns `true` if the code was removed.
+	pub fn prune(code_hash: CodeHash<T>) -> bool {
+		let Some(code_info) = <CodeInfoOf<T>>::get(&code_hash) else {
+			<CodeUploadedAt<T>>::remove(&code_hash);
+			return false
+		};
+		if code_info.refcount != 0 {
+			return false
+		}
+		let deposit_released = T::Currency::release(
+			&HoldReason::CodeUploadDepositReserve.into(),
+			&code_info.owner,
+			code_info.deposit,
+			BestEffort,
+		)
+		.unwrap_or_default();
+
+		<CodeInfoOf<T>>::remove(&code_hash);
+		<PristineCode<T>>::remove(&code_hash);
+		<CodeUploadedAt<T>>::remove(&code_hash);
+		<Pallet<T>>::deposit_event(
+			vec![code_hash],
+			Event::CodePruned { code_hash, deposit_released, uploader: code_info.owner },
+		);
+		true
+	}
+
 	/// Creates and returns an instance of the supplied code.
 	///
 	/// This is either used for later executing a contract or for validation of a contract.
@@ -269,6 +299,12 @@ impl<T: Config> WasmBlob<T> {
 
 					self.code_info.refcount = 0;
 					<PristineCode<T>>::insert(code_hash, &self.code);
+					if T::CodePruningDelay::get().is_some() {
+						<CodeUploadedAt<T>>::insert(
+							code_hash,
+							<frame_system::Pallet<T>>::block_number(),
+						);
+					}
 					*stored_code_info = Some(self.code_info.clone());
 					<Pallet<T>>::deposit_event(
 						vec![code_hash],
@@ -438,14 +474,16 @@ mod tests {
 	use crate::{
 		exec::{AccountIdOf, ErrorOrigin, ExecError, Executable, Ext, Key, SeedOf},
 		gas::GasMeter,
-		primitives::ExecReturnValue,
+		primitives::{ExecReturnValue, RandomnessOutput, StorageDeposit},
 		storage::WriteOutcome,
 		tests::{RuntimeCall, Test, ALICE, BOB},
 		BalanceOf, CodeHash, Error, Origin, Pallet as Contracts,
 	};
 	use assert_matches::assert_matches;
 	use frame_support::{
-		assert_err, assert_ok, dispatch::DispatchResultWithPostInfo, weights::Weight,
+		assert_err, assert_ok,
+		dispatch::{DispatchResult, DispatchResultWithPostInfo},
+		weights::Weight,
 	};
 	use frame_system::pallet_prelude::BlockNumberFor;
 	use pallet_contracts_uapi::ReturnFlags;
@@ -513,6 +551,9 @@ mod tests {
 		code_hashes: Vec<CodeHash<Test>>,
 		caller: Origin<Test>,
 		delegate_dependencies: RefCell<HashSet<CodeHash<Test>>>,
+		pinned_code_hashes: RefCell<HashSet<CodeHash<Test>>>,
+		reentrancy_guard: bool,
+		immutable_data: Vec<u8>,
 	}
 
 	/// The call is mocked and just returns this hardcoded value.
@@ -539,6 +580,9 @@ mod tests {
 				caller: Default::default(),
 				sr25519_verify: Default::default(),
 				delegate_dependencies: Default::default(),
+				pinned_code_hashes: Default::default(),
+				reentrancy_guard: false,
+				immutable_data: Default::default(),
 			}
 		}
 	}
@@ -554,6 +598,8 @@ mod tests {
 			value: u64,
 			data: Vec<u8>,
 			allows_reentry: bool,
+			_read_only: bool,
+			_use_cache: bool,
 		) -> Result<ExecReturnValue, ExecError> {
 			self.calls.push(CallEntry { to, value, data, allows_reentry });
 			Ok(ExecReturnValue { flags: ReturnFlags::empty(), data: call_return_data() })
@@ -595,6 +641,12 @@ mod tests {
 			self.transfers.push(TransferEntry { to: to.clone(), value });
 			Ok(())
 		}
+		fn is_read_only(&self) -> bool {
+			false
+		}
+		fn ensure_writable(&mut self) -> DispatchResult {
+			Ok(())
+		}
 		fn terminate(&mut self, beneficiary: &AccountIdOf<Self::T>) -> Result<(), DispatchError> {
 			self.terminations.push(TerminationEntry { beneficiary: beneficiary.clone() });
 			Ok(())
@@ -661,9 +713,17 @@ mod tests {
 		fn random(&self, subject: &[u8]) -> (SeedOf<Self::T>, BlockNumberFor<Self::T>) {
 			(H256::from_slice(subject), 42)
 		}
+		fn randomness(&self, domain: &[u8]) -> RandomnessOutput<H256, u64> {
+			let mut seed = H256::zero();
+			seed[..domain.len()].copy_from_slice(domain);
+			RandomnessOutput { seed, known_since: 42 }
+		}
 		fn deposit_event(&mut self, topics: Vec<H256>, data: Vec<u8>) {
 			self.events.push((topics, data))
 		}
+		fn register_event_topic(&mut self, topic: Vec<u8>) -> Result<H256, DispatchError> {
+			Ok(H256::from(sp_io::hashing::blake2_256(&topic)))
+		}
 		fn block_number(&self) -> u64 {
 			121
 		}
@@ -687,6 +747,12 @@ mod tests {
 			&mut self.gas_meter
 		}
 		fn charge_storage(&mut self, _diff: &crate::storage::meter::Diff) {}
+		fn storage_deposit_consumed(&mut self) -> StorageDeposit<u64> {
+			StorageDeposit::Charge(42)
+		}
+		fn storage_deposit_limit(&self) -> u64 {
+			1000
+		}
 
 		fn debug_buffer_enabled(&self) -> bool {
 			true
@@ -726,6 +792,16 @@ mod tests {
 		fn account_reentrance_count(&self, _account_id: &AccountIdOf<Self::T>) -> u32 {
 			12
 		}
+		fn set_reentrancy_guard(&mut self, enabled: bool) {
+			self.reentrancy_guard = enabled;
+		}
+		fn immutable_data(&mut self) -> Vec<u8> {
+			self.immutable_data.clone()
+		}
+		fn set_immutable_data(&mut self, data: Vec<u8>) -> DispatchResult {
+			self.immutable_data = data;
+			Ok(())
+		}
 		fn nonce(&mut self) -> u64 {
 			995
 		}
@@ -747,6 +823,42 @@ mod tests {
 			self.delegate_dependencies.borrow_mut().remove(code);
 			Ok(())
 		}
+		fn pin_code_hash(&mut self, code: CodeHash<Self::T>) -> Result<(), DispatchError> {
+			self.pinned_code_hashes.borrow_mut().insert(code);
+			Ok(())
+		}
+		fn unpin_code_hash(&mut self, code: &CodeHash<Self::T>) -> Result<(), DispatchError> {
+			self.pinned_code_hashes.borrow_mut().remove(code);
+			Ok(())
+		}
+		fn schedule_call(
+			&mut self,
+			_when: BlockNumberFor<Self::T>,
+			_input_data: Vec<u8>,
+			_gas_limit: Weight,
+			_storage_deposit_limit: Option<BalanceOf<Self::T>>,
+		) -> Result<u32, DispatchError> {
+			Ok(0)
+		}
+		fn cancel_scheduled_call(
+			&mut self,
+			_when: BlockNumberFor<Self::T>,
+			_index: u32,
+		) -> Result<(), DispatchError> {
+			Ok(())
+		}
+		fn register_xcm_query(
+			&mut self,
+			_query_id: crate::XcmQueryIdOf<Self::T>,
+		) -> DispatchResult {
+			Ok(())
+		}
+		fn is_xcm_query_owner(&self, _query_id: crate::XcmQueryIdOf<Self::T>) -> bool {
+			true
+		}
+		fn forget_xcm_query(&mut self, _query_id: crate::XcmQueryIdOf<Self::T>) -> DispatchResult {
+			Ok(())
+		}
 	}
 
 	/// Execute the supplied code.
@@ -2198,6 +2310,48 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn randomness_works() {
+		const CODE: &str = r#"
+(module
+	(import "seal0" "randomness" (func $randomness (param i32 i32 i32 i32)))
+	(import "seal0" "seal_return" (func $seal_return (param i32 i32 i32)))
+	(import "env" "memory" (memory 1 1))
+
+	;; [0,128) is reserved for the output.
+
+	;; the domain. [128,135)
+	(data (i32.const 128) "lottery")
+
+	;; size of our buffer is 128 bytes
+	(data (i32.const 160) "\80")
+
+	(func (export "call")
+		(call $randomness
+			(i32.const 128) ;; Pointer in memory to the start of the domain
+			(i32.const 7) ;; The domain's length
+			(i32.const 0) ;; Pointer to the output buffer
+			(i32.const 160) ;; Pointer to the output buffer length
+		)
+
+		;; return the randomness
+		(call $seal_return
+			(i32.const 0)
+			(i32.const 0)
+			(i32.load (i32.const 160))
+		)
+	)
+	(func (export "deploy"))
+)
+"#;
+		let output = execute(CODE, vec![], MockExt::default()).unwrap();
+
+		// The mock ext pads the domain to get the seed.
+		let mut seed = H256::zero();
+		seed[..7].copy_from_slice(b"lottery");
+		assert_eq!(output.data, RandomnessOutput { seed, known_since: 42u64 }.encode());
+	}
+
 	const CODE_DEPOSIT_EVENT: &str = r#"
 (module
 	(import "seal0" "seal_deposit_event" (func $seal_deposit_event (param i32 i32 i32 i32)))
@@ -3264,6 +3418,143 @@ mod tests {
 		execute(CODE, vec![], &mut mock_ext).unwrap();
 	}
 
+	#[test]
+	fn set_reentrancy_guard_works() {
+		const CODE: &str = r#"
+(module
+	(import "seal0" "set_reentrancy_guard" (func $set_reentrancy_guard (param i32)))
+	(import "env" "memory" (memory 1 1))
+	(func (export "call")
+		(call $set_reentrancy_guard (i32.const 1))
+	)
+	(func (export "deploy"))
+)
+"#;
+
+		let mut mock_ext = MockExt::default();
+		assert_ok!(execute(CODE, vec![], &mut mock_ext));
+		assert!(mock_ext.reentrancy_guard);
+	}
+
+	#[test]
+	fn set_immutable_data_works() {
+		const CODE: &str = r#"
+(module
+	(import "seal0" "set_immutable_data" (func $set_immutable_data (param i32 i32)))
+	(import "env" "memory" (memory 1 1))
+
+	(data (i32.const 0) "\01\02\03\04")
+
+	(func (export "call"))
+	(func (export "deploy")
+		(call $set_immutable_data (i32.const 0) (i32.const 4))
+	)
+)
+"#;
+
+		let mut mock_ext = MockExt::default();
+		assert_ok!(execute_instantiate(CODE, vec![], &mut mock_ext));
+		assert_eq!(mock_ext.immutable_data, vec![1, 2, 3, 4]);
+	}
+
+	#[test]
+	fn get_immutable_data_works() {
+		const CODE: &str = r#"
+(module
+	(import "seal0" "get_immutable_data" (func $get_immutable_data (param i32 i32)))
+	(import "seal0" "seal_return" (func $seal_return (param i32 i32 i32)))
+	(import "env" "memory" (memory 1 1))
+
+	;; [0, 4) size of the output buffer
+	(data (i32.const 0) "\20")
+
+	;; [4, 36) output buffer
+
+	(func (export "call")
+		(call $get_immutable_data (i32.const 4) (i32.const 0))
+		(call $seal_return (i32.const 0) (i32.const 4) (i32.load (i32.const 0)))
+	)
+	(func (export "deploy"))
+)
+"#;
+
+		let mut mock_ext = MockExt::default();
+		mock_ext.immutable_data = vec![1, 2, 3, 4];
+		let output = execute(CODE, vec![], &mut mock_ext).unwrap();
+		assert_eq!(output, ExecReturnValue { flags: ReturnFlags::empty(), data: vec![1, 2, 3, 4] });
+	}
+
+	#[test]
+	fn storage_deposit_consumed_and_limit_work() {
+		const CODE: &str = r#"
+(module
+	(import "seal0" "storage_deposit_consumed" (func $storage_deposit_consumed (param i32 i32)))
+	(import "seal0" "storage_deposit_limit" (func $storage_deposit_limit (param i32 i32)))
+	(import "env" "memory" (memory 1 1))
+
+	;; size of our buffers is 32 bytes
+	(data (i32.const 32) "\20")
+	(data (i32.const 96) "\20")
+
+	(func $assert (param i32)
+		(block $ok
+			(br_if $ok
+				(local.get 0)
+			)
+			(unreachable)
+		)
+	)
+
+	(func (export "call")
+		(call $storage_deposit_consumed (i32.const 0) (i32.const 32))
+
+		;; assert len == 9
+		(call $assert
+			(i32.eq
+				(i32.load (i32.const 32))
+				(i32.const 9)
+			)
+		)
+
+		;; assert that the buffer contains `StorageDeposit::Charge(42)`
+		(call $assert
+			(i32.eq
+				(i32.load8_u (i32.const 0))
+				(i32.const 1)
+			)
+		)
+		(call $assert
+			(i64.eq
+				(i64.load (i32.const 1))
+				(i64.const 42)
+			)
+		)
+
+		(call $storage_deposit_limit (i32.const 64) (i32.const 96))
+
+		;; assert len == 8
+		(call $assert
+			(i32.eq
+				(i32.load (i32.const 96))
+				(i32.const 8)
+			)
+		)
+
+		;; assert that the buffer contains the limit of 1000
+		(call $assert
+			(i64.eq
+				(i64.load (i32.const 64))
+				(i64.const 1000)
+			)
+		)
+	)
+	(func (export "deploy"))
+)
+"#;
+
+		assert_ok!(execute(CODE, vec![], MockExt::default()));
+	}
+
 	#[test]
 	fn instantiation_nonce_works() {
 		const CODE: &str = r#"
@@ -3433,6 +3724,40 @@ mod tests {
 		assert_eq!(delegate_dependencies[0].as_bytes(), [1; 32]);
 	}
 
+	#[test]
+	fn pin_unpin_code_hash() {
+		const CODE_PIN_UNPIN_CODE_HASH: &str = r#"
+(module
+	(import "seal0" "pin_code_hash" (func $pin_code_hash (param i32)))
+	(import "seal0" "unpin_code_hash" (func $unpin_code_hash (param i32)))
+	(import "env" "memory" (memory 1 1))
+	(func (export "call")
+		(call $pin_code_hash (i32.const 0))
+		(call $pin_code_hash (i32.const 32))
+		(call $unpin_code_hash (i32.const 0))
+	)
+	(func (export "deploy"))
+
+	;;  hash1 (32 bytes)
+	(data (i32.const 0)
+		"\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01"
+		"\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01"
+	)
+
+	;;  hash2 (32 bytes)
+	(data (i32.const 32)
+		"\02\02\02\02\02\02\02\02\02\02\02\02\02\02\02\02"
+		"\02\02\02\02\02\02\02\02\02\02\02\02\02\02\02\02"
+	)
+)
+"#;
+		let mut mock_ext = MockExt::default();
+		assert_ok!(execute(&CODE_PIN_UNPIN_CODE_HASH, vec![], &mut mock_ext));
+		let pinned: Vec<_> = mock_ext.pinned_code_hashes.into_inner().into_iter().collect();
+		assert_eq!(pinned.len(), 1);
+		assert_eq!(pinned[0].as_bytes(), [2; 32]);
+	}
+
 	// This test checks that [`Runtime::read_sandbox_memory_as`] works, when the decoded type has a
 	// max_len greater than the memory size, but the decoded data fits into the memory.
 	#[test]
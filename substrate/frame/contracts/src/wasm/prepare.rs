@@ -29,7 +29,10 @@ use crate::{
 	AccountIdOf, CodeVec, Config, Error, Schedule, LOG_TARGET,
 };
 use codec::MaxEncodedLen;
-use sp_runtime::{traits::Hash, DispatchError};
+use sp_runtime::{
+	traits::{Convert, Hash, Saturating},
+	DispatchError,
+};
 #[cfg(any(test, feature = "runtime-benchmarks"))]
 use sp_std::prelude::Vec;
 use wasmi::{
@@ -289,7 +292,8 @@ where
 	let bytes_added = code_len.saturating_add(<CodeInfo<T>>::max_encoded_len() as u32);
 	let deposit = Diff { bytes_added, items_added: 2, ..Default::default() }
 		.update_contract::<T>(None)
-		.charge_or_zero();
+		.charge_or_zero()
+		.saturating_add(T::CodeDepositCurve::convert(code_len));
 	let code_info = CodeInfo { owner, deposit, determinism, refcount: 0, code_len };
 	let code_hash = T::Hashing::hash(&code);
 
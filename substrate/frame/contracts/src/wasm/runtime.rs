@@ -18,7 +18,7 @@
 //! Environment definition of the wasm smart-contract runtime.
 
 use crate::{
-	exec::{ExecError, ExecResult, Ext, Key, TopicOf},
+	exec::{ErrorOrigin, ExecError, ExecResult, Ext, Key, TopicOf},
 	gas::{ChargedAmount, Token},
 	primitives::ExecReturnValue,
 	BalanceOf, CodeHash, Config, DebugBufferVec, Error, SENTINEL,
@@ -32,12 +32,13 @@ use frame_support::{
 	traits::Get,
 	weights::Weight,
 };
+use frame_system::pallet_prelude::BlockNumberFor;
 use pallet_contracts_proc_macro::define_env;
-use pallet_contracts_uapi::{CallFlags, ReturnFlags};
+use pallet_contracts_uapi::{CallFlags, CalleeFailure, ReturnFlags};
 use sp_io::hashing::{blake2_128, blake2_256, keccak_256, sha2_256};
 use sp_runtime::{
 	traits::{Bounded, Zero},
-	DispatchError, RuntimeDebug,
+	DispatchError, ModuleError, RuntimeDebug,
 };
 use sp_std::{fmt, prelude::*};
 use wasmi::{core::HostError, errors::LinkerError, Linker, Memory, Store};
@@ -190,8 +191,12 @@ pub enum RuntimeCosts {
 	Terminate,
 	/// Weight of calling `seal_random`. It includes the weight for copying the subject.
 	Random,
+	/// Weight of calling `randomness`. It includes the weight for copying the domain.
+	Randomness,
 	/// Weight of calling `seal_deposit_event` with the given number of topics and event size.
 	DepositEvent { num_topic: u32, len: u32 },
+	/// Weight of hashing and registering the given number of topics in `deposit_event_v2`.
+	RegisterEventTopics(u32),
 	/// Weight of calling `seal_debug_message` per byte of passed message.
 	DebugMessage(u32),
 	/// Weight of calling `seal_set_storage` for the given storage item sizes.
@@ -214,6 +219,8 @@ pub enum RuntimeCosts {
 	CallSurchargeTransfer,
 	/// Weight per byte that is cloned by supplying the `CLONE_INPUT` flag.
 	CallInputCloned(u32),
+	/// Weight of looking up the cached result of a `READ_ONLY` call with the given input size.
+	CallCacheLookup(u32),
 	/// Weight of calling `seal_instantiate` for the given input length and salt.
 	InstantiateBase { input_data_len: u32, salt_len: u32 },
 	/// Weight of the transfer performed during an instantiate.
@@ -236,6 +243,13 @@ pub enum RuntimeCosts {
 	CallRuntime(Weight),
 	/// Weight charged for calling xcm_execute.
 	CallXcmExecute(Weight),
+	/// Weight of calling `xcm_weigh_message` for a message with the given number of
+	/// instructions.
+	XcmWeighMessage(u32),
+	/// Weight of recording the owner of a query registered with `xcm_query`.
+	XcmQuery,
+	/// Weight of checking the owner of a query in `xcm_take_response`.
+	XcmTakeResponse,
 	/// Weight of calling `seal_set_code_hash`
 	SetCodeHash,
 	/// Weight of calling `ecdsa_to_eth_address`
@@ -244,12 +258,30 @@ pub enum RuntimeCosts {
 	ReentrantCount,
 	/// Weight of calling `account_reentrance_count`
 	AccountEntranceCount,
+	/// Weight of calling `set_reentrancy_guard`
+	SetReentrancyGuard,
+	/// Weight of calling `set_immutable_data`
+	SetImmutableData,
+	/// Weight of calling `get_immutable_data`
+	GetImmutableData,
+	/// Weight of calling `storage_deposit_consumed`
+	StorageDepositConsumed,
+	/// Weight of calling `storage_deposit_limit`
+	StorageDepositLimit,
 	/// Weight of calling `instantiation_nonce`
 	InstantationNonce,
 	/// Weight of calling `lock_delegate_dependency`
 	LockDelegateDependency,
 	/// Weight of calling `unlock_delegate_dependency`
 	UnlockDelegateDependency,
+	/// Weight of calling `pin_code_hash`
+	PinCodeHash,
+	/// Weight of calling `unpin_code_hash`
+	UnpinCodeHash,
+	/// Weight of calling `schedule_call`
+	ScheduleCall { input_data_len: u32 },
+	/// Weight of calling `cancel_scheduled_call`
+	CancelScheduledCall,
 }
 
 impl<T: Config> Token<T> for RuntimeCosts {
@@ -284,10 +316,15 @@ impl<T: Config> Token<T> for RuntimeCosts {
 			Return(len) => s.r#return.saturating_add(s.return_per_byte.saturating_mul(len.into())),
 			Terminate => s.terminate,
 			Random => s.random,
+			Randomness => s.randomness,
 			DepositEvent { num_topic, len } => s
 				.deposit_event
 				.saturating_add(s.deposit_event_per_topic.saturating_mul(num_topic.into()))
 				.saturating_add(s.deposit_event_per_byte.saturating_mul(len.into())),
+			RegisterEventTopics(num_topic) => s
+				.deposit_event_per_topic
+				.saturating_add(s.register_event_topic)
+				.saturating_mul(num_topic.into()),
 			DebugMessage(len) => s
 				.debug_message
 				.saturating_add(s.deposit_event_per_byte.saturating_mul(len.into())),
@@ -311,6 +348,9 @@ impl<T: Config> Token<T> for RuntimeCosts {
 			DelegateCallBase => s.delegate_call,
 			CallSurchargeTransfer => s.call_transfer_surcharge,
 			CallInputCloned(len) => s.call_per_cloned_byte.saturating_mul(len.into()),
+			CallCacheLookup(len) => s
+				.hash_blake2_256
+				.saturating_add(s.hash_blake2_256_per_byte.saturating_mul(len.into())),
 			InstantiateBase { input_data_len, salt_len } => s
 				.instantiate
 				.saturating_add(s.instantiate_per_input_byte.saturating_mul(input_data_len.into()))
@@ -337,9 +377,25 @@ impl<T: Config> Token<T> for RuntimeCosts {
 			EcdsaToEthAddress => s.ecdsa_to_eth_address,
 			ReentrantCount => s.reentrance_count,
 			AccountEntranceCount => s.account_reentrance_count,
+			SetReentrancyGuard => s.set_reentrancy_guard,
+			SetImmutableData => s.set_immutable_data,
+			GetImmutableData => s.get_immutable_data,
+			StorageDepositConsumed => s.storage_deposit_consumed,
+			StorageDepositLimit => s.storage_deposit_limit,
 			InstantationNonce => s.instantiation_nonce,
 			LockDelegateDependency => s.lock_delegate_dependency,
 			UnlockDelegateDependency => s.unlock_delegate_dependency,
+			PinCodeHash => s.pin_code_hash,
+			UnpinCodeHash => s.unpin_code_hash,
+			ScheduleCall { input_data_len } => s
+				.schedule_call
+				.saturating_add(s.schedule_call_per_byte.saturating_mul(input_data_len.into())),
+			CancelScheduledCall => s.cancel_scheduled_call,
+			XcmWeighMessage(instructions) => s.xcm_weigh_message.saturating_add(
+				s.xcm_weigh_message_per_instruction.saturating_mul(instructions.into()),
+			),
+			XcmQuery => s.xcm_query,
+			XcmTakeResponse => s.xcm_take_response,
 		}
 	}
 }
@@ -379,26 +435,70 @@ fn already_charged(_: u32) -> Option<RuntimeCosts> {
 }
 
 /// Ensure that the XCM program is executable, by checking that it does not contain any [`Transact`]
-/// instruction with a call that is not allowed by the CallFilter.
+/// instruction with a call that is not allowed by the CallFilter, nor any instruction sending a
+/// message to a destination not allowed by [`Config::XcmDestinations`].
 fn ensure_executable<T: Config>(message: &VersionedXcm<CallOf<T>>) -> DispatchResult {
-	use frame_support::traits::Contains;
-	use xcm::prelude::{Transact, Xcm};
+	use xcm::prelude::Xcm;
 
 	let mut message: Xcm<CallOf<T>> =
 		message.clone().try_into().map_err(|_| Error::<T>::XCMDecodeFailed)?;
 
-	message.iter_mut().try_for_each(|inst| -> DispatchResult {
-		let Transact { ref mut call, .. } = inst else { return Ok(()) };
-		let call = call.ensure_decoded().map_err(|_| Error::<T>::XCMDecodeFailed)?;
+	ensure_instructions_allowed::<T>(&mut message)
+}
 
-		if !<T as Config>::CallFilter::contains(call) {
-			return Err(frame_system::Error::<T>::CallFiltered.into())
-		}
+/// Check the instructions of `message` for [`ensure_executable`], including the instructions of
+/// the error handler and appendix, which are executed locally as well.
+fn ensure_instructions_allowed<T: Config>(
+	message: &mut xcm::latest::Xcm<CallOf<T>>,
+) -> DispatchResult {
+	use frame_support::traits::Contains;
+	use xcm::prelude::*;
 
+	message.iter_mut().try_for_each(|inst| -> DispatchResult {
+		let dest = match inst {
+			Transact { ref mut call, .. } => {
+				let call = call.ensure_decoded().map_err(|_| Error::<T>::XCMDecodeFailed)?;
+				if !<T as Config>::CallFilter::contains(call) {
+					return Err(frame_system::Error::<T>::CallFiltered.into())
+				}
+				return Ok(())
+			},
+			SetErrorHandler(xcm) | SetAppendix(xcm) => return ensure_instructions_allowed::<T>(xcm),
+			TransferReserveAsset { dest, .. } |
+			DepositReserveAsset { dest, .. } |
+			InitiateTeleport { dest, .. } |
+			InitiateReserveWithdraw { reserve: dest, .. } |
+			LockAsset { unlocker: dest, .. } |
+			RequestUnlock { locker: dest, .. } => &*dest,
+			ReportHolding { response_info, .. } |
+			QueryPallet { response_info, .. } |
+			ReportError(response_info) |
+			ReportTransactStatus(response_info) => &response_info.destination,
+			// The exported message leaves the consensus system, no destination is ever allowed.
+			ExportMessage { .. } => return Err(Error::<T>::XcmDestinationNotAllowed.into()),
+			_ => return Ok(()),
+		};
+
+		ensure!(
+			<T as Config>::XcmDestinations::contains(dest),
+			Error::<T>::XcmDestinationNotAllowed
+		);
 		Ok(())
-	})?;
+	})
+}
 
-	Ok(())
+/// The number of instructions of `message`, including the instructions of its error handler and
+/// appendix.
+fn xcm_instruction_count<Call>(message: &xcm::latest::Xcm<Call>) -> u32 {
+	use xcm::prelude::{SetAppendix, SetErrorHandler};
+
+	message.iter().fold(0u32, |count, inst| {
+		let nested = match inst {
+			SetErrorHandler(xcm) | SetAppendix(xcm) => xcm_instruction_count(xcm),
+			_ => 0,
+		};
+		count.saturating_add(1).saturating_add(nested)
+	})
 }
 
 /// Can only be used for one call.
@@ -768,6 +868,9 @@ impl<'a, E: Ext + 'a> Runtime<'a, E> {
 		if value_len > max_size {
 			return Err(Error::<E::T>::ValueTooLarge.into())
 		}
+		if value_len > <E::T as Config>::MaxStorageItemSize::get() {
+			return Err(Error::<E::T>::StorageItemTooLarge.into())
+		}
 		let key = self.decode_key(memory, key_type, key_ptr)?;
 		let value = Some(self.read_sandbox_memory(memory, value_ptr, value_len)?);
 		let write_outcome = self.ext.set_storage(&key, value, false)?;
@@ -845,6 +948,7 @@ impl<'a, E: Ext + 'a> Runtime<'a, E> {
 		input_data_len: u32,
 		output_ptr: u32,
 		output_len_ptr: u32,
+		report_failure: bool,
 	) -> Result<ReturnErrorCode, TrapReason> {
 		self.charge_gas(call_type.cost())?;
 		let input_data = if flags.contains(CallFlags::CLONE_INPUT) {
@@ -872,6 +976,13 @@ impl<'a, E: Ext + 'a> Runtime<'a, E> {
 				if value > 0u32.into() {
 					self.charge_gas(RuntimeCosts::CallSurchargeTransfer)?;
 				}
+				let read_only = flags.contains(CallFlags::READ_ONLY);
+				let use_cache = read_only &&
+					!flags.contains(CallFlags::NO_CACHE) &&
+					<E::T as Config>::CallCacheSize::get() > 0;
+				if use_cache {
+					self.charge_gas(RuntimeCosts::CallCacheLookup(input_data.len() as u32))?;
+				}
 				self.ext.call(
 					weight,
 					deposit_limit,
@@ -879,10 +990,12 @@ impl<'a, E: Ext + 'a> Runtime<'a, E> {
 					value,
 					input_data,
 					flags.contains(CallFlags::ALLOW_REENTRY),
+					read_only,
+					use_cache,
 				)
 			},
 			CallType::DelegateCall { code_hash_ptr } => {
-				if flags.contains(CallFlags::ALLOW_REENTRY) {
+				if flags.intersects(CallFlags::ALLOW_REENTRY | CallFlags::READ_ONLY) {
 					return Err(Error::<E::T>::InvalidCallFlags.into())
 				}
 				let code_hash = self.read_sandbox_memory_as(memory, code_hash_ptr)?;
@@ -901,19 +1014,46 @@ impl<'a, E: Ext + 'a> Runtime<'a, E> {
 			}
 		}
 
-		if let Ok(output) = &call_outcome {
-			self.write_sandbox_output(
-				memory,
-				output_ptr,
-				output_len_ptr,
-				&output.data,
-				true,
-				|len| Some(RuntimeCosts::CopyToContract(len)),
-			)?;
+		match &call_outcome {
+			Ok(output) => {
+				self.write_sandbox_output(
+					memory,
+					output_ptr,
+					output_len_ptr,
+					&output.data,
+					true,
+					|len| Some(RuntimeCosts::CopyToContract(len)),
+				)?;
+			},
+			Err(ExecError { error, origin: ErrorOrigin::Callee }) if report_failure => {
+				self.write_sandbox_output(
+					memory,
+					output_ptr,
+					output_len_ptr,
+					&Runtime::<E>::callee_failure(*error).encode(),
+					true,
+					|len| Some(RuntimeCosts::CopyToContract(len)),
+				)?;
+			},
+			Err(_) => (),
 		}
 		Ok(Runtime::<E>::exec_into_return_code(call_outcome)?)
 	}
 
+	/// Classifies the error a callee failed with for [`pallet_contracts_uapi::HostFn::call_v3`].
+	fn callee_failure(error: DispatchError) -> CalleeFailure {
+		let out_of_gas = Error::<E::T>::OutOfGas.into();
+		let trapped = Error::<E::T>::ContractTrapped.into();
+
+		match error {
+			x if x == out_of_gas => CalleeFailure::OutOfGas,
+			x if x == trapped => CalleeFailure::Trapped,
+			DispatchError::Module(ModuleError { index, error, .. }) =>
+				CalleeFailure::Module { index, error },
+			_ => CalleeFailure::Trapped,
+		}
+	}
+
 	fn instantiate(
 		&mut self,
 		memory: &mut [u8],
@@ -1178,6 +1318,7 @@ pub mod env {
 			input_data_len,
 			output_ptr,
 			output_len_ptr,
+			false,
 		)
 	}
 
@@ -1210,6 +1351,7 @@ pub mod env {
 			input_data_len,
 			output_ptr,
 			output_len_ptr,
+			false,
 		)
 	}
 
@@ -1243,6 +1385,41 @@ pub mod env {
 			input_data_len,
 			output_ptr,
 			output_len_ptr,
+			false,
+		)
+	}
+
+	/// Make a call to another contract, reporting why the callee failed.
+	/// See [`pallet_contracts_uapi::HostFn::call_v3`].
+	#[version(3)]
+	fn call(
+		ctx: _,
+		memory: _,
+		flags: u32,
+		callee_ptr: u32,
+		ref_time_limit: u64,
+		proof_size_limit: u64,
+		deposit_ptr: u32,
+		value_ptr: u32,
+		input_data_ptr: u32,
+		input_data_len: u32,
+		output_ptr: u32,
+		output_len_ptr: u32,
+	) -> Result<ReturnErrorCode, TrapReason> {
+		ctx.call(
+			memory,
+			CallFlags::from_bits(flags).ok_or(Error::<E::T>::InvalidCallFlags)?,
+			CallType::Call {
+				callee_ptr,
+				value_ptr,
+				deposit_ptr,
+				weight: Weight::from_parts(ref_time_limit, proof_size_limit),
+			},
+			input_data_ptr,
+			input_data_len,
+			output_ptr,
+			output_len_ptr,
+			true,
 		)
 	}
 
@@ -1267,6 +1444,7 @@ pub mod env {
 			input_data_len,
 			output_ptr,
 			output_len_ptr,
+			false,
 		)
 	}
 
@@ -1739,6 +1917,32 @@ pub mod env {
 		)?)
 	}
 
+	/// Stores randomness for the current contract and the given domain into the supplied buffer.
+	/// See [`pallet_contracts_uapi::HostFn::randomness`].
+	#[unstable]
+	fn randomness(
+		ctx: _,
+		memory: _,
+		domain_ptr: u32,
+		domain_len: u32,
+		out_ptr: u32,
+		out_len_ptr: u32,
+	) -> Result<(), TrapReason> {
+		ctx.charge_gas(RuntimeCosts::Randomness)?;
+		if domain_len > ctx.ext.schedule().limits.subject_len {
+			return Err(Error::<E::T>::RandomSubjectTooLong.into())
+		}
+		let domain = ctx.read_sandbox_memory(memory, domain_ptr, domain_len)?;
+		Ok(ctx.write_sandbox_output(
+			memory,
+			out_ptr,
+			out_len_ptr,
+			&ctx.ext.randomness(&domain).encode(),
+			false,
+			already_charged,
+		)?)
+	}
+
 	/// Load the latest block timestamp into the supplied buffer
 	/// See [`pallet_contracts_uapi::HostFn::now`].
 	#[prefixed_alias]
@@ -1918,6 +2122,7 @@ pub mod env {
 			.checked_div(sp_std::mem::size_of::<TopicOf<E::T>>() as u32)
 			.ok_or("Zero sized topics are not allowed")?;
 		ctx.charge_gas(RuntimeCosts::DepositEvent { num_topic, len: data_len })?;
+		ctx.ext.ensure_writable()?;
 		if data_len > ctx.ext.max_value_size() {
 			return Err(Error::<E::T>::ValueTooLarge.into())
 		}
@@ -1939,6 +2144,42 @@ pub mod env {
 		Ok(())
 	}
 
+	/// Deposit a contract event with the data buffer and a list of topics hashed by the runtime.
+	/// See [`pallet_contracts_uapi::HostFn::deposit_event_v2`].
+	#[unstable]
+	fn deposit_event_v2(
+		ctx: _,
+		memory: _,
+		topics_ptr: u32,
+		topics_len: u32,
+		data_ptr: u32,
+		data_len: u32,
+	) -> Result<(), TrapReason> {
+		ctx.charge_gas(RuntimeCosts::DepositEvent { num_topic: 0, len: data_len })?;
+		ctx.charge_gas(RuntimeCosts::HashBlake256(topics_len))?;
+		ctx.ext.ensure_writable()?;
+		if data_len > ctx.ext.max_value_size() {
+			return Err(Error::<E::T>::ValueTooLarge.into())
+		}
+
+		let topics: Vec<Vec<u8>> =
+			ctx.read_sandbox_memory_as_unbounded(memory, topics_ptr, topics_len)?;
+		if topics.len() > ctx.ext.schedule().limits.event_topics as usize {
+			return Err(Error::<E::T>::TooManyTopics.into())
+		}
+		ctx.charge_gas(RuntimeCosts::RegisterEventTopics(topics.len() as u32))?;
+		let topics = topics
+			.into_iter()
+			.map(|topic| ctx.ext.register_event_topic(topic))
+			.collect::<Result<Vec<_>, _>>()?;
+
+		let event_data = ctx.read_sandbox_memory(memory, data_ptr, data_len)?;
+
+		ctx.ext.deposit_event(topics, event_data);
+
+		Ok(())
+	}
+
 	/// Stores the current block number of the current contract into the supplied buffer.
 	/// See [`pallet_contracts_uapi::HostFn::block_number`].
 	#[prefixed_alias]
@@ -2034,6 +2275,7 @@ pub mod env {
 		if !<E::T as Config>::ChainExtension::enabled() {
 			return Err(Error::<E::T>::NoChainExtension.into())
 		}
+		ctx.ext.ensure_writable()?;
 		let mut chain_extension = ctx.chain_extension.take().expect(
 			"Constructor initializes with `Some`. This is the only place where it is set to `None`.\
 			It is always reset to `Some` afterwards. qed"
@@ -2094,6 +2336,7 @@ pub mod env {
 	) -> Result<ReturnErrorCode, TrapReason> {
 		use frame_support::dispatch::GetDispatchInfo;
 		ctx.charge_gas(RuntimeCosts::CopyFromContract(call_len))?;
+		ctx.ext.ensure_writable()?;
 		let call: <E::T as Config>::RuntimeCall =
 			ctx.read_sandbox_memory_as_unbounded(memory, call_ptr, call_len)?;
 		ctx.call_dispatchable::<CallRuntimeFailed>(
@@ -2117,6 +2360,7 @@ pub mod env {
 		use xcm_builder::{ExecuteController, ExecuteControllerWeightInfo};
 
 		ctx.charge_gas(RuntimeCosts::CopyFromContract(msg_len))?;
+		ctx.ext.ensure_writable()?;
 		let message: VersionedXcm<CallOf<E::T>> =
 			ctx.read_sandbox_memory_as_unbounded(memory, msg_ptr, msg_len)?;
 		ensure_executable::<E::T>(&message)?;
@@ -2153,11 +2397,22 @@ pub mod env {
 		msg_len: u32,
 		output_ptr: u32,
 	) -> Result<ReturnErrorCode, TrapReason> {
-		use xcm::{VersionedLocation, VersionedXcm};
+		use frame_support::traits::Contains;
+		use xcm::{latest::Location, VersionedLocation, VersionedXcm};
 		use xcm_builder::{SendController, SendControllerWeightInfo};
 
 		ctx.charge_gas(RuntimeCosts::CopyFromContract(msg_len))?;
+		ctx.ext.ensure_writable()?;
 		let dest: VersionedLocation = ctx.read_sandbox_memory_as(memory, dest_ptr)?;
+		let allowed = Location::try_from(dest.clone())
+			.map_or(false, |dest| <E::T as Config>::XcmDestinations::contains(&dest));
+		if !allowed {
+			if ctx.ext.append_debug_buffer("") {
+				ctx.ext
+					.append_debug_buffer("seal0::xcm_send failed with: destination not allowed");
+			}
+			return Ok(ReturnErrorCode::XcmSendFailed)
+		}
 
 		let message: VersionedXcm<()> =
 			ctx.read_sandbox_memory_as_unbounded(memory, msg_ptr, msg_len)?;
@@ -2180,6 +2435,122 @@ pub mod env {
 		}
 	}
 
+	/// Weigh an XCM program as it would be executed locally by `xcm_execute`.
+	/// See [`pallet_contracts_uapi::HostFn::xcm_weigh_message`].
+	#[unstable]
+	fn xcm_weigh_message(
+		ctx: _,
+		memory: _,
+		msg_ptr: u32,
+		msg_len: u32,
+		out_ptr: u32,
+		out_len_ptr: u32,
+	) -> Result<ReturnErrorCode, TrapReason> {
+		use xcm::{latest::Xcm, VersionedXcm};
+		use xcm_executor::traits::WeightBounds;
+
+		ctx.charge_gas(RuntimeCosts::CopyFromContract(msg_len))?;
+		let message: VersionedXcm<CallOf<E::T>> =
+			ctx.read_sandbox_memory_as_unbounded(memory, msg_ptr, msg_len)?;
+		let Ok(mut message) = Xcm::try_from(message) else {
+			return Ok(ReturnErrorCode::XcmWeighFailed)
+		};
+
+		ctx.charge_gas(RuntimeCosts::XcmWeighMessage(xcm_instruction_count(&message)))?;
+		let Ok(weight) = <E::T as Config>::XcmWeigher::weight(&mut message) else {
+			return Ok(ReturnErrorCode::XcmWeighFailed)
+		};
+
+		ctx.write_sandbox_output(
+			memory,
+			out_ptr,
+			out_len_ptr,
+			&weight.encode(),
+			false,
+			already_charged,
+		)?;
+		Ok(ReturnErrorCode::Success)
+	}
+
+	/// Register a query expecting a response to an XCM program sent by the contract.
+	/// See [`pallet_contracts_uapi::HostFn::xcm_query`].
+	#[unstable]
+	fn xcm_query(
+		ctx: _,
+		memory: _,
+		timeout_ptr: u32,
+		match_querier_ptr: u32,
+		output_ptr: u32,
+	) -> Result<ReturnErrorCode, TrapReason> {
+		use xcm::VersionedLocation;
+		use xcm_builder::{QueryController, QueryControllerWeightInfo};
+
+		ctx.ext.ensure_writable()?;
+		let timeout: BlockNumberFor<E::T> = ctx.read_sandbox_memory_as(memory, timeout_ptr)?;
+		let match_querier: VersionedLocation =
+			ctx.read_sandbox_memory_as(memory, match_querier_ptr)?;
+		let weight = <<E::T as Config>::Xcm as QueryController<_, _>>::WeightInfo::query();
+		ctx.charge_gas(RuntimeCosts::CallRuntime(weight))?;
+		ctx.charge_gas(RuntimeCosts::XcmQuery)?;
+		let origin = crate::RawOrigin::Signed(ctx.ext.address().clone()).into();
+
+		match <<E::T as Config>::Xcm>::query(origin, timeout, match_querier) {
+			Ok(query_id) => {
+				ctx.ext.register_xcm_query(query_id)?;
+				ctx.write_sandbox_memory(memory, output_ptr, &query_id.encode())?;
+				Ok(ReturnErrorCode::Success)
+			},
+			Err(e) => {
+				if ctx.ext.append_debug_buffer("") {
+					ctx.ext.append_debug_buffer("seal0::xcm_query failed with: ");
+					ctx.ext.append_debug_buffer(e.into());
+				};
+				Ok(ReturnErrorCode::XcmQueryFailed)
+			},
+		}
+	}
+
+	/// Take the response to a query registered with `xcm_query`.
+	/// See [`pallet_contracts_uapi::HostFn::xcm_take_response`].
+	#[unstable]
+	fn xcm_take_response(
+		ctx: _,
+		memory: _,
+		query_id_ptr: u32,
+		out_ptr: u32,
+		out_len_ptr: u32,
+	) -> Result<(), TrapReason> {
+		use xcm_builder::{QueryController, QueryControllerWeightInfo};
+		use xcm_executor::traits::{QueryHandler, QueryResponseStatus};
+
+		let weight = <<E::T as Config>::Xcm as QueryController<_, _>>::WeightInfo::take_response();
+		ctx.charge_gas(RuntimeCosts::CallRuntime(weight))?;
+		ctx.charge_gas(RuntimeCosts::XcmTakeResponse)?;
+		ctx.ext.ensure_writable()?;
+		let query_id: crate::XcmQueryIdOf<E::T> =
+			ctx.read_sandbox_memory_as(memory, query_id_ptr)?;
+
+		// Queries registered by other contracts or outside of contracts are not visible.
+		let status = if ctx.ext.is_xcm_query_owner(query_id) {
+			let status = <<E::T as Config>::Xcm>::take_response(query_id);
+			if !matches!(status, QueryResponseStatus::Pending { .. }) {
+				ctx.ext.forget_xcm_query(query_id)?;
+			}
+			status
+		} else {
+			QueryResponseStatus::NotFound
+		};
+
+		Ok(ctx.write_sandbox_output(
+			memory,
+			out_ptr,
+			out_len_ptr,
+			&status.encode(),
+			false,
+			already_charged,
+		)?)
+	}
+
 	/// Recovers the ECDSA public key from the given message hash and signature.
 	/// See [`pallet_contracts_uapi::HostFn::ecdsa_recover`].
 	#[prefixed_alias]
@@ -2296,6 +2667,89 @@ pub mod env {
 		Ok(ctx.ext.account_reentrance_count(&account_id))
 	}
 
+	/// Enables or disables the reentrancy guard of the current contract.
+	/// See [`pallet_contracts_uapi::HostFn::set_reentrancy_guard`].
+	#[unstable]
+	fn set_reentrancy_guard(ctx: _, _memory: _, enabled: u32) -> Result<(), TrapReason> {
+		ctx.charge_gas(RuntimeCosts::SetReentrancyGuard)?;
+		ctx.ext.ensure_writable()?;
+		ctx.ext.set_reentrancy_guard(enabled != 0);
+		Ok(())
+	}
+
+	/// Sets the immutable data of the contract being instantiated.
+	/// See [`pallet_contracts_uapi::HostFn::set_immutable_data`].
+	#[unstable]
+	fn set_immutable_data(
+		ctx: _,
+		memory: _,
+		data_ptr: u32,
+		data_len: u32,
+	) -> Result<(), TrapReason> {
+		ctx.charge_gas(RuntimeCosts::SetImmutableData)?;
+		if data_len > <E::T as Config>::MaxImmutableDataLen::get() {
+			return Err(Error::<E::T>::ImmutableDataTooLarge.into())
+		}
+		let data = ctx.read_sandbox_memory(memory, data_ptr, data_len)?;
+		ctx.ext.set_immutable_data(data)?;
+		Ok(())
+	}
+
+	/// Stores the immutable data of the current contract into the supplied buffer.
+	/// See [`pallet_contracts_uapi::HostFn::get_immutable_data`].
+	#[unstable]
+	fn get_immutable_data(
+		ctx: _,
+		memory: _,
+		out_ptr: u32,
+		out_len_ptr: u32,
+	) -> Result<(), TrapReason> {
+		ctx.charge_gas(RuntimeCosts::GetImmutableData)?;
+		let data = ctx.ext.immutable_data();
+		Ok(ctx.write_sandbox_output(memory, out_ptr, out_len_ptr, &data, false, already_charged)?)
+	}
+
+	/// Stores the net storage deposit consumed by the current call frame into the supplied buffer.
+	/// See [`pallet_contracts_uapi::HostFn::storage_deposit_consumed`].
+	#[unstable]
+	fn storage_deposit_consumed(
+		ctx: _,
+		memory: _,
+		out_ptr: u32,
+		out_len_ptr: u32,
+	) -> Result<(), TrapReason> {
+		ctx.charge_gas(RuntimeCosts::StorageDepositConsumed)?;
+		let consumed = ctx.ext.storage_deposit_consumed();
+		Ok(ctx.write_sandbox_output(
+			memory,
+			out_ptr,
+			out_len_ptr,
+			&consumed.encode(),
+			false,
+			already_charged,
+		)?)
+	}
+
+	/// Stores the storage deposit limit of the current call frame into the supplied buffer.
+	/// See [`pallet_contracts_uapi::HostFn::storage_deposit_limit`].
+	#[unstable]
+	fn storage_deposit_limit(
+		ctx: _,
+		memory: _,
+		out_ptr: u32,
+		out_len_ptr: u32,
+	) -> Result<(), TrapReason> {
+		ctx.charge_gas(RuntimeCosts::StorageDepositLimit)?;
+		Ok(ctx.write_sandbox_output(
+			memory,
+			out_ptr,
+			out_len_ptr,
+			&ctx.ext.storage_deposit_limit().encode(),
+			false,
+			already_charged,
+		)?)
+	}
+
 	/// Returns a nonce that is unique per contract instantiation.
 	/// See [`pallet_contracts_uapi::HostFn::instantiation_nonce`].
 	fn instantiation_nonce(ctx: _, _memory: _) -> Result<u64, TrapReason> {
@@ -2322,4 +2776,64 @@ pub mod env {
 		ctx.ext.unlock_delegate_dependency(&code_hash)?;
 		Ok(())
 	}
+
+	/// Pins a code hash, preventing its code from being removed.
+	/// See [`pallet_contracts_uapi::HostFn::pin_code_hash`].
+	#[unstable]
+	fn pin_code_hash(ctx: _, memory: _, code_hash_ptr: u32) -> Result<(), TrapReason> {
+		ctx.charge_gas(RuntimeCosts::PinCodeHash)?;
+		let code_hash = ctx.read_sandbox_memory_as(memory, code_hash_ptr)?;
+		ctx.ext.pin_code_hash(code_hash)?;
+		Ok(())
+	}
+
+	/// Unpins a code hash previously pinned by the contract.
+	/// See [`pallet_contracts_uapi::HostFn::unpin_code_hash`].
+	#[unstable]
+	fn unpin_code_hash(ctx: _, memory: _, code_hash_ptr: u32) -> Result<(), TrapReason> {
+		ctx.charge_gas(RuntimeCosts::UnpinCodeHash)?;
+		let code_hash = ctx.read_sandbox_memory_as(memory, code_hash_ptr)?;
+		ctx.ext.unpin_code_hash(&code_hash)?;
+		Ok(())
+	}
+
+	/// Schedules a call of the contract to itself at the beginning of a future block.
+	/// See [`pallet_contracts_uapi::HostFn::schedule_call`].
+	#[unstable]
+	fn schedule_call(
+		ctx: _,
+		memory: _,
+		when_ptr: u32,
+		input_data_ptr: u32,
+		input_data_len: u32,
+		ref_time_limit: u64,
+		proof_size_limit: u64,
+		deposit_ptr: u32,
+	) -> Result<u32, TrapReason> {
+		ctx.charge_gas(RuntimeCosts::ScheduleCall { input_data_len })?;
+		let gas_limit = Weight::from_parts(ref_time_limit, proof_size_limit);
+		let when: BlockNumberFor<<E as Ext>::T> = ctx.read_sandbox_memory_as(memory, when_ptr)?;
+		let deposit_limit: Option<BalanceOf<<E as Ext>::T>> = if deposit_ptr == SENTINEL {
+			None
+		} else {
+			Some(ctx.read_sandbox_memory_as(memory, deposit_ptr)?)
+		};
+		let input_data = ctx.read_sandbox_memory(memory, input_data_ptr, input_data_len)?;
+		Ok(ctx.ext.schedule_call(when, input_data, gas_limit, deposit_limit)?)
+	}
+
+	/// Cancels a call that was scheduled by the contract.
+	/// See [`pallet_contracts_uapi::HostFn::cancel_scheduled_call`].
+	#[unstable]
+	fn cancel_scheduled_call(
+		ctx: _,
+		memory: _,
+		when_ptr: u32,
+		index: u32,
+	) -> Result<(), TrapReason> {
+		ctx.charge_gas(RuntimeCosts::CancelScheduledCall)?;
+		let when: BlockNumberFor<<E as Ext>::T> = ctx.read_sandbox_memory_as(memory, when_ptr)?;
+		ctx.ext.cancel_scheduled_call(when, index)?;
+		Ok(())
+	}
 }
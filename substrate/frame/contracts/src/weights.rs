@@ -51,6 +51,9 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
 	fn on_process_deletion_queue_batch() -> Weight;
 	fn on_initialize_per_trie_key(k: u32, ) -> Weight;
+	fn on_initialize_per_scheduled_call(n: u32, ) -> Weight;
+	fn on_process_code_pruning_batch() -> Weight;
+	fn on_idle_per_pruned_code() -> Weight;
 	fn v9_migration_step(c: u32, ) -> Weight;
 	fn v10_migration_step() -> Weight;
 	fn v11_migration_step(k: u32, ) -> Weight;
@@ -58,6 +61,9 @@ pub trait WeightInfo {
 	fn v13_migration_step() -> Weight;
 	fn v14_migration_step() -> Weight;
 	fn v15_migration_step() -> Weight;
+	fn v16_migration_step() -> Weight;
+	fn v17_migration_step() -> Weight;
+	fn v18_migration_step() -> Weight;
 	fn migration_noop() -> Weight;
 	fn migrate() -> Weight;
 	fn on_runtime_upgrade_noop() -> Weight;
@@ -70,6 +76,9 @@ pub trait WeightInfo {
 	fn upload_code(c: u32, ) -> Weight;
 	fn remove_code() -> Weight;
 	fn set_code() -> Weight;
+	fn set_instantiation_permission() -> Weight;
+	fn approve_deposit_sponsorship() -> Weight;
+	fn deposit_sponsorship() -> Weight;
 	fn seal_caller(r: u32, ) -> Weight;
 	fn seal_is_contract(r: u32, ) -> Weight;
 	fn seal_code_hash(r: u32, ) -> Weight;
@@ -90,6 +99,7 @@ pub trait WeightInfo {
 	fn seal_return_per_byte(n: u32, ) -> Weight;
 	fn seal_terminate(r: u32, ) -> Weight;
 	fn seal_random(r: u32, ) -> Weight;
+	fn seal_randomness(r: u32, ) -> Weight;
 	fn seal_deposit_event(r: u32, ) -> Weight;
 	fn seal_deposit_event_per_topic_and_byte(t: u32, n: u32, ) -> Weight;
 	fn seal_debug_message(r: u32, ) -> Weight;
@@ -126,9 +136,24 @@ pub trait WeightInfo {
 	fn seal_set_code_hash(r: u32, ) -> Weight;
 	fn lock_delegate_dependency(r: u32, ) -> Weight;
 	fn unlock_delegate_dependency(r: u32, ) -> Weight;
+	fn pin_code_hash(r: u32, ) -> Weight;
+	fn unpin_code_hash(r: u32, ) -> Weight;
+	fn seal_schedule_call(r: u32, ) -> Weight;
+	fn seal_schedule_call_per_byte(n: u32, ) -> Weight;
+	fn seal_cancel_scheduled_call(r: u32, ) -> Weight;
 	fn seal_reentrance_count(r: u32, ) -> Weight;
 	fn seal_account_reentrance_count(r: u32, ) -> Weight;
+	fn seal_set_reentrancy_guard(r: u32, ) -> Weight;
+	fn seal_set_immutable_data(r: u32, ) -> Weight;
+	fn seal_get_immutable_data(r: u32, ) -> Weight;
+	fn seal_storage_deposit_consumed(r: u32, ) -> Weight;
+	fn seal_storage_deposit_limit(r: u32, ) -> Weight;
 	fn seal_instantiation_nonce(r: u32, ) -> Weight;
+	fn seal_xcm_weigh_message(r: u32, ) -> Weight;
+	fn seal_xcm_weigh_message_per_instruction(n: u32, ) -> Weight;
+	fn seal_xcm_query(r: u32, ) -> Weight;
+	fn seal_xcm_take_response(r: u32, ) -> Weight;
+	fn seal_register_event_topic(t: u32, ) -> Weight;
 	fn instr_i64_load_store(r: u32, ) -> Weight;
 }
 
@@ -162,6 +187,59 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(k.into())))
 			.saturating_add(Weight::from_parts(0, 70).saturating_mul(k.into()))
 	}
+	/// Storage: `Contracts::ScheduledCalls` (r:1 w:1)
+	/// Proof: `Contracts::ScheduledCalls` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
+	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:16 w:16)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `Measured`)
+	/// The range of component `n` is `[0, 16]`.
+	fn on_initialize_per_scheduled_call(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `149 + n * (190 ±0)`
+		//  Estimated: `3614 + n * (2560 ±0)`
+		// Minimum execution time: 3_012_000 picoseconds.
+		Weight::from_parts(3_287_415, 3614)
+			// Standard Error: 4_113
+			.saturating_add(Weight::from_parts(11_604_352, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2560).saturating_mul(n.into()))
+	}
+	/// Storage: `Contracts::CodePruningCursor` (r:1 w:1)
+	/// Proof: `Contracts::CodePruningCursor` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `Measured`)
+	/// Storage: `Contracts::CodeUploadedAt` (r:1 w:0)
+	/// Proof: `Contracts::CodeUploadedAt` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `Measured`)
+	fn on_process_code_pruning_batch() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `1561`
+		// Minimum execution time: 2_843_000 picoseconds.
+		Weight::from_parts(3_011_000, 1561)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Contracts::CodeUploadedAt` (r:1 w:1)
+	/// Proof: `Contracts::CodeUploadedAt` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `Measured`)
+	/// Storage: `Contracts::CodeInfoOf` (r:1 w:1)
+	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `Measured`)
+	/// Storage: `System::EventTopics` (r:1 w:1)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Contracts::PristineCode` (r:0 w:1)
+	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
+	fn on_idle_per_pruned_code() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `388`
+		//  Estimated: `3853`
+		// Minimum execution time: 41_208_000 picoseconds.
+		Weight::from_parts(42_671_000, 3853)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
 	/// Storage: UNKNOWN KEY `0x4342193e496fab7ec59d615ed0dc553022fca90611ba8b7942f8bdb3b97f6580` (r:2 w:1)
 	/// Proof: UNKNOWN KEY `0x4342193e496fab7ec59d615ed0dc553022fca90611ba8b7942f8bdb3b97f6580` (r:2 w:1)
 	/// The range of component `c` is `[0, 125952]`.
@@ -268,6 +346,39 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: `Contracts::ContractInfoOf` (r:2 w:1)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1795), added: 4270, mode: `Measured`)
+	fn v16_migration_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `409`
+		//  Estimated: `6349`
+		// Minimum execution time: 12_315_000 picoseconds.
+		Weight::from_parts(12_874_000, 6349)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Contracts::ContractInfoOf` (r:2 w:1)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1799), added: 4274, mode: `Measured`)
+	fn v17_migration_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `409`
+		//  Estimated: `6349`
+		// Minimum execution time: 12_315_000 picoseconds.
+		Weight::from_parts(12_874_000, 6349)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Contracts::ContractInfoOf` (r:2 w:1)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(5899), added: 8374, mode: `Measured`)
+	fn v18_migration_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `413`
+		//  Estimated: `6353`
+		// Minimum execution time: 12_402_000 picoseconds.
+		Weight::from_parts(12_951_000, 6353)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 	/// Storage: `Contracts::MigrationInProgress` (r:1 w:1)
 	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
 	fn migration_noop() -> Weight {
@@ -372,6 +483,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
 	/// Storage: `Contracts::PristineCode` (r:0 w:1)
 	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
+	/// Storage: `Contracts::CodeUploadedAt` (r:0 w:1)
+	/// Proof: `Contracts::CodeUploadedAt` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `Measured`)
 	/// The range of component `c` is `[0, 125952]`.
 	/// The range of component `i` is `[0, 1048576]`.
 	/// The range of component `s` is `[0, 1048576]`.
@@ -388,7 +501,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			// Standard Error: 18
 			.saturating_add(Weight::from_parts(1_544, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(11_u64))
-			.saturating_add(T::DbWeight::get().writes(10_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
 	}
 	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
 	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
@@ -456,6 +569,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Contracts::PristineCode` (r:0 w:1)
 	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
+	/// Storage: `Contracts::CodeUploadedAt` (r:0 w:1)
+	/// Proof: `Contracts::CodeUploadedAt` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `Measured`)
 	/// The range of component `c` is `[0, 125952]`.
 	fn upload_code(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -466,7 +581,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			// Standard Error: 62
 			.saturating_add(Weight::from_parts(66_212, 0).saturating_mul(c.into()))
 			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
 	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
@@ -504,6 +619,45 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(7_u64))
 			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
+	/// Storage: `Contracts::InstantiationPermissions` (r:0 w:1)
+	/// Proof: `Contracts::InstantiationPermissions` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `Measured`)
+	/// Storage: `System::EventTopics` (r:1 w:1)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_instantiation_permission() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `3507`
+		// Minimum execution time: 12_154_000 picoseconds.
+		Weight::from_parts(12_631_000, 3507)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Contracts::DepositAllowances` (r:0 w:1)
+	/// Proof: `Contracts::DepositAllowances` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `Measured`)
+	/// Storage: `System::EventTopics` (r:2 w:2)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn approve_deposit_sponsorship() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `6024`
+		// Minimum execution time: 14_861_000 picoseconds.
+		Weight::from_parts(15_390_000, 6024)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Contracts::DepositAllowances` (r:1 w:1)
+	/// Proof: `Contracts::DepositAllowances` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `Measured`)
+	/// Storage: `System::EventTopics` (r:2 w:2)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn deposit_sponsorship() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `189`
+		//  Estimated: `6129`
+		// Minimum execution time: 13_974_000 picoseconds.
+		Weight::from_parts(14_528_000, 6129)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
 	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
 	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
 	/// Storage: `System::Account` (r:1 w:0)
@@ -1064,6 +1218,37 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `Babe::EpochStart` (r:1 w:0)
+	/// Proof: `Babe::EpochStart` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `Babe::Randomness` (r:1 w:0)
+	/// Proof: `Babe::Randomness` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `Measured`)
+	/// Storage: `System::EventTopics` (r:2 w:2)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `r` is `[0, 1600]`.
+	fn seal_randomness(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `897 + r * (10 ±0)`
+		//  Estimated: `6838 + r * (10 ±0)`
+		// Minimum execution time: 248_500_000 picoseconds.
+		Weight::from_parts(284_017_926, 6838)
+			// Standard Error: 1_144
+			.saturating_add(Weight::from_parts(1_247_305, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 10).saturating_mul(r.into()))
+	}
+	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
+	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
+	/// Storage: `Contracts::ContractInfoOf` (r:1 w:1)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1795), added: 4270, mode: `Measured`)
+	/// Storage: `Contracts::CodeInfoOf` (r:1 w:0)
+	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `Measured`)
+	/// Storage: `Contracts::PristineCode` (r:1 w:0)
+	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
 	/// Storage: `System::EventTopics` (r:2 w:2)
 	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `r` is `[0, 1600]`.
@@ -1940,6 +2125,164 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
 	/// Storage: `Contracts::ContractInfoOf` (r:1 w:1)
 	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1795), added: 4270, mode: `Measured`)
+	/// Storage: `Contracts::PinnedCodeHashes` (r:1 w:1)
+	/// Proof: `Contracts::PinnedCodeHashes` (`max_values`: None, `max_size`: Some(1537), added: 4012, mode: `Measured`)
+	/// Storage: `Contracts::CodeInfoOf` (r:33 w:32)
+	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `Measured`)
+	/// Storage: `Contracts::PristineCode` (r:1 w:0)
+	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `System::EventTopics` (r:2 w:2)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `r` is `[0, 32]`.
+	fn pin_code_hash(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `928 + r * (131 ±0)`
+		//  Estimated: `6878 + r * (2606 ±0)`
+		// Minimum execution time: 246_455_000 picoseconds.
+		Weight::from_parts(275_334_919, 6878)
+			// Standard Error: 20_911
+			.saturating_add(Weight::from_parts(6_427_525, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2606).saturating_mul(r.into()))
+	}
+	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
+	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Contracts::ContractInfoOf` (r:1 w:1)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1795), added: 4270, mode: `MaxEncodedLen`)
+	/// Storage: `Contracts::PinnedCodeHashes` (r:1 w:1)
+	/// Proof: `Contracts::PinnedCodeHashes` (`max_values`: None, `max_size`: Some(1537), added: 4012, mode: `MaxEncodedLen`)
+	/// Storage: `Contracts::CodeInfoOf` (r:33 w:32)
+	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `Contracts::PristineCode` (r:1 w:0)
+	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `System::EventTopics` (r:2 w:2)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `r` is `[0, 32]`.
+	fn unpin_code_hash(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `969 + r * (183 ±0)`
+		//  Estimated: `129453 + r * (2568 ±0)`
+		// Minimum execution time: 254_472_000 picoseconds.
+		Weight::from_parts(280_657_909, 129453)
+			// Standard Error: 20_131
+			.saturating_add(Weight::from_parts(5_644_006, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2568).saturating_mul(r.into()))
+	}
+	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
+	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
+	/// Storage: `Contracts::ContractInfoOf` (r:1 w:1)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1795), added: 4270, mode: `Measured`)
+	/// Storage: `Contracts::CodeInfoOf` (r:1 w:0)
+	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `Measured`)
+	/// Storage: `Contracts::PristineCode` (r:1 w:0)
+	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `Contracts::ScheduledCalls` (r:1 w:1)
+	/// Proof: `Contracts::ScheduledCalls` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `Measured`)
+	/// Storage: `System::EventTopics` (r:2 w:2)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `r` is `[0, 1]`.
+	fn seal_schedule_call(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `926 + r * (56 ±0)`
+		//  Estimated: `6866 + r * (2531 ±0)`
+		// Minimum execution time: 247_052_000 picoseconds.
+		Weight::from_parts(265_420_312, 6866)
+			// Standard Error: 183_211
+			.saturating_add(Weight::from_parts(42_817_388, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2531).saturating_mul(r.into()))
+	}
+	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
+	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
+	/// Storage: `Contracts::ContractInfoOf` (r:1 w:1)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1795), added: 4270, mode: `Measured`)
+	/// Storage: `Contracts::CodeInfoOf` (r:1 w:0)
+	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `Measured`)
+	/// Storage: `Contracts::PristineCode` (r:1 w:0)
+	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `Contracts::ScheduledCalls` (r:1 w:1)
+	/// Proof: `Contracts::ScheduledCalls` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `Measured`)
+	/// Storage: `System::EventTopics` (r:2 w:2)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[0, 1024]`.
+	fn seal_schedule_call_per_byte(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `982`
+		//  Estimated: `9397`
+		// Minimum execution time: 291_377_000 picoseconds.
+		Weight::from_parts(309_962_544, 9397)
+			// Standard Error: 12
+			.saturating_add(Weight::from_parts(1_204, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
+	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
+	/// Storage: `Contracts::ContractInfoOf` (r:1 w:1)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1795), added: 4270, mode: `Measured`)
+	/// Storage: `Contracts::CodeInfoOf` (r:1 w:0)
+	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `Measured`)
+	/// Storage: `Contracts::PristineCode` (r:1 w:0)
+	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `Contracts::ScheduledCalls` (r:1 w:1)
+	/// Proof: `Contracts::ScheduledCalls` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `Measured`)
+	/// Storage: `System::EventTopics` (r:2 w:2)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `r` is `[0, 1]`.
+	fn seal_cancel_scheduled_call(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1060 + r * (112 ±0)`
+		//  Estimated: `6997 + r * (2587 ±0)`
+		// Minimum execution time: 250_668_000 picoseconds.
+		Weight::from_parts(269_129_580, 6997)
+			// Standard Error: 176_904
+			.saturating_add(Weight::from_parts(38_390_019, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2587).saturating_mul(r.into()))
+	}
+	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
+	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
+	/// Storage: `Contracts::ContractInfoOf` (r:1 w:1)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1795), added: 4270, mode: `Measured`)
 	/// Storage: `Contracts::CodeInfoOf` (r:1 w:0)
 	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `Measured`)
 	/// Storage: `Contracts::PristineCode` (r:1 w:0)
@@ -2000,56 +2343,341 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
-	/// Storage: `Contracts::Nonce` (r:1 w:1)
-	/// Proof: `Contracts::Nonce` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
 	/// Storage: `System::EventTopics` (r:2 w:2)
 	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `r` is `[0, 1600]`.
-	fn seal_instantiation_nonce(r: u32, ) -> Weight {
+	fn seal_set_reentrancy_guard(r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `861 + r * (3 ±0)`
 		//  Estimated: `6801 + r * (3 ±0)`
-		// Minimum execution time: 246_540_000 picoseconds.
-		Weight::from_parts(268_913_509, 6801)
-			// Standard Error: 378
-			.saturating_add(Weight::from_parts(154_950, 0).saturating_mul(r.into()))
-			.saturating_add(T::DbWeight::get().reads(9_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+		// Minimum execution time: 247_120_000 picoseconds.
+		Weight::from_parts(269_406_238, 6801)
+			// Standard Error: 391
+			.saturating_add(Weight::from_parts(139_511, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 			.saturating_add(Weight::from_parts(0, 3).saturating_mul(r.into()))
 	}
-	/// The range of component `r` is `[0, 5000]`.
-	fn instr_i64_load_store(r: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 1_777_000 picoseconds.
-		Weight::from_parts(1_707_601, 0)
-			// Standard Error: 14
-			.saturating_add(Weight::from_parts(15_392, 0).saturating_mul(r.into()))
-	}
-}
-
-// For backwards compatibility and tests.
-impl WeightInfo for () {
-	/// Storage: `Contracts::DeletionQueueCounter` (r:1 w:0)
-	/// Proof: `Contracts::DeletionQueueCounter` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
-	fn on_process_deletion_queue_batch() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `142`
-		//  Estimated: `1627`
-		// Minimum execution time: 1_997_000 picoseconds.
-		Weight::from_parts(2_130_000, 1627)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
+	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
+	/// Storage: `Contracts::CodeInfoOf` (r:1 w:1)
+	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `Measured`)
+	/// Storage: `Contracts::PristineCode` (r:1 w:0)
+	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
+	/// Storage: `Contracts::Nonce` (r:1 w:1)
+	/// Proof: `Contracts::Nonce` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `Contracts::ContractInfoOf` (r:1 w:1)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(5899), added: 8374, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
+	/// Storage: `System::EventTopics` (r:2 w:2)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `Measured`)
+	/// The range of component `r` is `[0, 1]`.
+	fn seal_set_immutable_data(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `712`
+		//  Estimated: `6652`
+		// Minimum execution time: 379_612_000 picoseconds.
+		Weight::from_parts(392_245_127, 6652)
+			// Standard Error: 41_287
+			.saturating_add(Weight::from_parts(9_874_572, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
-	/// Storage: `Skipped::Metadata` (r:0 w:0)
-	/// Proof: `Skipped::Metadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// The range of component `k` is `[0, 1024]`.
-	fn on_initialize_per_trie_key(k: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `452 + k * (69 ±0)`
-		//  Estimated: `442 + k * (70 ±0)`
-		// Minimum execution time: 12_276_000 picoseconds.
-		Weight::from_parts(1_593_881, 442)
+	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
+	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
+	/// Storage: `Contracts::ContractInfoOf` (r:1 w:1)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(5899), added: 8374, mode: `Measured`)
+	/// Storage: `Contracts::CodeInfoOf` (r:1 w:0)
+	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `Measured`)
+	/// Storage: `Contracts::PristineCode` (r:1 w:0)
+	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `System::EventTopics` (r:2 w:2)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `r` is `[0, 1600]`.
+	fn seal_get_immutable_data(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4957 + r * (3 ±0)`
+		//  Estimated: `10897 + r * (3 ±0)`
+		// Minimum execution time: 251_874_000 picoseconds.
+		Weight::from_parts(274_120_563, 10897)
+			// Standard Error: 1_204
+			.saturating_add(Weight::from_parts(1_318_742, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 3).saturating_mul(r.into()))
+	}
+	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
+	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
+	/// Storage: `Contracts::ContractInfoOf` (r:1 w:1)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1795), added: 4270, mode: `Measured`)
+	/// Storage: `Contracts::CodeInfoOf` (r:1 w:0)
+	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `Measured`)
+	/// Storage: `Contracts::PristineCode` (r:1 w:0)
+	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `System::EventTopics` (r:2 w:2)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `r` is `[0, 1600]`.
+	fn seal_storage_deposit_consumed(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `865 + r * (6 ±0)`
+		//  Estimated: `6805 + r * (6 ±0)`
+		// Minimum execution time: 248_016_000 picoseconds.
+		Weight::from_parts(270_148_302, 6805)
+			// Standard Error: 1_402
+			.saturating_add(Weight::from_parts(1_612_477, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 6).saturating_mul(r.into()))
+	}
+	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
+	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
+	/// Storage: `Contracts::ContractInfoOf` (r:1 w:1)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1795), added: 4270, mode: `Measured`)
+	/// Storage: `Contracts::CodeInfoOf` (r:1 w:0)
+	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `Measured`)
+	/// Storage: `Contracts::PristineCode` (r:1 w:0)
+	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `System::EventTopics` (r:2 w:2)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `r` is `[0, 1600]`.
+	fn seal_storage_deposit_limit(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `865 + r * (6 ±0)`
+		//  Estimated: `6805 + r * (6 ±0)`
+		// Minimum execution time: 248_016_000 picoseconds.
+		Weight::from_parts(268_931_577, 6805)
+			// Standard Error: 1_402
+			.saturating_add(Weight::from_parts(1_498_206, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 6).saturating_mul(r.into()))
+	}
+	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
+	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
+	/// Storage: `Contracts::ContractInfoOf` (r:1 w:1)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1795), added: 4270, mode: `Measured`)
+	/// Storage: `Contracts::CodeInfoOf` (r:1 w:0)
+	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `Measured`)
+	/// Storage: `Contracts::PristineCode` (r:1 w:0)
+	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `Contracts::Nonce` (r:1 w:1)
+	/// Proof: `Contracts::Nonce` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `System::EventTopics` (r:2 w:2)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `r` is `[0, 1600]`.
+	fn seal_instantiation_nonce(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `861 + r * (3 ±0)`
+		//  Estimated: `6801 + r * (3 ±0)`
+		// Minimum execution time: 246_540_000 picoseconds.
+		Weight::from_parts(268_913_509, 6801)
+			// Standard Error: 378
+			.saturating_add(Weight::from_parts(154_950, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(Weight::from_parts(0, 3).saturating_mul(r.into()))
+	}
+	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
+	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
+	/// Storage: `Contracts::ContractInfoOf` (r:1 w:1)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1795), added: 4270, mode: `Measured`)
+	/// Storage: `Contracts::CodeInfoOf` (r:1 w:0)
+	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `Measured`)
+	/// Storage: `Contracts::PristineCode` (r:1 w:0)
+	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `System::EventTopics` (r:2 w:2)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `r` is `[0, 1600]`.
+	fn seal_xcm_weigh_message(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `858 + r * (3 ±0)`
+		//  Estimated: `6798 + r * (3 ±0)`
+		// Minimum execution time: 247_119_000 picoseconds.
+		Weight::from_parts(271_540_318, 6798)
+			// Standard Error: 1_152
+			.saturating_add(Weight::from_parts(1_923_467, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 3).saturating_mul(r.into()))
+	}
+	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
+	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
+	/// Storage: `Contracts::ContractInfoOf` (r:1 w:1)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1795), added: 4270, mode: `Measured`)
+	/// Storage: `Contracts::CodeInfoOf` (r:1 w:0)
+	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `Measured`)
+	/// Storage: `Contracts::PristineCode` (r:1 w:0)
+	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `System::EventTopics` (r:2 w:2)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[0, 100]`.
+	fn seal_xcm_weigh_message_per_instruction(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `858`
+		//  Estimated: `6798`
+		// Minimum execution time: 249_802_000 picoseconds.
+		Weight::from_parts(272_118_604, 6798)
+			// Standard Error: 2_377
+			.saturating_add(Weight::from_parts(612_335, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
+	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
+	/// Storage: `Contracts::ContractInfoOf` (r:1 w:1)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1795), added: 4270, mode: `Measured`)
+	/// Storage: `Contracts::CodeInfoOf` (r:1 w:0)
+	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `Measured`)
+	/// Storage: `Contracts::PristineCode` (r:1 w:0)
+	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `System::EventTopics` (r:2 w:2)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Contracts::XcmQueryOwner` (r:0 w:1600)
+	/// Proof: `Contracts::XcmQueryOwner` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `Measured`)
+	/// The range of component `r` is `[0, 1600]`.
+	fn seal_xcm_query(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `858 + r * (3 ±0)`
+		//  Estimated: `6798 + r * (3 ±0)`
+		// Minimum execution time: 248_361_000 picoseconds.
+		Weight::from_parts(270_466_212, 6798)
+			// Standard Error: 2_904
+			.saturating_add(Weight::from_parts(6_894_117, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 3).saturating_mul(r.into()))
+	}
+	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
+	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
+	/// Storage: `Contracts::ContractInfoOf` (r:1 w:1)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1795), added: 4270, mode: `Measured`)
+	/// Storage: `Contracts::CodeInfoOf` (r:1 w:0)
+	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `Measured`)
+	/// Storage: `Contracts::PristineCode` (r:1 w:0)
+	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `System::EventTopics` (r:2 w:2)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Contracts::XcmQueryOwner` (r:1600 w:1600)
+	/// Proof: `Contracts::XcmQueryOwner` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `Measured`)
+	/// The range of component `r` is `[0, 1600]`.
+	fn seal_xcm_take_response(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `858 + r * (77 ±0)`
+		//  Estimated: `6798 + r * (2531 ±0)`
+		// Minimum execution time: 247_903_000 picoseconds.
+		Weight::from_parts(269_774_340, 6798)
+			// Standard Error: 4_118
+			.saturating_add(Weight::from_parts(9_231_650, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2531).saturating_mul(r.into()))
+	}
+	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
+	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
+	/// Storage: `Contracts::ContractInfoOf` (r:1 w:1)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1795), added: 4270, mode: `Measured`)
+	/// Storage: `Contracts::CodeInfoOf` (r:1 w:0)
+	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `Measured`)
+	/// Storage: `Contracts::PristineCode` (r:1 w:0)
+	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `Contracts::EventTopicRegistry` (r:4 w:4)
+	/// Proof: `Contracts::EventTopicRegistry` (`max_values`: None, `max_size`: Some(342), added: 2817, mode: `Measured`)
+	/// Storage: `Contracts::RegisteredTopics` (r:1 w:1)
+	/// Proof: `Contracts::RegisteredTopics` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `Measured`)
+	/// Storage: `System::EventTopics` (r:6 w:6)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `t` is `[0, 4]`.
+	fn seal_register_event_topic(t: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `880 + t * (32 ±0)`
+		//  Estimated: `6825 + t * (2817 ±0)`
+		// Minimum execution time: 259_730_000 picoseconds.
+		Weight::from_parts(278_401_915, 6825)
+			// Standard Error: 98_764
+			.saturating_add(Weight::from_parts(9_845_302, 0).saturating_mul(t.into()))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(t.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(t.into())))
+			.saturating_add(Weight::from_parts(0, 2817).saturating_mul(t.into()))
+	}
+	/// The range of component `r` is `[0, 5000]`.
+	fn instr_i64_load_store(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 1_777_000 picoseconds.
+		Weight::from_parts(1_707_601, 0)
+			// Standard Error: 14
+			.saturating_add(Weight::from_parts(15_392, 0).saturating_mul(r.into()))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `Contracts::DeletionQueueCounter` (r:1 w:0)
+	/// Proof: `Contracts::DeletionQueueCounter` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	fn on_process_deletion_queue_batch() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142`
+		//  Estimated: `1627`
+		// Minimum execution time: 1_997_000 picoseconds.
+		Weight::from_parts(2_130_000, 1627)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Skipped::Metadata` (r:0 w:0)
+	/// Proof: `Skipped::Metadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `k` is `[0, 1024]`.
+	fn on_initialize_per_trie_key(k: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `452 + k * (69 ±0)`
+		//  Estimated: `442 + k * (70 ±0)`
+		// Minimum execution time: 12_276_000 picoseconds.
+		Weight::from_parts(1_593_881, 442)
 			// Standard Error: 1_135
 			.saturating_add(Weight::from_parts(1_109_302, 0).saturating_mul(k.into()))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
@@ -2058,6 +2686,59 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(k.into())))
 			.saturating_add(Weight::from_parts(0, 70).saturating_mul(k.into()))
 	}
+	/// Storage: `Contracts::ScheduledCalls` (r:1 w:1)
+	/// Proof: `Contracts::ScheduledCalls` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
+	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:16 w:16)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `Measured`)
+	/// The range of component `n` is `[0, 16]`.
+	fn on_initialize_per_scheduled_call(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `149 + n * (190 ±0)`
+		//  Estimated: `3614 + n * (2560 ±0)`
+		// Minimum execution time: 3_012_000 picoseconds.
+		Weight::from_parts(3_287_415, 3614)
+			// Standard Error: 4_113
+			.saturating_add(Weight::from_parts(11_604_352, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2560).saturating_mul(n.into()))
+	}
+	/// Storage: `Contracts::CodePruningCursor` (r:1 w:1)
+	/// Proof: `Contracts::CodePruningCursor` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `Measured`)
+	/// Storage: `Contracts::CodeUploadedAt` (r:1 w:0)
+	/// Proof: `Contracts::CodeUploadedAt` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `Measured`)
+	fn on_process_code_pruning_batch() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `1561`
+		// Minimum execution time: 2_843_000 picoseconds.
+		Weight::from_parts(3_011_000, 1561)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Contracts::CodeUploadedAt` (r:1 w:1)
+	/// Proof: `Contracts::CodeUploadedAt` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `Measured`)
+	/// Storage: `Contracts::CodeInfoOf` (r:1 w:1)
+	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `Measured`)
+	/// Storage: `System::EventTopics` (r:1 w:1)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Contracts::PristineCode` (r:0 w:1)
+	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
+	fn on_idle_per_pruned_code() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `388`
+		//  Estimated: `3853`
+		// Minimum execution time: 41_208_000 picoseconds.
+		Weight::from_parts(42_671_000, 3853)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
 	/// Storage: UNKNOWN KEY `0x4342193e496fab7ec59d615ed0dc553022fca90611ba8b7942f8bdb3b97f6580` (r:2 w:1)
 	/// Proof: UNKNOWN KEY `0x4342193e496fab7ec59d615ed0dc553022fca90611ba8b7942f8bdb3b97f6580` (r:2 w:1)
 	/// The range of component `c` is `[0, 125952]`.
@@ -2164,20 +2845,53 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
-	/// Storage: `Contracts::MigrationInProgress` (r:1 w:1)
-	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
-	fn migration_noop() -> Weight {
+	/// Storage: `Contracts::ContractInfoOf` (r:2 w:1)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1795), added: 4270, mode: `Measured`)
+	fn v16_migration_step() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `142`
-		//  Estimated: `1627`
-		// Minimum execution time: 2_375_000 picoseconds.
-		Weight::from_parts(2_487_000, 1627)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
+		//  Measured:  `409`
+		//  Estimated: `6349`
+		// Minimum execution time: 12_315_000 picoseconds.
+		Weight::from_parts(12_874_000, 6349)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-	/// Storage: `Contracts::MigrationInProgress` (r:1 w:1)
-	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
-	/// Storage: UNKNOWN KEY `0x4342193e496fab7ec59d615ed0dc55304e7b9012096b41c4eb3aaf947f6ea429` (r:1 w:1)
+	/// Storage: `Contracts::ContractInfoOf` (r:2 w:1)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1799), added: 4274, mode: `Measured`)
+	fn v17_migration_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `409`
+		//  Estimated: `6349`
+		// Minimum execution time: 12_315_000 picoseconds.
+		Weight::from_parts(12_874_000, 6349)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Contracts::ContractInfoOf` (r:2 w:1)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(5899), added: 8374, mode: `Measured`)
+	fn v18_migration_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `413`
+		//  Estimated: `6353`
+		// Minimum execution time: 12_402_000 picoseconds.
+		Weight::from_parts(12_951_000, 6353)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Contracts::MigrationInProgress` (r:1 w:1)
+	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
+	fn migration_noop() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142`
+		//  Estimated: `1627`
+		// Minimum execution time: 2_375_000 picoseconds.
+		Weight::from_parts(2_487_000, 1627)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Contracts::MigrationInProgress` (r:1 w:1)
+	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
+	/// Storage: UNKNOWN KEY `0x4342193e496fab7ec59d615ed0dc55304e7b9012096b41c4eb3aaf947f6ea429` (r:1 w:1)
 	/// Proof: UNKNOWN KEY `0x4342193e496fab7ec59d615ed0dc55304e7b9012096b41c4eb3aaf947f6ea429` (r:1 w:1)
 	fn migrate() -> Weight {
 		// Proof Size summary in bytes:
@@ -2268,6 +2982,8 @@ impl WeightInfo for () {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
 	/// Storage: `Contracts::PristineCode` (r:0 w:1)
 	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
+	/// Storage: `Contracts::CodeUploadedAt` (r:0 w:1)
+	/// Proof: `Contracts::CodeUploadedAt` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `Measured`)
 	/// The range of component `c` is `[0, 125952]`.
 	/// The range of component `i` is `[0, 1048576]`.
 	/// The range of component `s` is `[0, 1048576]`.
@@ -2284,7 +3000,7 @@ impl WeightInfo for () {
 			// Standard Error: 18
 			.saturating_add(Weight::from_parts(1_544, 0).saturating_mul(s.into()))
 			.saturating_add(RocksDbWeight::get().reads(11_u64))
-			.saturating_add(RocksDbWeight::get().writes(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
 	}
 	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
 	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
@@ -2352,6 +3068,8 @@ impl WeightInfo for () {
 	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Contracts::PristineCode` (r:0 w:1)
 	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
+	/// Storage: `Contracts::CodeUploadedAt` (r:0 w:1)
+	/// Proof: `Contracts::CodeUploadedAt` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `Measured`)
 	/// The range of component `c` is `[0, 125952]`.
 	fn upload_code(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -2362,7 +3080,7 @@ impl WeightInfo for () {
 			// Standard Error: 62
 			.saturating_add(Weight::from_parts(66_212, 0).saturating_mul(c.into()))
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
 	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
@@ -2400,6 +3118,45 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(7_u64))
 			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
+	/// Storage: `Contracts::InstantiationPermissions` (r:0 w:1)
+	/// Proof: `Contracts::InstantiationPermissions` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `Measured`)
+	/// Storage: `System::EventTopics` (r:1 w:1)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_instantiation_permission() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `3507`
+		// Minimum execution time: 12_154_000 picoseconds.
+		Weight::from_parts(12_631_000, 3507)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Contracts::DepositAllowances` (r:0 w:1)
+	/// Proof: `Contracts::DepositAllowances` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `Measured`)
+	/// Storage: `System::EventTopics` (r:2 w:2)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn approve_deposit_sponsorship() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `6024`
+		// Minimum execution time: 14_861_000 picoseconds.
+		Weight::from_parts(15_390_000, 6024)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Contracts::DepositAllowances` (r:1 w:1)
+	/// Proof: `Contracts::DepositAllowances` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `Measured`)
+	/// Storage: `System::EventTopics` (r:2 w:2)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn deposit_sponsorship() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `189`
+		//  Estimated: `6129`
+		// Minimum execution time: 13_974_000 picoseconds.
+		Weight::from_parts(14_528_000, 6129)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
 	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
 	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
 	/// Storage: `System::Account` (r:1 w:0)
@@ -2960,6 +3717,37 @@ impl WeightInfo for () {
 	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `Babe::EpochStart` (r:1 w:0)
+	/// Proof: `Babe::EpochStart` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `Babe::Randomness` (r:1 w:0)
+	/// Proof: `Babe::Randomness` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `Measured`)
+	/// Storage: `System::EventTopics` (r:2 w:2)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `r` is `[0, 1600]`.
+	fn seal_randomness(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `897 + r * (10 ±0)`
+		//  Estimated: `6838 + r * (10 ±0)`
+		// Minimum execution time: 248_500_000 picoseconds.
+		Weight::from_parts(284_017_926, 6838)
+			// Standard Error: 1_144
+			.saturating_add(Weight::from_parts(1_247_305, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 10).saturating_mul(r.into()))
+	}
+	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
+	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
+	/// Storage: `Contracts::ContractInfoOf` (r:1 w:1)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1795), added: 4270, mode: `Measured`)
+	/// Storage: `Contracts::CodeInfoOf` (r:1 w:0)
+	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `Measured`)
+	/// Storage: `Contracts::PristineCode` (r:1 w:0)
+	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
 	/// Storage: `System::EventTopics` (r:2 w:2)
 	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `r` is `[0, 1600]`.
@@ -3490,18 +4278,470 @@ impl WeightInfo for () {
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
 	/// Storage: `System::EventTopics` (r:2 w:2)
 	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// The range of component `r` is `[0, 1600]`.
-	fn seal_hash_keccak_256(r: u32, ) -> Weight {
+	/// The range of component `r` is `[0, 1600]`.
+	fn seal_hash_keccak_256(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `864 + r * (8 ±0)`
+		//  Estimated: `6806 + r * (8 ±0)`
+		// Minimum execution time: 243_583_000 picoseconds.
+		Weight::from_parts(270_025_058, 6806)
+			// Standard Error: 560
+			.saturating_add(Weight::from_parts(767_519, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 8).saturating_mul(r.into()))
+	}
+	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
+	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
+	/// Storage: `Contracts::ContractInfoOf` (r:1 w:1)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1795), added: 4270, mode: `Measured`)
+	/// Storage: `Contracts::CodeInfoOf` (r:1 w:0)
+	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `Measured`)
+	/// Storage: `Contracts::PristineCode` (r:1 w:0)
+	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `System::EventTopics` (r:2 w:2)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[0, 1048576]`.
+	fn seal_hash_keccak_256_per_byte(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `872`
+		//  Estimated: `6814`
+		// Minimum execution time: 253_798_000 picoseconds.
+		Weight::from_parts(265_542_351, 6814)
+			// Standard Error: 0
+			.saturating_add(Weight::from_parts(3_343, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
+	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
+	/// Storage: `Contracts::ContractInfoOf` (r:1 w:1)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1795), added: 4270, mode: `Measured`)
+	/// Storage: `Contracts::CodeInfoOf` (r:1 w:0)
+	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `Measured`)
+	/// Storage: `Contracts::PristineCode` (r:1 w:0)
+	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `System::EventTopics` (r:2 w:2)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `r` is `[0, 1600]`.
+	fn seal_hash_blake2_256(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `864 + r * (8 ±0)`
+		//  Estimated: `6808 + r * (8 ±0)`
+		// Minimum execution time: 247_332_000 picoseconds.
+		Weight::from_parts(269_183_656, 6808)
+			// Standard Error: 665
+			.saturating_add(Weight::from_parts(443_386, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 8).saturating_mul(r.into()))
+	}
+	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
+	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
+	/// Storage: `Contracts::ContractInfoOf` (r:1 w:1)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1795), added: 4270, mode: `Measured`)
+	/// Storage: `Contracts::CodeInfoOf` (r:1 w:0)
+	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `Measured`)
+	/// Storage: `Contracts::PristineCode` (r:1 w:0)
+	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `System::EventTopics` (r:2 w:2)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[0, 1048576]`.
+	fn seal_hash_blake2_256_per_byte(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `872`
+		//  Estimated: `6813`
+		// Minimum execution time: 250_855_000 picoseconds.
+		Weight::from_parts(258_752_975, 6813)
+			// Standard Error: 1
+			.saturating_add(Weight::from_parts(1_202, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
+	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
+	/// Storage: `Contracts::ContractInfoOf` (r:1 w:1)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1795), added: 4270, mode: `Measured`)
+	/// Storage: `Contracts::CodeInfoOf` (r:1 w:0)
+	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `Measured`)
+	/// Storage: `Contracts::PristineCode` (r:1 w:0)
+	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `System::EventTopics` (r:2 w:2)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `r` is `[0, 1600]`.
+	fn seal_hash_blake2_128(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `864 + r * (8 ±0)`
+		//  Estimated: `6805 + r * (8 ±0)`
+		// Minimum execution time: 240_733_000 picoseconds.
+		Weight::from_parts(269_134_358, 6805)
+			// Standard Error: 512
+			.saturating_add(Weight::from_parts(440_043, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 8).saturating_mul(r.into()))
+	}
+	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
+	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
+	/// Storage: `Contracts::ContractInfoOf` (r:1 w:1)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1795), added: 4270, mode: `Measured`)
+	/// Storage: `Contracts::CodeInfoOf` (r:1 w:0)
+	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `Measured`)
+	/// Storage: `Contracts::PristineCode` (r:1 w:0)
+	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `System::EventTopics` (r:2 w:2)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[0, 1048576]`.
+	fn seal_hash_blake2_128_per_byte(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `872`
+		//  Estimated: `6811`
+		// Minimum execution time: 247_377_000 picoseconds.
+		Weight::from_parts(261_077_322, 6811)
+			// Standard Error: 0
+			.saturating_add(Weight::from_parts(1_195, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
+	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
+	/// Storage: `Contracts::ContractInfoOf` (r:1 w:1)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1795), added: 4270, mode: `Measured`)
+	/// Storage: `Contracts::CodeInfoOf` (r:1 w:0)
+	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `Measured`)
+	/// Storage: `Contracts::PristineCode` (r:1 w:0)
+	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `System::EventTopics` (r:2 w:2)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[0, 125697]`.
+	fn seal_sr25519_verify_per_byte(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `997 + n * (1 ±0)`
+		//  Estimated: `6934 + n * (1 ±0)`
+		// Minimum execution time: 307_337_000 picoseconds.
+		Weight::from_parts(326_710_473, 6934)
+			// Standard Error: 9
+			.saturating_add(Weight::from_parts(5_765, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 1).saturating_mul(n.into()))
+	}
+	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
+	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
+	/// Storage: `Contracts::ContractInfoOf` (r:1 w:1)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1795), added: 4270, mode: `Measured`)
+	/// Storage: `Contracts::CodeInfoOf` (r:1 w:0)
+	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `Measured`)
+	/// Storage: `Contracts::PristineCode` (r:1 w:0)
+	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `System::EventTopics` (r:2 w:2)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `r` is `[0, 160]`.
+	fn seal_sr25519_verify(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `805 + r * (112 ±0)`
+		//  Estimated: `6748 + r * (112 ±0)`
+		// Minimum execution time: 245_432_000 picoseconds.
+		Weight::from_parts(294_206_377, 6748)
+			// Standard Error: 7_229
+			.saturating_add(Weight::from_parts(41_480_485, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 112).saturating_mul(r.into()))
+	}
+	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
+	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
+	/// Storage: `Contracts::ContractInfoOf` (r:1 w:1)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1795), added: 4270, mode: `Measured`)
+	/// Storage: `Contracts::CodeInfoOf` (r:1 w:0)
+	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `Measured`)
+	/// Storage: `Contracts::PristineCode` (r:1 w:0)
+	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `System::EventTopics` (r:2 w:2)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `r` is `[0, 160]`.
+	fn seal_ecdsa_recover(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `907 + r * (76 ±0)`
+		//  Estimated: `6802 + r * (77 ±0)`
+		// Minimum execution time: 247_788_000 picoseconds.
+		Weight::from_parts(303_940_062, 6802)
+			// Standard Error: 10_671
+			.saturating_add(Weight::from_parts(45_730_772, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 77).saturating_mul(r.into()))
+	}
+	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
+	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
+	/// Storage: `Contracts::ContractInfoOf` (r:1 w:1)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1795), added: 4270, mode: `Measured`)
+	/// Storage: `Contracts::CodeInfoOf` (r:1 w:0)
+	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `Measured`)
+	/// Storage: `Contracts::PristineCode` (r:1 w:0)
+	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `System::EventTopics` (r:2 w:2)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `r` is `[0, 160]`.
+	fn seal_ecdsa_to_eth_address(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `877 + r * (42 ±0)`
+		//  Estimated: `6816 + r * (42 ±0)`
+		// Minimum execution time: 248_825_000 picoseconds.
+		Weight::from_parts(286_832_225, 6816)
+			// Standard Error: 5_274
+			.saturating_add(Weight::from_parts(11_889_262, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 42).saturating_mul(r.into()))
+	}
+	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
+	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
+	/// Storage: `Contracts::ContractInfoOf` (r:1 w:1)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1795), added: 4270, mode: `Measured`)
+	/// Storage: `Contracts::CodeInfoOf` (r:1536 w:1536)
+	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `Measured`)
+	/// Storage: `Contracts::PristineCode` (r:1536 w:0)
+	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `System::EventTopics` (r:1538 w:1538)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `r` is `[0, 1600]`.
+	fn seal_set_code_hash(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + r * (965 ±0)`
+		//  Estimated: `6807 + r * (3090 ±7)`
+		// Minimum execution time: 244_982_000 picoseconds.
+		Weight::from_parts(265_297_000, 6807)
+			// Standard Error: 39_895
+			.saturating_add(Weight::from_parts(22_435_888, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 3090).saturating_mul(r.into()))
+	}
+	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
+	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
+	/// Storage: `Contracts::ContractInfoOf` (r:1 w:1)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1795), added: 4270, mode: `Measured`)
+	/// Storage: `Contracts::CodeInfoOf` (r:33 w:32)
+	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `Measured`)
+	/// Storage: `Contracts::PristineCode` (r:1 w:0)
+	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `System::EventTopics` (r:2 w:2)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `r` is `[0, 32]`.
+	fn lock_delegate_dependency(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `928 + r * (131 ±0)`
+		//  Estimated: `6878 + r * (2606 ±0)`
+		// Minimum execution time: 246_455_000 picoseconds.
+		Weight::from_parts(275_334_919, 6878)
+			// Standard Error: 20_911
+			.saturating_add(Weight::from_parts(6_427_525, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2606).saturating_mul(r.into()))
+	}
+	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
+	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Contracts::ContractInfoOf` (r:1 w:1)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1795), added: 4270, mode: `MaxEncodedLen`)
+	/// Storage: `Contracts::CodeInfoOf` (r:33 w:32)
+	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `Contracts::PristineCode` (r:1 w:0)
+	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `System::EventTopics` (r:2 w:2)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `r` is `[0, 32]`.
+	fn unlock_delegate_dependency(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `969 + r * (183 ±0)`
+		//  Estimated: `129453 + r * (2568 ±0)`
+		// Minimum execution time: 254_472_000 picoseconds.
+		Weight::from_parts(280_657_909, 129453)
+			// Standard Error: 20_131
+			.saturating_add(Weight::from_parts(5_644_006, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2568).saturating_mul(r.into()))
+	}
+	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
+	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
+	/// Storage: `Contracts::ContractInfoOf` (r:1 w:1)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1795), added: 4270, mode: `Measured`)
+	/// Storage: `Contracts::PinnedCodeHashes` (r:1 w:1)
+	/// Proof: `Contracts::PinnedCodeHashes` (`max_values`: None, `max_size`: Some(1537), added: 4012, mode: `Measured`)
+	/// Storage: `Contracts::CodeInfoOf` (r:33 w:32)
+	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `Measured`)
+	/// Storage: `Contracts::PristineCode` (r:1 w:0)
+	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `System::EventTopics` (r:2 w:2)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `r` is `[0, 32]`.
+	fn pin_code_hash(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `928 + r * (131 ±0)`
+		//  Estimated: `6878 + r * (2606 ±0)`
+		// Minimum execution time: 246_455_000 picoseconds.
+		Weight::from_parts(275_334_919, 6878)
+			// Standard Error: 20_911
+			.saturating_add(Weight::from_parts(6_427_525, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2606).saturating_mul(r.into()))
+	}
+	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
+	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Contracts::ContractInfoOf` (r:1 w:1)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1795), added: 4270, mode: `MaxEncodedLen`)
+	/// Storage: `Contracts::PinnedCodeHashes` (r:1 w:1)
+	/// Proof: `Contracts::PinnedCodeHashes` (`max_values`: None, `max_size`: Some(1537), added: 4012, mode: `MaxEncodedLen`)
+	/// Storage: `Contracts::CodeInfoOf` (r:33 w:32)
+	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `Contracts::PristineCode` (r:1 w:0)
+	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `System::EventTopics` (r:2 w:2)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `r` is `[0, 32]`.
+	fn unpin_code_hash(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `969 + r * (183 ±0)`
+		//  Estimated: `129453 + r * (2568 ±0)`
+		// Minimum execution time: 254_472_000 picoseconds.
+		Weight::from_parts(280_657_909, 129453)
+			// Standard Error: 20_131
+			.saturating_add(Weight::from_parts(5_644_006, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2568).saturating_mul(r.into()))
+	}
+	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
+	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
+	/// Storage: `Contracts::ContractInfoOf` (r:1 w:1)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1795), added: 4270, mode: `Measured`)
+	/// Storage: `Contracts::CodeInfoOf` (r:1 w:0)
+	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `Measured`)
+	/// Storage: `Contracts::PristineCode` (r:1 w:0)
+	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `Contracts::ScheduledCalls` (r:1 w:1)
+	/// Proof: `Contracts::ScheduledCalls` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `Measured`)
+	/// Storage: `System::EventTopics` (r:2 w:2)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `r` is `[0, 1]`.
+	fn seal_schedule_call(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `926 + r * (56 ±0)`
+		//  Estimated: `6866 + r * (2531 ±0)`
+		// Minimum execution time: 247_052_000 picoseconds.
+		Weight::from_parts(265_420_312, 6866)
+			// Standard Error: 183_211
+			.saturating_add(Weight::from_parts(42_817_388, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2531).saturating_mul(r.into()))
+	}
+	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
+	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
+	/// Storage: `Contracts::ContractInfoOf` (r:1 w:1)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1795), added: 4270, mode: `Measured`)
+	/// Storage: `Contracts::CodeInfoOf` (r:1 w:0)
+	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `Measured`)
+	/// Storage: `Contracts::PristineCode` (r:1 w:0)
+	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `Contracts::ScheduledCalls` (r:1 w:1)
+	/// Proof: `Contracts::ScheduledCalls` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `Measured`)
+	/// Storage: `System::EventTopics` (r:2 w:2)
+	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[0, 1024]`.
+	fn seal_schedule_call_per_byte(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `864 + r * (8 ±0)`
-		//  Estimated: `6806 + r * (8 ±0)`
-		// Minimum execution time: 243_583_000 picoseconds.
-		Weight::from_parts(270_025_058, 6806)
-			// Standard Error: 560
-			.saturating_add(Weight::from_parts(767_519, 0).saturating_mul(r.into()))
-			.saturating_add(RocksDbWeight::get().reads(8_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
-			.saturating_add(Weight::from_parts(0, 8).saturating_mul(r.into()))
+		//  Measured:  `982`
+		//  Estimated: `9397`
+		// Minimum execution time: 291_377_000 picoseconds.
+		Weight::from_parts(309_962_544, 9397)
+			// Standard Error: 12
+			.saturating_add(Weight::from_parts(1_204, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
 	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
@@ -3515,19 +4755,26 @@ impl WeightInfo for () {
 	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `Contracts::ScheduledCalls` (r:1 w:1)
+	/// Proof: `Contracts::ScheduledCalls` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `Measured`)
 	/// Storage: `System::EventTopics` (r:2 w:2)
 	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// The range of component `n` is `[0, 1048576]`.
-	fn seal_hash_keccak_256_per_byte(n: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `872`
-		//  Estimated: `6814`
-		// Minimum execution time: 253_798_000 picoseconds.
-		Weight::from_parts(265_542_351, 6814)
-			// Standard Error: 0
-			.saturating_add(Weight::from_parts(3_343, 0).saturating_mul(n.into()))
+	/// The range of component `r` is `[0, 1]`.
+	fn seal_cancel_scheduled_call(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1060 + r * (112 ±0)`
+		//  Estimated: `6997 + r * (2587 ±0)`
+		// Minimum execution time: 250_668_000 picoseconds.
+		Weight::from_parts(269_129_580, 6997)
+			// Standard Error: 176_904
+			.saturating_add(Weight::from_parts(38_390_019, 0).saturating_mul(r.into()))
 			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(r.into())))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2587).saturating_mul(r.into()))
 	}
 	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
 	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
@@ -3544,17 +4791,17 @@ impl WeightInfo for () {
 	/// Storage: `System::EventTopics` (r:2 w:2)
 	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `r` is `[0, 1600]`.
-	fn seal_hash_blake2_256(r: u32, ) -> Weight {
+	fn seal_reentrance_count(r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `864 + r * (8 ±0)`
-		//  Estimated: `6808 + r * (8 ±0)`
-		// Minimum execution time: 247_332_000 picoseconds.
-		Weight::from_parts(269_183_656, 6808)
-			// Standard Error: 665
-			.saturating_add(Weight::from_parts(443_386, 0).saturating_mul(r.into()))
+		//  Measured:  `858 + r * (3 ±0)`
+		//  Estimated: `6804 + r * (3 ±0)`
+		// Minimum execution time: 250_535_000 picoseconds.
+		Weight::from_parts(270_318_376, 6804)
+			// Standard Error: 386
+			.saturating_add(Weight::from_parts(174_627, 0).saturating_mul(r.into()))
 			.saturating_add(RocksDbWeight::get().reads(8_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
-			.saturating_add(Weight::from_parts(0, 8).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(0, 3).saturating_mul(r.into()))
 	}
 	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
 	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
@@ -3570,17 +4817,18 @@ impl WeightInfo for () {
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
 	/// Storage: `System::EventTopics` (r:2 w:2)
 	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// The range of component `n` is `[0, 1048576]`.
-	fn seal_hash_blake2_256_per_byte(n: u32, ) -> Weight {
+	/// The range of component `r` is `[0, 1600]`.
+	fn seal_account_reentrance_count(r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `872`
-		//  Estimated: `6813`
-		// Minimum execution time: 250_855_000 picoseconds.
-		Weight::from_parts(258_752_975, 6813)
-			// Standard Error: 1
-			.saturating_add(Weight::from_parts(1_202, 0).saturating_mul(n.into()))
+		//  Measured:  `2109 + r * (39 ±0)`
+		//  Estimated: `7899 + r * (40 ±0)`
+		// Minimum execution time: 248_174_000 picoseconds.
+		Weight::from_parts(301_826_520, 7899)
+			// Standard Error: 801
+			.saturating_add(Weight::from_parts(248_479, 0).saturating_mul(r.into()))
 			.saturating_add(RocksDbWeight::get().reads(8_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 40).saturating_mul(r.into()))
 	}
 	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
 	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
@@ -3597,50 +4845,54 @@ impl WeightInfo for () {
 	/// Storage: `System::EventTopics` (r:2 w:2)
 	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `r` is `[0, 1600]`.
-	fn seal_hash_blake2_128(r: u32, ) -> Weight {
+	fn seal_set_reentrancy_guard(r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `864 + r * (8 ±0)`
-		//  Estimated: `6805 + r * (8 ±0)`
-		// Minimum execution time: 240_733_000 picoseconds.
-		Weight::from_parts(269_134_358, 6805)
-			// Standard Error: 512
-			.saturating_add(Weight::from_parts(440_043, 0).saturating_mul(r.into()))
+		//  Measured:  `861 + r * (3 ±0)`
+		//  Estimated: `6801 + r * (3 ±0)`
+		// Minimum execution time: 247_120_000 picoseconds.
+		Weight::from_parts(269_406_238, 6801)
+			// Standard Error: 391
+			.saturating_add(Weight::from_parts(139_511, 0).saturating_mul(r.into()))
 			.saturating_add(RocksDbWeight::get().reads(8_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
-			.saturating_add(Weight::from_parts(0, 8).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(0, 3).saturating_mul(r.into()))
 	}
 	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
 	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
-	/// Storage: `System::Account` (r:1 w:0)
-	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
-	/// Storage: `Contracts::ContractInfoOf` (r:1 w:1)
-	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1795), added: 4270, mode: `Measured`)
-	/// Storage: `Contracts::CodeInfoOf` (r:1 w:0)
+	/// Storage: `Contracts::CodeInfoOf` (r:1 w:1)
 	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `Measured`)
 	/// Storage: `Contracts::PristineCode` (r:1 w:0)
 	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
+	/// Storage: `Contracts::Nonce` (r:1 w:1)
+	/// Proof: `Contracts::Nonce` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `Contracts::ContractInfoOf` (r:1 w:1)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(5899), added: 8374, mode: `Measured`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
 	/// Storage: `System::EventTopics` (r:2 w:2)
 	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// The range of component `n` is `[0, 1048576]`.
-	fn seal_hash_blake2_128_per_byte(n: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `872`
-		//  Estimated: `6811`
-		// Minimum execution time: 247_377_000 picoseconds.
-		Weight::from_parts(261_077_322, 6811)
-			// Standard Error: 0
-			.saturating_add(Weight::from_parts(1_195, 0).saturating_mul(n.into()))
-			.saturating_add(RocksDbWeight::get().reads(8_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `Measured`)
+	/// The range of component `r` is `[0, 1]`.
+	fn seal_set_immutable_data(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `712`
+		//  Estimated: `6652`
+		// Minimum execution time: 379_612_000 picoseconds.
+		Weight::from_parts(392_245_127, 6652)
+			// Standard Error: 41_287
+			.saturating_add(Weight::from_parts(9_874_572, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
 	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
 	/// Storage: `System::Account` (r:1 w:0)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
 	/// Storage: `Contracts::ContractInfoOf` (r:1 w:1)
-	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1795), added: 4270, mode: `Measured`)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(5899), added: 8374, mode: `Measured`)
 	/// Storage: `Contracts::CodeInfoOf` (r:1 w:0)
 	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `Measured`)
 	/// Storage: `Contracts::PristineCode` (r:1 w:0)
@@ -3649,18 +4901,18 @@ impl WeightInfo for () {
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
 	/// Storage: `System::EventTopics` (r:2 w:2)
 	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// The range of component `n` is `[0, 125697]`.
-	fn seal_sr25519_verify_per_byte(n: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `997 + n * (1 ±0)`
-		//  Estimated: `6934 + n * (1 ±0)`
-		// Minimum execution time: 307_337_000 picoseconds.
-		Weight::from_parts(326_710_473, 6934)
-			// Standard Error: 9
-			.saturating_add(Weight::from_parts(5_765, 0).saturating_mul(n.into()))
+	/// The range of component `r` is `[0, 1600]`.
+	fn seal_get_immutable_data(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4957 + r * (3 ±0)`
+		//  Estimated: `10897 + r * (3 ±0)`
+		// Minimum execution time: 251_874_000 picoseconds.
+		Weight::from_parts(274_120_563, 10897)
+			// Standard Error: 1_204
+			.saturating_add(Weight::from_parts(1_318_742, 0).saturating_mul(r.into()))
 			.saturating_add(RocksDbWeight::get().reads(8_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
-			.saturating_add(Weight::from_parts(0, 1).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 3).saturating_mul(r.into()))
 	}
 	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
 	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
@@ -3676,18 +4928,18 @@ impl WeightInfo for () {
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
 	/// Storage: `System::EventTopics` (r:2 w:2)
 	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// The range of component `r` is `[0, 160]`.
-	fn seal_sr25519_verify(r: u32, ) -> Weight {
+	/// The range of component `r` is `[0, 1600]`.
+	fn seal_storage_deposit_consumed(r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `805 + r * (112 ±0)`
-		//  Estimated: `6748 + r * (112 ±0)`
-		// Minimum execution time: 245_432_000 picoseconds.
-		Weight::from_parts(294_206_377, 6748)
-			// Standard Error: 7_229
-			.saturating_add(Weight::from_parts(41_480_485, 0).saturating_mul(r.into()))
+		//  Measured:  `865 + r * (6 ±0)`
+		//  Estimated: `6805 + r * (6 ±0)`
+		// Minimum execution time: 248_016_000 picoseconds.
+		Weight::from_parts(270_148_302, 6805)
+			// Standard Error: 1_402
+			.saturating_add(Weight::from_parts(1_612_477, 0).saturating_mul(r.into()))
 			.saturating_add(RocksDbWeight::get().reads(8_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
-			.saturating_add(Weight::from_parts(0, 112).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(0, 6).saturating_mul(r.into()))
 	}
 	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
 	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
@@ -3703,18 +4955,18 @@ impl WeightInfo for () {
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
 	/// Storage: `System::EventTopics` (r:2 w:2)
 	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// The range of component `r` is `[0, 160]`.
-	fn seal_ecdsa_recover(r: u32, ) -> Weight {
+	/// The range of component `r` is `[0, 1600]`.
+	fn seal_storage_deposit_limit(r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `907 + r * (76 ±0)`
-		//  Estimated: `6802 + r * (77 ±0)`
-		// Minimum execution time: 247_788_000 picoseconds.
-		Weight::from_parts(303_940_062, 6802)
-			// Standard Error: 10_671
-			.saturating_add(Weight::from_parts(45_730_772, 0).saturating_mul(r.into()))
+		//  Measured:  `865 + r * (6 ±0)`
+		//  Estimated: `6805 + r * (6 ±0)`
+		// Minimum execution time: 248_016_000 picoseconds.
+		Weight::from_parts(268_931_577, 6805)
+			// Standard Error: 1_402
+			.saturating_add(Weight::from_parts(1_498_206, 0).saturating_mul(r.into()))
 			.saturating_add(RocksDbWeight::get().reads(8_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
-			.saturating_add(Weight::from_parts(0, 77).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(0, 6).saturating_mul(r.into()))
 	}
 	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
 	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
@@ -3728,20 +4980,22 @@ impl WeightInfo for () {
 	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
+	/// Storage: `Contracts::Nonce` (r:1 w:1)
+	/// Proof: `Contracts::Nonce` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
 	/// Storage: `System::EventTopics` (r:2 w:2)
 	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// The range of component `r` is `[0, 160]`.
-	fn seal_ecdsa_to_eth_address(r: u32, ) -> Weight {
+	/// The range of component `r` is `[0, 1600]`.
+	fn seal_instantiation_nonce(r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `877 + r * (42 ±0)`
-		//  Estimated: `6816 + r * (42 ±0)`
-		// Minimum execution time: 248_825_000 picoseconds.
-		Weight::from_parts(286_832_225, 6816)
-			// Standard Error: 5_274
-			.saturating_add(Weight::from_parts(11_889_262, 0).saturating_mul(r.into()))
-			.saturating_add(RocksDbWeight::get().reads(8_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
-			.saturating_add(Weight::from_parts(0, 42).saturating_mul(r.into()))
+		//  Measured:  `861 + r * (3 ±0)`
+		//  Estimated: `6801 + r * (3 ±0)`
+		// Minimum execution time: 246_540_000 picoseconds.
+		Weight::from_parts(268_913_509, 6801)
+			// Standard Error: 378
+			.saturating_add(Weight::from_parts(154_950, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(Weight::from_parts(0, 3).saturating_mul(r.into()))
 	}
 	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
 	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
@@ -3749,28 +5003,26 @@ impl WeightInfo for () {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
 	/// Storage: `Contracts::ContractInfoOf` (r:1 w:1)
 	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1795), added: 4270, mode: `Measured`)
-	/// Storage: `Contracts::CodeInfoOf` (r:1536 w:1536)
+	/// Storage: `Contracts::CodeInfoOf` (r:1 w:0)
 	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `Measured`)
-	/// Storage: `Contracts::PristineCode` (r:1536 w:0)
+	/// Storage: `Contracts::PristineCode` (r:1 w:0)
 	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
-	/// Storage: `System::EventTopics` (r:1538 w:1538)
+	/// Storage: `System::EventTopics` (r:2 w:2)
 	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `r` is `[0, 1600]`.
-	fn seal_set_code_hash(r: u32, ) -> Weight {
+	fn seal_xcm_weigh_message(r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0 + r * (965 ±0)`
-		//  Estimated: `6807 + r * (3090 ±7)`
-		// Minimum execution time: 244_982_000 picoseconds.
-		Weight::from_parts(265_297_000, 6807)
-			// Standard Error: 39_895
-			.saturating_add(Weight::from_parts(22_435_888, 0).saturating_mul(r.into()))
+		//  Measured:  `858 + r * (3 ±0)`
+		//  Estimated: `6798 + r * (3 ±0)`
+		// Minimum execution time: 247_119_000 picoseconds.
+		Weight::from_parts(271_540_318, 6798)
+			// Standard Error: 1_152
+			.saturating_add(Weight::from_parts(1_923_467, 0).saturating_mul(r.into()))
 			.saturating_add(RocksDbWeight::get().reads(8_u64))
-			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(r.into())))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
-			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(r.into())))
-			.saturating_add(Weight::from_parts(0, 3090).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(0, 3).saturating_mul(r.into()))
 	}
 	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
 	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
@@ -3778,7 +5030,7 @@ impl WeightInfo for () {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
 	/// Storage: `Contracts::ContractInfoOf` (r:1 w:1)
 	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1795), added: 4270, mode: `Measured`)
-	/// Storage: `Contracts::CodeInfoOf` (r:33 w:32)
+	/// Storage: `Contracts::CodeInfoOf` (r:1 w:0)
 	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `Measured`)
 	/// Storage: `Contracts::PristineCode` (r:1 w:0)
 	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
@@ -3786,49 +5038,17 @@ impl WeightInfo for () {
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
 	/// Storage: `System::EventTopics` (r:2 w:2)
 	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// The range of component `r` is `[0, 32]`.
-	fn lock_delegate_dependency(r: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `928 + r * (131 ±0)`
-		//  Estimated: `6878 + r * (2606 ±0)`
-		// Minimum execution time: 246_455_000 picoseconds.
-		Weight::from_parts(275_334_919, 6878)
-			// Standard Error: 20_911
-			.saturating_add(Weight::from_parts(6_427_525, 0).saturating_mul(r.into()))
-			.saturating_add(RocksDbWeight::get().reads(8_u64))
-			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(r.into())))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
-			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(r.into())))
-			.saturating_add(Weight::from_parts(0, 2606).saturating_mul(r.into()))
-	}
-	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
-	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `MaxEncodedLen`)
-	/// Storage: `System::Account` (r:1 w:0)
-	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	/// Storage: `Contracts::ContractInfoOf` (r:1 w:1)
-	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1795), added: 4270, mode: `MaxEncodedLen`)
-	/// Storage: `Contracts::CodeInfoOf` (r:33 w:32)
-	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
-	/// Storage: `Contracts::PristineCode` (r:1 w:0)
-	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `MaxEncodedLen`)
-	/// Storage: `Timestamp::Now` (r:1 w:0)
-	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
-	/// Storage: `System::EventTopics` (r:2 w:2)
-	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// The range of component `r` is `[0, 32]`.
-	fn unlock_delegate_dependency(r: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `969 + r * (183 ±0)`
-		//  Estimated: `129453 + r * (2568 ±0)`
-		// Minimum execution time: 254_472_000 picoseconds.
-		Weight::from_parts(280_657_909, 129453)
-			// Standard Error: 20_131
-			.saturating_add(Weight::from_parts(5_644_006, 0).saturating_mul(r.into()))
+	/// The range of component `n` is `[0, 100]`.
+	fn seal_xcm_weigh_message_per_instruction(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `858`
+		//  Estimated: `6798`
+		// Minimum execution time: 249_802_000 picoseconds.
+		Weight::from_parts(272_118_604, 6798)
+			// Standard Error: 2_377
+			.saturating_add(Weight::from_parts(612_335, 0).saturating_mul(n.into()))
 			.saturating_add(RocksDbWeight::get().reads(8_u64))
-			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(r.into())))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
-			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(r.into())))
-			.saturating_add(Weight::from_parts(0, 2568).saturating_mul(r.into()))
 	}
 	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
 	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
@@ -3844,17 +5064,20 @@ impl WeightInfo for () {
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
 	/// Storage: `System::EventTopics` (r:2 w:2)
 	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Contracts::XcmQueryOwner` (r:0 w:1600)
+	/// Proof: `Contracts::XcmQueryOwner` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `Measured`)
 	/// The range of component `r` is `[0, 1600]`.
-	fn seal_reentrance_count(r: u32, ) -> Weight {
+	fn seal_xcm_query(r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `858 + r * (3 ±0)`
-		//  Estimated: `6804 + r * (3 ±0)`
-		// Minimum execution time: 250_535_000 picoseconds.
-		Weight::from_parts(270_318_376, 6804)
-			// Standard Error: 386
-			.saturating_add(Weight::from_parts(174_627, 0).saturating_mul(r.into()))
+		//  Estimated: `6798 + r * (3 ±0)`
+		// Minimum execution time: 248_361_000 picoseconds.
+		Weight::from_parts(270_466_212, 6798)
+			// Standard Error: 2_904
+			.saturating_add(Weight::from_parts(6_894_117, 0).saturating_mul(r.into()))
 			.saturating_add(RocksDbWeight::get().reads(8_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(r.into())))
 			.saturating_add(Weight::from_parts(0, 3).saturating_mul(r.into()))
 	}
 	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
@@ -3871,18 +5094,22 @@ impl WeightInfo for () {
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
 	/// Storage: `System::EventTopics` (r:2 w:2)
 	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Contracts::XcmQueryOwner` (r:1600 w:1600)
+	/// Proof: `Contracts::XcmQueryOwner` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `Measured`)
 	/// The range of component `r` is `[0, 1600]`.
-	fn seal_account_reentrance_count(r: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2109 + r * (39 ±0)`
-		//  Estimated: `7899 + r * (40 ±0)`
-		// Minimum execution time: 248_174_000 picoseconds.
-		Weight::from_parts(301_826_520, 7899)
-			// Standard Error: 801
-			.saturating_add(Weight::from_parts(248_479, 0).saturating_mul(r.into()))
+	fn seal_xcm_take_response(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `858 + r * (77 ±0)`
+		//  Estimated: `6798 + r * (2531 ±0)`
+		// Minimum execution time: 247_903_000 picoseconds.
+		Weight::from_parts(269_774_340, 6798)
+			// Standard Error: 4_118
+			.saturating_add(Weight::from_parts(9_231_650, 0).saturating_mul(r.into()))
 			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(r.into())))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
-			.saturating_add(Weight::from_parts(0, 40).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2531).saturating_mul(r.into()))
 	}
 	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
 	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
@@ -3896,22 +5123,26 @@ impl WeightInfo for () {
 	/// Proof: `Contracts::PristineCode` (`max_values`: None, `max_size`: Some(125988), added: 128463, mode: `Measured`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
-	/// Storage: `Contracts::Nonce` (r:1 w:1)
-	/// Proof: `Contracts::Nonce` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `Measured`)
-	/// Storage: `System::EventTopics` (r:2 w:2)
+	/// Storage: `Contracts::EventTopicRegistry` (r:4 w:4)
+	/// Proof: `Contracts::EventTopicRegistry` (`max_values`: None, `max_size`: Some(342), added: 2817, mode: `Measured`)
+	/// Storage: `Contracts::RegisteredTopics` (r:1 w:1)
+	/// Proof: `Contracts::RegisteredTopics` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `Measured`)
+	/// Storage: `System::EventTopics` (r:6 w:6)
 	/// Proof: `System::EventTopics` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// The range of component `r` is `[0, 1600]`.
-	fn seal_instantiation_nonce(r: u32, ) -> Weight {
+	/// The range of component `t` is `[0, 4]`.
+	fn seal_register_event_topic(t: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `861 + r * (3 ±0)`
-		//  Estimated: `6801 + r * (3 ±0)`
-		// Minimum execution time: 246_540_000 picoseconds.
-		Weight::from_parts(268_913_509, 6801)
-			// Standard Error: 378
-			.saturating_add(Weight::from_parts(154_950, 0).saturating_mul(r.into()))
+		//  Measured:  `880 + t * (32 ±0)`
+		//  Estimated: `6825 + t * (2817 ±0)`
+		// Minimum execution time: 259_730_000 picoseconds.
+		Weight::from_parts(278_401_915, 6825)
+			// Standard Error: 98_764
+			.saturating_add(Weight::from_parts(9_845_302, 0).saturating_mul(t.into()))
 			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(t.into())))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
-			.saturating_add(Weight::from_parts(0, 3).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(t.into())))
+			.saturating_add(Weight::from_parts(0, 2817).saturating_mul(t.into()))
 	}
 	/// The range of component `r` is `[0, 5000]`.
 	fn instr_i64_load_store(r: u32, ) -> Weight {
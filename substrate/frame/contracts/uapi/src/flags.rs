@@ -69,5 +69,24 @@ bitflags! {
 		/// For `seal_delegate_call` should be always unset, otherwise
 		/// [`Error::InvalidCallFlags`] is returned.
 		const ALLOW_REENTRY = 0b0000_1000;
+		/// Execute the callee in read-only mode.
+		///
+		/// Any attempt of the callee (or any of its callees) to change state, like writing
+		/// storage, transferring balance or emitting events, fails with
+		/// [`Error::StateChangeDenied`]. No value can be transferred by such a call.
+		///
+		/// The results of read-only calls may be cached for the rest of the transaction, in
+		/// which case a repeated call with the same callee and input returns the cached result
+		/// without executing the callee. Only the lookup is charged.
+		///
+		/// # Note
+		///
+		/// For `seal_delegate_call` should be always unset, otherwise
+		/// [`Error::InvalidCallFlags`] is returned.
+		const READ_ONLY = 0b0001_0000;
+		/// Always execute a [`Self::READ_ONLY`] call, bypassing the cache of call results.
+		///
+		/// Without [`Self::READ_ONLY`] this flag has no effect.
+		const NO_CACHE = 0b0010_0000;
 	}
 }
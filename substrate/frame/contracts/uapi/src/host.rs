@@ -11,7 +11,7 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
-use crate::{CallFlags, Result, ReturnFlags};
+use crate::{CallError, CallFlags, Result, ReturnFlags};
 use paste::paste;
 
 #[cfg(target_arch = "wasm32")]
@@ -187,6 +187,29 @@ pub trait HostFn {
 		output: Option<&mut &mut [u8]>,
 	) -> Result;
 
+	/// Call (possibly transferring some amount of funds) into the specified account, reporting
+	/// why the callee failed.
+	///
+	/// Takes the same parameters as [`Self::call_v2`].
+	///
+	/// # Errors
+	///
+	/// - [Reverted][`crate::CallError::Reverted`]: The callee reverted. Output buffer is returned.
+	/// - [Failed][`crate::CallError::Failed`]: The callee trapped, ran out of gas or failed with a
+	///   module error. The [`CalleeFailure`][`crate::CalleeFailure`] is decoded from the output
+	///   buffer, which must hold at least 6 bytes.
+	/// - [Other][`crate::CallError::Other`]: Any other error [`Self::call_v2`] can return.
+	fn call_v3(
+		flags: CallFlags,
+		callee: &[u8],
+		ref_time_limit: u64,
+		proof_size_limit: u64,
+		deposit: Option<&[u8]>,
+		value: &[u8],
+		input_data: &[u8],
+		output: &mut &mut [u8],
+	) -> core::result::Result<(), CallError>;
+
 	/// Call into the chain extension provided by the chain if any.
 	///
 	/// Handling of the input values is up to the specific chain extension and so is the
@@ -382,6 +405,21 @@ pub trait HostFn {
 	/// - `topics`: The topics list encoded as `Vec<T::Hash>`. It can't contain duplicates.
 	fn deposit_event(topics: &[u8], data: &[u8]);
 
+	/// Deposit a contract event with the data buffer and a list of topics hashed by the runtime.
+	/// There is a limit on the maximum number of topics specified by `event_topics`.
+	///
+	/// The preimages of the topics are kept in a per-contract registry, so that event indexers can
+	/// resolve them. A storage deposit is charged for every topic added to the registry.
+	///
+	/// # Parameters
+	///
+	/// - `topics`: The topics list encoded as `Vec<Vec<u8>>`. Every topic is hashed by the runtime.
+	///   Traps if a topic is longer than the `MaxEventTopicLen` of the runtime.
+	#[deprecated(
+		note = "Unstable function. Behaviour can change without further notice. Use only for testing."
+	)]
+	fn deposit_event_v2(topics: &[u8], data: &[u8]);
+
 	/// Recovers the ECDSA public key from the given message hash and signature.
 	///
 	/// Writes the public key into the given output buffer.
@@ -576,6 +614,17 @@ pub trait HostFn {
 	/// - `output`: A reference to the output data buffer to write the timestamp.
 	fn now(output: &mut &mut [u8]);
 
+	/// Pin a code hash, preventing the code from being removed while the contract depends on it.
+	///
+	/// A fraction of the code deposit is held from the contract until the code hash is unpinned
+	/// or the contract terminates. Traps if the code does not exist, if the maximum number of
+	/// pinned code hashes is reached or if the code hash is already pinned.
+	///
+	/// # Parameters
+	///
+	/// - `code_hash`: The code hash to pin. Should be decodable as an `T::Hash`. Traps otherwise.
+	fn pin_code_hash(code_hash: &[u8]);
+
 	/// Returns the number of times the currently executing contract exists on the call stack in
 	/// addition to the calling instance.
 	///
@@ -587,6 +636,105 @@ pub trait HostFn {
 	)]
 	fn reentrance_count() -> u32;
 
+	/// Enable or disable the reentrancy guard of the current contract.
+	///
+	/// While the guard is enabled every call that would reenter the contract fails, regardless
+	/// of the [`CallFlags`] used by the caller. The guard is stored with the contract and can
+	/// only be changed by the contract itself, e.g. from its constructor.
+	///
+	/// # Parameters
+	///
+	/// - `enabled`: Whether reentrant calls into the contract should be rejected.
+	#[deprecated(
+		note = "Unstable function. Behaviour can change without further notice. Use only for testing."
+	)]
+	fn set_reentrancy_guard(enabled: bool);
+
+	/// Set the immutable data of the contract.
+	///
+	/// The immutable data is stored with the contract and can be read cheaply with
+	/// [`Self::get_immutable_data`]. Its storage deposit is paid once, as part of the base deposit
+	/// of the contract.
+	///
+	/// Traps if not called by the constructor of the contract, if the immutable data was already
+	/// set, or if `data` is larger than the `MaxImmutableDataLen` of the chain.
+	///
+	/// # Parameters
+	///
+	/// - `data`: The immutable data of the contract.
+	#[deprecated(
+		note = "Unstable function. Behaviour can change without further notice. Use only for testing."
+	)]
+	fn set_immutable_data(data: &[u8]);
+
+	/// Stores the immutable data of the current contract into the supplied buffer.
+	///
+	/// The data is empty if the constructor of the contract did not set any. In a delegate call
+	/// this is the immutable data of the contract executing the call, not of the one the code was
+	/// taken from.
+	///
+	/// If the available space in `output` is less than the size of the value a trap is triggered.
+	///
+	/// # Parameters
+	///
+	/// - `output`: A reference to the output data buffer to write the immutable data.
+	#[deprecated(
+		note = "Unstable function. Behaviour can change without further notice. Use only for testing."
+	)]
+	fn get_immutable_data(output: &mut &mut [u8]);
+
+	/// Stores randomness for the current contract and the given domain into the supplied buffer.
+	///
+	/// The value is a SCALE encoded `RandomnessOutput { seed: Hash, known_since: BlockNumber }`.
+	/// The seed is derived from the runtime's randomness source, the address of the contract and
+	/// `domain`, so different contracts never get the same seed. `known_since` is the block
+	/// since which the seed was determinable by chain observers. The seed should only be used to
+	/// decide on commitments made before that block.
+	///
+	/// If the available space in `output` is less than the size of the value a trap is triggered.
+	///
+	/// # Parameters
+	///
+	/// - `domain`: A short domain separator chosen by the contract, e.g. `b"lottery"`. Traps if it
+	///   is longer than the `subject_len` limit of the schedule, 32 bytes by default.
+	/// - `output`: A reference to the output data buffer to write the randomness.
+	#[deprecated(
+		note = "Unstable function. Behaviour can change without further notice. Use only for testing."
+	)]
+	fn randomness(domain: &[u8], output: &mut &mut [u8]);
+
+	/// Stores the net storage deposit consumed by the current call frame into the supplied
+	/// buffer.
+	///
+	/// The value is a SCALE encoded `StorageDeposit<Balance>`, charging or refunding the amount
+	/// of the storage changes made so far by this call, including the calls it made that already
+	/// returned. Together with [`Self::storage_deposit_limit`] this allows a contract to fail
+	/// gracefully before exceeding the deposit limit.
+	///
+	/// If the available space in `output` is less than the size of the value a trap is triggered.
+	///
+	/// # Parameters
+	///
+	/// - `output`: A reference to the output data buffer to write the deposit.
+	#[deprecated(
+		note = "Unstable function. Behaviour can change without further notice. Use only for testing."
+	)]
+	fn storage_deposit_consumed(output: &mut &mut [u8]);
+
+	/// Stores the storage deposit limit of the current call frame into the supplied buffer.
+	///
+	/// The call fails once [`Self::storage_deposit_consumed`] charges more than this limit.
+	///
+	/// If the available space in `output` is less than the size of the value a trap is triggered.
+	///
+	/// # Parameters
+	///
+	/// - `output`: A reference to the output data buffer to write the limit.
+	#[deprecated(
+		note = "Unstable function. Behaviour can change without further notice. Use only for testing."
+	)]
+	fn storage_deposit_limit(output: &mut &mut [u8]);
+
 	/// Removes the delegate dependency from the contract.
 	///
 	/// Traps if the delegate dependency does not exist.
@@ -597,6 +745,58 @@ pub trait HostFn {
 	///   otherwise.
 	fn unlock_delegate_dependency(code_hash: &[u8]);
 
+	/// Unpin a code hash previously pinned with [`Self::pin_code_hash`] and release its deposit.
+	///
+	/// Traps if the code hash is not pinned by the contract.
+	///
+	/// # Parameters
+	///
+	/// - `code_hash`: The code hash to unpin. Should be decodable as an `T::Hash`. Traps otherwise.
+	fn unpin_code_hash(code_hash: &[u8]);
+
+	/// Schedule a call of the contract to itself at the beginning of a future block.
+	///
+	/// The storage deposit for the call is charged like any other storage of the contract. The
+	/// fee for the gas limit of the call is held from the contract's balance until the call is
+	/// executed or cancelled. The fee for the gas the call actually consumes is burned when it is
+	/// executed, the rest is released.
+	///
+	/// Traps if `when` is not a future block, if the per block limits of scheduled calls are
+	/// exceeded or if the contract can not pay the fee.
+	///
+	/// # Parameters
+	///
+	/// - `when`: The block number in which to execute the call. Should be decodable as a
+	///   `BlockNumberFor<T>`. Traps otherwise.
+	/// - `input_data`: The input data of the call.
+	/// - `ref_time_limit`: How much *ref_time* Weight the scheduled call may consume.
+	/// - `proof_size_limit`: How much *proof_size* Weight the scheduled call may consume.
+	/// - `deposit`: The storage deposit limit of the scheduled call. Should be decodable as a
+	///   `Option<T::Balance>`. Traps otherwise. Passing `None` means setting no specific limit for
+	///   the call.
+	///
+	/// # Return
+	///
+	/// The index of the call which is needed to cancel it with [`Self::cancel_scheduled_call`].
+	fn schedule_call(
+		when: &[u8],
+		input_data: &[u8],
+		ref_time_limit: u64,
+		proof_size_limit: u64,
+		deposit: Option<&[u8]>,
+	) -> u32;
+
+	/// Cancel a call that was scheduled by the contract, refund its deposit and release its fee.
+	///
+	/// Traps if the call does not exist or was not scheduled by the contract.
+	///
+	/// # Parameters
+	///
+	/// - `when`: The block number the call was scheduled for. Should be decodable as a
+	///   `BlockNumberFor<T>`. Traps otherwise.
+	/// - `index`: The index returned by [`Self::schedule_call`].
+	fn cancel_scheduled_call(when: &[u8], index: u32);
+
 	/// Cease contract execution and save a data buffer as a result of the execution.
 	///
 	/// This function never returns as it stops execution of the caller.
@@ -772,7 +972,8 @@ pub trait HostFn {
 	/// # Parameters
 	///
 	/// - `msg`: The message, should be decodable as a [VersionedXcm](https://paritytech.github.io/polkadot-sdk/master/staging_xcm/enum.VersionedXcm.html),
-	///   traps otherwise.
+	///   traps otherwise. Also traps if the message sends a message to a destination that the chain
+	///   doesn't allow contracts to send to.
 	/// - `output`: A reference to the output data buffer to write the [Outcome](https://paritytech.github.io/polkadot-sdk/master/staging_xcm/v3/enum.Outcome.html)
 	///
 	/// # Return
@@ -803,4 +1004,56 @@ pub trait HostFn {
 		note = "Unstable function. Behaviour can change without further notice. Use only for testing."
 	)]
 	fn xcm_send(dest: &[u8], msg: &[u8], output: &mut [u8; 32]) -> Result;
+
+	/// Weigh an XCM program as it would be executed locally by [`HostFn::xcm_execute`].
+	///
+	/// # Parameters
+	///
+	/// - `msg`: The message, should be decodable as a [VersionedXcm](https://paritytech.github.io/polkadot-sdk/master/staging_xcm/enum.VersionedXcm.html),
+	///   traps otherwise.
+	/// - `output`: A reference to the output data buffer to write the SCALE encoded weight.
+	///
+	/// # Return
+	///
+	/// Returns `ReturnCode::Success` when the message was weighed. When the runtime can't weigh
+	/// the message, `ReturnErrorCode::XcmWeighFailed` is returned.
+	#[deprecated(
+		note = "Unstable function. Behaviour can change without further notice. Use only for testing."
+	)]
+	fn xcm_weigh_message(msg: &[u8], output: &mut &mut [u8]) -> Result;
+
+	/// Register a query expecting a response, with the contract's address as the responder.
+	///
+	/// A query id is put into the `QueryResponse` instruction of a program sent with
+	/// [`HostFn::xcm_send`], the response is then taken with [`HostFn::xcm_take_response`].
+	///
+	/// # Parameters
+	///
+	/// - `timeout`: The SCALE encoded block number after which the query expires.
+	/// - `match_querier`: The querier the response must match, should be decodable as [VersionedLocation](https://paritytech.github.io/polkadot-sdk/master/staging_xcm/enum.VersionedLocation.html),
+	///   traps otherwise.
+	/// - `output`: A reference to the output data buffer to write the query id.
+	///
+	/// # Return
+	///
+	/// Returns `ReturnCode::Success` when the query was registered. Otherwise
+	/// `ReturnErrorCode::XcmQueryFailed` is returned.
+	#[deprecated(
+		note = "Unstable function. Behaviour can change without further notice. Use only for testing."
+	)]
+	fn xcm_query(timeout: &[u8], match_querier: &[u8], output: &mut [u8; 8]) -> Result;
+
+	/// Take the response to a query registered with [`HostFn::xcm_query`].
+	///
+	/// Only the contract that registered the query can take its response, the status of queries
+	/// registered by anyone else is reported as `NotFound`.
+	///
+	/// # Parameters
+	///
+	/// - `query_id`: The query id returned by [`HostFn::xcm_query`].
+	/// - `output`: A reference to the output data buffer to write the SCALE encoded [QueryResponseStatus](https://paritytech.github.io/polkadot-sdk/master/staging_xcm_executor/traits/enum.QueryResponseStatus.html).
+	#[deprecated(
+		note = "Unstable function. Behaviour can change without further notice. Use only for testing."
+	)]
+	fn xcm_take_response(query_id: &[u8; 8], output: &mut &mut [u8]);
 }
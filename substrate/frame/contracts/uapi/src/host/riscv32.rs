@@ -15,7 +15,7 @@
 // TODO: bring up to date with wasm32.rs
 
 use super::{CallFlags, HostFn, HostFnImpl, Result};
-use crate::ReturnFlags;
+use crate::{CallError, ReturnFlags};
 
 /// A macro to implement all Host functions with a signature of `fn(&mut &mut [u8])`.
 ///
@@ -139,6 +139,19 @@ impl HostFn for HostFnImpl {
 		todo!()
 	}
 
+	fn call_v3(
+		flags: CallFlags,
+		callee: &[u8],
+		ref_time_limit: u64,
+		proof_size_limit: u64,
+		deposit: Option<&[u8]>,
+		value: &[u8],
+		input_data: &[u8],
+		output: &mut &mut [u8],
+	) -> core::result::Result<(), CallError> {
+		todo!()
+	}
+
 	fn caller_is_root() -> u32 {
 		todo!()
 	}
@@ -160,6 +173,10 @@ impl HostFn for HostFnImpl {
 		todo!()
 	}
 
+	fn deposit_event_v2(topics: &[u8], data: &[u8]) {
+		todo!()
+	}
+
 	fn set_storage(key: &[u8], value: &[u8]) {
 		todo!()
 	}
@@ -225,6 +242,7 @@ impl HostFn for HostFnImpl {
 
 	impl_wrapper_for! {
 		() => [caller, block_number, address, balance, gas_left, value_transferred, now, minimum_balance],
+		() => [storage_deposit_consumed, storage_deposit_limit],
 		(v1) => [gas_left],
 	}
 
@@ -281,6 +299,22 @@ impl HostFn for HostFnImpl {
 		todo!()
 	}
 
+	fn set_reentrancy_guard(enabled: bool) {
+		todo!()
+	}
+
+	fn set_immutable_data(data: &[u8]) {
+		todo!()
+	}
+
+	fn get_immutable_data(output: &mut &mut [u8]) {
+		todo!()
+	}
+
+	fn randomness(domain: &[u8], output: &mut &mut [u8]) {
+		todo!()
+	}
+
 	fn lock_delegate_dependency(code_hash: &[u8]) {
 		todo!()
 	}
@@ -289,6 +323,28 @@ impl HostFn for HostFnImpl {
 		todo!()
 	}
 
+	fn pin_code_hash(code_hash: &[u8]) {
+		todo!()
+	}
+
+	fn unpin_code_hash(code_hash: &[u8]) {
+		todo!()
+	}
+
+	fn schedule_call(
+		when: &[u8],
+		input_data: &[u8],
+		ref_time_limit: u64,
+		proof_size_limit: u64,
+		deposit: Option<&[u8]>,
+	) -> u32 {
+		todo!()
+	}
+
+	fn cancel_scheduled_call(when: &[u8], index: u32) {
+		todo!()
+	}
+
 	fn instantiation_nonce() -> u64 {
 		todo!()
 	}
@@ -304,4 +360,16 @@ impl HostFn for HostFnImpl {
 	fn xcm_send(dest: &[u8], msg: &[u8], output: &mut [u8; 32]) -> Result {
 		todo!()
 	}
+
+	fn xcm_weigh_message(msg: &[u8], output: &mut &mut [u8]) -> Result {
+		todo!()
+	}
+
+	fn xcm_query(timeout: &[u8], match_querier: &[u8], output: &mut [u8; 8]) -> Result {
+		todo!()
+	}
+
+	fn xcm_take_response(query_id: &[u8; 8], output: &mut &mut [u8]) {
+		todo!()
+	}
 }
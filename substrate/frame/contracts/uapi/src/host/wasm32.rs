@@ -14,7 +14,7 @@
 use super::{
 	extract_from_slice, ptr_len_or_sentinel, ptr_or_sentinel, CallFlags, HostFn, HostFnImpl, Result,
 };
-use crate::{ReturnCode, ReturnFlags};
+use crate::{CallError, CalleeFailure, ReturnCode, ReturnErrorCode, ReturnFlags};
 
 mod sys {
 	use super::ReturnCode;
@@ -87,6 +87,13 @@ mod sys {
 			data_len: u32,
 		);
 
+		pub fn deposit_event_v2(
+			topics_ptr: *const u8,
+			topics_len: u32,
+			data_ptr: *const u8,
+			data_len: u32,
+		);
+
 		pub fn ecdsa_recover(
 			signature_ptr: *const u8,
 			message_hash_ptr: *const u8,
@@ -123,10 +130,42 @@ mod sys {
 
 		pub fn own_code_hash(output_ptr: *mut u8, output_len_ptr: *mut u32);
 
+		pub fn pin_code_hash(code_hash_ptr: *const u8);
+
+		pub fn randomness(
+			domain_ptr: *const u8,
+			domain_len: u32,
+			output_ptr: *mut u8,
+			output_len_ptr: *mut u32,
+		);
+
 		pub fn reentrance_count() -> u32;
 
+		pub fn set_reentrancy_guard(enabled: u32);
+
+		pub fn set_immutable_data(data_ptr: *const u8, data_len: u32);
+
+		pub fn get_immutable_data(output_ptr: *mut u8, output_len_ptr: *mut u32);
+
+		pub fn storage_deposit_consumed(output_ptr: *mut u8, output_len_ptr: *mut u32);
+
+		pub fn storage_deposit_limit(output_ptr: *mut u8, output_len_ptr: *mut u32);
+
 		pub fn unlock_delegate_dependency(code_hash_ptr: *const u8);
 
+		pub fn unpin_code_hash(code_hash_ptr: *const u8);
+
+		pub fn schedule_call(
+			when_ptr: *const u8,
+			input_data_ptr: *const u8,
+			input_data_len: u32,
+			ref_time_limit: u64,
+			proof_size_limit: u64,
+			deposit_ptr: *const u8,
+		) -> u32;
+
+		pub fn cancel_scheduled_call(when_ptr: *const u8, index: u32);
+
 		pub fn seal_return(flags: u32, data_ptr: *const u8, data_len: u32) -> !;
 
 		pub fn set_code_hash(code_hash_ptr: *const u8) -> ReturnCode;
@@ -168,6 +207,25 @@ mod sys {
 			msg_len: u32,
 			output_ptr: *mut u8,
 		) -> ReturnCode;
+
+		pub fn xcm_weigh_message(
+			msg_ptr: *const u8,
+			msg_len: u32,
+			output_ptr: *mut u8,
+			output_len_ptr: *mut u32,
+		) -> ReturnCode;
+
+		pub fn xcm_query(
+			timeout_ptr: *const u8,
+			match_querier_ptr: *const u8,
+			output_ptr: *mut u8,
+		) -> ReturnCode;
+
+		pub fn xcm_take_response(
+			query_id_ptr: *const u8,
+			output_ptr: *mut u8,
+			output_len_ptr: *mut u32,
+		);
 	}
 
 	pub mod v1 {
@@ -272,6 +330,26 @@ mod sys {
 			) -> ReturnCode;
 		}
 	}
+
+	pub mod v3 {
+		use crate::ReturnCode;
+
+		#[link(wasm_import_module = "seal3")]
+		extern "C" {
+			pub fn call(
+				flags: u32,
+				callee_ptr: *const u8,
+				ref_time_limit: u64,
+				proof_size_limit: u64,
+				deposit_ptr: *const u8,
+				transferred_value_ptr: *const u8,
+				input_data_ptr: *const u8,
+				input_data_len: u32,
+				output_ptr: *mut u8,
+				output_len_ptr: *mut u32,
+			) -> ReturnCode;
+		}
+	}
 }
 
 /// A macro to implement all Host functions with a signature of `fn(&mut &mut [u8])`.
@@ -519,6 +597,46 @@ impl HostFn for HostFnImpl {
 		ret_code.into()
 	}
 
+	fn call_v3(
+		flags: CallFlags,
+		callee: &[u8],
+		ref_time_limit: u64,
+		proof_size_limit: u64,
+		deposit: Option<&[u8]>,
+		value: &[u8],
+		input_data: &[u8],
+		output: &mut &mut [u8],
+	) -> core::result::Result<(), CallError> {
+		let mut output_len = output.len() as u32;
+		let deposit_ptr = ptr_or_sentinel(&deposit);
+		let ret_code = {
+			unsafe {
+				sys::v3::call(
+					flags.bits(),
+					callee.as_ptr(),
+					ref_time_limit,
+					proof_size_limit,
+					deposit_ptr,
+					value.as_ptr(),
+					input_data.as_ptr(),
+					input_data.len() as u32,
+					output.as_mut_ptr(),
+					&mut output_len,
+				)
+			}
+		};
+		extract_from_slice(output, output_len as usize);
+
+		match Result::from(ret_code) {
+			Ok(()) => Ok(()),
+			Err(ReturnErrorCode::CalleeReverted) => Err(CallError::Reverted),
+			Err(ReturnErrorCode::CalleeTrapped) => Err(CallError::Failed(
+				CalleeFailure::from_output(output).unwrap_or(CalleeFailure::Trapped),
+			)),
+			Err(code) => Err(CallError::Other(code)),
+		}
+	}
+
 	fn caller_is_root() -> u32 {
 		unsafe { sys::caller_is_root() }.into_u32()
 	}
@@ -574,6 +692,17 @@ impl HostFn for HostFnImpl {
 		}
 	}
 
+	fn deposit_event_v2(topics: &[u8], data: &[u8]) {
+		unsafe {
+			sys::deposit_event_v2(
+				topics.as_ptr(),
+				topics.len() as u32,
+				data.as_ptr(),
+				data.len() as u32,
+			)
+		}
+	}
+
 	fn set_storage(key: &[u8], value: &[u8]) {
 		unsafe { sys::set_storage(key.as_ptr(), value.as_ptr(), value.len() as u32) };
 	}
@@ -712,6 +841,7 @@ impl HostFn for HostFnImpl {
 
 	impl_wrapper_for! {
 		() => [caller, block_number, address, balance, gas_left, value_transferred, now, minimum_balance],
+		() => [storage_deposit_consumed, storage_deposit_limit],
 		(v1) => [gas_left],
 	}
 
@@ -803,6 +933,33 @@ impl HostFn for HostFnImpl {
 		unsafe { sys::account_reentrance_count(account.as_ptr()) }
 	}
 
+	fn set_reentrancy_guard(enabled: bool) {
+		unsafe { sys::set_reentrancy_guard(enabled as u32) }
+	}
+
+	fn set_immutable_data(data: &[u8]) {
+		unsafe { sys::set_immutable_data(data.as_ptr(), data.len() as u32) }
+	}
+
+	fn get_immutable_data(output: &mut &mut [u8]) {
+		let mut output_len = output.len() as u32;
+		unsafe { sys::get_immutable_data(output.as_mut_ptr(), &mut output_len) };
+		extract_from_slice(output, output_len as usize);
+	}
+
+	fn randomness(domain: &[u8], output: &mut &mut [u8]) {
+		let mut output_len = output.len() as u32;
+		unsafe {
+			sys::randomness(
+				domain.as_ptr(),
+				domain.len() as u32,
+				output.as_mut_ptr(),
+				&mut output_len,
+			)
+		};
+		extract_from_slice(output, output_len as usize);
+	}
+
 	fn lock_delegate_dependency(code_hash: &[u8]) {
 		unsafe { sys::lock_delegate_dependency(code_hash.as_ptr()) }
 	}
@@ -811,6 +968,38 @@ impl HostFn for HostFnImpl {
 		unsafe { sys::unlock_delegate_dependency(code_hash.as_ptr()) }
 	}
 
+	fn pin_code_hash(code_hash: &[u8]) {
+		unsafe { sys::pin_code_hash(code_hash.as_ptr()) }
+	}
+
+	fn unpin_code_hash(code_hash: &[u8]) {
+		unsafe { sys::unpin_code_hash(code_hash.as_ptr()) }
+	}
+
+	fn schedule_call(
+		when: &[u8],
+		input_data: &[u8],
+		ref_time_limit: u64,
+		proof_size_limit: u64,
+		deposit: Option<&[u8]>,
+	) -> u32 {
+		let deposit_ptr = ptr_or_sentinel(&deposit);
+		unsafe {
+			sys::schedule_call(
+				when.as_ptr(),
+				input_data.as_ptr(),
+				input_data.len() as u32,
+				ref_time_limit,
+				proof_size_limit,
+				deposit_ptr,
+			)
+		}
+	}
+
+	fn cancel_scheduled_call(when: &[u8], index: u32) {
+		unsafe { sys::cancel_scheduled_call(when.as_ptr(), index) }
+	}
+
 	fn instantiation_nonce() -> u64 {
 		unsafe { sys::instantiation_nonce() }
 	}
@@ -830,4 +1019,31 @@ impl HostFn for HostFnImpl {
 		};
 		ret_code.into()
 	}
+
+	fn xcm_weigh_message(msg: &[u8], output: &mut &mut [u8]) -> Result {
+		let mut output_len = output.len() as u32;
+		let ret_code = unsafe {
+			sys::xcm_weigh_message(
+				msg.as_ptr(),
+				msg.len() as _,
+				output.as_mut_ptr(),
+				&mut output_len,
+			)
+		};
+		extract_from_slice(output, output_len as usize);
+		ret_code.into()
+	}
+
+	fn xcm_query(timeout: &[u8], match_querier: &[u8], output: &mut [u8; 8]) -> Result {
+		let ret_code = unsafe {
+			sys::xcm_query(timeout.as_ptr(), match_querier.as_ptr(), output.as_mut_ptr())
+		};
+		ret_code.into()
+	}
+
+	fn xcm_take_response(query_id: &[u8; 8], output: &mut &mut [u8]) {
+		let mut output_len = output.len() as u32;
+		unsafe { sys::xcm_take_response(query_id.as_ptr(), output.as_mut_ptr(), &mut output_len) };
+		extract_from_slice(output, output_len as usize);
+	}
 }
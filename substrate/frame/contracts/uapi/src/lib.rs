@@ -103,6 +103,55 @@ define_error_codes! {
 	XcmExecutionFailed = 13,
 	/// The `xcm_send` call failed.
 	XcmSendFailed = 14,
+	/// The `xcm_weigh_message` call failed to weigh the message.
+	XcmWeighFailed = 15,
+	/// The `xcm_query` call failed.
+	XcmQueryFailed = 16,
+}
+
+/// Why a callee did not run to completion.
+///
+/// Written into the output buffer by `call_v3` together with
+/// [`ReturnErrorCode::CalleeTrapped`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+	feature = "scale",
+	derive(scale::Encode, scale::Decode, scale::MaxEncodedLen, scale_info::TypeInfo)
+)]
+pub enum CalleeFailure {
+	/// The callee trapped for any reason not covered by the other variants.
+	Trapped,
+	/// The callee ran out of gas.
+	OutOfGas,
+	/// The callee failed with the error of the runtime module at `index`.
+	Module { index: u8, error: [u8; 4] },
+}
+
+impl CalleeFailure {
+	/// Decodes a failure from the output buffer of `call_v3`.
+	///
+	/// The layout matches the SCALE encoding of this type so that contracts can decode it
+	/// without depending on `parity-scale-codec`.
+	pub fn from_output(output: &[u8]) -> Option<Self> {
+		match output {
+			[0] => Some(Self::Trapped),
+			[1] => Some(Self::OutOfGas),
+			[2, index, error @ ..] =>
+				Some(Self::Module { index: *index, error: error.try_into().ok()? }),
+			_ => None,
+		}
+	}
+}
+
+/// The error returned by `call_v3`.
+#[derive(Debug, PartialEq, Eq)]
+pub enum CallError {
+	/// The callee reverted. Its return data was written to the output buffer.
+	Reverted,
+	/// The callee did not run to completion.
+	Failed(CalleeFailure),
+	/// The call could not be made, e.g. because the callee is no contract.
+	Other(ReturnErrorCode),
 }
 
 /// The raw return code returned by the host side.
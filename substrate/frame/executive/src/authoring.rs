@@ -0,0 +1,65 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Filtering extrinsics while authoring a block.
+//!
+//! Some extrinsics are valid, but are known to fail cheaply when dispatched, e.g. calls into a
+//! paused pallet. They still pay their fee and take space in the block. A runtime can leave them
+//! out of the blocks built by its authors by applying extrinsics through
+//! [`Executive::apply_extrinsic_for_authoring`](crate::Executive::apply_extrinsic_for_authoring)
+//! in `BlockBuilder::apply_extrinsic`, with an [`AuthoringFilter`] deciding which extrinsics are
+//! skipped.
+//!
+//! The filter is a soft one: it is not consulted on block import, nor when validating
+//! transactions, so the filtered transactions stay in the transaction pool and blocks of authors
+//! including them stay valid. Skipped extrinsics are reported as
+//! [`UnknownTransaction::SkippedByAuthor`], which block authors treat as "not in this block"
+//! without banning the transaction or counting it against the resources of the block.
+//!
+//! [`UnknownTransaction::SkippedByAuthor`]: sp_runtime::transaction_validity::UnknownTransaction::SkippedByAuthor
+
+use frame_support::traits::Contains;
+use sp_runtime::{generic::UncheckedExtrinsic, traits::SignedExtension};
+use sp_std::marker::PhantomData;
+
+/// Decides which signed extrinsics a block author includes in the blocks it builds.
+pub trait AuthoringFilter<Extrinsic> {
+	/// Whether `xt` should be included in the block being built.
+	fn allows(xt: &Extrinsic) -> bool;
+}
+
+/// All extrinsics are included.
+impl<Extrinsic> AuthoringFilter<Extrinsic> for () {
+	fn allows(_xt: &Extrinsic) -> bool {
+		true
+	}
+}
+
+/// Includes the extrinsics whose call is contained in `Filter`, e.g. the `BaseCallFilter` of the
+/// runtime to skip calls that would be rejected on dispatch anyway.
+pub struct AllowedCalls<Filter>(PhantomData<Filter>);
+
+impl<Address, Call, Signature, Extra, Filter>
+	AuthoringFilter<UncheckedExtrinsic<Address, Call, Signature, Extra>> for AllowedCalls<Filter>
+where
+	Extra: SignedExtension,
+	Filter: Contains<Call>,
+{
+	fn allows(xt: &UncheckedExtrinsic<Address, Call, Signature, Extra>) -> bool {
+		Filter::contains(&xt.function)
+	}
+}
@@ -0,0 +1,207 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Fail-safe execution of runtime migrations.
+//!
+//! A failing migration aborts the block enacting the upgrade, and as every following block runs
+//! the same migration again, the chain stalls. [`FailSafe`] runs a [`FallibleMigration`] in a
+//! storage layer instead: if it returns an error its storage changes are discarded, it is recorded
+//! in [`frame_system::FailedMigrations`] and a `MigrationFailed` event is emitted, so that
+//! governance can respond. A recorded migration is never run again.
+//!
+//! Panics are out of scope: [`FailSafe`] does not detect or recover from them. A panic can't be
+//! caught in wasm and aborts the block, and every retry runs the migration on the same state, so
+//! the runtime can't tell that it panicked before. Governance can instead mark a migration known
+//! to panic as failed with [`frame_system::Pallet::mark_migration_failed`] before the upgrade is
+//! enacted, so that [`FailSafe`] skips it. [`NeverFails`] wraps an [`OnRuntimeUpgrade`] for this
+//! purpose.
+
+use frame_support::{
+	storage::with_storage_layer,
+	traits::{Get, OnRuntimeUpgrade},
+	weights::Weight,
+};
+use sp_runtime::DispatchResult;
+use sp_std::marker::PhantomData;
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+/// A runtime migration that reports failures instead of panicking.
+pub trait FallibleMigration {
+	/// Perform the migration, adding the weight it consumes to `weight` even if it fails.
+	///
+	/// The storage changes are discarded if an error is returned.
+	fn try_on_runtime_upgrade(weight: &mut Weight) -> DispatchResult;
+
+	/// See [`OnRuntimeUpgrade::pre_upgrade`].
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		Ok(Vec::new())
+	}
+
+	/// See [`OnRuntimeUpgrade::post_upgrade`].
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		Ok(())
+	}
+}
+
+/// Runs the migration `M` as a [`FallibleMigration`] that never fails.
+///
+/// Wrapped in [`FailSafe`], the migration can still be skipped by marking it as failed.
+pub struct NeverFails<M>(PhantomData<M>);
+
+impl<M: OnRuntimeUpgrade> FallibleMigration for NeverFails<M> {
+	fn try_on_runtime_upgrade(weight: &mut Weight) -> DispatchResult {
+		weight.saturating_accrue(M::on_runtime_upgrade());
+		Ok(())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		M::pre_upgrade()
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		M::post_upgrade(state)
+	}
+}
+
+/// Runs the migration `M`, identified by `Id`, skipping it if it failed before and recording it
+/// in [`frame_system::FailedMigrations`] if it fails now.
+///
+/// See the [module docs](self).
+pub struct FailSafe<T, M, Id>(PhantomData<(T, M, Id)>);
+
+impl<T, M, Id> OnRuntimeUpgrade for FailSafe<T, M, Id>
+where
+	T: frame_system::Config,
+	M: FallibleMigration,
+	Id: Get<&'static str>,
+{
+	fn on_runtime_upgrade() -> Weight {
+		let id = Id::get();
+		let mut weight = T::DbWeight::get().reads(1);
+		if frame_system::Pallet::<T>::migration_failed(id.as_bytes()) {
+			log::warn!(target: crate::LOG_TARGET, "skipping failed migration {}", id);
+			return weight
+		}
+
+		if let Err(err) = with_storage_layer(|| M::try_on_runtime_upgrade(&mut weight)) {
+			log::error!(target: crate::LOG_TARGET, "migration {} failed and was skipped: {:?}", id, err);
+			frame_system::Pallet::<T>::note_failed_migration(id.as_bytes());
+			weight.saturating_accrue(T::DbWeight::get().writes(2));
+		}
+		weight
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		if frame_system::Pallet::<T>::migration_failed(Id::get().as_bytes()) {
+			return Ok(Vec::new())
+		}
+		M::pre_upgrade()
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		if frame_system::Pallet::<T>::migration_failed(Id::get().as_bytes()) {
+			return Ok(())
+		}
+		M::post_upgrade(state)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::tests::{Runtime, RuntimeEvent, System};
+	use frame_support::{assert_ok, parameter_types, storage::unhashed, BuildStorage};
+	use sp_runtime::DispatchError;
+
+	struct Fails;
+	impl FallibleMigration for Fails {
+		fn try_on_runtime_upgrade(weight: &mut Weight) -> DispatchResult {
+			unhashed::put_raw(b"fails", b"written");
+			weight.saturating_accrue(Weight::from_parts(5, 0));
+			Err(DispatchError::Other("migration failed"))
+		}
+	}
+
+	struct Works;
+	impl OnRuntimeUpgrade for Works {
+		fn on_runtime_upgrade() -> Weight {
+			unhashed::put_raw(b"works", b"written");
+			Weight::from_parts(10, 0)
+		}
+	}
+
+	parameter_types! {
+		pub const FailsId: &'static str = "Fails";
+		pub const WorksId: &'static str = "Works";
+	}
+
+	fn new_test_ext() -> sp_io::TestExternalities {
+		let storage = frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
+		let mut ext = sp_io::TestExternalities::new(storage);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+
+	#[test]
+	fn failing_migration_is_skipped() {
+		new_test_ext().execute_with(|| {
+			let weight = <(
+				FailSafe<Runtime, Fails, FailsId>,
+				FailSafe<Runtime, NeverFails<Works>, WorksId>,
+			) as OnRuntimeUpgrade>::on_runtime_upgrade();
+
+			// The weight consumed by the failed migration is accounted for.
+			assert_eq!(weight, Weight::from_parts(15, 0));
+			assert!(System::migration_failed(b"Fails"));
+			assert!(!System::migration_failed(b"Works"));
+			System::assert_has_event(RuntimeEvent::System(frame_system::Event::MigrationFailed {
+				migration: sp_io::hashing::blake2_256(b"Fails"),
+			}));
+			// The changes of the failed migration were rolled back.
+			assert_eq!(unhashed::get_raw(b"fails"), None);
+			assert_eq!(unhashed::get_raw(b"works"), Some(b"written".to_vec()));
+
+			// It is not run again.
+			assert_eq!(FailSafe::<Runtime, Fails, FailsId>::on_runtime_upgrade(), Weight::zero());
+		});
+	}
+
+	#[test]
+	fn migration_marked_as_failed_is_skipped() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(System::mark_migration_failed(
+				frame_system::RawOrigin::Root.into(),
+				b"Works".to_vec().try_into().unwrap()
+			));
+
+			let weight = FailSafe::<Runtime, NeverFails<Works>, WorksId>::on_runtime_upgrade();
+
+			assert_eq!(weight, Weight::zero());
+			assert_eq!(unhashed::get_raw(b"works"), None);
+		});
+	}
+}
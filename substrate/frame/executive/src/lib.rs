@@ -113,9 +113,23 @@
 //!
 //! pub type Executive = executive::Executive<Runtime, Block, Context, Runtime, AllPalletsWithSystem, CustomOnRuntimeUpgrade>;
 //! ```
+//!
+//! ### Shadow execution
+//!
+//! A node can execute every block it imports a second time, against a candidate runtime that is
+//! not enacted yet, to check that the candidate executes the blocks of the chain identically. The
+//! candidate must be built with the `shadow-execution` feature: the blocks are executed on top of
+//! state that was not migrated, so such a build skips the `OnRuntimeUpgrade` hooks and never
+//! updates [`frame_system::LastRuntimeUpgrade`]. A runtime built with this feature must never be
+//! enacted.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub mod authoring;
+pub mod fail_safe;
+pub mod proof_size;
+pub mod replay;
+
 use codec::{Codec, Encode};
 use frame_support::{
 	dispatch::{DispatchClass, DispatchInfo, GetDispatchInfo, PostDispatchInfo},
@@ -130,11 +144,11 @@ use frame_system::pallet_prelude::BlockNumberFor;
 use sp_runtime::{
 	generic::Digest,
 	traits::{
-		self, Applyable, CheckEqual, Checkable, Dispatchable, Header, NumberFor, One,
+		self, Applyable, CheckEqual, Checkable, Dispatchable, Extrinsic, Header, NumberFor, One,
 		ValidateUnsigned, Zero,
 	},
-	transaction_validity::{TransactionSource, TransactionValidity},
-	ApplyExtrinsicResult,
+	transaction_validity::{TransactionSource, TransactionValidity, UnknownTransaction},
+	ApplyExtrinsicResult, DispatchError, TransactionOutcome,
 };
 use sp_std::{marker::PhantomData, prelude::*};
 
@@ -497,7 +511,8 @@ where
 		<frame_system::Pallet<System>>::reset_events();
 
 		let mut weight = Weight::zero();
-		if Self::runtime_upgraded() {
+		// A shadow execution build is never enacted, its migrations must not run.
+		if Self::runtime_upgraded() && !cfg!(feature = "shadow-execution") {
 			weight = weight.saturating_add(Self::execute_on_runtime_upgrade());
 
 			frame_system::LastRuntimeUpgrade::<System>::put(
@@ -567,6 +582,90 @@ where
 		}
 	}
 
+	/// Re-execute `blocks` on top of the current state, which must be the state of the parent of
+	/// the first block, without keeping any of the changes.
+	///
+	/// Unlike [`Self::execute_block`] this doesn't panic on invalid blocks. It reports the state
+	/// root computed for every block and stops at the first block that diverges from its header.
+	/// Meant to back [`replay::ReplayApi`] for debugging only.
+	pub fn replay_blocks(blocks: Vec<Block>) -> replay::ReplayReport<System::Hash> {
+		let replayed = frame_support::storage::with_transaction(|| {
+			let mut replayed = Vec::with_capacity(blocks.len());
+			let mut parent_hash = None;
+			for block in blocks {
+				let block = Self::replay_block(block, parent_hash);
+				let diverged = block.divergence.is_some();
+				parent_hash = Some(block.hash);
+				replayed.push(block);
+				if diverged {
+					break
+				}
+			}
+			TransactionOutcome::Rollback(Ok::<_, DispatchError>(replayed))
+		});
+
+		replay::ReplayReport::new(replayed.unwrap_or_else(|error| {
+			log::error!(target: LOG_TARGET, "Failed to replay blocks: {:?}", error);
+			Vec::new()
+		}))
+	}
+
+	/// Execute `block` like [`Self::execute_block`], but report divergences instead of panicking.
+	///
+	/// `parent_hash` is the hash of the previously replayed block, if any.
+	fn replay_block(
+		block: Block,
+		parent_hash: Option<System::Hash>,
+	) -> replay::ReplayedBlock<System::Hash> {
+		use replay::ReplayDivergence;
+
+		let hash = block.header().hash();
+		let number = *block.header().number();
+		// Checked before initializing the block, which records its parent hash.
+		let parent_is_valid = number ==
+			<frame_system::Pallet<System>>::block_number().saturating_add(One::one()) &&
+			parent_hash.map_or(true, |parent_hash| parent_hash == *block.header().parent_hash());
+
+		Self::initialize_block(block.header());
+
+		let mut divergence = if !parent_is_valid {
+			Some(ReplayDivergence::ParentHash)
+		} else {
+			System::ensure_inherents_are_first(&block)
+				.err()
+				.map(ReplayDivergence::InherentPosition)
+		};
+
+		let (header, extrinsics) = block.deconstruct();
+		if divergence.is_none() {
+			for (index, uxt) in extrinsics.into_iter().enumerate() {
+				if let Err(error) = Self::apply_extrinsic(uxt) {
+					divergence =
+						Some(ReplayDivergence::InvalidExtrinsic { index: index as u32, error });
+					break
+				}
+			}
+		}
+
+		<frame_system::Pallet<System>>::note_finished_extrinsics();
+		Self::idle_and_finalize_hook(number);
+		let new_header = <frame_system::Pallet<System>>::finalize();
+
+		if divergence.is_none() {
+			divergence = if header.digest() != new_header.digest() {
+				Some(ReplayDivergence::Digest)
+			} else if header.extrinsics_root() != new_header.extrinsics_root() {
+				Some(ReplayDivergence::ExtrinsicsRoot)
+			} else if header.state_root() != new_header.state_root() {
+				Some(ReplayDivergence::StateRoot)
+			} else {
+				None
+			};
+		}
+
+		replay::ReplayedBlock { hash, state_root: *new_header.state_root(), divergence }
+	}
+
 	/// Execute given extrinsics and take care of post-extrinsics book-keeping.
 	fn execute_extrinsics_with_book_keeping(
 		extrinsics: Vec<Block::Extrinsic>,
@@ -615,6 +714,9 @@ where
 		}
 
 		<AllPalletsWithSystem as OnFinalize<BlockNumberFor<System>>>::on_finalize(block_number);
+
+		#[cfg(feature = "proof-size-breakdown")]
+		proof_size::clear();
 	}
 
 	/// Apply extrinsic outside of the block execution function.
@@ -654,6 +756,53 @@ where
 		Ok(r.map(|_| ()).map_err(|e| e.error))
 	}
 
+	/// Apply extrinsic like [`Self::apply_extrinsic`] while authoring a block, skipping signed
+	/// extrinsics that are not allowed by `Filter`.
+	///
+	/// Must not be used to import blocks. See [`authoring`] for details.
+	pub fn apply_extrinsic_for_authoring<Filter: authoring::AuthoringFilter<Block::Extrinsic>>(
+		uxt: Block::Extrinsic,
+	) -> ApplyExtrinsicResult {
+		// Unsigned extrinsics are never skipped, so that inherents always make it into the block.
+		if uxt.is_signed().unwrap_or(false) && !Filter::allows(&uxt) {
+			log::debug!(target: LOG_TARGET, "Extrinsic skipped by the authoring filter");
+			return Err(UnknownTransaction::SkippedByAuthor.into())
+		}
+
+		Self::apply_extrinsic(uxt)
+	}
+
+	/// Apply extrinsic like [`Self::apply_extrinsic`], recording how much the storage proof grew.
+	///
+	/// The recorded sizes are returned by [`Self::extrinsic_proof_sizes`]. Nothing is recorded if
+	/// `P` doesn't report a proof size. See [`proof_size`] for details.
+	#[cfg(feature = "proof-size-breakdown")]
+	pub fn apply_extrinsic_recording_proof_size<P: proof_size::GetProofSize>(
+		uxt: Block::Extrinsic,
+	) -> ApplyExtrinsicResult {
+		let index = <frame_system::Pallet<System>>::extrinsic_index().unwrap_or_default();
+		let encoded_len = uxt.encoded_size() as u32;
+
+		let before = P::get_proof_size();
+		let result = Self::apply_extrinsic(uxt);
+		if let (Some(before), Some(after)) = (before, P::get_proof_size()) {
+			proof_size::record(proof_size::ExtrinsicProofSize {
+				index,
+				encoded_len,
+				proof_size: after.saturating_sub(before),
+			});
+		}
+
+		result
+	}
+
+	/// The proof sizes recorded by [`Self::apply_extrinsic_recording_proof_size`] in the current
+	/// block, in order. Always empty if the runtime is built without the `proof-size-breakdown`
+	/// feature.
+	pub fn extrinsic_proof_sizes() -> Vec<proof_size::ExtrinsicProofSize> {
+		proof_size::recorded()
+	}
+
 	fn final_checks(header: &frame_system::pallet_prelude::HeaderFor<System>) {
 		sp_tracing::enter_span!(sp_tracing::Level::TRACE, "final_checks");
 		// remove temporaries
@@ -1114,6 +1263,221 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn replay_blocks_reports_divergence() {
+		let state_root: H256 = array_bytes::hex_n_into_unchecked(
+			"5a19b3d6fdb7241836349fdcbe2d9df4d4f945b949d979e31ad50bff1cbcd1c2",
+		);
+		let block = |state_root| Block {
+			header: Header {
+				parent_hash: [69u8; 32].into(),
+				number: 1,
+				state_root,
+				extrinsics_root: array_bytes::hex_n_into_unchecked(
+					"03170a2e7597b7b7e3d84c05391d139a62b157e78786d8c082f29dcf4c111314",
+				),
+				digest: Digest { logs: vec![] },
+			},
+			extrinsics: vec![],
+		};
+
+		new_test_ext(1).execute_with(|| {
+			let root = sp_io::storage::root(sp_runtime::StateVersion::V1);
+			let report = Executive::replay_blocks(vec![block(state_root)]);
+			assert_eq!(report.divergence_at, None);
+			assert_eq!(report.blocks.len(), 1);
+			assert_eq!(report.blocks[0].state_root, state_root);
+			// The changes of the replay are discarded.
+			assert_eq!(sp_io::storage::root(sp_runtime::StateVersion::V1), root);
+
+			// The replay stops at the first diverging block.
+			let report = Executive::replay_blocks(vec![block([0u8; 32].into()), block(state_root)]);
+			assert_eq!(report.divergence_at, Some(0));
+			assert_eq!(
+				report.blocks,
+				vec![replay::ReplayedBlock {
+					hash: block([0u8; 32].into()).header.hash(),
+					state_root,
+					divergence: Some(replay::ReplayDivergence::StateRoot),
+				}]
+			);
+			assert_eq!(sp_io::storage::root(sp_runtime::StateVersion::V1), root);
+		});
+	}
+
+	#[test]
+	fn replay_blocks_reports_broken_chain() {
+		let block = |number, parent_hash: H256, state_root| Block {
+			header: Header {
+				parent_hash,
+				number,
+				state_root,
+				extrinsics_root: array_bytes::hex_n_into_unchecked(
+					"03170a2e7597b7b7e3d84c05391d139a62b157e78786d8c082f29dcf4c111314",
+				),
+				digest: Digest { logs: vec![] },
+			},
+			extrinsics: vec![],
+		};
+		let first = block(
+			1,
+			[69u8; 32].into(),
+			array_bytes::hex_n_into_unchecked(
+				"5a19b3d6fdb7241836349fdcbe2d9df4d4f945b949d979e31ad50bff1cbcd1c2",
+			),
+		);
+		let divergence = |report: replay::ReplayReport<H256>| {
+			report.blocks.last().and_then(|block| block.divergence.clone())
+		};
+
+		new_test_ext(1).execute_with(|| {
+			// The first block must follow the current block.
+			let report =
+				Executive::replay_blocks(vec![block(2, [69u8; 32].into(), [0u8; 32].into())]);
+			assert_eq!(report.divergence_at, Some(0));
+			assert_eq!(divergence(report), Some(replay::ReplayDivergence::ParentHash));
+
+			// The parent of the second block is not the first block.
+			let report = Executive::replay_blocks(vec![
+				first.clone(),
+				block(2, [69u8; 32].into(), [0u8; 32].into()),
+			]);
+			assert_eq!(report.divergence_at, Some(1));
+			assert_eq!(divergence(report), Some(replay::ReplayDivergence::ParentHash));
+
+			// The second block has the wrong number.
+			let report = Executive::replay_blocks(vec![
+				first.clone(),
+				block(3, first.header.hash(), [0u8; 32].into()),
+			]);
+			assert_eq!(report.divergence_at, Some(1));
+			assert_eq!(divergence(report), Some(replay::ReplayDivergence::ParentHash));
+
+			// The second block follows the first one, only its state root is wrong.
+			let report = Executive::replay_blocks(vec![
+				first.clone(),
+				block(2, first.header.hash(), [0u8; 32].into()),
+			]);
+			assert_eq!(report.divergence_at, Some(1));
+			assert_eq!(divergence(report), Some(replay::ReplayDivergence::StateRoot));
+		});
+	}
+
+	#[test]
+	#[cfg(feature = "proof-size-breakdown")]
+	fn proof_size_is_recorded_per_extrinsic() {
+		use frame_support::{assert_ok, storage::unhashed};
+
+		parameter_types! {
+			pub static RecordedProofSize: Option<u64> = None;
+		}
+
+		// Reports a proof growing by 10 bytes on every call.
+		struct GrowingProofSize;
+		impl proof_size::GetProofSize for GrowingProofSize {
+			fn get_proof_size() -> Option<u64> {
+				RecordedProofSize::mutate(|size| {
+					*size = size.map(|size| size + 10);
+					*size
+				})
+			}
+		}
+
+		let xt = |nonce| TestXt::new(call_transfer(2, 1), sign_extra(1, nonce, 0));
+		let encoded_len = xt(0).encoded_size() as u32;
+
+		new_test_ext(1).execute_with(|| {
+			Executive::initialize_block(&Header::new(
+				1,
+				H256::default(),
+				H256::default(),
+				[69u8; 32].into(),
+				Digest::default(),
+			));
+
+			RecordedProofSize::set(Some(100));
+			assert_ok!(Executive::apply_extrinsic_recording_proof_size::<GrowingProofSize>(xt(0)));
+			assert_ok!(Executive::apply_extrinsic_recording_proof_size::<GrowingProofSize>(xt(1)));
+			// Nothing is recorded without a proof size.
+			RecordedProofSize::set(None);
+			assert_ok!(Executive::apply_extrinsic_recording_proof_size::<GrowingProofSize>(xt(2)));
+
+			assert_eq!(
+				Executive::extrinsic_proof_sizes(),
+				vec![
+					proof_size::ExtrinsicProofSize { index: 0, encoded_len, proof_size: 10 },
+					proof_size::ExtrinsicProofSize { index: 1, encoded_len, proof_size: 10 },
+				]
+			);
+
+			// The breakdown doesn't end up in the finalized block.
+			Executive::finalize_block();
+			assert!(Executive::extrinsic_proof_sizes().is_empty());
+			assert_eq!(unhashed::get_raw(proof_size::PROOF_SIZES_KEY), None);
+		});
+	}
+
+	#[test]
+	fn authoring_filter_skips_signed_extrinsics() {
+		// Skips transfers to account 3 and everything unsigned.
+		struct NoTransfersTo3;
+		impl authoring::AuthoringFilter<TestXt> for NoTransfersTo3 {
+			fn allows(xt: &TestXt) -> bool {
+				xt.signature.is_some() &&
+					!matches!(
+						xt.call,
+						RuntimeCall::Balances(BalancesCall::transfer_allow_death { dest: 3, .. })
+					)
+			}
+		}
+
+		new_test_ext(1).execute_with(|| {
+			Executive::initialize_block(&Header::new(
+				1,
+				H256::default(),
+				H256::default(),
+				[69u8; 32].into(),
+				Digest::default(),
+			));
+
+			let skipped = TestXt::new(call_transfer(3, 1), sign_extra(1, 0, 0));
+			let result =
+				Executive::apply_extrinsic_for_authoring::<NoTransfersTo3>(skipped.clone());
+			assert_eq!(result, Err(UnknownTransaction::SkippedByAuthor.into()));
+			let error = result.unwrap_err();
+			assert!(error.skipped_by_author());
+			assert!(!error.exhausted_resources());
+			assert_eq!(<frame_system::Pallet<Runtime>>::extrinsic_index(), Some(0));
+
+			// The skipped transaction stays valid for the transaction pool.
+			assert!(Executive::validate_transaction(
+				TransactionSource::External,
+				skipped,
+				Default::default()
+			)
+			.is_ok());
+
+			let allowed = TestXt::new(call_transfer(2, 1), sign_extra(1, 0, 0));
+			assert_eq!(
+				Executive::apply_extrinsic_for_authoring::<NoTransfersTo3>(allowed),
+				Ok(Ok(()))
+			);
+
+			// Unsigned extrinsics are never skipped.
+			let unsigned =
+				TestXt::new(RuntimeCall::Custom(custom::Call::allowed_unsigned {}), None);
+			assert_eq!(
+				Executive::apply_extrinsic_for_authoring::<NoTransfersTo3>(unsigned),
+				Ok(Err(DispatchError::BadOrigin))
+			);
+
+			// The filter doesn't apply to block import.
+			let skipped = TestXt::new(call_transfer(3, 1), sign_extra(1, 1, 0));
+			assert_eq!(Executive::apply_extrinsic(skipped), Ok(Ok(())));
+			assert_eq!(<pallet_balances::Pallet<Runtime>>::total_balance(&3), 1);
+		});
+	}
+
 	#[test]
 	#[should_panic]
 	fn block_import_of_bad_state_root_fails() {
@@ -1560,7 +1924,8 @@ mod tests {
 				frame_system::Pallet::<Runtime>::block_weight().total(),
 				custom_runtime_upgrade_weight +
 					runtime_upgrade_weight +
-					on_initialize_weight + base_block_weight,
+					on_initialize_weight +
+					base_block_weight,
 			);
 		});
 	}
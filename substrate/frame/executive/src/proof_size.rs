@@ -0,0 +1,81 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Recording the storage proof size of every extrinsic while building a block.
+//!
+//! Parachain blocks are rejected by the relay chain if their proof of validity (PoV) gets too
+//! large. To find out which extrinsics make the PoV grow, a runtime built with the
+//! `proof-size-breakdown` feature can apply extrinsics through
+//! [`Executive::apply_extrinsic_recording_proof_size`](crate::Executive::apply_extrinsic_recording_proof_size).
+//! It measures how much the storage proof grew while applying each extrinsic, as reported by a
+//! [`GetProofSize`] implementation, typically backed by the `storage_proof_size` host function of
+//! cumulus. The breakdown is returned by
+//! [`Executive::extrinsic_proof_sizes`](crate::Executive::extrinsic_proof_sizes), which backs
+//! `BlockBuilder::extrinsic_proof_sizes`, and discarded when the block is finalized.
+//!
+//! The breakdown is kept in storage while the block is built, which costs proof size of its own.
+//! This is meant for debugging, not for production runtimes. Without the feature nothing is
+//! recorded and [`Executive::extrinsic_proof_sizes`](crate::Executive::extrinsic_proof_sizes)
+//! always returns an empty list, so every runtime can implement `BlockBuilder` version 7.
+
+#[cfg(feature = "proof-size-breakdown")]
+use codec::Encode;
+#[cfg(feature = "proof-size-breakdown")]
+use frame_support::storage::unhashed;
+use sp_std::prelude::*;
+
+pub use sp_block_builder::ExtrinsicProofSize;
+
+/// The storage key holding the proof sizes recorded in the current block.
+pub const PROOF_SIZES_KEY: &[u8] = b":executive:proof_sizes";
+
+/// Source of the size of the storage proof recorded so far.
+pub trait GetProofSize {
+	/// The current size of the storage proof, `None` if the proof isn't recorded.
+	fn get_proof_size() -> Option<u64>;
+}
+
+/// The storage proof is never recorded.
+impl GetProofSize for () {
+	fn get_proof_size() -> Option<u64> {
+		None
+	}
+}
+
+/// Record the proof size of an extrinsic.
+#[cfg(feature = "proof-size-breakdown")]
+pub(crate) fn record(proof_size: ExtrinsicProofSize) {
+	sp_io::storage::append(PROOF_SIZES_KEY, proof_size.encode());
+}
+
+/// The proof sizes recorded in the current block.
+#[cfg(feature = "proof-size-breakdown")]
+pub(crate) fn recorded() -> Vec<ExtrinsicProofSize> {
+	unhashed::get(PROOF_SIZES_KEY).unwrap_or_default()
+}
+
+/// Nothing is recorded without the `proof-size-breakdown` feature.
+#[cfg(not(feature = "proof-size-breakdown"))]
+pub(crate) fn recorded() -> Vec<ExtrinsicProofSize> {
+	Vec::new()
+}
+
+/// Discard the proof sizes recorded in the current block.
+#[cfg(feature = "proof-size-breakdown")]
+pub(crate) fn clear() {
+	unhashed::kill(PROOF_SIZES_KEY);
+}
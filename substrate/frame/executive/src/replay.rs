@@ -0,0 +1,91 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Replaying blocks for debugging.
+//!
+//! [`Executive::replay_blocks`](crate::Executive::replay_blocks) re-executes a range of blocks on
+//! top of the state of the parent of the first one and reports where the execution diverges from
+//! the headers of the blocks. All changes are discarded afterwards. Runtimes that want to offer
+//! this to debugging tools implement [`ReplayApi`]; it is not meant for production runtimes.
+
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{transaction_validity::TransactionValidityError, RuntimeDebug};
+use sp_std::prelude::*;
+
+/// Why the execution of a block diverged from its header.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum ReplayDivergence {
+	/// The block doesn't follow the previous block: its number or parent hash don't match.
+	///
+	/// The parent hash of the first block can't be checked against the state, only its number.
+	ParentHash,
+	/// The inherent at the given index comes after a transaction.
+	InherentPosition(u32),
+	/// The extrinsic at the given index could not be applied.
+	InvalidExtrinsic { index: u32, error: TransactionValidityError },
+	/// The digest doesn't match the one computed.
+	Digest,
+	/// The extrinsics root doesn't match the one computed.
+	ExtrinsicsRoot,
+	/// The state root doesn't match the one computed.
+	StateRoot,
+}
+
+/// The outcome of replaying a single block.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ReplayedBlock<Hash> {
+	/// The hash of the replayed block.
+	pub hash: Hash,
+	/// The state root computed by the replay.
+	pub state_root: Hash,
+	/// Why the replay diverged from the header of the block, if it did.
+	pub divergence: Option<ReplayDivergence>,
+}
+
+/// The outcome of replaying a range of blocks.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ReplayReport<Hash> {
+	/// The replayed blocks, in order.
+	///
+	/// The replay stops at the first diverging block, the blocks after it are not included.
+	pub blocks: Vec<ReplayedBlock<Hash>>,
+	/// The index of the first block that diverged from its header, if any.
+	pub divergence_at: Option<u32>,
+}
+
+impl<Hash> ReplayReport<Hash> {
+	/// Create the report of the replayed `blocks`.
+	pub fn new(blocks: Vec<ReplayedBlock<Hash>>) -> Self {
+		let divergence_at = blocks
+			.iter()
+			.position(|block| block.divergence.is_some())
+			.map(|index| index as u32);
+		Self { blocks, divergence_at }
+	}
+}
+
+sp_api::decl_runtime_apis! {
+	/// Replay blocks for debugging.
+	pub trait ReplayApi {
+		/// Re-execute `blocks` on top of the state this is called at, which must be the state of
+		/// the parent of the first block.
+		///
+		/// No changes are kept.
+		fn replay_blocks(blocks: Vec<Block>) -> ReplayReport<Block::Hash>;
+	}
+}
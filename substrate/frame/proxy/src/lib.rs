@@ -39,7 +39,10 @@ use frame_support::{
 	ensure,
 	traits::{Currency, Get, InstanceFilter, IsSubType, IsType, OriginTrait, ReservableCurrency},
 };
-use frame_system::{self as system, ensure_signed, pallet_prelude::BlockNumberFor};
+use frame_system::{
+	self as system, ensure_signed, pallet_prelude::BlockNumberFor, with_dispatch_frame,
+	DispatchFrame,
+};
 pub use pallet::*;
 use scale_info::TypeInfo;
 use sp_io::hashing::blake2_256;
@@ -795,7 +798,7 @@ impl<T: Config> Pallet<T> {
 				_ => def.proxy_type.filter(c),
 			}
 		});
-		let e = call.dispatch(origin);
+		let e = with_dispatch_frame(DispatchFrame::new::<Self>(0), || call.dispatch(origin));
 		Self::deposit_event(Event::ProxyExecuted { result: e.map(|_| ()).map_err(|e| e.error) });
 	}
 
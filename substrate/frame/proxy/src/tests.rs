@@ -490,6 +490,30 @@ fn cannot_add_proxy_without_balance() {
 	});
 }
 
+#[test]
+fn proxying_records_dispatch_paths() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(System::set_dispatch_path_recording(RuntimeOrigin::root(), true));
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 2, ProxyType::Any, 0));
+
+		let call = Box::new(RuntimeCall::Utility(UtilityCall::batch {
+			calls: vec![call_transfer(6, 1), call_transfer(6, 1)],
+		}));
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, call));
+
+		let proxy = frame_system::DispatchFrame::new::<Proxy>(0);
+		let utility = |item| frame_system::DispatchFrame::new::<Utility>(item);
+		let transfer_paths: Vec<_> = System::read_events_with_dispatch_paths()
+			.into_iter()
+			.filter(|e| {
+				matches!(e.record.event, RuntimeEvent::Balances(BalancesEvent::Transfer { .. }))
+			})
+			.map(|e| e.dispatch_path)
+			.collect();
+		assert_eq!(transfer_paths, vec![vec![proxy, utility(0)], vec![proxy, utility(1)]]);
+	});
+}
+
 #[test]
 fn proxying_works() {
 	new_test_ext().execute_with(|| {
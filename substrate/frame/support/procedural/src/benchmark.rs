@@ -28,9 +28,9 @@ use syn::{
 	punctuated::Punctuated,
 	spanned::Spanned,
 	token::{Comma, Gt, Lt, PathSep},
-	Attribute, Error, Expr, ExprBlock, ExprCall, ExprPath, FnArg, Item, ItemFn, ItemMod, Pat, Path,
-	PathArguments, PathSegment, Result, ReturnType, Signature, Stmt, Token, Type, TypePath,
-	Visibility, WhereClause,
+	Attribute, Error, Expr, ExprBlock, ExprCall, ExprPath, FnArg, Item, ItemFn, ItemMod, LitStr,
+	Pat, Path, PathArguments, PathSegment, Result, ReturnType, Signature, Stmt, Token, Type,
+	TypePath, Visibility, WhereClause,
 };
 
 mod keywords {
@@ -41,12 +41,16 @@ mod keywords {
 	custom_keyword!(block);
 	custom_keyword!(extra);
 	custom_keyword!(extrinsic_call);
+	custom_keyword!(reads);
 	custom_keyword!(skip_meta);
+	custom_keyword!(tags);
+	custom_keyword!(writes);
 	custom_keyword!(BenchmarkError);
 	custom_keyword!(Result);
 
 	pub const BENCHMARK_TOKEN: &str = stringify!(benchmark);
 	pub const BENCHMARKS_TOKEN: &str = stringify!(benchmarks);
+	pub const VERIFY_DB_TOKEN: &str = stringify!(verify_db);
 }
 
 /// This represents the raw parsed data for a param definition such as `x: Linear<10, 20>`.
@@ -72,12 +76,14 @@ struct RangeArgs {
 struct BenchmarkAttrs {
 	skip_meta: bool,
 	extra: bool,
+	tags: Vec<String>,
 }
 
 /// Represents a single benchmark option
 enum BenchmarkAttrKeyword {
 	Extra,
 	SkipMeta,
+	Tags(Vec<String>),
 }
 
 impl syn::parse::Parse for BenchmarkAttrKeyword {
@@ -89,6 +95,13 @@ impl syn::parse::Parse for BenchmarkAttrKeyword {
 		} else if lookahead.peek(keywords::skip_meta) {
 			let _skip_meta: keywords::skip_meta = input.parse()?;
 			return Ok(BenchmarkAttrKeyword::SkipMeta)
+		} else if lookahead.peek(keywords::tags) {
+			let _tags: keywords::tags = input.parse()?;
+			let _eq: Token![=] = input.parse()?;
+			let content;
+			syn::bracketed!(content in input);
+			let tags = Punctuated::<LitStr, Token![,]>::parse_terminated(&content)?;
+			return Ok(BenchmarkAttrKeyword::Tags(tags.iter().map(LitStr::value).collect()))
 		} else {
 			return Err(lookahead.error())
 		}
@@ -99,6 +112,7 @@ impl syn::parse::Parse for BenchmarkAttrs {
 	fn parse(input: ParseStream) -> syn::Result<Self> {
 		let mut extra = false;
 		let mut skip_meta = false;
+		let mut tags = None;
 		let args = Punctuated::<BenchmarkAttrKeyword, Token![,]>::parse_terminated(&input)?;
 		for arg in args.into_iter() {
 			match arg {
@@ -114,12 +128,31 @@ impl syn::parse::Parse for BenchmarkAttrs {
 					}
 					skip_meta = true;
 				},
+				BenchmarkAttrKeyword::Tags(new_tags) => {
+					if tags.is_some() {
+						return Err(input.error("`tags` can only be specified once"))
+					}
+					tags = Some(new_tags);
+				},
 			}
 		}
-		Ok(BenchmarkAttrs { extra, skip_meta })
+		Ok(BenchmarkAttrs { extra, skip_meta, tags: tags.unwrap_or_default() })
 	}
 }
 
+/// Allows easy parsing of the `reads = .., writes = ..` arguments of `#[verify_db]`.
+#[derive(Parse, Clone)]
+struct VerifyDbDef {
+	_reads_kw: keywords::reads,
+	_reads_eq: Token![=],
+	reads: Expr,
+	_comma: Comma,
+	_writes_kw: keywords::writes,
+	_writes_eq: Token![=],
+	writes: Expr,
+	_trailing_comma: Option<Comma>,
+}
+
 /// Represents the parsed extrinsic call for a benchmark
 #[derive(Clone)]
 enum BenchmarkCallDef {
@@ -148,6 +181,7 @@ struct BenchmarkDef {
 	fn_sig: Signature,
 	fn_vis: Visibility,
 	fn_attrs: Vec<Attribute>,
+	verify_db: Option<VerifyDbDef>,
 }
 
 /// used to parse something compatible with `Result<T, E>`
@@ -191,6 +225,19 @@ fn ensure_valid_return_type(item_fn: &ItemFn) -> Result<()> {
 	Ok(())
 }
 
+/// Parses the optional `#[verify_db(reads = .., writes = ..)]` attribute of a benchmark.
+fn parse_verify_db(item_fn: &ItemFn) -> Result<Option<VerifyDbDef>> {
+	let mut verify_db_attrs = item_fn
+		.attrs
+		.iter()
+		.filter(|attr| attr.path().is_ident(keywords::VERIFY_DB_TOKEN));
+	let Some(attr) = verify_db_attrs.next() else { return Ok(None) };
+	if let Some(dup) = verify_db_attrs.next() {
+		return Err(Error::new(dup.span(), "`#[verify_db]` can only be specified once"))
+	}
+	Ok(Some(attr.parse_args()?))
+}
+
 /// Parses params such as `x: Linear<0, 1>`
 fn parse_params(item_fn: &ItemFn) -> Result<Vec<ParamDef>> {
 	let mut params: Vec<ParamDef> = Vec::new();
@@ -296,6 +343,7 @@ impl BenchmarkDef {
 	/// Constructs a [`BenchmarkDef`] by traversing an existing [`ItemFn`] node.
 	pub fn from(item_fn: &ItemFn) -> Result<BenchmarkDef> {
 		let params = parse_params(item_fn)?;
+		let verify_db = parse_verify_db(item_fn)?;
 		ensure_valid_return_type(item_fn)?;
 		let (i, call_def) = parse_call_def(&item_fn)?;
 
@@ -333,6 +381,7 @@ impl BenchmarkDef {
 			fn_sig: item_fn.sig.clone(),
 			fn_vis: item_fn.vis.clone(),
 			fn_attrs: item_fn.attrs.clone(),
+			verify_db,
 		})
 	}
 }
@@ -363,6 +412,7 @@ pub fn benchmarks(
 	let mut benchmark_names: Vec<Ident> = Vec::new();
 	let mut extra_benchmark_names: Vec<Ident> = Vec::new();
 	let mut skip_meta_benchmark_names: Vec<Ident> = Vec::new();
+	let mut benchmark_tags: Vec<Vec<String>> = Vec::new();
 
 	let (_brace, mut content) =
 		module.content.ok_or(syn::Error::new(mod_span, "Module cannot be empty!"))?;
@@ -399,6 +449,9 @@ pub fn benchmarks(
 			} else if benchmark_attrs.skip_meta {
 				skip_meta_benchmark_names.push(name.clone());
 			}
+			benchmark_tags.push(benchmark_attrs.tags);
+		} else {
+			benchmark_tags.push(Vec::new());
 		}
 
 		// expand benchmark
@@ -429,6 +482,7 @@ pub fn benchmarks(
 		skip_meta_benchmark_names.iter().map(|n| n.to_string()).collect();
 	let mut selected_benchmark_mappings: Vec<TokenStream2> = Vec::new();
 	let mut benchmarks_by_name_mappings: Vec<TokenStream2> = Vec::new();
+	let mut benchmark_tags_mappings: Vec<TokenStream2> = Vec::new();
 	let test_idents: Vec<Ident> = benchmark_names_str
 		.iter()
 		.map(|n| Ident::new(format!("test_{}", n).as_str(), Span::call_site()))
@@ -438,7 +492,12 @@ pub fn benchmarks(
 		let name_str = &benchmark_names_str[i];
 		let test_ident = &test_idents[i];
 		selected_benchmark_mappings.push(quote!(#name_str => SelectedBenchmark::#name_ident));
-		benchmarks_by_name_mappings.push(quote!(#name_str => Self::#test_ident()))
+		benchmarks_by_name_mappings.push(quote!(#name_str => Self::#test_ident()));
+		let tags = &benchmark_tags[i];
+		benchmark_tags_mappings.push(quote!(#name_str => #krate::__private::vec![
+			#(#tags.as_bytes().to_vec()),
+			*
+		]));
 	}
 
 	// emit final quoted tokens
@@ -486,6 +545,22 @@ pub fn benchmarks(
 						*
 					}
 				}
+
+				fn expected_read_write_count(
+					&self,
+					components: &[(#krate::BenchmarkParameter, u32)],
+				) -> Result<Option<(u32, u32)>, #krate::BenchmarkError> {
+					match self {
+						#(
+							Self::#benchmark_names => {
+								<#benchmark_names as #krate::BenchmarkingSetup<
+									#type_use_generics
+								>>::expected_read_write_count(&#benchmark_names, components)
+							}
+						)
+						*
+					}
+				}
 			}
 			#[cfg(any(feature = "runtime-benchmarks", test))]
 			impl<#type_impl_generics> #krate::Benchmarking for Pallet<#type_use_generics>
@@ -518,6 +593,11 @@ pub fn benchmarks(
 							// TODO: Not supported by V2 syntax as of yet.
 							// https://github.com/paritytech/substrate/issues/13132
 							pov_modes: #krate::__private::vec![],
+							tags: match benchmark {
+								#(#benchmark_tags_mappings),
+								*,
+								_ => panic!("all benchmarks should be selectable")
+							},
 						}
 					}).collect::<#krate::__private::Vec<_>>()
 				}
@@ -624,6 +704,29 @@ pub fn benchmarks(
 							"Read/Write Count {:?}", read_write_count
 						);
 
+						// Check the counts declared with `#[verify_db]`. The verification run has
+						// no DB tracking, so only the measured runs can be checked.
+						if !verify {
+							let expected = <
+								SelectedBenchmark as #krate::BenchmarkingSetup<#type_use_generics>
+							>::expected_read_write_count(&selected_benchmark, c)?;
+							if let Some((reads, writes)) = expected {
+								if (read_write_count.0, read_write_count.2) != (reads, writes) {
+									#krate::__private::log::error!(
+										target: "benchmark",
+										"{} ({:?}): expected {} reads and {} writes, but recorded {} reads and {} writes",
+										extrinsic,
+										c,
+										reads,
+										writes,
+										read_write_count.0,
+										read_write_count.2,
+									);
+									return Err("Recorded DB reads/writes differ from `#[verify_db]`".into());
+								}
+							}
+						}
+
 						// Time the storage root recalculation.
 						let start_storage_root = #krate::benchmarking::current_time();
 						#krate::__private::storage_root(#krate::__private::StateVersion::V1);
@@ -733,6 +836,7 @@ fn expand_benchmark(
 	let setup_stmts = benchmark_def.setup_stmts;
 	let verify_stmts = benchmark_def.verify_stmts;
 	let last_stmt = benchmark_def.last_stmt;
+	let verify_db = benchmark_def.verify_db;
 	let test_ident = Ident::new(format!("test_{}", name.to_string()).as_str(), Span::call_site());
 
 	// unroll params (prepare for quoting)
@@ -837,11 +941,11 @@ fn expand_benchmark(
 
 	let vis = benchmark_def.fn_vis;
 
-	// remove #[benchmark] attribute
-	let fn_attrs = benchmark_def
-		.fn_attrs
-		.iter()
-		.filter(|attr| !attr.path().is_ident(keywords::BENCHMARK_TOKEN));
+	// remove #[benchmark] and #[verify_db] attributes
+	let fn_attrs = benchmark_def.fn_attrs.iter().filter(|attr| {
+		!attr.path().is_ident(keywords::BENCHMARK_TOKEN) &&
+			!attr.path().is_ident(keywords::VERIFY_DB_TOKEN)
+	});
 
 	// modify signature generics, ident, and inputs, e.g:
 	// before: `fn bench(u: Linear<1, 100>) -> Result<(), BenchmarkError>`
@@ -866,6 +970,25 @@ fn expand_benchmark(
 	};
 	let fn_attrs_clone = fn_attrs.clone();
 
+	// used in expected_read_write_count() impl
+	let impl_expected_read_write_count = match verify_db {
+		Some(VerifyDbDef { reads, writes, .. }) => quote! {
+			fn expected_read_write_count(
+				&self,
+				components: &[(#krate::BenchmarkParameter, u32)],
+			) -> Result<Option<(u32, u32)>, #krate::BenchmarkError> {
+				#(
+					let #param_names = components.iter()
+						.find(|&c| c.0 == #krate::BenchmarkParameter::#param_names)
+						.ok_or("Missing a component of `#[verify_db]`")?
+						.1;
+				)*
+				Ok(Some((#reads, #writes)))
+			}
+		},
+		None => quote!(),
+	};
+
 	let fn_def = quote! {
 		#(
 			#fn_attrs_clone
@@ -934,6 +1057,8 @@ fn expand_benchmark(
 					#impl_last_stmt
 				}))
 			}
+
+			#impl_expected_read_write_count
 		}
 
 		#[cfg(test)]
@@ -963,9 +1088,74 @@ fn expand_benchmark(
 					// Run execution + verification
 					closure_to_verify()
 				};
+				let verify_db = |
+					c: &[(#krate::BenchmarkParameter, u32)]
+				| -> Result<(), #krate::BenchmarkError> {
+					let expected = <
+						SelectedBenchmark as #krate::BenchmarkingSetup<T, _>
+					>::expected_read_write_count(&selected_benchmark, c)?;
+					let Some((reads, writes)) = expected else {
+						return Ok(());
+					};
+
+					// Always reset the state after the benchmark.
+					#krate::__private::defer!(#krate::benchmarking::wipe_db());
+
+					// Set up the benchmark without verification, which would be counted as well.
+					let closure_to_benchmark = <
+						SelectedBenchmark as #krate::BenchmarkingSetup<T, _>
+					>::instance(&selected_benchmark, c, false)?;
+
+					// Set the block number to at least 1 so events are deposited.
+					if #krate::__private::Zero::is_zero(&#frame_system::Pallet::<T>::block_number()) {
+						#frame_system::Pallet::<T>::set_block_number(1u32.into());
+					}
+
+					// Whitelist the same keys as a runtime does by default.
+					let mut whitelist = <
+						#frame_system::Pallet<T> as #krate::__private::traits::WhitelistedStorageKeys
+					>::whitelisted_storage_keys();
+					let whitelisted_caller_key = <#frame_system::Account<
+						T,
+					> as #krate::__private::storage::StorageMap<_, _,>>::hashed_key_for(
+						#krate::whitelisted_caller::<T::AccountId>()
+					);
+					whitelist.push(whitelisted_caller_key.into());
+					whitelist.push(#krate::__private::TrackedStorageKey::new(
+						#krate::__private::storage::transactional::TRANSACTION_LEVEL_KEY.into(),
+					));
+					whitelist.push(#krate::__private::TrackedStorageKey::new(
+						#krate::__private::well_known_keys::EXTRINSIC_INDEX.into()
+					));
+					whitelist.push(#krate::__private::TrackedStorageKey::new(
+						#krate::__private::well_known_keys::INTRABLOCK_ENTROPY.into()
+					));
+					#krate::benchmarking::set_whitelist(whitelist);
+
+					// Reset the read/write counter so we don't count operations in the setup process.
+					#krate::benchmarking::reset_read_write_count();
+					closure_to_benchmark()?;
+					let read_write_count = #krate::benchmarking::read_write_count();
+
+					if (read_write_count.0, read_write_count.2) != (reads, writes) {
+						#krate::__private::log::error!(
+							target: "benchmark",
+							"{} ({:?}): expected {} reads and {} writes, but recorded {} reads and {} writes",
+							stringify!(#name),
+							c,
+							reads,
+							writes,
+							read_write_count.0,
+							read_write_count.2,
+						);
+						return Err("Recorded DB reads/writes differ from `#[verify_db]`".into());
+					}
+					Ok(())
+				};
 
 				if components.is_empty() {
 					execute_benchmark(Default::default())?;
+					verify_db(&[])?;
 				} else {
 					let num_values: u32 = if let Ok(ev) = std::env::var("VALUES_PER_COMPONENT") {
 						ev.parse().map_err(|_| {
@@ -1012,6 +1202,7 @@ fn expand_benchmark(
 								)
 								.collect();
 
+							verify_db(&c)?;
 							execute_benchmark(c)?;
 						}
 					}
@@ -1308,6 +1308,10 @@ pub fn storage(_: TokenStream, _: TokenStream) -> TokenStream {
 /// The optional attribute `#[pallet::getter(fn $my_getter_fn_name)]` allows you to define a
 /// getter function on `Pallet`.
 ///
+/// With `#[pallet::getter(fn $my_getter_fn_name, len)]` a `$my_getter_fn_name_len` function is
+/// defined as well, returning the length of the value decoded without reading its items. The
+/// value must implement `StorageDecodeLength`, like `BoundedVec` and `WeakBoundedVec` do.
+///
 /// Also see [`pallet::storage`](`macro@storage`)
 #[proc_macro_attribute]
 pub fn getter(_: TokenStream, _: TokenStream) -> TokenStream {
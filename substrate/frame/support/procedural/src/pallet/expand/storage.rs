@@ -627,6 +627,103 @@ pub fn expand_storages(def: &mut Def) -> proc_macro2::TokenStream {
 		}
 	});
 
+	let len_getters = def.storages.iter().map(|storage| {
+		let Some(len_getter) = &storage.len_getter else { return Default::default() };
+		let completed_where_clause =
+			super::merge_where_clauses(&[&storage.where_clause, &def.config.where_clause]);
+
+		let ident = &storage.ident;
+		let gen = &def.type_use_generics(storage.attr_span);
+		let type_impl_gen = &def.type_impl_generics(storage.attr_span);
+		let type_use_gen = &def.type_use_generics(storage.attr_span);
+		let full_ident = quote::quote_spanned!(storage.attr_span => #ident<#gen> );
+
+		let cfg_attrs = &storage.cfg_attrs;
+
+		let len_getter_doc_line = if matches!(storage.vis, syn::Visibility::Public(_)) {
+			format!(
+				"An auto-generated getter of the length of [`{}`], decoded without reading the \
+				items.",
+				storage.ident
+			)
+		} else {
+			format!(
+				"An auto-generated getter of the length of `{}`, decoded without reading the items.",
+				storage.ident
+			)
+		};
+
+		let len_getter_fn = match &storage.metadata {
+			Metadata::Value { value } => quote::quote_spanned!(storage.attr_span =>
+				pub fn #len_getter() -> Option<usize> {
+					<
+						#full_ident as #frame_support::storage::StorageValue<#value>
+					>::decode_len()
+				}
+			),
+			Metadata::Map { key, value } => quote::quote_spanned!(storage.attr_span =>
+				pub fn #len_getter<KArg>(k: KArg) -> Option<usize> where
+					KArg: #frame_support::__private::codec::EncodeLike<#key>,
+				{
+					<
+						#full_ident as #frame_support::storage::StorageMap<#key, #value>
+					>::decode_len(k)
+				}
+			),
+			Metadata::CountedMap { key, .. } => quote::quote_spanned!(storage.attr_span =>
+				pub fn #len_getter<KArg>(k: KArg) -> Option<usize> where
+					KArg: #frame_support::__private::codec::EncodeLike<#key>,
+				{
+					<#full_ident>::decode_len(k)
+				}
+			),
+			Metadata::DoubleMap { key1, key2, value } => quote::quote_spanned!(storage.attr_span =>
+				pub fn #len_getter<KArg1, KArg2>(k1: KArg1, k2: KArg2) -> Option<usize> where
+					KArg1: #frame_support::__private::codec::EncodeLike<#key1>,
+					KArg2: #frame_support::__private::codec::EncodeLike<#key2>,
+				{
+					<
+						#full_ident as
+						#frame_support::storage::StorageDoubleMap<#key1, #key2, #value>
+					>::decode_len(k1, k2)
+				}
+			),
+			Metadata::NMap { keygen, value, .. } => quote::quote_spanned!(storage.attr_span =>
+				pub fn #len_getter<KArg>(key: KArg) -> Option<usize>
+				where
+					KArg: #frame_support::storage::types::EncodeLikeTuple<
+						<#keygen as #frame_support::storage::types::KeyGenerator>::KArg
+					>
+						+ #frame_support::storage::types::TupleToEncodedIter,
+				{
+					<
+						#full_ident as
+						#frame_support::storage::StorageNMap<#keygen, #value>
+					>::decode_len(key)
+				}
+			),
+			Metadata::CountedNMap { keygen, .. } => quote::quote_spanned!(storage.attr_span =>
+				pub fn #len_getter<KArg>(key: KArg) -> Option<usize>
+				where
+					KArg: #frame_support::storage::types::EncodeLikeTuple<
+						<#keygen as #frame_support::storage::types::KeyGenerator>::KArg
+					>
+						+ #frame_support::storage::types::TupleToEncodedIter,
+				{
+					<#full_ident>::decode_len(key)
+				}
+			),
+		};
+
+		quote::quote_spanned!(storage.attr_span =>
+			#(#cfg_attrs)*
+			impl<#type_impl_gen> #pallet_ident<#type_use_gen> #completed_where_clause {
+				#[doc = #len_getter_doc_line]
+				#len_getter_fn
+			}
+		)
+	});
+
 	let prefix_structs = def.storages.iter().map(|storage_def| {
 		let type_impl_gen = &def.type_impl_generics(storage_def.attr_span);
 		let type_use_gen = &def.type_use_generics(storage_def.attr_span);
@@ -908,6 +1005,7 @@ pub fn expand_storages(def: &mut Def) -> proc_macro2::TokenStream {
 		}
 
 		#( #getters )*
+		#( #len_getters )*
 		#( #prefix_structs )*
 		#( #on_empty_structs )*
 
@@ -26,6 +26,7 @@ mod keyword {
 	syn::custom_keyword!(Error);
 	syn::custom_keyword!(pallet);
 	syn::custom_keyword!(getter);
+	syn::custom_keyword!(len);
 	syn::custom_keyword!(storage_prefix);
 	syn::custom_keyword!(unbounded);
 	syn::custom_keyword!(whitelist_storage);
@@ -35,12 +36,12 @@ mod keyword {
 }
 
 /// Parse for one of the following:
-/// * `#[pallet::getter(fn dummy)]`
+/// * `#[pallet::getter(fn dummy)]` or `#[pallet::getter(fn dummy, len)]`
 /// * `#[pallet::storage_prefix = "CustomName"]`
 /// * `#[pallet::unbounded]`
 /// * `#[pallet::whitelist_storage]
 pub enum PalletStorageAttr {
-	Getter(syn::Ident, proc_macro2::Span),
+	Getter(syn::Ident, bool, proc_macro2::Span),
 	StorageName(syn::LitStr, proc_macro2::Span),
 	Unbounded(proc_macro2::Span),
 	WhitelistStorage(proc_macro2::Span),
@@ -49,7 +50,7 @@ pub enum PalletStorageAttr {
 impl PalletStorageAttr {
 	fn attr_span(&self) -> proc_macro2::Span {
 		match self {
-			Self::Getter(_, span) |
+			Self::Getter(_, _, span) |
 			Self::StorageName(_, span) |
 			Self::Unbounded(span) |
 			Self::WhitelistStorage(span) => *span,
@@ -73,7 +74,15 @@ impl syn::parse::Parse for PalletStorageAttr {
 			let generate_content;
 			syn::parenthesized!(generate_content in content);
 			generate_content.parse::<syn::Token![fn]>()?;
-			Ok(Self::Getter(generate_content.parse::<syn::Ident>()?, attr_span))
+			let getter = generate_content.parse::<syn::Ident>()?;
+			let with_len = if generate_content.peek(syn::Token![,]) {
+				generate_content.parse::<syn::Token![,]>()?;
+				generate_content.parse::<keyword::len>()?;
+				true
+			} else {
+				false
+			};
+			Ok(Self::Getter(getter, with_len, attr_span))
 		} else if lookahead.peek(keyword::storage_prefix) {
 			content.parse::<keyword::storage_prefix>()?;
 			content.parse::<syn::Token![=]>()?;
@@ -101,6 +110,7 @@ impl syn::parse::Parse for PalletStorageAttr {
 
 struct PalletStorageAttrInfo {
 	getter: Option<syn::Ident>,
+	len_getter: Option<syn::Ident>,
 	rename_as: Option<syn::LitStr>,
 	unbounded: bool,
 	whitelisted: bool,
@@ -109,12 +119,16 @@ struct PalletStorageAttrInfo {
 impl PalletStorageAttrInfo {
 	fn from_attrs(attrs: Vec<PalletStorageAttr>) -> syn::Result<Self> {
 		let mut getter = None;
+		let mut len_getter = None;
 		let mut rename_as = None;
 		let mut unbounded = false;
 		let mut whitelisted = false;
 		for attr in attrs {
 			match attr {
-				PalletStorageAttr::Getter(ident, ..) if getter.is_none() => getter = Some(ident),
+				PalletStorageAttr::Getter(ident, with_len, ..) if getter.is_none() => {
+					len_getter = with_len.then(|| quote::format_ident!("{}_len", ident));
+					getter = Some(ident);
+				},
 				PalletStorageAttr::StorageName(name, ..) if rename_as.is_none() =>
 					rename_as = Some(name),
 				PalletStorageAttr::Unbounded(..) if !unbounded => unbounded = true,
@@ -127,7 +141,7 @@ impl PalletStorageAttrInfo {
 			}
 		}
 
-		Ok(PalletStorageAttrInfo { getter, rename_as, unbounded, whitelisted })
+		Ok(PalletStorageAttrInfo { getter, len_getter, rename_as, unbounded, whitelisted })
 	}
 }
 
@@ -165,6 +179,8 @@ pub struct StorageDef {
 	pub instances: Vec<helper::InstanceUsage>,
 	/// Optional getter to generate. If some then query_kind is ensured to be some as well.
 	pub getter: Option<syn::Ident>,
+	/// Optional getter of the decoded length of the value to generate, next to `getter`.
+	pub len_getter: Option<syn::Ident>,
 	/// Optional expression that evaluates to a type that can be used as StoragePrefix instead of
 	/// ident.
 	pub rename_as: Option<syn::LitStr>,
@@ -775,7 +791,7 @@ impl StorageDef {
 		};
 
 		let attrs: Vec<PalletStorageAttr> = helper::take_item_pallet_attrs(&mut item.attrs)?;
-		let PalletStorageAttrInfo { getter, rename_as, mut unbounded, whitelisted } =
+		let PalletStorageAttrInfo { getter, len_getter, rename_as, mut unbounded, whitelisted } =
 			PalletStorageAttrInfo::from_attrs(attrs)?;
 
 		// set all storages to be unbounded if dev_mode is enabled
@@ -914,6 +930,7 @@ impl StorageDef {
 			metadata,
 			docs,
 			getter,
+			len_getter,
 			rename_as,
 			query_kind,
 			where_clause,
@@ -0,0 +1,413 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Content identifiers, as used by IPFS to address content.
+//!
+//! A [`Cid`] is made of a [`Version`], the multicodec code of the content and the [`Multihash`] of
+//! the content. Both CIDv0 and CIDv1 are supported, in their binary and textual representations.
+//! No hashing is done: the digest is provided by the caller and only its size is checked against
+//! the hash function, for the hash functions of [`multicodec`].
+
+use crate::{traits::ConstU32, BoundedVec};
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::{convert::TryInto, prelude::*};
+
+/// Codes of the multicodec table used by [`Cid`] and [`Multihash`].
+///
+/// See <https://github.com/multiformats/multicodec/blob/master/table.csv>.
+pub mod multicodec {
+	/// Raw binary content.
+	pub const RAW: u64 = 0x55;
+	/// MerkleDAG protobuf, the codec of CIDv0.
+	pub const DAG_PB: u64 = 0x70;
+	/// MerkleDAG CBOR.
+	pub const DAG_CBOR: u64 = 0x71;
+	/// Libp2p public key.
+	pub const LIBP2P_KEY: u64 = 0x72;
+	/// MerkleDAG JSON.
+	pub const DAG_JSON: u64 = 0x0129;
+
+	/// The content itself instead of a digest.
+	pub const IDENTITY: u64 = 0x00;
+	/// SHA2-256, the hash function of CIDv0.
+	pub const SHA2_256: u64 = 0x12;
+	/// SHA2-512.
+	pub const SHA2_512: u64 = 0x13;
+	/// SHA3-256.
+	pub const SHA3_256: u64 = 0x16;
+	/// Keccak-256.
+	pub const KECCAK_256: u64 = 0x1b;
+	/// BLAKE3, with its default 32 bytes output.
+	pub const BLAKE3: u64 = 0x1e;
+	/// BLAKE2b-256.
+	pub const BLAKE2B_256: u64 = 0xb220;
+}
+
+/// The maximum size of a digest.
+pub const MAX_DIGEST_SIZE: usize = 64;
+
+/// Errors of the construction, validation and decoding of a [`Cid`].
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum CidError {
+	/// The size of the digest doesn't match its hash function.
+	InvalidDigestSize,
+	/// The digest is larger than [`MAX_DIGEST_SIZE`].
+	DigestTooLarge,
+	/// A CIDv0 must be the DAG-PB SHA2-256 digest of the content.
+	InvalidV0,
+	/// The bytes are not a binary CID.
+	InvalidBytes,
+	/// The version of the CID is not supported.
+	UnknownVersion,
+}
+
+/// A Multihash instance that only supports the basic functionality and no hashing.
+#[derive(
+	Clone, PartialEq, Eq, PartialOrd, Ord, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen,
+)]
+pub struct Multihash {
+	/// The code of the Multihash.
+	pub code: u64,
+	/// The digest.
+	pub digest: BoundedVec<u8, ConstU32<68>>, // 4 byte dig size + 64 bytes hash digest
+}
+
+impl Multihash {
+	/// Creates a new multihash of the hash function `code`, checking the size of `digest`.
+	pub fn new(code: u64, digest: impl Into<Vec<u8>>) -> Result<Self, CidError> {
+		let digest: Vec<u8> = digest.into();
+		if digest.len() > MAX_DIGEST_SIZE {
+			return Err(CidError::DigestTooLarge)
+		}
+		let hash = Self { code, digest: digest.try_into().map_err(|_| CidError::DigestTooLarge)? };
+		hash.validate()?;
+		Ok(hash)
+	}
+
+	/// Returns the size of the digest.
+	pub fn size(&self) -> usize {
+		self.digest.len()
+	}
+
+	/// Checks the size of the digest against the hash function.
+	///
+	/// The digest of an unknown hash function only has to fit in [`MAX_DIGEST_SIZE`].
+	pub fn validate(&self) -> Result<(), CidError> {
+		use multicodec::*;
+
+		if self.size() > MAX_DIGEST_SIZE {
+			return Err(CidError::DigestTooLarge)
+		}
+		let expected = match self.code {
+			SHA2_256 | SHA3_256 | KECCAK_256 | BLAKE3 | BLAKE2B_256 => 32,
+			SHA2_512 => 64,
+			_ => return Ok(()),
+		};
+		if self.size() != expected {
+			return Err(CidError::InvalidDigestSize)
+		}
+		Ok(())
+	}
+
+	/// Returns the binary representation of the multihash.
+	pub fn to_bytes(&self) -> Vec<u8> {
+		let mut bytes = Vec::new();
+		push_varint(&mut bytes, self.code);
+		push_varint(&mut bytes, self.size() as u64);
+		bytes.extend_from_slice(&self.digest);
+		bytes
+	}
+
+	/// Reads a multihash from the start of `input`, advancing it.
+	fn read(input: &mut &[u8]) -> Result<Self, CidError> {
+		let code = read_varint(input)?;
+		let size = read_varint(input)? as usize;
+		if size > MAX_DIGEST_SIZE {
+			return Err(CidError::DigestTooLarge)
+		}
+		if input.len() < size {
+			return Err(CidError::InvalidBytes)
+		}
+		let (digest, rest) = input.split_at(size);
+		*input = rest;
+		Self::new(code, digest)
+	}
+}
+
+/// The version of the CID.
+#[derive(
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	PartialOrd,
+	Ord,
+	RuntimeDebug,
+	Encode,
+	Decode,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub enum Version {
+	/// CID version 0.
+	V0,
+	/// CID version 1.
+	V1,
+}
+
+/// Representation of a CID.
+#[derive(
+	Clone, PartialEq, Eq, PartialOrd, Ord, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen,
+)]
+pub struct Cid {
+	/// The version of CID.
+	pub version: Version,
+	/// The codec of CID.
+	pub codec: u64,
+	/// The multihash of CID.
+	pub hash: Multihash,
+}
+
+impl Cid {
+	/// Creates a new CIDv0.
+	///
+	/// Panics if the digest is not 32 bytes long.
+	pub fn new_v0(sha2_256_digest: impl Into<Vec<u8>>) -> Self {
+		let digest = sha2_256_digest.into();
+		assert_eq!(digest.len(), 32);
+
+		Self {
+			version: Version::V0,
+			codec: multicodec::DAG_PB,
+			hash: Multihash {
+				code: multicodec::SHA2_256,
+				digest: digest.try_into().expect("32 bytes fit in the bound; qed"),
+			},
+		}
+	}
+
+	/// Creates a new CIDv1 of content of the multicodec `codec`.
+	pub fn new_v1(codec: u64, hash: Multihash) -> Result<Self, CidError> {
+		let cid = Self { version: Version::V1, codec, hash };
+		cid.validate()?;
+		Ok(cid)
+	}
+
+	/// Checks that the CID is well formed.
+	///
+	/// Decoded CIDs are not validated, so this should be called on the CIDs passed to
+	/// dispatchables.
+	pub fn validate(&self) -> Result<(), CidError> {
+		self.hash.validate()?;
+		if self.version == Version::V0 &&
+			(self.codec != multicodec::DAG_PB || self.hash.code != multicodec::SHA2_256)
+		{
+			return Err(CidError::InvalidV0)
+		}
+		Ok(())
+	}
+
+	/// Returns the binary representation of the CID.
+	pub fn to_bytes(&self) -> Vec<u8> {
+		match self.version {
+			Version::V0 => self.hash.to_bytes(),
+			Version::V1 => {
+				let mut bytes = Vec::new();
+				push_varint(&mut bytes, 1);
+				push_varint(&mut bytes, self.codec);
+				bytes.extend(self.hash.to_bytes());
+				bytes
+			},
+		}
+	}
+
+	/// Reads a CID from its binary representation.
+	pub fn from_bytes(mut bytes: &[u8]) -> Result<Self, CidError> {
+		// A CIDv0 is a bare SHA2-256 multihash.
+		if bytes.len() == 34 && bytes[0] == 0x12 && bytes[1] == 0x20 {
+			return Ok(Self::new_v0(&bytes[2..]))
+		}
+
+		if read_varint(&mut bytes)? != 1 {
+			return Err(CidError::UnknownVersion)
+		}
+		let codec = read_varint(&mut bytes)?;
+		let hash = Multihash::read(&mut bytes)?;
+		if !bytes.is_empty() {
+			return Err(CidError::InvalidBytes)
+		}
+		Self::new_v1(codec, hash)
+	}
+
+	/// Returns the textual representation of the CID, as used in IPFS paths.
+	///
+	/// CIDv0 is encoded in base58btc, CIDv1 in lower case base32 with the `b` multibase prefix.
+	pub fn to_text(&self) -> Vec<u8> {
+		match self.version {
+			Version::V0 => base58btc(&self.to_bytes()),
+			Version::V1 => {
+				let mut text = vec![b'b'];
+				text.extend(base32_lower(&self.to_bytes()));
+				text
+			},
+		}
+	}
+}
+
+/// Appends `value` as unsigned varint.
+fn push_varint(out: &mut Vec<u8>, mut value: u64) {
+	while value >= 0x80 {
+		out.push((value as u8 & 0x7f) | 0x80);
+		value >>= 7;
+	}
+	out.push(value as u8);
+}
+
+/// Reads an unsigned varint from the start of `input`, advancing it.
+fn read_varint(input: &mut &[u8]) -> Result<u64, CidError> {
+	let mut value = 0u64;
+	// A `u64` takes at most 10 bytes.
+	for (i, byte) in input.iter().take(10).enumerate() {
+		value |= ((byte & 0x7f) as u64).checked_shl(7 * i as u32).ok_or(CidError::InvalidBytes)?;
+		if byte & 0x80 == 0 {
+			*input = &input[i + 1..];
+			return Ok(value)
+		}
+	}
+	Err(CidError::InvalidBytes)
+}
+
+fn base58btc(input: &[u8]) -> Vec<u8> {
+	const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+	// Base58 digits, least significant first.
+	let mut digits: Vec<u8> = Vec::new();
+	for byte in input {
+		let mut carry = *byte as u32;
+		for digit in digits.iter_mut() {
+			carry += (*digit as u32) << 8;
+			*digit = (carry % 58) as u8;
+			carry /= 58;
+		}
+		while carry > 0 {
+			digits.push((carry % 58) as u8);
+			carry /= 58;
+		}
+	}
+	let leading_zeros = input.iter().take_while(|byte| **byte == 0).count();
+	sp_std::iter::repeat(b'1')
+		.take(leading_zeros)
+		.chain(digits.iter().rev().map(|digit| ALPHABET[*digit as usize]))
+		.collect()
+}
+
+fn base32_lower(input: &[u8]) -> Vec<u8> {
+	const ALPHABET: &[u8; 32] = b"abcdefghijklmnopqrstuvwxyz234567";
+
+	let mut out = Vec::new();
+	let (mut buffer, mut bits) = (0u32, 0u32);
+	for byte in input {
+		buffer = (buffer << 8) | *byte as u32;
+		bits += 8;
+		while bits >= 5 {
+			bits -= 5;
+			out.push(ALPHABET[((buffer >> bits) & 0x1f) as usize]);
+		}
+		buffer &= (1 << bits) - 1;
+	}
+	if bits > 0 {
+		out.push(ALPHABET[((buffer << (5 - bits)) & 0x1f) as usize]);
+	}
+	out
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn hello_world_v1() -> Cid {
+		let hash = Multihash::new(
+			multicodec::SHA2_256,
+			sp_crypto_hashing::sha2_256(b"hello world").to_vec(),
+		)
+		.unwrap();
+		Cid::new_v1(multicodec::RAW, hash).unwrap()
+	}
+
+	#[test]
+	fn to_text_works() {
+		let v0 = Cid::new_v0(array_bytes::hex2bytes_unchecked(
+			"59948439065f29619ef41280cbb932be52c56d99c5966b65e0111239f098bbef",
+		));
+		assert_eq!(v0.to_text(), b"QmUNLLsPACCz1vLxQVkXqqLX5R1X345qqfHbsf67hvA3Nn".to_vec());
+
+		assert_eq!(
+			hello_world_v1().to_text(),
+			b"bafkreifzjut3te2nhyekklss27nh3k72ysco7y32koao5eei66wof36n5e".to_vec()
+		);
+	}
+
+	#[test]
+	fn bytes_roundtrip() {
+		let v0 = Cid::new_v0([7u8; 32]);
+		assert_eq!(v0.to_bytes().len(), 34);
+		assert_eq!(Cid::from_bytes(&v0.to_bytes()), Ok(v0));
+
+		let v1 = hello_world_v1();
+		assert_eq!(&v1.to_bytes()[..4], &[0x01, 0x55, 0x12, 0x20]);
+		assert_eq!(Cid::from_bytes(&v1.to_bytes()), Ok(v1.clone()));
+
+		// Multi-byte varint codes.
+		let blake2 = Multihash::new(multicodec::BLAKE2B_256, [1u8; 32]).unwrap();
+		let cid = Cid::new_v1(multicodec::DAG_JSON, blake2).unwrap();
+		assert_eq!(Cid::from_bytes(&cid.to_bytes()), Ok(cid));
+
+		let mut trailing = v1.to_bytes();
+		trailing.push(0);
+		assert_eq!(Cid::from_bytes(&trailing), Err(CidError::InvalidBytes));
+		assert_eq!(Cid::from_bytes(&v1.to_bytes()[..10]), Err(CidError::InvalidBytes));
+		assert_eq!(Cid::from_bytes(&[0x02, 0x55, 0x00, 0x00]), Err(CidError::UnknownVersion));
+		assert_eq!(Cid::from_bytes(&[0xff; 12]), Err(CidError::InvalidBytes));
+	}
+
+	#[test]
+	fn validation_works() {
+		assert_eq!(
+			Multihash::new(multicodec::SHA2_256, [0u8; 31]),
+			Err(CidError::InvalidDigestSize)
+		);
+		assert_eq!(
+			Multihash::new(multicodec::SHA2_512, [0u8; 32]),
+			Err(CidError::InvalidDigestSize)
+		);
+		assert!(Multihash::new(multicodec::SHA2_512, [0u8; 64]).is_ok());
+		assert!(Multihash::new(multicodec::IDENTITY, b"inline".to_vec()).is_ok());
+		assert_eq!(Multihash::new(multicodec::IDENTITY, [0u8; 65]), Err(CidError::DigestTooLarge));
+
+		// Decoded values are not validated.
+		let mut v0 = Cid::new_v0([0u8; 32]);
+		assert!(v0.validate().is_ok());
+		v0.codec = multicodec::RAW;
+		assert_eq!(v0.validate(), Err(CidError::InvalidV0));
+
+		let mut v1 = hello_world_v1();
+		v1.hash.digest.truncate(20);
+		assert_eq!(v1.validate(), Err(CidError::InvalidDigestSize));
+	}
+}
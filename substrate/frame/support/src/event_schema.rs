@@ -0,0 +1,159 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Versioned event schemas.
+//!
+//! Off-chain indexers decode events with the layout of the runtime they were built for, so an
+//! event gaining a field or moving to another index silently breaks them. Pallets can opt in to
+//! versioning the layout of their events:
+//!
+//! 1. Expose the version in the metadata, as the `EventSchemaVersion` constant:
+//!
+//! ```ignore
+//! #[pallet::extra_constants]
+//! impl<T: Config> Pallet<T> {
+//! 	/// The version of the layout of the events of this pallet.
+//! 	#[pallet::constant_name(EventSchemaVersion)]
+//! 	pub fn event_schema_version() -> u32 {
+//! 		1
+//! 	}
+//! }
+//! ```
+//!
+//! 2. Record the layout of every version in the tests of the pallet with
+//!    [`assert_event_schema`](crate::assert_event_schema). The test fails when the layout changes
+//!    until the version is increased and the new layout is recorded next to the previous ones.
+//!
+//! Indexers can then compare the `EventSchemaVersion` constants of the pallets with the versions
+//! they know how to decode.
+
+use scale_info::{form::MetaForm, Field, TypeDef, TypeInfo};
+
+/// The layout of the enum `E`, one line per variant ordered by index.
+///
+/// A line holds the index of the variant, its name and the names and types of its fields, e.g.
+/// `3: NewAccount { account: T::AccountId }`. Types are given as written in the definition of `E`
+/// with whitespace removed, so that the layout doesn't depend on the runtime configuration.
+///
+/// Returns no lines if `E` is not an enum.
+pub fn layout<E: TypeInfo + 'static>() -> Vec<String> {
+	let TypeDef::Variant(def) = E::type_info().type_def else { return Vec::new() };
+	let mut variants = def.variants;
+	variants.sort_by_key(|variant| variant.index);
+	variants
+		.into_iter()
+		.map(|variant| format!("{}: {}{}", variant.index, variant.name, fields(&variant.fields)))
+		.collect()
+}
+
+/// Assert that `E` has the layout recorded for `version`, the last of the `recorded` versions.
+///
+/// See [`assert_event_schema`](crate::assert_event_schema).
+pub fn assert_layout<E: TypeInfo + 'static>(version: u32, recorded: &[(u32, &[&str])]) {
+	assert!(
+		recorded.windows(2).all(|pair| pair[0].0 < pair[1].0),
+		"event schema versions must be recorded in increasing order"
+	);
+	let Some((last, expected)) = recorded.last() else {
+		panic!("no event schema recorded for version {}", version)
+	};
+	assert_eq!(
+		*last, version,
+		"the event schema of the last recorded version must be the one of the current version"
+	);
+
+	let actual = layout::<E>();
+	assert!(
+		actual.iter().map(String::as_str).eq(expected.iter().copied()),
+		"the layout of the events changed, increase the event schema version and record the new \
+		 layout:\n{:#?}",
+		actual,
+	);
+}
+
+/// The fields of a variant, as appended to its name.
+fn fields(fields: &[Field<MetaForm>]) -> String {
+	if fields.is_empty() {
+		return String::new()
+	}
+
+	let type_name = |field: &Field<MetaForm>| -> String {
+		field
+			.type_name
+			.unwrap_or_default()
+			.chars()
+			.filter(|c| !c.is_whitespace())
+			.collect()
+	};
+	if fields.iter().all(|field| field.name.is_some()) {
+		let fields = fields
+			.iter()
+			.map(|field| format!("{}: {}", field.name.unwrap_or_default(), type_name(field)))
+			.collect::<Vec<_>>();
+		format!(" {{ {} }}", fields.join(", "))
+	} else {
+		format!("({})", fields.iter().map(type_name).collect::<Vec<_>>().join(", "))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use codec::{Decode, Encode};
+
+	#[derive(Encode, Decode, TypeInfo)]
+	#[allow(dead_code)]
+	enum Event {
+		Unit,
+		#[codec(index = 5)]
+		Named {
+			who: Vec<u8>,
+			amount: Option<(u32, u64)>,
+		},
+		Unnamed(u8, [u8; 32]),
+	}
+
+	const EVENT_V1: &[&str] = &["0: Unit", "1: Named { who: Vec<u8> }"];
+	const EVENT_V2: &[&str] = &[
+		"0: Unit",
+		"2: Unnamed(u8, [u8;32])",
+		"5: Named { who: Vec<u8>, amount: Option<(u32,u64)> }",
+	];
+
+	#[test]
+	fn layout_works() {
+		assert_eq!(layout::<Event>(), EVENT_V2);
+		assert!(layout::<u32>().is_empty());
+	}
+
+	#[test]
+	fn assert_layout_works() {
+		assert_layout::<Event>(2, &[(1, EVENT_V1), (2, EVENT_V2)]);
+	}
+
+	#[test]
+	#[should_panic(expected = "the layout of the events changed")]
+	fn assert_layout_detects_changed_layout() {
+		assert_layout::<Event>(1, &[(1, EVENT_V1)]);
+	}
+
+	#[test]
+	#[should_panic(expected = "must be the one of the current version")]
+	fn assert_layout_requires_current_version() {
+		assert_layout::<Event>(3, &[(1, EVENT_V1), (2, EVENT_V2)]);
+	}
+}
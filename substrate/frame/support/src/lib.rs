@@ -65,8 +65,11 @@ pub mod __private {
 
 #[macro_use]
 pub mod dispatch;
+pub mod cid;
 pub mod crypto;
 pub mod dispatch_context;
+#[cfg(feature = "std")]
+pub mod event_schema;
 mod hash;
 pub mod inherent;
 pub mod instances;
@@ -803,6 +806,27 @@ macro_rules! assert_ok {
 	};
 }
 
+/// Assert that the events `$event` have the layout recorded for the current event schema
+/// version `$version`.
+///
+/// Used as `assert_event_schema!(Event<Test>, current_version, { 1 => [..], 2 => [..] })`, with the
+/// layout of each version listed as returned by [`event_schema::layout`]. Versions are recorded in
+/// increasing order and the current version must be the last one.
+#[macro_export]
+#[cfg(feature = "std")]
+macro_rules! assert_event_schema {
+	(
+		$event:ty,
+		$version:expr,
+		{ $( $recorded:literal => [ $( $line:literal ),* $(,)? ] ),+ $(,)? } $(,)?
+	) => {
+		$crate::event_schema::assert_layout::<$event>(
+			$version,
+			&[ $( ($recorded, &[ $( $line ),* ] as &[&str]) ),+ ],
+		);
+	};
+}
+
 /// Assert that the maximum encoding size does not exceed the value defined in
 /// [`MAX_MODULE_ERROR_ENCODED_SIZE`] during compilation.
 ///
@@ -866,7 +890,8 @@ pub use macro_magic;
 pub mod testing_prelude {
 	pub use super::{
 		assert_err, assert_err_ignore_postinfo, assert_err_with_weight, assert_error_encoded_size,
-		assert_noop, assert_ok, assert_storage_noop, parameter_types, traits::Get,
+		assert_event_schema, assert_noop, assert_ok, assert_storage_noop, parameter_types,
+		traits::Get,
 	};
 	pub use sp_arithmetic::assert_eq_error_rate;
 	pub use sp_runtime::{bounded_btree_map, bounded_vec};
@@ -1453,6 +1478,11 @@ pub mod pallet_prelude {
 /// The optional attribute `#[pallet::getter(fn $my_getter_fn_name)]` allows you to define a
 /// getter function on `Pallet`.
 ///
+/// With `#[pallet::getter(fn $my_getter_fn_name, len)]` a `$my_getter_fn_name_len` function is
+/// defined as well, returning the length of the value decoded without reading its items. The
+/// value must implement [`StorageDecodeLength`](frame_support::storage::StorageDecodeLength),
+/// like `BoundedVec` and `WeakBoundedVec` do.
+///
 /// Also see [`pallet::getter`](`frame_support::pallet_macros::getter`)
 ///
 /// ## `#[pallet::storage_prefix = "SomeName"]` (optional)
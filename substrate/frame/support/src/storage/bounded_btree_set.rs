@@ -17,11 +17,16 @@
 
 //! Traits, types and structs to support a bounded `BTreeSet`.
 
-use frame_support::storage::StorageDecodeNonDedupLength;
+use codec::Decode;
+use frame_support::storage::{StorageDecodeItems, StorageDecodeNonDedupLength};
 pub use sp_runtime::BoundedBTreeSet;
 
 impl<T, S> StorageDecodeNonDedupLength for BoundedBTreeSet<T, S> {}
 
+impl<T: Decode, S> StorageDecodeItems for BoundedBTreeSet<T, S> {
+	type Item = T;
+}
+
 #[cfg(test)]
 pub mod test {
 	use super::*;
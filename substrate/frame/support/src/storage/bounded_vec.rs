@@ -19,13 +19,18 @@
 //! or a double map.
 
 use crate::{
-	storage::{StorageDecodeLength, StorageTryAppend},
+	storage::{StorageDecodeItems, StorageDecodeLength, StorageTryAppend},
 	traits::Get,
 };
+use codec::Decode;
 pub use sp_runtime::{BoundedSlice, BoundedVec};
 
 impl<T, S> StorageDecodeLength for BoundedVec<T, S> {}
 
+impl<T: Decode, S> StorageDecodeItems for BoundedVec<T, S> {
+	type Item = T;
+}
+
 impl<T, S: Get<u32>> StorageTryAppend<T> for BoundedVec<T, S> {
 	fn bound() -> usize {
 		S::get() as usize
@@ -36,6 +41,7 @@ impl<T, S: Get<u32>> StorageTryAppend<T> for BoundedVec<T, S> {
 pub mod test {
 	use super::*;
 	use crate::{traits::ConstU32, Twox128};
+	use codec::Encode;
 	use sp_io::TestExternalities;
 	use sp_runtime::bounded_vec;
 
@@ -49,6 +55,9 @@ pub mod test {
 	type FooDoubleMap =
 		StorageDoubleMap<Prefix, Twox128, u32, Twox128, u32, BoundedVec<u32, ConstU32<7>>>;
 
+	#[crate::storage_alias]
+	type Bar = StorageValue<Prefix, BoundedVec<[u8; 50], ConstU32<10>>>;
+
 	#[test]
 	fn decode_len_works() {
 		TestExternalities::default().execute_with(|| {
@@ -74,4 +83,58 @@ pub mod test {
 			assert!(FooDoubleMap::decode_len(2, 2).is_none());
 		});
 	}
+
+	#[test]
+	fn decode_contains_works() {
+		TestExternalities::default().execute_with(|| {
+			assert!(!Foo::decode_contains(&1));
+			let bounded: BoundedVec<u32, ConstU32<7>> = bounded_vec![1, 2, 3];
+			Foo::put(bounded);
+			assert!(Foo::decode_contains(&1));
+			assert!(Foo::decode_contains(&3));
+			assert!(!Foo::decode_contains(&4));
+			assert!(Foo::decode_any(|i| *i > 2));
+			assert!(!Foo::decode_any(|i| *i > 3));
+		});
+
+		TestExternalities::default().execute_with(|| {
+			let bounded: BoundedVec<u32, ConstU32<7>> = bounded_vec![1, 2, 3];
+			FooMap::insert(1, bounded);
+			assert!(FooMap::decode_contains(1, &2));
+			assert!(!FooMap::decode_contains(1, &4));
+			assert!(!FooMap::decode_contains(0, &2));
+		});
+	}
+
+	#[test]
+	fn decode_any_stops_at_first_match() {
+		TestExternalities::default().execute_with(|| {
+			let bounded: BoundedVec<u32, ConstU32<7>> = bounded_vec![1, 2, 3];
+			Foo::put(bounded);
+			let mut decoded = Vec::new();
+			assert!(Foo::decode_any(|i| {
+				decoded.push(*i);
+				*i == 2
+			}));
+			assert_eq!(decoded, vec![1, 2]);
+		});
+	}
+
+	#[test]
+	fn decode_contains_reads_items_across_chunks() {
+		TestExternalities::default().execute_with(|| {
+			let items = (0..10u8).map(|i| [i; 50]).collect::<Vec<_>>();
+			Bar::put(BoundedVec::<_, ConstU32<10>>::truncate_from(items));
+			assert!(Bar::decode_contains(&[2; 50]));
+			assert!(Bar::decode_contains(&[9; 50]));
+			assert!(!Bar::decode_contains(&[10; 50]));
+
+			// A value shorter than its encoded length is not decodable.
+			let mut truncated = Bar::get().unwrap().encode();
+			truncated.truncate(truncated.len() - 1);
+			crate::storage::unhashed::put_raw(&Bar::hashed_key(), &truncated);
+			assert!(!Bar::decode_contains(&[9; 50]));
+			assert!(Bar::decode_contains(&[8; 50]));
+		});
+	}
 }
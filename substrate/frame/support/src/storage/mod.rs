@@ -193,6 +193,32 @@ pub trait StorageValue<T: FullCodec> {
 	{
 		T::decode_non_dedup_len(&Self::hashed_key())
 	}
+
+	/// Whether any item of the storage value matches `f`, without decoding the entire value.
+	///
+	/// `T` is required to implement [`StorageDecodeItems`].
+	///
+	/// The items are decoded one after the other and the decoding stops at the first match. If
+	/// the value does not exist or fails to decode, `false` is returned.
+	fn decode_any(f: impl FnMut(&T::Item) -> bool) -> bool
+	where
+		T: StorageDecodeItems,
+	{
+		T::decode_any(&Self::hashed_key(), f)
+	}
+
+	/// Whether the storage value contains `item`, without decoding the entire value.
+	///
+	/// `T` is required to implement [`StorageDecodeItems`].
+	///
+	/// See [`Self::decode_any`].
+	fn decode_contains(item: &T::Item) -> bool
+	where
+		T: StorageDecodeItems,
+		T::Item: PartialEq,
+	{
+		Self::decode_any(|i| i == item)
+	}
 }
 
 /// A non-continuous container type.
@@ -393,6 +419,34 @@ pub trait StorageMap<K: FullEncode, V: FullCodec> {
 		V::decode_non_dedup_len(&Self::hashed_key_for(key))
 	}
 
+	/// Whether any item of the storage value under the given `key` matches `f`, without decoding
+	/// the entire value.
+	///
+	/// `V` is required to implement [`StorageDecodeItems`].
+	///
+	/// The items are decoded one after the other and the decoding stops at the first match. If
+	/// the value does not exist or fails to decode, `false` is returned.
+	fn decode_any<KeyArg: EncodeLike<K>>(key: KeyArg, f: impl FnMut(&V::Item) -> bool) -> bool
+	where
+		V: StorageDecodeItems,
+	{
+		V::decode_any(&Self::hashed_key_for(key), f)
+	}
+
+	/// Whether the storage value under the given `key` contains `item`, without decoding the
+	/// entire value.
+	///
+	/// `V` is required to implement [`StorageDecodeItems`].
+	///
+	/// See [`Self::decode_any`].
+	fn decode_contains<KeyArg: EncodeLike<K>>(key: KeyArg, item: &V::Item) -> bool
+	where
+		V: StorageDecodeItems,
+		V::Item: PartialEq,
+	{
+		Self::decode_any(key, |i| i == item)
+	}
+
 	/// Migrate an item with the given `key` from a defunct `OldHasher` to the current hasher.
 	///
 	/// If the key doesn't exist, then it's a no-op. If it does, then it returns its value.
@@ -1082,10 +1136,45 @@ impl<T, OnRemoval> PrefixIterator<T, OnRemoval> {
 	}
 }
 
-impl<T, OnRemoval: PrefixIteratorOnRemoval> Iterator for PrefixIterator<T, OnRemoval> {
-	type Item = T;
+/// The result of advancing a [`PrefixIterator`] by a bounded number of elements.
+///
+/// See [`PrefixIterator::next_bounded`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoundedIterResults<T> {
+	/// The elements that were iterated, or removed if the iterator was draining.
+	pub items: Vec<T>,
+	/// The raw key to resume from, or `None` if there are no elements left under the prefix.
+	///
+	/// Pass it back to the function that produced these results to continue where it stopped.
+	pub maybe_cursor: Option<Vec<u8>>,
+}
 
-	fn next(&mut self) -> Option<Self::Item> {
+impl<T, OnRemoval: PrefixIteratorOnRemoval> PrefixIterator<T, OnRemoval> {
+	/// Advance the iterator by at most `limit` keys.
+	///
+	/// The returned cursor is `Some` only if more keys remain under the prefix, in which case it
+	/// can be given to [`Self::set_last_raw_key`] on a fresh iterator to resume.
+	///
+	/// NOTE: keys whose value fails to decode are skipped (and removed if draining). They count
+	/// towards `limit`, so that a call reads at most `limit` values even if it returns fewer items.
+	pub fn next_bounded(&mut self, limit: u32) -> BoundedIterResults<T> {
+		let mut items = Vec::new();
+		let mut visited = 0;
+		while visited < limit {
+			let Some(maybe_item) = self.next_entry() else { break };
+			visited += 1;
+			items.extend(maybe_item);
+		}
+		let has_more = visited == limit &&
+			sp_io::storage::next_key(&self.previous_key)
+				.map_or(false, |next| next.starts_with(&self.prefix));
+		let maybe_cursor = has_more.then(|| self.previous_key.clone());
+
+		BoundedIterResults { items, maybe_cursor }
+	}
+
+	/// Advance the iterator to the next key, returning `Some(None)` if its value failed to decode.
+	fn next_entry(&mut self) -> Option<Option<T>> {
 		loop {
 			let maybe_next = sp_io::storage::next_key(&self.previous_key)
 				.filter(|n| n.starts_with(&self.prefix));
@@ -1107,19 +1196,17 @@ impl<T, OnRemoval: PrefixIteratorOnRemoval> Iterator for PrefixIterator<T, OnRem
 						OnRemoval::on_removal(&self.previous_key, &raw_value);
 					}
 					let raw_key_without_prefix = &self.previous_key[self.prefix.len()..];
-					let item = match (self.closure)(raw_key_without_prefix, &raw_value[..]) {
-						Ok(item) => item,
+					match (self.closure)(raw_key_without_prefix, &raw_value[..]) {
+						Ok(item) => Some(Some(item)),
 						Err(e) => {
 							log::error!(
 								"(key, value) failed to decode at {:?}: {:?}",
 								self.previous_key,
 								e,
 							);
-							continue
+							Some(None)
 						},
-					};
-
-					Some(item)
+					}
 				},
 				None => None,
 			}
@@ -1127,6 +1214,19 @@ impl<T, OnRemoval: PrefixIteratorOnRemoval> Iterator for PrefixIterator<T, OnRem
 	}
 }
 
+impl<T, OnRemoval: PrefixIteratorOnRemoval> Iterator for PrefixIterator<T, OnRemoval> {
+	type Item = T;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		while let Some(maybe_item) = self.next_entry() {
+			if maybe_item.is_some() {
+				return maybe_item
+			}
+		}
+		None
+	}
+}
+
 /// Iterate over a prefix and decode raw_key into `T`.
 ///
 /// If any decoding fails it skips it and continues to the next key.
@@ -1511,6 +1611,82 @@ pub trait StorageDecodeNonDedupLength: private::Sealed + codec::DecodeLength {
 	}
 }
 
+/// A collection stored as a `Compact<u32>` length followed by its encoded items, whose items can
+/// be decoded one by one.
+///
+/// This trait is sealed.
+pub trait StorageDecodeItems: private::Sealed {
+	/// The type of the items of the collection.
+	type Item: Decode;
+
+	/// Whether any item of the storage value at `key` matches `f`.
+	///
+	/// The value is read from the storage in chunks as the items are decoded and the decoding
+	/// stops at the first match, the rest of the value is neither read into the runtime nor
+	/// decoded. The storage proof still contains the whole value, as the trie records a value
+	/// on any read of it.
+	///
+	/// Returns `false` if the storage value does not exist or the decoding failed.
+	fn decode_any(key: &[u8], mut f: impl FnMut(&Self::Item) -> bool) -> bool {
+		let input = &mut ChunkedInput::new(key);
+		let Ok(codec::Compact(len)) = codec::Compact::<u32>::decode(input) else { return false };
+		for _ in 0..len {
+			match Self::Item::decode(input) {
+				Ok(item) if f(&item) => return true,
+				Ok(_) => {},
+				Err(_) => return false,
+			}
+		}
+		false
+	}
+}
+
+/// A [`codec::Input`] reading the storage value at `key` in chunks, as it is consumed.
+struct ChunkedInput<'a> {
+	key: &'a [u8],
+	/// The offset in the storage value of the end of the last chunk read.
+	offset: u32,
+	chunk: [u8; Self::CHUNK_SIZE],
+	/// The range of `chunk` which was not consumed yet.
+	unread: core::ops::Range<usize>,
+}
+
+impl<'a> ChunkedInput<'a> {
+	const CHUNK_SIZE: usize = 128;
+
+	fn new(key: &'a [u8]) -> Self {
+		Self { key, offset: 0, chunk: [0; Self::CHUNK_SIZE], unread: 0..0 }
+	}
+}
+
+impl codec::Input for ChunkedInput<'_> {
+	fn remaining_len(&mut self) -> Result<Option<usize>, codec::Error> {
+		Ok(None)
+	}
+
+	fn read(&mut self, into: &mut [u8]) -> Result<(), codec::Error> {
+		let mut filled = 0;
+		while filled < into.len() {
+			if self.unread.is_empty() {
+				let remaining = sp_io::storage::read(self.key, &mut self.chunk, self.offset)
+					.ok_or("Storage value does not exist")?;
+				let len = self.chunk.len().min(remaining as usize);
+				if len == 0 {
+					return Err("Not enough data to fill buffer".into())
+				}
+				self.offset = self.offset.saturating_add(len as u32);
+				self.unread = 0..len;
+			}
+			let len = self.unread.len().min(into.len() - filled);
+			let start = self.unread.start;
+			into[filled..filled + len].copy_from_slice(&self.chunk[start..start + len]);
+			self.unread.start += len;
+			filled += len;
+		}
+		Ok(())
+	}
+}
+
 /// Provides `Sealed` trait to prevent implementing trait `StorageAppend` & `StorageDecodeLength`
 /// & `EncodeLikeTuple` outside of this crate.
 mod private {
@@ -1559,6 +1735,9 @@ mod private {
 
 impl<T: Encode> StorageAppend<T> for Vec<T> {}
 impl<T: Encode> StorageDecodeLength for Vec<T> {}
+impl<T: Encode + Decode> StorageDecodeItems for Vec<T> {
+	type Item = T;
+}
 
 impl<T: Encode> StorageAppend<T> for BTreeSet<T> {}
 impl<T: Encode> StorageDecodeNonDedupLength for BTreeSet<T> {}
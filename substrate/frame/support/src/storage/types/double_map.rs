@@ -662,6 +662,28 @@ where
 		<Self as crate::storage::IterableStorageDoubleMap<Key1, Key2, Value>>::drain_prefix(k1)
 	}
 
+	/// Remove at most `limit` elements from the map with first key `k1` and return them in no
+	/// particular order, together with a cursor to resume from.
+	///
+	/// `maybe_cursor` should be `None` on the first call and the cursor returned by the previous
+	/// call afterwards. The returned cursor is `None` once no elements remain under `k1`.
+	///
+	/// The values failing to decode are removed as well and count towards `limit`.
+	///
+	/// If you add elements with first key `k1` to the map while doing this, you'll get undefined
+	/// results.
+	pub fn drain_prefix_bounded(
+		k1: impl EncodeLike<Key1>,
+		limit: u32,
+		maybe_cursor: Option<&[u8]>,
+	) -> crate::storage::BoundedIterResults<(Key2, Value)> {
+		let mut iter = Self::drain_prefix(k1);
+		if let Some(cursor) = maybe_cursor {
+			iter.set_last_raw_key(cursor.to_vec());
+		}
+		iter.next_bounded(limit)
+	}
+
 	/// Enumerate all elements in the map in no particular order.
 	///
 	/// If you add or remove values to the map while doing this, you'll get undefined results.
@@ -21,9 +21,13 @@
 use crate::{
 	storage::{
 		types::{OptionQuery, QueryKindTrait, StorageEntryMetadataBuilder},
-		KeyLenOf, StorageAppend, StorageDecodeLength, StoragePrefixedMap, StorageTryAppend,
+		KeyLenOf, StorageAppend, StorageDecodeItems, StorageDecodeLength, StoragePrefixedMap,
+		StorageTryAppend,
+	},
+	traits::{
+		decode_or_flag, scan_undecodable, Get, GetDefault, OnUndecodable, StorageInfo,
+		StorageInstance, UndecodableScan,
 	},
-	traits::{Get, GetDefault, StorageInfo, StorageInstance},
 	StorageHasher, Twox128,
 };
 use codec::{Decode, Encode, EncodeLike, FullCodec, MaxEncodedLen};
@@ -179,6 +183,29 @@ where
 		<Self as crate::storage::StorageMap<Key, Value>>::try_get(key)
 	}
 
+	/// Try to get the value for the given key from the map.
+	///
+	/// Returns `None` if it doesn't exist or can't be decoded, reporting the latter to `H`.
+	pub fn try_get_or_flag<H: OnUndecodable, KeyArg: EncodeLike<Key>>(
+		key: KeyArg,
+	) -> Option<Value> {
+		let key = Self::hashed_key_for(key);
+		let raw = crate::storage::unhashed::get_raw(&key)?;
+		decode_or_flag::<Prefix, Value, H>(&key, &raw)
+	}
+
+	/// Read at most `limit` entries of the map, starting after `cursor`, and report the ones that
+	/// can't be decoded to `H`.
+	///
+	/// Pass `None` to start from the beginning, then the returned cursor until it is `None`.
+	pub fn scan_undecodable<H: OnUndecodable>(
+		cursor: Option<Vec<u8>>,
+		limit: u32,
+	) -> UndecodableScan {
+		let prefix = <Self as StoragePrefixedMap<Value>>::final_prefix();
+		scan_undecodable::<Prefix, Value, H>(&prefix, cursor, limit)
+	}
+
 	/// Swap the values of two keys.
 	pub fn swap<KeyArg1: EncodeLike<Key>, KeyArg2: EncodeLike<Key>>(key1: KeyArg1, key2: KeyArg2) {
 		<Self as crate::storage::StorageMap<Key, Value>>::swap(key1, key2)
@@ -307,6 +334,41 @@ where
 		<Self as crate::storage::StorageMap<Key, Value>>::decode_non_dedup_len(key)
 	}
 
+	/// Whether any item of the storage value under the given `key` matches `f`, without decoding
+	/// the entire value.
+	///
+	/// `Value` is required to implement [`StorageDecodeItems`].
+	///
+	/// The items are decoded one after the other and the decoding stops at the first match. If
+	/// the value does not exist or fails to decode, `false` is returned.
+	///
+	/// # Warning
+	///
+	/// The default value is completly ignored by this function.
+	pub fn decode_any<KeyArg: EncodeLike<Key>>(
+		key: KeyArg,
+		f: impl FnMut(&Value::Item) -> bool,
+	) -> bool
+	where
+		Value: StorageDecodeItems,
+	{
+		<Self as crate::storage::StorageMap<Key, Value>>::decode_any(key, f)
+	}
+
+	/// Whether the storage value under the given `key` contains `item`, without decoding the
+	/// entire value.
+	///
+	/// `Value` is required to implement [`StorageDecodeItems`].
+	///
+	/// See [`Self::decode_any`].
+	pub fn decode_contains<KeyArg: EncodeLike<Key>>(key: KeyArg, item: &Value::Item) -> bool
+	where
+		Value: StorageDecodeItems,
+		Value::Item: PartialEq,
+	{
+		<Self as crate::storage::StorageMap<Key, Value>>::decode_contains(key, item)
+	}
+
 	/// Migrate an item with the given `key` from a defunct `OldHasher` to the current hasher.
 	///
 	/// If the key doesn't exist, then it's a no-op. If it does, then it returns its value.
@@ -826,4 +888,51 @@ mod test {
 			assert_eq!(WithLen::decode_len(0), Some(1));
 		})
 	}
+
+	#[test]
+	fn undecodable_entries_are_flagged() {
+		use crate::traits::UndecodableEntry;
+		use std::cell::RefCell;
+
+		thread_local! {
+			static FLAGGED: RefCell<Vec<UndecodableEntry>> = RefCell::new(vec![]);
+		}
+		struct Flag;
+		impl OnUndecodable for Flag {
+			fn on_undecodable(entry: UndecodableEntry) {
+				FLAGGED.with(|flagged| flagged.borrow_mut().push(entry));
+			}
+		}
+		let flagged = || FLAGGED.with(|flagged| flagged.borrow().clone());
+
+		type A = StorageMap<Prefix, Blake2_128Concat, u16, u32, OptionQuery>;
+		TestExternalities::default().execute_with(|| {
+			for key in 0..5 {
+				A::insert(key, 10);
+			}
+			// Only a `u16` is stored at `3`.
+			crate::storage::unhashed::put(&A::hashed_key_for(3), &7u16);
+
+			assert_eq!(A::try_get_or_flag::<Flag, _>(1), Some(10));
+			assert_eq!(A::try_get_or_flag::<Flag, _>(9), None);
+			assert!(flagged().is_empty());
+
+			assert_eq!(A::get(3), None);
+			assert_eq!(A::try_get_or_flag::<Flag, _>(3), None);
+			let entry = UndecodableEntry::new::<Prefix, u32>(&A::hashed_key_for(3));
+			assert_eq!(flagged(), vec![entry.clone()]);
+			assert_eq!(&entry.pallet[..], b"test");
+			assert_eq!(&entry.storage[..], b"foo");
+			assert_eq!(&entry.expected[..], b"u32");
+
+			let mut scan = A::scan_undecodable::<Flag>(None, 2);
+			let mut scanned = scan.scanned;
+			while let Some(cursor) = scan.cursor {
+				scan = A::scan_undecodable::<Flag>(Some(cursor), 2);
+				scanned += scan.scanned;
+			}
+			assert_eq!(scanned, 5);
+			assert_eq!(flagged(), vec![entry.clone(), entry]);
+		})
+	}
 }
@@ -524,6 +524,32 @@ where
 		<Self as crate::storage::IterableStorageNMap<Key, Value>>::drain_prefix(kp)
 	}
 
+	/// Remove at most `limit` elements from the map with prefix key `kp` and return them in no
+	/// particular order, together with a cursor to resume from.
+	///
+	/// `maybe_cursor` should be `None` on the first call and the cursor returned by the previous
+	/// call afterwards. The returned cursor is `None` once no elements remain under `kp`, which
+	/// makes this suitable for weight-bounded cleanup spread over several blocks.
+	///
+	/// The values failing to decode are removed as well and count towards `limit`.
+	///
+	/// If you add elements with prefix key `kp` to the map while doing this, you'll get undefined
+	/// results.
+	pub fn drain_prefix_bounded<KP>(
+		kp: KP,
+		limit: u32,
+		maybe_cursor: Option<&[u8]>,
+	) -> crate::storage::BoundedIterResults<(<Key as HasKeyPrefix<KP>>::Suffix, Value)>
+	where
+		Key: HasReversibleKeyPrefix<KP>,
+	{
+		let mut iter = Self::drain_prefix(kp);
+		if let Some(cursor) = maybe_cursor {
+			iter.set_last_raw_key(cursor.to_vec());
+		}
+		iter.next_bounded(limit)
+	}
+
 	/// Enumerate all elements in the map in no particular order.
 	///
 	/// If you add or remove values to the map while doing this, you'll get undefined results.
@@ -653,7 +679,10 @@ mod test {
 	use super::*;
 	use crate::{
 		hash::{StorageHasher as _, *},
-		storage::types::{Key as NMapKey, ValueQuery},
+		storage::{
+			types::{Key as NMapKey, ValueQuery},
+			unhashed,
+		},
 	};
 	use sp_io::{hashing::twox_128, TestExternalities};
 	use sp_metadata_ir::{StorageEntryModifierIR, StorageHasherIR};
@@ -1069,6 +1098,83 @@ mod test {
 		});
 	}
 
+	#[test]
+	fn drain_prefix_bounded_works() {
+		type A = StorageNMap<
+			Prefix,
+			(NMapKey<Blake2_128Concat, u16>, NMapKey<Twox64Concat, u16>),
+			u32,
+			OptionQuery,
+		>;
+
+		TestExternalities::default().execute_with(|| {
+			for k2 in 0..5 {
+				A::insert((3, k2), k2 as u32);
+			}
+			A::insert((4, 40), 40);
+
+			let first = A::drain_prefix_bounded((3,), 2, None);
+			assert_eq!(first.items.len(), 2);
+			assert!(first.maybe_cursor.is_some());
+
+			let second = A::drain_prefix_bounded((3,), 2, first.maybe_cursor.as_deref());
+			assert_eq!(second.items.len(), 2);
+			assert!(second.maybe_cursor.is_some());
+
+			let third = A::drain_prefix_bounded((3,), 2, second.maybe_cursor.as_deref());
+			assert_eq!(third.items.len(), 1);
+			assert_eq!(third.maybe_cursor, None);
+
+			let mut drained = first
+				.items
+				.into_iter()
+				.chain(second.items)
+				.chain(third.items)
+				.collect::<Vec<_>>();
+			drained.sort();
+			assert_eq!(drained, (0..5).map(|k2| (k2, k2 as u32)).collect::<Vec<_>>());
+			assert_eq!(A::iter_prefix((3,)).count(), 0);
+			assert_eq!(A::get((4, 40)), Some(40));
+
+			// A limit that exactly exhausts the prefix reports no cursor.
+			A::insert((5, 50), 50);
+			let exact = A::drain_prefix_bounded((5,), 1, None);
+			assert_eq!(exact.items, vec![(50, 50)]);
+			assert_eq!(exact.maybe_cursor, None);
+		});
+	}
+
+	#[test]
+	fn drain_prefix_bounded_counts_undecodable_values() {
+		type A = StorageNMap<
+			Prefix,
+			(NMapKey<Blake2_128Concat, u16>, NMapKey<Twox64Concat, u16>),
+			u32,
+			OptionQuery,
+		>;
+
+		TestExternalities::default().execute_with(|| {
+			for k2 in 0..4 {
+				A::insert((3, k2), k2 as u32);
+			}
+			// A `u32` can't be decoded from a single byte.
+			for k2 in [0, 2] {
+				unhashed::put_raw(&A::hashed_key_for((3, k2)), &[1]);
+			}
+
+			let first = A::drain_prefix_bounded((3,), 2, None);
+			assert!(first.maybe_cursor.is_some());
+			let second = A::drain_prefix_bounded((3,), 2, first.maybe_cursor.as_deref());
+			assert_eq!(second.maybe_cursor, None);
+
+			let mut drained = first.items.into_iter().chain(second.items).collect::<Vec<_>>();
+			drained.sort();
+			assert_eq!(drained, vec![(1, 1), (3, 3)]);
+			assert_eq!(A::iter_prefix_values((3,)).count(), 0);
+			assert_eq!(unhashed::get_raw(&A::hashed_key_for((3, 0))), None);
+		});
+	}
+
 	#[test]
 	fn test_3_keys() {
 		type A = StorageNMap<
@@ -21,9 +21,9 @@ use crate::{
 	storage::{
 		generator::StorageValue as StorageValueT,
 		types::{OptionQuery, QueryKindTrait, StorageEntryMetadataBuilder},
-		StorageAppend, StorageDecodeLength, StorageTryAppend,
+		StorageAppend, StorageDecodeItems, StorageDecodeLength, StorageTryAppend,
 	},
-	traits::{Get, GetDefault, StorageInfo, StorageInstance},
+	traits::{decode_or_flag, Get, GetDefault, OnUndecodable, StorageInfo, StorageInstance},
 };
 use codec::{Decode, Encode, EncodeLike, FullCodec, MaxEncodedLen};
 use frame_support::storage::StorageDecodeNonDedupLength;
@@ -120,6 +120,15 @@ where
 		<Self as crate::storage::StorageValue<Value>>::try_get()
 	}
 
+	/// Try to get the underlying value from the provided storage instance.
+	///
+	/// Returns `None` if it doesn't exist or can't be decoded, reporting the latter to `H`.
+	pub fn try_get_or_flag<H: OnUndecodable>() -> Option<Value> {
+		let key = Self::hashed_key();
+		let raw = crate::storage::unhashed::get_raw(&key)?;
+		decode_or_flag::<Prefix, Value, H>(&key, &raw)
+	}
+
 	/// Translate a value from some previous type (`O`) to the current type.
 	///
 	/// `f: F` is the translation function.
@@ -255,6 +264,36 @@ where
 		<Self as crate::storage::StorageValue<Value>>::decode_non_dedup_len()
 	}
 
+	/// Whether any item of the storage value matches `f`, without decoding the entire value.
+	///
+	/// `Value` is required to implement [`StorageDecodeItems`].
+	///
+	/// The items are decoded one after the other and the decoding stops at the first match. If
+	/// the value does not exist or fails to decode, `false` is returned.
+	///
+	/// # Warning
+	///
+	/// The default value is completly ignored by this function.
+	pub fn decode_any(f: impl FnMut(&Value::Item) -> bool) -> bool
+	where
+		Value: StorageDecodeItems,
+	{
+		<Self as crate::storage::StorageValue<Value>>::decode_any(f)
+	}
+
+	/// Whether the storage value contains `item`, without decoding the entire value.
+	///
+	/// `Value` is required to implement [`StorageDecodeItems`].
+	///
+	/// See [`Self::decode_any`].
+	pub fn decode_contains(item: &Value::Item) -> bool
+	where
+		Value: StorageDecodeItems,
+		Value::Item: PartialEq,
+	{
+		<Self as crate::storage::StorageValue<Value>>::decode_contains(item)
+	}
+
 	/// Try and append the given item to the value in the storage.
 	///
 	/// Is only available if `Value` of the storage implements [`StorageTryAppend`].
@@ -19,13 +19,18 @@
 //! or a double map.
 
 use crate::{
-	storage::{StorageDecodeLength, StorageTryAppend},
+	storage::{StorageDecodeItems, StorageDecodeLength, StorageTryAppend},
 	traits::Get,
 };
+use codec::Decode;
 pub use sp_runtime::WeakBoundedVec;
 
 impl<T, S> StorageDecodeLength for WeakBoundedVec<T, S> {}
 
+impl<T: Decode, S> StorageDecodeItems for WeakBoundedVec<T, S> {
+	type Item = T;
+}
+
 impl<T, S: Get<u32>> StorageTryAppend<T> for WeakBoundedVec<T, S> {
 	fn bound() -> usize {
 		S::get() as usize
@@ -126,6 +126,18 @@ pub use tx_pause::{TransactionPause, TransactionPauseError};
 
 pub mod dynamic_params;
 
+mod feature_flags;
+pub use feature_flags::{
+	AllFeaturesEnabled, FeatureDisabled, FeatureEnabled, FeatureFlagSet, FeatureId,
+	RuntimeFeatureFlags,
+};
+
+mod undecodable;
+pub use undecodable::{
+	decode_or_flag, scan_undecodable, OnUndecodable, UndecodableEntry, UndecodableScan,
+	MAX_UNDECODABLE_KEY_LEN, MAX_UNDECODABLE_NAME_LEN,
+};
+
 pub mod tasks;
 pub use tasks::Task;
 
@@ -0,0 +1,185 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Types to soft-launch runtime features that can be toggled by governance.
+//!
+//! A runtime assigns a [`FeatureId`] to every feature it wants to gate. Pallets consult a
+//! [`RuntimeFeatureFlags`] implementation (usually `frame_system::Pallet`) through the
+//! [`FeatureEnabled`] guard before executing gated code paths, so a feature can be enabled or
+//! rolled back without a runtime upgrade.
+
+use crate::traits::{Contains, Get};
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{DispatchError, RuntimeDebug};
+use sp_std::marker::PhantomData;
+
+/// Identifier of a runtime feature.
+///
+/// The meaning of each identifier is defined by the runtime. Identifiers must never be reused for
+/// a different feature, since the enabled state is persisted in storage.
+pub type FeatureId = u8;
+
+/// A set of enabled runtime features, stored as a bitset with one bit per [`FeatureId`].
+#[derive(
+	Copy, Clone, Default, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen,
+)]
+pub struct FeatureFlagSet([u8; 32]);
+
+impl FeatureFlagSet {
+	/// Whether `feature` is enabled.
+	pub fn is_enabled(&self, feature: FeatureId) -> bool {
+		let (byte, mask) = Self::position(feature);
+		self.0[byte] & mask != 0
+	}
+
+	/// Enable or disable `feature`.
+	///
+	/// Returns `true` if this changed the set.
+	pub fn set(&mut self, feature: FeatureId, enabled: bool) -> bool {
+		let was_enabled = self.is_enabled(feature);
+		let (byte, mask) = Self::position(feature);
+		if enabled {
+			self.0[byte] |= mask;
+		} else {
+			self.0[byte] &= !mask;
+		}
+		was_enabled != enabled
+	}
+
+	/// Iterate over all enabled features in ascending order.
+	pub fn iter(&self) -> impl Iterator<Item = FeatureId> + '_ {
+		(0..=FeatureId::MAX).filter(|feature| self.is_enabled(*feature))
+	}
+
+	fn position(feature: FeatureId) -> (usize, u8) {
+		((feature / 8) as usize, 1 << (feature % 8))
+	}
+}
+
+impl FromIterator<FeatureId> for FeatureFlagSet {
+	fn from_iter<I: IntoIterator<Item = FeatureId>>(iter: I) -> Self {
+		let mut set = Self::default();
+		iter.into_iter().for_each(|feature| {
+			set.set(feature, true);
+		});
+		set
+	}
+}
+
+/// Something that knows which runtime features are currently enabled.
+pub trait RuntimeFeatureFlags {
+	/// Whether `feature` is enabled.
+	fn is_enabled(feature: FeatureId) -> bool;
+}
+
+/// No feature flags are configured: every feature is disabled.
+impl RuntimeFeatureFlags for () {
+	fn is_enabled(_feature: FeatureId) -> bool {
+		false
+	}
+}
+
+/// Every feature is enabled.
+///
+/// Useful for tests and for runtimes that want to launch gated features right away.
+pub struct AllFeaturesEnabled;
+impl RuntimeFeatureFlags for AllFeaturesEnabled {
+	fn is_enabled(_feature: FeatureId) -> bool {
+		true
+	}
+}
+
+/// The error returned by [`FeatureEnabled::ensure`] when the feature is disabled.
+#[derive(Copy, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct FeatureDisabled;
+
+impl From<FeatureDisabled> for DispatchError {
+	fn from(_: FeatureDisabled) -> DispatchError {
+		DispatchError::Other("Feature is disabled")
+	}
+}
+
+/// Guard for code paths that should only run while `Feature` is enabled in `Flags`.
+///
+/// Use it at the top of a dispatchable:
+///
+/// ```ignore
+/// FeatureEnabled::<T::FeatureFlags, T::SponsoredJoinFeature>::ensure()
+/// 	.map_err(|_| Error::<T>::FeatureDisabled)?;
+/// ```
+///
+/// It also implements [`Contains`] for any item, so it can be composed into call filters.
+pub struct FeatureEnabled<Flags, Feature>(PhantomData<(Flags, Feature)>);
+
+impl<Flags: RuntimeFeatureFlags, Feature: Get<FeatureId>> FeatureEnabled<Flags, Feature> {
+	/// Whether the feature is currently enabled.
+	pub fn is_enabled() -> bool {
+		Flags::is_enabled(Feature::get())
+	}
+
+	/// Returns an error if the feature is currently disabled.
+	pub fn ensure() -> Result<(), FeatureDisabled> {
+		if Self::is_enabled() {
+			Ok(())
+		} else {
+			Err(FeatureDisabled)
+		}
+	}
+}
+
+impl<T, Flags: RuntimeFeatureFlags, Feature: Get<FeatureId>> Contains<T>
+	for FeatureEnabled<Flags, Feature>
+{
+	fn contains(_: &T) -> bool {
+		Self::is_enabled()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::traits::ConstU8;
+
+	#[test]
+	fn feature_flag_set_works() {
+		let mut set = FeatureFlagSet::default();
+		assert!(!set.is_enabled(0));
+		assert!(!set.is_enabled(255));
+
+		assert!(set.set(0, true));
+		assert!(set.set(9, true));
+		assert!(set.set(255, true));
+		// Setting the same value again is not a change.
+		assert!(!set.set(9, true));
+		assert_eq!(set.iter().collect::<Vec<_>>(), vec![0, 9, 255]);
+
+		assert!(set.set(9, false));
+		assert!(!set.is_enabled(9));
+		assert!(!set.set(9, false));
+		assert_eq!(set, [0, 255].into_iter().collect());
+	}
+
+	#[test]
+	fn feature_enabled_guard_works() {
+		assert_eq!(FeatureEnabled::<(), ConstU8<3>>::ensure(), Err(FeatureDisabled));
+		assert!(!<FeatureEnabled<(), ConstU8<3>> as Contains<()>>::contains(&()));
+
+		assert_eq!(FeatureEnabled::<AllFeaturesEnabled, ConstU8<3>>::ensure(), Ok(()));
+		assert!(<FeatureEnabled<AllFeaturesEnabled, ConstU8<3>> as Contains<u32>>::contains(&1));
+	}
+}
@@ -0,0 +1,117 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Bookkeeping of deposits placed on hold.
+
+use super::{BalancedHold, Credit, InspectHold, MutateHold};
+use crate::{
+	storage::with_storage_layer,
+	traits::{tokens::Precision::BestEffort, ReservableCurrency},
+};
+use sp_arithmetic::traits::Saturating;
+use sp_core::Get;
+use sp_runtime::{DispatchError, DispatchResult};
+use sp_std::marker::PhantomData;
+
+/// Deposits of accounts, placed on hold for the reason `R` of the fungible `F`.
+///
+/// Pallets taking deposits used to reserve them and keep the amount of every deposit in their own
+/// storage, so that they knew how much to unreserve or slash later on. Holds already track the
+/// amount held for every reason, so a pallet with a dedicated reason for its deposits can use
+/// this type instead of storing the amounts itself. Deposits of all reasons can be queried with
+/// [`Self::total_of`].
+///
+/// Pallets moving from reserves to holds can use [`Self::migrate_from_reserve`] to move the
+/// existing deposits.
+pub struct DepositLedger<A, F, R>(PhantomData<fn() -> (A, F, R)>);
+
+impl<A, F, R> DepositLedger<A, F, R>
+where
+	F: MutateHold<A>,
+	R: Get<F::Reason>,
+{
+	/// The deposit held from `who` for the reason `R`.
+	pub fn deposit_of(who: &A) -> F::Balance {
+		F::balance_on_hold(&R::get(), who)
+	}
+
+	/// The deposits held from `who` for all reasons.
+	pub fn total_of(who: &A) -> F::Balance {
+		F::total_balance_on_hold(who)
+	}
+
+	/// Whether `amount` can be added to the deposit of `who`.
+	pub fn can_place(who: &A, amount: F::Balance) -> bool {
+		F::can_hold(&R::get(), who, amount)
+	}
+
+	/// Add `amount` to the deposit of `who`.
+	pub fn place(who: &A, amount: F::Balance) -> DispatchResult {
+		F::hold(&R::get(), who, amount)
+	}
+
+	/// Release up to `amount` of the deposit of `who`.
+	///
+	/// Returns the amount actually released.
+	pub fn release(who: &A, amount: F::Balance) -> Result<F::Balance, DispatchError> {
+		F::release(&R::get(), who, amount, BestEffort)
+	}
+
+	/// Release the whole deposit of `who`.
+	///
+	/// Returns the amount released.
+	pub fn release_all(who: &A) -> Result<F::Balance, DispatchError> {
+		F::release_all(&R::get(), who, BestEffort)
+	}
+
+	/// Move `amount` reserved from `who` in the currency `C` to the deposit held for `R`.
+	///
+	/// `C` and `F` must be backed by the same balances, like the `Currency` and `fungible`
+	/// implementations of `pallet-balances`. `amount` must not be more than what was reserved, as
+	/// reserves and holds may share the same balance. Returns the amount moved. Nothing is moved
+	/// if it can't be placed on hold.
+	pub fn migrate_from_reserve<C>(who: &A, amount: F::Balance) -> Result<F::Balance, DispatchError>
+	where
+		C: ReservableCurrency<A, Balance = F::Balance>,
+	{
+		with_storage_layer(|| {
+			let unreserved = amount.saturating_sub(C::unreserve(who, amount));
+			Self::place(who, unreserved)?;
+			Ok(unreserved)
+		})
+	}
+}
+
+impl<A, F, R> DepositLedger<A, F, R>
+where
+	F: MutateHold<A> + BalancedHold<A>,
+	R: Get<F::Reason>,
+{
+	/// Slash up to `amount` of the deposit of `who`.
+	///
+	/// Returns the slashed funds, which have to be handled by the caller.
+	pub fn slash(who: &A, amount: F::Balance) -> Credit<A, F> {
+		F::slash(&R::get(), who, amount).0
+	}
+
+	/// Slash the whole deposit of `who`.
+	///
+	/// Returns the slashed funds, which have to be handled by the caller.
+	pub fn slash_all(who: &A) -> Credit<A, F> {
+		Self::slash(who, Self::deposit_of(who))
+	}
+}
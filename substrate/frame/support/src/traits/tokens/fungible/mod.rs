@@ -39,6 +39,7 @@
 //!   them.
 
 pub mod conformance_tests;
+mod deposit_ledger;
 pub mod freeze;
 pub mod hold;
 pub(crate) mod imbalance;
@@ -55,6 +56,7 @@ use super::{
 	Fortitude::{Force, Polite},
 	Precision::BestEffort,
 };
+pub use deposit_ledger::DepositLedger;
 pub use freeze::{Inspect as InspectFreeze, Mutate as MutateFreeze};
 pub use hold::{
 	Balanced as BalancedHold, Inspect as InspectHold, Mutate as MutateHold,
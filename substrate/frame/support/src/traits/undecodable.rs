@@ -0,0 +1,137 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Types to surface storage entries that can't be decoded.
+//!
+//! Reading an entry whose encoding doesn't match the expected type, e.g. because a migration was
+//! missed, silently yields `None` with the usual getters. The `try_get_or_flag` functions of the
+//! storage types report such entries to an [`OnUndecodable`] handler (usually
+//! `frame_system::Pallet`, which records them on-chain) before returning `None`. Pallets can also
+//! scan whole maps bit by bit, e.g. in `on_idle`, with their `scan_undecodable` function, as
+//! `frame_system` does for its `Account` map.
+
+use crate::{storage::unhashed, traits::StorageInstance, BoundedVec};
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_core::ConstU32;
+use sp_runtime::RuntimeDebug;
+use sp_std::prelude::*;
+
+/// The maximum length of the names in an [`UndecodableEntry`].
+pub const MAX_UNDECODABLE_NAME_LEN: u32 = 64;
+
+/// The maximum length of the key and type name in an [`UndecodableEntry`].
+pub const MAX_UNDECODABLE_KEY_LEN: u32 = 128;
+
+/// A storage entry that could not be decoded.
+#[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct UndecodableEntry {
+	/// The prefix of the pallet the storage item belongs to.
+	pub pallet: BoundedVec<u8, ConstU32<MAX_UNDECODABLE_NAME_LEN>>,
+	/// The name of the storage item.
+	pub storage: BoundedVec<u8, ConstU32<MAX_UNDECODABLE_NAME_LEN>>,
+	/// The raw key of the entry, truncated to [`MAX_UNDECODABLE_KEY_LEN`] bytes.
+	pub key: BoundedVec<u8, ConstU32<MAX_UNDECODABLE_KEY_LEN>>,
+	/// The name of the type the entry should have decoded into, truncated to
+	/// [`MAX_UNDECODABLE_KEY_LEN`] bytes.
+	pub expected: BoundedVec<u8, ConstU32<MAX_UNDECODABLE_KEY_LEN>>,
+}
+
+impl UndecodableEntry {
+	/// The entry at `key` of the storage item `Prefix`, which should have decoded into `Value`.
+	pub fn new<Prefix: StorageInstance, Value>(key: &[u8]) -> Self {
+		Self {
+			pallet: BoundedVec::truncate_from(Prefix::pallet_prefix().as_bytes().to_vec()),
+			storage: BoundedVec::truncate_from(Prefix::STORAGE_PREFIX.as_bytes().to_vec()),
+			key: BoundedVec::truncate_from(key.to_vec()),
+			expected: BoundedVec::truncate_from(
+				core::any::type_name::<Value>().as_bytes().to_vec(),
+			),
+		}
+	}
+}
+
+/// Handler of storage entries that could not be decoded.
+pub trait OnUndecodable {
+	/// The storage entry `entry` could not be decoded.
+	fn on_undecodable(entry: UndecodableEntry);
+}
+
+/// Undecodable entries are ignored.
+impl OnUndecodable for () {
+	fn on_undecodable(_entry: UndecodableEntry) {}
+}
+
+/// Decode the `raw` value at `key` of the storage item `Prefix`, reporting it to `H` if it can't be
+/// decoded.
+pub fn decode_or_flag<Prefix, Value, H>(key: &[u8], raw: &[u8]) -> Option<Value>
+where
+	Prefix: StorageInstance,
+	Value: Decode,
+	H: OnUndecodable,
+{
+	match Value::decode(&mut &raw[..]) {
+		Ok(value) => Some(value),
+		Err(_) => {
+			log::error!(
+				target: "runtime::storage",
+				"Corrupted state at `{}::{}`: {:?}",
+				Prefix::pallet_prefix(),
+				Prefix::STORAGE_PREFIX,
+				key,
+			);
+			H::on_undecodable(UndecodableEntry::new::<Prefix, Value>(key));
+			None
+		},
+	}
+}
+
+/// The progress of scanning a map for undecodable entries.
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct UndecodableScan {
+	/// The number of entries read.
+	pub scanned: u32,
+	/// The cursor to continue the scan with, or `None` if the whole map was scanned.
+	pub cursor: Option<Vec<u8>>,
+}
+
+/// Read at most `limit` entries under `prefix`, the final prefix of the map `Prefix`, starting
+/// after `cursor`, and report the ones that can't be decoded into `Value` to `H`.
+pub fn scan_undecodable<Prefix, Value, H>(
+	prefix: &[u8],
+	cursor: Option<Vec<u8>>,
+	limit: u32,
+) -> UndecodableScan
+where
+	Prefix: StorageInstance,
+	Value: Decode,
+	H: OnUndecodable,
+{
+	let mut previous = cursor.unwrap_or_else(|| prefix.to_vec());
+	let mut scanned = 0;
+	while scanned < limit {
+		let Some(key) = sp_io::storage::next_key(&previous).filter(|key| key.starts_with(prefix))
+		else {
+			return UndecodableScan { scanned, cursor: None }
+		};
+		if let Some(raw) = unhashed::get_raw(&key) {
+			let _ = decode_or_flag::<Prefix, Value, H>(&key, &raw);
+		}
+		scanned += 1;
+		previous = key;
+	}
+	UndecodableScan { scanned, cursor: Some(previous) }
+}
@@ -0,0 +1,169 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests that `#[verify_db]` fails benchmarks declaring the wrong number of DB reads and writes.
+
+use frame_benchmarking::v2::*;
+use frame_support::{assert_err, assert_ok, derive_impl, pallet_prelude::ConstU32};
+use pallet::*;
+use sp_io::TestExternalities;
+
+#[frame_support::pallet(dev_mode)]
+pub mod pallet {
+	use frame_support::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {}
+
+	#[pallet::storage]
+	pub type Value<T> = StorageValue<_, u32, ValueQuery>;
+
+	#[pallet::storage]
+	pub type Map<T> = StorageMap<_, Blake2_128Concat, u32, u32, ValueQuery>;
+}
+
+pub type BlockNumber = u32;
+pub type Nonce = u64;
+pub type AccountId = u64;
+pub type Header = sp_runtime::generic::Header<BlockNumber, sp_runtime::traits::BlakeTwo256>;
+pub type UncheckedExtrinsic = sp_runtime::generic::UncheckedExtrinsic<u32, RuntimeCall, (), ()>;
+pub type Block = sp_runtime::generic::Block<Header, UncheckedExtrinsic>;
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Runtime {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = Nonce;
+	type Hash = sp_runtime::testing::H256;
+	type Hashing = sp_runtime::traits::BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = sp_runtime::traits::IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU32<250>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl Config for Runtime {}
+
+frame_support::construct_runtime!(
+	pub enum Runtime {
+		System: frame_system,
+		MyPallet: pallet,
+	}
+);
+
+#[benchmarks]
+mod benches {
+	use super::*;
+
+	const MISMATCH: BenchmarkError =
+		BenchmarkError::Stop("Recorded DB reads/writes differ from `#[verify_db]`");
+
+	#[benchmark]
+	#[verify_db(reads = 1, writes = 1)]
+	fn read_and_write() {
+		#[block]
+		{
+			Value::<T>::mutate(|value| *value += 1);
+		}
+	}
+
+	#[benchmark]
+	#[verify_db(reads = x, writes = x)]
+	fn read_and_write_x(x: Linear<0, 4>) {
+		#[block]
+		{
+			for key in 0..x {
+				Map::<T>::mutate(key, |value| *value += 1);
+			}
+		}
+	}
+
+	// Repeated accesses to the same key are only counted once.
+	#[benchmark]
+	#[verify_db(reads = 1, writes = 1)]
+	fn read_and_write_twice() {
+		#[block]
+		{
+			Value::<T>::mutate(|value| *value += 1);
+			Value::<T>::mutate(|value| *value += 1);
+		}
+	}
+
+	#[benchmark]
+	#[verify_db(reads = 0, writes = 1)]
+	fn too_few_reads() {
+		#[block]
+		{
+			Value::<T>::mutate(|value| *value += 1);
+		}
+	}
+
+	#[benchmark]
+	#[verify_db(reads = 1, writes = 2)]
+	fn too_many_writes() {
+		#[block]
+		{
+			Value::<T>::mutate(|value| *value += 1);
+		}
+	}
+
+	#[benchmark]
+	#[verify_db(reads = x, writes = x + 1)]
+	fn wrong_writes_x(x: Linear<0, 4>) {
+		#[block]
+		{
+			for key in 0..x {
+				Map::<T>::mutate(key, |value| *value += 1);
+			}
+		}
+	}
+
+	#[test]
+	fn declared_counts_pass() {
+		TestExternalities::default().execute_with(|| {
+			assert_ok!(Pallet::<Runtime>::test_read_and_write());
+			assert_ok!(Pallet::<Runtime>::test_read_and_write_x());
+			assert_ok!(Pallet::<Runtime>::test_read_and_write_twice());
+		});
+	}
+
+	#[test]
+	fn wrong_counts_fail() {
+		TestExternalities::default().execute_with(|| {
+			assert_err!(Pallet::<Runtime>::test_too_few_reads(), MISMATCH);
+			assert_err!(Pallet::<Runtime>::test_too_many_writes(), MISMATCH);
+			assert_err!(Pallet::<Runtime>::test_wrong_writes_x(), MISMATCH);
+		});
+	}
+}
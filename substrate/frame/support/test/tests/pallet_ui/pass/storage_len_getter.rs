@@ -0,0 +1,54 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#[frame_support::pallet]
+mod pallet {
+	use frame_support::pallet_prelude::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(core::marker::PhantomData<T>);
+
+	#[pallet::storage]
+	#[pallet::getter(fn items, len)]
+	pub type Items<T> = StorageValue<_, BoundedVec<u32, ConstU32<10>>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn items_of, len)]
+	pub type ItemsOf<T> =
+		StorageMap<_, Blake2_128Concat, u32, WeakBoundedVec<u32, ConstU32<10>>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn entries_of, len)]
+	pub type EntriesOf<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		u32,
+		Blake2_128Concat,
+		u32,
+		BoundedBTreeMap<u32, u32, ConstU32<10>>,
+	>;
+
+	impl<T: Config> Pallet<T> {
+		pub fn lengths() -> (Option<usize>, Option<usize>, Option<usize>) {
+			(Self::items_len(), Self::items_of_len(1), Self::entries_of_len(1, 2))
+		}
+	}
+}
+
+fn main() {}
@@ -231,5 +231,82 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_feature_flag() -> Result<(), BenchmarkError> {
+		#[extrinsic_call]
+		set_feature_flag(RawOrigin::Root, 0, true);
+
+		assert!(System::<T>::enabled_features().is_enabled(0));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn force_set_nonce() -> Result<(), BenchmarkError> {
+		let who: T::AccountId = account("who", 0, 0);
+		System::<T>::inc_providers(&who);
+		let nonce = 10u32.into();
+
+		#[extrinsic_call]
+		force_set_nonce(RawOrigin::Root, who.clone(), nonce);
+
+		assert_eq!(System::<T>::account_nonce(&who), nonce);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_block_hash_retention() -> Result<(), BenchmarkError> {
+		let retention = 100u32.into();
+
+		#[extrinsic_call]
+		set_block_hash_retention(RawOrigin::Root, Some(retention));
+
+		assert_eq!(System::<T>::block_hash_retention(), retention);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_dispatch_path_recording() -> Result<(), BenchmarkError> {
+		let enabled = T::AllowDispatchPathRecording::get();
+
+		#[extrinsic_call]
+		set_dispatch_path_recording(RawOrigin::Root, enabled);
+
+		assert_eq!(frame_system::DispatchPathRecording::<T>::get(), enabled);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn scan_undecodable_accounts(
+		n: Linear<0, { frame_system::MAX_UNDECODABLE_SCANNED }>,
+	) -> Result<(), BenchmarkError> {
+		// Worst case: every checked account is undecodable and gets recorded.
+		for i in 0..n {
+			let who: T::AccountId = account("scanned", i, 0);
+			storage::unhashed::put_raw(&frame_system::Account::<T>::hashed_key_for(&who), &[1]);
+		}
+		let scanned;
+
+		#[block]
+		{
+			scanned = System::<T>::scan_undecodable_accounts(n);
+		}
+
+		assert_eq!(scanned, n);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn mark_migration_failed(
+		n: Linear<0, { frame_system::MAX_MIGRATION_NAME_LEN }>,
+	) -> Result<(), BenchmarkError> {
+		let migration = vec![1u8; n as usize];
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, migration.clone().try_into().unwrap());
+
+		assert!(System::<T>::migration_failed(&migration));
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
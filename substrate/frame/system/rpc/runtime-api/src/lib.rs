@@ -32,4 +32,18 @@ sp_api::decl_runtime_apis! {
 		/// Get current account nonce of given `AccountId`.
 		fn account_nonce(account: AccountId) -> Nonce;
 	}
+
+	/// The API to query which block hashes are kept by the runtime.
+	///
+	/// The hash of the birth block of a mortal transaction must be kept for the transaction to
+	/// be valid, so its period shouldn't exceed the retention.
+	pub trait BlockHashApi<BlockNumber> where
+		BlockNumber: codec::Codec,
+	{
+		/// Get the number of block hashes currently kept.
+		fn block_hash_retention() -> BlockNumber;
+
+		/// Get the oldest block whose hash is kept, besides the genesis block.
+		fn oldest_block_hash() -> BlockNumber;
+	}
 }
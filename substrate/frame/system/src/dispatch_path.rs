@@ -0,0 +1,124 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Correlation of events with the nested dispatches that deposited them.
+//!
+//! The [`Phase`](crate::Phase) of an event tells which extrinsic deposited it, but not which of
+//! the calls nested in that extrinsic did, e.g. which call of a batch. Pallets dispatching nested
+//! calls wrap every dispatch in [`with_dispatch_frame`], which pushes a [`DispatchFrame`] on a
+//! stack kept in the [dispatch context](frame_support::dispatch_context) for the duration of the
+//! nested call.
+//!
+//! The recording is opt-in: a runtime allows it with
+//! [`Config::AllowDispatchPathRecording`](crate::Config::AllowDispatchPathRecording), and Root
+//! then enables it with
+//! [`Pallet::set_dispatch_path_recording`](crate::Pallet::set_dispatch_path_recording).
+//!
+//! While [`DispatchPathRecording`](crate::DispatchPathRecording) is enabled, the stack of every
+//! event deposited by a nested call is stored in [`EventDispatchPaths`](crate::EventDispatchPaths)
+//! next to the events of the block. Events deposited outside of nested calls have an empty path
+//! and are not recorded.
+//! [`Pallet::read_events_with_dispatch_paths`](crate::Pallet::read_events_with_dispatch_paths)
+//! combines both into
+//! [`ExtendedEventRecord`]s.
+
+use crate::EventRecord;
+use codec::{Decode, Encode};
+use frame_support::{
+	dispatch_context::{run_in_context, with_context},
+	traits::PalletInfoAccess,
+	Parameter,
+};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::Serialize;
+use sp_runtime::{traits::Member, RuntimeDebug};
+use sp_std::prelude::*;
+
+/// A nested dispatch: the `item`-th call dispatched by the pallet at `pallet_index`.
+#[derive(Copy, Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize))]
+pub struct DispatchFrame {
+	/// The index of the dispatching pallet in the runtime.
+	pub pallet_index: u8,
+	/// The position of the nested call among the calls dispatched by the same call of the
+	/// pallet, e.g. the index of the call in a batch. Zero for calls dispatching a single call.
+	pub item: u32,
+}
+
+impl DispatchFrame {
+	/// The frame of the `item`-th call dispatched by pallet `P`.
+	pub fn new<P: PalletInfoAccess>(item: u32) -> Self {
+		Self { pallet_index: P::index() as u8, item }
+	}
+}
+
+/// An [`EventRecord`] along with the nested dispatches that deposited it, outermost first.
+#[derive(Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, PartialEq, Eq, Clone))]
+pub struct ExtendedEventRecord<E: Parameter + Member, T> {
+	/// The event record.
+	pub record: EventRecord<E, T>,
+	/// The nested dispatches that deposited the event, empty if it was not deposited by a
+	/// nested call or [`DispatchPathRecording`](crate::DispatchPathRecording) was disabled.
+	pub dispatch_path: Vec<DispatchFrame>,
+}
+
+/// The nested dispatches leading to the call being executed.
+#[derive(Default)]
+struct DispatchPath(Vec<DispatchFrame>);
+
+/// Run `f`, which dispatches a nested call, with `frame` pushed on the dispatch path.
+pub fn with_dispatch_frame<R>(frame: DispatchFrame, f: impl FnOnce() -> R) -> R {
+	// Also keeps the path when the dispatching call is not run in a dispatch context, e.g. when
+	// it is called directly from a test.
+	run_in_context(|| {
+		with_context::<DispatchPath, _>(|path| path.or_default().0.push(frame));
+		let result = f();
+		with_context::<DispatchPath, _>(|path| path.or_default().0.pop());
+		result
+	})
+}
+
+/// The nested dispatches leading to the call being executed, outermost first.
+pub fn current_dispatch_path() -> Vec<DispatchFrame> {
+	with_context::<DispatchPath, _>(|path| path.get().map(|path| path.0.clone()))
+		.flatten()
+		.unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn dispatch_path_follows_nesting() {
+		assert!(current_dispatch_path().is_empty());
+
+		let outer = DispatchFrame { pallet_index: 1, item: 2 };
+		let inner = DispatchFrame { pallet_index: 3, item: 0 };
+		with_dispatch_frame(outer, || {
+			assert_eq!(current_dispatch_path(), vec![outer]);
+			with_dispatch_frame(inner, || {
+				assert_eq!(current_dispatch_path(), vec![outer, inner]);
+			});
+			assert_eq!(current_dispatch_path(), vec![outer]);
+		});
+
+		assert!(current_dispatch_path().is_empty());
+	}
+}
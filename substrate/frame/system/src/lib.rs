@@ -53,6 +53,7 @@
 //! - `authorize_upgrade_without_checks`: Authorize new runtime code and an upgrade sans
 //!   verification.
 //! - `apply_authorized_upgrade`: Provide new, already-authorized runtime code.
+//! - `set_feature_flag`: Enable or disable a runtime feature flag.
 //!
 //! #### A Note on Upgrades
 //!
@@ -114,7 +115,7 @@ use sp_runtime::{
 		InvalidTransaction, TransactionLongevity, TransactionSource, TransactionValidity,
 		ValidTransaction,
 	},
-	DispatchError, RuntimeDebug,
+	BoundedVec, DispatchError, RuntimeDebug,
 };
 #[cfg(any(feature = "std", test))]
 use sp_std::map;
@@ -133,8 +134,9 @@ use frame_support::{
 	pallet_prelude::Pays,
 	storage::{self, StorageStreamIter},
 	traits::{
-		ConstU32, Contains, EnsureOrigin, EnsureOriginWithArg, Get, HandleLifetime,
-		OnKilledAccount, OnNewAccount, OriginTrait, PalletInfo, SortedMembers, StoredMap, TypedGet,
+		ConstU32, Contains, EnsureOrigin, EnsureOriginWithArg, FeatureFlagSet, FeatureId, Get,
+		HandleLifetime, OnKilledAccount, OnNewAccount, OnUndecodable, OriginTrait, PalletInfo,
+		RuntimeFeatureFlags, SortedMembers, StoredMap, TypedGet, UndecodableEntry,
 	},
 	Parameter,
 };
@@ -145,6 +147,7 @@ use sp_weights::{RuntimeDbWeight, Weight};
 #[cfg(any(feature = "std", test))]
 use sp_io::TestExternalities;
 
+pub mod dispatch_path;
 pub mod limits;
 #[cfg(test)]
 pub(crate) mod mock;
@@ -159,6 +162,8 @@ pub mod weights;
 
 pub mod migrations;
 
+pub use dispatch_path::{with_dispatch_frame, DispatchFrame, ExtendedEventRecord};
+
 pub use extensions::{
 	check_genesis::CheckGenesis, check_mortality::CheckMortality,
 	check_non_zero_sender::CheckNonZeroSender, check_nonce::CheckNonce,
@@ -191,6 +196,29 @@ pub fn extrinsics_data_root<H: Hash>(xts: Vec<Vec<u8>>) -> H::Output {
 /// An object to track the currently used extrinsic weight in a block.
 pub type ConsumedWeight = PerDispatchClass<Weight>;
 
+/// The maximum number of undecodable storage entries recorded in [`UndecodableEntries`].
+pub const MAX_UNDECODABLE_ENTRIES: u32 = 32;
+
+/// The maximum number of [`Account`] entries checked for undecodable values per block, see
+/// [`UndecodableScanCursor`].
+pub const MAX_UNDECODABLE_SCANNED: u32 = 64;
+
+/// The lowest retention of block hashes accepted by [`Pallet::set_block_hash_retention`].
+///
+/// Mortal transactions are valid for at least this many blocks, so their birth block must be
+/// known for as long.
+pub const MIN_BLOCK_HASH_RETENTION: u32 = 4;
+
+/// The maximum number of block hashes pruned per block while catching up with a lowered
+/// retention, see [`Pallet::set_block_hash_retention`].
+pub const MAX_BLOCK_HASHES_PRUNED: u32 = 8;
+
+/// The identity of a runtime migration: the blake2-256 hash of its name.
+pub type MigrationId = [u8; 32];
+
+/// The maximum length of a migration name accepted by [`Pallet::mark_migration_failed`].
+pub const MAX_MIGRATION_NAME_LEN: u32 = 128;
+
 pub use pallet::*;
 
 /// Do something when we should be setting the code.
@@ -298,6 +326,8 @@ pub mod pallet {
 			type BaseCallFilter = frame_support::traits::Everything;
 			type BlockHashCount = frame_support::traits::ConstU64<10>;
 			type OnSetCode = ();
+			type AllowDispatchPathRecording = frame_support::traits::ConstBool<true>;
+			type ScanUndecodableAccounts = frame_support::traits::ConstBool<false>;
 		}
 
 		/// Default configurations of this pallet in a solo-chain environment.
@@ -392,6 +422,12 @@ pub mod pallet {
 
 			/// The set code logic, just the default since we're not a parachain.
 			type OnSetCode = ();
+
+			/// The dispatch paths of events can't be recorded, this is opt-in.
+			type AllowDispatchPathRecording = frame_support::traits::ConstBool<false>;
+
+			/// Accounts are not scanned for undecodable values, this is opt-in.
+			type ScanUndecodableAccounts = frame_support::traits::ConstBool<false>;
 		}
 
 		/// Default configurations of this pallet in a relay-chain environment.
@@ -570,6 +606,20 @@ pub mod pallet {
 
 		/// The maximum number of consumers allowed on a single account.
 		type MaxConsumers: ConsumerLimits;
+
+		/// Whether the dispatch paths of events may be recorded, see
+		/// [`Pallet::set_dispatch_path_recording`].
+		///
+		/// While `false`, the recording can't be enabled and no dispatch path is stored.
+		#[pallet::constant]
+		type AllowDispatchPathRecording: Get<bool>;
+
+		/// Whether [`Account`] is scanned for undecodable values in `on_idle`, see
+		/// [`Pallet::scan_undecodable_accounts`].
+		///
+		/// The scan is limited by the remaining weight of the block, including its proof size.
+		#[pallet::constant]
+		type ScanUndecodableAccounts: Get<bool>;
 	}
 
 	#[pallet::pallet]
@@ -581,6 +631,25 @@ pub mod pallet {
 		fn integrity_test() {
 			T::BlockWeights::get().validate().expect("The weights are invalid.");
 		}
+
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			if !T::ScanUndecodableAccounts::get() {
+				return Weight::zero()
+			}
+			Self::scan_undecodable_accounts_within(remaining_weight)
+		}
+	}
+
+	#[pallet::extra_constants]
+	impl<T: Config> Pallet<T> {
+		/// The version of the layout of the events of this pallet.
+		///
+		/// It is increased whenever an event is added, removed or changes its fields, see
+		/// [`frame_support::event_schema`].
+		#[pallet::constant_name(EventSchemaVersion)]
+		pub fn event_schema_version() -> u32 {
+			3
+		}
 	}
 
 	#[pallet::call]
@@ -763,6 +832,144 @@ pub mod pallet {
 			let post = Self::do_apply_authorize_upgrade(code)?;
 			Ok(post)
 		}
+
+		/// Enable or disable a runtime feature flag.
+		///
+		/// Pallets gate soft-launched functionality behind
+		/// [`FeatureEnabled`](frame_support::traits::FeatureEnabled), which consults the flags
+		/// stored here. Toggling a flag takes effect in the same block. Setting a flag to its
+		/// current state is a no-op and emits no event.
+		///
+		/// This call requires Root origin.
+		#[pallet::call_index(12)]
+		#[pallet::weight((T::SystemWeightInfo::set_feature_flag(), DispatchClass::Operational))]
+		pub fn set_feature_flag(
+			origin: OriginFor<T>,
+			feature: FeatureId,
+			enabled: bool,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			if EnabledFeatures::<T>::mutate(|flags| flags.set(feature, enabled)) {
+				Self::deposit_event(Event::FeatureFlagToggled { feature, enabled });
+			}
+			Ok(())
+		}
+
+		/// Raise the nonce of an existing `account` to `nonce`.
+		///
+		/// Used to recover an account whose transactions are stuck behind a transaction that was
+		/// lost for good. The nonce can't be lowered, as that would allow replaying transactions
+		/// of the account.
+		///
+		/// This call requires Root origin.
+		#[pallet::call_index(13)]
+		#[pallet::weight((T::SystemWeightInfo::force_set_nonce(), DispatchClass::Operational))]
+		pub fn force_set_nonce(
+			origin: OriginFor<T>,
+			account: T::AccountId,
+			nonce: T::Nonce,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(Self::account_exists(&account), Error::<T>::NonExistentAccount);
+			let old_nonce = Account::<T>::mutate(&account, |info| {
+				let old_nonce = info.nonce;
+				if nonce > old_nonce {
+					info.nonce = nonce;
+				}
+				old_nonce
+			});
+			ensure!(nonce > old_nonce, Error::<T>::NonceNotIncreased);
+			Self::deposit_event(Event::NonceForceSet { account, old_nonce, new_nonce: nonce });
+			Ok(())
+		}
+
+		/// Set the number of block hashes to keep in [`BlockHash`], overriding
+		/// [`Config::BlockHashCount`].
+		///
+		/// `None` restores [`Config::BlockHashCount`]. The retention can't be set below
+		/// [`MIN_BLOCK_HASH_RETENTION`]. When it is lowered, the hashes outside of the new
+		/// retention are pruned over the following blocks, at most [`MAX_BLOCK_HASHES_PRUNED`]
+		/// per block. When it is raised, the hashes already pruned are not restored; use
+		/// [`Pallet::oldest_block_hash`] to learn which hashes are kept.
+		///
+		/// This call requires Root origin.
+		#[pallet::call_index(14)]
+		#[pallet::weight((
+			T::SystemWeightInfo::set_block_hash_retention(),
+			DispatchClass::Operational,
+		))]
+		pub fn set_block_hash_retention(
+			origin: OriginFor<T>,
+			retention: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			if let Some(retention) = retention {
+				ensure!(
+					retention >= MIN_BLOCK_HASH_RETENTION.into(),
+					Error::<T>::BlockHashRetentionTooLow
+				);
+			}
+
+			// The hashes kept under the previous retention, which are pruned from there on.
+			if BlockHashPruneCursor::<T>::get().is_none() {
+				let oldest = Self::block_number()
+					.saturating_sub(Self::block_hash_retention())
+					.saturating_sub(One::one());
+				BlockHashPruneCursor::<T>::put(oldest);
+			}
+			BlockHashRetention::<T>::set(retention);
+
+			Self::deposit_event(Event::BlockHashRetentionSet {
+				retention: Self::block_hash_retention(),
+			});
+			Ok(())
+		}
+
+		/// Enable or disable the recording of the nested dispatches that deposit events, see
+		/// [`dispatch_path`](crate::dispatch_path).
+		///
+		/// Takes effect for the events deposited from there on. Setting the recording to its
+		/// current state is a no-op and emits no event. The recording can only be enabled if
+		/// [`Config::AllowDispatchPathRecording`] is `true`.
+		///
+		/// This call requires Root origin.
+		#[pallet::call_index(15)]
+		#[pallet::weight((
+			T::SystemWeightInfo::set_dispatch_path_recording(),
+			DispatchClass::Operational,
+		))]
+		pub fn set_dispatch_path_recording(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(
+				!enabled || T::AllowDispatchPathRecording::get(),
+				Error::<T>::DispatchPathRecordingNotAllowed
+			);
+			if DispatchPathRecording::<T>::get() != enabled {
+				DispatchPathRecording::<T>::put(enabled);
+				Self::deposit_event(Event::DispatchPathRecordingSet { enabled });
+			}
+			Ok(())
+		}
+
+		/// Mark the runtime migration named `migration` as failed, so that it is skipped when
+		/// wrapped in `frame_executive::fail_safe::FailSafe`.
+		///
+		/// Used to skip a migration known to panic before the upgrade running it is enacted.
+		///
+		/// This call requires Root origin.
+		#[pallet::call_index(16)]
+		#[pallet::weight((
+			T::SystemWeightInfo::mark_migration_failed(migration.len() as u32),
+			DispatchClass::Operational,
+		))]
+		pub fn mark_migration_failed(
+			origin: OriginFor<T>,
+			migration: BoundedVec<u8, ConstU32<MAX_MIGRATION_NAME_LEN>>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			Self::note_failed_migration(&migration);
+			Ok(())
+		}
 	}
 
 	/// Event for the System pallet.
@@ -791,6 +998,18 @@ pub mod pallet {
 		TaskFailed { task: T::RuntimeTask, err: DispatchError },
 		/// An upgrade was authorized.
 		UpgradeAuthorized { code_hash: T::Hash, check_version: bool },
+		/// A runtime feature flag was enabled or disabled.
+		FeatureFlagToggled { feature: FeatureId, enabled: bool },
+		/// A storage entry could not be decoded.
+		UndecodableStorage { entry: UndecodableEntry },
+		/// The nonce of an account was raised by Root.
+		NonceForceSet { account: T::AccountId, old_nonce: T::Nonce, new_nonce: T::Nonce },
+		/// A runtime migration failed, or was marked as failed by Root, and is skipped.
+		MigrationFailed { migration: MigrationId },
+		/// The number of block hashes kept was changed.
+		BlockHashRetentionSet { retention: BlockNumberFor<T> },
+		/// The recording of the dispatch paths of events was enabled or disabled.
+		DispatchPathRecordingSet { enabled: bool },
 	}
 
 	/// Error for the System pallet
@@ -822,6 +1041,14 @@ pub mod pallet {
 		NothingAuthorized,
 		/// The submitted code is not authorized.
 		Unauthorized,
+		/// The account does not exist.
+		NonExistentAccount,
+		/// The nonce of an account can only be raised.
+		NonceNotIncreased,
+		/// The retention of block hashes is below [`MIN_BLOCK_HASH_RETENTION`].
+		BlockHashRetentionTooLow,
+		/// The runtime does not allow recording the dispatch paths of events.
+		DispatchPathRecordingNotAllowed,
 	}
 
 	/// Exposed trait-generic origin type.
@@ -902,6 +1129,16 @@ pub mod pallet {
 	#[pallet::getter(fn event_count)]
 	pub(super) type EventCount<T: Config> = StorageValue<_, EventIndex, ValueQuery>;
 
+	/// The nested dispatches that deposited the events in `Events<T>`, by event index.
+	///
+	/// Only holds the events deposited by nested calls while [`DispatchPathRecording`] is
+	/// enabled. Like `Events<T>`, it should never be read on chain.
+	#[pallet::storage]
+	#[pallet::whitelist_storage]
+	#[pallet::unbounded]
+	pub(super) type EventDispatchPaths<T: Config> =
+		StorageValue<_, Vec<(EventIndex, Vec<DispatchFrame>)>, ValueQuery>;
+
 	/// Mapping between a topic (represented by T::Hash) and a vector of indexes
 	/// of events in the `<Events<T>>` list.
 	///
@@ -943,6 +1180,52 @@ pub mod pallet {
 	pub(super) type AuthorizedUpgrade<T: Config> =
 		StorageValue<_, CodeUpgradeAuthorization<T>, OptionQuery>;
 
+	/// The set of runtime features currently enabled by governance.
+	#[pallet::storage]
+	#[pallet::getter(fn enabled_features)]
+	pub type EnabledFeatures<T: Config> = StorageValue<_, FeatureFlagSet, ValueQuery>;
+
+	/// Storage entries reported as undecodable, see [`OnUndecodable`].
+	///
+	/// Holds at most [`MAX_UNDECODABLE_ENTRIES`] distinct entries, further ones are only logged.
+	/// Can be cleared with `kill_storage` once the entries are fixed.
+	#[pallet::storage]
+	#[pallet::getter(fn undecodable_entries)]
+	pub type UndecodableEntries<T: Config> = StorageValue<
+		_,
+		BoundedVec<UndecodableEntry, ConstU32<MAX_UNDECODABLE_ENTRIES>>,
+		ValueQuery,
+	>;
+
+	/// The raw key of the last [`Account`] entry checked for an undecodable value in `on_idle`.
+	///
+	/// `None` while the next scan starts from the beginning of the map.
+	#[pallet::storage]
+	#[pallet::unbounded]
+	pub type UndecodableScanCursor<T: Config> = StorageValue<_, Vec<u8>, OptionQuery>;
+
+	/// Runtime migrations that failed and are skipped, see [`Pallet::note_failed_migration`] and
+	/// [`Pallet::mark_migration_failed`].
+	#[pallet::storage]
+	pub type FailedMigrations<T: Config> = StorageMap<_, Identity, MigrationId, ()>;
+
+	/// The number of block hashes to keep in [`BlockHash`], overriding
+	/// [`Config::BlockHashCount`]. Set with [`Pallet::set_block_hash_retention`].
+	#[pallet::storage]
+	pub type BlockHashRetention<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+	/// The oldest block whose hash may still be in [`BlockHash`], while the pruning of block
+	/// hashes catches up with a change of [`BlockHashRetention`].
+	#[pallet::storage]
+	pub(super) type BlockHashPruneCursor<T: Config> =
+		StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+	/// Whether the dispatch paths of events are recorded in [`EventDispatchPaths`]. Set with
+	/// [`Pallet::set_dispatch_path_recording`].
+	#[pallet::storage]
+	#[pallet::whitelist_storage]
+	pub type DispatchPathRecording<T: Config> = StorageValue<_, bool, ValueQuery>;
+
 	#[derive(frame_support::DefaultNoBound)]
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
@@ -1638,6 +1921,15 @@ impl<T: Config> Pallet<T> {
 
 		Events::<T>::append(event);
 
+		// Only read the flag for events deposited by nested calls.
+		let path = dispatch_path::current_dispatch_path();
+		if !path.is_empty() &&
+			T::AllowDispatchPathRecording::get() &&
+			DispatchPathRecording::<T>::get()
+		{
+			EventDispatchPaths::<T>::append((event_idx, path));
+		}
+
 		for topic in topics {
 			<EventTopics<T>>::append(topic, &(block_number, event_idx));
 		}
@@ -1678,6 +1970,23 @@ impl<T: Config> Pallet<T> {
 		});
 	}
 
+	/// The number of block hashes kept in [`BlockHash`].
+	///
+	/// This is [`Config::BlockHashCount`], unless overridden with
+	/// [`Pallet::set_block_hash_retention`].
+	pub fn block_hash_retention() -> BlockNumberFor<T> {
+		BlockHashRetention::<T>::get().unwrap_or_else(T::BlockHashCount::get)
+	}
+
+	/// The oldest block whose hash is kept in [`BlockHash`], besides the genesis block.
+	///
+	/// This can differ from the block [`Pallet::block_hash_retention`] blocks back while the
+	/// pruning catches up with a change of the retention.
+	pub fn oldest_block_hash() -> BlockNumberFor<T> {
+		BlockHashPruneCursor::<T>::get()
+			.unwrap_or_else(|| Self::block_number().saturating_sub(Self::block_hash_retention()))
+	}
+
 	/// Start the execution of a particular block.
 	pub fn initialize(number: &BlockNumberFor<T>, parent_hash: &T::Hash, digest: &generic::Digest) {
 		// populate environment
@@ -1756,12 +2065,33 @@ impl<T: Config> Pallet<T> {
 		let extrinsics_root = extrinsics_data_root::<T::Hashing>(extrinsics);
 
 		// move block hash pruning window by one block
-		let block_hash_count = T::BlockHashCount::get();
-		let to_remove = number.saturating_sub(block_hash_count).saturating_sub(One::one());
-
-		// keep genesis hash
-		if !to_remove.is_zero() {
-			<BlockHash<T>>::remove(to_remove);
+		let oldest_kept = number.saturating_sub(Self::block_hash_retention());
+		match BlockHashPruneCursor::<T>::get() {
+			None => {
+				let to_remove = oldest_kept.saturating_sub(One::one());
+
+				// keep genesis hash
+				if !to_remove.is_zero() {
+					<BlockHash<T>>::remove(to_remove);
+				}
+			},
+			// the retention was raised, wait for the window to reach the oldest hash kept
+			Some(cursor) if cursor > oldest_kept => {},
+			// the retention was lowered, prune the hashes outside of the window bit by bit
+			Some(cursor) => {
+				// keep genesis hash
+				let mut next = cursor.max(One::one());
+				let end = oldest_kept.min(cursor.saturating_add(MAX_BLOCK_HASHES_PRUNED.into()));
+				while next < end {
+					<BlockHash<T>>::remove(next);
+					next += One::one();
+				}
+				if next >= oldest_kept {
+					BlockHashPruneCursor::<T>::kill();
+				} else {
+					BlockHashPruneCursor::<T>::put(next);
+				}
+			},
 		}
 
 		let version = T::Version::get().state_version();
@@ -1819,6 +2149,25 @@ impl<T: Config> Pallet<T> {
 		Events::<T>::stream_iter()
 	}
 
+	/// Get the current events deposited by the runtime along with their dispatch paths.
+	///
+	/// The dispatch path of an event is empty unless it was recorded, see
+	/// [`dispatch_path`](crate::dispatch_path). Should only be called outside of the runtime block
+	/// execution, like [`Self::read_events_no_consensus`].
+	pub fn read_events_with_dispatch_paths() -> Vec<ExtendedEventRecord<T::RuntimeEvent, T::Hash>> {
+		let mut dispatch_paths = EventDispatchPaths::<T>::get().into_iter().peekable();
+		Self::read_events_no_consensus()
+			.enumerate()
+			.map(|(index, record)| {
+				let dispatch_path = dispatch_paths
+					.next_if(|(event_idx, _)| *event_idx as usize == index)
+					.map(|(_, path)| path)
+					.unwrap_or_default();
+				ExtendedEventRecord { record: *record, dispatch_path }
+			})
+			.collect()
+	}
+
 	/// Read and return the events of a specific pallet, as denoted by `E`.
 	///
 	/// This is useful for a pallet that wishes to read only the events it has deposited into
@@ -1870,6 +2219,7 @@ impl<T: Config> Pallet<T> {
 	pub fn reset_events() {
 		<Events<T>>::kill();
 		EventCount::<T>::kill();
+		EventDispatchPaths::<T>::kill();
 		let _ = <EventTopics<T>>::clear(u32::max_value(), None);
 	}
 
@@ -2089,6 +2439,64 @@ impl<T: Config> HandleLifetime<T::AccountId> for Consumer<T> {
 	}
 }
 
+impl<T: Config> RuntimeFeatureFlags for Pallet<T> {
+	fn is_enabled(feature: FeatureId) -> bool {
+		EnabledFeatures::<T>::get().is_enabled(feature)
+	}
+}
+
+impl<T: Config> OnUndecodable for Pallet<T> {
+	fn on_undecodable(entry: UndecodableEntry) {
+		let recorded = UndecodableEntries::<T>::mutate(|entries| {
+			!entries.contains(&entry) && entries.try_push(entry.clone()).is_ok()
+		});
+		if recorded {
+			Self::deposit_event(Event::UndecodableStorage { entry });
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Check at most `limit` entries of [`Account`] for undecodable values, continuing after
+	/// [`UndecodableScanCursor`].
+	///
+	/// Starts over once the whole map was checked. Returns the number of checked entries.
+	pub fn scan_undecodable_accounts(limit: u32) -> u32 {
+		let scan = Account::<T>::scan_undecodable::<Self>(UndecodableScanCursor::<T>::get(), limit);
+		UndecodableScanCursor::<T>::set(scan.cursor);
+		scan.scanned
+	}
+
+	/// Check as many entries of [`Account`] as fit in `remaining_weight`, at most
+	/// [`MAX_UNDECODABLE_SCANNED`]. Returns the weight used.
+	pub(crate) fn scan_undecodable_accounts_within(remaining_weight: Weight) -> Weight {
+		let base = T::SystemWeightInfo::scan_undecodable_accounts(0);
+		let per_entry = T::SystemWeightInfo::scan_undecodable_accounts(1).saturating_sub(base);
+		let Some(available) = remaining_weight.checked_sub(&base) else { return Weight::zero() };
+		let limit = (available.ref_time() / per_entry.ref_time().max(1))
+			.min(available.proof_size() / per_entry.proof_size().max(1))
+			.min(MAX_UNDECODABLE_SCANNED.into()) as u32;
+		if limit == 0 {
+			return Weight::zero()
+		}
+
+		let scanned = Self::scan_undecodable_accounts(limit);
+		T::SystemWeightInfo::scan_undecodable_accounts(scanned)
+	}
+
+	/// Record that the runtime migration named `migration` failed, so that it is skipped.
+	pub fn note_failed_migration(migration: &[u8]) {
+		let migration = sp_io::hashing::blake2_256(migration);
+		FailedMigrations::<T>::insert(migration, ());
+		Self::deposit_event(Event::MigrationFailed { migration });
+	}
+
+	/// Whether the runtime migration named `migration` failed before.
+	pub fn migration_failed(migration: &[u8]) -> bool {
+		FailedMigrations::<T>::contains_key(sp_io::hashing::blake2_256(migration))
+	}
+}
+
 impl<T: Config> BlockNumberProvider for Pallet<T> {
 	type BlockNumber = BlockNumberFor<T>;
 
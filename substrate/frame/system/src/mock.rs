@@ -69,6 +69,8 @@ parameter_types! {
 
 parameter_types! {
 	pub static Killed: Vec<u64> = vec![];
+	pub static AllowDispatchPathRecording: bool = true;
+	pub static ScanUndecodableAccounts: bool = true;
 }
 
 pub struct RecordKilled;
@@ -86,6 +88,8 @@ impl Config for Test {
 	type Version = Version;
 	type AccountData = u32;
 	type OnKilledAccount = RecordKilled;
+	type AllowDispatchPathRecording = AllowDispatchPathRecording;
+	type ScanUndecodableAccounts = ScanUndecodableAccounts;
 }
 
 pub type SysEvent = frame_system::Event<Test>;
@@ -17,9 +17,9 @@
 
 use crate::*;
 use frame_support::{
-	assert_noop, assert_ok,
+	assert_event_schema, assert_noop, assert_ok,
 	dispatch::{Pays, PostDispatchInfo, WithPostDispatchInfo},
-	traits::{OnRuntimeUpgrade, WhitelistedStorageKeys},
+	traits::{Hooks, OnRuntimeUpgrade, WhitelistedStorageKeys},
 };
 use std::collections::BTreeSet;
 
@@ -591,6 +591,59 @@ fn prunes_block_hash_mappings() {
 	})
 }
 
+#[test]
+fn block_hash_retention_can_be_changed() {
+	new_test_ext().execute_with(|| {
+		let import = |blocks: std::ops::RangeInclusive<u64>| {
+			for n in blocks {
+				System::initialize(&n, &[n as u8 - 1; 32].into(), &Default::default());
+				System::finalize();
+			}
+		};
+		let kept = |n: u64| System::block_hash(n) != H256::zero();
+
+		import(1..=15);
+		assert_eq!(System::block_hash_retention(), 10);
+		assert_eq!(System::oldest_block_hash(), 5);
+
+		assert_noop!(
+			System::set_block_hash_retention(RuntimeOrigin::signed(1), Some(5)),
+			BadOrigin
+		);
+		assert_noop!(
+			System::set_block_hash_retention(RawOrigin::Root.into(), Some(3)),
+			Error::<Test>::BlockHashRetentionTooLow
+		);
+
+		// Raising the retention keeps the hashes of the following blocks.
+		System::initialize(&16, &[15; 32].into(), &Default::default());
+		assert_ok!(System::set_block_hash_retention(RawOrigin::Root.into(), Some(20)));
+		System::assert_last_event(SysEvent::BlockHashRetentionSet { retention: 20 }.into());
+		System::finalize();
+		import(17..=20);
+		assert_eq!(System::oldest_block_hash(), 5);
+		assert!(!kept(4) && (5..20).all(kept));
+		import(21..=40);
+		assert_eq!(System::oldest_block_hash(), 20);
+		assert!(!kept(19) && (20..40).all(kept));
+
+		// Lowering the retention prunes the hashes outside of it over a few blocks.
+		System::initialize(&41, &[40; 32].into(), &Default::default());
+		assert_ok!(System::set_block_hash_retention(RawOrigin::Root.into(), Some(4)));
+		System::finalize();
+		assert_eq!(System::oldest_block_hash(), 28);
+		assert!(!kept(27) && (28..41).all(kept));
+		import(42..=43);
+		assert_eq!(System::oldest_block_hash(), 39);
+		assert!(!kept(38) && (39..43).all(kept));
+
+		// `None` restores `BlockHashCount`.
+		assert_ok!(System::set_block_hash_retention(RawOrigin::Root.into(), None));
+		System::assert_last_event(SysEvent::BlockHashRetentionSet { retention: 10 }.into());
+		assert_eq!(System::block_hash_retention(), 10);
+	})
+}
+
 #[test]
 fn set_code_checks_works() {
 	struct ReadRuntimeVersion(Vec<u8>);
@@ -715,6 +768,253 @@ fn set_code_via_authorization_works() {
 	});
 }
 
+#[test]
+fn set_feature_flag_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert!(!<System as RuntimeFeatureFlags>::is_enabled(7));
+
+		assert_noop!(System::set_feature_flag(RuntimeOrigin::signed(1), 7, true), BadOrigin);
+
+		assert_ok!(System::set_feature_flag(RawOrigin::Root.into(), 7, true));
+		System::assert_last_event(
+			SysEvent::FeatureFlagToggled { feature: 7, enabled: true }.into(),
+		);
+		assert!(<System as RuntimeFeatureFlags>::is_enabled(7));
+		assert!(!<System as RuntimeFeatureFlags>::is_enabled(8));
+
+		// Setting the flag to its current state does not emit another event.
+		let events = System::events().len();
+		assert_ok!(System::set_feature_flag(RawOrigin::Root.into(), 7, true));
+		assert_eq!(System::events().len(), events);
+
+		assert_ok!(System::set_feature_flag(RawOrigin::Root.into(), 7, false));
+		System::assert_last_event(
+			SysEvent::FeatureFlagToggled { feature: 7, enabled: false }.into(),
+		);
+		assert!(!<System as RuntimeFeatureFlags>::is_enabled(7));
+	});
+}
+
+#[test]
+fn set_dispatch_path_recording_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			System::set_dispatch_path_recording(RuntimeOrigin::signed(1), true),
+			BadOrigin
+		);
+
+		assert_ok!(System::set_dispatch_path_recording(RawOrigin::Root.into(), true));
+		System::assert_last_event(SysEvent::DispatchPathRecordingSet { enabled: true }.into());
+		assert!(DispatchPathRecording::<Test>::get());
+
+		// Setting the recording to its current state does not emit another event.
+		let events = System::events().len();
+		assert_ok!(System::set_dispatch_path_recording(RawOrigin::Root.into(), true));
+		assert_eq!(System::events().len(), events);
+
+		assert_ok!(System::set_dispatch_path_recording(RawOrigin::Root.into(), false));
+		System::assert_last_event(SysEvent::DispatchPathRecordingSet { enabled: false }.into());
+		assert!(!DispatchPathRecording::<Test>::get());
+	});
+}
+
+#[test]
+fn dispatch_path_recording_follows_the_runtime_config() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let frame = DispatchFrame { pallet_index: 3, item: 1 };
+		let remarked = RuntimeEvent::System(SysEvent::Remarked { sender: 1, hash: H256::zero() });
+		assert_ok!(System::set_dispatch_path_recording(RawOrigin::Root.into(), true));
+
+		AllowDispatchPathRecording::set(false);
+		assert_noop!(
+			System::set_dispatch_path_recording(RawOrigin::Root.into(), true),
+			Error::<Test>::DispatchPathRecordingNotAllowed
+		);
+		// Nothing is recorded, even though the recording was enabled before.
+		with_dispatch_frame(frame, || System::deposit_event(remarked.clone()));
+		assert!(EventDispatchPaths::<Test>::get().is_empty());
+		// The recording can still be disabled.
+		assert_ok!(System::set_dispatch_path_recording(RawOrigin::Root.into(), false));
+		assert!(!DispatchPathRecording::<Test>::get());
+		AllowDispatchPathRecording::set(true);
+	});
+}
+
+#[test]
+fn event_dispatch_paths_are_recorded() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let remarked =
+			|sender| RuntimeEvent::System(SysEvent::Remarked { sender, hash: H256::zero() });
+		let outer = DispatchFrame { pallet_index: 3, item: 1 };
+		let inner = DispatchFrame { pallet_index: 5, item: 0 };
+		let deposit_nested = |sender| {
+			with_dispatch_frame(outer, || {
+				with_dispatch_frame(inner, || System::deposit_event(remarked(sender)))
+			})
+		};
+
+		// Nothing is recorded while the recording is disabled.
+		deposit_nested(1);
+		assert!(EventDispatchPaths::<Test>::get().is_empty());
+
+		assert_ok!(System::set_dispatch_path_recording(RawOrigin::Root.into(), true));
+		System::reset_events();
+		System::deposit_event(remarked(2));
+		deposit_nested(3);
+		with_dispatch_frame(outer, || System::deposit_event(remarked(4)));
+
+		let events = System::read_events_with_dispatch_paths();
+		let paths: Vec<_> = events
+			.iter()
+			.map(|e| (e.record.event.clone(), e.dispatch_path.clone()))
+			.collect();
+		assert_eq!(
+			paths,
+			vec![
+				(remarked(2), vec![]),
+				(remarked(3), vec![outer, inner]),
+				(remarked(4), vec![outer]),
+			]
+		);
+		assert_eq!(
+			EventDispatchPaths::<Test>::get(),
+			vec![(1, vec![outer, inner]), (2, vec![outer])]
+		);
+
+		// The paths are reset along with the events.
+		System::reset_events();
+		assert!(EventDispatchPaths::<Test>::get().is_empty());
+	});
+}
+
+#[test]
+fn force_set_nonce_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(System::force_set_nonce(RuntimeOrigin::signed(1), 1, 5), BadOrigin);
+		assert_noop!(
+			System::force_set_nonce(RawOrigin::Root.into(), 1, 5),
+			Error::<Test>::NonExistentAccount
+		);
+
+		System::inc_providers(&1);
+		System::inc_account_nonce(&1);
+		assert_ok!(System::force_set_nonce(RawOrigin::Root.into(), 1, 5));
+		assert_eq!(System::account_nonce(&1), 5);
+		System::assert_last_event(
+			SysEvent::NonceForceSet { account: 1, old_nonce: 1, new_nonce: 5 }.into(),
+		);
+
+		// The nonce can't be lowered or set to its current value.
+		assert_noop!(
+			System::force_set_nonce(RawOrigin::Root.into(), 1, 5),
+			Error::<Test>::NonceNotIncreased
+		);
+		assert_noop!(
+			System::force_set_nonce(RawOrigin::Root.into(), 1, 2),
+			Error::<Test>::NonceNotIncreased
+		);
+	});
+}
+
+#[test]
+fn undecodable_entries_are_recorded() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let key = Account::<Test>::hashed_key_for(1);
+		storage::unhashed::put_raw(&key, &[1]);
+
+		assert_eq!(Account::<Test>::try_get_or_flag::<System, _>(1), None);
+		let entry = System::undecodable_entries()[0].clone();
+		assert_eq!(&entry.pallet[..], b"System");
+		assert_eq!(&entry.storage[..], b"Account");
+		assert_eq!(&entry.key[..], &key[..]);
+		System::assert_last_event(SysEvent::UndecodableStorage { entry }.into());
+
+		// The same entry is recorded once.
+		let events = System::events().len();
+		assert_eq!(Account::<Test>::try_get_or_flag::<System, _>(1), None);
+		assert_eq!(System::events().len(), events);
+		assert_eq!(System::undecodable_entries().len(), 1);
+	});
+}
+
+#[test]
+fn full_undecodable_list_emits_no_event() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let entries: Vec<_> = (0..MAX_UNDECODABLE_ENTRIES as u8)
+			.map(|i| UndecodableEntry {
+				pallet: BoundedVec::truncate_from(b"System".to_vec()),
+				storage: BoundedVec::truncate_from(b"Account".to_vec()),
+				key: BoundedVec::truncate_from(vec![i]),
+				expected: Default::default(),
+			})
+			.collect();
+		UndecodableEntries::<Test>::put(BoundedVec::truncate_from(entries.clone()));
+		storage::unhashed::put_raw(&Account::<Test>::hashed_key_for(1), &[1]);
+
+		assert_eq!(Account::<Test>::try_get_or_flag::<System, _>(1), None);
+		assert_eq!(Account::<Test>::try_get_or_flag::<System, _>(1), None);
+		assert!(System::events().is_empty());
+		assert_eq!(System::undecodable_entries().into_inner(), entries);
+	});
+}
+
+#[test]
+fn undecodable_accounts_are_scanned_on_idle() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		for who in 1..=3 {
+			System::inc_providers(&who);
+		}
+		let key = Account::<Test>::hashed_key_for(2);
+		storage::unhashed::put_raw(&key, &[1]);
+
+		let scan_weight = <Test as Config>::SystemWeightInfo::scan_undecodable_accounts;
+		let (base, per_entry) = (scan_weight(0), scan_weight(1) - scan_weight(0));
+
+		// Disabled by the runtime.
+		ScanUndecodableAccounts::set(false);
+		assert_eq!(System::on_idle(1, Weight::MAX), Weight::zero());
+		assert_eq!(UndecodableScanCursor::<Test>::get(), None);
+		ScanUndecodableAccounts::set(true);
+
+		assert_eq!(System::on_idle(1, scan_weight(1)), scan_weight(1));
+		assert!(UndecodableScanCursor::<Test>::get().is_some());
+
+		// Not enough weight to check an entry.
+		assert_eq!(System::on_idle(1, base), Weight::zero());
+		// Not enough proof size to check an entry.
+		let no_proof_size = Weight::from_parts(u64::MAX, base.proof_size());
+		let one_entry = no_proof_size + Weight::from_parts(0, per_entry.proof_size());
+		assert_eq!(System::on_idle(1, one_entry), scan_weight(1));
+		assert_eq!(System::on_idle(1, no_proof_size), Weight::zero());
+
+		assert_eq!(System::on_idle(1, Weight::MAX), scan_weight(1));
+		assert_eq!(UndecodableScanCursor::<Test>::get(), None);
+		let entry = System::undecodable_entries()[0].clone();
+		assert_eq!(&entry.key[..], &key[..]);
+		assert_eq!(
+			System::events()
+				.into_iter()
+				.filter(|e| e.event == SysEvent::UndecodableStorage { entry: entry.clone() }.into())
+				.count(),
+			1
+		);
+
+		// The next scan starts over without recording the entry again.
+		let events = System::events().len();
+		System::on_idle(1, Weight::MAX);
+		assert_eq!(System::events().len(), events);
+		assert_eq!(System::undecodable_entries().len(), 1);
+	});
+}
+
 #[test]
 fn runtime_upgraded_with_set_storage() {
 	let executor = substrate_test_runtime_client::new_native_or_wasm_executor();
@@ -836,3 +1136,52 @@ fn last_runtime_upgrade_spec_version_usage() {
 		}
 	}
 }
+
+#[test]
+#[cfg(not(feature = "experimental"))]
+fn event_schema_is_recorded() {
+	assert_event_schema!(Event<Test>, System::event_schema_version(), {
+		1 => [
+			"0: ExtrinsicSuccess { dispatch_info: DispatchInfo }",
+			"1: ExtrinsicFailed { dispatch_error: DispatchError, dispatch_info: DispatchInfo }",
+			"2: CodeUpdated",
+			"3: NewAccount { account: T::AccountId }",
+			"4: KilledAccount { account: T::AccountId }",
+			"5: Remarked { sender: T::AccountId, hash: T::Hash }",
+			"6: UpgradeAuthorized { code_hash: T::Hash, check_version: bool }",
+			"7: FeatureFlagToggled { feature: FeatureId, enabled: bool }",
+			"8: UndecodableStorage { entry: UndecodableEntry }",
+			"9: NonceForceSet { account: T::AccountId, old_nonce: T::Nonce, new_nonce: T::Nonce }",
+			"10: MigrationFailed { migration: MigrationId }",
+		],
+		2 => [
+			"0: ExtrinsicSuccess { dispatch_info: DispatchInfo }",
+			"1: ExtrinsicFailed { dispatch_error: DispatchError, dispatch_info: DispatchInfo }",
+			"2: CodeUpdated",
+			"3: NewAccount { account: T::AccountId }",
+			"4: KilledAccount { account: T::AccountId }",
+			"5: Remarked { sender: T::AccountId, hash: T::Hash }",
+			"6: UpgradeAuthorized { code_hash: T::Hash, check_version: bool }",
+			"7: FeatureFlagToggled { feature: FeatureId, enabled: bool }",
+			"8: UndecodableStorage { entry: UndecodableEntry }",
+			"9: NonceForceSet { account: T::AccountId, old_nonce: T::Nonce, new_nonce: T::Nonce }",
+			"10: MigrationFailed { migration: MigrationId }",
+			"11: BlockHashRetentionSet { retention: BlockNumberFor<T> }",
+		],
+		3 => [
+			"0: ExtrinsicSuccess { dispatch_info: DispatchInfo }",
+			"1: ExtrinsicFailed { dispatch_error: DispatchError, dispatch_info: DispatchInfo }",
+			"2: CodeUpdated",
+			"3: NewAccount { account: T::AccountId }",
+			"4: KilledAccount { account: T::AccountId }",
+			"5: Remarked { sender: T::AccountId, hash: T::Hash }",
+			"6: UpgradeAuthorized { code_hash: T::Hash, check_version: bool }",
+			"7: FeatureFlagToggled { feature: FeatureId, enabled: bool }",
+			"8: UndecodableStorage { entry: UndecodableEntry }",
+			"9: NonceForceSet { account: T::AccountId, old_nonce: T::Nonce, new_nonce: T::Nonce }",
+			"10: MigrationFailed { migration: MigrationId }",
+			"11: BlockHashRetentionSet { retention: BlockNumberFor<T> }",
+			"12: DispatchPathRecordingSet { enabled: bool }",
+		],
+	});
+}
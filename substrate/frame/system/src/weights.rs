@@ -59,6 +59,12 @@ pub trait WeightInfo {
 	fn kill_prefix(p: u32, ) -> Weight;
 	fn authorize_upgrade() -> Weight;
 	fn apply_authorized_upgrade() -> Weight;
+	fn set_feature_flag() -> Weight;
+	fn force_set_nonce() -> Weight;
+	fn set_block_hash_retention() -> Weight;
+	fn set_dispatch_path_recording() -> Weight;
+	fn scan_undecodable_accounts(n: u32, ) -> Weight;
+	fn mark_migration_failed(n: u32, ) -> Weight;
 }
 
 /// Weights for frame_system using the Substrate node and recommended hardware.
@@ -178,6 +184,89 @@ impl<T: crate::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	/// Storage: `System::EnabledFeatures` (r:1 w:1)
+	/// Proof: `System::EnabledFeatures` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	fn set_feature_flag() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1517`
+		// Minimum execution time: 7_215_000 picoseconds.
+		Weight::from_parts(7_542_000, 0)
+			.saturating_add(Weight::from_parts(0, 1517))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn force_set_nonce() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `52`
+		//  Estimated: `3593`
+		// Minimum execution time: 8_391_000 picoseconds.
+		Weight::from_parts(8_804_000, 0)
+			.saturating_add(Weight::from_parts(0, 3593))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `System::BlockHashPruneCursor` (r:1 w:1)
+	/// Proof: `System::BlockHashPruneCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::BlockHashRetention` (r:1 w:1)
+	/// Proof: `System::BlockHashRetention` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_block_hash_retention() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1489`
+		// Minimum execution time: 7_926_000 picoseconds.
+		Weight::from_parts(8_283_000, 0)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `System::DispatchPathRecording` (r:1 w:1)
+	/// Proof: `System::DispatchPathRecording` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn set_dispatch_path_recording() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1486`
+		// Minimum execution time: 6_872_000 picoseconds.
+		Weight::from_parts(7_193_000, 0)
+			.saturating_add(Weight::from_parts(0, 1486))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `System::UndecodableScanCursor` (r:1 w:1)
+	/// Proof: `System::UndecodableScanCursor` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:64 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `System::UndecodableEntries` (r:1 w:1)
+	/// Proof: `System::UndecodableEntries` (`max_values`: Some(1), `max_size`: Some(12481), added: 12976, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 64]`.
+	fn scan_undecodable_accounts(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1170 + n * (111 ±0)`
+		//  Estimated: `13966 + n * (2603 ±0)`
+		// Minimum execution time: 5_116_000 picoseconds.
+		Weight::from_parts(5_421_348, 13966)
+			// Standard Error: 7_163
+			.saturating_add(Weight::from_parts(11_845_204, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(n.into()))
+	}
+	/// Storage: `System::FailedMigrations` (r:0 w:1)
+	/// Proof: `System::FailedMigrations` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 128]`.
+	fn mark_migration_failed(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_902_000 picoseconds.
+		Weight::from_parts(9_385_117, 0)
+			// Standard Error: 214
+			.saturating_add(Weight::from_parts(1_362, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -296,4 +385,87 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2))
 			.saturating_add(RocksDbWeight::get().writes(3))
 	}
+	/// Storage: `System::EnabledFeatures` (r:1 w:1)
+	/// Proof: `System::EnabledFeatures` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	fn set_feature_flag() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1517`
+		// Minimum execution time: 7_215_000 picoseconds.
+		Weight::from_parts(7_542_000, 0)
+			.saturating_add(Weight::from_parts(0, 1517))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn force_set_nonce() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `52`
+		//  Estimated: `3593`
+		// Minimum execution time: 8_391_000 picoseconds.
+		Weight::from_parts(8_804_000, 0)
+			.saturating_add(Weight::from_parts(0, 3593))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `System::BlockHashPruneCursor` (r:1 w:1)
+	/// Proof: `System::BlockHashPruneCursor` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::BlockHashRetention` (r:1 w:1)
+	/// Proof: `System::BlockHashRetention` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_block_hash_retention() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1489`
+		// Minimum execution time: 7_926_000 picoseconds.
+		Weight::from_parts(8_283_000, 0)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: `System::DispatchPathRecording` (r:1 w:1)
+	/// Proof: `System::DispatchPathRecording` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn set_dispatch_path_recording() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1486`
+		// Minimum execution time: 6_872_000 picoseconds.
+		Weight::from_parts(7_193_000, 0)
+			.saturating_add(Weight::from_parts(0, 1486))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `System::UndecodableScanCursor` (r:1 w:1)
+	/// Proof: `System::UndecodableScanCursor` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:64 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `System::UndecodableEntries` (r:1 w:1)
+	/// Proof: `System::UndecodableEntries` (`max_values`: Some(1), `max_size`: Some(12481), added: 12976, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 64]`.
+	fn scan_undecodable_accounts(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1170 + n * (111 ±0)`
+		//  Estimated: `13966 + n * (2603 ±0)`
+		// Minimum execution time: 5_116_000 picoseconds.
+		Weight::from_parts(5_421_348, 13966)
+			// Standard Error: 7_163
+			.saturating_add(Weight::from_parts(11_845_204, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(n.into()))
+	}
+	/// Storage: `System::FailedMigrations` (r:0 w:1)
+	/// Proof: `System::FailedMigrations` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 128]`.
+	fn mark_migration_failed(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_902_000 picoseconds.
+		Weight::from_parts(9_385_117, 0)
+			// Standard Error: 214
+			.saturating_add(Weight::from_parts(1_362, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
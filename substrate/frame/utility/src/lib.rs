@@ -61,6 +61,7 @@ use frame_support::{
 	dispatch::{extract_actual_weight, GetDispatchInfo, PostDispatchInfo},
 	traits::{IsSubType, OriginTrait, UnfilteredDispatchable},
 };
+use frame_system::{with_dispatch_frame, DispatchFrame};
 use sp_core::TypeId;
 use sp_io::hashing::blake2_256;
 use sp_runtime::traits::{BadOrigin, Dispatchable, TrailingZeroInput};
@@ -216,11 +217,13 @@ pub mod pallet {
 			for (index, call) in calls.into_iter().enumerate() {
 				let info = call.get_dispatch_info();
 				// If origin is root, don't apply any dispatch filters; root can call anything.
-				let result = if is_root {
-					call.dispatch_bypass_filter(origin.clone())
-				} else {
-					call.dispatch(origin.clone())
-				};
+				let result = with_dispatch_frame(DispatchFrame::new::<Self>(index as u32), || {
+					if is_root {
+						call.dispatch_bypass_filter(origin.clone())
+					} else {
+						call.dispatch(origin.clone())
+					}
+				});
 				// Add the weight of this call.
 				weight = weight.saturating_add(extract_actual_weight(&result, &info));
 				if let Err(e) = result {
@@ -338,19 +341,21 @@ pub mod pallet {
 			for (index, call) in calls.into_iter().enumerate() {
 				let info = call.get_dispatch_info();
 				// If origin is root, bypass any dispatch filter; root can call anything.
-				let result = if is_root {
-					call.dispatch_bypass_filter(origin.clone())
-				} else {
-					let mut filtered_origin = origin.clone();
-					// Don't allow users to nest `batch_all` calls.
-					filtered_origin.add_filter(
-						move |c: &<T as frame_system::Config>::RuntimeCall| {
-							let c = <T as Config>::RuntimeCall::from_ref(c);
-							!matches!(c.is_sub_type(), Some(Call::batch_all { .. }))
-						},
-					);
-					call.dispatch(filtered_origin)
-				};
+				let result = with_dispatch_frame(DispatchFrame::new::<Self>(index as u32), || {
+					if is_root {
+						call.dispatch_bypass_filter(origin.clone())
+					} else {
+						let mut filtered_origin = origin.clone();
+						// Don't allow users to nest `batch_all` calls.
+						filtered_origin.add_filter(
+							move |c: &<T as frame_system::Config>::RuntimeCall| {
+								let c = <T as Config>::RuntimeCall::from_ref(c);
+								!matches!(c.is_sub_type(), Some(Call::batch_all { .. }))
+							},
+						);
+						call.dispatch(filtered_origin)
+					}
+				});
 				// Add the weight of this call.
 				weight = weight.saturating_add(extract_actual_weight(&result, &info));
 				result.map_err(|mut err| {
@@ -446,14 +451,16 @@ pub mod pallet {
 			let mut weight = Weight::zero();
 			// Track failed dispatch occur.
 			let mut has_error: bool = false;
-			for call in calls.into_iter() {
+			for (index, call) in calls.into_iter().enumerate() {
 				let info = call.get_dispatch_info();
 				// If origin is root, don't apply any dispatch filters; root can call anything.
-				let result = if is_root {
-					call.dispatch_bypass_filter(origin.clone())
-				} else {
-					call.dispatch(origin.clone())
-				};
+				let result = with_dispatch_frame(DispatchFrame::new::<Self>(index as u32), || {
+					if is_root {
+						call.dispatch_bypass_filter(origin.clone())
+					} else {
+						call.dispatch(origin.clone())
+					}
+				});
 				// Add the weight of this call.
 				weight = weight.saturating_add(extract_actual_weight(&result, &info));
 				if let Err(e) = result {
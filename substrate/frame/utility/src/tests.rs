@@ -745,6 +745,37 @@ fn force_batch_works() {
 	});
 }
 
+#[test]
+fn batch_records_dispatch_paths() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(System::set_dispatch_path_recording(RuntimeOrigin::root(), true));
+		assert_ok!(Utility::batch(
+			RuntimeOrigin::signed(1),
+			vec![
+				call_transfer(2, 5),
+				RuntimeCall::Utility(UtilityCall::batch { calls: vec![call_transfer(2, 5)] }),
+			]
+		));
+
+		let frame = |item| frame_system::DispatchFrame::new::<Utility>(item);
+		let transfer_paths: Vec<_> = System::read_events_with_dispatch_paths()
+			.into_iter()
+			.filter(|e| {
+				matches!(
+					e.record.event,
+					RuntimeEvent::Balances(pallet_balances::Event::Transfer { .. })
+				)
+			})
+			.map(|e| e.dispatch_path)
+			.collect();
+		assert_eq!(transfer_paths, vec![vec![frame(0)], vec![frame(1), frame(0)]]);
+		// The events of the batch itself are not nested.
+		System::assert_last_event(utility::Event::BatchCompleted.into());
+		let last = System::read_events_with_dispatch_paths().pop().unwrap();
+		assert!(last.dispatch_path.is_empty());
+	});
+}
+
 #[test]
 fn none_origin_does_not_work() {
 	new_test_ext().execute_with(|| {
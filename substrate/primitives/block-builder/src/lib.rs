@@ -19,12 +19,25 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
 use sp_inherents::{CheckInherentsResult, InherentData};
-use sp_runtime::{traits::Block as BlockT, ApplyExtrinsicResult};
+use sp_runtime::{traits::Block as BlockT, ApplyExtrinsicResult, RuntimeDebug};
+
+/// The storage proof size recorded while applying an extrinsic.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ExtrinsicProofSize {
+	/// The index of the extrinsic in the block.
+	pub index: u32,
+	/// The length of the encoded extrinsic.
+	pub encoded_len: u32,
+	/// How much the storage proof grew while applying the extrinsic.
+	pub proof_size: u64,
+}
 
 sp_api::decl_runtime_apis! {
 	/// The `BlockBuilder` api trait that provides the required functionality for building a block.
-	#[api_version(6)]
+	#[api_version(7)]
 	pub trait BlockBuilder {
 		/// Apply the given extrinsic.
 		///
@@ -48,5 +61,11 @@ sp_api::decl_runtime_apis! {
 
 		/// Check that the inherents are valid. The inherent data will vary from chain to chain.
 		fn check_inherents(block: Block, data: InherentData) -> CheckInherentsResult;
+
+		/// The storage proof size recorded for the extrinsics applied so far, in order.
+		///
+		/// Only runtimes built with the proof size breakdown of `frame-executive` record anything,
+		/// others return an empty list. Must be called before [`Self::finalize_block`].
+		fn extrinsic_proof_sizes() -> sp_std::vec::Vec<ExtrinsicProofSize>;
 	}
 }
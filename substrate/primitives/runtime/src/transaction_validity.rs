@@ -127,6 +127,17 @@ pub enum UnknownTransaction {
 	NoUnsignedValidator,
 	/// Any other custom unknown validity that is not covered by this enum.
 	Custom(u8),
+	/// The block author leaves the transaction out of the block it builds.
+	///
+	/// The transaction might be valid, and might be included by other block authors.
+	SkippedByAuthor,
+}
+
+impl UnknownTransaction {
+	/// Returns if the block author left the transaction out of the block.
+	pub fn skipped_by_author(&self) -> bool {
+		matches!(self, Self::SkippedByAuthor)
+	}
 }
 
 impl From<UnknownTransaction> for &'static str {
@@ -137,6 +148,8 @@ impl From<UnknownTransaction> for &'static str {
 			UnknownTransaction::NoUnsignedValidator =>
 				"Could not find an unsigned validator for the unsigned transaction",
 			UnknownTransaction::Custom(_) => "UnknownTransaction custom error",
+			UnknownTransaction::SkippedByAuthor =>
+				"Transaction is not included by the block author",
 		}
 	}
 }
@@ -168,6 +181,14 @@ impl TransactionValidityError {
 			Self::Unknown(_) => false,
 		}
 	}
+
+	/// Returns `true` if the block author left the transaction out of the block.
+	pub fn skipped_by_author(&self) -> bool {
+		match self {
+			Self::Invalid(_) => false,
+			Self::Unknown(e) => e.skipped_by_author(),
+		}
+	}
 }
 
 impl From<TransactionValidityError> for &'static str {
@@ -17,11 +17,11 @@
 
 //! Concrete externalities implementation.
 
-#[cfg(feature = "std")]
-use crate::overlayed_changes::OverlayedExtensions;
 use crate::{
 	backend::Backend, IndexOperation, IterArgs, OverlayedChanges, StorageKey, StorageValue,
 };
+#[cfg(feature = "std")]
+use crate::{overlayed_changes::OverlayedExtensions, ReadWriteTracker};
 use codec::{Encode, EncodeAppend};
 use hash_db::Hasher;
 #[cfg(feature = "std")]
@@ -32,6 +32,8 @@ use sp_core::storage::{
 use sp_externalities::{Extension, ExtensionStore, Externalities, MultiRemovalResults};
 
 use crate::{log_error, trace, warn};
+#[cfg(feature = "std")]
+use parking_lot::Mutex;
 use sp_std::{
 	any::{Any, TypeId},
 	boxed::Box,
@@ -105,6 +107,9 @@ where
 	/// Extensions registered with this instance.
 	#[cfg(feature = "std")]
 	extensions: Option<OverlayedExtensions<'a>>,
+	/// Tracks the DB reads and writes of the storage accesses, if set.
+	#[cfg(feature = "std")]
+	read_write_tracker: Option<&'a Mutex<ReadWriteTracker>>,
 }
 
 impl<'a, H, B> Ext<'a, H, B>
@@ -130,10 +135,73 @@ where
 			backend,
 			id: rand::random(),
 			extensions: extensions.map(OverlayedExtensions::new),
+			read_write_tracker: None,
+		}
+	}
+
+	/// Track the DB reads and writes of the storage accesses with `tracker`, instead of asking the
+	/// backend for them.
+	#[cfg(feature = "std")]
+	pub fn with_read_write_tracker(mut self, tracker: &'a Mutex<ReadWriteTracker>) -> Self {
+		self.read_write_tracker = Some(tracker);
+		self
+	}
+
+	/// Track a read of `key` of `child_info`, or of the main trie.
+	fn track_read(&self, child_info: Option<&ChildInfo>, key: &[u8]) {
+		#[cfg(feature = "std")]
+		if let Some(tracker) = self.read_write_tracker {
+			tracker.lock().add_read(child_info.map(ChildInfo::storage_key), key);
+		}
+		#[cfg(not(feature = "std"))]
+		let _ = (child_info, key);
+	}
+
+	/// Track a write of `key` of `child_info`, or of the main trie.
+	fn track_write(&self, child_info: Option<&ChildInfo>, key: &[u8]) {
+		#[cfg(feature = "std")]
+		if let Some(tracker) = self.read_write_tracker {
+			tracker.lock().add_write(child_info.map(ChildInfo::storage_key), key);
 		}
+		#[cfg(not(feature = "std"))]
+		let _ = (child_info, key);
+	}
+
+	/// Track the removal of the keys of the overlay of `child_info`, or of the main trie, that
+	/// start with `prefix` and are about to be cleared.
+	fn track_overlay_removal(&self, child_info: Option<&ChildInfo>, prefix: &[u8]) {
+		#[cfg(feature = "std")]
+		if self.read_write_tracker.map_or(false, |tracker| tracker.lock().is_enabled()) {
+			let keys = match child_info {
+				Some(child_info) => self
+					.overlay
+					.child_changes(child_info.storage_key())
+					.map(|(changes, _)| keys_with_prefix(changes, prefix))
+					.unwrap_or_default(),
+				None => keys_with_prefix(self.overlay.changes(), prefix),
+			};
+			for key in keys {
+				self.track_read(child_info, &key);
+				self.track_write(child_info, &key);
+			}
+		}
+		#[cfg(not(feature = "std"))]
+		let _ = (child_info, prefix);
 	}
 }
 
+/// The keys starting with `prefix` of the overlay `changes` that set a value.
+#[cfg(feature = "std")]
+fn keys_with_prefix<'a>(
+	changes: impl Iterator<Item = (&'a StorageKey, &'a crate::overlayed_changes::OverlayedValue)>,
+	prefix: &[u8],
+) -> Vec<StorageKey> {
+	changes
+		.filter(|(key, value)| key.starts_with(prefix) && value.value().is_some())
+		.map(|(key, _)| key.clone())
+		.collect()
+}
+
 #[cfg(test)]
 impl<'a, H, B> Ext<'a, H, B>
 where
@@ -169,6 +237,7 @@ where
 
 	fn storage(&self, key: &[u8]) -> Option<StorageValue> {
 		let _guard = guard();
+		self.track_read(None, key);
 		let result = self
 			.overlay
 			.storage(key)
@@ -195,6 +264,7 @@ where
 
 	fn storage_hash(&self, key: &[u8]) -> Option<Vec<u8>> {
 		let _guard = guard();
+		self.track_read(None, key);
 		let result = self
 			.overlay
 			.storage(key)
@@ -213,6 +283,7 @@ where
 
 	fn child_storage(&self, child_info: &ChildInfo, key: &[u8]) -> Option<StorageValue> {
 		let _guard = guard();
+		self.track_read(Some(child_info), key);
 		let result = self
 			.overlay
 			.child_storage(child_info, key)
@@ -235,6 +306,7 @@ where
 
 	fn child_storage_hash(&self, child_info: &ChildInfo, key: &[u8]) -> Option<Vec<u8>> {
 		let _guard = guard();
+		self.track_read(Some(child_info), key);
 		let result = self
 			.overlay
 			.child_storage(child_info, key)
@@ -257,6 +329,7 @@ where
 
 	fn exists_storage(&self, key: &[u8]) -> bool {
 		let _guard = guard();
+		self.track_read(None, key);
 		let result = match self.overlay.storage(key) {
 			Some(x) => x.is_some(),
 			_ => self.backend.exists_storage(key).expect(EXT_NOT_ALLOWED_TO_FAIL),
@@ -275,6 +348,7 @@ where
 
 	fn exists_child_storage(&self, child_info: &ChildInfo, key: &[u8]) -> bool {
 		let _guard = guard();
+		self.track_read(Some(child_info), key);
 
 		let result = match self.overlay.child_storage(child_info, key) {
 			Some(x) => x.is_some(),
@@ -296,6 +370,7 @@ where
 	}
 
 	fn next_storage_key(&self, key: &[u8]) -> Option<StorageKey> {
+		self.track_read(None, key);
 		let mut next_backend_key =
 			self.backend.next_storage_key(key).expect(EXT_NOT_ALLOWED_TO_FAIL);
 		let mut overlay_changes = self.overlay.iter_after(key).peekable();
@@ -334,6 +409,7 @@ where
 	}
 
 	fn next_child_storage_key(&self, child_info: &ChildInfo, key: &[u8]) -> Option<StorageKey> {
+		self.track_read(Some(child_info), key);
 		let mut next_backend_key = self
 			.backend
 			.next_child_storage_key(child_info, key)
@@ -396,6 +472,7 @@ where
 			),
 		);
 
+		self.track_write(None, &key);
 		self.overlay.set_storage(key, value);
 	}
 
@@ -415,6 +492,7 @@ where
 		);
 		let _guard = guard();
 
+		self.track_write(Some(child_info), &key);
 		self.overlay.set_child_storage(child_info, key, value);
 	}
 
@@ -431,6 +509,7 @@ where
 			child_info = %HexDisplay::from(&child_info.storage_key()),
 		);
 		let _guard = guard();
+		self.track_overlay_removal(Some(child_info), &[]);
 		let overlay = self.overlay.clear_child_storage(child_info);
 		let (maybe_cursor, backend, loops) =
 			self.limit_remove_from_backend(Some(child_info), None, maybe_limit, maybe_cursor);
@@ -459,6 +538,7 @@ where
 			return MultiRemovalResults { maybe_cursor: None, backend: 0, unique: 0, loops: 0 }
 		}
 
+		self.track_overlay_removal(None, prefix);
 		let overlay = self.overlay.clear_prefix(prefix);
 		let (maybe_cursor, backend, loops) =
 			self.limit_remove_from_backend(None, Some(prefix), maybe_limit, maybe_cursor);
@@ -481,6 +561,7 @@ where
 		);
 		let _guard = guard();
 
+		self.track_overlay_removal(Some(child_info), prefix);
 		let overlay = self.overlay.clear_child_prefix(child_info, prefix);
 		let (maybe_cursor, backend, loops) = self.limit_remove_from_backend(
 			Some(child_info),
@@ -502,6 +583,8 @@ where
 
 		let _guard = guard();
 
+		self.track_read(None, &key);
+		self.track_write(None, &key);
 		let backend = &mut self.backend;
 		let current_value = self.overlay.value_mut_or_insert_with(&key, || {
 			backend.storage(&key).expect(EXT_NOT_ALLOWED_TO_FAIL).unwrap_or_default()
@@ -600,6 +683,10 @@ where
 			.drain_storage_changes(self.backend, Default::default())
 			.expect(EXT_NOT_ALLOWED_TO_FAIL);
 		self.backend.wipe().expect(EXT_NOT_ALLOWED_TO_FAIL);
+		#[cfg(feature = "std")]
+		if let Some(tracker) = self.read_write_tracker {
+			tracker.lock().wipe();
+		}
 		self.overlay
 			.enter_runtime()
 			.expect("We have reset the overlay above, so we can not be in the runtime; qed");
@@ -629,18 +716,34 @@ where
 	}
 
 	fn read_write_count(&self) -> (u32, u32, u32, u32) {
+		#[cfg(feature = "std")]
+		if let Some(tracker) = self.read_write_tracker {
+			return tracker.lock().read_write_count()
+		}
 		self.backend.read_write_count()
 	}
 
 	fn reset_read_write_count(&mut self) {
+		#[cfg(feature = "std")]
+		if let Some(tracker) = self.read_write_tracker {
+			return tracker.lock().reset()
+		}
 		self.backend.reset_read_write_count()
 	}
 
 	fn get_whitelist(&self) -> Vec<TrackedStorageKey> {
+		#[cfg(feature = "std")]
+		if let Some(tracker) = self.read_write_tracker {
+			return tracker.lock().whitelist()
+		}
 		self.backend.get_whitelist()
 	}
 
 	fn set_whitelist(&mut self, new: Vec<TrackedStorageKey>) {
+		#[cfg(feature = "std")]
+		if let Some(tracker) = self.read_write_tracker {
+			return tracker.lock().set_whitelist(new)
+		}
 		self.backend.set_whitelist(new)
 	}
 
@@ -695,12 +798,14 @@ where
 				maybe_next_key = Some(key);
 				break
 			}
+			self.track_read(child_info, &key);
 			let overlay = match child_info {
 				Some(child_info) => self.overlay.child_storage(child_info, &key),
 				None => self.overlay.storage(&key),
 			};
 			if !matches!(overlay, Some(None)) {
 				// not pending deletion from the backend - delete it.
+				self.track_write(child_info, &key);
 				if let Some(child_info) = child_info {
 					self.overlay.set_child_storage(child_info, key, None);
 				} else {
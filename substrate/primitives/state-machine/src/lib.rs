@@ -30,6 +30,8 @@ mod in_memory_backend;
 pub(crate) mod overlayed_changes;
 #[cfg(feature = "std")]
 mod read_only;
+#[cfg(feature = "std")]
+mod read_write_tracker;
 mod stats;
 #[cfg(feature = "std")]
 mod testing;
@@ -144,6 +146,7 @@ mod std_reexport {
 		basic::BasicExternalities,
 		in_memory_backend::new_in_mem,
 		read_only::{InspectState, ReadOnlyExternalities},
+		read_write_tracker::ReadWriteTracker,
 		testing::TestExternalities,
 		trie_backend::create_proof_check_backend,
 	};
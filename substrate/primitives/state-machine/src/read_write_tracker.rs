@@ -0,0 +1,145 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tracking of the DB reads and writes of the storage accesses of [`Ext`](crate::Ext).
+
+use sp_core::storage::TrackedStorageKey;
+use std::collections::HashMap;
+
+/// Tracks the keys accessed through [`Ext`](crate::Ext), to count the DB reads and writes of a
+/// benchmark in tests, where the state is not backed by a DB.
+///
+/// The counts follow the ones of the benchmarking state of the client, as if the state was
+/// committed to the DB when the counts are reset: the first access to a key is a DB read, unless
+/// the key was written before, and every written key is one DB write. Writes that are rolled back
+/// are counted as well. Tracking starts when the counts are reset and stops when the state is
+/// wiped.
+#[derive(Debug, Default)]
+pub struct ReadWriteTracker {
+	/// Whether the accesses are tracked.
+	enabled: bool,
+	/// The keys whose accesses are not counted.
+	whitelist: Vec<TrackedStorageKey>,
+	/// The accessed keys, by child trie and key.
+	keys: HashMap<(Option<Vec<u8>>, Vec<u8>), TrackedStorageKey>,
+}
+
+impl ReadWriteTracker {
+	/// Track a read of `key` of `child_trie`, or of the main trie.
+	pub(crate) fn add_read(&mut self, child_trie: Option<&[u8]>, key: &[u8]) {
+		if let Some(tracker) = self.tracker(child_trie, key) {
+			// A written key is read from the overlay.
+			if !tracker.has_been_written() {
+				tracker.add_read();
+			}
+		}
+	}
+
+	/// Track a write of `key` of `child_trie`, or of the main trie.
+	pub(crate) fn add_write(&mut self, child_trie: Option<&[u8]>, key: &[u8]) {
+		if let Some(tracker) = self.tracker(child_trie, key) {
+			tracker.add_write();
+		}
+	}
+
+	fn tracker(&mut self, child_trie: Option<&[u8]>, key: &[u8]) -> Option<&mut TrackedStorageKey> {
+		self.enabled.then(|| {
+			self.keys
+				.entry((child_trie.map(Into::into), key.to_vec()))
+				.or_insert_with(|| TrackedStorageKey::new(key.to_vec()))
+		})
+	}
+
+	/// Whether the accesses are tracked.
+	pub(crate) fn is_enabled(&self) -> bool {
+		self.enabled
+	}
+
+	/// The numbers of DB reads, repeated reads, DB writes and repeated writes.
+	pub(crate) fn read_write_count(&self) -> (u32, u32, u32, u32) {
+		self.keys.values().filter(|tracker| !tracker.whitelisted).fold(
+			(0, 0, 0, 0),
+			|(reads, repeat_reads, writes, repeat_writes), tracker| {
+				(
+					reads + u32::from(tracker.reads > 0),
+					repeat_reads + tracker.reads.saturating_sub(1),
+					writes + u32::from(tracker.writes > 0),
+					repeat_writes + tracker.writes.saturating_sub(1),
+				)
+			},
+		)
+	}
+
+	/// Forget the tracked accesses and start tracking.
+	pub(crate) fn reset(&mut self) {
+		self.enabled = true;
+		self.keys = self
+			.whitelist
+			.iter()
+			.map(|key| ((None, key.key.clone()), key.key.clone().into()))
+			.collect();
+	}
+
+	/// Forget the tracked accesses and stop tracking.
+	pub(crate) fn wipe(&mut self) {
+		self.enabled = false;
+		self.keys.clear();
+	}
+
+	/// The keys whose accesses are not counted.
+	pub(crate) fn whitelist(&self) -> Vec<TrackedStorageKey> {
+		self.whitelist.clone()
+	}
+
+	/// Set the keys whose accesses are not counted, from the next reset on.
+	pub(crate) fn set_whitelist(&mut self, whitelist: Vec<TrackedStorageKey>) {
+		self.whitelist = whitelist;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn read_write_count_works() {
+		let mut tracker = ReadWriteTracker::default();
+		tracker.set_whitelist(vec![b"whitelisted".to_vec().into()]);
+
+		// Nothing is tracked before the first reset.
+		tracker.add_read(None, b"a");
+		assert_eq!(tracker.read_write_count(), (0, 0, 0, 0));
+
+		tracker.reset();
+		tracker.add_read(None, b"a");
+		tracker.add_read(None, b"a");
+		tracker.add_write(None, b"b");
+		tracker.add_read(None, b"b");
+		tracker.add_write(None, b"b");
+		tracker.add_read(Some(b"child"), b"a");
+		tracker.add_read(None, b"whitelisted");
+		tracker.add_write(None, b"whitelisted");
+		assert_eq!(tracker.read_write_count(), (2, 1, 1, 1));
+
+		tracker.reset();
+		assert_eq!(tracker.read_write_count(), (0, 0, 0, 0));
+
+		tracker.wipe();
+		tracker.add_write(None, b"a");
+		assert_eq!(tracker.read_write_count(), (0, 0, 0, 0));
+	}
+}
@@ -23,11 +23,12 @@ use std::{
 };
 
 use crate::{
-	backend::Backend, ext::Ext, InMemoryBackend, OverlayedChanges, StorageKey, StorageValue,
-	TrieBackendBuilder,
+	backend::Backend, ext::Ext, InMemoryBackend, OverlayedChanges, ReadWriteTracker, StorageKey,
+	StorageValue, TrieBackendBuilder,
 };
 
 use hash_db::{HashDB, Hasher};
+use parking_lot::Mutex;
 use sp_core::{
 	offchain::testing::TestPersistentOffchainDB,
 	storage::{
@@ -53,6 +54,8 @@ where
 	pub extensions: Extensions,
 	/// State version to use during tests.
 	pub state_version: StateVersion,
+	/// Tracks the DB reads and writes of the storage accesses.
+	read_write_tracker: Mutex<ReadWriteTracker>,
 }
 
 impl<H> TestExternalities<H>
@@ -63,6 +66,7 @@ where
 	/// Get externalities implementation.
 	pub fn ext(&mut self) -> Ext<H, InMemoryBackend<H>> {
 		Ext::new(&mut self.overlay, &self.backend, Some(&mut self.extensions))
+			.with_read_write_tracker(&self.read_write_tracker)
 	}
 
 	/// Create a new instance of `TestExternalities` with storage.
@@ -106,6 +110,7 @@ where
 			extensions: Default::default(),
 			backend,
 			state_version,
+			read_write_tracker: Default::default(),
 		}
 	}
 
@@ -399,6 +404,27 @@ mod tests {
 		assert_eq!(H256::from_slice(ext.storage_root(Default::default()).as_slice()), root);
 	}
 
+	#[test]
+	fn read_write_count_is_tracked() {
+		let mut ext = TestExternalities::<BlakeTwo256>::default();
+		let mut ext = ext.ext();
+		ext.set_storage(b"doe".to_vec(), b"reindeer".to_vec());
+
+		// Only the accesses after the first reset are tracked.
+		assert_eq!(ext.read_write_count(), (0, 0, 0, 0));
+		ext.reset_read_write_count();
+		assert_eq!(ext.storage(b"doe"), Some(b"reindeer".to_vec()));
+		assert_eq!(ext.storage(b"dog"), None);
+		ext.set_storage(b"dog".to_vec(), b"puppy".to_vec());
+		assert_eq!(ext.storage(b"dog"), Some(b"puppy".to_vec()));
+		let _ = ext.clear_prefix(b"do", None, None);
+		assert_eq!(ext.read_write_count(), (2, 1, 2, 1));
+
+		ext.wipe();
+		let _ = ext.storage(b"doe");
+		assert_eq!(ext.read_write_count(), (0, 0, 0, 0));
+	}
+
 	#[test]
 	fn raw_storage_drain_and_restore() {
 		// Create a TestExternalities with some data in it.
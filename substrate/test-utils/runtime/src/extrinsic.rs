@@ -154,6 +154,11 @@ impl ExtrinsicBuilder {
 		Self::new(PalletCall::call_do_not_propagate {})
 	}
 
+	/// Create builder for `PalletCall::skipped_by_author` call
+	pub fn new_skipped_by_author() -> Self {
+		Self::new(PalletCall::skipped_by_author {})
+	}
+
 	/// Create builder for `PalletCall::call_with_priority` call using given parameters
 	pub fn new_call_with_priority(priority: TransactionPriority) -> Self {
 		Self::new(PalletCall::call_with_priority { priority })
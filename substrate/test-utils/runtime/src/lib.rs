@@ -31,7 +31,7 @@ use frame_support::{
 	dispatch::DispatchClass,
 	genesis_builder_helper::{build_config, create_default_config},
 	parameter_types,
-	traits::{ConstU32, ConstU64},
+	traits::{ConstU32, ConstU64, Contains},
 	weights::{
 		constants::{BlockExecutionWeight, ExtrinsicBaseWeight, WEIGHT_REF_TIME_PER_SECOND},
 		Weight,
@@ -342,6 +342,17 @@ parameter_types! {
 		.build_or_panic();
 }
 
+/// Leaves [`substrate_test_pallet::Call::skipped_by_author`] out of the blocks built.
+pub struct AuthoringFilter;
+impl Contains<RuntimeCall> for AuthoringFilter {
+	fn contains(call: &RuntimeCall) -> bool {
+		!matches!(
+			call,
+			RuntimeCall::SubstrateTest(substrate_test_pallet::Call::skipped_by_author {})
+		)
+	}
+}
+
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
 impl frame_system::pallet::Config for Runtime {
 	type BaseCallFilter = frame_support::traits::Everything;
@@ -513,7 +524,9 @@ impl_runtime_apis! {
 
 	impl sp_block_builder::BlockBuilder<Block> for Runtime {
 		fn apply_extrinsic(extrinsic: <Block as BlockT>::Extrinsic) -> ApplyExtrinsicResult {
-			Executive::apply_extrinsic(extrinsic)
+			Executive::apply_extrinsic_for_authoring::<
+				frame_executive::authoring::AllowedCalls<AuthoringFilter>,
+			>(extrinsic)
 		}
 
 		fn finalize_block() -> <Block as BlockT>::Header {
@@ -528,6 +541,10 @@ impl_runtime_apis! {
 		fn check_inherents(_block: Block, _data: InherentData) -> CheckInherentsResult {
 			CheckInherentsResult::new()
 		}
+
+		fn extrinsic_proof_sizes() -> Vec<sp_block_builder::ExtrinsicProofSize> {
+			Executive::extrinsic_proof_sizes()
+		}
 	}
 
 	impl frame_system_rpc_runtime_api::AccountNonceApi<Block, AccountId, Nonce> for Runtime {
@@ -189,6 +189,13 @@ pub mod pallet {
 		pub fn read_and_panic(_origin: OriginFor<T>, count: u32) -> DispatchResult {
 			Self::execute_read(count, true)
 		}
+
+		/// Does nothing, but is left out of the blocks built by the runtime.
+		#[pallet::call_index(12)]
+		#[pallet::weight(100)]
+		pub fn skipped_by_author(_origin: OriginFor<T>) -> DispatchResult {
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
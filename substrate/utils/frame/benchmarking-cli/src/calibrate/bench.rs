@@ -0,0 +1,326 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains the core calibration logic.
+
+use codec::{Codec, Encode};
+use frame_support::{dispatch::DispatchClass, weights::constants::WEIGHT_REF_TIME_PER_NANOS};
+use pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi;
+use sc_block_builder::BlockBuilderApi;
+use sc_cli::{Error, Result};
+use sc_client_api::{BlockBackend, HeaderBackend};
+use sp_api::{ApiExt, Core, ProvideRuntimeApi};
+use sp_blockchain::Error::RuntimeApiError;
+use sp_runtime::{
+	generic::BlockId,
+	traits::{Block as BlockT, Header as HeaderT, MaybeDisplay, UniqueSaturatedInto},
+	DigestItem, OpaqueExtrinsic,
+};
+
+use clap::Args;
+use log::{info, warn};
+use serde::Serialize;
+use std::{fmt::Debug, marker::PhantomData, path::PathBuf, sync::Arc, time::Instant};
+use thousands::Separable;
+
+use crate::shared::{StatSelect, Stats};
+
+const LOG_TARGET: &'static str = "benchmark::calibrate";
+
+#[derive(Debug, Default, Serialize, Clone, PartialEq, Args)]
+pub struct CalibrateParams {
+	/// Number of the most recent blocks to sample from.
+	#[arg(long, default_value_t = 100)]
+	pub last: u32,
+
+	/// Only replay every n-th of the sampled blocks.
+	#[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+	pub step: u32,
+
+	/// Number of times that each block is replayed.
+	#[arg(long, default_value_t = 5, value_parser = clap::value_parser!(u32).range(1..))]
+	pub repeat: u32,
+
+	/// Write the calibration report as JSON to this file.
+	#[arg(long)]
+	pub json_file: Option<PathBuf>,
+}
+
+/// The measured execution time of the extrinsics of one dispatch class.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ClassCalibration {
+	/// The dispatch class of the extrinsics.
+	pub class: DispatchClass,
+	/// Number of measured extrinsics.
+	pub extrinsics: u64,
+	/// Sum of the weights of the extrinsics in nanoseconds.
+	pub weight: NanoSeconds,
+	/// Sum of the execution times of the extrinsics in nanoseconds.
+	pub took: NanoSeconds,
+	/// The factor by which the weights must be multiplied to match the execution time.
+	pub factor: f64,
+	/// The median of the factors of the single extrinsics.
+	pub median_factor: f64,
+	/// The 95th percentile of the factors of the single extrinsics.
+	pub p95_factor: f64,
+}
+
+/// The outcome of a calibration.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CalibrationReport {
+	/// Number of replayed blocks.
+	pub blocks: u32,
+	/// The calibration per dispatch class, for classes that had extrinsics.
+	pub classes: Vec<ClassCalibration>,
+	/// The factor by which all weights must be multiplied to match the execution time.
+	pub factor: f64,
+}
+
+/// The weights and execution times of the extrinsics of one dispatch class.
+#[derive(Default)]
+struct ClassSamples {
+	/// Weight and execution time in nanoseconds of each extrinsic.
+	samples: Vec<(NanoSeconds, NanoSeconds)>,
+}
+
+impl ClassSamples {
+	fn calibrate(&self, class: DispatchClass) -> ClassCalibration {
+		let weight = self.samples.iter().map(|(weight, _)| weight).sum();
+		let took = self.samples.iter().map(|(_, took)| took).sum();
+		let mut factors = self
+			.samples
+			.iter()
+			.filter(|(weight, _)| *weight > 0)
+			.map(|(weight, took)| *took as f64 / *weight as f64)
+			.collect::<Vec<_>>();
+		factors.sort_by(|a, b| a.total_cmp(b));
+
+		ClassCalibration {
+			class,
+			extrinsics: self.samples.len() as u64,
+			weight,
+			took,
+			factor: factor(took, weight),
+			median_factor: percentile(&factors, 50),
+			p95_factor: percentile(&factors, 95),
+		}
+	}
+}
+
+pub struct Calibration<Block, C, Balance> {
+	client: Arc<C>,
+	params: CalibrateParams,
+	_p: PhantomData<(Block, C, Balance)>,
+}
+
+type NanoSeconds = u64;
+
+impl<Block, C, Balance> Calibration<Block, C, Balance>
+where
+	Block: BlockT<Extrinsic = OpaqueExtrinsic>,
+	C: ProvideRuntimeApi<Block> + BlockBackend<Block> + HeaderBackend<Block>,
+	C::Api: ApiExt<Block> + BlockBuilderApi<Block> + TransactionPaymentApi<Block, Balance>,
+	Balance: Codec + MaybeDisplay,
+{
+	/// Returns a new [`Self`] from the arguments.
+	pub fn new(client: Arc<C>, params: CalibrateParams) -> Self {
+		Self { client, params, _p: PhantomData }
+	}
+
+	/// Replay the sampled blocks and report how their weights relate to the execution time of
+	/// their extrinsics.
+	pub fn run(&self) -> Result<CalibrationReport> {
+		let best: u32 = self.client.info().best_number.unique_saturated_into();
+		if best == 0 {
+			return Err("Cannot calibrate without any blocks besides genesis".into())
+		}
+		let from = best.saturating_sub(self.params.last.saturating_sub(1)).max(1);
+
+		let mut samples: Vec<(DispatchClass, ClassSamples)> =
+			DispatchClass::all().iter().map(|class| (*class, Default::default())).collect();
+		let mut blocks = 0;
+		for i in (from..=best).step_by(self.params.step as usize) {
+			let block_num = BlockId::Number(i.into());
+			let hash = self.client.expect_block_hash_from_id(&block_num)?;
+			let block = self.client.block(hash)?.ok_or(format!("Block {} not found", block_num))?;
+			let block = self.unsealed(block.block);
+			let parent_hash = *block.header().parent_hash();
+
+			let weights = self.weights(&block, parent_hash).map_err(|e| {
+				format!(
+					"Could not get the weights of block {}, its parent state must be available, \
+					use an archive node: {}",
+					i, e
+				)
+			})?;
+			let took = self.measure_extrinsics(&block, parent_hash)?;
+			for ((class, weight), took) in weights.into_iter().zip(took) {
+				if let Some((_, class_samples)) = samples.iter_mut().find(|(c, _)| *c == class) {
+					class_samples.samples.push((weight, took));
+				}
+			}
+
+			blocks += 1;
+			info!(target: LOG_TARGET, "Replayed block {} with {} extrinsics", i, block.extrinsics().len());
+		}
+
+		let classes = samples
+			.iter()
+			.filter(|(_, class_samples)| !class_samples.samples.is_empty())
+			.map(|(class, class_samples)| class_samples.calibrate(*class))
+			.collect::<Vec<_>>();
+		let weight = classes.iter().map(|class| class.weight).sum();
+		let took = classes.iter().map(|class| class.took).sum();
+		let report = CalibrationReport { blocks, classes, factor: factor(took, weight) };
+
+		self.log_report(&report);
+		if let Some(path) = &self.params.json_file {
+			let json = serde_json::to_string_pretty(&report)
+				.map_err(|e| format!("Serializing into JSON: {:?}", e))?;
+			std::fs::write(path, json)?;
+			info!(target: LOG_TARGET, "Wrote the calibration report to {:?}", path);
+		}
+		Ok(report)
+	}
+
+	/// Returns the dispatch class and the weight in nanoseconds of each extrinsic of the block.
+	///
+	/// These are the pre-dispatch weights, which is what the weight files provide.
+	fn weights(
+		&self,
+		block: &Block,
+		parent_hash: Block::Hash,
+	) -> Result<Vec<(DispatchClass, NanoSeconds)>> {
+		let runtime_api = self.client.runtime_api();
+		block
+			.extrinsics()
+			.iter()
+			.map(|ext| {
+				let info = runtime_api
+					.query_info(parent_hash, ext.clone(), ext.encoded_size() as u32)
+					.map_err(|e| Error::Client(RuntimeApiError(e)))?;
+				Ok((info.class, info.weight.ref_time() / WEIGHT_REF_TIME_PER_NANOS))
+			})
+			.collect()
+	}
+
+	/// Return the median execution time of each extrinsic of the block.
+	fn measure_extrinsics(
+		&self,
+		block: &Block,
+		parent_hash: Block::Hash,
+	) -> Result<Vec<NanoSeconds>> {
+		let mut records = vec![Vec::<NanoSeconds>::new(); block.extrinsics().len()];
+		for _ in 0..self.params.repeat {
+			// The runtime API instance keeps the changes of every call, like a block builder.
+			let runtime_api = self.client.runtime_api();
+			runtime_api
+				.initialize_block(parent_hash, block.header())
+				.map_err(|e| Error::Client(RuntimeApiError(e)))?;
+
+			for (index, (ext, record)) in
+				block.extrinsics().iter().zip(records.iter_mut()).enumerate()
+			{
+				let ext = ext.clone();
+				let start = Instant::now();
+				let applied = runtime_api
+					.apply_extrinsic(parent_hash, ext)
+					.map_err(|e| Error::Client(RuntimeApiError(e)))?;
+				record.push(start.elapsed().as_nanos() as NanoSeconds);
+
+				if let Err(e) = applied {
+					warn!(target: LOG_TARGET, "Extrinsic {} could not be applied: {:?}", index, e);
+				}
+			}
+		}
+
+		records
+			.iter()
+			.map(|record| Ok(Stats::new(record)?.select(StatSelect::Median)))
+			.collect()
+	}
+
+	/// Prints the calibration report to the console.
+	fn log_report(&self, report: &CalibrationReport) {
+		for class in &report.classes {
+			info!(
+				target: LOG_TARGET,
+				"{:?}: {} extrinsics took {:.2}x their weight ({} of {} ns), median {:.2}x, p95 {:.2}x",
+				class.class,
+				class.extrinsics,
+				class.factor,
+				class.took.separate_with_commas(),
+				class.weight.separate_with_commas(),
+				class.median_factor,
+				class.p95_factor,
+			);
+		}
+
+		let msg = format!(
+			"Extrinsics of {} blocks took {:.2}x their weight, multiply the weights by this factor \
+			to calibrate them to this machine",
+			report.blocks, report.factor,
+		);
+		if report.factor <= 1.0 {
+			info!(target: LOG_TARGET, "{}", msg);
+		} else {
+			warn!(target: LOG_TARGET, "{} - UNDER WEIGHT!", msg);
+		}
+	}
+
+	/// Removes the consensus seal from the block.
+	fn unsealed(&self, block: Block) -> Block {
+		let (mut header, exts) = block.deconstruct();
+		header.digest_mut().logs.retain(|item| !matches!(item, DigestItem::Seal(_, _)));
+		Block::new(header, exts)
+	}
+}
+
+/// The factor by which `weight` must be multiplied to match `took`.
+fn factor(took: NanoSeconds, weight: NanoSeconds) -> f64 {
+	if weight == 0 {
+		return 0.0
+	}
+	took as f64 / weight as f64
+}
+
+/// The `p`th percentile of the sorted `xs`.
+fn percentile(xs: &[f64], p: usize) -> f64 {
+	if xs.is_empty() {
+		return 0.0
+	}
+	xs[((xs.len() - 1) * p) / 100]
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn class_calibration_works() {
+		let samples = ClassSamples { samples: vec![(100, 150), (200, 200), (0, 10), (100, 50)] };
+		let calibration = samples.calibrate(DispatchClass::Normal);
+
+		assert_eq!(calibration.extrinsics, 4);
+		assert_eq!(calibration.weight, 400);
+		assert_eq!(calibration.took, 410);
+		assert_eq!(calibration.factor, 1.025);
+		// Extrinsics without weight are not considered for the single factors.
+		assert_eq!(calibration.median_factor, 1.0);
+		assert_eq!(calibration.p95_factor, 1.0);
+	}
+}
@@ -0,0 +1,96 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains the [`CalibrateCmd`] as entry point for the CLI to execute
+//! the *calibrate* benchmark.
+
+use codec::Codec;
+use pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi;
+use sc_block_builder::BlockBuilderApi;
+use sc_cli::{CliConfiguration, ImportParams, Result, SharedParams};
+use sc_client_api::BlockBackend;
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{
+	traits::{Block as BlockT, MaybeDisplay},
+	OpaqueExtrinsic,
+};
+
+use clap::Parser;
+use std::{fmt::Debug, sync::Arc};
+
+use super::bench::{CalibrateParams, Calibration};
+
+/// Benchmark the execution time of the extrinsics of recent blocks and compare it to their weight.
+///
+/// The blocks are replayed on top of the state of their parents, so a node that keeps the state
+/// of historic blocks is needed, for example an archive node. The resulting correction factor
+/// tells by how much the weights of the runtime are off on this machine.
+#[derive(Debug, Parser)]
+pub struct CalibrateCmd {
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub import_params: ImportParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub params: CalibrateParams,
+
+	/// Enable the Trie cache.
+	///
+	/// This should only be used for performance analysis and not for final results.
+	#[arg(long)]
+	pub enable_trie_cache: bool,
+}
+
+impl CalibrateCmd {
+	/// Replay recent blocks and compare the execution time of their extrinsics to their weight.
+	///
+	/// Output will be printed to console and optionally written to a JSON file.
+	pub fn run<Block, C, Balance>(&self, client: Arc<C>) -> Result<()>
+	where
+		Block: BlockT<Extrinsic = OpaqueExtrinsic>,
+		C: BlockBackend<Block> + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+		C::Api: ApiExt<Block> + BlockBuilderApi<Block> + TransactionPaymentApi<Block, Balance>,
+		Balance: Codec + MaybeDisplay,
+	{
+		Calibration::<Block, C, Balance>::new(client, self.params.clone()).run()?;
+		Ok(())
+	}
+}
+
+impl CliConfiguration for CalibrateCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn import_params(&self) -> Option<&ImportParams> {
+		Some(&self.import_params)
+	}
+
+	fn trie_cache_maximum_size(&self) -> Result<Option<usize>> {
+		if self.enable_trie_cache {
+			Ok(self.import_params().map(|x| x.trie_cache_maximum_size()).unwrap_or_default())
+		} else {
+			Ok(None)
+		}
+	}
+}
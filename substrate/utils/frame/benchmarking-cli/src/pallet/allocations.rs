@@ -0,0 +1,145 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tracking of the memory allocated by the runtime while running benchmarks.
+
+use codec::Decode;
+use frame_benchmarking::{
+	BenchmarkAllocations, BenchmarkBatch, BenchmarkBatchSplitResults, BenchmarkParameter,
+};
+use sc_cli::Result;
+use sc_client_db::BenchmarkingState;
+use sc_executor::WasmExecutor;
+use sc_executor_common::runtime_blob::RuntimeBlob;
+use sp_core::traits::FetchRuntimeCode;
+use sp_externalities::Extensions;
+use sp_runtime::traits::Hash;
+use sp_state_machine::{backend::BackendRuntimeCode, Ext, OverlayedChanges};
+use std::collections::HashMap;
+
+/// Identifies a benchmark by its pallet, instance and name.
+pub(crate) type BenchmarkKey = (Vec<u8>, Vec<u8>, Vec<u8>);
+
+/// Run `Benchmark_dispatch_benchmark` with `call_data` and return what the runtime allocated.
+///
+/// The call uses a fresh runtime instance, so that the allocator only accounts for this benchmark.
+/// Every benchmark in the returned batches is attributed the allocations of the whole call.
+pub(crate) fn measure<Hasher, H>(
+	executor: &WasmExecutor<H>,
+	state: &BenchmarkingState<Hasher>,
+	changes: &mut OverlayedChanges<Hasher>,
+	mut extensions: Extensions,
+	components: &[(BenchmarkParameter, u32)],
+	call_data: &[u8],
+) -> Result<Vec<(BenchmarkKey, BenchmarkAllocations)>>
+where
+	Hasher: Hash,
+	H: sp_wasm_interface::HostFunctions,
+{
+	let code = BackendRuntimeCode::new(state)
+		.fetch_runtime_code()
+		.ok_or("The state does not contain any runtime code")?
+		.into_owned();
+	let blob = RuntimeBlob::uncompress_if_needed(&code)
+		.map_err(|e| format!("Failed to load the runtime: {}", e))?;
+
+	changes.enter_runtime().expect("Benchmarks are never run from the runtime; qed");
+	let (result, stats) = {
+		let mut ext = Ext::new(&mut *changes, state, Some(&mut extensions));
+		executor.uncached_call_with_allocation_stats(
+			blob,
+			&mut ext,
+			false,
+			"Benchmark_dispatch_benchmark",
+			call_data,
+		)
+	};
+	changes.exit_runtime().expect("Entered the runtime above; qed");
+
+	let result = result.map_err(|e| format!("Error executing runtime benchmark: {}", e))?;
+	let batches =
+		<std::result::Result<Vec<BenchmarkBatch>, String> as Decode>::decode(&mut &result[..])
+			.map_err(|e| format!("Failed to decode benchmark results: {:?}", e))??;
+	let stats = stats.ok_or("The executor did not report the allocations of the runtime")?;
+
+	Ok(batches
+		.into_iter()
+		.map(|batch| {
+			let allocations = BenchmarkAllocations {
+				components: components.to_vec(),
+				peak: stats.bytes_allocated_peak,
+				total: stats.bytes_allocated_sum,
+			};
+			((batch.pallet, batch.instance, batch.benchmark), allocations)
+		})
+		.collect())
+}
+
+/// Add the tracked `allocations` to the results of their benchmarks.
+pub(crate) fn attach(
+	mut batches: Vec<BenchmarkBatchSplitResults>,
+	mut allocations: HashMap<BenchmarkKey, Vec<BenchmarkAllocations>>,
+) -> Vec<BenchmarkBatchSplitResults> {
+	for batch in &mut batches {
+		let key = (batch.pallet.clone(), batch.instance.clone(), batch.benchmark.clone());
+		batch.allocation_results = allocations.remove(&key).unwrap_or_default();
+	}
+	batches
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn batch(benchmark: &[u8]) -> BenchmarkBatchSplitResults {
+		BenchmarkBatchSplitResults {
+			pallet: b"pallet".to_vec(),
+			instance: b"instance".to_vec(),
+			benchmark: benchmark.to_vec(),
+			time_results: vec![],
+			db_results: vec![],
+			allocation_results: vec![],
+		}
+	}
+
+	#[test]
+	fn attach_works() {
+		let allocations = BenchmarkAllocations {
+			components: vec![(BenchmarkParameter::n, 1)],
+			peak: 1024,
+			total: 4096,
+		};
+		let key = (b"pallet".to_vec(), b"instance".to_vec(), b"first".to_vec());
+		let batches = attach(
+			vec![batch(b"first"), batch(b"second")],
+			HashMap::from([(key, vec![allocations.clone()])]),
+		);
+
+		assert_eq!(batches[0].allocation_results, vec![allocations]);
+		assert!(batches[1].allocation_results.is_empty());
+	}
+
+	#[test]
+	fn results_without_allocations_are_unchanged() {
+		let json = serde_json::to_string(&batch(b"first")).unwrap();
+		assert!(!json.contains("allocation_results"));
+		assert_eq!(
+			serde_json::from_str::<BenchmarkBatchSplitResults>(&json).unwrap(),
+			batch(b"first")
+		);
+	}
+}
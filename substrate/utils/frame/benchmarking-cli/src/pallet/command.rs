@@ -15,19 +15,23 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{writer, ListOutput, PalletCmd};
+use super::{
+	timing::{self, BenchmarkTimes},
+	writer, ListOutput, PalletCmd,
+};
 use codec::{Decode, Encode};
 use frame_benchmarking::{
-	Analysis, BenchmarkBatch, BenchmarkBatchSplitResults, BenchmarkList, BenchmarkParameter,
-	BenchmarkResult, BenchmarkSelector,
+	Analysis, Benchmark, BenchmarkBatch, BenchmarkBatchSplitResults, BenchmarkList,
+	BenchmarkListV1, BenchmarkParameter, BenchmarkResult, BenchmarkSelector,
 };
 use frame_support::traits::StorageInfo;
 use linked_hash_map::LinkedHashMap;
 use sc_cli::{execution_method_from_cli, CliConfiguration, Result, SharedParams};
 use sc_client_db::BenchmarkingState;
-use sc_executor::{HeapAllocStrategy, WasmExecutor, DEFAULT_HEAP_ALLOC_STRATEGY};
+use sc_executor::{HeapAllocStrategy, RuntimeVersionOf, WasmExecutor, DEFAULT_HEAP_ALLOC_STRATEGY};
 use sc_service::Configuration;
 use serde::Serialize;
+use sp_api::RuntimeApiInfo;
 use sp_core::{
 	offchain::{
 		testing::{TestOffchainExt, TestTransactionPoolExt},
@@ -37,8 +41,12 @@ use sp_core::{
 };
 use sp_externalities::Extensions;
 use sp_keystore::{testing::MemoryKeystore, KeystoreExt};
-use sp_runtime::traits::Hash;
-use sp_state_machine::StateMachine;
+use sp_runtime::{
+	generic,
+	traits::{BlakeTwo256, Hash},
+	OpaqueExtrinsic,
+};
+use sp_state_machine::{BasicExternalities, StateMachine};
 use std::{
 	collections::{BTreeMap, BTreeSet, HashMap},
 	fmt::Debug,
@@ -50,6 +58,10 @@ use std::{
 /// Logging target
 const LOG_TARGET: &'static str = "frame::benchmark::pallet";
 
+/// Block type used to look up the version of the `Benchmark` runtime API, whose id does not
+/// depend on the block type.
+type OpaqueBlock = generic::Block<generic::Header<u32, BlakeTwo256>, OpaqueExtrinsic>;
+
 /// The inclusive range of a component.
 #[derive(Serialize, Debug, Clone, Eq, PartialEq)]
 pub(crate) struct ComponentRange {
@@ -134,7 +146,14 @@ fn combine_batches(
 	all_benchmarks
 		.into_iter()
 		.map(|((pallet, instance, benchmark), (time_results, db_results))| {
-			BenchmarkBatchSplitResults { pallet, instance, benchmark, time_results, db_results }
+			BenchmarkBatchSplitResults {
+				pallet,
+				instance,
+				benchmark,
+				time_results,
+				db_results,
+				allocation_results: Vec::new(),
+			}
 		})
 		.collect::<Vec<_>>()
 }
@@ -258,6 +277,14 @@ impl PalletCmd {
 
 		// Get Benchmark List
 		let state = &state_without_tracking;
+		let runtime_version = executor
+			.runtime_version(
+				&mut BasicExternalities::default(),
+				&sp_state_machine::backend::BackendRuntimeCode::new(state).runtime_code()?,
+			)
+			.map_err(|e| format!("Failed to read the runtime version: {}", e))?;
+		let api_version =
+			runtime_version.api_version(&<dyn Benchmark<OpaqueBlock>>::ID).unwrap_or(1);
 		let result = StateMachine::new(
 			state,
 			&mut changes,
@@ -271,9 +298,16 @@ impl PalletCmd {
 		.execute()
 		.map_err(|e| format!("{}: {}", ERROR_METADATA_NOT_FOUND, e))?;
 
-		let (list, storage_info) =
+		// Runtimes implementing version 1 of the API return benchmarks without tags.
+		let (list, storage_info) = if api_version < 2 {
+			let (list, storage_info) =
+				<(Vec<BenchmarkListV1>, Vec<StorageInfo>) as Decode>::decode(&mut &result[..])
+					.map_err(|e| format!("Failed to decode benchmark metadata: {:?}", e))?;
+			(list.into_iter().map(Into::into).collect::<Vec<BenchmarkList>>(), storage_info)
+		} else {
 			<(Vec<BenchmarkList>, Vec<StorageInfo>) as Decode>::decode(&mut &result[..])
-				.map_err(|e| format!("Failed to decode benchmark metadata: {:?}", e))?;
+				.map_err(|e| format!("Failed to decode benchmark metadata: {:?}", e))?
+		};
 
 		// Use the benchmark list and the user input to determine the set of benchmarks to run.
 		let mut benchmarks_to_run = Vec::new();
@@ -282,9 +316,15 @@ impl PalletCmd {
 			.for_each(|item| {
 				for benchmark in &item.benchmarks {
 					let benchmark_name = &benchmark.name;
-					if extrinsic.is_empty() ||
-						extrinsic.as_bytes() == &b"*"[..] ||
-						extrinsics.contains(&&benchmark_name[..])
+					let tagged = self.tags.is_empty() ||
+						benchmark
+							.tags
+							.iter()
+							.any(|tag| self.tags.iter().any(|t| t.as_bytes() == tag));
+					if tagged &&
+						(extrinsic.is_empty() ||
+							extrinsic.as_bytes() == &b"*"[..] ||
+							extrinsics.contains(&&benchmark_name[..]))
 					{
 						benchmarks_to_run.push((
 							item.pallet.clone(),
@@ -328,14 +368,18 @@ impl PalletCmd {
 		// Maps (pallet, extrinsic) to its component ranges.
 		let mut component_ranges = HashMap::<(Vec<u8>, Vec<u8>), Vec<ComponentRange>>::new();
 		let pov_modes = Self::parse_pov_modes(&benchmarks_to_run)?;
+		// The time spent in every benchmark, if `--profile-setup` is given.
+		let mut profiles = Vec::new();
+		// The memory allocated by every benchmark, if `--track-allocations` is given.
+		#[cfg(feature = "allocation-tracking")]
+		let mut allocation_results = HashMap::<_, Vec<_>>::new();
 
 		for (pallet, extrinsic, components, _) in benchmarks_to_run.clone() {
-			log::info!(
-				target: LOG_TARGET,
-				"Starting benchmark: {}::{}",
-				String::from_utf8(pallet.clone()).expect("Encoded from String; qed"),
-				String::from_utf8(extrinsic.clone()).expect("Encoded from String; qed"),
-			);
+			let pallet_name = String::from_utf8(pallet.clone()).expect("Encoded from String; qed");
+			let extrinsic_name =
+				String::from_utf8(extrinsic.clone()).expect("Encoded from String; qed");
+			log::info!(target: LOG_TARGET, "Starting benchmark: {}::{}", pallet_name, extrinsic_name);
+			let mut times = BenchmarkTimes::default();
 			let all_components = if components.is_empty() {
 				vec![Default::default()]
 			} else {
@@ -386,6 +430,7 @@ impl PalletCmd {
 				// First we run a verification
 				if !self.no_verify {
 					let state = &state_without_tracking;
+					let start = time::Instant::now();
 					let result = StateMachine::new(
 						state,
 						&mut changes,
@@ -409,7 +454,7 @@ impl PalletCmd {
 						format!("Error executing and verifying runtime benchmark: {}", e)
 					})?;
 					// Dont use these results since verification code will add overhead.
-					let _batch =
+					let batch =
 						<std::result::Result<Vec<BenchmarkBatch>, String> as Decode>::decode(
 							&mut &result[..],
 						)
@@ -422,10 +467,20 @@ impl PalletCmd {
 								e
 							)
 						})?;
+
+					times.record(start.elapsed(), &batch);
+					self.check_time_limits(
+						&pallet_name,
+						&extrinsic_name,
+						&times,
+						selected_components,
+						(s, all_components.len()),
+					)?;
 				}
 				// Do one loop of DB tracking.
 				{
 					let state = &state_with_tracking;
+					let start = time::Instant::now();
 					let result = StateMachine::new(
 						state, // todo remove tracking
 						&mut changes,
@@ -453,11 +508,43 @@ impl PalletCmd {
 						)
 						.map_err(|e| format!("Failed to decode benchmark results: {:?}", e))??;
 
+					times.record(start.elapsed(), &batch);
+					self.check_time_limits(
+						&pallet_name,
+						&extrinsic_name,
+						&times,
+						selected_components,
+						(s, all_components.len()),
+					)?;
+
 					batches_db.extend(batch);
 				}
+				// Measure the memory allocated by a single run.
+				#[cfg(feature = "allocation-tracking")]
+				if self.track_allocations {
+					let measured = super::allocations::measure(
+						&executor,
+						&state_without_tracking,
+						&mut changes,
+						extensions(),
+						selected_components,
+						&(
+							&pallet,
+							&extrinsic,
+							&selected_components.clone(),
+							false, // dont run verification code for final values
+							1,     // the setup is included, repeating would only add to it
+						)
+							.encode(),
+					)?;
+					for (key, allocations) in measured {
+						allocation_results.entry(key).or_default().push(allocations);
+					}
+				}
 				// Finally run a bunch of loops to get extrinsic timing information.
 				for r in 0..self.external_repeat {
 					let state = &state_without_tracking;
+					let start = time::Instant::now();
 					let result = StateMachine::new(
 						state, // todo remove tracking
 						&mut changes,
@@ -485,6 +572,15 @@ impl PalletCmd {
 						)
 						.map_err(|e| format!("Failed to decode benchmark results: {:?}", e))??;
 
+					times.record(start.elapsed(), &batch);
+					self.check_time_limits(
+						&pallet_name,
+						&extrinsic_name,
+						&times,
+						selected_components,
+						(s, all_components.len()),
+					)?;
+
 					batches.extend(batch);
 
 					// Show progress information
@@ -495,10 +591,8 @@ impl PalletCmd {
 							log::info!(
 								target: LOG_TARGET,
 								"Running  benchmark: {}.{}({} args) {}/{} {}/{}",
-								String::from_utf8(pallet.clone())
-									.expect("Encoded from String; qed"),
-								String::from_utf8(extrinsic.clone())
-									.expect("Encoded from String; qed"),
+								pallet_name,
+								extrinsic_name,
 								components.len(),
 								s + 1, // s starts at 0.
 								all_components.len(),
@@ -509,12 +603,64 @@ impl PalletCmd {
 					}
 				}
 			}
+
+			if self.profile_setup {
+				profiles.push((pallet_name, extrinsic_name, times));
+			}
 		}
 
 		// Combine all of the benchmark results, so that benchmarks of the same pallet/function
 		// are together.
 		let batches = combine_batches(batches, batches_db);
-		self.output(&batches, &storage_info, &component_ranges, pov_modes)
+		#[cfg(feature = "allocation-tracking")]
+		let batches = super::allocations::attach(batches, allocation_results);
+		self.output(&batches, &storage_info, &component_ranges, pov_modes)?;
+
+		if self.profile_setup {
+			print_setup_profile(&profiles);
+		}
+		Ok(())
+	}
+
+	/// Fail if a benchmark exceeded its `--max-setup-time` or `--max-execution-time`.
+	///
+	/// `step` is the index of the current component step and the number of all steps.
+	fn check_time_limits(
+		&self,
+		pallet: &str,
+		extrinsic: &str,
+		times: &BenchmarkTimes,
+		components: &[(BenchmarkParameter, u32)],
+		step: (usize, usize),
+	) -> Result<()> {
+		let limits = [
+			("setup", &self.max_setup_time, times.setup),
+			("execution", &self.max_execution_time, times.execution),
+		];
+		for (phase, limits, spent) in limits {
+			let Some(limit) = timing::limit_for(limits, pallet, extrinsic) else { continue };
+			if spent <= limit {
+				continue
+			}
+			return Err(format!(
+				"Benchmark {}::{} exceeded its maximum {} time of {:?} at step {}/{} with \
+				components {:?}: spent {:?} in setup and {:?} in execution ({:.1}% setup). Setup \
+				taking much longer than the measured code usually points at accidentally quadratic \
+				setup logic, use `--profile-setup` to compare benchmarks.",
+				pallet,
+				extrinsic,
+				phase,
+				limit,
+				step.0 + 1,
+				step.1,
+				components,
+				times.setup,
+				times.execution,
+				times.setup_percent(),
+			)
+			.into())
+		}
+		Ok(())
 	}
 
 	fn output(
@@ -648,6 +794,17 @@ impl PalletCmd {
 				println!();
 			}
 
+			if !batch.allocation_results.is_empty() {
+				println!("Allocations\n========");
+				for allocations in &batch.allocation_results {
+					println!(
+						"{:?}: peak = {} bytes, total = {} bytes",
+						allocations.components, allocations.peak, allocations.total,
+					);
+				}
+				println!();
+			}
+
 			// Conduct analysis.
 			if !self.no_median_slopes {
 				println!("Median Slopes Analysis\n========");
@@ -759,6 +916,24 @@ impl CliConfiguration for PalletCmd {
 	}
 }
 
+/// Print the time spent setting up versus measuring every benchmark, most setup first.
+fn print_setup_profile(profiles: &[(String, String, BenchmarkTimes)]) {
+	let mut profiles = profiles.iter().collect::<Vec<_>>();
+	profiles.sort_by(|a, b| b.2.setup.cmp(&a.2.setup));
+
+	println!("Setup profile:");
+	for (pallet, extrinsic, times) in profiles {
+		println!(
+			"{}::{}: setup {:?}, execution {:?} ({:.1}% setup)",
+			pallet,
+			extrinsic,
+			times.setup,
+			times.execution,
+			times.setup_percent()
+		);
+	}
+}
+
 /// List the benchmarks available in the runtime, in a CSV friendly format.
 fn list_benchmark(
 	benchmarks_to_run: Vec<(
@@ -15,9 +15,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(feature = "allocation-tracking")]
+mod allocations;
 mod command;
+mod timing;
 mod writer;
 
+pub use timing::TimeLimit;
+
 use crate::shared::HostInfoParams;
 use clap::ValueEnum;
 use sc_cli::{
@@ -58,6 +63,13 @@ pub struct PalletCmd {
 	#[arg(long)]
 	pub all: bool,
 
+	/// Only run the benchmarks tagged with any of these tags.
+	///
+	/// Benchmarks are tagged with `#[benchmark(tags = ["..."])]`. The selection by pallet and
+	/// extrinsic still applies.
+	#[arg(long, value_delimiter = ',')]
+	pub tags: Vec<String>,
+
 	/// Select how many samples we should take across the variable components.
 	#[arg(short, long, default_value_t = 50)]
 	pub steps: u32,
@@ -216,6 +228,33 @@ pub struct PalletCmd {
 	#[arg(long)]
 	pub json_input: Option<PathBuf>,
 
+	/// Fail if setting up a benchmark takes longer than this in total.
+	///
+	/// Either `SECONDS` for all benchmarks or `PALLET::EXTRINSIC=SECONDS` for a single one. Can be
+	/// given multiple times, the limit of a single benchmark takes precedence. Everything the
+	/// runtime does not measure counts as setup.
+	#[arg(long, value_name = "LIMIT", value_parser = timing::parse_time_limit)]
+	pub max_setup_time: Vec<TimeLimit>,
+
+	/// Fail if the measured code of a benchmark takes longer than this in total.
+	///
+	/// Accepts the same values as `--max-setup-time`.
+	#[arg(long, value_name = "LIMIT", value_parser = timing::parse_time_limit)]
+	pub max_execution_time: Vec<TimeLimit>,
+
+	/// Report the time spent setting up versus measuring every benchmark.
+	#[arg(long)]
+	pub profile_setup: bool,
+
+	/// Track the memory allocated by the runtime in every benchmark.
+	///
+	/// Every component step is run once more in a fresh runtime instance, whose peak and total
+	/// allocations are reported along with the other results. The setup of the benchmark is
+	/// included. This is slow, since the runtime is compiled anew for every measurement.
+	#[cfg(feature = "allocation-tracking")]
+	#[arg(long)]
+	pub track_allocations: bool,
+
 	/// Allow overwriting a single file with multiple results.
 	///
 	/// This exists only to restore legacy behaviour. It should never actually be needed.
@@ -0,0 +1,151 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Wall-clock limits and profiling of the time spent in benchmarks.
+
+use frame_benchmarking::BenchmarkBatch;
+use std::time::Duration;
+
+/// A wall-clock limit for all benchmarks or a single one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeLimit {
+	/// The pallet and extrinsic of the benchmark, or `None` for all benchmarks.
+	benchmark: Option<(String, String)>,
+	/// The maximal duration.
+	limit: Duration,
+}
+
+/// Parse a [`TimeLimit`] from either `SECONDS` or `PALLET::EXTRINSIC=SECONDS`.
+pub(crate) fn parse_time_limit(s: &str) -> Result<TimeLimit, String> {
+	let (benchmark, seconds) = match s.split_once('=') {
+		Some((benchmark, seconds)) => {
+			let (pallet, extrinsic) = benchmark
+				.split_once("::")
+				.ok_or_else(|| format!("Expected `PALLET::EXTRINSIC=SECONDS`, got `{}`", s))?;
+			(Some((pallet.replace("-", "_"), extrinsic.to_string())), seconds)
+		},
+		None => (None, s),
+	};
+	let seconds = seconds
+		.trim()
+		.parse::<u64>()
+		.map_err(|e| format!("Invalid number of seconds `{}`: {}", seconds, e))?;
+	Ok(TimeLimit { benchmark, limit: Duration::from_secs(seconds) })
+}
+
+/// The limit of the benchmark of `extrinsic` in `pallet`.
+///
+/// A limit given for the benchmark itself takes precedence over one for all benchmarks.
+pub(crate) fn limit_for(limits: &[TimeLimit], pallet: &str, extrinsic: &str) -> Option<Duration> {
+	let specific = limits.iter().rev().find(|limit| {
+		limit.benchmark.as_ref().map_or(false, |(p, e)| p == pallet && e == extrinsic)
+	});
+	let general = || limits.iter().rev().find(|limit| limit.benchmark.is_none());
+	specific.or_else(general).map(|limit| limit.limit)
+}
+
+/// The wall-clock time spent in a benchmark.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct BenchmarkTimes {
+	/// Time spent outside of the measured code, mostly setting up the benchmark.
+	pub setup: Duration,
+	/// Time spent in the measured code, including the storage root calculation.
+	pub execution: Duration,
+}
+
+impl BenchmarkTimes {
+	/// Account for a runtime call that took `elapsed` and returned `batches`.
+	///
+	/// Everything that is not measured by the runtime counts as setup, including the
+	/// instantiation of the runtime and committing the state.
+	pub fn record(&mut self, elapsed: Duration, batches: &[BenchmarkBatch]) {
+		let measured_nanos: u128 = batches
+			.iter()
+			.flat_map(|batch| batch.results.iter())
+			.map(|result| result.extrinsic_time.saturating_add(result.storage_root_time))
+			.sum();
+		let measured = Duration::from_nanos(measured_nanos.try_into().unwrap_or(u64::MAX));
+		self.execution = self.execution.saturating_add(measured);
+		self.setup = self.setup.saturating_add(elapsed.saturating_sub(measured));
+	}
+
+	/// The share of the total time that was spent in setup, in percent.
+	pub fn setup_percent(&self) -> f64 {
+		let total = self.setup + self.execution;
+		if total.is_zero() {
+			return 0.0
+		}
+		self.setup.as_secs_f64() * 100.0 / total.as_secs_f64()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use frame_benchmarking::BenchmarkResult;
+
+	#[test]
+	fn parse_time_limit_works() {
+		assert_eq!(
+			parse_time_limit("60"),
+			Ok(TimeLimit { benchmark: None, limit: Duration::from_secs(60) })
+		);
+		assert_eq!(
+			parse_time_limit("pallet-alliance::announce=5"),
+			Ok(TimeLimit {
+				benchmark: Some(("pallet_alliance".into(), "announce".into())),
+				limit: Duration::from_secs(5)
+			})
+		);
+		assert!(parse_time_limit("pallet_alliance=5").is_err());
+		assert!(parse_time_limit("soon").is_err());
+	}
+
+	#[test]
+	fn specific_limits_take_precedence() {
+		let limits = ["60", "pallet_alliance::announce=5"]
+			.into_iter()
+			.map(|limit| parse_time_limit(limit).unwrap())
+			.collect::<Vec<_>>();
+		assert_eq!(limit_for(&limits, "pallet_alliance", "announce"), Some(Duration::from_secs(5)));
+		assert_eq!(limit_for(&limits, "pallet_alliance", "join"), Some(Duration::from_secs(60)));
+		assert_eq!(limit_for(&limits[1..], "pallet_alliance", "join"), None);
+	}
+
+	#[test]
+	fn record_splits_setup_and_execution() {
+		let result = BenchmarkResult {
+			extrinsic_time: 2_000_000_000,
+			storage_root_time: 1_000_000_000,
+			..Default::default()
+		};
+		let batch = BenchmarkBatch {
+			pallet: b"pallet".to_vec(),
+			instance: b"instance".to_vec(),
+			benchmark: b"benchmark".to_vec(),
+			results: vec![result.clone(), result],
+		};
+
+		let mut times = BenchmarkTimes::default();
+		times.record(Duration::from_secs(8), &[batch]);
+		assert_eq!(
+			times,
+			BenchmarkTimes { setup: Duration::from_secs(2), execution: Duration::from_secs(6) }
+		);
+		assert_eq!(times.setup_percent(), 25.0);
+	}
+}
@@ -866,6 +866,7 @@ mod test {
 			benchmark: [benchmark.to_vec(), b"_benchmark".to_vec()].concat(),
 			time_results: results.clone(),
 			db_results: results,
+			allocation_results: vec![],
 		}
 	}
 
@@ -945,6 +946,7 @@ mod test {
 			benchmark: b"first_benchmark".to_vec(),
 			time_results: results.clone(),
 			db_results: results,
+			allocation_results: vec![],
 		};
 
 		let storage_info = vec![StorageInfo {
@@ -1002,6 +1004,7 @@ mod test {
 			benchmark: b"first_benchmark".to_vec(),
 			time_results: results.clone(),
 			db_results: results,
+			allocation_results: vec![],
 		};
 
 		let storage_info = vec![StorageInfo {
@@ -1059,6 +1062,7 @@ mod test {
 			benchmark: b"first_benchmark".to_vec(),
 			time_results: results.clone(),
 			db_results: results,
+			allocation_results: vec![],
 		};
 
 		let storage_info = vec![StorageInfo {
@@ -1114,6 +1118,7 @@ mod test {
 			benchmark: b"first_benchmark".to_vec(),
 			time_results: results.clone(),
 			db_results: results,
+			allocation_results: vec![],
 		};
 
 		let storage_info = vec![StorageInfo {
@@ -1171,6 +1176,7 @@ mod test {
 			benchmark: b"first_benchmark".to_vec(),
 			time_results: results.clone(),
 			db_results: results,
+			allocation_results: vec![],
 		};
 
 		let storage_info = vec![StorageInfo {
@@ -17,13 +17,14 @@
 
 use sc_cli::{CliConfiguration, DatabaseParams, PruningParams, Result, SharedParams};
 use sc_client_api::{Backend as ClientBackend, StorageProvider, UsageProvider};
-use sc_client_db::DbHash;
+use sc_client_db::{DbHash, DbStateBuilder};
 use sc_service::Configuration;
 use sp_blockchain::HeaderBackend;
 use sp_database::{ColumnId, Database};
-use sp_runtime::traits::{Block as BlockT, HashingFor};
-use sp_state_machine::Storage;
+use sp_runtime::traits::{Block as BlockT, HashingFor, Header as HeaderT};
+use sp_state_machine::{Backend as StateBackend, Storage};
 use sp_storage::{ChildInfo, ChildType, PrefixedStorageKey, StateVersion};
+use sp_trie::cache::{CacheSize, SharedTrieCache};
 
 use clap::{Args, Parser};
 use log::info;
@@ -116,6 +117,14 @@ pub struct StorageParams {
 	/// Include child trees in benchmark.
 	#[arg(long)]
 	pub include_child_trees: bool,
+
+	/// Additionally benchmark with a shared trie cache of each of these sizes in bytes.
+	///
+	/// Each size yields its own read and write weight constants. Providing `0` benchmarks
+	/// directly against the database column of the state. The cache is warmed up and kept
+	/// between the read and write rounds, like the cache of a running node.
+	#[arg(long, value_name = "Bytes", value_delimiter = ',')]
+	pub trie_cache_sizes: Vec<usize>,
 }
 
 impl StorageCmd {
@@ -137,6 +146,29 @@ impl StorageCmd {
 
 		let block_id = BlockId::<Block>::Number(client.usage_info().chain.best_number);
 		template.set_block_number(block_id.to_string());
+		template.set_state_column(db.1);
+
+		for &size in &self.params.trie_cache_sizes {
+			info!("Benchmarking with a trie cache of {} bytes", size);
+			let cache =
+				(size > 0).then(|| SharedTrieCache::<HashingFor<Block>>::new(CacheSize::new(size)));
+
+			let read = if self.params.skip_read {
+				None
+			} else {
+				self.bench_warmup_trie(&client, storage.clone(), cache.as_ref())?;
+				let record = self.bench_read_trie(&client, storage.clone(), cache.as_ref())?;
+				Some(record.calculate_stats()?)
+			};
+			let write = if self.params.skip_write {
+				None
+			} else {
+				let record =
+					self.bench_write(client.clone(), db.clone(), storage.clone(), cache.as_ref())?;
+				Some(record.calculate_stats()?)
+			};
+			template.add_trie_cache_stats(size, read, write)?;
+		}
 
 		if !self.params.skip_read {
 			self.bench_warmup(&client)?;
@@ -151,7 +183,7 @@ impl StorageCmd {
 
 		if !self.params.skip_write {
 			self.bench_warmup(&client)?;
-			let record = self.bench_write(client, db, storage)?;
+			let record = self.bench_write(client, db, storage, None)?;
 			if let Some(path) = &self.params.json_write_path {
 				record.save_json(&cfg, path, "write")?;
 			}
@@ -207,6 +239,40 @@ impl StorageCmd {
 
 		Ok(())
 	}
+
+	/// Run some rounds of the (read) benchmark against the state trie as warmup.
+	///
+	/// Every round reads through a fresh local cache that is merged into the shared `cache` once
+	/// the round is over.
+	fn bench_warmup_trie<B, BA, C>(
+		&self,
+		client: &Arc<C>,
+		storage: Arc<dyn Storage<HashingFor<B>>>,
+		cache: Option<&SharedTrieCache<HashingFor<B>>>,
+	) -> Result<()>
+	where
+		C: UsageProvider<B> + StorageProvider<B, BA> + HeaderBackend<B>,
+		B: BlockT<Hash = DbHash> + Debug,
+		BA: ClientBackend<B>,
+	{
+		let hash = client.usage_info().chain.best_hash;
+		let root = *client.header(hash)?.ok_or("Header not found")?.state_root();
+		let mut keys: Vec<_> = client.storage_keys(hash, None, None)?.collect();
+		let (mut rng, _) = new_rng(None);
+		keys.shuffle(&mut rng);
+
+		for i in 0..self.params.warmups {
+			info!("Warmup round {}/{}", i + 1, self.params.warmups);
+			let trie = DbStateBuilder::<HashingFor<B>>::new(storage.clone(), root)
+				.with_optional_cache(cache.map(|c| c.local_cache()))
+				.build();
+			for key in keys.as_slice() {
+				let _ = trie.storage(&key.0)?;
+			}
+		}
+
+		Ok(())
+	}
 }
 
 // Boilerplate
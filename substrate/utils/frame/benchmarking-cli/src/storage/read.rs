@@ -17,7 +17,11 @@
 
 use sc_cli::Result;
 use sc_client_api::{Backend as ClientBackend, StorageProvider, UsageProvider};
-use sp_runtime::traits::{Block as BlockT, Header as HeaderT};
+use sc_client_db::{DbHash, DbStateBuilder};
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::{Block as BlockT, HashingFor, Header as HeaderT};
+use sp_state_machine::{Backend as StateBackend, Storage};
+use sp_trie::cache::SharedTrieCache;
 
 use log::info;
 use rand::prelude::*;
@@ -84,4 +88,61 @@ impl StorageCmd {
 		}
 		Ok(record)
 	}
+
+	/// Benchmarks the time it takes to read a single Storage item directly from the state trie.
+	///
+	/// Unlike [`Self::bench_read`] this bypasses the client and reads through the given shared
+	/// trie `cache`, or straight from the `storage` if there is none.
+	pub(crate) fn bench_read_trie<B, BA, C>(
+		&self,
+		client: &Arc<C>,
+		storage: Arc<dyn Storage<HashingFor<B>>>,
+		cache: Option<&SharedTrieCache<HashingFor<B>>>,
+	) -> Result<BenchRecord>
+	where
+		C: UsageProvider<B> + StorageProvider<B, BA> + HeaderBackend<B>,
+		B: BlockT<Hash = DbHash> + Debug,
+		BA: ClientBackend<B>,
+	{
+		let mut record = BenchRecord::default();
+		let best_hash = client.usage_info().chain.best_hash;
+		let root = *client.header(best_hash)?.ok_or("Header not found")?.state_root();
+		let trie = DbStateBuilder::<HashingFor<B>>::new(storage, root)
+			.with_optional_cache(cache.map(|c| c.local_cache()))
+			.build();
+
+		info!("Preparing keys from block {}", best_hash);
+		let mut keys: Vec<_> = client.storage_keys(best_hash, None, None)?.collect();
+		let (mut rng, _) = new_rng(None);
+		keys.shuffle(&mut rng);
+
+		let mut child_nodes = Vec::new();
+		info!("Reading {} keys", keys.len());
+		for key in keys.as_slice() {
+			match (self.params.include_child_trees, self.is_child_key(key.clone().0)) {
+				(true, Some(info)) => {
+					for ck in client.child_storage_keys(best_hash, info.clone(), None, None)? {
+						child_nodes.push((ck.clone(), info.clone()));
+					}
+				},
+				_ => {
+					let start = Instant::now();
+					let v = trie.storage(&key.0)?.ok_or("Value unexpectedly empty")?;
+					record.append(v.len(), start.elapsed())?;
+				},
+			}
+		}
+
+		if self.params.include_child_trees {
+			child_nodes.shuffle(&mut rng);
+
+			info!("Reading {} child keys", child_nodes.len());
+			for (key, info) in child_nodes.as_slice() {
+				let start = Instant::now();
+				let v = trie.child_storage(info, &key.0)?.ok_or("Value unexpectedly empty")?;
+				record.append(v.len(), start.elapsed())?;
+			}
+		}
+		Ok(record)
+	}
 }
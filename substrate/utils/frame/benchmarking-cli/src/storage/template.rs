@@ -33,6 +33,8 @@ static TEMPLATE: &str = include_str!("./weights.hbs");
 pub(crate) struct TemplateData {
 	/// Name of the database used.
 	db_name: String,
+	/// Column of the database that holds the state.
+	state_column: u32,
 	/// Block number that was used.
 	block_number: String,
 	/// Name of the runtime. Taken from the chain spec.
@@ -61,6 +63,23 @@ pub(crate) struct TemplateData {
 	/// Stats about a `write` benchmark. Contains *time* and *value size* stats.
 	/// The *value size* stats are currently not used in the template.
 	write: Option<(Stats, Stats)>,
+	/// Weights per trie cache size of the `--trie-cache-sizes` benchmarks.
+	trie_cache: Vec<TrieCacheData>,
+}
+
+/// Weights and stats of the benchmarks that ran with one trie cache size.
+#[derive(Serialize, Default, Debug, Clone)]
+pub(crate) struct TrieCacheData {
+	/// Size of the shared trie cache in bytes. `0` means no cache.
+	size: usize,
+	/// The weight for one `read`.
+	read_weight: u64,
+	/// The weight for one `write`.
+	write_weight: u64,
+	/// Stats about the `read` benchmark.
+	read: Option<(Stats, Stats)>,
+	/// Stats about the `write` benchmark.
+	write: Option<(Stats, Stats)>,
 }
 
 impl TemplateData {
@@ -104,6 +123,35 @@ impl TemplateData {
 		Ok(())
 	}
 
+	/// Adds the stats of the benchmarks with a trie cache of `size` bytes and calculates their
+	/// weights.
+	pub fn add_trie_cache_stats(
+		&mut self,
+		size: usize,
+		read: Option<(Stats, Stats)>,
+		write: Option<(Stats, Stats)>,
+	) -> Result<()> {
+		let weight_params = &self.params.weight_params;
+		let read_weight = read
+			.as_ref()
+			.map(|r| weight_params.calc_weight(&r.0))
+			.transpose()?
+			.unwrap_or_default();
+		let write_weight = write
+			.as_ref()
+			.map(|w| weight_params.calc_weight(&w.0))
+			.transpose()?
+			.unwrap_or_default();
+		self.trie_cache
+			.push(TrieCacheData { size, read_weight, write_weight, read, write });
+		Ok(())
+	}
+
+	/// Sets the database column that holds the state.
+	pub fn set_state_column(&mut self, state_column: u32) {
+		self.state_column = state_column
+	}
+
 	/// Sets the block id that was used.
 	pub fn set_block_number(&mut self, block_number: String) {
 		self.block_number = block_number
@@ -22,7 +22,7 @@ use sp_blockchain::HeaderBackend;
 use sp_database::{ColumnId, Transaction};
 use sp_runtime::traits::{Block as BlockT, HashingFor, Header as HeaderT};
 use sp_state_machine::Backend as StateBackend;
-use sp_trie::PrefixedMemoryDB;
+use sp_trie::{cache::SharedTrieCache, PrefixedMemoryDB};
 
 use log::{info, trace};
 use rand::prelude::*;
@@ -39,11 +39,14 @@ use crate::shared::{new_rng, BenchRecord};
 impl StorageCmd {
 	/// Benchmarks the time it takes to write a single Storage item.
 	/// Uses the latest state that is available for the given client.
+	///
+	/// The storage roots are calculated through the shared trie `cache` if one is given.
 	pub(crate) fn bench_write<Block, BA, H, C>(
 		&self,
 		client: Arc<C>,
 		(db, state_col): (Arc<dyn sp_database::Database<DbHash>>, ColumnId),
 		storage: Arc<dyn sp_state_machine::Storage<HashingFor<Block>>>,
+		cache: Option<&SharedTrieCache<HashingFor<Block>>>,
 	) -> Result<BenchRecord>
 	where
 		Block: BlockT<Header = H, Hash = DbHash> + Debug,
@@ -57,7 +60,9 @@ impl StorageCmd {
 		let best_hash = client.usage_info().chain.best_hash;
 		let header = client.header(best_hash)?.ok_or("Header not found")?;
 		let original_root = *header.state_root();
-		let trie = DbStateBuilder::<HashingFor<Block>>::new(storage.clone(), original_root).build();
+		let trie = DbStateBuilder::<HashingFor<Block>>::new(storage.clone(), original_root)
+			.with_optional_cache(cache.map(|c| c.local_cache()))
+			.build();
 
 		info!("Preparing keys from block {}", best_hash);
 		// Load all KV pairs and randomly shuffle them.
@@ -26,8 +26,8 @@ use jsonrpsee::{
 	types::error::ErrorObject,
 };
 
-use sc_rpc_api::DenyUnsafe;
-use sc_transaction_pool_api::{InPoolTransaction, TransactionPool};
+use sc_rpc_api::{AccountNonceProvider, DenyUnsafe};
+use sc_transaction_pool_api::{AccountStatus, InPoolTransaction, TransactionPool};
 use sp_api::ApiExt;
 use sp_block_builder::BlockBuilder;
 use sp_blockchain::HeaderBackend;
@@ -50,6 +50,14 @@ pub trait SystemApi<BlockHash, AccountId, Nonce> {
 	/// Dry run an extrinsic at a given block. Return SCALE encoded ApplyExtrinsicResult.
 	#[method(name = "system_dryRun", aliases = ["system_dryRunAt"])]
 	async fn dry_run(&self, extrinsic: Bytes, at: Option<BlockHash>) -> RpcResult<Bytes>;
+
+	/// Returns the transactions of the given account in the pool.
+	///
+	/// Lists ready and future transactions ordered by nonce, together with the ranges of nonces
+	/// missing between the state nonce and the highest nonce in the pool. Future transactions
+	/// only become ready once these gaps are filled. Transaction hashes are hex encoded.
+	#[method(name = "txpool_unstable_accountStatus")]
+	async fn account_status(&self, account: AccountId) -> RpcResult<AccountStatus<Bytes, Nonce>>;
 }
 
 /// Error type of this RPC api.
@@ -69,6 +77,33 @@ impl From<Error> for i32 {
 	}
 }
 
+/// Provides account nonces with the [`AccountNonceApi`] of the runtime, e.g. to
+/// `sc_rpc_spec_v2::transaction::TransactionNonce`.
+pub struct RuntimeAccountNonce<C> {
+	client: Arc<C>,
+}
+
+impl<C> RuntimeAccountNonce<C> {
+	/// Create a new [`RuntimeAccountNonce`] querying the runtime of `client`.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client }
+	}
+}
+
+impl<C, Block, AccountId, Nonce> AccountNonceProvider<Block, AccountId, Nonce>
+	for RuntimeAccountNonce<C>
+where
+	C: sp_api::ProvideRuntimeApi<Block> + Send + Sync,
+	C::Api: AccountNonceApi<Block, AccountId, Nonce>,
+	Block: traits::Block,
+	AccountId: Codec,
+	Nonce: Codec,
+{
+	fn account_nonce(&self, at: Block::Hash, account: AccountId) -> Result<Nonce, String> {
+		self.client.runtime_api().account_nonce(at, account).map_err(|e| e.to_string())
+	}
+}
+
 /// An implementation of System-specific RPC methods on full client.
 pub struct System<P: TransactionPool, C, B> {
 	client: Arc<C>,
@@ -112,6 +147,21 @@ where
 		Ok(adjust_nonce(&*self.pool, account, nonce))
 	}
 
+	async fn account_status(&self, account: AccountId) -> RpcResult<AccountStatus<Bytes, Nonce>> {
+		let api = self.client.runtime_api();
+		let best = self.client.info().best_hash;
+
+		let nonce = api.account_nonce(best, account.clone()).map_err(|e| {
+			ErrorObject::owned(
+				Error::RuntimeError.into(),
+				"Unable to query nonce.",
+				Some(e.to_string()),
+			)
+		})?;
+		Ok(AccountStatus::from_pool(&*self.pool, &account, nonce)
+			.map_hash(|hash| hash.encode().into()))
+	}
+
 	async fn dry_run(
 		&self,
 		extrinsic: Bytes,
@@ -217,6 +267,7 @@ mod tests {
 	use assert_matches::assert_matches;
 	use futures::executor::block_on;
 	use sc_transaction_pool::BasicPool;
+	use sc_transaction_pool_api::NonceGap;
 	use sp_runtime::{
 		transaction_validity::{InvalidTransaction, TransactionValidityError},
 		ApplyExtrinsicResult,
@@ -259,6 +310,49 @@ mod tests {
 		assert_eq!(nonce.unwrap(), 2);
 	}
 
+	#[tokio::test]
+	async fn should_report_nonce_gaps_for_some_account() {
+		sp_tracing::try_init_simple();
+
+		// given
+		let client = Arc::new(substrate_test_runtime_client::new());
+		let spawner = sp_core::testing::TaskExecutor::new();
+		let pool =
+			BasicPool::new_full(Default::default(), true.into(), None, spawner, client.clone());
+
+		let source = sp_runtime::transaction_validity::TransactionSource::External;
+		let new_transaction = |nonce: u64| {
+			let t = Transfer {
+				from: AccountKeyring::Alice.into(),
+				to: AccountKeyring::Bob.into(),
+				amount: 5,
+				nonce,
+			};
+			t.into_unchecked_extrinsic()
+		};
+		let hash_of_block0 = client.info().genesis_hash;
+		// Populate the pool
+		for nonce in [0, 2, 5] {
+			block_on(pool.submit_one(hash_of_block0, source, new_transaction(nonce))).unwrap();
+		}
+
+		let accounts = System::new(client, pool, DenyUnsafe::Yes);
+
+		// when
+		let status = accounts.account_status(AccountKeyring::Alice.into()).await.unwrap();
+
+		// then
+		assert_eq!(status.on_chain_nonce, 0);
+		assert_eq!(status.ready.iter().map(|tx| tx.nonce).collect::<Vec<_>>(), vec![0]);
+		assert_eq!(status.future.iter().map(|tx| tx.nonce).collect::<Vec<_>>(), vec![2, 5]);
+		assert_eq!(status.gaps, vec![NonceGap { start: 1, end: 1 }, NonceGap { start: 3, end: 4 }]);
+		assert_eq!(status.next_nonce(), 1);
+
+		// Other accounts are not affected.
+		let status = accounts.account_status(AccountKeyring::Bob.into()).await.unwrap();
+		assert!(status.ready.is_empty() && status.future.is_empty() && status.gaps.is_empty());
+	}
+
 	#[tokio::test]
 	async fn dry_run_should_deny_unsafe() {
 		sp_tracing::try_init_simple();